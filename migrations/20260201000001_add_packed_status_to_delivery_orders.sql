@@ -0,0 +1,21 @@
+-- Migration: Add 'packed' status to delivery_orders
+-- Description: The delivery workflow transitions picked -> packed -> shipped, but the
+--              original CHECK constraint did not include 'packed'
+-- Created: 2026-02-01
+
+-- ============================================
+-- Step 1: Drop existing status CHECK constraint
+-- ============================================
+ALTER TABLE delivery_orders DROP CONSTRAINT IF EXISTS delivery_orders_status_check;
+
+-- ============================================
+-- Step 2: Add CHECK constraint including 'packed'
+-- ============================================
+ALTER TABLE delivery_orders
+ADD CONSTRAINT delivery_orders_status_check
+CHECK (status IN ('draft', 'confirmed', 'partially_picked', 'picked', 'packed', 'partially_shipped', 'shipped', 'cancelled'));
+
+-- ============================================
+-- Step 3: Comments for documentation
+-- ============================================
+COMMENT ON COLUMN delivery_orders.status IS 'Delivery status: draft/confirmed/partially_picked/picked/packed/partially_shipped/shipped/cancelled';
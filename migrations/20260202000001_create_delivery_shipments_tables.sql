@@ -0,0 +1,68 @@
+-- Migration: Create delivery_shipments and delivery_shipment_items tables
+-- Description: Records each parcel shipped against a delivery order so a delivery
+--              can be shipped in several partial shipments
+-- Created: 2026-02-02
+
+-- ============================================
+-- Step 1: Shipment header (one row per ship call)
+-- ============================================
+CREATE TABLE delivery_shipments (
+    shipment_id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    tenant_id UUID NOT NULL REFERENCES tenants(tenant_id),
+    delivery_id UUID NOT NULL,
+
+    -- Carrier details for this parcel
+    tracking_number VARCHAR(100),
+    carrier VARCHAR(100),
+    shipping_cost BIGINT,   -- In smallest currency unit (cents/xu)
+    notes TEXT,
+
+    shipped_by UUID NOT NULL REFERENCES users(user_id),
+    shipped_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT delivery_shipments_tenant_delivery_fk
+        FOREIGN KEY (tenant_id, delivery_id)
+        REFERENCES delivery_orders (tenant_id, delivery_id),
+    CONSTRAINT delivery_shipments_tenant_shipment_unique
+        UNIQUE (tenant_id, shipment_id),
+    CONSTRAINT delivery_shipments_positive_cost
+        CHECK (shipping_cost IS NULL OR shipping_cost >= 0)
+);
+
+-- ============================================
+-- Step 2: Shipment lines (quantity shipped per delivery line)
+-- ============================================
+CREATE TABLE delivery_shipment_items (
+    shipment_item_id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    tenant_id UUID NOT NULL REFERENCES tenants(tenant_id),
+    shipment_id UUID NOT NULL,
+    delivery_item_id UUID NOT NULL REFERENCES delivery_order_items(delivery_item_id),
+    product_id UUID NOT NULL,
+    quantity BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT delivery_shipment_items_tenant_shipment_fk
+        FOREIGN KEY (tenant_id, shipment_id)
+        REFERENCES delivery_shipments (tenant_id, shipment_id),
+    CONSTRAINT delivery_shipment_items_positive_quantity
+        CHECK (quantity > 0),
+    CONSTRAINT delivery_shipment_items_unique_line
+        UNIQUE (shipment_id, delivery_item_id)
+);
+
+-- ============================================
+-- Step 3: Indexes
+-- ============================================
+CREATE INDEX idx_delivery_shipments_tenant_delivery
+    ON delivery_shipments(tenant_id, delivery_id);
+
+CREATE INDEX idx_delivery_shipment_items_tenant_delivery_item
+    ON delivery_shipment_items(tenant_id, delivery_item_id);
+
+-- ============================================
+-- Step 4: Comments for documentation
+-- ============================================
+COMMENT ON TABLE delivery_shipments IS 'Parcels shipped against a delivery order (supports partial shipments)';
+COMMENT ON TABLE delivery_shipment_items IS 'Quantity of each delivery line included in a shipment';
+COMMENT ON COLUMN delivery_shipment_items.quantity IS 'Units of the delivery line shipped in this parcel';
@@ -0,0 +1,43 @@
+-- Migration: Quality hold gate for received stock
+-- Description: Received quantities of products with an active incoming QC point land in
+--              quality_hold_quantity (not sellable) until a quality check passes. A pass
+--              releases the quantity to available; a fail moves it to quarantine.
+-- Created: 2026-02-03
+
+-- ============================================
+-- Step 1: Hold and quarantine buckets on inventory levels
+-- ============================================
+ALTER TABLE inventory_levels
+    ADD COLUMN IF NOT EXISTS quality_hold_quantity BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS quarantined_quantity BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE inventory_levels
+    ADD CONSTRAINT inventory_levels_quality_hold_non_negative
+        CHECK (quality_hold_quantity >= 0),
+    ADD CONSTRAINT inventory_levels_quarantined_non_negative
+        CHECK (quarantined_quantity >= 0);
+
+-- ============================================
+-- Step 2: Track the held quantity on each quality check
+-- ============================================
+ALTER TABLE quality_checks
+    ADD COLUMN IF NOT EXISTS warehouse_id UUID,
+    ADD COLUMN IF NOT EXISTS quantity BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE quality_checks
+    ADD CONSTRAINT quality_checks_quantity_non_negative CHECK (quantity >= 0),
+    ADD CONSTRAINT quality_checks_tenant_warehouse_fk
+        FOREIGN KEY (tenant_id, warehouse_id) REFERENCES warehouses(tenant_id, warehouse_id);
+
+-- Pending checks are looked up when inspectors record results
+CREATE INDEX IF NOT EXISTS idx_quality_checks_tenant_pending
+    ON quality_checks(tenant_id, product_id)
+    WHERE status = 'pending';
+
+-- ============================================
+-- Step 3: Comments for documentation
+-- ============================================
+COMMENT ON COLUMN inventory_levels.quality_hold_quantity IS 'Received quantity awaiting incoming quality inspection (not available for sale)';
+COMMENT ON COLUMN inventory_levels.quarantined_quantity IS 'Quantity that failed quality inspection and is held in quarantine';
+COMMENT ON COLUMN quality_checks.warehouse_id IS 'Warehouse holding the inspected stock';
+COMMENT ON COLUMN quality_checks.quantity IS 'Quantity held in quality_hold pending this check';
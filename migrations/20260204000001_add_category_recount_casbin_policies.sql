@@ -0,0 +1,22 @@
+-- Migration: Add Casbin policies for the category product count recount endpoint
+-- Description: POST /api/v1/inventory/categories/recount recomputes product counts for
+--              all categories of a tenant. It is not covered by the existing
+--              /api/v1/inventory/categories/bulk/* policy, so grant it explicitly.
+
+-- ============================================================================
+-- CATEGORY RECOUNT POLICIES (owner and admin only)
+-- ============================================================================
+
+-- Owner: May trigger a recount
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/categories/recount', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May trigger a recount
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/categories/recount', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
@@ -0,0 +1,52 @@
+-- Migration: Tenant row-level security
+-- Description: Defense-in-depth for multi-tenancy. Every table with a tenant_id
+--              column gets a tenant_isolation policy keyed on the transaction-local
+--              setting app.current_tenant (set by shared_db::begin_tenant_tx).
+--              When the setting is present only that tenant's rows are visible or
+--              writable; when it is absent the policy allows all rows, so existing
+--              application-level filtering keeps working unchanged.
+
+-- ============================================================================
+-- CURRENT TENANT HELPER
+-- ============================================================================
+
+-- Returns NULL when no tenant scope is set (never set, or reset to '' after the
+-- scoping transaction ended on a pooled connection)
+CREATE OR REPLACE FUNCTION app_current_tenant()
+RETURNS UUID AS $$
+    SELECT NULLIF(current_setting('app.current_tenant', true), '')::UUID
+$$ LANGUAGE sql STABLE;
+
+COMMENT ON FUNCTION app_current_tenant() IS 'Tenant scope of the current transaction (NULL if unscoped)';
+
+-- ============================================================================
+-- TENANT ISOLATION POLICIES
+-- ============================================================================
+
+-- FORCE applies the policies to the table owner too, which is the role the
+-- services connect as. The tenants table itself is the registry and stays global.
+DO $$
+DECLARE
+    t RECORD;
+BEGIN
+    FOR t IN
+        SELECT c.table_name
+        FROM information_schema.columns c
+        JOIN information_schema.tables tb
+            ON tb.table_schema = c.table_schema AND tb.table_name = c.table_name
+        WHERE c.table_schema = 'public'
+          AND c.column_name = 'tenant_id'
+          AND tb.table_type = 'BASE TABLE'
+          AND c.table_name <> 'tenants'
+    LOOP
+        EXECUTE format('ALTER TABLE %I ENABLE ROW LEVEL SECURITY', t.table_name);
+        EXECUTE format('ALTER TABLE %I FORCE ROW LEVEL SECURITY', t.table_name);
+        EXECUTE format('DROP POLICY IF EXISTS tenant_isolation ON %I', t.table_name);
+        EXECUTE format(
+            'CREATE POLICY tenant_isolation ON %I
+                 USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+                 WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())',
+            t.table_name
+        );
+    END LOOP;
+END $$;
@@ -0,0 +1,52 @@
+-- Migration: Transfer ETA and tracking
+-- Description: Adds expected_arrival to stock transfers so inbound teams can see when an
+--              open transfer will arrive. carrier and tracking_number already exist and are
+--              now editable through PUT /api/v1/inventory/transfers/{id}/tracking.
+-- Created: 2026-02-06
+
+-- ============================================
+-- Step 1: Expected arrival column
+-- ============================================
+ALTER TABLE stock_transfers
+    ADD COLUMN IF NOT EXISTS expected_arrival TIMESTAMPTZ;
+
+-- The in-transit listing filters open transfers and sorts by expected arrival
+CREATE INDEX IF NOT EXISTS idx_stock_transfers_tenant_in_transit_arrival
+    ON stock_transfers(tenant_id, expected_arrival)
+    WHERE deleted_at IS NULL
+      AND status IN ('confirmed', 'partially_picked', 'picked', 'partially_shipped', 'shipped');
+
+COMMENT ON COLUMN stock_transfers.expected_arrival IS 'Estimated arrival at the destination warehouse (carrier ETA)';
+
+-- ============================================
+-- Step 2: Casbin policies for the tracking endpoint
+-- ============================================
+-- The /api/v1/inventory/transfers/* policy doesn't match nested paths like /{id}/tracking
+
+-- Owner: May update tracking
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/transfers/*/tracking', 'PUT', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May update tracking
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/transfers/*/tracking', 'PUT', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Manager: May update tracking
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'manager', t.tenant_id::text, '/api/v1/inventory/transfers/*/tracking', 'PUT', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- User: May update tracking (same as confirm/receive)
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'user', t.tenant_id::text, '/api/v1/inventory/transfers/*/tracking', 'PUT', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
@@ -0,0 +1,32 @@
+-- Migration: Reservation over-commit (backorder) buffer
+-- Description: Lets a product be reserved beyond its available stock by a configurable
+--              percentage of on-hand quantity. The part of a reservation that is not covered
+--              by available stock is tracked as backordered_quantity on the inventory level
+--              instead of reserved_quantity, so it can be reported separately.
+-- Created: 2026-02-07
+
+-- ============================================
+-- Step 1: Per-product over-commit percentage
+-- ============================================
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS overcommit_pct INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE products
+    ADD CONSTRAINT products_overcommit_pct_range
+        CHECK (overcommit_pct >= 0 AND overcommit_pct <= 100);
+
+-- ============================================
+-- Step 2: Backordered bucket on inventory levels
+-- ============================================
+ALTER TABLE inventory_levels
+    ADD COLUMN IF NOT EXISTS backordered_quantity BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE inventory_levels
+    ADD CONSTRAINT inventory_levels_backordered_non_negative
+        CHECK (backordered_quantity >= 0);
+
+-- ============================================
+-- Step 3: Comments for documentation
+-- ============================================
+COMMENT ON COLUMN products.overcommit_pct IS 'Percentage of on-hand stock (available + reserved) that may be reserved beyond availability as backorders';
+COMMENT ON COLUMN inventory_levels.backordered_quantity IS 'Reserved quantity not covered by physical stock (over-committed reservations)';
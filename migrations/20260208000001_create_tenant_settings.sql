@@ -0,0 +1,85 @@
+-- Migration: Tenant settings (feature flags)
+-- Description: Central per-tenant toggles for optional behaviors. A missing row means the
+--              flag is at its default (off). Read through FeatureFlagService, which caches
+--              lookups in Redis and invalidates them on update.
+-- Created: 2026-02-08
+
+-- ============================================
+-- Step 1: Tenant settings table
+-- ============================================
+CREATE TABLE IF NOT EXISTS tenant_settings (
+    tenant_id UUID NOT NULL REFERENCES tenants(tenant_id),
+    setting_key VARCHAR(100) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+
+    -- Audit fields
+    updated_by UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tenant_settings_pkey PRIMARY KEY (tenant_id, setting_key),
+    CONSTRAINT tenant_settings_key_format CHECK (setting_key ~ '^[a-z][a-z0-9_]*$')
+);
+
+CREATE TRIGGER update_tenant_settings_updated_at
+    BEFORE UPDATE ON tenant_settings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================
+-- Step 2: Tenant isolation (same policy as other tenant tables)
+-- ============================================
+ALTER TABLE tenant_settings ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tenant_settings FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON tenant_settings
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
+
+COMMENT ON TABLE tenant_settings IS 'Per-tenant feature flags; absent rows fall back to the flag default (off)';
+COMMENT ON COLUMN tenant_settings.setting_key IS 'Feature flag key, e.g. negative_stock, blind_counts';
+
+-- ============================================
+-- Step 3: Casbin policies for the feature flag admin endpoints (owner and admin only)
+-- ============================================
+
+-- Owner: May list flags
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/feature-flags', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Owner: May read a single flag
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/feature-flags/*', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Owner: May set a flag
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/feature-flags/*', 'PUT', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May list flags
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/feature-flags', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May read a single flag
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/feature-flags/*', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May set a flag
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/feature-flags/*', 'PUT', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
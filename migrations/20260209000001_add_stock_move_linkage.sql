@@ -0,0 +1,73 @@
+-- Migration: Stock move reason and source document linkage
+-- Description: Every stock move now records a reason_code and the document it came from
+--              (source_type/source_id). Reversals are compensating moves that point back
+--              to the original through reversal_of_move_id.
+-- Created: 2026-02-09
+
+-- ============================================
+-- Step 1: Linkage columns
+-- ============================================
+ALTER TABLE stock_moves
+    ADD COLUMN IF NOT EXISTS reason_code VARCHAR(50),
+    ADD COLUMN IF NOT EXISTS source_type VARCHAR(30),
+    ADD COLUMN IF NOT EXISTS source_id UUID,
+    ADD COLUMN IF NOT EXISTS reversal_of_move_id UUID REFERENCES stock_moves(move_id);
+
+-- ============================================
+-- Step 2: Backfill existing moves from the legacy reference columns
+-- ============================================
+UPDATE stock_moves
+SET source_type = CASE reference_type
+        WHEN 'grn' THEN 'receipt'
+        WHEN 'do' THEN 'delivery'
+        WHEN 'manual' THEN 'adjustment'
+        ELSE reference_type
+    END,
+    source_id = reference_id,
+    reason_code = COALESCE(reason_code, move_type)
+WHERE source_type IS NULL;
+
+-- ============================================
+-- Step 3: Constraints
+-- ============================================
+-- The original move_type/reference_type lists predate scrap, RMA, putaway and count
+-- documents; services already write those values, so widen move_type and let
+-- source_type carry the controlled document vocabulary instead of reference_type.
+ALTER TABLE stock_moves DROP CONSTRAINT IF EXISTS stock_moves_move_type_check;
+ALTER TABLE stock_moves DROP CONSTRAINT IF EXISTS stock_moves_reference_type_check;
+
+ALTER TABLE stock_moves
+    ADD CONSTRAINT stock_moves_move_type_check
+    CHECK (move_type IN (
+        'receipt', 'delivery', 'transfer', 'adjustment', 'production', 'consumption',
+        'scrap', 'putaway', 'rma_return', 'reversal'
+    ));
+
+ALTER TABLE stock_moves
+    ADD CONSTRAINT stock_moves_source_type_check
+    CHECK (source_type IS NULL OR source_type IN (
+        'receipt', 'delivery', 'transfer', 'scrap', 'adjustment', 'rma',
+        'cycle_count', 'stock_take', 'reconciliation', 'putaway', 'production', 'consumption'
+    ));
+
+-- Source linkage is all-or-nothing
+ALTER TABLE stock_moves
+    ADD CONSTRAINT stock_moves_source_complete
+    CHECK ((source_type IS NULL) = (source_id IS NULL));
+
+-- A move is reversed at most once, and only by a reversal move
+ALTER TABLE stock_moves
+    ADD CONSTRAINT stock_moves_reversal_type
+    CHECK (reversal_of_move_id IS NULL OR move_type = 'reversal');
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_stock_moves_tenant_reversal_of
+    ON stock_moves(tenant_id, reversal_of_move_id)
+    WHERE reversal_of_move_id IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_stock_moves_tenant_source
+    ON stock_moves(tenant_id, source_type, source_id);
+
+COMMENT ON COLUMN stock_moves.reason_code IS 'Machine-readable reason, e.g. goods_receipt, damaged, cycle_count_variance, reversal';
+COMMENT ON COLUMN stock_moves.source_type IS 'Type of the document that caused the move';
+COMMENT ON COLUMN stock_moves.source_id IS 'ID of the document that caused the move';
+COMMENT ON COLUMN stock_moves.reversal_of_move_id IS 'Original move this compensating move reverses';
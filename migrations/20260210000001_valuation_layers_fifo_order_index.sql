@@ -0,0 +1,10 @@
+-- Migration: Deterministic FIFO layer order
+-- Description: FIFO consumption orders layers by (created_at, layer_id) so layers
+--              created in the same transaction are consumed in insertion order.
+--              Extend the ordering index with the layer_id tie-breaker.
+-- Created: 2026-02-10
+
+DROP INDEX IF EXISTS idx_valuation_layers_tenant_product_created;
+
+CREATE INDEX IF NOT EXISTS idx_valuation_layers_tenant_product_created
+    ON inventory_valuation_layers(tenant_id, product_id, created_at, layer_id);
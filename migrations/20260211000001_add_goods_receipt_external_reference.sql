@@ -0,0 +1,14 @@
+-- Migration: Idempotent receipt posting by external reference
+-- Description: Integrations (e.g. NATS consumers) resubmit the same purchase receipt on
+--              retries. An external reference is unique per tenant so a resubmission
+--              resolves to the receipt that was already created.
+-- Created: 2026-02-11
+
+ALTER TABLE goods_receipts
+    ADD COLUMN IF NOT EXISTS external_reference VARCHAR(100);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_goods_receipts_tenant_external_reference
+    ON goods_receipts(tenant_id, external_reference)
+    WHERE external_reference IS NOT NULL AND deleted_at IS NULL;
+
+COMMENT ON COLUMN goods_receipts.external_reference IS 'Caller-supplied idempotency reference, unique per tenant';
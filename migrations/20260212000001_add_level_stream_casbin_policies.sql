@@ -0,0 +1,35 @@
+-- Migration: Add Casbin policies for the inventory level change stream
+-- Description: Grants read access to the SSE level stream to the same roles that can read stock levels
+-- The stream endpoint is read-only (GET only)
+
+-- ============================================================================
+-- LEVEL STREAM POLICIES
+-- ============================================================================
+
+-- Admin: Subscribe to level changes
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.slug, '/api/v1/inventory/levels/stream', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Manager: Subscribe to level changes
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'manager', t.slug, '/api/v1/inventory/levels/stream', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- User: Subscribe to level changes
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'user', t.slug, '/api/v1/inventory/levels/stream', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Viewer: Subscribe to level changes
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'viewer', t.slug, '/api/v1/inventory/levels/stream', 'GET', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
@@ -0,0 +1,72 @@
+-- Migration: Per-location inventory level history
+-- Description: Append-only timeline of quantity changes per (location, product), written
+--              alongside every stock move. A move between two locations adds one row per
+--              side; a move within one location adds a single signed row. Balances are
+--              computed on read with a running sum, so writes never read or update rows.
+-- Created: 2026-02-13
+
+-- ============================================
+-- Step 1: History table
+-- ============================================
+-- No foreign keys: rows are only ever inserted in the same transaction as their
+-- stock move, and skipping the checks keeps the extra write cheap.
+CREATE TABLE IF NOT EXISTS inventory_level_history (
+    history_id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    location_id UUID NOT NULL,
+    product_id UUID NOT NULL,
+    move_id UUID NOT NULL,
+    move_type VARCHAR(50) NOT NULL,
+    quantity_change BIGINT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_inventory_level_history_timeline
+    ON inventory_level_history(tenant_id, location_id, product_id, history_id);
+
+-- ============================================
+-- Step 2: Tenant isolation (same policy as other tenant tables)
+-- ============================================
+ALTER TABLE inventory_level_history ENABLE ROW LEVEL SECURITY;
+ALTER TABLE inventory_level_history FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON inventory_level_history
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
+
+-- ============================================
+-- Step 3: Backfill from existing stock moves
+-- ============================================
+-- Same rule as location_deltas(): one signed row for a move within a location,
+-- otherwise |quantity| out of the source and into the destination.
+INSERT INTO inventory_level_history (
+    tenant_id, location_id, product_id, move_id, move_type, quantity_change, recorded_at
+)
+SELECT sm.tenant_id, change.location_id, sm.product_id, sm.move_id, sm.move_type,
+       change.quantity_change, sm.created_at
+FROM stock_moves sm
+CROSS JOIN LATERAL (
+    SELECT sm.source_location_id AS location_id, sm.quantity AS quantity_change
+    WHERE sm.source_location_id = sm.destination_location_id
+    UNION ALL
+    SELECT sm.source_location_id, -ABS(sm.quantity)
+    WHERE sm.source_location_id IS NOT NULL
+      AND sm.source_location_id IS DISTINCT FROM sm.destination_location_id
+    UNION ALL
+    SELECT sm.destination_location_id, ABS(sm.quantity)
+    WHERE sm.destination_location_id IS NOT NULL
+      AND sm.destination_location_id IS DISTINCT FROM sm.source_location_id
+) change
+ORDER BY sm.created_at, sm.move_id;
+
+COMMENT ON TABLE inventory_level_history IS 'Append-only per-location quantity changes, one row per location touched by a stock move';
+COMMENT ON COLUMN inventory_level_history.quantity_change IS 'Signed change at location_id; running SUM ordered by history_id gives the balance';
+
+-- ============================================
+-- Step 4: Casbin policies for the history endpoint (read-only, same roles as stock levels)
+-- ============================================
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', r.role, t.slug, '/api/v1/inventory/stock-levels/history', 'GET', '', ''
+FROM tenants t
+CROSS JOIN (VALUES ('admin'), ('manager'), ('user'), ('viewer')) AS r(role)
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
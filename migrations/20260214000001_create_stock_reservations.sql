@@ -0,0 +1,72 @@
+-- Migration: Prioritised stock reservations
+-- Description: Reservations that wait for stock. When stock is scarce they are filled by
+--              priority (higher first, e.g. paid orders before quotes) and then creation
+--              order; a reservation that cannot be covered in full keeps the remainder as
+--              backordered until more stock is allocated.
+-- Created: 2026-02-14
+
+-- ============================================
+-- Step 1: Reservations table
+-- ============================================
+CREATE TABLE IF NOT EXISTS stock_reservations (
+    reservation_id UUID NOT NULL DEFAULT uuid_generate_v7(),
+    tenant_id UUID NOT NULL REFERENCES tenants(tenant_id),
+    warehouse_id UUID NOT NULL,
+    product_id UUID NOT NULL,
+
+    -- Document holding the reservation (e.g. a sales order)
+    source_type VARCHAR(50),
+    source_id UUID,
+
+    priority INTEGER NOT NULL DEFAULT 0,
+    requested_quantity BIGINT NOT NULL,
+    allocated_quantity BIGINT NOT NULL DEFAULT 0,
+    backordered_quantity BIGINT NOT NULL DEFAULT 0,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT stock_reservations_pkey PRIMARY KEY (reservation_id),
+    CONSTRAINT stock_reservations_warehouse_fk
+        FOREIGN KEY (tenant_id, warehouse_id) REFERENCES warehouses (tenant_id, warehouse_id),
+    CONSTRAINT stock_reservations_product_fk
+        FOREIGN KEY (tenant_id, product_id) REFERENCES products (tenant_id, product_id),
+    CONSTRAINT stock_reservations_requested_positive CHECK (requested_quantity > 0),
+    CONSTRAINT stock_reservations_quantities_valid CHECK (
+        allocated_quantity >= 0
+        AND backordered_quantity >= 0
+        AND allocated_quantity + backordered_quantity <= requested_quantity
+    ),
+    CONSTRAINT stock_reservations_status_check
+        CHECK (status IN ('pending', 'reserved', 'backordered')),
+    CONSTRAINT stock_reservations_source_complete
+        CHECK ((source_type IS NULL) = (source_id IS NULL))
+);
+
+-- Open reservations of a product in allocation order
+CREATE INDEX IF NOT EXISTS idx_stock_reservations_allocation
+    ON stock_reservations(tenant_id, product_id, priority DESC, created_at)
+    WHERE status IN ('pending', 'backordered');
+
+CREATE INDEX IF NOT EXISTS idx_stock_reservations_source
+    ON stock_reservations(tenant_id, source_id)
+    WHERE source_id IS NOT NULL;
+
+CREATE TRIGGER update_stock_reservations_updated_at
+    BEFORE UPDATE ON stock_reservations
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================
+-- Step 2: Tenant isolation (same policy as other tenant tables)
+-- ============================================
+ALTER TABLE stock_reservations ENABLE ROW LEVEL SECURITY;
+ALTER TABLE stock_reservations FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON stock_reservations
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
+
+COMMENT ON TABLE stock_reservations IS 'Reservations competing for scarce stock, filled by priority then creation order';
+COMMENT ON COLUMN stock_reservations.priority IS 'Higher values are allocated first (e.g. paid orders above quotes)';
+COMMENT ON COLUMN stock_reservations.backordered_quantity IS 'Requested quantity not yet covered by allocated stock';
@@ -0,0 +1,64 @@
+-- Migration: Internal (warehouse-to-warehouse) replenishment rules
+-- Description: Rules for resupplying a destination warehouse from a source warehouse
+--              (e.g. a store from a central DC). When the destination drops below
+--              min_quantity and the source holds stock above source_safety_stock, a
+--              transfer topping the destination up to max_quantity is proposed.
+-- Created: 2026-02-15
+
+-- ============================================
+-- Step 1: Rules table
+-- ============================================
+CREATE TABLE IF NOT EXISTS internal_replenishment_rules (
+    rule_id UUID NOT NULL DEFAULT uuid_generate_v7(),
+    tenant_id UUID NOT NULL REFERENCES tenants(tenant_id),
+    product_id UUID NOT NULL,
+    from_warehouse_id UUID NOT NULL,
+    to_warehouse_id UUID NOT NULL,
+
+    -- Thresholds at the destination
+    min_quantity BIGINT NOT NULL,
+    max_quantity BIGINT NOT NULL,
+    -- Stock the source keeps for itself and never proposes to move
+    source_safety_stock BIGINT NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+
+    CONSTRAINT internal_replenishment_rules_pkey PRIMARY KEY (rule_id),
+    CONSTRAINT internal_replenishment_rules_product_fk
+        FOREIGN KEY (tenant_id, product_id) REFERENCES products (tenant_id, product_id),
+    CONSTRAINT internal_replenishment_rules_from_warehouse_fk
+        FOREIGN KEY (tenant_id, from_warehouse_id) REFERENCES warehouses (tenant_id, warehouse_id),
+    CONSTRAINT internal_replenishment_rules_to_warehouse_fk
+        FOREIGN KEY (tenant_id, to_warehouse_id) REFERENCES warehouses (tenant_id, warehouse_id),
+    CONSTRAINT internal_replenishment_rules_different_warehouses
+        CHECK (from_warehouse_id != to_warehouse_id),
+    CONSTRAINT internal_replenishment_rules_quantities_valid CHECK (
+        min_quantity >= 0
+        AND max_quantity >= min_quantity
+        AND source_safety_stock >= 0
+    )
+);
+
+-- One active route per product and warehouse pair
+CREATE UNIQUE INDEX IF NOT EXISTS idx_internal_replenishment_rules_active
+    ON internal_replenishment_rules(tenant_id, product_id, from_warehouse_id, to_warehouse_id)
+    WHERE deleted_at IS NULL;
+
+CREATE TRIGGER update_internal_replenishment_rules_updated_at
+    BEFORE UPDATE ON internal_replenishment_rules
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================
+-- Step 2: Tenant isolation (same policy as other tenant tables)
+-- ============================================
+ALTER TABLE internal_replenishment_rules ENABLE ROW LEVEL SECURITY;
+ALTER TABLE internal_replenishment_rules FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON internal_replenishment_rules
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
+
+COMMENT ON TABLE internal_replenishment_rules IS 'Warehouse-to-warehouse resupply rules checked before external reorders';
+COMMENT ON COLUMN internal_replenishment_rules.source_safety_stock IS 'Quantity the source warehouse keeps; only stock above it is proposed for transfer';
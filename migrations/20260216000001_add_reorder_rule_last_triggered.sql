@@ -0,0 +1,11 @@
+-- Migration: Track when reorder rules last fired
+-- Description: The scheduled replenishment check emits a reorder event when a rule is
+--              below its reorder point. last_triggered_at lets it skip rules that already
+--              fired within the cooldown window, and is claimed with a conditional UPDATE
+--              so concurrent workers emit at most one event per window.
+-- Created: 2026-02-16
+
+ALTER TABLE reorder_rules
+    ADD COLUMN IF NOT EXISTS last_triggered_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN reorder_rules.last_triggered_at IS 'When the scheduled replenishment check last emitted a reorder event for this rule';
@@ -0,0 +1,23 @@
+-- Migration: Add Casbin policies for the category import endpoint
+-- Description: POST /api/v1/inventory/categories/import recreates a category tree from an
+--              export document. It is not covered by the existing
+--              /api/v1/inventory/categories/bulk/* policy, so grant it explicitly.
+--              The export endpoint is a GET and is already covered by categories/*.
+
+-- ============================================================================
+-- CATEGORY IMPORT POLICIES (owner and admin only)
+-- ============================================================================
+
+-- Owner: May import a category tree
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/categories/import', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May import a category tree
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/categories/import', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
@@ -0,0 +1,12 @@
+-- Add payload schema version to event_outbox
+-- The outbox worker publishes it in the event envelope so consumers can
+-- dispatch on (event_type, schema_version). Existing rows are version 1.
+
+ALTER TABLE event_outbox
+ADD COLUMN schema_version INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE event_outbox
+ADD CONSTRAINT event_outbox_schema_version_check
+CHECK (schema_version > 0);
+
+COMMENT ON COLUMN event_outbox.schema_version IS 'Schema version of event_data, published in the event envelope';
@@ -0,0 +1,7 @@
+-- Index product attributes for attribute filters in product listing
+-- Listing filters such as ?attr.color=red use JSONB containment (@>),
+-- which jsonb_path_ops serves with a smaller index than the default opclass.
+
+CREATE INDEX IF NOT EXISTS idx_products_attributes
+ON products USING GIN (attributes jsonb_path_ops)
+WHERE deleted_at IS NULL;
@@ -0,0 +1,17 @@
+-- Migration: Released stock reservations
+-- Description: Reservations of a cancelled order are marked released once their
+--              allocated stock is handed back, so a redelivered cancellation
+--              event finds nothing left to release.
+-- Created: 2026-02-20
+
+ALTER TABLE stock_reservations
+    DROP CONSTRAINT IF EXISTS stock_reservations_status_check;
+
+ALTER TABLE stock_reservations
+    ADD CONSTRAINT stock_reservations_status_check
+    CHECK (status IN ('pending', 'reserved', 'backordered', 'released'));
+
+ALTER TABLE stock_reservations
+    ADD COLUMN IF NOT EXISTS released_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN stock_reservations.released_at IS 'When the reservation was released (e.g. order cancelled)';
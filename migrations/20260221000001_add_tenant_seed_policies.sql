@@ -0,0 +1,18 @@
+-- Migration: Tenant onboarding seed policies
+-- Description: Lets tenant owners and admins seed their tenant's default
+--              warehouse, root category and removal strategy.
+-- Created: 2026-02-21
+
+-- Owner: May seed tenant defaults
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/tenants/*', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May seed tenant defaults
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/tenants/*', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
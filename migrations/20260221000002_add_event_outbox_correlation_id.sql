@@ -0,0 +1,9 @@
+-- Add correlation id to event_outbox
+-- Set from the id of the request that enqueued the event; the outbox worker
+-- publishes it in the event envelope so consumers can join their traces to
+-- the originating request. Events enqueued outside a request have none.
+
+ALTER TABLE event_outbox
+ADD COLUMN correlation_id TEXT;
+
+COMMENT ON COLUMN event_outbox.correlation_id IS 'Correlation id of the request that enqueued the event, published in the event envelope';
@@ -0,0 +1,35 @@
+-- Migration: Tenant data exports
+-- Description: One row per requested tenant export (GDPR requests, migrations off
+--              the platform). The export file itself lives in object storage under
+--              object_key; the row records who asked for it and lets the service
+--              refuse a new export while the tenant's previous one is recent.
+-- Created: 2026-02-21
+
+-- ============================================
+-- Step 1: Export table
+-- ============================================
+CREATE TABLE IF NOT EXISTS tenant_exports (
+    export_id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    tenant_id UUID NOT NULL REFERENCES tenants(tenant_id),
+    requested_by UUID NOT NULL,
+    object_key TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'running'
+        CHECK (status IN ('running', 'completed', 'failed')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_tenant_exports_tenant_created
+    ON tenant_exports(tenant_id, created_at DESC);
+
+-- ============================================
+-- Step 2: Tenant isolation (same policy as other tenant tables)
+-- ============================================
+ALTER TABLE tenant_exports ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tenant_exports FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON tenant_exports
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
+
+COMMENT ON TABLE tenant_exports IS 'Requested tenant data exports; the NDJSON file is stored under object_key';
+COMMENT ON COLUMN tenant_exports.status IS 'running while rows are being written, then completed or failed';
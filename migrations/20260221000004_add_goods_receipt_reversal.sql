@@ -0,0 +1,53 @@
+-- Migration: Goods receipt reversal
+-- Description: A posted receipt entered in error can be reversed: its stock moves
+--              are compensated, the received stock and its valuation are taken back
+--              out, and the receipt ends in status 'reversed'. Valuation layers
+--              remember the receipt that created them so a reversal removes exactly
+--              those layers.
+-- Created: 2026-02-21
+
+-- ============================================
+-- Step 1: Reversed status and audit columns
+-- ============================================
+ALTER TABLE goods_receipts DROP CONSTRAINT IF EXISTS goods_receipts_status_check;
+
+ALTER TABLE goods_receipts
+    ADD CONSTRAINT goods_receipts_status_check
+    CHECK (status IN ('draft', 'confirmed', 'partially_received', 'received', 'cancelled', 'reversed'));
+
+ALTER TABLE goods_receipts
+    ADD COLUMN IF NOT EXISTS reversed_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS reversed_by UUID,
+    ADD COLUMN IF NOT EXISTS reversal_reason TEXT;
+
+COMMENT ON COLUMN goods_receipts.status IS 'Receipt status: draft/confirmed/partially_received/received/cancelled/reversed';
+COMMENT ON COLUMN goods_receipts.reversal_reason IS 'Why a received receipt was reversed';
+
+-- ============================================
+-- Step 2: Link valuation layers to their receipt
+-- ============================================
+-- Layers created before this migration stay unlinked; their receipts cannot be reversed.
+ALTER TABLE inventory_valuation_layers
+    ADD COLUMN IF NOT EXISTS receipt_id UUID;
+
+CREATE INDEX IF NOT EXISTS idx_valuation_layers_tenant_receipt
+    ON inventory_valuation_layers(tenant_id, receipt_id)
+    WHERE receipt_id IS NOT NULL;
+
+COMMENT ON COLUMN inventory_valuation_layers.receipt_id IS 'Goods receipt whose validation created this layer';
+
+-- ============================================
+-- Step 3: Permissions
+-- ============================================
+-- Validating and reversing a receipt are POSTs below /receipts/{id}
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/receipts/*', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/receipts/*', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
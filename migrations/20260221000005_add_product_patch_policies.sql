@@ -0,0 +1,26 @@
+-- Migration: Product PATCH policies
+-- Description: PATCH /api/v1/inventory/products/{id} partially updates a product.
+--              Roles that may PUT a product may also PATCH it. Category PATCH is
+--              already covered by the categories/* policies.
+-- Created: 2026-02-21
+
+-- Owner: May partially update products
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/products/*', 'PATCH', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May partially update products
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/products/*', 'PATCH', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Manager: May partially update products
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'manager', t.tenant_id::text, '/api/v1/inventory/products/*', 'PATCH', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
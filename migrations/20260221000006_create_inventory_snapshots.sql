@@ -0,0 +1,66 @@
+-- Migration: Inventory snapshots
+-- Description: Admins snapshot a tenant's inventory levels before a risky bulk
+--              operation and can restore them afterwards. A restore never writes
+--              levels directly; it records compensating adjustment stock moves.
+-- Created: 2026-02-21
+
+-- ============================================
+-- Step 1: Snapshot headers
+-- ============================================
+CREATE TABLE IF NOT EXISTS inventory_snapshots (
+    snapshot_id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    tenant_id UUID NOT NULL REFERENCES tenants(tenant_id),
+    level_count BIGINT NOT NULL DEFAULT 0,
+    created_by UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_restored_at TIMESTAMPTZ,
+    last_restored_by UUID
+);
+
+CREATE INDEX IF NOT EXISTS idx_inventory_snapshots_tenant
+    ON inventory_snapshots(tenant_id, created_at DESC);
+
+-- ============================================
+-- Step 2: Captured levels
+-- ============================================
+CREATE TABLE IF NOT EXISTS inventory_snapshot_levels (
+    snapshot_id UUID NOT NULL REFERENCES inventory_snapshots(snapshot_id) ON DELETE CASCADE,
+    tenant_id UUID NOT NULL,
+    warehouse_id UUID NOT NULL,
+    location_id UUID,
+    product_id UUID NOT NULL,
+    available_quantity BIGINT NOT NULL,
+    reserved_quantity BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_inventory_snapshot_levels_snapshot
+    ON inventory_snapshot_levels(snapshot_id);
+
+COMMENT ON TABLE inventory_snapshots IS 'Point-in-time copies of a tenant''s inventory levels';
+COMMENT ON TABLE inventory_snapshot_levels IS 'Inventory levels captured by a snapshot; reserved_quantity is informational, restores only touch available_quantity';
+
+-- ============================================
+-- Step 3: Tenant isolation (same policy as other tenant tables)
+-- ============================================
+ALTER TABLE inventory_snapshots ENABLE ROW LEVEL SECURITY;
+ALTER TABLE inventory_snapshots FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON inventory_snapshots
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
+
+ALTER TABLE inventory_snapshot_levels ENABLE ROW LEVEL SECURITY;
+ALTER TABLE inventory_snapshot_levels FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON inventory_snapshot_levels
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
+
+-- ============================================
+-- Step 4: Permissions (owner and admin only)
+-- ============================================
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', r.role, t.tenant_id::text, p.path, 'POST', '', ''
+FROM tenants t
+CROSS JOIN (VALUES ('owner'), ('admin')) AS r(role)
+CROSS JOIN (VALUES ('/api/v1/inventory/snapshots'), ('/api/v1/inventory/snapshots/*')) AS p(path)
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
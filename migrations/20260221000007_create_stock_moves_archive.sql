@@ -0,0 +1,60 @@
+-- Migration: Stock move archive
+-- Description: Moves older than the configured retention window are moved out of
+--              stock_moves into stock_moves_archive by a background job, so the hot
+--              table stays small. Archived rows are kept verbatim; reports read
+--              stock_moves_with_archive when asked to include them.
+-- Created: 2026-02-21
+
+-- ============================================
+-- Step 1: Archive table
+-- ============================================
+-- Same columns in the same order as stock_moves so rows can be copied with
+-- SELECT * and both tables can be combined with UNION ALL. A column added to
+-- stock_moves must be added here too.
+--
+-- No foreign keys or CHECK constraints: rows were validated on the way into
+-- stock_moves and are never written again.
+CREATE TABLE IF NOT EXISTS stock_moves_archive (
+    LIKE stock_moves INCLUDING DEFAULTS,
+    PRIMARY KEY (move_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_stock_moves_archive_tenant_product_date
+    ON stock_moves_archive(tenant_id, product_id, move_date DESC);
+
+CREATE INDEX IF NOT EXISTS idx_stock_moves_archive_tenant_date
+    ON stock_moves_archive(tenant_id, move_date DESC);
+
+-- Hot moves are selected for archival by age
+CREATE INDEX IF NOT EXISTS idx_stock_moves_move_date
+    ON stock_moves(move_date);
+
+COMMENT ON TABLE stock_moves_archive IS 'Stock moves older than the retention window, moved out of stock_moves unchanged';
+
+-- ============================================
+-- Step 2: Combined view for reports
+-- ============================================
+-- security_invoker keeps the tenant policies of both tables in force
+CREATE OR REPLACE VIEW stock_moves_with_archive WITH (security_invoker = true) AS
+    SELECT * FROM stock_moves
+    UNION ALL
+    SELECT * FROM stock_moves_archive;
+
+COMMENT ON VIEW stock_moves_with_archive IS 'Every stock move, hot or archived';
+
+-- ============================================
+-- Step 3: Archived moves stay immutable
+-- ============================================
+CREATE TRIGGER prevent_stock_moves_archive_updates_trigger
+    BEFORE UPDATE ON stock_moves_archive
+    FOR EACH ROW
+    EXECUTE FUNCTION prevent_stock_moves_updates();
+
+-- ============================================
+-- Step 4: Tenant isolation (same policy as other tenant tables)
+-- ============================================
+ALTER TABLE stock_moves_archive ENABLE ROW LEVEL SECURITY;
+ALTER TABLE stock_moves_archive FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON stock_moves_archive
+    USING (app_current_tenant() IS NULL OR tenant_id = app_current_tenant())
+    WITH CHECK (app_current_tenant() IS NULL OR tenant_id = app_current_tenant());
@@ -0,0 +1,13 @@
+-- Migration: HEAD existence check policies
+-- Description: HEAD /api/v1/inventory/products/{id} and /categories/{id} answer
+--              200/404 without a body. Casbin matches the method exactly, so every
+--              role that may GET one of these paths is given HEAD on it as well.
+-- Created: 2026-02-21
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', v0, v1, v2, 'HEAD', '', ''
+FROM casbin_rule
+WHERE ptype = 'p'
+  AND v2 IN ('/api/v1/inventory/products/*', '/api/v1/inventory/categories/*')
+  AND v3 = 'GET'
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
@@ -0,0 +1,29 @@
+-- Migration: Warehouse-scoped Casbin policies
+-- Description: The Casbin model gains a warehouse field (v4 of 'p' rules).
+--              '*' means the rule applies in every warehouse; a warehouse_id
+--              limits it to that warehouse. Casbin requires every 'p' rule to
+--              have all fields, so existing rules become unscoped ('*').
+-- Created: 2026-02-21
+
+UPDATE casbin_rule
+SET v4 = '*'
+WHERE ptype = 'p'
+  AND v4 = '';
+
+-- Rules inserted without a warehouse (seed migrations, SQL fixtures) are unscoped
+CREATE OR REPLACE FUNCTION default_casbin_policy_warehouse()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.ptype = 'p' AND COALESCE(NEW.v4, '') = '' THEN
+        NEW.v4 := '*';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER casbin_rule_default_warehouse
+    BEFORE INSERT OR UPDATE ON casbin_rule
+    FOR EACH ROW
+    EXECUTE FUNCTION default_casbin_policy_warehouse();
+
+COMMENT ON COLUMN casbin_rule.v4 IS 'p rules: warehouse scope (* for all warehouses, or a warehouse_id)';
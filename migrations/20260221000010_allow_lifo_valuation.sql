@@ -0,0 +1,18 @@
+-- Migration: Allow LIFO valuation
+-- Description: Products and valuation settings may use 'lifo'; deliveries
+--              consume cost layers newest first.
+-- Created: 2026-02-21
+
+ALTER TABLE inventory_valuations
+    DROP CONSTRAINT IF EXISTS inventory_valuations_valuation_method_check;
+
+ALTER TABLE inventory_valuations
+    ADD CONSTRAINT inventory_valuations_valuation_method_check
+    CHECK (valuation_method IN ('fifo', 'lifo', 'avco', 'standard'));
+
+ALTER TABLE inventory_valuation_settings
+    DROP CONSTRAINT IF EXISTS inventory_valuation_settings_method_check;
+
+ALTER TABLE inventory_valuation_settings
+    ADD CONSTRAINT inventory_valuation_settings_method_check
+    CHECK (method IN ('fifo', 'lifo', 'avco', 'standard'));
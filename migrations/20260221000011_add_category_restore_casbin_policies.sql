@@ -0,0 +1,22 @@
+-- Migration: Add Casbin policies for the category restore endpoint
+-- Description: POST /api/v1/inventory/categories/{category_id}/restore undoes a soft
+--              delete. The existing /api/v1/inventory/categories/* policies only grant
+--              reads and updates, so grant the restore action explicitly.
+
+-- ============================================================================
+-- CATEGORY RESTORE POLICIES (owner and admin only)
+-- ============================================================================
+
+-- Owner: May restore deleted categories
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'owner', t.tenant_id::text, '/api/v1/inventory/categories/:category_id/restore', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- Admin: May restore deleted categories
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', 'admin', t.tenant_id::text, '/api/v1/inventory/categories/:category_id/restore', 'POST', '', ''
+FROM tenants t
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
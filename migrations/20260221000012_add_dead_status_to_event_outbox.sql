@@ -0,0 +1,20 @@
+-- Dead-letter state for outbox events
+-- The outbox worker moves an event to 'dead' once it has used up its publish
+-- attempts, so it stops being retried and no longer holds up the queue.
+-- retry_count and error_message hold the attempt count and last error.
+-- 'failed' was the previous name for this state; existing rows are migrated.
+
+UPDATE event_outbox SET status = 'dead' WHERE status = 'failed';
+
+ALTER TABLE event_outbox
+DROP CONSTRAINT event_outbox_status_check;
+
+ALTER TABLE event_outbox
+ADD CONSTRAINT event_outbox_status_check
+CHECK (status IN ('pending', 'in_progress', 'published', 'dead'));
+
+-- Index for inspecting and replaying dead-lettered events
+CREATE INDEX idx_event_outbox_dead ON event_outbox (tenant_id, updated_at) WHERE status = 'dead';
+
+COMMENT ON COLUMN event_outbox.retry_count IS 'Number of failed publish attempts';
+COMMENT ON COLUMN event_outbox.error_message IS 'Error from the last failed publish attempt';
@@ -0,0 +1,44 @@
+-- Migration: Casbin policies for quick stock adjustments
+-- Description: POST /api/v1/inventory/adjustments/quick changes stock at once,
+--              without the draft/post workflow, so only owners, admins and
+--              managers may use it. Their existing /api/v1/inventory/adjustments/*
+--              POST grants already cover it; the explicit rules below document
+--              the intent. The 'user' role's POST wildcard also matched /quick,
+--              so it is replaced with the document routes it was meant to grant.
+-- Created: 2026-02-21
+
+-- ============================================================================
+-- QUICK ADJUSTMENT POLICIES (owner, admin and manager only)
+-- ============================================================================
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', r.role, t.tenant_id::text, '/api/v1/inventory/adjustments/quick', 'POST', '', ''
+FROM tenants t
+CROSS JOIN (VALUES ('owner'), ('admin'), ('manager')) AS r(role)
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+-- ============================================================================
+-- USER: document routes instead of the POST wildcard
+-- ============================================================================
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT rule.ptype, rule.v0, rule.v1, routes.path, rule.v3, rule.v4, rule.v5
+FROM casbin_rule rule
+CROSS JOIN (
+    VALUES
+        ('/api/v1/inventory/adjustments/:adjustment_id/lines'),
+        ('/api/v1/inventory/adjustments/:adjustment_id/post'),
+        ('/api/v1/inventory/adjustments/:adjustment_id/cancel')
+) AS routes(path)
+WHERE rule.ptype = 'p'
+  AND rule.v0 = 'user'
+  AND rule.v2 = '/api/v1/inventory/adjustments/*'
+  AND rule.v3 = 'POST'
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
+
+DELETE FROM casbin_rule
+WHERE ptype = 'p'
+  AND v0 = 'user'
+  AND v2 = '/api/v1/inventory/adjustments/*'
+  AND v3 = 'POST';
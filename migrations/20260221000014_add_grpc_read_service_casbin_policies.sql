@@ -0,0 +1,14 @@
+-- Migration: Casbin policies for the inventory gRPC read API
+-- Description: gRPC calls are now authorized like REST requests, on the RPC
+--              path (/anthill.inventory.v1.InventoryReadService/<Method>) with
+--              action POST. The service only reads products, levels and
+--              availability, so every role that can read inventory over REST
+--              may call it.
+-- Created: 2026-02-21
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+SELECT 'p', r.role, t.tenant_id::text, '/anthill.inventory.v1.InventoryReadService/*', 'POST', '', ''
+FROM tenants t
+CROSS JOIN (VALUES ('owner'), ('admin'), ('manager'), ('user')) AS r(role)
+WHERE t.deleted_at IS NULL
+ON CONFLICT (ptype, v0, v1, v2, v3, v4, v5) DO NOTHING;
@@ -0,0 +1,74 @@
+//! Category product count recount worker
+//!
+//! This module contains the background worker that periodically recomputes
+//! `product_count`/`total_product_count` for the categories of every active
+//! tenant, correcting drift left by bulk moves or restores.
+
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use inventory_service_core::repositories::category::CategoryRepository;
+use inventory_service_infra::repositories::CategoryRepositoryImpl;
+use shared_error::AppError;
+
+/// Configuration for the category recount worker
+#[derive(Debug, Clone)]
+pub struct CategoryRecountWorkerConfig {
+    /// How often to recompute counts (in seconds)
+    pub interval_seconds: u64,
+}
+
+impl Default for CategoryRecountWorkerConfig {
+    fn default() -> Self {
+        Self {
+            interval_seconds: 3600,
+        }
+    }
+}
+
+/// Start the category recount worker
+pub async fn start_category_recount_worker(pool: PgPool, config: CategoryRecountWorkerConfig) {
+    info!("Starting category recount worker with config: {:?}", config);
+
+    let mut interval = time::interval(Duration::from_secs(config.interval_seconds));
+
+    loop {
+        interval.tick().await;
+
+        match recount_all_tenants(&pool).await {
+            Ok(corrected) if corrected > 0 => {
+                info!("Category recount corrected {} categories", corrected);
+            },
+            Ok(_) => {},
+            Err(e) => error!("Error recounting category product counts: {}", e),
+        }
+    }
+}
+
+/// Recompute category product counts for every active tenant
+///
+/// Runs one statement per tenant so no transaction spans multiple tenants.
+/// A failure for one tenant is logged and does not stop the others.
+/// Returns the total number of corrected categories.
+pub async fn recount_all_tenants(pool: &PgPool) -> Result<u64, AppError> {
+    let tenant_ids: Vec<Uuid> = sqlx::query_scalar(
+        "SELECT tenant_id FROM tenants WHERE deleted_at IS NULL AND status = 'active'",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let repository = CategoryRepositoryImpl::new(pool.clone());
+    let mut corrected = 0;
+
+    for tenant_id in tenant_ids {
+        match repository.recount_all_product_counts(tenant_id).await {
+            Ok(count) => corrected += count,
+            Err(e) => error!("Failed to recount categories for tenant {}: {}", tenant_id, e),
+        }
+    }
+
+    Ok(corrected)
+}
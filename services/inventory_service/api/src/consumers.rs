@@ -2,21 +2,102 @@ use std::sync::Arc;
 
 use futures::stream::StreamExt;
 
+use inventory_service_core::services::InventoryService;
 use inventory_service_infra::repositories::{
-    PgDeliveryOrderItemRepository, PgDeliveryOrderRepository, PgInventoryRepository,
+    LotSerialRepositoryImpl, PgDeliveryOrderItemRepository, PgDeliveryOrderRepository,
+    PgInventoryRepository, ProductRepositoryImpl,
 };
+use inventory_service_infra::services::InventoryServiceImpl;
 use shared_error::AppError;
-use shared_events::{EventEnvelope, OrderConfirmedEvent};
+use shared_events::{EventDispatcher, EventEnvelope, OrderCancelledEvent, OrderConfirmedEvent};
 use uuid::Uuid;
 
-pub async fn init_event_consumers(_pool: sqlx::PgPool, _nats_url: &str) -> Result<(), AppError> {
+const ORDER_CONFIRMED: &str = "order.confirmed";
+pub const ORDER_CANCELLED: &str = "order.cancelled";
+
+pub async fn init_event_consumers(pool: sqlx::PgPool, nats_url: &str) -> Result<(), AppError> {
+    shared_events::init_nats_client(nats_url).await?;
+
+    let product_repo = Arc::new(ProductRepositoryImpl::new(pool.clone()));
+    let lot_serial_repo = Arc::new(LotSerialRepositoryImpl::new(pool.clone()));
+    let inventory_repo = Arc::new(PgInventoryRepository::new(
+        Arc::new(pool.clone()),
+        product_repo,
+        lot_serial_repo,
+    ));
+    start_order_cancelled_consumer(Arc::new(InventoryServiceImpl::new(inventory_repo))).await?;
+
     // Delivery service is temporarily disabled - commenting out delivery event consumer
     // let delivery_repo = Arc::new(PgDeliveryOrderRepository::new(pool.clone()));
     // let delivery_item_repo = Arc::new(PgDeliveryOrderItemRepository::new(pool.clone()));
     // let inventory_repo = Arc::new(PgInventoryRepository::new(pool.clone()));
 
     // start_order_confirmed_consumer(delivery_repo, delivery_item_repo, inventory_repo, pool).await;
-    // TODO: Re-enable NATS initialization when delivery consumer is re-enabled
+    Ok(())
+}
+
+/// Dispatcher releasing the stock reservations of cancelled orders
+pub fn order_cancelled_dispatcher(inventory_service: Arc<dyn InventoryService>) -> EventDispatcher {
+    EventDispatcher::new().register(
+        ORDER_CANCELLED,
+        1,
+        move |event: EventEnvelope<OrderCancelledEvent>| {
+            let inventory_service = inventory_service.clone();
+            async move { handle_order_cancelled(event, inventory_service.as_ref()).await }
+        },
+    )
+}
+
+async fn start_order_cancelled_consumer(
+    inventory_service: Arc<dyn InventoryService>,
+) -> Result<(), AppError> {
+    let client = shared_events::get_nats_client()?;
+    let mut subscriber = client
+        .subscribe_event::<OrderCancelledEvent>(ORDER_CANCELLED.to_string())
+        .await?;
+    let dispatcher = order_cancelled_dispatcher(inventory_service);
+
+    tokio::spawn(async move {
+        while let Some(message) = subscriber.next().await {
+            if let Err(e) = dispatcher.dispatch(&message.payload).await {
+                tracing::error!("Failed to handle {} event: {}", ORDER_CANCELLED, e);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Release every open reservation held by the cancelled order
+///
+/// Redelivered events find the reservations already released and do nothing.
+async fn handle_order_cancelled(
+    event: EventEnvelope<OrderCancelledEvent>,
+    inventory_service: &dyn InventoryService,
+) -> Result<(), AppError> {
+    let order = event.data;
+    let released = inventory_service
+        .release_source_reservations(order.tenant_id, order.order_id)
+        .await?;
+
+    if released.is_empty() {
+        // Already shipped, already released, or never reserved
+        tracing::info!(
+            "No open reservations for cancelled order {} in tenant {}",
+            order.order_id,
+            order.tenant_id
+        );
+    } else {
+        let quantity: i64 = released.iter().map(|r| r.allocated_quantity).sum();
+        tracing::info!(
+            "Released {} reservations ({} units) for cancelled order {} in tenant {}",
+            released.len(),
+            quantity,
+            order.order_id,
+            order.tenant_id
+        );
+    }
+
     Ok(())
 }
 
@@ -29,28 +110,35 @@ async fn start_order_confirmed_consumer(
 ) -> Result<(), AppError> {
     let client = shared_events::get_nats_client()?;
     let mut subscriber = client
-        .subscribe_event::<OrderConfirmedEvent>("order.confirmed".to_string())
+        .subscribe_event::<OrderConfirmedEvent>(ORDER_CONFIRMED.to_string())
         .await?;
 
+    let dispatcher = EventDispatcher::new().register(
+        ORDER_CONFIRMED,
+        1,
+        move |event: EventEnvelope<OrderConfirmedEvent>| {
+            let delivery_repo = delivery_repo.clone();
+            let delivery_item_repo = delivery_item_repo.clone();
+            let inventory_repo = inventory_repo.clone();
+            let pool = pool.clone();
+            async move {
+                handle_order_confirmed(
+                    event,
+                    delivery_repo,
+                    delivery_item_repo,
+                    inventory_repo,
+                    &pool,
+                )
+                .await
+            }
+        },
+    );
+
     tokio::spawn(async move {
         while let Some(message) = subscriber.next().await {
-            match serde_json::from_slice::<EventEnvelope<OrderConfirmedEvent>>(&message.payload) {
-                Ok(event) => {
-                    if let Err(e) = handle_order_confirmed(
-                        event,
-                        delivery_repo.clone(),
-                        delivery_item_repo.clone(),
-                        inventory_repo.clone(),
-                        &pool,
-                    )
-                    .await
-                    {
-                        tracing::error!("Failed to handle order.confirmed event: {}", e);
-                    }
-                },
-                Err(e) => {
-                    tracing::error!("Error deserializing order.confirmed event: {}", e);
-                },
+            // Unknown event types or schema versions are logged and skipped
+            if let Err(e) = dispatcher.dispatch(&message.payload).await {
+                tracing::error!("Failed to handle {} event: {}", ORDER_CONFIRMED, e);
             }
         }
     });
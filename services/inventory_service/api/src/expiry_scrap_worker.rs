@@ -0,0 +1,80 @@
+//! Expired lot scrap proposal worker
+//!
+//! This module contains the background worker that periodically creates draft
+//! scrap documents for lots that expired more than a grace period ago, so
+//! expired stock does not linger after quarantine without scrap paperwork.
+
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use inventory_service_core::services::scrap::ScrapService;
+use inventory_service_infra::services::PgScrapService;
+use shared_error::AppError;
+
+/// Configuration for the expiry scrap worker
+#[derive(Debug, Clone)]
+pub struct ExpiryScrapWorkerConfig {
+    /// How often to look for expired lots (in seconds)
+    pub interval_seconds: u64,
+    /// Days past expiry before a lot is proposed for scrap
+    pub grace_days: u32,
+}
+
+impl Default for ExpiryScrapWorkerConfig {
+    fn default() -> Self {
+        Self {
+            interval_seconds: 86400,
+            grace_days: 7,
+        }
+    }
+}
+
+/// Start the expiry scrap worker
+pub async fn start_expiry_scrap_worker(pool: PgPool, config: ExpiryScrapWorkerConfig) {
+    info!("Starting expiry scrap worker with config: {:?}", config);
+
+    let mut interval = time::interval(Duration::from_secs(config.interval_seconds));
+
+    loop {
+        interval.tick().await;
+
+        match propose_for_all_tenants(&pool, config.grace_days).await {
+            Ok(created) if created > 0 => {
+                info!("Expiry scrap worker created {} draft scrap documents", created);
+            },
+            Ok(_) => {},
+            Err(e) => error!("Error proposing scrap for expired lots: {}", e),
+        }
+    }
+}
+
+/// Create draft scrap documents for expired lots of every active tenant
+///
+/// A failure for one tenant is logged and does not stop the others.
+/// Returns the total number of created draft documents.
+pub async fn propose_for_all_tenants(pool: &PgPool, grace_days: u32) -> Result<usize, AppError> {
+    let tenant_ids: Vec<Uuid> = sqlx::query_scalar(
+        "SELECT tenant_id FROM tenants WHERE deleted_at IS NULL AND status = 'active'",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let service = PgScrapService::new(Arc::new(pool.clone()));
+    let mut created = 0;
+
+    for tenant_id in tenant_ids {
+        match service
+            .propose_expired_lot_scraps(tenant_id, grace_days)
+            .await
+        {
+            Ok(proposals) => created += proposals.len(),
+            Err(e) => error!("Failed to propose expiry scrap for tenant {}: {}", tenant_id, e),
+        }
+    }
+
+    Ok(created)
+}
@@ -0,0 +1,348 @@
+//! gRPC read API
+//!
+//! Exposes product, inventory level and availability reads over gRPC for
+//! services that prefer it to JSON over HTTP. The handlers use the same
+//! repositories as the REST API and report failures through the shared
+//! `AppError` to `tonic::Status` mapping.
+//!
+//! Callers authenticate with the same access tokens as the REST API, sent as
+//! `authorization: Bearer <jwt>` metadata, and need a Casbin policy for the RPC
+//! path; every read is scoped to the token's tenant.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use sqlx::PgPool;
+use tokio::net::TcpListener;
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+use tower::{Layer, Service};
+use uuid::Uuid;
+
+use inventory_service_core::domains::inventory::product::{Product, ProductTrackingMethod};
+use inventory_service_core::models::InventoryLevel;
+use inventory_service_core::repositories::product::ProductRepository;
+use inventory_service_core::repositories::stock::InventoryLevelRepository;
+use inventory_service_infra::repositories::{PgInventoryLevelRepository, ProductRepositoryImpl};
+use shared_auth::middleware::check_permission;
+use shared_auth::{AuthUser, AuthzState, NO_WAREHOUSE};
+use shared_error::AppError;
+
+use proto::inventory_read_service_server::{InventoryReadService, InventoryReadServiceServer};
+
+/// Generated protobuf types and service stubs for `anthill.inventory.v1`
+pub mod proto {
+    tonic::include_proto!("anthill.inventory.v1");
+}
+
+/// Maximum number of products per `GetInventoryLevels` call
+pub const MAX_INVENTORY_LEVEL_PRODUCTS: usize = 500;
+
+/// gRPC implementation of `InventoryReadService`
+pub struct InventoryGrpcService {
+    products: Arc<dyn ProductRepository>,
+    inventory_levels: Arc<dyn InventoryLevelRepository>,
+}
+
+impl InventoryGrpcService {
+    pub fn new(
+        products: Arc<dyn ProductRepository>,
+        inventory_levels: Arc<dyn InventoryLevelRepository>,
+    ) -> Self {
+        Self {
+            products,
+            inventory_levels,
+        }
+    }
+
+    /// Build the service on the PostgreSQL repositories
+    pub fn from_pool(pool: PgPool) -> Self {
+        Self::new(
+            Arc::new(ProductRepositoryImpl::new(pool.clone())),
+            Arc::new(PgInventoryLevelRepository::new(Arc::new(pool))),
+        )
+    }
+}
+
+#[tonic::async_trait]
+impl InventoryReadService for InventoryGrpcService {
+    async fn get_product(
+        &self,
+        request: Request<proto::GetProductRequest>,
+    ) -> Result<Response<proto::Product>, Status> {
+        let tenant_id = tenant_id(&request)?;
+        let product_id = parse_uuid(&request.get_ref().product_id, "product_id")?;
+
+        let product = self
+            .products
+            .find_by_id(tenant_id, product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product {} not found", product_id)))?;
+
+        Ok(Response::new(product_to_proto(product)))
+    }
+
+    async fn get_inventory_levels(
+        &self,
+        request: Request<proto::GetInventoryLevelsRequest>,
+    ) -> Result<Response<proto::GetInventoryLevelsResponse>, Status> {
+        let tenant_id = tenant_id(&request)?;
+        let request = request.into_inner();
+        let warehouse_id = parse_uuid(&request.warehouse_id, "warehouse_id")?;
+
+        if request.product_ids.is_empty() {
+            return Err(
+                AppError::ValidationError("product_ids must not be empty".to_string()).into()
+            );
+        }
+        if request.product_ids.len() > MAX_INVENTORY_LEVEL_PRODUCTS {
+            return Err(AppError::ValidationError(format!(
+                "At most {} product_ids are allowed per request",
+                MAX_INVENTORY_LEVEL_PRODUCTS
+            ))
+            .into());
+        }
+        let product_ids = request
+            .product_ids
+            .iter()
+            .map(|id| parse_uuid(id, "product_ids"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut levels = self
+            .inventory_levels
+            .find_by_products(tenant_id, warehouse_id, &product_ids)
+            .await?;
+
+        // Answer in request order
+        let levels = product_ids
+            .iter()
+            .filter_map(|product_id| levels.remove(product_id))
+            .map(level_to_proto)
+            .collect();
+
+        Ok(Response::new(proto::GetInventoryLevelsResponse { levels }))
+    }
+
+    async fn check_availability(
+        &self,
+        request: Request<proto::CheckAvailabilityRequest>,
+    ) -> Result<Response<proto::CheckAvailabilityResponse>, Status> {
+        let tenant_id = tenant_id(&request)?;
+        let request = request.into_inner();
+        let warehouse_id = parse_uuid(&request.warehouse_id, "warehouse_id")?;
+        let product_id = parse_uuid(&request.product_id, "product_id")?;
+
+        if request.quantity <= 0 {
+            return Err(AppError::ValidationError("quantity must be positive".to_string()).into());
+        }
+
+        let available_quantity = match self
+            .inventory_levels
+            .find_by_product(tenant_id, warehouse_id, product_id)
+            .await?
+        {
+            Some(level) => level.available_quantity,
+            None => {
+                // No stock row yet: distinguish "none on hand" from an unknown product
+                if self
+                    .products
+                    .find_by_id(tenant_id, product_id)
+                    .await?
+                    .is_none()
+                {
+                    return Err(
+                        AppError::NotFound(format!("Product {} not found", product_id)).into()
+                    );
+                }
+                0
+            },
+        };
+
+        Ok(Response::new(proto::CheckAvailabilityResponse {
+            is_available: available_quantity >= request.quantity,
+            available_quantity,
+            requested_quantity: request.quantity,
+        }))
+    }
+}
+
+/// Authenticates and authorizes every call like `casbin_middleware` does for REST
+///
+/// Rejects missing and revoked access tokens, checks the caller's Casbin
+/// permission on the RPC path (e.g.
+/// `/anthill.inventory.v1.InventoryReadService/GetProduct` with action `POST`)
+/// and attaches the caller as an `AuthUser`. Interceptors are synchronous, so
+/// this is a tower layer around the whole server.
+#[derive(Clone)]
+pub struct BearerAuthLayer {
+    state: AuthzState,
+}
+
+impl BearerAuthLayer {
+    pub fn new(state: AuthzState) -> Self {
+        Self { state }
+    }
+}
+
+impl<S> Layer<S> for BearerAuthLayer {
+    type Service = BearerAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BearerAuthService {
+            inner,
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BearerAuthService<S> {
+    inner: S,
+    state: AuthzState,
+}
+
+impl<S, B> Service<http::Request<B>> for BearerAuthService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        let state = self.state.clone();
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            match authorize(&state, &request).await {
+                Ok(user) => {
+                    request.extensions_mut().insert(user);
+                    inner.call(request).await
+                },
+                Err(status) => Ok(status.into_http()),
+            }
+        })
+    }
+}
+
+async fn authorize<B>(state: &AuthzState, request: &http::Request<B>) -> Result<AuthUser, Status> {
+    let token = request
+        .headers()
+        .get(http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| AppError::Unauthorized("Missing bearer token".to_string()))?;
+
+    // Refresh tokens are only valid at the refresh endpoint
+    let claims =
+        shared_jwt::decode_access_checked(token, &state.jwt_secret, state.denylist.as_ref())
+            .await?;
+
+    let resource = request.uri().path();
+    let action = request.method().as_str();
+    let allowed = check_permission(
+        &state.enforcer,
+        &claims.sub.to_string(),
+        &claims.tenant_id.to_string(),
+        resource,
+        action,
+        NO_WAREHOUSE,
+    )
+    .await
+    .map_err(|e| AppError::InternalError(format!("Authorization check failed: {:?}", e)))?;
+
+    if !allowed {
+        tracing::warn!(
+            "Permission denied: user={}, tenant={}, resource={}, action={}",
+            claims.sub,
+            claims.tenant_id,
+            resource,
+            action
+        );
+        return Err(AppError::Forbidden("Permission denied".to_string()).into());
+    }
+
+    Ok(AuthUser::from_claims(claims))
+}
+
+/// Serve the gRPC API on an already bound listener until `shutdown` resolves
+///
+/// `authz` should be the state the REST router uses, so both APIs enforce the
+/// same policies and see the same revoked tokens. In-flight calls are allowed
+/// to finish once `shutdown` resolves.
+pub async fn serve(
+    listener: TcpListener,
+    pool: PgPool,
+    authz: AuthzState,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let incoming = TcpIncoming::from_listener(listener, true, None)?;
+    Server::builder()
+        .layer(BearerAuthLayer::new(authz))
+        .add_service(InventoryReadServiceServer::new(InventoryGrpcService::from_pool(pool)))
+        .serve_with_incoming_shutdown(incoming, shutdown)
+        .await?;
+    Ok(())
+}
+
+fn tenant_id<T>(request: &Request<T>) -> Result<Uuid, Status> {
+    request
+        .extensions()
+        .get::<AuthUser>()
+        .map(|user| user.tenant_id)
+        .ok_or_else(|| AppError::Unauthorized("Missing bearer token".to_string()).into())
+}
+
+fn parse_uuid(value: &str, field: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(value)
+        .map_err(|_| AppError::ValidationError(format!("{} must be a valid UUID", field)))
+}
+
+fn tracking_method_str(method: &ProductTrackingMethod) -> &'static str {
+    match method {
+        ProductTrackingMethod::None => "none",
+        ProductTrackingMethod::Lot => "lot",
+        ProductTrackingMethod::Serial => "serial",
+    }
+}
+
+fn product_to_proto(product: Product) -> proto::Product {
+    proto::Product {
+        product_id: product.product_id.to_string(),
+        tracking_method: tracking_method_str(&product.tracking_method).to_string(),
+        sku: product.sku,
+        name: product.name,
+        description: product.description,
+        product_type: product.product_type,
+        barcode: product.barcode,
+        category_id: product.category_id.map(|id| id.to_string()),
+        track_inventory: product.track_inventory,
+        default_uom_id: product.default_uom_id.map(|id| id.to_string()),
+        sale_price: product.sale_price,
+        cost_price: product.cost_price,
+        currency_code: product.currency_code,
+        is_active: product.is_active,
+        is_sellable: product.is_sellable,
+        is_purchaseable: product.is_purchaseable,
+    }
+}
+
+fn level_to_proto(level: InventoryLevel) -> proto::InventoryLevel {
+    proto::InventoryLevel {
+        product_id: level.product_id.to_string(),
+        warehouse_id: level.warehouse_id.to_string(),
+        available_quantity: level.available_quantity,
+        reserved_quantity: level.reserved_quantity,
+    }
+}
@@ -7,16 +7,17 @@ use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
     routing::{get, post},
-    Json, Router,
+    Router,
 };
-use shared_auth::extractors::AuthUser;
+use shared_auth::extractors::{AuthUser, RequirePermission};
+use shared_error::extract::Json;
 use shared_error::AppError;
 use uuid::Uuid;
 
 use inventory_service_core::dto::adjustment::{
     AddAdjustmentLinesRequest, AdjustmentDocumentResponse, AdjustmentDocumentWithLinesResponse,
     AdjustmentListQuery, AdjustmentListResponse, AdjustmentSummary, CreateAdjustmentRequest,
-    PostAdjustmentRequest,
+    PostAdjustmentRequest, QuickAdjustmentRequest, QuickAdjustmentResponse,
 };
 
 use crate::state::AppState;
@@ -25,6 +26,7 @@ use crate::state::AppState;
 pub fn create_adjustment_routes() -> Router {
     Router::new()
         .route("/", post(create_adjustment).get(list_adjustments))
+        .route("/quick", post(quick_adjust))
         .route("/{adjustment_id}", get(get_adjustment))
         .route("/{adjustment_id}/lines", post(add_adjustment_lines))
         .route("/{adjustment_id}/post", post(post_adjustment))
@@ -63,6 +65,44 @@ pub async fn create_adjustment(
     Ok((StatusCode::CREATED, Json(response)))
 }
 
+/// Adjust stock immediately with a reason code
+///
+/// Applies a signed delta to a product's stock and records an adjustment stock
+/// move in one transaction, skipping the draft/post workflow. Because the change
+/// takes effect at once, the Casbin policies only grant it to owners, admins
+/// and managers.
+///
+/// Mounted at `/adjustments/quick` rather than `POST /adjustments`, which
+/// already creates draft adjustment documents.
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/adjustments/quick",
+    tag = "adjustments",
+    operation_id = "inventory_adjustment_quick",
+    request_body = QuickAdjustmentRequest,
+    responses(
+        (status = 201, description = "Stock adjusted", body = QuickAdjustmentResponse),
+        (status = 400, description = "Invalid request or adjustment would make stock negative"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn quick_adjust(
+    auth_user: AuthUser,
+    RequirePermission { .. }: RequirePermission,
+    Extension(state): Extension<AppState>,
+    Json(request): Json<QuickAdjustmentRequest>,
+) -> Result<(StatusCode, Json<QuickAdjustmentResponse>), AppError> {
+    let response = state
+        .adjustment_service
+        .adjust(auth_user.tenant_id, auth_user.user_id, request)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
 /// Get an adjustment document by ID with its lines
 #[utoipa::path(
     get,
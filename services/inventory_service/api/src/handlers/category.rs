@@ -5,7 +5,7 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
+    response::Response,
     routing::{get, post},
     Router,
 };
@@ -14,16 +14,18 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use inventory_service_core::dto::category::{
-    BulkOperationResponse, CategoryCreateRequest, CategoryListQuery, CategoryListResponse,
-    CategoryResponse, CategoryStatsResponse, CategoryTreeResponse, CategoryUpdateRequest,
-    MoveToCategoryRequest,
+    BulkOperationResponse, CategoryCreateRequest, CategoryExportDocument, CategoryImportResponse,
+    CategoryListQuery, CategoryListResponse, CategoryPatchRequest, CategoryResponse,
+    CategoryStatsResponse, CategoryTreeResponse, CategoryUpdateRequest, MoveToCategoryRequest,
 };
 
 // use inventory_service_core::services::delivery::DeliveryService;
 
 use shared_auth::extractors::{AuthUser, RequireAdmin};
+use shared_error::extract::Json;
 use shared_error::AppError;
 
+use crate::models::ResponseShape;
 use crate::state::AppState;
 
 /// Create the category routes with state
@@ -36,16 +38,22 @@ pub fn create_category_routes() -> Router {
         .route("/bulk/activate", post(bulk_activate_categories))
         .route("/bulk/deactivate", post(bulk_deactivate_categories))
         .route("/bulk/delete", post(bulk_delete_categories))
+        .route("/recount", post(recount_category_product_counts))
+        .route("/export", get(export_categories))
+        .route("/import", post(import_categories))
         .route(
             "/{category_id}",
             get(get_category)
+                .head(category_exists)
                 .put(update_category)
+                .patch(patch_category)
                 .delete(delete_category),
         )
         .route("/{category_id}/children", get(get_children))
         .route("/{category_id}/breadcrumbs", get(get_breadcrumbs))
         .route("/{category_id}/stats", get(get_category_stats))
         .route("/{category_id}/can-delete", get(can_delete_category))
+        .route("/{category_id}/restore", post(restore_category))
         .route("/products/move", post(move_products_to_category))
 }
 
@@ -124,9 +132,12 @@ pub async fn create_category(
 /// * `is_visible` - Filter by visibility (optional)
 /// * `search` - Search in name and description (optional)
 /// * `page` - Page number (default: 1, min: 1)
-/// * `page_size` - Items per page (default: 20, max: 100)
-/// * `sort_by` - Sort field (default: display_order)
+/// * `page_size` - Items per page (default: the configured default page size; larger values are clamped to the configured max page size)
+/// * `sort_by` - Sort field (default: display_order; `relevance` ranks search matches)
 /// * `sort_dir` - Sort direction (default: asc)
+/// * `envelope` - `true` to wrap the page as `{data, meta}` (optional)
+///
+/// The envelope can also be requested with `Accept: application/json; profile="envelope"`.
 ///
 /// # Returns
 /// * `200` - Paginated list of categories with metadata
@@ -157,13 +168,16 @@ pub async fn create_category(
 pub async fn list_categories(
     auth_user: AuthUser,
     Extension(state): Extension<AppState>,
-    Query(query): Query<CategoryListQuery>,
-) -> Result<Json<CategoryListResponse>, AppError> {
+    Query(mut query): Query<CategoryListQuery>,
+    shape: ResponseShape,
+) -> Result<Response, AppError> {
+    state.page_limits.apply(&mut query)?;
+
     let response = state
         .category_service
         .list_categories(auth_user.tenant_id, query)
         .await?;
-    Ok(Json(response))
+    Ok(shape.respond(response))
 }
 
 /// GET /api/v1/inventory/categories/tree - Get hierarchical category tree
@@ -369,6 +383,55 @@ pub async fn get_category(
     Ok(Json(CategoryResponse::from(category)))
 }
 
+/// HEAD /api/v1/inventory/categories/{category_id} - Check that a category exists
+///
+/// Answers an existence check without loading or sending the category.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `category_id` - UUID of the category to check
+///
+/// # Returns
+/// * `200` - Category exists (empty body)
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Category not found or belongs to different tenant (empty body)
+#[utoipa::path(
+    head,
+    path = "/api/v1/inventory/categories/{category_id}",
+    tag = "categories",
+    operation_id = "category_exists",
+    params(
+        ("category_id" = Uuid, Path, description = "UUID of the category to check")
+    ),
+    responses(
+        (status = 200, description = "Category exists"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Category not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn category_exists(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(category_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let exists = state
+        .category_service
+        .category_exists(auth_user.tenant_id, category_id)
+        .await?;
+    Ok(if exists {
+        StatusCode::OK
+    } else {
+        StatusCode::NOT_FOUND
+    })
+}
+
 /// PUT /api/v1/inventory/categories/{category_id} - Update category
 ///
 /// Updates an existing category with the provided fields. Only specified fields
@@ -434,6 +497,54 @@ pub async fn update_category(
     Ok(Json(CategoryResponse::from(category)))
 }
 
+/// PATCH /api/v1/inventory/categories/{category_id} - Partially update category
+///
+/// Changes only the fields present in the body. Nullable fields can be
+/// cleared by sending them as `null`, and `"parentCategoryId": null` makes
+/// the category a root; omitted fields keep their value.
+///
+/// # Example
+/// ```json
+/// PATCH /api/v1/inventory/categories/123e4567-e89b-12d3-a456-426614174000
+/// {
+///   "name": "Updated Electronics",
+///   "description": null
+/// }
+/// ```
+#[utoipa::path(
+    patch,
+    path = "/api/v1/inventory/categories/{category_id}",
+    tag = "categories",
+    operation_id = "patch_category",
+    params(
+        ("category_id" = Uuid, Path, description = "UUID of the category to update")
+    ),
+    request_body = CategoryPatchRequest,
+    responses(
+        (status = 200, description = "Updated category details", body = CategoryResponse),
+        (status = 400, description = "Invalid request data"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Category or parent category not found"),
+        (status = 409, description = "Updated code or slug conflicts with existing category")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn patch_category(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(category_id): Path<Uuid>,
+    Json(request): Json<CategoryPatchRequest>,
+) -> Result<Json<CategoryResponse>, AppError> {
+    let category = state
+        .category_service
+        .patch_category(auth_user.tenant_id, category_id, request)
+        .await?;
+    Ok(Json(CategoryResponse::from(category)))
+}
+
 /// DELETE /api/v1/inventory/categories/{category_id} - Soft delete category
 ///
 /// Marks a category as deleted (soft delete). The category will no longer
@@ -488,6 +599,54 @@ pub async fn delete_category(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// POST /api/v1/inventory/categories/{category_id}/restore - Restore a deleted category
+///
+/// Undoes a soft delete. The category returns to its previous place in the
+/// tree, so every ancestor must be live: restore deleted parents first.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `category_id` - UUID of the category to restore
+///
+/// # Returns
+/// * `200` - Restored category with recomputed product counts
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Category not found
+/// * `409` - A parent category is deleted, or the code or slug is now taken
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/categories/{category_id}/restore",
+    tag = "categories",
+    operation_id = "restore_category",
+    params(
+        ("category_id" = Uuid, Path, description = "UUID of the category to restore")
+    ),
+    responses(
+        (status = 200, description = "Restored category details", body = CategoryResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Category not found"),
+        (status = 409, description = "Parent category is deleted, or code or slug is taken")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_category(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(category_id): Path<Uuid>,
+) -> Result<Json<CategoryResponse>, AppError> {
+    let category = state
+        .category_service
+        .restore_category(auth_user.tenant_id, category_id)
+        .await?;
+    Ok(Json(CategoryResponse::from(category)))
+}
+
 /// GET /api/v1/inventory/categories/{category_id}/children - Get direct children
 ///
 /// Returns all direct child categories of the specified parent category.
@@ -882,6 +1041,125 @@ pub async fn bulk_delete_categories(
     Ok(Json(response))
 }
 
+/// POST /api/v1/inventory/categories/recount - Recompute category product counts
+///
+/// Recomputes `product_count` and `total_product_count` for every category of
+/// the tenant, correcting drift left by bulk moves or restores. The same
+/// recount also runs periodically in the background.
+///
+/// # Authentication
+/// Requires admin user authentication
+///
+/// # Returns
+/// * `200` - Operation result with number of corrected categories
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/categories/recount",
+    tag = "categories",
+    operation_id = "recount_category_product_counts",
+    responses(
+        (status = 200, description = "Operation result with corrected category count", body = BulkOperationResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn recount_category_product_counts(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+) -> Result<Json<BulkOperationResponse>, AppError> {
+    let response = state
+        .category_service
+        .recount_product_counts(auth_user.tenant_id)
+        .await?;
+    Ok(Json(response))
+}
+
+/// GET /api/v1/inventory/categories/export - Export the category tree
+///
+/// Returns every active category of the tenant as a portable JSON document.
+/// Parents are referenced by code rather than ID and always precede their
+/// children, so the document can be imported into another environment.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Returns
+/// * `200` - Export document
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/categories/export",
+    tag = "categories",
+    operation_id = "export_categories",
+    responses(
+        (status = 200, description = "Portable category tree", body = CategoryExportDocument),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn export_categories(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+) -> Result<Json<CategoryExportDocument>, AppError> {
+    let document = state
+        .category_service
+        .export_categories(auth_user.tenant_id)
+        .await?;
+    Ok(Json(document))
+}
+
+/// POST /api/v1/inventory/categories/import - Import a category tree
+///
+/// Recreates the categories of an export document in dependency order,
+/// mapping codes to new IDs. Categories whose code already exists are reused,
+/// so the import can safely be repeated. Categories that cannot be created
+/// are reported as conflicts instead of failing the whole import.
+///
+/// # Authentication
+/// Requires admin user authentication
+///
+/// # Returns
+/// * `200` - Code to ID mappings and conflicts
+/// * `400` - Invalid or unsupported document
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/categories/import",
+    tag = "categories",
+    operation_id = "import_categories",
+    request_body = CategoryExportDocument,
+    responses(
+        (status = 200, description = "Import result with mappings and conflicts", body = CategoryImportResponse),
+        (status = 400, description = "Invalid or unsupported document"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn import_categories(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Json(document): Json<CategoryExportDocument>,
+) -> Result<Json<CategoryImportResponse>, AppError> {
+    let response = state
+        .category_service
+        .import_categories(auth_user.tenant_id, document)
+        .await?;
+    Ok(Json(response))
+}
+
 /// POST /api/v1/inventory/categories/products/move - Move products to category
 ///
 /// Moves multiple products from their current categories to a new target category.
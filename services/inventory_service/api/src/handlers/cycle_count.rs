@@ -6,7 +6,6 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
     routing::{get, post},
     Router,
 };
@@ -19,6 +18,7 @@ use inventory_service_core::dto::cycle_count::{
 };
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -72,6 +72,9 @@ pub fn create_cycle_count_routes() -> Router {
         (status = 400, description = "Invalid request"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn create_cycle_count(
@@ -124,6 +127,9 @@ pub async fn create_cycle_count(
         (status = 200, description = "List retrieved successfully", body = CycleCountListResponse),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn list_cycle_counts(
@@ -167,6 +173,9 @@ pub async fn list_cycle_counts(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Cycle count not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn get_cycle_count(
@@ -187,6 +196,11 @@ pub async fn get_cycle_count(
 /// Generates or refreshes count lines based on current inventory filtered by session scope.
 /// Expected quantities are computed as of the session's as_of timestamp.
 ///
+/// The `strategy` picks which stocked products get lines: `all` (default),
+/// `abc` (A/B/C items whose count interval has elapsed), `zone` (stock in one
+/// warehouse zone), `last_count_age` (longest-uncounted first) or
+/// `random_sample`.
+///
 /// # Authentication
 /// Requires authenticated user with appropriate tenant access
 ///
@@ -199,7 +213,8 @@ pub async fn get_cycle_count(
 ///   "product_id": null,
 ///   "category_id": null,
 ///   "include_lots": false,
-///   "replace_existing": true
+///   "replace_existing": true,
+///   "strategy": { "type": "last_count_age", "max_lines": 50 }
 /// }
 /// ```
 ///
@@ -224,6 +239,9 @@ pub async fn get_cycle_count(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Cycle count not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn generate_lines(
@@ -285,6 +303,9 @@ pub async fn generate_lines(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Cycle count or line not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn submit_counts(
@@ -345,6 +366,9 @@ pub async fn submit_counts(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Cycle count or lines not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn skip_lines(
@@ -396,6 +420,9 @@ pub async fn skip_lines(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Cycle count not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn close_session(
@@ -456,6 +483,9 @@ pub async fn close_session(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Cycle count not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn reconcile(
@@ -502,6 +532,9 @@ pub async fn reconcile(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Cycle count not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn cancel_session(
@@ -3,11 +3,49 @@
 //! This module contains HTTP handlers for delivery order operations including
 //! picking, packing, and shipping items.
 //!
-//! NOTE: Delivery handlers are temporarily commented out as delivery service is disabled.
+//! The service behind these handlers is selected by the `delivery_enabled` config flag;
+//! when the flag is off a stub service answers every request with 503.
 
-use axum::Router;
+use axum::{
+    extract::{Extension, Path},
+    routing::post,
+    Router,
+};
+use uuid::Uuid;
 
-/*
+use inventory_service_core::dto::delivery::{
+    PackItemsRequest, PackItemsResponse, PickItemsRequest, PickItemsResponse, ShipItemsRequest,
+    ShipItemsResponse,
+};
+use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
+use shared_error::AppError;
+
+use crate::state::AppState;
+
+/// Create the delivery routes
+pub fn create_delivery_routes() -> Router {
+    Router::new()
+        .route("/{delivery_id}/pick", post(pick_items))
+        .route("/{delivery_id}/pack", post(pack_items))
+        .route("/{delivery_id}/ship", post(ship_items))
+}
+
+/// POST /api/v1/inventory/deliveries/{delivery_id}/pick - Pick items for a delivery order
+///
+/// Records picked quantities for delivery lines and reserves the picked stock
+/// in the source warehouse.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Returns
+/// * `200` - Items picked successfully
+/// * `400` - Invalid request, business rule violation or insufficient stock
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Delivery order not found
+/// * `503` - Delivery workflow disabled
 #[utoipa::path(
     post,
     path = "/api/v1/inventory/deliveries/{delivery_id}/pick",
@@ -22,7 +60,11 @@ use axum::Router;
         (status = 400, description = "Invalid request or business rule violation"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
-        (status = 404, description = "Delivery order not found")
+        (status = 404, description = "Delivery order not found"),
+        (status = 503, description = "Delivery workflow disabled")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn pick_items(
@@ -63,16 +105,17 @@ pub async fn pick_items(
 /// * `401` - Authentication required
 /// * `403` - Insufficient permissions
 /// * `404` - Delivery order not found
+/// * `503` - Delivery workflow disabled
 ///
 /// # Business Rules
-/// - Delivery order must be in 'Picked' status
+/// - Delivery order must be in 'picked' status
 /// - Only picked orders can be packed
 ///
 /// # Example Response
 /// ```json
 /// {
 ///   "delivery_id": "550e8400-e29b-41d4-a716-446655440002",
-///   "status": "Packed",
+///   "status": "packed",
 ///   "packed_at": "2023-10-15T14:30:00Z"
 /// }
 /// ```
@@ -90,7 +133,11 @@ pub async fn pick_items(
         (status = 400, description = "Invalid request or business rule violation"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
-        (status = 404, description = "Delivery order not found")
+        (status = 404, description = "Delivery order not found"),
+        (status = 503, description = "Delivery workflow disabled")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn pack_items(
@@ -109,8 +156,9 @@ pub async fn pack_items(
 
 /// POST /api/v1/inventory/deliveries/{delivery_id}/ship - Ship items for a delivery order
 ///
-/// Ships a delivery order by creating stock moves, updating inventory levels, calculating COGS,
-/// and marking the order as shipped. This is the final step in the delivery process.
+/// Ships a parcel for a delivery order by recording a shipment, creating stock moves, updating
+/// inventory levels and calculating COGS. A delivery may be shipped in several parcels; the order
+/// is marked shipped once every line has been shipped in full.
 ///
 /// # Authentication
 /// Requires authenticated user with appropriate tenant access
@@ -121,6 +169,9 @@ pub async fn pack_items(
 /// # Request Body
 /// ```json
 /// {
+///   "items": [
+///     { "delivery_item_id": "550e8400-e29b-41d4-a716-446655440010", "quantity": 4 }
+///   ],
 ///   "tracking_number": "TRACK123456",
 ///   "carrier": "FedEx",
 ///   "shipping_cost": 500,
@@ -134,22 +185,33 @@ pub async fn pack_items(
 /// * `401` - Authentication required
 /// * `403` - Insufficient permissions
 /// * `404` - Delivery order not found
+/// * `503` - Delivery workflow disabled
 ///
 /// # Business Rules
-/// - Delivery order must be in 'Packed' status
+/// - Delivery order must be in 'packed' or 'partially_shipped' status
+/// - Omitting `items` ships every picked-but-unshipped quantity
+/// - A line can never ship more than has been picked
 /// - Creates immutable stock moves for audit trail
-/// - Updates inventory levels (decrements available stock)
+/// - Consumes the stock reserved when the items were picked
 /// - Calculates and records Cost of Goods Sold (COGS)
-/// - Publishes inventory.delivery.completed event
 ///
 /// # Example Response
 /// ```json
 /// {
 ///   "delivery_id": "550e8400-e29b-41d4-a716-446655440002",
-///   "status": "Shipped",
+///   "shipment_id": "550e8400-e29b-41d4-a716-446655440020",
+///   "status": "partially_shipped",
 ///   "shipped_at": "2023-10-15T14:30:00Z",
-///   "stock_moves_created": 3,
-///   "total_cogs": 15000
+///   "stock_moves_created": 1,
+///   "total_cogs": 2000,
+///   "remaining": [
+///     {
+///       "delivery_item_id": "550e8400-e29b-41d4-a716-446655440010",
+///       "ordered_quantity": 10,
+///       "shipped_quantity": 4,
+///       "remaining_quantity": 6
+///     }
+///   ]
 /// }
 /// ```
 #[utoipa::path(
@@ -166,7 +228,11 @@ pub async fn pack_items(
         (status = 400, description = "Invalid request or business rule violation"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
-        (status = 404, description = "Delivery order not found")
+        (status = 404, description = "Delivery order not found"),
+        (status = 503, description = "Delivery workflow disabled")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn ship_items(
@@ -182,12 +248,3 @@ pub async fn ship_items(
 
     Ok(Json(response))
 }
-*/
-
-// Dummy delivery routes function - delivery feature is disabled
-pub fn create_delivery_routes() -> Router {
-    Router::new()
-    // .route("/{delivery_id}/pick", post(pick_items))
-    // .route("/{delivery_id}/pack", post(pack_items))
-    // .route("/{delivery_id}/ship", post(ship_items))
-}
@@ -0,0 +1,162 @@
+//! Feature flag HTTP handlers
+//!
+//! Admin endpoints for reading and setting per-tenant feature flags.
+
+use axum::{
+    extract::{Extension, Path},
+    routing::get,
+    Router,
+};
+
+use inventory_service_core::domains::inventory::dto::feature_flag_dto::{
+    FeatureFlagDto, FeatureFlagListResponse, SetFeatureFlagRequest,
+};
+use inventory_service_core::domains::inventory::feature_flag::FeatureFlag;
+use shared_auth::extractors::RequireAdmin;
+use shared_error::extract::Json;
+use shared_error::AppError;
+
+use crate::state::AppState;
+
+/// Create the feature flag routes
+pub fn create_feature_flag_routes() -> Router {
+    Router::new()
+        .route("/", get(list_feature_flags))
+        .route("/{flag}", get(get_feature_flag).put(set_feature_flag))
+}
+
+fn parse_flag(flag: &str) -> Result<FeatureFlag, AppError> {
+    FeatureFlag::parse(flag)
+        .ok_or_else(|| AppError::NotFound(format!("Unknown feature flag: {}", flag)))
+}
+
+/// GET /api/v1/inventory/feature-flags - List feature flags
+///
+/// Returns every known feature flag with its effective value for the tenant.
+/// Flags without a tenant setting report their default (off).
+///
+/// # Authentication
+/// Requires admin user authentication
+///
+/// # Returns
+/// * `200` - All flags with their effective values
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/feature-flags",
+    tag = "feature-flags",
+    operation_id = "list_feature_flags",
+    responses(
+        (status = 200, description = "Feature flags for the tenant", body = FeatureFlagListResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_feature_flags(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+) -> Result<Json<FeatureFlagListResponse>, AppError> {
+    let flags = state
+        .feature_flag_service
+        .list_flags(auth_user.tenant_id)
+        .await?;
+    Ok(Json(flags))
+}
+
+/// GET /api/v1/inventory/feature-flags/{flag} - Get a feature flag
+///
+/// # Authentication
+/// Requires admin user authentication
+///
+/// # Returns
+/// * `200` - Flag with its effective value
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+/// * `404` - Unknown flag
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/feature-flags/{flag}",
+    tag = "feature-flags",
+    operation_id = "get_feature_flag",
+    params(
+        ("flag" = String, Path, description = "Feature flag key, e.g. negative_stock")
+    ),
+    responses(
+        (status = 200, description = "Feature flag", body = FeatureFlagDto),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 404, description = "Unknown feature flag")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_feature_flag(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Path(flag): Path<String>,
+) -> Result<Json<FeatureFlagDto>, AppError> {
+    let flag = parse_flag(&flag)?;
+    let dto = state
+        .feature_flag_service
+        .get_flag(auth_user.tenant_id, flag)
+        .await?;
+    Ok(Json(dto))
+}
+
+/// PUT /api/v1/inventory/feature-flags/{flag} - Enable or disable a feature flag
+///
+/// Stores the tenant setting and invalidates the cached value, so the next
+/// flag read returns the new value. Flags are recorded per tenant; no inventory
+/// operation changes its behavior based on them yet.
+///
+/// # Authentication
+/// Requires admin user authentication
+///
+/// # Returns
+/// * `200` - Updated flag
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+/// * `404` - Unknown flag
+///
+/// # Example
+/// ```json
+/// PUT /api/v1/inventory/feature-flags/blind_counts
+/// { "enabled": true }
+/// ```
+#[utoipa::path(
+    put,
+    path = "/api/v1/inventory/feature-flags/{flag}",
+    tag = "feature-flags",
+    operation_id = "set_feature_flag",
+    params(
+        ("flag" = String, Path, description = "Feature flag key, e.g. negative_stock")
+    ),
+    request_body = SetFeatureFlagRequest,
+    responses(
+        (status = 200, description = "Feature flag updated", body = FeatureFlagDto),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 404, description = "Unknown feature flag")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_feature_flag(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Path(flag): Path<String>,
+    Json(request): Json<SetFeatureFlagRequest>,
+) -> Result<Json<FeatureFlagDto>, AppError> {
+    let flag = parse_flag(&flag)?;
+    let dto = state
+        .feature_flag_service
+        .set_flag(auth_user.tenant_id, flag, request.enabled, auth_user.user_id)
+        .await?;
+    Ok(Json(dto))
+}
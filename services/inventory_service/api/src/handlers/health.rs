@@ -4,9 +4,12 @@ use axum::{
     Json,
 };
 use chrono::{DateTime, Utc};
+use inventory_service_infra::services::RedisDistributedLockService;
 use serde::Serialize;
 use shared_config::Config;
 use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
 use utoipa::ToSchema;
 
 use shared_error::AppError;
@@ -20,31 +23,38 @@ pub struct HealthResp {
     pub nats: String,
 }
 
-#[utoipa::path(
-    get,
-    path = "/health",
-    tag = "health",
-    operation_id = "inventory_health_check",
-    responses(
-        (status = 200, description = "Service is healthy", body = HealthResp),
-        (status = 503, description = "Service is unhealthy", body = HealthResp)
-    )
-)]
-pub async fn health_check(
-    axum::Extension(pool): axum::Extension<PgPool>,
-    axum::Extension(config): axum::Extension<Config>,
-) -> Result<Response, AppError> {
-    // Check database connection
-    let db_status = match sqlx::query("SELECT 1").execute(&pool).await {
+/// Upper bound for each dependency check in the readiness probe
+const READINESS_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Per-dependency status: "healthy", "unhealthy" or "not_configured"
+#[derive(Serialize, ToSchema)]
+pub struct DependencyStatus {
+    pub database: String,
+    pub redis: String,
+    pub nats: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ReadinessResp {
+    /// "ready" when every configured dependency is healthy, otherwise "not_ready"
+    pub status: String,
+    pub version: String,
+    pub timestamp: DateTime<Utc>,
+    pub checks: DependencyStatus,
+}
+
+async fn check_database(pool: &PgPool) -> String {
+    match sqlx::query("SELECT 1").execute(pool).await {
         Ok(_) => "healthy".to_string(),
         Err(e) => {
             tracing::error!("Database health check failed: {}", e);
             "unhealthy".to_string()
         },
-    };
+    }
+}
 
-    // Check NATS connection
-    let nats_status = if config.nats_url.is_some() {
+fn check_nats(config: &Config) -> String {
+    if config.nats_url.is_some() {
         match shared_events::get_nats_client() {
             Ok(client) => {
                 // Try to check if NATS is responsive by attempting a simple operation
@@ -63,7 +73,49 @@ pub async fn health_check(
         }
     } else {
         "not_configured".to_string()
-    };
+    }
+}
+
+async fn check_redis(lock_service: &RedisDistributedLockService) -> String {
+    match lock_service.ping().await {
+        Ok(()) => "healthy".to_string(),
+        Err(e) => {
+            tracing::error!("Redis health check failed: {}", e);
+            "unhealthy".to_string()
+        },
+    }
+}
+
+/// Run a dependency check, reporting it unhealthy if it does not finish in time
+async fn with_timeout(name: &str, check: impl std::future::Future<Output = String>) -> String {
+    match tokio::time::timeout(READINESS_CHECK_TIMEOUT, check).await {
+        Ok(status) => status,
+        Err(_) => {
+            tracing::error!("{} readiness check timed out", name);
+            "unhealthy".to_string()
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    operation_id = "inventory_health_check",
+    responses(
+        (status = 200, description = "Service is healthy", body = HealthResp),
+        (status = 503, description = "Service is unhealthy", body = HealthResp)
+    )
+)]
+pub async fn health_check(
+    axum::Extension(pool): axum::Extension<PgPool>,
+    axum::Extension(config): axum::Extension<Config>,
+) -> Result<Response, AppError> {
+    // Check database connection
+    let db_status = check_database(&pool).await;
+
+    // Check NATS connection
+    let nats_status = check_nats(&config);
 
     let overall_status = if db_status == "healthy"
         && (nats_status == "healthy" || nats_status == "not_configured")
@@ -87,3 +139,51 @@ pub async fn health_check(
         Ok((StatusCode::OK, Json(resp)).into_response())
     }
 }
+
+/// Readiness probe: verifies Postgres, Redis and (when configured) NATS
+///
+/// Returns 200 only when every configured dependency answers; otherwise 503,
+/// so the pod is taken out of rotation. `/health` stays as the liveness probe.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    operation_id = "inventory_readiness_check",
+    responses(
+        (status = 200, description = "All dependencies are reachable", body = ReadinessResp),
+        (status = 503, description = "At least one dependency is unavailable", body = ReadinessResp)
+    )
+)]
+pub async fn readiness_check(
+    axum::Extension(pool): axum::Extension<PgPool>,
+    axum::Extension(config): axum::Extension<Config>,
+    axum::Extension(lock_service): axum::Extension<Arc<RedisDistributedLockService>>,
+) -> Response {
+    let (database, redis) = tokio::join!(
+        with_timeout("Database", check_database(&pool)),
+        with_timeout("Redis", check_redis(&lock_service)),
+    );
+    let nats = check_nats(&config);
+
+    let ready = database == "healthy"
+        && redis == "healthy"
+        && (nats == "healthy" || nats == "not_configured");
+
+    let resp = ReadinessResp {
+        status: if ready { "ready" } else { "not_ready" }.to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        timestamp: Utc::now(),
+        checks: DependencyStatus {
+            database,
+            redis,
+            nats,
+        },
+    };
+
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(resp)).into_response()
+}
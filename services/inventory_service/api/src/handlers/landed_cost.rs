@@ -4,7 +4,6 @@
 
 use axum::{
     extract::{Extension, Path, Query},
-    response::Json,
     routing::{delete, get, post, put},
     Router,
 };
@@ -20,6 +19,7 @@ use inventory_service_core::domains::inventory::landed_cost::LandedCostDocument;
 
 use crate::state::AppState;
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 /// Query parameters for listing documents
@@ -0,0 +1,111 @@
+//! Inventory level change stream (Server-Sent Events)
+//!
+//! Pushes level-changing events for the caller's tenant so dashboards do not
+//! have to poll the stock level endpoints.
+
+use std::convert::Infallible;
+
+use axum::{
+    extract::Extension,
+    response::sse::{Event, KeepAlive, Sse},
+    routing::get,
+    Router,
+};
+use futures::stream::{self, Stream};
+use serde_json::json;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use shared_auth::extractors::AuthUser;
+use shared_events::is_valid_correlation_id;
+
+use crate::level_events::{LevelChangeBroadcaster, LevelChangeEvent};
+
+/// SSE event name sent when a subscriber fell behind and missed changes
+pub const LAGGED_EVENT: &str = "lagged";
+
+/// Create the level stream routes
+pub fn create_level_stream_routes() -> Router {
+    Router::new().route("/stream", get(stream_level_changes))
+}
+
+/// GET /api/v1/inventory/levels/stream - Stream inventory level changes
+///
+/// Opens a `text/event-stream` that emits one event per level change in the
+/// caller's tenant. The SSE event name is the domain event type (for example
+/// `inventory.updated`) and the data is the event payload as JSON. When the
+/// change was caused by an HTTP request, the SSE event id is that request's
+/// correlation id.
+///
+/// A client that reads too slowly skips the oldest changes instead of holding
+/// back other subscribers; it then receives a `lagged` event with the number of
+/// skipped changes and should refetch stock levels. The subscription is dropped
+/// as soon as the client disconnects.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Returns
+/// * `200` - Event stream of level changes
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/levels/stream",
+    tag = "stock-levels",
+    operation_id = "stream_level_changes",
+    responses(
+        (status = 200, description = "Server-Sent Events stream of level changes", content_type = "text/event-stream", body = String),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn stream_level_changes(
+    auth_user: AuthUser,
+    Extension(level_events): Extension<LevelChangeBroadcaster>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    Sse::new(tenant_level_changes(&level_events, auth_user.tenant_id))
+        .keep_alive(KeepAlive::default())
+}
+
+/// Subscribe now and yield the tenant's changes as SSE events
+fn tenant_level_changes(
+    level_events: &LevelChangeBroadcaster,
+    tenant_id: Uuid,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    // Subscribe before the response is returned so no change published after
+    // the request is accepted can be missed
+    let receiver = level_events.subscribe();
+
+    stream::unfold(receiver, move |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(change) if change.tenant_id == tenant_id => {
+                    return Some((Ok(to_sse_event(&change)), receiver));
+                },
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => {
+                    let event = Event::default()
+                        .event(LAGGED_EVENT)
+                        .data(json!({ "skipped": skipped }).to_string());
+                    return Some((Ok(event), receiver));
+                },
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
+fn to_sse_event(change: &LevelChangeEvent) -> Event {
+    let event = Event::default()
+        .event(&change.event_type)
+        .data(change.data.to_string());
+    // Ids from other producers are only echoed when they are safe header values
+    match &change.correlation_id {
+        Some(correlation_id) if is_valid_correlation_id(correlation_id) => event.id(correlation_id),
+        _ => event,
+    }
+}
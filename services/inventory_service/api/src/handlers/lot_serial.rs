@@ -1,7 +1,7 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    Json, Router,
+    Router,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -13,6 +13,7 @@ use inventory_service_core::models::{
 };
 
 use shared_auth::extractors::{AuthUser, RequireAdmin};
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
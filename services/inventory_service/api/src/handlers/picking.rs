@@ -5,7 +5,6 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
     routing::{delete, get, post, put},
     Router,
 };
@@ -17,6 +16,7 @@ use inventory_service_core::domains::inventory::dto::picking_method_dto::{
     PickingOptimizationRequest, PickingPlanResponse, UpdatePickingMethodRequest,
 };
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -43,7 +43,8 @@ pub fn create_picking_routes() -> Router {
     request_body = CreatePickingMethodRequest,
     responses(
         (status = 201, description = "Picking method created", body = PickingMethodResponse),
-        (status = 400, description = "Invalid request data"),
+        (status = 400, description = "Malformed request body"),
+        (status = 422, description = "Request failed validation; lists the failing fields"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
     ),
@@ -143,6 +144,8 @@ pub async fn get_picking_method(
     request_body = UpdatePickingMethodRequest,
     responses(
         (status = 200, description = "Picking method updated", body = PickingMethodResponse),
+        (status = 400, description = "Malformed request body"),
+        (status = 422, description = "Request failed validation; lists the failing fields"),
         (status = 404, description = "Picking method not found"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
@@ -243,7 +246,8 @@ pub async fn set_default_method(
     request_body = PickingOptimizationRequest,
     responses(
         (status = 200, description = "Picking plan generated", body = PickingPlanResponse),
-        (status = 400, description = "Invalid request data"),
+        (status = 400, description = "Malformed request body"),
+        (status = 422, description = "Request failed validation; lists the failing fields"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
     ),
@@ -274,7 +278,8 @@ pub async fn optimize_picking(
     request_body = ConfirmPickingPlanRequest,
     responses(
         (status = 200, description = "Picking plan confirmed"),
-        (status = 400, description = "Invalid request data"),
+        (status = 400, description = "Malformed request body"),
+        (status = 422, description = "Request failed validation; lists the failing fields"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
     ),
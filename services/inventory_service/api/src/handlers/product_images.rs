@@ -5,7 +5,6 @@
 use axum::{
     extract::{Extension, Multipart, Path},
     http::StatusCode,
-    response::Json,
     routing::{get, post},
     Router,
 };
@@ -17,14 +16,21 @@ use inventory_service_core::dto::product_image::{
 };
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
+use crate::middleware::{concurrency_limit_middleware, ConcurrencyLimit};
 use crate::state::AppState;
 
 /// Create the product image routes
-pub fn create_product_image_routes() -> Router {
+///
+/// Uploads are admitted through `upload_limit`; the other routes are not limited.
+pub fn create_product_image_routes(upload_limit: ConcurrencyLimit) -> Router {
+    let upload = post(upload_image)
+        .layer(axum::middleware::from_fn_with_state(upload_limit, concurrency_limit_middleware));
+
     Router::new()
-        .route("/", get(list_images).post(upload_image))
+        .route("/", get(list_images).merge(upload))
         .route("/{image_id}", get(get_image).put(update_image).delete(delete_image))
         .route("/reorder", post(reorder_images))
         .route("/{image_id}/set-primary", post(set_primary_image))
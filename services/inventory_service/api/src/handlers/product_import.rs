@@ -8,7 +8,7 @@ use axum::{
     http::{header, StatusCode},
     response::{IntoResponse, Response},
     routing::{get, post},
-    Json, Router,
+    Router,
 };
 
 use inventory_service_core::dto::product_import::{
@@ -16,6 +16,7 @@ use inventory_service_core::dto::product_import::{
 };
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -5,7 +5,6 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
     routing::{get, post},
     Router,
 };
@@ -20,6 +19,7 @@ use inventory_service_core::dto::product_variant::{
 };
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
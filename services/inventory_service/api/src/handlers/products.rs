@@ -5,7 +5,7 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
+    response::Response,
     routing::{get, post},
     Router,
 };
@@ -17,13 +17,16 @@ use validator::Validate;
 // Import DTOs for requests/responses
 use inventory_service_core::dto::category::BulkOperationResponse;
 use inventory_service_core::dto::product::{
-    ProductCreateRequest, ProductListQuery, ProductListResponse, ProductResponse,
-    ProductUpdateRequest,
+    parse_attribute_filters, ProductAttributesResponse, ProductCreateRequest, ProductListQuery,
+    ProductListResponse, ProductPatchRequest, ProductResponse, ProductUpdateRequest,
+    SetProductAttributesRequest,
 };
 
 use shared_auth::extractors::{AuthUser, RequireAdmin};
+use shared_error::extract::Json;
 use shared_error::AppError;
 
+use crate::models::ResponseShape;
 use crate::state::AppState;
 
 /// Request body for bulk operations
@@ -38,10 +41,22 @@ pub fn create_product_routes() -> Router {
     Router::new()
         .route("/", get(list_products).post(create_product))
         .route("/by-barcode/{barcode}", get(get_product_by_barcode))
-        .route("/{product_id}", get(get_product).put(update_product).delete(delete_product))
+        .route(
+            "/{product_id}",
+            get(get_product)
+                .head(product_exists)
+                .put(update_product)
+                .patch(patch_product)
+                .delete(delete_product),
+        )
+        .route(
+            "/{product_id}/attributes",
+            get(get_product_attributes).put(set_product_attributes),
+        )
         .route("/bulk/activate", post(bulk_activate_products))
         .route("/bulk/deactivate", post(bulk_deactivate_products))
         .route("/bulk/delete", post(bulk_delete_products))
+        .route("/bulk/restore", post(bulk_restore_products))
 }
 
 /// POST /api/v1/inventory/products - Create a new product
@@ -133,19 +148,27 @@ pub async fn create_product(
 /// * `is_purchaseable` - Filter by purchaseable status (optional)
 /// * `search` - Search in name, SKU, and description (optional)
 /// * `page` - Page number (default: 1, min: 1)
-/// * `page_size` - Items per page (default: 20, max: 100)
+/// * `page_size` - Items per page (default: the configured default page size; larger values are clamped to the configured max page size)
 /// * `sort_by` - Sort field (default: name)
 /// * `sort_dir` - Sort direction (default: asc)
+/// * `sort` - Multi-field sort, e.g. `category:asc,name:asc` (overrides `sort_by`/`sort_dir`)
+/// * `attr.<key>` - Only products whose attribute `<key>` equals the value (repeatable per key)
+/// * `envelope` - `true` to wrap the page as `{data, meta}` (optional)
+///
+/// The envelope can also be requested with `Accept: application/json; profile="envelope"`.
 ///
 /// # Returns
 /// * `200` - Paginated list of products with metadata
-/// * `400` - Invalid query parameters
+/// * `400` - Invalid query parameters or unknown sort field
 /// * `401` - Authentication required
 /// * `403` - Insufficient permissions
 ///
 /// # Example
 /// ```
 /// GET /api/v1/inventory/products?page=1&page_size=10&is_active=true&product_type=goods
+/// GET /api/v1/inventory/products?sort=category:asc,name:asc
+/// GET /api/v1/inventory/products?attr.color=red&attr.size=xl
+/// GET /api/v1/inventory/products?envelope=true
 /// ```
 #[utoipa::path(
     get,
@@ -166,8 +189,13 @@ pub async fn create_product(
 pub async fn list_products(
     auth_user: AuthUser,
     Extension(state): Extension<AppState>,
-    Query(query): Query<ProductListQuery>,
-) -> Result<Json<ProductListResponse>, AppError> {
+    Query(mut query): Query<ProductListQuery>,
+    Query(params): Query<Vec<(String, String)>>,
+    shape: ResponseShape,
+) -> Result<Response, AppError> {
+    state.page_limits.apply(&mut query)?;
+    query.attributes = parse_attribute_filters(&params).map_err(AppError::ValidationError)?;
+
     // Validate query parameters
     query
         .validate()
@@ -177,7 +205,7 @@ pub async fn list_products(
         .product_service
         .list_products(auth_user.tenant_id, query)
         .await?;
-    Ok(Json(response))
+    Ok(shape.respond(response))
 }
 
 /// GET /api/v1/inventory/products/{product_id} - Get product by ID
@@ -231,6 +259,55 @@ pub async fn get_product(
     Ok(Json(ProductResponse::from(product)))
 }
 
+/// HEAD /api/v1/inventory/products/{product_id} - Check that a product exists
+///
+/// Answers an existence check without loading or sending the product.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `product_id` - UUID of the product to check
+///
+/// # Returns
+/// * `200` - Product exists (empty body)
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Product not found or belongs to different tenant (empty body)
+#[utoipa::path(
+    head,
+    path = "/api/v1/inventory/products/{product_id}",
+    tag = "products",
+    operation_id = "product_exists",
+    params(
+        ("product_id" = Uuid, Path, description = "UUID of the product to check")
+    ),
+    responses(
+        (status = 200, description = "Product exists"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Product not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn product_exists(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(product_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let exists = state
+        .product_service
+        .product_exists(auth_user.tenant_id, product_id)
+        .await?;
+    Ok(if exists {
+        StatusCode::OK
+    } else {
+        StatusCode::NOT_FOUND
+    })
+}
+
 /// GET /api/v1/inventory/products/by-barcode/{barcode} - Get product by barcode
 ///
 /// Retrieves a single product by its barcode (EAN, UPC, custom, etc.) within the tenant.
@@ -352,6 +429,180 @@ pub async fn update_product(
     Ok(Json(ProductResponse::from(product)))
 }
 
+/// PATCH /api/v1/inventory/products/{product_id} - Partially update a product
+///
+/// Changes only the fields present in the body. Nullable fields can be
+/// cleared by sending them as `null`; omitted fields keep their value.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `product_id` - UUID of the product to update
+///
+/// # Request Body
+/// JSON object with the fields to change
+///
+/// # Returns
+/// * `200` - Product updated successfully
+/// * `400` - Invalid request data
+/// * `404` - Product not found
+///
+/// # Example
+/// ```json
+/// PATCH /api/v1/inventory/products/123e4567-e89b-12d3-a456-426614174000
+/// {
+///   "name": "Updated Widget Name",
+///   "description": null
+/// }
+/// ```
+#[utoipa::path(
+    patch,
+    path = "/api/v1/inventory/products/{product_id}",
+    tag = "products",
+    operation_id = "patch_product",
+    params(
+        ("product_id" = Uuid, Path, description = "UUID of the product to update")
+    ),
+    request_body = ProductPatchRequest,
+    responses(
+        (status = 200, description = "Updated product details", body = ProductResponse),
+        (status = 400, description = "Invalid request data"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Product not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn patch_product(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(product_id): Path<Uuid>,
+    Json(request): Json<ProductPatchRequest>,
+) -> Result<Json<ProductResponse>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let product = state
+        .product_service
+        .patch_product(auth_user.tenant_id, product_id, request)
+        .await?;
+    Ok(Json(ProductResponse::from(product)))
+}
+
+/// GET /api/v1/inventory/products/{product_id}/attributes - Get product attributes
+///
+/// Retrieves the specification attributes (key/value pairs) of a product.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `product_id` - UUID of the product
+///
+/// # Returns
+/// * `200` - Product attributes
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Product not found
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/products/{product_id}/attributes",
+    tag = "products",
+    operation_id = "get_product_attributes",
+    params(
+        ("product_id" = Uuid, Path, description = "UUID of the product")
+    ),
+    responses(
+        (status = 200, description = "Product attributes", body = ProductAttributesResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Product not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_product_attributes(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(product_id): Path<Uuid>,
+) -> Result<Json<ProductAttributesResponse>, AppError> {
+    let attributes = state
+        .product_service
+        .get_product_attributes(auth_user.tenant_id, product_id)
+        .await?;
+    Ok(Json(ProductAttributesResponse {
+        product_id,
+        attributes,
+    }))
+}
+
+/// PUT /api/v1/inventory/products/{product_id}/attributes - Replace product attributes
+///
+/// Replaces all specification attributes of a product. Keys must start with a
+/// lowercase letter and contain only `a-z`, `0-9`, `_` or `-`; values must be
+/// non-empty strings.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `product_id` - UUID of the product
+///
+/// # Returns
+/// * `200` - Stored product attributes
+/// * `400` - Invalid attribute key or value
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Product not found
+///
+/// # Example
+/// ```json
+/// PUT /api/v1/inventory/products/123e4567-e89b-12d3-a456-426614174000/attributes
+/// {
+///   "attributes": { "color": "red", "voltage": "220V" }
+/// }
+/// ```
+#[utoipa::path(
+    put,
+    path = "/api/v1/inventory/products/{product_id}/attributes",
+    tag = "products",
+    operation_id = "set_product_attributes",
+    params(
+        ("product_id" = Uuid, Path, description = "UUID of the product")
+    ),
+    request_body = SetProductAttributesRequest,
+    responses(
+        (status = 200, description = "Stored product attributes", body = ProductAttributesResponse),
+        (status = 400, description = "Invalid attribute key or value"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Product not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_product_attributes(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(product_id): Path<Uuid>,
+    Json(request): Json<SetProductAttributesRequest>,
+) -> Result<Json<ProductAttributesResponse>, AppError> {
+    let attributes = state
+        .product_service
+        .set_product_attributes(auth_user.tenant_id, product_id, request.attributes)
+        .await?;
+    Ok(Json(ProductAttributesResponse {
+        product_id,
+        attributes,
+    }))
+}
+
 /// DELETE /api/v1/inventory/products/{product_id} - Soft delete product
 ///
 /// Marks a product as deleted (soft delete). The product will no longer
@@ -508,7 +759,8 @@ pub async fn bulk_deactivate_products(
 
 /// POST /api/v1/inventory/products/bulk/delete - Bulk delete products
 ///
-/// Soft deletes multiple products at once. Only admins can perform this operation.
+/// Soft deletes multiple products at once in one transaction and recalculates
+/// the product counts of their categories. Only admins can perform this operation.
 ///
 /// # Authentication
 /// Requires admin privileges
@@ -553,3 +805,53 @@ pub async fn bulk_delete_products(
         message: format!("{} products deleted successfully", affected_count),
     }))
 }
+
+/// POST /api/v1/inventory/products/bulk/restore - Bulk restore products
+///
+/// Restores multiple soft-deleted products at once in one transaction and
+/// recalculates the product counts of their categories. Products that are not
+/// deleted are skipped. Only admins can perform this operation.
+///
+/// # Authentication
+/// Requires admin privileges
+///
+/// # Parameters
+/// * `request` - List of product IDs to restore
+///
+/// # Returns
+/// * `200` - Operation result with affected count
+/// * `400` - Invalid product IDs
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/products/bulk/restore",
+    tag = "products",
+    operation_id = "bulk_restore_products",
+    request_body = BulkProductIds,
+    responses(
+        (status = 200, description = "Operation result with affected count", body = BulkOperationResponse),
+        (status = 400, description = "Invalid product IDs"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn bulk_restore_products(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Json(request): Json<BulkProductIds>,
+) -> Result<Json<BulkOperationResponse>, AppError> {
+    let affected_count = state
+        .product_service
+        .bulk_restore_products(auth_user.tenant_id, &request.product_ids)
+        .await?;
+
+    Ok(Json(BulkOperationResponse {
+        success: true,
+        affected_count: affected_count as u32,
+        message: format!("{} products restored successfully", affected_count),
+    }))
+}
@@ -2,12 +2,13 @@
 //!
 //! This module contains the Axum handlers for putaway operations.
 
-use axum::{extract::Extension, response::Json, routing::post, Router};
+use axum::{extract::Extension, routing::post, Router};
 
 use inventory_service_core::models::{
     ConfirmPutawayRequest, ConfirmPutawayResponse, PutawayRequest, PutawayResponse,
 };
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
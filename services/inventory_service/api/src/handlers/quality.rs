@@ -1,14 +1,15 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    Json,
 };
 use inventory_service_core::domains::quality::{
-    CreateQualityControlPoint, QualityControlPoint, UpdateQualityControlPoint,
+    CreateQualityControlPoint, QualityCheck, QualityControlPoint, RecordQualityCheckResult,
+    UpdateQualityControlPoint,
 };
 use inventory_service_core::AppError;
 use serde::Deserialize;
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use uuid::Uuid;
 
 use crate::state::AppState;
@@ -194,6 +195,74 @@ pub async fn delete_qc_point(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[derive(Deserialize, utoipa::IntoParams)]
+pub struct ListQualityChecksQuery {
+    /// Receipt whose quality checks should be listed
+    pub receipt_id: Uuid,
+}
+
+/// List quality checks created for a goods receipt
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/quality/checks",
+    tag = "quality",
+    operation_id = "list_quality_checks",
+    params(
+        ListQualityChecksQuery
+    ),
+    responses(
+        (status = 200, body = Vec<QualityCheck>),
+        (status = 401, body = ErrorResponse),
+        (status = 500, body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_quality_checks(
+    Extension(state): Extension<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<ListQualityChecksQuery>,
+) -> Result<Json<Vec<QualityCheck>>, AppError> {
+    let checks = state
+        .quality_service
+        .list_checks_for_receipt(auth_user.tenant_id, query.receipt_id)
+        .await?;
+    Ok(Json(checks))
+}
+
+/// Record the result of a pending quality check
+///
+/// A pass releases the held quantity to available stock; a fail moves it to quarantine.
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/quality/checks/{qc_id}/result",
+    tag = "quality",
+    operation_id = "record_quality_check_result",
+    params(
+        ("qc_id" = Uuid, Path, description = "Quality check ID")
+    ),
+    request_body = RecordQualityCheckResult,
+    responses(
+        (status = 200, body = QualityCheck),
+        (status = 400, body = ErrorResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 401, body = ErrorResponse),
+        (status = 500, body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn record_quality_check_result(
+    Extension(state): Extension<AppState>,
+    auth_user: AuthUser,
+    Path(qc_id): Path<Uuid>,
+    Json(result): Json<RecordQualityCheckResult>,
+) -> Result<Json<QualityCheck>, AppError> {
+    let check = state
+        .quality_service
+        .record_check_result(auth_user.tenant_id, qc_id, auth_user.user_id, result)
+        .await?;
+    Ok(Json(check))
+}
+
 pub fn create_quality_routes() -> axum::Router {
     axum::Router::new()
         .route("/points", axum::routing::post(create_qc_point).get(list_qc_points))
@@ -203,4 +272,6 @@ pub fn create_quality_routes() -> axum::Router {
                 .put(update_qc_point)
                 .delete(delete_qc_point),
         )
+        .route("/checks", axum::routing::get(list_quality_checks))
+        .route("/checks/{qc_id}/result", axum::routing::post(record_quality_check_result))
 }
@@ -5,7 +5,6 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
     routing::{get, post},
     Router,
 };
@@ -14,8 +13,10 @@ use validator::Validate;
 
 use inventory_service_core::dto::receipt::{
     ReceiptCreateRequest, ReceiptListQuery, ReceiptListResponse, ReceiptResponse,
+    ReceiptReverseRequest,
 };
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -26,6 +27,7 @@ pub fn create_receipt_routes() -> Router {
         .route("/", post(create_receipt).get(list_receipts))
         .route("/{receipt_id}", get(get_receipt))
         .route("/{receipt_id}/validate", post(validate_receipt))
+        .route("/{receipt_id}/reverse", post(reverse_receipt))
 }
 
 /// POST /api/v1/inventory/receipts - Create a new Goods Receipt Note
@@ -121,7 +123,7 @@ pub async fn create_receipt(
 /// * `created_after` - Filter receipts created after this date (optional)
 /// * `created_before` - Filter receipts created before this date (optional)
 /// * `page` - Page number (default: 1, min: 1)
-/// * `page_size` - Items per page (default: 20, max: 100)
+/// * `page_size` - Items per page (default: the configured default page size; larger values are clamped to the configured max page size)
 ///
 /// # Returns
 /// * `200` - Paginated list of receipts with summary information
@@ -152,8 +154,10 @@ pub async fn create_receipt(
 pub async fn list_receipts(
     auth_user: AuthUser,
     Extension(state): Extension<AppState>,
-    Query(query): Query<ReceiptListQuery>,
+    Query(mut query): Query<ReceiptListQuery>,
 ) -> Result<Json<ReceiptListResponse>, AppError> {
+    state.page_limits.apply(&mut query)?;
+
     query
         .validate()
         .map_err(|e| AppError::ValidationError(e.to_string()))?;
@@ -296,3 +300,69 @@ pub async fn validate_receipt(
 
     Ok(Json(receipt))
 }
+
+/// POST /api/v1/inventory/receipts/{receipt_id}/reverse - Reverse a received receipt
+///
+/// Undoes a receipt that was posted in error. Each of its stock moves gets a
+/// compensating move, the received quantity is taken back out of stock, and the
+/// valuation layers and value it added are removed. The receipt ends in status
+/// 'reversed'.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `receipt_id` - UUID of the receipt to reverse
+///
+/// # Returns
+/// * `200` - Receipt reversed
+/// * `400` - Missing reason, receipt is not 'received', or its stock was already
+///   reserved, picked or consumed
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Receipt not found
+///
+/// # Example
+/// ```json
+/// POST /api/v1/inventory/receipts/550e8400-e29b-41d4-a716-446655440000/reverse
+/// {
+///   "reason": "Entered twice"
+/// }
+/// ```
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/receipts/{receipt_id}/reverse",
+    tag = "receipts",
+    operation_id = "reverse_receipt",
+    params(
+        ("receipt_id" = Uuid, Path, description = "UUID of the receipt to reverse")
+    ),
+    request_body = ReceiptReverseRequest,
+    responses(
+        (status = 200, description = "Receipt reversed", body = ReceiptResponse),
+        (status = 400, description = "Receipt cannot be reversed"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Receipt not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn reverse_receipt(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(receipt_id): Path<Uuid>,
+    Json(request): Json<ReceiptReverseRequest>,
+) -> Result<Json<ReceiptResponse>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let receipt = state
+        .receipt_service
+        .reverse_receipt(auth_user.tenant_id, receipt_id, &request.reason, auth_user.user_id)
+        .await?;
+
+    Ok(Json(receipt))
+}
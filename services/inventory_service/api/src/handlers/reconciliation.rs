@@ -1,21 +1,25 @@
 use axum::{
+    body::Bytes,
     extract::{Extension, Path, Query},
-    http::StatusCode,
-    response::Json,
+    http::{header, HeaderMap, StatusCode, Uri},
     routing::{get, post},
     Router,
 };
 use uuid::Uuid;
 
+use inventory_service_core::dto::common::DateRangeParams;
 use inventory_service_core::dto::reconciliation::{
     ApproveReconciliationRequest, ApproveReconciliationResponse, CountReconciliationRequest,
     CountReconciliationResponse, CreateReconciliationRequest, CreateReconciliationResponse,
-    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ReconciliationAnalyticsQuery,
-    ReconciliationAnalyticsResponse, ReconciliationDetailResponse, ReconciliationListQuery,
-    ReconciliationListResponse, ScanBarcodeRequest, ScanBarcodeResponse, VarianceAnalysisResponse,
+    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ImportReconciliationRequest,
+    ImportReconciliationResponse, ReconciliationAnalyticsQuery, ReconciliationAnalyticsResponse,
+    ReconciliationDetailResponse, ReconciliationImportQuery, ReconciliationListQuery,
+    ReconciliationListResponse, ReconciliationTrendQuery, ReconciliationTrendResponse,
+    ScanBarcodeRequest, ScanBarcodeResponse, VarianceAnalysisResponse,
 };
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -24,7 +28,9 @@ use crate::state::AppState;
 pub fn create_reconciliation_routes() -> Router {
     Router::new()
         .route("/", post(create_reconciliation))
+        .route("/import", post(import_reconciliation))
         .route("/analytics", get(get_reconciliation_analytics))
+        .route("/analytics/trend", get(get_reconciliation_variance_trend))
         .route("/{reconciliation_id}/count", post(count_reconciliation))
         .route("/{reconciliation_id}/scan", post(scan_barcode))
         .route("/{reconciliation_id}/finalize", post(finalize_reconciliation))
@@ -89,6 +95,9 @@ pub fn create_reconciliation_routes() -> Router {
         (status = 400, description = "Invalid request"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn create_reconciliation(
@@ -104,6 +113,105 @@ pub async fn create_reconciliation(
     Ok((StatusCode::CREATED, Json(response)))
 }
 
+/// POST /api/v1/inventory/reconciliations/import - Create reconciliation from a stock snapshot
+///
+/// Creates a reconciliation with counted items for every row of a stock snapshot in one
+/// transaction, instead of creating the session and submitting counts item by item.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Request Body
+/// Either `text/csv` with a `sku,location,counted_qty` header (the reconciliation is
+/// described by the `name`, `warehouse_id`, `description` and `notes` query parameters),
+/// or JSON:
+/// ```json
+/// {
+///   "reconciliation": {
+///     "name": "Year-end count",
+///     "cycle_type": "Full",
+///     "warehouse_id": "550e8400-e29b-41d4-a716-446655440000"
+///   },
+///   "rows": [
+///     { "sku": "SKU-001", "location": "A-01-01", "counted_qty": 95 },
+///     { "sku": "SKU-002", "counted_qty": 0 }
+///   ]
+/// }
+/// ```
+///
+/// # Returns
+/// * `201` - Reconciliation created with all rows counted
+/// * `400` - Invalid request or unparseable file
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Warehouse not found
+/// * `422` - Some rows could not be resolved; nothing was created and `errors` lists them
+///
+/// # Business Rules
+/// - SKUs and location codes are resolved within the tenant and warehouse
+/// - Rows for the same SKU are summed into one item
+/// - Expected quantities come from current available stock
+/// - The reconciliation starts in 'InProgress' status
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/reconciliations/import",
+    tag = "reconciliations",
+    operation_id = "import_reconciliation",
+    params(ReconciliationImportQuery),
+    request_body(
+        content(
+            (ImportReconciliationRequest = "application/json"),
+            (String = "text/csv")
+        )
+    ),
+    responses(
+        (status = 201, description = "Reconciliation created from snapshot", body = ImportReconciliationResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Warehouse not found"),
+        (status = 422, description = "Rows could not be resolved", body = ImportReconciliationResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn import_reconciliation(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    headers: HeaderMap,
+    uri: Uri,
+    body: Bytes,
+) -> Result<(StatusCode, Json<ImportReconciliationResponse>), AppError> {
+    let is_csv = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/csv"));
+
+    let response = if is_csv {
+        let Query(query) = Query::<ReconciliationImportQuery>::try_from_uri(&uri)
+            .map_err(|e| AppError::ValidationError(e.body_text()))?;
+        state
+            .reconciliation_service
+            .import_reconciliation_csv(auth_user.tenant_id, auth_user.user_id, query, &body)
+            .await?
+    } else {
+        let request = serde_json::from_slice::<ImportReconciliationRequest>(&body)
+            .map_err(|e| AppError::ValidationError(format!("Invalid JSON body: {}", e)))?;
+        state
+            .reconciliation_service
+            .import_reconciliation(auth_user.tenant_id, auth_user.user_id, request)
+            .await?
+    };
+
+    let status = if response.errors.is_empty() {
+        StatusCode::CREATED
+    } else {
+        StatusCode::UNPROCESSABLE_ENTITY
+    };
+    Ok((status, Json(response)))
+}
+
 /// POST /api/v1/inventory/reconciliations/{reconciliation_id}/count - Record counts
 ///
 /// Records counted quantities for reconciliation items and updates variance calculations.
@@ -180,6 +288,9 @@ pub async fn create_reconciliation(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Reconciliation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn count_reconciliation(
@@ -255,6 +366,9 @@ pub async fn count_reconciliation(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Reconciliation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn finalize_reconciliation(
@@ -331,6 +445,9 @@ pub async fn finalize_reconciliation(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Reconciliation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn approve_reconciliation(
@@ -360,6 +477,8 @@ pub async fn approve_reconciliation(
 /// * `cycle_type` - Filter by cycle type (optional)
 /// * `page` - Page number (optional, default 1)
 /// * `limit` - Items per page (optional, default 50, max 100)
+/// * `include_total` - Count total matches (optional, default true; when false the
+///   totals are omitted and only `hasNext`/`hasPrev` are reported)
 ///
 /// # Returns
 /// * `200` - List retrieved successfully
@@ -372,9 +491,11 @@ pub async fn approve_reconciliation(
 ///   "reconciliations": [...],
 ///   "pagination": {
 ///     "page": 1,
-///     "limit": 50,
-///     "total": 25,
-///     "total_pages": 1
+///     "pageSize": 50,
+///     "totalItems": 25,
+///     "totalPages": 1,
+///     "hasNext": false,
+///     "hasPrev": false
 ///   }
 /// }
 /// ```
@@ -390,6 +511,9 @@ pub async fn approve_reconciliation(
         (status = 200, description = "List retrieved successfully", body = ReconciliationListResponse),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn list_reconciliations(
@@ -441,6 +565,9 @@ pub async fn list_reconciliations(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Reconciliation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn get_reconciliation(
@@ -492,6 +619,9 @@ pub async fn get_reconciliation(
         (status = 200, description = "Analytics retrieved successfully", body = ReconciliationAnalyticsResponse),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn get_reconciliation_analytics(
@@ -507,6 +637,76 @@ pub async fn get_reconciliation_analytics(
     Ok(Json(response))
 }
 
+/// GET /api/v1/inventory/reconciliations/analytics/trend - Get variance trend
+///
+/// Aggregates completed reconciliations per week or month so managers can see
+/// whether count variance is improving over time.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Query Parameters
+/// * `from` - Start of the range, inclusive (RFC 3339, default: `to` minus the
+///   configured default span)
+/// * `to` - End of the range, exclusive (RFC 3339, default: now)
+/// * `group_by` - `month` (default) or `week`
+/// * `warehouse_id` - Filter by warehouse (optional)
+///
+/// # Returns
+/// * `200` - Trend retrieved successfully
+/// * `400` - `from` after `to`, or range longer than the configured maximum
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+///
+/// # Example Response
+/// ```json
+/// {
+///   "group_by": "month",
+///   "from": "2025-01-01T00:00:00Z",
+///   "to": "2025-04-01T00:00:00Z",
+///   "periods": [
+///     {
+///       "period_start": "2025-01-01T00:00:00Z",
+///       "reconciliation_count": 2,
+///       "counted_items": 40,
+///       "variance_count": 4,
+///       "variance_value": 125.5,
+///       "accuracy_percentage": 90.0
+///     }
+///   ]
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/reconciliations/analytics/trend",
+    tag = "reconciliations",
+    operation_id = "get_reconciliation_variance_trend",
+    params(DateRangeParams, ReconciliationTrendQuery),
+    responses(
+        (status = 200, description = "Trend retrieved successfully", body = ReconciliationTrendResponse),
+        (status = 400, description = "Invalid date range"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_reconciliation_variance_trend(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Query(range): Query<DateRangeParams>,
+    Query(query): Query<ReconciliationTrendQuery>,
+) -> Result<Json<ReconciliationTrendResponse>, AppError> {
+    let range = state.date_range_limits.resolve(range)?;
+    let response = state
+        .reconciliation_service
+        .get_variance_trend(auth_user.tenant_id, range, query)
+        .await?;
+
+    Ok(Json(response))
+}
+
 /// GET /api/v1/inventory/reconciliations/{reconciliation_id}/variance - Get variance analysis
 ///
 /// Returns detailed variance analysis for a specific reconciliation.
@@ -547,6 +747,9 @@ pub async fn get_reconciliation_analytics(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Reconciliation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn get_variance_analysis(
@@ -619,6 +822,9 @@ pub async fn get_variance_analysis(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Reconciliation or product not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn scan_barcode(
@@ -1,13 +1,16 @@
 use axum::{
     extract::{Extension, Path},
     http::StatusCode,
-    Json,
 };
+use inventory_service_core::domains::inventory::dto::transfer_dto::CreateTransferResponse;
 use inventory_service_core::domains::replenishment::{
-    CreateReorderRule, ReplenishmentCheckResult, UpdateReorderRule,
+    draft_transfer_requests, CreateInternalReplenishmentRule, CreateReorderRule,
+    InternalReplenishmentRule, InternalTransferSuggestion, ReplenishmentCheckResult,
+    UpdateReorderRule,
 };
 use serde::Deserialize;
 use shared_auth::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -224,6 +227,147 @@ pub async fn check_product_replenishment(
     Ok(Json(result))
 }
 
+/// Create a warehouse-to-warehouse replenishment rule
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/replenishment/internal-rules",
+    tag = "replenishment",
+    operation_id = "create_internal_replenishment_rule",
+    request_body = CreateInternalReplenishmentRule,
+    responses(
+        (status = 201, body = InternalReplenishmentRule),
+        (status = 400, body = ErrorResponse),
+        (status = 401, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 500, body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_internal_replenishment_rule(
+    Extension(state): Extension<AppState>,
+    auth_user: AuthUser,
+    Json(rule): Json<CreateInternalReplenishmentRule>,
+) -> Result<(StatusCode, Json<InternalReplenishmentRule>), AppError> {
+    let created_rule = state
+        .replenishment_service
+        .create_internal_replenishment_rule(auth_user.tenant_id, rule)
+        .await?;
+    Ok((StatusCode::CREATED, Json(created_rule)))
+}
+
+/// List warehouse-to-warehouse replenishment rules
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/replenishment/internal-rules",
+    tag = "replenishment",
+    operation_id = "list_internal_replenishment_rules",
+    responses(
+        (status = 200, body = Vec<InternalReplenishmentRule>),
+        (status = 401, body = ErrorResponse),
+        (status = 500, body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_internal_replenishment_rules(
+    Extension(state): Extension<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<InternalReplenishmentRule>>, AppError> {
+    let rules = state
+        .replenishment_service
+        .list_internal_replenishment_rules(auth_user.tenant_id)
+        .await?;
+    Ok(Json(rules))
+}
+
+/// Delete a warehouse-to-warehouse replenishment rule
+#[utoipa::path(
+    delete,
+    path = "/api/v1/inventory/replenishment/internal-rules/{rule_id}",
+    tag = "replenishment",
+    operation_id = "delete_internal_replenishment_rule",
+    params(
+        ("rule_id" = Uuid, Path, description = "Internal replenishment rule ID")
+    ),
+    responses(
+        (status = 204, description = "Rule deleted"),
+        (status = 404, body = ErrorResponse),
+        (status = 401, body = ErrorResponse),
+        (status = 500, body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_internal_replenishment_rule(
+    Extension(state): Extension<AppState>,
+    auth_user: AuthUser,
+    Path(rule_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state
+        .replenishment_service
+        .delete_internal_replenishment_rule(auth_user.tenant_id, rule_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Suggest warehouse-to-warehouse transfers for low destinations
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/replenishment/internal-transfers/suggest",
+    tag = "replenishment",
+    operation_id = "suggest_internal_transfers",
+    responses(
+        (status = 200, body = Vec<InternalTransferSuggestion>),
+        (status = 401, body = ErrorResponse),
+        (status = 500, body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn suggest_internal_transfers(
+    Extension(state): Extension<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Vec<InternalTransferSuggestion>>, AppError> {
+    let suggestions = state
+        .replenishment_service
+        .suggest_internal_transfers(auth_user.tenant_id)
+        .await?;
+    Ok(Json(suggestions))
+}
+
+/// Create draft transfers for the current internal replenishment suggestions
+///
+/// One draft `auto_replenishment` transfer is created per source/destination pair,
+/// all in a single transaction.
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/replenishment/internal-transfers/drafts",
+    tag = "replenishment",
+    operation_id = "create_internal_transfer_drafts",
+    responses(
+        (status = 201, body = Vec<CreateTransferResponse>),
+        (status = 401, body = ErrorResponse),
+        (status = 500, body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_internal_transfer_drafts(
+    Extension(state): Extension<AppState>,
+    auth_user: AuthUser,
+) -> Result<(StatusCode, Json<Vec<CreateTransferResponse>>), AppError> {
+    let suggestions = state
+        .replenishment_service
+        .suggest_internal_transfers(auth_user.tenant_id)
+        .await?;
+
+    let created = state
+        .transfer_service
+        .create_transfers(
+            auth_user.tenant_id,
+            auth_user.user_id,
+            draft_transfer_requests(&suggestions),
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
 #[derive(Deserialize, ToSchema)]
 pub struct WarehouseFilterQuery {
     pub warehouse_id: Option<Uuid>,
@@ -244,4 +388,18 @@ pub fn create_replenishment_routes() -> axum::Router {
         )
         .route("/check", axum::routing::post(run_replenishment_check))
         .route("/check/product/{product_id}", axum::routing::post(check_product_replenishment))
+        .route(
+            "/internal-rules",
+            axum::routing::post(create_internal_replenishment_rule)
+                .get(list_internal_replenishment_rules),
+        )
+        .route(
+            "/internal-rules/{rule_id}",
+            axum::routing::delete(delete_internal_replenishment_rule),
+        )
+        .route("/internal-transfers/suggest", axum::routing::post(suggest_internal_transfers))
+        .route(
+            "/internal-transfers/drafts",
+            axum::routing::post(create_internal_transfer_drafts),
+        )
 }
@@ -1,15 +1,31 @@
-use axum::{
-    extract::{Extension, Query},
-    Json,
+use axum::extract::{Extension, Query};
+use chrono::{DateTime, Duration, Utc};
+use inventory_service_core::dto::common::DateRangeParams;
+use inventory_service_core::dto::reports::{
+    build_capacity_report, CapacityReportQuery, CapacityReportResponse, LocationOccupancy,
 };
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 use sqlx::{FromRow, PgPool};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::state::AppState;
+
+/// Relation the stock move reports read from
+///
+/// Moves past the retention window live in `stock_moves_archive`; reports
+/// only scan them when the caller asks for archived data.
+fn stock_moves_source(include_archived: bool) -> &'static str {
+    if include_archived {
+        "stock_moves_with_archive"
+    } else {
+        "stock_moves"
+    }
+}
+
 #[derive(Deserialize, ToSchema)]
 pub struct StockLedgerQuery {
     /// Product ID to filter the ledger (required)
@@ -20,6 +36,9 @@ pub struct StockLedgerQuery {
     pub date_from: Option<DateTime<Utc>>,
     /// End date for filtering (optional)
     pub date_to: Option<DateTime<Utc>>,
+    /// Also read moves moved to the archive by the retention job (default: false)
+    #[serde(default)]
+    pub include_archived: bool,
 }
 
 #[derive(Serialize, ToSchema, FromRow)]
@@ -123,7 +142,8 @@ pub struct DeadStockEntry {
         ("product_id" = Uuid, Query, description = "Product ID to filter the ledger"),
         ("warehouse_id" = Option<Uuid>, Query, description = "Warehouse ID to filter by location"),
         ("date_from" = Option<DateTime<Utc>>, Query, description = "Start date for filtering"),
-        ("date_to" = Option<DateTime<Utc>>, Query, description = "End date for filtering")
+        ("date_to" = Option<DateTime<Utc>>, Query, description = "End date for filtering"),
+        ("include_archived" = Option<bool>, Query, description = "Also read archived stock moves (default: false)")
     ),
     responses(
         (status = 200, description = "Stock ledger report", body = Vec<StockLedgerEntry>),
@@ -140,8 +160,26 @@ pub async fn get_stock_ledger(
 ) -> Result<Json<Vec<StockLedgerEntry>>, AppError> {
     let tenant_id = auth_user.tenant_id;
 
-    // Build the query with conditional WHERE clauses
-    let sql = r#"
+    // When archived rows are not listed, their net quantity opens the running
+    // balance, so archiving never changes a balance
+    let sql = format!(
+        r#"
+        WITH opening AS (
+            SELECT COALESCE(SUM(
+                CASE
+                    WHEN $3::uuid IS NOT NULL AND a.destination_location_id = $3 THEN a.quantity
+                    WHEN $3::uuid IS NOT NULL AND a.source_location_id = $3 THEN -a.quantity
+                    ELSE a.quantity
+                END
+            ), 0)::BIGINT as quantity
+            FROM stock_moves_archive a
+            WHERE NOT $6::BOOLEAN
+            AND a.tenant_id = $1
+            AND a.product_id = $2
+            AND ($3::UUID IS NULL OR a.source_location_id = $3 OR a.destination_location_id = $3)
+            AND ($4::TIMESTAMPTZ IS NULL OR a.move_date >= $4)
+            AND ($5::TIMESTAMPTZ IS NULL OR a.move_date <= $5)
+        )
         SELECT
             sm.move_id,
             sm.move_date,
@@ -150,7 +188,7 @@ pub async fn get_stock_ledger(
             sm.move_reason as description,
             CASE WHEN $3::uuid IS NOT NULL AND sm.destination_location_id = $3 THEN sm.quantity ELSE NULL END as quantity_in,
             CASE WHEN $3::uuid IS NOT NULL AND sm.source_location_id = $3 THEN sm.quantity ELSE NULL END as quantity_out,
-            SUM(
+            opening.quantity + SUM(
                 CASE
                     WHEN $3::uuid IS NOT NULL AND sm.destination_location_id = $3 THEN sm.quantity
                     WHEN $3::uuid IS NOT NULL AND sm.source_location_id = $3 THEN -sm.quantity
@@ -163,21 +201,25 @@ pub async fn get_stock_ledger(
             )::BIGINT as balance,
             sm.unit_cost,
             sm.total_cost
-        FROM stock_moves sm
+        FROM {moves} sm
+        CROSS JOIN opening
         WHERE sm.tenant_id = $1
         AND sm.product_id = $2
         AND ($3::UUID IS NULL OR sm.source_location_id = $3 OR sm.destination_location_id = $3)
         AND ($4::TIMESTAMPTZ IS NULL OR sm.move_date >= $4)
         AND ($5::TIMESTAMPTZ IS NULL OR sm.move_date <= $5)
         ORDER BY sm.move_date, sm.created_at
-    "#;
+    "#,
+        moves = stock_moves_source(query.include_archived)
+    );
 
-    let entries = sqlx::query_as::<_, StockLedgerEntry>(sql)
+    let entries = sqlx::query_as::<_, StockLedgerEntry>(&sql)
         .bind(tenant_id)
         .bind(query.product_id)
         .bind(query.warehouse_id)
         .bind(query.date_from)
         .bind(query.date_to)
+        .bind(query.include_archived)
         .fetch_all(&pool)
         .await
         .map_err(|e| AppError::DatabaseError(format!("Failed to fetch stock ledger: {}", e)))?;
@@ -191,7 +233,8 @@ pub async fn get_stock_ledger(
     tag = "reports",
     operation_id = "get_stock_aging",
     params(
-        ("warehouse_id" = Option<Uuid>, Query, description = "Warehouse ID to filter by location")
+        ("warehouse_id" = Option<Uuid>, Query, description = "Warehouse ID to filter by location"),
+        ("include_archived" = Option<bool>, Query, description = "Also read archived stock moves (default: false)")
     ),
     responses(
         (status = 200, description = "Stock aging report", body = Vec<StockAgingEntry>),
@@ -207,13 +250,14 @@ pub async fn get_stock_aging(
 ) -> Result<Json<Vec<StockAgingEntry>>, AppError> {
     let tenant_id = auth_user.tenant_id;
 
-    let sql = r#"
+    let sql = format!(
+        r#"
         WITH current_stock AS (
             SELECT
                 product_id,
                 source_location_id as warehouse_id,
                 SUM(quantity) as on_hand
-            FROM stock_moves
+            FROM {moves}
             WHERE tenant_id = $1 AND ($2::UUID IS NULL OR source_location_id = $2)
             GROUP BY product_id, source_location_id
             HAVING SUM(quantity) > 0
@@ -223,7 +267,7 @@ pub async fn get_stock_aging(
                 source_location_id as warehouse_id,
                 MAX(move_date) as last_inbound_date,
                 (EXTRACT(EPOCH FROM (NOW() - MAX(move_date))) / 86400) as days
-            FROM stock_moves
+            FROM {moves}
             WHERE tenant_id = $1 AND quantity > 0 AND ($2::UUID IS NULL OR source_location_id = $2)
             GROUP BY product_id, source_location_id
         )
@@ -247,9 +291,11 @@ pub async fn get_stock_aging(
         LEFT JOIN last_inbound li ON cs.product_id = li.product_id AND cs.warehouse_id = li.warehouse_id
         WHERE p.tenant_id = $1 AND w.tenant_id = $1
         ORDER BY p.name, w.name
-    "#;
+    "#,
+        moves = stock_moves_source(query.include_archived)
+    );
 
-    let entries = sqlx::query_as::<_, StockAgingEntry>(sql)
+    let entries = sqlx::query_as::<_, StockAgingEntry>(&sql)
         .bind(tenant_id)
         .bind(query.warehouse_id)
         .fetch_all(&pool)
@@ -263,6 +309,9 @@ pub async fn get_stock_aging(
 pub struct StockAgingQuery {
     /// Warehouse ID to filter by location (optional)
     pub warehouse_id: Option<Uuid>,
+    /// Also read moves moved to the archive by the retention job (default: false)
+    #[serde(default)]
+    pub include_archived: bool,
 }
 
 pub fn create_reports_routes() -> axum::Router {
@@ -272,6 +321,7 @@ pub fn create_reports_routes() -> axum::Router {
         .route("/turnover", axum::routing::get(get_inventory_turnover))
         .route("/low-stock", axum::routing::get(get_low_stock))
         .route("/dead-stock", axum::routing::get(get_dead_stock))
+        .route("/capacity", axum::routing::get(get_capacity_report))
 }
 
 #[utoipa::path(
@@ -280,10 +330,13 @@ pub fn create_reports_routes() -> axum::Router {
     tag = "reports",
     operation_id = "get_inventory_turnover",
     params(
-        ("period" = String, Query, description = "Reporting period in days (default: 90, e.g., '30', '90')")
+        DateRangeParams,
+        ("period" = Option<String>, Query, description = "Reporting period in days ending at `to`, used when `from` is omitted (e.g., '30', '90')"),
+        ("include_archived" = Option<bool>, Query, description = "Also read archived stock moves (default: false)")
     ),
     responses(
         (status = 200, description = "Inventory turnover report", body = Vec<InventoryTurnoverEntry>),
+        (status = 400, description = "Invalid period or date range"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
     ),
@@ -292,37 +345,45 @@ pub fn create_reports_routes() -> axum::Router {
 pub async fn get_inventory_turnover(
     auth_user: AuthUser,
     Extension(pool): Extension<PgPool>,
+    Extension(state): Extension<AppState>,
+    Query(mut range): Query<DateRangeParams>,
     Query(query): Query<InventoryTurnoverQuery>,
 ) -> Result<Json<Vec<InventoryTurnoverEntry>>, AppError> {
     let tenant_id = auth_user.tenant_id;
-    let period_days = query.period.parse::<i32>().map_err(|_| {
-        AppError::ValidationError("period must be an integer number of days".to_string())
-    })?;
 
-    let sql = r#"
-        WITH period_range AS (
-            SELECT (NOW() - ($2::text || ' days')::interval) as start_date
-        ),
-        cogs AS (
+    // `period` predates `from`/`to` and only stands in for a missing `from`
+    if let (None, Some(period)) = (range.from, query.period.as_deref()) {
+        let period_days = period.parse::<u32>().map_err(|_| {
+            AppError::ValidationError("period must be a whole number of days".to_string())
+        })?;
+        let to = range.to.unwrap_or_else(Utc::now);
+        range.from = to.checked_sub_signed(Duration::days(period_days as i64));
+    }
+    let range = state.date_range_limits.resolve(range)?;
+
+    let sql = format!(
+        r#"
+        WITH cogs AS (
             SELECT
                 product_id,
                 SUM(ABS(total_cost)) as total_cogs
-            FROM stock_moves, period_range
+            FROM {moves}
             WHERE tenant_id = $1
               AND quantity < 0
-              AND move_date >= period_range.start_date
+              AND move_date >= $2
+              AND move_date < $3
             GROUP BY product_id
         ),
         start_inventory AS (
             SELECT product_id, SUM(total_cost) as value
-            FROM stock_moves, period_range
-            WHERE tenant_id = $1 AND move_date < period_range.start_date
+            FROM {moves}
+            WHERE tenant_id = $1 AND move_date < $2
             GROUP BY product_id
         ),
         end_inventory AS (
             SELECT product_id, SUM(total_cost) as value
-            FROM stock_moves
-            WHERE tenant_id = $1 AND move_date <= NOW()
+            FROM {moves}
+            WHERE tenant_id = $1 AND move_date < $3
             GROUP BY product_id
         ),
         avg_inventory AS (
@@ -343,17 +404,21 @@ pub async fn get_inventory_turnover(
                 WHEN COALESCE(a.avg_value, 0) <= 0 THEN 0.0
                 ELSE COALESCE(c.total_cogs, 0)::FLOAT / a.avg_value::FLOAT
             END as turnover_ratio,
-            CONCAT($2, ' days') as period
+            CONCAT($4::BIGINT, ' days') as period
         FROM products p
         LEFT JOIN cogs c ON p.product_id = c.product_id
         LEFT JOIN avg_inventory a ON p.product_id = a.product_id
         WHERE p.tenant_id = $1
         ORDER BY turnover_ratio DESC
-    "#;
+    "#,
+        moves = stock_moves_source(query.include_archived)
+    );
 
-    let entries = sqlx::query_as::<_, InventoryTurnoverEntry>(sql)
+    let entries = sqlx::query_as::<_, InventoryTurnoverEntry>(&sql)
         .bind(tenant_id)
-        .bind(period_days)
+        .bind(range.from)
+        .bind(range.to)
+        .bind(range.days())
         .fetch_all(&pool)
         .await
         .map_err(|e| {
@@ -365,13 +430,11 @@ pub async fn get_inventory_turnover(
 
 #[derive(Deserialize, ToSchema)]
 pub struct InventoryTurnoverQuery {
-    /// Reporting period in days (default: 90)
-    #[serde(default = "default_period")]
-    pub period: String,
-}
-
-fn default_period() -> String {
-    "90".to_string()
+    /// Reporting period in days ending at `to`, used when `from` is omitted
+    pub period: Option<String>,
+    /// Also read moves moved to the archive by the retention job (default: false)
+    #[serde(default)]
+    pub include_archived: bool,
 }
 
 #[utoipa::path(
@@ -380,7 +443,8 @@ fn default_period() -> String {
     tag = "reports",
     operation_id = "get_low_stock",
     params(
-        ("warehouse_id" = Option<Uuid>, Query, description = "Warehouse ID to filter by location")
+        ("warehouse_id" = Option<Uuid>, Query, description = "Warehouse ID to filter by location"),
+        ("include_archived" = Option<bool>, Query, description = "Also read archived stock moves (default: false)")
     ),
     responses(
         (status = 200, description = "Low stock report", body = Vec<LowStockEntry>),
@@ -396,7 +460,8 @@ pub async fn get_low_stock(
 ) -> Result<Json<Vec<LowStockEntry>>, AppError> {
     let tenant_id = auth_user.tenant_id;
 
-    let sql = r#"
+    let sql = format!(
+        r#"
         SELECT
             p.product_id,
             p.name as product_name,
@@ -407,7 +472,7 @@ pub async fn get_low_stock(
         FROM products p
         INNER JOIN reorder_rules rr ON p.product_id = rr.product_id AND p.tenant_id = rr.tenant_id
         CROSS JOIN warehouses w
-        LEFT JOIN stock_moves sm ON p.product_id = sm.product_id
+        LEFT JOIN {moves} sm ON p.product_id = sm.product_id
             AND w.warehouse_id = sm.source_location_id
             AND sm.tenant_id = $1
         WHERE p.tenant_id = $1
@@ -418,9 +483,11 @@ pub async fn get_low_stock(
         GROUP BY p.product_id, p.name, rr.reorder_point, rr.safety_stock, w.warehouse_id, w.name
         HAVING COALESCE(SUM(sm.quantity), 0) < (rr.reorder_point + rr.safety_stock)
         ORDER BY ((rr.reorder_point + rr.safety_stock) - COALESCE(SUM(sm.quantity), 0)) DESC
-    "#;
+    "#,
+        moves = stock_moves_source(query.include_archived)
+    );
 
-    let entries = sqlx::query_as::<_, LowStockEntry>(sql)
+    let entries = sqlx::query_as::<_, LowStockEntry>(&sql)
         .bind(tenant_id)
         .bind(query.warehouse_id)
         .fetch_all(&pool)
@@ -434,6 +501,9 @@ pub async fn get_low_stock(
 pub struct LowStockQuery {
     /// Warehouse ID to filter by location (optional)
     pub warehouse_id: Option<Uuid>,
+    /// Also read moves moved to the archive by the retention job (default: false)
+    #[serde(default)]
+    pub include_archived: bool,
 }
 
 #[utoipa::path(
@@ -443,7 +513,8 @@ pub struct LowStockQuery {
     operation_id = "get_dead_stock",
     params(
         ("days_threshold" = Option<i32>, Query, description = "Days threshold for dead stock (default: 90)"),
-        ("warehouse_id" = Option<Uuid>, Query, description = "Warehouse ID to filter by location")
+        ("warehouse_id" = Option<Uuid>, Query, description = "Warehouse ID to filter by location"),
+        ("include_archived" = Option<bool>, Query, description = "Also read archived stock moves (default: false)")
     ),
     responses(
         (status = 200, description = "Dead stock report", body = Vec<DeadStockEntry>),
@@ -460,13 +531,14 @@ pub async fn get_dead_stock(
     let tenant_id = auth_user.tenant_id;
     let days_threshold = query.days_threshold.unwrap_or(90);
 
-    let sql = r#"
+    let sql = format!(
+        r#"
         WITH stock_by_warehouse AS (
             SELECT
                 product_id,
                 source_location_id as warehouse_id,
                 SUM(quantity) as stock_qty
-            FROM stock_moves
+            FROM {moves}
             WHERE tenant_id = $1
               AND ($3::UUID IS NULL OR source_location_id = $3)
             GROUP BY product_id, source_location_id
@@ -477,7 +549,7 @@ pub async fn get_dead_stock(
                 product_id,
                 source_location_id as warehouse_id,
                 MAX(move_date) as last_date
-            FROM stock_moves
+            FROM {moves}
             WHERE tenant_id = $1 AND quantity < 0 AND ($3::UUID IS NULL OR source_location_id = $3)
             GROUP BY product_id, source_location_id
         )
@@ -497,9 +569,11 @@ pub async fn get_dead_stock(
           AND w.tenant_id = $1
           AND (lo.last_date IS NULL OR lo.last_date < NOW() - INTERVAL '1 day' * $2)
         ORDER BY days_since_last_outbound DESC NULLS LAST
-    "#;
+    "#,
+        moves = stock_moves_source(query.include_archived)
+    );
 
-    let entries = sqlx::query_as::<_, DeadStockEntry>(sql)
+    let entries = sqlx::query_as::<_, DeadStockEntry>(&sql)
         .bind(tenant_id)
         .bind(days_threshold)
         .bind(query.warehouse_id)
@@ -516,4 +590,125 @@ pub struct DeadStockQuery {
     pub days_threshold: Option<i32>,
     /// Warehouse ID to filter by location (optional)
     pub warehouse_id: Option<Uuid>,
+    /// Also read moves moved to the archive by the retention job (default: false)
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+#[derive(FromRow)]
+struct LocationOccupancyRow {
+    warehouse_id: Uuid,
+    warehouse_name: String,
+    zone_id: Option<Uuid>,
+    zone_name: Option<String>,
+    location_id: Uuid,
+    location_code: String,
+    capacity: Option<i64>,
+    occupancy: i64,
+}
+
+/// Warehouse capacity utilization per location, rolled up to zone and warehouse
+///
+/// With `basis=quantity` occupancy is on-hand units (available + reserved)
+/// against the location's `capacity`. With `basis=volume` it is product volume
+/// in cm³ (from `dimensions` in mm) against length × width × height of the
+/// location; products without dimensions do not add volume.
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/reports/capacity",
+    tag = "reports",
+    operation_id = "get_capacity_report",
+    params(CapacityReportQuery),
+    responses(
+        (status = 200, description = "Capacity utilization report", body = CapacityReportResponse),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_capacity_report(
+    auth_user: AuthUser,
+    Extension(pool): Extension<PgPool>,
+    Query(query): Query<CapacityReportQuery>,
+) -> Result<Json<CapacityReportResponse>, AppError> {
+    let tenant_id = auth_user.tenant_id;
+
+    let sql = r#"
+        WITH occupancy AS (
+            SELECT
+                il.location_id,
+                SUM(
+                    CASE
+                        WHEN $4 = 'volume' THEN
+                            (il.available_quantity + il.reserved_quantity)
+                            * COALESCE(
+                                CASE
+                                    WHEN jsonb_typeof(p.dimensions->'length_mm') = 'number'
+                                     AND jsonb_typeof(p.dimensions->'width_mm') = 'number'
+                                     AND jsonb_typeof(p.dimensions->'height_mm') = 'number'
+                                    THEN (p.dimensions->>'length_mm')::NUMERIC
+                                       * (p.dimensions->>'width_mm')::NUMERIC
+                                       * (p.dimensions->>'height_mm')::NUMERIC / 1000
+                                END,
+                                0
+                            )
+                        ELSE il.available_quantity + il.reserved_quantity
+                    END
+                ) as occupancy
+            FROM inventory_levels il
+            JOIN products p ON il.product_id = p.product_id AND p.tenant_id = $1
+            WHERE il.tenant_id = $1
+              AND il.deleted_at IS NULL
+              AND il.location_id IS NOT NULL
+            GROUP BY il.location_id
+        )
+        SELECT
+            w.warehouse_id,
+            w.warehouse_name,
+            wl.zone_id,
+            wz.zone_name,
+            wl.location_id,
+            wl.location_code,
+            CASE
+                WHEN $4 = 'volume' THEN wl.length_cm::BIGINT * wl.width_cm * wl.height_cm
+                ELSE wl.capacity
+            END as capacity,
+            COALESCE(ROUND(o.occupancy), 0)::BIGINT as occupancy
+        FROM warehouse_locations wl
+        JOIN warehouses w ON wl.warehouse_id = w.warehouse_id AND w.tenant_id = $1
+        LEFT JOIN warehouse_zones wz ON wl.zone_id = wz.zone_id AND wz.tenant_id = $1
+        LEFT JOIN occupancy o ON wl.location_id = o.location_id
+        WHERE wl.tenant_id = $1
+          AND wl.deleted_at IS NULL
+          AND wl.is_active = true
+          AND w.deleted_at IS NULL
+          AND ($2::UUID IS NULL OR wl.warehouse_id = $2)
+          AND ($3::UUID IS NULL OR wl.zone_id = $3)
+    "#;
+
+    let rows = sqlx::query_as::<_, LocationOccupancyRow>(sql)
+        .bind(tenant_id)
+        .bind(query.warehouse_id)
+        .bind(query.zone_id)
+        .bind(query.basis.as_str())
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to fetch capacity report: {}", e)))?;
+
+    let locations = rows
+        .into_iter()
+        .map(|row| LocationOccupancy {
+            warehouse_id: row.warehouse_id,
+            warehouse_name: row.warehouse_name,
+            zone_id: row.zone_id,
+            zone_name: row.zone_name,
+            location_id: row.location_id,
+            location_code: row.location_code,
+            capacity: row.capacity,
+            occupancy: row.occupancy,
+        })
+        .collect();
+
+    Ok(Json(build_capacity_report(query.basis, locations)))
 }
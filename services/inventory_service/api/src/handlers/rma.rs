@@ -5,7 +5,7 @@
 use axum::{
     extract::{Extension, Path},
     routing::post,
-    Json, Router,
+    Router,
 };
 use uuid::Uuid;
 
@@ -14,6 +14,7 @@ use inventory_service_core::dto::rma::{
     ReceiveRmaResponse,
 };
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -30,6 +31,9 @@ use crate::state::AppState;
         (status = 400, description = "Invalid request"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn create_rma(
@@ -61,6 +65,9 @@ pub async fn create_rma(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "RMA not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn approve_rma(
@@ -101,6 +108,9 @@ pub fn create_rma_routes() -> Router {
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "RMA not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn receive_rma(
@@ -7,9 +7,10 @@ use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
     routing::{get, post},
-    Json, Router,
+    Router,
 };
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 use uuid::Uuid;
 
@@ -4,7 +4,6 @@
 
 use axum::{
     extract::{Extension, Query},
-    response::Json,
     routing::get,
     Router,
 };
@@ -16,6 +15,7 @@ use inventory_service_core::domains::inventory::dto::search_dto::{
 
 use crate::state::AppState;
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 /// Error response for OpenAPI documentation
@@ -179,6 +179,7 @@ pub struct ProductSearchQuery {
     pub sort_order: Option<String>,
     pub page: Option<u32>,
     pub limit: Option<u32>,
+    pub include_total: Option<bool>,
 }
 
 impl ProductSearchQuery {
@@ -240,8 +241,11 @@ impl ProductSearchQuery {
             sellable_only: self.sellable_only,
             sort_by,
             sort_order,
+            order_by: Vec::new(),
+            attributes: Default::default(),
             page: self.page,
             limit: self.limit,
+            include_total: self.include_total,
         };
 
         // Validate price range
@@ -0,0 +1,110 @@
+//! Inventory snapshot HTTP handlers
+//!
+//! Admin endpoints for snapshotting a tenant's inventory levels and restoring
+//! them after a bulk operation went wrong.
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    routing::post,
+    Router,
+};
+use uuid::Uuid;
+
+use inventory_service_core::dto::inventory_snapshot::{
+    InventorySnapshotResponse, SnapshotRestoreResponse,
+};
+use shared_auth::extractors::RequireAdmin;
+use shared_error::extract::Json;
+use shared_error::AppError;
+
+use crate::state::AppState;
+
+/// Create the inventory snapshot routes
+pub fn create_snapshot_routes() -> Router {
+    Router::new()
+        .route("/", post(create_snapshot))
+        .route("/{snapshot_id}/restore", post(restore_snapshot))
+}
+
+/// POST /api/v1/inventory/snapshots - Snapshot inventory levels
+///
+/// Stores the available and reserved quantity of every inventory level of
+/// the caller's tenant, to be restored later if needed.
+///
+/// # Authentication
+/// Requires admin user authentication
+///
+/// # Returns
+/// * `201` - Snapshot created
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/snapshots",
+    tag = "snapshots",
+    operation_id = "create_inventory_snapshot",
+    responses(
+        (status = 201, description = "Snapshot created", body = InventorySnapshotResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_snapshot(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+) -> Result<(StatusCode, Json<InventorySnapshotResponse>), AppError> {
+    let snapshot = state
+        .inventory_snapshot_service
+        .snapshot(auth_user.tenant_id, auth_user.user_id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(snapshot)))
+}
+
+/// POST /api/v1/inventory/snapshots/{snapshot_id}/restore - Restore a snapshot
+///
+/// Returns every inventory level to its snapshot value. Differences are
+/// applied as `adjustment` stock moves with reason `snapshot_restore`, so
+/// the stock move history keeps both the change and its rollback. Only
+/// available quantity is restored; reservations are left in place.
+///
+/// # Authentication
+/// Requires admin user authentication
+///
+/// # Returns
+/// * `200` - Snapshot restored
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+/// * `404` - Snapshot not found
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/snapshots/{snapshot_id}/restore",
+    tag = "snapshots",
+    operation_id = "restore_inventory_snapshot",
+    params(
+        ("snapshot_id" = Uuid, Path, description = "Snapshot ID")
+    ),
+    responses(
+        (status = 200, description = "Snapshot restored", body = SnapshotRestoreResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 404, description = "Snapshot not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_snapshot(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Path(snapshot_id): Path<Uuid>,
+) -> Result<Json<SnapshotRestoreResponse>, AppError> {
+    let response = state
+        .inventory_snapshot_service
+        .restore(auth_user.tenant_id, snapshot_id, auth_user.user_id)
+        .await?;
+    Ok(Json(response))
+}
@@ -4,7 +4,6 @@
 
 use axum::{
     extract::{Extension, Query},
-    response::Json,
     routing::get,
     Router,
 };
@@ -12,16 +11,21 @@ use axum::{
 use validator::Validate;
 
 // Import DTOs for requests/responses
-use inventory_service_core::dto::stock_levels::{StockLevelListQuery, StockLevelListResponse};
+use inventory_service_core::dto::stock_levels::{
+    LevelHistoryQuery, LevelHistoryResponse, StockLevelListQuery, StockLevelListResponse,
+};
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
 
 /// Create the stock levels routes
 pub fn create_stock_levels_routes() -> Router {
-    Router::new().route("/", get(list_stock_levels))
+    Router::new()
+        .route("/", get(list_stock_levels))
+        .route("/history", get(get_level_history))
 }
 
 /// GET /api/v1/inventory/stock-levels - List stock levels with pagination and filtering
@@ -81,3 +85,52 @@ pub async fn list_stock_levels(
 
     Ok(Json(response))
 }
+
+/// GET /api/v1/inventory/stock-levels/history - Level history for a location
+///
+/// Returns every quantity change for a product at a location, oldest first,
+/// with the running balance after each change. Changes are recorded with
+/// every stock move (receipts, transfers, adjustments, counts, reversals).
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Query Parameters
+/// * `location_id` - Location to read (required)
+/// * `product_id` - Product to read (required)
+/// * `from` - Only changes recorded at or after this time (optional)
+/// * `to` - Only changes recorded before this time (optional)
+///
+/// # Returns
+/// * `200` - Ordered level changes with running balances
+/// * `400` - Invalid query parameters
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/stock-levels/history",
+    tag = "stock-levels",
+    operation_id = "get_level_history",
+    params(LevelHistoryQuery),
+    responses(
+        (status = 200, description = "Level changes with running balances", body = LevelHistoryResponse),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_level_history(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Query(query): Query<LevelHistoryQuery>,
+) -> Result<Json<LevelHistoryResponse>, AppError> {
+    let response = state
+        .stock_levels_service
+        .level_history(auth_user.tenant_id, query)
+        .await?;
+
+    Ok(Json(response))
+}
@@ -1,7 +1,6 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
     routing::{get, post},
     Router,
 };
@@ -14,6 +13,7 @@ use inventory_service_core::dto::stock_take::{
 };
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -78,6 +78,9 @@ pub fn create_stock_take_routes() -> Router {
         (status = 400, description = "Invalid request"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn create_stock_take(
@@ -160,6 +163,9 @@ pub async fn create_stock_take(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Stock take not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn count_stock_take(
@@ -237,6 +243,9 @@ pub async fn count_stock_take(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Stock take not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn finalize_stock_take(
@@ -299,6 +308,9 @@ pub async fn finalize_stock_take(
         (status = 200, description = "List retrieved successfully", body = StockTakeListResponse),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn list_stock_takes(
@@ -350,6 +362,9 @@ pub async fn list_stock_takes(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Stock take not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn get_stock_take(
@@ -0,0 +1,129 @@
+//! Tenant administration HTTP handlers
+//!
+//! Admin endpoints for seeding a tenant's default entities and exporting
+//! its data.
+
+use axum::{
+    extract::{Extension, Path},
+    routing::post,
+    Router,
+};
+use uuid::Uuid;
+
+use inventory_service_core::dto::tenant_export::TenantExportResponse;
+use inventory_service_core::dto::tenant_provisioning::TenantSeedResponse;
+use shared_auth::extractors::RequireAdmin;
+use shared_error::extract::Json;
+use shared_error::AppError;
+
+use crate::state::AppState;
+
+/// Create the tenant administration routes
+pub fn create_tenant_routes() -> Router {
+    Router::new()
+        .route("/{tenant_id}/seed", post(seed_tenant_defaults))
+        .route("/{tenant_id}/export", post(export_tenant_data))
+}
+
+/// POST /api/v1/inventory/tenants/{tenant_id}/seed - Seed tenant defaults
+///
+/// Creates the configured default warehouse, root category and tenant-wide
+/// removal strategy in one transaction. Entities that already exist are left
+/// untouched, so seeding again is safe.
+///
+/// # Authentication
+/// Requires admin user authentication. Admins can only seed their own tenant.
+///
+/// # Returns
+/// * `200` - Seeded entities, flagged as created or already present
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required, or another tenant's id
+/// * `404` - Tenant not found
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/tenants/{tenant_id}/seed",
+    tag = "tenants",
+    operation_id = "seed_tenant_defaults",
+    params(
+        ("tenant_id" = Uuid, Path, description = "Tenant ID")
+    ),
+    responses(
+        (status = 200, description = "Tenant defaults seeded", body = TenantSeedResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 404, description = "Tenant not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn seed_tenant_defaults(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<Json<TenantSeedResponse>, AppError> {
+    if tenant_id != auth_user.tenant_id {
+        return Err(AppError::Forbidden("Cannot seed another tenant".to_string()));
+    }
+
+    let response = state
+        .tenant_provisioning_service
+        .seed_defaults(tenant_id, auth_user.user_id)
+        .await?;
+    Ok(Json(response))
+}
+
+/// POST /api/v1/inventory/tenants/{tenant_id}/export - Export tenant data
+///
+/// Writes the tenant's categories, products, warehouses, inventory levels,
+/// stock moves and valuations (including soft-deleted rows) to an NDJSON
+/// file, one `{"entity": ..., "data": ...}` object per line, and returns a
+/// presigned URL to download it. Intended for data subject requests and
+/// migrations off the platform.
+///
+/// # Authentication
+/// Requires admin user authentication. Admins can only export their own tenant.
+///
+/// # Rate Limiting
+/// A tenant can be exported once per configured cooldown (default: one hour).
+///
+/// # Returns
+/// * `200` - Download URL, its expiry, and row counts per entity
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required, or another tenant's id
+/// * `404` - Tenant not found
+/// * `429` - Tenant was exported within the cooldown
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/tenants/{tenant_id}/export",
+    tag = "tenants",
+    operation_id = "export_tenant_data",
+    params(
+        ("tenant_id" = Uuid, Path, description = "Tenant ID")
+    ),
+    responses(
+        (status = 200, description = "Tenant data exported", body = TenantExportResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 404, description = "Tenant not found"),
+        (status = 429, description = "Tenant exported too recently")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn export_tenant_data(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<Json<TenantExportResponse>, AppError> {
+    if tenant_id != auth_user.tenant_id {
+        return Err(AppError::Forbidden("Cannot export another tenant".to_string()));
+    }
+
+    let response = state
+        .tenant_export_service
+        .export_tenant(tenant_id, auth_user.user_id)
+        .await?;
+    Ok(Json(response))
+}
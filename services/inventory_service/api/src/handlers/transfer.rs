@@ -1,19 +1,21 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    response::Json,
-    routing::{get, post},
+    routing::{get, post, put},
     Router,
 };
 use uuid::Uuid;
 
 use inventory_service_core::domains::inventory::dto::transfer_dto::{
     CancelTransferRequest, CancelTransferResponse, ConfirmTransferRequest, ConfirmTransferResponse,
-    CreateTransferRequest, CreateTransferResponse, ListTransfersParams, ListTransfersResponse,
+    CreateTransferRequest, CreateTransferResponse, ListInTransitTransfersParams,
+    ListInTransitTransfersResponse, ListTransfersParams, ListTransfersResponse,
     ReceiveTransferRequest, ReceiveTransferResponse, TransferResponse,
+    UpdateTransferTrackingRequest,
 };
 
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 use crate::state::AppState;
@@ -22,10 +24,12 @@ use crate::state::AppState;
 pub fn create_transfer_routes() -> Router {
     Router::new()
         .route("/", get(list_transfers).post(create_transfer))
+        .route("/in-transit", get(list_in_transit_transfers))
         .route("/{transfer_id}", get(get_transfer))
         .route("/{transfer_id}/confirm", post(confirm_transfer))
         .route("/{transfer_id}/receive", post(receive_transfer))
         .route("/{transfer_id}/cancel", post(cancel_transfer))
+        .route("/{transfer_id}/tracking", put(update_transfer_tracking))
 }
 
 /// POST /api/v1/inventory/transfers - Create a new stock transfer
@@ -94,6 +98,9 @@ pub fn create_transfer_routes() -> Router {
         (status = 400, description = "Invalid request or business rule violation"),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn create_transfer(
@@ -163,6 +170,9 @@ pub async fn create_transfer(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Transfer not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn confirm_transfer(
@@ -235,6 +245,9 @@ pub async fn confirm_transfer(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Transfer not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn receive_transfer(
@@ -296,6 +309,9 @@ pub async fn receive_transfer(
         (status = 200, description = "List of transfers", body = ListTransfersResponse),
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn list_transfers(
@@ -347,6 +363,9 @@ pub async fn list_transfers(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Transfer not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn get_transfer(
@@ -413,6 +432,9 @@ pub async fn get_transfer(
         (status = 401, description = "Authentication required"),
         (status = 403, description = "Insufficient permissions"),
         (status = 404, description = "Transfer not found")
+    ),
+    security(
+        ("bearer_auth" = [])
     )
 )]
 pub async fn cancel_transfer(
@@ -428,3 +450,110 @@ pub async fn cancel_transfer(
 
     Ok(Json(response))
 }
+
+/// PUT /api/v1/inventory/transfers/{transfer_id}/tracking - Set transfer ETA and tracking
+///
+/// Sets or updates the expected arrival, carrier and tracking number of an
+/// open transfer. Omitted fields keep their current value.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Path Parameters
+/// * `transfer_id` - UUID of the transfer
+///
+/// # Request Body
+/// ```json
+/// {
+///   "expectedArrival": "2026-02-10T14:00:00Z",
+///   "carrier": "Internal fleet",
+///   "trackingNumber": "TRK-00042"
+/// }
+/// ```
+///
+/// # Returns
+/// * `200` - Updated transfer with items
+/// * `400` - Invalid date, arrival not after ship date, or transfer already closed
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+/// * `404` - Transfer not found
+#[utoipa::path(
+    put,
+    path = "/api/v1/inventory/transfers/{transfer_id}/tracking",
+    tag = "transfers",
+    operation_id = "update_transfer_tracking",
+    params(
+        ("transfer_id" = Uuid, Path, description = "Transfer ID")
+    ),
+    request_body = UpdateTransferTrackingRequest,
+    responses(
+        (status = 200, description = "Transfer tracking updated", body = TransferResponse),
+        (status = 400, description = "Invalid request or business rule violation"),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Transfer not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_transfer_tracking(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Path(transfer_id): Path<Uuid>,
+    Json(request): Json<UpdateTransferTrackingRequest>,
+) -> Result<Json<TransferResponse>, AppError> {
+    let response = state
+        .transfer_service
+        .update_tracking(auth_user.tenant_id, transfer_id, auth_user.user_id, request)
+        .await?;
+
+    Ok(Json(response))
+}
+
+/// GET /api/v1/inventory/transfers/in-transit - List in-transit transfers
+///
+/// Lists open transfers (confirmed through shipped) sorted by expected
+/// arrival, soonest first. Transfers without an ETA are listed last.
+///
+/// # Authentication
+/// Requires authenticated user with appropriate tenant access
+///
+/// # Query Parameters
+/// * `destination_warehouse_id` - Filter by receiving warehouse (optional)
+/// * `limit` - Maximum number of transfers (default: 100, max: 500)
+///
+/// # Returns
+/// * `200` - In-transit transfers
+/// * `401` - Authentication required
+/// * `403` - Insufficient permissions
+#[utoipa::path(
+    get,
+    path = "/api/v1/inventory/transfers/in-transit",
+    tag = "transfers",
+    operation_id = "list_in_transit_transfers",
+    params(
+        ("destination_warehouse_id" = Option<Uuid>, Query, description = "Filter by destination warehouse"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of transfers")
+    ),
+    responses(
+        (status = 200, description = "In-transit transfers", body = ListInTransitTransfersResponse),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Insufficient permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_in_transit_transfers(
+    auth_user: AuthUser,
+    Extension(state): Extension<AppState>,
+    Query(params): Query<ListInTransitTransfersParams>,
+) -> Result<Json<ListInTransitTransfersResponse>, AppError> {
+    let response = state
+        .transfer_service
+        .list_in_transit(auth_user.tenant_id, params)
+        .await?;
+
+    Ok(Json(response))
+}
@@ -4,7 +4,6 @@
 
 use axum::{
     extract::{Extension, Path, Query},
-    response::Json,
     routing::{get, post, put},
     Router,
 };
@@ -22,7 +21,8 @@ use inventory_service_core::domains::inventory::dto::valuation_dto::{
 use inventory_service_core::domains::inventory::valuation::{ValuationMethod, ValuationScopeType};
 
 use crate::state::AppState;
-use shared_auth::extractors::AuthUser;
+use shared_auth::extractors::{AuthUser, RequireAdmin};
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 /// Error response for OpenAPI documentation
@@ -44,6 +44,7 @@ pub fn create_valuation_routes() -> Router {
         .route("/{product_id}/history", get(get_valuation_history))
         .route("/{product_id}/adjust", post(adjust_cost))
         .route("/{product_id}/revalue", post(revalue_inventory))
+        .route("/{product_id}/rebuild-layers", post(rebuild_layers))
         // Valuation settings endpoints
         .route("/settings", get(list_valuation_settings))
         .route("/settings/tenant", get(get_tenant_settings).put(set_tenant_method))
@@ -56,7 +57,7 @@ pub fn create_valuation_routes() -> Router {
 ///
 /// Returns the current inventory valuation for a specific product.
 /// The valuation includes current quantity, value, and cost based on the
-/// product's valuation method (FIFO, AVCO, or Standard).
+/// product's valuation method (FIFO, LIFO, AVCO, or Standard).
 ///
 /// # Authentication
 /// Requires authenticated user with appropriate tenant access
@@ -105,7 +106,9 @@ pub async fn get_valuation(
 /// PUT /api/v1/inventory/valuation/{product_id}/method - Set valuation method for a product
 ///
 /// Changes the valuation method for a product. This affects how inventory
-/// costs are calculated and tracked.
+/// costs are calculated and tracked. Existing stock is re-layered: FIFO cost
+/// layers collapse into an average, and an average becomes a single FIFO
+/// layer, preserving quantity and total value.
 ///
 /// # Authentication
 /// Requires authenticated user with appropriate tenant access
@@ -116,13 +119,13 @@ pub async fn get_valuation(
 /// # Request Body
 /// ```json
 /// {
-///   "valuation_method": "fifo" | "avco" | "standard"
+///   "valuation_method": "fifo" | "lifo" | "avco" | "standard"
 /// }
 /// ```
 ///
 /// # Returns
 /// * `200` - Updated valuation data
-/// * `400` - Invalid valuation method
+/// * `400` - Invalid valuation method or product in negative stock
 /// * `404` - Product not found
 /// * `401` - Authentication required
 /// * `403` - Insufficient permissions
@@ -137,7 +140,7 @@ pub async fn get_valuation(
     request_body = SetValuationMethodPayload,
     responses(
         (status = 200, description = "Updated valuation data", body = ValuationDto),
-        (status = 400, description = "Invalid valuation method", body = ErrorResponse),
+        (status = 400, description = "Invalid valuation method or product in negative stock", body = ErrorResponse),
         (status = 404, description = "Product not found", body = ErrorResponse),
         (status = 401, description = "Authentication required", body = ErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ErrorResponse)
@@ -468,6 +471,56 @@ pub async fn revalue_inventory(
     Ok(Json(valuation))
 }
 
+/// POST /api/v1/inventory/valuation/{product_id}/rebuild-layers - Rebuild FIFO cost layers
+///
+/// Replays the product's stock moves with their unit costs to reconstruct the
+/// FIFO cost layers and valuation totals, for example after the layers were
+/// corrupted. The previous state is kept in the valuation history.
+///
+/// # Authentication
+/// Requires an administrator
+///
+/// # Path Parameters
+/// * `product_id` - Product UUID
+///
+/// # Returns
+/// * `200` - Rebuilt valuation data
+/// * `404` - Product or valuation not found
+/// * `400` - Product is not FIFO or its move history cannot be replayed
+/// * `401` - Authentication required
+/// * `403` - Admin privileges required
+#[utoipa::path(
+    post,
+    path = "/api/v1/inventory/valuation/{product_id}/rebuild-layers",
+    tag = "valuation",
+    operation_id = "rebuild_valuation_layers",
+    params(
+        ("product_id" = Uuid, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Rebuilt valuation data", body = ValuationDto),
+        (status = 404, description = "Product or valuation not found", body = ErrorResponse),
+        (status = 400, description = "Product is not FIFO or its move history cannot be replayed", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = ErrorResponse),
+        (status = 403, description = "Admin privileges required", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn rebuild_layers(
+    RequireAdmin(auth_user): RequireAdmin,
+    Extension(state): Extension<AppState>,
+    Path(product_id): Path<Uuid>,
+) -> Result<Json<ValuationDto>, AppError> {
+    let valuation = state
+        .valuation_service
+        .rebuild_layers(auth_user.tenant_id, product_id, Some(auth_user.user_id))
+        .await?;
+
+    Ok(Json(valuation))
+}
+
 // ============================================
 // Valuation Settings Handlers
 // ============================================
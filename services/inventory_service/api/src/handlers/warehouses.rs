@@ -2,7 +2,7 @@ use axum::{
     extract::{Extension, Path},
     http::StatusCode,
     routing::{get, put},
-    Json, Router,
+    Router,
 };
 
 use uuid::Uuid;
@@ -17,6 +17,7 @@ use inventory_service_core::domains::inventory::dto::warehouse_dto::{
 use inventory_service_core::domains::inventory::BaseEntity;
 
 use shared_auth::extractors::{AuthUser, RequirePermission};
+use shared_error::extract::Json;
 use shared_error::AppError;
 
 /// Error response for OpenAPI documentation
@@ -713,6 +714,9 @@ pub async fn delete_location(
 }
 
 /// Create warehouse routes
+///
+/// Requests under `/{warehouse_id}` are authorized against that warehouse, so
+/// warehouse-scoped Casbin policies only grant access to their own warehouse.
 pub fn create_warehouse_routes() -> Router {
     Router::new()
         .route("/", get(get_warehouses).post(create_warehouse))
@@ -0,0 +1,193 @@
+//! In-process fan-out of inventory level changes
+//!
+//! The outbox worker publishes level-changing events to NATS under
+//! `inventory.events.{tenant_id}.{event_type}`. Each service instance keeps a
+//! single NATS subscription and re-broadcasts matching events on a bounded
+//! channel, so any number of SSE clients can follow them without each holding
+//! its own NATS subscription. Subscribing through NATS (rather than the local
+//! outbox worker) means every instance sees events claimed by any worker.
+
+use async_nats::Client;
+use futures::StreamExt;
+use serde_json::Value;
+use tokio::sync::broadcast;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use inventory_service_core::events::event_types;
+use shared_events::EventEnvelope;
+
+/// Event types that change inventory levels
+pub const LEVEL_CHANGE_EVENT_TYPES: &[&str] = &[
+    event_types::INVENTORY_UPDATED,
+    event_types::GOODS_RECEIPT_VALIDATED,
+    event_types::DELIVERY_ORDER_SHIPPED,
+    event_types::STOCK_ADJUSTMENT,
+];
+
+/// Payload schema version the level stream understands
+pub const LEVEL_CHANGE_SCHEMA_VERSION: u32 = 1;
+
+/// Default number of buffered changes before slow subscribers start lagging
+pub const DEFAULT_LEVEL_EVENT_CAPACITY: usize = 1024;
+
+/// A level-changing event for one tenant
+#[derive(Debug, Clone)]
+pub struct LevelChangeEvent {
+    pub tenant_id: Uuid,
+    pub event_type: String,
+    pub data: Value,
+    /// Correlation id of the request that caused the change
+    pub correlation_id: Option<String>,
+}
+
+/// Broadcasts level changes to every connected subscriber
+///
+/// Cheap to clone; all clones share the same channel. The channel is bounded:
+/// a subscriber that falls more than `capacity` events behind skips the oldest
+/// ones and is told how many it missed, so one slow client never holds back
+/// the others or grows memory without bound.
+#[derive(Debug, Clone)]
+pub struct LevelChangeBroadcaster {
+    sender: broadcast::Sender<LevelChangeEvent>,
+}
+
+impl LevelChangeBroadcaster {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Publish a change to current subscribers
+    ///
+    /// Returns the number of subscribers that received it. Changes published
+    /// while nobody is subscribed are dropped.
+    pub fn publish(&self, event: LevelChangeEvent) -> usize {
+        self.sender.send(event).unwrap_or(0)
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<LevelChangeEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for LevelChangeBroadcaster {
+    fn default() -> Self {
+        Self::new(DEFAULT_LEVEL_EVENT_CAPACITY)
+    }
+}
+
+/// Parse `{prefix}.{tenant_id}.{event_type}` into a level change, if it is one
+///
+/// The payload is the outbox worker's versioned envelope; envelopes with a
+/// schema version other than [`LEVEL_CHANGE_SCHEMA_VERSION`] are skipped.
+pub fn parse_level_change(
+    subject_prefix: &str,
+    subject: &str,
+    payload: &[u8],
+) -> Option<LevelChangeEvent> {
+    let rest = subject.strip_prefix(subject_prefix)?.strip_prefix('.')?;
+    let (tenant_id, event_type) = rest.split_once('.')?;
+    if !LEVEL_CHANGE_EVENT_TYPES.contains(&event_type) {
+        return None;
+    }
+    let tenant_id = Uuid::parse_str(tenant_id).ok()?;
+    let envelope: EventEnvelope<Value> = match serde_json::from_slice(payload) {
+        Ok(envelope) => envelope,
+        Err(e) => {
+            warn!("Ignoring malformed {} event on {}: {}", event_type, subject, e);
+            return None;
+        },
+    };
+    if envelope.schema_version != LEVEL_CHANGE_SCHEMA_VERSION {
+        warn!(
+            "Skipping {} event on {} with unsupported schema version {}",
+            event_type, subject, envelope.schema_version
+        );
+        return None;
+    }
+
+    Some(LevelChangeEvent {
+        tenant_id,
+        event_type: event_type.to_string(),
+        data: envelope.data,
+        correlation_id: envelope.correlation_id,
+    })
+}
+
+/// Forward level-changing outbox events from NATS to the broadcaster
+///
+/// Runs until the subscription ends.
+pub async fn forward_nats_level_events(
+    client: Client,
+    subject_prefix: String,
+    broadcaster: LevelChangeBroadcaster,
+) -> Result<(), async_nats::SubscribeError> {
+    let mut subscriber = client.subscribe(format!("{}.>", subject_prefix)).await?;
+
+    while let Some(message) = subscriber.next().await {
+        if let Some(event) = parse_level_change(&subject_prefix, &message.subject, &message.payload)
+        {
+            let receivers = broadcaster.publish(event);
+            debug!("Forwarded {} to {} level stream subscribers", message.subject, receivers);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn envelope(event_type: &str, schema_version: u32, data: Value) -> Vec<u8> {
+        let envelope = EventEnvelope::new(event_type, data).with_schema_version(schema_version);
+        serde_json::to_vec(&envelope).unwrap()
+    }
+
+    #[test]
+    fn test_parse_level_change_subject() {
+        let tenant_id = Uuid::now_v7();
+        let subject = format!("inventory.events.{}.inventory.updated", tenant_id);
+        let payload = envelope("inventory.updated", 1, json!({ "quantity_change": 5 }));
+
+        let event = parse_level_change("inventory.events", &subject, &payload)
+            .expect("inventory.updated is a level change");
+        assert_eq!(event.tenant_id, tenant_id);
+        assert_eq!(event.event_type, "inventory.updated");
+        assert_eq!(event.data["quantity_change"], 5);
+        assert_eq!(event.correlation_id, None);
+
+        let payload = serde_json::to_vec(
+            &EventEnvelope::new("inventory.updated", json!({}))
+                .with_correlation_id(Some("req-7".to_string())),
+        )
+        .unwrap();
+        let event = parse_level_change("inventory.events", &subject, &payload).unwrap();
+        assert_eq!(event.correlation_id.as_deref(), Some("req-7"));
+    }
+
+    #[test]
+    fn test_parse_level_change_ignores_other_events() {
+        let tenant_id = Uuid::now_v7();
+        let payload = envelope("inventory.updated", 1, json!({}));
+        let other_type = format!("inventory.events.{}.product.created", tenant_id);
+        assert!(parse_level_change("inventory.events", &other_type, &payload).is_none());
+
+        let bad_tenant = "inventory.events.not-a-uuid.inventory.updated";
+        assert!(parse_level_change("inventory.events", bad_tenant, &payload).is_none());
+
+        let other_prefix = format!("orders.events.{}.inventory.updated", tenant_id);
+        assert!(parse_level_change("inventory.events", &other_prefix, &payload).is_none());
+    }
+
+    #[test]
+    fn test_parse_level_change_skips_unknown_schema_version() {
+        let tenant_id = Uuid::now_v7();
+        let subject = format!("inventory.events.{}.inventory.updated", tenant_id);
+        let payload = envelope("inventory.updated", 2, json!({ "delta": { "value": 5 } }));
+
+        assert!(parse_level_change("inventory.events", &subject, &payload).is_none());
+    }
+}
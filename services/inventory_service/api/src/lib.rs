@@ -7,21 +7,29 @@
 //!
 //! - `handlers/`: Axum HTTP handlers
 //! - `routes/`: Route definitions and middleware
+//! - `grpc`: Read-only gRPC service
 //! - `middleware/`: Custom middleware
 //! - `models/`: API-specific models and conversions
 
+pub mod category_recount_worker;
 pub mod consumers;
+pub mod expiry_scrap_worker;
+pub mod grpc;
 pub mod handlers;
+pub mod level_events;
 pub mod middleware;
 pub mod models;
 pub mod openapi;
+pub mod replenishment_worker;
 pub mod routes;
+pub mod shutdown;
 pub mod state;
+pub mod stock_move_archive_worker;
 pub mod worker;
 
 // Re-export main components for convenience
-pub use routes::create_router;
 pub use routes::StubDeliveryService;
+pub use routes::{create_authz_state, create_router, create_router_with_level_events};
 
 use axum::Router;
 use shared_config::Config;
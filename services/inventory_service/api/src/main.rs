@@ -3,11 +3,18 @@
 //! This is the main entry point for the inventory service.
 //! It sets up the web server and starts the application.
 
-use inventory_service_api::{create_router, worker};
+use inventory_service_api::level_events::{self, LevelChangeBroadcaster};
+use inventory_service_api::shutdown::{self, InFlightRequests};
+use inventory_service_api::{
+    category_recount_worker, create_authz_state, create_router_with_level_events,
+    expiry_scrap_worker, grpc, replenishment_worker, stock_move_archive_worker, worker,
+};
 use shared_config::Config;
 use shared_db::init_pool;
+use std::future::IntoFuture;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
 
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -42,6 +49,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize database connection pool
     let pool = init_pool(&config.database_url, config.max_connections.unwrap_or(10)).await?;
 
+    // Level changes streamed to SSE clients; fed from NATS when it is configured
+    let level_change_broadcaster = LevelChangeBroadcaster::default();
+
+    // Cancelled on Ctrl+C / SIGTERM to stop the servers and the outbox worker
+    let shutdown_token = CancellationToken::new();
+    let in_flight = InFlightRequests::default();
+    let signal_task =
+        tokio::spawn(shutdown::cancel_on_signal(shutdown_token.clone(), in_flight.clone()));
+    let mut outbox_worker = None;
+
     // Initialize event consumers and outbox worker (if NATS is configured)
     if let Some(nats_url) = &config.nats_url {
         let nats_client = match async_nats::connect(nats_url).await {
@@ -66,31 +83,189 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 tracing::info!("NATS event consumers initialized successfully");
             }
 
+            // Forward published level changes to SSE subscribers
+            let worker_config = worker::OutboxWorkerConfig {
+                poll_interval_ms: config.outbox_poll_interval_ms,
+                batch_size: config.outbox_batch_size,
+                max_in_flight: config.outbox_max_in_flight,
+                max_attempts: config.outbox_max_attempts,
+                ..Default::default()
+            };
+            let forward_client = nats_client.clone();
+            let forward_prefix = worker_config.nats_subject_prefix.clone();
+            let forward_broadcaster = level_change_broadcaster.clone();
+            tokio::spawn(async move {
+                if let Err(e) = level_events::forward_nats_level_events(
+                    forward_client,
+                    forward_prefix,
+                    forward_broadcaster,
+                )
+                .await
+                {
+                    tracing::error!("Level change forwarding failed: {}", e);
+                }
+            });
+
+            // Start scheduled replenishment checks (interval 0 disables them)
+            if config.replenishment_check_interval_seconds > 0 {
+                let replenishment_config = replenishment_worker::ReplenishmentWorkerConfig {
+                    interval_seconds: config.replenishment_check_interval_seconds,
+                    cooldown_seconds: config.replenishment_cooldown_seconds,
+                };
+                let replenishment_service = replenishment_worker::create_replenishment_service(
+                    pool.clone(),
+                    nats_client.clone(),
+                );
+                let replenishment_pool = pool.clone();
+                tokio::spawn(async move {
+                    replenishment_worker::start_replenishment_worker(
+                        replenishment_pool,
+                        replenishment_service,
+                        replenishment_config,
+                    )
+                    .await;
+                });
+                tracing::info!("Replenishment worker started");
+            }
+
             // Start outbox worker
-            let worker_config = worker::OutboxWorkerConfig::default();
             let worker_pool = pool.clone();
-            tokio::spawn(async move {
-                if let Err(e) =
-                    worker::start_outbox_worker(worker_pool, nats_client, worker_config).await
+            let worker_shutdown = shutdown_token.clone();
+            outbox_worker = Some(tokio::spawn(async move {
+                if let Err(e) = worker::start_outbox_worker(
+                    worker_pool,
+                    nats_client,
+                    worker_config,
+                    worker_shutdown,
+                )
+                .await
                 {
                     tracing::error!("Outbox worker failed: {}", e);
                 }
-            });
+            }));
             tracing::info!("Outbox worker started");
         }
     }
 
+    // Start category product count recount worker (interval 0 disables it)
+    if config.category_recount_interval_seconds > 0 {
+        let recount_config = category_recount_worker::CategoryRecountWorkerConfig {
+            interval_seconds: config.category_recount_interval_seconds,
+        };
+        let recount_pool = pool.clone();
+        tokio::spawn(async move {
+            category_recount_worker::start_category_recount_worker(recount_pool, recount_config)
+                .await;
+        });
+        tracing::info!("Category recount worker started");
+    }
+
+    // Start expired lot scrap proposal worker (interval 0 disables it)
+    if config.expiry_scrap_interval_seconds > 0 {
+        let expiry_scrap_config = expiry_scrap_worker::ExpiryScrapWorkerConfig {
+            interval_seconds: config.expiry_scrap_interval_seconds,
+            grace_days: config.expiry_scrap_grace_days,
+        };
+        let expiry_scrap_pool = pool.clone();
+        tokio::spawn(async move {
+            expiry_scrap_worker::start_expiry_scrap_worker(expiry_scrap_pool, expiry_scrap_config)
+                .await;
+        });
+        tracing::info!("Expiry scrap worker started");
+    }
+
+    // Start the stock move archival worker (retention 0 keeps every move hot)
+    if config.stock_move_archive_interval_seconds > 0 && config.stock_move_retention_days > 0 {
+        let archive_config = stock_move_archive_worker::StockMoveArchiveWorkerConfig {
+            interval_seconds: config.stock_move_archive_interval_seconds,
+            retention_days: config.stock_move_retention_days,
+            ..Default::default()
+        };
+        let archive_pool = pool.clone();
+        tokio::spawn(async move {
+            stock_move_archive_worker::start_stock_move_archive_worker(
+                archive_pool,
+                archive_config,
+            )
+            .await;
+        });
+        tracing::info!("Stock move archive worker started");
+    }
+
+    // Casbin enforcer and token denylist shared by the REST and gRPC APIs
+    let authz_state = create_authz_state(&config).await;
+
+    // Start the gRPC read API on its own port (port 0 disables it)
+    let mut grpc_server = None;
+    if config.grpc_port > 0 {
+        let grpc_addr = SocketAddr::from(([0, 0, 0, 0], config.grpc_port));
+        let grpc_listener = TcpListener::bind(grpc_addr).await?;
+        let grpc_pool = pool.clone();
+        let grpc_authz = authz_state.clone();
+        let grpc_shutdown = shutdown_token.clone().cancelled_owned();
+        grpc_server = Some(tokio::spawn(async move {
+            if let Err(e) = grpc::serve(grpc_listener, grpc_pool, grpc_authz, grpc_shutdown).await {
+                tracing::error!("gRPC server failed: {}", e);
+            }
+        }));
+        tracing::info!("Inventory gRPC service listening on {}", grpc_addr);
+    }
+
     // Create the application router
-    let app = create_router(pool, &config).await;
+    let app = create_router_with_level_events(pool, &config, level_change_broadcaster, authz_state)
+        .await
+        .layer(axum::middleware::from_fn_with_state(
+            in_flight.clone(),
+            shutdown::track_in_flight,
+        ));
 
     // Start the server
     let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
     tracing::info!("Inventory service listening on {}", addr);
 
     let listener = TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_token.clone().cancelled_owned())
+        .into_future();
+
+    // Stop waiting for stragglers (e.g. open SSE streams) once the drain timeout elapses
+    let drain_deadline = async {
+        shutdown_token.cancelled().await;
+        tokio::time::sleep(shutdown::DRAIN_TIMEOUT).await;
+    };
+    tokio::select! {
+        result = server => result?,
+        _ = drain_deadline => {
+            tracing::warn!(
+                "Drain timeout of {:?} elapsed with {} requests still in flight",
+                shutdown::DRAIN_TIMEOUT,
+                in_flight.current()
+            );
+        },
+    }
+
+    let draining = signal_task.await.unwrap_or_default();
+    tracing::info!(
+        "HTTP server stopped; drained {} of {} in-flight requests",
+        draining.saturating_sub(in_flight.current()),
+        draining
+    );
+
+    // Let the gRPC server finish the calls it is serving
+    if let Some(grpc_server) = grpc_server {
+        if let Err(e) = grpc_server.await {
+            tracing::error!("gRPC server did not shut down cleanly: {}", e);
+        }
+    }
+
+    // Let the outbox worker publish the batch it is working on
+    if let Some(outbox_worker) = outbox_worker {
+        if let Err(e) = outbox_worker.await {
+            tracing::error!("Outbox worker did not shut down cleanly: {}", e);
+        }
+    }
 
-    // tracing::info!("Inventory service config loaded successfully, port: {}", config.port);
+    tracing::info!("Inventory service shut down");
 
     Ok(())
 }
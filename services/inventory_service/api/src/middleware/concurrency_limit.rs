@@ -0,0 +1,93 @@
+//! Concurrency limit middleware
+//!
+//! Some routes hold expensive resources for the whole request: reports run
+//! long aggregate queries on the connection pool, and uploads buffer whole
+//! files in memory while holding an S3 connection. A `ConcurrencyLimit` caps
+//! how many such requests run at once. Requests beyond the limit wait in a
+//! queue for a slot and are otherwise rejected with 503.
+//!
+//! The limit is per process. A slot is held for the whole request, including
+//! any retries the handler makes, so retries never wait on a slot themselves.
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use metrics::{counter, gauge};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use shared_error::AppError;
+
+/// Shared limit on concurrently handled requests of one kind
+#[derive(Clone)]
+pub struct ConcurrencyLimit {
+    name: &'static str,
+    permits: Arc<Semaphore>,
+    queue_timeout: Duration,
+    queued: Arc<AtomicUsize>,
+}
+
+impl ConcurrencyLimit {
+    /// Allow `max_concurrent` requests at once, queueing others for up to `queue_timeout`
+    ///
+    /// `name` labels the limit's metrics and log lines, e.g. `"reports"`.
+    pub fn new(name: &'static str, max_concurrent: usize, queue_timeout: Duration) -> Self {
+        Self {
+            name,
+            permits: Arc::new(Semaphore::new(max_concurrent.max(1))),
+            queue_timeout,
+            queued: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Number of requests currently waiting for a slot
+    pub fn queue_depth(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    fn record_queue_depth(&self, depth: usize) {
+        gauge!("inventory_concurrency_queue_depth", "limit" => self.name).set(depth as f64);
+    }
+}
+
+/// Run the request once a slot is free, or reject it when none frees up in time
+pub async fn concurrency_limit_middleware(
+    State(limit): State<ConcurrencyLimit>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let permit = match limit.permits.clone().try_acquire_owned() {
+        Ok(permit) => Some(permit),
+        Err(_) if limit.queue_timeout.is_zero() => None,
+        Err(_) => {
+            limit.record_queue_depth(limit.queued.fetch_add(1, Ordering::SeqCst) + 1);
+            let permit =
+                tokio::time::timeout(limit.queue_timeout, limit.permits.clone().acquire_owned())
+                    .await
+                    .ok()
+                    .and_then(Result::ok);
+            limit.record_queue_depth(limit.queued.fetch_sub(1, Ordering::SeqCst) - 1);
+            permit
+        },
+    };
+
+    let Some(_permit) = permit else {
+        tracing::warn!(
+            limit = limit.name,
+            path = %request.uri().path(),
+            "Concurrency limit reached"
+        );
+        counter!("inventory_concurrency_rejected_total", "limit" => limit.name).increment(1);
+        return AppError::ServiceUnavailable(format!(
+            "Too many {} are in progress, please retry shortly",
+            limit.name
+        ))
+        .into_response();
+    };
+
+    next.run(request).await
+}
@@ -0,0 +1,39 @@
+//! Request correlation id middleware
+//!
+//! Gives every request a correlation id, taken from the caller's
+//! `x-correlation-id` header when it is acceptable or generated otherwise.
+//! The request is handled inside a tracing span and correlation scope tagged
+//! with the id, so outbox events it enqueues carry the id on to consumers,
+//! and the id is echoed back in the response header.
+
+use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
+use tracing::Instrument;
+use uuid::Uuid;
+
+use shared_events::{is_valid_correlation_id, with_correlation_id, CORRELATION_ID_HEADER};
+
+/// Run the request in the scope of its correlation id
+pub async fn correlation_id_middleware(request: Request, next: Next) -> Response {
+    let correlation_id = request
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_correlation_id(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::now_v7().to_string());
+
+    let span = tracing::info_span!(
+        "request",
+        correlation_id = %correlation_id,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+
+    let mut response =
+        with_correlation_id(correlation_id.clone(), next.run(request).instrument(span)).await;
+
+    if let Ok(value) = HeaderValue::from_str(&correlation_id) {
+        response.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+    response
+}
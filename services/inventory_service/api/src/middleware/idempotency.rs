@@ -1,13 +1,81 @@
+//! Idempotency middleware
+//!
+//! A mutation's idempotency key is claimed in Redis with an in-flight marker
+//! before the handler runs and turned into a "processed" marker once it
+//! succeeds, together with the response it produced. Repeats of a processed
+//! key replay that response, repeats of an in-flight key are conflicts. A
+//! marker left in flight by a crashed process is reclaimed once it is older
+//! than the stale threshold, and a watchdog clears such markers.
+//!
+//! Client keys are scoped to the caller and the route (see [`scoped_key`]), so
+//! two users, or two endpoints, reusing the same key never collide.
+
 use axum::{
+    body::{to_bytes, Body, Bytes, HttpBody},
     extract::{Request, State},
-    http::{HeaderMap, StatusCode},
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use metrics::counter;
 use redis::AsyncCommands;
+use shared_auth::AuthUser;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time;
 use tracing::{error, info, warn};
 
+/// Value stored for a key whose request completed successfully
+const PROCESSED_MARKER: &str = "processed";
+
+/// Prefix of the value stored for a key whose request is running; followed by
+/// the claim time in Unix milliseconds
+const IN_FLIGHT_PREFIX: &str = "in_flight:";
+
+/// Prefix of the hash holding a processed key's response; kept apart from the
+/// `idempotency:` namespace so no client key can collide with it
+const RESPONSE_PREFIX: &str = "idempotency_response:";
+
+/// Header set on replayed responses
+pub const REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Sorted set of in-flight keys, scored by claim time, scanned by the watchdog
+const IN_FLIGHT_SET: &str = "idempotency:in_flight";
+
+/// Claim a key whose in-flight marker is stale (or that vanished meanwhile)
+const RECLAIM_SCRIPT: &str = r#"
+local value = redis.call('GET', KEYS[1])
+if value then
+    if string.sub(value, 1, 10) ~= 'in_flight:' then return 0 end
+    if tonumber(string.sub(value, 11)) > tonumber(ARGV[1]) then return 0 end
+end
+redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+return 1
+"#;
+
+/// Delete a key if it still holds the given marker
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// Delete a key left in flight since before the cutoff and drop it from the in-flight set
+const CLEAR_STALE_SCRIPT: &str = r#"
+local value = redis.call('GET', KEYS[1])
+if value and string.sub(value, 1, 10) == 'in_flight:' then
+    if tonumber(string.sub(value, 11)) > tonumber(ARGV[1]) then return 0 end
+    redis.call('DEL', KEYS[1])
+    redis.call('ZREM', KEYS[2], KEYS[1])
+    return 1
+end
+redis.call('ZREM', KEYS[2], KEYS[1])
+return 0
+"#;
+
 /// Configuration for idempotency middleware
 #[derive(Clone)]
 pub struct IdempotencyConfig {
@@ -17,6 +85,12 @@ pub struct IdempotencyConfig {
     pub ttl_seconds: u64,
     /// Header name for idempotency key (default: "x-idempotency-key")
     pub header_name: String,
+    /// Age in seconds after which an in-flight key counts as abandoned
+    /// (default: 5 minutes, 0 never reclaims)
+    pub in_flight_stale_seconds: u64,
+    /// Largest response body stored for replay (default: 1 MiB); repeats of a
+    /// request with a larger or streamed body get a conflict instead
+    pub max_stored_body_bytes: usize,
 }
 
 impl Default for IdempotencyConfig {
@@ -25,15 +99,98 @@ impl Default for IdempotencyConfig {
             redis_url: "redis://localhost:6379".to_string(),
             ttl_seconds: 24 * 60 * 60, // 24 hours
             header_name: "x-idempotency-key".to_string(),
+            in_flight_stale_seconds: 5 * 60,
+            max_stored_body_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Outcome of claiming an idempotency key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyClaim {
+    /// The key is ours; holds the in-flight marker to complete or release it with
+    Claimed(String),
+    /// A request with this key already completed
+    Processed,
+    /// A request with this key is still running
+    InFlight,
+}
+
+/// Response of a completed request, replayed for repeats of its key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: StatusCode,
+    pub content_type: Option<HeaderValue>,
+    pub body: Bytes,
+}
+
+impl StoredResponse {
+    fn to_fields(&self) -> Vec<(&'static str, Vec<u8>)> {
+        let mut fields = vec![
+            ("status", self.status.as_u16().to_string().into_bytes()),
+            ("body", self.body.to_vec()),
+        ];
+        if let Some(content_type) = &self.content_type {
+            fields.push(("content_type", content_type.as_bytes().to_vec()));
         }
+        fields
+    }
+
+    fn from_fields(mut fields: HashMap<String, Vec<u8>>) -> Option<Self> {
+        let status = std::str::from_utf8(fields.get("status")?)
+            .ok()?
+            .parse::<u16>()
+            .ok()
+            .and_then(|code| StatusCode::from_u16(code).ok())?;
+        let content_type = fields
+            .remove("content_type")
+            .and_then(|value| HeaderValue::from_bytes(&value).ok());
+        Some(Self {
+            status,
+            content_type,
+            body: Bytes::from(fields.remove("body").unwrap_or_default()),
+        })
     }
 }
 
+impl IntoResponse for StoredResponse {
+    fn into_response(self) -> Response {
+        let mut response = (self.status, self.body).into_response();
+        let headers = response.headers_mut();
+        match self.content_type {
+            Some(content_type) => headers.insert(header::CONTENT_TYPE, content_type),
+            None => headers.remove(header::CONTENT_TYPE),
+        };
+        headers.insert(REPLAYED_HEADER, HeaderValue::from_static("true"));
+        response
+    }
+}
+
+/// Running totals of idempotency outcomes, mirrored to the metrics recorder
+#[derive(Debug, Default)]
+struct IdempotencyCounters {
+    hit: AtomicU64,
+    replay: AtomicU64,
+    conflict: AtomicU64,
+}
+
+/// Snapshot of idempotency outcome counts since startup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IdempotencyCounts {
+    /// Requests whose key was already known
+    pub hit: u64,
+    /// Requests repeating a completed request
+    pub replay: u64,
+    /// Requests repeating a request that is still running
+    pub conflict: u64,
+}
+
 /// Idempotency middleware state
 #[derive(Clone)]
 pub struct IdempotencyState {
     config: IdempotencyConfig,
     redis_client: redis::Client,
+    counters: Arc<IdempotencyCounters>,
 }
 
 impl IdempotencyState {
@@ -42,23 +199,221 @@ impl IdempotencyState {
         Ok(Self {
             config,
             redis_client,
+            counters: Arc::default(),
         })
     }
 
-    /// Check if a request with the given idempotency key has already been processed
-    pub async fn is_processed(&self, key: &str) -> Result<bool, redis::RedisError> {
+    /// Idempotency outcome counts since this state was created
+    pub fn counts(&self) -> IdempotencyCounts {
+        IdempotencyCounts {
+            hit: self.counters.hit.load(Ordering::Relaxed),
+            replay: self.counters.replay.load(Ordering::Relaxed),
+            conflict: self.counters.conflict.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Claim `key` for a new request
+    ///
+    /// An in-flight marker older than the stale threshold is taken over, so a
+    /// request that crashed between claim and completion does not block its key
+    /// until the TTL runs out.
+    pub async fn claim(&self, key: &str) -> Result<IdempotencyClaim, redis::RedisError> {
+        let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
+        let claimed_at = now_millis();
+        let marker = format!("{}{}", IN_FLIGHT_PREFIX, claimed_at);
+
+        let created: Option<String> = redis::cmd("SET")
+            .arg(key)
+            .arg(&marker)
+            .arg("NX")
+            .arg("EX")
+            .arg(self.config.ttl_seconds)
+            .query_async(&mut conn)
+            .await?;
+
+        let claimed = if created.is_some() {
+            true
+        } else {
+            let existing: Option<String> = conn.get(key).await?;
+            match existing.as_deref() {
+                Some(PROCESSED_MARKER) => return Ok(IdempotencyClaim::Processed),
+                _ => match self.stale_cutoff(claimed_at) {
+                    Some(cutoff) => {
+                        let reclaimed: i64 = redis::Script::new(RECLAIM_SCRIPT)
+                            .key(key)
+                            .arg(cutoff)
+                            .arg(&marker)
+                            .arg(self.config.ttl_seconds)
+                            .invoke_async(&mut conn)
+                            .await?;
+                        if reclaimed == 1 {
+                            warn!("Reclaimed stale in-flight idempotency key: {}", key);
+                        }
+                        reclaimed == 1
+                    },
+                    None => false,
+                },
+            }
+        };
+
+        if !claimed {
+            return Ok(IdempotencyClaim::InFlight);
+        }
+
+        conn.zadd::<_, _, _, ()>(IN_FLIGHT_SET, key, claimed_at)
+            .await?;
+        Ok(IdempotencyClaim::Claimed(marker))
+    }
+
+    /// Mark a claimed key as processed with TTL, storing `response` for replay
+    ///
+    /// The response and the marker are written in one transaction, so a key
+    /// seen as processed already has its response.
+    pub async fn complete(
+        &self,
+        key: &str,
+        response: Option<&StoredResponse>,
+    ) -> Result<(), redis::RedisError> {
         let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
-        let exists: bool = conn.exists(key).await?;
-        Ok(exists)
+        let response_key = response_key(key);
+
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(&response_key).ignore();
+        if let Some(response) = response {
+            pipe.hset_multiple(&response_key, &response.to_fields())
+                .ignore()
+                .expire(&response_key, self.config.ttl_seconds as i64)
+                .ignore();
+        }
+        pipe.set_ex(key, PROCESSED_MARKER, self.config.ttl_seconds)
+            .ignore()
+            .zrem(IN_FLIGHT_SET, key)
+            .ignore();
+        let () = pipe.query_async(&mut conn).await?;
+        Ok(())
     }
 
-    /// Mark a request as processed with TTL
-    pub async fn mark_processed(&self, key: &str) -> Result<(), redis::RedisError> {
+    /// Response stored for a processed key, if any
+    pub async fn stored_response(
+        &self,
+        key: &str,
+    ) -> Result<Option<StoredResponse>, redis::RedisError> {
         let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
-        conn.set_ex::<_, _, ()>(key, "processed", self.config.ttl_seconds)
+        let fields: HashMap<String, Vec<u8>> = conn.hgetall(response_key(key)).await?;
+        if fields.is_empty() {
+            return Ok(None);
+        }
+        Ok(StoredResponse::from_fields(fields))
+    }
+
+    /// Give up a claim after a failed request, so the key can be retried
+    pub async fn release(&self, key: &str, marker: &str) -> Result<(), redis::RedisError> {
+        let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
+        let _released: i64 = redis::Script::new(RELEASE_SCRIPT)
+            .key(key)
+            .arg(marker)
+            .invoke_async(&mut conn)
             .await?;
+        conn.zrem::<_, _, ()>(IN_FLIGHT_SET, key).await?;
         Ok(())
     }
+
+    /// Delete in-flight markers older than the stale threshold
+    ///
+    /// # Returns
+    /// Number of keys cleared
+    pub async fn clear_stale_in_flight(&self) -> Result<usize, redis::RedisError> {
+        let Some(cutoff) = self.stale_cutoff(now_millis()) else {
+            return Ok(0);
+        };
+        let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
+        let stale_keys: Vec<String> = conn.zrangebyscore(IN_FLIGHT_SET, "-inf", cutoff).await?;
+
+        let script = redis::Script::new(CLEAR_STALE_SCRIPT);
+        let mut cleared = 0;
+        for key in stale_keys {
+            let removed: i64 = script
+                .key(&key)
+                .key(IN_FLIGHT_SET)
+                .arg(cutoff)
+                .invoke_async(&mut conn)
+                .await?;
+            if removed == 1 {
+                warn!("Cleared stale in-flight idempotency key: {}", key);
+                cleared += 1;
+            }
+        }
+        Ok(cleared)
+    }
+
+    /// Claim time at or before which an in-flight marker is stale
+    fn stale_cutoff(&self, now_millis: u64) -> Option<u64> {
+        match self.config.in_flight_stale_seconds {
+            0 => None,
+            stale => Some(now_millis.saturating_sub(stale * 1000)),
+        }
+    }
+
+    fn record_replay(&self) {
+        self.counters.hit.fetch_add(1, Ordering::Relaxed);
+        self.counters.replay.fetch_add(1, Ordering::Relaxed);
+        counter!("idempotency_hit").increment(1);
+        counter!("idempotency_replay").increment(1);
+    }
+
+    fn record_conflict(&self) {
+        self.counters.hit.fetch_add(1, Ordering::Relaxed);
+        self.counters.conflict.fetch_add(1, Ordering::Relaxed);
+        counter!("idempotency_hit").increment(1);
+        counter!("idempotency_conflict").increment(1);
+    }
+}
+
+/// Redis key for a client-supplied idempotency key
+///
+/// Namespaced by tenant, user, method and path. Requests that reach the
+/// middleware without an authenticated user share an `anonymous` scope.
+pub fn scoped_key(user: Option<&AuthUser>, method: &Method, path: &str, key: &str) -> String {
+    let (tenant_id, user_id) = match user {
+        Some(user) => (user.tenant_id.to_string(), user.user_id.to_string()),
+        None => ("anonymous".to_string(), "anonymous".to_string()),
+    };
+    format!("idempotency:{}:{}:{}:{}:{}", tenant_id, user_id, method, path, key)
+}
+
+fn response_key(key: &str) -> String {
+    format!("{}{}", RESPONSE_PREFIX, key)
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Periodically clear in-flight markers left behind by crashed requests
+///
+/// Sweeps twice per stale window; returns immediately when reclaiming is disabled.
+pub async fn start_idempotency_watchdog(state: Arc<IdempotencyState>) {
+    let stale_seconds = state.config.in_flight_stale_seconds;
+    if stale_seconds == 0 {
+        return;
+    }
+    info!("Starting idempotency watchdog with stale threshold {}s", stale_seconds);
+
+    let mut interval = time::interval(Duration::from_secs((stale_seconds / 2).max(1)));
+    loop {
+        interval.tick().await;
+
+        match state.clear_stale_in_flight().await {
+            Ok(cleared) if cleared > 0 => {
+                info!("Idempotency watchdog cleared {} stale in-flight keys", cleared);
+            },
+            Ok(_) => {},
+            Err(e) => error!("Error clearing stale idempotency keys: {}", e),
+        }
+    }
 }
 
 /// Idempotency middleware for Axum
@@ -100,43 +455,108 @@ pub async fn idempotency_middleware(
         return (StatusCode::BAD_REQUEST, "Invalid idempotency key").into_response();
     }
 
-    // Check if request has already been processed
-    match state.is_processed(idempotency_key).await {
-        Ok(true) => {
+    let redis_key = scoped_key(
+        request.extensions().get::<AuthUser>(),
+        request.method(),
+        request.uri().path(),
+        idempotency_key,
+    );
+
+    // Claim the key before running the request
+    let marker = match state.claim(&redis_key).await {
+        Ok(IdempotencyClaim::Claimed(marker)) => Some(marker),
+        Ok(IdempotencyClaim::Processed) => {
             info!("Duplicate request detected with key: {}", idempotency_key);
+            state.record_replay();
+            match state.stored_response(&redis_key).await {
+                Ok(Some(stored)) => return stored.into_response(),
+                Ok(None) => {},
+                Err(e) => error!("Redis error loading stored response: {}", e),
+            }
+            // Nothing to replay (body too large to store, or stored by an older version)
             return (
                 StatusCode::CONFLICT,
                 format!("Request with idempotency key '{}' already processed", idempotency_key),
             )
                 .into_response();
         },
-        Ok(false) => {
-            // Key doesn't exist, proceed with request
+        Ok(IdempotencyClaim::InFlight) => {
+            info!("Concurrent request detected with key: {}", idempotency_key);
+            state.record_conflict();
+            return (
+                StatusCode::CONFLICT,
+                format!("Request with idempotency key '{}' is still in progress", idempotency_key),
+            )
+                .into_response();
         },
         Err(e) => {
-            error!("Redis error checking idempotency key: {}", e);
+            error!("Redis error claiming idempotency key: {}", e);
             // In case of Redis failure, allow request to proceed to avoid blocking
             // This is a fail-open approach for better availability
             warn!("Redis unavailable, allowing request to proceed");
+            None
         },
-    }
+    };
 
     // Execute the request
     let response = next.run(request).await;
 
-    // Only mark as processed if request was successful (2xx status)
-    if response.status().is_success() {
-        if let Err(e) = state.mark_processed(idempotency_key).await {
-            error!("Failed to mark request as processed: {}", e);
-            // Don't fail the response if Redis write fails
-        } else {
-            info!("Marked request as processed: {}", idempotency_key);
+    let Some(marker) = marker else {
+        return response;
+    };
+
+    // Only mark as processed if request was successful (2xx status); otherwise
+    // free the key so the client can retry
+    if !response.status().is_success() {
+        if let Err(e) = state.release(&redis_key, &marker).await {
+            error!("Failed to release idempotency key: {}", e);
         }
+        return response;
+    }
+
+    let (response, stored) =
+        match buffer_response(response, state.config.max_stored_body_bytes).await {
+            Ok(buffered) => buffered,
+            Err(e) => {
+                error!("Failed to read response body: {}", e);
+                if let Err(e) = state.release(&redis_key, &marker).await {
+                    error!("Failed to release idempotency key: {}", e);
+                }
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            },
+        };
+
+    if let Err(e) = state.complete(&redis_key, stored.as_ref()).await {
+        error!("Failed to mark request as processed: {}", e);
+        // Don't fail the response if Redis write fails
+    } else {
+        info!("Marked request as processed: {}", idempotency_key);
     }
 
     response
 }
 
+/// Buffer a response whose body is known to fit within `limit`, returning it
+/// together with its stored form; streamed or larger bodies pass through unstored
+async fn buffer_response(
+    response: Response,
+    limit: usize,
+) -> Result<(Response, Option<StoredResponse>), axum::Error> {
+    let fits = matches!(response.body().size_hint().exact(), Some(len) if len <= limit as u64);
+    if !fits {
+        return Ok((response, None));
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = to_bytes(body, limit).await?;
+    let stored = StoredResponse {
+        status: parts.status,
+        content_type: parts.headers.get(header::CONTENT_TYPE).cloned(),
+        body: body.clone(),
+    };
+    Ok((Response::from_parts(parts, Body::from(body)), Some(stored)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,4 +605,30 @@ mod tests {
         let response = app.oneshot(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
+
+    #[test]
+    fn test_scoped_key_separates_users_and_routes() {
+        let user = |user_id: u128| AuthUser {
+            user_id: uuid::Uuid::from_u128(user_id),
+            tenant_id: uuid::Uuid::from_u128(7),
+            role: "user".to_string(),
+            email: None,
+        };
+        let (alice, bob) = (user(1), user(2));
+
+        let key = scoped_key(Some(&alice), &Method::POST, "/api/v1/inventory/receipts", "k1");
+        assert_eq!(
+            key,
+            format!(
+                "idempotency:{}:{}:POST:/api/v1/inventory/receipts:k1",
+                alice.tenant_id, alice.user_id
+            )
+        );
+        assert_ne!(key, scoped_key(Some(&bob), &Method::POST, "/api/v1/inventory/receipts", "k1"));
+        assert_ne!(
+            key,
+            scoped_key(Some(&alice), &Method::POST, "/api/v1/inventory/transfers", "k1")
+        );
+        assert_ne!(key, scoped_key(Some(&alice), &Method::PUT, "/api/v1/inventory/receipts", "k1"));
+    }
 }
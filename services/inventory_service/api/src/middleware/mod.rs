@@ -1,6 +1,12 @@
 //! Custom middleware for the inventory service
 
+pub mod concurrency_limit;
+pub mod correlation;
 pub mod idempotency;
+pub mod tenant_seed;
 
+pub use concurrency_limit::{concurrency_limit_middleware, ConcurrencyLimit};
+pub use correlation::correlation_id_middleware;
 pub use idempotency::*;
 pub use shared_auth::middleware::{casbin_middleware, AuthzState};
+pub use tenant_seed::{tenant_seed_middleware, TenantSeedState};
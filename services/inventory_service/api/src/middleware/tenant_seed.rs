@@ -0,0 +1,78 @@
+//! First-access tenant seeding middleware
+//!
+//! Seeds a tenant's default entities the first time one of its users makes an
+//! authenticated request to this instance. The seed itself is idempotent, so a
+//! restart or a second instance only costs one extra lookup per tenant.
+
+use axum::{
+    extract::{FromRequestParts, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use inventory_service_core::services::tenant_provisioning::TenantProvisioningService;
+use shared_auth::AuthUser;
+
+/// State of the first-access seeding middleware
+#[derive(Clone)]
+pub struct TenantSeedState {
+    service: Arc<dyn TenantProvisioningService>,
+    seeded: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+impl TenantSeedState {
+    pub fn new(service: Arc<dyn TenantProvisioningService>) -> Self {
+        Self {
+            service,
+            seeded: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    fn is_seeded(&self, tenant_id: Uuid) -> bool {
+        self.seeded
+            .lock()
+            .map(|seeded| seeded.contains(&tenant_id))
+            .unwrap_or(false)
+    }
+
+    fn mark_seeded(&self, tenant_id: Uuid) {
+        if let Ok(mut seeded) = self.seeded.lock() {
+            seeded.insert(tenant_id);
+        }
+    }
+}
+
+/// Seed the caller's tenant before handling its first request
+///
+/// Unauthenticated requests pass through untouched; a failed seed is logged
+/// and retried on the tenant's next request instead of failing this one.
+pub async fn tenant_seed_middleware(
+    State(state): State<TenantSeedState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (mut parts, body) = request.into_parts();
+
+    if let Ok(auth_user) = AuthUser::from_request_parts(&mut parts, &()).await {
+        let tenant_id = auth_user.tenant_id;
+        if !state.is_seeded(tenant_id) {
+            match state
+                .service
+                .seed_defaults(tenant_id, auth_user.user_id)
+                .await
+            {
+                Ok(_) => {
+                    info!("Seeded default entities for tenant {}", tenant_id);
+                    state.mark_seeded(tenant_id);
+                },
+                Err(e) => warn!("Failed to seed defaults for tenant {}: {}", tenant_id, e),
+            }
+        }
+    }
+
+    next.run(Request::from_parts(parts, body)).await
+}
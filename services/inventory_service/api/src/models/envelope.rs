@@ -0,0 +1,115 @@
+//! Opt-in `{data, meta}` envelope for list responses
+//!
+//! List endpoints keep their existing shape by default. A client that wants
+//! the uniform [`Envelope`] asks for it with an `Accept` profile
+//! (`application/json; profile="envelope"`) or the `envelope=true` query flag.
+
+use std::convert::Infallible;
+
+use axum::{
+    extract::{FromRequestParts, Query},
+    http::{header, request::Parts},
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+
+use inventory_service_core::dto::common::PaginatedResponse;
+use shared_error::extract::Json;
+
+/// `Accept` profile that selects the envelope
+pub const ENVELOPE_PROFILE: &str = "envelope";
+
+/// Shape a list response is rendered in, chosen by the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseShape {
+    /// The endpoint's own shape, e.g. `{products, pagination}`
+    #[default]
+    Bare,
+    /// `{data, meta}`
+    Enveloped,
+}
+
+#[derive(Deserialize)]
+struct EnvelopeFlag {
+    #[serde(default)]
+    envelope: bool,
+}
+
+impl ResponseShape {
+    /// Pick the shape from the request's `Accept` profile or query flag
+    pub fn from_parts(parts: &Parts) -> Self {
+        let flagged = Query::<EnvelopeFlag>::try_from_uri(&parts.uri)
+            .map(|Query(flag)| flag.envelope)
+            .unwrap_or(false);
+
+        let profiled = parts
+            .headers
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .any(accepts_envelope_profile);
+
+        if flagged || profiled {
+            ResponseShape::Enveloped
+        } else {
+            ResponseShape::Bare
+        }
+    }
+
+    /// Render a list response in this shape
+    pub fn respond<T>(self, body: T) -> Response
+    where
+        T: PaginatedResponse + Serialize,
+    {
+        match self {
+            ResponseShape::Bare => Json(body).into_response(),
+            ResponseShape::Enveloped => Json(body.into_envelope()).into_response(),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for ResponseShape
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_parts(parts))
+    }
+}
+
+/// Whether an `Accept` value asks for the envelope profile
+///
+/// `profile` may hold several space-separated profiles, quoted or not.
+fn accepts_envelope_profile(accept: &str) -> bool {
+    accept.split(',').any(|media_range| {
+        media_range
+            .split(';')
+            .skip(1)
+            .filter_map(|param| param.split_once('='))
+            .filter(|(name, _)| name.trim().eq_ignore_ascii_case("profile"))
+            .any(|(_, value)| {
+                value
+                    .trim()
+                    .trim_matches('"')
+                    .split_whitespace()
+                    .any(|profile| profile == ENVELOPE_PROFILE)
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_envelope_profile() {
+        assert!(accepts_envelope_profile(r#"application/json; profile="envelope""#));
+        assert!(accepts_envelope_profile("text/html, application/json;profile=envelope"));
+        assert!(accepts_envelope_profile(r#"application/json; profile="urn:x envelope""#));
+        assert!(!accepts_envelope_profile("application/json"));
+        assert!(!accepts_envelope_profile(r#"application/json; profile="envelopes""#));
+        assert!(!accepts_envelope_profile("application/json; charset=envelope"));
+    }
+}
@@ -3,5 +3,9 @@
 //! This module contains models that are specific to the API layer,
 //! including request/response conversions and API-specific types.
 
+pub mod envelope;
+
+pub use envelope::ResponseShape;
+
 // Re-export from core for convenience
 pub use inventory_service_core::dto::category::*;
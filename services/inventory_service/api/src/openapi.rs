@@ -1,3 +1,5 @@
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::Modify;
 #[allow(unused_imports)]
 use utoipa::OpenApi;
 
@@ -5,13 +7,16 @@ use utoipa::OpenApi;
 #[allow(unused_imports)]
 use crate::handlers::category::{
     bulk_activate_categories, bulk_deactivate_categories, bulk_delete_categories,
-    can_delete_category, create_category, delete_category, get_breadcrumbs, get_category,
-    get_category_stats, get_category_tree, get_children, get_top_categories, list_categories,
-    move_products_to_category, search_categories, update_category, BulkCategoryIds,
-    CategoryTreeQuery, SearchQuery, TopCategoriesQuery,
+    can_delete_category, category_exists, create_category, delete_category, export_categories,
+    get_breadcrumbs, get_category, get_category_stats, get_category_tree, get_children,
+    get_top_categories, import_categories, list_categories, move_products_to_category,
+    patch_category, recount_category_product_counts, restore_category, search_categories,
+    update_category, BulkCategoryIds, CategoryTreeQuery, SearchQuery, TopCategoriesQuery,
 };
 #[allow(unused_imports)]
-use crate::handlers::health::HealthResp;
+use crate::handlers::feature_flags::{get_feature_flag, list_feature_flags, set_feature_flag};
+#[allow(unused_imports)]
+use crate::handlers::health::{DependencyStatus, HealthResp, ReadinessResp};
 #[allow(unused_imports)]
 use crate::handlers::lot_serial::{
     create_lot_serial, delete_lot_serial, get_lot_serial, get_lot_serial_lifecycle,
@@ -25,30 +30,38 @@ use crate::handlers::picking::{
 };
 #[allow(unused_imports)]
 use crate::handlers::products::{
-    create_product, delete_product, get_product, list_products, update_product,
+    create_product, delete_product, get_product, get_product_attributes, list_products,
+    patch_product, product_exists, set_product_attributes, update_product,
 };
 #[allow(unused_imports)]
 use crate::handlers::putaway::{confirm_putaway, suggest_putaway};
 #[allow(unused_imports)]
 use crate::handlers::quality::{
-    create_qc_point, delete_qc_point, get_qc_point, list_qc_points, update_qc_point,
+    create_qc_point, delete_qc_point, get_qc_point, list_qc_points, list_quality_checks,
+    record_quality_check_result, update_qc_point,
 };
 #[allow(unused_imports)]
-use crate::handlers::receipt::{create_receipt, get_receipt, list_receipts, validate_receipt};
+use crate::handlers::receipt::{
+    create_receipt, get_receipt, list_receipts, reverse_receipt, validate_receipt,
+};
 #[allow(unused_imports)]
 use crate::handlers::reconciliation::{
     approve_reconciliation, count_reconciliation, create_reconciliation, finalize_reconciliation,
-    get_reconciliation, get_reconciliation_analytics, get_variance_analysis, list_reconciliations,
-    scan_barcode,
+    get_reconciliation, get_reconciliation_analytics, get_reconciliation_variance_trend,
+    get_variance_analysis, import_reconciliation, list_reconciliations, scan_barcode,
 };
 #[allow(unused_imports)]
 use crate::handlers::replenishment::{
-    check_product_replenishment, create_reorder_rule, delete_reorder_rule, get_reorder_rule,
-    list_reorder_rules_for_product, run_replenishment_check, update_reorder_rule,
+    check_product_replenishment, create_internal_replenishment_rule,
+    create_internal_transfer_drafts, create_reorder_rule, delete_internal_replenishment_rule,
+    delete_reorder_rule, get_reorder_rule, list_internal_replenishment_rules,
+    list_reorder_rules_for_product, run_replenishment_check, suggest_internal_transfers,
+    update_reorder_rule,
 };
 #[allow(unused_imports)]
 use crate::handlers::reports::{
-    get_dead_stock, get_inventory_turnover, get_low_stock, get_stock_aging, get_stock_ledger,
+    get_capacity_report, get_dead_stock, get_inventory_turnover, get_low_stock, get_stock_aging,
+    get_stock_ledger,
 };
 #[allow(unused_imports)]
 use crate::handlers::rma::{approve_rma, create_rma, receive_rma};
@@ -58,15 +71,22 @@ use crate::handlers::search::{
     SearchSuggestionsQuery,
 };
 #[allow(unused_imports)]
+use crate::handlers::snapshots::{create_snapshot, restore_snapshot};
+#[allow(unused_imports)]
 use crate::handlers::stock_take::{
     count_stock_take, create_stock_take, finalize_stock_take, get_stock_take, list_stock_takes,
 };
 #[allow(unused_imports)]
-use crate::handlers::transfer::{confirm_transfer, create_transfer, receive_transfer};
+use crate::handlers::tenants::{export_tenant_data, seed_tenant_defaults};
+#[allow(unused_imports)]
+use crate::handlers::transfer::{
+    confirm_transfer, create_transfer, list_in_transit_transfers, receive_transfer,
+    update_transfer_tracking,
+};
 #[allow(unused_imports)]
 use crate::handlers::valuation::{
-    adjust_cost, get_valuation, get_valuation_history, get_valuation_layers, revalue_inventory,
-    set_standard_cost, set_valuation_method, CostAdjustmentPayload,
+    adjust_cost, get_valuation, get_valuation_history, get_valuation_layers, rebuild_layers,
+    revalue_inventory, set_standard_cost, set_valuation_method, CostAdjustmentPayload,
     ErrorResponse as ValuationErrorResponse, HistoryQueryParams, RevaluationPayload,
     SetStandardCostPayload, SetValuationMethodPayload,
 };
@@ -77,6 +97,9 @@ use crate::handlers::warehouses::{
 };
 
 // Import DTOs for components
+use inventory_service_core::domains::inventory::dto::feature_flag_dto::{
+    FeatureFlagDto, FeatureFlagListResponse, SetFeatureFlagRequest,
+};
 use inventory_service_core::domains::inventory::dto::picking_method_dto::{
     CreatePickingMethodRequest, PickingMethodResponse, UpdatePickingMethodRequest,
 };
@@ -85,7 +108,8 @@ use inventory_service_core::domains::inventory::dto::search_dto::{
 };
 use inventory_service_core::domains::inventory::dto::transfer_dto::{
     ConfirmTransferRequest, ConfirmTransferResponse, CreateTransferRequest, CreateTransferResponse,
-    ReceiveTransferRequest, ReceiveTransferResponse,
+    ListInTransitTransfersResponse, ReceiveTransferRequest, ReceiveTransferResponse,
+    TransferResponse, UpdateTransferTrackingRequest,
 };
 use inventory_service_core::domains::inventory::dto::valuation_dto::{
     ValuationDto, ValuationHistoryResponse, ValuationLayersResponse,
@@ -95,31 +119,48 @@ use inventory_service_core::domains::inventory::dto::warehouse_dto::{
     WarehouseLocationResponse, WarehouseResponse, WarehouseTreeResponse, WarehouseZoneResponse,
 };
 use inventory_service_core::domains::quality::{
-    CreateQualityControlPoint, QualityControlPoint, UpdateQualityControlPoint,
+    CreateQualityControlPoint, QcStatus, QualityCheck, QualityControlPoint,
+    RecordQualityCheckResult, UpdateQualityControlPoint,
 };
 use inventory_service_core::domains::replenishment::{
-    CreateReorderRule, ReplenishmentCheckResult, UpdateReorderRule,
+    CreateInternalReplenishmentRule, CreateReorderRule, InternalReplenishmentRule,
+    InternalTransferSuggestion, ReplenishmentCheckResult, UpdateReorderRule,
 };
 use inventory_service_core::dto::category::{
-    BulkOperationResponse, CategoryCreateRequest, CategoryListResponse, CategoryResponse,
-    CategoryStatsResponse, CategoryUpdateRequest, MoveToCategoryRequest,
+    BulkOperationResponse, CategoryCreateRequest, CategoryExportDocument, CategoryExportItem,
+    CategoryImportConflict, CategoryImportMapping, CategoryImportResponse, CategoryImportStatus,
+    CategoryListResponse, CategoryPatchRequest, CategoryResponse, CategoryStatsResponse,
+    CategoryUpdateRequest, MoveToCategoryRequest,
 };
 use inventory_service_core::dto::common::PaginationInfo;
+use inventory_service_core::dto::delivery::{
+    PackItemsRequest, PackItemsResponse, PickItemRequest, PickItemsRequest, PickItemsResponse,
+    ShipItemRequest, ShipItemsRequest, ShipItemsResponse, ShipmentRemainingLine,
+};
+use inventory_service_core::dto::inventory_snapshot::{
+    InventorySnapshotResponse, SnapshotRestoreResponse,
+};
 use inventory_service_core::dto::product::{
-    ProductCreateRequest, ProductListQuery, ProductListResponse, ProductResponse,
-    ProductUpdateRequest,
+    ProductAttributesResponse, ProductCreateRequest, ProductListQuery, ProductListResponse,
+    ProductPatchRequest, ProductResponse, ProductUpdateRequest, SetProductAttributesRequest,
 };
 use inventory_service_core::dto::receipt::{
     ReceiptCreateRequest, ReceiptItemCreateRequest, ReceiptItemResponse, ReceiptListResponse,
-    ReceiptResponse, ReceiptSummaryResponse,
+    ReceiptResponse, ReceiptReverseRequest, ReceiptSummaryResponse,
 };
 use inventory_service_core::dto::reconciliation::{
     ApproveReconciliationRequest, ApproveReconciliationResponse, CountReconciliationRequest,
     CountReconciliationResponse, CreateReconciliationRequest, CreateReconciliationResponse,
-    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ReconciliationAnalyticsResponse,
-    ReconciliationDetailResponse, ReconciliationListResponse, ScanBarcodeRequest,
-    ScanBarcodeResponse, VarianceAnalysisResponse,
+    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ImportReconciliationRequest,
+    ImportReconciliationResponse, ReconciliationAnalyticsResponse, ReconciliationDetailResponse,
+    ReconciliationListResponse, ReconciliationSnapshotRow, ReconciliationTrendPeriod,
+    ReconciliationTrendResponse, ScanBarcodeRequest, ScanBarcodeResponse, TrendGroupBy,
+    VarianceAnalysisResponse,
 };
+use inventory_service_core::dto::tenant_export::{
+    TenantExportEntity, TenantExportEntityCount, TenantExportResponse,
+};
+use inventory_service_core::dto::tenant_provisioning::{SeededEntity, TenantSeedResponse};
 // Reports DTOs are defined in handlers/reports.rs
 use crate::handlers::reports::{
     DeadStockEntry, DeadStockQuery, InventoryTurnoverEntry, InventoryTurnoverQuery, LowStockEntry,
@@ -139,9 +180,10 @@ use inventory_service_core::models::{
 #[openapi(
     paths(
         crate::handlers::health::health_check,
+        crate::handlers::health::readiness_check,
     ),
     components(
-        schemas(HealthResp)
+        schemas(HealthResp, ReadinessResp, DependencyStatus)
     ),
     tags(
         (name = "health", description = "Health check endpoints"),
@@ -155,12 +197,16 @@ pub struct HealthApiDoc;
     paths(
         crate::handlers::category::create_category,
         crate::handlers::category::get_category,
+        crate::handlers::category::category_exists,
         crate::handlers::category::update_category,
+        crate::handlers::category::patch_category,
         crate::handlers::category::delete_category,
+        crate::handlers::category::restore_category,
     ),
     components(schemas(
         CategoryCreateRequest,
         CategoryUpdateRequest,
+        CategoryPatchRequest,
         CategoryResponse
     )),
     tags((name = "categories", description = "Category management endpoints")),
@@ -203,9 +249,22 @@ pub struct CategoriesHierarchyApiDoc;
         crate::handlers::category::bulk_activate_categories,
         crate::handlers::category::bulk_deactivate_categories,
         crate::handlers::category::bulk_delete_categories,
+        crate::handlers::category::recount_category_product_counts,
+        crate::handlers::category::export_categories,
+        crate::handlers::category::import_categories,
         crate::handlers::category::move_products_to_category,
     ),
-    components(schemas(BulkCategoryIds, BulkOperationResponse, MoveToCategoryRequest))
+    components(schemas(
+        BulkCategoryIds,
+        BulkOperationResponse,
+        MoveToCategoryRequest,
+        CategoryExportDocument,
+        CategoryExportItem,
+        CategoryImportResponse,
+        CategoryImportMapping,
+        CategoryImportConflict,
+        CategoryImportStatus
+    ))
 )]
 pub struct CategoriesBulkApiDoc;
 
@@ -270,10 +329,12 @@ pub struct PickingApiDoc;
         crate::handlers::receipt::get_receipt,
         crate::handlers::receipt::list_receipts,
         crate::handlers::receipt::validate_receipt,
+        crate::handlers::receipt::reverse_receipt,
     ),
     components(
         schemas(
             ReceiptCreateRequest,
+            ReceiptReverseRequest,
             ReceiptResponse,
             ReceiptListResponse,
             ReceiptItemCreateRequest,
@@ -320,6 +381,7 @@ pub struct SearchApiDoc;
         crate::handlers::valuation::set_standard_cost,
         crate::handlers::valuation::adjust_cost,
         crate::handlers::valuation::revalue_inventory,
+        crate::handlers::valuation::rebuild_layers,
     ),
     components(
         schemas(
@@ -371,17 +433,40 @@ pub struct ValuationApiDoc;
 )]
 pub struct WarehouseApiDoc;
 
+/// Adds the `bearer_auth` JWT scheme that protected inventory operations reference
+pub struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
 /// OpenAPI documentation for Inventory Service
 #[derive(OpenApi)]
 #[openapi(
+    modifiers(&SecurityAddon),
     paths(
         // Health
         crate::handlers::health::health_check,
+        crate::handlers::health::readiness_check,
         // Categories - CRUD operations (excluding recursive tree endpoints)
         crate::handlers::category::create_category,
         crate::handlers::category::get_category,
+        crate::handlers::category::category_exists,
         crate::handlers::category::update_category,
+        crate::handlers::category::patch_category,
         crate::handlers::category::delete_category,
+        crate::handlers::category::restore_category,
         crate::handlers::category::list_categories,
         crate::handlers::category::search_categories,
         crate::handlers::category::get_top_categories,
@@ -391,13 +476,20 @@ pub struct WarehouseApiDoc;
         crate::handlers::category::bulk_activate_categories,
         crate::handlers::category::bulk_deactivate_categories,
         crate::handlers::category::bulk_delete_categories,
+        crate::handlers::category::recount_category_product_counts,
+        crate::handlers::category::export_categories,
+        crate::handlers::category::import_categories,
         crate::handlers::category::move_products_to_category,
         // Products - CRUD operations
         crate::handlers::products::create_product,
         crate::handlers::products::get_product,
+        crate::handlers::products::product_exists,
         crate::handlers::products::list_products,
         crate::handlers::products::update_product,
+        crate::handlers::products::patch_product,
         crate::handlers::products::delete_product,
+        crate::handlers::products::get_product_attributes,
+        crate::handlers::products::set_product_attributes,
         // Warehouses - CRUD operations (excluding recursive tree endpoints)
         crate::handlers::warehouses::create_warehouse,
         crate::handlers::warehouses::get_warehouse,
@@ -411,6 +503,21 @@ pub struct WarehouseApiDoc;
         crate::handlers::receipt::get_receipt,
         crate::handlers::receipt::list_receipts,
         crate::handlers::receipt::validate_receipt,
+        crate::handlers::receipt::reverse_receipt,
+        // Deliveries - Pick/pack/ship
+        crate::handlers::delivery::pick_items,
+        crate::handlers::delivery::pack_items,
+        crate::handlers::delivery::ship_items,
+        // Feature flags - Tenant admin
+        crate::handlers::feature_flags::list_feature_flags,
+        crate::handlers::feature_flags::get_feature_flag,
+        crate::handlers::feature_flags::set_feature_flag,
+        // Tenant administration
+        crate::handlers::tenants::seed_tenant_defaults,
+        crate::handlers::tenants::export_tenant_data,
+        // Inventory snapshots
+        crate::handlers::snapshots::create_snapshot,
+        crate::handlers::snapshots::restore_snapshot,
         // Lot Serial - Full operations
         crate::handlers::lot_serial::create_lot_serial,
         crate::handlers::lot_serial::get_lot_serial,
@@ -437,14 +544,18 @@ pub struct WarehouseApiDoc;
         crate::handlers::quality::list_qc_points,
         crate::handlers::quality::update_qc_point,
         crate::handlers::quality::delete_qc_point,
+        crate::handlers::quality::list_quality_checks,
+        crate::handlers::quality::record_quality_check_result,
         // Reconciliation - Full operations
         crate::handlers::reconciliation::create_reconciliation,
+        crate::handlers::reconciliation::import_reconciliation,
         crate::handlers::reconciliation::count_reconciliation,
         crate::handlers::reconciliation::finalize_reconciliation,
         crate::handlers::reconciliation::approve_reconciliation,
         crate::handlers::reconciliation::list_reconciliations,
         crate::handlers::reconciliation::get_reconciliation,
         crate::handlers::reconciliation::get_reconciliation_analytics,
+        crate::handlers::reconciliation::get_reconciliation_variance_trend,
         crate::handlers::reconciliation::get_variance_analysis,
         crate::handlers::reconciliation::scan_barcode,
         // Replenishment - Full operations
@@ -455,12 +566,18 @@ pub struct WarehouseApiDoc;
         crate::handlers::replenishment::list_reorder_rules_for_product,
         crate::handlers::replenishment::run_replenishment_check,
         crate::handlers::replenishment::check_product_replenishment,
+        crate::handlers::replenishment::create_internal_replenishment_rule,
+        crate::handlers::replenishment::list_internal_replenishment_rules,
+        crate::handlers::replenishment::delete_internal_replenishment_rule,
+        crate::handlers::replenishment::suggest_internal_transfers,
+        crate::handlers::replenishment::create_internal_transfer_drafts,
         // Reports - Full operations
         crate::handlers::reports::get_stock_ledger,
         crate::handlers::reports::get_stock_aging,
         crate::handlers::reports::get_inventory_turnover,
         crate::handlers::reports::get_low_stock,
         crate::handlers::reports::get_dead_stock,
+        crate::handlers::reports::get_capacity_report,
         // RMA - Full operations
         crate::handlers::rma::create_rma,
         crate::handlers::rma::approve_rma,
@@ -469,6 +586,8 @@ pub struct WarehouseApiDoc;
         crate::handlers::transfer::create_transfer,
         crate::handlers::transfer::confirm_transfer,
         crate::handlers::transfer::receive_transfer,
+        crate::handlers::transfer::update_transfer_tracking,
+        crate::handlers::transfer::list_in_transit_transfers,
         // Valuation - Full operations
         crate::handlers::valuation::get_valuation,
         crate::handlers::valuation::get_valuation_history,
@@ -477,27 +596,40 @@ pub struct WarehouseApiDoc;
         crate::handlers::valuation::set_standard_cost,
         crate::handlers::valuation::adjust_cost,
         crate::handlers::valuation::revalue_inventory,
+        crate::handlers::valuation::rebuild_layers,
     ),
     components(
         schemas(
             // Health
             HealthResp,
+            ReadinessResp,
+            DependencyStatus,
             // Categories
             CategoryCreateRequest,
             CategoryUpdateRequest,
+            CategoryPatchRequest,
             CategoryResponse,
             CategoryListResponse,
             CategoryStatsResponse,
             BulkCategoryIds,
             BulkOperationResponse,
             MoveToCategoryRequest,
+            CategoryExportDocument,
+            CategoryExportItem,
+            CategoryImportResponse,
+            CategoryImportMapping,
+            CategoryImportConflict,
+            CategoryImportStatus,
             inventory_service_core::domains::category::CategoryBreadcrumb,
             // Products
             ProductCreateRequest,
             ProductResponse,
             ProductListResponse,
             ProductUpdateRequest,
+            ProductPatchRequest,
             ProductListQuery,
+            SetProductAttributesRequest,
+            ProductAttributesResponse,
             // Warehouses
             CreateWarehouseRequest,
             WarehouseResponse,
@@ -508,11 +640,36 @@ pub struct WarehouseApiDoc;
             WarehouseErrorResponse,
             // Receipts
             ReceiptCreateRequest,
+            ReceiptReverseRequest,
             ReceiptResponse,
             ReceiptListResponse,
             ReceiptItemCreateRequest,
             ReceiptItemResponse,
             ReceiptSummaryResponse,
+            // Deliveries
+            PickItemsRequest,
+            PickItemRequest,
+            PickItemsResponse,
+            PackItemsRequest,
+            PackItemsResponse,
+            ShipItemsRequest,
+            ShipItemRequest,
+            ShipItemsResponse,
+            ShipmentRemainingLine,
+            // Feature flags
+            inventory_service_core::domains::inventory::feature_flag::FeatureFlag,
+            FeatureFlagDto,
+            FeatureFlagListResponse,
+            SetFeatureFlagRequest,
+            // Tenant onboarding
+            SeededEntity,
+            TenantSeedResponse,
+            TenantExportEntity,
+            TenantExportEntityCount,
+            TenantExportResponse,
+            // Inventory snapshots
+            InventorySnapshotResponse,
+            SnapshotRestoreResponse,
             // Lot Serial
             LotSerial,
             LotSerialLifecycle,
@@ -533,9 +690,15 @@ pub struct WarehouseApiDoc;
             CreateQualityControlPoint,
             QualityControlPoint,
             UpdateQualityControlPoint,
+            QualityCheck,
+            QcStatus,
+            RecordQualityCheckResult,
             // Reconciliation
             CreateReconciliationRequest,
             CreateReconciliationResponse,
+            ImportReconciliationRequest,
+            ImportReconciliationResponse,
+            ReconciliationSnapshotRow,
             CountReconciliationRequest,
             CountReconciliationResponse,
             FinalizeReconciliationRequest,
@@ -546,6 +709,9 @@ pub struct WarehouseApiDoc;
             ReconciliationDetailResponse,
 
             ReconciliationAnalyticsResponse,
+            ReconciliationTrendResponse,
+            ReconciliationTrendPeriod,
+            TrendGroupBy,
             VarianceAnalysisResponse,
             ScanBarcodeRequest,
             ScanBarcodeResponse,
@@ -553,6 +719,9 @@ pub struct WarehouseApiDoc;
             CreateReorderRule,
             ReplenishmentCheckResult,
             UpdateReorderRule,
+            InternalReplenishmentRule,
+            CreateInternalReplenishmentRule,
+            InternalTransferSuggestion,
             // Reports
             StockLedgerQuery,
             StockLedgerEntry,
@@ -564,6 +733,12 @@ pub struct WarehouseApiDoc;
             LowStockEntry,
             DeadStockQuery,
             DeadStockEntry,
+            inventory_service_core::dto::reports::CapacityBasis,
+            inventory_service_core::dto::reports::CapacityReportQuery,
+            inventory_service_core::dto::reports::CapacityReportResponse,
+            inventory_service_core::dto::reports::WarehouseCapacityEntry,
+            inventory_service_core::dto::reports::ZoneCapacityEntry,
+            inventory_service_core::dto::reports::LocationCapacityEntry,
             // RMA
             CreateRmaRequest,
             CreateRmaResponse,
@@ -578,6 +753,9 @@ pub struct WarehouseApiDoc;
             ConfirmTransferResponse,
             ReceiveTransferRequest,
             ReceiveTransferResponse,
+            TransferResponse,
+            UpdateTransferTrackingRequest,
+            ListInTransitTransfersResponse,
             // Valuation
             ValuationDto,
             ValuationHistoryResponse,
@@ -598,10 +776,14 @@ pub struct WarehouseApiDoc;
         (name = "products", description = "Product management endpoints"),
         (name = "warehouses", description = "Warehouse management endpoints"),
         (name = "receipts", description = "Goods receipt note operations"),
+        (name = "deliveries", description = "Delivery order pick/pack/ship operations"),
+        (name = "feature-flags", description = "Per-tenant feature flag administration"),
+        (name = "tenants", description = "Tenant onboarding defaults and data export"),
+        (name = "snapshots", description = "Inventory level snapshots and point-in-time restore"),
         (name = "lot-serial", description = "Lot serial management endpoints"),
         (name = "picking", description = "Warehouse picking and optimization operations"),
         (name = "putaway", description = "Putaway and storage location operations"),
-        (name = "quality", description = "Quality control point management"),
+        (name = "quality", description = "Quality control points and incoming quality checks"),
         (name = "reconciliation", description = "Inventory reconciliation operations"),
         (name = "replenishment", description = "Automatic replenishment rules"),
         (name = "reports", description = "Inventory reports and analytics"),
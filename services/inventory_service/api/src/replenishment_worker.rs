@@ -0,0 +1,114 @@
+//! Scheduled replenishment check worker
+//!
+//! This module contains the background worker that periodically runs the
+//! replenishment check for every active tenant and emits a
+//! `ReorderTriggeredEvent` for rules below their reorder point. Each rule
+//! fires at most once per cooldown window.
+
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use inventory_service_core::services::ReplenishmentService;
+use inventory_service_infra::repositories::{
+    PgInternalReplenishmentRuleRepository, PgInventoryLevelRepository, PgReorderRuleRepository,
+};
+use inventory_service_infra::services::{PgReplenishmentService, ReorderEventPublisher};
+use shared_error::AppError;
+use shared_events::NatsClient;
+
+/// Configuration for the replenishment worker
+#[derive(Debug, Clone)]
+pub struct ReplenishmentWorkerConfig {
+    /// How often to run the check (in seconds)
+    pub interval_seconds: u64,
+    /// Minimum time between events for the same rule (in seconds)
+    pub cooldown_seconds: u64,
+}
+
+impl Default for ReplenishmentWorkerConfig {
+    fn default() -> Self {
+        Self {
+            interval_seconds: 900,
+            cooldown_seconds: 86400,
+        }
+    }
+}
+
+/// Build the replenishment service the worker runs, publishing through `nats_client`
+pub fn create_replenishment_service(
+    pool: PgPool,
+    nats_client: async_nats::Client,
+) -> Arc<dyn ReplenishmentService> {
+    let publisher: Arc<dyn ReorderEventPublisher> = Arc::new(NatsClient::from(nats_client));
+    Arc::new(PgReplenishmentService::new(
+        Arc::new(PgReorderRuleRepository::new(pool.clone())),
+        Arc::new(PgInternalReplenishmentRuleRepository::new(pool.clone())),
+        Arc::new(PgInventoryLevelRepository::new(Arc::new(pool))),
+        Some(publisher),
+    ))
+}
+
+/// Start the replenishment worker
+pub async fn start_replenishment_worker(
+    pool: PgPool,
+    service: Arc<dyn ReplenishmentService>,
+    config: ReplenishmentWorkerConfig,
+) {
+    info!("Starting replenishment worker with config: {:?}", config);
+
+    let mut interval = time::interval(Duration::from_secs(config.interval_seconds));
+
+    loop {
+        interval.tick().await;
+
+        match check_all_tenants(&pool, service.as_ref(), config.cooldown_seconds).await {
+            Ok(published) if published > 0 => {
+                info!("Replenishment check published {} reorder events", published);
+            },
+            Ok(_) => {},
+            Err(e) => error!("Error running replenishment check: {}", e),
+        }
+    }
+}
+
+/// Run the scheduled replenishment check for every active tenant
+///
+/// A failure for one tenant is logged and does not stop the others.
+/// Returns the total number of published reorder events.
+pub async fn check_all_tenants(
+    pool: &PgPool,
+    service: &dyn ReplenishmentService,
+    cooldown_seconds: u64,
+) -> Result<usize, AppError> {
+    let tenant_ids: Vec<Uuid> = sqlx::query_scalar(
+        "SELECT tenant_id FROM tenants WHERE deleted_at IS NULL AND status = 'active'",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let cooldown_seconds = i64::try_from(cooldown_seconds).unwrap_or(i64::MAX);
+    let mut published = 0;
+
+    for tenant_id in tenant_ids {
+        match service
+            .run_scheduled_replenishment_check(tenant_id, cooldown_seconds)
+            .await
+        {
+            Ok(results) => {
+                published += results
+                    .iter()
+                    .filter(|r| {
+                        r.action_taken.as_deref() == Some("Reorder triggered event published")
+                    })
+                    .count();
+            },
+            Err(e) => error!("Failed to run replenishment check for tenant {}: {}", tenant_id, e),
+        }
+    }
+
+    Ok(published)
+}
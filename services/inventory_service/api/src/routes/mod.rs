@@ -9,10 +9,9 @@ mod reports;
 
 // Standard library/external crates
 use async_trait::async_trait;
-use axum::{extract::Extension, http::HeaderValue, routing::get, Router};
+use axum::{extract::Extension, routing::get, Router};
 use sqlx::PgPool;
 use std::sync::Arc;
-use tower_http::cors::{AllowOrigin, CorsLayer};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 use uuid::Uuid;
@@ -21,6 +20,14 @@ use uuid::Uuid;
 use shared_auth::enforcer::create_enforcer;
 use shared_config::Config;
 use shared_error::AppError;
+use shared_events::NatsClient;
+
+// Inventory-service core - list pagination limits
+use inventory_service_core::dto::common::{DateRangeLimits, PageSizeLimits};
+
+// Inventory-service core - tenant onboarding seed
+use inventory_service_core::dto::tenant_provisioning::TenantSeedConfig;
+use inventory_service_core::services::tenant_provisioning::TenantProvisioningService;
 
 // Inventory-service core - DTOs and traits for delivery stub
 use inventory_service_core::dto::delivery::{
@@ -28,28 +35,33 @@ use inventory_service_core::dto::delivery::{
     ShipItemsResponse,
 };
 use inventory_service_core::services::delivery::DeliveryService;
+use inventory_service_core::services::FeatureFlagCache;
 
 // Inventory-service infra - Repository implementations
 use inventory_service_infra::repositories::{
     CategoryRepositoryImpl, LandedCostAllocationRepositoryImpl, LandedCostDocumentRepositoryImpl,
-    LandedCostLineRepositoryImpl, LotSerialRepositoryImpl, PgInventoryLevelRepository,
+    LandedCostLineRepositoryImpl, LotSerialRepositoryImpl, PgDeliveryOrderItemRepository,
+    PgDeliveryOrderRepository, PgInternalReplenishmentRuleRepository, PgInventoryLevelRepository,
     PgPutawayRepository, PgQualityControlPointRepository, PgReorderRuleRepository,
     PgRmaItemRepository, PgRmaRepository, PgStockMoveRepository,
     PgStockReconciliationItemRepository, PgStockReconciliationRepository,
-    PgStockTakeLineRepository, PgStockTakeRepository, PgTransferItemRepository,
-    PgTransferRepository, PickingMethodRepositoryImpl, ProductImageRepositoryImpl,
-    ProductRepositoryImpl, ProductVariantRepositoryImpl, ReceiptRepositoryImpl,
-    ValuationRepositoryImpl, ValuationSettingsRepositoryImpl, WarehouseRepositoryImpl,
+    PgStockTakeLineRepository, PgStockTakeRepository, PgTenantSettingsRepository,
+    PgTransferItemRepository, PgTransferRepository, PickingMethodRepositoryImpl,
+    ProductImageRepositoryImpl, ProductRepositoryImpl, ProductVariantRepositoryImpl,
+    ReceiptRepositoryImpl, ValuationRepositoryImpl, ValuationSettingsRepositoryImpl,
+    WarehouseRepositoryImpl,
 };
 
 // Inventory-service infra - Service implementations
 use inventory_service_infra::services::{
-    CategoryServiceImpl, LandedCostServiceImpl, LotSerialServiceImpl, PgAdjustmentService,
+    CacheTtlConfig, CategoryServiceImpl, DeliveryServiceImpl, FeatureFlagServiceImpl,
+    LandedCostServiceImpl, LotSerialServiceImpl, PgAdjustmentService, PgInventorySnapshotService,
     PgPutawayService, PgQualityControlPointService, PgReplenishmentService, PgRmaService,
     PgScrapService, PgStockLevelsService, PgStockReconciliationService, PgStockTakeService,
-    PgTransferService, PickingMethodServiceImpl, ProductImageServiceImpl, ProductImportServiceImpl,
-    ProductServiceImpl, ProductVariantServiceImpl, ReceiptServiceImpl, RedisDistributedLockService,
-    ValuationServiceImpl,
+    PgTenantExportService, PgTenantProvisioningService, PgTransferService,
+    PickingMethodServiceImpl, ProductImageServiceImpl, ProductImportServiceImpl,
+    ProductServiceImpl, ProductVariantServiceImpl, ReceiptServiceImpl, RedisCache,
+    RedisDistributedLockService, ReorderEventPublisher, ValuationServiceImpl,
 };
 
 // Storage client for product images
@@ -60,8 +72,10 @@ use crate::handlers::adjustment::create_adjustment_routes;
 use crate::handlers::category::create_category_routes;
 use crate::handlers::cycle_count::create_cycle_count_routes;
 use crate::handlers::delivery::create_delivery_routes;
-use crate::handlers::health::health_check;
+use crate::handlers::feature_flags::create_feature_flag_routes;
+use crate::handlers::health::{health_check, readiness_check};
 use crate::handlers::landed_cost::create_landed_cost_routes;
+use crate::handlers::level_stream::create_level_stream_routes;
 use crate::handlers::lot_serial::create_lot_serial_routes;
 use crate::handlers::picking::create_picking_routes;
 use crate::handlers::product_images::create_product_image_routes;
@@ -77,14 +91,17 @@ use crate::handlers::reports::create_reports_routes;
 use crate::handlers::rma::create_rma_routes;
 use crate::handlers::scrap::create_scrap_routes;
 use crate::handlers::search::create_search_routes;
+use crate::handlers::snapshots::create_snapshot_routes;
 use crate::handlers::stock_levels::create_stock_levels_routes;
 use crate::handlers::stock_take::create_stock_take_routes;
+use crate::handlers::tenants::create_tenant_routes;
 use crate::handlers::transfer::create_transfer_routes;
 use crate::handlers::valuation::create_valuation_routes;
 use crate::handlers::warehouses::create_warehouse_routes;
+use crate::level_events::LevelChangeBroadcaster;
 use crate::openapi::ApiDoc;
 
-/// Stub delivery service used when delivery functionality is temporarily unavailable.
+/// Stub delivery service used when the `delivery_enabled` config flag is off.
 ///
 /// This implementation consistently returns a generic "delivery service is temporarily
 /// unavailable" error suitable for API consumers. Infra-specific details about the
@@ -101,7 +118,7 @@ impl DeliveryService for StubDeliveryService {
         _user_id: Uuid,
         _request: PickItemsRequest,
     ) -> Result<PickItemsResponse, AppError> {
-        tracing::warn!("Delivery pick_items called but DeliveryServiceImpl is disabled in infra");
+        tracing::warn!("Delivery pick_items called but DELIVERY_ENABLED is off");
         Err(AppError::ServiceUnavailable(
             "Delivery service is temporarily unavailable. Please try again later.".to_string(),
         ))
@@ -114,7 +131,7 @@ impl DeliveryService for StubDeliveryService {
         _user_id: Uuid,
         _request: PackItemsRequest,
     ) -> Result<PackItemsResponse, AppError> {
-        tracing::warn!("Delivery pack_items called but DeliveryServiceImpl is disabled in infra");
+        tracing::warn!("Delivery pack_items called but DELIVERY_ENABLED is off");
         Err(AppError::ServiceUnavailable(
             "Delivery service is temporarily unavailable. Please try again later.".to_string(),
         ))
@@ -127,7 +144,7 @@ impl DeliveryService for StubDeliveryService {
         _user_id: Uuid,
         _request: ShipItemsRequest,
     ) -> Result<ShipItemsResponse, AppError> {
-        tracing::warn!("Delivery ship_items called but DeliveryServiceImpl is disabled in infra");
+        tracing::warn!("Delivery ship_items called but DELIVERY_ENABLED is off");
         Err(AppError::ServiceUnavailable(
             "Delivery service is temporarily unavailable. Please try again later.".to_string(),
         ))
@@ -136,29 +153,42 @@ impl DeliveryService for StubDeliveryService {
 
 /// Create the main application router with all services wired
 ///
-/// This function performs dependency injection following the 3-crate pattern:
-/// 1. Initialize repositories from infra layer
-/// 2. Initialize services with their repository dependencies
-/// 3. Create AppState with all services
-/// 4. Wire all route modules
+/// Nothing feeds the level change stream of a router built this way; the binary
+/// uses `create_router_with_level_events` with the broadcaster forwarded from NATS.
 pub async fn create_router(pool: PgPool, config: &Config) -> Router {
-    // =========================================================================
-    // Environment & Production Checks
-    // =========================================================================
+    let authz_state = create_authz_state(config).await;
+    create_router_with_level_events(pool, config, LevelChangeBroadcaster::default(), authz_state)
+        .await
+}
+
+fn is_production() -> bool {
     let app_env = std::env::var("APP_ENV").unwrap_or_else(|_| "development".to_string());
     let rust_env = std::env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string());
-    let is_production = app_env == "production" || rust_env == "production";
+    app_env == "production" || rust_env == "production"
+}
 
-    if is_production && config.get_cors_origins().is_empty() {
-        panic!(
-            "CORS_ORIGINS must be configured in production environment. \
-             Set CORS_ORIGINS=https://your-domain.com,https://admin.your-domain.com"
-        );
+fn redis_url(config: &Config, is_production: bool) -> String {
+    if is_production {
+        config
+            .redis_url
+            .clone()
+            .expect("REDIS_URL must be configured in production")
+    } else {
+        config
+            .redis_url
+            .clone()
+            .unwrap_or_else(|| "redis://localhost:6379".to_string())
     }
+}
+
+/// Build the Casbin enforcer and access token denylist
+///
+/// The binary builds this once and shares it between the REST router and the
+/// gRPC server, so both enforce the same policies and see the same logouts.
+pub async fn create_authz_state(config: &Config) -> crate::middleware::AuthzState {
+    let is_production = is_production();
+    let redis_url = redis_url(config, is_production);
 
-    // =========================================================================
-    // Initialize Casbin Enforcer
-    // =========================================================================
     let model_paths = [
         "shared/auth/model.conf",             // From workspace root
         "../../../shared/auth/model.conf",    // From services/inventory_service/api
@@ -185,20 +215,55 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
         .await
         .expect("Failed to initialize Casbin enforcer");
 
+    // Access tokens revoked at logout by the user service
+    let token_denylist: Arc<dyn shared_jwt::TokenDenylist> =
+        match shared_jwt::RedisTokenDenylist::new(&redis_url).await {
+            Ok(denylist) => Arc::new(denylist),
+            Err(e) if is_production => panic!("Failed to initialize token denylist: {}", e),
+            Err(e) => {
+                tracing::warn!("Token denylist falling back to memory, logouts not seen: {}", e);
+                Arc::new(shared_jwt::InMemoryTokenDenylist::new())
+            },
+        };
+
+    crate::middleware::AuthzState {
+        enforcer,
+        jwt_secret: config.jwt_secret.clone(),
+        denylist: token_denylist,
+    }
+}
+
+/// Create the main application router, streaming level changes from `level_events`
+///
+/// This function performs dependency injection following the 3-crate pattern:
+/// 1. Initialize repositories from infra layer
+/// 2. Initialize services with their repository dependencies
+/// 3. Create AppState with all services
+/// 4. Wire all route modules
+pub async fn create_router_with_level_events(
+    pool: PgPool,
+    config: &Config,
+    level_events: LevelChangeBroadcaster,
+    authz_state: crate::middleware::AuthzState,
+) -> Router {
+    // =========================================================================
+    // Environment & Production Checks
+    // =========================================================================
+    let is_production = is_production();
+
+    if is_production && config.get_cors_origins().is_empty() {
+        panic!(
+            "CORS_ORIGINS must be configured in production environment. \
+             Set CORS_ORIGINS=https://your-domain.com,https://admin.your-domain.com"
+        );
+    }
+
+    let enforcer = authz_state.enforcer.clone();
+
     // =========================================================================
     // Initialize Redis URL
     // =========================================================================
-    let redis_url = if is_production {
-        config
-            .redis_url
-            .clone()
-            .expect("REDIS_URL must be configured in production")
-    } else {
-        config
-            .redis_url
-            .clone()
-            .unwrap_or_else(|| "redis://localhost:6379".to_string())
-    };
+    let redis_url = redis_url(config, is_production);
 
     // =========================================================================
     // Initialize Idempotency State
@@ -207,12 +272,17 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
         redis_url: redis_url.clone(),
         ttl_seconds: 24 * 60 * 60, // 24 hours
         header_name: "x-idempotency-key".to_string(),
+        in_flight_stale_seconds: config.idempotency_in_flight_stale_seconds,
+        ..Default::default()
     };
     let idempotency_state = Arc::new(
         crate::middleware::IdempotencyState::new(idempotency_config)
             .expect("Failed to initialize idempotency state"),
     );
 
+    // Clear keys left in flight by crashed requests (stale threshold 0 disables it)
+    tokio::spawn(crate::middleware::start_idempotency_watchdog(idempotency_state.clone()));
+
     // =========================================================================
     // Phase 1: Initialize Base Repositories
     // =========================================================================
@@ -264,6 +334,8 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
 
     // Replenishment - needs PgPool (not Arc)
     let reorder_rule_repo = Arc::new(PgReorderRuleRepository::new(pool.clone()));
+    let internal_replenishment_rule_repo =
+        Arc::new(PgInternalReplenishmentRuleRepository::new(pool.clone()));
 
     // Quality - needs PgPool (not Arc)
     let quality_repo = Arc::new(PgQualityControlPointRepository::new(pool.clone()));
@@ -289,15 +361,50 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
             .expect("Failed to create Redis distributed lock service"),
     );
 
+    // Feature flag and product lookups are cached in Redis; without it they read the database directly
+    let cache_ttls = CacheTtlConfig {
+        product_ttl: config
+            .product_cache_ttl_seconds
+            .map(std::time::Duration::from_secs),
+        inventory_ttl: config
+            .inventory_cache_ttl_seconds
+            .map(std::time::Duration::from_secs),
+        category_ttl: config
+            .category_cache_ttl_seconds
+            .map(std::time::Duration::from_secs),
+    };
+    let redis_cache = match RedisCache::new(&redis_url, cache_ttls).await {
+        Ok(cache) => Some(Arc::new(cache)),
+        Err(e) => {
+            tracing::warn!("Feature flag and product caches disabled: {}", e);
+            None
+        },
+    };
+    let feature_flag_cache = redis_cache
+        .clone()
+        .map(|cache| cache as Arc<dyn FeatureFlagCache>);
+
     // =========================================================================
     // Phase 3: Initialize Services with Dependencies
     // =========================================================================
 
     // Category Service
-    let category_service = Arc::new(CategoryServiceImpl::new(category_repo));
+    let category_service = {
+        let service = CategoryServiceImpl::new(category_repo);
+        Arc::new(match redis_cache.clone() {
+            Some(cache) => service.with_product_cache(cache),
+            None => service,
+        })
+    };
 
     // Product Service
-    let product_service = Arc::new(ProductServiceImpl::new(product_repo.clone()));
+    let product_service = {
+        let service = ProductServiceImpl::new(product_repo.clone());
+        Arc::new(match redis_cache {
+            Some(cache) => service.with_cache(cache),
+            None => service,
+        })
+    };
 
     // Product Image Service (with RustFS storage)
     let storage_client = Arc::new(
@@ -307,7 +414,7 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
     );
     let product_image_repo = Arc::new(ProductImageRepositoryImpl::new(pool.clone()));
     let product_image_service =
-        Arc::new(ProductImageServiceImpl::new(product_image_repo, storage_client));
+        Arc::new(ProductImageServiceImpl::new(product_image_repo, storage_client.clone()));
 
     // Product Import/Export Service
     let product_import_service = Arc::new(ProductImportServiceImpl::new(product_repo.clone()));
@@ -335,9 +442,9 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
 
     // Transfer Service
     let transfer_service = Arc::new(PgTransferService::new(
+        pool_arc.clone(),
         transfer_repo,
         transfer_item_repo,
-        stock_move_repo.clone(),
         inventory_level_repo.clone(),
         warehouse_repo.clone(),
     ));
@@ -373,11 +480,22 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
     // RMA Service
     let rma_service = Arc::new(PgRmaService::new(rma_repo, rma_item_repo, stock_move_repo.clone()));
 
-    // Replenishment Service
+    // Replenishment Service (reorder events are published only when NATS is configured)
+    let reorder_event_publisher = match &config.nats_url {
+        Some(nats_url) => match NatsClient::connect(nats_url).await {
+            Ok(client) => Some(Arc::new(client) as Arc<dyn ReorderEventPublisher>),
+            Err(e) => {
+                tracing::warn!("Reorder events disabled, NATS unavailable: {}", e);
+                None
+            },
+        },
+        None => None,
+    };
     let replenishment_service = Arc::new(PgReplenishmentService::new(
         reorder_rule_repo,
+        internal_replenishment_rule_repo,
         inventory_level_repo.clone(),
-        None, // NATS client - optional for now
+        reorder_event_publisher,
     ));
 
     // Quality Service
@@ -389,10 +507,10 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
     // with the same pool_arc is resource-equivalent to the existing stock_move_repo.
     // TODO: Consider updating PgPutawayService to accept Arc<PgStockMoveRepository>
     // for consistency with other services (transfer, stock_take, reconciliation, rma).
-    let putaway_service = Arc::new(PgPutawayService::new(
-        putaway_repo,
-        PgStockMoveRepository::new(pool_arc.clone()),
-    ));
+    let putaway_service = Arc::new(
+        PgPutawayService::new(putaway_repo, PgStockMoveRepository::new(pool_arc.clone()))
+            .with_warehouse_repository(warehouse_repo.clone()),
+    );
 
     // Valuation Service
     let valuation_service = Arc::new(ValuationServiceImpl::new(
@@ -409,8 +527,17 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
         landed_cost_allocation_repo,
     ));
 
-    // Delivery Service (stub - implementation disabled in infra)
-    let delivery_service = Arc::new(StubDeliveryService);
+    // Delivery Service (real implementation only when the feature flag is on)
+    let delivery_service: Arc<dyn DeliveryService> = if config.delivery_enabled {
+        Arc::new(DeliveryServiceImpl::new(
+            Arc::new(PgDeliveryOrderRepository::new(pool_arc.clone())),
+            Arc::new(PgDeliveryOrderItemRepository::new(pool_arc.clone())),
+            stock_move_repo.clone(),
+            inventory_level_repo.clone(),
+        ))
+    } else {
+        Arc::new(StubDeliveryService)
+    };
 
     // Scrap Service
     let scrap_service = Arc::new(PgScrapService::new(pool_arc.clone()));
@@ -421,6 +548,34 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
     // Adjustment Service
     let adjustment_service = Arc::new(PgAdjustmentService::new(pool_arc.clone()));
 
+    // Feature Flag Service
+    let feature_flag_service = Arc::new(FeatureFlagServiceImpl::new(
+        Arc::new(PgTenantSettingsRepository::new(pool.clone())),
+        feature_flag_cache,
+    ));
+
+    // Tenant Provisioning Service
+    let tenant_seed_config = TenantSeedConfig::new(
+        &config.tenant_seed_warehouse_code,
+        &config.tenant_seed_warehouse_name,
+        &config.tenant_seed_category_name,
+        &config.tenant_seed_removal_strategy,
+    )
+    .expect("Invalid tenant seed configuration");
+    let tenant_provisioning_service: Arc<dyn TenantProvisioningService> =
+        Arc::new(PgTenantProvisioningService::new(pool_arc.clone(), tenant_seed_config));
+
+    // Tenant Export Service (shares the product image storage client)
+    let tenant_export_service = Arc::new(PgTenantExportService::new(
+        pool_arc.clone(),
+        storage_client,
+        std::time::Duration::from_secs(config.tenant_export_cooldown_seconds),
+        std::time::Duration::from_secs(config.tenant_export_url_ttl_seconds),
+    ));
+
+    // Inventory Snapshot Service
+    let inventory_snapshot_service = Arc::new(PgInventorySnapshotService::new(pool_arc.clone()));
+
     // =========================================================================
     // Phase 4: Create AppState with All Services
     // =========================================================================
@@ -448,50 +603,25 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
         adjustment_service,
         stock_levels_service,
         landed_cost_service,
-        distributed_lock_service,
+        distributed_lock_service: distributed_lock_service.clone(),
+        feature_flag_service,
+        tenant_provisioning_service: tenant_provisioning_service.clone(),
+        tenant_export_service,
+        inventory_snapshot_service,
         enforcer: enforcer.clone(),
         jwt_secret: config.jwt_secret.clone(),
         idempotency_state: idempotency_state.clone(),
+        page_limits: PageSizeLimits::new(config.default_page_size, config.max_page_size),
+        date_range_limits: DateRangeLimits::new(
+            config.report_default_range_days,
+            config.report_max_range_days,
+        ),
     };
 
     // =========================================================================
     // Phase 5: Configure CORS
     // =========================================================================
-    let cors = CorsLayer::new()
-        .allow_origin({
-            let origins = config.get_cors_origins();
-            if origins.is_empty() {
-                AllowOrigin::any()
-            } else {
-                let header_values: Result<Vec<HeaderValue>, _> = origins
-                    .into_iter()
-                    .map(|origin| {
-                        HeaderValue::from_str(&origin)
-                            .map_err(|e| format!("Invalid CORS origin '{}': {}", origin, e))
-                    })
-                    .collect();
-
-                match header_values {
-                    Ok(values) => AllowOrigin::list(values),
-                    Err(e) => {
-                        panic!("CORS configuration error: {}", e);
-                    },
-                }
-            }
-        })
-        .allow_methods([
-            axum::http::Method::GET,
-            axum::http::Method::POST,
-            axum::http::Method::PUT,
-            axum::http::Method::DELETE,
-            axum::http::Method::PATCH,
-        ])
-        .allow_headers([
-            axum::http::header::CONTENT_TYPE,
-            axum::http::header::AUTHORIZATION,
-            axum::http::header::HeaderName::from_static("x-idempotency-key"),
-            axum::http::header::HeaderName::from_static("x-tenant-id"),
-        ]);
+    let cors = shared_http::build_cors(config);
 
     // =========================================================================
     // Phase 6: Wire All Routes
@@ -504,7 +634,11 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
         // Product images (nested under products)
         .nest(
             "/api/v1/inventory/products/{product_id}/images",
-            create_product_image_routes(),
+            create_product_image_routes(crate::middleware::ConcurrencyLimit::new(
+                "uploads",
+                config.max_concurrent_uploads,
+                std::time::Duration::from_millis(config.upload_queue_timeout_ms),
+            )),
         )
         // Product import/export
         .nest(
@@ -550,28 +684,56 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
             create_replenishment_routes(),
         )
         // Reports
-        .nest("/api/v1/inventory/reports", create_reports_routes())
+        .nest(
+            "/api/v1/inventory/reports",
+            create_reports_routes().layer(axum::middleware::from_fn_with_state(
+                crate::middleware::ConcurrencyLimit::new(
+                    "reports",
+                    config.report_max_concurrency,
+                    std::time::Duration::from_millis(config.report_queue_timeout_ms),
+                ),
+                crate::middleware::concurrency_limit_middleware,
+            )),
+        )
         // Search
         .nest("/api/v1/inventory/search", create_search_routes())
         // Scrap management
         .nest("/api/v1/inventory/scrap", create_scrap_routes())
         // Stock levels
         .nest("/api/v1/inventory/stock-levels", create_stock_levels_routes())
+        // Live level changes (SSE)
+        .nest("/api/v1/inventory/levels", create_level_stream_routes())
         // Stock adjustments
-        .nest("/api/v1/inventory/adjustments", create_adjustment_routes());
+        .nest("/api/v1/inventory/adjustments", create_adjustment_routes())
+        // Inventory snapshots and restore
+        .nest("/api/v1/inventory/snapshots", create_snapshot_routes())
+        // Tenant feature flags
+        .nest(
+            "/api/v1/inventory/feature-flags",
+            create_feature_flag_routes(),
+        )
+        // Tenant onboarding
+        .nest("/api/v1/inventory/tenants", create_tenant_routes());
 
     // =========================================================================
     // Phase 7: Apply Middleware Layers
     // =========================================================================
-    let authz_state = crate::middleware::AuthzState {
-        enforcer: enforcer.clone(),
-        jwt_secret: config.jwt_secret.clone(),
-    };
 
-    let protected_routes_with_layers = protected_routes
+    let mut protected_routes = protected_routes
         .layer(Extension(pool.clone()))
         .layer(Extension(config.clone()))
         .layer(Extension(state))
+        .layer(Extension(level_events));
+
+    // Seed each tenant's defaults on its first authenticated request
+    if config.tenant_seed_on_first_access {
+        protected_routes = protected_routes.layer(axum::middleware::from_fn_with_state(
+            crate::middleware::TenantSeedState::new(tenant_provisioning_service),
+            crate::middleware::tenant_seed_middleware,
+        ));
+    }
+
+    let protected_routes_with_layers = protected_routes
         .layer(axum::middleware::from_fn_with_state(
             idempotency_state,
             crate::middleware::idempotency_middleware,
@@ -584,9 +746,12 @@ pub async fn create_router(pool: PgPool, config: &Config) -> Router {
     // =========================================================================
     Router::new()
         .route("/health", get(health_check))
+        .route("/health/ready", get(readiness_check))
         .merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", ApiDoc::openapi()))
         .merge(protected_routes_with_layers)
         .layer(Extension(pool.clone()))
         .layer(Extension(config.clone()))
+        .layer(Extension(distributed_lock_service))
+        .layer(axum::middleware::from_fn(crate::middleware::correlation_id_middleware))
         .layer(cors)
 }
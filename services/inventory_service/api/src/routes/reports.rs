@@ -12,4 +12,5 @@ pub fn create_reports_routes() -> Router {
         .route("/turnover", get(get_inventory_turnover))
         .route("/low-stock", get(get_low_stock))
         .route("/dead-stock", get(get_dead_stock))
+        .route("/capacity", get(get_capacity_report))
 }
@@ -0,0 +1,93 @@
+//! Graceful shutdown
+//!
+//! Waits for Ctrl+C or SIGTERM, then cancels a shared [`CancellationToken`] so
+//! the HTTP server stops accepting connections and background workers finish
+//! their current batch. In-flight HTTP requests are counted so the drain can
+//! be logged.
+
+use axum::{extract::Request, extract::State, middleware::Next, response::Response};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+/// How long in-flight requests get to finish after the shutdown signal
+///
+/// Kept below the default Kubernetes termination grace period (30s) so the
+/// process exits on its own before it is killed. Long-lived SSE streams never
+/// finish by themselves and are cut off when this elapses.
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(25);
+
+/// Number of HTTP requests currently being handled
+#[derive(Clone, Default)]
+pub struct InFlightRequests(Arc<AtomicUsize>);
+
+impl InFlightRequests {
+    pub fn current(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Decrements the in-flight counter when the request finishes or is dropped
+struct InFlightGuard(InFlightRequests);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        (self.0).0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Middleware counting requests in flight
+pub async fn track_in_flight(
+    State(in_flight): State<InFlightRequests>,
+    request: Request,
+    next: Next,
+) -> Response {
+    in_flight.0.fetch_add(1, Ordering::SeqCst);
+    let _guard = InFlightGuard(in_flight);
+    next.run(request).await
+}
+
+/// Resolve once Ctrl+C or (on Unix) SIGTERM is received
+async fn wait_for_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            },
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            },
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received Ctrl+C"),
+        _ = terminate => info!("Received SIGTERM"),
+    }
+}
+
+/// Cancel `shutdown` when a termination signal arrives
+///
+/// Returns the number of requests that were in flight at that moment, i.e.
+/// the ones the server will drain.
+pub async fn cancel_on_signal(shutdown: CancellationToken, in_flight: InFlightRequests) -> usize {
+    wait_for_signal().await;
+    let draining = in_flight.current();
+    info!("Shutting down: draining {} in-flight requests", draining);
+    shutdown.cancel();
+    draining
+}
@@ -4,6 +4,7 @@
 
 use std::sync::Arc;
 
+use inventory_service_core::dto::common::{DateRangeLimits, PageSizeLimits};
 use inventory_service_core::repositories::putaway::PutawayService;
 use inventory_service_core::repositories::warehouse::WarehouseRepository;
 use inventory_service_core::services::adjustment::AdjustmentService;
@@ -11,6 +12,8 @@ use inventory_service_core::services::category::CategoryService;
 use inventory_service_core::services::cycle_count::CycleCountingService;
 use inventory_service_core::services::delivery::DeliveryService;
 use inventory_service_core::services::distributed_lock::DistributedLockService;
+use inventory_service_core::services::feature_flags::FeatureFlagService;
+use inventory_service_core::services::inventory_snapshot::InventorySnapshotService;
 use inventory_service_core::services::landed_cost::LandedCostService;
 use inventory_service_core::services::lot_serial::LotSerialService;
 use inventory_service_core::services::picking_method::PickingMethodService;
@@ -26,6 +29,8 @@ use inventory_service_core::services::rma::RmaService;
 use inventory_service_core::services::scrap::ScrapService;
 use inventory_service_core::services::stock_levels::StockLevelsService;
 use inventory_service_core::services::stock_take::StockTakeService;
+use inventory_service_core::services::tenant_export::TenantExportService;
+use inventory_service_core::services::tenant_provisioning::TenantProvisioningService;
 use inventory_service_core::services::transfer::TransferService;
 use inventory_service_core::services::valuation::ValuationService;
 
@@ -60,9 +65,15 @@ pub struct AppState {
     pub stock_levels_service: Arc<dyn StockLevelsService>,
     pub landed_cost_service: Arc<dyn LandedCostService>,
     pub distributed_lock_service: Arc<dyn DistributedLockService>,
+    pub feature_flag_service: Arc<dyn FeatureFlagService>,
+    pub tenant_provisioning_service: Arc<dyn TenantProvisioningService>,
+    pub tenant_export_service: Arc<dyn TenantExportService>,
+    pub inventory_snapshot_service: Arc<dyn InventorySnapshotService>,
     pub enforcer: SharedEnforcer,
     pub jwt_secret: String,
     pub idempotency_state: Arc<IdempotencyState>,
+    pub page_limits: PageSizeLimits,
+    pub date_range_limits: DateRangeLimits,
 }
 
 impl Clone for AppState {
@@ -92,9 +103,15 @@ impl Clone for AppState {
             stock_levels_service: self.stock_levels_service.clone(),
             landed_cost_service: self.landed_cost_service.clone(),
             distributed_lock_service: self.distributed_lock_service.clone(),
+            feature_flag_service: self.feature_flag_service.clone(),
+            tenant_provisioning_service: self.tenant_provisioning_service.clone(),
+            tenant_export_service: self.tenant_export_service.clone(),
+            inventory_snapshot_service: self.inventory_snapshot_service.clone(),
             enforcer: self.enforcer.clone(),
             jwt_secret: self.jwt_secret.clone(),
             idempotency_state: self.idempotency_state.clone(),
+            page_limits: self.page_limits,
+            date_range_limits: self.date_range_limits,
         }
     }
 }
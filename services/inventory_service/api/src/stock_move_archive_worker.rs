@@ -0,0 +1,75 @@
+//! Stock move archival worker
+//!
+//! This module contains the background worker that periodically moves stock
+//! moves older than the retention window from `stock_moves` into
+//! `stock_moves_archive`, so ledger and report queries on the hot table do not
+//! slow down as history grows.
+
+use chrono::{Duration as ChronoDuration, Utc};
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+
+use inventory_service_infra::repositories::stock::PgStockMoveRepository;
+use shared_error::AppError;
+
+/// Configuration for the stock move archive worker
+#[derive(Debug, Clone)]
+pub struct StockMoveArchiveWorkerConfig {
+    /// How often to archive old moves (in seconds)
+    pub interval_seconds: u64,
+    /// Age in days after which a move is archived
+    pub retention_days: u32,
+    /// Moves archived per statement, so one run never holds a long transaction
+    pub batch_size: i64,
+}
+
+impl Default for StockMoveArchiveWorkerConfig {
+    fn default() -> Self {
+        Self {
+            interval_seconds: 86400,
+            retention_days: 730,
+            batch_size: 1000,
+        }
+    }
+}
+
+/// Start the stock move archive worker
+pub async fn start_stock_move_archive_worker(pool: PgPool, config: StockMoveArchiveWorkerConfig) {
+    info!("Starting stock move archive worker with config: {:?}", config);
+
+    let mut interval = time::interval(Duration::from_secs(config.interval_seconds));
+
+    loop {
+        interval.tick().await;
+
+        match archive_expired_moves(&pool, config.retention_days, config.batch_size).await {
+            Ok(archived) if archived > 0 => {
+                info!("Stock move archive worker archived {} moves", archived);
+            },
+            Ok(_) => {},
+            Err(e) => error!("Error archiving stock moves: {}", e),
+        }
+    }
+}
+
+/// Archive every stock move older than `retention_days`, one batch at a time
+///
+/// Returns the total number of archived moves.
+pub async fn archive_expired_moves(
+    pool: &PgPool,
+    retention_days: u32,
+    batch_size: i64,
+) -> Result<u64, AppError> {
+    let cutoff = Utc::now() - ChronoDuration::days(retention_days as i64);
+    let mut archived = 0;
+
+    loop {
+        let batch = PgStockMoveRepository::archive_before(pool, cutoff, batch_size).await?;
+        archived += batch;
+        if batch < batch_size as u64 {
+            return Ok(archived);
+        }
+    }
+}
@@ -2,26 +2,51 @@
 //!
 //! This module contains the background worker that polls the event_outbox table
 //! and publishes events to NATS.
+//!
+//! Each poll claims up to `batch_size` pending rows (`FOR UPDATE SKIP LOCKED`,
+//! so several workers can run side by side), publishes them with at most
+//! `max_in_flight` publishes outstanding, and marks only the rows whose publish
+//! succeeded as published. Failed rows go back to pending, or are dead-lettered
+//! (status `dead`) once they have used up `max_attempts`, so a poison event
+//! cannot hold up the rest of the queue. A full batch is followed immediately by the next
+//! one; failed publishes back the worker off instead of retrying in a tight loop.
+//!
+//! Rows left `in_progress` by a worker that crashed or lost its database
+//! connection mid-batch are returned to pending once they have sat untouched
+//! for `in_progress_timeout_secs`.
 
 use async_nats::Client;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::{stream, StreamExt};
 use serde_json::Value;
 use sqlx::PgPool;
 use std::time::Duration;
-use tokio::time;
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use shared_error::AppError;
+use shared_events::EventEnvelope;
+
+/// Longest pause between polls while publishes keep failing
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
 
 /// Configuration for the outbox worker
 #[derive(Debug, Clone)]
 pub struct OutboxWorkerConfig {
-    /// How often to poll for new events (in seconds)
-    pub poll_interval_seconds: u64,
-    /// Maximum number of events to process in one batch
+    /// How long to wait between polls when the outbox has been drained (in milliseconds)
+    pub poll_interval_ms: u64,
+    /// Maximum number of events to claim in one batch
     pub batch_size: i32,
-    /// Maximum number of retry attempts per event
-    pub max_retries: i32,
+    /// Maximum number of publishes awaiting NATS at the same time
+    pub max_in_flight: usize,
+    /// Failed attempts after which an event is dead-lettered
+    pub max_attempts: i32,
+    /// How long a claimed event may stay in progress before it is reclaimed (in seconds)
+    pub in_progress_timeout_secs: i64,
+    /// Only claim this tenant's events; `None` claims every tenant's
+    pub tenant_id: Option<Uuid>,
     /// NATS subject prefix for events
     pub nats_subject_prefix: String,
 }
@@ -29,41 +54,104 @@ pub struct OutboxWorkerConfig {
 impl Default for OutboxWorkerConfig {
     fn default() -> Self {
         Self {
-            poll_interval_seconds: 5,
+            poll_interval_ms: 5_000,
             batch_size: 50,
-            max_retries: 3,
+            max_in_flight: 10,
+            max_attempts: 3,
+            in_progress_timeout_secs: 300,
+            tenant_id: None,
             nats_subject_prefix: "inventory.events".to_string(),
         }
     }
 }
 
+/// Destination for outbox events
+///
+/// Implemented for the NATS client; tests substitute their own.
+#[async_trait]
+pub trait OutboxPublisher: Send + Sync {
+    async fn publish(&self, subject: String, payload: Vec<u8>) -> Result<(), String>;
+}
+
+#[async_trait]
+impl OutboxPublisher for Client {
+    async fn publish(&self, subject: String, payload: Vec<u8>) -> Result<(), String> {
+        Client::publish(self, subject, payload.into())
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// What happened to one claimed batch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutboxBatchOutcome {
+    /// Rows claimed from the outbox
+    pub claimed: usize,
+    /// Rows published and marked as such
+    pub published: usize,
+    /// Rows returned to pending or dead-lettered
+    pub failed: usize,
+}
+
 /// Start the outbox worker
-pub async fn start_outbox_worker(
+///
+/// Runs until `shutdown` is cancelled. Cancellation is only observed between
+/// batches, so a claimed batch is always published before the worker exits.
+pub async fn start_outbox_worker<P: OutboxPublisher>(
     pool: PgPool,
-    nats_client: Client,
+    publisher: P,
     config: OutboxWorkerConfig,
+    shutdown: CancellationToken,
 ) -> Result<(), AppError> {
     info!("Starting outbox worker with config: {:?}", config);
 
-    let mut interval = time::interval(Duration::from_secs(config.poll_interval_seconds));
+    let poll_interval = Duration::from_millis(config.poll_interval_ms);
+    let mut delay = Duration::ZERO;
 
     loop {
-        interval.tick().await;
-
-        if let Err(e) = process_pending_events(&pool, &nats_client, &config).await {
-            error!("Error processing pending events: {}", e);
+        tokio::select! {
+            _ = shutdown.cancelled() => {
+                info!("Outbox worker stopped");
+                return Ok(());
+            },
+            _ = tokio::time::sleep(delay) => {},
         }
+
+        delay = match process_outbox_batch(&pool, &publisher, &config).await {
+            Ok(outcome) if outcome.failed > 0 => {
+                let backoff = next_backoff(delay, poll_interval);
+                warn!(
+                    "{} of {} outbox events failed to publish, backing off for {:?}",
+                    outcome.failed, outcome.claimed, backoff
+                );
+                backoff
+            },
+            // More rows are probably waiting: go again straight away
+            Ok(outcome) if outcome.claimed >= config.batch_size.max(1) as usize => Duration::ZERO,
+            Ok(_) => poll_interval,
+            Err(e) => {
+                error!("Error processing pending events: {}", e);
+                next_backoff(delay, poll_interval)
+            },
+        };
     }
 }
 
-/// Process pending events from the outbox using atomic claim pattern
-async fn process_pending_events(
+/// Double the previous delay, starting from the poll interval and capped at [`MAX_BACKOFF`]
+fn next_backoff(previous: Duration, poll_interval: Duration) -> Duration {
+    (previous * 2).max(poll_interval).min(MAX_BACKOFF)
+}
+
+/// Claim, publish and settle one batch of pending outbox events
+pub async fn process_outbox_batch<P: OutboxPublisher + ?Sized>(
     pool: &PgPool,
-    nats_client: &Client,
+    publisher: &P,
     config: &OutboxWorkerConfig,
-) -> Result<(), AppError> {
-    // Atomically claim pending events by setting status to 'in_progress'
-    // This prevents double processing by multiple workers
+) -> Result<OutboxBatchOutcome, AppError> {
+    reclaim_stale_events(pool, config).await?;
+
+    // Atomically claim pending events by setting status to 'in_progress'.
+    // SKIP LOCKED lets concurrent workers claim disjoint batches without waiting.
     let events = sqlx::query_as!(
         EventRow,
         r#"
@@ -73,175 +161,250 @@ async fn process_pending_events(
             SELECT id
             FROM event_outbox
             WHERE status = 'pending'
+              AND ($2::uuid IS NULL OR tenant_id = $2)
             ORDER BY created_at ASC
             LIMIT $1
+            FOR UPDATE SKIP LOCKED
         ) AND status = 'pending'
-        RETURNING id, tenant_id, event_type, event_data as "event_data: _", retry_count
+        RETURNING id, tenant_id, event_type, schema_version, event_data as "event_data: _",
+            correlation_id, retry_count, created_at
         "#,
-        config.batch_size as i64
+        config.batch_size.max(1) as i64,
+        config.tenant_id
     )
     .fetch_all(pool)
     .await?;
 
+    let mut outcome = OutboxBatchOutcome {
+        claimed: events.len(),
+        ..Default::default()
+    };
     if events.is_empty() {
-        return Ok(());
+        return Ok(outcome);
     }
 
     info!("Claimed {} events for processing", events.len());
 
-    for event in events {
-        if let Err(e) = process_event(pool, nats_client, config, &event).await {
-            error!("Failed to process event {}: {}", event.id, e);
+    // Serialize the payloads inside versioned envelopes
+    let mut messages = Vec::with_capacity(events.len());
+    let mut failures = Vec::new();
+    for event in &events {
+        let envelope = outbox_envelope(
+            &event.event_type,
+            event.schema_version,
+            &event.event_data,
+            event.created_at,
+            event.correlation_id.clone(),
+        );
+        match serde_json::to_vec(&envelope) {
+            Ok(bytes) => {
+                let subject = format!(
+                    "{}.{}.{}",
+                    config.nats_subject_prefix, event.tenant_id, event.event_type
+                );
+                messages.push((event, subject, bytes));
+            },
+            Err(e) => {
+                // Treat serialization failure as retryable
+                failures.push((event, "serialize", e.to_string()));
+            },
+        }
+    }
+
+    // Publish with a bounded number of publishes outstanding
+    let results: Vec<_> = stream::iter(messages)
+        .map(|(event, subject, bytes)| async move {
+            let result = publisher.publish(subject.clone(), bytes).await;
+            (event, subject, result)
+        })
+        .buffer_unordered(config.max_in_flight.max(1))
+        .collect()
+        .await;
+
+    let mut published_ids = Vec::with_capacity(results.len());
+    for (event, subject, result) in results {
+        match result {
+            Ok(()) => {
+                info!(
+                    correlation_id = event.correlation_id.as_deref().unwrap_or_default(),
+                    "Published event {} for tenant {} to subject {}",
+                    event.id,
+                    event.tenant_id,
+                    subject
+                );
+                published_ids.push(event.id);
+            },
+            Err(e) => failures.push((event, "publish", e)),
+        }
+    }
+
+    // Only rows NATS accepted are marked as published. Settle these first so a
+    // failed failure update below cannot cause already-published events to be
+    // sent again.
+    if !published_ids.is_empty() {
+        sqlx::query!(
+            r#"
+            UPDATE event_outbox
+            SET status = 'published', published_at = NOW(), updated_at = NOW()
+            WHERE id = ANY($1)
+            "#,
+            &published_ids
+        )
+        .execute(pool)
+        .await?;
+    }
+    outcome.published = published_ids.len();
+
+    // A row whose failure cannot be recorded stays in progress until it is reclaimed
+    for (event, action, reason) in failures {
+        if let Err(e) = record_failure(pool, config, event, action, &reason).await {
+            error!(
+                event_id = %event.id,
+                "Failed to record outbox {} failure, leaving event for reclaim: {}",
+                action,
+                e
+            );
         }
+        outcome.failed += 1;
     }
 
+    Ok(outcome)
+}
+
+/// Return events stuck in progress for longer than `in_progress_timeout_secs` to pending
+///
+/// The worker holding them may already have published some, so a reclaimed
+/// event can be delivered twice (delivery is at-least-once).
+async fn reclaim_stale_events(pool: &PgPool, config: &OutboxWorkerConfig) -> Result<(), AppError> {
+    let reclaimed = sqlx::query!(
+        r#"
+        UPDATE event_outbox
+        SET status = 'pending', updated_at = NOW()
+        WHERE status = 'in_progress'
+          AND updated_at < NOW() - make_interval(secs => $1)
+          AND ($2::uuid IS NULL OR tenant_id = $2)
+        "#,
+        config.in_progress_timeout_secs as f64,
+        config.tenant_id
+    )
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    if reclaimed > 0 {
+        warn!("Reclaimed {} outbox events stuck in progress", reclaimed);
+    }
     Ok(())
 }
 
-/// Process a single event
-async fn process_event(
+/// Return a claimed event to pending, or dead-letter it once it has used up its attempts
+async fn record_failure(
     pool: &PgPool,
-    nats_client: &Client,
     config: &OutboxWorkerConfig,
     event: &EventRow,
+    action: &str,
+    reason: &str,
 ) -> Result<(), AppError> {
-    let subject =
-        format!("{}.{}.{}", config.nats_subject_prefix, event.tenant_id, event.event_type);
-
-    // Start a transaction for atomic event processing
-    let mut tx = pool.begin().await?;
-
-    // Serialize event payload
-    let event_bytes = match serde_json::to_vec(&event.event_data) {
-        Ok(bytes) => bytes,
-        Err(e) => {
-            // Treat serialization failure as retryable
-            let new_retry_count = event.retry_count + 1;
-            if new_retry_count >= config.max_retries {
-                sqlx::query!(
-                    r#"
-                    UPDATE event_outbox
-                    SET status = 'failed', retry_count = $2, error_message = $3, updated_at = NOW()
-                    WHERE id = $1
-                    "#,
-                    event.id,
-                    new_retry_count,
-                    format!(
-                        "Failed to serialize event after {} retries: {}",
-                        config.max_retries, e
-                    )
-                )
-                .execute(&mut *tx)
-                .await?;
-                tx.commit().await?;
-                error!(
-                    "Event {} for tenant {} failed permanently due to serialization error after {} retries",
-                    event.id, event.tenant_id, config.max_retries
-                );
-            } else {
-                sqlx::query!(
-                    r#"
-                    UPDATE event_outbox
-                    SET status = 'pending', retry_count = $2, error_message = $3, updated_at = NOW()
-                    WHERE id = $1
-                    "#,
-                    event.id,
-                    new_retry_count,
-                    format!("Serialization attempt {} failed: {}", new_retry_count, e)
-                )
-                .execute(&mut *tx)
-                .await?;
-                tx.commit().await?;
-                warn!(
-                    "Event {} for tenant {} serialization failed, retry count: {}",
-                    event.id, event.tenant_id, new_retry_count
-                );
-            }
-            return Ok(());
-        },
-    };
+    let attempts = event.retry_count + 1;
+    let last_error = format!("Attempt {} to {} failed: {}", attempts, action, reason);
 
-    // Publish to NATS
-    match nats_client
-        .publish(subject.clone(), event_bytes.into())
-        .await
-    {
-        Ok(_) => {
-            // Mark as published
-            sqlx::query!(
-                r#"
-                UPDATE event_outbox
-                SET status = 'published', published_at = NOW(), updated_at = NOW()
-                WHERE id = $1
-                "#,
-                event.id
-            )
-            .execute(&mut *tx)
-            .await?;
-
-            tx.commit().await?;
-            info!(
-                "Published event {} for tenant {} to subject {}",
-                event.id, event.tenant_id, subject
-            );
-        },
-        Err(e) => {
-            // Increment retry count
-            let new_retry_count = event.retry_count + 1;
-
-            if new_retry_count >= config.max_retries {
-                // Mark as failed
-                sqlx::query!(
-                    r#"
-                    UPDATE event_outbox
-                    SET status = 'failed', retry_count = $2, error_message = $3, updated_at = NOW()
-                    WHERE id = $1
-                    "#,
-                    event.id,
-                    new_retry_count,
-                    format!("Failed to publish after {} retries: {}", config.max_retries, e)
-                )
-                .execute(&mut *tx)
-                .await?;
-
-                tx.commit().await?;
-                error!(
-                    "Event {} for tenant {} failed permanently after {} retries",
-                    event.id, event.tenant_id, config.max_retries
-                );
-            } else {
-                // Update retry count
-                sqlx::query!(
-                    r#"
-                    UPDATE event_outbox
-                    SET status = 'pending', retry_count = $2, error_message = $3, updated_at = NOW()
-                    WHERE id = $1
-                    "#,
-                    event.id,
-                    new_retry_count,
-                    format!("Publish attempt {} failed: {}", new_retry_count, e)
-                )
-                .execute(&mut *tx)
-                .await?;
-
-                tx.commit().await?;
-                warn!(
-                    "Event {} for tenant {} publish failed, retry count: {}",
-                    event.id, event.tenant_id, new_retry_count
-                );
-            }
-        },
+    if attempts >= config.max_attempts {
+        sqlx::query!(
+            r#"
+            UPDATE event_outbox
+            SET status = 'dead', retry_count = $2, error_message = $3, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            event.id,
+            attempts,
+            last_error
+        )
+        .execute(pool)
+        .await?;
+        error!(
+            event_id = %event.id,
+            tenant_id = %event.tenant_id,
+            event_type = %event.event_type,
+            attempts,
+            "Dead-lettered outbox event: {}",
+            last_error
+        );
+    } else {
+        sqlx::query!(
+            r#"
+            UPDATE event_outbox
+            SET status = 'pending', retry_count = $2, error_message = $3, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            event.id,
+            attempts,
+            last_error
+        )
+        .execute(pool)
+        .await?;
+        warn!(
+            "Event {} for tenant {} {} failed, attempt {} of {}",
+            event.id, event.tenant_id, action, attempts, config.max_attempts
+        );
     }
 
     Ok(())
 }
 
+/// Wrap an outbox event's payload in the envelope published to NATS
+///
+/// The envelope keeps the enqueue time and the correlation id of the request
+/// that enqueued the event.
+pub fn outbox_envelope<'a>(
+    event_type: &str,
+    schema_version: i32,
+    event_data: &'a Value,
+    created_at: DateTime<Utc>,
+    correlation_id: Option<String>,
+) -> EventEnvelope<&'a Value> {
+    EventEnvelope {
+        event_type: event_type.to_string(),
+        schema_version: schema_version as u32,
+        data: event_data,
+        timestamp: created_at,
+        correlation_id,
+    }
+}
+
 /// Struct to represent event row from database
 #[derive(sqlx::FromRow)]
 struct EventRow {
     id: Uuid,
     tenant_id: Uuid,
     event_type: String,
+    schema_version: i32,
     event_data: Value,
+    correlation_id: Option<String>,
     retry_count: i32,
+    created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_from_poll_interval_up_to_cap() {
+        let poll = Duration::from_millis(500);
+        assert_eq!(next_backoff(Duration::ZERO, poll), poll);
+        assert_eq!(next_backoff(poll, poll), Duration::from_secs(1));
+        assert_eq!(next_backoff(Duration::from_secs(1), poll), Duration::from_secs(2));
+        assert_eq!(next_backoff(Duration::from_secs(50), poll), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_default_config() {
+        let config = OutboxWorkerConfig::default();
+        assert_eq!(config.poll_interval_ms, 5_000);
+        assert_eq!(config.batch_size, 50);
+        assert_eq!(config.max_in_flight, 10);
+        assert_eq!(config.max_attempts, 3);
+        assert_eq!(config.in_progress_timeout_secs, 300);
+        assert_eq!(config.tenant_id, None);
+    }
 }
@@ -0,0 +1,260 @@
+//! Quick Stock Adjustment Integration Tests
+//!
+//! Verifies that immediate adjustments update inventory levels and write an
+//! adjustment stock move atomically, never drive stock negative, and are only
+//! reachable by roles the Casbin policies grant them to.
+
+mod business_logic_test_helpers;
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+    Router,
+};
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, create_inventory_level, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use helpers::create_test_state;
+use inventory_service_api::handlers::adjustment::create_adjustment_routes;
+use inventory_service_api::middleware::AuthzState;
+use inventory_service_core::dto::adjustment::{AdjustmentReasonCode, QuickAdjustmentRequest};
+use inventory_service_core::services::adjustment::AdjustmentService;
+use inventory_service_infra::services::PgAdjustmentService;
+use serde_json::json;
+use shared_error::AppError;
+use shared_jwt::{encode_jwt, Claims, InMemoryTokenDenylist};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn quick_request(
+    warehouse_id: Uuid,
+    product_id: Uuid,
+    delta: i64,
+    reason_code: AdjustmentReasonCode,
+) -> QuickAdjustmentRequest {
+    QuickAdjustmentRequest {
+        warehouse_id,
+        location_id: None,
+        product_id,
+        delta,
+        reason_code,
+        note: Some("Cycle count correction".to_string()),
+    }
+}
+
+async fn get_available(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+) -> i64 {
+    sqlx::query_scalar::<_, i64>(
+        "SELECT available_quantity FROM inventory_levels
+         WHERE tenant_id = $1 AND warehouse_id = $2 AND product_id = $3 AND deleted_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to read inventory level")
+}
+
+/// Quantities of adjustment moves recorded for a product, oldest first
+async fn get_adjustment_moves(pool: &PgPool, tenant_id: Uuid, product_id: Uuid) -> Vec<i64> {
+    sqlx::query_scalar::<_, i64>(
+        "SELECT quantity FROM stock_moves
+         WHERE tenant_id = $1 AND product_id = $2 AND move_type = 'adjustment'
+         ORDER BY move_date, move_id",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .fetch_all(pool)
+    .await
+    .expect("Failed to read stock moves")
+}
+
+/// Give a new user `role` in the tenant, with the given POST grant for the role
+async fn user_with_role(pool: &PgPool, tenant_id: Uuid, role: &str, post_path: &str) -> Uuid {
+    let user_id = Uuid::now_v7();
+    sqlx::query("INSERT INTO casbin_rule (ptype, v0, v1, v2, v3) VALUES ('g', $1, $2, $3, '')")
+        .bind(user_id.to_string())
+        .bind(role)
+        .bind(tenant_id.to_string())
+        .execute(pool)
+        .await
+        .expect("Failed to add casbin role");
+    sqlx::query(
+        "INSERT INTO casbin_rule (ptype, v0, v1, v2, v3) VALUES ('p', $1, $2, $3, 'POST')
+         ON CONFLICT DO NOTHING",
+    )
+    .bind(role)
+    .bind(tenant_id.to_string())
+    .bind(post_path)
+    .execute(pool)
+    .await
+    .expect("Failed to add casbin policy");
+    user_id
+}
+
+/// Adjustment routes behind the `RequirePermission` check; build after the
+/// test's policies are in place, since the enforcer loads them once
+async fn adjustment_app(pool: &PgPool) -> Router {
+    let app_state = create_test_state(pool.clone()).await;
+    let authz_state = AuthzState {
+        enforcer: app_state.enforcer.clone(),
+        jwt_secret: app_state.jwt_secret.clone(),
+        denylist: Arc::new(InMemoryTokenDenylist::new()),
+    };
+
+    Router::new()
+        .nest("/api/v1/inventory/adjustments", create_adjustment_routes())
+        .layer(axum::Extension(app_state.enforcer.clone()))
+        .layer(axum::Extension(app_state))
+        .layer(axum::Extension(authz_state))
+}
+
+async fn post_quick_adjustment(
+    app: &Router,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    body: serde_json::Value,
+) -> StatusCode {
+    let claims = Claims::new_access(user_id, tenant_id, "user".to_string(), 900);
+    let token = encode_jwt(&claims, "test_jwt_secret").unwrap();
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/v1/inventory/adjustments/quick")
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    app.clone().oneshot(request).await.unwrap().status()
+}
+
+async fn cleanup_adjustment_test_data(pool: &PgPool, tenant_id: Uuid) {
+    let _ = sqlx::query("DELETE FROM stock_moves WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query("DELETE FROM casbin_rule WHERE v1 = $1 OR v2 = $1")
+        .bind(tenant_id.to_string())
+        .execute(pool)
+        .await;
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_quick_adjustment_positive_delta() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = Uuid::now_v7();
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 10).await;
+    let service = PgAdjustmentService::new(Arc::new(pool.clone()));
+
+    let response = service
+        .adjust(
+            tenant_id,
+            user_id,
+            quick_request(warehouse_id, product_id, 5, AdjustmentReasonCode::Found),
+        )
+        .await
+        .expect("Positive adjustment should succeed");
+
+    assert_eq!(response.available_quantity, 15);
+    assert_eq!(response.delta, 5);
+    assert_eq!(response.adjusted_by, user_id);
+    assert_eq!(get_available(&pool, tenant_id, warehouse_id, product_id).await, 15);
+    assert_eq!(get_adjustment_moves(&pool, tenant_id, product_id).await, vec![5]);
+
+    cleanup_adjustment_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_quick_adjustment_negative_delta() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 10).await;
+    let service = PgAdjustmentService::new(Arc::new(pool.clone()));
+
+    let response = service
+        .adjust(
+            tenant_id,
+            Uuid::now_v7(),
+            quick_request(warehouse_id, product_id, -10, AdjustmentReasonCode::Damaged),
+        )
+        .await
+        .expect("Negative adjustment down to zero should succeed");
+
+    assert_eq!(response.available_quantity, 0);
+    assert_eq!(get_available(&pool, tenant_id, warehouse_id, product_id).await, 0);
+    assert_eq!(get_adjustment_moves(&pool, tenant_id, product_id).await, vec![-10]);
+
+    cleanup_adjustment_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_quick_adjustment_rejected_when_stock_would_go_negative() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 3).await;
+    let service = PgAdjustmentService::new(Arc::new(pool.clone()));
+
+    let result = service
+        .adjust(
+            tenant_id,
+            Uuid::now_v7(),
+            quick_request(warehouse_id, product_id, -4, AdjustmentReasonCode::Lost),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+    // Nothing changed: level untouched and no move written
+    assert_eq!(get_available(&pool, tenant_id, warehouse_id, product_id).await, 3);
+    assert!(get_adjustment_moves(&pool, tenant_id, product_id)
+        .await
+        .is_empty());
+
+    cleanup_adjustment_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_quick_adjustment_requires_casbin_permission() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 10).await;
+
+    // As after the policy migrations: managers hold the quick route, users
+    // only the document routes
+    let manager =
+        user_with_role(&pool, tenant_id, "manager", "/api/v1/inventory/adjustments/quick").await;
+    let user = user_with_role(
+        &pool,
+        tenant_id,
+        "user",
+        "/api/v1/inventory/adjustments/:adjustment_id/lines",
+    )
+    .await;
+    let app = adjustment_app(&pool).await;
+
+    let body = json!({
+        "warehouse_id": warehouse_id,
+        "product_id": product_id,
+        "delta": 2,
+        "reason_code": "found",
+    });
+    assert_eq!(
+        post_quick_adjustment(&app, tenant_id, user, body.clone()).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(get_available(&pool, tenant_id, warehouse_id, product_id).await, 10);
+
+    assert_eq!(post_quick_adjustment(&app, tenant_id, manager, body).await, StatusCode::CREATED);
+    assert_eq!(get_available(&pool, tenant_id, warehouse_id, product_id).await, 12);
+
+    cleanup_adjustment_test_data(&pool, tenant_id).await;
+}
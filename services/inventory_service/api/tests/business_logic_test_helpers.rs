@@ -1,7 +1,10 @@
 //! Shared Test Helpers for Business Logic Tests
 //!
-//! Common utilities for integration tests covering valuation and reorder rules.
-//! This module consolidates duplicate setup/cleanup logic to reduce code duplication.
+//! Common utilities for inventory integration tests: pools, service factories,
+//! fixtures (tenants, users, products, categories, warehouses, locations, levels,
+//! seeded transfer and cycle count tenants), stock level and category count
+//! reads and per-tenant cleanup. Add fixtures here rather than copying them per
+//! file.
 
 #![allow(dead_code)]
 
@@ -89,17 +92,84 @@ pub fn create_valuation_service(pool: &PgPool) -> ValuationServiceImpl {
     ValuationServiceImpl::new(repo.clone(), repo.clone(), repo, settings_repo)
 }
 
-use inventory_service_infra::repositories::{PgInventoryLevelRepository, PgReorderRuleRepository};
-use inventory_service_infra::services::PgReplenishmentService;
+use inventory_service_core::domains::inventory::dto::valuation_dto::SetValuationMethodRequest;
+use inventory_service_core::domains::inventory::valuation::ValuationMethod;
+
+/// Build a request switching a product to the given valuation method.
+pub fn method_request(
+    tenant_id: Uuid,
+    product_id: Uuid,
+    valuation_method: ValuationMethod,
+) -> SetValuationMethodRequest {
+    SetValuationMethodRequest {
+        tenant_id,
+        product_id,
+        valuation_method,
+    }
+}
+
+use inventory_service_infra::repositories::{
+    PgInternalReplenishmentRuleRepository, PgInventoryLevelRepository, PgReorderRuleRepository,
+};
+use inventory_service_infra::services::{PgReplenishmentService, ReorderEventPublisher};
 
 /// Create a ReplenishmentService instance for testing.
 pub fn create_replenishment_service(pool: &PgPool) -> PgReplenishmentService {
+    create_replenishment_service_with_publisher(pool, None)
+}
+
+/// Create a ReplenishmentService instance that emits reorder events to `publisher`.
+pub fn create_replenishment_service_with_publisher(
+    pool: &PgPool,
+    publisher: Option<Arc<dyn ReorderEventPublisher>>,
+) -> PgReplenishmentService {
     let reorder_rule_repo = Arc::new(PgReorderRuleRepository::new(pool.clone()));
+    let internal_rule_repo = Arc::new(PgInternalReplenishmentRuleRepository::new(pool.clone()));
     // Use shared Arc for pool to avoid unnecessary allocations
     let pool_arc = Arc::new(pool.clone());
     let inventory_level_repo = Arc::new(PgInventoryLevelRepository::new(pool_arc));
 
-    PgReplenishmentService::new(reorder_rule_repo, inventory_level_repo, None)
+    PgReplenishmentService::new(
+        reorder_rule_repo,
+        internal_rule_repo,
+        inventory_level_repo,
+        publisher,
+    )
+}
+
+use inventory_service_infra::repositories::{
+    LotSerialRepositoryImpl, PgInventoryRepository, ProductRepositoryImpl,
+};
+use inventory_service_infra::services::InventoryServiceImpl;
+
+/// Create an InventoryService instance for testing.
+pub fn create_inventory_service(pool: &PgPool) -> InventoryServiceImpl {
+    // PgInventoryRepository handles both standard and lot-tracked reservations
+    let product_repo = Arc::new(ProductRepositoryImpl::new(pool.clone()));
+    let lot_serial_repo = Arc::new(LotSerialRepositoryImpl::new(pool.clone()));
+    let inventory_repo = Arc::new(PgInventoryRepository::new(
+        Arc::new(pool.clone()),
+        product_repo,
+        lot_serial_repo,
+    ));
+    InventoryServiceImpl::new(inventory_repo)
+}
+
+use inventory_service_infra::repositories::{
+    PgTransferItemRepository, PgTransferRepository, WarehouseRepositoryImpl,
+};
+use inventory_service_infra::services::PgTransferService;
+
+/// Create a TransferService instance for testing.
+pub fn create_transfer_service(pool: &PgPool) -> PgTransferService {
+    let pool_arc = Arc::new(pool.clone());
+    PgTransferService::new(
+        pool_arc.clone(),
+        Arc::new(PgTransferRepository::new(pool_arc.clone())),
+        Arc::new(PgTransferItemRepository::new(pool_arc.clone())),
+        Arc::new(PgInventoryLevelRepository::new(pool_arc)),
+        Arc::new(WarehouseRepositoryImpl::new(pool.clone())),
+    )
 }
 
 // ============================================================================
@@ -109,7 +179,6 @@ pub fn create_replenishment_service(pool: &PgPool) -> PgReplenishmentService {
 /// Create a test tenant and product, returning their IDs.
 pub async fn setup_test_tenant_and_product(pool: &PgPool) -> (Uuid, Uuid) {
     let tenant_id = Uuid::now_v7();
-    let product_id = Uuid::now_v7();
 
     // Insert test tenant
     sqlx::query(
@@ -123,43 +192,149 @@ pub async fn setup_test_tenant_and_product(pool: &PgPool) -> (Uuid, Uuid) {
     .await
     .expect("Failed to insert tenant");
 
-    // Insert test product
+    let product_id = create_product(pool, tenant_id).await;
+
+    (tenant_id, product_id)
+}
+
+/// Create a test tenant, product, and warehouse, returning their IDs.
+pub async fn setup_test_tenant_product_warehouse(pool: &PgPool) -> (Uuid, Uuid, Uuid) {
+    let (tenant_id, product_id) = setup_test_tenant_and_product(pool).await;
+    let warehouse_id = create_warehouse(pool, tenant_id, "Test Warehouse").await;
+
+    (tenant_id, product_id, warehouse_id)
+}
+
+/// Create a product with a generated SKU, returning its ID.
+pub async fn create_product(pool: &PgPool, tenant_id: Uuid) -> Uuid {
+    create_named_product(pool, tenant_id, "Test Product", None).await
+}
+
+/// Create a product with the given name and optional category and a generated SKU.
+pub async fn create_named_product(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    name: &str,
+    category_id: Option<Uuid>,
+) -> Uuid {
+    let sku = format!("TEST-{}", Uuid::now_v7());
+    create_product_with(pool, tenant_id, &sku, name, category_id).await
+}
+
+/// Create a product with the given SKU, name and optional category, returning its ID.
+pub async fn create_product_with(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    sku: &str,
+    name: &str,
+    category_id: Option<Uuid>,
+) -> Uuid {
+    let product_id = Uuid::now_v7();
     sqlx::query(
-        "INSERT INTO products (product_id, tenant_id, sku, name, created_at)
-         VALUES ($1, $2, $3, $4, NOW())
-         ON CONFLICT (product_id) DO NOTHING",
+        "INSERT INTO products (product_id, tenant_id, sku, name, category_id, created_at)
+         VALUES ($1, $2, $3, $4, $5, NOW())",
     )
     .bind(product_id)
     .bind(tenant_id)
-    .bind(format!("TEST-{}", Uuid::now_v7()))
-    .bind("Test Product")
+    .bind(sku)
+    .bind(name)
+    .bind(category_id)
     .execute(pool)
     .await
     .expect("Failed to insert product");
+    product_id
+}
 
-    (tenant_id, product_id)
+/// Create a product category, returning its ID.
+pub async fn create_category(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    parent_id: Option<Uuid>,
+    name: &str,
+) -> Uuid {
+    let category_id = Uuid::now_v7();
+    // path and level are filled in by the category path trigger
+    sqlx::query(
+        "INSERT INTO product_categories (category_id, tenant_id, parent_category_id, name, path)
+         VALUES ($1, $2, $3, $4, '')",
+    )
+    .bind(category_id)
+    .bind(tenant_id)
+    .bind(parent_id)
+    .bind(name)
+    .execute(pool)
+    .await
+    .expect("Failed to insert category");
+    category_id
 }
 
-/// Create a test tenant, product, and warehouse, returning their IDs.
-pub async fn setup_test_tenant_product_warehouse(pool: &PgPool) -> (Uuid, Uuid, Uuid) {
-    let (tenant_id, product_id) = setup_test_tenant_and_product(pool).await;
+/// Create a warehouse, returning its ID.
+pub async fn create_warehouse(pool: &PgPool, tenant_id: Uuid, name: &str) -> Uuid {
     let warehouse_id = Uuid::now_v7();
-
-    // Insert test warehouse
     sqlx::query(
         "INSERT INTO warehouses (tenant_id, warehouse_id, warehouse_name, warehouse_code, created_at, updated_at)
-         VALUES ($1, $2, $3, $4, NOW(), NOW())
-         ON CONFLICT (warehouse_id) DO NOTHING",
+         VALUES ($1, $2, $3, $4, NOW(), NOW())",
     )
     .bind(tenant_id)
     .bind(warehouse_id)
-    .bind("Test Warehouse")
+    .bind(name)
     .bind(format!("WH-{}", &Uuid::now_v7().to_string()[..8].to_uppercase()))
     .execute(pool)
     .await
     .expect("Failed to insert warehouse");
+    warehouse_id
+}
 
-    (tenant_id, product_id, warehouse_id)
+/// Create a bin location in a warehouse, returning its ID.
+pub async fn create_location(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    code: &str,
+    capacity: Option<i64>,
+) -> Uuid {
+    let location_id = Uuid::now_v7();
+    sqlx::query(
+        "INSERT INTO warehouse_locations (location_id, tenant_id, warehouse_id, location_code, location_type, capacity)
+         VALUES ($1, $2, $3, $4, 'bin', $5)",
+    )
+    .bind(location_id)
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(code)
+    .bind(capacity)
+    .execute(pool)
+    .await
+    .expect("Failed to insert location");
+    location_id
+}
+
+/// Create a unit of measure, returning its ID.
+pub async fn create_uom(pool: &PgPool, tenant_id: Uuid) -> Uuid {
+    let uom_id = Uuid::now_v7();
+    sqlx::query("INSERT INTO unit_of_measures (uom_id, tenant_id, name) VALUES ($1, $2, 'Piece')")
+        .bind(uom_id)
+        .bind(tenant_id)
+        .execute(pool)
+        .await
+        .expect("Failed to insert unit of measure");
+    uom_id
+}
+
+/// Create a test user (needed wherever `created_by` references users).
+pub async fn create_user(pool: &PgPool, tenant_id: Uuid) -> Uuid {
+    let user_id = Uuid::now_v7();
+    sqlx::query(
+        "INSERT INTO users (user_id, tenant_id, email, password_hash, created_at)
+         VALUES ($1, $2, $3, 'not-a-real-hash', NOW())",
+    )
+    .bind(user_id)
+    .bind(tenant_id)
+    .bind(format!("test-{}@example.com", user_id))
+    .execute(pool)
+    .await
+    .expect("Failed to insert user");
+    user_id
 }
 
 /// Create an inventory level record for testing.
@@ -193,6 +368,270 @@ pub async fn create_inventory_level(
     .expect("Failed to create inventory level");
 }
 
+/// Create an inventory level at a location for testing.
+pub async fn stock_at(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    location_id: Uuid,
+    product_id: Uuid,
+    available: i64,
+    reserved: i64,
+) {
+    sqlx::query(
+        "INSERT INTO inventory_levels (tenant_id, warehouse_id, location_id, product_id, available_quantity, reserved_quantity)
+         VALUES ($1, $2, $3, $4, $5, $6)",
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(location_id)
+    .bind(product_id)
+    .bind(available)
+    .bind(reserved)
+    .execute(pool)
+    .await
+    .expect("Failed to insert inventory level");
+}
+
+/// Available quantity of a product's warehouse-level (location-less) stock.
+pub async fn available(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+) -> i64 {
+    sqlx::query_scalar(
+        "SELECT available_quantity FROM inventory_levels
+         WHERE tenant_id = $1 AND warehouse_id = $2 AND product_id = $3
+           AND location_id IS NULL AND deleted_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+/// Read (available, reserved, quality_hold, quarantined) for a product in a warehouse.
+pub async fn get_levels(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+) -> (i64, i64, i64, i64) {
+    sqlx::query_as::<_, (i64, i64, i64, i64)>(
+        "SELECT available_quantity, reserved_quantity, quality_hold_quantity, quarantined_quantity
+         FROM inventory_levels
+         WHERE tenant_id = $1 AND warehouse_id = $2 AND product_id = $3 AND deleted_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to read inventory level")
+}
+
+/// Read (product_count, total_product_count) for a category.
+pub async fn get_counts(pool: &PgPool, tenant_id: Uuid, category_id: Uuid) -> (i32, i32) {
+    sqlx::query_as::<_, (i32, i32)>(
+        "SELECT product_count, total_product_count FROM product_categories
+         WHERE tenant_id = $1 AND category_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(category_id)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to read category counts")
+}
+
+/// Tenant with a product stocked 100 units in a source warehouse, an empty
+/// destination warehouse, a user and a unit of measure, ready for transfers.
+pub struct TransferFixture {
+    pub tenant_id: Uuid,
+    pub product_id: Uuid,
+    pub source_id: Uuid,
+    pub destination_id: Uuid,
+    pub user_id: Uuid,
+    pub uom_id: Uuid,
+}
+
+/// Seed a [`TransferFixture`].
+pub async fn setup_transfer_fixture(pool: &PgPool) -> TransferFixture {
+    let (tenant_id, product_id, source_id) = setup_test_tenant_product_warehouse(pool).await;
+    let destination_id = create_warehouse(pool, tenant_id, "Destination Warehouse").await;
+    let user_id = create_user(pool, tenant_id).await;
+    let uom_id = create_uom(pool, tenant_id).await;
+    create_inventory_level(pool, tenant_id, product_id, source_id, 100).await;
+    TransferFixture {
+        tenant_id,
+        product_id,
+        source_id,
+        destination_id,
+        user_id,
+        uom_id,
+    }
+}
+
+use inventory_service_core::dto::cycle_count::{CountType, CreateCycleCountRequest};
+use inventory_service_core::services::cycle_count::CycleCountingService;
+use inventory_service_infra::services::PgCycleCountingService;
+
+/// Seeded tenant: four stocked products worth 70%, 13%, 12% and 5% of the
+/// warehouse value (classes A, A, B, C), the middle two stocked in one zone
+pub struct CycleCountFixture {
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub zone_id: Uuid,
+    /// Class A, counted 45 days ago
+    pub a_stale: Uuid,
+    /// Class A, counted 10 days ago, in the zone
+    pub a_fresh: Uuid,
+    /// Class B, counted 60 days ago, in the zone
+    pub b_fresh: Uuid,
+    /// Class C, never counted
+    pub c_never: Uuid,
+}
+
+/// Create a product stocked at cost 100 per unit, so the quantity sets the stock value
+pub async fn create_stocked_product(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    location_id: Option<Uuid>,
+    value: i64,
+) -> Uuid {
+    let product_id = create_product(pool, tenant_id).await;
+    sqlx::query("UPDATE products SET cost_price = 100 WHERE product_id = $1")
+        .bind(product_id)
+        .execute(pool)
+        .await
+        .expect("Failed to set cost price");
+
+    match location_id {
+        Some(location_id) => {
+            stock_at(pool, tenant_id, warehouse_id, location_id, product_id, value / 100, 0).await
+        },
+        None => {
+            create_inventory_level(pool, tenant_id, product_id, warehouse_id, value / 100).await
+        },
+    }
+
+    product_id
+}
+
+/// Seed the [`CycleCountFixture`] tenant, recording its past counts through `service`
+pub async fn setup_cycle_count_fixture(
+    pool: &PgPool,
+    service: &PgCycleCountingService,
+) -> CycleCountFixture {
+    let tenant_id = Uuid::now_v7();
+    let zone_id = Uuid::now_v7();
+    let location_id = Uuid::now_v7();
+
+    sqlx::query(
+        "INSERT INTO tenants (tenant_id, name, slug, created_at) VALUES ($1, $2, $3, NOW())",
+    )
+    .bind(tenant_id)
+    .bind("Cycle Count Tenant")
+    .bind(format!("cc-{}", tenant_id))
+    .execute(pool)
+    .await
+    .expect("Failed to insert tenant");
+
+    let user_id = create_user(pool, tenant_id).await;
+    let warehouse_id = create_warehouse(pool, tenant_id, "Counted Warehouse").await;
+
+    sqlx::query(
+        "INSERT INTO warehouse_zones (zone_id, tenant_id, warehouse_id, zone_code, zone_name)
+         VALUES ($1, $2, $3, 'FAST', 'Fast Movers')",
+    )
+    .bind(zone_id)
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert zone");
+
+    sqlx::query(
+        "INSERT INTO warehouse_locations (location_id, tenant_id, warehouse_id, zone_id, location_code, location_type)
+         VALUES ($1, $2, $3, $4, 'FAST-01', 'bin')",
+    )
+    .bind(location_id)
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(zone_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert location");
+
+    let a_stale = create_stocked_product(pool, tenant_id, warehouse_id, None, 70_000).await;
+    let a_fresh =
+        create_stocked_product(pool, tenant_id, warehouse_id, Some(location_id), 13_000).await;
+    let b_fresh =
+        create_stocked_product(pool, tenant_id, warehouse_id, Some(location_id), 12_000).await;
+    let c_never = create_stocked_product(pool, tenant_id, warehouse_id, None, 5_000).await;
+
+    // Record earlier counts in a past session of the same warehouse
+    let past = create_cycle_count_session(service, tenant_id, user_id, warehouse_id).await;
+    for (product_id, days_ago) in [(a_stale, 45), (a_fresh, 10), (b_fresh, 60)] {
+        sqlx::query(
+            "INSERT INTO stock_take_lines (
+                 tenant_id, stock_take_id, product_id, expected_quantity, actual_quantity,
+                 line_status, counted_by, counted_at
+             )
+             VALUES ($1, $2, $3, 1, 1, 'counted', $4, NOW() - make_interval(days => $5))",
+        )
+        .bind(tenant_id)
+        .bind(past)
+        .bind(product_id)
+        .bind(user_id)
+        .bind(days_ago)
+        .execute(pool)
+        .await
+        .expect("Failed to insert past count");
+    }
+
+    CycleCountFixture {
+        tenant_id,
+        user_id,
+        warehouse_id,
+        zone_id,
+        a_stale,
+        a_fresh,
+        b_fresh,
+        c_never,
+    }
+}
+
+/// Open a cycle count session over a whole warehouse, returning its ID.
+pub async fn create_cycle_count_session(
+    service: &PgCycleCountingService,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    warehouse_id: Uuid,
+) -> Uuid {
+    let request = CreateCycleCountRequest {
+        schedule_id: None,
+        warehouse_id: Some(warehouse_id),
+        location_id: None,
+        product_id: None,
+        category_id: None,
+        include_lots: false,
+        as_of: None,
+        count_type: CountType::Cycle,
+        notes: None,
+    };
+    service
+        .create_session(tenant_id, user_id, request)
+        .await
+        .expect("Failed to create session")
+        .cycle_count
+        .cycle_count_id
+}
+
 // ============================================================================
 // Test Data Cleanup
 // ============================================================================
@@ -233,6 +672,65 @@ pub async fn cleanup_reorder_test_data(pool: &PgPool, tenant_id: Uuid) {
     cleanup_base_test_data(pool, tenant_id).await;
 }
 
+/// Delete a tenant's rows from `tables`, in order, then its reorder test data.
+///
+/// List dependent tables before the tables they reference.
+pub async fn cleanup_tenant_tables(pool: &PgPool, tenant_id: Uuid, tables: &[&str]) {
+    for table in tables {
+        let _ = sqlx::query(&format!("DELETE FROM {} WHERE tenant_id = $1", table))
+            .bind(tenant_id)
+            .execute(pool)
+            .await;
+    }
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+/// Clean up transfer-related test data for a tenant.
+pub async fn cleanup_transfer_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "stock_moves",
+            "stock_transfer_items",
+            "stock_transfers",
+            "inventory_levels",
+            "warehouse_locations",
+            "unit_of_measures",
+            "users",
+        ],
+    )
+    .await;
+}
+
+/// Clean up outbox events and base test data for a tenant.
+pub async fn cleanup_outbox_test_data(pool: &PgPool, tenant_id: Uuid) {
+    let _ = sqlx::query("DELETE FROM event_outbox WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+/// Clean up products and the category tree for a tenant.
+pub async fn cleanup_category_test_data(pool: &PgPool, tenant_id: Uuid) {
+    // Products are removed with the reorder data; detach them so categories can go first
+    let _ = sqlx::query("UPDATE products SET category_id = NULL WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    // Children first so parent references never dangle
+    let _ = sqlx::query("DELETE FROM product_categories WHERE tenant_id = $1 AND level > 0")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query("DELETE FROM product_categories WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
 /// Clean up base test data (products and tenants).
 async fn cleanup_base_test_data(pool: &PgPool, tenant_id: Uuid) {
     let _ = sqlx::query("DELETE FROM products WHERE tenant_id = $1")
@@ -0,0 +1,90 @@
+//! Category Code Reuse Integration Tests
+//!
+//! A soft-deleted category releases its code and slug: a new category may
+//! reuse them, while active categories still may not share them.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_category_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::dto::category::CategoryCreateRequest;
+use inventory_service_core::services::category::CategoryService;
+use inventory_service_infra::repositories::category::CategoryRepositoryImpl;
+use inventory_service_infra::services::category::CategoryServiceImpl;
+use serde_json::json;
+use shared_error::AppError;
+
+fn create_request(name: &str, code: &str) -> CategoryCreateRequest {
+    serde_json::from_value(json!({ "name": name, "code": code })).expect("Valid category request")
+}
+
+#[tokio::test]
+async fn test_deleted_category_code_can_be_reused() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let original = service
+        .create_category(tenant_id, create_request("Electronics", "ELEC"))
+        .await
+        .expect("First category should be created");
+    assert!(service
+        .delete_category(tenant_id, original.category_id)
+        .await
+        .unwrap());
+
+    // Same code and (generated) slug as the deleted category
+    let recreated = service
+        .create_category(tenant_id, create_request("Electronics", "ELEC"))
+        .await
+        .expect("Deleted category's code should be reusable");
+    assert_ne!(recreated.category_id, original.category_id);
+    assert_eq!(recreated.code.as_deref(), Some("ELEC"));
+    assert_eq!(recreated.slug, original.slug);
+
+    // Deleted rows may share the code among themselves too
+    service
+        .delete_category(tenant_id, recreated.category_id)
+        .await
+        .unwrap();
+    service
+        .create_category(tenant_id, create_request("Electronics", "ELEC"))
+        .await
+        .expect("Code should be reusable after a second delete");
+
+    let deleted_with_code: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM product_categories
+         WHERE tenant_id = $1 AND code = 'ELEC' AND deleted_at IS NOT NULL",
+    )
+    .bind(tenant_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(deleted_with_code, 2);
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_active_category_code_conflicts() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    service
+        .create_category(tenant_id, create_request("Electronics", "ELEC"))
+        .await
+        .unwrap();
+
+    let result = service
+        .create_category(tenant_id, create_request("Gadgets", "ELEC"))
+        .await;
+    assert!(
+        matches!(result, Err(AppError::Conflict(ref msg)) if msg.contains("ELEC")),
+        "unexpected result: {:?}",
+        result
+    );
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,159 @@
+//! Category Import/Export Integration Tests
+//!
+//! Exporting a category tree and importing it into another tenant recreates
+//! the same structure, and importing the same document again is a no-op.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::dto::category::{
+    CategoryCreateRequest, CategoryExportDocument, CategoryImportStatus,
+};
+use inventory_service_core::services::category::CategoryService;
+use inventory_service_infra::repositories::category::CategoryRepositoryImpl;
+use inventory_service_infra::services::category::CategoryServiceImpl;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+type Service = CategoryServiceImpl<CategoryRepositoryImpl>;
+
+async fn create_category(
+    service: &Service,
+    tenant_id: Uuid,
+    parent_id: Option<Uuid>,
+    name: &str,
+    code: &str,
+) -> Uuid {
+    let request: CategoryCreateRequest = serde_json::from_value(json!({
+        "parentCategoryId": parent_id,
+        "name": name,
+        "code": code,
+        "description": format!("{} category", name),
+    }))
+    .expect("Valid category request");
+    service
+        .create_category(tenant_id, request)
+        .await
+        .expect("Failed to create category")
+        .category_id
+}
+
+async fn cleanup_category_test_data(pool: &PgPool, tenant_id: Uuid) {
+    // Children first so parent references never dangle
+    let _ = sqlx::query("DELETE FROM product_categories WHERE tenant_id = $1 AND level > 0")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query("DELETE FROM product_categories WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+/// Export document with timestamps stripped and categories sorted by code
+async fn structure(service: &Service, tenant_id: Uuid) -> CategoryExportDocument {
+    let mut document = service
+        .export_categories(tenant_id)
+        .await
+        .expect("Export should succeed");
+    document.exported_at = None;
+    document.categories.sort_by(|a, b| a.code.cmp(&b.code));
+    document
+}
+
+#[tokio::test]
+async fn test_export_import_round_trip() {
+    let pool = setup_test_pool().await;
+    let (source_tenant, _) = setup_test_tenant_and_product(&pool).await;
+    let (target_tenant, _) = setup_test_tenant_and_product(&pool).await;
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let electronics = create_category(&service, source_tenant, None, "Electronics", "ELEC").await;
+    let phones =
+        create_category(&service, source_tenant, Some(electronics), "Phones", "PHONE").await;
+    create_category(&service, source_tenant, Some(phones), "Smartphones", "SMART").await;
+    create_category(&service, source_tenant, Some(electronics), "Laptops", "LAPTOP").await;
+    create_category(&service, source_tenant, None, "Garden", "GARDEN").await;
+
+    let exported = service
+        .export_categories(source_tenant)
+        .await
+        .expect("Export should succeed");
+    assert_eq!(exported.categories.len(), 5);
+
+    // Parents always precede their children
+    for (index, item) in exported.categories.iter().enumerate() {
+        if let Some(parent_code) = &item.parent_code {
+            let parent_index = exported
+                .categories
+                .iter()
+                .position(|c| &c.code == parent_code)
+                .expect("Parent should be exported");
+            assert!(parent_index < index, "{} exported before its parent", item.code);
+        }
+    }
+
+    // Round-trip through JSON like a real migration would
+    let document: CategoryExportDocument =
+        serde_json::from_str(&serde_json::to_string(&exported).unwrap()).unwrap();
+    let result = service
+        .import_categories(target_tenant, document)
+        .await
+        .expect("Import should succeed");
+    assert_eq!(result.created_count, 5);
+    assert_eq!(result.existing_count, 0);
+    assert!(result.conflicts.is_empty(), "Unexpected conflicts: {:?}", result.conflicts);
+
+    assert_eq!(
+        structure(&service, source_tenant).await.categories,
+        structure(&service, target_tenant).await.categories
+    );
+
+    // Importing again reuses every category by code
+    let again = service
+        .import_categories(target_tenant, exported)
+        .await
+        .expect("Repeated import should succeed");
+    assert_eq!(again.created_count, 0);
+    assert_eq!(again.existing_count, 5);
+    assert!(again
+        .mappings
+        .iter()
+        .all(|m| m.status == CategoryImportStatus::Existing));
+    assert_eq!(structure(&service, target_tenant).await.categories.len(), 5);
+
+    cleanup_category_test_data(&pool, source_tenant).await;
+    cleanup_category_test_data(&pool, target_tenant).await;
+}
+
+#[tokio::test]
+async fn test_import_reports_unknown_parent_and_descendants() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let document: CategoryExportDocument = serde_json::from_value(json!({
+        "categories": [
+            { "code": "TOOLS", "name": "Tools" },
+            { "code": "DRILLS", "parentCode": "MISSING", "name": "Drills" },
+            { "code": "CORDLESS", "parentCode": "DRILLS", "name": "Cordless" }
+        ]
+    }))
+    .unwrap();
+
+    let result = service
+        .import_categories(tenant_id, document)
+        .await
+        .expect("Import should succeed");
+
+    assert_eq!(result.created_count, 1);
+    let mut conflict_codes: Vec<_> = result.conflicts.iter().map(|c| c.code.as_str()).collect();
+    conflict_codes.sort();
+    assert_eq!(conflict_codes, vec!["CORDLESS", "DRILLS"]);
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,122 @@
+//! Category Move Integration Tests
+//!
+//! Re-parenting a category carries its subtree along, and a move under the
+//! category itself or one of its descendants is rejected instead of creating
+//! a cycle, also when two crossing moves race each other.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_category_test_data, create_category, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::repositories::category::CategoryRepository;
+use inventory_service_infra::repositories::category::CategoryRepositoryImpl;
+use shared_error::AppError;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_move_category_rejects_cycles() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = CategoryRepositoryImpl::new(pool.clone());
+
+    // root -> child -> grandchild
+    let root = create_category(&pool, tenant_id, None, "Root").await;
+    let child = create_category(&pool, tenant_id, Some(root), "Child").await;
+    let grandchild = create_category(&pool, tenant_id, Some(child), "Grandchild").await;
+
+    for new_parent in [root, child, grandchild] {
+        let result = repo.move_category(tenant_id, root, Some(new_parent)).await;
+        assert!(
+            matches!(result, Err(AppError::ValidationError(_))),
+            "moving under {} should be rejected: {:?}",
+            new_parent,
+            result
+        );
+    }
+
+    // The hierarchy is untouched
+    let unchanged = repo.find_by_id(tenant_id, root).await.unwrap().unwrap();
+    assert_eq!(unchanged.parent_category_id, None);
+    assert_eq!(unchanged.path, root.to_string());
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_move_category_carries_subtree() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = CategoryRepositoryImpl::new(pool.clone());
+
+    let root = create_category(&pool, tenant_id, None, "Root").await;
+    let child = create_category(&pool, tenant_id, Some(root), "Child").await;
+    let grandchild = create_category(&pool, tenant_id, Some(child), "Grandchild").await;
+    let other_root = create_category(&pool, tenant_id, None, "Other Root").await;
+
+    let moved = repo
+        .move_category(tenant_id, child, Some(other_root))
+        .await
+        .expect("Move should succeed");
+    assert_eq!(moved.parent_category_id, Some(other_root));
+    assert_eq!(moved.path, format!("{}/{}", other_root, child));
+    assert_eq!(moved.level, 1);
+
+    let moved_grandchild = repo
+        .find_by_id(tenant_id, grandchild)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(moved_grandchild.path, format!("{}/{}/{}", other_root, child, grandchild));
+    assert_eq!(moved_grandchild.level, 2);
+
+    // Moving to the top level makes the category a root
+    let promoted = repo
+        .move_category(tenant_id, child, None)
+        .await
+        .expect("Move to root should succeed");
+    assert_eq!(promoted.level, 0);
+    assert_eq!(promoted.path, child.to_string());
+
+    let result = repo
+        .move_category(tenant_id, child, Some(Uuid::now_v7()))
+        .await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_concurrent_crossing_moves_cannot_form_cycle() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = CategoryRepositoryImpl::new(pool.clone());
+
+    let a = create_category(&pool, tenant_id, None, "A").await;
+    let b = create_category(&pool, tenant_id, None, "B").await;
+
+    for _ in 0..5 {
+        // Each move is valid on its own; together they would be a cycle
+        let (a_under_b, b_under_a) = tokio::join!(
+            repo.move_category(tenant_id, a, Some(b)),
+            repo.move_category(tenant_id, b, Some(a)),
+        );
+        assert!(
+            a_under_b.is_err() || b_under_a.is_err(),
+            "only one of the crossing moves may succeed"
+        );
+
+        let a_now = repo.find_by_id(tenant_id, a).await.unwrap().unwrap();
+        let b_now = repo.find_by_id(tenant_id, b).await.unwrap().unwrap();
+        assert!(
+            a_now.parent_category_id.is_none() || b_now.parent_category_id.is_none(),
+            "A and B must not be each other's parent"
+        );
+
+        // Back to two roots for the next round
+        repo.move_category(tenant_id, a, None).await.unwrap();
+        repo.move_category(tenant_id, b, None).await.unwrap();
+    }
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
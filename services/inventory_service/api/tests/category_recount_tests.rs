@@ -0,0 +1,48 @@
+//! Category Recount Integration Tests
+//!
+//! Verifies that the periodic recount job corrects drifted category product counts.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_category_test_data, create_category, get_counts, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_api::category_recount_worker::recount_all_tenants;
+
+#[tokio::test]
+async fn test_recount_job_corrects_skewed_counts() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, _warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+
+    let root_id = create_category(&pool, tenant_id, None, "Electronics").await;
+    let child_id = create_category(&pool, tenant_id, Some(root_id), "Phones").await;
+
+    sqlx::query("UPDATE products SET category_id = $1 WHERE tenant_id = $2 AND product_id = $3")
+        .bind(child_id)
+        .bind(tenant_id)
+        .bind(product_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to assign product to category");
+
+    // Artificially skew the stored counts
+    sqlx::query(
+        "UPDATE product_categories SET product_count = 42, total_product_count = 7
+         WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .execute(&pool)
+    .await
+    .expect("Failed to skew category counts");
+
+    let corrected = recount_all_tenants(&pool)
+        .await
+        .expect("Recount job should succeed");
+    assert!(corrected >= 2);
+
+    assert_eq!(get_counts(&pool, tenant_id, child_id).await, (1, 1));
+    assert_eq!(get_counts(&pool, tenant_id, root_id).await, (0, 1));
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
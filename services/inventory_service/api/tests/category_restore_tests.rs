@@ -0,0 +1,87 @@
+//! Category Restore Integration Tests
+//!
+//! A soft-deleted category can be restored as long as its ancestors are live;
+//! deleted parents have to be restored first.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_category_test_data, create_category, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::repositories::category::CategoryRepository;
+use inventory_service_core::services::category::CategoryService;
+use inventory_service_infra::repositories::category::CategoryRepositoryImpl;
+use inventory_service_infra::services::category::CategoryServiceImpl;
+use shared_error::AppError;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_restore_category_clears_deleted_at() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = CategoryRepositoryImpl::new(pool.clone());
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let category = create_category(&pool, tenant_id, None, "Seasonal").await;
+    assert!(repo.delete(tenant_id, category).await.unwrap());
+    assert!(repo
+        .find_by_id(tenant_id, category)
+        .await
+        .unwrap()
+        .is_none());
+
+    let restored = service
+        .restore_category(tenant_id, category)
+        .await
+        .expect("Restore should succeed");
+    assert!(!restored.is_deleted());
+    assert_eq!(restored.path, category.to_string());
+    assert_eq!(restored.product_count, 0);
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_restore_category_requires_live_parent() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = CategoryRepositoryImpl::new(pool.clone());
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let parent = create_category(&pool, tenant_id, None, "Parent").await;
+    let child = create_category(&pool, tenant_id, Some(parent), "Child").await;
+    assert!(repo.delete(tenant_id, child).await.unwrap());
+    assert!(repo.delete(tenant_id, parent).await.unwrap());
+
+    let result = service.restore_category(tenant_id, child).await;
+    assert!(
+        matches!(result, Err(AppError::Conflict(ref msg)) if msg.contains("Parent")),
+        "restoring under a deleted parent should conflict: {:?}",
+        result
+    );
+
+    // Restoring the parent first unblocks the child
+    service
+        .restore_category(tenant_id, parent)
+        .await
+        .expect("Parent restore should succeed");
+    let restored = service
+        .restore_category(tenant_id, child)
+        .await
+        .expect("Child restore should succeed");
+    assert_eq!(restored.parent_category_id, Some(parent));
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_restore_unknown_category_is_not_found() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let result = service.restore_category(tenant_id, Uuid::now_v7()).await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,75 @@
+//! Category Search Ranking Integration Tests
+//!
+//! Listing with `sort_by=relevance` puts name prefix matches first, then the
+//! best full-text matches; without a search term it keeps display order.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_category_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::dto::category::{CategoryListQuery, CategorySortField};
+use inventory_service_core::repositories::category::CategoryRepository;
+use inventory_service_core::services::category::CategoryService;
+use inventory_service_infra::repositories::category::CategoryRepositoryImpl;
+use inventory_service_infra::services::category::CategoryServiceImpl;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn create_category(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    name: &str,
+    description: &str,
+    order: u32,
+) {
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+    let request = serde_json::from_value(json!({
+        "name": name,
+        "description": description,
+        "displayOrder": order,
+    }))
+    .expect("Valid category request");
+    service
+        .create_category(tenant_id, request)
+        .await
+        .expect("Category should be created");
+}
+
+fn list_query(search: Option<&str>) -> CategoryListQuery {
+    let mut query: CategoryListQuery = serde_json::from_value(json!({})).unwrap();
+    query.search = search.map(str::to_string);
+    query.sort_by = CategorySortField::Relevance;
+    query
+}
+
+#[tokio::test]
+async fn test_relevance_sort_ranks_best_matches_first() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = CategoryRepositoryImpl::new(pool.clone());
+
+    // Display order deliberately puts the weakest match first
+    create_category(&pool, tenant_id, "Accessories", "Cables and garden hoses", 0).await;
+    create_category(&pool, tenant_id, "Outdoor", "Garden furniture, garden tools", 1).await;
+    create_category(&pool, tenant_id, "Garden", "Plants", 2).await;
+    create_category(&pool, tenant_id, "Kitchen", "Pots and pans", 3).await;
+
+    let (ranked, _) = repo
+        .list(tenant_id, &list_query(Some("garden")))
+        .await
+        .expect("Search should succeed");
+    let names: Vec<_> = ranked.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["Garden", "Outdoor", "Accessories"]);
+
+    // Without a search term relevance falls back to display order
+    let (all, _) = repo
+        .list(tenant_id, &list_query(None))
+        .await
+        .expect("List should succeed");
+    let names: Vec<_> = all.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["Accessories", "Outdoor", "Garden", "Kitchen"]);
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
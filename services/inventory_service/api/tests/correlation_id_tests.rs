@@ -0,0 +1,143 @@
+//! Correlation Id Propagation Tests
+//!
+//! A request's correlation id travels with the outbox events it enqueues,
+//! through the published envelope, into the consumer that handles them.
+
+mod business_logic_test_helpers;
+
+use std::sync::{Arc, Mutex};
+
+use axum::{body::Body, http::Request, routing::get, Router};
+use business_logic_test_helpers::{
+    cleanup_outbox_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use chrono::{DateTime, Utc};
+use http_body_util::BodyExt;
+use inventory_service_api::middleware::correlation_id_middleware;
+use inventory_service_api::worker::outbox_envelope;
+use inventory_service_core::repositories::event::EventRepository;
+use inventory_service_infra::repositories::EventRepositoryImpl;
+use serde_json::{json, Value};
+use shared_events::{
+    current_correlation_id, with_correlation_id, EventDispatcher, EventEnvelope,
+    CORRELATION_ID_HEADER,
+};
+use sqlx::PgPool;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Publish an outbox row the way the outbox worker does
+async fn published_payload(pool: &PgPool, event_id: Uuid) -> Vec<u8> {
+    let (event_type, schema_version, event_data, created_at, correlation_id): (
+        String,
+        i32,
+        Value,
+        DateTime<Utc>,
+        Option<String>,
+    ) = sqlx::query_as(
+        "SELECT event_type, schema_version, event_data, created_at, correlation_id
+         FROM event_outbox WHERE id = $1",
+    )
+    .bind(event_id)
+    .fetch_one(pool)
+    .await
+    .expect("Outbox event should exist");
+
+    let envelope =
+        outbox_envelope(&event_type, schema_version, &event_data, created_at, correlation_id);
+    serde_json::to_vec(&envelope).unwrap()
+}
+
+#[tokio::test]
+async fn test_outbox_event_carries_correlation_id_to_consumer() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let repo = EventRepositoryImpl::new(pool.clone());
+
+    // Enqueued while handling a request, and by a background job
+    let correlated = with_correlation_id(
+        "req-7f3a".to_string(),
+        repo.insert_event(tenant_id, "stock.adjustment", json!({ "product_id": product_id })),
+    )
+    .await
+    .unwrap();
+    let uncorrelated = repo
+        .insert_event(tenant_id, "stock.adjustment", json!({ "product_id": product_id }))
+        .await
+        .unwrap();
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorder = seen.clone();
+    let dispatcher = EventDispatcher::new().register(
+        "stock.adjustment",
+        1,
+        move |event: EventEnvelope<Value>| {
+            let recorder = recorder.clone();
+            async move {
+                recorder
+                    .lock()
+                    .unwrap()
+                    .push((event.correlation_id, current_correlation_id()));
+                Ok(())
+            }
+        },
+    );
+
+    for event_id in [correlated, uncorrelated] {
+        dispatcher
+            .dispatch(&published_payload(&pool, event_id).await)
+            .await
+            .unwrap();
+    }
+
+    let seen = seen.lock().unwrap().clone();
+    assert_eq!(
+        seen,
+        vec![
+            (Some("req-7f3a".to_string()), Some("req-7f3a".to_string())),
+            (None, None)
+        ]
+    );
+
+    cleanup_outbox_test_data(&pool, tenant_id).await;
+}
+
+fn correlation_app() -> Router {
+    Router::new()
+        .route("/", get(|| async { current_correlation_id().unwrap_or_default() }))
+        .layer(axum::middleware::from_fn(correlation_id_middleware))
+}
+
+async fn call(request: Request<Body>) -> (Option<String>, String) {
+    let response = correlation_app().oneshot(request).await.unwrap();
+    let header = response
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .map(|value| value.to_str().unwrap().to_string());
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (header, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_middleware_scopes_request_correlation_id() {
+    // A caller-supplied id is adopted and echoed back
+    let request = Request::builder()
+        .uri("/")
+        .header(CORRELATION_ID_HEADER, "client-trace-1")
+        .body(Body::empty())
+        .unwrap();
+    let (header, in_scope) = call(request).await;
+    assert_eq!(header.as_deref(), Some("client-trace-1"));
+    assert_eq!(in_scope, "client-trace-1");
+
+    // Without one (or with an unusable one) an id is generated
+    let request = Request::builder()
+        .uri("/")
+        .header(CORRELATION_ID_HEADER, "not valid")
+        .body(Body::empty())
+        .unwrap();
+    let (header, in_scope) = call(request).await;
+    let generated = header.expect("Generated id should be echoed");
+    assert!(Uuid::parse_str(&generated).is_ok());
+    assert_eq!(in_scope, generated);
+}
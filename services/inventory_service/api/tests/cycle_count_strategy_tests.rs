@@ -0,0 +1,133 @@
+//! Cycle Count Line Selection Strategy Tests
+//!
+//! Each `generate_lines` strategy selects the expected products given seeded
+//! stock values, zone placement and last-count timestamps.
+
+mod business_logic_test_helpers;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_cycle_count_session, setup_cycle_count_fixture, setup_test_pool,
+    CycleCountFixture,
+};
+use inventory_service_core::dto::cycle_count::{GenerateLinesRequest, LineSelectionStrategy};
+use inventory_service_core::services::cycle_count::CycleCountingService;
+use inventory_service_infra::repositories::{PgInventoryLevelRepository, PgStockMoveRepository};
+use inventory_service_infra::services::PgCycleCountingService;
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Generate lines for a fresh session and return the selected products
+async fn generate(
+    service: &PgCycleCountingService,
+    fixture: &CycleCountFixture,
+    strategy: LineSelectionStrategy,
+) -> Result<HashSet<Uuid>, AppError> {
+    let session = create_cycle_count_session(
+        service,
+        fixture.tenant_id,
+        fixture.user_id,
+        fixture.warehouse_id,
+    )
+    .await;
+    let request = GenerateLinesRequest {
+        product_id: None,
+        category_id: None,
+        include_lots: false,
+        replace_existing: false,
+        strategy,
+    };
+    let response = service
+        .generate_lines(fixture.tenant_id, session, fixture.user_id, request)
+        .await?;
+    Ok(response.lines.iter().map(|line| line.product_id).collect())
+}
+
+async fn cleanup_strategy_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "stock_take_lines",
+            "stock_takes",
+            "inventory_levels",
+            "warehouse_locations",
+            "warehouse_zones",
+            "users",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_generate_lines_strategies_select_expected_products() {
+    let pool = setup_test_pool().await;
+    let shared_pool = Arc::new(pool.clone());
+    let service = PgCycleCountingService::new(
+        shared_pool.clone(),
+        Arc::new(PgStockMoveRepository::new(shared_pool.clone())),
+        Arc::new(PgInventoryLevelRepository::new(shared_pool)),
+    );
+    let fixture = setup_cycle_count_fixture(&pool, &service).await;
+    let set = |ids: &[Uuid]| ids.iter().copied().collect::<HashSet<_>>();
+
+    // All stocked products by default
+    let all = generate(&service, &fixture, LineSelectionStrategy::All)
+        .await
+        .unwrap();
+    assert_eq!(
+        all,
+        set(&[
+            fixture.a_stale,
+            fixture.a_fresh,
+            fixture.b_fresh,
+            fixture.c_never
+        ])
+    );
+
+    // ABC: the A item past 30 days and the never-counted C item are due;
+    // the fresh A item and the B item counted 60 days ago are not
+    let abc = LineSelectionStrategy::Abc {
+        a_interval_days: 30,
+        b_interval_days: 90,
+        c_interval_days: 180,
+    };
+    assert_eq!(
+        generate(&service, &fixture, abc).await.unwrap(),
+        set(&[fixture.a_stale, fixture.c_never])
+    );
+
+    // Zone: only products stocked in the zone's locations
+    let zone = LineSelectionStrategy::Zone {
+        zone_id: fixture.zone_id,
+    };
+    assert_eq!(
+        generate(&service, &fixture, zone).await.unwrap(),
+        set(&[fixture.a_fresh, fixture.b_fresh])
+    );
+
+    // Age: never counted first, then the oldest count
+    let age = LineSelectionStrategy::LastCountAge { max_lines: 2 };
+    assert_eq!(
+        generate(&service, &fixture, age).await.unwrap(),
+        set(&[fixture.c_never, fixture.b_fresh])
+    );
+
+    // Random: the requested number of distinct stocked products
+    let sample =
+        generate(&service, &fixture, LineSelectionStrategy::RandomSample { sample_size: 3 })
+            .await
+            .unwrap();
+    assert_eq!(sample.len(), 3);
+    assert!(sample.is_subset(&all));
+
+    // Empty samples are rejected
+    let result =
+        generate(&service, &fixture, LineSelectionStrategy::RandomSample { sample_size: 0 }).await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+    cleanup_strategy_test_data(&pool, fixture.tenant_id).await;
+}
@@ -0,0 +1,426 @@
+//! Delivery Integration Tests
+//!
+//! Verifies the pick -> pack -> ship flow of DeliveryServiceImpl, including
+//! stock reservation on pick, reservation consumption on ship and partial shipments.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_inventory_level, create_user, get_levels, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::dto::delivery::{
+    PackItemsRequest, PickItemRequest, PickItemsRequest, ShipItemRequest, ShipItemsRequest,
+};
+use inventory_service_core::services::delivery::DeliveryService;
+use inventory_service_infra::repositories::{
+    PgDeliveryOrderItemRepository, PgDeliveryOrderRepository, PgInventoryLevelRepository,
+    PgStockMoveRepository,
+};
+use inventory_service_infra::services::DeliveryServiceImpl;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Helper to create DeliveryService
+fn create_delivery_service(pool: &PgPool) -> DeliveryServiceImpl {
+    let pool_arc = Arc::new(pool.clone());
+    DeliveryServiceImpl::new(
+        Arc::new(PgDeliveryOrderRepository::new(pool_arc.clone())),
+        Arc::new(PgDeliveryOrderItemRepository::new(pool_arc.clone())),
+        Arc::new(PgStockMoveRepository::new(pool_arc.clone())),
+        Arc::new(PgInventoryLevelRepository::new(pool_arc)),
+    )
+}
+
+/// Create a confirmed delivery order with a single line, returning (delivery_id, delivery_item_id)
+async fn create_confirmed_delivery(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+    user_id: Uuid,
+    ordered_quantity: i64,
+    unit_price: i64,
+) -> (Uuid, Uuid) {
+    let delivery_id = Uuid::now_v7();
+    let delivery_item_id = Uuid::now_v7();
+
+    sqlx::query(
+        "INSERT INTO delivery_orders (delivery_id, tenant_id, delivery_number, warehouse_id, customer_id,
+                                      status, created_by, total_quantity, total_value, currency_code)
+         VALUES ($1, $2, $3, $4, $5, 'confirmed', $6, $7, $8, 'VND')",
+    )
+    .bind(delivery_id)
+    .bind(tenant_id)
+    .bind(format!("DO-TEST-{}", &delivery_id.to_string()[..8]))
+    .bind(warehouse_id)
+    .bind(Uuid::now_v7())
+    .bind(user_id)
+    .bind(ordered_quantity)
+    .bind(ordered_quantity * unit_price)
+    .execute(pool)
+    .await
+    .expect("Failed to insert delivery order");
+
+    sqlx::query(
+        "INSERT INTO delivery_order_items (delivery_item_id, tenant_id, delivery_id, product_id,
+                                           ordered_quantity, unit_price, line_total)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)",
+    )
+    .bind(delivery_item_id)
+    .bind(tenant_id)
+    .bind(delivery_id)
+    .bind(product_id)
+    .bind(ordered_quantity)
+    .bind(unit_price)
+    .bind(ordered_quantity * unit_price)
+    .execute(pool)
+    .await
+    .expect("Failed to insert delivery order item");
+
+    (delivery_id, delivery_item_id)
+}
+
+async fn cleanup_delivery_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "stock_moves",
+            "delivery_shipment_items",
+            "delivery_shipments",
+            "delivery_order_items",
+            "delivery_orders",
+            "users",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_pick_pack_ship_flow() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_delivery_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+    let (delivery_id, delivery_item_id) =
+        create_confirmed_delivery(&pool, tenant_id, warehouse_id, product_id, user_id, 10, 500)
+            .await;
+
+    // Pick: reserves the picked quantity
+    let picked = service
+        .pick_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            PickItemsRequest {
+                items: vec![PickItemRequest {
+                    delivery_item_id,
+                    picked_quantity: 10,
+                }],
+            },
+        )
+        .await
+        .expect("Pick should succeed");
+    assert_eq!(picked.status, "picked");
+    assert_eq!(picked.total_picked_quantity, 10);
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (90, 10, 0, 0));
+
+    // Pack: status transition only
+    let packed = service
+        .pack_items(tenant_id, delivery_id, user_id, PackItemsRequest { notes: None })
+        .await
+        .expect("Pack should succeed");
+    assert_eq!(packed.status, "packed");
+
+    // Ship: consumes the reservation and records a stock move
+    let shipped = service
+        .ship_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            ShipItemsRequest {
+                items: None,
+                tracking_number: Some("TRACK-1".to_string()),
+                carrier: Some("Test Carrier".to_string()),
+                shipping_cost: None,
+                notes: None,
+            },
+        )
+        .await
+        .expect("Ship should succeed");
+    assert_eq!(shipped.status, "shipped");
+    assert_eq!(shipped.stock_moves_created, 1);
+    assert_eq!(shipped.total_cogs, 5000);
+    assert_eq!(shipped.remaining.len(), 1);
+    assert_eq!(shipped.remaining[0].remaining_quantity, 0);
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (90, 0, 0, 0));
+
+    let move_quantity: i64 = sqlx::query_scalar(
+        "SELECT quantity::BIGINT FROM stock_moves
+         WHERE tenant_id = $1 AND reference_type = 'do' AND reference_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(delivery_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(move_quantity, -10);
+
+    let delivered: i64 = sqlx::query_scalar(
+        "SELECT delivered_quantity FROM delivery_order_items WHERE delivery_item_id = $1",
+    )
+    .bind(delivery_item_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(delivered, 10);
+
+    cleanup_delivery_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_pick_insufficient_stock_fails() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_delivery_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 5).await;
+    let (delivery_id, delivery_item_id) =
+        create_confirmed_delivery(&pool, tenant_id, warehouse_id, product_id, user_id, 10, 500)
+            .await;
+
+    let result = service
+        .pick_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            PickItemsRequest {
+                items: vec![PickItemRequest {
+                    delivery_item_id,
+                    picked_quantity: 10,
+                }],
+            },
+        )
+        .await;
+    assert!(result.is_err(), "Pick should fail due to insufficient stock");
+
+    // Nothing reserved, nothing picked
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (5, 0, 0, 0));
+    let picked: i64 = sqlx::query_scalar(
+        "SELECT picked_quantity FROM delivery_order_items WHERE delivery_item_id = $1",
+    )
+    .bind(delivery_item_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(picked, 0);
+
+    cleanup_delivery_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_ship_requires_packed_status() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_delivery_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+    let (delivery_id, _) =
+        create_confirmed_delivery(&pool, tenant_id, warehouse_id, product_id, user_id, 10, 500)
+            .await;
+
+    let result = service
+        .ship_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            ShipItemsRequest {
+                items: None,
+                tracking_number: None,
+                carrier: None,
+                shipping_cost: None,
+                notes: None,
+            },
+        )
+        .await;
+    assert!(result.is_err(), "Shipping a confirmed (unpacked) order should fail");
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (100, 0, 0, 0));
+
+    cleanup_delivery_test_data(&pool, tenant_id).await;
+}
+
+/// Pick and pack a single-line delivery so it is ready to ship
+async fn pick_and_pack(
+    service: &DeliveryServiceImpl,
+    tenant_id: Uuid,
+    delivery_id: Uuid,
+    delivery_item_id: Uuid,
+    user_id: Uuid,
+    quantity: i64,
+) {
+    service
+        .pick_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            PickItemsRequest {
+                items: vec![PickItemRequest {
+                    delivery_item_id,
+                    picked_quantity: quantity,
+                }],
+            },
+        )
+        .await
+        .expect("Pick should succeed");
+    service
+        .pack_items(tenant_id, delivery_id, user_id, PackItemsRequest { notes: None })
+        .await
+        .expect("Pack should succeed");
+}
+
+#[tokio::test]
+async fn test_two_parcel_partial_shipment() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_delivery_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+    let (delivery_id, delivery_item_id) =
+        create_confirmed_delivery(&pool, tenant_id, warehouse_id, product_id, user_id, 10, 500)
+            .await;
+    pick_and_pack(&service, tenant_id, delivery_id, delivery_item_id, user_id, 10).await;
+
+    // First parcel: 4 of 10
+    let first = service
+        .ship_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            ShipItemsRequest {
+                items: Some(vec![ShipItemRequest {
+                    delivery_item_id,
+                    quantity: 4,
+                }]),
+                tracking_number: Some("PARCEL-1".to_string()),
+                carrier: None,
+                shipping_cost: Some(100),
+                notes: None,
+            },
+        )
+        .await
+        .expect("First parcel should ship");
+    assert_eq!(first.status, "partially_shipped");
+    assert_eq!(first.total_cogs, 2000);
+    assert_eq!(first.remaining[0].shipped_quantity, 4);
+    assert_eq!(first.remaining[0].remaining_quantity, 6);
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (90, 6, 0, 0));
+
+    // Second parcel: the rest (no explicit lines)
+    let second = service
+        .ship_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            ShipItemsRequest {
+                items: None,
+                tracking_number: Some("PARCEL-2".to_string()),
+                carrier: None,
+                shipping_cost: Some(150),
+                notes: None,
+            },
+        )
+        .await
+        .expect("Second parcel should ship");
+    assert_eq!(second.status, "shipped");
+    assert_ne!(first.shipment_id, second.shipment_id);
+    assert_eq!(second.remaining[0].shipped_quantity, 10);
+    assert_eq!(second.remaining[0].remaining_quantity, 0);
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (90, 0, 0, 0));
+
+    // One shipment record and one stock move per parcel
+    let shipment_quantities: Vec<i64> = sqlx::query_scalar(
+        "SELECT si.quantity FROM delivery_shipment_items si
+         JOIN delivery_shipments s ON s.shipment_id = si.shipment_id
+         WHERE s.tenant_id = $1 AND s.delivery_id = $2
+         ORDER BY s.shipped_at",
+    )
+    .bind(tenant_id)
+    .bind(delivery_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(shipment_quantities, vec![4, 6]);
+
+    let move_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM stock_moves
+         WHERE tenant_id = $1 AND reference_type = 'do' AND reference_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(delivery_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(move_count, 2);
+
+    let shipping_cost: Option<i64> =
+        sqlx::query_scalar("SELECT shipping_cost FROM delivery_orders WHERE delivery_id = $1")
+            .bind(delivery_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(shipping_cost, Some(250));
+
+    cleanup_delivery_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_ship_more_than_picked_is_rejected() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_delivery_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+    let (delivery_id, delivery_item_id) =
+        create_confirmed_delivery(&pool, tenant_id, warehouse_id, product_id, user_id, 10, 500)
+            .await;
+    pick_and_pack(&service, tenant_id, delivery_id, delivery_item_id, user_id, 10).await;
+
+    let result = service
+        .ship_items(
+            tenant_id,
+            delivery_id,
+            user_id,
+            ShipItemsRequest {
+                items: Some(vec![ShipItemRequest {
+                    delivery_item_id,
+                    quantity: 11,
+                }]),
+                tracking_number: None,
+                carrier: None,
+                shipping_cost: None,
+                notes: None,
+            },
+        )
+        .await;
+    assert!(result.is_err(), "Shipping more than picked should fail");
+
+    // Nothing shipped, reservation untouched
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (90, 10, 0, 0));
+    let shipments: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM delivery_shipments WHERE delivery_id = $1")
+            .bind(delivery_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(shipments, 0);
+
+    cleanup_delivery_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,107 @@
+//! HEAD Existence Check Tests
+//!
+//! `HEAD /products/{id}` and `HEAD /categories/{id}` answer 200 or 404 with an
+//! empty body, so clients can check existence without fetching the entity.
+
+mod business_logic_test_helpers;
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+    Router,
+};
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use helpers::create_catalog_test_app;
+use http_body_util::BodyExt;
+use inventory_service_core::services::category::CategoryService;
+use inventory_service_infra::repositories::CategoryRepositoryImpl;
+use inventory_service_infra::services::category::CategoryServiceImpl;
+use serde_json::json;
+use shared_jwt::{encode_jwt, Claims};
+use sqlx::PgPool;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Secret the catalog test app validates tokens with
+const JWT_SECRET: &str = "test_jwt_secret";
+
+/// Send a HEAD request as a user of the tenant; returns status and body length
+async fn head(app: &Router, tenant_id: Uuid, uri: &str) -> (StatusCode, usize) {
+    let claims = Claims::new_access(Uuid::now_v7(), tenant_id, "user".to_string(), 900);
+    let token = encode_jwt(&claims, JWT_SECRET).unwrap();
+    let request = Request::builder()
+        .method(Method::HEAD)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, body.len())
+}
+
+async fn create_category(pool: &PgPool, tenant_id: Uuid) -> Uuid {
+    let request = serde_json::from_value(json!({ "name": "Head Check", "code": "HEAD-CHECK" }))
+        .expect("Valid category request");
+    CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()))
+        .create_category(tenant_id, request)
+        .await
+        .expect("Category should be created")
+        .category_id
+}
+
+async fn cleanup_head_test_data(pool: &PgPool, tenant_id: Uuid) {
+    let _ = sqlx::query("DELETE FROM product_categories WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_head_product_reports_existence_without_body() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let (other_tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let app = create_catalog_test_app(pool.clone()).await;
+
+    let uri = format!("/api/v1/inventory/products/{}", product_id);
+    assert_eq!(head(&app, tenant_id, &uri).await, (StatusCode::OK, 0));
+
+    // Another tenant's product and an unknown ID are both absent
+    assert_eq!(head(&app, other_tenant_id, &uri).await, (StatusCode::NOT_FOUND, 0));
+    let missing = format!("/api/v1/inventory/products/{}", Uuid::now_v7());
+    assert_eq!(head(&app, tenant_id, &missing).await, (StatusCode::NOT_FOUND, 0));
+
+    // A soft-deleted product no longer exists
+    sqlx::query("UPDATE products SET deleted_at = NOW() WHERE product_id = $1")
+        .bind(product_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    assert_eq!(head(&app, tenant_id, &uri).await, (StatusCode::NOT_FOUND, 0));
+
+    cleanup_head_test_data(&pool, tenant_id).await;
+    cleanup_head_test_data(&pool, other_tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_head_category_reports_existence_without_body() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let category_id = create_category(&pool, tenant_id).await;
+    let app = create_catalog_test_app(pool.clone()).await;
+
+    let uri = format!("/api/v1/inventory/categories/{}", category_id);
+    assert_eq!(head(&app, tenant_id, &uri).await, (StatusCode::OK, 0));
+
+    let missing = format!("/api/v1/inventory/categories/{}", Uuid::now_v7());
+    assert_eq!(head(&app, tenant_id, &missing).await, (StatusCode::NOT_FOUND, 0));
+
+    cleanup_head_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,166 @@
+//! Expiry Scrap Proposal Integration Tests
+//!
+//! Lots expired beyond the grace period get a draft scrap document with an
+//! `Expired` line for their remaining quantity; lots within the grace period
+//! and lots already proposed are left alone.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, create_user, setup_test_pool, setup_test_tenant_product_warehouse,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use inventory_service_core::dto::scrap::{ScrapReasonCode, ScrapStatus};
+use inventory_service_core::services::scrap::ScrapService;
+use inventory_service_infra::services::PgScrapService;
+use shared_types::MockClock;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[allow(clippy::too_many_arguments)]
+async fn create_lot(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    product_id: Uuid,
+    warehouse_id: Uuid,
+    created_by: Uuid,
+    lot_number: &str,
+    remaining_quantity: i64,
+    expiry_date: DateTime<Utc>,
+) -> Uuid {
+    let lot_id = Uuid::now_v7();
+    sqlx::query(
+        "INSERT INTO lots_serial_numbers (lot_serial_id, tenant_id, product_id, warehouse_id,
+             tracking_type, lot_number, initial_quantity, remaining_quantity, expiry_date,
+             status, created_by, created_at)
+         VALUES ($1, $2, $3, $4, 'lot', $5, $6, $6, $7, 'quarantined', $8, NOW())",
+    )
+    .bind(lot_id)
+    .bind(tenant_id)
+    .bind(product_id)
+    .bind(warehouse_id)
+    .bind(lot_number)
+    .bind(remaining_quantity)
+    .bind(expiry_date)
+    .bind(created_by)
+    .execute(pool)
+    .await
+    .expect("Failed to insert lot");
+    lot_id
+}
+
+async fn cleanup_expiry_test_data(pool: &PgPool, tenant_id: Uuid) {
+    for table in [
+        "scrap_lines",
+        "scrap_documents",
+        "lots_serial_numbers",
+        "users",
+    ] {
+        let _ = sqlx::query(&format!("DELETE FROM {} WHERE tenant_id = $1", table))
+            .bind(tenant_id)
+            .execute(pool)
+            .await;
+    }
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_lot_expired_beyond_grace_gets_draft_scrap() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+
+    let stale_lot = create_lot(
+        &pool,
+        tenant_id,
+        product_id,
+        warehouse_id,
+        user_id,
+        "LOT-OLD",
+        40,
+        Utc::now() - Duration::days(30),
+    )
+    .await;
+    // Expired, but still within the grace period
+    create_lot(
+        &pool,
+        tenant_id,
+        product_id,
+        warehouse_id,
+        user_id,
+        "LOT-NEW",
+        15,
+        Utc::now() - Duration::days(2),
+    )
+    .await;
+
+    let service = PgScrapService::new(Arc::new(pool.clone()));
+    let proposals = service
+        .propose_expired_lot_scraps(tenant_id, 7)
+        .await
+        .expect("Proposal run should succeed");
+
+    assert_eq!(proposals.len(), 1);
+    let proposal = &proposals[0];
+    assert_eq!(proposal.scrap.status, ScrapStatus::Draft);
+    assert_eq!(proposal.scrap.scrap_location_id, warehouse_id);
+    assert_eq!(proposal.lines.len(), 1);
+
+    let line = &proposal.lines[0];
+    assert_eq!(line.lot_id, Some(stale_lot));
+    assert_eq!(line.product_id, product_id);
+    assert_eq!(line.qty, 40);
+    assert_eq!(line.reason_code, Some(ScrapReasonCode::Expired));
+
+    // A second run does not propose the same lot again
+    let again = service
+        .propose_expired_lot_scraps(tenant_id, 7)
+        .await
+        .expect("Repeated proposal run should succeed");
+    assert!(again.is_empty());
+
+    cleanup_expiry_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_grace_period_boundary_follows_injected_clock() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+
+    let expiry_date = Utc.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap();
+    let lot = create_lot(
+        &pool,
+        tenant_id,
+        product_id,
+        warehouse_id,
+        user_id,
+        "LOT-EDGE",
+        10,
+        expiry_date,
+    )
+    .await;
+
+    // Exactly at the end of the grace period the lot is not yet overdue
+    let clock = MockClock::new(expiry_date + Duration::days(7));
+    let service = PgScrapService::new(Arc::new(pool.clone())).with_clock(Arc::new(clock.clone()));
+    let proposals = service
+        .propose_expired_lot_scraps(tenant_id, 7)
+        .await
+        .expect("Proposal run should succeed");
+    assert!(proposals.is_empty());
+
+    // One second later it is
+    clock.advance(Duration::seconds(1));
+    let proposals = service
+        .propose_expired_lot_scraps(tenant_id, 7)
+        .await
+        .expect("Proposal run should succeed");
+    assert_eq!(proposals.len(), 1);
+    assert_eq!(proposals[0].lines[0].lot_id, Some(lot));
+    assert_eq!(proposals[0].scrap.reference.as_deref(), Some("EXPIRY-20250308"));
+
+    cleanup_expiry_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,188 @@
+//! gRPC Read API Integration Tests
+//!
+//! Starts the gRPC server on an ephemeral port and calls it through the
+//! generated client.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, create_inventory_level, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_api::grpc::{
+    self,
+    proto::{inventory_read_service_client::InventoryReadServiceClient, CheckAvailabilityRequest},
+};
+use shared_auth::casbin::{CoreApi, DefaultModel, Enforcer, MemoryAdapter};
+use shared_auth::{add_policy, add_role_for_user, AuthzState, SharedEnforcer};
+use shared_error::grpc::ERROR_CODE_METADATA_KEY;
+use shared_jwt::{encode_jwt, Claims, InMemoryTokenDenylist, TokenDenylist};
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+use tonic::transport::Channel;
+use tonic::{Code, Request};
+use uuid::Uuid;
+
+const JWT_SECRET: &str = "grpc-test-secret";
+const READ_SERVICE_PATH: &str = "/anthill.inventory.v1.InventoryReadService/*";
+
+/// Authorization state where each `(user_id, tenant_id)` may call the read service
+async fn authz_state(grants: &[(Uuid, Uuid)], denylist: Arc<InMemoryTokenDenylist>) -> AuthzState {
+    let model = DefaultModel::from_file(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../../shared/auth/model.conf"
+    ))
+    .await
+    .unwrap();
+    let enforcer: SharedEnforcer = Arc::new(RwLock::new(
+        Enforcer::new(model, MemoryAdapter::default())
+            .await
+            .unwrap(),
+    ));
+    for (user_id, tenant_id) in grants {
+        let tenant = tenant_id.to_string();
+        add_policy(&enforcer, "user", &tenant, READ_SERVICE_PATH, "POST")
+            .await
+            .unwrap();
+        add_role_for_user(&enforcer, &user_id.to_string(), "user", &tenant)
+            .await
+            .unwrap();
+    }
+
+    AuthzState {
+        enforcer,
+        jwt_secret: JWT_SECRET.to_string(),
+        denylist,
+    }
+}
+
+async fn start_grpc_server(
+    pool: sqlx::PgPool,
+    authz: AuthzState,
+) -> InventoryReadServiceClient<Channel> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        grpc::serve(listener, pool, authz, std::future::pending())
+            .await
+            .expect("gRPC server failed");
+    });
+
+    InventoryReadServiceClient::connect(format!("http://{}", addr))
+        .await
+        .expect("Failed to connect to gRPC server")
+}
+
+fn with_token<T>(message: T, claims: &Claims) -> Request<T> {
+    let token = encode_jwt(claims, JWT_SECRET).unwrap();
+    let mut request = Request::new(message);
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {}", token).parse().unwrap());
+    request
+}
+
+fn authorized<T>(message: T, user_id: Uuid, tenant_id: Uuid) -> Request<T> {
+    with_token(message, &Claims::new_access(user_id, tenant_id, "user".to_string(), 900))
+}
+
+#[tokio::test]
+async fn test_check_availability_over_grpc() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 25).await;
+    let user_id = Uuid::now_v7();
+    let (other_user, other_tenant_id) = (Uuid::now_v7(), Uuid::now_v7());
+    let authz = authz_state(
+        &[(user_id, tenant_id), (other_user, other_tenant_id)],
+        Arc::new(InMemoryTokenDenylist::new()),
+    )
+    .await;
+    let mut client = start_grpc_server(pool.clone(), authz).await;
+
+    let check = |quantity| CheckAvailabilityRequest {
+        warehouse_id: warehouse_id.to_string(),
+        product_id: product_id.to_string(),
+        quantity,
+    };
+
+    let enough = client
+        .check_availability(authorized(check(10), user_id, tenant_id))
+        .await
+        .expect("CheckAvailability should succeed")
+        .into_inner();
+    assert!(enough.is_available);
+    assert_eq!(enough.available_quantity, 25);
+    assert_eq!(enough.requested_quantity, 10);
+
+    let short = client
+        .check_availability(authorized(check(30), user_id, tenant_id))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(!short.is_available);
+    assert_eq!(short.available_quantity, 25);
+
+    // Another tenant cannot see the product
+    let other_tenant = client
+        .check_availability(authorized(check(1), other_user, other_tenant_id))
+        .await
+        .unwrap_err();
+    assert_eq!(other_tenant.code(), Code::NotFound);
+
+    // Errors carry the same code as the REST envelope
+    let invalid = client
+        .check_availability(authorized(check(0), user_id, tenant_id))
+        .await
+        .unwrap_err();
+    assert_eq!(invalid.code(), Code::InvalidArgument);
+    assert_eq!(invalid.metadata().get(ERROR_CODE_METADATA_KEY).unwrap(), "VALIDATION_ERROR");
+
+    let unauthenticated = client
+        .check_availability(Request::new(check(1)))
+        .await
+        .unwrap_err();
+    assert_eq!(unauthenticated.code(), Code::Unauthenticated);
+
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_grpc_enforces_policies_and_revocation() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 25).await;
+    let user_id = Uuid::now_v7();
+    let denylist = Arc::new(InMemoryTokenDenylist::new());
+    let authz = authz_state(&[(user_id, tenant_id)], denylist.clone()).await;
+    let mut client = start_grpc_server(pool.clone(), authz).await;
+
+    let check = || CheckAvailabilityRequest {
+        warehouse_id: warehouse_id.to_string(),
+        product_id: product_id.to_string(),
+        quantity: 1,
+    };
+
+    // A valid token without a policy for the RPC is refused
+    let denied = client
+        .check_availability(authorized(check(), Uuid::now_v7(), tenant_id))
+        .await
+        .unwrap_err();
+    assert_eq!(denied.code(), Code::PermissionDenied);
+
+    // A token revoked at logout is refused even though its user is allowed
+    let claims = Claims::new_access(user_id, tenant_id, "user".to_string(), 900);
+    client
+        .check_availability(with_token(check(), &claims))
+        .await
+        .expect("Token should be accepted before logout");
+    denylist.revoke(claims.jti, claims.exp).await.unwrap();
+    let revoked = client
+        .check_availability(with_token(check(), &claims))
+        .await
+        .unwrap_err();
+    assert_eq!(revoked.code(), Code::Unauthenticated);
+
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
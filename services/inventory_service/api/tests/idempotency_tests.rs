@@ -0,0 +1,217 @@
+//! Idempotency Middleware Tests
+//!
+//! Repeats of a completed key replay its stored response, repeats of a running
+//! key are conflicts, and a key left in flight by
+//! a crashed request can be claimed again once it is stale. Keys are scoped
+//! to the calling user, so different users may reuse the same key.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request as ExtractRequest,
+    http::{header, Method, Request, StatusCode},
+    middleware::{from_fn, from_fn_with_state, Next},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use inventory_service_api::middleware::{
+    idempotency_middleware, scoped_key, IdempotencyClaim, IdempotencyConfig, IdempotencyCounts,
+    IdempotencyState, REPLAYED_HEADER,
+};
+use serde_json::json;
+use shared_auth::AuthUser;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const STALE_SECONDS: u64 = 1;
+
+fn idempotency_state() -> Arc<IdempotencyState> {
+    let redis_url =
+        std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://localhost:6379".to_string());
+    Arc::new(
+        IdempotencyState::new(IdempotencyConfig {
+            redis_url,
+            ttl_seconds: 60,
+            in_flight_stale_seconds: STALE_SECONDS,
+            ..Default::default()
+        })
+        .expect("Failed to create idempotency state"),
+    )
+}
+
+/// Redis key the middleware uses for an unauthenticated `POST /`
+fn anonymous_key(key: &str) -> String {
+    scoped_key(None, &Method::POST, "/", key)
+}
+
+async fn post_with_key(state: &Arc<IdempotencyState>, key: &str) -> StatusCode {
+    let app = Router::new()
+        .route("/", post(|| async { "ok" }))
+        .layer(from_fn_with_state(state.clone(), idempotency_middleware));
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/")
+        .header("x-idempotency-key", key)
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+/// Stands in for the auth middleware: authenticates the user named in `x-test-user`
+async fn test_auth(mut request: ExtractRequest, next: Next) -> Response {
+    let user_id = request
+        .headers()
+        .get("x-test-user")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| Uuid::parse_str(v).ok());
+    if let Some(user_id) = user_id {
+        request.extensions_mut().insert(AuthUser {
+            user_id,
+            tenant_id: Uuid::nil(),
+            role: "user".to_string(),
+            email: None,
+        });
+    }
+    next.run(request).await
+}
+
+async fn post_as_user(state: &Arc<IdempotencyState>, user_id: Uuid, key: &str) -> StatusCode {
+    let app = Router::new()
+        .route("/receipts", post(|| async { "ok" }))
+        .layer(from_fn_with_state(state.clone(), idempotency_middleware))
+        .layer(from_fn(test_auth));
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/receipts")
+        .header("x-idempotency-key", key)
+        .header("x-test-user", user_id.to_string())
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+async fn wait_until_stale() {
+    tokio::time::sleep(Duration::from_millis(STALE_SECONDS * 1000 + 200)).await;
+}
+
+#[tokio::test]
+async fn test_crashed_key_is_reclaimable_after_stale_window() {
+    let state = idempotency_state();
+    let key = format!("crashed-{}", Uuid::now_v7());
+
+    // A request claimed the key and its process died before completing it
+    assert!(matches!(
+        state.claim(&anonymous_key(&key)).await.unwrap(),
+        IdempotencyClaim::Claimed(_)
+    ));
+
+    // While the claim is fresh, a retry is a conflict
+    assert_eq!(post_with_key(&state, &key).await, StatusCode::CONFLICT);
+    assert_eq!(
+        state.counts(),
+        IdempotencyCounts {
+            hit: 1,
+            replay: 0,
+            conflict: 1
+        }
+    );
+
+    // Once stale, the retry takes the key over and runs
+    wait_until_stale().await;
+    assert_eq!(post_with_key(&state, &key).await, StatusCode::OK);
+
+    // The completed request is now replayed rather than conflicting
+    assert_eq!(post_with_key(&state, &key).await, StatusCode::OK);
+    assert_eq!(
+        state.counts(),
+        IdempotencyCounts {
+            hit: 2,
+            replay: 1,
+            conflict: 1
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_watchdog_clears_only_stale_in_flight_keys() {
+    let state = idempotency_state();
+    let crashed = anonymous_key(&format!("watchdog-crashed-{}", Uuid::now_v7()));
+    let completed_key = format!("watchdog-completed-{}", Uuid::now_v7());
+    let completed = anonymous_key(&completed_key);
+
+    assert!(matches!(state.claim(&crashed).await.unwrap(), IdempotencyClaim::Claimed(_)));
+    assert_eq!(post_with_key(&state, &completed_key).await, StatusCode::OK);
+
+    wait_until_stale().await;
+    let fresh = format!("watchdog-fresh-{}", Uuid::now_v7());
+    assert!(matches!(state.claim(&fresh).await.unwrap(), IdempotencyClaim::Claimed(_)));
+
+    assert!(state.clear_stale_in_flight().await.unwrap() >= 1);
+
+    // The crashed key is free again, the fresh and completed ones are untouched
+    assert!(matches!(state.claim(&crashed).await.unwrap(), IdempotencyClaim::Claimed(_)));
+    assert_eq!(state.claim(&fresh).await.unwrap(), IdempotencyClaim::InFlight);
+    assert_eq!(state.claim(&completed).await.unwrap(), IdempotencyClaim::Processed);
+}
+
+#[tokio::test]
+async fn test_same_key_from_different_users_does_not_collide() {
+    let state = idempotency_state();
+    let key = format!("shared-{}", Uuid::now_v7());
+    let (alice, bob) = (Uuid::now_v7(), Uuid::now_v7());
+
+    // Both users happen to generate the same key: each request runs
+    assert_eq!(post_as_user(&state, alice, &key).await, StatusCode::OK);
+    assert_eq!(post_as_user(&state, bob, &key).await, StatusCode::OK);
+
+    // Each user's own repeat is still recognised as a replay
+    assert_eq!(post_as_user(&state, alice, &key).await, StatusCode::OK);
+    assert_eq!(post_as_user(&state, bob, &key).await, StatusCode::OK);
+    assert_eq!(state.counts().replay, 2);
+}
+
+#[tokio::test]
+async fn test_repeat_replays_original_response() {
+    let state = idempotency_state();
+    let key = format!("replay-{}", Uuid::now_v7());
+
+    // Every execution creates a new resource, so a rerun would be visible
+    let app = Router::new()
+        .route(
+            "/",
+            post(|| async {
+                (StatusCode::CREATED, Json(json!({ "receipt_id": Uuid::now_v7() }))).into_response()
+            }),
+        )
+        .layer(from_fn_with_state(state.clone(), idempotency_middleware));
+    let send = || {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/")
+                .header("x-idempotency-key", &key)
+                .body(Body::empty())
+                .unwrap(),
+        )
+    };
+
+    let original = send().await.unwrap();
+    assert_eq!(original.status(), StatusCode::CREATED);
+    assert!(original.headers().get(REPLAYED_HEADER).is_none());
+    let original_type = original.headers().get(header::CONTENT_TYPE).cloned();
+    let original_body = to_bytes(original.into_body(), usize::MAX).await.unwrap();
+
+    let replayed = send().await.unwrap();
+    assert_eq!(replayed.status(), StatusCode::CREATED);
+    assert_eq!(replayed.headers().get(REPLAYED_HEADER).unwrap(), "true");
+    assert_eq!(replayed.headers().get(header::CONTENT_TYPE).cloned(), original_type);
+    let replayed_body = to_bytes(replayed.into_body(), usize::MAX).await.unwrap();
+
+    assert_eq!(replayed_body, original_body);
+    assert_eq!(state.counts().replay, 1);
+}
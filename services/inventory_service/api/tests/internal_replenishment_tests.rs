@@ -0,0 +1,195 @@
+//! Internal Replenishment Integration Tests
+//!
+//! Warehouse-to-warehouse resupply: a destination below its minimum is topped
+//! up from a source warehouse, but only out of the source's surplus.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_inventory_level, create_replenishment_service,
+    create_transfer_service, create_user, create_warehouse, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::domains::replenishment::{
+    draft_transfer_requests, CreateInternalReplenishmentRule,
+};
+use inventory_service_core::services::{ReplenishmentService, TransferService};
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn cleanup_internal_replenishment_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "internal_replenishment_rules",
+            "stock_transfer_items",
+            "stock_transfers",
+            "users",
+        ],
+    )
+    .await;
+}
+
+fn store_rule(
+    product_id: Uuid,
+    from_warehouse_id: Uuid,
+    to_warehouse_id: Uuid,
+) -> CreateInternalReplenishmentRule {
+    CreateInternalReplenishmentRule {
+        product_id,
+        from_warehouse_id,
+        to_warehouse_id,
+        min_quantity: 20,
+        max_quantity: 100,
+        source_safety_stock: 50,
+    }
+}
+
+#[tokio::test]
+async fn test_low_destination_with_stocked_source_suggests_transfer() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, central_dc) = setup_test_tenant_product_warehouse(&pool).await;
+    let store = create_warehouse(&pool, tenant_id, "Store").await;
+    let service = create_replenishment_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, central_dc, 500).await;
+    create_inventory_level(&pool, tenant_id, product_id, store, 5).await;
+    let rule = service
+        .create_internal_replenishment_rule(tenant_id, store_rule(product_id, central_dc, store))
+        .await
+        .expect("Rule should be created");
+
+    let suggestions = service
+        .suggest_internal_transfers(tenant_id)
+        .await
+        .expect("Suggestions should load");
+
+    assert_eq!(suggestions.len(), 1);
+    let suggestion = &suggestions[0];
+    assert_eq!(suggestion.rule_id, rule.rule_id);
+    assert_eq!(suggestion.from_warehouse_id, central_dc);
+    assert_eq!(suggestion.to_warehouse_id, store);
+    assert_eq!(suggestion.destination_quantity, 5);
+    assert_eq!(suggestion.source_quantity, 500);
+    // Tops the store up to max: 100 - 5
+    assert_eq!(suggestion.suggested_quantity, 95);
+
+    // Capped by the source surplus above its safety stock: 80 - 50
+    create_inventory_level(&pool, tenant_id, product_id, central_dc, 80).await;
+    let suggestions = service.suggest_internal_transfers(tenant_id).await.unwrap();
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].suggested_quantity, 30);
+
+    cleanup_internal_replenishment_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_low_source_yields_no_suggestion() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, central_dc) = setup_test_tenant_product_warehouse(&pool).await;
+    let store = create_warehouse(&pool, tenant_id, "Store").await;
+    let service = create_replenishment_service(&pool);
+
+    // The source only holds its own safety stock
+    create_inventory_level(&pool, tenant_id, product_id, central_dc, 50).await;
+    create_inventory_level(&pool, tenant_id, product_id, store, 5).await;
+    service
+        .create_internal_replenishment_rule(tenant_id, store_rule(product_id, central_dc, store))
+        .await
+        .unwrap();
+
+    let suggestions = service.suggest_internal_transfers(tenant_id).await.unwrap();
+    assert!(suggestions.is_empty(), "unexpected suggestions: {:?}", suggestions);
+
+    // A destination above its minimum needs nothing either
+    create_inventory_level(&pool, tenant_id, product_id, central_dc, 500).await;
+    create_inventory_level(&pool, tenant_id, product_id, store, 40).await;
+    let suggestions = service.suggest_internal_transfers(tenant_id).await.unwrap();
+    assert!(suggestions.is_empty(), "unexpected suggestions: {:?}", suggestions);
+
+    cleanup_internal_replenishment_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_shared_source_surplus_is_split_across_rules() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, central_dc) = setup_test_tenant_product_warehouse(&pool).await;
+    let north = create_warehouse(&pool, tenant_id, "North Store").await;
+    let south = create_warehouse(&pool, tenant_id, "South Store").await;
+    let service = create_replenishment_service(&pool);
+
+    // Surplus above safety stock: 120 - 50 = 70, while each store wants 95
+    create_inventory_level(&pool, tenant_id, product_id, central_dc, 120).await;
+    create_inventory_level(&pool, tenant_id, product_id, north, 5).await;
+    create_inventory_level(&pool, tenant_id, product_id, south, 5).await;
+    for store in [north, south] {
+        service
+            .create_internal_replenishment_rule(
+                tenant_id,
+                store_rule(product_id, central_dc, store),
+            )
+            .await
+            .unwrap();
+    }
+
+    let suggestions = service.suggest_internal_transfers(tenant_id).await.unwrap();
+    assert_eq!(suggestions.len(), 1, "surplus was double-counted: {:?}", suggestions);
+    assert_eq!(suggestions[0].to_warehouse_id, north);
+    assert_eq!(suggestions[0].suggested_quantity, 70);
+
+    cleanup_internal_replenishment_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_open_draft_transfers_count_toward_projection() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, central_dc) = setup_test_tenant_product_warehouse(&pool).await;
+    let store = create_warehouse(&pool, tenant_id, "Store").await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_replenishment_service(&pool);
+    let transfer_service = create_transfer_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, central_dc, 500).await;
+    create_inventory_level(&pool, tenant_id, product_id, store, 5).await;
+    service
+        .create_internal_replenishment_rule(tenant_id, store_rule(product_id, central_dc, store))
+        .await
+        .unwrap();
+
+    let suggestions = service.suggest_internal_transfers(tenant_id).await.unwrap();
+    assert_eq!(suggestions.len(), 1);
+    let created = transfer_service
+        .create_transfers(tenant_id, user_id, draft_transfer_requests(&suggestions))
+        .await
+        .expect("Drafts should be created");
+    assert_eq!(created.len(), 1);
+
+    // The draft already tops the store up, so nothing more is suggested
+    let suggestions = service.suggest_internal_transfers(tenant_id).await.unwrap();
+    assert!(suggestions.is_empty(), "unexpected suggestions: {:?}", suggestions);
+
+    cleanup_internal_replenishment_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_internal_rule_requires_distinct_warehouses() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let service = create_replenishment_service(&pool);
+
+    let result = service
+        .create_internal_replenishment_rule(
+            tenant_id,
+            store_rule(product_id, warehouse_id, warehouse_id),
+        )
+        .await;
+    assert!(
+        matches!(result, Err(AppError::ValidationError(_))),
+        "unexpected result: {:?}",
+        result
+    );
+
+    cleanup_internal_replenishment_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,171 @@
+//! Inventory Level History Integration Tests
+//!
+//! Records receipt, transfer and adjustment moves through the stock move
+//! repository and checks the per-location timeline and running balances.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_location, setup_test_pool, setup_test_tenant_product_warehouse,
+};
+use chrono::Utc;
+use inventory_service_core::models::{InventoryLevelHistoryEntry, MoveIntent, MoveSourceType};
+use inventory_service_core::repositories::{InventoryLevelRepository, StockMoveRepository};
+use inventory_service_infra::repositories::stock::{
+    PgInventoryLevelRepository, PgStockMoveRepository,
+};
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn cleanup_history_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "inventory_level_history",
+            "stock_moves",
+            "warehouse_locations",
+        ],
+    )
+    .await;
+}
+
+fn intent(
+    source_type: MoveSourceType,
+    product_id: Uuid,
+    quantity: i64,
+    source: Option<Uuid>,
+    destination: Option<Uuid>,
+) -> MoveIntent {
+    let source_id = Uuid::now_v7();
+    MoveIntent::new(
+        source_type,
+        source_id,
+        source_type.as_str(),
+        product_id,
+        quantity,
+        format!("history-test-{}", source_id),
+    )
+    .with_locations(source, destination)
+}
+
+fn timeline(entries: &[InventoryLevelHistoryEntry]) -> Vec<(&str, i64, i64)> {
+    entries
+        .iter()
+        .map(|entry| (entry.move_type.as_str(), entry.quantity_change, entry.balance))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_moves_produce_ordered_history_with_running_balances() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let shelf = create_location(&pool, tenant_id, warehouse_id, "HIST-A", None).await;
+    let overflow = create_location(&pool, tenant_id, warehouse_id, "HIST-B", None).await;
+    let adjustments = create_location(&pool, tenant_id, warehouse_id, "HIST-ADJ", None).await;
+
+    let pool_arc = Arc::new(pool.clone());
+    let moves = PgStockMoveRepository::new(pool_arc.clone());
+    let levels = PgInventoryLevelRepository::new(pool_arc);
+
+    let receipt = moves
+        .record(&intent(MoveSourceType::Receipt, product_id, 10, None, Some(shelf)), tenant_id)
+        .await
+        .expect("Receipt should be recorded");
+    moves
+        .record(
+            &intent(MoveSourceType::Transfer, product_id, 4, Some(shelf), Some(overflow)),
+            tenant_id,
+        )
+        .await
+        .expect("Transfer should be recorded");
+    // Decrease adjustments move stock from the shelf into the adjustment location
+    moves
+        .record(
+            &intent(MoveSourceType::Adjustment, product_id, -1, Some(shelf), Some(adjustments)),
+            tenant_id,
+        )
+        .await
+        .expect("Adjustment should be recorded");
+    let reversal = moves
+        .reverse_move(tenant_id, receipt.move_id)
+        .await
+        .expect("Receipt should be reversible");
+
+    // Replaying a move is idempotent and must not append history twice
+    let replay = intent(MoveSourceType::Receipt, product_id, 10, None, Some(shelf));
+    moves.record(&replay, tenant_id).await.unwrap();
+    moves.record(&replay, tenant_id).await.unwrap();
+
+    let shelf_history = levels
+        .history(tenant_id, shelf, product_id, None, None)
+        .await
+        .expect("History should load");
+    assert_eq!(
+        timeline(&shelf_history),
+        vec![
+            ("receipt", 10, 10),
+            ("transfer", -4, 6),
+            ("adjustment", -1, 5),
+            ("reversal", -10, -5),
+            ("receipt", 10, 5),
+        ]
+    );
+    assert_eq!(shelf_history[0].move_id, receipt.move_id);
+    assert_eq!(shelf_history[3].move_id, reversal.move_id);
+    assert!(shelf_history
+        .windows(2)
+        .all(|pair| pair[0].history_id < pair[1].history_id));
+
+    let overflow_history = levels
+        .history(tenant_id, overflow, product_id, None, None)
+        .await
+        .unwrap();
+    assert_eq!(timeline(&overflow_history), vec![("transfer", 4, 4)]);
+
+    // Another product at the same location has its own timeline
+    let other_product = levels
+        .history(tenant_id, shelf, Uuid::now_v7(), None, None)
+        .await
+        .unwrap();
+    assert!(other_product.is_empty());
+
+    cleanup_history_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_history_range_keeps_balances_from_earlier_changes() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let shelf = create_location(&pool, tenant_id, warehouse_id, "HIST-R", None).await;
+
+    let pool_arc = Arc::new(pool.clone());
+    let moves = PgStockMoveRepository::new(pool_arc.clone());
+    let levels = PgInventoryLevelRepository::new(pool_arc);
+
+    moves
+        .record(&intent(MoveSourceType::Receipt, product_id, 7, None, Some(shelf)), tenant_id)
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    let cutoff = Utc::now();
+    moves
+        .record(&intent(MoveSourceType::Receipt, product_id, 3, None, Some(shelf)), tenant_id)
+        .await
+        .unwrap();
+
+    let after_cutoff = levels
+        .history(tenant_id, shelf, product_id, Some(cutoff), None)
+        .await
+        .unwrap();
+    assert_eq!(timeline(&after_cutoff), vec![("receipt", 3, 10)]);
+
+    let before_cutoff = levels
+        .history(tenant_id, shelf, product_id, None, Some(cutoff))
+        .await
+        .unwrap();
+    assert_eq!(timeline(&before_cutoff), vec![("receipt", 7, 7)]);
+
+    cleanup_history_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,128 @@
+//! Inventory Snapshot Integration Tests
+//!
+//! Restoring a snapshot brings every level back to its captured value through
+//! compensating adjustment moves, never by overwriting the levels.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    available, cleanup_tenant_tables, create_inventory_level, create_product, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::dto::adjustment::{AdjustmentReasonCode, QuickAdjustmentRequest};
+use inventory_service_core::services::adjustment::AdjustmentService;
+use inventory_service_core::services::inventory_snapshot::InventorySnapshotService;
+use inventory_service_infra::services::{PgAdjustmentService, PgInventorySnapshotService};
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// (product, quantity) of the restore moves recorded for a snapshot
+async fn restore_moves(pool: &PgPool, tenant_id: Uuid, snapshot_id: Uuid) -> Vec<(Uuid, i64)> {
+    sqlx::query_as(
+        "SELECT product_id, quantity::BIGINT FROM stock_moves
+         WHERE tenant_id = $1 AND source_type = 'adjustment' AND source_id = $2
+           AND reason_code = 'snapshot_restore'
+         ORDER BY product_id",
+    )
+    .bind(tenant_id)
+    .bind(snapshot_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+async fn cleanup_snapshot_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "inventory_level_history",
+            "stock_moves",
+            "inventory_snapshot_levels",
+            "inventory_snapshots",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_restore_returns_every_level_to_its_snapshot_value() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, adjusted, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let overwritten = create_product(&pool, tenant_id).await;
+    let added_later = create_product(&pool, tenant_id).await;
+    let admin_id = Uuid::now_v7();
+
+    create_inventory_level(&pool, tenant_id, adjusted, warehouse_id, 10).await;
+    create_inventory_level(&pool, tenant_id, overwritten, warehouse_id, 4).await;
+
+    let service = PgInventorySnapshotService::new(Arc::new(pool.clone()));
+    let snapshot = service.snapshot(tenant_id, admin_id).await.unwrap();
+    assert_eq!(snapshot.level_count, 2);
+
+    // A bulk operation goes wrong: one level adjusted, one rewritten, one created
+    PgAdjustmentService::new(Arc::new(pool.clone()))
+        .adjust(
+            tenant_id,
+            admin_id,
+            QuickAdjustmentRequest {
+                warehouse_id,
+                location_id: None,
+                product_id: adjusted,
+                delta: -3,
+                reason_code: AdjustmentReasonCode::Damaged,
+                note: None,
+            },
+        )
+        .await
+        .unwrap();
+    create_inventory_level(&pool, tenant_id, overwritten, warehouse_id, 9).await;
+    create_inventory_level(&pool, tenant_id, added_later, warehouse_id, 5).await;
+
+    let restored = service
+        .restore(tenant_id, snapshot.snapshot_id, admin_id)
+        .await
+        .unwrap();
+    assert_eq!(restored.levels_adjusted, 3);
+
+    assert_eq!(available(&pool, tenant_id, warehouse_id, adjusted).await, 10);
+    assert_eq!(available(&pool, tenant_id, warehouse_id, overwritten).await, 4);
+    assert_eq!(available(&pool, tenant_id, warehouse_id, added_later).await, 0);
+
+    // Each difference is recorded as a compensating move
+    let mut expected = vec![(adjusted, 3), (overwritten, -5), (added_later, -5)];
+    expected.sort();
+    assert_eq!(restore_moves(&pool, tenant_id, snapshot.snapshot_id).await, expected);
+
+    // Nothing drifted since, so restoring again records no moves
+    let again = service
+        .restore(tenant_id, snapshot.snapshot_id, admin_id)
+        .await
+        .unwrap();
+    assert_eq!(again.levels_adjusted, 0);
+    assert_eq!(restore_moves(&pool, tenant_id, snapshot.snapshot_id).await, expected);
+
+    cleanup_snapshot_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_restore_of_other_tenants_snapshot_is_not_found() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let (other_tenant_id, _, _) = setup_test_tenant_product_warehouse(&pool).await;
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 7).await;
+
+    let service = PgInventorySnapshotService::new(Arc::new(pool.clone()));
+    let snapshot = service.snapshot(tenant_id, Uuid::now_v7()).await.unwrap();
+
+    let result = service
+        .restore(other_tenant_id, snapshot.snapshot_id, Uuid::now_v7())
+        .await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_snapshot_test_data(&pool, tenant_id).await;
+    cleanup_snapshot_test_data(&pool, other_tenant_id).await;
+}
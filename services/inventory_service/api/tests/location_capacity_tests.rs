@@ -0,0 +1,126 @@
+//! Warehouse Location Capacity Integration Tests
+//!
+//! Available capacity is the location's limit minus the stock on hand, and
+//! putaway is refused when an allocation would not fit.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_location, setup_test_pool, setup_test_tenant_product_warehouse,
+    stock_at,
+};
+use inventory_service_core::models::{ConfirmPutawayRequest, PutawayAllocation};
+use inventory_service_core::repositories::putaway::PutawayService;
+use inventory_service_core::repositories::warehouse::WarehouseRepository;
+use inventory_service_infra::repositories::{
+    PgPutawayRepository, PgStockMoveRepository, WarehouseRepositoryImpl,
+};
+use inventory_service_infra::services::PgPutawayService;
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn cleanup_capacity_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "inventory_level_history",
+            "stock_moves",
+            "inventory_levels",
+            "warehouse_locations",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_available_capacity_subtracts_on_hand_stock() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let repo = WarehouseRepositoryImpl::new(pool.clone());
+
+    let bin = create_location(&pool, tenant_id, warehouse_id, "BIN-C", Some(100)).await;
+    let open_floor = create_location(&pool, tenant_id, warehouse_id, "FLOOR", None).await;
+
+    assert_eq!(repo.get_available_capacity(tenant_id, bin).await.unwrap(), Some(100));
+
+    // Reserved stock still occupies the location
+    stock_at(&pool, tenant_id, warehouse_id, bin, product_id, 30, 10).await;
+    assert_eq!(repo.get_available_capacity(tenant_id, bin).await.unwrap(), Some(60));
+    let location = repo
+        .find_location_by_id(tenant_id, bin)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(location.max_capacity_units, Some(100));
+
+    // No limit means unlimited
+    assert_eq!(
+        repo.get_available_capacity(tenant_id, open_floor)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let result = repo.get_available_capacity(tenant_id, Uuid::now_v7()).await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_capacity_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_putaway_rejects_allocation_beyond_available_capacity() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let warehouse_repo = Arc::new(WarehouseRepositoryImpl::new(pool.clone()));
+    let service = PgPutawayService::new(
+        Arc::new(PgPutawayRepository::new(pool.clone())),
+        PgStockMoveRepository::new(Arc::new(pool.clone())),
+    )
+    .with_warehouse_repository(warehouse_repo);
+
+    let bin = create_location(&pool, tenant_id, warehouse_id, "BIN-P", Some(25)).await;
+    stock_at(&pool, tenant_id, warehouse_id, bin, product_id, 20, 0).await;
+
+    // Two lines of 3 fit individually but not together
+    let request = ConfirmPutawayRequest {
+        product_id,
+        allocations: vec![
+            PutawayAllocation {
+                location_id: bin,
+                quantity: 3,
+                unit_cost: None,
+            },
+            PutawayAllocation {
+                location_id: bin,
+                quantity: 3,
+                unit_cost: None,
+            },
+        ],
+        reference_type: "receipt".to_string(),
+        reference_id: Uuid::now_v7(),
+    };
+    let result = service
+        .confirm_putaway(&tenant_id, &request, &Uuid::now_v7())
+        .await;
+    assert!(
+        matches!(result, Err(AppError::Conflict(_))),
+        "over-capacity putaway should conflict: {:?}",
+        result
+    );
+
+    let moves: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM stock_moves WHERE tenant_id = $1 AND product_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(moves, 0);
+
+    cleanup_capacity_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,170 @@
+//! Warehouse Location Merge Integration Tests
+//!
+//! Merging a location moves its stock into the target, keeps the old
+//! location's move history, and is refused when the target would overflow.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, cleanup_tenant_tables, create_location, create_product,
+    setup_test_pool, setup_test_tenant_and_product, setup_test_tenant_product_warehouse, stock_at,
+};
+use inventory_service_core::repositories::warehouse::WarehouseRepository;
+use inventory_service_infra::repositories::WarehouseRepositoryImpl;
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Live available quantity of a product at a location
+async fn available_at(pool: &PgPool, tenant_id: Uuid, location_id: Uuid, product_id: Uuid) -> i64 {
+    sqlx::query_scalar(
+        "SELECT COALESCE(SUM(available_quantity), 0)::BIGINT FROM inventory_levels
+         WHERE tenant_id = $1 AND location_id = $2 AND product_id = $3 AND deleted_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(location_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn is_active(pool: &PgPool, tenant_id: Uuid, location_id: Uuid) -> bool {
+    sqlx::query_scalar(
+        "SELECT is_active FROM warehouse_locations WHERE tenant_id = $1 AND location_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(location_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn cleanup_merge_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "inventory_level_history",
+            "stock_moves",
+            "inventory_levels",
+            "warehouse_locations",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_merge_location_consolidates_stock_and_keeps_history() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, shared_product, warehouse_id) =
+        setup_test_tenant_product_warehouse(&pool).await;
+    let only_in_source = create_product(&pool, tenant_id).await;
+    let repo = WarehouseRepositoryImpl::new(pool.clone());
+    let user_id = Uuid::now_v7();
+
+    let bin_a = create_location(&pool, tenant_id, warehouse_id, "BIN-A", None).await;
+    let bin_b = create_location(&pool, tenant_id, warehouse_id, "BIN-B", Some(100)).await;
+    stock_at(&pool, tenant_id, warehouse_id, bin_a, shared_product, 30, 0).await;
+    stock_at(&pool, tenant_id, warehouse_id, bin_a, only_in_source, 20, 0).await;
+    stock_at(&pool, tenant_id, warehouse_id, bin_b, shared_product, 10, 0).await;
+
+    // Earlier receipt into the bin being retired
+    let receipt_move: Uuid = sqlx::query_scalar(
+        "INSERT INTO stock_moves (
+            tenant_id, product_id, destination_location_id, move_type, quantity,
+            reference_type, reference_id, idempotency_key
+        ) VALUES ($1, $2, $3, 'receipt', 30, 'grn', $4, $5)
+        RETURNING move_id",
+    )
+    .bind(tenant_id)
+    .bind(shared_product)
+    .bind(bin_a)
+    .bind(Uuid::now_v7())
+    .bind(format!("merge-receipt-{}", Uuid::now_v7()))
+    .fetch_one(&pool)
+    .await
+    .expect("Failed to insert receipt move");
+
+    let merged = repo
+        .merge_location(tenant_id, bin_a, bin_b, user_id)
+        .await
+        .expect("Merge should succeed");
+    assert_eq!(merged.products_moved, 2);
+    assert_eq!(merged.quantity_moved, 50);
+    assert_eq!(merged.move_ids.len(), 2);
+
+    // Stock consolidated into B; A is empty and retired
+    assert_eq!(available_at(&pool, tenant_id, bin_b, shared_product).await, 40);
+    assert_eq!(available_at(&pool, tenant_id, bin_b, only_in_source).await, 20);
+    assert_eq!(available_at(&pool, tenant_id, bin_a, shared_product).await, 0);
+    assert_eq!(available_at(&pool, tenant_id, bin_a, only_in_source).await, 0);
+    assert!(!is_active(&pool, tenant_id, bin_a).await);
+    assert!(is_active(&pool, tenant_id, bin_b).await);
+
+    // The receipt still points at A, and the merge is recorded as A -> B transfers
+    let receipt_destination: Option<Uuid> =
+        sqlx::query_scalar("SELECT destination_location_id FROM stock_moves WHERE move_id = $1")
+            .bind(receipt_move)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(receipt_destination, Some(bin_a));
+
+    let merge_moves: Vec<(Uuid, i64, Option<Uuid>, Option<Uuid>, String)> = sqlx::query_as(
+        "SELECT product_id, quantity::BIGINT, source_location_id, destination_location_id, move_type
+         FROM stock_moves WHERE tenant_id = $1 AND move_id = ANY($2)
+         ORDER BY quantity DESC",
+    )
+    .bind(tenant_id)
+    .bind(&merged.move_ids)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        merge_moves,
+        vec![
+            (shared_product, 30, Some(bin_a), Some(bin_b), "transfer".to_string()),
+            (only_in_source, 20, Some(bin_a), Some(bin_b), "transfer".to_string()),
+        ]
+    );
+
+    cleanup_merge_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_merge_location_rejects_exceeding_target_capacity() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let repo = WarehouseRepositoryImpl::new(pool.clone());
+
+    let bin_a = create_location(&pool, tenant_id, warehouse_id, "BIN-A", None).await;
+    let small_bin = create_location(&pool, tenant_id, warehouse_id, "BIN-S", Some(25)).await;
+    stock_at(&pool, tenant_id, warehouse_id, bin_a, product_id, 20, 0).await;
+    stock_at(&pool, tenant_id, warehouse_id, small_bin, product_id, 10, 0).await;
+
+    let result = repo
+        .merge_location(tenant_id, bin_a, small_bin, Uuid::now_v7())
+        .await;
+    assert!(matches!(result, Err(AppError::BusinessError(_))));
+
+    // Nothing moved and the source stays in service
+    assert_eq!(available_at(&pool, tenant_id, bin_a, product_id).await, 20);
+    assert_eq!(available_at(&pool, tenant_id, small_bin, product_id).await, 10);
+    assert!(is_active(&pool, tenant_id, bin_a).await);
+
+    // Merging into itself or another tenant's location is refused too
+    let result = repo
+        .merge_location(tenant_id, bin_a, bin_a, Uuid::now_v7())
+        .await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+    let (other_tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let result = repo
+        .merge_location(other_tenant_id, bin_a, small_bin, Uuid::now_v7())
+        .await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_merge_test_data(&pool, tenant_id).await;
+    cleanup_reorder_test_data(&pool, other_tenant_id).await;
+}
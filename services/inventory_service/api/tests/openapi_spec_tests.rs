@@ -0,0 +1,47 @@
+//! OpenAPI Spec Tests
+//!
+//! Round-trips the generated spec through YAML (the `export_spec` format) and
+//! checks that the bearer scheme is declared and applied to protected operations.
+
+use inventory_service_api::openapi::ApiDoc;
+use serde_json::Value;
+use utoipa::OpenApi;
+
+fn exported_spec() -> Value {
+    let yaml = serde_yaml::to_string(&ApiDoc::openapi()).expect("Spec should serialize to YAML");
+    serde_yaml::from_str(&yaml).expect("Exported spec should deserialize")
+}
+
+#[test]
+fn test_spec_declares_bearer_security_scheme() {
+    let spec = exported_spec();
+    let scheme = &spec["components"]["securitySchemes"]["bearer_auth"];
+
+    assert_eq!(scheme["type"], "http");
+    assert_eq!(scheme["scheme"], "bearer");
+    assert_eq!(scheme["bearerFormat"], "JWT");
+}
+
+#[test]
+fn test_protected_operations_require_bearer_auth() {
+    let spec = exported_spec();
+    let paths = spec["paths"].as_object().expect("Spec should have paths");
+
+    for (path, item) in paths {
+        if path == "/health" || path == "/health/ready" {
+            assert!(item["get"].get("security").is_none(), "Health checks must stay public");
+            continue;
+        }
+        for (method, operation) in item.as_object().unwrap() {
+            let security = operation["security"]
+                .as_array()
+                .unwrap_or_else(|| panic!("{} {} has no security requirement", method, path));
+            assert!(
+                security.iter().any(|s| s.get("bearer_auth").is_some()),
+                "{} {} should require bearer_auth",
+                method,
+                path
+            );
+        }
+    }
+}
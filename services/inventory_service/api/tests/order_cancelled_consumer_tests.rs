@@ -0,0 +1,133 @@
+//! Order Cancelled Consumer Integration Tests
+//!
+//! An `order.cancelled` event releases the order's stock reservations back to
+//! available stock exactly once, even when the event is delivered twice.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, create_inventory_level, create_inventory_service, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_api::consumers::{order_cancelled_dispatcher, ORDER_CANCELLED};
+use inventory_service_core::models::NewStockReservation;
+use inventory_service_core::services::InventoryService;
+use shared_events::{DispatchOutcome, EventEnvelope, OrderCancelledEvent};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn cancelled_event(tenant_id: Uuid, order_id: Uuid) -> Vec<u8> {
+    let envelope = EventEnvelope::new(
+        ORDER_CANCELLED,
+        OrderCancelledEvent {
+            order_id,
+            tenant_id,
+            reason: Some("customer request".to_string()),
+            cancelled_at: chrono::Utc::now(),
+        },
+    );
+    serde_json::to_vec(&envelope).unwrap()
+}
+
+async fn stock_level(pool: &PgPool, tenant_id: Uuid, product_id: Uuid) -> (i64, i64) {
+    sqlx::query_as(
+        "SELECT available_quantity, reserved_quantity FROM inventory_levels
+         WHERE tenant_id = $1 AND product_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn cleanup_reservations(pool: &PgPool, tenant_id: Uuid) {
+    let _ = sqlx::query("DELETE FROM stock_reservations WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_order_cancelled_releases_reservation_once() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let service = Arc::new(create_inventory_service(&pool));
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+
+    // The order holds 30 units
+    let order_id = Uuid::now_v7();
+    service
+        .reserve_stock(tenant_id, warehouse_id, product_id, 30)
+        .await
+        .unwrap();
+    service
+        .queue_reservation(
+            tenant_id,
+            NewStockReservation {
+                warehouse_id,
+                product_id,
+                quantity: 30,
+                priority: 0,
+                source_type: Some("sales_order".to_string()),
+                source_id: Some(order_id),
+            },
+        )
+        .await
+        .unwrap();
+    service
+        .allocate_scarce(tenant_id, product_id, 30)
+        .await
+        .unwrap();
+    assert_eq!(stock_level(&pool, tenant_id, product_id).await, (70, 30));
+
+    let dispatcher = order_cancelled_dispatcher(service.clone());
+    let payload = cancelled_event(tenant_id, order_id);
+
+    let outcome = dispatcher.dispatch(&payload).await.unwrap();
+    assert_eq!(outcome, DispatchOutcome::Handled);
+    assert_eq!(stock_level(&pool, tenant_id, product_id).await, (100, 0));
+
+    // A redelivered event must not release the stock again
+    dispatcher.dispatch(&payload).await.unwrap();
+    assert_eq!(stock_level(&pool, tenant_id, product_id).await, (100, 0));
+
+    let status: String =
+        sqlx::query_scalar("SELECT status FROM stock_reservations WHERE source_id = $1")
+            .bind(order_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(status, "released");
+
+    cleanup_reservations(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_order_cancelled_without_reservations_is_noop() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let service = Arc::new(create_inventory_service(&pool));
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+
+    // Stock held by another order stays reserved
+    service
+        .reserve_stock(tenant_id, warehouse_id, product_id, 10)
+        .await
+        .unwrap();
+
+    // An order that already shipped has no open reservation left
+    let dispatcher = order_cancelled_dispatcher(service.clone());
+    let outcome = dispatcher
+        .dispatch(&cancelled_event(tenant_id, Uuid::now_v7()))
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, DispatchOutcome::Handled);
+    assert_eq!(stock_level(&pool, tenant_id, product_id).await, (90, 10));
+
+    cleanup_reservations(&pool, tenant_id).await;
+}
@@ -0,0 +1,243 @@
+//! Outbox Worker Tests
+//!
+//! Batch claiming, bounded concurrent publishing and settling of outbox rows,
+//! using a mock publisher in place of NATS.
+
+mod business_logic_test_helpers;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use business_logic_test_helpers::{cleanup_outbox_test_data, setup_test_pool};
+use inventory_service_api::worker::{process_outbox_batch, OutboxPublisher, OutboxWorkerConfig};
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Records publishes for one tenant
+///
+/// Workers under test only claim that tenant's rows (see [`test_config`]);
+/// anything else reaching the publisher is failed rather than marked published.
+struct CountingPublisher {
+    tenant_id: Uuid,
+    fail: bool,
+    published: Mutex<Vec<String>>,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+impl CountingPublisher {
+    fn new(tenant_id: Uuid, fail: bool) -> Self {
+        Self {
+            tenant_id,
+            fail,
+            published: Mutex::new(Vec::new()),
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of this tenant's events published since the last call
+    fn take_count(&self) -> usize {
+        std::mem::take(&mut *self.published.lock().unwrap()).len()
+    }
+}
+
+#[async_trait]
+impl OutboxPublisher for CountingPublisher {
+    async fn publish(&self, subject: String, _payload: Vec<u8>) -> Result<(), String> {
+        if !subject.contains(&self.tenant_id.to_string()) {
+            return Err(format!("unexpected subject {}", subject));
+        }
+
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        if self.fail {
+            return Err("nats: slow consumer".to_string());
+        }
+        self.published.lock().unwrap().push(subject);
+        Ok(())
+    }
+}
+
+async fn insert_events(pool: &PgPool, tenant_id: Uuid, count: usize) {
+    for i in 0..count {
+        sqlx::query(
+            "INSERT INTO event_outbox (tenant_id, event_type, event_data) VALUES ($1, $2, $3)",
+        )
+        .bind(tenant_id)
+        .bind("stock.adjustment")
+        .bind(json!({ "seq": i }))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+}
+
+async fn statuses(pool: &PgPool, tenant_id: Uuid) -> Vec<(String, i32)> {
+    sqlx::query_as(
+        "SELECT status, retry_count FROM event_outbox WHERE tenant_id = $1 ORDER BY created_at",
+    )
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+/// Worker settings that leave other tests' outbox rows in the shared database alone
+fn test_config(tenant_id: Uuid) -> OutboxWorkerConfig {
+    OutboxWorkerConfig {
+        batch_size: 3,
+        max_in_flight: 2,
+        tenant_id: Some(tenant_id),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_outbox_publishes_in_bounded_batches() {
+    let pool = setup_test_pool().await;
+    let tenant_id = Uuid::now_v7();
+    insert_events(&pool, tenant_id, 7).await;
+
+    let publisher = CountingPublisher::new(tenant_id, false);
+    let config = test_config(tenant_id);
+
+    let mut batch_sizes = Vec::new();
+    for _ in 0..20 {
+        let outcome = process_outbox_batch(&pool, &publisher, &config)
+            .await
+            .unwrap();
+        assert!(outcome.claimed <= 3, "claimed {} rows in one batch", outcome.claimed);
+        assert_eq!(outcome.failed, 0);
+        assert_eq!(publisher.take_count(), outcome.published);
+
+        if outcome.claimed == 0 {
+            break;
+        }
+        batch_sizes.push(outcome.published);
+    }
+
+    assert_eq!(batch_sizes.iter().sum::<usize>(), 7, "batches: {:?}", batch_sizes);
+    assert!(batch_sizes.iter().all(|&n| n <= 3), "batches: {:?}", batch_sizes);
+    assert!(batch_sizes.len() >= 3, "batches: {:?}", batch_sizes);
+    assert!(publisher.max_in_flight.load(Ordering::SeqCst) <= 2);
+    assert!(statuses(&pool, tenant_id)
+        .await
+        .iter()
+        .all(|(status, _)| status == "published"));
+
+    cleanup_outbox_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_outbox_failed_publishes_stay_unpublished() {
+    let pool = setup_test_pool().await;
+    let tenant_id = Uuid::now_v7();
+    insert_events(&pool, tenant_id, 2).await;
+
+    let publisher = CountingPublisher::new(tenant_id, true);
+    let config = OutboxWorkerConfig {
+        batch_size: 100,
+        ..test_config(tenant_id)
+    };
+
+    let outcome = process_outbox_batch(&pool, &publisher, &config)
+        .await
+        .unwrap();
+    assert_eq!(outcome.claimed, 2);
+    assert_eq!(outcome.failed, 2);
+
+    assert_eq!(publisher.take_count(), 0);
+    assert_eq!(
+        statuses(&pool, tenant_id).await,
+        vec![("pending".to_string(), 1), ("pending".to_string(), 1)]
+    );
+
+    cleanup_outbox_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_outbox_dead_letters_after_max_attempts() {
+    let pool = setup_test_pool().await;
+    let tenant_id = Uuid::now_v7();
+    insert_events(&pool, tenant_id, 1).await;
+
+    let publisher = CountingPublisher::new(tenant_id, true);
+    let config = OutboxWorkerConfig {
+        batch_size: 100,
+        max_attempts: 2,
+        ..test_config(tenant_id)
+    };
+
+    // First failure: back to pending for another attempt
+    process_outbox_batch(&pool, &publisher, &config)
+        .await
+        .unwrap();
+    assert_eq!(statuses(&pool, tenant_id).await, vec![("pending".to_string(), 1)]);
+
+    // Second failure uses up max_attempts: the row is dead-lettered and left alone
+    process_outbox_batch(&pool, &publisher, &config)
+        .await
+        .unwrap();
+    assert_eq!(statuses(&pool, tenant_id).await, vec![("dead".to_string(), 2)]);
+
+    let last_error: Option<String> =
+        sqlx::query_scalar("SELECT error_message FROM event_outbox WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(last_error.unwrap().contains("slow consumer"));
+
+    process_outbox_batch(&pool, &publisher, &config)
+        .await
+        .unwrap();
+    assert_eq!(statuses(&pool, tenant_id).await, vec![("dead".to_string(), 2)]);
+
+    cleanup_outbox_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_outbox_reclaims_stale_in_progress_rows() {
+    let pool = setup_test_pool().await;
+    let tenant_id = Uuid::now_v7();
+    insert_events(&pool, tenant_id, 2).await;
+
+    // One row abandoned by a crashed worker long ago, one claimed just now
+    sqlx::query(
+        "UPDATE event_outbox SET status = 'in_progress', updated_at = NOW() - INTERVAL '1 hour'
+         WHERE id = (SELECT id FROM event_outbox WHERE tenant_id = $1 ORDER BY created_at LIMIT 1)",
+    )
+    .bind(tenant_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "UPDATE event_outbox SET status = 'in_progress', updated_at = NOW()
+         WHERE tenant_id = $1 AND status = 'pending'",
+    )
+    .bind(tenant_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let publisher = CountingPublisher::new(tenant_id, false);
+    let outcome = process_outbox_batch(&pool, &publisher, &test_config(tenant_id))
+        .await
+        .unwrap();
+
+    assert_eq!(outcome.claimed, 1);
+    assert_eq!(outcome.published, 1);
+    assert_eq!(
+        statuses(&pool, tenant_id).await,
+        vec![("published".to_string(), 0), ("in_progress".to_string(), 0)]
+    );
+
+    cleanup_outbox_test_data(&pool, tenant_id).await;
+}
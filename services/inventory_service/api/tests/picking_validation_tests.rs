@@ -0,0 +1,104 @@
+//! Picking Request Validation Tests
+//!
+//! Picking handlers validate their bodies with `validate()?`, which answers
+//! 422 with one entry per failing field; a body that is not valid JSON is
+//! still a 400.
+
+mod business_logic_test_helpers;
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+    Router,
+};
+use business_logic_test_helpers::setup_test_pool;
+use helpers::create_test_state;
+use http_body_util::BodyExt;
+use inventory_service_api::handlers::picking::create_picking_routes;
+use inventory_service_api::middleware::AuthzState;
+use serde_json::{json, Value};
+use shared_jwt::{encode_jwt, Claims, InMemoryTokenDenylist};
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn picking_app() -> Router {
+    let app_state = create_test_state(setup_test_pool().await).await;
+    let authz_state = AuthzState {
+        enforcer: app_state.enforcer.clone(),
+        jwt_secret: app_state.jwt_secret.clone(),
+        denylist: Arc::new(InMemoryTokenDenylist::new()),
+    };
+
+    Router::new()
+        .nest("/api/v1/inventory/picking", create_picking_routes())
+        .layer(axum::Extension(app_state))
+        .layer(axum::Extension(authz_state))
+}
+
+async fn send(app: &Router, method: Method, uri: &str, body: String) -> (StatusCode, Value) {
+    let claims = Claims::new_access(Uuid::now_v7(), Uuid::now_v7(), "user".to_string(), 900);
+    let token = encode_jwt(&claims, "test_jwt_secret").unwrap();
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+fn field_names(body: &Value) -> Vec<&str> {
+    body["fields"]
+        .as_array()
+        .expect("fields should be listed")
+        .iter()
+        .map(|f| f["field"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_invalid_picking_method_lists_failing_fields() {
+    let app = picking_app().await;
+
+    let request = json!({
+        "name": "",
+        "methodType": "zigzag",
+        "warehouseId": Uuid::now_v7(),
+        "config": { "batchSize": 10 },
+    });
+    let (status, body) =
+        send(&app, Method::POST, "/api/v1/inventory/picking/methods", request.to_string()).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["code"], "VALIDATION_ERROR");
+    assert_eq!(field_names(&body), vec!["method_type", "name"]);
+}
+
+#[tokio::test]
+async fn test_invalid_picking_method_update_is_unprocessable() {
+    let app = picking_app().await;
+
+    let uri = format!("/api/v1/inventory/picking/methods/{}", Uuid::now_v7());
+    let (status, body) = send(&app, Method::PUT, &uri, json!({ "name": "" }).to_string()).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(field_names(&body), vec!["name"]);
+}
+
+#[tokio::test]
+async fn test_malformed_picking_body_is_bad_request() {
+    let app = picking_app().await;
+
+    let (status, body) =
+        send(&app, Method::POST, "/api/v1/inventory/picking/methods", "{".to_string()).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "VALIDATION_ERROR");
+}
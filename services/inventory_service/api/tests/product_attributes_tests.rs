@@ -0,0 +1,144 @@
+//! Product Attribute Integration Tests
+//!
+//! Attributes can be replaced and read back per product, and product listing
+//! filters on attribute values via JSONB containment.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, create_named_product, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::dto::product::{
+    parse_attribute_filters, ProductAttributes, ProductListQuery,
+};
+use inventory_service_core::services::product::ProductService;
+use inventory_service_infra::repositories::ProductRepositoryImpl;
+use inventory_service_infra::services::ProductServiceImpl;
+use serde_json::json;
+use shared_error::AppError;
+use uuid::Uuid;
+
+fn attributes(pairs: &[(&str, &str)]) -> ProductAttributes {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_set_and_get_product_attributes() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = ProductServiceImpl::new(Arc::new(ProductRepositoryImpl::new(pool.clone())));
+
+    let stored = service
+        .set_product_attributes(
+            tenant_id,
+            product_id,
+            attributes(&[("color", "red"), ("voltage", "220V")]),
+        )
+        .await
+        .expect("Setting attributes should succeed");
+    assert_eq!(stored.len(), 2);
+
+    let fetched = service
+        .get_product_attributes(tenant_id, product_id)
+        .await
+        .expect("Getting attributes should succeed");
+    assert_eq!(fetched, attributes(&[("color", "red"), ("voltage", "220V")]));
+
+    // Attributes are replaced, not merged
+    service
+        .set_product_attributes(tenant_id, product_id, attributes(&[("material", "steel")]))
+        .await
+        .expect("Replacing attributes should succeed");
+    let fetched = service
+        .get_product_attributes(tenant_id, product_id)
+        .await
+        .unwrap();
+    assert_eq!(fetched, attributes(&[("material", "steel")]));
+
+    let product = service.get_product(tenant_id, product_id).await.unwrap();
+    assert_eq!(product.attributes, Some(json!({ "material": "steel" })));
+
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_set_attributes_rejects_invalid_input() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = ProductServiceImpl::new(Arc::new(ProductRepositoryImpl::new(pool.clone())));
+
+    let result = service
+        .set_product_attributes(tenant_id, product_id, attributes(&[("Bad Key", "red")]))
+        .await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+    let result = service
+        .set_product_attributes(tenant_id, Uuid::now_v7(), attributes(&[("color", "red")]))
+        .await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_list_products_filters_by_attribute() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, helper_product_id) = setup_test_tenant_and_product(&pool).await;
+    // Only the products created below take part in the filter
+    sqlx::query("DELETE FROM products WHERE product_id = $1")
+        .bind(helper_product_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to remove helper product");
+
+    let service = ProductServiceImpl::new(Arc::new(ProductRepositoryImpl::new(pool.clone())));
+    let red_shirt = create_named_product(&pool, tenant_id, "Red Shirt", None).await;
+    let blue_shirt = create_named_product(&pool, tenant_id, "Blue Shirt", None).await;
+    let red_hat = create_named_product(&pool, tenant_id, "Red Hat", None).await;
+    create_named_product(&pool, tenant_id, "Plain Box", None).await;
+
+    for (product_id, pairs) in [
+        (red_shirt, attributes(&[("color", "red"), ("size", "xl")])),
+        (blue_shirt, attributes(&[("color", "blue"), ("size", "xl")])),
+        (red_hat, attributes(&[("color", "red")])),
+    ] {
+        service
+            .set_product_attributes(tenant_id, product_id, pairs)
+            .await
+            .expect("Setting attributes should succeed");
+    }
+
+    let mut query: ProductListQuery = serde_json::from_value(json!({})).unwrap();
+    query.attributes =
+        parse_attribute_filters(&[("attr.color".to_string(), "red".to_string())]).unwrap();
+    let response = service
+        .list_products(tenant_id, query)
+        .await
+        .expect("Listing should succeed");
+    let mut names: Vec<_> = response.products.iter().map(|p| p.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["Red Hat", "Red Shirt"]);
+    assert_eq!(response.pagination.total_items, Some(2));
+    assert!(response
+        .products
+        .iter()
+        .all(|p| p.attributes.as_ref().map(|a| &a["color"]) == Some(&json!("red"))));
+
+    // Multiple filters must all match
+    let mut query: ProductListQuery = serde_json::from_value(json!({})).unwrap();
+    query.attributes = parse_attribute_filters(&[
+        ("attr.color".to_string(), "red".to_string()),
+        ("attr.size".to_string(), "xl".to_string()),
+    ])
+    .unwrap();
+    let response = service.list_products(tenant_id, query).await.unwrap();
+    let names: Vec<_> = response.products.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["Red Shirt"]);
+
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,83 @@
+//! Product Bulk Delete/Restore Integration Tests
+//!
+//! Bulk soft-deleting and restoring products keeps category product counts
+//! in step with the live products.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_category_test_data, create_category, create_named_product, get_counts, setup_test_pool,
+    setup_test_tenant_and_product,
+};
+use inventory_service_core::repositories::product::ProductRepository;
+use inventory_service_infra::repositories::ProductRepositoryImpl;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn count_deleted(pool: &PgPool, tenant_id: Uuid, product_ids: &[Uuid]) -> i64 {
+    sqlx::query_scalar(
+        "SELECT COUNT(*) FROM products
+         WHERE tenant_id = $1 AND product_id = ANY($2) AND deleted_at IS NOT NULL",
+    )
+    .bind(tenant_id)
+    .bind(product_ids)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_bulk_delete_three_and_restore_two_products() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _product_id) = setup_test_tenant_and_product(&pool).await;
+    let repo = ProductRepositoryImpl::new(pool.clone());
+
+    let root_id = create_category(&pool, tenant_id, None, "Apparel").await;
+    let shirts_id = create_category(&pool, tenant_id, Some(root_id), "Shirts").await;
+    let hats_id = create_category(&pool, tenant_id, Some(root_id), "Hats").await;
+
+    let shirt_a = create_named_product(&pool, tenant_id, "Bulk Product", Some(shirts_id)).await;
+    let shirt_b = create_named_product(&pool, tenant_id, "Bulk Product", Some(shirts_id)).await;
+    let hat = create_named_product(&pool, tenant_id, "Bulk Product", Some(hats_id)).await;
+    let kept_hat = create_named_product(&pool, tenant_id, "Bulk Product", Some(hats_id)).await;
+
+    assert_eq!(get_counts(&pool, tenant_id, shirts_id).await, (2, 2));
+    assert_eq!(get_counts(&pool, tenant_id, hats_id).await, (2, 2));
+
+    // Discontinue three products; an unknown id is ignored
+    let deleted = repo
+        .bulk_soft_delete(tenant_id, &[shirt_a, shirt_b, hat, Uuid::now_v7()])
+        .await
+        .expect("Bulk delete should succeed");
+    assert_eq!(deleted, 3);
+    assert_eq!(count_deleted(&pool, tenant_id, &[shirt_a, shirt_b, hat, kept_hat]).await, 3);
+    assert_eq!(get_counts(&pool, tenant_id, shirts_id).await, (0, 0));
+    assert_eq!(get_counts(&pool, tenant_id, hats_id).await, (1, 1));
+    assert_eq!(get_counts(&pool, tenant_id, root_id).await, (0, 1));
+
+    // Deleting again affects nothing
+    assert_eq!(repo.bulk_soft_delete(tenant_id, &[shirt_a]).await.unwrap(), 0);
+
+    // Restore two of them; the live product is skipped
+    let restored = repo
+        .bulk_restore(tenant_id, &[shirt_a, hat, kept_hat])
+        .await
+        .expect("Bulk restore should succeed");
+    assert_eq!(restored, 2);
+    assert_eq!(count_deleted(&pool, tenant_id, &[shirt_a, shirt_b, hat, kept_hat]).await, 1);
+    assert_eq!(get_counts(&pool, tenant_id, shirts_id).await, (1, 1));
+    assert_eq!(get_counts(&pool, tenant_id, hats_id).await, (2, 2));
+    assert_eq!(get_counts(&pool, tenant_id, root_id).await, (0, 3));
+
+    // Other tenants cannot restore this tenant's products
+    let (other_tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    assert_eq!(
+        repo.bulk_restore(other_tenant_id, &[shirt_b])
+            .await
+            .unwrap(),
+        0
+    );
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+    cleanup_category_test_data(&pool, other_tenant_id).await;
+}
@@ -0,0 +1,60 @@
+//! Product Multi-Field Sort Integration Tests
+//!
+//! Product listing applies every `sort` key in order and rejects fields
+//! outside the allowlist.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    cleanup_category_test_data, create_category, create_named_product, setup_test_pool,
+    setup_test_tenant_and_product,
+};
+use inventory_service_core::dto::product::ProductListQuery;
+use inventory_service_core::services::product::ProductService;
+use inventory_service_infra::repositories::ProductRepositoryImpl;
+use inventory_service_infra::services::ProductServiceImpl;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_two_key_sort_orders_by_category_then_name() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, helper_product_id) = setup_test_tenant_and_product(&pool).await;
+    // Only the products created below take part in the ordering
+    sqlx::query("DELETE FROM products WHERE product_id = $1")
+        .bind(helper_product_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to remove helper product");
+
+    let garden = create_category(&pool, tenant_id, None, "Garden").await;
+    let books = create_category(&pool, tenant_id, None, "Books").await;
+    create_named_product(&pool, tenant_id, "Shovel", Some(garden)).await;
+    create_named_product(&pool, tenant_id, "Zen Guide", Some(books)).await;
+    create_named_product(&pool, tenant_id, "Hose", Some(garden)).await;
+    create_named_product(&pool, tenant_id, "Atlas", Some(books)).await;
+
+    let service = ProductServiceImpl::new(Arc::new(ProductRepositoryImpl::new(pool.clone())));
+    let query: ProductListQuery =
+        serde_json::from_value(json!({ "sort": "category:asc,name:desc" })).unwrap();
+
+    let response = service
+        .list_products(tenant_id, query)
+        .await
+        .expect("Listing should succeed");
+
+    let names: Vec<_> = response.products.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["Zen Guide", "Atlas", "Shovel", "Hose"]);
+
+    cleanup_category_test_data(&pool, tenant_id).await;
+}
+
+#[test]
+fn test_unknown_sort_field_is_rejected() {
+    let result: Result<ProductListQuery, _> =
+        serde_json::from_value(json!({ "sort": "category:asc,p.name; DROP TABLE products" }));
+
+    let err = result.expect_err("Unknown sort field must be rejected");
+    assert!(err.to_string().contains("unknown sort field"));
+}
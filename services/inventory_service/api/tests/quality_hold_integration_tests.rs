@@ -0,0 +1,251 @@
+//! Quality Hold Integration Tests
+//!
+//! Verifies that receipts of products covered by an incoming QC point land in
+//! quality hold, are released to available on a pass and quarantined on a fail.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_user, get_levels, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::domains::quality::{
+    CreateQualityControlPoint, QcPointType, QcStatus, RecordQualityCheckResult,
+};
+use inventory_service_core::repositories::receipt::ReceiptRepository;
+use inventory_service_core::services::quality::QualityControlPointService;
+use inventory_service_infra::repositories::{
+    PgInventoryLevelRepository, PgQualityControlPointRepository, ReceiptRepositoryImpl,
+};
+use inventory_service_infra::services::PgQualityControlPointService;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn create_quality_service(pool: &PgPool) -> PgQualityControlPointService {
+    PgQualityControlPointService::new(Arc::new(PgQualityControlPointRepository::new(pool.clone())))
+}
+
+/// Create a confirmed receipt with a single line (no unit cost, so no valuation layers)
+async fn create_confirmed_receipt(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+    user_id: Uuid,
+    received_quantity: i64,
+) -> Uuid {
+    let receipt_id = Uuid::now_v7();
+
+    sqlx::query(
+        "INSERT INTO goods_receipts (receipt_id, tenant_id, receipt_number, warehouse_id, status, created_by)
+         VALUES ($1, $2, $3, $4, 'confirmed', $5)",
+    )
+    .bind(receipt_id)
+    .bind(tenant_id)
+    .bind(format!("GRN-TEST-{}", &receipt_id.to_string()[..8]))
+    .bind(warehouse_id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert receipt");
+
+    sqlx::query(
+        "INSERT INTO goods_receipt_items (receipt_item_id, tenant_id, receipt_id, product_id,
+                                          expected_quantity, received_quantity)
+         VALUES ($1, $2, $3, $4, $5, $5)",
+    )
+    .bind(Uuid::now_v7())
+    .bind(tenant_id)
+    .bind(receipt_id)
+    .bind(product_id)
+    .bind(received_quantity)
+    .execute(pool)
+    .await
+    .expect("Failed to insert receipt item");
+
+    receipt_id
+}
+
+/// Try to reserve stock, returning whether it succeeded
+async fn can_reserve(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+    quantity: i64,
+) -> bool {
+    let level_repo = PgInventoryLevelRepository::new(Arc::new(pool.clone()));
+    let tx = pool.begin().await.unwrap();
+    match level_repo
+        .reserve_with_tx(tx, tenant_id, warehouse_id, product_id, quantity)
+        .await
+    {
+        // Roll back: only the outcome matters
+        Ok(tx) => {
+            tx.rollback().await.unwrap();
+            true
+        },
+        Err(_) => false,
+    }
+}
+
+async fn cleanup_quality_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "quality_checks",
+            "quality_control_points",
+            "event_outbox",
+            "goods_receipt_items",
+            "goods_receipts",
+            "users",
+        ],
+    )
+    .await;
+}
+
+/// Receive 10 units of a product guarded by an incoming QC point, returning the pending check id
+async fn receive_under_qc(
+    pool: &PgPool,
+    service: &PgQualityControlPointService,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+    user_id: Uuid,
+) -> Uuid {
+    service
+        .create_qc_point(
+            tenant_id,
+            CreateQualityControlPoint {
+                name: "Incoming inspection".to_string(),
+                r#type: QcPointType::Incoming,
+                product_id: Some(product_id),
+                warehouse_id: None,
+            },
+        )
+        .await
+        .expect("Failed to create QC point");
+
+    let receipt_id =
+        create_confirmed_receipt(pool, tenant_id, warehouse_id, product_id, user_id, 10).await;
+    ReceiptRepositoryImpl::new(pool.clone())
+        .validate_receipt(tenant_id, receipt_id, user_id)
+        .await
+        .expect("Receipt validation should succeed");
+
+    let checks = service
+        .list_checks_for_receipt(tenant_id, receipt_id)
+        .await
+        .unwrap();
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].status, QcStatus::Pending);
+    assert_eq!(checks[0].quantity, 10);
+    checks[0].qc_id
+}
+
+#[tokio::test]
+async fn test_qc_required_receipt_not_sellable_until_passed() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_quality_service(&pool);
+
+    let qc_id =
+        receive_under_qc(&pool, &service, tenant_id, warehouse_id, product_id, user_id).await;
+
+    // Held, not available
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (0, 0, 10, 0));
+    assert!(!can_reserve(&pool, tenant_id, warehouse_id, product_id, 1).await);
+
+    let check = service
+        .record_check_result(
+            tenant_id,
+            qc_id,
+            user_id,
+            RecordQualityCheckResult {
+                passed: true,
+                notes: Some("Looks good".to_string()),
+            },
+        )
+        .await
+        .expect("Recording a pass should succeed");
+    assert_eq!(check.status, QcStatus::Passed);
+    assert_eq!(check.inspector_id, Some(user_id));
+
+    // Released to available
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (10, 0, 0, 0));
+    assert!(can_reserve(&pool, tenant_id, warehouse_id, product_id, 10).await);
+
+    // A check can only be recorded once
+    let again = service
+        .record_check_result(
+            tenant_id,
+            qc_id,
+            user_id,
+            RecordQualityCheckResult {
+                passed: true,
+                notes: None,
+            },
+        )
+        .await;
+    assert!(again.is_err());
+
+    cleanup_quality_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_qc_fail_routes_to_quarantine() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_quality_service(&pool);
+
+    let qc_id =
+        receive_under_qc(&pool, &service, tenant_id, warehouse_id, product_id, user_id).await;
+
+    let check = service
+        .record_check_result(
+            tenant_id,
+            qc_id,
+            user_id,
+            RecordQualityCheckResult {
+                passed: false,
+                notes: Some("Damaged packaging".to_string()),
+            },
+        )
+        .await
+        .expect("Recording a fail should succeed");
+    assert_eq!(check.status, QcStatus::Failed);
+
+    // Quarantined, never available
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (0, 0, 0, 10));
+    assert!(!can_reserve(&pool, tenant_id, warehouse_id, product_id, 1).await);
+
+    cleanup_quality_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_receipt_without_qc_point_is_available_immediately() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let service = create_quality_service(&pool);
+
+    let receipt_id =
+        create_confirmed_receipt(&pool, tenant_id, warehouse_id, product_id, user_id, 10).await;
+    ReceiptRepositoryImpl::new(pool.clone())
+        .validate_receipt(tenant_id, receipt_id, user_id)
+        .await
+        .expect("Receipt validation should succeed");
+
+    assert_eq!(get_levels(&pool, tenant_id, warehouse_id, product_id).await, (10, 0, 0, 0));
+    assert!(service
+        .list_checks_for_receipt(tenant_id, receipt_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    cleanup_quality_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,201 @@
+//! Receipt Reversal Integration Tests
+//!
+//! Reversing a received receipt compensates its stock moves and takes its stock
+//! and valuation back out; it is refused once any of that stock was picked.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{
+    available, cleanup_tenant_tables, create_user, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::dto::receipt::{ReceiptCreateRequest, ReceiptItemCreateRequest};
+use inventory_service_core::repositories::receipt::ReceiptRepository;
+use inventory_service_infra::repositories::{PgInventoryLevelRepository, ReceiptRepositoryImpl};
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Create and validate a single-line receipt, putting its stock on hand
+async fn receive(
+    repo: &ReceiptRepositoryImpl,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+    user_id: Uuid,
+    quantity: i64,
+    unit_cost: i64,
+) -> Uuid {
+    let request = ReceiptCreateRequest {
+        warehouse_id,
+        supplier_id: None,
+        reference_number: None,
+        external_reference: None,
+        expected_delivery_date: None,
+        notes: None,
+        currency_code: "USD".to_string(),
+        items: vec![ReceiptItemCreateRequest {
+            product_id,
+            expected_quantity: quantity,
+            received_quantity: quantity,
+            unit_cost: Some(unit_cost),
+            uom_id: None,
+            lot_number: None,
+            serial_numbers: None,
+            expiry_date: None,
+            notes: None,
+        }],
+    };
+    let receipt = repo
+        .create_receipt(tenant_id, user_id, &request, &format!("reversal-{}", Uuid::now_v7()))
+        .await
+        .expect("Receipt should be created");
+    repo.validate_receipt(tenant_id, receipt.receipt_id, user_id)
+        .await
+        .expect("Receipt should be validated");
+    receipt.receipt_id
+}
+
+/// (total_quantity, total_value, current_unit_cost) of a product's valuation
+async fn valuation(pool: &PgPool, tenant_id: Uuid, product_id: Uuid) -> (i64, i64, Option<i64>) {
+    sqlx::query_as(
+        "SELECT total_quantity, total_value, current_unit_cost FROM inventory_valuations
+         WHERE tenant_id = $1 AND product_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+/// Remaining quantity of the layers a receipt created
+async fn layer_quantity(pool: &PgPool, tenant_id: Uuid, receipt_id: Uuid) -> i64 {
+    sqlx::query_scalar(
+        "SELECT COALESCE(SUM(quantity), 0)::BIGINT FROM inventory_valuation_layers
+         WHERE tenant_id = $1 AND receipt_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(receipt_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+/// Quantities of the compensating moves written for a receipt's moves
+async fn reversal_quantities(pool: &PgPool, tenant_id: Uuid, receipt_id: Uuid) -> Vec<i64> {
+    sqlx::query_scalar(
+        "SELECT reversal.quantity::BIGINT
+         FROM stock_moves reversal
+         JOIN stock_moves original ON original.move_id = reversal.reversal_of_move_id
+         WHERE reversal.tenant_id = $1 AND original.source_type = 'receipt'
+           AND original.source_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(receipt_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+async fn receipt_status(pool: &PgPool, tenant_id: Uuid, receipt_id: Uuid) -> String {
+    sqlx::query_scalar("SELECT status FROM goods_receipts WHERE tenant_id = $1 AND receipt_id = $2")
+        .bind(tenant_id)
+        .bind(receipt_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+async fn cleanup_reversal_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "event_outbox",
+            "inventory_level_history",
+            "stock_moves",
+            "inventory_valuation_history",
+            "inventory_valuation_layers",
+            "inventory_valuations",
+            "goods_receipt_items",
+            "goods_receipts",
+            "users",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_reverse_receipt_rolls_back_stock_moves_and_valuation() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let repo = ReceiptRepositoryImpl::new(pool.clone());
+
+    let kept = receive(&repo, tenant_id, warehouse_id, product_id, user_id, 5, 100).await;
+    let mistaken = receive(&repo, tenant_id, warehouse_id, product_id, user_id, 10, 250).await;
+    assert_eq!(available(&pool, tenant_id, warehouse_id, product_id).await, 15);
+    assert_eq!(valuation(&pool, tenant_id, product_id).await, (15, 3000, Some(200)));
+
+    let reversed = repo
+        .reverse_receipt(tenant_id, mistaken, "Entered twice", user_id)
+        .await
+        .expect("Reversal should succeed");
+    assert_eq!(reversed.status, "reversed");
+
+    // Stock and value are back to what the first receipt brought in
+    assert_eq!(available(&pool, tenant_id, warehouse_id, product_id).await, 5);
+    assert_eq!(valuation(&pool, tenant_id, product_id).await, (5, 500, Some(100)));
+    assert_eq!(layer_quantity(&pool, tenant_id, mistaken).await, 0);
+    assert_eq!(layer_quantity(&pool, tenant_id, kept).await, 5);
+
+    // The receipt move is compensated, the other receipt's is untouched
+    assert_eq!(reversal_quantities(&pool, tenant_id, mistaken).await, vec![-10]);
+    assert!(reversal_quantities(&pool, tenant_id, kept).await.is_empty());
+
+    // A reversed receipt cannot be reversed again
+    let again = repo
+        .reverse_receipt(tenant_id, mistaken, "Entered twice", user_id)
+        .await;
+    assert!(matches!(again, Err(AppError::ValidationError(_))));
+
+    cleanup_reversal_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_reverse_receipt_refused_after_stock_was_picked() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let repo = ReceiptRepositoryImpl::new(pool.clone());
+
+    let receipt_id = receive(&repo, tenant_id, warehouse_id, product_id, user_id, 10, 250).await;
+
+    // Pick part of the received stock
+    let level_repo = PgInventoryLevelRepository::new(Arc::new(pool.clone()));
+    let tx = pool.begin().await.unwrap();
+    let tx = level_repo
+        .reserve_with_tx(tx, tenant_id, warehouse_id, product_id, 4)
+        .await
+        .expect("Reservation should succeed");
+    tx.commit().await.unwrap();
+
+    let result = repo
+        .reverse_receipt(tenant_id, receipt_id, "Entered twice", user_id)
+        .await;
+    assert!(matches!(result, Err(AppError::BusinessError(_))));
+
+    // Nothing was rolled back
+    assert_eq!(receipt_status(&pool, tenant_id, receipt_id).await, "received");
+    assert_eq!(available(&pool, tenant_id, warehouse_id, product_id).await, 6);
+    assert_eq!(valuation(&pool, tenant_id, product_id).await, (10, 2500, Some(250)));
+    assert_eq!(layer_quantity(&pool, tenant_id, receipt_id).await, 10);
+    assert!(reversal_quantities(&pool, tenant_id, receipt_id)
+        .await
+        .is_empty());
+
+    cleanup_reversal_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,180 @@
+//! Reconciliation Snapshot Import Integration Tests
+//!
+//! Verifies that a stock snapshot creates a reconciliation with every row
+//! counted, and that unresolvable rows are reported without creating anything.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_inventory_level, create_location, create_product_with,
+    create_user, setup_test_pool, setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::domains::inventory::reconciliation::{CycleType, ReconciliationStatus};
+use inventory_service_core::dto::reconciliation::{
+    CreateReconciliationRequest, ImportReconciliationRequest, ReconciliationImportQuery,
+    ReconciliationSnapshotRow,
+};
+use inventory_service_core::services::reconciliation::StockReconciliationService;
+use inventory_service_infra::repositories::product::ProductRepositoryImpl;
+use inventory_service_infra::repositories::reconciliation::{
+    PgStockReconciliationItemRepository, PgStockReconciliationRepository,
+};
+use inventory_service_infra::repositories::stock::{
+    PgInventoryLevelRepository, PgStockMoveRepository,
+};
+use inventory_service_infra::services::reconciliation::PgStockReconciliationService;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn create_reconciliation_service(pool: &PgPool) -> PgStockReconciliationService {
+    let pool_arc = Arc::new(pool.clone());
+    PgStockReconciliationService::new(
+        pool_arc.clone(),
+        Arc::new(PgStockReconciliationRepository::new(pool_arc.clone())),
+        Arc::new(PgStockReconciliationItemRepository::new(pool_arc.clone())),
+        Arc::new(PgStockMoveRepository::new(pool_arc.clone())),
+        Arc::new(PgInventoryLevelRepository::new(pool_arc)),
+        Arc::new(ProductRepositoryImpl::new(pool.clone())),
+    )
+}
+
+async fn count_reconciliations(pool: &PgPool, tenant_id: Uuid) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM stock_reconciliations WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to count reconciliations")
+}
+
+async fn cleanup_import_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "stock_reconciliation_items",
+            "stock_reconciliations",
+            "warehouse_locations",
+            "users",
+        ],
+    )
+    .await;
+}
+
+fn row(sku: &str, location: Option<&str>, counted_qty: i64) -> ReconciliationSnapshotRow {
+    ReconciliationSnapshotRow {
+        sku: sku.to_string(),
+        location: location.map(str::to_string),
+        counted_qty,
+    }
+}
+
+#[tokio::test]
+async fn test_clean_csv_import_creates_counted_reconciliation() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let suffix = Uuid::now_v7().simple().to_string();
+    let sku_a = format!("SNAP-A-{}", suffix);
+    let sku_b = format!("SNAP-B-{}", suffix);
+    let product_a = create_product_with(&pool, tenant_id, &sku_a, "Snapshot Product", None).await;
+    let product_b = create_product_with(&pool, tenant_id, &sku_b, "Snapshot Product", None).await;
+    let shelf = create_location(&pool, tenant_id, warehouse_id, "A-01-01", None).await;
+    create_inventory_level(&pool, tenant_id, product_a, warehouse_id, 10).await;
+    create_inventory_level(&pool, tenant_id, product_b, warehouse_id, 4).await;
+
+    let service = create_reconciliation_service(&pool);
+    let csv = format!("sku,location,counted_qty\n{},A-01-01,8\n{},,4\n", sku_a, sku_b);
+    let response = service
+        .import_reconciliation_csv(
+            tenant_id,
+            user_id,
+            ReconciliationImportQuery {
+                name: "Year-end count".to_string(),
+                description: None,
+                warehouse_id,
+                notes: None,
+            },
+            csv.as_bytes(),
+        )
+        .await
+        .expect("Import should succeed");
+
+    assert!(response.errors.is_empty(), "unexpected errors: {:?}", response.errors);
+    let reconciliation = response
+        .reconciliation
+        .expect("Reconciliation should be created");
+    assert_eq!(reconciliation.status, ReconciliationStatus::InProgress);
+    assert_eq!(reconciliation.total_items, 2);
+    assert_eq!(reconciliation.counted_items, 2);
+    assert_eq!(reconciliation.total_variance, -2);
+
+    assert_eq!(response.items.len(), 2);
+    let item_a = response
+        .items
+        .iter()
+        .find(|item| item.product_id == product_a)
+        .unwrap();
+    assert_eq!(item_a.location_id, Some(shelf));
+    assert_eq!(item_a.expected_quantity, 10);
+    assert_eq!(item_a.counted_quantity, Some(8));
+    assert_eq!(item_a.variance, Some(-2));
+    let item_b = response
+        .items
+        .iter()
+        .find(|item| item.product_id == product_b)
+        .unwrap();
+    assert_eq!(item_b.location_id, None);
+    assert_eq!(item_b.variance, Some(0));
+
+    cleanup_import_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_import_with_unknown_skus_reports_rows_and_creates_nothing() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let known_sku = format!("SNAP-{}", Uuid::now_v7().simple());
+    create_product_with(&pool, tenant_id, &known_sku, "Snapshot Product", None).await;
+
+    let service = create_reconciliation_service(&pool);
+    let response = service
+        .import_reconciliation(
+            tenant_id,
+            user_id,
+            ImportReconciliationRequest {
+                reconciliation: CreateReconciliationRequest {
+                    name: "Year-end count".to_string(),
+                    description: None,
+                    cycle_type: CycleType::Full,
+                    warehouse_id: Some(warehouse_id),
+                    location_filter: None,
+                    product_filter: None,
+                    notes: None,
+                },
+                rows: vec![
+                    row(&known_sku, None, 3),
+                    row("NO-SUCH-SKU-1", None, 1),
+                    row(&known_sku, Some("NO-SUCH-BIN"), 1),
+                    row("NO-SUCH-SKU-2", None, 2),
+                ],
+            },
+        )
+        .await
+        .expect("Import should report errors, not fail");
+
+    assert!(response.reconciliation.is_none());
+    assert!(response.items.is_empty());
+    let reported: Vec<(i32, &str)> = response
+        .errors
+        .iter()
+        .map(|error| (error.row_number, error.field.as_str()))
+        .collect();
+    assert_eq!(reported, vec![(2, "sku"), (3, "location"), (4, "sku")]);
+    assert!(response.errors[0].error.contains("NO-SUCH-SKU-1"));
+
+    assert_eq!(count_reconciliations(&pool, tenant_id).await, 0);
+
+    cleanup_import_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,216 @@
+//! Reconciliation Variance Trend Integration Tests
+//!
+//! Verifies that completed reconciliations are aggregated per period with
+//! variance counts, variance value and accuracy percentage.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_tenant_tables, create_product, create_user, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use inventory_service_core::dto::reconciliation::TrendGroupBy;
+use inventory_service_core::repositories::reconciliation::StockReconciliationRepository;
+use inventory_service_infra::repositories::reconciliation::PgStockReconciliationRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+}
+
+/// Insert a reconciliation with the given status and completion time
+async fn create_reconciliation(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    user_id: Uuid,
+    status: &str,
+    completed_at: Option<DateTime<Utc>>,
+) -> Uuid {
+    let reconciliation_id = Uuid::now_v7();
+    sqlx::query(
+        "INSERT INTO stock_reconciliations
+             (tenant_id, reconciliation_id, reconciliation_number, name, status, warehouse_id,
+              created_by, completed_at)
+         VALUES ($1, $2, '', 'Trend test', $3, $4, $5, $6)",
+    )
+    .bind(tenant_id)
+    .bind(reconciliation_id)
+    .bind(status)
+    .bind(warehouse_id)
+    .bind(user_id)
+    .bind(completed_at)
+    .execute(pool)
+    .await
+    .expect("Failed to insert reconciliation");
+    reconciliation_id
+}
+
+/// Insert an item; `counted` is (variance, variance_value_cents) or None if not counted
+async fn create_item(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    reconciliation_id: Uuid,
+    product_id: Uuid,
+    warehouse_id: Uuid,
+    counted: Option<(i64, i64)>,
+) {
+    let expected = 10_i64;
+    sqlx::query(
+        "INSERT INTO stock_reconciliation_items
+             (tenant_id, reconciliation_id, product_id, warehouse_id, expected_quantity,
+              counted_quantity, variance, unit_cost, variance_value)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, 100, $8)",
+    )
+    .bind(tenant_id)
+    .bind(reconciliation_id)
+    .bind(product_id)
+    .bind(warehouse_id)
+    .bind(expected)
+    .bind(counted.map(|(variance, _)| expected + variance))
+    .bind(counted.map(|(variance, _)| variance))
+    .bind(counted.map(|(_, value)| value))
+    .execute(pool)
+    .await
+    .expect("Failed to insert reconciliation item");
+}
+
+async fn cleanup_trend_test_data(pool: &PgPool, tenant_id: Uuid) {
+    cleanup_tenant_tables(
+        pool,
+        tenant_id,
+        &[
+            "stock_reconciliation_items",
+            "stock_reconciliations",
+            "users",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_variance_trend_aggregates_per_month() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, p1, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let p2 = create_product(&pool, tenant_id).await;
+    let p3 = create_product(&pool, tenant_id).await;
+    let p4 = create_product(&pool, tenant_id).await;
+
+    // January: 3 counted, 1 with variance, one item never counted
+    let jan = create_reconciliation(
+        &pool,
+        tenant_id,
+        warehouse_id,
+        user_id,
+        "completed",
+        Some(utc(2025, 1, 10)),
+    )
+    .await;
+    create_item(&pool, tenant_id, jan, p1, warehouse_id, Some((0, 0))).await;
+    create_item(&pool, tenant_id, jan, p2, warehouse_id, Some((2, 500))).await;
+    create_item(&pool, tenant_id, jan, p3, warehouse_id, Some((0, 0))).await;
+    create_item(&pool, tenant_id, jan, p4, warehouse_id, None).await;
+
+    // February: two reconciliations, 4 counted, 1 with variance
+    let feb_a = create_reconciliation(
+        &pool,
+        tenant_id,
+        warehouse_id,
+        user_id,
+        "completed",
+        Some(utc(2025, 2, 5)),
+    )
+    .await;
+    create_item(&pool, tenant_id, feb_a, p1, warehouse_id, Some((0, 0))).await;
+    create_item(&pool, tenant_id, feb_a, p2, warehouse_id, Some((-1, -300))).await;
+    let feb_b = create_reconciliation(
+        &pool,
+        tenant_id,
+        warehouse_id,
+        user_id,
+        "completed",
+        Some(utc(2025, 2, 20)),
+    )
+    .await;
+    create_item(&pool, tenant_id, feb_b, p1, warehouse_id, Some((0, 0))).await;
+    create_item(&pool, tenant_id, feb_b, p2, warehouse_id, Some((0, 0))).await;
+
+    // March: every counted item off
+    let mar = create_reconciliation(
+        &pool,
+        tenant_id,
+        warehouse_id,
+        user_id,
+        "completed",
+        Some(utc(2025, 3, 15)),
+    )
+    .await;
+    create_item(&pool, tenant_id, mar, p1, warehouse_id, Some((-4, -1000))).await;
+    create_item(&pool, tenant_id, mar, p2, warehouse_id, Some((1, 200))).await;
+
+    // Excluded: still in progress, and completed after the range
+    let draft =
+        create_reconciliation(&pool, tenant_id, warehouse_id, user_id, "in_progress", None).await;
+    create_item(&pool, tenant_id, draft, p1, warehouse_id, Some((5, 500))).await;
+    let april = create_reconciliation(
+        &pool,
+        tenant_id,
+        warehouse_id,
+        user_id,
+        "completed",
+        Some(utc(2025, 4, 2)),
+    )
+    .await;
+    create_item(&pool, tenant_id, april, p1, warehouse_id, Some((3, 300))).await;
+
+    let repo = PgStockReconciliationRepository::new(Arc::new(pool.clone()));
+    let periods = repo
+        .variance_trend(
+            tenant_id,
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+            TrendGroupBy::Month,
+            None,
+        )
+        .await
+        .expect("Trend query should succeed");
+
+    assert_eq!(periods.len(), 3);
+
+    let starts: Vec<DateTime<Utc>> = periods.iter().map(|p| p.period_start).collect();
+    assert_eq!(
+        starts,
+        vec![
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+        ]
+    );
+
+    let january = &periods[0];
+    assert_eq!(january.reconciliation_count, 1);
+    assert_eq!(january.counted_items, 3);
+    assert_eq!(january.variance_count, 1);
+    assert_eq!(january.variance_value, 5.0);
+    assert_eq!(january.accuracy_percentage, Some(66.67));
+
+    let february = &periods[1];
+    assert_eq!(february.reconciliation_count, 2);
+    assert_eq!(february.counted_items, 4);
+    assert_eq!(february.variance_count, 1);
+    assert_eq!(february.variance_value, 3.0);
+    assert_eq!(february.accuracy_percentage, Some(75.0));
+
+    let march = &periods[2];
+    assert_eq!(march.reconciliation_count, 1);
+    assert_eq!(march.counted_items, 2);
+    assert_eq!(march.variance_count, 2);
+    assert_eq!(march.variance_value, 12.0);
+    assert_eq!(march.accuracy_percentage, Some(0.0));
+
+    cleanup_trend_test_data(&pool, tenant_id).await;
+}
@@ -7,7 +7,8 @@ mod business_logic_test_helpers;
 
 use business_logic_test_helpers::{
     cleanup_reorder_test_data, create_inventory_level, create_replenishment_service,
-    setup_test_pool, setup_test_tenant_product_warehouse,
+    create_replenishment_service_with_publisher, setup_test_pool,
+    setup_test_tenant_product_warehouse,
 };
 use inventory_service_core::domains::replenishment::CreateReorderRule;
 use inventory_service_core::services::ReplenishmentService;
@@ -589,3 +590,143 @@ mod reorder_edge_cases {
         cleanup_reorder_test_data(&pool, tenant_id).await;
     }
 }
+
+// ============================================================================
+// Scheduled Replenishment Check Tests
+// ============================================================================
+
+#[cfg(test)]
+mod scheduled_check_tests {
+    use super::*;
+    use async_trait::async_trait;
+    use inventory_service_infra::services::ReorderEventPublisher;
+    use shared_error::AppError;
+    use shared_events::ReorderTriggeredEvent;
+    use std::sync::{Arc, Mutex};
+
+    /// Publisher that records events instead of sending them to NATS
+    #[derive(Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<ReorderTriggeredEvent>>,
+    }
+
+    #[async_trait]
+    impl ReorderEventPublisher for RecordingPublisher {
+        async fn publish_reorder(&self, event: &ReorderTriggeredEvent) -> Result<(), AppError> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    fn below_threshold_rule(product_id: uuid::Uuid, warehouse_id: uuid::Uuid) -> CreateReorderRule {
+        CreateReorderRule {
+            product_id,
+            warehouse_id: Some(warehouse_id),
+            reorder_point: 50,
+            min_quantity: 20,
+            max_quantity: 100,
+            lead_time_days: 7,
+            safety_stock: 5,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_check_emits_once_within_cooldown() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id, warehouse_id) =
+            setup_test_tenant_product_warehouse(&pool).await;
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = create_replenishment_service_with_publisher(
+            &pool,
+            Some(publisher.clone() as Arc<dyn ReorderEventPublisher>),
+        );
+
+        create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 10).await;
+        let rule = service
+            .create_reorder_rule(tenant_id, below_threshold_rule(product_id, warehouse_id))
+            .await
+            .expect("Failed to create reorder rule");
+
+        let first = service
+            .run_scheduled_replenishment_check(tenant_id, 3600)
+            .await
+            .expect("First scheduled check should succeed");
+        let second = service
+            .run_scheduled_replenishment_check(tenant_id, 3600)
+            .await
+            .expect("Second scheduled check should succeed");
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].action_taken.as_deref(), Some("Reorder triggered event published"));
+        assert_eq!(second.len(), 1);
+        assert_eq!(
+            second[0].action_taken.as_deref(),
+            Some("Reorder already triggered within cooldown")
+        );
+
+        let events = publisher.events.lock().unwrap().clone();
+        assert_eq!(events.len(), 1, "Rule should fire exactly once within the cooldown");
+        assert_eq!(events[0].rule_id, rule.rule_id);
+        assert_eq!(events[0].tenant_id, tenant_id);
+        assert_eq!(events[0].current_quantity, 10);
+
+        cleanup_reorder_test_data(&pool, tenant_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_check_fires_again_after_cooldown() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id, warehouse_id) =
+            setup_test_tenant_product_warehouse(&pool).await;
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = create_replenishment_service_with_publisher(
+            &pool,
+            Some(publisher.clone() as Arc<dyn ReorderEventPublisher>),
+        );
+
+        create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 10).await;
+        service
+            .create_reorder_rule(tenant_id, below_threshold_rule(product_id, warehouse_id))
+            .await
+            .expect("Failed to create reorder rule");
+
+        // A zero cooldown means the previous trigger has always expired
+        for _ in 0..2 {
+            service
+                .run_scheduled_replenishment_check(tenant_id, 0)
+                .await
+                .expect("Scheduled check should succeed");
+        }
+
+        assert_eq!(publisher.events.lock().unwrap().len(), 2);
+
+        cleanup_reorder_test_data(&pool, tenant_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_check_ignores_rules_above_threshold() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id, warehouse_id) =
+            setup_test_tenant_product_warehouse(&pool).await;
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = create_replenishment_service_with_publisher(
+            &pool,
+            Some(publisher.clone() as Arc<dyn ReorderEventPublisher>),
+        );
+
+        create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 500).await;
+        service
+            .create_reorder_rule(tenant_id, below_threshold_rule(product_id, warehouse_id))
+            .await
+            .expect("Failed to create reorder rule");
+
+        service
+            .run_scheduled_replenishment_check(tenant_id, 3600)
+            .await
+            .expect("Scheduled check should succeed");
+
+        assert!(publisher.events.lock().unwrap().is_empty());
+
+        cleanup_reorder_test_data(&pool, tenant_id).await;
+    }
+}
@@ -0,0 +1,135 @@
+//! Report Concurrency Limit Tests
+//!
+//! Report requests beyond the concurrency limit wait for a slot or are
+//! rejected with 503, so no more than the limit ever reach the database.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    routing::get,
+    Router,
+};
+use inventory_service_api::middleware::{concurrency_limit_middleware, ConcurrencyLimit};
+use shared_error::SERVICE_UNAVAILABLE_RETRY_AFTER_SECS;
+use tokio::sync::Notify;
+use tower::ServiceExt;
+
+/// Tracks how many report handlers are executing at once
+#[derive(Default)]
+struct Probe {
+    running: AtomicUsize,
+    peak: AtomicUsize,
+    executed: AtomicUsize,
+}
+
+/// A report route whose handler holds its slot until `release` is notified
+fn report_app(limit: ConcurrencyLimit, probe: Arc<Probe>, release: Arc<Notify>) -> Router {
+    let handler = move || {
+        let probe = probe.clone();
+        let release = release.clone();
+        async move {
+            let running = probe.running.fetch_add(1, Ordering::SeqCst) + 1;
+            probe.peak.fetch_max(running, Ordering::SeqCst);
+            probe.executed.fetch_add(1, Ordering::SeqCst);
+            release.notified().await;
+            probe.running.fetch_sub(1, Ordering::SeqCst);
+            "report"
+        }
+    };
+
+    Router::new()
+        .route("/aging", get(handler))
+        .layer(axum::middleware::from_fn_with_state(limit, concurrency_limit_middleware))
+}
+
+/// Fire `count` concurrent report requests, releasing the handlers once the
+/// first `limit` are running
+async fn fire(
+    app: Router,
+    count: usize,
+    limit: usize,
+    probe: &Probe,
+    release: &Notify,
+) -> Vec<(StatusCode, Option<String>)> {
+    let requests: Vec<_> = (0..count)
+        .map(|_| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                let request = Request::builder()
+                    .uri("/aging")
+                    .body(Body::empty())
+                    .unwrap();
+                let response = app.oneshot(request).await.unwrap();
+                let retry_after = response
+                    .headers()
+                    .get(header::RETRY_AFTER)
+                    .map(|value| value.to_str().unwrap().to_string());
+                (response.status(), retry_after)
+            })
+        })
+        .collect();
+
+    while probe.running.load(Ordering::SeqCst) < limit {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    // Give the excess requests time to reach the limiter
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let mut results = Vec::new();
+    for request in requests {
+        while !request.is_finished() {
+            release.notify_waiters();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        results.push(request.await.unwrap());
+    }
+    results
+}
+
+#[tokio::test]
+async fn test_excess_report_requests_are_rejected() {
+    let probe = Arc::new(Probe::default());
+    let release = Arc::new(Notify::new());
+    let limit = ConcurrencyLimit::new("reports", 2, Duration::ZERO);
+    let app = report_app(limit, probe.clone(), release.clone());
+
+    let results = fire(app, 6, 2, &probe, &release).await;
+
+    let ok = results
+        .iter()
+        .filter(|(status, _)| *status == StatusCode::OK)
+        .count();
+    let rejected: Vec<_> = results
+        .iter()
+        .filter(|(status, _)| *status == StatusCode::SERVICE_UNAVAILABLE)
+        .collect();
+    assert_eq!(ok, 2);
+    assert_eq!(rejected.len(), 4);
+    assert!(rejected
+        .iter()
+        .all(|(_, retry_after)| retry_after
+            == &Some(SERVICE_UNAVAILABLE_RETRY_AFTER_SECS.to_string())));
+
+    // Only the admitted requests ever ran
+    assert_eq!(probe.executed.load(Ordering::SeqCst), 2);
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_excess_report_requests_queue_for_a_slot() {
+    let probe = Arc::new(Probe::default());
+    let release = Arc::new(Notify::new());
+    let limit = ConcurrencyLimit::new("reports", 2, Duration::from_secs(10));
+    let app = report_app(limit, probe.clone(), release.clone());
+
+    let results = fire(app, 6, 2, &probe, &release).await;
+
+    // Every request is served, but never more than two at a time
+    assert!(results.iter().all(|(status, _)| *status == StatusCode::OK));
+    assert_eq!(probe.executed.load(Ordering::SeqCst), 6);
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+}
@@ -663,7 +663,7 @@ async fn test_inventory_turnover_tenant_isolation() {
     }
 }
 
-/// Test Inventory Turnover Report validates required parameters
+/// Test Inventory Turnover Report validates its date range
 #[cfg(feature = "integration_tests_reports")]
 #[tokio::test]
 async fn test_inventory_turnover_report_validation() {
@@ -672,23 +672,39 @@ async fn test_inventory_turnover_report_validation() {
     let (tenant_id, user_id, _, _) = setup_tenant_with_stock_data(&pool, "TurnoverValid").await;
     let auth_user = create_auth_user(user_id, tenant_id, "turnover-valid@example.com");
 
-    // Missing 'from' parameter
+    let get = |query: &str| {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/v1/inventory/reports/turnover?{}", query))
+            .header("x-user-id", auth_user.user_id.to_string())
+            .header("x-tenant-id", auth_user.tenant_id.to_string())
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // 'from' after 'to'
     let response = app
         .clone()
-        .oneshot(
-            Request::builder()
-                .method("GET")
-                .uri("/api/v1/inventory/reports/turnover?to=2024-12-31T23:59:59Z&group_by=product")
-                .header("x-user-id", auth_user.user_id.to_string())
-                .header("x-tenant-id", auth_user.tenant_id.to_string())
-                .body(Body::empty())
-                .unwrap(),
-        )
+        .oneshot(get("from=2024-12-31T00:00:00Z&to=2024-01-01T00:00:00Z"))
         .await
         .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 
-    // Should return 400 Bad Request for missing required parameter
+    // Longer than the default maximum span of 730 days
+    let response = app
+        .clone()
+        .oneshot(get("from=2020-01-01T00:00:00Z&to=2024-12-31T00:00:00Z"))
+        .await
+        .unwrap();
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Missing 'from' defaults to the 90 days before 'to'
+    let response = app
+        .clone()
+        .oneshot(get("to=2024-12-31T23:59:59Z&group_by=product"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
 }
 
 // ============================================================================
@@ -0,0 +1,77 @@
+//! Response Envelope Tests
+//!
+//! A list handler answers in its own shape by default and as `{data, meta}`
+//! when the client opts in through the query flag or the `Accept` profile.
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    response::Response,
+    routing::get,
+    Router,
+};
+use http_body_util::BodyExt;
+use inventory_service_api::models::ResponseShape;
+use inventory_service_core::dto::category::CategoryListResponse;
+use inventory_service_core::dto::common::PaginationInfo;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+/// List handler shaped like the real list endpoints
+async fn list_handler(shape: ResponseShape) -> Response {
+    shape.respond(CategoryListResponse {
+        categories: Vec::new(),
+        pagination: PaginationInfo::new(2, 10, 15),
+    })
+}
+
+async fn get_json(uri: &str, accept: Option<&str>) -> Value {
+    let app = Router::new().route("/categories", get(list_handler));
+
+    let mut request = Request::builder().uri(uri);
+    if let Some(accept) = accept {
+        request = request.header(header::ACCEPT, accept);
+    }
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+fn expected_meta() -> Value {
+    json!({
+        "page": 2,
+        "pageSize": 10,
+        "totalItems": 15,
+        "totalPages": 2,
+        "hasNext": false,
+        "hasPrev": true
+    })
+}
+
+#[tokio::test]
+async fn test_list_response_is_bare_by_default() {
+    for (uri, accept) in [
+        ("/categories", None),
+        ("/categories", Some("application/json")),
+        ("/categories?envelope=false", None),
+    ] {
+        let body = get_json(uri, accept).await;
+        assert_eq!(body, json!({ "categories": [], "pagination": expected_meta() }));
+    }
+}
+
+#[tokio::test]
+async fn test_list_response_is_enveloped_on_request() {
+    for (uri, accept) in [
+        ("/categories?envelope=true", None),
+        ("/categories", Some(r#"application/json; profile="envelope""#)),
+    ] {
+        let body = get_json(uri, accept).await;
+        assert_eq!(body, json!({ "data": [], "meta": expected_meta() }));
+    }
+}
@@ -0,0 +1,477 @@
+//! Soft-Delete Filter Tests
+//!
+//! Every read of a soft-deletable table must leave out rows with `deleted_at`
+//! set. Each test seeds live and deleted categories, products or warehouses and
+//! asserts that no repository read method returns a deleted one.
+
+mod business_logic_test_helpers;
+
+use std::collections::HashSet;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::domains::category::{Category, CategoryNode};
+use inventory_service_core::domains::inventory::dto::search_dto::{
+    ProductSearchRequest, SearchSuggestionsRequest,
+};
+use inventory_service_core::domains::inventory::dto::warehouse_dto::WarehouseTreeNode;
+use inventory_service_core::domains::inventory::warehouse::Warehouse;
+use inventory_service_core::repositories::category::CategoryRepository;
+use inventory_service_core::repositories::product::ProductRepository;
+use inventory_service_core::repositories::warehouse::WarehouseRepository;
+use inventory_service_core::services::category::CategoryService;
+use inventory_service_infra::repositories::{
+    CategoryRepositoryImpl, ProductRepositoryImpl, WarehouseRepositoryImpl,
+};
+use inventory_service_infra::services::category::CategoryServiceImpl;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn create_category(
+    service: &CategoryServiceImpl<CategoryRepositoryImpl>,
+    tenant_id: Uuid,
+    name: &str,
+    parent_id: Option<Uuid>,
+) -> Category {
+    let request = serde_json::from_value(json!({
+        "name": name,
+        "code": name.to_uppercase().replace(' ', "-"),
+        "parentCategoryId": parent_id,
+    }))
+    .expect("Valid category request");
+    service
+        .create_category(tenant_id, request)
+        .await
+        .expect("Category should be created")
+}
+
+/// Insert a product whose barcode equals its SKU, optionally already deleted
+async fn insert_product(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    sku: &str,
+    category_id: Option<Uuid>,
+    deleted: bool,
+) -> Uuid {
+    let product_id = Uuid::now_v7();
+    sqlx::query(
+        "INSERT INTO products (product_id, tenant_id, sku, name, barcode, category_id, created_at, deleted_at)
+         VALUES ($1, $2, $3, $4, $3, $5, NOW(), CASE WHEN $6 THEN NOW() END)",
+    )
+    .bind(product_id)
+    .bind(tenant_id)
+    .bind(sku)
+    .bind(format!("Soft {}", sku))
+    .bind(category_id)
+    .bind(deleted)
+    .execute(pool)
+    .await
+    .expect("Failed to insert product");
+    product_id
+}
+
+fn category_ids(categories: &[Category]) -> HashSet<Uuid> {
+    categories.iter().map(|c| c.category_id).collect()
+}
+
+fn category_tree_ids(nodes: &[CategoryNode], ids: &mut HashSet<Uuid>) {
+    for node in nodes {
+        ids.insert(node.category.category_id);
+        category_tree_ids(&node.children, ids);
+    }
+}
+
+fn warehouse_ids(warehouses: &[Warehouse]) -> HashSet<Uuid> {
+    warehouses.iter().map(|w| w.warehouse_id).collect()
+}
+
+fn warehouse_tree_ids(nodes: &[WarehouseTreeNode], ids: &mut HashSet<Uuid>) {
+    for node in nodes {
+        ids.insert(node.warehouse.warehouse_id);
+        for zone in &node.zones {
+            ids.insert(zone.zone.zone_id);
+            ids.extend(zone.locations.iter().map(|l| l.location_id));
+        }
+        warehouse_tree_ids(&node.children, ids);
+    }
+}
+
+async fn cleanup_soft_delete_test_data(pool: &PgPool, tenant_id: Uuid) {
+    for table in [
+        "products",
+        "product_categories",
+        "warehouse_locations",
+        "warehouse_zones",
+    ] {
+        let _ = sqlx::query(&format!("DELETE FROM {} WHERE tenant_id = $1", table))
+            .bind(tenant_id)
+            .execute(pool)
+            .await;
+    }
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_category_reads_exclude_deleted_rows() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = CategoryRepositoryImpl::new(pool.clone());
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let live_root = create_category(&service, tenant_id, "Soft Live Root", None).await;
+    let live_child =
+        create_category(&service, tenant_id, "Soft Live Child", Some(live_root.category_id)).await;
+    let gone_child =
+        create_category(&service, tenant_id, "Soft Gone Child", Some(live_root.category_id)).await;
+    let gone_root = create_category(&service, tenant_id, "Soft Gone Root", None).await;
+    // Holds nothing but deleted rows
+    let emptied = create_category(&service, tenant_id, "Soft Emptied", None).await;
+    let emptied_child =
+        create_category(&service, tenant_id, "Soft Emptied Child", Some(emptied.category_id)).await;
+
+    for category in [&gone_child, &gone_root, &emptied_child] {
+        assert!(repo.delete(tenant_id, category.category_id).await.unwrap());
+    }
+    insert_product(&pool, tenant_id, "SOFT-CAT-1", Some(live_root.category_id), true).await;
+    insert_product(&pool, tenant_id, "SOFT-CAT-2", Some(emptied.category_id), true).await;
+
+    let deleted: HashSet<Uuid> = [&gone_child, &gone_root, &emptied_child]
+        .iter()
+        .map(|c| c.category_id)
+        .collect();
+    let assert_live = |ids: HashSet<Uuid>, method: &str| {
+        assert!(ids.is_disjoint(&deleted), "{} returned a deleted category", method);
+    };
+
+    // Lookups by key
+    assert!(repo
+        .find_by_id(tenant_id, gone_child.category_id)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_slug(tenant_id, gone_child.slug.as_deref().unwrap())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_code(tenant_id, gone_child.code.as_deref().unwrap())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(!repo
+        .exists(tenant_id, gone_child.category_id)
+        .await
+        .unwrap());
+
+    // Listings
+    let query = serde_json::from_value(json!({ "pageSize": 100 })).unwrap();
+    let (listed, total) = repo.list(tenant_id, &query).await.unwrap();
+    assert_eq!(total, Some(3));
+    assert_live(category_ids(&listed), "list");
+
+    let search = serde_json::from_value(json!({ "search": "Gone", "pageSize": 100 })).unwrap();
+    let (listed, _) = repo.list(tenant_id, &search).await.unwrap();
+    assert!(listed.is_empty(), "list with search returned a deleted category");
+
+    assert_live(
+        category_ids(&repo.get_root_categories(tenant_id).await.unwrap()),
+        "get_root_categories",
+    );
+    assert_live(
+        category_ids(&repo.get_top_categories(tenant_id, 10).await.unwrap()),
+        "get_top_categories",
+    );
+    assert_live(category_ids(&repo.search(tenant_id, "Soft", 50).await.unwrap()), "search");
+
+    // Hierarchy
+    let children = repo
+        .get_children(tenant_id, live_root.category_id)
+        .await
+        .unwrap();
+    assert_eq!(category_ids(&children), HashSet::from([live_child.category_id]));
+    let descendants = repo
+        .get_descendants(tenant_id, live_root.category_id)
+        .await
+        .unwrap();
+    assert_eq!(category_ids(&descendants), HashSet::from([live_child.category_id]));
+    assert!(repo
+        .get_ancestors(tenant_id, gone_child.category_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let mut tree_ids = HashSet::new();
+    category_tree_ids(&repo.get_tree(tenant_id, None).await.unwrap(), &mut tree_ids);
+    assert_eq!(
+        tree_ids,
+        HashSet::from([
+            live_root.category_id,
+            live_child.category_id,
+            emptied.category_id
+        ])
+    );
+
+    // Deleted children and products do not count
+    assert!(!repo
+        .has_children(tenant_id, emptied.category_id)
+        .await
+        .unwrap());
+    assert!(!repo
+        .has_products(tenant_id, emptied.category_id)
+        .await
+        .unwrap());
+    assert!(repo
+        .get_products_in_tree(tenant_id, live_root.category_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let stats = repo
+        .get_stats(tenant_id, live_root.category_id)
+        .await
+        .unwrap();
+    assert_eq!(stats.subcategory_count, 1);
+    assert_eq!(stats.active_product_count + stats.inactive_product_count, 0);
+
+    cleanup_soft_delete_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_product_reads_exclude_deleted_rows() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = ProductRepositoryImpl::new(pool.clone());
+    let service = CategoryServiceImpl::new(CategoryRepositoryImpl::new(pool.clone()));
+
+    let live = insert_product(&pool, tenant_id, "SOFT-LIVE", None, false).await;
+    let gone = insert_product(&pool, tenant_id, "SOFT-GONE", None, true).await;
+
+    // A live product left in a category that was deleted
+    let category = create_category(&service, tenant_id, "Soft Retired", None).await;
+    let orphan =
+        insert_product(&pool, tenant_id, "SOFT-ORPHAN", Some(category.category_id), false).await;
+    assert!(CategoryRepositoryImpl::new(pool.clone())
+        .delete(tenant_id, category.category_id)
+        .await
+        .unwrap());
+
+    // Lookups by key
+    assert!(repo.find_by_id(tenant_id, gone).await.unwrap().is_none());
+    assert!(repo
+        .find_by_sku(tenant_id, "SOFT-GONE")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_barcode(tenant_id, "SOFT-GONE")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .get_attributes(tenant_id, gone)
+        .await
+        .unwrap()
+        .is_none());
+    let found = repo.find_by_ids(tenant_id, &[live, gone]).await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].product_id, live);
+
+    // Search, suggestions and export
+    let request = ProductSearchRequest {
+        active_only: None,
+        sellable_only: None,
+        ..Default::default()
+    };
+    let results = repo.search_products(tenant_id, request).await.unwrap();
+    let found: HashSet<Uuid> = results.products.iter().map(|p| p.product_id).collect();
+    assert!(!found.contains(&gone), "search_products returned a deleted product");
+    assert!(found.contains(&live));
+    let orphan_result = results
+        .products
+        .iter()
+        .find(|p| p.product_id == orphan)
+        .expect("Product in a deleted category is still live");
+    assert_eq!(orphan_result.category_name, None);
+
+    let suggestions = repo
+        .get_search_suggestions(
+            tenant_id,
+            SearchSuggestionsRequest {
+                query: "SOFT-".to_string(),
+                limit: Some(20),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(suggestions
+        .suggestions
+        .iter()
+        .all(|s| !s.text.contains("SOFT-GONE")));
+
+    let exported = repo
+        .find_all_for_export(tenant_id, None, None, None, None)
+        .await
+        .unwrap();
+    assert!(exported.iter().all(|p| p.product_id != gone));
+
+    cleanup_soft_delete_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_warehouse_reads_exclude_deleted_rows() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, _) = setup_test_tenant_and_product(&pool).await;
+    let repo = WarehouseRepositoryImpl::new(pool.clone());
+
+    let warehouse = |code: &str, parent: Option<Uuid>| {
+        serde_json::from_value(json!({
+            "warehouseCode": code,
+            "warehouseName": format!("Soft {}", code),
+            "warehouseType": "main",
+            "parentWarehouseId": parent,
+        }))
+        .unwrap()
+    };
+    let main = repo
+        .create(tenant_id, warehouse("SOFT-MAIN", None))
+        .await
+        .unwrap();
+    let live_child = repo
+        .create(tenant_id, warehouse("SOFT-CHILD", Some(main.warehouse_id)))
+        .await
+        .unwrap();
+    let gone_child = repo
+        .create(tenant_id, warehouse("SOFT-GONE", Some(main.warehouse_id)))
+        .await
+        .unwrap();
+    let gone_root = repo
+        .create(tenant_id, warehouse("SOFT-GONE-ROOT", None))
+        .await
+        .unwrap();
+
+    let zone = |code: &str| {
+        serde_json::from_value(json!({
+            "zoneCode": code,
+            "zoneName": code,
+            "zoneType": "storage",
+        }))
+        .unwrap()
+    };
+    let live_zone = repo
+        .create_zone(tenant_id, main.warehouse_id, zone("Z-LIVE"))
+        .await
+        .unwrap();
+    let gone_zone = repo
+        .create_zone(tenant_id, main.warehouse_id, zone("Z-GONE"))
+        .await
+        .unwrap();
+
+    let location = |code: &str| {
+        serde_json::from_value(json!({
+            "zoneId": live_zone.zone_id,
+            "locationCode": code,
+            "locationType": "bin",
+        }))
+        .unwrap()
+    };
+    let live_location = repo
+        .create_location(tenant_id, main.warehouse_id, location("L-LIVE"))
+        .await
+        .unwrap();
+    let gone_location = repo
+        .create_location(tenant_id, main.warehouse_id, location("L-GONE"))
+        .await
+        .unwrap();
+
+    assert!(repo
+        .delete(tenant_id, gone_child.warehouse_id)
+        .await
+        .unwrap());
+    assert!(repo
+        .delete(tenant_id, gone_root.warehouse_id)
+        .await
+        .unwrap());
+    assert!(repo
+        .delete_zone(tenant_id, gone_zone.zone_id)
+        .await
+        .unwrap());
+    assert!(repo
+        .delete_location(tenant_id, gone_location.location_id)
+        .await
+        .unwrap());
+
+    let deleted = HashSet::from([
+        gone_child.warehouse_id,
+        gone_root.warehouse_id,
+        gone_zone.zone_id,
+        gone_location.location_id,
+    ]);
+
+    // Lookups by key
+    assert!(repo
+        .find_by_id(tenant_id, gone_child.warehouse_id)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_code(tenant_id, "SOFT-GONE")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_zone_by_id(tenant_id, gone_zone.zone_id)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_location_by_id(tenant_id, gone_location.location_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Listings and hierarchy
+    assert!(warehouse_ids(&repo.find_all(tenant_id).await.unwrap()).is_disjoint(&deleted));
+    let children = repo
+        .get_children(tenant_id, main.warehouse_id)
+        .await
+        .unwrap();
+    assert_eq!(warehouse_ids(&children), HashSet::from([live_child.warehouse_id]));
+    let descendants = repo
+        .get_descendants(tenant_id, main.warehouse_id)
+        .await
+        .unwrap();
+    assert_eq!(warehouse_ids(&descendants), HashSet::from([live_child.warehouse_id]));
+    assert!(repo
+        .get_ancestors(tenant_id, gone_child.warehouse_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let zones = repo.get_all_zones(tenant_id).await.unwrap();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].zone_id, live_zone.zone_id);
+    let zones = repo
+        .get_zones_by_warehouse(tenant_id, main.warehouse_id)
+        .await
+        .unwrap();
+    assert_eq!(zones.len(), 1);
+
+    let locations = repo.get_all_locations(tenant_id).await.unwrap();
+    assert_eq!(locations.len(), 1);
+    assert_eq!(locations[0].location_id, live_location.location_id);
+    let locations = repo
+        .get_locations_by_warehouse(tenant_id, main.warehouse_id)
+        .await
+        .unwrap();
+    assert_eq!(locations.len(), 1);
+
+    let mut tree_ids = HashSet::new();
+    warehouse_tree_ids(&repo.get_warehouse_tree(tenant_id).await.unwrap().roots, &mut tree_ids);
+    assert!(tree_ids.is_disjoint(&deleted), "get_warehouse_tree returned a deleted row");
+    assert!(tree_ids.contains(&live_location.location_id));
+
+    cleanup_soft_delete_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,132 @@
+//! Stock Move Archive Integration Tests
+//!
+//! Archiving moves old stock moves out of the hot table without changing any
+//! balance computed over hot and archived moves together.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use inventory_service_infra::repositories::stock::PgStockMoveRepository;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn day(month: u32, day: u32) -> DateTime<Utc> {
+    // Far enough in the past that no other test's moves fall before the cutoff
+    Utc.with_ymd_and_hms(2000, month, day, 12, 0, 0).unwrap()
+}
+
+async fn insert_move(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    product_id: Uuid,
+    quantity: i64,
+    move_date: DateTime<Utc>,
+    reversal_of: Option<Uuid>,
+) -> Uuid {
+    let move_id = Uuid::now_v7();
+    let move_type = if reversal_of.is_some() {
+        "reversal"
+    } else {
+        "adjustment"
+    };
+    sqlx::query(
+        "INSERT INTO stock_moves (
+             move_id, tenant_id, product_id, move_type, quantity, unit_cost,
+             reference_type, reference_id, idempotency_key, move_date, reversal_of_move_id
+         )
+         VALUES ($1, $2, $3, $4, $5, 100, 'adjustment', $6, $7, $8, $9)",
+    )
+    .bind(move_id)
+    .bind(tenant_id)
+    .bind(product_id)
+    .bind(move_type)
+    .bind(quantity)
+    .bind(Uuid::now_v7())
+    .bind(format!("archive-test-{}", move_id))
+    .bind(move_date)
+    .bind(reversal_of)
+    .execute(pool)
+    .await
+    .expect("Failed to insert stock move");
+    move_id
+}
+
+async fn move_ids(pool: &PgPool, table: &str, tenant_id: Uuid) -> Vec<Uuid> {
+    sqlx::query_scalar(&format!(
+        "SELECT move_id FROM {} WHERE tenant_id = $1 ORDER BY move_date",
+        table
+    ))
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+/// (quantity, value) summed over hot and archived moves
+async fn balance(pool: &PgPool, tenant_id: Uuid, product_id: Uuid) -> (i64, i64) {
+    sqlx::query_as(
+        "SELECT COALESCE(SUM(quantity), 0)::BIGINT, COALESCE(SUM(total_cost), 0)::BIGINT
+         FROM stock_moves_with_archive
+         WHERE tenant_id = $1 AND product_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn cleanup_archive_test_data(pool: &PgPool, tenant_id: Uuid) {
+    for sql in [
+        "DELETE FROM stock_moves WHERE tenant_id = $1 AND reversal_of_move_id IS NOT NULL",
+        "DELETE FROM stock_moves WHERE tenant_id = $1",
+        "DELETE FROM stock_moves_archive WHERE tenant_id = $1",
+    ] {
+        let _ = sqlx::query(sql).bind(tenant_id).execute(pool).await;
+    }
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_archiving_old_moves_keeps_balances_intact() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+
+    let received = insert_move(&pool, tenant_id, product_id, 50, day(1, 5), None).await;
+    let issued = insert_move(&pool, tenant_id, product_id, -20, day(1, 20), None).await;
+    let reversed = insert_move(&pool, tenant_id, product_id, -5, day(2, 1), None).await;
+    let recent = insert_move(&pool, tenant_id, product_id, 8, day(4, 1), None).await;
+    // The reversal is newer than the cutoff and still points at its original
+    let reversal = insert_move(&pool, tenant_id, product_id, 5, day(4, 2), Some(reversed)).await;
+
+    let before = balance(&pool, tenant_id, product_id).await;
+    assert_eq!(before, (38, 3800));
+
+    // Small batches exercise the repeated statement
+    let mut archived = 0;
+    loop {
+        let batch = PgStockMoveRepository::archive_before(&pool, day(3, 1), 1)
+            .await
+            .unwrap();
+        if batch == 0 {
+            break;
+        }
+        archived += batch;
+    }
+    assert_eq!(archived, 2);
+
+    // Old moves left the hot table; the reversed one stays with its reversal
+    assert_eq!(
+        move_ids(&pool, "stock_moves", tenant_id).await,
+        vec![reversed, recent, reversal]
+    );
+    assert_eq!(move_ids(&pool, "stock_moves_archive", tenant_id).await, vec![received, issued]);
+
+    // Hot plus archive still adds up to the same balance
+    assert_eq!(balance(&pool, tenant_id, product_id).await, before);
+
+    cleanup_archive_test_data(&pool, tenant_id).await;
+}
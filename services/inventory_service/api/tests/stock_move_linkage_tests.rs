@@ -0,0 +1,259 @@
+//! Stock Move Linkage Integration Tests
+//!
+//! Verifies that services tag every stock move with its reason code and source
+//! document, and that reversing a move writes a compensating move that nets the
+//! original to zero.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_transfer_test_data, create_inventory_level, create_uom, create_user, create_warehouse,
+    setup_test_pool, setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::domains::inventory::dto::transfer_dto::{
+    ConfirmTransferRequest, CreateTransferItemRequest, CreateTransferRequest,
+    ReceiveTransferRequest,
+};
+use inventory_service_core::domains::inventory::transfer::{TransferPriority, TransferType};
+use inventory_service_core::dto::adjustment::{AdjustmentReasonCode, QuickAdjustmentRequest};
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
+use inventory_service_core::repositories::StockMoveRepository;
+use inventory_service_core::services::adjustment::AdjustmentService;
+use inventory_service_core::services::TransferService;
+use inventory_service_infra::repositories::{
+    PgInventoryLevelRepository, PgStockMoveRepository, PgTransferItemRepository,
+    PgTransferRepository, WarehouseRepositoryImpl,
+};
+use inventory_service_infra::services::{PgAdjustmentService, PgTransferService};
+use shared_error::AppError;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// (move_type, reason_code, source_type, source_id, quantity) of a product's moves, oldest first
+type MoveTags = (String, Option<String>, Option<String>, Option<Uuid>, i64);
+
+async fn get_move_tags(pool: &PgPool, tenant_id: Uuid, product_id: Uuid) -> Vec<MoveTags> {
+    sqlx::query_as::<_, MoveTags>(
+        "SELECT move_type, reason_code, source_type, source_id, quantity FROM stock_moves
+         WHERE tenant_id = $1 AND product_id = $2
+         ORDER BY created_at, move_id",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .fetch_all(pool)
+    .await
+    .expect("Failed to read stock moves")
+}
+
+async fn cleanup_linkage_test_data(pool: &PgPool, tenant_id: Uuid) {
+    // Reversals reference their originals, so delete them first
+    let _ = sqlx::query(
+        "DELETE FROM stock_moves WHERE tenant_id = $1 AND reversal_of_move_id IS NOT NULL",
+    )
+    .bind(tenant_id)
+    .execute(pool)
+    .await;
+    cleanup_transfer_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_quick_adjustment_move_is_tagged() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 10).await;
+    let service = PgAdjustmentService::new(Arc::new(pool.clone()));
+
+    service
+        .adjust(
+            tenant_id,
+            Uuid::now_v7(),
+            QuickAdjustmentRequest {
+                warehouse_id,
+                location_id: None,
+                product_id,
+                delta: -2,
+                reason_code: AdjustmentReasonCode::Damaged,
+                note: None,
+            },
+        )
+        .await
+        .expect("Adjustment should succeed");
+
+    let moves = get_move_tags(&pool, tenant_id, product_id).await;
+    assert_eq!(moves.len(), 1);
+    let (move_type, reason_code, source_type, source_id, quantity) = &moves[0];
+    assert_eq!(move_type, "adjustment");
+    assert_eq!(reason_code.as_deref(), Some("damaged"));
+    assert_eq!(source_type.as_deref(), Some("adjustment"));
+    assert!(source_id.is_some());
+    assert_eq!(*quantity, -2);
+
+    cleanup_linkage_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_transfer_moves_are_tagged() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, source_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let destination_id = create_warehouse(&pool, tenant_id, "Destination Warehouse").await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let uom_id = create_uom(&pool, tenant_id).await;
+    create_inventory_level(&pool, tenant_id, product_id, source_id, 100).await;
+
+    let pool_arc = Arc::new(pool.clone());
+    let service = PgTransferService::new(
+        pool_arc.clone(),
+        Arc::new(PgTransferRepository::new(pool_arc.clone())),
+        Arc::new(PgTransferItemRepository::new(pool_arc.clone())),
+        Arc::new(PgInventoryLevelRepository::new(pool_arc.clone())),
+        Arc::new(WarehouseRepositoryImpl::new(pool.clone())),
+    );
+
+    let transfer_id = service
+        .create_transfer(
+            tenant_id,
+            user_id,
+            CreateTransferRequest {
+                reference_number: None,
+                source_warehouse_id: source_id,
+                destination_warehouse_id: destination_id,
+                transfer_type: TransferType::Manual,
+                priority: TransferPriority::Normal,
+                expected_ship_date: None,
+                expected_receive_date: None,
+                shipping_method: None,
+                notes: None,
+                reason: None,
+                items: vec![CreateTransferItemRequest {
+                    product_id,
+                    quantity: 30,
+                    uom_id: Some(uom_id),
+                    unit_cost: Some(1_000),
+                    line_number: 1,
+                    source_zone_id: None,
+                    source_location_id: None,
+                    destination_zone_id: None,
+                    destination_location_id: None,
+                    notes: None,
+                }],
+            },
+        )
+        .await
+        .expect("Transfer creation should succeed")
+        .transfer_id;
+    service
+        .confirm_transfer(tenant_id, transfer_id, user_id, ConfirmTransferRequest { notes: None })
+        .await
+        .expect("Shipping should succeed");
+    service
+        .receive_transfer(tenant_id, transfer_id, user_id, ReceiveTransferRequest { notes: None })
+        .await
+        .expect("Receiving should succeed");
+
+    let moves = get_move_tags(&pool, tenant_id, product_id).await;
+    let reasons: Vec<_> = moves
+        .iter()
+        .map(|(move_type, reason_code, source_type, move_source_id, _)| {
+            assert_eq!(move_type, "transfer");
+            assert_eq!(source_type.as_deref(), Some("transfer"));
+            assert_eq!(*move_source_id, Some(transfer_id));
+            reason_code.clone().unwrap_or_default()
+        })
+        .collect();
+    assert_eq!(reasons, vec!["transfer_out", "transfer_in"]);
+
+    cleanup_linkage_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_reverse_move_nets_to_zero() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, _warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let repo = PgStockMoveRepository::new(Arc::new(pool.clone()));
+    let receipt_id = Uuid::now_v7();
+
+    let original = repo
+        .record(
+            &MoveIntent::new(
+                MoveSourceType::Receipt,
+                receipt_id,
+                "goods_receipt",
+                product_id,
+                12,
+                format!("linkage-{}", receipt_id),
+            )
+            .with_unit_cost(Some(500)),
+            tenant_id,
+        )
+        .await
+        .expect("Recording the move should succeed");
+    assert_eq!(original.move_type, "receipt");
+    assert_eq!(original.reference_type, "grn");
+    assert_eq!(original.source_id, Some(receipt_id));
+
+    let reversal = repo
+        .reverse_move(tenant_id, original.move_id)
+        .await
+        .expect("Reversal should succeed");
+
+    assert_eq!(reversal.reversal_of_move_id, Some(original.move_id));
+    assert_eq!(reversal.move_type, "reversal");
+    assert_eq!(reversal.reason_code.as_deref(), Some("reversal"));
+    assert_eq!(reversal.source_type.as_deref(), Some("receipt"));
+    assert_eq!(reversal.source_id, Some(receipt_id));
+    assert_eq!(reversal.source_location_id, original.destination_location_id);
+    assert_eq!(reversal.destination_location_id, original.source_location_id);
+    assert_eq!(original.quantity + reversal.quantity, 0);
+
+    let net: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(quantity), 0)::BIGINT FROM stock_moves
+         WHERE tenant_id = $1 AND source_type = 'receipt' AND source_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(receipt_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(net, 0);
+
+    cleanup_linkage_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_reverse_move_rejects_repeat_and_chained_reversals() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, _warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let repo = PgStockMoveRepository::new(Arc::new(pool.clone()));
+    let receipt_id = Uuid::now_v7();
+
+    let original = repo
+        .record(
+            &MoveIntent::new(
+                MoveSourceType::Receipt,
+                receipt_id,
+                "goods_receipt",
+                product_id,
+                4,
+                format!("linkage-{}", receipt_id),
+            ),
+            tenant_id,
+        )
+        .await
+        .unwrap();
+    let reversal = repo
+        .reverse_move(tenant_id, original.move_id)
+        .await
+        .unwrap();
+
+    let again = repo.reverse_move(tenant_id, original.move_id).await;
+    assert!(matches!(again, Err(AppError::Conflict(_))));
+
+    let chained = repo.reverse_move(tenant_id, reversal.move_id).await;
+    assert!(matches!(chained, Err(AppError::BusinessError(_))));
+
+    let missing = repo.reverse_move(tenant_id, Uuid::now_v7()).await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+
+    cleanup_linkage_test_data(&pool, tenant_id).await;
+}
@@ -5,40 +5,19 @@
 mod business_logic_test_helpers;
 
 use business_logic_test_helpers::{
-    cleanup_reorder_test_data, create_inventory_level, setup_test_pool,
+    cleanup_reorder_test_data, create_inventory_level, create_inventory_service, setup_test_pool,
     setup_test_tenant_product_warehouse,
 };
+use inventory_service_core::models::{
+    NewStockReservation, ReservationStatus, StockReservationStatus,
+};
 use inventory_service_core::services::InventoryService;
-use inventory_service_infra::repositories::PgInventoryRepository;
-use inventory_service_infra::services::InventoryServiceImpl;
-use std::sync::Arc;
-
-/// Helper to create InventoryService
-async fn create_inventory_service(pool: &sqlx::PgPool) -> InventoryServiceImpl {
-    // We need ProductRepository and LotSerialRepository to instantiate PgInventoryRepository
-    // because it handles both standard and lot-tracked reservations.
-
-    // Using default implementations from existing repositories
-    let product_repo =
-        Arc::new(inventory_service_infra::repositories::ProductRepositoryImpl::new(pool.clone()));
-    let lot_serial_repo = Arc::new(
-        inventory_service_infra::repositories::LotSerialRepositoryImpl::new(pool.clone()),
-    );
-
-    let inventory_repo = Arc::new(PgInventoryRepository::new(
-        Arc::new(pool.clone()),
-        product_repo,
-        lot_serial_repo,
-    ));
-
-    InventoryServiceImpl::new(inventory_repo)
-}
 
 #[tokio::test]
 async fn test_reserve_stock_standard_product() {
     let pool = setup_test_pool().await;
     let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
-    let service = create_inventory_service(&pool).await;
+    let service = create_inventory_service(&pool);
 
     // Set initial inventory: 100 available
     create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
@@ -78,7 +57,7 @@ async fn test_reserve_stock_standard_product() {
 async fn test_reserve_insufficient_stock_fails() {
     let pool = setup_test_pool().await;
     let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
-    let service = create_inventory_service(&pool).await;
+    let service = create_inventory_service(&pool);
 
     create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 50).await;
 
@@ -103,7 +82,7 @@ async fn test_reserve_insufficient_stock_fails() {
 async fn test_release_stock() {
     let pool = setup_test_pool().await;
     let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
-    let service = create_inventory_service(&pool).await;
+    let service = create_inventory_service(&pool);
 
     // Initial: 100
     create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
@@ -137,3 +116,218 @@ async fn test_release_stock() {
 
     cleanup_reorder_test_data(&pool, tenant_id).await;
 }
+
+/// Set the product's over-commit buffer directly
+async fn set_overcommit_pct(
+    pool: &sqlx::PgPool,
+    tenant_id: uuid::Uuid,
+    product_id: uuid::Uuid,
+    pct: i32,
+) {
+    sqlx::query!(
+        "UPDATE products SET overcommit_pct = $3 WHERE tenant_id = $1 AND product_id = $2",
+        tenant_id,
+        product_id,
+        pct
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to set overcommit_pct");
+}
+
+#[tokio::test]
+async fn test_reserve_within_overcommit_buffer_is_backordered() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let service = create_inventory_service(&pool);
+
+    // 100 on hand, 10% buffer => up to 10 units may be backordered
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+    set_overcommit_pct(&pool, tenant_id, product_id, 10).await;
+
+    let status = service
+        .reserve_stock(tenant_id, warehouse_id, product_id, 105)
+        .await
+        .expect("Reservation within the buffer should succeed");
+    assert_eq!(status, ReservationStatus::Backordered);
+
+    let level = sqlx::query!(
+        "SELECT available_quantity, reserved_quantity, backordered_quantity FROM inventory_levels
+         WHERE tenant_id = $1 AND product_id = $2 AND warehouse_id = $3",
+        tenant_id,
+        product_id,
+        warehouse_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    // Physical stock is fully reserved; the shortfall is reported separately
+    assert_eq!(level.available_quantity, 0);
+    assert_eq!(level.reserved_quantity, 100);
+    assert_eq!(level.backordered_quantity, 5);
+
+    // Releasing cancels the backorder before freeing physical stock
+    service
+        .release_stock(tenant_id, warehouse_id, product_id, 8)
+        .await
+        .expect("Release should succeed");
+
+    let level = sqlx::query!(
+        "SELECT available_quantity, reserved_quantity, backordered_quantity FROM inventory_levels
+         WHERE tenant_id = $1 AND product_id = $2 AND warehouse_id = $3",
+        tenant_id,
+        product_id,
+        warehouse_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(level.backordered_quantity, 0);
+    assert_eq!(level.available_quantity, 3);
+    assert_eq!(level.reserved_quantity, 97);
+
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_reserve_beyond_overcommit_buffer_fails() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let service = create_inventory_service(&pool);
+
+    create_inventory_level(&pool, tenant_id, product_id, warehouse_id, 100).await;
+    set_overcommit_pct(&pool, tenant_id, product_id, 10).await;
+
+    // 11 units short exceeds the 10-unit buffer
+    let result = service
+        .reserve_stock(tenant_id, warehouse_id, product_id, 111)
+        .await;
+    assert!(result.is_err(), "Should fail beyond the over-commit buffer");
+
+    // Backorders accumulate against the same buffer
+    let status = service
+        .reserve_stock(tenant_id, warehouse_id, product_id, 106)
+        .await
+        .expect("Reservation within the buffer should succeed");
+    assert_eq!(status, ReservationStatus::Backordered);
+
+    let result = service
+        .reserve_stock(tenant_id, warehouse_id, product_id, 5)
+        .await;
+    assert!(result.is_err(), "Buffer already holds 6 of 10 units");
+
+    let level = sqlx::query!(
+        "SELECT available_quantity, reserved_quantity, backordered_quantity FROM inventory_levels
+         WHERE tenant_id = $1 AND product_id = $2 AND warehouse_id = $3",
+        tenant_id,
+        product_id,
+        warehouse_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(level.available_quantity, 0);
+    assert_eq!(level.reserved_quantity, 100);
+    assert_eq!(level.backordered_quantity, 6);
+
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
+
+fn queued(
+    warehouse_id: uuid::Uuid,
+    product_id: uuid::Uuid,
+    quantity: i64,
+    priority: i32,
+) -> NewStockReservation {
+    NewStockReservation {
+        warehouse_id,
+        product_id,
+        quantity,
+        priority,
+        source_type: Some("sales_order".to_string()),
+        source_id: Some(uuid::Uuid::now_v7()),
+    }
+}
+
+#[tokio::test]
+async fn test_allocate_scarce_fills_by_priority_then_creation_order() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let service = create_inventory_service(&pool);
+
+    // A quote queued first, then two paid orders competing for 8 units
+    let quote = service
+        .queue_reservation(tenant_id, queued(warehouse_id, product_id, 5, 0))
+        .await
+        .expect("Queueing should succeed");
+    assert_eq!(quote.status, StockReservationStatus::Pending);
+    let first_paid = service
+        .queue_reservation(tenant_id, queued(warehouse_id, product_id, 6, 10))
+        .await
+        .unwrap();
+    let second_paid = service
+        .queue_reservation(tenant_id, queued(warehouse_id, product_id, 4, 10))
+        .await
+        .unwrap();
+
+    let allocated = service
+        .allocate_scarce(tenant_id, product_id, 8)
+        .await
+        .expect("Allocation should succeed");
+    let outcome: Vec<_> = allocated
+        .iter()
+        .map(|r| (r.reservation_id, r.allocated_quantity, r.backordered_quantity, r.status))
+        .collect();
+    assert_eq!(
+        outcome,
+        vec![
+            (first_paid.reservation_id, 6, 0, StockReservationStatus::Reserved),
+            (second_paid.reservation_id, 2, 2, StockReservationStatus::Backordered),
+            (quote.reservation_id, 0, 5, StockReservationStatus::Backordered),
+        ]
+    );
+
+    // The allocation is persisted
+    let stored: Vec<(uuid::Uuid, i64, i64, String)> = sqlx::query_as(
+        "SELECT reservation_id, allocated_quantity, backordered_quantity, status
+         FROM stock_reservations WHERE tenant_id = $1 ORDER BY created_at",
+    )
+    .bind(tenant_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        stored,
+        vec![
+            (quote.reservation_id, 0, 5, "backordered".to_string()),
+            (first_paid.reservation_id, 6, 0, "reserved".to_string()),
+            (second_paid.reservation_id, 2, 2, "backordered".to_string()),
+        ]
+    );
+
+    // New stock tops up the backorders in the same order
+    let allocated = service
+        .allocate_scarce(tenant_id, product_id, 3)
+        .await
+        .unwrap();
+    let outcome: Vec<_> = allocated
+        .iter()
+        .map(|r| (r.reservation_id, r.allocated_quantity, r.status))
+        .collect();
+    assert_eq!(
+        outcome,
+        vec![
+            (second_paid.reservation_id, 4, StockReservationStatus::Reserved),
+            (quote.reservation_id, 1, StockReservationStatus::Backordered),
+        ]
+    );
+
+    let _ = sqlx::query("DELETE FROM stock_reservations WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(&pool)
+        .await;
+    cleanup_reorder_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,146 @@
+//! Tenant Export Integration Tests
+//!
+//! An export contains a line for every exported entity type and only the
+//! exported tenant's rows.
+
+mod business_logic_test_helpers;
+
+use std::collections::{HashMap, HashSet};
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, create_inventory_level, setup_test_pool,
+    setup_test_tenant_product_warehouse,
+};
+use inventory_service_core::dto::tenant_export::TenantExportEntity;
+use inventory_service_infra::services::write_tenant_export;
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Seed one row of every exported entity for a new tenant
+async fn setup_exported_tenant(pool: &PgPool) -> Uuid {
+    let (tenant_id, product_id, warehouse_id) = setup_test_tenant_product_warehouse(pool).await;
+    create_inventory_level(pool, tenant_id, product_id, warehouse_id, 25).await;
+
+    // path and level are filled in by the category path trigger
+    sqlx::query(
+        "INSERT INTO product_categories (category_id, tenant_id, name, path)
+         VALUES ($1, $2, 'Exported', '')",
+    )
+    .bind(Uuid::now_v7())
+    .bind(tenant_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert category");
+
+    sqlx::query(
+        "INSERT INTO stock_moves (
+            tenant_id, product_id, move_type, quantity, unit_cost,
+            reference_type, reference_id, idempotency_key
+        ) VALUES ($1, $2, 'receipt', 25, 400, 'grn', $3, $4)",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .bind(Uuid::now_v7())
+    .bind(format!("export-{}", Uuid::now_v7()))
+    .execute(pool)
+    .await
+    .expect("Failed to insert stock move");
+
+    let valuation_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO inventory_valuations (
+            tenant_id, product_id, valuation_method, current_unit_cost, total_quantity, total_value
+        ) VALUES ($1, $2, 'fifo', 400, 25, 10000)
+        RETURNING valuation_id",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to insert valuation");
+
+    sqlx::query(
+        "INSERT INTO inventory_valuation_layers (tenant_id, product_id, quantity, unit_cost, total_value)
+         VALUES ($1, $2, 25, 400, 10000)",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert valuation layer");
+
+    sqlx::query(
+        "INSERT INTO inventory_valuation_history (
+            valuation_id, tenant_id, product_id, valuation_method, unit_cost,
+            total_quantity, total_value, change_reason
+        ) VALUES ($1, $2, $3, 'fifo', 400, 25, 10000, 'stock_move')",
+    )
+    .bind(valuation_id)
+    .bind(tenant_id)
+    .bind(product_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert valuation history");
+
+    tenant_id
+}
+
+async fn cleanup_export_test_data(pool: &PgPool, tenant_id: Uuid) {
+    for table in [
+        "stock_moves",
+        "inventory_valuation_history",
+        "inventory_valuation_layers",
+        "inventory_valuations",
+        "product_categories",
+    ] {
+        let _ = sqlx::query(&format!("DELETE FROM {} WHERE tenant_id = $1", table))
+            .bind(tenant_id)
+            .execute(pool)
+            .await;
+    }
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_export_contains_every_entity_of_only_the_tenant() {
+    let pool = setup_test_pool().await;
+    let tenant_id = setup_exported_tenant(&pool).await;
+    let other_tenant_id = setup_exported_tenant(&pool).await;
+
+    let mut file = Vec::new();
+    let counts = write_tenant_export(&pool, tenant_id, &mut file)
+        .await
+        .expect("Export should succeed");
+
+    let lines: Vec<Value> = String::from_utf8(file)
+        .expect("Export should be UTF-8")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Each line should be a JSON object"))
+        .collect();
+
+    // Every line belongs to the exported tenant
+    assert!(lines
+        .iter()
+        .all(|line| line["data"]["tenant_id"] == Value::String(tenant_id.to_string())));
+    assert!(!lines
+        .iter()
+        .any(|line| line["data"]["tenant_id"] == Value::String(other_tenant_id.to_string())));
+
+    // One line per seeded row of each entity, matching the reported counts
+    let mut per_entity: HashMap<String, u64> = HashMap::new();
+    for line in &lines {
+        *per_entity
+            .entry(line["entity"].as_str().unwrap().to_string())
+            .or_default() += 1;
+    }
+    let exported: HashSet<_> = counts.iter().map(|count| count.entity).collect();
+    assert_eq!(exported, TenantExportEntity::ALL.into_iter().collect());
+    for count in &counts {
+        assert_eq!(count.rows, 1, "{:?} should have one row", count.entity);
+        assert_eq!(per_entity.get(count.entity.as_str()), Some(&1));
+    }
+    assert_eq!(lines.len(), TenantExportEntity::ALL.len());
+
+    cleanup_export_test_data(&pool, tenant_id).await;
+    cleanup_export_test_data(&pool, other_tenant_id).await;
+}
@@ -0,0 +1,193 @@
+//! Tenant Isolation Integration Tests
+//!
+//! Verifies the row-level security policies: a transaction scoped to tenant A
+//! cannot read or write tenant B's rows, even with no tenant filter in the query,
+//! and the product repository's own queries run under that scope.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_reorder_test_data, setup_test_pool, setup_test_tenant_and_product,
+};
+use inventory_service_core::repositories::product::ProductRepository;
+use inventory_service_infra::repositories::ProductRepositoryImpl;
+use shared_db::{begin_tenant_tx, TenantScopedPool};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::{Executor, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+/// Non-superuser role used to run scoped queries
+///
+/// Superusers always bypass row-level security, and test databases usually
+/// connect as one, so the assertions run under this role instead.
+const PROBE_ROLE: &str = "anthill_rls_probe";
+
+async fn ensure_probe_role(pool: &PgPool) {
+    sqlx::query(&format!(
+        "DO $$ BEGIN
+             CREATE ROLE {PROBE_ROLE} NOLOGIN;
+         EXCEPTION WHEN duplicate_object OR unique_violation THEN NULL;
+         END $$"
+    ))
+    .execute(pool)
+    .await
+    .expect("Failed to create probe role");
+
+    sqlx::query(&format!("GRANT SELECT, INSERT ON products TO {PROBE_ROLE}"))
+        .execute(pool)
+        .await
+        .expect("Failed to grant probe role");
+    sqlx::query(&format!("GRANT SELECT ON product_variants TO {PROBE_ROLE}"))
+        .execute(pool)
+        .await
+        .expect("Failed to grant probe role");
+}
+
+/// Pool on the same database whose connections all run as the probe role
+///
+/// Repositories take a plain pool, so the role switch has to happen per connection.
+async fn probe_pool(pool: &PgPool) -> PgPool {
+    PgPoolOptions::new()
+        .max_connections(2)
+        .after_connect(|conn, _meta| {
+            Box::pin(async move {
+                conn.execute(format!("SET ROLE {PROBE_ROLE}").as_str())
+                    .await?;
+                Ok(())
+            })
+        })
+        .connect_with((*pool.connect_options()).clone())
+        .await
+        .expect("Failed to connect probe pool")
+}
+
+/// Begin a tenant-scoped transaction running as the probe role
+async fn begin_probe_tx(pool: &PgPool, tenant_id: Uuid) -> Transaction<'static, Postgres> {
+    let mut tx = begin_tenant_tx(pool, tenant_id)
+        .await
+        .expect("Failed to begin tenant transaction");
+    sqlx::query(&format!("SET LOCAL ROLE {PROBE_ROLE}"))
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to switch role");
+    tx
+}
+
+#[tokio::test]
+async fn test_scoped_query_cannot_read_other_tenant_rows() {
+    let pool = setup_test_pool().await;
+    ensure_probe_role(&pool).await;
+    let (tenant_a, product_a) = setup_test_tenant_and_product(&pool).await;
+    let (tenant_b, product_b) = setup_test_tenant_and_product(&pool).await;
+
+    let mut tx = begin_probe_tx(&pool, tenant_a).await;
+
+    // Deliberately no tenant filter: RLS must hide tenant B's row
+    let visible: Vec<Uuid> = sqlx::query_scalar("SELECT product_id FROM products")
+        .fetch_all(&mut *tx)
+        .await
+        .unwrap();
+    assert_eq!(visible, vec![product_a]);
+
+    let leaked: Option<Uuid> =
+        sqlx::query_scalar("SELECT product_id FROM products WHERE product_id = $1")
+            .bind(product_b)
+            .fetch_optional(&mut *tx)
+            .await
+            .unwrap();
+    assert_eq!(leaked, None);
+
+    tx.rollback().await.unwrap();
+
+    cleanup_reorder_test_data(&pool, tenant_a).await;
+    cleanup_reorder_test_data(&pool, tenant_b).await;
+}
+
+#[tokio::test]
+async fn test_scoped_insert_rejected_for_other_tenant() {
+    let pool = setup_test_pool().await;
+    ensure_probe_role(&pool).await;
+    let (tenant_a, _product_a) = setup_test_tenant_and_product(&pool).await;
+    let (tenant_b, _product_b) = setup_test_tenant_and_product(&pool).await;
+
+    let mut tx = begin_probe_tx(&pool, tenant_a).await;
+    let result = sqlx::query(
+        "INSERT INTO products (product_id, tenant_id, sku, name, created_at)
+         VALUES ($1, $2, $3, 'Cross-tenant', NOW())",
+    )
+    .bind(Uuid::now_v7())
+    .bind(tenant_b)
+    .bind(format!("RLS-{}", Uuid::now_v7()))
+    .execute(&mut *tx)
+    .await;
+    assert!(result.is_err(), "Insert into another tenant must violate the policy");
+    tx.rollback().await.unwrap();
+
+    cleanup_reorder_test_data(&pool, tenant_a).await;
+    cleanup_reorder_test_data(&pool, tenant_b).await;
+}
+
+#[tokio::test]
+async fn test_scope_ends_with_transaction() {
+    let pool = setup_test_pool().await;
+    let (tenant_a, product_a) = setup_test_tenant_and_product(&pool).await;
+    let (tenant_b, product_b) = setup_test_tenant_and_product(&pool).await;
+
+    let scoped = TenantScopedPool::new(pool.clone());
+    let tx = scoped.begin(tenant_a).await.unwrap();
+    tx.commit().await.unwrap();
+
+    // Unscoped connections keep seeing every tenant (application filters apply)
+    let visible: Vec<Uuid> =
+        sqlx::query_scalar("SELECT product_id FROM products WHERE product_id = ANY($1)")
+            .bind(vec![product_a, product_b])
+            .fetch_all(scoped.inner())
+            .await
+            .unwrap();
+    assert_eq!(visible.len(), 2);
+
+    cleanup_reorder_test_data(&pool, tenant_a).await;
+    cleanup_reorder_test_data(&pool, tenant_b).await;
+}
+
+#[tokio::test]
+async fn test_repository_queries_cannot_read_other_tenant_rows() {
+    let pool = setup_test_pool().await;
+    ensure_probe_role(&pool).await;
+    let (tenant_a, product_a) = setup_test_tenant_and_product(&pool).await;
+    let (tenant_b, product_b) = setup_test_tenant_and_product(&pool).await;
+
+    // A tenant A variant pointing at tenant B's product. The barcode lookup
+    // joins products without filtering on their tenant, so only the tenant
+    // scope keeps tenant B's product out of the result.
+    let barcode = format!("RLS-{}", Uuid::now_v7());
+    sqlx::query(
+        "INSERT INTO product_variants (tenant_id, parent_product_id, sku, barcode)
+         VALUES ($1, $2, $3, $4)",
+    )
+    .bind(tenant_a)
+    .bind(product_b)
+    .bind(&barcode)
+    .bind(&barcode)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let probe = probe_pool(&pool).await;
+    let repo = ProductRepositoryImpl::new(probe.clone());
+
+    let own = repo.find_by_id(tenant_a, product_a).await.unwrap();
+    assert_eq!(own.map(|p| p.product_id), Some(product_a));
+
+    let leaked = repo.find_by_barcode(tenant_a, &barcode).await.unwrap();
+    assert_eq!(leaked.map(|p| p.product_id), None);
+
+    probe.close().await;
+    sqlx::query("DELETE FROM product_variants WHERE tenant_id = $1")
+        .bind(tenant_a)
+        .execute(&pool)
+        .await
+        .unwrap();
+    cleanup_reorder_test_data(&pool, tenant_a).await;
+    cleanup_reorder_test_data(&pool, tenant_b).await;
+}
@@ -0,0 +1,166 @@
+//! Tenant Provisioning Integration Tests
+//!
+//! Seeding a fresh tenant creates exactly the configured default entities,
+//! and seeding it again leaves them untouched.
+
+mod business_logic_test_helpers;
+
+use std::sync::Arc;
+
+use business_logic_test_helpers::{cleanup_reorder_test_data, setup_test_pool};
+use inventory_service_core::dto::tenant_provisioning::TenantSeedConfig;
+use inventory_service_core::services::tenant_provisioning::TenantProvisioningService;
+use inventory_service_infra::services::PgTenantProvisioningService;
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Create a tenant with no inventory data and one user
+async fn setup_fresh_tenant(pool: &PgPool) -> (Uuid, Uuid) {
+    let tenant_id = Uuid::now_v7();
+    let user_id = Uuid::now_v7();
+
+    sqlx::query(
+        "INSERT INTO tenants (tenant_id, name, slug, created_at) VALUES ($1, $2, $3, NOW())",
+    )
+    .bind(tenant_id)
+    .bind("Fresh Tenant")
+    .bind(format!("fresh-{}", tenant_id))
+    .execute(pool)
+    .await
+    .expect("Failed to insert tenant");
+
+    sqlx::query(
+        "INSERT INTO users (user_id, tenant_id, email, created_at) VALUES ($1, $2, $3, NOW())",
+    )
+    .bind(user_id)
+    .bind(tenant_id)
+    .bind(format!("admin-{}@example.com", tenant_id))
+    .execute(pool)
+    .await
+    .expect("Failed to insert user");
+
+    (tenant_id, user_id)
+}
+
+async fn count(pool: &PgPool, table: &str, tenant_id: Uuid) -> i64 {
+    sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM {} WHERE tenant_id = $1 AND deleted_at IS NULL",
+        table
+    ))
+    .bind(tenant_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn cleanup_provisioning_test_data(pool: &PgPool, tenant_id: Uuid) {
+    for table in ["removal_strategies", "product_categories", "users"] {
+        let _ = sqlx::query(&format!("DELETE FROM {} WHERE tenant_id = $1", table))
+            .bind(tenant_id)
+            .execute(pool)
+            .await;
+    }
+    cleanup_reorder_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_seed_fresh_tenant_is_idempotent() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, user_id) = setup_fresh_tenant(&pool).await;
+    let service =
+        PgTenantProvisioningService::new(Arc::new(pool.clone()), TenantSeedConfig::default());
+
+    let seeded = service
+        .seed_defaults(tenant_id, user_id)
+        .await
+        .expect("Seeding should succeed");
+    let warehouse = seeded.warehouse.expect("Warehouse should be seeded");
+    let category = seeded.category.expect("Category should be seeded");
+    let strategy = seeded
+        .removal_strategy
+        .expect("Removal strategy should be seeded");
+    assert!(warehouse.created && category.created && strategy.created);
+
+    // Exactly the default entities exist
+    let warehouses: Vec<(Uuid, String, String)> = sqlx::query_as(
+        "SELECT warehouse_id, warehouse_code, warehouse_name FROM warehouses WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        warehouses,
+        vec![(warehouse.id, "MAIN".to_string(), "Main Warehouse".to_string())]
+    );
+
+    let categories: Vec<(Uuid, String, Option<Uuid>)> = sqlx::query_as(
+        "SELECT category_id, name, parent_category_id FROM product_categories WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(categories, vec![(category.id, "Uncategorized".to_string(), None)]);
+
+    let strategies: Vec<(Uuid, String, String, Option<Uuid>, Option<Uuid>)> = sqlx::query_as(
+        "SELECT strategy_id, name, strategy_type, warehouse_id, product_id
+         FROM removal_strategies WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        strategies,
+        vec![(strategy.id, "Default".to_string(), "fifo".to_string(), None, None)]
+    );
+
+    // Re-seeding finds the same entities and creates nothing
+    let reseeded = service.seed_defaults(tenant_id, user_id).await.unwrap();
+    assert_eq!(reseeded.warehouse.map(|e| (e.id, e.created)), Some((warehouse.id, false)));
+    assert_eq!(reseeded.category.map(|e| (e.id, e.created)), Some((category.id, false)));
+    assert_eq!(reseeded.removal_strategy.map(|e| (e.id, e.created)), Some((strategy.id, false)));
+    assert_eq!(count(&pool, "warehouses", tenant_id).await, 1);
+    assert_eq!(count(&pool, "product_categories", tenant_id).await, 1);
+    assert_eq!(count(&pool, "removal_strategies", tenant_id).await, 1);
+
+    cleanup_provisioning_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_seed_follows_configured_seed_set() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, user_id) = setup_fresh_tenant(&pool).await;
+    let config = TenantSeedConfig::new("HQ", "Head Office", "", "lifo").unwrap();
+    let service = PgTenantProvisioningService::new(Arc::new(pool.clone()), config);
+
+    let seeded = service.seed_defaults(tenant_id, user_id).await.unwrap();
+    assert!(seeded.warehouse.is_some());
+    assert!(seeded.category.is_none());
+    assert!(seeded.removal_strategy.is_some());
+
+    let code: String =
+        sqlx::query_scalar("SELECT warehouse_code FROM warehouses WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(code, "HQ");
+    assert_eq!(count(&pool, "product_categories", tenant_id).await, 0);
+
+    let strategy_type: String =
+        sqlx::query_scalar("SELECT strategy_type FROM removal_strategies WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(strategy_type, "lifo");
+
+    // Unknown tenants are rejected
+    let result = service.seed_defaults(Uuid::now_v7(), user_id).await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_provisioning_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,409 @@
+//! Transfer Compensation Integration Tests
+//!
+//! Injects a failure into the destination step of a transfer receipt and
+//! verifies the compensation returns the shipped stock to the source, while a
+//! transient failure leaves the transfer shipped for a retry.
+
+mod business_logic_test_helpers;
+
+use async_trait::async_trait;
+use business_logic_test_helpers::{
+    cleanup_transfer_test_data, create_inventory_level, create_uom, create_user, create_warehouse,
+    setup_test_pool, setup_test_tenant_product_warehouse,
+};
+use chrono::{DateTime, Utc};
+use inventory_service_core::domains::inventory::dto::transfer_dto::{
+    ConfirmTransferRequest, CreateTransferItemRequest, CreateTransferRequest,
+    ReceiveTransferRequest,
+};
+use inventory_service_core::domains::inventory::transfer::{
+    TransferPriority, TransferStatus, TransferType,
+};
+use inventory_service_core::models::{InventoryLevel, InventoryLevelHistoryEntry};
+use inventory_service_core::repositories::InventoryLevelRepository;
+use inventory_service_core::services::TransferService;
+use inventory_service_infra::repositories::{
+    PgInventoryLevelRepository, PgTransferItemRepository, PgTransferRepository,
+    WarehouseRepositoryImpl,
+};
+use inventory_service_infra::services::PgTransferService;
+use shared_error::AppError;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Inventory repository that fails every quantity update for one warehouse
+struct FailingWarehouseInventoryRepository {
+    inner: PgInventoryLevelRepository,
+    failing_warehouse_id: Uuid,
+    error: fn() -> AppError,
+}
+
+#[async_trait]
+impl InventoryLevelRepository for FailingWarehouseInventoryRepository {
+    async fn find_by_product(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<Option<InventoryLevel>, AppError> {
+        self.inner
+            .find_by_product(tenant_id, warehouse_id, product_id)
+            .await
+    }
+
+    async fn find_by_products(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, InventoryLevel>, AppError> {
+        self.inner
+            .find_by_products(tenant_id, warehouse_id, product_ids)
+            .await
+    }
+
+    async fn update_available_quantity(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        location_id: Option<Uuid>,
+        product_id: Uuid,
+        quantity_change: i64,
+    ) -> Result<(), AppError> {
+        if warehouse_id == self.failing_warehouse_id {
+            return Err((self.error)());
+        }
+        self.inner
+            .update_available_quantity(
+                tenant_id,
+                warehouse_id,
+                location_id,
+                product_id,
+                quantity_change,
+            )
+            .await
+    }
+
+    async fn update_available_quantity_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        location_id: Option<Uuid>,
+        product_id: Uuid,
+        quantity_change: i64,
+    ) -> Result<(), AppError> {
+        if warehouse_id == self.failing_warehouse_id {
+            return Err((self.error)());
+        }
+        self.inner
+            .update_available_quantity_in_tx(
+                tx,
+                tenant_id,
+                warehouse_id,
+                location_id,
+                product_id,
+                quantity_change,
+            )
+            .await
+    }
+
+    async fn upsert(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        product_id: Uuid,
+        available_quantity: i64,
+        reserved_quantity: i64,
+    ) -> Result<(), AppError> {
+        self.inner
+            .upsert(tenant_id, warehouse_id, product_id, available_quantity, reserved_quantity)
+            .await
+    }
+    async fn history(
+        &self,
+        tenant_id: Uuid,
+        location_id: Uuid,
+        product_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<InventoryLevelHistoryEntry>, AppError> {
+        self.inner
+            .history(tenant_id, location_id, product_id, from, to)
+            .await
+    }
+}
+
+fn create_transfer_service(
+    pool: &PgPool,
+    inventory_repo: Arc<dyn InventoryLevelRepository>,
+) -> PgTransferService {
+    let pool_arc = Arc::new(pool.clone());
+    PgTransferService::new(
+        pool_arc.clone(),
+        Arc::new(PgTransferRepository::new(pool_arc.clone())),
+        Arc::new(PgTransferItemRepository::new(pool_arc.clone())),
+        inventory_repo,
+        Arc::new(WarehouseRepositoryImpl::new(pool.clone())),
+    )
+}
+
+async fn available_quantity(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    warehouse_id: Uuid,
+    product_id: Uuid,
+) -> i64 {
+    sqlx::query_scalar::<_, Option<i64>>(
+        "SELECT SUM(available_quantity)::BIGINT FROM inventory_levels
+         WHERE tenant_id = $1 AND warehouse_id = $2 AND product_id = $3 AND deleted_at IS NULL",
+    )
+    .bind(tenant_id)
+    .bind(warehouse_id)
+    .bind(product_id)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to read inventory level")
+    .unwrap_or(0)
+}
+
+/// Create a transfer of 30 units and ship it
+async fn create_shipped_transfer(
+    service: &PgTransferService,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    source_id: Uuid,
+    destination_id: Uuid,
+    product_id: Uuid,
+    uom_id: Uuid,
+) -> Uuid {
+    let created = service
+        .create_transfer(
+            tenant_id,
+            user_id,
+            CreateTransferRequest {
+                reference_number: None,
+                source_warehouse_id: source_id,
+                destination_warehouse_id: destination_id,
+                transfer_type: TransferType::Manual,
+                priority: TransferPriority::Normal,
+                expected_ship_date: None,
+                expected_receive_date: None,
+                shipping_method: None,
+                notes: None,
+                reason: None,
+                items: vec![CreateTransferItemRequest {
+                    product_id,
+                    quantity: 30,
+                    uom_id: Some(uom_id),
+                    unit_cost: Some(1_000),
+                    line_number: 1,
+                    source_zone_id: None,
+                    source_location_id: None,
+                    destination_zone_id: None,
+                    destination_location_id: None,
+                    notes: None,
+                }],
+            },
+        )
+        .await
+        .expect("Transfer creation should succeed");
+    service
+        .confirm_transfer(
+            tenant_id,
+            created.transfer_id,
+            user_id,
+            ConfirmTransferRequest { notes: None },
+        )
+        .await
+        .expect("Shipping should succeed");
+    created.transfer_id
+}
+
+#[tokio::test]
+async fn test_failed_destination_step_restores_source_stock() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, source_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let destination_id = create_warehouse(&pool, tenant_id, "Destination Warehouse").await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let uom_id = create_uom(&pool, tenant_id).await;
+    create_inventory_level(&pool, tenant_id, product_id, source_id, 100).await;
+
+    let service = create_transfer_service(
+        &pool,
+        Arc::new(FailingWarehouseInventoryRepository {
+            inner: PgInventoryLevelRepository::new(Arc::new(pool.clone())),
+            failing_warehouse_id: destination_id,
+            error: || AppError::BusinessError("Injected destination failure".to_string()),
+        }),
+    );
+
+    let transfer_id = create_shipped_transfer(
+        &service,
+        tenant_id,
+        user_id,
+        source_id,
+        destination_id,
+        product_id,
+        uom_id,
+    )
+    .await;
+    assert_eq!(available_quantity(&pool, tenant_id, source_id, product_id).await, 70);
+
+    let result = service
+        .receive_transfer(tenant_id, transfer_id, user_id, ReceiveTransferRequest { notes: None })
+        .await;
+    assert!(result.is_err(), "Injected destination failure must surface");
+
+    // Shipped stock is back at the source and never reached the destination
+    assert_eq!(available_quantity(&pool, tenant_id, source_id, product_id).await, 100);
+    assert_eq!(available_quantity(&pool, tenant_id, destination_id, product_id).await, 0);
+
+    let compensating_moves: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM stock_moves
+         WHERE tenant_id = $1 AND reference_id = $2 AND idempotency_key LIKE 'transfer-compensate-%'",
+    )
+    .bind(tenant_id)
+    .bind(transfer_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(compensating_moves, 1);
+
+    // The failed receipt rolled back as a whole, leaving no receipt moves
+    let receipt_moves: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM stock_moves
+         WHERE tenant_id = $1 AND reference_id = $2 AND idempotency_key LIKE 'transfer-receive-%'",
+    )
+    .bind(tenant_id)
+    .bind(transfer_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(receipt_moves, 0);
+
+    let transfer = service
+        .get_transfer(tenant_id, transfer_id)
+        .await
+        .expect("Transfer lookup should succeed");
+    assert_eq!(transfer.transfer.status, TransferStatus::Cancelled);
+    assert_eq!(
+        transfer.transfer.reason.as_deref(),
+        Some("Receipt failed, stock returned to source")
+    );
+
+    cleanup_transfer_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_transient_destination_failure_keeps_transfer_shipped() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, source_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let destination_id = create_warehouse(&pool, tenant_id, "Destination Warehouse").await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let uom_id = create_uom(&pool, tenant_id).await;
+    create_inventory_level(&pool, tenant_id, product_id, source_id, 100).await;
+
+    let service = create_transfer_service(
+        &pool,
+        Arc::new(FailingWarehouseInventoryRepository {
+            inner: PgInventoryLevelRepository::new(Arc::new(pool.clone())),
+            failing_warehouse_id: destination_id,
+            error: || AppError::Database(sqlx::Error::PoolTimedOut),
+        }),
+    );
+
+    let transfer_id = create_shipped_transfer(
+        &service,
+        tenant_id,
+        user_id,
+        source_id,
+        destination_id,
+        product_id,
+        uom_id,
+    )
+    .await;
+
+    let result = service
+        .receive_transfer(tenant_id, transfer_id, user_id, ReceiveTransferRequest { notes: None })
+        .await;
+    assert!(
+        matches!(result, Err(ref e) if e.is_retryable()),
+        "unexpected result: {:?}",
+        result
+    );
+
+    // Nothing was compensated: the stock stays in transit for a retry
+    assert_eq!(available_quantity(&pool, tenant_id, source_id, product_id).await, 70);
+    assert_eq!(available_quantity(&pool, tenant_id, destination_id, product_id).await, 0);
+    let transfer = service
+        .get_transfer(tenant_id, transfer_id)
+        .await
+        .expect("Transfer lookup should succeed");
+    assert_eq!(transfer.transfer.status, TransferStatus::Shipped);
+
+    // Receiving again with a healthy repository completes the transfer
+    let healthy = create_transfer_service(
+        &pool,
+        Arc::new(PgInventoryLevelRepository::new(Arc::new(pool.clone()))),
+    );
+    healthy
+        .receive_transfer(tenant_id, transfer_id, user_id, ReceiveTransferRequest { notes: None })
+        .await
+        .expect("Retried receipt should succeed");
+    assert_eq!(available_quantity(&pool, tenant_id, destination_id, product_id).await, 30);
+
+    cleanup_transfer_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_receiving_twice_credits_destination_once() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id, source_id) = setup_test_tenant_product_warehouse(&pool).await;
+    let destination_id = create_warehouse(&pool, tenant_id, "Destination Warehouse").await;
+    let user_id = create_user(&pool, tenant_id).await;
+    let uom_id = create_uom(&pool, tenant_id).await;
+    create_inventory_level(&pool, tenant_id, product_id, source_id, 100).await;
+
+    let service = create_transfer_service(
+        &pool,
+        Arc::new(PgInventoryLevelRepository::new(Arc::new(pool.clone()))),
+    );
+    let transfer_id = create_shipped_transfer(
+        &service,
+        tenant_id,
+        user_id,
+        source_id,
+        destination_id,
+        product_id,
+        uom_id,
+    )
+    .await;
+
+    let first = service
+        .receive_transfer(tenant_id, transfer_id, user_id, ReceiveTransferRequest { notes: None })
+        .await
+        .expect("First receipt should succeed");
+    assert_eq!(first.stock_moves_created, 1);
+
+    let second = service
+        .receive_transfer(tenant_id, transfer_id, user_id, ReceiveTransferRequest { notes: None })
+        .await
+        .expect("Replayed receipt should succeed");
+    assert_eq!(second.status, TransferStatus::Received);
+    assert_eq!(second.stock_moves_created, 0);
+
+    assert_eq!(available_quantity(&pool, tenant_id, source_id, product_id).await, 70);
+    assert_eq!(available_quantity(&pool, tenant_id, destination_id, product_id).await, 30);
+
+    // Confirming a shipped transfer again must not debit the source twice
+    let reconfirm = service
+        .confirm_transfer(tenant_id, transfer_id, user_id, ConfirmTransferRequest { notes: None })
+        .await;
+    assert!(reconfirm.is_err());
+    assert_eq!(available_quantity(&pool, tenant_id, source_id, product_id).await, 70);
+
+    cleanup_transfer_test_data(&pool, tenant_id).await;
+}
@@ -0,0 +1,196 @@
+//! Transfer Tracking Integration Tests
+//!
+//! Covers setting ETA/carrier/tracking on a shipped transfer and the
+//! ordering of the in-transit listing.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_transfer_test_data, create_transfer_service, setup_test_pool, setup_transfer_fixture,
+    TransferFixture,
+};
+use chrono::{Duration, Utc};
+use inventory_service_core::domains::inventory::dto::transfer_dto::{
+    ConfirmTransferRequest, CreateTransferItemRequest, CreateTransferRequest,
+    ListInTransitTransfersParams, UpdateTransferTrackingRequest,
+};
+use inventory_service_core::domains::inventory::transfer::{TransferPriority, TransferType};
+use inventory_service_core::services::TransferService;
+use inventory_service_infra::services::PgTransferService;
+use shared_error::AppError;
+use uuid::Uuid;
+
+/// Create a draft transfer of 5 units, optionally confirming (shipping) it
+async fn create_transfer(service: &PgTransferService, fx: &TransferFixture, ship: bool) -> Uuid {
+    let created = service
+        .create_transfer(
+            fx.tenant_id,
+            fx.user_id,
+            CreateTransferRequest {
+                reference_number: None,
+                source_warehouse_id: fx.source_id,
+                destination_warehouse_id: fx.destination_id,
+                transfer_type: TransferType::Manual,
+                priority: TransferPriority::Normal,
+                expected_ship_date: None,
+                expected_receive_date: None,
+                shipping_method: None,
+                notes: None,
+                reason: None,
+                items: vec![CreateTransferItemRequest {
+                    product_id: fx.product_id,
+                    quantity: 5,
+                    uom_id: Some(fx.uom_id),
+                    unit_cost: Some(1_000),
+                    line_number: 1,
+                    source_zone_id: None,
+                    source_location_id: None,
+                    destination_zone_id: None,
+                    destination_location_id: None,
+                    notes: None,
+                }],
+            },
+        )
+        .await
+        .expect("Transfer creation should succeed");
+
+    if ship {
+        service
+            .confirm_transfer(
+                fx.tenant_id,
+                created.transfer_id,
+                fx.user_id,
+                ConfirmTransferRequest { notes: None },
+            )
+            .await
+            .expect("Shipping should succeed");
+    }
+
+    created.transfer_id
+}
+
+#[tokio::test]
+async fn test_set_tracking_info_on_shipped_transfer() {
+    let pool = setup_test_pool().await;
+    let fx = setup_transfer_fixture(&pool).await;
+    let service = create_transfer_service(&pool);
+    let transfer_id = create_transfer(&service, &fx, true).await;
+
+    let arrival = Utc::now() + Duration::days(3);
+    let updated = service
+        .update_tracking(
+            fx.tenant_id,
+            transfer_id,
+            fx.user_id,
+            UpdateTransferTrackingRequest {
+                expected_arrival: Some(arrival.to_rfc3339()),
+                carrier: Some("  Internal fleet ".to_string()),
+                tracking_number: Some("TRK-00042".to_string()),
+            },
+        )
+        .await
+        .expect("Tracking update should succeed");
+
+    assert_eq!(updated.transfer.carrier.as_deref(), Some("Internal fleet"));
+    assert_eq!(updated.transfer.tracking_number.as_deref(), Some("TRK-00042"));
+    let stored_arrival = updated
+        .transfer
+        .expected_arrival
+        .expect("expected_arrival should be set");
+    assert_eq!(stored_arrival.timestamp(), arrival.timestamp());
+
+    // A partial update keeps the fields that were not sent
+    let updated = service
+        .update_tracking(
+            fx.tenant_id,
+            transfer_id,
+            fx.user_id,
+            UpdateTransferTrackingRequest {
+                tracking_number: Some("TRK-00043".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("Partial tracking update should succeed");
+    assert_eq!(updated.transfer.carrier.as_deref(), Some("Internal fleet"));
+    assert_eq!(updated.transfer.tracking_number.as_deref(), Some("TRK-00043"));
+    assert_eq!(
+        updated.transfer.expected_arrival.map(|a| a.timestamp()),
+        Some(arrival.timestamp())
+    );
+
+    cleanup_transfer_test_data(&pool, fx.tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_expected_arrival_before_ship_date_rejected() {
+    let pool = setup_test_pool().await;
+    let fx = setup_transfer_fixture(&pool).await;
+    let service = create_transfer_service(&pool);
+    let transfer_id = create_transfer(&service, &fx, true).await;
+
+    let result = service
+        .update_tracking(
+            fx.tenant_id,
+            transfer_id,
+            fx.user_id,
+            UpdateTransferTrackingRequest {
+                expected_arrival: Some((Utc::now() - Duration::days(1)).to_rfc3339()),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+    let transfer = service
+        .get_transfer(fx.tenant_id, transfer_id)
+        .await
+        .unwrap();
+    assert!(transfer.transfer.expected_arrival.is_none());
+
+    cleanup_transfer_test_data(&pool, fx.tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_in_transit_listing_orders_by_expected_arrival() {
+    let pool = setup_test_pool().await;
+    let fx = setup_transfer_fixture(&pool).await;
+    let service = create_transfer_service(&pool);
+
+    let late = create_transfer(&service, &fx, true).await;
+    let no_eta = create_transfer(&service, &fx, true).await;
+    let soon = create_transfer(&service, &fx, true).await;
+    let draft = create_transfer(&service, &fx, false).await;
+
+    for (transfer_id, days) in [(late, 5), (soon, 1)] {
+        service
+            .update_tracking(
+                fx.tenant_id,
+                transfer_id,
+                fx.user_id,
+                UpdateTransferTrackingRequest {
+                    expected_arrival: Some((Utc::now() + Duration::days(days)).to_rfc3339()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("Tracking update should succeed");
+    }
+
+    let listed = service
+        .list_in_transit(
+            fx.tenant_id,
+            ListInTransitTransfersParams {
+                destination_warehouse_id: Some(fx.destination_id),
+                limit: None,
+            },
+        )
+        .await
+        .expect("In-transit listing should succeed");
+
+    let ids: Vec<Uuid> = listed.items.iter().map(|t| t.transfer_id).collect();
+    assert_eq!(ids, vec![soon, late, no_eta], "Soonest first, unknown ETA last");
+    assert!(!ids.contains(&draft), "Draft transfers are not in transit");
+
+    cleanup_transfer_test_data(&pool, fx.tenant_id).await;
+}
@@ -0,0 +1,136 @@
+//! Upload Concurrency Limit Tests
+//!
+//! Uploads beyond the per-process limit queue for a slot instead of running
+//! at once, and are rejected with 503 when no slot frees up in time.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+    routing::post,
+    Router,
+};
+use inventory_service_api::middleware::{concurrency_limit_middleware, ConcurrencyLimit};
+use shared_error::SERVICE_UNAVAILABLE_RETRY_AFTER_SECS;
+use tokio::sync::Notify;
+use tower::ServiceExt;
+
+/// Tracks how many upload handlers are executing at once
+#[derive(Default)]
+struct Probe {
+    running: AtomicUsize,
+    peak: AtomicUsize,
+    executed: AtomicUsize,
+}
+
+/// An upload route whose handler holds its slot until `release` is notified
+fn upload_app(limit: ConcurrencyLimit, probe: Arc<Probe>, release: Arc<Notify>) -> Router {
+    let handler = move || {
+        let probe = probe.clone();
+        let release = release.clone();
+        async move {
+            let running = probe.running.fetch_add(1, Ordering::SeqCst) + 1;
+            probe.peak.fetch_max(running, Ordering::SeqCst);
+            probe.executed.fetch_add(1, Ordering::SeqCst);
+            release.notified().await;
+            probe.running.fetch_sub(1, Ordering::SeqCst);
+            StatusCode::CREATED
+        }
+    };
+
+    Router::new().route(
+        "/images",
+        post(handler)
+            .layer(axum::middleware::from_fn_with_state(limit, concurrency_limit_middleware)),
+    )
+}
+
+fn spawn_uploads(
+    app: &Router,
+    count: usize,
+) -> Vec<tokio::task::JoinHandle<(StatusCode, Option<String>)>> {
+    (0..count)
+        .map(|_| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                let request = Request::builder()
+                    .method(Method::POST)
+                    .uri("/images")
+                    .body(Body::from(vec![0u8; 1024]))
+                    .unwrap();
+                let response = app.oneshot(request).await.unwrap();
+                let retry_after = response
+                    .headers()
+                    .get(header::RETRY_AFTER)
+                    .map(|value| value.to_str().unwrap().to_string());
+                (response.status(), retry_after)
+            })
+        })
+        .collect()
+}
+
+async fn wait_until(condition: impl Fn() -> bool) {
+    while !condition() {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_excess_uploads_serialize_behind_the_limit() {
+    let probe = Arc::new(Probe::default());
+    let release = Arc::new(Notify::new());
+    let limit = ConcurrencyLimit::new("uploads", 2, Duration::from_secs(10));
+    let app = upload_app(limit.clone(), probe.clone(), release.clone());
+
+    let uploads = spawn_uploads(&app, 6);
+
+    // Two uploads run; the other four wait in the queue rather than starting
+    wait_until(|| probe.running.load(Ordering::SeqCst) == 2 && limit.queue_depth() == 4).await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(probe.executed.load(Ordering::SeqCst), 2);
+
+    let mut results = Vec::new();
+    for upload in uploads {
+        while !upload.is_finished() {
+            release.notify_waiters();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        results.push(upload.await.unwrap());
+    }
+
+    // Every upload completes, but never more than two at a time
+    assert!(results
+        .iter()
+        .all(|(status, _)| *status == StatusCode::CREATED));
+    assert_eq!(probe.executed.load(Ordering::SeqCst), 6);
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+    assert_eq!(limit.queue_depth(), 0);
+}
+
+#[tokio::test]
+async fn test_upload_rejected_when_no_slot_frees_in_time() {
+    let probe = Arc::new(Probe::default());
+    let release = Arc::new(Notify::new());
+    let limit = ConcurrencyLimit::new("uploads", 1, Duration::from_millis(100));
+    let app = upload_app(limit.clone(), probe.clone(), release.clone());
+
+    let mut uploads = spawn_uploads(&app, 1);
+    wait_until(|| probe.running.load(Ordering::SeqCst) == 1).await;
+
+    // The slot stays taken past the queue timeout
+    let (status, retry_after) = spawn_uploads(&app, 1).pop().unwrap().await.unwrap();
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(retry_after, Some(SERVICE_UNAVAILABLE_RETRY_AFTER_SECS.to_string()));
+    assert_eq!(limit.queue_depth(), 0);
+
+    let first = uploads.pop().unwrap();
+    while !first.is_finished() {
+        release.notify_waiters();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(first.await.unwrap().0, StatusCode::CREATED);
+    assert_eq!(probe.executed.load(Ordering::SeqCst), 1);
+}
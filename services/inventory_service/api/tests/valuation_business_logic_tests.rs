@@ -1,12 +1,12 @@
 //! Advanced Business Logic Tests for Valuation
 //!
-//! Integration tests for FIFO, AVCO, and Standard costing methods.
+//! Integration tests for FIFO, LIFO, AVCO, and Standard costing methods.
 //! Covers cost layer management, average cost recalculation, and edge cases.
 
 mod business_logic_test_helpers;
 
 use business_logic_test_helpers::{
-    cleanup_valuation_test_data, create_valuation_service, setup_test_pool,
+    cleanup_valuation_test_data, create_valuation_service, method_request, setup_test_pool,
     setup_test_tenant_and_product,
 };
 use inventory_service_core::domains::inventory::valuation::ValuationMethod;
@@ -255,6 +255,261 @@ mod fifo_valuation_tests {
 
         cleanup_valuation_test_data(&pool, tenant_id).await;
     }
+
+    #[tokio::test]
+    async fn test_fifo_same_timestamp_layers_consume_first_inserted() {
+        use inventory_service_core::repositories::valuation::ValuationLayerRepository;
+        use inventory_service_infra::repositories::ValuationRepositoryImpl;
+
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let repo = ValuationRepositoryImpl::new(pool.clone());
+
+        // Two layers sharing one timestamp, as when created in the same transaction
+        let created_at = chrono::Utc::now();
+        let first_layer_id = Uuid::now_v7();
+        let second_layer_id = Uuid::now_v7();
+        for (layer_id, unit_cost) in [(first_layer_id, 1000i64), (second_layer_id, 2000i64)] {
+            sqlx::query(
+                "INSERT INTO inventory_valuation_layers (
+                    layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
+                    created_at, updated_at
+                 ) VALUES ($1, $2, $3, 10, $4, $5, $6, $6)",
+            )
+            .bind(layer_id)
+            .bind(tenant_id)
+            .bind(product_id)
+            .bind(unit_cost)
+            .bind(unit_cost * 10)
+            .bind(created_at)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert valuation layer");
+        }
+
+        // Consuming 10 units must drain the first-inserted layer at $10.00
+        let cost = repo
+            .consume_layers(tenant_id, product_id, 10)
+            .await
+            .expect("Consumption should succeed");
+        assert_eq!(cost, 10_000);
+
+        let remaining = repo
+            .find_active_by_product_id(tenant_id, product_id)
+            .await
+            .unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].layer_id, second_layer_id);
+
+        let _ = sqlx::query("DELETE FROM inventory_valuation_layers WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .execute(&pool)
+            .await;
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_fifo_consume_layers_detailed_reports_each_layer() {
+        use inventory_service_core::domains::inventory::valuation::LayerConsumption;
+        use inventory_service_core::repositories::valuation::ValuationLayerRepository;
+        use inventory_service_infra::repositories::ValuationRepositoryImpl;
+
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let repo = ValuationRepositoryImpl::new(pool.clone());
+
+        // Three layers of 10 units, oldest first
+        let now = chrono::Utc::now();
+        let layers = [
+            (Uuid::now_v7(), 1000i64, now - chrono::Duration::minutes(30)),
+            (Uuid::now_v7(), 1200i64, now - chrono::Duration::minutes(20)),
+            (Uuid::now_v7(), 1500i64, now - chrono::Duration::minutes(10)),
+        ];
+        for (layer_id, unit_cost, created_at) in layers {
+            sqlx::query(
+                "INSERT INTO inventory_valuation_layers (
+                    layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
+                    created_at, updated_at
+                 ) VALUES ($1, $2, $3, 10, $4, $5, $6, $6)",
+            )
+            .bind(layer_id)
+            .bind(tenant_id)
+            .bind(product_id)
+            .bind(unit_cost)
+            .bind(unit_cost * 10)
+            .bind(created_at)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert valuation layer");
+        }
+
+        // 25 units drain the first two layers and take 5 from the third
+        let (cost, consumed) = repo
+            .consume_layers_detailed(tenant_id, product_id, 25)
+            .await
+            .expect("Consumption should succeed");
+        assert_eq!(cost, 10 * 1000 + 10 * 1200 + 5 * 1500);
+        assert_eq!(
+            consumed,
+            vec![
+                LayerConsumption {
+                    layer_id: layers[0].0,
+                    quantity_taken: 10,
+                    unit_cost: 1000,
+                },
+                LayerConsumption {
+                    layer_id: layers[1].0,
+                    quantity_taken: 10,
+                    unit_cost: 1200,
+                },
+                LayerConsumption {
+                    layer_id: layers[2].0,
+                    quantity_taken: 5,
+                    unit_cost: 1500,
+                },
+            ]
+        );
+
+        // The total-only variant reports the same cost for the next consumption
+        let cost = repo
+            .consume_layers(tenant_id, product_id, 5)
+            .await
+            .expect("Consumption should succeed");
+        assert_eq!(cost, 5 * 1500);
+
+        let _ = sqlx::query("DELETE FROM inventory_valuation_layers WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .execute(&pool)
+            .await;
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
+}
+
+// ============================================================================
+// LIFO Valuation Tests
+// ============================================================================
+
+#[cfg(test)]
+mod lifo_valuation_tests {
+    use super::*;
+    use inventory_service_core::domains::inventory::dto::valuation_dto::SetValuationMethodRequest;
+
+    #[tokio::test]
+    async fn test_lifo_delivery_consumes_newest_layer_first() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let service = create_valuation_service(&pool);
+
+        let set_method_request = SetValuationMethodRequest {
+            tenant_id,
+            product_id,
+            valuation_method: ValuationMethod::Lifo,
+        };
+        service
+            .set_valuation_method(set_method_request)
+            .await
+            .unwrap();
+
+        // The method round-trips through the database as "lifo"
+        let method = service
+            .get_valuation_method(tenant_id, product_id)
+            .await
+            .unwrap();
+        assert_eq!(method, ValuationMethod::Lifo);
+
+        // Receipt 1: 50 units at $10.00
+        service
+            .process_stock_movement(tenant_id, product_id, 50, Some(1000), None)
+            .await
+            .expect("First receipt should succeed");
+
+        // Receipt 2: 50 units at $20.00
+        service
+            .process_stock_movement(tenant_id, product_id, 50, Some(2000), None)
+            .await
+            .expect("Second receipt should succeed");
+
+        // Delivery of 60 units (should consume all of layer 2 and 10 from layer 1)
+        let after_delivery = service
+            .process_stock_movement(tenant_id, product_id, -60, None, None)
+            .await
+            .expect("Delivery should succeed");
+
+        // Remaining: 40 units at $10.00 each = 40000
+        assert_eq!(after_delivery.total_quantity, 40);
+        assert_eq!(after_delivery.total_value, 40_000);
+
+        let layers_request =
+            inventory_service_core::domains::inventory::dto::valuation_dto::GetValuationLayersRequest {
+                tenant_id,
+                product_id,
+            };
+        let layers = service.get_valuation_layers(layers_request).await.unwrap();
+        assert_eq!(layers.layers.len(), 1, "Should have 1 active layer remaining");
+        assert_eq!(layers.layers[0].quantity, 40);
+        assert_eq!(layers.layers[0].unit_cost, 1000);
+
+        // Over-issuing fails without touching the remaining layer
+        let result = service
+            .process_stock_movement(tenant_id, product_id, -41, None, None)
+            .await;
+        assert!(result.is_err(), "Delivery beyond the layers should fail");
+
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_lifo_same_timestamp_layers_consume_last_inserted() {
+        use inventory_service_core::repositories::valuation::ValuationLayerRepository;
+        use inventory_service_infra::repositories::ValuationRepositoryImpl;
+
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let repo = ValuationRepositoryImpl::new(pool.clone());
+
+        // Two layers sharing one timestamp, as when created in the same transaction
+        let created_at = chrono::Utc::now();
+        let first_layer_id = Uuid::now_v7();
+        let second_layer_id = Uuid::now_v7();
+        for (layer_id, unit_cost) in [(first_layer_id, 1000i64), (second_layer_id, 2000i64)] {
+            sqlx::query(
+                "INSERT INTO inventory_valuation_layers (
+                    layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
+                    created_at, updated_at
+                 ) VALUES ($1, $2, $3, 10, $4, $5, $6, $6)",
+            )
+            .bind(layer_id)
+            .bind(tenant_id)
+            .bind(product_id)
+            .bind(unit_cost)
+            .bind(unit_cost * 10)
+            .bind(created_at)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert valuation layer");
+        }
+
+        // Consuming 15 units drains the last-inserted layer, then 5 from the first
+        let cost = repo
+            .consume_layers_lifo(tenant_id, product_id, 15)
+            .await
+            .expect("Consumption should succeed");
+        assert_eq!(cost, 20_000 + 5_000);
+
+        let remaining = repo
+            .find_active_by_product_id(tenant_id, product_id)
+            .await
+            .unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].layer_id, first_layer_id);
+        assert_eq!(remaining[0].quantity, 5);
+
+        let _ = sqlx::query("DELETE FROM inventory_valuation_layers WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .execute(&pool)
+            .await;
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
 }
 
 // ============================================================================
@@ -382,6 +637,56 @@ mod avco_valuation_tests {
 
         cleanup_valuation_test_data(&pool, tenant_id).await;
     }
+
+    #[tokio::test]
+    async fn test_avco_long_sequence_does_not_drift() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let service = create_valuation_service(&pool);
+
+        service
+            .set_valuation_method(SetValuationMethodRequest {
+                tenant_id,
+                product_id,
+                valuation_method: ValuationMethod::Avco,
+            })
+            .await
+            .unwrap();
+
+        // Unit costs that never divide evenly, so every delivery has to round
+        let mut received_value = 0i64;
+        let mut issued_value = 0i64;
+        let mut previous_value = 0i64;
+        for step in 0..200i64 {
+            let unit_cost = 333 + step % 7;
+            let after_receipt = service
+                .process_stock_movement(tenant_id, product_id, 3, Some(unit_cost), None)
+                .await
+                .expect("Receipt should succeed");
+            received_value += 3 * unit_cost;
+            previous_value = after_receipt.total_value;
+
+            let after_delivery = service
+                .process_stock_movement(tenant_id, product_id, -2, None, None)
+                .await
+                .expect("Delivery should succeed");
+            issued_value += previous_value - after_delivery.total_value;
+            previous_value = after_delivery.total_value;
+        }
+
+        // Drain the remaining stock: the final issue takes exactly what is left
+        let remaining = service
+            .process_stock_movement(tenant_id, product_id, -200, None, None)
+            .await
+            .expect("Final delivery should succeed");
+        issued_value += previous_value - remaining.total_value;
+
+        assert_eq!(remaining.total_quantity, 0);
+        assert_eq!(remaining.total_value, 0);
+        assert_eq!(issued_value, received_value);
+
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
 }
 
 // ============================================================================
@@ -620,4 +925,291 @@ mod valuation_edge_cases {
 
         cleanup_valuation_test_data(&pool, tenant_id).await;
     }
+
+    #[tokio::test]
+    async fn test_find_by_product_ids_returns_only_existing_valuations() {
+        use inventory_service_core::repositories::valuation::ValuationRepository;
+        use inventory_service_infra::repositories::ValuationRepositoryImpl;
+
+        let pool = setup_test_pool().await;
+        let (tenant_id, valued_product_id) = setup_test_tenant_and_product(&pool).await;
+        let (other_tenant_id, other_product_id) = setup_test_tenant_and_product(&pool).await;
+        let service = create_valuation_service(&pool);
+
+        for (tenant_id, product_id) in [
+            (tenant_id, valued_product_id),
+            (other_tenant_id, other_product_id),
+        ] {
+            service
+                .set_valuation_method(SetValuationMethodRequest {
+                    tenant_id,
+                    product_id,
+                    valuation_method: ValuationMethod::Avco,
+                })
+                .await
+                .unwrap();
+        }
+
+        // A product of the same tenant without a valuation row
+        let unvalued_product_id = Uuid::now_v7();
+        sqlx::query(
+            "INSERT INTO products (product_id, tenant_id, sku, name, created_at)
+             VALUES ($1, $2, $3, 'Unvalued Product', NOW())",
+        )
+        .bind(unvalued_product_id)
+        .bind(tenant_id)
+        .bind(format!("TEST-{}", unvalued_product_id))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert product");
+
+        let repo = ValuationRepositoryImpl::new(pool.clone());
+        let valuations = repo
+            .find_by_product_ids(
+                tenant_id,
+                &[valued_product_id, unvalued_product_id, other_product_id],
+            )
+            .await
+            .expect("Batch lookup should succeed");
+
+        // Missing valuations and other tenants' products are simply absent
+        assert_eq!(valuations.len(), 1);
+        let valuation = &valuations[&valued_product_id];
+        assert_eq!(valuation.product_id, valued_product_id);
+        assert_eq!(valuation.valuation_method, ValuationMethod::Avco);
+
+        assert!(repo
+            .find_by_product_ids(tenant_id, &[])
+            .await
+            .unwrap()
+            .is_empty());
+
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+        cleanup_valuation_test_data(&pool, other_tenant_id).await;
+    }
+}
+
+// ============================================================================
+// Valuation Method Change Tests
+// ============================================================================
+
+#[cfg(test)]
+mod method_change_tests {
+    use super::*;
+    use inventory_service_core::domains::inventory::dto::valuation_dto::{
+        GetValuationHistoryRequest, GetValuationLayersRequest,
+    };
+
+    #[tokio::test]
+    async fn test_fifo_to_avco_collapses_layers_and_conserves_value() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let service = create_valuation_service(&pool);
+
+        service
+            .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Fifo))
+            .await
+            .unwrap();
+
+        // 50 @ $10.00 + 30 @ $13.01 = 80 units worth 89030
+        service
+            .process_stock_movement(tenant_id, product_id, 50, Some(1000), None)
+            .await
+            .expect("First receipt");
+        let before = service
+            .process_stock_movement(tenant_id, product_id, 30, Some(1301), None)
+            .await
+            .expect("Second receipt");
+        assert_eq!(before.total_quantity, 80);
+        assert_eq!(before.total_value, 89_030);
+
+        let after = service
+            .change_method(method_request(tenant_id, product_id, ValuationMethod::Avco))
+            .await
+            .expect("FIFO to AVCO should succeed");
+
+        assert_eq!(after.valuation_method, ValuationMethod::Avco);
+        assert_eq!(after.total_quantity, before.total_quantity);
+        assert_eq!(after.total_value, before.total_value);
+        assert_eq!(after.current_unit_cost, Some(89_030 / 80));
+
+        let layers = service
+            .get_valuation_layers(GetValuationLayersRequest {
+                tenant_id,
+                product_id,
+            })
+            .await
+            .unwrap();
+        assert!(layers.layers.is_empty(), "FIFO layers should be collapsed");
+
+        // Delivering through the new method keeps working off the average
+        let delivered = service
+            .process_stock_movement(tenant_id, product_id, -10, None, None)
+            .await
+            .expect("AVCO delivery after switch");
+        assert_eq!(delivered.total_quantity, 70);
+
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_avco_to_fifo_explodes_average_and_conserves_value() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let service = create_valuation_service(&pool);
+
+        service
+            .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Avco))
+            .await
+            .unwrap();
+
+        // 100 @ $10.00 + 50 @ $13.01 = 150 units worth 165050
+        service
+            .process_stock_movement(tenant_id, product_id, 100, Some(1000), None)
+            .await
+            .expect("First receipt");
+        let before = service
+            .process_stock_movement(tenant_id, product_id, 50, Some(1301), None)
+            .await
+            .expect("Second receipt");
+        assert_eq!(before.total_quantity, 150);
+        assert_eq!(before.total_value, 165_050);
+
+        let after = service
+            .change_method(method_request(tenant_id, product_id, ValuationMethod::Fifo))
+            .await
+            .expect("AVCO to FIFO should succeed");
+
+        assert_eq!(after.valuation_method, ValuationMethod::Fifo);
+        assert_eq!(after.total_quantity, before.total_quantity);
+        assert_eq!(after.total_value, before.total_value);
+
+        let layers = service
+            .get_valuation_layers(GetValuationLayersRequest {
+                tenant_id,
+                product_id,
+            })
+            .await
+            .unwrap();
+        // 165050 / 150 = 1100 remainder 50: 50 units at 1101, 100 at 1100
+        let mut seeded: Vec<(i64, i64)> = layers
+            .layers
+            .iter()
+            .map(|layer| (layer.quantity, layer.unit_cost))
+            .collect();
+        seeded.sort();
+        assert_eq!(seeded, vec![(50, 1101), (100, 1100)]);
+        for layer in &layers.layers {
+            assert_eq!(layer.total_value, layer.quantity * layer.unit_cost);
+        }
+        let layered_value: i64 = layers.layers.iter().map(|layer| layer.total_value).sum();
+        assert_eq!(layered_value, 165_050);
+
+        // Delivering all stock consumes the layers under FIFO with no value left over
+        let delivered = service
+            .process_stock_movement(tenant_id, product_id, -150, None, None)
+            .await
+            .expect("FIFO delivery after switch");
+        assert_eq!(delivered.total_quantity, 0);
+        assert_eq!(delivered.total_value, 0);
+
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_fifo_to_lifo_keeps_existing_layers() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let service = create_valuation_service(&pool);
+
+        service
+            .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Fifo))
+            .await
+            .unwrap();
+
+        // 50 @ $10.00 + 30 @ $13.01 = 80 units worth 89030
+        service
+            .process_stock_movement(tenant_id, product_id, 50, Some(1000), None)
+            .await
+            .expect("First receipt");
+        service
+            .process_stock_movement(tenant_id, product_id, 30, Some(1301), None)
+            .await
+            .expect("Second receipt");
+
+        let after = service
+            .change_method(method_request(tenant_id, product_id, ValuationMethod::Lifo))
+            .await
+            .expect("FIFO to LIFO should succeed");
+        assert_eq!(after.valuation_method, ValuationMethod::Lifo);
+        assert_eq!(after.total_value, 89_030);
+
+        // The receipt layers survive instead of being averaged
+        let layers = service
+            .get_valuation_layers(GetValuationLayersRequest {
+                tenant_id,
+                product_id,
+            })
+            .await
+            .unwrap();
+        let mut kept: Vec<(i64, i64)> = layers
+            .layers
+            .iter()
+            .map(|layer| (layer.quantity, layer.unit_cost))
+            .collect();
+        kept.sort();
+        assert_eq!(kept, vec![(30, 1301), (50, 1000)]);
+
+        // LIFO now consumes the newest receipt first
+        let delivered = service
+            .process_stock_movement(tenant_id, product_id, -30, None, None)
+            .await
+            .expect("LIFO delivery after switch");
+        assert_eq!(delivered.total_quantity, 50);
+        assert_eq!(delivered.total_value, 50_000);
+
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_method_change_records_history() {
+        let pool = setup_test_pool().await;
+        let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+        let service = create_valuation_service(&pool);
+
+        service
+            .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Fifo))
+            .await
+            .unwrap();
+        service
+            .process_stock_movement(tenant_id, product_id, 10, Some(500), None)
+            .await
+            .expect("Receipt");
+
+        // The existing endpoint path re-layers as well
+        service
+            .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Avco))
+            .await
+            .expect("Method change should succeed");
+
+        let history = service
+            .get_valuation_history(GetValuationHistoryRequest {
+                tenant_id,
+                product_id,
+                limit: None,
+                offset: None,
+            })
+            .await
+            .unwrap();
+        let entry = history
+            .history
+            .iter()
+            .find(|h| h.change_reason.as_deref() == Some("method_change: fifo -> avco"))
+            .expect("Method change should be recorded in history");
+        assert_eq!(entry.valuation_method, ValuationMethod::Fifo);
+        assert_eq!(entry.total_quantity, 10);
+        assert_eq!(entry.total_value, 5_000);
+
+        cleanup_valuation_test_data(&pool, tenant_id).await;
+    }
 }
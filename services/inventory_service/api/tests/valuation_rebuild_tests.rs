@@ -0,0 +1,438 @@
+//! Valuation Rebuild Integration Tests
+//!
+//! Rebuilding replays the stock move history into cost layers and valuation
+//! totals, repairing layers that no longer match the moves. Recomputing AVCO
+//! replays the same history into the running average after a unit cost is
+//! corrected. Both replays include moves already moved to the archive.
+
+mod business_logic_test_helpers;
+
+use business_logic_test_helpers::{
+    cleanup_valuation_test_data, create_valuation_service, method_request, setup_test_pool,
+    setup_test_tenant_and_product,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use inventory_service_core::domains::inventory::dto::valuation_dto::{
+    GetValuationHistoryRequest, GetValuationLayersRequest, GetValuationRequest,
+};
+use inventory_service_core::domains::inventory::valuation::ValuationMethod;
+use inventory_service_core::services::valuation::ValuationService;
+use inventory_service_infra::repositories::stock::PgStockMoveRepository;
+use shared_error::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Record a stock move without locations; the quantity carries the direction
+async fn record_move(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    product_id: Uuid,
+    quantity: i64,
+    unit_cost: Option<i64>,
+    minutes_ago: i64,
+) {
+    let move_date = Utc::now() - Duration::minutes(minutes_ago);
+    record_move_at(pool, tenant_id, product_id, quantity, unit_cost, move_date).await;
+}
+
+/// Record a stock move at a fixed date
+async fn record_move_at(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    product_id: Uuid,
+    quantity: i64,
+    unit_cost: Option<i64>,
+    move_date: DateTime<Utc>,
+) {
+    let (move_type, reference_type) = if quantity > 0 {
+        ("receipt", "grn")
+    } else {
+        ("delivery", "do")
+    };
+    sqlx::query(
+        "INSERT INTO stock_moves (
+            tenant_id, product_id, move_type, quantity, unit_cost,
+            reference_type, reference_id, idempotency_key, move_date
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .bind(move_type)
+    .bind(quantity)
+    .bind(unit_cost)
+    .bind(reference_type)
+    .bind(Uuid::now_v7())
+    .bind(format!("rebuild-{}", Uuid::now_v7()))
+    .bind(move_date)
+    .execute(pool)
+    .await
+    .expect("Failed to insert stock move");
+}
+
+/// A date far enough in the past that no other test's moves fall before it
+fn archived_day(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2001, 1, day, 12, 0, 0).unwrap()
+}
+
+/// Archive every move dated before February 2001
+async fn archive_old_moves(pool: &PgPool) {
+    let cutoff = Utc.with_ymd_and_hms(2001, 2, 1, 0, 0, 0).unwrap();
+    while PgStockMoveRepository::archive_before(pool, cutoff, 100)
+        .await
+        .expect("Archiving should succeed")
+        > 0
+    {}
+}
+
+async fn hot_move_count(pool: &PgPool, tenant_id: Uuid) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM stock_moves WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+async fn cleanup_rebuild_test_data(pool: &PgPool, tenant_id: Uuid) {
+    let _ = sqlx::query("DELETE FROM stock_moves WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query("DELETE FROM stock_moves_archive WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query("DELETE FROM inventory_valuation_history WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query("DELETE FROM inventory_valuation_layers WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query("DELETE FROM inventory_valuations WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await;
+    cleanup_valuation_test_data(pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_rebuild_restores_corrupted_layers_from_move_history() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = create_valuation_service(&pool);
+
+    service
+        .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Fifo))
+        .await
+        .unwrap();
+
+    // 50 @ 1000 + 30 @ 1200, issue 60, then 20 @ 1100:
+    // 20 @ 1200 + 20 @ 1100 remain, worth 46000
+    let history: [(i64, Option<i64>); 4] = [
+        (50, Some(1000)),
+        (30, Some(1200)),
+        (-60, None),
+        (20, Some(1100)),
+    ];
+    for (step, (quantity, unit_cost)) in history.into_iter().enumerate() {
+        record_move(&pool, tenant_id, product_id, quantity, unit_cost, 40 - step as i64 * 10).await;
+        service
+            .process_stock_movement(tenant_id, product_id, quantity, unit_cost, None)
+            .await
+            .expect("Movement should be valued");
+    }
+
+    // Corrupt both the layers and the totals
+    sqlx::query(
+        "UPDATE inventory_valuation_layers SET quantity = 999, total_value = 1
+         WHERE tenant_id = $1 AND product_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "UPDATE inventory_valuations SET total_quantity = 7, total_value = 3
+         WHERE tenant_id = $1 AND product_id = $2",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let admin_id = Uuid::now_v7();
+    let rebuilt = service
+        .rebuild_layers(tenant_id, product_id, Some(admin_id))
+        .await
+        .expect("Rebuild should succeed");
+    assert_eq!(rebuilt.total_quantity, 40);
+    assert_eq!(rebuilt.total_value, 46_000);
+
+    let layers = service
+        .get_valuation_layers(GetValuationLayersRequest {
+            tenant_id,
+            product_id,
+        })
+        .await
+        .unwrap();
+    let remaining: Vec<_> = layers
+        .layers
+        .iter()
+        .map(|l| (l.quantity, l.unit_cost, l.total_value))
+        .collect();
+    assert_eq!(remaining, vec![(20, 1200, 24_000), (20, 1100, 22_000)]);
+
+    let valuation = service
+        .get_valuation(GetValuationRequest {
+            tenant_id,
+            product_id,
+        })
+        .await
+        .unwrap();
+    assert_eq!(valuation.total_quantity, 40);
+    assert_eq!(valuation.total_value, 46_000);
+
+    // The corrupted state is kept in the history
+    let history = service
+        .get_valuation_history(GetValuationHistoryRequest {
+            tenant_id,
+            product_id,
+            limit: None,
+            offset: None,
+        })
+        .await
+        .unwrap();
+    let entry = history
+        .history
+        .iter()
+        .find(|h| h.change_reason.as_deref() == Some("layer_rebuild: 4 stock moves replayed"))
+        .expect("Rebuild should be recorded in history");
+    assert_eq!(entry.total_quantity, 7);
+    assert_eq!(entry.total_value, 3);
+
+    // Later deliveries consume the rebuilt layers in FIFO order
+    let delivered = service
+        .process_stock_movement(tenant_id, product_id, -25, None, None)
+        .await
+        .expect("Delivery after rebuild");
+    assert_eq!(delivered.total_value, 46_000 - 20 * 1200 - 5 * 1100);
+
+    cleanup_rebuild_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_rebuild_rejects_non_fifo_product() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = create_valuation_service(&pool);
+
+    service
+        .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Avco))
+        .await
+        .unwrap();
+    record_move(&pool, tenant_id, product_id, 10, Some(500), 5).await;
+
+    let result = service.rebuild_layers(tenant_id, product_id, None).await;
+    assert!(matches!(result, Err(AppError::BusinessError(_))));
+
+    let result = service
+        .rebuild_layers(tenant_id, Uuid::now_v7(), None)
+        .await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_rebuild_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_recompute_avco_applies_corrected_historical_cost() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = create_valuation_service(&pool);
+
+    service
+        .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Avco))
+        .await
+        .unwrap();
+
+    // 10 @ 100 + 10 @ 200 average 150, issue 10, then 10 @ 300:
+    // 20 units worth 4500
+    let history: [(i64, Option<i64>); 4] = [
+        (10, Some(100)),
+        (10, Some(200)),
+        (-10, None),
+        (10, Some(300)),
+    ];
+    for (step, (quantity, unit_cost)) in history.into_iter().enumerate() {
+        record_move(&pool, tenant_id, product_id, quantity, unit_cost, 40 - step as i64 * 10).await;
+        service
+            .process_stock_movement(tenant_id, product_id, quantity, unit_cost, None)
+            .await
+            .expect("Movement should be valued");
+    }
+
+    // The first receipt was actually costed at 160
+    sqlx::query(
+        "UPDATE stock_moves SET unit_cost = 160
+         WHERE tenant_id = $1 AND product_id = $2 AND unit_cost = 100",
+    )
+    .bind(tenant_id)
+    .bind(product_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let before = service
+        .get_valuation(GetValuationRequest {
+            tenant_id,
+            product_id,
+        })
+        .await
+        .unwrap();
+
+    // 10 @ 160 + 10 @ 200 average 180, issue 10 (1800), then 10 @ 300
+    let recomputed = service
+        .recompute_avco(tenant_id, product_id)
+        .await
+        .expect("Recompute should succeed");
+    assert_eq!(recomputed.total_quantity, 20);
+    assert_eq!(recomputed.total_value, 4_800);
+    assert_eq!(recomputed.current_unit_cost, Some(240));
+    assert!(recomputed.last_updated > before.last_updated);
+
+    let history = service
+        .get_valuation_history(GetValuationHistoryRequest {
+            tenant_id,
+            product_id,
+            limit: None,
+            offset: None,
+        })
+        .await
+        .unwrap();
+    let entry = history
+        .history
+        .iter()
+        .find(|h| h.change_reason.as_deref() == Some("avco_recompute"))
+        .expect("Recompute should be recorded in history");
+    assert_eq!(entry.total_value, 4_500);
+
+    cleanup_rebuild_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_recompute_avco_rejects_non_avco_product() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = create_valuation_service(&pool);
+
+    service
+        .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Fifo))
+        .await
+        .unwrap();
+    record_move(&pool, tenant_id, product_id, 10, Some(500), 5).await;
+
+    let result = service.recompute_avco(tenant_id, product_id).await;
+    assert!(matches!(result, Err(AppError::BusinessError(_))));
+
+    let result = service.recompute_avco(tenant_id, Uuid::now_v7()).await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    cleanup_rebuild_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_rebuild_replays_archived_moves() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = create_valuation_service(&pool);
+
+    service
+        .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Fifo))
+        .await
+        .unwrap();
+
+    // Same history as above, but the first three moves get archived
+    let old_history: [(i64, Option<i64>, u32); 3] =
+        [(50, Some(1000), 5), (30, Some(1200), 10), (-60, None, 20)];
+    for (quantity, unit_cost, day) in old_history {
+        record_move_at(&pool, tenant_id, product_id, quantity, unit_cost, archived_day(day)).await;
+        service
+            .process_stock_movement(tenant_id, product_id, quantity, unit_cost, None)
+            .await
+            .expect("Movement should be valued");
+    }
+    record_move(&pool, tenant_id, product_id, 20, Some(1100), 5).await;
+    service
+        .process_stock_movement(tenant_id, product_id, 20, Some(1100), None)
+        .await
+        .expect("Movement should be valued");
+
+    archive_old_moves(&pool).await;
+    assert_eq!(hot_move_count(&pool, tenant_id).await, 1);
+
+    let rebuilt = service
+        .rebuild_layers(tenant_id, product_id, None)
+        .await
+        .expect("Rebuild should succeed");
+    assert_eq!(rebuilt.total_quantity, 40);
+    assert_eq!(rebuilt.total_value, 46_000);
+
+    let layers = service
+        .get_valuation_layers(GetValuationLayersRequest {
+            tenant_id,
+            product_id,
+        })
+        .await
+        .unwrap();
+    let remaining: Vec<_> = layers
+        .layers
+        .iter()
+        .map(|l| (l.quantity, l.unit_cost, l.total_value))
+        .collect();
+    assert_eq!(remaining, vec![(20, 1200, 24_000), (20, 1100, 22_000)]);
+
+    cleanup_rebuild_test_data(&pool, tenant_id).await;
+}
+
+#[tokio::test]
+async fn test_recompute_avco_replays_archived_moves() {
+    let pool = setup_test_pool().await;
+    let (tenant_id, product_id) = setup_test_tenant_and_product(&pool).await;
+    let service = create_valuation_service(&pool);
+
+    service
+        .set_valuation_method(method_request(tenant_id, product_id, ValuationMethod::Avco))
+        .await
+        .unwrap();
+
+    // 10 @ 100 + 10 @ 200 average 150, issue 10, all archived; then 10 @ 300
+    let old_history: [(i64, Option<i64>, u32); 3] =
+        [(10, Some(100), 5), (10, Some(200), 10), (-10, None, 20)];
+    for (quantity, unit_cost, day) in old_history {
+        record_move_at(&pool, tenant_id, product_id, quantity, unit_cost, archived_day(day)).await;
+        service
+            .process_stock_movement(tenant_id, product_id, quantity, unit_cost, None)
+            .await
+            .expect("Movement should be valued");
+    }
+    record_move(&pool, tenant_id, product_id, 10, Some(300), 5).await;
+    service
+        .process_stock_movement(tenant_id, product_id, 10, Some(300), None)
+        .await
+        .expect("Movement should be valued");
+
+    archive_old_moves(&pool).await;
+    assert_eq!(hot_move_count(&pool, tenant_id).await, 1);
+
+    let recomputed = service
+        .recompute_avco(tenant_id, product_id)
+        .await
+        .expect("Recompute should succeed");
+    assert_eq!(recomputed.total_quantity, 20);
+    assert_eq!(recomputed.total_value, 4_500);
+    assert_eq!(recomputed.current_unit_cost, Some(225));
+
+    cleanup_rebuild_test_data(&pool, tenant_id).await;
+}
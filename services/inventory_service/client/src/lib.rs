@@ -0,0 +1,362 @@
+//! Inventory Service Client
+//!
+//! Typed HTTP client for the inventory service read API, for services (order,
+//! payment, ...) that would otherwise hand-roll requests and deserialization.
+//! Responses are the `inventory_service_core` DTOs the server returns, and
+//! failures are reported as `AppError` so callers can propagate them with `?`.
+//!
+//! The client depends only on the core crate and the shared error type, not on
+//! `inventory_service_infra` or the API crate.
+//!
+//! ```no_run
+//! # async fn example(token: String) -> Result<(), shared_error::AppError> {
+//! use inventory_service_client::InventoryClient;
+//! use uuid::Uuid;
+//!
+//! let client = InventoryClient::new("http://inventory-service:8001").with_bearer_token(token);
+//! let availability = client
+//!     .check_availability(Uuid::now_v7(), Uuid::now_v7(), 5)
+//!     .await?;
+//! if !availability.is_available {
+//!     // ...
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use inventory_service_core::dto::product::ProductResponse;
+use inventory_service_core::dto::stock_levels::{StockLevelListQuery, StockLevelListResponse};
+use shared_error::AppError;
+
+/// Path prefix of the inventory REST API
+const API_PREFIX: &str = "/api/v1/inventory";
+
+/// Result of an availability check for one product in one warehouse
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Availability {
+    pub product_id: Uuid,
+    pub warehouse_id: Uuid,
+    /// Quantity that can be sold or reserved right now
+    pub available_quantity: i64,
+    pub requested_quantity: i64,
+    /// Whether `available_quantity` covers `requested_quantity`
+    pub is_available: bool,
+}
+
+/// Error body returned by the inventory service
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    error: String,
+    #[serde(default)]
+    code: Option<String>,
+}
+
+/// Client for the inventory service read endpoints
+///
+/// Cloning is cheap; clones share the underlying connection pool.
+#[derive(Debug, Clone)]
+pub struct InventoryClient {
+    http: reqwest::Client,
+    base_url: String,
+    bearer_token: Option<String>,
+}
+
+impl InventoryClient {
+    /// Create a client for the service at `base_url` (e.g. `http://inventory-service:8001`)
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_http_client(reqwest::Client::new(), base_url)
+    }
+
+    /// Create a client on an existing `reqwest::Client` (timeouts, proxies, ...)
+    pub fn with_http_client(http: reqwest::Client, base_url: impl Into<String>) -> Self {
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            bearer_token: None,
+        }
+    }
+
+    /// Send `token` as `Authorization: Bearer <token>` on every request
+    ///
+    /// Reads are scoped to the token's tenant, so callers usually forward the
+    /// access token of the request they are serving.
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    /// GET /api/v1/inventory/products/{product_id}
+    pub async fn get_product(&self, product_id: Uuid) -> Result<ProductResponse, AppError> {
+        self.send(self.get(&format!("/products/{}", product_id)))
+            .await
+    }
+
+    /// GET /api/v1/inventory/stock-levels
+    pub async fn list_stock_levels(
+        &self,
+        query: &StockLevelListQuery,
+    ) -> Result<StockLevelListResponse, AppError> {
+        self.send(self.get("/stock-levels").query(query)).await
+    }
+
+    /// Check whether `quantity` of a product is available in a warehouse
+    ///
+    /// Reads the product's stock level in the warehouse. A product without a
+    /// stock row has nothing available; use [`Self::get_product`] to tell that
+    /// apart from an unknown product.
+    pub async fn check_availability(
+        &self,
+        product_id: Uuid,
+        warehouse_id: Uuid,
+        quantity: i64,
+    ) -> Result<Availability, AppError> {
+        if quantity <= 0 {
+            return Err(AppError::ValidationError("quantity must be positive".to_string()));
+        }
+
+        let query = StockLevelListQuery {
+            warehouse_id: Some(warehouse_id),
+            product_id: Some(product_id),
+            page: 1,
+            page_size: 1,
+            sort_by: "product_name".to_string(),
+            sort_dir: "asc".to_string(),
+            ..Default::default()
+        };
+        let levels = self.list_stock_levels(&query).await?;
+        let available_quantity = levels
+            .items
+            .iter()
+            .find(|level| level.product_id == product_id && level.warehouse_id == warehouse_id)
+            .map(|level| level.available_quantity)
+            .unwrap_or(0);
+
+        Ok(Availability {
+            product_id,
+            warehouse_id,
+            available_quantity,
+            requested_quantity: quantity,
+            is_available: available_quantity >= quantity,
+        })
+    }
+
+    /// GET request for a path under the API prefix, with the bearer token attached
+    fn get(&self, path: &str) -> RequestBuilder {
+        let request = self
+            .http
+            .get(format!("{}{}{}", self.base_url, API_PREFIX, path));
+        match &self.bearer_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, AppError> {
+        let response = request.send().await.map_err(|e| {
+            AppError::ServiceUnavailable(format!("Inventory service request failed: {}", e))
+        })?;
+        if !response.status().is_success() {
+            return Err(error_from_response(response).await);
+        }
+
+        response.json::<T>().await.map_err(|e| {
+            AppError::InternalError(format!("Invalid inventory service response: {}", e))
+        })
+    }
+}
+
+/// Map a non-2xx response to the `AppError` the server most likely raised
+async fn error_from_response(response: Response) -> AppError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let (message, code) = match serde_json::from_str::<ErrorBody>(&body) {
+        Ok(parsed) => (parsed.error, parsed.code),
+        Err(_) => (body, None),
+    };
+    app_error_for(status, code.as_deref(), message)
+}
+
+fn app_error_for(status: StatusCode, code: Option<&str>, message: String) -> AppError {
+    match (status, code) {
+        (StatusCode::UNAUTHORIZED, Some("TOKEN_EXPIRED")) => AppError::TokenExpired,
+        (StatusCode::UNAUTHORIZED, Some("INVALID_TOKEN")) => AppError::InvalidToken,
+        (StatusCode::UNAUTHORIZED, _) => AppError::Unauthorized(message),
+        (StatusCode::BAD_REQUEST, Some("BUSINESS_ERROR")) => AppError::BusinessError(message),
+        (StatusCode::BAD_REQUEST, _) => AppError::ValidationError(message),
+        (StatusCode::FORBIDDEN, _) => AppError::Forbidden(message),
+        (StatusCode::NOT_FOUND, _) => AppError::NotFound(message),
+        (StatusCode::CONFLICT, _) => AppError::Conflict(message),
+        (StatusCode::GONE, _) => AppError::Gone(message),
+        (StatusCode::PAYLOAD_TOO_LARGE, _) => AppError::PayloadTooLarge(message),
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, _) => AppError::UnsupportedMediaType(message),
+        (StatusCode::TOO_MANY_REQUESTS, _) => AppError::TooManyRequests(message),
+        (StatusCode::SERVICE_UNAVAILABLE, _) => AppError::ServiceUnavailable(message),
+        _ => AppError::InternalError(format!("Inventory service returned {}: {}", status, message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn stock_level(product_id: Uuid, warehouse_id: Uuid, available: i64) -> serde_json::Value {
+        json!({
+            "inventoryId": Uuid::now_v7(),
+            "tenantId": Uuid::now_v7(),
+            "productId": product_id,
+            "productSku": "SKU-1",
+            "productName": "Widget",
+            "warehouseId": warehouse_id,
+            "warehouseCode": "WH-1",
+            "warehouseName": "Main",
+            "availableQuantity": available,
+            "reservedQuantity": 2,
+            "backorderedQuantity": 0,
+            "totalQuantity": available + 2,
+            "status": "in_stock",
+            "reorderPoint": null,
+            "updatedAt": Utc::now(),
+        })
+    }
+
+    fn level_list(items: Vec<serde_json::Value>) -> serde_json::Value {
+        json!({
+            "items": items,
+            "pagination": { "page": 1, "pageSize": 1, "hasNext": false, "hasPrev": false },
+            "summary": {
+                "totalProducts": 0,
+                "totalAvailableQuantity": 0,
+                "totalReservedQuantity": 0,
+                "totalBackorderedQuantity": 0,
+                "lowStockCount": 0,
+                "outOfStockCount": 0,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_check_availability_sends_scoped_authenticated_request() {
+        let server = MockServer::start().await;
+        let (product_id, warehouse_id) = (Uuid::now_v7(), Uuid::now_v7());
+        Mock::given(method("GET"))
+            .and(path("/api/v1/inventory/stock-levels"))
+            .and(header("authorization", "Bearer test-token"))
+            .and(query_param("productId", product_id.to_string()))
+            .and(query_param("warehouseId", warehouse_id.to_string()))
+            .and(query_param("pageSize", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(level_list(vec![stock_level(
+                product_id,
+                warehouse_id,
+                7,
+            )])))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client =
+            InventoryClient::new(format!("{}/", server.uri())).with_bearer_token("test-token");
+
+        let enough = client
+            .check_availability(product_id, warehouse_id, 7)
+            .await
+            .unwrap();
+        assert_eq!(
+            enough,
+            Availability {
+                product_id,
+                warehouse_id,
+                available_quantity: 7,
+                requested_quantity: 7,
+                is_available: true,
+            }
+        );
+
+        let short = client
+            .check_availability(product_id, warehouse_id, 8)
+            .await
+            .unwrap();
+        assert!(!short.is_available);
+        assert_eq!(short.available_quantity, 7);
+    }
+
+    #[tokio::test]
+    async fn test_check_availability_without_stock_row_is_unavailable() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/inventory/stock-levels"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(level_list(vec![])))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(server.uri());
+        let availability = client
+            .check_availability(Uuid::now_v7(), Uuid::now_v7(), 1)
+            .await
+            .unwrap();
+        assert_eq!(availability.available_quantity, 0);
+        assert!(!availability.is_available);
+    }
+
+    #[tokio::test]
+    async fn test_check_availability_rejects_non_positive_quantity() {
+        // No server: the request must not be sent
+        let client = InventoryClient::new("http://127.0.0.1:9");
+        let err = client
+            .check_availability(Uuid::now_v7(), Uuid::now_v7(), 0)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::ValidationError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_error_responses_map_to_app_error() {
+        let server = MockServer::start().await;
+        let missing = Uuid::now_v7();
+        Mock::given(method("GET"))
+            .and(path(format!("/api/v1/inventory/products/{}", missing)))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error": "Product not found",
+                "code": "NOT_FOUND",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/inventory/stock-levels"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": "Token expired",
+                "code": "TOKEN_EXPIRED",
+            })))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(server.uri());
+
+        match client.get_product(missing).await.unwrap_err() {
+            AppError::NotFound(message) => assert_eq!(message, "Product not found"),
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+        let err = client
+            .list_stock_levels(&StockLevelListQuery::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::TokenExpired));
+    }
+
+    #[test]
+    fn test_unexpected_status_maps_to_internal_error() {
+        let err = app_error_for(StatusCode::BAD_GATEWAY, None, "upstream".to_string());
+        assert!(matches!(err, AppError::InternalError(_)));
+        let err = app_error_for(StatusCode::SERVICE_UNAVAILABLE, None, "down".to_string());
+        assert!(matches!(err, AppError::ServiceUnavailable(_)));
+    }
+}
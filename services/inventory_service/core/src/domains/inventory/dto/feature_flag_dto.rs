@@ -0,0 +1,37 @@
+//! Feature Flag DTOs.
+//!
+//! Data transfer objects for tenant feature flag administration.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domains::inventory::feature_flag::FeatureFlag;
+
+/// Effective value of a feature flag for the current tenant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FeatureFlagDto {
+    /// Flag key
+    pub flag: FeatureFlag,
+    /// Whether the flag is enabled for the tenant
+    pub enabled: bool,
+    /// True if the tenant has an explicit setting, false if the default applies
+    pub overridden: bool,
+    /// When the tenant setting was last changed
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// All feature flags for the current tenant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FeatureFlagListResponse {
+    pub flags: Vec<FeatureFlagDto>,
+}
+
+/// Request to enable or disable a feature flag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SetFeatureFlagRequest {
+    /// New value for the flag
+    pub enabled: bool,
+}
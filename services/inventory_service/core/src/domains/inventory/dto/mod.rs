@@ -1,4 +1,5 @@
 pub mod common;
+pub mod feature_flag_dto;
 pub mod landed_cost_dto;
 pub mod picking_method_dto;
 pub mod search_dto;
@@ -178,7 +178,8 @@ pub struct PickingTask {
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PickingMetrics {
-    /// Total distance (meters)
+    /// Estimated walking distance between task locations (meters); absent when
+    /// a location has no x/y coordinates
     pub total_distance_meters: Option<f64>,
 
     /// Total estimated time (seconds)
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::dto::product::{ProductAttributes, SortSpec};
 use crate::dto::PaginationInfo;
 
 /// Product search request DTO
@@ -38,11 +39,24 @@ pub struct ProductSearchRequest {
     pub sort_by: Option<ProductSortBy>,
     pub sort_order: Option<SortOrder>,
 
+    /// Multi-field sort from product listing; overrides `sort_by` when non-empty
+    #[serde(skip)]
+    #[cfg_attr(feature = "openapi", schema(ignore))]
+    pub order_by: Vec<SortSpec>,
+
+    /// Attribute filters from product listing; products must contain every pair
+    #[serde(skip)]
+    #[cfg_attr(feature = "openapi", schema(ignore))]
+    pub attributes: ProductAttributes,
+
     /// Pagination
     #[validate(range(min = 1, max = 1000))]
     pub page: Option<u32>,
     #[validate(range(min = 1, max = 100))]
     pub limit: Option<u32>,
+
+    /// Count total matches (defaults to true; skip for cheaper deep paging)
+    pub include_total: Option<bool>,
 }
 
 impl Default for ProductSearchRequest {
@@ -58,8 +72,11 @@ impl Default for ProductSearchRequest {
             sellable_only: Some(true),
             sort_by: Some(ProductSortBy::Relevance),
             sort_order: Some(SortOrder::Desc),
+            order_by: Vec::new(),
+            attributes: ProductAttributes::new(),
             page: Some(1),
             limit: Some(20),
+            include_total: Some(true),
         }
     }
 }
@@ -136,6 +153,9 @@ pub struct ProductSearchResult {
     pub is_active: bool,
     pub is_sellable: bool,
 
+    /// Specification attributes
+    pub attributes: Option<serde_json::Value>,
+
     /// Search highlights (highlighted text snippets)
     pub highlights: Vec<String>,
 
@@ -206,8 +226,9 @@ pub struct SearchMeta {
     /// Search execution time in milliseconds
     pub execution_time_ms: u64,
 
-    /// Total products found before filtering
-    pub total_found: u64,
+    /// Total products found before filtering (omitted when the count was skipped)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_found: Option<u64>,
 
     /// Applied filters summary
     pub applied_filters: AppliedFilters,
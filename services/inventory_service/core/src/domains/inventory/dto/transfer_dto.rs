@@ -226,3 +226,38 @@ pub struct CancelTransferResponse {
     /// Cancellation timestamp
     pub cancelled_at: String, // ISO 8601
 }
+
+/// Request to set or update a transfer's ETA and carrier tracking
+///
+/// Omitted fields are left unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct UpdateTransferTrackingRequest {
+    /// Expected arrival at the destination, after the ship date
+    pub expected_arrival: Option<String>, // ISO 8601 date string
+    /// Shipping carrier
+    pub carrier: Option<String>,
+    /// Carrier tracking number
+    pub tracking_number: Option<String>,
+}
+
+/// Parameters for listing in-transit transfers
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ListInTransitTransfersParams {
+    /// Filter by destination warehouse
+    pub destination_warehouse_id: Option<Uuid>,
+    /// Maximum number of transfers (default 100)
+    pub limit: Option<i64>,
+}
+
+/// Open transfers ordered by expected arrival (unknown ETAs last)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ListInTransitTransfersResponse {
+    /// In-transit transfers
+    pub items: Vec<Transfer>,
+}
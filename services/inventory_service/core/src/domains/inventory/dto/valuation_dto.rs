@@ -1,7 +1,7 @@
 //! Valuation DTOs for API communication
 //!
 //! Data transfer objects for inventory valuation operations,
-//! supporting FIFO, AVCO, and Standard costing methods.
+//! supporting FIFO, LIFO, AVCO, and Standard costing methods.
 
 use crate::domains::inventory::valuation::{ValuationMethod, ValuationScopeType};
 use serde::{Deserialize, Serialize};
@@ -299,6 +299,7 @@ pub struct WarehouseLocationResponse {
 
     /// Capacity and attributes
     pub capacity_info: Option<serde_json::Value>,
+    pub max_capacity_units: Option<i64>,
     pub location_attributes: Option<serde_json::Value>,
 
     /// Status
@@ -356,6 +357,27 @@ pub struct WarehouseTreeResponse {
     pub total_locations: u32,
 }
 
+/// Outcome of merging one location into another
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LocationMergeResponse {
+    /// Location that was emptied and deactivated
+    pub from_location_id: Uuid,
+
+    /// Location that received the stock
+    pub to_location_id: Uuid,
+
+    /// Number of products moved
+    pub products_moved: u32,
+
+    /// Total quantity moved
+    pub quantity_moved: i64,
+
+    /// Transfer moves recorded for the merge, one per product
+    pub move_ids: Vec<Uuid>,
+}
+
 impl From<crate::domains::inventory::warehouse_zone::WarehouseZone> for WarehouseZoneResponse {
     fn from(zone: crate::domains::inventory::warehouse_zone::WarehouseZone) -> Self {
         Self {
@@ -391,6 +413,7 @@ impl From<crate::domains::inventory::warehouse_location::WarehouseLocation>
             coordinates: location.coordinates,
             dimensions: location.dimensions,
             capacity_info: location.capacity_info,
+            max_capacity_units: location.max_capacity_units,
             location_attributes: location.location_attributes,
             is_active: location.is_active,
             created_at: location.created_at,
@@ -0,0 +1,93 @@
+//! Tenant feature flag domain models.
+//!
+//! Feature flags are per-tenant toggles for optional behaviors. Every flag
+//! defaults to off; a tenant override is stored in `tenant_settings`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Known tenant feature flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum FeatureFlag {
+    /// Allow stock moves that drive available quantity below zero
+    NegativeStock,
+    /// Keep separate valuations per warehouse instead of per product
+    PerWarehouseValuation,
+    /// Enable delivery order pick/pack/ship for the tenant
+    DeliveryEnabled,
+    /// Hide expected quantities from counters during stock takes
+    BlindCounts,
+}
+
+impl FeatureFlag {
+    /// All known flags, in display order.
+    pub const ALL: [FeatureFlag; 4] = [
+        Self::NegativeStock,
+        Self::PerWarehouseValuation,
+        Self::DeliveryEnabled,
+        Self::BlindCounts,
+    ];
+
+    /// Convert flag to its storage key.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NegativeStock => "negative_stock",
+            Self::PerWarehouseValuation => "per_warehouse_valuation",
+            Self::DeliveryEnabled => "delivery_enabled",
+            Self::BlindCounts => "blind_counts",
+        }
+    }
+
+    /// Parse flag from its storage key.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "negative_stock" => Some(Self::NegativeStock),
+            "per_warehouse_valuation" => Some(Self::PerWarehouseValuation),
+            "delivery_enabled" => Some(Self::DeliveryEnabled),
+            "blind_counts" => Some(Self::BlindCounts),
+            _ => None,
+        }
+    }
+
+    /// Value used when the tenant has no override. All flags are off by default.
+    pub fn default_enabled(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Display for FeatureFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A tenant's stored override for a feature flag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenantFeatureFlag {
+    pub tenant_id: Uuid,
+    pub flag: FeatureFlag,
+    pub enabled: bool,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_key_round_trip() {
+        for flag in FeatureFlag::ALL {
+            assert_eq!(FeatureFlag::parse(flag.as_str()), Some(flag));
+        }
+        assert_eq!(FeatureFlag::parse("unknown_flag"), None);
+    }
+
+    #[test]
+    fn test_flags_default_off() {
+        assert!(FeatureFlag::ALL.iter().all(|f| !f.default_enabled()));
+    }
+}
@@ -1,6 +1,8 @@
 pub mod dto;
+pub mod feature_flag;
 pub mod landed_cost;
 pub mod picking_method;
+pub mod picking_route;
 pub mod product;
 pub mod product_image;
 pub mod product_variant;
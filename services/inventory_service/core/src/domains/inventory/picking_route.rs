@@ -0,0 +1,275 @@
+//! Picking route planning
+//!
+//! Orders the stops of a picking plan so the picker walks as little as
+//! possible. Locations carry optional `x`/`y`/`z` coordinates (metres) in
+//! their `coordinates` JSON; when every stop has them the route is built with
+//! a nearest-neighbour pass refined by 2-opt, otherwise stops fall back to
+//! location code order and no distance is estimated.
+
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Physical position of a location within its warehouse, in metres
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocationPoint {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl LocationPoint {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Read `x`/`y`/`z` from a location's `coordinates` JSON
+    ///
+    /// `x` and `y` are required; a missing `z` means floor level.
+    pub fn from_coordinates(coordinates: &Value) -> Option<Self> {
+        let axis = |name: &str| coordinates.get(name).and_then(Value::as_f64);
+        Some(Self::new(axis("x")?, axis("y")?, axis("z").unwrap_or(0.0)))
+    }
+
+    /// Straight-line distance to another point
+    pub fn distance_to(&self, other: &LocationPoint) -> f64 {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2))
+            .sqrt()
+    }
+}
+
+/// A location the picker has to visit
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteStop {
+    pub location_id: Uuid,
+    pub location_code: String,
+    pub point: Option<LocationPoint>,
+}
+
+/// Stops in visiting order with the estimated walking distance
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedRoute {
+    pub stops: Vec<RouteStop>,
+    /// `None` when at least one stop has no coordinates
+    pub total_distance_meters: Option<f64>,
+}
+
+/// Total distance walking the stops in the given order
+///
+/// Returns `None` if any stop lacks coordinates.
+pub fn route_distance(stops: &[RouteStop]) -> Option<f64> {
+    let points = stops
+        .iter()
+        .map(|stop| stop.point)
+        .collect::<Option<Vec<_>>>()?;
+    Some(path_distance(&points))
+}
+
+/// Order stops to reduce total travel
+///
+/// The route starts at the stop closest to the warehouse origin (where the
+/// dock is assumed to be). The result is never longer than walking the stops
+/// in location code order.
+pub fn plan_route(mut stops: Vec<RouteStop>) -> PlannedRoute {
+    stops.sort_by(|a, b| a.location_code.cmp(&b.location_code));
+
+    let Some(points) = stops
+        .iter()
+        .map(|stop| stop.point)
+        .collect::<Option<Vec<_>>>()
+    else {
+        return PlannedRoute {
+            stops,
+            total_distance_meters: None,
+        };
+    };
+
+    let code_order_distance = path_distance(&points);
+    let mut order = nearest_neighbour_order(&points);
+    two_opt(&points, &mut order);
+    let optimized: Vec<LocationPoint> = order.iter().map(|&i| points[i]).collect();
+    let optimized_distance = path_distance(&optimized);
+
+    if optimized_distance >= code_order_distance {
+        return PlannedRoute {
+            stops,
+            total_distance_meters: Some(code_order_distance),
+        };
+    }
+
+    let mut slots: Vec<Option<RouteStop>> = stops.into_iter().map(Some).collect();
+    let stops = order.iter().filter_map(|&i| slots[i].take()).collect();
+    PlannedRoute {
+        stops,
+        total_distance_meters: Some(optimized_distance),
+    }
+}
+
+fn path_distance(points: &[LocationPoint]) -> f64 {
+    points
+        .windows(2)
+        .map(|pair| pair[0].distance_to(&pair[1]))
+        .sum()
+}
+
+fn nearest_neighbour_order(points: &[LocationPoint]) -> Vec<usize> {
+    let origin = LocationPoint::new(0.0, 0.0, 0.0);
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut order = Vec::with_capacity(points.len());
+    let mut current = origin;
+
+    while !remaining.is_empty() {
+        // Ties keep the earlier (code-ordered) stop so results are deterministic
+        let (slot, _) = remaining.iter().enumerate().fold(
+            (0, f64::INFINITY),
+            |(best_slot, best_distance), (slot, &index)| {
+                let distance = current.distance_to(&points[index]);
+                if distance < best_distance {
+                    (slot, distance)
+                } else {
+                    (best_slot, best_distance)
+                }
+            },
+        );
+        let next = remaining.remove(slot);
+        current = points[next];
+        order.push(next);
+    }
+
+    order
+}
+
+/// Reverse segments of an open path while that shortens it
+fn two_opt(points: &[LocationPoint], order: &mut [usize]) {
+    let n = order.len();
+    if n < 4 {
+        return;
+    }
+
+    // Distance from `from` to the stop after the segment, zero at the path end
+    let leg_out = |from: usize, next: Option<usize>| {
+        next.map_or(0.0, |next| points[from].distance_to(&points[next]))
+    };
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 1..n - 1 {
+            for j in i + 1..n {
+                let next = order.get(j + 1).copied();
+                let before =
+                    points[order[i - 1]].distance_to(&points[order[i]]) + leg_out(order[j], next);
+                let after =
+                    points[order[i - 1]].distance_to(&points[order[j]]) + leg_out(order[i], next);
+                if after + 1e-9 < before {
+                    order[i..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn stop(code: &str, point: Option<(f64, f64)>) -> RouteStop {
+        RouteStop {
+            location_id: Uuid::now_v7(),
+            location_code: code.to_string(),
+            point: point.map(|(x, y)| LocationPoint::new(x, y, 0.0)),
+        }
+    }
+
+    fn codes(route: &PlannedRoute) -> Vec<&str> {
+        route
+            .stops
+            .iter()
+            .map(|stop| stop.location_code.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_point_from_coordinates_json() {
+        let point = LocationPoint::from_coordinates(&json!({"aisle": "A", "x": 3, "y": 4.5}));
+        assert_eq!(point, Some(LocationPoint::new(3.0, 4.5, 0.0)));
+
+        assert_eq!(LocationPoint::from_coordinates(&json!({"aisle": "A", "x": 3})), None);
+        assert_eq!(LocationPoint::from_coordinates(&json!({"x": "3", "y": 4})), None);
+    }
+
+    #[test]
+    fn test_optimized_route_beats_code_order() {
+        // Codes alternate between the two ends of the warehouse
+        let stops = vec![
+            stop("A-01", Some((0.0, 0.0))),
+            stop("A-02", Some((50.0, 0.0))),
+            stop("A-03", Some((1.0, 0.0))),
+            stop("A-04", Some((51.0, 0.0))),
+            stop("A-05", Some((2.0, 0.0))),
+        ];
+        let naive = route_distance(&stops).unwrap();
+        assert_eq!(naive, 50.0 + 49.0 + 50.0 + 49.0);
+
+        let route = plan_route(stops);
+        let optimized = route.total_distance_meters.unwrap();
+        assert_eq!(codes(&route), vec!["A-01", "A-03", "A-05", "A-02", "A-04"]);
+        assert_eq!(optimized, 51.0);
+        assert!(optimized < naive);
+        assert_eq!(route_distance(&route.stops), Some(optimized));
+    }
+
+    #[test]
+    fn test_grid_route_visits_near_corner_first() {
+        // Code order zig-zags across the square; the planned route walks its edge
+        let stops = vec![
+            stop("L1", Some((0.0, 0.0))),
+            stop("L2", Some((10.0, 10.0))),
+            stop("L3", Some((0.0, 10.0))),
+            stop("L4", Some((10.0, 0.0))),
+            stop("L5", Some((1.0, 1.0))),
+        ];
+        let naive = route_distance(&stops).unwrap();
+
+        let route = plan_route(stops);
+        let optimized = route.total_distance_meters.unwrap();
+        assert!(optimized < naive);
+        assert!(optimized <= 1.0_f64.hypot(1.0) + 9.0_f64.hypot(1.0) + 10.0 + 10.0 + 1e-9);
+    }
+
+    #[test]
+    fn test_missing_coordinates_fall_back_to_code_order() {
+        let stops = vec![
+            stop("B-02", Some((0.0, 0.0))),
+            stop("A-01", None),
+            stop("B-01", Some((5.0, 0.0))),
+        ];
+
+        let route = plan_route(stops);
+        assert_eq!(codes(&route), vec!["A-01", "B-01", "B-02"]);
+        assert_eq!(route.total_distance_meters, None);
+    }
+
+    #[test]
+    fn test_route_is_never_longer_than_code_order() {
+        let stops = vec![
+            stop("A-01", Some((0.0, 0.0))),
+            stop("A-02", Some((1.0, 0.0))),
+            stop("A-03", Some((2.0, 0.0))),
+        ];
+
+        let route = plan_route(stops);
+        assert_eq!(codes(&route), vec!["A-01", "A-02", "A-03"]);
+        assert_eq!(route.total_distance_meters, Some(2.0));
+    }
+
+    #[test]
+    fn test_empty_and_single_stop_routes() {
+        assert_eq!(plan_route(Vec::new()).total_distance_meters, Some(0.0));
+
+        let route = plan_route(vec![stop("A-01", Some((4.0, 2.0)))]);
+        assert_eq!(route.stops.len(), 1);
+        assert_eq!(route.total_distance_meters, Some(0.0));
+    }
+}
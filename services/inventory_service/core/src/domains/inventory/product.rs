@@ -323,6 +323,12 @@ pub struct Product {
     pub is_sellable: bool,
     pub is_purchaseable: bool,
 
+    /// Reservation over-commit buffer, as a percentage of on-hand stock
+    /// that may be reserved beyond availability (backordered)
+    #[serde(default)]
+    #[validate(range(min = 0, max = 100))]
+    pub overcommit_pct: i32,
+
     /// Audit fields
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -361,6 +367,7 @@ impl Product {
             is_active: true,
             is_sellable: true,
             is_purchaseable: true,
+            overcommit_pct: 0,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             deleted_at: None,
@@ -452,6 +459,7 @@ mod openapi {
         pub is_active: bool,
         pub is_sellable: bool,
         pub is_purchaseable: bool,
+        pub overcommit_pct: i32,
 
         /// Audit fields
         pub created_at: DateTime<Utc>,
@@ -483,6 +491,7 @@ mod openapi {
                 is_active: product.is_active,
                 is_sellable: product.is_sellable,
                 is_purchaseable: product.is_purchaseable,
+                overcommit_pct: product.overcommit_pct,
                 created_at: product.created_at,
                 updated_at: product.updated_at,
             }
@@ -46,6 +46,8 @@ pub struct Transfer {
     pub carrier: Option<String>,
     /// Tracking number
     pub tracking_number: Option<String>,
+    /// Estimated arrival at the destination (carrier ETA)
+    pub expected_arrival: Option<DateTime<Utc>>,
     /// Shipping cost in cents
     pub shipping_cost: Option<i64>,
     /// Additional notes
@@ -1,10 +1,23 @@
 //! Valuation domain entities
 //!
 //! Core business entities for inventory valuation system,
-//! supporting FIFO, AVCO, and Standard costing methods.
+//! supporting FIFO, LIFO, AVCO, and Standard costing methods.
 //!
-//! Note: LIFO is intentionally not supported as it is prohibited
-//! by IFRS and most accounting standards outside the US.
+//! Note: LIFO is prohibited by IFRS and most accounting standards outside
+//! the US; it is offered for product lines in jurisdictions that permit it.
+//!
+//! # AVCO precision
+//!
+//! Amounts are whole cents. For AVCO the product's `total_value` is the
+//! authoritative figure: receipts add exactly `quantity * unit_cost`, and a
+//! delivery issues its proportional share `total_value * quantity / on_hand`
+//! rounded with a [`RoundingPolicy`]. Delivering the whole stock on hand issues
+//! the whole remaining value, so rounding remainders are carried forward and
+//! released on the final issue instead of being lost. `current_unit_cost` is
+//! derived from `total_value / total_quantity` with the same policy and is
+//! informational only.
+
+use std::collections::VecDeque;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -17,6 +30,8 @@ use uuid::Uuid;
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum ValuationMethod {
     Fifo,
+    /// Cost layers consumed newest first
+    Lifo,
     Avco,
     Standard,
 }
@@ -30,6 +45,7 @@ impl From<String> for ValuationMethod {
     fn from(s: String) -> Self {
         match s.to_lowercase().as_str() {
             "fifo" => ValuationMethod::Fifo,
+            "lifo" => ValuationMethod::Lifo,
             "avco" => ValuationMethod::Avco,
             "standard" => ValuationMethod::Standard,
             _ => panic!(
@@ -235,12 +251,135 @@ impl Valuation {
     /// Get current unit cost based on method
     pub fn get_current_unit_cost(&self) -> Option<i64> {
         match self.valuation_method {
-            ValuationMethod::Fifo | ValuationMethod::Avco => self.current_unit_cost,
+            ValuationMethod::Fifo | ValuationMethod::Lifo | ValuationMethod::Avco => {
+                self.current_unit_cost
+            },
             ValuationMethod::Standard => self.standard_cost,
         }
     }
 }
 
+/// Rounding applied when an amount in cents is divided
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingPolicy {
+    /// Round half away from zero
+    #[default]
+    HalfUp,
+    /// Round half to even (banker's rounding)
+    HalfEven,
+    /// Round toward zero
+    Truncate,
+}
+
+impl RoundingPolicy {
+    /// Divide `numerator` by `denominator` with this policy.
+    /// Returns `None` for a zero denominator or a result outside `i64`.
+    pub fn divide(self, numerator: i128, denominator: i128) -> Option<i64> {
+        if denominator == 0 {
+            return None;
+        }
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        let step = if (numerator < 0) == (denominator < 0) {
+            1
+        } else {
+            -1
+        };
+        let twice_remainder = remainder.abs() * 2;
+        let round_away = match self {
+            RoundingPolicy::Truncate => false,
+            RoundingPolicy::HalfUp => twice_remainder >= denominator.abs(),
+            RoundingPolicy::HalfEven => {
+                twice_remainder > denominator.abs()
+                    || (twice_remainder == denominator.abs() && quotient % 2 != 0)
+            },
+        };
+        let rounded = if remainder != 0 && round_away {
+            quotient + step
+        } else {
+            quotient
+        };
+        i64::try_from(rounded).ok()
+    }
+}
+
+impl Valuation {
+    /// Quantity on hand after a stock movement
+    ///
+    /// # Errors
+    /// Returns a description naming the product and the shortfall when a
+    /// delivery exceeds the quantity on hand, or when the sum overflows.
+    pub fn quantity_after_move(&self, quantity_change: i64) -> Result<i64, String> {
+        let new_quantity = self
+            .total_quantity
+            .checked_add(quantity_change)
+            .ok_or_else(|| format!("Product {} quantity overflows", self.product_id))?;
+        if new_quantity < 0 {
+            return Err(format!(
+                "Insufficient stock to value delivery of product {}: {} on hand, {} requested, short by {}",
+                self.product_id,
+                self.total_quantity,
+                -quantity_change,
+                -new_quantity
+            ));
+        }
+        Ok(new_quantity)
+    }
+
+    /// AVCO value issued for an outgoing `quantity`.
+    ///
+    /// The proportional share of `total_value`, rounded with `policy`. Issuing all
+    /// of the stock on hand takes the whole remaining value; anything beyond it
+    /// (negative stock) is valued at the current unit cost. Returns `None` on overflow.
+    pub fn avco_issue_value(&self, quantity: i64, policy: RoundingPolicy) -> Option<i64> {
+        let unit_cost = self.current_unit_cost.unwrap_or(0);
+        if self.total_quantity <= 0 {
+            return unit_cost.checked_mul(quantity);
+        }
+        if quantity >= self.total_quantity {
+            let excess = unit_cost.checked_mul(quantity - self.total_quantity)?;
+            return self.total_value.checked_add(excess);
+        }
+        policy.divide(
+            i128::from(self.total_value) * i128::from(quantity),
+            i128::from(self.total_quantity),
+        )
+    }
+
+    /// AVCO state after a stock movement as `(total_quantity, total_value, current_unit_cost)`.
+    ///
+    /// `quantity_change` is positive for receipts (valued at `unit_cost`) and
+    /// negative for deliveries (valued by [`Valuation::avco_issue_value`]).
+    /// Returns `None` on overflow.
+    pub fn avco_after_move(
+        &self,
+        quantity_change: i64,
+        unit_cost: Option<i64>,
+        policy: RoundingPolicy,
+    ) -> Option<(i64, i64, Option<i64>)> {
+        let new_quantity = self.total_quantity.checked_add(quantity_change)?;
+        if new_quantity == 0 {
+            return Some((0, 0, None));
+        }
+
+        let new_value = if quantity_change > 0 {
+            let receipt_value = unit_cost.unwrap_or(0).checked_mul(quantity_change)?;
+            self.total_value.checked_add(receipt_value)?
+        } else {
+            let issued = self.avco_issue_value(quantity_change.checked_neg()?, policy)?;
+            self.total_value.checked_sub(issued)?
+        };
+
+        let new_unit_cost = if new_quantity > 0 {
+            Some(policy.divide(i128::from(new_value), i128::from(new_quantity))?)
+        } else {
+            self.current_unit_cost
+        };
+        Some((new_quantity, new_value, new_unit_cost))
+    }
+}
+
 /// Cost layer entity for FIFO valuation tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValuationLayer {
@@ -304,6 +443,161 @@ impl ValuationLayer {
     }
 }
 
+/// Quantity taken from one cost layer when stock is issued
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerConsumption {
+    /// Cost layer the quantity was taken from
+    pub layer_id: Uuid,
+    /// Quantity taken from the layer
+    pub quantity_taken: i64,
+    /// The layer's cost per unit in cents
+    pub unit_cost: i64,
+}
+
+/// A stock move replayed when rebuilding FIFO cost layers
+#[derive(Debug, Clone)]
+pub struct CostedMove {
+    pub move_id: Uuid,
+    /// Change to the product's total stock (positive for inflows)
+    pub quantity_change: i64,
+    pub unit_cost: Option<i64>,
+    pub moved_at: DateTime<Utc>,
+}
+
+impl CostedMove {
+    /// Build a replay entry from a stock move's locations and quantity
+    ///
+    /// A move between two locations stays inside the company and does not
+    /// change stock on hand. A move with only a destination brings `|quantity|`
+    /// in, one with only a source takes it out, and any other move (such as an
+    /// adjustment without locations) changes stock by its signed quantity.
+    pub fn from_move(
+        move_id: Uuid,
+        source_location_id: Option<Uuid>,
+        destination_location_id: Option<Uuid>,
+        quantity: i64,
+        unit_cost: Option<i64>,
+        moved_at: DateTime<Utc>,
+    ) -> Self {
+        let quantity_change = match (source_location_id, destination_location_id) {
+            (Some(source), Some(destination)) if source != destination => 0,
+            (None, Some(_)) => quantity.abs(),
+            (Some(_), None) => -quantity.abs(),
+            _ => quantity,
+        };
+        Self {
+            move_id,
+            quantity_change,
+            unit_cost,
+            moved_at,
+        }
+    }
+}
+
+/// Replay stock moves, oldest first, into FIFO cost layers
+///
+/// Inflows open a layer at the move's unit cost, dated at the move so the
+/// rebuilt layers keep their FIFO order; outflows consume the oldest layers
+/// first. Returns the layers still holding stock.
+///
+/// # Errors
+/// Returns a description of the offending move when an inflow has no unit
+/// cost, an outflow exceeds the stock received before it, or a value overflows.
+pub fn replay_fifo_layers(
+    tenant_id: Uuid,
+    product_id: Uuid,
+    moves: &[CostedMove],
+) -> Result<Vec<ValuationLayer>, String> {
+    let mut layers: VecDeque<ValuationLayer> = VecDeque::new();
+
+    for stock_move in moves {
+        if stock_move.quantity_change > 0 {
+            let unit_cost = stock_move.unit_cost.ok_or_else(|| {
+                format!("Stock move {} adds stock without a unit cost", stock_move.move_id)
+            })?;
+            let total_value = unit_cost
+                .checked_mul(stock_move.quantity_change)
+                .ok_or_else(|| format!("Stock move {} value overflows", stock_move.move_id))?;
+            layers.push_back(ValuationLayer {
+                layer_id: Uuid::now_v7(),
+                tenant_id,
+                product_id,
+                quantity: stock_move.quantity_change,
+                unit_cost,
+                total_value,
+                created_at: stock_move.moved_at,
+                updated_at: stock_move.moved_at,
+            });
+            continue;
+        }
+
+        let mut remaining = -stock_move.quantity_change;
+        while remaining > 0 {
+            let layer = layers.front_mut().ok_or_else(|| {
+                format!(
+                    "Stock move {} issues {} units more than was received before it",
+                    stock_move.move_id, remaining
+                )
+            })?;
+            remaining -= layer.consume(remaining);
+            if layer.is_empty() {
+                layers.pop_front();
+            }
+        }
+    }
+
+    Ok(layers.into())
+}
+
+/// Replay stock moves, oldest first, into an AVCO running average
+///
+/// Receipts add their quantity at the move's unit cost and deliveries issue
+/// their share of the value on hand, exactly as live moves are valued.
+/// Returns `(total_quantity, total_value, current_unit_cost)`.
+///
+/// # Errors
+/// Returns a description of the offending move when a receipt has no unit
+/// cost, a delivery exceeds the stock received before it, or a value overflows.
+pub fn replay_avco(
+    tenant_id: Uuid,
+    product_id: Uuid,
+    moves: &[CostedMove],
+    policy: RoundingPolicy,
+) -> Result<(i64, i64, Option<i64>), String> {
+    let mut running = Valuation::new(tenant_id, product_id, ValuationMethod::Avco);
+
+    for stock_move in moves {
+        if stock_move.quantity_change == 0 {
+            continue;
+        }
+        if stock_move.quantity_change > 0 && stock_move.unit_cost.is_none() {
+            return Err(format!(
+                "Stock move {} adds stock without a unit cost",
+                stock_move.move_id
+            ));
+        }
+        if running
+            .quantity_after_move(stock_move.quantity_change)
+            .is_err()
+        {
+            return Err(format!(
+                "Stock move {} issues {} units more than was received before it",
+                stock_move.move_id,
+                -stock_move.quantity_change - running.total_quantity
+            ));
+        }
+
+        let (total_quantity, total_value, unit_cost) = running
+            .avco_after_move(stock_move.quantity_change, stock_move.unit_cost, policy)
+            .ok_or_else(|| format!("Stock move {} value overflows", stock_move.move_id))?;
+        running.total_quantity = total_quantity;
+        running.total_value = total_value;
+        running.current_unit_cost = unit_cost;
+    }
+
+    Ok((running.total_quantity, running.total_value, running.current_unit_cost))
+}
+
 /// Historical valuation record for audit trail
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValuationHistory {
@@ -421,3 +715,182 @@ mod openapi {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn avco(total_quantity: i64, total_value: i64, unit_cost: Option<i64>) -> Valuation {
+        let mut valuation = Valuation::new(Uuid::now_v7(), Uuid::now_v7(), ValuationMethod::Avco);
+        valuation.update(unit_cost, total_quantity, total_value, None);
+        valuation
+    }
+
+    #[test]
+    fn test_delivery_beyond_stock_on_hand_is_rejected() {
+        for method in [ValuationMethod::Avco, ValuationMethod::Standard] {
+            let mut valuation = Valuation::new(Uuid::now_v7(), Uuid::now_v7(), method.clone());
+            valuation.update(Some(100), 10, 1_000, None);
+
+            let err = valuation.quantity_after_move(-15).unwrap_err();
+            assert!(err.contains(&valuation.product_id.to_string()), "{:?}: {}", method, err);
+            assert!(err.contains("short by 5"), "{:?}: {}", method, err);
+
+            assert_eq!(valuation.quantity_after_move(-10), Ok(0));
+            assert_eq!(valuation.quantity_after_move(5), Ok(15));
+        }
+    }
+
+    #[test]
+    fn test_rounding_policy_divide() {
+        assert_eq!(RoundingPolicy::Truncate.divide(7, 2), Some(3));
+        assert_eq!(RoundingPolicy::HalfUp.divide(7, 2), Some(4));
+        assert_eq!(RoundingPolicy::HalfUp.divide(-7, 2), Some(-4));
+        assert_eq!(RoundingPolicy::HalfEven.divide(5, 2), Some(2));
+        assert_eq!(RoundingPolicy::HalfEven.divide(7, 2), Some(4));
+        assert_eq!(RoundingPolicy::HalfUp.divide(10, 3), Some(3));
+        assert_eq!(RoundingPolicy::HalfUp.divide(1, 0), None);
+    }
+
+    #[test]
+    fn test_avco_issue_all_takes_remaining_value() {
+        // 3 units worth 100 cents: no exact unit cost
+        let valuation = avco(3, 100, Some(33));
+
+        assert_eq!(valuation.avco_issue_value(1, RoundingPolicy::HalfUp), Some(33));
+        assert_eq!(valuation.avco_issue_value(2, RoundingPolicy::HalfUp), Some(67));
+        assert_eq!(valuation.avco_issue_value(3, RoundingPolicy::HalfUp), Some(100));
+    }
+
+    #[test]
+    fn test_avco_long_sequence_conserves_value() {
+        for policy in [
+            RoundingPolicy::HalfUp,
+            RoundingPolicy::HalfEven,
+            RoundingPolicy::Truncate,
+        ] {
+            let mut valuation = avco(0, 0, None);
+            let mut received = 0i64;
+            let mut issued = 0i64;
+
+            for step in 0..500i64 {
+                let (quantity_change, unit_cost) = if step % 3 == 2 {
+                    (-(step % 4 + 1), None)
+                } else {
+                    (step % 7 + 1, Some(97 + step % 13))
+                };
+                let (quantity, value, cost) = valuation
+                    .avco_after_move(quantity_change, unit_cost, policy)
+                    .unwrap();
+                if quantity_change > 0 {
+                    received += quantity_change * unit_cost.unwrap();
+                } else {
+                    // Each issue is within a cent of its exact proportional share
+                    let issue = valuation.total_value - value;
+                    let exact_scaled = valuation.total_value * -quantity_change;
+                    assert!(
+                        (issue * valuation.total_quantity - exact_scaled).abs()
+                            < valuation.total_quantity
+                    );
+                    issued += issue;
+                }
+                valuation.update(cost, quantity, value, None);
+
+                assert!(valuation.total_quantity >= 0);
+                assert_eq!(valuation.total_value, received - issued);
+            }
+
+            // Draining the stock issues exactly what is left
+            let (quantity, value, cost) = valuation
+                .avco_after_move(-valuation.total_quantity, None, policy)
+                .unwrap();
+            issued += valuation.total_value - value;
+            assert_eq!((quantity, value, cost), (0, 0, None));
+            assert_eq!(issued, received, "{:?} lost value", policy);
+        }
+    }
+
+    fn costed(quantity_change: i64, unit_cost: Option<i64>) -> CostedMove {
+        CostedMove {
+            move_id: Uuid::now_v7(),
+            quantity_change,
+            unit_cost,
+            moved_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_costed_move_quantity_change_from_locations() {
+        let (a, b) = (Some(Uuid::now_v7()), Some(Uuid::now_v7()));
+        let change = |source, destination, quantity| {
+            CostedMove::from_move(Uuid::now_v7(), source, destination, quantity, None, Utc::now())
+                .quantity_change
+        };
+
+        assert_eq!(change(None, a, 10), 10);
+        assert_eq!(change(a, None, 10), -10);
+        assert_eq!(change(a, None, -10), -10);
+        assert_eq!(change(a, b, 10), 0);
+        assert_eq!(change(None, None, -4), -4);
+    }
+
+    #[test]
+    fn test_replay_fifo_layers_consumes_oldest_first() {
+        let moves = vec![
+            costed(50, Some(1000)),
+            costed(30, Some(1200)),
+            costed(-60, None),
+            costed(0, None),
+            costed(20, Some(1100)),
+        ];
+
+        let layers = replay_fifo_layers(Uuid::now_v7(), Uuid::now_v7(), &moves).unwrap();
+
+        let remaining: Vec<_> = layers.iter().map(|l| (l.quantity, l.unit_cost)).collect();
+        assert_eq!(remaining, vec![(20, 1200), (20, 1100)]);
+        assert_eq!(layers.iter().map(|l| l.total_value).sum::<i64>(), 46_000);
+    }
+
+    #[test]
+    fn test_replay_fifo_layers_rejects_uncosted_inflow() {
+        let result = replay_fifo_layers(Uuid::now_v7(), Uuid::now_v7(), &[costed(5, None)]);
+        assert!(result.unwrap_err().contains("without a unit cost"));
+    }
+
+    #[test]
+    fn test_replay_fifo_layers_rejects_overissue() {
+        let moves = vec![costed(5, Some(100)), costed(-8, None)];
+        let result = replay_fifo_layers(Uuid::now_v7(), Uuid::now_v7(), &moves);
+        assert!(result.unwrap_err().contains("3 units more"));
+    }
+
+    #[test]
+    fn test_replay_avco_recomputes_running_average() {
+        let moves = vec![
+            costed(10, Some(100)),
+            costed(10, Some(200)),
+            costed(-5, None),
+            costed(0, None),
+            costed(5, Some(300)),
+        ];
+
+        let replayed =
+            replay_avco(Uuid::now_v7(), Uuid::now_v7(), &moves, RoundingPolicy::Truncate).unwrap();
+
+        // 20 @ 150, issue 5 (750), then 5 @ 300: 20 units worth 3750
+        assert_eq!(replayed, (20, 3_750, Some(187)));
+    }
+
+    #[test]
+    fn test_replay_avco_rejects_uncosted_receipt_and_overissue() {
+        let (tenant_id, product_id) = (Uuid::now_v7(), Uuid::now_v7());
+        let policy = RoundingPolicy::Truncate;
+
+        let result = replay_avco(tenant_id, product_id, &[costed(5, None)], policy);
+        assert!(result.unwrap_err().contains("without a unit cost"));
+
+        let moves = vec![costed(5, Some(100)), costed(-8, None)];
+        let result = replay_avco(tenant_id, product_id, &moves, policy);
+        assert!(result.unwrap_err().contains("3 units more"));
+    }
+}
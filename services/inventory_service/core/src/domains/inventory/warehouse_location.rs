@@ -4,6 +4,7 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::domains::inventory::dto::common::validate_location_type;
+use crate::domains::inventory::picking_route::LocationPoint;
 use crate::domains::inventory::BaseEntity;
 
 /// Warehouse location domain entity representing storage positions within warehouses
@@ -38,6 +39,9 @@ pub struct WarehouseLocation {
     /// Capacity and operational data
     pub capacity_info: Option<serde_json::Value>,
 
+    /// Maximum units the location can hold (None means unlimited)
+    pub max_capacity_units: Option<i64>,
+
     /// Location properties
     pub location_attributes: Option<serde_json::Value>,
 
@@ -123,6 +127,7 @@ impl WarehouseLocation {
             coordinates: None,
             dimensions: None,
             capacity_info: None,
+            max_capacity_units: None,
             location_attributes: None,
             is_active: true,
             created_at: Utc::now(),
@@ -149,6 +154,13 @@ impl WarehouseLocation {
     pub fn has_zone(&self) -> bool {
         self.zone_id.is_some()
     }
+
+    /// Physical `x`/`y`/`z` position, if the coordinates include one
+    pub fn point(&self) -> Option<LocationPoint> {
+        self.coordinates
+            .as_ref()
+            .and_then(LocationPoint::from_coordinates)
+    }
 }
 
 #[cfg(feature = "openapi")]
@@ -185,6 +197,9 @@ mod openapi {
         /// Capacity and operational data
         pub capacity_info: Option<serde_json::Value>,
 
+        /// Maximum units the location can hold (None means unlimited)
+        pub max_capacity_units: Option<i64>,
+
         /// Location properties
         pub location_attributes: Option<serde_json::Value>,
 
@@ -210,6 +225,7 @@ mod openapi {
                 coordinates: location.coordinates,
                 dimensions: location.dimensions,
                 capacity_info: location.capacity_info,
+                max_capacity_units: location.max_capacity_units,
                 location_attributes: location.location_attributes,
                 is_active: location.is_active,
                 created_at: location.created_at,
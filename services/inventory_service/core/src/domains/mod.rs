@@ -10,5 +10,6 @@ pub mod replenishment;
 // Re-export main types for convenience
 pub use category::{Category, CategoryBreadcrumb, CategoryNode};
 pub use quality::{
-    CreateQualityControlPoint, QcPointType, QualityControlPoint, UpdateQualityControlPoint,
+    CreateQualityControlPoint, QcPointType, QcStatus, QualityCheck, QualityControlPoint,
+    RecordQualityCheckResult, UpdateQualityControlPoint,
 };
@@ -45,3 +45,40 @@ pub struct UpdateQualityControlPoint {
     pub warehouse_id: Option<Uuid>,
     pub active: Option<bool>,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[sqlx(type_name = "qc_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum QcStatus {
+    Pending,
+    Passed,
+    Failed,
+}
+
+/// Inspection of received stock held back by an incoming QC point
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct QualityCheck {
+    pub qc_id: Uuid,
+    pub tenant_id: Uuid,
+    pub qc_point_id: Uuid,
+    pub reference_type: String,
+    pub reference_id: Uuid,
+    pub product_id: Uuid,
+    pub warehouse_id: Option<Uuid>,
+    pub quantity: i64,
+    pub status: QcStatus,
+    pub inspector_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Result recorded by an inspector for a pending quality check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RecordQualityCheckResult {
+    pub passed: bool,
+    pub notes: Option<String>,
+}
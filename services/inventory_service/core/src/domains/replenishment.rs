@@ -4,6 +4,11 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domains::inventory::dto::transfer_dto::{
+    CreateTransferItemRequest, CreateTransferRequest,
+};
+use crate::domains::inventory::transfer::{TransferPriority, TransferType};
+
 /// Reorder rule for automated stock replenishment
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -60,3 +65,191 @@ pub struct ReplenishmentCheckResult {
     pub needs_replenishment: bool,
     pub action_taken: Option<String>,
 }
+
+/// Rule for resupplying one warehouse from another (e.g. a store from a central DC)
+/// before falling back to external reorders.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InternalReplenishmentRule {
+    pub rule_id: Uuid,
+    pub tenant_id: Uuid,
+    pub product_id: Uuid,
+    /// Warehouse that supplies the stock
+    pub from_warehouse_id: Uuid,
+    /// Warehouse being resupplied
+    pub to_warehouse_id: Uuid,
+    /// Destination level below which a transfer is proposed
+    pub min_quantity: i64,
+    /// Destination level a transfer tops up to
+    pub max_quantity: i64,
+    /// Quantity the source always keeps for itself
+    pub source_safety_stock: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl InternalReplenishmentRule {
+    /// Quantity to move from the source, if the destination is below its minimum
+    /// and the source holds stock beyond its safety stock.
+    ///
+    /// Tops the destination up to `max_quantity`, capped by the source surplus.
+    pub fn suggested_transfer_quantity(
+        &self,
+        destination_quantity: i64,
+        source_quantity: i64,
+    ) -> Option<i64> {
+        if destination_quantity >= self.min_quantity {
+            return None;
+        }
+        let shortfall = self.max_quantity.saturating_sub(destination_quantity);
+        let surplus = source_quantity.saturating_sub(self.source_safety_stock);
+        let quantity = shortfall.min(surplus);
+        (quantity > 0).then_some(quantity)
+    }
+}
+
+/// DTO for creating an internal replenishment rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CreateInternalReplenishmentRule {
+    pub product_id: Uuid,
+    pub from_warehouse_id: Uuid,
+    pub to_warehouse_id: Uuid,
+    pub min_quantity: i64,
+    pub max_quantity: i64,
+    #[serde(default)]
+    pub source_safety_stock: i64,
+}
+
+/// Proposed warehouse-to-warehouse transfer for a low destination
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InternalTransferSuggestion {
+    pub rule_id: Uuid,
+    pub product_id: Uuid,
+    pub from_warehouse_id: Uuid,
+    pub to_warehouse_id: Uuid,
+    pub destination_quantity: i64,
+    pub source_quantity: i64,
+    pub suggested_quantity: i64,
+}
+
+/// Build one draft auto-replenishment transfer per (source, destination) pair.
+pub fn draft_transfer_requests(
+    suggestions: &[InternalTransferSuggestion],
+) -> Vec<CreateTransferRequest> {
+    let mut requests: Vec<CreateTransferRequest> = Vec::new();
+    for suggestion in suggestions {
+        let request = match requests.iter_mut().find(|r| {
+            r.source_warehouse_id == suggestion.from_warehouse_id
+                && r.destination_warehouse_id == suggestion.to_warehouse_id
+        }) {
+            Some(request) => request,
+            None => {
+                requests.push(CreateTransferRequest {
+                    reference_number: None,
+                    source_warehouse_id: suggestion.from_warehouse_id,
+                    destination_warehouse_id: suggestion.to_warehouse_id,
+                    transfer_type: TransferType::AutoReplenishment,
+                    priority: TransferPriority::Normal,
+                    expected_ship_date: None,
+                    expected_receive_date: None,
+                    shipping_method: None,
+                    notes: None,
+                    reason: Some("Internal replenishment".to_string()),
+                    items: Vec::new(),
+                });
+                requests.last_mut().expect("request was just pushed")
+            },
+        };
+        let line_number = request.items.len() as i32 + 1;
+        request.items.push(CreateTransferItemRequest {
+            product_id: suggestion.product_id,
+            quantity: suggestion.suggested_quantity,
+            uom_id: None,
+            unit_cost: None,
+            line_number,
+            source_zone_id: None,
+            source_location_id: None,
+            destination_zone_id: None,
+            destination_location_id: None,
+            notes: None,
+        });
+    }
+    requests
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn internal_rule(
+        min_quantity: i64,
+        max_quantity: i64,
+        source_safety_stock: i64,
+    ) -> InternalReplenishmentRule {
+        InternalReplenishmentRule {
+            rule_id: Uuid::now_v7(),
+            tenant_id: Uuid::now_v7(),
+            product_id: Uuid::now_v7(),
+            from_warehouse_id: Uuid::now_v7(),
+            to_warehouse_id: Uuid::now_v7(),
+            min_quantity,
+            max_quantity,
+            source_safety_stock,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_suggested_transfer_quantity() {
+        let rule = internal_rule(20, 100, 50);
+
+        // Destination at or above min: nothing to do
+        assert_eq!(rule.suggested_transfer_quantity(20, 500), None);
+        // Tops up to max when the source has plenty
+        assert_eq!(rule.suggested_transfer_quantity(5, 500), Some(95));
+        // Capped by what the source can spare
+        assert_eq!(rule.suggested_transfer_quantity(5, 80), Some(30));
+        // Source at or below its safety stock has no surplus
+        assert_eq!(rule.suggested_transfer_quantity(5, 50), None);
+        assert_eq!(rule.suggested_transfer_quantity(5, 10), None);
+    }
+
+    #[test]
+    fn test_draft_transfer_requests_group_by_route() {
+        let from = Uuid::now_v7();
+        let to = Uuid::now_v7();
+        let other_to = Uuid::now_v7();
+        let suggestion = |to_warehouse_id, suggested_quantity| InternalTransferSuggestion {
+            rule_id: Uuid::now_v7(),
+            product_id: Uuid::now_v7(),
+            from_warehouse_id: from,
+            to_warehouse_id,
+            destination_quantity: 0,
+            source_quantity: 100,
+            suggested_quantity,
+        };
+
+        let requests = draft_transfer_requests(&[
+            suggestion(to, 5),
+            suggestion(other_to, 7),
+            suggestion(to, 3),
+        ]);
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].destination_warehouse_id, to);
+        assert_eq!(requests[0].transfer_type, TransferType::AutoReplenishment);
+        let lines: Vec<(i32, i64)> = requests[0]
+            .items
+            .iter()
+            .map(|item| (item.line_number, item.quantity))
+            .collect();
+        assert_eq!(lines, vec![(1, 5), (2, 3)]);
+        assert_eq!(requests[1].destination_warehouse_id, other_to);
+        assert_eq!(requests[1].items.len(), 1);
+    }
+}
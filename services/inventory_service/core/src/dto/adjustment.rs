@@ -256,6 +256,27 @@ pub struct PostAdjustmentRequest {
     pub idempotency_key: Option<String>,
 }
 
+/// Request to adjust stock immediately, without a draft document
+///
+/// Used for manual corrections: the delta is applied to the product's level in
+/// the warehouse (or a specific location) and recorded as an adjustment move.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct QuickAdjustmentRequest {
+    /// Warehouse holding the stock
+    pub warehouse_id: Uuid,
+    /// Optional location within warehouse (warehouse-level stock when omitted)
+    pub location_id: Option<Uuid>,
+    /// Product being adjusted
+    pub product_id: Uuid,
+    /// Signed quantity change (positive adds stock, negative removes it)
+    pub delta: i64,
+    /// Reason code
+    pub reason_code: AdjustmentReasonCode,
+    /// Free-text note explaining the correction
+    pub note: Option<String>,
+}
+
 // ============================================================================
 // Response DTOs
 // ============================================================================
@@ -278,6 +299,30 @@ pub struct AdjustmentDocumentWithLinesResponse {
     pub lines: Vec<AdjustmentLine>,
 }
 
+/// Result of an immediate stock adjustment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct QuickAdjustmentResponse {
+    /// Stock move recording the adjustment
+    pub move_id: Uuid,
+    /// Product adjusted
+    pub product_id: Uuid,
+    /// Warehouse adjusted
+    pub warehouse_id: Uuid,
+    /// Location adjusted, if any
+    pub location_id: Option<Uuid>,
+    /// Signed quantity change applied
+    pub delta: i64,
+    /// Reason code
+    pub reason_code: AdjustmentReasonCode,
+    /// Available quantity after the adjustment
+    pub available_quantity: i64,
+    /// User who made the adjustment
+    pub adjusted_by: Uuid,
+    /// When the adjustment was applied
+    pub adjusted_at: DateTime<Utc>,
+}
+
 /// Query parameters for listing adjustment documents
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[cfg_attr(feature = "openapi", derive(IntoParams))]
@@ -360,6 +405,19 @@ pub fn validate_adjustment_line(
     Ok(())
 }
 
+/// Validate an immediate stock adjustment
+pub fn validate_quick_adjustment(
+    request: &QuickAdjustmentRequest,
+) -> Result<(), AdjustmentValidationError> {
+    if request.delta == 0 {
+        return Err(AdjustmentValidationError {
+            field: "delta".to_string(),
+            message: "Delta must not be zero".to_string(),
+        });
+    }
+    Ok(())
+}
+
 /// Validate status transition
 pub fn validate_status_transition(
     current: AdjustmentStatus,
@@ -420,6 +478,22 @@ mod tests {
         assert!(validate_adjustment_line(&line).is_err());
     }
 
+    #[test]
+    fn test_validate_quick_adjustment_zero_delta() {
+        let mut request = QuickAdjustmentRequest {
+            warehouse_id: Uuid::new_v4(),
+            location_id: None,
+            product_id: Uuid::new_v4(),
+            delta: 0,
+            reason_code: AdjustmentReasonCode::CountCorrection,
+            note: None,
+        };
+        assert!(validate_quick_adjustment(&request).is_err());
+
+        request.delta = -3;
+        assert!(validate_quick_adjustment(&request).is_ok());
+    }
+
     #[test]
     fn test_adjustment_type_display() {
         assert_eq!(AdjustmentType::Increase.to_string(), "increase");
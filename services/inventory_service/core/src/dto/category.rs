@@ -14,6 +14,10 @@ use utoipa::ToSchema;
 use regex::Regex;
 
 use crate::domains::category::{Category, CategoryBreadcrumb, CategoryNode};
+use crate::dto::common::{
+    default_include_total, double_option, Envelope, PaginatedQuery, PaginatedResponse,
+    DEFAULT_PAGE_SIZE,
+};
 use crate::dto::PaginationInfo;
 
 static COLOR_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^#[0-9A-Fa-f]{6}$").unwrap());
@@ -143,6 +147,127 @@ pub struct CategoryUpdateRequest {
     pub meta_keywords: Option<String>,
 }
 
+/// Request to partially update a category (PATCH)
+///
+/// Only the fields present in the body are changed. Nullable fields take an
+/// explicit `null` to clear the stored value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryPatchRequest {
+    /// Parent category ID (`null` makes it a root category)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>, format = "uuid"))]
+    pub parent_category_id: Option<Option<Uuid>>,
+
+    /// Category name
+    #[validate(length(min = 1, max = 255))]
+    pub name: Option<String>,
+
+    /// Category description (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 5000))]
+    pub description: Option<Option<String>>,
+
+    /// Category code (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 100))]
+    pub code: Option<Option<String>>,
+
+    /// Display order within same level
+    pub display_order: Option<u32>,
+
+    /// Icon name/class for UI (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 100))]
+    pub icon: Option<Option<String>>,
+
+    /// Hex color code (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(regex(path = "COLOR_REGEX"))]
+    pub color: Option<Option<String>>,
+
+    /// Category image URL (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(url)]
+    pub image_url: Option<Option<String>>,
+
+    /// Whether category is active
+    pub is_active: Option<bool>,
+
+    /// Whether category is visible
+    pub is_visible: Option<bool>,
+
+    /// URL-friendly identifier (`null` clears it; regenerated from a new name when absent)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 255))]
+    pub slug: Option<Option<String>>,
+
+    /// SEO meta title (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 255))]
+    pub meta_title: Option<Option<String>>,
+
+    /// SEO meta description (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 1000))]
+    pub meta_description: Option<Option<String>>,
+
+    /// SEO meta keywords (`null` clears them)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 500))]
+    pub meta_keywords: Option<Option<String>>,
+}
+
 /// Request to move multiple products to a category
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -185,10 +310,10 @@ pub struct CategoryListQuery {
     #[validate(range(min = 1))]
     pub page: u32,
 
-    /// Page size
-    #[serde(default = "default_page_size")]
-    #[validate(range(min = 1, max = 100))]
-    pub page_size: u32,
+    /// Page size (default: the configured page size, clamped to the configured maximum)
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub page_size: Option<u32>,
 
     /// Sort field
     #[serde(default)]
@@ -197,6 +322,33 @@ pub struct CategoryListQuery {
     /// Sort direction
     #[serde(default)]
     pub sort_dir: SortDirection,
+
+    /// Whether to count the total matching categories (skip for cheaper deep paging)
+    #[serde(default = "default_include_total")]
+    pub include_total: bool,
+}
+
+impl CategoryListQuery {
+    /// Items per page, after [`PageSizeLimits::apply`] the configured value
+    ///
+    /// [`PageSizeLimits::apply`]: crate::dto::common::PageSizeLimits::apply
+    pub fn page_size(&self) -> u32 {
+        self.page_size.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+}
+
+impl PaginatedQuery for CategoryListQuery {
+    fn page(&self) -> i64 {
+        self.page as i64
+    }
+
+    fn requested_page_size(&self) -> Option<i64> {
+        self.page_size.map(i64::from)
+    }
+
+    fn set_page_size(&mut self, page_size: u32) {
+        self.page_size = Some(page_size);
+    }
 }
 
 /// Sort field options for categories
@@ -212,6 +364,10 @@ pub enum CategorySortField {
     UpdatedAt,
     ProductCount,
     Level,
+    /// Best search match first: name prefix matches, then by full-text rank
+    /// of name and description. Falls back to display order without a search
+    /// term; the sort direction only applies to that fallback.
+    Relevance,
 }
 
 /// Sort direction
@@ -301,6 +457,17 @@ pub struct CategoryListResponse {
     pub pagination: PaginationInfo,
 }
 
+impl PaginatedResponse for CategoryListResponse {
+    type Item = CategoryResponse;
+
+    fn into_envelope(self) -> Envelope<Vec<CategoryResponse>> {
+        Envelope {
+            data: self.categories,
+            meta: self.pagination,
+        }
+    }
+}
+
 /// Category tree response (hierarchical structure)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -356,6 +523,223 @@ pub struct BulkOperationResponse {
     pub message: String,
 }
 
+// ============================================================================
+// Import / Export DTOs
+// ============================================================================
+
+/// Current version of the category export document format
+pub const CATEGORY_EXPORT_VERSION: u32 = 1;
+
+/// Portable category tree for moving a catalog between environments
+///
+/// Categories reference their parent by code instead of ID, so the document
+/// can be imported into any tenant. Parents always precede their children.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryExportDocument {
+    /// Document format version
+    #[serde(default = "default_export_version")]
+    pub version: u32,
+
+    /// When the document was exported
+    #[serde(default)]
+    pub exported_at: Option<DateTime<Utc>>,
+
+    /// Categories in dependency order (parents before children)
+    #[validate(length(max = 10000))]
+    #[validate(nested)]
+    pub categories: Vec<CategoryExportItem>,
+}
+
+/// A single category in an export document
+///
+/// Categories without a code are exported under their slug, which becomes
+/// their code when the document is imported.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryExportItem {
+    /// Portable category identifier
+    #[validate(length(min = 1, max = 100))]
+    pub code: String,
+
+    /// Code of the parent category (None for root categories)
+    #[validate(length(min = 1, max = 100))]
+    pub parent_code: Option<String>,
+
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub display_order: u32,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+    pub image_url: Option<String>,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+    #[serde(default = "default_true")]
+    pub is_visible: bool,
+    pub slug: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub meta_keywords: Option<String>,
+}
+
+impl CategoryExportItem {
+    /// Build an export item for `category` under the given parent code
+    pub fn from_category(category: &Category, parent_code: Option<String>) -> Self {
+        Self {
+            code: export_code(category),
+            parent_code,
+            name: category.name.clone(),
+            description: category.description.clone(),
+            display_order: u32::try_from(category.display_order).unwrap_or(0),
+            icon: category.icon.clone(),
+            color: category.color.clone(),
+            image_url: category.image_url.clone(),
+            is_active: category.is_active,
+            is_visible: category.is_visible,
+            slug: category.slug.clone(),
+            meta_title: category.meta_title.clone(),
+            meta_description: category.meta_description.clone(),
+            meta_keywords: category.meta_keywords.clone(),
+        }
+    }
+
+    /// Convert into a create request under the given parent
+    pub fn into_create_request(self, parent_category_id: Option<Uuid>) -> CategoryCreateRequest {
+        CategoryCreateRequest {
+            parent_category_id,
+            name: self.name,
+            description: self.description,
+            code: Some(self.code),
+            display_order: self.display_order,
+            icon: self.icon,
+            color: self.color,
+            image_url: self.image_url,
+            is_active: self.is_active,
+            is_visible: self.is_visible,
+            slug: self.slug,
+            meta_title: self.meta_title,
+            meta_description: self.meta_description,
+            meta_keywords: self.meta_keywords,
+        }
+    }
+}
+
+/// Code a category is exported under: its code, falling back to its slug, then its ID
+pub fn export_code(category: &Category) -> String {
+    category
+        .code
+        .clone()
+        .or_else(|| category.slug.clone())
+        .unwrap_or_else(|| category.category_id.to_string())
+}
+
+/// Outcome of importing a single category
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CategoryImportStatus {
+    /// A new category was created
+    Created,
+    /// A category with this code already existed and was reused
+    Existing,
+}
+
+/// Mapping from an imported code to the category it resolved to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryImportMapping {
+    pub code: String,
+    pub category_id: Uuid,
+    pub status: CategoryImportStatus,
+}
+
+/// A category that could not be imported
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryImportConflict {
+    pub code: String,
+    pub reason: String,
+}
+
+/// Result of a category import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryImportResponse {
+    pub created_count: u32,
+    pub existing_count: u32,
+    pub mappings: Vec<CategoryImportMapping>,
+    pub conflicts: Vec<CategoryImportConflict>,
+}
+
+/// Order export items so every parent precedes its children
+///
+/// Items whose parent code is neither in the document nor resolvable by
+/// `is_known_code`, or that sit on a parent cycle, are returned separately
+/// as conflicts. Duplicate codes keep their first occurrence.
+pub fn order_for_import(
+    items: Vec<CategoryExportItem>,
+    is_known_code: impl Fn(&str) -> bool,
+) -> (Vec<CategoryExportItem>, Vec<CategoryImportConflict>) {
+    use std::collections::{HashMap, HashSet};
+
+    let mut conflicts = Vec::new();
+    let mut by_code: HashMap<String, CategoryExportItem> = HashMap::new();
+    let mut input_order = Vec::new();
+    for item in items {
+        if by_code.contains_key(&item.code) {
+            conflicts.push(CategoryImportConflict {
+                code: item.code.clone(),
+                reason: "Duplicate code in import document".to_string(),
+            });
+            continue;
+        }
+        input_order.push(item.code.clone());
+        by_code.insert(item.code.clone(), item);
+    }
+
+    let mut ordered = Vec::with_capacity(by_code.len());
+    let mut placed: HashSet<String> = HashSet::new();
+    let mut pending = input_order;
+    loop {
+        let before = pending.len();
+        pending.retain(|code| {
+            let item = &by_code[code];
+            let ready = match &item.parent_code {
+                None => true,
+                Some(parent) if placed.contains(parent) => true,
+                Some(parent) => !by_code.contains_key(parent) && is_known_code(parent),
+            };
+            if ready {
+                placed.insert(code.clone());
+                ordered.push(item.clone());
+            }
+            !ready
+        });
+        if pending.is_empty() || pending.len() == before {
+            break;
+        }
+    }
+
+    for code in pending {
+        let parent = by_code[&code].parent_code.clone().unwrap_or_default();
+        let reason = if by_code.contains_key(&parent) {
+            format!("Parent code '{}' is part of a cycle or could not be imported", parent)
+        } else {
+            format!("Parent code '{}' not found", parent)
+        };
+        conflicts.push(CategoryImportConflict { code, reason });
+    }
+
+    (ordered, conflicts)
+}
+
 // ============================================================================
 // Helper functions and constants
 // ============================================================================
@@ -368,13 +752,14 @@ fn default_page() -> u32 {
     1
 }
 
-fn default_page_size() -> u32 {
-    20
+fn default_export_version() -> u32 {
+    CATEGORY_EXPORT_VERSION
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dto::common::PageSizeLimits;
 
     #[test]
     fn test_category_create_request_validation() {
@@ -500,9 +885,11 @@ mod tests {
     fn test_category_list_query_defaults() {
         let query: CategoryListQuery = serde_json::from_str("{}").unwrap();
         assert_eq!(query.page, 1);
-        assert_eq!(query.page_size, 20);
+        assert_eq!(query.page_size, None);
+        assert_eq!(query.page_size(), 20);
         assert_eq!(query.sort_by, CategorySortField::DisplayOrder);
         assert_eq!(query.sort_dir, SortDirection::Asc);
+        assert!(query.include_total);
     }
 
     #[test]
@@ -515,9 +902,10 @@ mod tests {
             is_visible: Some(false),
             search: Some("electronics".to_string()),
             page: 2,
-            page_size: 50,
+            page_size: Some(50),
             sort_by: CategorySortField::Name,
             sort_dir: SortDirection::Desc,
+            include_total: true,
         };
         assert!(query.validate().is_ok());
 
@@ -528,11 +916,14 @@ mod tests {
 
         // Invalid page_size
         let mut invalid_query = query.clone();
-        invalid_query.page_size = 0;
+        invalid_query.page_size = Some(0);
         assert!(invalid_query.validate().is_err());
 
-        invalid_query.page_size = 101;
-        assert!(invalid_query.validate().is_err());
+        // Oversized page_size is clamped to the configured maximum, not rejected
+        invalid_query.page_size = Some(101);
+        PageSizeLimits::default().apply(&mut invalid_query).unwrap();
+        assert_eq!(invalid_query.page_size, Some(100));
+        assert!(invalid_query.validate().is_ok());
 
         // Invalid level
         let mut invalid_query = query.clone();
@@ -661,6 +1052,85 @@ mod tests {
         assert_eq!(tree.children[0].name, "Child Category");
     }
 
+    fn export_item(code: &str, parent_code: Option<&str>) -> CategoryExportItem {
+        serde_json::from_value(serde_json::json!({
+            "code": code,
+            "parentCode": parent_code,
+            "name": code,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_order_for_import_places_parents_first() {
+        let items = vec![
+            export_item("LEAF", Some("MID")),
+            export_item("MID", Some("ROOT")),
+            export_item("ROOT", None),
+        ];
+
+        let (ordered, conflicts) = order_for_import(items, |_| false);
+
+        let codes: Vec<_> = ordered.iter().map(|i| i.code.as_str()).collect();
+        assert_eq!(codes, vec!["ROOT", "MID", "LEAF"]);
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_order_for_import_reports_missing_parents_cycles_and_duplicates() {
+        let items = vec![
+            export_item("A", Some("B")),
+            export_item("B", Some("A")),
+            export_item("ORPHAN", Some("NOPE")),
+            export_item("UNDER_EXISTING", Some("EXISTING")),
+            export_item("UNDER_EXISTING", None),
+        ];
+
+        let (ordered, conflicts) = order_for_import(items, |code| code == "EXISTING");
+
+        let codes: Vec<_> = ordered.iter().map(|i| i.code.as_str()).collect();
+        assert_eq!(codes, vec!["UNDER_EXISTING"]);
+        assert_eq!(ordered[0].parent_code.as_deref(), Some("EXISTING"));
+
+        let mut conflict_codes: Vec<_> = conflicts.iter().map(|c| c.code.as_str()).collect();
+        conflict_codes.sort();
+        assert_eq!(conflict_codes, vec!["A", "B", "ORPHAN", "UNDER_EXISTING"]);
+    }
+
+    #[test]
+    fn test_export_code_falls_back_to_slug() {
+        let mut category: Category = serde_json::from_value(serde_json::json!({
+            "category_id": Uuid::new_v4(),
+            "tenant_id": Uuid::new_v4(),
+            "parent_category_id": null,
+            "name": "Garden",
+            "description": null,
+            "code": null,
+            "path": "",
+            "level": 0,
+            "display_order": 0,
+            "icon": null,
+            "color": null,
+            "image_url": null,
+            "is_active": true,
+            "is_visible": true,
+            "slug": "garden",
+            "meta_title": null,
+            "meta_description": null,
+            "meta_keywords": null,
+            "product_count": 0,
+            "total_product_count": 0,
+            "created_at": Utc::now(),
+            "updated_at": Utc::now(),
+            "deleted_at": null
+        }))
+        .unwrap();
+        assert_eq!(export_code(&category), "garden");
+
+        category.code = Some("GARDEN".to_string());
+        assert_eq!(export_code(&category), "GARDEN");
+    }
+
     #[test]
     fn test_sort_field_serialization() {
         let field = CategorySortField::Name;
@@ -669,6 +1139,8 @@ mod tests {
 
         let deserialized: CategorySortField = serde_json::from_str("\"created_at\"").unwrap();
         assert!(matches!(deserialized, CategorySortField::CreatedAt));
+        let deserialized: CategorySortField = serde_json::from_str("\"relevance\"").unwrap();
+        assert_eq!(deserialized, CategorySortField::Relevance);
     }
 
     #[test]
@@ -1,5 +1,7 @@
 /// Shared DTOs for inventory service
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use shared_error::AppError;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -7,8 +9,12 @@ use serde::{Deserialize, Serialize};
 pub struct PaginationInfo {
     pub page: u32,
     pub page_size: u32,
-    pub total_items: u64,
-    pub total_pages: u32,
+    /// Total matching items; omitted when the count was skipped (`include_total=false`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_items: Option<u64>,
+    /// Total pages; omitted when the count was skipped (`include_total=false`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<u32>,
     pub has_next: bool,
     pub has_prev: bool,
 }
@@ -23,25 +29,280 @@ impl PaginationInfo {
         Self {
             page,
             page_size,
-            total_items,
-            total_pages,
+            total_items: Some(total_items),
+            total_pages: Some(total_pages),
             has_next: page < total_pages,
             has_prev: page > 1,
         }
     }
+
+    /// Pagination for a page listed without counting the total.
+    ///
+    /// `items` must have been fetched with a limit of `page_size + 1`; the extra
+    /// look-ahead row decides `has_next` and is dropped from `items`.
+    pub fn without_total<T>(page: u32, page_size: u32, items: &mut Vec<T>) -> Self {
+        let has_next = items.len() > page_size as usize;
+        items.truncate(page_size as usize);
+        Self {
+            page,
+            page_size,
+            total_items: None,
+            total_pages: None,
+            has_next,
+            has_prev: page > 1,
+        }
+    }
+
+    /// Pagination from an optional total, as returned by list queries that honor
+    /// `include_total`.
+    pub fn from_optional_total<T>(
+        page: u32,
+        page_size: u32,
+        total_items: Option<u64>,
+        items: &mut Vec<T>,
+    ) -> Self {
+        match total_items {
+            Some(total) => Self::new(page, page_size, total),
+            None => Self::without_total(page, page_size, items),
+        }
+    }
+}
+
+/// Row limit for a list query: one look-ahead row is fetched when the total
+/// count is skipped, so `has_next` can still be reported.
+pub fn list_fetch_limit(page_size: u32, include_total: bool) -> i64 {
+    if include_total {
+        page_size as i64
+    } else {
+        page_size as i64 + 1
+    }
+}
+
+/// Default for `include_total` list query parameters
+pub fn default_include_total() -> bool {
+    true
+}
+
+/// Deserialize a nullable field of a PATCH body as `Option<Option<T>>`
+///
+/// Use with `#[serde(default, deserialize_with = "double_option")]`: an absent
+/// field stays `None`, an explicit `null` becomes `Some(None)` (clear it) and a
+/// value becomes `Some(Some(value))`.
+pub fn double_option<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Page size used when neither the client nor the service config picks one
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+
+/// List query with 1-based `page` and `page_size` parameters
+pub trait PaginatedQuery {
+    fn page(&self) -> i64;
+    /// The client's `page_size`, `None` when it was not sent
+    fn requested_page_size(&self) -> Option<i64>;
+    fn set_page_size(&mut self, page_size: u32);
+}
+
+/// Uniform `{data, meta}` shape for list responses, for clients that need the
+/// same envelope on every endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Envelope<T> {
+    pub data: T,
+    pub meta: PaginationInfo,
+}
+
+/// List response made of one page of items and its pagination
+pub trait PaginatedResponse {
+    type Item: Serialize;
+
+    /// Split into the page's items and pagination, as used by [`Envelope`]
+    fn into_envelope(self) -> Envelope<Vec<Self::Item>>;
+}
+
+/// Page-size limits applied to list queries, taken from the service config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageSizeLimits {
+    pub default_page_size: u32,
+    pub max_page_size: u32,
+}
+
+impl Default for PageSizeLimits {
+    fn default() -> Self {
+        Self {
+            default_page_size: DEFAULT_PAGE_SIZE,
+            max_page_size: 100,
+        }
+    }
+}
+
+impl PageSizeLimits {
+    pub fn new(default_page_size: u32, max_page_size: u32) -> Self {
+        let max_page_size = max_page_size.max(1);
+        Self {
+            default_page_size: default_page_size.clamp(1, max_page_size),
+            max_page_size,
+        }
+    }
+
+    /// Normalize a list query's pagination in place.
+    ///
+    /// A missing, zero or negative `page_size` falls back to the configured
+    /// default and anything above the maximum is clamped to it; a `page` below
+    /// 1 is rejected.
+    pub fn apply<Q: PaginatedQuery>(&self, query: &mut Q) -> Result<(), AppError> {
+        if query.page() < 1 {
+            return Err(AppError::ValidationError(format!(
+                "page must be at least 1, got {}",
+                query.page()
+            )));
+        }
+
+        let page_size = match query.requested_page_size() {
+            Some(size) if size >= 1 => size.min(self.max_page_size as i64) as u32,
+            _ => self.default_page_size,
+        };
+        query.set_page_size(page_size);
+        Ok(())
+    }
+}
+
+/// Optional `from`/`to` query parameters of a date-ranged report
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams))]
+pub struct DateRangeParams {
+    /// Start of the range (default: `to` minus the configured default span)
+    pub from: Option<DateTime<Utc>>,
+    /// End of the range (default: now)
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// A validated report date range, `from` never after `to`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+impl DateRange {
+    /// Whole days covered by the range
+    pub fn days(&self) -> i64 {
+        (self.to - self.from).num_days()
+    }
+}
+
+/// Date-range limits applied to report queries, taken from the service config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRangeLimits {
+    pub default_span_days: u32,
+    pub max_span_days: u32,
+}
+
+impl Default for DateRangeLimits {
+    fn default() -> Self {
+        Self {
+            default_span_days: 90,
+            max_span_days: 730,
+        }
+    }
+}
+
+impl DateRangeLimits {
+    pub fn new(default_span_days: u32, max_span_days: u32) -> Self {
+        let max_span_days = max_span_days.max(1);
+        Self {
+            default_span_days: default_span_days.clamp(1, max_span_days),
+            max_span_days,
+        }
+    }
+
+    /// Resolve a report's date range against the current time.
+    pub fn resolve(&self, params: DateRangeParams) -> Result<DateRange, AppError> {
+        self.resolve_at(params, Utc::now())
+    }
+
+    /// Resolve a report's date range against `now`.
+    ///
+    /// A missing `to` is `now` and a missing `from` lies the default span
+    /// before `to`. A range running backwards or longer than the maximum span
+    /// is rejected.
+    pub fn resolve_at(
+        &self,
+        params: DateRangeParams,
+        now: DateTime<Utc>,
+    ) -> Result<DateRange, AppError> {
+        let to = params.to.unwrap_or(now);
+        let from = match params.from {
+            Some(from) => from,
+            None => to
+                .checked_sub_signed(Duration::days(self.default_span_days as i64))
+                .ok_or_else(|| {
+                    AppError::ValidationError(format!("'to' ({}) is out of range", to.to_rfc3339()))
+                })?,
+        };
+
+        if from > to {
+            return Err(AppError::ValidationError(format!(
+                "'from' ({}) must not be after 'to' ({})",
+                from.to_rfc3339(),
+                to.to_rfc3339()
+            )));
+        }
+        if to - from > Duration::days(self.max_span_days as i64) {
+            return Err(AppError::ValidationError(format!(
+                "Date range may span at most {} days, got {}",
+                self.max_span_days,
+                (to - from).num_days()
+            )));
+        }
+
+        Ok(DateRange { from, to })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[derive(Serialize)]
+    struct ItemList {
+        items: Vec<u32>,
+        pagination: PaginationInfo,
+    }
+
+    impl PaginatedResponse for ItemList {
+        type Item = u32;
+
+        fn into_envelope(self) -> Envelope<Vec<u32>> {
+            Envelope {
+                data: self.items,
+                meta: self.pagination,
+            }
+        }
+    }
+
+    #[test]
+    fn test_envelope_shape() {
+        let list = ItemList {
+            items: vec![1, 2],
+            pagination: PaginationInfo::new(1, 2, 3),
+        };
+        let value = serde_json::to_value(list.into_envelope()).unwrap();
+        assert_eq!(value["data"], serde_json::json!([1, 2]));
+        assert_eq!(value["meta"]["totalItems"], 3);
+        assert_eq!(value["meta"]["hasNext"], true);
+    }
+
     #[test]
     fn test_pagination_info() {
         let info = PaginationInfo::new(1, 20, 100);
         assert_eq!(info.page, 1);
         assert_eq!(info.page_size, 20);
-        assert_eq!(info.total_items, 100);
-        assert_eq!(info.total_pages, 5);
+        assert_eq!(info.total_items, Some(100));
+        assert_eq!(info.total_pages, Some(5));
         assert!(info.has_next);
         assert!(!info.has_prev);
 
@@ -55,13 +316,249 @@ mod tests {
 
         // Edge cases
         let info = PaginationInfo::new(1, 10, 0);
-        assert_eq!(info.total_pages, 0);
+        assert_eq!(info.total_pages, Some(0));
         assert!(!info.has_next);
         assert!(!info.has_prev);
 
         let info = PaginationInfo::new(1, 10, 1);
-        assert_eq!(info.total_pages, 1);
+        assert_eq!(info.total_pages, Some(1));
         assert!(!info.has_next);
         assert!(!info.has_prev);
     }
+
+    #[test]
+    fn test_pagination_page_math() {
+        // Partial last page rounds up
+        let info = PaginationInfo::new(2, 20, 41);
+        assert_eq!(info.total_pages, Some(3));
+        assert!(info.has_next);
+        assert!(info.has_prev);
+
+        // Page past the end
+        let info = PaginationInfo::new(4, 20, 41);
+        assert!(!info.has_next);
+        assert!(info.has_prev);
+
+        // Exact multiple
+        let info = PaginationInfo::new(2, 20, 40);
+        assert_eq!(info.total_pages, Some(2));
+        assert!(!info.has_next);
+    }
+
+    #[test]
+    fn test_pagination_without_total() {
+        // Look-ahead row present: more pages, extra row dropped
+        let mut items: Vec<u32> = (0..11).collect();
+        let info = PaginationInfo::without_total(2, 10, &mut items);
+        assert_eq!(items.len(), 10);
+        assert_eq!(info.total_items, None);
+        assert_eq!(info.total_pages, None);
+        assert!(info.has_next);
+        assert!(info.has_prev);
+
+        // Short page: last page
+        let mut items: Vec<u32> = (0..4).collect();
+        let info = PaginationInfo::without_total(1, 10, &mut items);
+        assert_eq!(items.len(), 4);
+        assert!(!info.has_next);
+        assert!(!info.has_prev);
+
+        // Skipped totals are omitted from the JSON
+        let json = serde_json::to_value(&info).unwrap();
+        assert!(json.get("totalItems").is_none());
+        assert!(json.get("totalPages").is_none());
+        assert_eq!(json["hasNext"], false);
+
+        let json = serde_json::to_value(PaginationInfo::new(1, 10, 4)).unwrap();
+        assert_eq!(json["totalItems"], 4);
+        assert_eq!(json["totalPages"], 1);
+    }
+
+    struct TestQuery {
+        page: i64,
+        page_size: Option<i64>,
+    }
+
+    impl PaginatedQuery for TestQuery {
+        fn page(&self) -> i64 {
+            self.page
+        }
+
+        fn requested_page_size(&self) -> Option<i64> {
+            self.page_size
+        }
+
+        fn set_page_size(&mut self, page_size: u32) {
+            self.page_size = Some(page_size as i64);
+        }
+    }
+
+    #[test]
+    fn test_page_size_limits_clamp_oversized_page_size() {
+        let limits = PageSizeLimits::new(25, 200);
+        let mut query = TestQuery {
+            page: 1,
+            page_size: Some(1_000_000),
+        };
+        limits.apply(&mut query).unwrap();
+        assert_eq!(query.page_size, Some(200));
+
+        // In-range sizes are left alone
+        let mut query = TestQuery {
+            page: 3,
+            page_size: Some(50),
+        };
+        limits.apply(&mut query).unwrap();
+        assert_eq!(query.page_size, Some(50));
+
+        // Zero falls back to the configured default
+        let mut query = TestQuery {
+            page: 1,
+            page_size: Some(0),
+        };
+        limits.apply(&mut query).unwrap();
+        assert_eq!(query.page_size, Some(25));
+    }
+
+    #[test]
+    fn test_page_size_limits_fill_missing_page_size_with_configured_default() {
+        let limits = PageSizeLimits::new(50, 200);
+        let mut query = TestQuery {
+            page: 1,
+            page_size: None,
+        };
+        limits.apply(&mut query).unwrap();
+        assert_eq!(query.page_size, Some(50));
+    }
+
+    #[test]
+    fn test_page_size_limits_reject_zero_page() {
+        let limits = PageSizeLimits::default();
+        let mut query = TestQuery {
+            page: 0,
+            page_size: Some(20),
+        };
+        assert!(matches!(limits.apply(&mut query), Err(AppError::ValidationError(_))));
+
+        query.page = -1;
+        assert!(limits.apply(&mut query).is_err());
+    }
+
+    #[test]
+    fn test_page_size_limits_new_keeps_default_within_max() {
+        let limits = PageSizeLimits::new(500, 100);
+        assert_eq!(limits.default_page_size, 100);
+
+        let limits = PageSizeLimits::new(20, 0);
+        assert_eq!(limits.max_page_size, 1);
+    }
+
+    fn at(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        use chrono::TimeZone;
+        Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_date_range_limits_reject_reversed_range() {
+        let limits = DateRangeLimits::default();
+        let params = DateRangeParams {
+            from: Some(at(2024, 3, 1)),
+            to: Some(at(2024, 1, 1)),
+        };
+        assert!(matches!(
+            limits.resolve_at(params, at(2024, 6, 1)),
+            Err(AppError::ValidationError(_))
+        ));
+
+        // A `from` in the future is after the default `to`
+        let params = DateRangeParams {
+            from: Some(at(2024, 7, 1)),
+            to: None,
+        };
+        assert!(limits.resolve_at(params, at(2024, 6, 1)).is_err());
+    }
+
+    #[test]
+    fn test_date_range_limits_reject_overlong_span() {
+        let limits = DateRangeLimits::new(30, 365);
+        let params = DateRangeParams {
+            from: Some(at(2022, 1, 1)),
+            to: Some(at(2024, 1, 1)),
+        };
+        assert!(matches!(
+            limits.resolve_at(params, at(2024, 6, 1)),
+            Err(AppError::ValidationError(_))
+        ));
+
+        // Exactly the maximum span is allowed
+        let params = DateRangeParams {
+            from: Some(at(2023, 1, 1)),
+            to: Some(at(2024, 1, 1)),
+        };
+        assert_eq!(limits.resolve_at(params, at(2024, 6, 1)).unwrap().days(), 365);
+    }
+
+    #[test]
+    fn test_date_range_limits_accept_valid_range_and_fill_defaults() {
+        let limits = DateRangeLimits::new(30, 365);
+        let now = at(2024, 6, 1);
+
+        let params = DateRangeParams {
+            from: Some(at(2024, 1, 1)),
+            to: Some(at(2024, 3, 31)),
+        };
+        assert_eq!(
+            limits.resolve_at(params, now).unwrap(),
+            DateRange {
+                from: at(2024, 1, 1),
+                to: at(2024, 3, 31)
+            }
+        );
+
+        // Nothing given: the default span up to now
+        let range = limits.resolve_at(DateRangeParams::default(), now).unwrap();
+        assert_eq!(range.to, now);
+        assert_eq!(range.days(), 30);
+
+        // Only `to` given: the default span up to it
+        let params = DateRangeParams {
+            from: None,
+            to: Some(at(2024, 2, 1)),
+        };
+        let range = limits.resolve_at(params, now).unwrap();
+        assert_eq!(range.from, at(2024, 1, 2));
+    }
+
+    #[test]
+    fn test_date_range_limits_new_keeps_default_within_max() {
+        let limits = DateRangeLimits::new(1000, 365);
+        assert_eq!(limits.default_span_days, 365);
+
+        let limits = DateRangeLimits::new(0, 0);
+        assert_eq!(limits, DateRangeLimits::new(1, 1));
+    }
+
+    #[test]
+    fn test_list_fetch_limit() {
+        assert_eq!(list_fetch_limit(20, true), 20);
+        assert_eq!(list_fetch_limit(20, false), 21);
+    }
+
+    #[test]
+    fn test_double_option_distinguishes_absent_from_null() {
+        #[derive(Deserialize)]
+        struct Patch {
+            #[serde(default, deserialize_with = "double_option")]
+            note: Option<Option<String>>,
+        }
+
+        let absent: Patch = serde_json::from_str("{}").unwrap();
+        assert_eq!(absent.note, None);
+
+        let null: Patch = serde_json::from_str(r#"{"note": null}"#).unwrap();
+        assert_eq!(null.note, Some(None));
+
+        let value: Patch = serde_json::from_str(r#"{"note": "x"}"#).unwrap();
+        assert_eq!(value.note, Some(Some("x".to_string())));
+    }
 }
@@ -247,6 +247,56 @@ pub struct CreateCycleCountRequest {
     pub notes: Option<String>,
 }
 
+/// Strategy for choosing which stocked products get count lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum LineSelectionStrategy {
+    /// Every product with stock in the warehouse
+    #[default]
+    All,
+    /// Products whose ABC class is due for a count; A items (top 80% of
+    /// stock value) come due more often than B (next 15%) and C items
+    Abc {
+        /// Days between counts of A items (default: 30)
+        #[serde(default = "default_abc_a_interval_days")]
+        a_interval_days: u32,
+        /// Days between counts of B items (default: 90)
+        #[serde(default = "default_abc_b_interval_days")]
+        b_interval_days: u32,
+        /// Days between counts of C items (default: 180)
+        #[serde(default = "default_abc_c_interval_days")]
+        c_interval_days: u32,
+    },
+    /// Products stocked in locations of one warehouse zone
+    Zone {
+        /// Zone to count
+        zone_id: Uuid,
+    },
+    /// The longest-uncounted products, never-counted first
+    LastCountAge {
+        /// Maximum number of lines to generate
+        max_lines: u32,
+    },
+    /// A random sample of stocked products
+    RandomSample {
+        /// Number of products to sample
+        sample_size: u32,
+    },
+}
+
+fn default_abc_a_interval_days() -> u32 {
+    30
+}
+
+fn default_abc_b_interval_days() -> u32 {
+    90
+}
+
+fn default_abc_c_interval_days() -> u32 {
+    180
+}
+
 /// Request to generate or refresh count lines
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -261,6 +311,9 @@ pub struct GenerateLinesRequest {
     /// Replace existing lines (only allowed in Draft status)
     #[serde(default)]
     pub replace_existing: bool,
+    /// Line selection strategy (default: all stocked products)
+    #[serde(default)]
+    pub strategy: LineSelectionStrategy,
 }
 
 /// A single count submission
@@ -468,6 +521,155 @@ pub fn calculate_difference(expected_qty: i64, counted_qty: i64) -> i64 {
     counted_qty - expected_qty
 }
 
+/// ABC class of a product by its share of warehouse stock value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbcClass {
+    A,
+    B,
+    C,
+}
+
+/// A stocked product considered for line generation
+#[derive(Debug, Clone)]
+pub struct LineCandidate {
+    /// Product ID
+    pub product_id: Uuid,
+    /// On-hand quantity times cost price
+    pub stock_value: i64,
+    /// When the product was last counted in this warehouse
+    pub last_counted_at: Option<DateTime<Utc>>,
+    /// Random key used to draw samples
+    pub sample_key: f64,
+}
+
+/// Validate a line selection strategy
+pub fn validate_line_selection_strategy(
+    strategy: &LineSelectionStrategy,
+) -> Result<(), CycleCountValidationError> {
+    let invalid = |field: &str, message: &str| {
+        Err(CycleCountValidationError {
+            field: field.to_string(),
+            message: message.to_string(),
+        })
+    };
+
+    match *strategy {
+        LineSelectionStrategy::Abc {
+            a_interval_days,
+            b_interval_days,
+            c_interval_days,
+        } if a_interval_days == 0 || b_interval_days == 0 || c_interval_days == 0 => {
+            invalid("strategy", "ABC count intervals must be at least one day")
+        },
+        LineSelectionStrategy::LastCountAge { max_lines: 0 } => {
+            invalid("strategy.max_lines", "At least one line must be requested")
+        },
+        LineSelectionStrategy::RandomSample { sample_size: 0 } => {
+            invalid("strategy.sample_size", "Sample size must be at least one")
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Classify candidates by cumulative share of total stock value
+///
+/// Products covering the first 80% of value are A, the next 15% B and the
+/// rest C. Returns one class per candidate, in input order.
+pub fn classify_abc(candidates: &[LineCandidate]) -> Vec<AbcClass> {
+    let total: i128 = candidates
+        .iter()
+        .map(|c| c.stock_value.max(0) as i128)
+        .sum();
+    let mut classes = vec![AbcClass::C; candidates.len()];
+    if total == 0 {
+        return classes;
+    }
+
+    let mut order: Vec<usize> = (0..candidates.len()).collect();
+    order.sort_by(|&a, &b| {
+        candidates[b]
+            .stock_value
+            .cmp(&candidates[a].stock_value)
+            .then(candidates[a].product_id.cmp(&candidates[b].product_id))
+    });
+
+    let mut cumulative: i128 = 0;
+    for index in order {
+        let value = candidates[index].stock_value.max(0) as i128;
+        if value == 0 {
+            break;
+        }
+        classes[index] = if cumulative * 100 < total * 80 {
+            AbcClass::A
+        } else if cumulative * 100 < total * 95 {
+            AbcClass::B
+        } else {
+            AbcClass::C
+        };
+        cumulative += value;
+    }
+
+    classes
+}
+
+/// Choose the products that get count lines under a strategy
+///
+/// Zone scoping happens when candidates are loaded, so `Zone` keeps every
+/// candidate, like `All`.
+pub fn select_line_products(
+    strategy: &LineSelectionStrategy,
+    candidates: &[LineCandidate],
+    now: DateTime<Utc>,
+) -> Vec<Uuid> {
+    match *strategy {
+        LineSelectionStrategy::All | LineSelectionStrategy::Zone { .. } => {
+            candidates.iter().map(|c| c.product_id).collect()
+        },
+        LineSelectionStrategy::Abc {
+            a_interval_days,
+            b_interval_days,
+            c_interval_days,
+        } => candidates
+            .iter()
+            .zip(classify_abc(candidates))
+            .filter(|(candidate, class)| {
+                let interval_days = match class {
+                    AbcClass::A => a_interval_days,
+                    AbcClass::B => b_interval_days,
+                    AbcClass::C => c_interval_days,
+                };
+                candidate.last_counted_at.is_none_or(|counted_at| {
+                    now - counted_at >= chrono::Duration::days(interval_days as i64)
+                })
+            })
+            .map(|(candidate, _)| candidate.product_id)
+            .collect(),
+        LineSelectionStrategy::LastCountAge { max_lines } => {
+            let mut ordered: Vec<&LineCandidate> = candidates.iter().collect();
+            // None sorts before Some, so never-counted products come first
+            ordered.sort_by(|a, b| {
+                a.last_counted_at
+                    .cmp(&b.last_counted_at)
+                    .then(a.product_id.cmp(&b.product_id))
+            });
+            ordered
+                .into_iter()
+                .take(max_lines as usize)
+                .map(|c| c.product_id)
+                .collect()
+        },
+        LineSelectionStrategy::RandomSample { sample_size } => {
+            let mut ordered: Vec<&LineCandidate> = candidates.iter().collect();
+            ordered.sort_by(|a, b| a.sample_key.total_cmp(&b.sample_key));
+            ordered
+                .into_iter()
+                .take(sample_size as usize)
+                .map(|c| c.product_id)
+                .collect()
+        },
+    }
+}
+
 /// Calculate summary statistics from lines
 pub fn calculate_summary(lines: &[CycleCountLine]) -> CycleCountSummary {
     let total_lines = lines.len() as u32;
@@ -701,6 +903,125 @@ mod tests {
         assert_eq!(summary.total_negative_variance, -10);
     }
 
+    fn candidate(stock_value: i64, days_since_count: Option<i64>) -> LineCandidate {
+        LineCandidate {
+            product_id: Uuid::new_v4(),
+            stock_value,
+            last_counted_at: days_since_count.map(|days| Utc::now() - chrono::Duration::days(days)),
+            sample_key: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_classify_abc_by_value_share() {
+        let candidates = vec![
+            candidate(10, None),
+            candidate(700, None),
+            candidate(150, None),
+            candidate(140, None),
+            candidate(0, None),
+        ];
+
+        // 700 opens the ranking (A), 150 starts at 70% (A), 140 at 85% (B),
+        // 10 at 99% (C); worthless stock is always C
+        assert_eq!(
+            classify_abc(&candidates),
+            vec![
+                AbcClass::C,
+                AbcClass::A,
+                AbcClass::A,
+                AbcClass::B,
+                AbcClass::C
+            ]
+        );
+        assert_eq!(classify_abc(&[candidate(0, None)]), vec![AbcClass::C]);
+    }
+
+    #[test]
+    fn test_select_abc_uses_class_intervals() {
+        let strategy = LineSelectionStrategy::Abc {
+            a_interval_days: 30,
+            b_interval_days: 90,
+            c_interval_days: 180,
+        };
+        let a_stale = candidate(800, Some(45));
+        let b_recent = candidate(150, Some(45));
+        let c_stale = candidate(50, Some(200));
+        let candidates = vec![a_stale.clone(), b_recent, c_stale.clone()];
+
+        assert_eq!(
+            select_line_products(&strategy, &candidates, Utc::now()),
+            vec![a_stale.product_id, c_stale.product_id]
+        );
+    }
+
+    #[test]
+    fn test_select_by_last_count_age() {
+        let never = candidate(1, None);
+        let oldest = candidate(1, Some(400));
+        let recent = candidate(1, Some(3));
+        let candidates = vec![recent, oldest.clone(), never.clone()];
+
+        let strategy = LineSelectionStrategy::LastCountAge { max_lines: 2 };
+        assert_eq!(
+            select_line_products(&strategy, &candidates, Utc::now()),
+            vec![never.product_id, oldest.product_id]
+        );
+    }
+
+    #[test]
+    fn test_select_random_sample_size() {
+        let candidates: Vec<LineCandidate> = (0..5)
+            .map(|i| LineCandidate {
+                sample_key: 1.0 / (i as f64 + 1.0),
+                ..candidate(1, None)
+            })
+            .collect();
+
+        let strategy = LineSelectionStrategy::RandomSample { sample_size: 2 };
+        assert_eq!(
+            select_line_products(&strategy, &candidates, Utc::now()),
+            vec![candidates[4].product_id, candidates[3].product_id]
+        );
+        let strategy = LineSelectionStrategy::RandomSample { sample_size: 10 };
+        assert_eq!(select_line_products(&strategy, &candidates, Utc::now()).len(), 5);
+    }
+
+    #[test]
+    fn test_validate_line_selection_strategy() {
+        assert!(validate_line_selection_strategy(&LineSelectionStrategy::All).is_ok());
+        assert!(validate_line_selection_strategy(&LineSelectionStrategy::RandomSample {
+            sample_size: 0
+        })
+        .is_err());
+        assert!(validate_line_selection_strategy(&LineSelectionStrategy::LastCountAge {
+            max_lines: 0
+        })
+        .is_err());
+        assert!(validate_line_selection_strategy(&LineSelectionStrategy::Abc {
+            a_interval_days: 0,
+            b_interval_days: 90,
+            c_interval_days: 180,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_strategy_deserializes_with_defaults() {
+        let request: GenerateLinesRequest = serde_json::from_str("{}").unwrap();
+        assert_eq!(request.strategy, LineSelectionStrategy::All);
+
+        let strategy: LineSelectionStrategy = serde_json::from_str(r#"{"type":"abc"}"#).unwrap();
+        assert_eq!(
+            strategy,
+            LineSelectionStrategy::Abc {
+                a_interval_days: 30,
+                b_interval_days: 90,
+                c_interval_days: 180,
+            }
+        );
+    }
+
     #[test]
     fn test_status_display() {
         assert_eq!(CycleCountStatus::Draft.to_string(), "draft");
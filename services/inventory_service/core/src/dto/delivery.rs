@@ -48,22 +48,47 @@ pub struct PackItemsResponse {
 }
 
 /// Request to ship a delivery order
+///
+/// When `items` is omitted or empty, every picked-but-unshipped quantity is shipped.
+/// Otherwise only the listed lines/quantities are shipped as one parcel.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct ShipItemsRequest {
+    #[serde(default)]
+    pub items: Option<Vec<ShipItemRequest>>,
     pub tracking_number: Option<String>,
     pub carrier: Option<String>,
     pub shipping_cost: Option<i64>,
     pub notes: Option<String>,
 }
 
+/// Individual line to ship in a (partial) shipment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ShipItemRequest {
+    pub delivery_item_id: Uuid,
+    pub quantity: i64,
+}
+
+/// Remaining quantity to ship for a delivery line after a shipment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ShipmentRemainingLine {
+    pub delivery_item_id: Uuid,
+    pub ordered_quantity: i64,
+    pub shipped_quantity: i64,
+    pub remaining_quantity: i64,
+}
+
 /// Response for ship operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct ShipItemsResponse {
     pub delivery_id: Uuid,
+    pub shipment_id: Uuid,
     pub status: String,
     pub shipped_at: DateTime<Utc>,
     pub stock_moves_created: usize,
     pub total_cogs: i64,
+    pub remaining: Vec<ShipmentRemainingLine>,
 }
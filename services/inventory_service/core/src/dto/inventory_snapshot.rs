@@ -0,0 +1,36 @@
+//! Inventory Snapshot Data Transfer Objects
+//!
+//! A snapshot records a tenant's inventory levels at one point in time so
+//! they can be restored after a risky bulk operation.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Reason code on the stock moves written by a snapshot restore
+pub const SNAPSHOT_RESTORE_REASON: &str = "snapshot_restore";
+
+/// A stored inventory snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InventorySnapshotResponse {
+    pub snapshot_id: Uuid,
+    pub tenant_id: Uuid,
+    /// Number of inventory levels captured
+    pub level_count: u64,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Outcome of restoring a snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SnapshotRestoreResponse {
+    pub snapshot_id: Uuid,
+    /// Levels that differed from the snapshot, one compensating move each
+    pub levels_adjusted: u64,
+    pub restored_by: Uuid,
+    pub restored_at: DateTime<Utc>,
+}
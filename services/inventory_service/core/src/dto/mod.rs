@@ -7,6 +7,7 @@ pub mod category;
 pub mod common;
 pub mod cycle_count;
 pub mod delivery;
+pub mod inventory_snapshot;
 pub mod product;
 pub mod product_image;
 pub mod product_import;
@@ -19,6 +20,8 @@ pub mod rma;
 pub mod scrap;
 pub mod stock_levels;
 pub mod stock_take;
+pub mod tenant_export;
+pub mod tenant_provisioning;
 pub mod transfer;
 
 // Re-export main types for convenience
@@ -28,10 +31,12 @@ pub use category::{
     CategoryUpdateRequest, MoveToCategoryRequest, SortDirection,
 };
 // pub use delivery::{PickItemRequest, PickItemsRequest, PickItemsResponse};
-pub use common::PaginationInfo;
+pub use common::{Envelope, PaginatedResponse, PaginationInfo};
+pub use inventory_snapshot::{InventorySnapshotResponse, SnapshotRestoreResponse};
 pub use product::{
-    ProductCreateRequest, ProductListQuery, ProductListResponse, ProductResponse,
-    ProductUpdateRequest,
+    ProductAttributes, ProductAttributesResponse, ProductCreateRequest, ProductListQuery,
+    ProductListResponse, ProductResponse, ProductSortField, ProductUpdateRequest,
+    SetProductAttributesRequest, SortSpec,
 };
 pub use product_image::{
     DeleteImageResponse, ProductImageResponse, ProductImagesListResponse, ReorderImagesRequest,
@@ -51,9 +56,11 @@ pub use receipt::{
 pub use reconciliation::{
     ApproveReconciliationRequest, ApproveReconciliationResponse, CountReconciliationRequest,
     CountReconciliationResponse, CreateReconciliationRequest, CreateReconciliationResponse,
-    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ReconciliationAnalyticsQuery,
-    ReconciliationAnalyticsResponse, ReconciliationCountItem, ReconciliationDetailResponse,
-    ReconciliationListQuery, ReconciliationListResponse, VarianceAnalysisResponse,
+    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ImportReconciliationRequest,
+    ImportReconciliationResponse, ReconciliationAnalyticsQuery, ReconciliationAnalyticsResponse,
+    ReconciliationCountItem, ReconciliationDetailResponse, ReconciliationImportQuery,
+    ReconciliationListQuery, ReconciliationListResponse, ReconciliationSnapshotRow,
+    VarianceAnalysisResponse,
 };
 pub use removal_strategy::{
     RemovalStrategyCreateRequest, RemovalStrategyListQuery, RemovalStrategyListResponse,
@@ -69,6 +76,8 @@ pub use stock_take::{
     CreateStockTakeResponse, FinalizeStockTakeRequest, FinalizeStockTakeResponse, StockAdjustment,
     StockTakeDetailResponse, StockTakeListQuery, StockTakeListResponse,
 };
+pub use tenant_export::{TenantExportEntity, TenantExportEntityCount, TenantExportResponse};
+pub use tenant_provisioning::{SeededEntity, TenantSeedConfig, TenantSeedResponse, WarehouseSeed};
 pub use transfer::{
     CreateTransferItemRequest, CreateTransferRequest, TransferItemResponse, TransferResponse,
     UpdateTransferItemRequest, UpdateTransferRequest,
@@ -79,14 +88,16 @@ pub use cycle_count::{
     CountSubmission, CountType, CreateCycleCountRequest, CycleCountLine, CycleCountLineStatus,
     CycleCountListQuery, CycleCountListResponse, CycleCountResponse, CycleCountSession,
     CycleCountStatus, CycleCountSummary, CycleCountWithLinesResponse, GenerateLinesRequest,
-    LineAdjustment, ReconcileRequest, ReconcileResponse, SkipLinesRequest, SubmitCountsRequest,
+    LineAdjustment, LineSelectionStrategy, ReconcileRequest, ReconcileResponse, SkipLinesRequest,
+    SubmitCountsRequest,
 };
 
 // Reports DTOs
 pub use reports::{
-    AgeBucket, AgeBucketPreset, AgingBasis, StockAgingReportQuery, StockAgingReportResponse,
+    AgeBucket, AgeBucketPreset, AgingBasis, CapacityBasis, CapacityReportQuery,
+    CapacityReportResponse, LocationCapacityEntry, StockAgingReportQuery, StockAgingReportResponse,
     StockAgingReportRow, TurnoverGroupBy, TurnoverReportQuery, TurnoverReportResponse,
-    TurnoverReportRow,
+    TurnoverReportRow, WarehouseCapacityEntry, ZoneCapacityEntry,
 };
 
 // Scrap management DTOs
@@ -98,7 +109,8 @@ pub use scrap::{
 
 // Stock Levels DTOs
 pub use stock_levels::{
-    StockLevelListQuery, StockLevelListResponse, StockLevelResponse, StockLevelSummary, StockStatus,
+    LevelHistoryQuery, LevelHistoryResponse, StockLevelListQuery, StockLevelListResponse,
+    StockLevelResponse, StockLevelSummary, StockStatus,
 };
 
 // Stock Adjustment DTOs
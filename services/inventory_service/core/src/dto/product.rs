@@ -2,6 +2,8 @@
 //!
 //! This module defines the DTOs used for product API operations.
 
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "openapi")]
@@ -10,19 +12,251 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::domains::inventory::product::{BarcodeType, Product, ProductTrackingMethod};
-use crate::dto::common::PaginationInfo;
+use crate::dto::common::{
+    default_include_total, double_option, Envelope, PaginatedQuery, PaginatedResponse,
+    PaginationInfo, DEFAULT_PAGE_SIZE,
+};
 
 /// Sort direction enum for product list queries
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum SortDirection {
     /// Ascending order
+    #[default]
     Asc,
     /// Descending order
     Desc,
 }
 
+/// Sortable product fields
+///
+/// Only these fields may appear in a product list sort; the repository maps
+/// each variant to a fixed column, so user input never reaches the SQL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ProductSortField {
+    Name,
+    Sku,
+    /// Category name
+    Category,
+    ProductType,
+    SalePrice,
+    CostPrice,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl ProductSortField {
+    /// All sortable fields
+    pub const ALL: [ProductSortField; 8] = [
+        ProductSortField::Name,
+        ProductSortField::Sku,
+        ProductSortField::Category,
+        ProductSortField::ProductType,
+        ProductSortField::SalePrice,
+        ProductSortField::CostPrice,
+        ProductSortField::CreatedAt,
+        ProductSortField::UpdatedAt,
+    ];
+
+    /// Field name as used in the `sort` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProductSortField::Name => "name",
+            ProductSortField::Sku => "sku",
+            ProductSortField::Category => "category",
+            ProductSortField::ProductType => "product_type",
+            ProductSortField::SalePrice => "sale_price",
+            ProductSortField::CostPrice => "cost_price",
+            ProductSortField::CreatedAt => "created_at",
+            ProductSortField::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+impl std::str::FromStr for ProductSortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_str() == s)
+            .ok_or_else(|| {
+                let allowed: Vec<_> = Self::ALL.iter().map(|f| f.as_str()).collect();
+                format!("unknown sort field '{}', expected one of: {}", s, allowed.join(", "))
+            })
+    }
+}
+
+/// One key of a multi-field product sort
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SortSpec {
+    pub field: ProductSortField,
+    #[serde(default)]
+    pub direction: SortDirection,
+}
+
+impl std::str::FromStr for SortSpec {
+    type Err = String;
+
+    /// Parse `field` or `field:asc|desc`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (field, direction) = match s.split_once(':') {
+            Some((field, direction)) => (field.trim(), Some(direction.trim())),
+            None => (s.trim(), None),
+        };
+        let direction = match direction {
+            None | Some("asc") => SortDirection::Asc,
+            Some("desc") => SortDirection::Desc,
+            Some(other) => {
+                return Err(format!("invalid sort direction '{}', expected asc or desc", other))
+            },
+        };
+        Ok(Self {
+            field: field.parse()?,
+            direction,
+        })
+    }
+}
+
+/// Deserialize a comma-separated `field:direction` list (e.g. `category:asc,name:desc`)
+fn deserialize_sort_specs<'de, D>(deserializer: D) -> Result<Vec<SortSpec>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    raw.split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
+
+/// Product specification attributes, e.g. `{"voltage": "220V", "material": "steel"}`
+pub type ProductAttributes = BTreeMap<String, String>;
+
+/// Maximum number of attributes on one product
+pub const MAX_PRODUCT_ATTRIBUTES: usize = 50;
+
+/// Maximum length of an attribute key
+pub const MAX_ATTRIBUTE_KEY_LENGTH: usize = 64;
+
+/// Maximum length of an attribute value
+pub const MAX_ATTRIBUTE_VALUE_LENGTH: usize = 255;
+
+/// Query parameter prefix for attribute filters, as in `?attr.color=red`
+pub const ATTRIBUTE_FILTER_PREFIX: &str = "attr.";
+
+/// Validate an attribute key: lowercase letters, digits, `_` and `-`, starting with a letter
+pub fn validate_attribute_key(key: &str) -> Result<(), String> {
+    if key.is_empty() || key.len() > MAX_ATTRIBUTE_KEY_LENGTH {
+        return Err(format!(
+            "Attribute key must be 1-{} characters: '{}'",
+            MAX_ATTRIBUTE_KEY_LENGTH, key
+        ));
+    }
+    let valid = key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+    if !valid {
+        return Err(format!(
+            "Attribute key must start with a lowercase letter and contain only a-z, 0-9, '_' or '-': '{}'",
+            key
+        ));
+    }
+    Ok(())
+}
+
+/// Validate an attribute value: non-blank, bounded, no control characters
+pub fn validate_attribute_value(key: &str, value: &str) -> Result<(), String> {
+    if value.trim().is_empty() {
+        return Err(format!("Attribute '{}' must have a non-empty value", key));
+    }
+    if value.chars().count() > MAX_ATTRIBUTE_VALUE_LENGTH {
+        return Err(format!(
+            "Attribute '{}' value exceeds {} characters",
+            key, MAX_ATTRIBUTE_VALUE_LENGTH
+        ));
+    }
+    if value.chars().any(char::is_control) {
+        return Err(format!("Attribute '{}' value contains control characters", key));
+    }
+    Ok(())
+}
+
+/// Validate a full attribute set
+pub fn validate_product_attributes(attributes: &ProductAttributes) -> Result<(), String> {
+    if attributes.len() > MAX_PRODUCT_ATTRIBUTES {
+        return Err(format!("A product can have at most {} attributes", MAX_PRODUCT_ATTRIBUTES));
+    }
+    for (key, value) in attributes {
+        validate_attribute_key(key)?;
+        validate_attribute_value(key, value)?;
+    }
+    Ok(())
+}
+
+/// Parse and validate attributes given as free-form JSON on create/update requests
+///
+/// The JSON must be an object whose values are all strings.
+pub fn parse_product_attributes(value: &serde_json::Value) -> Result<ProductAttributes, String> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| "Attributes must be a JSON object".to_string())?;
+    let attributes = object
+        .iter()
+        .map(|(key, value)| match value.as_str() {
+            Some(text) => Ok((key.clone(), text.to_string())),
+            None => Err(format!("Attribute '{}' value must be a string", key)),
+        })
+        .collect::<Result<ProductAttributes, String>>()?;
+    validate_product_attributes(&attributes)?;
+    Ok(attributes)
+}
+
+/// Collect `attr.<key>=<value>` query parameters into attribute filters
+///
+/// Other parameters are ignored. Keys and values are validated like stored
+/// attributes, and a key may only be filtered once.
+pub fn parse_attribute_filters(params: &[(String, String)]) -> Result<ProductAttributes, String> {
+    let mut filters = ProductAttributes::new();
+    for (name, value) in params {
+        let Some(key) = name.strip_prefix(ATTRIBUTE_FILTER_PREFIX) else {
+            continue;
+        };
+        validate_attribute_key(key)?;
+        validate_attribute_value(key, value)?;
+        if filters.insert(key.to_string(), value.clone()).is_some() {
+            return Err(format!("Attribute filter '{}' given more than once", key));
+        }
+    }
+    Ok(filters)
+}
+
+/// Request body replacing all attributes of a product
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SetProductAttributesRequest {
+    /// New attribute key/value pairs; an empty map clears all attributes
+    #[cfg_attr(feature = "openapi", schema(example = json!({"voltage": "220V", "material": "steel"})))]
+    pub attributes: ProductAttributes,
+}
+
+/// Attributes of one product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ProductAttributesResponse {
+    pub product_id: Uuid,
+    pub attributes: ProductAttributes,
+}
+
 /// Product creation request DTO
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -96,6 +330,10 @@ pub struct ProductCreateRequest {
 
     /// Whether product is available for purchase
     pub is_purchaseable: Option<bool>,
+
+    /// Percentage of on-hand stock that may be reserved beyond availability (backordered)
+    #[validate(range(min = 0, max = 100))]
+    pub overcommit_pct: Option<i32>,
 }
 
 /// Product update request DTO
@@ -167,6 +405,154 @@ pub struct ProductUpdateRequest {
 
     /// Whether product is available for purchase
     pub is_purchaseable: Option<bool>,
+
+    /// Percentage of on-hand stock that may be reserved beyond availability (backordered)
+    #[validate(range(min = 0, max = 100))]
+    pub overcommit_pct: Option<i32>,
+}
+
+/// Product partial update (PATCH) request DTO
+///
+/// Only the fields present in the body are changed. Nullable fields take an
+/// explicit `null` to clear the stored value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ProductPatchRequest {
+    /// Product name
+    #[validate(length(min = 1, max = 255))]
+    pub name: Option<String>,
+
+    /// Product description (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 1000))]
+    pub description: Option<Option<String>>,
+
+    /// Product type
+    #[validate(length(min = 1, max = 50))]
+    pub product_type: Option<String>,
+
+    /// Barcode for product identification (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    #[validate(length(max = 50))]
+    pub barcode: Option<Option<String>>,
+
+    /// Type of barcode (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<BarcodeType>))]
+    pub barcode_type: Option<Option<BarcodeType>>,
+
+    /// Category ID (`null` removes the product from its category)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>, format = "uuid"))]
+    pub category_id: Option<Option<Uuid>>,
+
+    /// Item group ID (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>, format = "uuid"))]
+    pub item_group_id: Option<Option<Uuid>>,
+
+    /// Whether to track inventory
+    pub track_inventory: Option<bool>,
+
+    /// Inventory tracking method
+    pub tracking_method: Option<ProductTrackingMethod>,
+
+    /// Default unit of measure ID (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>, format = "uuid"))]
+    pub default_uom_id: Option<Option<Uuid>>,
+
+    /// Sale price in cents (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<i64>))]
+    #[validate(range(min = 0))]
+    pub sale_price: Option<Option<i64>>,
+
+    /// Cost price in cents (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<i64>))]
+    #[validate(range(min = 0))]
+    pub cost_price: Option<Option<i64>>,
+
+    /// Currency code
+    #[validate(length(min = 3, max = 3))]
+    pub currency_code: Option<String>,
+
+    /// Product weight in grams (`null` clears it)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<i32>))]
+    #[validate(range(min = 0))]
+    pub weight_grams: Option<Option<i32>>,
+
+    /// Product dimensions (JSON, `null` clears them)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<Object>))]
+    pub dimensions: Option<Option<serde_json::Value>>,
+
+    /// Additional product attributes (JSON, `null` clears them)
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<Object>))]
+    pub attributes: Option<Option<serde_json::Value>>,
+
+    /// Whether product is active
+    pub is_active: Option<bool>,
+
+    /// Whether product is available for sale
+    pub is_sellable: Option<bool>,
+
+    /// Whether product is available for purchase
+    pub is_purchaseable: Option<bool>,
+
+    /// Percentage of on-hand stock that may be reserved beyond availability (backordered)
+    #[validate(range(min = 0, max = 100))]
+    pub overcommit_pct: Option<i32>,
 }
 
 /// Product response DTO
@@ -219,6 +605,9 @@ pub struct ProductResponse {
     pub is_sellable: bool,
     pub is_purchaseable: bool,
 
+    /// Reservation over-commit buffer (percent)
+    pub overcommit_pct: i32,
+
     /// Audit fields
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -249,6 +638,7 @@ impl From<Product> for ProductResponse {
             is_active: product.is_active,
             is_sellable: product.is_sellable,
             is_purchaseable: product.is_purchaseable,
+            overcommit_pct: product.overcommit_pct,
             created_at: product.created_at,
             updated_at: product.updated_at,
         }
@@ -283,10 +673,10 @@ pub struct ProductListQuery {
     #[validate(range(min = 1))]
     pub page: i64,
 
-    /// Items per page
-    #[serde(default = "default_page_size")]
-    #[validate(range(min = 1, max = 100))]
-    pub page_size: i64,
+    /// Items per page (default: the configured page size, clamped to the configured maximum)
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub page_size: Option<i64>,
 
     /// Sort field
     #[serde(default = "default_sort_by")]
@@ -296,14 +686,72 @@ pub struct ProductListQuery {
     /// Sort direction
     #[serde(default = "default_sort_dir")]
     pub sort_dir: SortDirection,
+
+    /// Multi-field sort applied in order, e.g. `category:asc,name:asc`
+    ///
+    /// Takes precedence over `sort_by`/`sort_dir` when present.
+    #[serde(default, deserialize_with = "deserialize_sort_specs")]
+    #[validate(length(max = 5))]
+    #[cfg_attr(
+        feature = "openapi",
+        schema(value_type = Option<String>, example = "category:asc,name:asc"),
+        param(value_type = Option<String>, example = "category:asc,name:asc")
+    )]
+    pub sort: Vec<SortSpec>,
+
+    /// Whether to count the total matching products (skip for cheaper deep paging)
+    #[serde(default = "default_include_total")]
+    pub include_total: bool,
+
+    /// Attribute filters from `attr.<key>=<value>` parameters; all must match
+    #[serde(skip)]
+    pub attributes: ProductAttributes,
 }
 
-fn default_page() -> i64 {
-    1
+impl ProductListQuery {
+    /// Items per page, after [`PageSizeLimits::apply`] the configured value
+    ///
+    /// [`PageSizeLimits::apply`]: crate::dto::common::PageSizeLimits::apply
+    pub fn page_size(&self) -> i64 {
+        self.page_size.unwrap_or(DEFAULT_PAGE_SIZE as i64)
+    }
+
+    /// Effective sort keys for this query
+    ///
+    /// Uses `sort` when given, otherwise `sort_by`/`sort_dir`. A legacy
+    /// `sort_by` outside the allowlist yields no keys (default ordering).
+    pub fn sort_specs(&self) -> Vec<SortSpec> {
+        if !self.sort.is_empty() {
+            return self.sort.clone();
+        }
+        self.sort_by
+            .parse::<ProductSortField>()
+            .map(|field| {
+                vec![SortSpec {
+                    field,
+                    direction: self.sort_dir,
+                }]
+            })
+            .unwrap_or_default()
+    }
 }
 
-fn default_page_size() -> i64 {
-    20
+impl PaginatedQuery for ProductListQuery {
+    fn page(&self) -> i64 {
+        self.page
+    }
+
+    fn requested_page_size(&self) -> Option<i64> {
+        self.page_size
+    }
+
+    fn set_page_size(&mut self, page_size: u32) {
+        self.page_size = Some(page_size as i64);
+    }
+}
+
+fn default_page() -> i64 {
+    1
 }
 
 fn default_sort_by() -> String {
@@ -325,3 +773,130 @@ pub struct ProductListResponse {
     /// Pagination information
     pub pagination: PaginationInfo,
 }
+
+impl PaginatedResponse for ProductListResponse {
+    type Item = ProductResponse;
+
+    fn into_envelope(self) -> Envelope<Vec<ProductResponse>> {
+        Envelope {
+            data: self.products,
+            meta: self.pagination,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_query(query: &str) -> Result<ProductListQuery, serde_json::Error> {
+        let sort: serde_json::Value = query.into();
+        serde_json::from_value(serde_json::json!({ "sort": sort }))
+    }
+
+    #[test]
+    fn test_sort_specs_parse_in_order() {
+        let query = parse_query("category:asc, name:desc,sku").unwrap();
+
+        assert_eq!(
+            query.sort_specs(),
+            vec![
+                SortSpec {
+                    field: ProductSortField::Category,
+                    direction: SortDirection::Asc
+                },
+                SortSpec {
+                    field: ProductSortField::Name,
+                    direction: SortDirection::Desc
+                },
+                SortSpec {
+                    field: ProductSortField::Sku,
+                    direction: SortDirection::Asc
+                },
+            ]
+        );
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn test_unknown_sort_field_is_rejected() {
+        let err = parse_query("name:asc,price; DROP TABLE products").unwrap_err();
+        assert!(err.to_string().contains("unknown sort field"));
+
+        assert!(parse_query("name:sideways").is_err());
+    }
+
+    #[test]
+    fn test_sort_specs_fall_back_to_sort_by() {
+        let query: ProductListQuery =
+            serde_json::from_value(serde_json::json!({ "sortBy": "sku", "sortDir": "desc" }))
+                .unwrap();
+        assert_eq!(
+            query.sort_specs(),
+            vec![SortSpec {
+                field: ProductSortField::Sku,
+                direction: SortDirection::Desc
+            }]
+        );
+
+        let query: ProductListQuery =
+            serde_json::from_value(serde_json::json!({ "sortBy": "unknown" })).unwrap();
+        assert!(query.sort_specs().is_empty());
+    }
+
+    #[test]
+    fn test_too_many_sort_keys_fail_validation() {
+        let query = parse_query("name,sku,category,product_type,sale_price,cost_price").unwrap();
+        assert!(query.validate().is_err());
+    }
+    #[test]
+    fn test_parse_product_attributes() {
+        let attributes =
+            parse_product_attributes(&serde_json::json!({ "voltage": "220V", "color": "red" }))
+                .unwrap();
+        assert_eq!(attributes.get("voltage").map(String::as_str), Some("220V"));
+
+        assert!(parse_product_attributes(&serde_json::json!(["red"])).is_err());
+        assert!(parse_product_attributes(&serde_json::json!({ "voltage": 220 })).is_err());
+        assert!(parse_product_attributes(&serde_json::json!({ "Color": "red" })).is_err());
+        assert!(parse_product_attributes(&serde_json::json!({ "color": "  " })).is_err());
+    }
+
+    #[test]
+    fn test_attribute_key_validation() {
+        assert!(validate_attribute_key("battery_life-hours").is_ok());
+        assert!(validate_attribute_key("").is_err());
+        assert!(validate_attribute_key("1color").is_err());
+        assert!(validate_attribute_key("color'; DROP").is_err());
+        assert!(validate_attribute_key(&"a".repeat(MAX_ATTRIBUTE_KEY_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_too_many_attributes_rejected() {
+        let attributes: ProductAttributes = (0..=MAX_PRODUCT_ATTRIBUTES)
+            .map(|i| (format!("key{}", i), "value".to_string()))
+            .collect();
+        assert!(validate_product_attributes(&attributes).is_err());
+    }
+
+    #[test]
+    fn test_parse_attribute_filters() {
+        let params = vec![
+            ("page".to_string(), "2".to_string()),
+            ("attr.color".to_string(), "red".to_string()),
+            ("attr.size".to_string(), "L".to_string()),
+        ];
+        let filters = parse_attribute_filters(&params).unwrap();
+        assert_eq!(filters.len(), 2);
+        assert_eq!(filters.get("color").map(String::as_str), Some("red"));
+
+        let duplicate = vec![
+            ("attr.color".to_string(), "red".to_string()),
+            ("attr.color".to_string(), "blue".to_string()),
+        ];
+        assert!(parse_attribute_filters(&duplicate).is_err());
+
+        let bad_key = vec![("attr.".to_string(), "red".to_string())];
+        assert!(parse_attribute_filters(&bad_key).is_err());
+    }
+}
@@ -8,6 +8,7 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::dto::common::{default_include_total, PaginatedQuery, DEFAULT_PAGE_SIZE};
 use crate::dto::PaginationInfo;
 
 /// Request to create a new Goods Receipt Note
@@ -24,6 +25,12 @@ pub struct ReceiptCreateRequest {
     #[validate(length(max = 100))]
     pub reference_number: Option<String>,
 
+    /// Caller-supplied idempotency reference, unique per tenant.
+    /// Resubmitting a receipt with the same value returns the existing receipt.
+    #[serde(default)]
+    #[validate(length(min = 1, max = 100))]
+    pub external_reference: Option<String>,
+
     /// Expected delivery date from supplier
     pub expected_delivery_date: Option<chrono::DateTime<chrono::Utc>>,
 
@@ -77,6 +84,15 @@ pub struct ReceiptItemCreateRequest {
     pub notes: Option<String>,
 }
 
+/// Request to reverse a received goods receipt
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ReceiptReverseRequest {
+    /// Why the receipt is being reversed, e.g. "entered twice"
+    #[validate(length(min = 1, max = 500))]
+    pub reason: String,
+}
+
 /// Response containing created receipt details
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -99,6 +115,9 @@ pub struct ReceiptResponse {
     /// External reference number
     pub reference_number: Option<String>,
 
+    /// Caller-supplied idempotency reference
+    pub external_reference: Option<String>,
+
     /// Current receipt status
     pub status: String,
 
@@ -195,10 +214,10 @@ pub struct ReceiptListQuery {
     #[validate(range(min = 1))]
     pub page: u32,
 
-    /// Items per page
-    #[serde(default = "default_page_size")]
-    #[validate(range(min = 1, max = 100))]
-    pub page_size: u32,
+    /// Items per page (default: the configured page size, clamped to the configured maximum)
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub page_size: Option<u32>,
 
     /// Filter by warehouse
     pub warehouse_id: Option<Uuid>,
@@ -217,6 +236,33 @@ pub struct ReceiptListQuery {
 
     /// Filter receipts created before this date
     pub created_before: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Whether to count the total matching receipts (skip for cheaper deep paging)
+    #[serde(default = "default_include_total")]
+    pub include_total: bool,
+}
+
+impl ReceiptListQuery {
+    /// Items per page, after [`PageSizeLimits::apply`] the configured value
+    ///
+    /// [`PageSizeLimits::apply`]: crate::dto::common::PageSizeLimits::apply
+    pub fn page_size(&self) -> u32 {
+        self.page_size.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+}
+
+impl PaginatedQuery for ReceiptListQuery {
+    fn page(&self) -> i64 {
+        self.page as i64
+    }
+
+    fn requested_page_size(&self) -> Option<i64> {
+        self.page_size.map(i64::from)
+    }
+
+    fn set_page_size(&mut self, page_size: u32) {
+        self.page_size = Some(page_size);
+    }
 }
 
 /// Paginated response for receipt listing
@@ -278,7 +324,3 @@ pub struct ReceiptSummaryResponse {
 fn default_page() -> u32 {
     1
 }
-
-fn default_page_size() -> u32 {
-    20
-}
@@ -2,17 +2,20 @@
 //!
 //! This module contains request and response structures for reconciliation operations.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "openapi")]
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
+use super::product_import::ImportRowError;
 use super::stock_take::StockAdjustment;
 
 use crate::domains::inventory::reconciliation::{
     CycleType, ReconciliationStatus, StockReconciliation, StockReconciliationItem,
 };
+use crate::dto::common::default_include_total;
 use crate::dto::PaginationInfo;
 
 /// Request to create a new reconciliation session
@@ -134,6 +137,9 @@ pub struct ReconciliationListQuery {
     /// Items per page
     #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
     pub limit: Option<u32>,
+    /// Whether to count the total matching reconciliations (skip for cheaper deep paging)
+    #[serde(default = "default_include_total")]
+    pub include_total: bool,
 }
 
 /// Query parameters for reconciliation analytics
@@ -144,6 +150,86 @@ pub struct ReconciliationAnalyticsQuery {
     pub warehouse_id: Option<Uuid>,
 }
 
+/// Period granularity for reconciliation trend analytics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TrendGroupBy {
+    /// ISO weeks (starting Monday)
+    Week,
+    /// Calendar months
+    #[default]
+    Month,
+}
+
+impl TrendGroupBy {
+    /// PostgreSQL `date_trunc` field name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrendGroupBy::Week => "week",
+            TrendGroupBy::Month => "month",
+        }
+    }
+}
+
+/// Query parameters for the reconciliation variance trend
+///
+/// The `from`/`to` range is read separately as
+/// [`DateRangeParams`](crate::dto::common::DateRangeParams) and matched against
+/// completion time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+pub struct ReconciliationTrendQuery {
+    /// Period granularity (default: month)
+    #[serde(default)]
+    pub group_by: TrendGroupBy,
+    /// Warehouse ID filter
+    pub warehouse_id: Option<Uuid>,
+}
+
+/// Aggregated variance figures for one trend period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ReconciliationTrendPeriod {
+    /// Start of the period (UTC)
+    pub period_start: DateTime<Utc>,
+    /// Completed reconciliations in the period
+    pub reconciliation_count: i64,
+    /// Items counted across those reconciliations
+    pub counted_items: i64,
+    /// Counted items whose count differed from the expected quantity
+    pub variance_count: i64,
+    /// Sum of absolute variance values
+    pub variance_value: f64,
+    /// Percentage of counted items without variance (None when nothing was counted)
+    pub accuracy_percentage: Option<f64>,
+}
+
+impl ReconciliationTrendPeriod {
+    /// Accuracy as the share of counted items that matched the expected quantity
+    pub fn accuracy(counted_items: i64, variance_count: i64) -> Option<f64> {
+        if counted_items <= 0 {
+            return None;
+        }
+        let accurate = (counted_items - variance_count).max(0) as f64;
+        Some((accurate / counted_items as f64 * 10_000.0).round() / 100.0)
+    }
+}
+
+/// Reconciliation variance trend response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ReconciliationTrendResponse {
+    /// Period granularity used
+    pub group_by: TrendGroupBy,
+    /// Start of the range (inclusive)
+    pub from: DateTime<Utc>,
+    /// End of the range (exclusive)
+    pub to: DateTime<Utc>,
+    /// Periods with at least one completed reconciliation, oldest first
+    pub periods: Vec<ReconciliationTrendPeriod>,
+}
+
 /// Response for reconciliation list
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -235,3 +321,99 @@ pub struct ScanBarcodeResponse {
     /// Whether this was a new count or update
     pub is_new_count: bool,
 }
+
+/// One line of a stock snapshot file: `sku,location,counted_qty`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ReconciliationSnapshotRow {
+    /// Product SKU
+    pub sku: String,
+    /// Location code within the warehouse (optional)
+    #[serde(default)]
+    pub location: Option<String>,
+    /// Counted quantity
+    pub counted_qty: i64,
+}
+
+/// Request to create a reconciliation with counts from a stock snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ImportReconciliationRequest {
+    /// Reconciliation to create; `warehouse_id` is required
+    #[validate(nested)]
+    pub reconciliation: CreateReconciliationRequest,
+    /// Snapshot rows
+    #[validate(length(min = 1, message = "At least one row must be imported"))]
+    pub rows: Vec<ReconciliationSnapshotRow>,
+}
+
+/// Query parameters describing the reconciliation for a CSV snapshot import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+pub struct ReconciliationImportQuery {
+    /// Reconciliation name
+    pub name: String,
+    /// Optional description
+    pub description: Option<String>,
+    /// Warehouse the snapshot was taken in
+    pub warehouse_id: Uuid,
+    /// Optional notes
+    pub notes: Option<String>,
+}
+
+impl ReconciliationImportQuery {
+    /// Build the import request for rows parsed from the snapshot file
+    pub fn into_request(self, rows: Vec<ReconciliationSnapshotRow>) -> ImportReconciliationRequest {
+        ImportReconciliationRequest {
+            reconciliation: CreateReconciliationRequest {
+                name: self.name,
+                description: self.description,
+                cycle_type: CycleType::Full,
+                warehouse_id: Some(self.warehouse_id),
+                location_filter: None,
+                product_filter: None,
+                notes: self.notes,
+            },
+            rows,
+        }
+    }
+}
+
+/// Result of a snapshot import
+///
+/// The import is all-or-nothing: if any row cannot be resolved no
+/// reconciliation is created and every failing row is listed in `errors`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ImportReconciliationResponse {
+    /// The created reconciliation, absent when any row failed
+    pub reconciliation: Option<StockReconciliation>,
+    /// Counted reconciliation items
+    pub items: Vec<StockReconciliationItem>,
+    /// Per-row resolution errors (row numbers are 1-based data rows)
+    pub errors: Vec<ImportRowError>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trend_accuracy_percentage() {
+        assert_eq!(ReconciliationTrendPeriod::accuracy(8, 2), Some(75.0));
+        assert_eq!(ReconciliationTrendPeriod::accuracy(3, 1), Some(66.67));
+        assert_eq!(ReconciliationTrendPeriod::accuracy(5, 0), Some(100.0));
+        assert_eq!(ReconciliationTrendPeriod::accuracy(0, 0), None);
+    }
+
+    #[test]
+    fn test_trend_group_by_defaults_to_month() {
+        let query: ReconciliationTrendQuery = serde_json::from_value(serde_json::json!({
+            "from": "2025-01-01T00:00:00Z",
+            "to": "2025-04-01T00:00:00Z"
+        }))
+        .unwrap();
+        assert_eq!(query.group_by, TrendGroupBy::Month);
+        assert_eq!(query.group_by.as_str(), "month");
+    }
+}
@@ -252,8 +252,8 @@ mod tests {
         let info = PaginationInfo::new(1, 10, 25);
         assert_eq!(info.page, 1);
         assert_eq!(info.page_size, 10);
-        assert_eq!(info.total_items, 25);
-        assert_eq!(info.total_pages, 3);
+        assert_eq!(info.total_items, Some(25));
+        assert_eq!(info.total_pages, Some(3));
         assert!(info.has_next);
         assert!(!info.has_prev);
     }
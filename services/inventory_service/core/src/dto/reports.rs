@@ -24,6 +24,9 @@ pub enum AgingBasis {
     LastInbound,
     /// Age since last movement of any type
     LastMovement,
+    /// Age of the oldest receipt layer still on hand, with outbound moves
+    /// consuming layers in the order of the product's valuation method
+    CostLayers,
 }
 
 impl std::fmt::Display for AgingBasis {
@@ -31,6 +34,7 @@ impl std::fmt::Display for AgingBasis {
         match self {
             AgingBasis::LastInbound => write!(f, "last_inbound"),
             AgingBasis::LastMovement => write!(f, "last_movement"),
+            AgingBasis::CostLayers => write!(f, "cost_layers"),
         }
     }
 }
@@ -365,6 +369,129 @@ pub struct TurnoverReportResponse {
     pub page_size: u32,
 }
 
+// ============================================================================
+// Warehouse Capacity Utilization Report DTOs
+// ============================================================================
+
+/// Measure used to compare location occupancy against capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum CapacityBasis {
+    /// Units on hand against the location's `capacity` (base units)
+    #[default]
+    Quantity,
+    /// Product volume (cm³) against the location's length × width × height
+    Volume,
+}
+
+impl CapacityBasis {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CapacityBasis::Quantity => "quantity",
+            CapacityBasis::Volume => "volume",
+        }
+    }
+}
+
+/// Query parameters for warehouse capacity utilization report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(IntoParams, ToSchema))]
+pub struct CapacityReportQuery {
+    /// Warehouse ID filter (optional)
+    pub warehouse_id: Option<Uuid>,
+    /// Zone ID filter (optional)
+    pub zone_id: Option<Uuid>,
+    /// Occupancy measure (default: quantity)
+    #[serde(default)]
+    pub basis: CapacityBasis,
+}
+
+/// Occupancy of one location, as loaded from storage
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationOccupancy {
+    pub warehouse_id: Uuid,
+    pub warehouse_name: String,
+    pub zone_id: Option<Uuid>,
+    pub zone_name: Option<String>,
+    pub location_id: Uuid,
+    pub location_code: String,
+    /// Configured capacity in the report basis; None if not configured
+    pub capacity: Option<i64>,
+    /// Current occupancy in the report basis
+    pub occupancy: i64,
+}
+
+/// Utilization of a single location
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct LocationCapacityEntry {
+    /// Location ID
+    pub location_id: Uuid,
+    /// Location code
+    pub location_code: String,
+    /// Configured capacity (None if the location has no capacity set)
+    pub capacity: Option<i64>,
+    /// Current occupancy
+    pub occupancy: i64,
+    /// Occupancy as a percentage of capacity (None without capacity)
+    pub utilization_pct: Option<f64>,
+    /// Whether occupancy exceeds capacity
+    pub over_capacity: bool,
+}
+
+/// Utilization rolled up to a zone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ZoneCapacityEntry {
+    /// Zone ID (None for locations outside any zone)
+    pub zone_id: Option<Uuid>,
+    /// Zone name
+    pub zone_name: Option<String>,
+    /// Total capacity of locations with a configured capacity
+    pub capacity: i64,
+    /// Occupancy of locations with a configured capacity
+    pub occupancy: i64,
+    /// Occupancy as a percentage of capacity (None if no capacity is configured)
+    pub utilization_pct: Option<f64>,
+    /// Number of over-capacity locations in this zone
+    pub over_capacity_locations: u32,
+    /// Locations in this zone, ordered by code
+    pub locations: Vec<LocationCapacityEntry>,
+}
+
+/// Utilization rolled up to a warehouse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WarehouseCapacityEntry {
+    /// Warehouse ID
+    pub warehouse_id: Uuid,
+    /// Warehouse name
+    pub warehouse_name: String,
+    /// Total capacity of locations with a configured capacity
+    pub capacity: i64,
+    /// Occupancy of locations with a configured capacity
+    pub occupancy: i64,
+    /// Occupancy as a percentage of capacity (None if no capacity is configured)
+    pub utilization_pct: Option<f64>,
+    /// Number of over-capacity locations in this warehouse
+    pub over_capacity_locations: u32,
+    /// Zones in this warehouse, ordered by name
+    pub zones: Vec<ZoneCapacityEntry>,
+}
+
+/// Response for warehouse capacity utilization report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CapacityReportResponse {
+    /// Occupancy measure used
+    pub basis: CapacityBasis,
+    /// Warehouses ordered by name
+    pub warehouses: Vec<WarehouseCapacityEntry>,
+    /// Number of over-capacity locations across all warehouses
+    pub over_capacity_locations: u32,
+}
+
 // ============================================================================
 // Pure Domain Logic (no infrastructure dependencies)
 // ============================================================================
@@ -407,6 +534,201 @@ pub fn get_age_bucket_label(age_days: i32, buckets: &[AgeBucket]) -> String {
     "Unknown".to_string()
 }
 
+/// Occupancy as a percentage of capacity, rounded to two decimals
+/// Returns None if capacity is zero or negative
+pub fn calculate_utilization_pct(occupancy: i64, capacity: i64) -> Option<f64> {
+    if capacity <= 0 {
+        return None;
+    }
+    let pct = occupancy as f64 * 100.0 / capacity as f64;
+    Some((pct * 100.0).round() / 100.0)
+}
+
+/// Roll location occupancy up to zones and warehouses
+///
+/// Locations without a configured capacity are listed but left out of the
+/// zone and warehouse totals, so rollup percentages only cover measured space.
+pub fn build_capacity_report(
+    basis: CapacityBasis,
+    locations: Vec<LocationOccupancy>,
+) -> CapacityReportResponse {
+    let mut warehouses: Vec<WarehouseCapacityEntry> = Vec::new();
+
+    for location in locations {
+        let over_capacity = location
+            .capacity
+            .is_some_and(|capacity| location.occupancy > capacity);
+        let entry = LocationCapacityEntry {
+            location_id: location.location_id,
+            location_code: location.location_code,
+            capacity: location.capacity,
+            occupancy: location.occupancy,
+            utilization_pct: location
+                .capacity
+                .and_then(|capacity| calculate_utilization_pct(location.occupancy, capacity)),
+            over_capacity,
+        };
+
+        let warehouse_index = match warehouses
+            .iter()
+            .position(|w| w.warehouse_id == location.warehouse_id)
+        {
+            Some(index) => index,
+            None => {
+                warehouses.push(WarehouseCapacityEntry {
+                    warehouse_id: location.warehouse_id,
+                    warehouse_name: location.warehouse_name,
+                    capacity: 0,
+                    occupancy: 0,
+                    utilization_pct: None,
+                    over_capacity_locations: 0,
+                    zones: Vec::new(),
+                });
+                warehouses.len() - 1
+            },
+        };
+        let warehouse = &mut warehouses[warehouse_index];
+
+        let zone_index = match warehouse
+            .zones
+            .iter()
+            .position(|z| z.zone_id == location.zone_id)
+        {
+            Some(index) => index,
+            None => {
+                warehouse.zones.push(ZoneCapacityEntry {
+                    zone_id: location.zone_id,
+                    zone_name: location.zone_name,
+                    capacity: 0,
+                    occupancy: 0,
+                    utilization_pct: None,
+                    over_capacity_locations: 0,
+                    locations: Vec::new(),
+                });
+                warehouse.zones.len() - 1
+            },
+        };
+        let zone = &mut warehouse.zones[zone_index];
+
+        if let Some(capacity) = entry.capacity {
+            zone.capacity += capacity;
+            zone.occupancy += entry.occupancy;
+            warehouse.capacity += capacity;
+            warehouse.occupancy += entry.occupancy;
+        }
+        if over_capacity {
+            zone.over_capacity_locations += 1;
+            warehouse.over_capacity_locations += 1;
+        }
+        zone.locations.push(entry);
+    }
+
+    warehouses.sort_by(|a, b| a.warehouse_name.cmp(&b.warehouse_name));
+    for warehouse in &mut warehouses {
+        warehouse.utilization_pct =
+            calculate_utilization_pct(warehouse.occupancy, warehouse.capacity);
+        // Unzoned locations sort last
+        warehouse.zones.sort_by(|a, b| {
+            (a.zone_name.is_none(), &a.zone_name).cmp(&(b.zone_name.is_none(), &b.zone_name))
+        });
+        for zone in &mut warehouse.zones {
+            zone.utilization_pct = calculate_utilization_pct(zone.occupancy, zone.capacity);
+            zone.locations
+                .sort_by(|a, b| a.location_code.cmp(&b.location_code));
+        }
+    }
+
+    let over_capacity_locations = warehouses.iter().map(|w| w.over_capacity_locations).sum();
+    CapacityReportResponse {
+        basis,
+        warehouses,
+        over_capacity_locations,
+    }
+}
+
+/// Order in which outbound moves consume inbound layers for cost-layer aging
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerConsumptionOrder {
+    /// Oldest layer first
+    Fifo,
+    /// Newest layer first
+    Lifo,
+}
+
+impl LayerConsumptionOrder {
+    /// Consumption order for a product's valuation method
+    ///
+    /// Methods without their own layer order (AVCO, standard) follow the
+    /// physical FIFO flow.
+    pub fn for_valuation_method(method: &str) -> Self {
+        if method.eq_ignore_ascii_case("lifo") {
+            LayerConsumptionOrder::Lifo
+        } else {
+            LayerConsumptionOrder::Fifo
+        }
+    }
+}
+
+/// Quantity from a single inbound move that is still on hand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgingLayer {
+    /// When the layer was received
+    pub received_at: DateTime<Utc>,
+    /// Remaining quantity
+    pub quantity: i64,
+}
+
+/// Replay chronological moves into the layers still on hand
+///
+/// Positive quantities open a layer; negative quantities consume existing
+/// layers in `order`. Outbound quantity beyond what is on hand is ignored.
+/// Remaining layers are returned oldest first.
+pub fn remaining_layers(
+    moves: &[(DateTime<Utc>, i64)],
+    order: LayerConsumptionOrder,
+) -> Vec<AgingLayer> {
+    let mut layers: Vec<AgingLayer> = Vec::new();
+
+    for &(moved_at, quantity) in moves {
+        if quantity > 0 {
+            layers.push(AgingLayer {
+                received_at: moved_at,
+                quantity,
+            });
+            continue;
+        }
+
+        let mut to_consume = quantity.saturating_neg();
+        while to_consume > 0 {
+            let layer = match order {
+                LayerConsumptionOrder::Fifo => layers.first_mut(),
+                LayerConsumptionOrder::Lifo => layers.last_mut(),
+            };
+            let Some(layer) = layer else {
+                break;
+            };
+
+            let taken = layer.quantity.min(to_consume);
+            layer.quantity -= taken;
+            to_consume -= taken;
+
+            if layer.quantity == 0 {
+                match order {
+                    LayerConsumptionOrder::Fifo => layers.remove(0),
+                    LayerConsumptionOrder::Lifo => layers.pop().expect("layer exists"),
+                };
+            }
+        }
+    }
+
+    layers
+}
+
+/// Receipt date of the oldest layer still on hand
+pub fn oldest_remaining_layer(layers: &[AgingLayer]) -> Option<DateTime<Utc>> {
+    layers.iter().map(|layer| layer.received_at).min()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -463,6 +785,90 @@ mod tests {
         assert_eq!(get_age_bucket_label(1000, &buckets), "366+ days");
     }
 
+    /// Two receipts 110 days apart, then a delivery of one receipt's worth
+    fn receipt_delivery_history(as_of: DateTime<Utc>) -> Vec<(DateTime<Utc>, i64)> {
+        use chrono::Duration;
+        vec![
+            (as_of - Duration::days(120), 10),
+            (as_of - Duration::days(10), 10),
+            (as_of - Duration::days(5), -10),
+        ]
+    }
+
+    fn layer_age_bucket(
+        history: &[(DateTime<Utc>, i64)],
+        order: LayerConsumptionOrder,
+        as_of: DateTime<Utc>,
+    ) -> String {
+        let layers = remaining_layers(history, order);
+        let oldest = oldest_remaining_layer(&layers).expect("stock remains");
+        let age_days = (as_of - oldest).num_days() as i32;
+        get_age_bucket_label(age_days, &AgeBucketPreset::Default.buckets())
+    }
+
+    #[test]
+    fn test_fifo_and_lifo_aging_differ() {
+        let as_of = Utc::now();
+        let history = receipt_delivery_history(as_of);
+
+        // FIFO ships the old receipt, leaving the recent one
+        assert_eq!(layer_age_bucket(&history, LayerConsumptionOrder::Fifo, as_of), "0-30 days");
+        // LIFO ships the recent receipt, leaving the old one
+        assert_eq!(layer_age_bucket(&history, LayerConsumptionOrder::Lifo, as_of), "91-180 days");
+    }
+
+    #[test]
+    fn test_remaining_layers_partial_consumption() {
+        use chrono::Duration;
+        let as_of = Utc::now();
+        let (first, second) = (as_of - Duration::days(60), as_of - Duration::days(30));
+        let history = vec![(first, 10), (second, 10), (as_of, -15)];
+
+        assert_eq!(
+            remaining_layers(&history, LayerConsumptionOrder::Fifo),
+            vec![AgingLayer {
+                received_at: second,
+                quantity: 5
+            }]
+        );
+        assert_eq!(
+            remaining_layers(&history, LayerConsumptionOrder::Lifo),
+            vec![AgingLayer {
+                received_at: first,
+                quantity: 5
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lifo_only_consumes_layers_received_before_the_delivery() {
+        use chrono::Duration;
+        let as_of = Utc::now();
+        let old = as_of - Duration::days(100);
+        let recent = as_of - Duration::days(3);
+        // The delivery happens before the second receipt exists
+        let history = vec![(old, 10), (as_of - Duration::days(50), -10), (recent, 10)];
+
+        let layers = remaining_layers(&history, LayerConsumptionOrder::Lifo);
+        assert_eq!(oldest_remaining_layer(&layers), Some(recent));
+    }
+
+    #[test]
+    fn test_consumption_order_for_valuation_method() {
+        assert_eq!(
+            LayerConsumptionOrder::for_valuation_method("lifo"),
+            LayerConsumptionOrder::Lifo
+        );
+        assert_eq!(
+            LayerConsumptionOrder::for_valuation_method("fifo"),
+            LayerConsumptionOrder::Fifo
+        );
+        assert_eq!(
+            LayerConsumptionOrder::for_valuation_method("avco"),
+            LayerConsumptionOrder::Fifo
+        );
+    }
+
     #[test]
     fn test_period_days() {
         use chrono::TimeZone;
@@ -479,4 +885,108 @@ mod tests {
         };
         assert_eq!(query.period_days(), 91);
     }
+
+    fn location(
+        warehouse: (Uuid, &str),
+        zone: Option<(Uuid, &str)>,
+        code: &str,
+        capacity: Option<i64>,
+        occupancy: i64,
+    ) -> LocationOccupancy {
+        LocationOccupancy {
+            warehouse_id: warehouse.0,
+            warehouse_name: warehouse.1.to_string(),
+            zone_id: zone.map(|z| z.0),
+            zone_name: zone.map(|z| z.1.to_string()),
+            location_id: Uuid::new_v4(),
+            location_code: code.to_string(),
+            capacity,
+            occupancy,
+        }
+    }
+
+    #[test]
+    fn test_utilization_pct() {
+        assert_eq!(calculate_utilization_pct(50, 200), Some(25.0));
+        assert_eq!(calculate_utilization_pct(1, 3), Some(33.33));
+        assert_eq!(calculate_utilization_pct(120, 100), Some(120.0));
+        assert_eq!(calculate_utilization_pct(10, 0), None);
+    }
+
+    #[test]
+    fn test_capacity_report_rollup() {
+        let main = (Uuid::new_v4(), "Main");
+        let overflow = (Uuid::new_v4(), "Overflow");
+        let bulk = (Uuid::new_v4(), "Bulk");
+        let picking = (Uuid::new_v4(), "Picking");
+
+        let report = build_capacity_report(
+            CapacityBasis::Quantity,
+            vec![
+                location(overflow, None, "O-01", Some(100), 10),
+                location(main, Some(picking), "P-02", Some(100), 100),
+                location(main, Some(bulk), "B-01", Some(100), 20),
+                location(main, Some(picking), "P-01", Some(100), 50),
+                location(main, Some(bulk), "B-02", Some(300), 340),
+                // Unmeasured locations are listed but not rolled up
+                location(main, Some(bulk), "B-03", None, 75),
+            ],
+        );
+
+        assert_eq!(report.basis, CapacityBasis::Quantity);
+        assert_eq!(report.over_capacity_locations, 1);
+        let names: Vec<_> = report
+            .warehouses
+            .iter()
+            .map(|w| w.warehouse_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Main", "Overflow"]);
+
+        let main_report = &report.warehouses[0];
+        // (20 + 340 + 50 + 100) / (100 + 300 + 100 + 100)
+        assert_eq!(main_report.capacity, 600);
+        assert_eq!(main_report.occupancy, 510);
+        assert_eq!(main_report.utilization_pct, Some(85.0));
+        assert_eq!(main_report.over_capacity_locations, 1);
+
+        let bulk_zone = &main_report.zones[0];
+        assert_eq!(bulk_zone.zone_name.as_deref(), Some("Bulk"));
+        assert_eq!(bulk_zone.capacity, 400);
+        assert_eq!(bulk_zone.occupancy, 360);
+        assert_eq!(bulk_zone.utilization_pct, Some(90.0));
+        assert_eq!(bulk_zone.over_capacity_locations, 1);
+        let codes: Vec<_> = bulk_zone
+            .locations
+            .iter()
+            .map(|l| l.location_code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["B-01", "B-02", "B-03"]);
+        assert!(bulk_zone.locations[1].over_capacity);
+        assert_eq!(bulk_zone.locations[1].utilization_pct, Some(113.33));
+        assert_eq!(bulk_zone.locations[2].utilization_pct, None);
+        assert!(!bulk_zone.locations[2].over_capacity);
+
+        let picking_zone = &main_report.zones[1];
+        assert_eq!(picking_zone.utilization_pct, Some(75.0));
+        // Exactly full is not over capacity
+        assert_eq!(picking_zone.over_capacity_locations, 0);
+
+        let overflow_report = &report.warehouses[1];
+        assert_eq!(overflow_report.utilization_pct, Some(10.0));
+        assert_eq!(overflow_report.zones[0].zone_id, None);
+    }
+
+    #[test]
+    fn test_capacity_report_without_configured_capacity() {
+        let main = (Uuid::new_v4(), "Main");
+        let report = build_capacity_report(
+            CapacityBasis::Volume,
+            vec![location(main, None, "FLOOR", None, 500)],
+        );
+
+        let warehouse = &report.warehouses[0];
+        assert_eq!(warehouse.capacity, 0);
+        assert_eq!(warehouse.utilization_pct, None);
+        assert_eq!(report.over_capacity_locations, 0);
+    }
 }
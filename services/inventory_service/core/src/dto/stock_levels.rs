@@ -11,9 +11,10 @@ use validator::Validate;
 use utoipa::{IntoParams, ToSchema};
 
 use super::common::PaginationInfo;
+use crate::models::InventoryLevelHistoryEntry;
 
 /// Query parameters for listing stock levels
-#[derive(Debug, Clone, Deserialize, Default, Validate)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Validate)]
 #[cfg_attr(feature = "openapi", derive(IntoParams))]
 #[serde(rename_all = "camelCase")]
 pub struct StockLevelListQuery {
@@ -79,6 +80,8 @@ pub struct StockLevelResponse {
     pub available_quantity: i64,
     /// Reserved quantity (held for orders)
     pub reserved_quantity: i64,
+    /// Backordered quantity (reserved beyond physical stock via over-commit)
+    pub backordered_quantity: i64,
     /// Total quantity (available + reserved)
     pub total_quantity: i64,
     /// Stock status based on quantity
@@ -129,6 +132,8 @@ pub struct StockLevelSummary {
     pub total_available_quantity: i64,
     /// Total reserved quantity across all products
     pub total_reserved_quantity: i64,
+    /// Total backordered quantity across all products
+    pub total_backordered_quantity: i64,
     /// Number of products with low stock
     pub low_stock_count: i64,
     /// Number of products that are out of stock
@@ -147,3 +152,31 @@ pub struct StockLevelListResponse {
     /// Summary statistics
     pub summary: StockLevelSummary,
 }
+
+/// Query parameters for a location's level history
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct LevelHistoryQuery {
+    /// Location to read the timeline for
+    pub location_id: Uuid,
+    /// Product to read the timeline for
+    pub product_id: Uuid,
+    /// Only changes recorded at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only changes recorded before this time
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Quantity changes for a product at a location with running balances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LevelHistoryResponse {
+    /// Location ID
+    pub location_id: Uuid,
+    /// Product ID
+    pub product_id: Uuid,
+    /// Changes in the order they were recorded
+    pub entries: Vec<InventoryLevelHistoryEntry>,
+}
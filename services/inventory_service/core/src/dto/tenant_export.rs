@@ -0,0 +1,78 @@
+//! Tenant Export Data Transfer Objects
+//!
+//! A tenant export is an NDJSON file with one line per row, each tagged with
+//! the entity it came from: `{"entity": "product", "data": {...}}`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// MIME type of the export file
+pub const TENANT_EXPORT_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Kinds of rows included in a tenant export, in the order they are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TenantExportEntity {
+    Category,
+    Product,
+    Warehouse,
+    InventoryLevel,
+    StockMove,
+    Valuation,
+    ValuationLayer,
+    ValuationHistory,
+}
+
+impl TenantExportEntity {
+    /// Every exported entity, parents before the rows that reference them
+    pub const ALL: [TenantExportEntity; 8] = [
+        TenantExportEntity::Category,
+        TenantExportEntity::Product,
+        TenantExportEntity::Warehouse,
+        TenantExportEntity::InventoryLevel,
+        TenantExportEntity::StockMove,
+        TenantExportEntity::Valuation,
+        TenantExportEntity::ValuationLayer,
+        TenantExportEntity::ValuationHistory,
+    ];
+
+    /// Tag written in the `entity` field of each export line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TenantExportEntity::Category => "category",
+            TenantExportEntity::Product => "product",
+            TenantExportEntity::Warehouse => "warehouse",
+            TenantExportEntity::InventoryLevel => "inventory_level",
+            TenantExportEntity::StockMove => "stock_move",
+            TenantExportEntity::Valuation => "valuation",
+            TenantExportEntity::ValuationLayer => "valuation_layer",
+            TenantExportEntity::ValuationHistory => "valuation_history",
+        }
+    }
+}
+
+/// Number of rows exported for one entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TenantExportEntityCount {
+    pub entity: TenantExportEntity,
+    pub rows: u64,
+}
+
+/// A completed tenant export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TenantExportResponse {
+    pub export_id: Uuid,
+    pub tenant_id: Uuid,
+    /// Presigned URL the NDJSON file can be downloaded from
+    pub download_url: String,
+    /// When the download URL stops working
+    pub expires_at: DateTime<Utc>,
+    /// Rows written per entity, in file order
+    pub counts: Vec<TenantExportEntityCount>,
+}
@@ -0,0 +1,130 @@
+//! Tenant Provisioning Data Transfer Objects
+//!
+//! Describes the baseline entities a new tenant is seeded with and the
+//! outcome of a seed run.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domains::inventory::dto::common::validate_removal_strategy_type;
+
+/// Name of the tenant-wide removal strategy created by the seed
+pub const DEFAULT_REMOVAL_STRATEGY_NAME: &str = "Default";
+
+/// Default warehouse created for a new tenant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarehouseSeed {
+    pub code: String,
+    pub name: String,
+}
+
+/// The set of default entities seeded into a tenant
+///
+/// Each entry is optional; a `None` entry is skipped by the seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantSeedConfig {
+    pub warehouse: Option<WarehouseSeed>,
+    /// Name of the root category for products without one
+    pub category_name: Option<String>,
+    /// Strategy type of the tenant-wide removal strategy
+    pub removal_strategy: Option<String>,
+}
+
+impl TenantSeedConfig {
+    /// Build a seed set from configuration values
+    ///
+    /// A blank warehouse code, category name or strategy type leaves that
+    /// entity out of the seed. A blank warehouse name falls back to the code.
+    pub fn new(
+        warehouse_code: &str,
+        warehouse_name: &str,
+        category_name: &str,
+        removal_strategy: &str,
+    ) -> Result<Self, String> {
+        let non_blank = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+
+        let removal_strategy = non_blank(removal_strategy).map(|s| s.to_ascii_lowercase());
+        if let Some(strategy_type) = &removal_strategy {
+            validate_removal_strategy_type(strategy_type)
+                .map_err(|_| format!("Unknown removal strategy type: {}", strategy_type))?;
+        }
+
+        Ok(Self {
+            warehouse: non_blank(warehouse_code).map(|code| WarehouseSeed {
+                name: non_blank(warehouse_name).unwrap_or_else(|| code.clone()),
+                code,
+            }),
+            category_name: non_blank(category_name),
+            removal_strategy,
+        })
+    }
+}
+
+impl Default for TenantSeedConfig {
+    fn default() -> Self {
+        Self {
+            warehouse: Some(WarehouseSeed {
+                code: "MAIN".to_string(),
+                name: "Main Warehouse".to_string(),
+            }),
+            category_name: Some("Uncategorized".to_string()),
+            removal_strategy: Some("fifo".to_string()),
+        }
+    }
+}
+
+/// A default entity after seeding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SeededEntity {
+    pub id: Uuid,
+    /// `false` when the entity already existed and was left untouched
+    pub created: bool,
+}
+
+/// Result of seeding a tenant's defaults
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TenantSeedResponse {
+    pub tenant_id: Uuid,
+    /// Default warehouse, if part of the seed set
+    pub warehouse: Option<SeededEntity>,
+    /// Default root category, if part of the seed set
+    pub category: Option<SeededEntity>,
+    /// Tenant-wide removal strategy, if part of the seed set
+    pub removal_strategy: Option<SeededEntity>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_config_from_values() {
+        let config = TenantSeedConfig::new("MAIN", "Main Warehouse", "Uncategorized", "FIFO")
+            .expect("valid seed config");
+        assert_eq!(config, TenantSeedConfig::default());
+    }
+
+    #[test]
+    fn test_seed_config_blank_values_disable_entities() {
+        let config = TenantSeedConfig::new(" ", "Ignored", "", "").unwrap();
+        assert_eq!(config.warehouse, None);
+        assert_eq!(config.category_name, None);
+        assert_eq!(config.removal_strategy, None);
+
+        let config = TenantSeedConfig::new("WH1", "", "", "").unwrap();
+        assert_eq!(config.warehouse.unwrap().name, "WH1");
+    }
+
+    #[test]
+    fn test_seed_config_rejects_unknown_strategy() {
+        let err = TenantSeedConfig::new("MAIN", "Main", "Uncategorized", "random").unwrap_err();
+        assert!(err.contains("random"));
+    }
+}
@@ -174,6 +174,33 @@ pub struct DeliveryOrderItem {
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+/// A single parcel shipped against a delivery order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryShipment {
+    pub shipment_id: Uuid,
+    pub tenant_id: Uuid,
+    pub delivery_id: Uuid,
+    pub tracking_number: Option<String>,
+    pub carrier: Option<String>,
+    pub shipping_cost: Option<i64>, // in cents
+    pub notes: Option<String>,
+    pub shipped_by: Uuid,
+    pub shipped_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Quantity of a delivery line included in a shipment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryShipmentItem {
+    pub shipment_item_id: Uuid,
+    pub tenant_id: Uuid,
+    pub shipment_id: Uuid,
+    pub delivery_item_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: i64,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateDeliveryOrderRequest {
     pub reference_number: Option<String>,
@@ -263,6 +290,141 @@ pub struct StockMove {
     pub batch_info: Option<serde_json::Value>,
     pub metadata: Option<serde_json::Value>,
     pub created_at: DateTime<Utc>,
+    /// Machine-readable reason, e.g. `goods_receipt`, `damaged`, `reversal`
+    pub reason_code: Option<String>,
+    /// Type of the document that caused the move
+    pub source_type: Option<String>,
+    /// ID of the document that caused the move
+    pub source_id: Option<Uuid>,
+    /// Original move, when this move is a reversal
+    pub reversal_of_move_id: Option<Uuid>,
+}
+
+/// Move type written for compensating moves created by `reverse_move`
+pub const REVERSAL_MOVE_TYPE: &str = "reversal";
+
+/// Reason code written for compensating moves created by `reverse_move`
+pub const REVERSAL_REASON_CODE: &str = "reversal";
+
+/// Outcome of a stock reservation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationStatus {
+    /// Fully covered by available stock
+    Reserved,
+    /// Exceeded available stock within the product's over-commit buffer;
+    /// the uncovered part is tracked as backordered quantity
+    Backordered,
+}
+
+impl fmt::Display for ReservationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ReservationStatus::Reserved => "reserved",
+            ReservationStatus::Backordered => "backordered",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Lifecycle of a queued stock reservation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+pub enum StockReservationStatus {
+    /// Waiting for stock; nothing allocated yet
+    Pending,
+    /// Requested quantity fully allocated
+    Reserved,
+    /// Partially or not allocated; the remainder waits for more stock
+    Backordered,
+    /// Handed back (e.g. the order was cancelled); no longer allocated
+    Released,
+}
+
+impl fmt::Display for StockReservationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            StockReservationStatus::Pending => "pending",
+            StockReservationStatus::Reserved => "reserved",
+            StockReservationStatus::Backordered => "backordered",
+            StockReservationStatus::Released => "released",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A reservation competing for a product's stock
+///
+/// When stock is scarce, open reservations are filled by `priority` (higher
+/// first, e.g. paid orders before quotes) and then by creation order.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StockReservation {
+    pub reservation_id: Uuid,
+    pub tenant_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    /// Type of the document holding the reservation, e.g. `sales_order`
+    pub source_type: Option<String>,
+    /// ID of the document holding the reservation
+    pub source_id: Option<Uuid>,
+    pub priority: i32,
+    pub requested_quantity: i64,
+    pub allocated_quantity: i64,
+    pub backordered_quantity: i64,
+    pub status: StockReservationStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StockReservation {
+    /// Quantity still waiting to be allocated
+    pub fn outstanding_quantity(&self) -> i64 {
+        (self.requested_quantity - self.allocated_quantity).max(0)
+    }
+}
+
+/// A reservation to queue for allocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewStockReservation {
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: i64,
+    pub priority: i32,
+    pub source_type: Option<String>,
+    pub source_id: Option<Uuid>,
+}
+
+/// Distribute scarce stock over open reservations
+///
+/// Reservations are served by priority (highest first), then creation order.
+/// Each one takes as much of its outstanding quantity as is left; whatever
+/// cannot be covered is recorded as backordered. Reservations are updated in
+/// place and left in allocation order. Returns the quantity left unallocated.
+pub fn allocate_by_priority(reservations: &mut [StockReservation], available: i64) -> i64 {
+    reservations.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then(a.created_at.cmp(&b.created_at))
+            .then(a.reservation_id.cmp(&b.reservation_id))
+    });
+
+    let mut remaining = available.max(0);
+    for reservation in reservations.iter_mut() {
+        let share = reservation.outstanding_quantity().min(remaining);
+        remaining -= share;
+        reservation.allocated_quantity += share;
+        reservation.backordered_quantity = reservation.outstanding_quantity();
+        reservation.status = if reservation.backordered_quantity == 0 {
+            StockReservationStatus::Reserved
+        } else {
+            StockReservationStatus::Backordered
+        };
+    }
+
+    remaining
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -278,21 +440,226 @@ pub struct InventoryLevel {
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+/// One change to a product's quantity at a location, with the balance after it
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct InventoryLevelHistoryEntry {
+    pub history_id: i64,
+    /// Stock move that caused the change
+    pub move_id: Uuid,
+    pub move_type: String,
+    pub quantity_change: i64,
+    /// Quantity at the location after this change
+    pub balance: i64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Quantity change per location caused by a stock move
+///
+/// A move that stays in one location (adjustments, counts) changes it by the
+/// signed quantity. A move between two locations takes `|quantity|` out of the
+/// source and puts it into the destination, so reversals (swapped locations,
+/// negated quantity) undo the original. A missing location, such as the
+/// supplier side of a receipt, is not tracked.
+pub fn location_deltas(
+    source_location_id: Option<Uuid>,
+    destination_location_id: Option<Uuid>,
+    quantity: i64,
+) -> Vec<(Uuid, i64)> {
+    match (source_location_id, destination_location_id) {
+        (Some(source), Some(destination)) if source == destination => vec![(source, quantity)],
+        (source, destination) => {
+            let magnitude = quantity.abs();
+            source
+                .map(|location| (location, -magnitude))
+                .into_iter()
+                .chain(destination.map(|location| (location, magnitude)))
+                .collect()
+        },
+    }
+}
+
+/// Document type that causes a stock move
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MoveSourceType {
+    Receipt,
+    Delivery,
+    Transfer,
+    Scrap,
+    Adjustment,
+    Rma,
+    CycleCount,
+    StockTake,
+    Reconciliation,
+    Putaway,
+}
+
+impl MoveSourceType {
+    /// Value stored in `stock_moves.source_type`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MoveSourceType::Receipt => "receipt",
+            MoveSourceType::Delivery => "delivery",
+            MoveSourceType::Transfer => "transfer",
+            MoveSourceType::Scrap => "scrap",
+            MoveSourceType::Adjustment => "adjustment",
+            MoveSourceType::Rma => "rma",
+            MoveSourceType::CycleCount => "cycle_count",
+            MoveSourceType::StockTake => "stock_take",
+            MoveSourceType::Reconciliation => "reconciliation",
+            MoveSourceType::Putaway => "putaway",
+        }
+    }
+
+    /// Value stored in `stock_moves.move_type` for moves from this source
+    pub fn move_type(&self) -> &'static str {
+        match self {
+            MoveSourceType::Receipt => "receipt",
+            MoveSourceType::Delivery => "delivery",
+            MoveSourceType::Transfer => "transfer",
+            MoveSourceType::Scrap => "scrap",
+            MoveSourceType::Rma => "rma_return",
+            MoveSourceType::Putaway => "putaway",
+            MoveSourceType::Adjustment
+            | MoveSourceType::CycleCount
+            | MoveSourceType::StockTake
+            | MoveSourceType::Reconciliation => "adjustment",
+        }
+    }
+
+    /// Default value for the legacy `stock_moves.reference_type` column
+    pub fn reference_type(&self) -> &'static str {
+        match self {
+            MoveSourceType::Receipt => "grn",
+            MoveSourceType::Delivery => "do",
+            other => other.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for MoveSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MoveSourceType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "receipt" => Ok(MoveSourceType::Receipt),
+            "delivery" => Ok(MoveSourceType::Delivery),
+            "transfer" => Ok(MoveSourceType::Transfer),
+            "scrap" => Ok(MoveSourceType::Scrap),
+            "adjustment" => Ok(MoveSourceType::Adjustment),
+            "rma" => Ok(MoveSourceType::Rma),
+            "cycle_count" => Ok(MoveSourceType::CycleCount),
+            "stock_take" => Ok(MoveSourceType::StockTake),
+            "reconciliation" => Ok(MoveSourceType::Reconciliation),
+            "putaway" => Ok(MoveSourceType::Putaway),
+            _ => Err(format!("Unknown stock move source type: {}", s)),
+        }
+    }
+}
+
+/// A stock move to be recorded through `StockMoveRepository::record`.
+///
+/// Every move names its source document and a reason code; `move_type` and
+/// `reference_type` are derived from the source type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreateStockMoveRequest {
+pub struct MoveIntent {
+    pub source_type: MoveSourceType,
+    pub source_id: Uuid,
+    pub reason_code: String,
     pub product_id: Uuid,
+    pub quantity: i64,
+    pub idempotency_key: String,
     pub source_location_id: Option<Uuid>,
     pub destination_location_id: Option<Uuid>,
-    pub move_type: String,
-    pub quantity: i64,
     pub unit_cost: Option<i64>,
-    pub reference_type: String,
-    pub reference_id: Uuid,
     pub lot_serial_id: Option<Uuid>,
-    pub idempotency_key: String,
+    /// Free-text note stored in `move_reason`
     pub move_reason: Option<String>,
     pub batch_info: Option<serde_json::Value>,
     pub metadata: Option<serde_json::Value>,
+    /// Overrides the reference type derived from `source_type`
+    pub reference_type: Option<String>,
+}
+
+impl MoveIntent {
+    pub fn new(
+        source_type: MoveSourceType,
+        source_id: Uuid,
+        reason_code: impl Into<String>,
+        product_id: Uuid,
+        quantity: i64,
+        idempotency_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            source_type,
+            source_id,
+            reason_code: reason_code.into(),
+            product_id,
+            quantity,
+            idempotency_key: idempotency_key.into(),
+            source_location_id: None,
+            destination_location_id: None,
+            unit_cost: None,
+            lot_serial_id: None,
+            move_reason: None,
+            batch_info: None,
+            metadata: None,
+            reference_type: None,
+        }
+    }
+
+    pub fn with_locations(mut self, source: Option<Uuid>, destination: Option<Uuid>) -> Self {
+        self.source_location_id = source;
+        self.destination_location_id = destination;
+        self
+    }
+
+    pub fn with_unit_cost(mut self, unit_cost: Option<i64>) -> Self {
+        self.unit_cost = unit_cost;
+        self
+    }
+
+    pub fn with_lot_serial(mut self, lot_serial_id: Option<Uuid>) -> Self {
+        self.lot_serial_id = lot_serial_id;
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.move_reason = Some(note.into());
+        self
+    }
+
+    pub fn with_batch_info(mut self, batch_info: Option<serde_json::Value>) -> Self {
+        self.batch_info = batch_info;
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: Option<serde_json::Value>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn with_reference_type(mut self, reference_type: impl Into<String>) -> Self {
+        self.reference_type = Some(reference_type.into());
+        self
+    }
+
+    pub fn move_type(&self) -> &'static str {
+        self.source_type.move_type()
+    }
+
+    pub fn reference_type(&self) -> &str {
+        self.reference_type
+            .as_deref()
+            .unwrap_or_else(|| self.source_type.reference_type())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, sqlx::Type)]
@@ -797,3 +1164,140 @@ pub struct ConfirmPutawayResponse {
     pub stock_moves_created: Vec<Uuid>,
     pub total_quantity_putaway: i64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_SOURCES: [MoveSourceType; 10] = [
+        MoveSourceType::Receipt,
+        MoveSourceType::Delivery,
+        MoveSourceType::Transfer,
+        MoveSourceType::Scrap,
+        MoveSourceType::Adjustment,
+        MoveSourceType::Rma,
+        MoveSourceType::CycleCount,
+        MoveSourceType::StockTake,
+        MoveSourceType::Reconciliation,
+        MoveSourceType::Putaway,
+    ];
+
+    #[test]
+    fn test_move_source_type_round_trip() {
+        for source in ALL_SOURCES {
+            assert_eq!(source.as_str().parse::<MoveSourceType>(), Ok(source));
+        }
+        assert!("manual".parse::<MoveSourceType>().is_err());
+    }
+
+    #[test]
+    fn test_move_source_type_legacy_columns() {
+        assert_eq!(MoveSourceType::Receipt.move_type(), "receipt");
+        assert_eq!(MoveSourceType::Receipt.reference_type(), "grn");
+        assert_eq!(MoveSourceType::Delivery.reference_type(), "do");
+        assert_eq!(MoveSourceType::Rma.move_type(), "rma_return");
+        assert_eq!(MoveSourceType::CycleCount.move_type(), "adjustment");
+        assert_eq!(MoveSourceType::CycleCount.reference_type(), "cycle_count");
+    }
+
+    #[test]
+    fn test_move_intent_reference_type_override() {
+        let source_id = Uuid::now_v7();
+        let intent = MoveIntent::new(
+            MoveSourceType::Putaway,
+            source_id,
+            "putaway",
+            Uuid::now_v7(),
+            5,
+            "key",
+        );
+        assert_eq!(intent.move_type(), "putaway");
+        assert_eq!(intent.reference_type(), "putaway");
+
+        let intent = intent.with_reference_type("grn");
+        assert_eq!(intent.reference_type(), "grn");
+        assert_eq!(intent.source_type.as_str(), "putaway");
+        assert_eq!(intent.source_id, source_id);
+    }
+
+    #[test]
+    fn test_location_deltas() {
+        let a = Uuid::now_v7();
+        let b = Uuid::now_v7();
+
+        assert_eq!(location_deltas(None, Some(a), 10), vec![(a, 10)]);
+        assert_eq!(location_deltas(Some(a), None, 4), vec![(a, -4)]);
+        assert_eq!(location_deltas(Some(a), Some(b), 3), vec![(a, -3), (b, 3)]);
+        assert_eq!(location_deltas(Some(a), Some(a), -2), vec![(a, -2)]);
+        assert!(location_deltas(None, None, 5).is_empty());
+
+        // A reversal swaps the locations and negates the quantity
+        assert_eq!(location_deltas(Some(b), Some(a), -3), vec![(b, -3), (a, 3)]);
+        assert_eq!(location_deltas(Some(a), None, -10), vec![(a, -10)]);
+    }
+
+    fn pending_reservation(priority: i32, quantity: i64, age_secs: i64) -> StockReservation {
+        let created_at = Utc::now() - chrono::Duration::seconds(age_secs);
+        StockReservation {
+            reservation_id: Uuid::now_v7(),
+            tenant_id: Uuid::nil(),
+            warehouse_id: Uuid::nil(),
+            product_id: Uuid::nil(),
+            source_type: None,
+            source_id: None,
+            priority,
+            requested_quantity: quantity,
+            allocated_quantity: 0,
+            backordered_quantity: 0,
+            status: StockReservationStatus::Pending,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn test_allocate_by_priority_then_creation_order() {
+        let quote = pending_reservation(0, 5, 300);
+        let older_paid = pending_reservation(10, 6, 200);
+        let newer_paid = pending_reservation(10, 4, 100);
+        let ids = [
+            older_paid.reservation_id,
+            newer_paid.reservation_id,
+            quote.reservation_id,
+        ];
+        let mut reservations = vec![quote, newer_paid, older_paid];
+
+        let left = allocate_by_priority(&mut reservations, 8);
+
+        assert_eq!(left, 0);
+        let allocated: Vec<(Uuid, i64, i64, StockReservationStatus)> = reservations
+            .iter()
+            .map(|r| (r.reservation_id, r.allocated_quantity, r.backordered_quantity, r.status))
+            .collect();
+        assert_eq!(
+            allocated,
+            vec![
+                (ids[0], 6, 0, StockReservationStatus::Reserved),
+                (ids[1], 2, 2, StockReservationStatus::Backordered),
+                (ids[2], 0, 5, StockReservationStatus::Backordered),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_allocate_by_priority_tops_up_backorders() {
+        let mut partial = pending_reservation(5, 10, 60);
+        partial.allocated_quantity = 7;
+        partial.backordered_quantity = 3;
+        partial.status = StockReservationStatus::Backordered;
+        let mut reservations = vec![partial, pending_reservation(1, 2, 30)];
+
+        let left = allocate_by_priority(&mut reservations, 20);
+
+        assert_eq!(left, 15);
+        assert!(reservations
+            .iter()
+            .all(|r| r.status == StockReservationStatus::Reserved && r.backordered_quantity == 0));
+        assert_eq!(reservations[0].allocated_quantity, 10);
+    }
+}
@@ -88,6 +88,21 @@ pub trait CategoryRepository: Send + Sync + 'static {
     /// True if deleted, false if not found
     async fn hard_delete(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
 
+    /// Restore a soft-deleted category
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `category_id` - Category to restore
+    ///
+    /// # Returns
+    /// The restored category (unchanged if it was not deleted)
+    ///
+    /// # Errors
+    /// - `NotFound` if the category doesn't exist
+    /// - `Conflict` if an ancestor is still deleted, or a live category has
+    ///   since taken its code or slug
+    async fn restore(&self, tenant_id: Uuid, category_id: Uuid) -> Result<Category>;
+
     // ========================================================================
     // Query Operations
     // ========================================================================
@@ -99,12 +114,13 @@ pub trait CategoryRepository: Send + Sync + 'static {
     /// * `query` - Query parameters for filtering and pagination
     ///
     /// # Returns
-    /// Tuple of (categories, total_count)
+    /// Tuple of (categories, total_count). When `query.include_total` is false the
+    /// count is skipped (`None`) and one extra look-ahead row is returned.
     async fn list(
         &self,
         tenant_id: Uuid,
         query: &CategoryListQuery,
-    ) -> Result<(Vec<Category>, i64)>;
+    ) -> Result<(Vec<Category>, Option<i64>)>;
 
     /// Get all root categories (no parent)
     ///
@@ -230,6 +246,18 @@ pub trait CategoryRepository: Send + Sync + 'static {
     /// Number of categories updated
     async fn update_product_counts(&self, tenant_id: Uuid, category_id: Uuid) -> Result<i32>;
 
+    /// Recompute product counts for every category of a tenant
+    ///
+    /// Corrects drift in `product_count`/`total_product_count` (e.g. after bulk
+    /// moves or restores) in a single statement scoped to the tenant.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    ///
+    /// # Returns
+    /// Number of categories whose counts were corrected
+    async fn recount_all_product_counts(&self, tenant_id: Uuid) -> Result<u64>;
+
     // ========================================================================
     // Bulk Operations
     // ========================================================================
@@ -250,6 +278,30 @@ pub trait CategoryRepository: Send + Sync + 'static {
         category_id: Uuid,
     ) -> Result<i32>;
 
+    /// Move a category under a new parent
+    ///
+    /// Descendants keep their place below the category; their paths and
+    /// levels follow the move.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `category_id` - Category to move
+    /// * `new_parent_id` - New parent, or `None` to make the category a root
+    ///
+    /// # Returns
+    /// The moved category
+    ///
+    /// # Errors
+    /// - `NotFound` if the category or the new parent doesn't exist
+    /// - `ValidationError` if the new parent is the category itself or one of
+    ///   its descendants, which would create a cycle
+    async fn move_category(
+        &self,
+        tenant_id: Uuid,
+        category_id: Uuid,
+        new_parent_id: Option<Uuid>,
+    ) -> Result<Category>;
+
     /// Get products in category tree (category + all subcategories)
     ///
     /// # Arguments
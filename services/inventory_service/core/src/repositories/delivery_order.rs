@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::models::{DeliveryOrder, DeliveryOrderItem, DeliveryOrderStatus};
+use crate::models::{
+    DeliveryOrder, DeliveryOrderItem, DeliveryOrderStatus, NewStockReservation, ReservationStatus,
+    StockReservation, StockReservationStatus,
+};
 use shared_error::AppError;
 
 #[async_trait]
@@ -59,13 +62,16 @@ pub trait DeliveryOrderItemRepository: Send + Sync {
 
 #[async_trait]
 pub trait InventoryRepository: Send + Sync {
+    /// Reserve stock, over-committing up to the product's `overcommit_pct`
+    /// buffer when available stock is short (returns `Backordered` then)
     async fn reserve_stock(
         &self,
         tenant_id: Uuid,
         warehouse_id: Uuid,
         product_id: Uuid,
         quantity: i64,
-    ) -> Result<(), AppError>;
+    ) -> Result<ReservationStatus, AppError>;
+    /// Release reserved stock, cancelling backordered quantity first
     async fn release_stock(
         &self,
         tenant_id: Uuid,
@@ -79,4 +85,32 @@ pub trait InventoryRepository: Send + Sync {
         warehouse_id: Uuid,
         product_id: Uuid,
     ) -> Result<i64, AppError>;
+    /// Queue a pending reservation to be filled by `allocate_scarce`
+    async fn queue_reservation(
+        &self,
+        tenant_id: Uuid,
+        reservation: &NewStockReservation,
+    ) -> Result<StockReservation, AppError>;
+    /// Distribute `available` units over the product's open reservations by
+    /// priority, then creation order; returns them in allocation order
+    async fn allocate_scarce(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        available: i64,
+    ) -> Result<Vec<StockReservation>, AppError>;
+    /// Mark every open reservation of a source document released and return
+    /// them with the status they had before; already released ones are skipped
+    async fn claim_source_reservations(
+        &self,
+        tenant_id: Uuid,
+        source_id: Uuid,
+    ) -> Result<Vec<StockReservation>, AppError>;
+    /// Put a claimed reservation back into `status` after a failed release
+    async fn restore_reservation_status(
+        &self,
+        tenant_id: Uuid,
+        reservation_id: Uuid,
+        status: StockReservationStatus,
+    ) -> Result<(), AppError>;
 }
@@ -13,6 +13,9 @@ use shared_error::AppError;
 #[async_trait]
 pub trait EventRepository: Send + Sync {
     /// Insert an event into the outbox table
+    ///
+    /// The correlation id of the current request scope, if any, is stored
+    /// with the event and published in its envelope.
     async fn insert_event(
         &self,
         tenant_id: Uuid,
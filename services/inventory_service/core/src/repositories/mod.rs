@@ -33,6 +33,7 @@ pub mod reconciliation;
 pub mod rma;
 pub mod stock;
 pub mod stock_take;
+pub mod tenant_settings;
 pub mod transfer;
 pub mod valuation;
 pub mod warehouse;
@@ -56,10 +57,11 @@ pub use quality::QualityControlPointRepository;
 pub use receipt::ReceiptRepository;
 pub use reconciliation::{StockReconciliationItemRepository, StockReconciliationRepository};
 pub use removal_strategy::RemovalStrategyRepository;
-pub use replenishment::ReorderRuleRepository;
+pub use replenishment::{InternalReplenishmentRuleRepository, ReorderRuleRepository};
 pub use rma::{RmaItemRepository, RmaRepository};
 pub use stock::{InventoryLevelRepository, StockMoveRepository};
 pub use stock_take::{StockTakeLineRepository, StockTakeRepository};
+pub use tenant_settings::TenantSettingsRepository;
 pub use transfer::{TransferItemRepository, TransferRepository};
 pub use valuation::{ValuationRepository, ValuationSettingsRepository};
 pub use warehouse::WarehouseRepository;
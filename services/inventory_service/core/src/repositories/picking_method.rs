@@ -10,6 +10,7 @@ use crate::domains::inventory::dto::picking_method_dto::{
     UpdatePickingMethodRequest,
 };
 use crate::domains::inventory::picking_method::PickingMethod;
+use crate::domains::inventory::picking_route::RouteStop;
 use crate::Result;
 
 /// Repository trait for picking method data access
@@ -164,6 +165,24 @@ pub trait PickingMethodRepository: Send + Sync {
         request: PickingOptimizationRequest,
     ) -> Result<PickingPlanResponse>;
 
+    /// Load the locations a picking plan visits as route stops
+    ///
+    /// Locations outside the warehouse, deleted or unknown are omitted.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `warehouse_id` - Warehouse the plan runs in
+    /// * `location_ids` - Locations to load
+    ///
+    /// # Returns
+    /// Route stops with coordinates where the location has them
+    async fn find_route_stops(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        location_ids: &[Uuid],
+    ) -> Result<Vec<RouteStop>>;
+
     /// Validate picking method configuration
     ///
     /// # Arguments
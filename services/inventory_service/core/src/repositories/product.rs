@@ -11,6 +11,7 @@ use crate::domains::inventory::dto::search_dto::{
     SearchSuggestionsResponse,
 };
 use crate::domains::inventory::product::Product;
+use crate::dto::product::ProductAttributes;
 use crate::Result;
 
 /// Repository trait for product data access
@@ -95,6 +96,16 @@ pub trait ProductRepository: Send + Sync {
     /// Product if found (searches both products.attributes and product_variants.barcode)
     async fn find_by_barcode(&self, tenant_id: Uuid, barcode: &str) -> Result<Option<Product>>;
 
+    /// Check if a product exists
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    ///
+    /// # Returns
+    /// True if the product exists, belongs to the tenant and is not deleted
+    async fn exists(&self, tenant_id: Uuid, product_id: Uuid) -> Result<bool>;
+
     /// Create new product
     ///
     /// # Arguments
@@ -210,7 +221,10 @@ pub trait ProductRepository: Send + Sync {
     /// Number of products deactivated
     async fn bulk_deactivate(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64>;
 
-    /// Bulk delete products (soft delete)
+    /// Bulk soft delete products
+    ///
+    /// Runs in one transaction and recalculates the product counts of the
+    /// affected categories and their ancestors.
     ///
     /// # Arguments
     /// * `tenant_id` - Tenant identifier for isolation
@@ -218,7 +232,20 @@ pub trait ProductRepository: Send + Sync {
     ///
     /// # Returns
     /// Number of products deleted
-    async fn bulk_delete(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64>;
+    async fn bulk_soft_delete(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64>;
+
+    /// Bulk restore soft-deleted products
+    ///
+    /// Runs in one transaction and recalculates the product counts of the
+    /// affected categories and their ancestors.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_ids` - List of product IDs to restore
+    ///
+    /// # Returns
+    /// Number of products restored
+    async fn bulk_restore(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64>;
 
     // ========================================================================
     // Import/Export Operations
@@ -252,4 +279,38 @@ pub trait ProductRepository: Send + Sync {
         is_active: Option<bool>,
         search: Option<&str>,
     ) -> Result<Vec<Product>>;
+
+    // ========================================================================
+    // Attribute Operations
+    // ========================================================================
+
+    /// Get the specification attributes of a product
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    ///
+    /// # Returns
+    /// Attributes if the product exists (empty when none are set)
+    async fn get_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<Option<ProductAttributes>>;
+
+    /// Replace the specification attributes of a product
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    /// * `attributes` - Complete set of attributes to store
+    ///
+    /// # Returns
+    /// True if the product was found and updated
+    async fn set_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        attributes: &ProductAttributes,
+    ) -> Result<bool>;
 }
@@ -1,5 +1,5 @@
 use crate::domains::quality::{
-    CreateQualityControlPoint, QualityControlPoint, UpdateQualityControlPoint,
+    CreateQualityControlPoint, QualityCheck, QualityControlPoint, UpdateQualityControlPoint,
 };
 use crate::AppError;
 use async_trait::async_trait;
@@ -36,4 +36,21 @@ pub trait QualityControlPointRepository: Send + Sync {
         updates: UpdateQualityControlPoint,
     ) -> Result<QualityControlPoint, AppError>;
     async fn delete(&self, tenant_id: Uuid, qc_point_id: Uuid) -> Result<(), AppError>;
+
+    async fn find_checks_by_reference(
+        &self,
+        tenant_id: Uuid,
+        reference_type: &str,
+        reference_id: Uuid,
+    ) -> Result<Vec<QualityCheck>, AppError>;
+    /// Record the result of a pending check and move its held quantity:
+    /// pass -> available, fail -> quarantine
+    async fn record_check_result(
+        &self,
+        tenant_id: Uuid,
+        qc_id: Uuid,
+        inspector_id: Uuid,
+        passed: bool,
+        notes: Option<String>,
+    ) -> Result<QualityCheck, AppError>;
 }
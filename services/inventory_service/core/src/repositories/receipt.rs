@@ -36,6 +36,13 @@ pub trait ReceiptRepository: Send + Sync {
         query: ReceiptListQuery,
     ) -> Result<ReceiptListResponse, AppError>;
 
+    /// Find the receipt created with an external reference, if any
+    async fn find_id_by_external_reference(
+        &self,
+        tenant_id: Uuid,
+        external_reference: &str,
+    ) -> Result<Option<Uuid>, AppError>;
+
     /// Check if a receipt exists by ID
     async fn receipt_exists(&self, tenant_id: Uuid, receipt_id: Uuid) -> Result<bool, AppError>;
 
@@ -46,4 +53,24 @@ pub trait ReceiptRepository: Send + Sync {
         receipt_id: Uuid,
         user_id: Uuid,
     ) -> Result<ReceiptResponse, AppError>;
+
+    /// Reverse a received goods receipt entered in error
+    ///
+    /// Writes a compensating move for each of the receipt's stock moves, takes the
+    /// received quantity back out of stock, removes the valuation layers the receipt
+    /// created and backs its value out of the product valuation, then marks the
+    /// receipt `reversed`.
+    ///
+    /// # Errors
+    /// - `NotFound` if the receipt doesn't exist
+    /// - `ValidationError` if the receipt is not `received`
+    /// - `BusinessError` if any of the received stock was already reserved, picked,
+    ///   shipped or otherwise consumed
+    async fn reverse_receipt(
+        &self,
+        tenant_id: Uuid,
+        receipt_id: Uuid,
+        reason: &str,
+        user_id: Uuid,
+    ) -> Result<ReceiptResponse, AppError>;
 }
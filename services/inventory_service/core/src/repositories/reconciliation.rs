@@ -8,6 +8,7 @@ use uuid::Uuid;
 use crate::domains::inventory::reconciliation::{
     CycleType, ReconciliationStatus, StockReconciliation, StockReconciliationItem,
 };
+use crate::dto::reconciliation::{ReconciliationTrendPeriod, TrendGroupBy};
 use shared_error::AppError;
 
 /// Variance analysis result from repository
@@ -101,6 +102,19 @@ pub trait StockReconciliationRepository: Send + Sync {
         status: Option<ReconciliationStatus>,
         cycle_type: Option<CycleType>,
     ) -> Result<i64, AppError>;
+
+    /// Aggregate completed reconciliations into variance trend periods
+    ///
+    /// Reconciliations are bucketed by `completed_at` in `[from, to)`; only
+    /// periods containing at least one reconciliation are returned.
+    async fn variance_trend(
+        &self,
+        tenant_id: Uuid,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        group_by: TrendGroupBy,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<Vec<ReconciliationTrendPeriod>, AppError>;
 }
 
 /// Repository trait for reconciliation item operations
@@ -1,6 +1,10 @@
-use crate::domains::replenishment::{CreateReorderRule, ReorderRule, UpdateReorderRule};
+use crate::domains::replenishment::{
+    CreateInternalReplenishmentRule, CreateReorderRule, InternalReplenishmentRule, ReorderRule,
+    UpdateReorderRule,
+};
 use crate::AppError;
 use async_trait::async_trait;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Repository trait for reorder rules
@@ -41,4 +45,46 @@ pub trait ReorderRuleRepository: Send + Sync {
 
     /// Soft delete a reorder rule
     async fn delete(&self, tenant_id: Uuid, rule_id: Uuid) -> Result<(), AppError>;
+
+    /// Record that the rule fired, unless it already fired within the cooldown
+    ///
+    /// Returns `true` if the caller claimed the trigger and should emit the event.
+    async fn try_mark_triggered(
+        &self,
+        tenant_id: Uuid,
+        rule_id: Uuid,
+        cooldown_seconds: i64,
+    ) -> Result<bool, AppError>;
+
+    /// Forget the last trigger so the rule may fire again on the next check
+    async fn clear_triggered(&self, tenant_id: Uuid, rule_id: Uuid) -> Result<(), AppError>;
+}
+
+/// Repository trait for warehouse-to-warehouse replenishment rules
+#[async_trait]
+pub trait InternalReplenishmentRuleRepository: Send + Sync {
+    /// Find all active internal replenishment rules for a tenant
+    async fn find_all_active(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<InternalReplenishmentRule>, AppError>;
+
+    /// Create a new internal replenishment rule
+    async fn create(
+        &self,
+        tenant_id: Uuid,
+        rule: CreateInternalReplenishmentRule,
+    ) -> Result<InternalReplenishmentRule, AppError>;
+
+    /// Soft delete an internal replenishment rule
+    async fn delete(&self, tenant_id: Uuid, rule_id: Uuid) -> Result<(), AppError>;
+
+    /// Net quantity still pending on open transfers, keyed by (warehouse_id, product_id).
+    ///
+    /// Transfers not yet received count as incoming at their destination; transfers
+    /// not yet shipped count as outgoing (negative) at their source.
+    async fn find_open_transfer_quantities(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<HashMap<(Uuid, Uuid), i64>, AppError>;
 }
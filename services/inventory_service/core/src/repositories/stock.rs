@@ -3,19 +3,25 @@
 //! This module contains trait definitions for StockMove and InventoryLevel operations.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::models::{CreateStockMoveRequest, InventoryLevel, StockMove};
+use crate::models::{InventoryLevel, InventoryLevelHistoryEntry, MoveIntent, StockMove};
 use shared_error::AppError;
 
 #[async_trait]
 pub trait StockMoveRepository: Send + Sync {
-    /// Create a new stock move
-    async fn create(
-        &self,
-        stock_move: &CreateStockMoveRequest,
-        tenant_id: Uuid,
-    ) -> Result<StockMove, AppError>;
+    /// Record a stock move tagged with its reason and source document.
+    /// Idempotent on `intent.idempotency_key`: a repeat returns the existing move.
+    async fn record(&self, intent: &MoveIntent, tenant_id: Uuid) -> Result<StockMove, AppError>;
+
+    /// Create a compensating move for `move_id`: negated quantity, swapped
+    /// locations, same source document, `reversal_of_move_id` set to the original.
+    ///
+    /// Only the ledger is written; callers own any inventory level updates.
+    /// Fails with `Conflict` if the move was already reversed and with
+    /// `BusinessError` if the move is itself a reversal.
+    async fn reverse_move(&self, tenant_id: Uuid, move_id: Uuid) -> Result<StockMove, AppError>;
 
     /// Find stock moves by reference
     async fn find_by_reference(
@@ -70,6 +76,20 @@ pub trait InventoryLevelRepository: Send + Sync {
         quantity_change: i64,
     ) -> Result<(), AppError>;
 
+    /// Update available quantity inside the caller's transaction
+    ///
+    /// Same upsert as [`Self::update_available_quantity`], for services that
+    /// must change levels together with other writes.
+    async fn update_available_quantity_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        location_id: Option<Uuid>,
+        product_id: Uuid,
+        quantity_change: i64,
+    ) -> Result<(), AppError>;
+
     /// Create or update inventory level
     async fn upsert(
         &self,
@@ -79,4 +99,17 @@ pub trait InventoryLevelRepository: Send + Sync {
         available_quantity: i64,
         reserved_quantity: i64,
     ) -> Result<(), AppError>;
+    /// Timeline of quantity changes for a product at a location, oldest first
+    ///
+    /// Entries come from the history written with every recorded stock move.
+    /// `from`/`to` bound `recorded_at` (inclusive start, exclusive end); each
+    /// entry's `balance` still counts every earlier change.
+    async fn history(
+        &self,
+        tenant_id: Uuid,
+        location_id: Uuid,
+        product_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<InventoryLevelHistoryEntry>, AppError>;
 }
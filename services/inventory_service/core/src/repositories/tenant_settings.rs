@@ -0,0 +1,40 @@
+//! Tenant settings repository trait
+//!
+//! Defines data access for per-tenant settings such as feature flags.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domains::inventory::feature_flag::{FeatureFlag, TenantFeatureFlag};
+use crate::Result;
+
+/// Repository trait for tenant settings data access
+#[async_trait]
+pub trait TenantSettingsRepository: Send + Sync {
+    /// Get the tenant's stored value for a flag
+    ///
+    /// # Returns
+    /// The stored setting, or None if the tenant has no override
+    async fn find_flag(
+        &self,
+        tenant_id: Uuid,
+        flag: FeatureFlag,
+    ) -> Result<Option<TenantFeatureFlag>>;
+
+    /// List all stored flag settings for a tenant
+    ///
+    /// Rows with keys that are not known flags are skipped.
+    async fn list_flags(&self, tenant_id: Uuid) -> Result<Vec<TenantFeatureFlag>>;
+
+    /// Create or update the tenant's value for a flag
+    ///
+    /// # Returns
+    /// The stored setting
+    async fn upsert_flag(
+        &self,
+        tenant_id: Uuid,
+        flag: FeatureFlag,
+        enabled: bool,
+        updated_by: Option<Uuid>,
+    ) -> Result<TenantFeatureFlag>;
+}
@@ -3,6 +3,7 @@
 //! This module defines the repository traits for stock transfer operations.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domains::inventory::transfer::{Transfer, TransferItem, TransferStatus};
@@ -76,6 +77,25 @@ pub trait TransferRepository: Send + Sync {
         reason: Option<String>,
     ) -> Result<(), AppError>;
 
+    /// Update ETA and carrier tracking; `None` fields are left unchanged
+    async fn update_tracking(
+        &self,
+        tenant_id: Uuid,
+        transfer_id: Uuid,
+        expected_arrival: Option<DateTime<Utc>>,
+        carrier: Option<String>,
+        tracking_number: Option<String>,
+        updated_by: Uuid,
+    ) -> Result<(), AppError>;
+
+    /// List open (confirmed through shipped) transfers, soonest expected arrival first
+    async fn list_in_transit(
+        &self,
+        tenant_id: Uuid,
+        destination_warehouse_id: Option<Uuid>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Transfer>, AppError>;
+
     /// Delete transfer (soft delete)
     async fn delete(
         &self,
@@ -1,14 +1,16 @@
 //! Valuation repository traits
 //!
 //! Defines data access interfaces for inventory valuation operations.
-//! Supports multiple valuation methods (FIFO, AVCO, Standard) with cost layer management.
+//! Supports multiple valuation methods (FIFO, LIFO, AVCO, Standard) with cost layer management.
+
+use std::collections::HashMap;
 
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domains::inventory::valuation::{
-    Valuation, ValuationHistory, ValuationLayer, ValuationMethod, ValuationScopeType,
-    ValuationSettings,
+    LayerConsumption, Valuation, ValuationHistory, ValuationLayer, ValuationMethod,
+    ValuationScopeType, ValuationSettings,
 };
 use crate::Result;
 
@@ -29,6 +31,20 @@ pub trait ValuationRepository: Send + Sync {
         product_id: Uuid,
     ) -> Result<Option<Valuation>>;
 
+    /// Get current valuations for several products in one query
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_ids` - Product identifiers
+    ///
+    /// # Returns
+    /// Map of product_id -> valuation; products without a valuation are absent
+    async fn find_by_product_ids(
+        &self,
+        tenant_id: Uuid,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Valuation>>;
+
     /// Create new valuation record
     ///
     /// # Arguments
@@ -72,6 +88,33 @@ pub trait ValuationRepository: Send + Sync {
         updated_by: Option<Uuid>,
     ) -> Result<Valuation>;
 
+    /// Change valuation method and re-layer existing stock in one transaction
+    ///
+    /// Leaving FIFO collapses the cost layers into a single average cost;
+    /// switching to FIFO replaces any layers with one layer at the current
+    /// average. Quantity and total value are preserved and the pre-change
+    /// state is recorded in the valuation history.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    /// * `method` - New valuation method
+    /// * `updated_by` - User making the change
+    ///
+    /// # Returns
+    /// Updated valuation
+    ///
+    /// # Errors
+    /// - `NotFound` if the product has no valuation
+    /// - `BusinessError` if the product is in negative stock
+    async fn change_valuation_method(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        method: ValuationMethod,
+        updated_by: Option<Uuid>,
+    ) -> Result<Valuation>;
+
     /// Set standard cost for a product
     ///
     /// # Arguments
@@ -149,6 +192,59 @@ pub trait ValuationRepository: Send + Sync {
         reason: &str,
         updated_by: Option<Uuid>,
     ) -> Result<Valuation>;
+
+    /// Rebuild FIFO cost layers from the product's stock move history
+    ///
+    /// Replays every stock move oldest first, replaces the existing layers and
+    /// valuation totals with the result and records the pre-rebuild state in
+    /// the valuation history, all in one transaction.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    /// * `updated_by` - User requesting the rebuild
+    ///
+    /// # Returns
+    /// Updated valuation
+    ///
+    /// # Errors
+    /// - `NotFound` if the product has no valuation
+    /// - `BusinessError` if the product is not valued with FIFO or the move
+    ///   history cannot be replayed (an inflow without unit cost, or an
+    ///   outflow exceeding the stock received before it)
+    async fn rebuild_layers(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        updated_by: Option<Uuid>,
+    ) -> Result<Valuation>;
+
+    /// Recompute the AVCO running average from the product's stock moves
+    ///
+    /// Locks the valuation row, replays every receipt and delivery oldest
+    /// first, replaces the valuation totals and unit cost with the result and
+    /// records the pre-recompute state in the valuation history, all in one
+    /// transaction.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    /// * `updated_by` - User requesting the recompute
+    ///
+    /// # Returns
+    /// Updated valuation
+    ///
+    /// # Errors
+    /// - `NotFound` if the product has no valuation
+    /// - `BusinessError` if the product is not valued with AVCO or the move
+    ///   history cannot be replayed (a receipt without unit cost, or a
+    ///   delivery exceeding the stock received before it)
+    async fn recompute_avco(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        updated_by: Option<Uuid>,
+    ) -> Result<Valuation>;
 }
 
 /// Repository trait for valuation layer data access (FIFO)
@@ -193,6 +289,43 @@ pub trait ValuationLayerRepository: Send + Sync {
         quantity_to_consume: i64,
     ) -> Result<i64>;
 
+    /// Consume quantity from cost layers (FIFO), reporting each layer taken from
+    ///
+    /// Same as [`Self::consume_layers`] but also returns what was taken from
+    /// each layer, in consumption order, so the cost can be reconciled per layer.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    /// * `quantity_to_consume` - Quantity to consume
+    ///
+    /// # Returns
+    /// Total cost of consumed quantity and the per-layer breakdown
+    async fn consume_layers_detailed(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        quantity_to_consume: i64,
+    ) -> Result<(i64, Vec<LayerConsumption>)>;
+
+    /// Consume quantity from cost layers (LIFO)
+    ///
+    /// Same as [`Self::consume_layers`] but takes from the newest layers first.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `product_id` - Product identifier
+    /// * `quantity_to_consume` - Quantity to consume
+    ///
+    /// # Returns
+    /// Total cost of consumed quantity
+    async fn consume_layers_lifo(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        quantity_to_consume: i64,
+    ) -> Result<i64>;
+
     /// Get total remaining quantity in layers
     ///
     /// # Arguments
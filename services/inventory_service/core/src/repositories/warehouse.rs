@@ -7,7 +7,8 @@ use uuid::Uuid;
 
 use crate::domains::inventory::dto::warehouse_dto::{
     CreateWarehouseLocationRequest, CreateWarehouseRequest, CreateWarehouseZoneRequest,
-    UpdateWarehouseLocationRequest, UpdateWarehouseZoneRequest, WarehouseTreeResponse,
+    LocationMergeResponse, UpdateWarehouseLocationRequest, UpdateWarehouseZoneRequest,
+    WarehouseTreeResponse,
 };
 use crate::domains::inventory::warehouse::Warehouse;
 use crate::domains::inventory::warehouse_location::WarehouseLocation;
@@ -306,6 +307,37 @@ pub trait WarehouseRepository: Send + Sync {
     /// Success status
     async fn delete_location(&self, tenant_id: Uuid, location_id: Uuid) -> Result<bool>;
 
+    /// Merge a location into another location of the same warehouse
+    ///
+    /// Moves every product's available stock from `from_location_id` to
+    /// `to_location_id`, recording one transfer stock move per product, then
+    /// deactivates the emptied location. Existing stock moves are immutable and
+    /// keep pointing at the old location, so its history stays intact. Runs in
+    /// a single transaction.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `from_location_id` - Location to empty and deactivate
+    /// * `to_location_id` - Location receiving the stock
+    /// * `user_id` - User performing the merge
+    ///
+    /// # Returns
+    /// Products and quantity moved, with the recorded stock moves
+    ///
+    /// # Errors
+    /// - `NotFound` if either location doesn't exist
+    /// - `ValidationError` if the locations are the same, in different
+    ///   warehouses, or the target is inactive
+    /// - `BusinessError` if the source holds reserved or held stock, or the
+    ///   target's capacity would be exceeded
+    async fn merge_location(
+        &self,
+        tenant_id: Uuid,
+        from_location_id: Uuid,
+        to_location_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<LocationMergeResponse>;
+
     /// Check if warehouse hierarchy is valid (no cycles)
     ///
     /// # Arguments
@@ -340,6 +372,27 @@ pub trait WarehouseRepository: Send + Sync {
         warehouse_id: Uuid,
     ) -> Result<Option<serde_json::Value>>;
 
+    /// Get the remaining capacity of a location
+    ///
+    /// Computes the location's `max_capacity_units` minus the stock currently
+    /// on hand there (available, reserved, held and quarantined), never below
+    /// zero.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for isolation
+    /// * `location_id` - Location ID
+    ///
+    /// # Returns
+    /// Units that still fit, or None if the location has no capacity limit
+    ///
+    /// # Errors
+    /// - `NotFound` if the location doesn't exist
+    async fn get_available_capacity(
+        &self,
+        tenant_id: Uuid,
+        location_id: Uuid,
+    ) -> Result<Option<i64>>;
+
     /// Get warehouse statistics
     ///
     /// # Arguments
@@ -9,7 +9,7 @@ use uuid::Uuid;
 use crate::dto::adjustment::{
     AddAdjustmentLinesRequest, AdjustmentDocumentResponse, AdjustmentDocumentWithLinesResponse,
     AdjustmentListQuery, AdjustmentListResponse, AdjustmentSummary, CreateAdjustmentRequest,
-    PostAdjustmentRequest,
+    PostAdjustmentRequest, QuickAdjustmentRequest, QuickAdjustmentResponse,
 };
 use shared_error::AppError;
 
@@ -169,4 +169,27 @@ pub trait AdjustmentService: Send + Sync {
         adjustment_id: Uuid,
         user_id: Uuid,
     ) -> Result<AdjustmentDocumentResponse, AppError>;
+
+    /// Adjust stock immediately (manual correction)
+    ///
+    /// Atomically writes an `adjustment` stock move and applies the signed delta
+    /// to the product's available quantity, without a draft document.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant isolation key
+    /// * `user_id` - User making the correction
+    /// * `request` - Location, product, delta, reason code and note
+    ///
+    /// # Returns
+    /// The recorded stock move and the resulting available quantity
+    ///
+    /// # Errors
+    /// - Zero delta
+    /// - Insufficient inventory (the adjustment would make stock negative)
+    async fn adjust(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        request: QuickAdjustmentRequest,
+    ) -> Result<QuickAdjustmentResponse, AppError>;
 }
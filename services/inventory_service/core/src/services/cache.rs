@@ -3,6 +3,7 @@
 //! This module defines traits for caching frequently accessed data
 //! to reduce database load and improve response times.
 
+use crate::domains::inventory::feature_flag::FeatureFlag;
 use crate::domains::inventory::product::Product;
 use crate::models::InventoryLevel;
 use async_trait::async_trait;
@@ -86,3 +87,30 @@ pub trait InventoryCache: Send + Sync {
         product_id: &uuid::Uuid,
     ) -> Result<(), AppError>;
 }
+
+/// Tenant feature flag cache operations
+#[async_trait]
+pub trait FeatureFlagCache: Send + Sync {
+    /// Get cached flag value for a tenant
+    async fn get_feature_flag(
+        &self,
+        tenant_id: &uuid::Uuid,
+        flag: FeatureFlag,
+    ) -> Result<Option<bool>, AppError>;
+
+    /// Cache flag value with TTL
+    async fn set_feature_flag(
+        &self,
+        tenant_id: &uuid::Uuid,
+        flag: FeatureFlag,
+        enabled: bool,
+        ttl: Option<Duration>,
+    ) -> Result<(), AppError>;
+
+    /// Invalidate flag cache
+    async fn invalidate_feature_flag(
+        &self,
+        tenant_id: &uuid::Uuid,
+        flag: FeatureFlag,
+    ) -> Result<(), AppError>;
+}
@@ -8,8 +8,9 @@ use uuid::Uuid;
 
 use crate::domains::category::{Category, CategoryBreadcrumb};
 use crate::dto::category::{
-    BulkOperationResponse, CategoryCreateRequest, CategoryListQuery, CategoryListResponse,
-    CategoryStatsResponse, CategoryTreeResponse, CategoryUpdateRequest, MoveToCategoryRequest,
+    BulkOperationResponse, CategoryCreateRequest, CategoryExportDocument, CategoryImportResponse,
+    CategoryListQuery, CategoryListResponse, CategoryPatchRequest, CategoryStatsResponse,
+    CategoryTreeResponse, CategoryUpdateRequest, MoveToCategoryRequest,
 };
 use crate::Result;
 
@@ -64,6 +65,16 @@ pub trait CategoryService: Send + Sync + 'static {
     /// - `NotFound` if category doesn't exist
     async fn get_category(&self, tenant_id: Uuid, category_id: Uuid) -> Result<Category>;
 
+    /// Check if a category exists without loading it
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `category_id` - Category identifier
+    ///
+    /// # Returns
+    /// True if the category exists and is not deleted
+    async fn category_exists(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
+
     /// Get category by ID with breadcrumbs
     ///
     /// # Arguments
@@ -122,6 +133,22 @@ pub trait CategoryService: Send + Sync + 'static {
         request: CategoryUpdateRequest,
     ) -> Result<Category>;
 
+    /// Partially update a category (PATCH)
+    ///
+    /// Only the fields present in the request change; nullable fields set to
+    /// `null` are cleared and a `null` parent makes the category a root.
+    ///
+    /// # Errors
+    /// - `NotFound` if category or new parent doesn't exist
+    /// - `ValidationError` if validation fails or the new parent creates a cycle
+    /// - `Conflict` if slug or code conflicts
+    async fn patch_category(
+        &self,
+        tenant_id: Uuid,
+        category_id: Uuid,
+        request: CategoryPatchRequest,
+    ) -> Result<Category>;
+
     /// Delete category (soft delete)
     ///
     /// # Business Rules
@@ -141,6 +168,25 @@ pub trait CategoryService: Send + Sync + 'static {
     /// - `Conflict` if category has children or products
     async fn delete_category(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
 
+    /// Restore a soft-deleted category
+    ///
+    /// # Business Rules
+    /// - Every ancestor must be live; deleted parents are restored first
+    /// - Product counts of the category and its ancestors are recomputed
+    /// - Restoring a category that is not deleted returns it unchanged
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `category_id` - Category to restore
+    ///
+    /// # Returns
+    /// The restored category with recomputed product counts
+    ///
+    /// # Errors
+    /// - `NotFound` if category doesn't exist
+    /// - `Conflict` if a parent is still deleted, or the code or slug is taken
+    async fn restore_category(&self, tenant_id: Uuid, category_id: Uuid) -> Result<Category>;
+
     // ========================================================================
     // List and Query Operations
     // ========================================================================
@@ -296,6 +342,54 @@ pub trait CategoryService: Send + Sync + 'static {
         category_ids: Vec<Uuid>,
     ) -> Result<BulkOperationResponse>;
 
+    /// Recompute product counts for all categories of a tenant
+    ///
+    /// Used by the periodic recount job and the admin recount endpoint to
+    /// correct drifted `product_count`/`total_product_count` values.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    ///
+    /// # Returns
+    /// Bulk operation result with count of corrected categories
+    async fn recount_product_counts(&self, tenant_id: Uuid) -> Result<BulkOperationResponse>;
+
+    // ========================================================================
+    // Import / Export
+    // ========================================================================
+
+    /// Export the full category tree as a portable document
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    ///
+    /// # Returns
+    /// All active categories in dependency order, referencing parents by code
+    async fn export_categories(&self, tenant_id: Uuid) -> Result<CategoryExportDocument>;
+
+    /// Recreate a category tree from an export document
+    ///
+    /// # Business Rules
+    /// - Creates parents before children, mapping codes to new IDs
+    /// - Idempotent by code: categories whose code already exists are reused
+    /// - Categories that cannot be created are reported as conflicts, along
+    ///   with their descendants
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `document` - Export document to import
+    ///
+    /// # Returns
+    /// Code to ID mappings and conflicts
+    ///
+    /// # Errors
+    /// - `ValidationError` if the document is invalid or has an unsupported version
+    async fn import_categories(
+        &self,
+        tenant_id: Uuid,
+        document: CategoryExportDocument,
+    ) -> Result<CategoryImportResponse>;
+
     // ========================================================================
     // Search Operations
     // ========================================================================
@@ -32,7 +32,7 @@ pub trait DeliveryService: Send + Sync {
         request: PackItemsRequest,
     ) -> Result<PackItemsResponse, AppError>;
 
-    /// Ship items for a delivery order
+    /// Ship items for a delivery order, optionally as a partial shipment of selected lines
     async fn ship_items(
         &self,
         tenant_id: Uuid,
@@ -0,0 +1,43 @@
+//! Feature flag service trait
+//!
+//! Central place for per-tenant behavior toggles. Flags are stored and read
+//! here; services that adopt a toggle should check `is_enabled` rather than
+//! reading scattered configuration.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domains::inventory::dto::feature_flag_dto::{FeatureFlagDto, FeatureFlagListResponse};
+use crate::domains::inventory::feature_flag::FeatureFlag;
+use crate::Result;
+
+/// Service trait for tenant feature flags
+#[async_trait]
+pub trait FeatureFlagService: Send + Sync {
+    /// Check whether a flag is enabled for a tenant
+    ///
+    /// # Business Rules
+    /// - Returns the tenant override if one is stored
+    /// - Otherwise returns the flag default (off)
+    /// - Lookups are cached; cache failures fall back to the database
+    async fn is_enabled(&self, tenant_id: Uuid, flag: FeatureFlag) -> Result<bool>;
+
+    /// List every known flag with its effective value for a tenant
+    async fn list_flags(&self, tenant_id: Uuid) -> Result<FeatureFlagListResponse>;
+
+    /// Get a single flag with its effective value for a tenant
+    async fn get_flag(&self, tenant_id: Uuid, flag: FeatureFlag) -> Result<FeatureFlagDto>;
+
+    /// Set a flag for a tenant
+    ///
+    /// # Business Rules
+    /// - Stores the override and records who changed it
+    /// - Invalidates the cached value so the change applies immediately
+    async fn set_flag(
+        &self,
+        tenant_id: Uuid,
+        flag: FeatureFlag,
+        enabled: bool,
+        user_id: Uuid,
+    ) -> Result<FeatureFlagDto>;
+}
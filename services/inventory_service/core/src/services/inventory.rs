@@ -5,6 +5,7 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
+use crate::models::{NewStockReservation, ReservationStatus, StockReservation};
 use shared_error::AppError;
 
 /// Service for managing inventory stock and reservations
@@ -14,17 +15,23 @@ pub trait InventoryService: Send + Sync {
     ///
     /// Validates availability and creates reservation.
     /// Supports both standard and lot-tracked products.
+    ///
+    /// Standard products with an `overcommit_pct` may be reserved beyond
+    /// available stock, up to that percentage of on-hand quantity. Such
+    /// reservations return `ReservationStatus::Backordered` and the shortfall
+    /// is tracked as backordered quantity.
     async fn reserve_stock(
         &self,
         tenant_id: Uuid,
         warehouse_id: Uuid,
         product_id: Uuid,
         quantity: i64,
-    ) -> Result<(), AppError>;
+    ) -> Result<ReservationStatus, AppError>;
 
     /// Release reserved stock
     ///
     /// Frees up reserved stock, making it available again.
+    /// Backordered quantity is cancelled before reserved stock is freed.
     async fn release_stock(
         &self,
         tenant_id: Uuid,
@@ -40,4 +47,38 @@ pub trait InventoryService: Send + Sync {
         warehouse_id: Uuid,
         product_id: Uuid,
     ) -> Result<i64, AppError>;
+    /// Queue a reservation that waits for stock
+    ///
+    /// The reservation starts as `Pending` with the given priority and is
+    /// filled by `allocate_scarce`.
+    async fn queue_reservation(
+        &self,
+        tenant_id: Uuid,
+        reservation: NewStockReservation,
+    ) -> Result<StockReservation, AppError>;
+
+    /// Distribute limited stock over a product's open reservations
+    ///
+    /// Pending and backordered reservations are served by priority (highest
+    /// first), then creation order. A reservation that cannot be covered in
+    /// full takes what is left and the remainder is marked backordered.
+    /// Returns the affected reservations in allocation order.
+    async fn allocate_scarce(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        available: i64,
+    ) -> Result<Vec<StockReservation>, AppError>;
+
+    /// Release all open reservations held by a source document (e.g. a
+    /// cancelled sales order)
+    ///
+    /// Allocated quantity goes back to available stock. Reservations already
+    /// released are skipped, so calling this again is a no-op; a document with
+    /// no open reservations (e.g. already shipped) returns an empty list.
+    async fn release_source_reservations(
+        &self,
+        tenant_id: Uuid,
+        source_id: Uuid,
+    ) -> Result<Vec<StockReservation>, AppError>;
 }
@@ -0,0 +1,48 @@
+//! Inventory snapshot service trait
+//!
+//! Captures a tenant's inventory levels before a risky bulk operation and
+//! rolls them back afterwards through compensating stock moves.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::dto::inventory_snapshot::{InventorySnapshotResponse, SnapshotRestoreResponse};
+use crate::Result;
+
+/// Service trait for inventory snapshots and point-in-time restore
+#[async_trait]
+pub trait InventorySnapshotService: Send + Sync {
+    /// Store the tenant's current inventory levels
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant to snapshot
+    /// * `user_id` - User recorded as creator of the snapshot
+    ///
+    /// # Returns
+    /// The snapshot, identified by its `snapshot_id`
+    async fn snapshot(&self, tenant_id: Uuid, user_id: Uuid) -> Result<InventorySnapshotResponse>;
+
+    /// Return every inventory level to its snapshot value
+    ///
+    /// # Business Rules
+    /// - Levels are never overwritten: each difference is applied as an
+    ///   `adjustment` stock move with reason `snapshot_restore`, so the audit
+    ///   trail shows both the change and its rollback
+    /// - Levels created after the snapshot are brought back to zero
+    /// - Only available quantity is restored; live reservations are kept
+    /// - Restoring again when nothing changed records no moves
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant owning the snapshot
+    /// * `snapshot_id` - Snapshot to restore
+    /// * `user_id` - User performing the restore
+    ///
+    /// # Errors
+    /// - `NotFound` if the snapshot doesn't exist for the tenant
+    async fn restore(
+        &self,
+        tenant_id: Uuid,
+        snapshot_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<SnapshotRestoreResponse>;
+}
@@ -9,7 +9,9 @@ pub mod category;
 pub mod cycle_count;
 pub mod delivery;
 pub mod distributed_lock;
+pub mod feature_flags;
 pub mod inventory;
+pub mod inventory_snapshot;
 pub mod landed_cost;
 pub mod lot_serial;
 
@@ -28,18 +30,22 @@ pub mod rma;
 pub mod scrap;
 pub mod stock_levels;
 pub mod stock_take;
+pub mod tenant_export;
+pub mod tenant_provisioning;
 pub mod transfer;
 pub mod valuation;
 
 // Re-export main types for convenience
 pub use category::CategoryService;
 pub use distributed_lock::DistributedLockService;
+pub use feature_flags::FeatureFlagService;
 pub use landed_cost::LandedCostService;
 pub use picking_method::PickingMethodService;
 pub use quality::QualityControlPointService;
 // pub use delivery::DeliveryService;
-pub use cache::{CacheService, InventoryCache, ProductCache};
+pub use cache::{CacheService, FeatureFlagCache, InventoryCache, ProductCache};
 pub use inventory::InventoryService;
+pub use inventory_snapshot::InventorySnapshotService;
 pub use lot_serial::LotSerialService;
 pub use product::ProductService;
 pub use product_image::ProductImageService;
@@ -51,6 +57,8 @@ pub use removal_strategy::RemovalStrategyService;
 pub use replenishment::ReplenishmentService;
 pub use rma::RmaService;
 pub use stock_take::StockTakeService;
+pub use tenant_export::TenantExportService;
+pub use tenant_provisioning::TenantProvisioningService;
 pub use transfer::TransferService;
 pub use valuation::ValuationService;
 
@@ -11,6 +11,7 @@ use crate::domains::inventory::dto::search_dto::{
     SearchSuggestionsResponse,
 };
 use crate::domains::inventory::product::Product;
+use crate::dto::product::ProductAttributes;
 use crate::Result;
 
 /// Service trait for product business logic
@@ -98,6 +99,16 @@ pub trait ProductService: Send + Sync {
     /// - `NotFound` if product doesn't exist
     async fn get_product(&self, tenant_id: Uuid, product_id: Uuid) -> Result<Product>;
 
+    /// Check if a product exists without loading it
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    ///
+    /// # Returns
+    /// True if the product exists and is not deleted
+    async fn product_exists(&self, tenant_id: Uuid, product_id: Uuid) -> Result<bool>;
+
     /// List products with filtering and pagination
     ///
     /// # Arguments
@@ -133,6 +144,29 @@ pub trait ProductService: Send + Sync {
         request: crate::dto::product::ProductUpdateRequest,
     ) -> Result<Product>;
 
+    /// Partially update a product (PATCH)
+    ///
+    /// Merges the fields present in the request into the stored product;
+    /// nullable fields set to `null` are cleared.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `request` - Fields to change
+    ///
+    /// # Returns
+    /// Updated product
+    ///
+    /// # Errors
+    /// - `NotFound` if product doesn't exist
+    /// - `ValidationError` if the merged product is invalid
+    async fn patch_product(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        request: crate::dto::product::ProductPatchRequest,
+    ) -> Result<Product>;
+
     /// Delete a product (soft delete)
     ///
     /// # Arguments
@@ -251,4 +285,46 @@ pub trait ProductService: Send + Sync {
     /// # Errors
     /// - `ValidationError` if no product IDs provided
     async fn bulk_delete_products(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64>;
+
+    /// Bulk restore soft-deleted products
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_ids` - List of product IDs to restore
+    ///
+    /// # Returns
+    /// Number of products restored
+    ///
+    /// # Errors
+    /// - `ValidationError` if no product IDs provided
+    async fn bulk_restore_products(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64>;
+
+    // ========================================================================
+    // Attribute Operations
+    // ========================================================================
+
+    /// Get the specification attributes of a product
+    ///
+    /// # Errors
+    /// - `NotFound` if product doesn't exist
+    async fn get_product_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<ProductAttributes>;
+
+    /// Replace the specification attributes of a product
+    ///
+    /// # Returns
+    /// The attributes as stored
+    ///
+    /// # Errors
+    /// - `ValidationError` if a key or value is invalid
+    /// - `NotFound` if product doesn't exist
+    async fn set_product_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        attributes: ProductAttributes,
+    ) -> Result<ProductAttributes>;
 }
@@ -1,5 +1,6 @@
 use crate::domains::quality::{
-    CreateQualityControlPoint, QualityControlPoint, UpdateQualityControlPoint,
+    CreateQualityControlPoint, QualityCheck, QualityControlPoint, RecordQualityCheckResult,
+    UpdateQualityControlPoint,
 };
 use crate::AppError;
 use async_trait::async_trait;
@@ -46,4 +47,20 @@ pub trait QualityControlPointService: Send + Sync {
         tenant_id: Uuid,
         warehouse_id: Uuid,
     ) -> Result<Vec<QualityControlPoint>, AppError>;
+
+    async fn list_checks_for_receipt(
+        &self,
+        tenant_id: Uuid,
+        receipt_id: Uuid,
+    ) -> Result<Vec<QualityCheck>, AppError>;
+
+    /// Record an inspection result. A pass releases the held quantity to available stock;
+    /// a fail moves it to quarantine.
+    async fn record_check_result(
+        &self,
+        tenant_id: Uuid,
+        qc_id: Uuid,
+        inspector_id: Uuid,
+        result: RecordQualityCheckResult,
+    ) -> Result<QualityCheck, AppError>;
 }
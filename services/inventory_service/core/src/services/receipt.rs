@@ -43,6 +43,26 @@ pub trait ReceiptService: Send + Sync {
         user_id: Uuid,
     ) -> Result<ReceiptResponse, AppError>;
 
+    /// Reverse a received goods receipt entered in error
+    ///
+    /// Writes a compensating move for each of the receipt's stock moves, takes the
+    /// received quantity back out of stock, removes the valuation layers the receipt
+    /// created and backs its value out of the product valuation, then marks the
+    /// receipt `reversed`.
+    ///
+    /// # Errors
+    /// - `NotFound` if the receipt doesn't exist
+    /// - `ValidationError` if the receipt is not `received`
+    /// - `BusinessError` if any of the received stock was already reserved, picked,
+    ///   shipped or otherwise consumed
+    async fn reverse_receipt(
+        &self,
+        tenant_id: Uuid,
+        receipt_id: Uuid,
+        reason: &str,
+        user_id: Uuid,
+    ) -> Result<ReceiptResponse, AppError>;
+
     /// Validate receipt data before creation
     async fn validate_receipt_request(
         &self,
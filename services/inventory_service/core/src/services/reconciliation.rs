@@ -5,12 +5,14 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
+use crate::dto::common::DateRange;
 use crate::dto::reconciliation::{
     ApproveReconciliationRequest, ApproveReconciliationResponse, CountReconciliationRequest,
     CountReconciliationResponse, CreateReconciliationRequest, CreateReconciliationResponse,
-    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ReconciliationAnalyticsResponse,
-    ReconciliationDetailResponse, ReconciliationListQuery, ReconciliationListResponse,
-    VarianceAnalysisResponse,
+    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ImportReconciliationRequest,
+    ImportReconciliationResponse, ReconciliationAnalyticsResponse, ReconciliationDetailResponse,
+    ReconciliationImportQuery, ReconciliationListQuery, ReconciliationListResponse,
+    ReconciliationTrendQuery, ReconciliationTrendResponse, VarianceAnalysisResponse,
 };
 use shared_error::AppError;
 
@@ -28,6 +30,29 @@ pub trait StockReconciliationService: Send + Sync {
         request: CreateReconciliationRequest,
     ) -> Result<CreateReconciliationResponse, AppError>;
 
+    /// Create a reconciliation with counts from a stock snapshot
+    ///
+    /// Resolves each row's SKU and location code, then creates the reconciliation and
+    /// its counted items in a single transaction. Nothing is created if any row fails
+    /// to resolve; the response then lists the errors per row.
+    async fn import_reconciliation(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        request: ImportReconciliationRequest,
+    ) -> Result<ImportReconciliationResponse, AppError>;
+
+    /// Create a reconciliation with counts from a `sku,location,counted_qty` CSV snapshot
+    ///
+    /// Same as `import_reconciliation`, with the reconciliation described by `query`.
+    async fn import_reconciliation_csv(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        query: ReconciliationImportQuery,
+        data: &[u8],
+    ) -> Result<ImportReconciliationResponse, AppError>;
+
     /// Submit counted quantities for reconciliation items
     ///
     /// Updates counted quantities for the specified products, calculates variances,
@@ -84,6 +109,14 @@ pub trait StockReconciliationService: Send + Sync {
         warehouse_id: Option<Uuid>,
     ) -> Result<ReconciliationAnalyticsResponse, AppError>;
 
+    /// Get variance trend across completed reconciliations, grouped by week or month
+    async fn get_variance_trend(
+        &self,
+        tenant_id: Uuid,
+        range: DateRange,
+        query: ReconciliationTrendQuery,
+    ) -> Result<ReconciliationTrendResponse, AppError>;
+
     /// Get variance analysis for a specific reconciliation
     async fn get_variance_analysis(
         &self,
@@ -1,5 +1,6 @@
 use crate::domains::replenishment::{
-    CreateReorderRule, ReorderRule, ReplenishmentCheckResult, UpdateReorderRule,
+    CreateInternalReplenishmentRule, CreateReorderRule, InternalReplenishmentRule,
+    InternalTransferSuggestion, ReorderRule, ReplenishmentCheckResult, UpdateReorderRule,
 };
 use crate::AppError;
 use async_trait::async_trait;
@@ -47,6 +48,16 @@ pub trait ReplenishmentService: Send + Sync {
         tenant_id: Uuid,
     ) -> Result<Vec<ReplenishmentCheckResult>, AppError>;
 
+    /// Run replenishment check for all active rules as a scheduled job
+    ///
+    /// Emits at most one reorder event per rule within `cooldown_seconds`,
+    /// so a rule that stays below its reorder point does not fire on every run.
+    async fn run_scheduled_replenishment_check(
+        &self,
+        tenant_id: Uuid,
+        cooldown_seconds: i64,
+    ) -> Result<Vec<ReplenishmentCheckResult>, AppError>;
+
     /// Run replenishment check for a specific product
     async fn check_product_replenishment(
         &self,
@@ -54,4 +65,31 @@ pub trait ReplenishmentService: Send + Sync {
         product_id: Uuid,
         warehouse_id: Option<Uuid>,
     ) -> Result<ReplenishmentCheckResult, AppError>;
+
+    /// Create a warehouse-to-warehouse replenishment rule
+    async fn create_internal_replenishment_rule(
+        &self,
+        tenant_id: Uuid,
+        rule: CreateInternalReplenishmentRule,
+    ) -> Result<InternalReplenishmentRule, AppError>;
+
+    /// List active warehouse-to-warehouse replenishment rules
+    async fn list_internal_replenishment_rules(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<InternalReplenishmentRule>, AppError>;
+
+    /// Delete a warehouse-to-warehouse replenishment rule
+    async fn delete_internal_replenishment_rule(
+        &self,
+        tenant_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<(), AppError>;
+
+    /// Propose transfers for destinations below their minimum whose source
+    /// warehouse has surplus stock
+    async fn suggest_internal_transfers(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<InternalTransferSuggestion>, AppError>;
 }
@@ -154,4 +154,24 @@ pub trait ScrapService: Send + Sync {
         scrap_id: Uuid,
         user_id: Uuid,
     ) -> Result<ScrapDocumentResponse, AppError>;
+
+    /// Propose draft scrap documents for expired lots
+    ///
+    /// Finds lots whose expiry date passed more than `grace_days` ago and that
+    /// still hold stock, and creates one Draft document per warehouse with an
+    /// `Expired` line per lot. Lots already on a draft or posted scrap document
+    /// are skipped, so repeated runs do not duplicate proposals. The documents
+    /// are left in Draft for a human to review and post.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant isolation key
+    /// * `grace_days` - Days past expiry before a lot is proposed for scrap
+    ///
+    /// # Returns
+    /// The created draft documents with their lines (empty if nothing is due)
+    async fn propose_expired_lot_scraps(
+        &self,
+        tenant_id: Uuid,
+        grace_days: u32,
+    ) -> Result<Vec<ScrapDocumentWithLinesResponse>, AppError>;
 }
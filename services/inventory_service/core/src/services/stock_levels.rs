@@ -1,12 +1,14 @@
 //! Stock Levels Service trait
 //!
 //! This module defines the service trait for listing inventory stock levels
-//! with product and warehouse details.
+//! with product and warehouse details and for reading a location's level history.
 
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::dto::stock_levels::{StockLevelListQuery, StockLevelListResponse};
+use crate::dto::stock_levels::{
+    LevelHistoryQuery, LevelHistoryResponse, StockLevelListQuery, StockLevelListResponse,
+};
 use shared_error::AppError;
 
 /// Service for querying stock levels with details
@@ -21,4 +23,13 @@ pub trait StockLevelsService: Send + Sync {
         tenant_id: Uuid,
         query: StockLevelListQuery,
     ) -> Result<StockLevelListResponse, AppError>;
+
+    /// Timeline of quantity changes for a product at a location
+    ///
+    /// Fails with `ValidationError` if `from` is not before `to`.
+    async fn level_history(
+        &self,
+        tenant_id: Uuid,
+        query: LevelHistoryQuery,
+    ) -> Result<LevelHistoryResponse, AppError>;
 }
@@ -0,0 +1,34 @@
+//! Tenant export service trait
+//!
+//! Exports all of a tenant's inventory data to a downloadable file, for data
+//! subject requests and migrations off the platform.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::dto::tenant_export::TenantExportResponse;
+use crate::Result;
+
+/// Service trait for exporting a tenant's data
+#[async_trait]
+pub trait TenantExportService: Send + Sync {
+    /// Export the tenant's products, categories, warehouses, levels, moves and valuations
+    ///
+    /// # Business Rules
+    /// - Rows are read page by page, so memory use does not grow with the tenant's size
+    /// - Soft-deleted rows are included; the export is a full copy of the tenant
+    /// - The file is stored privately and only reachable through a presigned URL
+    /// - A tenant can only be exported once per configured cooldown
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant to export
+    /// * `user_id` - User recorded as requester of the export
+    ///
+    /// # Returns
+    /// Download URL, its expiry, and the number of rows per entity
+    ///
+    /// # Errors
+    /// - `NotFound` if the tenant doesn't exist
+    /// - `TooManyRequests` if the tenant was exported within the cooldown
+    async fn export_tenant(&self, tenant_id: Uuid, user_id: Uuid) -> Result<TenantExportResponse>;
+}
@@ -0,0 +1,33 @@
+//! Tenant provisioning service trait
+//!
+//! Seeds the baseline entities every tenant needs before its first receipt,
+//! so integrations do not have to re-create them.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::dto::tenant_provisioning::TenantSeedResponse;
+use crate::Result;
+
+/// Service trait for provisioning tenant defaults
+#[async_trait]
+pub trait TenantProvisioningService: Send + Sync {
+    /// Create the configured default entities for a tenant
+    ///
+    /// # Business Rules
+    /// - Seeds the default warehouse, root category and tenant-wide removal
+    ///   strategy that are part of the configured seed set
+    /// - Entities that already exist are left untouched, so re-seeding is a no-op
+    /// - Runs in a single transaction
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant to seed
+    /// * `user_id` - User recorded as creator of the seeded entities
+    ///
+    /// # Returns
+    /// Each seeded entity and whether it was created by this call
+    ///
+    /// # Errors
+    /// - `NotFound` if the tenant doesn't exist
+    async fn seed_defaults(&self, tenant_id: Uuid, user_id: Uuid) -> Result<TenantSeedResponse>;
+}
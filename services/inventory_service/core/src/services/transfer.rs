@@ -7,8 +7,10 @@ use uuid::Uuid;
 
 use crate::domains::inventory::dto::transfer_dto::{
     CancelTransferRequest, CancelTransferResponse, ConfirmTransferRequest, ConfirmTransferResponse,
-    CreateTransferRequest, CreateTransferResponse, ListTransfersParams, ListTransfersResponse,
+    CreateTransferRequest, CreateTransferResponse, ListInTransitTransfersParams,
+    ListInTransitTransfersResponse, ListTransfersParams, ListTransfersResponse,
     ReceiveTransferRequest, ReceiveTransferResponse, TransferResponse,
+    UpdateTransferTrackingRequest,
 };
 use shared_error::AppError;
 
@@ -44,6 +46,16 @@ pub trait TransferService: Send + Sync {
         request: CreateTransferRequest,
     ) -> Result<CreateTransferResponse, AppError>;
 
+    /// Create several draft transfers atomically
+    ///
+    /// Either every transfer is created or none is; responses follow the request order.
+    async fn create_transfers(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        requests: Vec<CreateTransferRequest>,
+    ) -> Result<Vec<CreateTransferResponse>, AppError>;
+
     /// Confirm a draft transfer and move stock to In-Transit location
     ///
     /// Updates transfer status to confirmed, creates stock moves to move inventory
@@ -80,4 +92,23 @@ pub trait TransferService: Send + Sync {
         user_id: Uuid,
         request: CancelTransferRequest,
     ) -> Result<CancelTransferResponse, AppError>;
+
+    /// Set or update the ETA and carrier tracking of an open transfer
+    ///
+    /// The expected arrival must be after the ship date (actual, or expected
+    /// if not yet shipped). Received and cancelled transfers cannot be updated.
+    async fn update_tracking(
+        &self,
+        tenant_id: Uuid,
+        transfer_id: Uuid,
+        user_id: Uuid,
+        request: UpdateTransferTrackingRequest,
+    ) -> Result<TransferResponse, AppError>;
+
+    /// List open transfers sorted by expected arrival, soonest first
+    async fn list_in_transit(
+        &self,
+        tenant_id: Uuid,
+        params: ListInTransitTransfersParams,
+    ) -> Result<ListInTransitTransfersResponse, AppError>;
 }
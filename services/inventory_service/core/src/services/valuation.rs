@@ -1,7 +1,7 @@
 //! Valuation service trait
 //!
 //! Defines the business logic interface for inventory valuation operations.
-//! Supports multiple costing methods (FIFO, AVCO, Standard) with cost layer management.
+//! Supports multiple costing methods (FIFO, LIFO, AVCO, Standard) with cost layer management.
 
 use async_trait::async_trait;
 use uuid::Uuid;
@@ -15,7 +15,7 @@ use crate::domains::inventory::dto::valuation_dto::{
     SetValuationMethodRequest, ValuationDto, ValuationHistoryResponse, ValuationLayersResponse,
     ValuationSettingsDto, ValuationSettingsListResponse,
 };
-use crate::domains::inventory::valuation::ValuationMethod;
+use crate::domains::inventory::valuation::{Valuation, ValuationMethod};
 use crate::Result;
 
 /// Service trait for inventory valuation business logic
@@ -61,6 +61,26 @@ pub trait ValuationService: Send + Sync {
         request: SetValuationMethodRequest,
     ) -> Result<ValuationDto>;
 
+    /// Change the valuation method of an existing valuation
+    ///
+    /// # Business Rules
+    /// - FIFO to AVCO/Standard: cost layers collapse into a single average cost
+    /// - AVCO/Standard to FIFO: the average is exploded into one cost layer
+    /// - Quantity and total value are preserved across the switch
+    /// - Records the pre-change state in history
+    /// - Runs in a single transaction
+    ///
+    /// # Arguments
+    /// * `request` - Request with tenant, product, and new method
+    ///
+    /// # Returns
+    /// Updated valuation data
+    ///
+    /// # Errors
+    /// - `NotFound` if product valuation doesn't exist
+    /// - `BusinessError` if the product is in negative stock
+    async fn change_method(&self, request: SetValuationMethodRequest) -> Result<ValuationDto>;
+
     /// Set standard cost for a product
     ///
     /// # Business Rules
@@ -142,6 +162,53 @@ pub trait ValuationService: Send + Sync {
     /// Updated valuation data
     async fn revalue_inventory(&self, request: RevaluationRequest) -> Result<ValuationDto>;
 
+    /// Rebuild FIFO cost layers from the stock move history
+    ///
+    /// # Business Rules
+    /// - Only applies to products using FIFO costing
+    /// - Replays every stock move with its unit cost, oldest first
+    /// - Replaces the cost layers and valuation totals in a single transaction
+    /// - Records the pre-rebuild state in history
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `user_id` - Administrator requesting the rebuild
+    ///
+    /// # Returns
+    /// Updated valuation data
+    ///
+    /// # Errors
+    /// - `NotFound` if product valuation doesn't exist
+    /// - `BusinessError` if the product is not FIFO or its move history cannot be replayed
+    async fn rebuild_layers(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<ValuationDto>;
+
+    /// Recompute the AVCO running average from the stock move history
+    ///
+    /// # Business Rules
+    /// - Only applies to products using AVCO costing
+    /// - Replays every receipt and delivery with its unit cost, oldest first
+    /// - Replaces the unit cost and valuation totals in a single transaction,
+    ///   with the valuation row locked against concurrent movements
+    /// - Records the pre-recompute state in history with reason "avco_recompute"
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    ///
+    /// # Returns
+    /// Updated valuation
+    ///
+    /// # Errors
+    /// - `NotFound` if product valuation doesn't exist
+    /// - `BusinessError` if the product is not AVCO or its move history cannot be replayed
+    async fn recompute_avco(&self, tenant_id: Uuid, product_id: Uuid) -> Result<Valuation>;
+
     /// Process stock movement for valuation
     ///
     /// # Business Rules
@@ -9,8 +9,33 @@ use sqlx::{PgPool, Row};
 
 use inventory_service_core::domains::category::{Category, CategoryNode};
 use inventory_service_core::dto::category::CategoryListQuery;
+use inventory_service_core::dto::common::list_fetch_limit;
 use inventory_service_core::repositories::category::CategoryRepository;
 use inventory_service_core::Result;
+use shared_error::AppError;
+
+use super::soft_delete::not_deleted;
+
+/// Report a clash with an active category's code, slug or name as a conflict
+///
+/// The unique indexes only cover rows with `deleted_at IS NULL`, so this fires
+/// when a concurrent request took the identifier, never for deleted categories.
+fn map_unique_violation(err: sqlx::Error) -> AppError {
+    match err.as_database_error() {
+        Some(db_err) if db_err.is_unique_violation() => AppError::Conflict(format!(
+            "Category already exists ({})",
+            db_err.constraint().unwrap_or("unique constraint")
+        )),
+        _ => AppError::Database(err),
+    }
+}
+
+/// Category IDs along a materialized path, root first
+fn path_category_ids(path: &str) -> Vec<uuid::Uuid> {
+    path.split('/')
+        .filter_map(|id| uuid::Uuid::parse_str(id).ok())
+        .collect()
+}
 
 /// PostgreSQL implementation of CategoryRepository
 ///
@@ -77,7 +102,8 @@ impl CategoryRepository for CategoryRepositoryImpl {
             category.total_product_count
         )
         .fetch_one(&self.pool)
-        .await?;
+        .await
+        .map_err(map_unique_violation)?;
 
         Ok(row)
     }
@@ -201,7 +227,8 @@ impl CategoryRepository for CategoryRepositoryImpl {
             category.meta_keywords
         )
         .fetch_one(&self.pool)
-        .await?;
+        .await
+        .map_err(map_unique_violation)?;
 
         Ok(row)
     }
@@ -226,6 +253,95 @@ impl CategoryRepository for CategoryRepositoryImpl {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Restore a soft-deleted category
+    ///
+    /// Refuses while an ancestor on the category's path is still deleted,
+    /// since the category would hang under a missing parent.
+    async fn restore(&self, tenant_id: uuid::Uuid, category_id: uuid::Uuid) -> Result<Category> {
+        let mut tx = self.pool.begin().await?;
+
+        // The row lock keeps the category's path fixed until the restore commits
+        let category = sqlx::query_as!(
+            Category,
+            r#"
+            SELECT
+                category_id, tenant_id, parent_category_id, name, description, code,
+                path, level, display_order, icon, color, image_url, is_active, is_visible,
+                slug, meta_title, meta_description, meta_keywords,
+                product_count, total_product_count,
+                created_at, updated_at, deleted_at
+            FROM product_categories
+            WHERE tenant_id = $1 AND category_id = $2
+            FOR UPDATE
+            "#,
+            tenant_id,
+            category_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Category {} not found", category_id)))?;
+
+        if !category.is_deleted() {
+            return Ok(category);
+        }
+
+        let ancestor_ids: Vec<uuid::Uuid> = category
+            .get_path_ids()
+            .into_iter()
+            .filter(|id| *id != category_id)
+            .collect();
+        // Share-lock the whole chain so no ancestor is deleted between this
+        // check and the update; locks are taken in id order like move_category
+        let ancestors = sqlx::query!(
+            r#"
+            SELECT name, level, deleted_at
+            FROM product_categories
+            WHERE tenant_id = $1 AND category_id = ANY($2)
+            ORDER BY category_id
+            FOR SHARE
+            "#,
+            tenant_id,
+            &ancestor_ids
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        let deleted_ancestor = ancestors
+            .into_iter()
+            .filter(|ancestor| ancestor.deleted_at.is_some())
+            .min_by_key(|ancestor| ancestor.level);
+        if let Some(ancestor) = deleted_ancestor {
+            return Err(AppError::Conflict(format!(
+                "Parent category '{}' is deleted; restore it before restoring this category",
+                ancestor.name
+            )));
+        }
+
+        let row = sqlx::query_as!(
+            Category,
+            r#"
+            UPDATE product_categories
+            SET deleted_at = NULL, updated_at = NOW()
+            WHERE tenant_id = $1 AND category_id = $2 AND deleted_at IS NOT NULL
+            RETURNING
+                category_id, tenant_id, parent_category_id, name, description, code,
+                path, level, display_order, icon, color, image_url, is_active, is_visible,
+                slug, meta_title, meta_description, meta_keywords,
+                product_count, total_product_count,
+                created_at, updated_at, deleted_at
+            "#,
+            tenant_id,
+            category_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_unique_violation)?
+        .unwrap_or(category);
+
+        tx.commit().await?;
+
+        Ok(row)
+    }
+
     /// Permanently delete a category
     ///
     /// Completely removes the category from the database.
@@ -253,8 +369,9 @@ impl CategoryRepository for CategoryRepositoryImpl {
         &self,
         tenant_id: uuid::Uuid,
         query: &CategoryListQuery,
-    ) -> Result<(Vec<Category>, i64)> {
-        let offset = (query.page - 1) * query.page_size;
+    ) -> Result<(Vec<Category>, Option<i64>)> {
+        let offset = (query.page - 1) * query.page_size();
+        let limit = list_fetch_limit(query.page_size(), query.include_total);
 
         // Build ORDER BY
         let order_field = match query.sort_by {
@@ -268,6 +385,9 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 "pc.product_count"
             },
             inventory_service_core::dto::category::CategorySortField::Level => "pc.level",
+            inventory_service_core::dto::category::CategorySortField::Relevance => {
+                "pc.display_order"
+            },
         };
 
         let order_dir = match query.sort_dir {
@@ -275,49 +395,74 @@ impl CategoryRepository for CategoryRepositoryImpl {
             inventory_service_core::dto::category::SortDirection::Desc => "DESC",
         };
 
-        let order_clause = format!("{} {}", order_field, order_dir);
-
-        let search_pattern = query
+        let search_term = query
             .search
             .as_ref()
             .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .map(|s| format!("%{}%", s));
-        let has_search = search_pattern.is_some();
+            .filter(|s| !s.is_empty());
+        let has_search = search_term.is_some();
+
+        // Relevance ranks name prefix matches first, then by full-text rank.
+        // to_tsvector/ts_rank are core Postgres full-text search and need no
+        // extension; the 'simple' configuration skips stemming, which suits
+        // short names in any language. ($6 is the raw search term.)
+        let order_clause = if has_search
+            && query.sort_by == inventory_service_core::dto::category::CategorySortField::Relevance
+        {
+            "(pc.name ILIKE ($6 || '%')) DESC,
+                 ts_rank(
+                     to_tsvector('simple', pc.name || ' ' || COALESCE(pc.description, '')),
+                     plainto_tsquery('simple', $6)
+                 ) DESC,
+                 pc.display_order ASC"
+                .to_string()
+        } else {
+            format!("{} {}", order_field, order_dir)
+        };
+
+        let live = not_deleted("pc");
 
         // Count query with search support
         let count_sql = if has_search {
-            "SELECT COUNT(*) as count FROM product_categories pc
+            format!(
+                "SELECT COUNT(*) as count FROM product_categories pc
              WHERE pc.tenant_id = $1
-               AND pc.deleted_at IS NULL
+               AND {live}
                AND (pc.parent_category_id = $2 OR $2 IS NULL)
                AND (pc.level = $3 OR $3 IS NULL)
                AND (pc.is_active = $4 OR $4 IS NULL)
                AND (pc.is_visible = $5 OR $5 IS NULL)
-               AND (pc.name ILIKE $6 OR pc.description ILIKE $6)"
+               AND (pc.name ILIKE '%' || $6 || '%' OR pc.description ILIKE '%' || $6 || '%')"
+            )
         } else {
-            "SELECT COUNT(*) as count FROM product_categories pc
+            format!(
+                "SELECT COUNT(*) as count FROM product_categories pc
              WHERE pc.tenant_id = $1
-               AND pc.deleted_at IS NULL
+               AND {live}
                AND (pc.parent_category_id = $2 OR $2 IS NULL)
                AND (pc.level = $3 OR $3 IS NULL)
                AND (pc.is_active = $4 OR $4 IS NULL)
                AND (pc.is_visible = $5 OR $5 IS NULL)"
+            )
         };
 
-        let mut count_query = sqlx::query(count_sql)
-            .bind(tenant_id)
-            .bind(query.parent_id)
-            .bind(query.level)
-            .bind(query.is_active)
-            .bind(query.is_visible);
+        let count = if query.include_total {
+            let mut count_query = sqlx::query(&count_sql)
+                .bind(tenant_id)
+                .bind(query.parent_id)
+                .bind(query.level)
+                .bind(query.is_active)
+                .bind(query.is_visible);
 
-        if let Some(ref search) = search_pattern {
-            count_query = count_query.bind(search);
-        }
+            if let Some(search) = search_term {
+                count_query = count_query.bind(search);
+            }
 
-        let count_row = count_query.fetch_one(&self.pool).await?;
-        let count: i64 = count_row.get("count");
+            let count_row = count_query.fetch_one(&self.pool).await?;
+            Some(count_row.get::<i64, _>("count"))
+        } else {
+            None
+        };
 
         // Data query with search and dynamic sort support
         let sql = if has_search {
@@ -331,12 +476,12 @@ impl CategoryRepository for CategoryRepositoryImpl {
                     pc.created_at, pc.updated_at, pc.deleted_at
                 FROM product_categories pc
                 WHERE pc.tenant_id = $1
-                  AND pc.deleted_at IS NULL
+                  AND {live}
                   AND (pc.parent_category_id = $2 OR $2 IS NULL)
                   AND (pc.level = $3 OR $3 IS NULL)
                   AND (pc.is_active = $4 OR $4 IS NULL)
                   AND (pc.is_visible = $5 OR $5 IS NULL)
-                  AND (pc.name ILIKE $6 OR pc.description ILIKE $6)
+                  AND (pc.name ILIKE '%' || $6 || '%' OR pc.description ILIKE '%' || $6 || '%')
                 ORDER BY {}
                 LIMIT $7 OFFSET $8
                 "#,
@@ -353,7 +498,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
                     pc.created_at, pc.updated_at, pc.deleted_at
                 FROM product_categories pc
                 WHERE pc.tenant_id = $1
-                  AND pc.deleted_at IS NULL
+                  AND {live}
                   AND (pc.parent_category_id = $2 OR $2 IS NULL)
                   AND (pc.level = $3 OR $3 IS NULL)
                   AND (pc.is_active = $4 OR $4 IS NULL)
@@ -365,7 +510,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
             )
         };
 
-        let categories = if let Some(ref search) = search_pattern {
+        let categories = if let Some(search) = search_term {
             sqlx::query(&sql)
                 .bind(tenant_id)
                 .bind(query.parent_id)
@@ -373,7 +518,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 .bind(query.is_active)
                 .bind(query.is_visible)
                 .bind(search)
-                .bind(query.page_size as i64)
+                .bind(limit)
                 .bind(offset as i64)
                 .map(|row: PgRow| Category {
                     category_id: row.get("category_id"),
@@ -409,7 +554,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 .bind(query.level)
                 .bind(query.is_active)
                 .bind(query.is_visible)
-                .bind(query.page_size as i64)
+                .bind(limit)
                 .bind(offset as i64)
                 .map(|row: PgRow| Category {
                     category_id: row.get("category_id"),
@@ -834,6 +979,52 @@ impl CategoryRepository for CategoryRepositoryImpl {
         Ok(1)
     }
 
+    /// Recompute product counts for every category of a tenant
+    ///
+    /// Only rows whose stored counts differ are updated, so the result is the
+    /// number of categories that had drifted.
+    async fn recount_all_product_counts(&self, tenant_id: uuid::Uuid) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            WITH counts AS (
+                SELECT
+                    pc.category_id,
+                    (
+                        SELECT COUNT(*) FROM products p
+                        WHERE p.category_id = pc.category_id
+                          AND p.tenant_id = pc.tenant_id
+                          AND p.deleted_at IS NULL
+                    ) AS product_count,
+                    (
+                        SELECT COUNT(*) FROM products p
+                        JOIN product_categories child
+                          ON child.category_id = p.category_id
+                         AND child.tenant_id = p.tenant_id
+                        WHERE (child.path = pc.path OR child.path LIKE pc.path || '/%')
+                          AND p.tenant_id = pc.tenant_id
+                          AND p.deleted_at IS NULL
+                    ) AS total_product_count
+                FROM product_categories pc
+                WHERE pc.tenant_id = $1 AND pc.deleted_at IS NULL
+            )
+            UPDATE product_categories pc
+            SET
+                product_count = counts.product_count,
+                total_product_count = counts.total_product_count
+            FROM counts
+            WHERE pc.tenant_id = $1
+              AND pc.category_id = counts.category_id
+              AND (pc.product_count, pc.total_product_count)
+                  IS DISTINCT FROM (counts.product_count::INTEGER, counts.total_product_count::INTEGER)
+            "#,
+            tenant_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     /// Move multiple products to a category
     ///
     /// Uses the database function to efficiently move products in bulk.
@@ -858,6 +1049,104 @@ impl CategoryRepository for CategoryRepositoryImpl {
         Ok(row.moved_count.unwrap_or(0))
     }
 
+    /// Move a category under a new parent
+    ///
+    /// Rejects parents inside the category's own subtree, then updates the
+    /// parent and lets the path trigger recompute path and level for the
+    /// category and its descendants.
+    ///
+    /// The check and the update share one transaction that locks the category
+    /// and the new parent's ancestor chain, so two concurrent moves touching
+    /// the same chain run one after the other and cannot form a cycle together.
+    async fn move_category(
+        &self,
+        tenant_id: uuid::Uuid,
+        category_id: uuid::Uuid,
+        new_parent_id: Option<uuid::Uuid>,
+    ) -> Result<Category> {
+        let mut tx = self.pool.begin().await?;
+
+        if let Some(parent_id) = new_parent_id {
+            if parent_id == category_id {
+                return Err(AppError::ValidationError(
+                    "A category cannot be its own parent".to_string(),
+                ));
+            }
+
+            // Lock the chain the parent path names, then re-read the path: if a
+            // concurrent move changed it before the locks were granted, lock the
+            // new chain as well and check again
+            let mut locked_path: Option<String> = None;
+            let parent_path = loop {
+                let parent_path = sqlx::query_scalar!(
+                    r#"
+                    SELECT path FROM product_categories
+                    WHERE tenant_id = $1 AND category_id = $2 AND deleted_at IS NULL
+                    "#,
+                    tenant_id,
+                    parent_id
+                )
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or_else(|| {
+                    AppError::NotFound(format!("Parent category {} not found", parent_id))
+                })?;
+
+                if locked_path.as_deref() == Some(parent_path.as_str()) {
+                    break parent_path;
+                }
+
+                let mut lock_ids = path_category_ids(&parent_path);
+                lock_ids.push(category_id);
+                sqlx::query!(
+                    r#"
+                    SELECT category_id FROM product_categories
+                    WHERE tenant_id = $1 AND category_id = ANY($2)
+                    ORDER BY category_id
+                    FOR UPDATE
+                    "#,
+                    tenant_id,
+                    &lock_ids
+                )
+                .fetch_all(&mut *tx)
+                .await?;
+                locked_path = Some(parent_path);
+            };
+
+            if path_category_ids(&parent_path).contains(&category_id) {
+                return Err(AppError::ValidationError(format!(
+                    "Cannot move category {} under its own descendant {}",
+                    category_id, parent_id
+                )));
+            }
+        }
+
+        let row = sqlx::query_as!(
+            Category,
+            r#"
+            UPDATE product_categories
+            SET parent_category_id = $3, updated_at = NOW()
+            WHERE tenant_id = $1 AND category_id = $2 AND deleted_at IS NULL
+            RETURNING
+                category_id, tenant_id, parent_category_id, name, description, code,
+                path, level, display_order, icon, color, image_url, is_active, is_visible,
+                slug, meta_title, meta_description, meta_keywords,
+                product_count, total_product_count,
+                created_at, updated_at, deleted_at
+            "#,
+            tenant_id,
+            category_id,
+            new_parent_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Category {} not found", category_id)))?;
+
+        tx.commit().await?;
+
+        Ok(row)
+    }
+
     /// Get all product IDs in a category tree
     ///
     /// Returns all product IDs that belong to the specified category
@@ -997,6 +1286,7 @@ mod tests {
     use inventory_service_core::dto::category::CategoryListQuery;
     use inventory_service_core::dto::category::CategorySortField;
     use inventory_service_core::dto::category::SortDirection;
+    use inventory_service_core::dto::common::PageSizeLimits;
     use serde_json;
     use uuid::Uuid;
     use validator::Validate;
@@ -1045,7 +1335,7 @@ mod tests {
     fn test_category_list_query_defaults() {
         let query: CategoryListQuery = serde_json::from_str("{}").unwrap();
         assert_eq!(query.page, 1);
-        assert_eq!(query.page_size, 20);
+        assert_eq!(query.page_size(), 20);
         assert_eq!(query.sort_by, CategorySortField::DisplayOrder);
         assert_eq!(query.sort_dir, SortDirection::Asc);
     }
@@ -1060,9 +1350,10 @@ mod tests {
             is_visible: Some(false),
             search: Some("electronics".to_string()),
             page: 2,
-            page_size: 50,
+            page_size: Some(50),
             sort_by: CategorySortField::Name,
             sort_dir: SortDirection::Desc,
+            include_total: true,
         };
         assert!(query.validate().is_ok());
 
@@ -1073,11 +1364,14 @@ mod tests {
 
         // Invalid page_size
         let mut invalid_query = query.clone();
-        invalid_query.page_size = 0;
+        invalid_query.page_size = Some(0);
         assert!(invalid_query.validate().is_err());
 
-        invalid_query.page_size = 101;
-        assert!(invalid_query.validate().is_err());
+        // Oversized page_size is clamped to the configured maximum, not rejected
+        invalid_query.page_size = Some(101);
+        PageSizeLimits::default().apply(&mut invalid_query).unwrap();
+        assert_eq!(invalid_query.page_size, Some(100));
+        assert!(invalid_query.validate().is_ok());
 
         // Invalid level
         let mut invalid_query = query.clone();
@@ -7,7 +7,11 @@ use uuid::Uuid;
 pub type InfraTx<'a> = &'a mut Transaction<'a, sqlx::Postgres>;
 
 use inventory_service_core::domains::inventory::product::ProductTrackingMethod;
-use inventory_service_core::models::{DeliveryOrder, DeliveryOrderItem, DeliveryOrderStatus};
+use inventory_service_core::models::{
+    allocate_by_priority, DeliveryOrder, DeliveryOrderItem, DeliveryOrderStatus, DeliveryShipment,
+    DeliveryShipmentItem, NewStockReservation, ReservationStatus, StockReservation,
+    StockReservationStatus,
+};
 use inventory_service_core::repositories::{
     DeliveryOrderItemRepository, DeliveryOrderRepository, InventoryRepository, LotSerialRepository,
     ProductRepository,
@@ -356,6 +360,59 @@ impl PgDeliveryOrderRepository {
         .await?;
         Ok(())
     }
+
+    pub async fn create_shipment_with_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        shipment: &DeliveryShipment,
+    ) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO delivery_shipments (
+                shipment_id, tenant_id, delivery_id, tracking_number, carrier,
+                shipping_cost, notes, shipped_by, shipped_at, created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            shipment.shipment_id,
+            shipment.tenant_id,
+            shipment.delivery_id,
+            shipment.tracking_number,
+            shipment.carrier,
+            shipment.shipping_cost,
+            shipment.notes,
+            shipment.shipped_by,
+            shipment.shipped_at,
+            shipment.created_at,
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn create_shipment_item_with_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        shipment_item: &DeliveryShipmentItem,
+    ) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO delivery_shipment_items (
+                shipment_item_id, tenant_id, shipment_id, delivery_item_id,
+                product_id, quantity, created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            shipment_item.shipment_item_id,
+            shipment_item.tenant_id,
+            shipment_item.shipment_id,
+            shipment_item.delivery_item_id,
+            shipment_item.product_id,
+            shipment_item.quantity,
+            shipment_item.created_at,
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
 }
 
 pub struct PgDeliveryOrderItemRepository {
@@ -610,7 +667,7 @@ impl InventoryRepository for PgInventoryRepository {
         warehouse_id: Uuid,
         product_id: Uuid,
         quantity: i64,
-    ) -> Result<(), AppError> {
+    ) -> Result<ReservationStatus, AppError> {
         if quantity <= 0 {
             return Err(AppError::ValidationError(
                 "Quantity to reserve must be positive".to_string(),
@@ -714,7 +771,7 @@ impl InventoryRepository for PgInventoryRepository {
                 tx.commit().await.map_err(|e| {
                     AppError::DatabaseError(format!("Failed to commit transaction: {}", e))
                 })?;
-                Ok(())
+                Ok(ReservationStatus::Reserved)
             },
             ProductTrackingMethod::None => {
                 // Standard reservation from inventory_levels
@@ -736,13 +793,49 @@ impl InventoryRepository for PgInventoryRepository {
                 .execute(&*self.pool)
                 .await?;
 
-                if res.rows_affected() == 0 {
-                    return Err(AppError::ValidationError(
-                        "Insufficient stock available for reservation".to_string(),
-                    ));
+                if res.rows_affected() > 0 {
+                    return Ok(ReservationStatus::Reserved);
                 }
 
-                Ok(())
+                // Over-commit: take what is available and backorder the shortfall, as
+                // long as total backorders stay within overcommit_pct of on-hand stock.
+                // SET expressions all see the pre-update row.
+                if product.overcommit_pct > 0 {
+                    let res = sqlx::query!(
+                        r#"
+                        UPDATE inventory_levels
+                        SET reserved_quantity = reserved_quantity + available_quantity,
+                            backordered_quantity = backordered_quantity + ($4 - available_quantity),
+                            available_quantity = 0,
+                            updated_at = NOW()
+                        WHERE tenant_id = $1 AND product_id = $2 AND warehouse_id = $3
+                          AND available_quantity < $4
+                          AND (backordered_quantity + ($4 - available_quantity)) * 100
+                              <= (available_quantity + reserved_quantity) * $5::BIGINT
+                          AND deleted_at IS NULL
+                        "#,
+                        tenant_id,
+                        product_id,
+                        warehouse_id,
+                        quantity,
+                        product.overcommit_pct as i64,
+                    )
+                    .execute(&*self.pool)
+                    .await?;
+
+                    if res.rows_affected() > 0 {
+                        return Ok(ReservationStatus::Backordered);
+                    }
+
+                    return Err(AppError::ValidationError(format!(
+                        "Insufficient stock available for reservation (over-commit buffer of {}% exceeded)",
+                        product.overcommit_pct
+                    )));
+                }
+
+                Err(AppError::ValidationError(
+                    "Insufficient stock available for reservation".to_string(),
+                ))
             },
         }
     }
@@ -860,14 +953,16 @@ impl InventoryRepository for PgInventoryRepository {
                 Ok(())
             },
             ProductTrackingMethod::None => {
+                // Backorders are cancelled first; only the remainder frees reserved stock
                 let res = sqlx::query!(
                     r#"
                     UPDATE inventory_levels
-                    SET available_quantity = available_quantity + $4,
-                        reserved_quantity = reserved_quantity - $4,
+                    SET backordered_quantity = backordered_quantity - LEAST(backordered_quantity, $4),
+                        available_quantity = available_quantity + ($4 - LEAST(backordered_quantity, $4)),
+                        reserved_quantity = reserved_quantity - ($4 - LEAST(backordered_quantity, $4)),
                         updated_at = NOW()
                     WHERE tenant_id = $1 AND product_id = $2 AND warehouse_id = $3
-                      AND reserved_quantity >= $4
+                      AND reserved_quantity + backordered_quantity >= $4
                       AND deleted_at IS NULL
                     "#,
                     tenant_id,
@@ -913,6 +1008,156 @@ impl InventoryRepository for PgInventoryRepository {
             None => Ok(0), // No inventory record means 0 available
         }
     }
+
+    async fn queue_reservation(
+        &self,
+        tenant_id: Uuid,
+        reservation: &NewStockReservation,
+    ) -> Result<StockReservation, AppError> {
+        sqlx::query_as::<_, StockReservation>(
+            r#"
+            INSERT INTO stock_reservations (
+                tenant_id, warehouse_id, product_id, source_type, source_id,
+                priority, requested_quantity, status
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING reservation_id, tenant_id, warehouse_id, product_id, source_type, source_id,
+                      priority, requested_quantity, allocated_quantity, backordered_quantity,
+                      status, created_at, updated_at
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(reservation.warehouse_id)
+        .bind(reservation.product_id)
+        .bind(&reservation.source_type)
+        .bind(reservation.source_id)
+        .bind(reservation.priority)
+        .bind(reservation.quantity)
+        .bind(StockReservationStatus::Pending)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to queue reservation: {}", e)))
+    }
+
+    async fn allocate_scarce(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        available: i64,
+    ) -> Result<Vec<StockReservation>, AppError> {
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                AppError::DatabaseError(format!("Failed to begin transaction: {}", e))
+            })?;
+
+        // Lock the open reservations so concurrent allocations cannot hand
+        // out the same stock twice
+        let mut reservations = sqlx::query_as::<_, StockReservation>(
+            r#"
+            SELECT reservation_id, tenant_id, warehouse_id, product_id, source_type, source_id,
+                   priority, requested_quantity, allocated_quantity, backordered_quantity,
+                   status, created_at, updated_at
+            FROM stock_reservations
+            WHERE tenant_id = $1 AND product_id = $2 AND status IN ('pending', 'backordered')
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(product_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to load reservations: {}", e)))?;
+
+        allocate_by_priority(&mut reservations, available);
+
+        let ids: Vec<Uuid> = reservations.iter().map(|r| r.reservation_id).collect();
+        let allocated: Vec<i64> = reservations.iter().map(|r| r.allocated_quantity).collect();
+        let backordered: Vec<i64> = reservations
+            .iter()
+            .map(|r| r.backordered_quantity)
+            .collect();
+        let statuses: Vec<String> = reservations.iter().map(|r| r.status.to_string()).collect();
+
+        sqlx::query(
+            r#"
+            UPDATE stock_reservations sr
+            SET allocated_quantity = a.allocated_quantity,
+                backordered_quantity = a.backordered_quantity,
+                status = a.status,
+                updated_at = NOW()
+            FROM UNNEST($2::uuid[], $3::bigint[], $4::bigint[], $5::text[])
+                AS a(reservation_id, allocated_quantity, backordered_quantity, status)
+            WHERE sr.tenant_id = $1 AND sr.reservation_id = a.reservation_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(&ids)
+        .bind(&allocated)
+        .bind(&backordered)
+        .bind(&statuses)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to allocate reservations: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(reservations)
+    }
+
+    async fn claim_source_reservations(
+        &self,
+        tenant_id: Uuid,
+        source_id: Uuid,
+    ) -> Result<Vec<StockReservation>, AppError> {
+        // A single UPDATE claims the rows, so duplicate deliveries of the same
+        // event cannot both see them open; `prev` reports the status before
+        sqlx::query_as::<_, StockReservation>(
+            r#"
+            UPDATE stock_reservations sr
+            SET status = 'released', released_at = NOW(), updated_at = NOW()
+            FROM (
+                SELECT reservation_id, status
+                FROM stock_reservations
+                WHERE tenant_id = $1 AND source_id = $2
+                  AND status IN ('pending', 'reserved', 'backordered')
+                FOR UPDATE
+            ) prev
+            WHERE sr.tenant_id = $1 AND sr.reservation_id = prev.reservation_id
+            RETURNING sr.reservation_id, sr.tenant_id, sr.warehouse_id, sr.product_id,
+                      sr.source_type, sr.source_id, sr.priority, sr.requested_quantity,
+                      sr.allocated_quantity, sr.backordered_quantity, prev.status,
+                      sr.created_at, sr.updated_at
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(source_id)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to claim reservations: {}", e)))
+    }
+
+    async fn restore_reservation_status(
+        &self,
+        tenant_id: Uuid,
+        reservation_id: Uuid,
+        status: StockReservationStatus,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE stock_reservations
+            SET status = $3, released_at = NULL, updated_at = NOW()
+            WHERE tenant_id = $1 AND reservation_id = $2 AND status = 'released'
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(reservation_id)
+        .bind(status)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to restore reservation: {}", e)))?;
+        Ok(())
+    }
 }
 
 // sqlx implementations for DeliveryOrderStatus (moved from core to avoid infra deps)
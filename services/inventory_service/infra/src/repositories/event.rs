@@ -11,6 +11,7 @@ use uuid::Uuid;
 
 use inventory_service_core::repositories::event::EventRepository;
 use shared_error::AppError;
+use shared_events::current_correlation_id;
 
 /// PostgreSQL implementation of EventRepository
 pub struct EventRepositoryImpl {
@@ -37,14 +38,16 @@ impl EventRepository for EventRepositoryImpl {
         sqlx::query!(
             r#"
             INSERT INTO event_outbox (
-                id, tenant_id, event_type, event_data, status, created_at, updated_at
+                id, tenant_id, event_type, event_data, correlation_id, status,
+                created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, 'pending', NOW(), NOW())
+            VALUES ($1, $2, $3, $4, $5, 'pending', NOW(), NOW())
             "#,
             event_id,
             tenant_id,
             event_type,
-            event_data
+            event_data,
+            current_correlation_id()
         )
         .execute(&self.pool)
         .await?;
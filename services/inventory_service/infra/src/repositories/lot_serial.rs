@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 use inventory_service_core::models::{LotSerial, LotSerialStatus, LotSerialTrackingType};
 use inventory_service_core::repositories::lot_serial::LotSerialRepository;
+use shared_db::AssertTenantFiltered;
 use shared_error::AppError;
 
 /// PostgreSQL implementation of LotSerialRepository
@@ -148,7 +149,11 @@ impl LotSerialRepository for LotSerialRepositoryImpl {
 
         query.push(" ORDER BY expiry_date ASC NULLS LAST");
 
-        let rows = query.build().fetch_all(&self.pool).await?;
+        let rows = query
+            .assert_tenant_filtered()
+            .build()
+            .fetch_all(&self.pool)
+            .await?;
 
         let lot_serials = rows.into_iter().map(Self::map_row_to_lot_serial).collect();
 
@@ -189,7 +194,11 @@ impl LotSerialRepository for LotSerialRepositoryImpl {
 
         query.push(" ORDER BY expiry_date ASC NULLS LAST, created_at ASC");
 
-        let rows = query.build().fetch_all(&self.pool).await?;
+        let rows = query
+            .assert_tenant_filtered()
+            .build()
+            .fetch_all(&self.pool)
+            .await?;
 
         let lot_serials: Vec<LotSerial> =
             rows.into_iter().map(Self::map_row_to_lot_serial).collect();
@@ -18,8 +18,10 @@ pub mod reconciliation;
 pub mod removal_strategy;
 pub mod replenishment;
 pub mod rma;
+pub mod soft_delete;
 pub mod stock;
 pub mod stock_take;
+pub mod tenant_settings;
 pub mod transfer;
 pub mod valuation;
 pub mod warehouse;
@@ -44,10 +46,11 @@ pub use quality::PgQualityControlPointRepository;
 pub use receipt::ReceiptRepositoryImpl;
 pub use reconciliation::{PgStockReconciliationItemRepository, PgStockReconciliationRepository};
 pub use removal_strategy::RemovalStrategyRepositoryImpl;
-pub use replenishment::PgReorderRuleRepository;
+pub use replenishment::{PgInternalReplenishmentRuleRepository, PgReorderRuleRepository};
 pub use rma::{PgRmaItemRepository, PgRmaRepository};
 pub use stock::{PgInventoryLevelRepository, PgStockMoveRepository};
 pub use stock_take::{PgStockTakeLineRepository, PgStockTakeRepository};
+pub use tenant_settings::PgTenantSettingsRepository;
 pub use transfer::{PgTransferItemRepository, PgTransferRepository};
 pub use valuation::{ValuationRepositoryImpl, ValuationSettingsRepositoryImpl};
 pub use warehouse::WarehouseRepositoryImpl;
@@ -11,6 +11,7 @@ use inventory_service_core::domains::inventory::dto::picking_method_dto::{
     UpdatePickingMethodRequest,
 };
 use inventory_service_core::domains::inventory::picking_method::PickingMethod;
+use inventory_service_core::domains::inventory::picking_route::{LocationPoint, RouteStop};
 use inventory_service_core::repositories::picking_method::PickingMethodRepository;
 use inventory_service_core::Result;
 
@@ -350,6 +351,44 @@ impl PickingMethodRepository for PickingMethodRepositoryImpl {
         })
     }
 
+    async fn find_route_stops(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        location_ids: &[Uuid],
+    ) -> Result<Vec<RouteStop>> {
+        if location_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query_as::<_, (Uuid, String, Option<serde_json::Value>)>(
+            r#"
+            SELECT location_id, location_code, coordinates
+            FROM warehouse_locations
+            WHERE tenant_id = $1
+              AND warehouse_id = $2
+              AND location_id = ANY($3)
+              AND deleted_at IS NULL
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(warehouse_id)
+        .bind(location_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(location_id, location_code, coordinates)| RouteStop {
+                location_id,
+                location_code,
+                point: coordinates
+                    .as_ref()
+                    .and_then(LocationPoint::from_coordinates),
+            })
+            .collect())
+    }
+
     async fn validate_method_config(&self, tenant_id: Uuid, method_id: Uuid) -> Result<bool> {
         if let Some(method) = self.find_by_id(tenant_id, method_id).await? {
             // Minimal structural validation:
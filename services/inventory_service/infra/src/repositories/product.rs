@@ -1,9 +1,12 @@
 //! Product repository implementation
 //!
 //! PostgreSQL implementation of the ProductRepository trait.
+//!
+//! Every query runs in a transaction scoped to the caller's tenant (see
+//! [`begin_tenant_tx`]), so row-level security backs up the `tenant_id` filters.
 
 use async_trait::async_trait;
-use sqlx::{PgPool, QueryBuilder, Row};
+use sqlx::{PgConnection, PgPool, QueryBuilder, Row};
 use uuid::Uuid;
 
 use inventory_service_core::domains::inventory::dto::search_dto::{
@@ -13,9 +16,16 @@ use inventory_service_core::domains::inventory::dto::search_dto::{
 use inventory_service_core::domains::inventory::product::{
     BarcodeType, Product, ProductTrackingMethod,
 };
+use inventory_service_core::dto::product::{
+    ProductAttributes, ProductSortField, SortDirection as ListSortDirection,
+};
 use inventory_service_core::repositories::product::ProductRepository;
 use inventory_service_core::Result;
 
+use shared_db::{begin_tenant_tx, AssertTenantFiltered};
+
+use super::soft_delete::{not_deleted, push_not_deleted};
+
 /// PostgreSQL implementation of ProductRepository
 pub struct ProductRepositoryImpl {
     pool: PgPool,
@@ -26,6 +36,55 @@ impl ProductRepositoryImpl {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Recalculate product counts for the given categories and their ancestors
+    ///
+    /// The category count trigger only fires when `category_id` changes, so
+    /// soft deletes and restores have to refresh the counts themselves.
+    async fn recount_categories(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        category_ids: &[Uuid],
+    ) -> Result<()> {
+        if category_ids.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE product_categories pc
+            SET
+                product_count = (
+                    SELECT COUNT(*) FROM products
+                    WHERE category_id = pc.category_id
+                      AND tenant_id = pc.tenant_id
+                      AND deleted_at IS NULL
+                ),
+                total_product_count = (
+                    SELECT COUNT(*) FROM products p
+                    JOIN product_categories child
+                      ON child.category_id = p.category_id
+                     AND child.tenant_id = p.tenant_id
+                    WHERE (child.path = pc.path OR child.path LIKE pc.path || '/%')
+                      AND p.tenant_id = pc.tenant_id
+                      AND p.deleted_at IS NULL
+                )
+            WHERE pc.tenant_id = $1
+              AND EXISTS (
+                  SELECT 1 FROM product_categories target
+                  WHERE target.tenant_id = $1
+                    AND target.category_id = ANY($2)
+                    AND (target.path = pc.path OR target.path LIKE pc.path || '/%')
+              )
+            "#,
+            tenant_id,
+            category_ids
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -42,7 +101,7 @@ impl ProductRepository for ProductRepositoryImpl {
         use inventory_service_core::domains::inventory::dto::search_dto::{
             ProductSearchResult, SearchFacets, SearchMeta,
         };
-        use inventory_service_core::dto::common::PaginationInfo;
+        use inventory_service_core::dto::common::{list_fetch_limit, PaginationInfo};
 
         let start_time = std::time::Instant::now();
 
@@ -63,6 +122,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 p.currency_code,
                 p.is_active,
                 p.is_sellable,
+                p.attributes,
                 p.created_at,
                 p.updated_at
             "#,
@@ -90,15 +150,18 @@ impl ProductRepository for ProductRepositoryImpl {
             );
         }
 
-        query_builder.push(
+        // A deleted category no longer names the products left in it
+        query_builder.push(format!(
             r#"
             FROM products p
             LEFT JOIN product_categories c ON p.category_id = c.category_id AND c.tenant_id = p.tenant_id
+                AND {}
             WHERE p.tenant_id =
             "#,
-        );
+            not_deleted("c")
+        ));
         query_builder.push_bind(tenant_id);
-        query_builder.push(" AND p.deleted_at IS NULL");
+        push_not_deleted(&mut query_builder, "p");
 
         // Add full-text search if query provided
         if let Some(q) = &request.query {
@@ -161,52 +224,92 @@ impl ProductRepository for ProductRepositoryImpl {
             query_builder.push(" AND p.track_inventory = false");
         }
 
+        // Attribute filters use JSONB containment (served by the GIN index)
+        if !request.attributes.is_empty() {
+            query_builder.push(" AND p.attributes @> ");
+            query_builder.push_bind(sqlx::types::Json(request.attributes.clone()));
+        }
+
         // Add sorting
-        let sort_by = request
-            .sort_by
-            .as_ref()
-            .unwrap_or(&ProductSortBy::Relevance);
-        let sort_order = request.sort_order.as_ref().unwrap_or(&SortOrder::Desc);
-
-        let order_clause = match sort_by {
-            ProductSortBy::Relevance => {
-                if request.query.is_some() {
-                    "relevance_score"
-                } else {
-                    "p.created_at"
-                }
-            },
-            ProductSortBy::Name => "p.name",
-            ProductSortBy::Price => "p.sale_price",
-            ProductSortBy::Popularity => "p.created_at", // TODO: implement popularity
-            ProductSortBy::CreatedAt => "p.created_at",
-            ProductSortBy::UpdatedAt => "p.updated_at",
-        };
+        if !request.order_by.is_empty() {
+            // Columns come from the allowlist enum; product_id keeps pages stable on ties
+            let keys: Vec<String> = request
+                .order_by
+                .iter()
+                .map(|spec| {
+                    let column = match spec.field {
+                        ProductSortField::Name => "p.name",
+                        ProductSortField::Sku => "p.sku",
+                        ProductSortField::Category => "c.name",
+                        ProductSortField::ProductType => "p.product_type",
+                        ProductSortField::SalePrice => "p.sale_price",
+                        ProductSortField::CostPrice => "p.cost_price",
+                        ProductSortField::CreatedAt => "p.created_at",
+                        ProductSortField::UpdatedAt => "p.updated_at",
+                    };
+                    let direction = match spec.direction {
+                        ListSortDirection::Asc => "ASC",
+                        ListSortDirection::Desc => "DESC",
+                    };
+                    format!("{} {}", column, direction)
+                })
+                .collect();
+            query_builder.push(" ORDER BY ");
+            query_builder.push(keys.join(", "));
+            query_builder.push(", p.product_id ASC");
+        } else {
+            let sort_by = request
+                .sort_by
+                .as_ref()
+                .unwrap_or(&ProductSortBy::Relevance);
+            let sort_order = request.sort_order.as_ref().unwrap_or(&SortOrder::Desc);
+
+            let order_clause = match sort_by {
+                ProductSortBy::Relevance => {
+                    if request.query.is_some() {
+                        "relevance_score"
+                    } else {
+                        "p.created_at"
+                    }
+                },
+                ProductSortBy::Name => "p.name",
+                ProductSortBy::Price => "p.sale_price",
+                ProductSortBy::Popularity => "p.created_at", // TODO: implement popularity
+                ProductSortBy::CreatedAt => "p.created_at",
+                ProductSortBy::UpdatedAt => "p.updated_at",
+            };
 
-        query_builder.push(" ORDER BY ");
-        query_builder.push(order_clause);
+            query_builder.push(" ORDER BY ");
+            query_builder.push(order_clause);
 
-        let order_direction = match sort_order {
-            SortOrder::Asc => " ASC",
-            SortOrder::Desc => " DESC",
-        };
-        query_builder.push(order_direction);
+            let order_direction = match sort_order {
+                SortOrder::Asc => " ASC",
+                SortOrder::Desc => " DESC",
+            };
+            query_builder.push(order_direction);
+        }
 
         // Add pagination
         let page = request.page.unwrap_or(1).max(1);
         let limit = request.limit.unwrap_or(20).min(100);
         let offset = (page - 1) * limit;
+        let include_total = request.include_total.unwrap_or(true);
 
         query_builder.push(" LIMIT ");
-        query_builder.push_bind(limit as i64);
+        query_builder.push_bind(list_fetch_limit(limit, include_total));
         query_builder.push(" OFFSET ");
         query_builder.push_bind(offset as i64);
 
         // Execute query
-        let rows = query_builder.build().fetch_all(&self.pool).await?;
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+        let rows = query_builder
+            .assert_tenant_filtered()
+            .build()
+            .fetch_all(&mut *tx)
+            .await?;
 
         // Convert rows to results
-        let products: Vec<ProductSearchResult> = rows
+        let mut products: Vec<ProductSearchResult> = rows
             .into_iter()
             .map(|row| {
                 let highlights = if let Some(q) = &request.query {
@@ -232,6 +335,7 @@ impl ProductRepository for ProductRepositoryImpl {
                     in_stock: Some(!row.get::<bool, _>("track_inventory")),
                     is_active: row.get("is_active"),
                     is_sellable: row.get("is_sellable"),
+                    attributes: row.get("attributes"),
                     highlights,
                     relevance_score: row.try_get::<f32, _>("relevance_score").unwrap_or(0.0),
                     created_at: row.get("created_at"),
@@ -240,81 +344,85 @@ impl ProductRepository for ProductRepositoryImpl {
             })
             .collect();
 
-        // Get total count
-        let mut count_builder =
-            QueryBuilder::new("SELECT COUNT(*) as count FROM products p WHERE p.tenant_id = ");
-        count_builder.push_bind(tenant_id);
-        count_builder.push(" AND p.deleted_at IS NULL");
-
-        // Apply same filters for count
-        if let Some(q) = &request.query {
-            count_builder.push(" AND to_tsvector('english', p.name || ' ' || COALESCE(p.description, '')) @@ plainto_tsquery('english', ");
-            count_builder.push_bind(q.as_str());
-            count_builder.push(")");
-        }
-
-        if let Some(category_ids) = &request.category_ids {
-            if !category_ids.is_empty() {
-                count_builder.push(" AND p.category_id = ANY(");
-                count_builder.push_bind(category_ids.as_slice());
+        // Get total count (skipped when include_total is false)
+        let total_count = if include_total {
+            let mut count_builder =
+                QueryBuilder::new("SELECT COUNT(*) as count FROM products p WHERE p.tenant_id = ");
+            count_builder.push_bind(tenant_id);
+            push_not_deleted(&mut count_builder, "p");
+
+            // Apply same filters for count
+            if let Some(q) = &request.query {
+                count_builder.push(" AND to_tsvector('english', p.name || ' ' || COALESCE(p.description, '')) @@ plainto_tsquery('english', ");
+                count_builder.push_bind(q.as_str());
                 count_builder.push(")");
             }
-        }
 
-        if let Some(min_price) = request.price_min {
-            count_builder.push(" AND p.sale_price >= ");
-            count_builder.push_bind(min_price);
-        }
-        if let Some(max_price) = request.price_max {
-            count_builder.push(" AND p.sale_price <= ");
-            count_builder.push_bind(max_price);
-        }
+            if let Some(category_ids) = &request.category_ids {
+                if !category_ids.is_empty() {
+                    count_builder.push(" AND p.category_id = ANY(");
+                    count_builder.push_bind(category_ids.as_slice());
+                    count_builder.push(")");
+                }
+            }
 
-        if let Some(types) = &request.product_types {
-            if !types.is_empty() {
-                count_builder.push(" AND p.product_type = ANY(");
-                count_builder.push_bind(types.as_slice());
-                count_builder.push(")");
+            if let Some(min_price) = request.price_min {
+                count_builder.push(" AND p.sale_price >= ");
+                count_builder.push_bind(min_price);
+            }
+            if let Some(max_price) = request.price_max {
+                count_builder.push(" AND p.sale_price <= ");
+                count_builder.push_bind(max_price);
             }
-        }
 
-        // Add status filters (must match main query logic)
-        if let Some(active) = request.active_only {
-            if active {
-                count_builder.push(" AND p.is_active = true");
-            } else {
-                count_builder.push(" AND p.is_active = false");
+            if let Some(types) = &request.product_types {
+                if !types.is_empty() {
+                    count_builder.push(" AND p.product_type = ANY(");
+                    count_builder.push_bind(types.as_slice());
+                    count_builder.push(")");
+                }
             }
-        }
-        if let Some(sellable) = request.sellable_only {
-            if sellable {
-                count_builder.push(" AND p.is_sellable = true");
-            } else {
-                count_builder.push(" AND p.is_sellable = false");
+
+            // Add status filters (must match main query logic)
+            if let Some(active) = request.active_only {
+                if active {
+                    count_builder.push(" AND p.is_active = true");
+                } else {
+                    count_builder.push(" AND p.is_active = false");
+                }
+            }
+            if let Some(sellable) = request.sellable_only {
+                if sellable {
+                    count_builder.push(" AND p.is_sellable = true");
+                } else {
+                    count_builder.push(" AND p.is_sellable = false");
+                }
             }
-        }
 
-        // Add in-stock filter (must match main query logic)
-        if request.in_stock_only.unwrap_or(false) {
-            count_builder.push(" AND p.track_inventory = false");
-        }
+            // Add in-stock filter (must match main query logic)
+            if request.in_stock_only.unwrap_or(false) {
+                count_builder.push(" AND p.track_inventory = false");
+            }
 
-        let total_count: i64 = count_builder
-            .build_query_scalar()
-            .fetch_one(&self.pool)
-            .await?;
+            if !request.attributes.is_empty() {
+                count_builder.push(" AND p.attributes @> ");
+                count_builder.push_bind(sqlx::types::Json(request.attributes.clone()));
+            }
 
-        // Build pagination info
-        #[allow(clippy::manual_div_ceil)]
-        let total_pages = ((total_count as u32 + limit - 1) / limit).max(1);
-        let pagination = PaginationInfo {
-            page,
-            page_size: limit,
-            total_items: total_count as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
+            let total_count: i64 = count_builder
+                .assert_tenant_filtered()
+                .build_query_scalar()
+                .fetch_one(&mut *tx)
+                .await?;
+            Some(total_count as u64)
+        } else {
+            None
         };
+        tx.commit().await?;
+
+        // Build pagination info (drops the look-ahead row when the count was skipped)
+        let pagination =
+            PaginationInfo::from_optional_total(page, limit, total_count, &mut products);
 
         // TODO: Implement facets
         let facets = SearchFacets {
@@ -328,7 +436,7 @@ impl ProductRepository for ProductRepositoryImpl {
         let meta = SearchMeta {
             query: request.query.clone(),
             execution_time_ms: execution_time,
-            total_found: total_count as u64,
+            total_found: total_count,
             applied_filters: AppliedFilters {
                 category_ids: request.category_ids,
                 price_min: request.price_min,
@@ -360,6 +468,8 @@ impl ProductRepository for ProductRepositoryImpl {
         let limit = request.limit.unwrap_or(10).min(20) as i64;
         let search_pattern = format!("%{}%", request.query);
 
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+
         // Search for product names
         let product_suggestions = sqlx::query!(
             r#"
@@ -376,7 +486,7 @@ impl ProductRepository for ProductRepositoryImpl {
             search_pattern,
             limit
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await?;
 
         // Search for SKUs
@@ -395,8 +505,9 @@ impl ProductRepository for ProductRepositoryImpl {
             search_pattern,
             limit
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         // Combine and deduplicate suggestions
         let mut suggestions_map: std::collections::HashMap<String, SearchSuggestion> =
@@ -442,6 +553,7 @@ impl ProductRepository for ProductRepositoryImpl {
     // ========================================================================
 
     async fn find_by_id(&self, tenant_id: Uuid, product_id: Uuid) -> Result<Option<Product>> {
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
         let row = sqlx::query!(
             r#"
             SELECT
@@ -449,7 +561,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at, deleted_at
             FROM products
             WHERE tenant_id = $1 AND product_id = $2 AND deleted_at IS NULL
@@ -457,8 +569,9 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             product_id
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(row.map(|row| Product {
             product_id: row.product_id,
@@ -487,6 +600,7 @@ impl ProductRepository for ProductRepositoryImpl {
             is_active: row.is_active,
             is_sellable: row.is_sellable,
             is_purchaseable: row.is_purchaseable,
+            overcommit_pct: row.overcommit_pct,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -498,6 +612,7 @@ impl ProductRepository for ProductRepositoryImpl {
             return Ok(vec![]);
         }
 
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
         let rows = sqlx::query!(
             r#"
             SELECT
@@ -505,7 +620,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at, deleted_at
             FROM products
             WHERE tenant_id = $1 AND product_id = ANY($2) AND deleted_at IS NULL
@@ -513,8 +628,9 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             &product_ids
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         let products = rows
             .into_iter()
@@ -545,6 +661,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 is_active: row.is_active,
                 is_sellable: row.is_sellable,
                 is_purchaseable: row.is_purchaseable,
+                overcommit_pct: row.overcommit_pct,
                 created_at: row.created_at,
                 updated_at: row.updated_at,
                 deleted_at: row.deleted_at,
@@ -555,6 +672,7 @@ impl ProductRepository for ProductRepositoryImpl {
     }
 
     async fn find_by_sku(&self, tenant_id: Uuid, sku: &str) -> Result<Option<Product>> {
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
         let row = sqlx::query!(
             r#"
             SELECT
@@ -562,7 +680,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at, deleted_at
             FROM products
             WHERE tenant_id = $1 AND sku = $2 AND deleted_at IS NULL
@@ -570,8 +688,9 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             sku
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(row.map(|row| Product {
             product_id: row.product_id,
@@ -600,6 +719,7 @@ impl ProductRepository for ProductRepositoryImpl {
             is_active: row.is_active,
             is_sellable: row.is_sellable,
             is_purchaseable: row.is_purchaseable,
+            overcommit_pct: row.overcommit_pct,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -607,6 +727,8 @@ impl ProductRepository for ProductRepositoryImpl {
     }
 
     async fn find_by_barcode(&self, tenant_id: Uuid, barcode: &str) -> Result<Option<Product>> {
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+
         // First, try to find in products.barcode column (new dedicated field)
         let row = sqlx::query!(
             r#"
@@ -615,7 +737,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at, deleted_at
             FROM products
             WHERE tenant_id = $1 AND barcode = $2 AND deleted_at IS NULL
@@ -624,7 +746,7 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             barcode
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?;
 
         if let Some(row) = row {
@@ -655,10 +777,12 @@ impl ProductRepository for ProductRepositoryImpl {
                 is_active: row.is_active,
                 is_sellable: row.is_sellable,
                 is_purchaseable: row.is_purchaseable,
+                overcommit_pct: row.overcommit_pct,
                 created_at: row.created_at,
                 updated_at: row.updated_at,
                 deleted_at: row.deleted_at,
             };
+            tx.commit().await?;
             return Ok(Some(product));
         }
 
@@ -670,7 +794,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 p.product_type, p.barcode, p.barcode_type, p.category_id, p.item_group_id, p.track_inventory, p.tracking_method,
                 p.default_uom_id, p.sale_price, p.cost_price, p.currency_code,
                 p.weight_grams, p.dimensions, p.attributes,
-                p.is_active, p.is_sellable, p.is_purchaseable,
+                p.is_active, p.is_sellable, p.is_purchaseable, p.overcommit_pct,
                 p.created_at, p.updated_at, p.deleted_at
             FROM product_variants pv
             JOIN products p ON pv.parent_product_id = p.product_id
@@ -681,7 +805,7 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             barcode
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?;
 
         if let Some(row) = row {
@@ -712,20 +836,43 @@ impl ProductRepository for ProductRepositoryImpl {
                 is_active: row.is_active,
                 is_sellable: row.is_sellable,
                 is_purchaseable: row.is_purchaseable,
+                overcommit_pct: row.overcommit_pct,
                 created_at: row.created_at,
                 updated_at: row.updated_at,
                 deleted_at: row.deleted_at,
             };
+            tx.commit().await?;
             return Ok(Some(product));
         }
 
+        tx.commit().await?;
         Ok(None)
     }
 
+    async fn exists(&self, tenant_id: Uuid, product_id: Uuid) -> Result<bool> {
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM products
+                WHERE tenant_id = $1 AND product_id = $2 AND deleted_at IS NULL
+            ) as "exists!"
+            "#,
+            tenant_id,
+            product_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(exists)
+    }
+
     async fn create(&self, product: &Product) -> Result<Product> {
         let tracking_method_str = product.tracking_method.to_string();
         let barcode_type_str = product.barcode_type.as_ref().map(|bt| bt.to_string());
 
+        let mut tx = begin_tenant_tx(&self.pool, product.tenant_id).await?;
         let row = sqlx::query!(
             r#"
             INSERT INTO products (
@@ -733,22 +880,22 @@ impl ProductRepository for ProductRepositoryImpl {
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at
             ) VALUES (
                 $1, $2, $3, $4, $5,
                 $6, $7, $8, $9, $10, $11, $12,
                 $13, $14, $15, $16,
                 $17, $18, $19,
-                $20, $21, $22,
-                $23, $24
+                $20, $21, $22, $23,
+                $24, $25
             )
             RETURNING
                 product_id, tenant_id, sku, name, description,
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at, deleted_at
             "#,
             product.product_id,
@@ -773,11 +920,13 @@ impl ProductRepository for ProductRepositoryImpl {
             product.is_active,
             product.is_sellable,
             product.is_purchaseable,
+            product.overcommit_pct,
             product.created_at,
             product.updated_at
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(Product {
             product_id: row.product_id,
@@ -806,6 +955,7 @@ impl ProductRepository for ProductRepositoryImpl {
             is_active: row.is_active,
             is_sellable: row.is_sellable,
             is_purchaseable: row.is_purchaseable,
+            overcommit_pct: row.overcommit_pct,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -821,6 +971,7 @@ impl ProductRepository for ProductRepositoryImpl {
         let tracking_method_str = product.tracking_method.to_string();
         let barcode_type_str = product.barcode_type.as_ref().map(|bt| bt.to_string());
 
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
         let row = sqlx::query!(
             r#"
             UPDATE products SET
@@ -843,14 +994,15 @@ impl ProductRepository for ProductRepositoryImpl {
                 is_active = $19,
                 is_sellable = $20,
                 is_purchaseable = $21,
-                updated_at = $22
+                overcommit_pct = $22,
+                updated_at = $23
             WHERE tenant_id = $1 AND product_id = $2 AND deleted_at IS NULL
             RETURNING
                 product_id, tenant_id, sku, name, description,
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at, deleted_at
             "#,
             tenant_id,
@@ -874,11 +1026,13 @@ impl ProductRepository for ProductRepositoryImpl {
             product.is_active,
             product.is_sellable,
             product.is_purchaseable,
+            product.overcommit_pct,
             product.updated_at
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?
         .ok_or_else(|| shared_error::AppError::NotFound("Product not found".to_string()))?;
+        tx.commit().await?;
 
         Ok(Product {
             product_id: row.product_id,
@@ -907,6 +1061,7 @@ impl ProductRepository for ProductRepositoryImpl {
             is_active: row.is_active,
             is_sellable: row.is_sellable,
             is_purchaseable: row.is_purchaseable,
+            overcommit_pct: row.overcommit_pct,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -914,6 +1069,7 @@ impl ProductRepository for ProductRepositoryImpl {
     }
 
     async fn delete(&self, tenant_id: Uuid, product_id: Uuid) -> Result<bool> {
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
         let result = sqlx::query!(
             r#"
             UPDATE products
@@ -923,8 +1079,9 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             product_id
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(result.rows_affected() > 0)
     }
@@ -972,6 +1129,7 @@ impl ProductRepository for ProductRepositoryImpl {
             return Ok(0);
         }
 
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
         let result = sqlx::query!(
             r#"
             UPDATE products
@@ -981,8 +1139,9 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             &product_ids
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(result.rows_affected() as i64)
     }
@@ -992,6 +1151,7 @@ impl ProductRepository for ProductRepositoryImpl {
             return Ok(0);
         }
 
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
         let result = sqlx::query!(
             r#"
             UPDATE products
@@ -1001,30 +1161,67 @@ impl ProductRepository for ProductRepositoryImpl {
             tenant_id,
             &product_ids
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(result.rows_affected() as i64)
     }
 
-    async fn bulk_delete(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64> {
+    async fn bulk_soft_delete(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64> {
         if product_ids.is_empty() {
             return Ok(0);
         }
 
-        let result = sqlx::query!(
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+
+        let rows = sqlx::query_scalar!(
             r#"
             UPDATE products
             SET deleted_at = NOW(), updated_at = NOW()
             WHERE tenant_id = $1 AND product_id = ANY($2) AND deleted_at IS NULL
+            RETURNING category_id
             "#,
             tenant_id,
             &product_ids
         )
-        .execute(&self.pool)
+        .fetch_all(&mut *tx)
         .await?;
 
-        Ok(result.rows_affected() as i64)
+        let category_ids: Vec<Uuid> = rows.iter().flatten().copied().collect();
+        Self::recount_categories(&mut tx, tenant_id, &category_ids).await?;
+
+        tx.commit().await?;
+
+        Ok(rows.len() as i64)
+    }
+
+    async fn bulk_restore(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64> {
+        if product_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+
+        let rows = sqlx::query_scalar!(
+            r#"
+            UPDATE products
+            SET deleted_at = NULL, updated_at = NOW()
+            WHERE tenant_id = $1 AND product_id = ANY($2) AND deleted_at IS NOT NULL
+            RETURNING category_id
+            "#,
+            tenant_id,
+            &product_ids
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let category_ids: Vec<Uuid> = rows.iter().flatten().copied().collect();
+        Self::recount_categories(&mut tx, tenant_id, &category_ids).await?;
+
+        tx.commit().await?;
+
+        Ok(rows.len() as i64)
     }
 
     // ========================================================================
@@ -1035,6 +1232,7 @@ impl ProductRepository for ProductRepositoryImpl {
         let tracking_method_str = product.tracking_method.to_string();
         let barcode_type_str = product.barcode_type.as_ref().map(|bt| bt.to_string());
 
+        let mut tx = begin_tenant_tx(&self.pool, product.tenant_id).await?;
         sqlx::query!(
             r#"
             INSERT INTO products (
@@ -1042,15 +1240,15 @@ impl ProductRepository for ProductRepositoryImpl {
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at
             ) VALUES (
                 $1, $2, $3, $4, $5,
                 $6, $7, $8, $9, $10, $11, $12,
                 $13, $14, $15, $16,
                 $17, $18, $19,
-                $20, $21, $22,
-                $23, $24
+                $20, $21, $22, $23,
+                $24, $25
             )
             ON CONFLICT (tenant_id, sku) DO UPDATE SET
                 name = EXCLUDED.name,
@@ -1072,6 +1270,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 is_active = EXCLUDED.is_active,
                 is_sellable = EXCLUDED.is_sellable,
                 is_purchaseable = EXCLUDED.is_purchaseable,
+                overcommit_pct = EXCLUDED.overcommit_pct,
                 updated_at = EXCLUDED.updated_at
             "#,
             product.product_id,
@@ -1096,11 +1295,13 @@ impl ProductRepository for ProductRepositoryImpl {
             product.is_active,
             product.is_sellable,
             product.is_purchaseable,
+            product.overcommit_pct,
             product.created_at,
             product.updated_at
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -1120,14 +1321,14 @@ impl ProductRepository for ProductRepositoryImpl {
                 product_type, barcode, barcode_type, category_id, item_group_id, track_inventory, tracking_method,
                 default_uom_id, sale_price, cost_price, currency_code,
                 weight_grams, dimensions, attributes,
-                is_active, is_sellable, is_purchaseable,
+                is_active, is_sellable, is_purchaseable, overcommit_pct,
                 created_at, updated_at, deleted_at
             FROM products
             WHERE tenant_id =
             "#,
         );
         query_builder.push_bind(tenant_id);
-        query_builder.push(" AND deleted_at IS NULL");
+        push_not_deleted(&mut query_builder, "products");
 
         if let Some(cat_id) = category_id {
             query_builder.push(" AND category_id = ");
@@ -1155,7 +1356,13 @@ impl ProductRepository for ProductRepositoryImpl {
 
         query_builder.push(" ORDER BY sku ASC");
 
-        let rows = query_builder.build().fetch_all(&self.pool).await?;
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+        let rows = query_builder
+            .assert_tenant_filtered()
+            .build()
+            .fetch_all(&mut *tx)
+            .await?;
+        tx.commit().await?;
 
         let products = rows
             .into_iter()
@@ -1190,6 +1397,7 @@ impl ProductRepository for ProductRepositoryImpl {
                 is_active: row.get("is_active"),
                 is_sellable: row.get("is_sellable"),
                 is_purchaseable: row.get("is_purchaseable"),
+                overcommit_pct: row.get("overcommit_pct"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
                 deleted_at: row.get("deleted_at"),
@@ -1198,4 +1406,63 @@ impl ProductRepository for ProductRepositoryImpl {
 
         Ok(products)
     }
+
+    async fn get_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<Option<ProductAttributes>> {
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+        let row = sqlx::query(
+            r#"
+            SELECT attributes
+            FROM products
+            WHERE tenant_id = $1 AND product_id = $2 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(product_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(row.map(|row| {
+            // Legacy rows may hold non-string values; only string pairs are exposed
+            let value: Option<serde_json::Value> = row.get("attributes");
+            value
+                .as_ref()
+                .and_then(|v| v.as_object())
+                .map(|object| {
+                    object
+                        .iter()
+                        .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }))
+    }
+
+    async fn set_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        attributes: &ProductAttributes,
+    ) -> Result<bool> {
+        let mut tx = begin_tenant_tx(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            UPDATE products
+            SET attributes = $3, updated_at = NOW()
+            WHERE tenant_id = $1 AND product_id = $2 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(product_id)
+        .bind(sqlx::types::Json(attributes))
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
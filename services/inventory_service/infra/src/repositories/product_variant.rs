@@ -13,6 +13,7 @@ use inventory_service_core::dto::product_variant::{
 };
 use inventory_service_core::repositories::product_variant::ProductVariantRepository;
 use inventory_service_core::Result;
+use shared_db::AssertTenantFiltered;
 use shared_error::AppError;
 
 /// PostgreSQL implementation of ProductVariantRepository
@@ -235,7 +236,11 @@ impl ProductVariantRepository for ProductVariantRepositoryImpl {
         query_builder.push_bind(offset);
 
         // Execute main query
-        let rows = query_builder.build().fetch_all(&self.pool).await?;
+        let rows = query_builder
+            .assert_tenant_filtered()
+            .build()
+            .fetch_all(&self.pool)
+            .await?;
         let variants: Vec<VariantResponse> = rows.iter().map(Self::map_row_to_response).collect();
 
         // Build count query
@@ -266,6 +271,7 @@ impl ProductVariantRepository for ProductVariantRepositoryImpl {
         }
 
         let total_count: i64 = count_builder
+            .assert_tenant_filtered()
             .build_query_scalar()
             .fetch_one(&self.pool)
             .await?;
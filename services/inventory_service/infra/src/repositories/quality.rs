@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use inventory_service_core::domains::quality::{
-    CreateQualityControlPoint, QcPointType, QualityControlPoint, UpdateQualityControlPoint,
+    CreateQualityControlPoint, QcPointType, QcStatus, QualityCheck, QualityControlPoint,
+    UpdateQualityControlPoint,
 };
 use inventory_service_core::repositories::quality::QualityControlPointRepository;
 use inventory_service_core::AppError;
@@ -239,4 +240,150 @@ impl QualityControlPointRepository for PgQualityControlPointRepository {
 
         Ok(())
     }
+
+    async fn find_checks_by_reference(
+        &self,
+        tenant_id: Uuid,
+        reference_type: &str,
+        reference_id: Uuid,
+    ) -> Result<Vec<QualityCheck>, AppError> {
+        let checks = sqlx::query_as!(
+            QualityCheck,
+            r#"
+            SELECT
+                qc_id, tenant_id, qc_point_id, reference_type, reference_id,
+                product_id, warehouse_id, quantity,
+                status as "status: QcStatus",
+                inspector_id, notes, created_at, updated_at
+            FROM quality_checks
+            WHERE tenant_id = $1 AND reference_type = $2 AND reference_id = $3
+            ORDER BY created_at
+            "#,
+            tenant_id,
+            reference_type,
+            reference_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(checks)
+    }
+
+    async fn record_check_result(
+        &self,
+        tenant_id: Uuid,
+        qc_id: Uuid,
+        inspector_id: Uuid,
+        passed: bool,
+        notes: Option<String>,
+    ) -> Result<QualityCheck, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let check = sqlx::query_as!(
+            QualityCheck,
+            r#"
+            SELECT
+                qc_id, tenant_id, qc_point_id, reference_type, reference_id,
+                product_id, warehouse_id, quantity,
+                status as "status: QcStatus",
+                inspector_id, notes, created_at, updated_at
+            FROM quality_checks
+            WHERE tenant_id = $1 AND qc_id = $2
+            FOR UPDATE
+            "#,
+            tenant_id,
+            qc_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?
+        .ok_or_else(|| AppError::NotFound(format!("Quality check {} not found", qc_id)))?;
+
+        if check.status != QcStatus::Pending {
+            return Err(AppError::ValidationError(format!(
+                "Quality check {} has already been recorded",
+                qc_id
+            )));
+        }
+
+        // Move the held quantity out of quality hold: pass -> available, fail -> quarantine
+        if let Some(warehouse_id) = check.warehouse_id {
+            if check.quantity > 0 {
+                let (released, quarantined) = if passed {
+                    (check.quantity, 0)
+                } else {
+                    (0, check.quantity)
+                };
+
+                let result = sqlx::query!(
+                    r#"
+                    UPDATE inventory_levels
+                    SET quality_hold_quantity = quality_hold_quantity - $4,
+                        available_quantity = available_quantity + $5,
+                        quarantined_quantity = quarantined_quantity + $6,
+                        updated_at = NOW()
+                    WHERE tenant_id = $1 AND warehouse_id = $2 AND product_id = $3
+                      AND location_id IS NULL
+                      AND quality_hold_quantity >= $4
+                      AND deleted_at IS NULL
+                    "#,
+                    tenant_id,
+                    warehouse_id,
+                    check.product_id,
+                    check.quantity,
+                    released,
+                    quarantined
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+                if result.rows_affected() == 0 {
+                    return Err(AppError::ValidationError(format!(
+                        "Insufficient quality hold stock for product {}",
+                        check.product_id
+                    )));
+                }
+            }
+        }
+
+        let new_status = if passed {
+            QcStatus::Passed
+        } else {
+            QcStatus::Failed
+        };
+
+        let updated = sqlx::query_as!(
+            QualityCheck,
+            r#"
+            UPDATE quality_checks
+            SET status = $3, inspector_id = $4, notes = COALESCE($5, notes), updated_at = NOW()
+            WHERE tenant_id = $1 AND qc_id = $2
+            RETURNING
+                qc_id, tenant_id, qc_point_id, reference_type, reference_id,
+                product_id, warehouse_id, quantity,
+                status as "status: QcStatus",
+                inspector_id, notes, created_at, updated_at
+            "#,
+            tenant_id,
+            qc_id,
+            new_status as QcStatus,
+            inspector_id,
+            notes
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(updated)
+    }
 }
@@ -7,16 +7,25 @@
 use async_trait::async_trait;
 use serde_json;
 use sqlx::PgPool;
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
+use inventory_service_core::dto::common::list_fetch_limit;
 use inventory_service_core::dto::receipt::{
     ReceiptCreateRequest, ReceiptItemResponse, ReceiptListQuery, ReceiptListResponse,
     ReceiptResponse, ReceiptSummaryResponse,
 };
 use inventory_service_core::events::{event_types, GoodsReceiptValidatedEvent, ReceiptItemEvent};
 
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
 use inventory_service_core::repositories::receipt::ReceiptRepository;
 use shared_error::AppError;
+use shared_events::current_correlation_id;
+
+use crate::repositories::stock::PgStockMoveRepository;
+
+/// Unique index enforcing one receipt per (tenant, external reference)
+const EXTERNAL_REFERENCE_CONSTRAINT: &str = "idx_goods_receipts_tenant_external_reference";
 
 /// PostgreSQL implementation of ReceiptRepository
 ///
@@ -80,10 +89,10 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
             INSERT INTO goods_receipts (
                 receipt_id, tenant_id, receipt_number, reference_number,
                 warehouse_id, supplier_id, status, expected_delivery_date, notes,
-                created_by, currency_code
+                created_by, currency_code, external_reference
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            RETURNING receipt_id, receipt_number, reference_number,
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING receipt_id, receipt_number, reference_number, external_reference,
                       warehouse_id, supplier_id, status, receipt_date,
                       expected_delivery_date, actual_delivery_date, notes,
                       created_by, total_quantity, total_value, currency_code,
@@ -99,10 +108,21 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
             request.expected_delivery_date,
             request.notes,
             user_id,
-            request.currency_code.clone()
+            request.currency_code.clone(),
+            request.external_reference
         )
         .fetch_one(&mut *tx)
-        .await?;
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err)
+                if db_err.constraint() == Some(EXTERNAL_REFERENCE_CONSTRAINT) =>
+            {
+                AppError::Conflict(
+                    "Receipt with this external reference already exists".to_string(),
+                )
+            },
+            _ => AppError::from(e),
+        })?;
 
         // Create receipt items
         let mut items = Vec::new();
@@ -177,27 +197,17 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
 
         // Create stock moves within the same transaction
         for (index, item_request) in request.items.iter().enumerate() {
-            let move_id = Uuid::now_v7();
-            let item_idempotency_key = format!("{}-{}", idempotency_key, index);
-            sqlx::query!(
-                r#"
-                INSERT INTO stock_moves (
-                    move_id, tenant_id, product_id, move_type, quantity,
-                    unit_cost, reference_type, reference_id, lot_serial_id, idempotency_key,
-                    move_date, move_reason
-                )
-                VALUES ($1, $2, $3, 'receipt', $4, $5, 'grn', $6, NULL, $7, NOW(), 'Goods receipt')
-                "#,
-                move_id,
-                tenant_id,
-                item_request.product_id,
-                item_request.received_quantity as i32,
-                item_request.unit_cost,
+            let stock_move = MoveIntent::new(
+                MoveSourceType::Receipt,
                 receipt_id,
-                item_idempotency_key
+                "goods_receipt",
+                item_request.product_id,
+                item_request.received_quantity,
+                format!("{}-{}", idempotency_key, index),
             )
-            .execute(&mut *tx)
-            .await?;
+            .with_unit_cost(item_request.unit_cost)
+            .with_note("Goods receipt");
+            PgStockMoveRepository::insert_move(&mut tx, &stock_move, tenant_id).await?;
         }
 
         // TODO: Publish receipt created event to outbox (when outbox table is implemented)
@@ -211,6 +221,7 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
             warehouse_id: receipt.warehouse_id,
             supplier_id: receipt.supplier_id,
             reference_number: receipt.reference_number,
+            external_reference: receipt.external_reference,
             status: receipt.status,
             receipt_date: receipt.receipt_date,
             expected_delivery_date: receipt.expected_delivery_date,
@@ -235,7 +246,7 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
         // Get receipt
         let receipt = sqlx::query!(
             r#"
-            SELECT receipt_id, receipt_number, reference_number,
+            SELECT receipt_id, receipt_number, reference_number, external_reference,
                    warehouse_id, supplier_id, status, receipt_date,
                    expected_delivery_date, actual_delivery_date, notes,
                    created_by, total_quantity, total_value, currency_code,
@@ -297,6 +308,7 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
             warehouse_id: receipt.warehouse_id,
             supplier_id: receipt.supplier_id,
             reference_number: receipt.reference_number,
+            external_reference: receipt.external_reference,
             status: receipt.status,
             receipt_date: receipt.receipt_date,
             expected_delivery_date: receipt.expected_delivery_date,
@@ -312,42 +324,68 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
         })
     }
 
-    /// List receipts with pagination and filtering
-    #[allow(clippy::manual_div_ceil)]
-    async fn list_receipts(
+    /// Find the receipt created with an external reference, if any
+    async fn find_id_by_external_reference(
         &self,
         tenant_id: Uuid,
-        query: ReceiptListQuery,
-    ) -> Result<ReceiptListResponse, AppError> {
-        let offset = (query.page - 1) * query.page_size;
-
-        // Count query
-        let count: i64 = sqlx::query_scalar!(
+        external_reference: &str,
+    ) -> Result<Option<Uuid>, AppError> {
+        let receipt_id = sqlx::query_scalar!(
             r#"
-            SELECT COUNT(*)::BIGINT as "count!"
+            SELECT receipt_id
             FROM goods_receipts
-            WHERE tenant_id = $1
-              AND deleted_at IS NULL
-              AND ($2::UUID IS NULL OR warehouse_id = $2)
-              AND ($3::UUID IS NULL OR supplier_id = $3)
-              AND ($4::TEXT IS NULL OR status = $4)
-              AND ($5::TEXT IS NULL OR receipt_number ILIKE '%' || $5 || '%' OR reference_number ILIKE '%' || $5 || '%')
-              AND ($6::TIMESTAMPTZ IS NULL OR created_at >= $6)
-              AND ($7::TIMESTAMPTZ IS NULL OR created_at <= $7)
+            WHERE tenant_id = $1 AND external_reference = $2 AND deleted_at IS NULL
             "#,
             tenant_id,
-            query.warehouse_id,
-            query.supplier_id,
-            query.status,
-            query.search,
-            query.created_after,
-            query.created_before
+            external_reference
         )
-        .fetch_one(&self.pool)
+        .fetch_optional(&self.pool)
         .await?;
 
+        Ok(receipt_id)
+    }
+
+    /// List receipts with pagination and filtering
+    #[allow(clippy::manual_div_ceil)]
+    async fn list_receipts(
+        &self,
+        tenant_id: Uuid,
+        query: ReceiptListQuery,
+    ) -> Result<ReceiptListResponse, AppError> {
+        let offset = (query.page - 1) * query.page_size();
+
+        // Count query (skipped when include_total is false)
+        let count = if query.include_total {
+            let count: i64 = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*)::BIGINT as "count!"
+                FROM goods_receipts
+                WHERE tenant_id = $1
+                  AND deleted_at IS NULL
+                  AND ($2::UUID IS NULL OR warehouse_id = $2)
+                  AND ($3::UUID IS NULL OR supplier_id = $3)
+                  AND ($4::TEXT IS NULL OR status = $4)
+                  AND ($5::TEXT IS NULL OR receipt_number ILIKE '%' || $5 || '%' OR reference_number ILIKE '%' || $5 || '%')
+                  AND ($6::TIMESTAMPTZ IS NULL OR created_at >= $6)
+                  AND ($7::TIMESTAMPTZ IS NULL OR created_at <= $7)
+                "#,
+                tenant_id,
+                query.warehouse_id,
+                query.supplier_id,
+                query.status,
+                query.search,
+                query.created_after,
+                query.created_before
+            )
+            .fetch_one(&self.pool)
+            .await?;
+            Some(count as u64)
+        } else {
+            None
+        };
+
         // Data query
-        let receipts = sqlx::query!(
+        let mut receipts: Vec<ReceiptSummaryResponse> = sqlx::query!(
             r#"
             SELECT receipt_id, receipt_number, reference_number,
                    warehouse_id, supplier_id, status, receipt_date,
@@ -377,7 +415,7 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
             query.search,
             query.created_after,
             query.created_before,
-            query.page_size as i64,
+            list_fetch_limit(query.page_size(), query.include_total),
             offset as i64
         )
         .fetch_all(&self.pool)
@@ -399,21 +437,16 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
         })
         .collect();
 
-        let count_u64 = count as u64;
-        let page_size_u64 = query.page_size as u64;
-        let total_pages_u64 = count_u64.div_ceil(page_size_u64);
-        let total_pages = total_pages_u64.max(1) as u32;
+        let pagination = inventory_service_core::dto::common::PaginationInfo::from_optional_total(
+            query.page,
+            query.page_size(),
+            count,
+            &mut receipts,
+        );
 
         Ok(ReceiptListResponse {
             receipts,
-            pagination: inventory_service_core::dto::common::PaginationInfo {
-                page: query.page,
-                page_size: query.page_size,
-                total_items: count as u64,
-                total_pages,
-                has_next: query.page < total_pages,
-                has_prev: query.page > 1,
-            },
+            pagination,
         })
     }
 
@@ -506,6 +539,79 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
         .fetch_all(&mut *tx)
         .await?;
 
+        // Put received stock on hand. Products covered by an active incoming QC point
+        // land in quality hold (not sellable) with a pending quality check; everything
+        // else becomes available immediately.
+        for item in &items {
+            if item.received_quantity <= 0 {
+                continue;
+            }
+
+            let qc_point_id: Option<Uuid> = sqlx::query_scalar!(
+                r#"
+                SELECT qc_point_id
+                FROM quality_control_points
+                WHERE tenant_id = $1 AND active = true AND type = 'incoming'
+                  AND (product_id = $2 OR product_id IS NULL)
+                  AND (warehouse_id = $3 OR warehouse_id IS NULL)
+                ORDER BY product_id NULLS LAST, warehouse_id NULLS LAST
+                LIMIT 1
+                "#,
+                tenant_id,
+                item.product_id,
+                receipt.warehouse_id
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            let (available_delta, hold_delta) = match qc_point_id {
+                Some(_) => (0, item.received_quantity),
+                None => (item.received_quantity, 0),
+            };
+
+            sqlx::query!(
+                r#"
+                INSERT INTO inventory_levels (
+                    tenant_id, warehouse_id, location_id, product_id,
+                    available_quantity, reserved_quantity, quality_hold_quantity
+                )
+                VALUES ($1, $2, NULL, $3, $4, 0, $5)
+                ON CONFLICT (tenant_id, warehouse_id, location_id, product_id) WHERE deleted_at IS NULL
+                DO UPDATE SET
+                    available_quantity = inventory_levels.available_quantity + $4,
+                    quality_hold_quantity = inventory_levels.quality_hold_quantity + $5,
+                    updated_at = NOW()
+                "#,
+                tenant_id,
+                receipt.warehouse_id,
+                item.product_id,
+                available_delta,
+                hold_delta
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            if let Some(qc_point_id) = qc_point_id {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO quality_checks (
+                        tenant_id, qc_point_id, reference_type, reference_id,
+                        product_id, warehouse_id, quantity, status
+                    )
+                    VALUES ($1, $2, 'receipt', $3, $4, $5, $6, 'pending')
+                    "#,
+                    tenant_id,
+                    qc_point_id,
+                    receipt_id,
+                    item.product_id,
+                    receipt.warehouse_id,
+                    item.received_quantity
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
         // Update inventory valuation layers for each item
         for item in &items {
             if let Some(unit_cost) = item.unit_cost {
@@ -513,15 +619,16 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
                 sqlx::query!(
                     r#"
                     INSERT INTO inventory_valuation_layers (
-                        tenant_id, product_id, quantity, unit_cost, total_value
+                        tenant_id, product_id, quantity, unit_cost, total_value, receipt_id
                     )
-                    VALUES ($1, $2, $3, $4, $5)
+                    VALUES ($1, $2, $3, $4, $5, $6)
                     "#,
                     tenant_id,
                     item.product_id,
                     item.received_quantity,
                     unit_cost,
-                    item.received_quantity * unit_cost
+                    item.received_quantity * unit_cost,
+                    receipt_id
                 )
                 .execute(&mut *tx)
                 .await?;
@@ -578,14 +685,16 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
         sqlx::query!(
             r#"
             INSERT INTO event_outbox (
-                id, tenant_id, event_type, event_data, status, created_at, updated_at
+                id, tenant_id, event_type, event_data, correlation_id, status,
+                created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, 'pending', NOW(), NOW())
+            VALUES ($1, $2, $3, $4, $5, 'pending', NOW(), NOW())
             "#,
             event_id,
             tenant_id,
             event_types::GOODS_RECEIPT_VALIDATED,
-            event_data
+            event_data,
+            current_correlation_id()
         )
         .execute(&mut *tx)
         .await?;
@@ -595,4 +704,250 @@ impl ReceiptRepository for ReceiptRepositoryImpl {
         // Return updated receipt
         self.get_receipt(tenant_id, receipt_id).await
     }
+
+    /// Reverse a received goods receipt entered in error
+    async fn reverse_receipt(
+        &self,
+        tenant_id: Uuid,
+        receipt_id: Uuid,
+        reason: &str,
+        user_id: Uuid,
+    ) -> Result<ReceiptResponse, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let receipt = sqlx::query!(
+            r#"
+            SELECT status, warehouse_id
+            FROM goods_receipts
+            WHERE tenant_id = $1 AND receipt_id = $2 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+            tenant_id,
+            receipt_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Receipt not found".to_string()))?;
+
+        // Only received stock is on hand; earlier receipts are cancelled instead
+        if receipt.status != "received" {
+            return Err(AppError::ValidationError(format!(
+                "Cannot reverse receipt with status '{}'. Must be 'received'",
+                receipt.status
+            )));
+        }
+
+        let items = sqlx::query!(
+            r#"
+            SELECT product_id, received_quantity, unit_cost
+            FROM goods_receipt_items
+            WHERE tenant_id = $1 AND receipt_id = $2 AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            receipt_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        // Received and costed quantity per product, in a stable order for row locking
+        let mut received: BTreeMap<Uuid, (i64, i64)> = BTreeMap::new();
+        for item in items.iter().filter(|item| item.received_quantity > 0) {
+            let entry = received.entry(item.product_id).or_default();
+            entry.0 += item.received_quantity;
+            if item.unit_cost.is_some() {
+                entry.1 += item.received_quantity;
+            }
+        }
+
+        // Where the received stock landed: pending checks hold it in quality hold,
+        // failed checks moved it to quarantine, the rest is available
+        let checks = sqlx::query!(
+            r#"
+            SELECT product_id, status::TEXT AS "status!", SUM(quantity)::BIGINT AS "quantity!"
+            FROM quality_checks
+            WHERE tenant_id = $1 AND reference_type = 'receipt' AND reference_id = $2
+            GROUP BY product_id, status
+            "#,
+            tenant_id,
+            receipt_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for (&product_id, &(quantity, costed_quantity)) in &received {
+            let checked = |status: &str| -> i64 {
+                checks
+                    .iter()
+                    .filter(|check| check.product_id == product_id && check.status == status)
+                    .map(|check| check.quantity)
+                    .sum()
+            };
+            let held = checked("pending");
+            let quarantined = checked("failed");
+            let available = quantity - held - quarantined;
+
+            let taken_back = sqlx::query!(
+                r#"
+                UPDATE inventory_levels
+                SET available_quantity = available_quantity - $4,
+                    quality_hold_quantity = quality_hold_quantity - $5,
+                    quarantined_quantity = quarantined_quantity - $6,
+                    updated_at = NOW()
+                WHERE tenant_id = $1 AND warehouse_id = $2 AND product_id = $3
+                  AND location_id IS NULL
+                  AND deleted_at IS NULL
+                  AND available_quantity >= $4
+                  AND quality_hold_quantity >= $5
+                  AND quarantined_quantity >= $6
+                "#,
+                tenant_id,
+                receipt.warehouse_id,
+                product_id,
+                available,
+                held,
+                quarantined
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            if taken_back.rows_affected() == 0 {
+                return Err(stock_consumed_error(product_id));
+            }
+
+            if costed_quantity == 0 {
+                continue;
+            }
+
+            // The layers this receipt created must still hold everything it brought in
+            let layers = sqlx::query!(
+                r#"
+                SELECT COALESCE(SUM(quantity), 0)::BIGINT AS "quantity!",
+                       COALESCE(SUM(total_value), 0)::BIGINT AS "value!"
+                FROM (
+                    SELECT quantity, total_value
+                    FROM inventory_valuation_layers
+                    WHERE tenant_id = $1 AND receipt_id = $2 AND product_id = $3
+                    FOR UPDATE
+                ) receipt_layers
+                "#,
+                tenant_id,
+                receipt_id,
+                product_id
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if layers.quantity != costed_quantity {
+                return Err(stock_consumed_error(product_id));
+            }
+
+            sqlx::query!(
+                r#"
+                UPDATE inventory_valuation_layers
+                SET quantity = 0, total_value = 0, updated_at = NOW()
+                WHERE tenant_id = $1 AND receipt_id = $2 AND product_id = $3
+                "#,
+                tenant_id,
+                receipt_id,
+                product_id
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            // Back the receipt out of the running totals. For FIFO that removes exactly
+            // the layers above; for AVCO it unwinds the blend the receipt caused.
+            let unwound = sqlx::query!(
+                r#"
+                UPDATE inventory_valuations
+                SET current_unit_cost = CASE
+                        WHEN total_quantity - $3 = 0 THEN 0
+                        ELSE GREATEST(total_value - $4, 0) / (total_quantity - $3)
+                    END,
+                    total_quantity = total_quantity - $3,
+                    total_value = GREATEST(total_value - $4, 0),
+                    last_updated = NOW(),
+                    updated_by = $5
+                WHERE tenant_id = $1 AND product_id = $2 AND total_quantity >= $3
+                "#,
+                tenant_id,
+                product_id,
+                costed_quantity,
+                layers.value,
+                user_id
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            if unwound.rows_affected() == 0 {
+                return Err(stock_consumed_error(product_id));
+            }
+        }
+
+        // Checks on stock that is no longer there have nothing left to inspect
+        sqlx::query!(
+            r#"
+            DELETE FROM quality_checks
+            WHERE tenant_id = $1 AND reference_type = 'receipt' AND reference_id = $2
+              AND status = 'pending'
+            "#,
+            tenant_id,
+            receipt_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Compensate every move the receipt wrote; the ledger itself is immutable
+        let move_ids = sqlx::query_scalar!(
+            r#"
+            SELECT move_id
+            FROM stock_moves
+            WHERE tenant_id = $1 AND source_type = $2 AND source_id = $3
+              AND reversal_of_move_id IS NULL
+            ORDER BY created_at, move_id
+            "#,
+            tenant_id,
+            MoveSourceType::Receipt.as_str(),
+            receipt_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for move_id in move_ids {
+            PgStockMoveRepository::insert_reversal(&mut tx, tenant_id, move_id).await?;
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE goods_receipts
+            SET status = 'reversed', reversed_at = NOW(), reversed_by = $3, reversal_reason = $4
+            WHERE tenant_id = $1 AND receipt_id = $2
+            "#,
+            tenant_id,
+            receipt_id,
+            user_id,
+            reason
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!(
+            tenant_id = %tenant_id,
+            receipt_id = %receipt_id,
+            reversed_by = %user_id,
+            "Goods receipt reversed"
+        );
+
+        self.get_receipt(tenant_id, receipt_id).await
+    }
+}
+
+/// Refusal for a reversal whose received stock is no longer all on hand
+fn stock_consumed_error(product_id: Uuid) -> AppError {
+    AppError::BusinessError(format!(
+        "Received stock of product {} has already been reserved, moved or consumed; \
+         the receipt can no longer be reversed",
+        product_id
+    ))
 }
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 
 use num_traits::ToPrimitive;
 use sqlx::{PgPool, Postgres, QueryBuilder, Transaction};
+use std::collections::HashMap;
 use std::ops::DerefMut;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -12,10 +13,12 @@ pub type InfraTx<'a> = &'a mut Transaction<'a, sqlx::Postgres>;
 use inventory_service_core::domains::inventory::reconciliation::{
     CycleType, ReconciliationStatus, StockReconciliation, StockReconciliationItem,
 };
+use inventory_service_core::dto::reconciliation::{ReconciliationTrendPeriod, TrendGroupBy};
 use inventory_service_core::repositories::reconciliation::{
     ReconciliationItemCountUpdate, StockReconciliationItemRepository,
     StockReconciliationRepository, VarianceAnalysisResult,
 };
+use shared_db::AssertTenantFiltered;
 use shared_error::AppError;
 
 /// PostgreSQL implementation of StockReconciliationRepository
@@ -72,43 +75,15 @@ impl PgStockReconciliationRepository {
         Ok(cents)
     }
 
-    /// Internal helper: Finalize reconciliation within transaction
-    /// This is used by services for transactional orchestration
-    pub async fn finalize_with_tx<'a>(
-        &self,
-        mut tx: sqlx::Transaction<'a, sqlx::Postgres>,
-        tenant_id: Uuid,
-        reconciliation_id: Uuid,
-        completed_at: chrono::DateTime<chrono::Utc>,
-    ) -> Result<sqlx::Transaction<'a, sqlx::Postgres>, AppError> {
-        sqlx::query!(
-            r#"
-            UPDATE stock_reconciliations
-            SET status = $1, completed_at = $2, updated_at = NOW()
-            WHERE tenant_id = $3 AND reconciliation_id = $4 AND deleted_at IS NULL
-            "#,
-            "completed",
-            completed_at,
-            tenant_id,
-            reconciliation_id
-        )
-        .execute(tx.deref_mut())
-        .await
-        .map_err(|e| {
-            AppError::DatabaseError(format!("Failed to finalize reconciliation: {}", e))
-        })?;
-
-        Ok(tx)
-    }
-}
-
-#[async_trait]
-impl StockReconciliationRepository for PgStockReconciliationRepository {
-    async fn create(
-        &self,
+    /// Insert a reconciliation row using any executor (pool or transaction)
+    async fn insert_reconciliation<'e, E>(
+        executor: E,
         tenant_id: Uuid,
         reconciliation: &StockReconciliation,
-    ) -> Result<StockReconciliation, AppError> {
+    ) -> Result<StockReconciliation, AppError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
         let row = sqlx::query!(
             r#"
             INSERT INTO stock_reconciliations (
@@ -158,7 +133,7 @@ impl StockReconciliationRepository for PgStockReconciliationRepository {
             reconciliation.approved_at,
             reconciliation.notes
         )
-        .fetch_one(&*self.pool)
+        .fetch_one(executor)
         .await
         .map_err(|e| AppError::DatabaseError(format!("Failed to create reconciliation: {}", e)))?;
 
@@ -187,6 +162,219 @@ impl StockReconciliationRepository for PgStockReconciliationRepository {
         })
     }
 
+    /// Check that a warehouse exists for the tenant
+    pub async fn warehouse_exists(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+    ) -> Result<bool, AppError> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM warehouses
+                WHERE tenant_id = $1 AND warehouse_id = $2 AND deleted_at IS NULL
+            )
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(warehouse_id)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to check warehouse: {}", e)))?;
+
+        Ok(exists)
+    }
+
+    /// Map SKUs to product IDs; unknown or deleted SKUs are left out
+    pub async fn resolve_skus(
+        &self,
+        tenant_id: Uuid,
+        skus: &[String],
+    ) -> Result<HashMap<String, Uuid>, AppError> {
+        if skus.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = sqlx::query_as::<_, (String, Uuid)>(
+            r#"
+            SELECT sku, product_id FROM products
+            WHERE tenant_id = $1 AND sku = ANY($2) AND deleted_at IS NULL
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(skus)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to resolve SKUs: {}", e)))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Map location codes within a warehouse to location IDs
+    pub async fn resolve_location_codes(
+        &self,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        codes: &[String],
+    ) -> Result<HashMap<String, Uuid>, AppError> {
+        if codes.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = sqlx::query_as::<_, (String, Uuid)>(
+            r#"
+            SELECT location_code, location_id FROM warehouse_locations
+            WHERE tenant_id = $1 AND warehouse_id = $2 AND location_code = ANY($3)
+              AND deleted_at IS NULL
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(warehouse_id)
+        .bind(codes)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to resolve location codes: {}", e)))?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Create a reconciliation together with its counted items in one transaction
+    ///
+    /// Expected quantities are taken from the product's current available stock in the
+    /// item's warehouse.
+    pub async fn create_with_counts(
+        &self,
+        tenant_id: Uuid,
+        reconciliation: &StockReconciliation,
+        counts: &[ReconciliationItemCountUpdate],
+    ) -> Result<StockReconciliation, AppError> {
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                AppError::DatabaseError(format!("Failed to begin transaction: {}", e))
+            })?;
+
+        let created = Self::insert_reconciliation(&mut *tx, tenant_id, reconciliation).await?;
+
+        if !counts.is_empty() {
+            let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+                r#"
+                INSERT INTO stock_reconciliation_items (
+                    tenant_id, reconciliation_id, product_id, warehouse_id, location_id,
+                    expected_quantity, counted_quantity, unit_cost, counted_by, counted_at, notes
+                )
+                SELECT "#,
+            );
+            query_builder.push_bind(tenant_id);
+            query_builder.push(", ");
+            query_builder.push_bind(created.reconciliation_id);
+            query_builder.push(
+                r#", data.product_id, data.warehouse_id, data.location_id,
+                    COALESCE((
+                        SELECT SUM(il.available_quantity)
+                        FROM inventory_levels il
+                        WHERE il.tenant_id = "#,
+            );
+            query_builder.push_bind(tenant_id);
+            query_builder.push(
+                r#"
+                        AND il.warehouse_id = data.warehouse_id
+                        AND il.product_id = data.product_id
+                        AND il.deleted_at IS NULL
+                    ), 0)::BIGINT,
+                    data.counted_quantity, data.unit_cost, data.counted_by, NOW(), data.notes
+                FROM (VALUES
+                "#,
+            );
+
+            let mut separated = query_builder.separated(", ");
+            for count in counts {
+                let unit_cost_cents = match count.unit_cost {
+                    Some(c) => Self::f64_to_cents(c)?,
+                    None => 0,
+                };
+                separated.push_unseparated("(");
+                separated.push_bind_unseparated(count.product_id);
+                separated.push_unseparated(", ");
+                separated.push_bind_unseparated(count.warehouse_id);
+                separated.push_unseparated(", ");
+                separated.push_bind_unseparated(count.location_id);
+                separated.push_unseparated(", ");
+                separated.push_bind_unseparated(count.counted_quantity);
+                separated.push_unseparated(", ");
+                separated.push_bind_unseparated(unit_cost_cents);
+                separated.push_unseparated(", ");
+                separated.push_bind_unseparated(count.counted_by);
+                separated.push_unseparated(", ");
+                separated.push_bind_unseparated(&count.notes);
+                separated.push_unseparated(")");
+            }
+
+            query_builder.push(
+                r#"
+                ) AS data(product_id, warehouse_id, location_id, counted_quantity, unit_cost, counted_by, notes)
+                "#,
+            );
+
+            query_builder
+                .assert_tenant_filtered()
+                .build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(format!(
+                        "Failed to create imported reconciliation items: {}",
+                        e
+                    ))
+                })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(created)
+    }
+
+    /// Internal helper: Finalize reconciliation within transaction
+    /// This is used by services for transactional orchestration
+    pub async fn finalize_with_tx<'a>(
+        &self,
+        mut tx: sqlx::Transaction<'a, sqlx::Postgres>,
+        tenant_id: Uuid,
+        reconciliation_id: Uuid,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<sqlx::Transaction<'a, sqlx::Postgres>, AppError> {
+        sqlx::query!(
+            r#"
+            UPDATE stock_reconciliations
+            SET status = $1, completed_at = $2, updated_at = NOW()
+            WHERE tenant_id = $3 AND reconciliation_id = $4 AND deleted_at IS NULL
+            "#,
+            "completed",
+            completed_at,
+            tenant_id,
+            reconciliation_id
+        )
+        .execute(tx.deref_mut())
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to finalize reconciliation: {}", e))
+        })?;
+
+        Ok(tx)
+    }
+}
+
+#[async_trait]
+impl StockReconciliationRepository for PgStockReconciliationRepository {
+    async fn create(
+        &self,
+        tenant_id: Uuid,
+        reconciliation: &StockReconciliation,
+    ) -> Result<StockReconciliation, AppError> {
+        Self::insert_reconciliation(&*self.pool, tenant_id, reconciliation).await
+    }
+
     async fn find_by_id(
         &self,
         tenant_id: Uuid,
@@ -479,6 +667,63 @@ impl StockReconciliationRepository for PgStockReconciliationRepository {
 
         Ok(row.count.unwrap_or(0))
     }
+
+    async fn variance_trend(
+        &self,
+        tenant_id: Uuid,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        group_by: TrendGroupBy,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<Vec<ReconciliationTrendPeriod>, AppError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT date_trunc($4, r.completed_at, 'UTC') AS "period_start!",
+                   COUNT(DISTINCT r.reconciliation_id) AS "reconciliation_count!",
+                   COUNT(i.counted_quantity) AS "counted_items!",
+                   COUNT(*) FILTER (
+                       WHERE i.counted_quantity IS NOT NULL AND i.variance <> 0
+                   ) AS "variance_count!",
+                   COALESCE(SUM(ABS(i.variance_value)), 0)::BIGINT AS "variance_value!"
+            FROM stock_reconciliations r
+            LEFT JOIN stock_reconciliation_items i
+                ON i.tenant_id = r.tenant_id
+                AND i.reconciliation_id = r.reconciliation_id
+                AND i.deleted_at IS NULL
+            WHERE r.tenant_id = $1 AND r.deleted_at IS NULL
+            AND r.status = 'completed'
+            AND r.completed_at >= $2 AND r.completed_at < $3
+            AND ($5::uuid IS NULL OR r.warehouse_id = $5)
+            GROUP BY 1
+            ORDER BY 1
+            "#,
+            tenant_id,
+            from,
+            to,
+            group_by.as_str(),
+            warehouse_id
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to get reconciliation variance trend: {}", e))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| ReconciliationTrendPeriod {
+                period_start: r.period_start,
+                reconciliation_count: r.reconciliation_count,
+                counted_items: r.counted_items,
+                variance_count: r.variance_count,
+                variance_value: Self::cents_to_f64(r.variance_value),
+                accuracy_percentage: ReconciliationTrendPeriod::accuracy(
+                    r.counted_items,
+                    r.variance_count,
+                ),
+            })
+            .collect())
+    }
 }
 
 /// PostgreSQL implementation of StockReconciliationItemRepository
@@ -805,7 +1050,7 @@ impl StockReconciliationItemRepository for PgStockReconciliationItemRepository {
             "#,
         );
 
-        let query = query_builder.build();
+        let query = query_builder.assert_tenant_filtered().build();
         query.execute(&*self.pool).await.map_err(|e| {
             AppError::DatabaseError(format!(
                 "Failed to batch update reconciliation item counts: {}",
@@ -14,6 +14,7 @@ use inventory_service_core::dto::removal_strategy::{
 };
 use inventory_service_core::repositories::removal_strategy::RemovalStrategyRepository;
 use inventory_service_core::Result;
+use shared_db::AssertTenantFiltered;
 
 /// PostgreSQL implementation of RemovalStrategyRepository
 ///
@@ -155,7 +156,9 @@ impl RemovalStrategyRepository for RemovalStrategyRepositoryImpl {
             count_builder.push_bind(format!("%{}%", search));
         }
 
-        let count_query = count_builder.build_query_as::<(i64,)>();
+        let count_query = count_builder
+            .assert_tenant_filtered()
+            .build_query_as::<(i64,)>();
         let (count,) = count_query.fetch_one(&self.pool).await?;
 
         // Build data query
@@ -196,7 +199,7 @@ impl RemovalStrategyRepository for RemovalStrategyRepositoryImpl {
         data_builder.push(" OFFSET ");
         data_builder.push_bind(offset);
 
-        let data_query = data_builder.build();
+        let data_query = data_builder.assert_tenant_filtered().build();
         let strategies = data_query
             .map(|row: sqlx::postgres::PgRow| RemovalStrategy {
                 strategy_id: row.get("strategy_id"),
@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use inventory_service_core::domains::replenishment::{
-    CreateReorderRule, ReorderRule, UpdateReorderRule,
+    CreateInternalReplenishmentRule, CreateReorderRule, InternalReplenishmentRule, ReorderRule,
+    UpdateReorderRule,
+};
+use inventory_service_core::repositories::replenishment::{
+    InternalReplenishmentRuleRepository, ReorderRuleRepository,
 };
-use inventory_service_core::repositories::replenishment::ReorderRuleRepository;
 use inventory_service_core::AppError;
 use sqlx::PgPool;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// PostgreSQL implementation of ReorderRuleRepository
@@ -205,4 +209,195 @@ impl ReorderRuleRepository for PgReorderRuleRepository {
 
         Ok(())
     }
+
+    async fn try_mark_triggered(
+        &self,
+        tenant_id: Uuid,
+        rule_id: Uuid,
+        cooldown_seconds: i64,
+    ) -> Result<bool, AppError> {
+        // Single conditional UPDATE so concurrent workers cannot both claim the trigger
+        let result = sqlx::query(
+            r#"
+            UPDATE reorder_rules
+            SET last_triggered_at = NOW()
+            WHERE tenant_id = $1 AND rule_id = $2 AND deleted_at IS NULL
+              AND (
+                  last_triggered_at IS NULL
+                  OR last_triggered_at <= NOW() - make_interval(secs => $3)
+              )
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(rule_id)
+        .bind(cooldown_seconds as f64)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to mark reorder rule triggered: {}", e))
+        })?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn clear_triggered(&self, tenant_id: Uuid, rule_id: Uuid) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE reorder_rules
+            SET last_triggered_at = NULL
+            WHERE tenant_id = $1 AND rule_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(rule_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to clear reorder rule trigger: {}", e))
+        })?;
+
+        Ok(())
+    }
+}
+
+/// PostgreSQL implementation of InternalReplenishmentRuleRepository
+pub struct PgInternalReplenishmentRuleRepository {
+    pool: PgPool,
+}
+
+impl PgInternalReplenishmentRuleRepository {
+    /// Create a new PostgreSQL internal replenishment rule repository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InternalReplenishmentRuleRepository for PgInternalReplenishmentRuleRepository {
+    async fn find_all_active(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<InternalReplenishmentRule>, AppError> {
+        let rules = sqlx::query_as::<_, InternalReplenishmentRule>(
+            r#"
+            SELECT
+                rule_id, tenant_id, product_id, from_warehouse_id, to_warehouse_id,
+                min_quantity, max_quantity, source_safety_stock,
+                created_at, updated_at, deleted_at
+            FROM internal_replenishment_rules
+            WHERE tenant_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to load internal replenishment rules: {}", e))
+        })?;
+
+        Ok(rules)
+    }
+
+    async fn create(
+        &self,
+        tenant_id: Uuid,
+        rule: CreateInternalReplenishmentRule,
+    ) -> Result<InternalReplenishmentRule, AppError> {
+        let new_rule = sqlx::query_as::<_, InternalReplenishmentRule>(
+            r#"
+            INSERT INTO internal_replenishment_rules (
+                tenant_id, product_id, from_warehouse_id, to_warehouse_id,
+                min_quantity, max_quantity, source_safety_stock
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING
+                rule_id, tenant_id, product_id, from_warehouse_id, to_warehouse_id,
+                min_quantity, max_quantity, source_safety_stock,
+                created_at, updated_at, deleted_at
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(rule.product_id)
+        .bind(rule.from_warehouse_id)
+        .bind(rule.to_warehouse_id)
+        .bind(rule.min_quantity)
+        .bind(rule.max_quantity)
+        .bind(rule.source_safety_stock)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict(format!(
+                    "An internal replenishment rule already exists for product {} from {} to {}",
+                    rule.product_id, rule.from_warehouse_id, rule.to_warehouse_id
+                ))
+            },
+            e => AppError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(new_rule)
+    }
+
+    async fn delete(&self, tenant_id: Uuid, rule_id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE internal_replenishment_rules
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE tenant_id = $1 AND rule_id = $2 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(rule_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!(
+                "Internal replenishment rule {} not found",
+                rule_id
+            )));
+        }
+
+        Ok(())
+    }
+    async fn find_open_transfer_quantities(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<HashMap<(Uuid, Uuid), i64>, AppError> {
+        let rows = sqlx::query_as::<_, (Uuid, Uuid, i64)>(
+            r#"
+            SELECT warehouse_id, product_id, SUM(quantity)::BIGINT
+            FROM (
+                SELECT t.destination_warehouse_id AS warehouse_id, i.product_id, i.quantity
+                FROM stock_transfers t
+                JOIN stock_transfer_items i
+                  ON i.tenant_id = t.tenant_id AND i.transfer_id = t.transfer_id
+                WHERE t.tenant_id = $1 AND t.deleted_at IS NULL AND i.deleted_at IS NULL
+                  AND t.status IN ('draft', 'confirmed', 'partially_picked', 'picked',
+                                   'partially_shipped', 'shipped')
+                UNION ALL
+                SELECT t.source_warehouse_id, i.product_id, -i.quantity
+                FROM stock_transfers t
+                JOIN stock_transfer_items i
+                  ON i.tenant_id = t.tenant_id AND i.transfer_id = t.transfer_id
+                WHERE t.tenant_id = $1 AND t.deleted_at IS NULL AND i.deleted_at IS NULL
+                  AND t.status IN ('draft', 'confirmed', 'partially_picked', 'picked')
+            ) pending
+            GROUP BY warehouse_id, product_id
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to load open transfer quantities: {}", e))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(warehouse_id, product_id, quantity)| ((warehouse_id, product_id), quantity))
+            .collect())
+    }
 }
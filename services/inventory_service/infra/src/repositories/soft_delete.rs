@@ -0,0 +1,47 @@
+//! Soft-delete filters
+//!
+//! Soft-deleted rows keep their data with `deleted_at` set and must never be
+//! returned by a read. Queries checked by `sqlx::query!` spell the filter out
+//! in their literal; queries assembled at runtime take it from here, so the
+//! clause is written once and only the table alias varies.
+
+use shared_db::AssertTenantFiltered;
+use sqlx::{Postgres, QueryBuilder};
+
+/// `<alias>.deleted_at IS NULL`
+pub fn not_deleted(alias: &str) -> String {
+    format!("{}.deleted_at IS NULL", alias)
+}
+
+/// Append ` AND <alias>.deleted_at IS NULL` to a query whose WHERE clause is open
+///
+/// The tenant filter must already be in place, so it is checked here too.
+#[track_caller]
+pub fn push_not_deleted(builder: &mut QueryBuilder<'_, Postgres>, alias: &str) {
+    builder
+        .assert_tenant_filtered()
+        .push(" AND ")
+        .push(not_deleted(alias));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_deleted_qualifies_the_column() {
+        assert_eq!(not_deleted("pc"), "pc.deleted_at IS NULL");
+    }
+
+    #[test]
+    fn test_push_not_deleted_extends_where_clause() {
+        let mut builder =
+            QueryBuilder::<Postgres>::new("SELECT 1 FROM products p WHERE p.tenant_id = ");
+        builder.push_bind(uuid::Uuid::nil());
+        push_not_deleted(&mut builder, "p");
+        assert_eq!(
+            builder.sql(),
+            "SELECT 1 FROM products p WHERE p.tenant_id = $1 AND p.deleted_at IS NULL"
+        );
+    }
+}
@@ -3,12 +3,16 @@
 //! This module contains PostgreSQL implementations of StockMoveRepository and InventoryLevelRepository.
 
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
 use std::ops::DerefMut;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use inventory_service_core::models::{CreateStockMoveRequest, InventoryLevel, StockMove};
+use inventory_service_core::models::{
+    location_deltas, InventoryLevel, InventoryLevelHistoryEntry, MoveIntent, StockMove,
+    REVERSAL_MOVE_TYPE, REVERSAL_REASON_CODE,
+};
 use inventory_service_core::repositories::{InventoryLevelRepository, StockMoveRepository};
 use shared_error::AppError;
 
@@ -26,53 +30,223 @@ impl PgStockMoveRepository {
         Self { pool }
     }
 
-    /// Internal helper: Create a stock move within a transaction
-    /// This is used by services for transactional orchestration
-    /// Returns the created move_id and the transaction
-    pub async fn create_with_tx<'a>(
-        &self,
-        mut tx: sqlx::Transaction<'a, sqlx::Postgres>,
-        stock_move: CreateStockMoveRequest,
+    /// Insert a stock move on an existing connection or transaction.
+    /// Shared by every move-producing service so all moves carry reason and source linkage.
+    pub async fn insert_move(
+        conn: &mut PgConnection,
+        intent: &MoveIntent,
         tenant_id: Uuid,
-    ) -> Result<(Uuid, sqlx::Transaction<'a, sqlx::Postgres>), AppError> {
+    ) -> Result<Uuid, AppError> {
         let move_id = sqlx::query!(
             r#"
             INSERT INTO stock_moves (
                 tenant_id, product_id, source_location_id, destination_location_id,
                 move_type, quantity, unit_cost, reference_type, reference_id,
-                lot_serial_id, idempotency_key, move_reason, batch_info, metadata
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                lot_serial_id, idempotency_key, move_reason, batch_info, metadata,
+                reason_code, source_type, source_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
             RETURNING move_id
             "#,
             tenant_id,
-            stock_move.product_id,
-            stock_move.source_location_id,
-            stock_move.destination_location_id,
-            stock_move.move_type,
-            stock_move.quantity,
-            stock_move.unit_cost,
-            stock_move.reference_type,
-            stock_move.reference_id,
-            stock_move.lot_serial_id,
-            stock_move.idempotency_key,
-            stock_move.move_reason,
-            stock_move.batch_info,
-            stock_move.metadata,
+            intent.product_id,
+            intent.source_location_id,
+            intent.destination_location_id,
+            intent.move_type(),
+            intent.quantity,
+            intent.unit_cost,
+            intent.reference_type(),
+            intent.source_id,
+            intent.lot_serial_id,
+            intent.idempotency_key,
+            intent.move_reason,
+            intent.batch_info,
+            intent.metadata,
+            intent.reason_code,
+            intent.source_type.as_str(),
+            intent.source_id,
         )
-        .fetch_one(tx.deref_mut())
+        .fetch_one(&mut *conn)
         .await
         .map_err(|e| AppError::DatabaseError(e.to_string()))?
         .move_id;
 
+        Self::insert_level_history(conn, tenant_id, move_id, intent).await?;
+
+        Ok(move_id)
+    }
+
+    /// Append the per-location quantity changes of a move to `inventory_level_history`.
+    /// Insert-only so every move pays a single extra statement; balances are derived on read.
+    async fn insert_level_history(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        move_id: Uuid,
+        intent: &MoveIntent,
+    ) -> Result<(), AppError> {
+        Self::insert_level_history_rows(
+            conn,
+            tenant_id,
+            move_id,
+            intent.product_id,
+            intent.move_type(),
+            location_deltas(
+                intent.source_location_id,
+                intent.destination_location_id,
+                intent.quantity,
+            ),
+        )
+        .await
+    }
+
+    async fn insert_level_history_rows(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        move_id: Uuid,
+        product_id: Uuid,
+        move_type: &str,
+        deltas: Vec<(Uuid, i64)>,
+    ) -> Result<(), AppError> {
+        if deltas.is_empty() {
+            return Ok(());
+        }
+        let (location_ids, quantity_changes): (Vec<Uuid>, Vec<i64>) = deltas.into_iter().unzip();
+
+        sqlx::query(
+            r#"
+            INSERT INTO inventory_level_history (
+                tenant_id, location_id, product_id, move_id, move_type, quantity_change
+            )
+            SELECT $1, change.location_id, $2, $3, $4, change.quantity_change
+            FROM UNNEST($5::uuid[], $6::bigint[]) AS change(location_id, quantity_change)
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(product_id)
+        .bind(move_id)
+        .bind(move_type)
+        .bind(&location_ids)
+        .bind(&quantity_changes)
+        .execute(conn)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to record inventory level history: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    /// Insert the compensating move for `move_id` on an existing connection or transaction.
+    /// Shared by `reverse_move` and document reversals that undo several moves at once.
+    pub async fn insert_reversal(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        move_id: Uuid,
+    ) -> Result<StockMove, AppError> {
+        let original = sqlx::query_as!(
+            StockMove,
+            r#"
+            SELECT
+                move_id, tenant_id, product_id, source_location_id, destination_location_id,
+                move_type, quantity, unit_cost, total_cost, reference_type, reference_id,
+                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at,
+                reason_code, source_type, source_id, reversal_of_move_id
+            FROM stock_moves
+            WHERE tenant_id = $1 AND move_id = $2
+            FOR UPDATE
+            "#,
+            tenant_id,
+            move_id,
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?
+        .ok_or_else(|| AppError::NotFound(format!("Stock move {} not found", move_id)))?;
+
+        if original.reversal_of_move_id.is_some() {
+            return Err(AppError::BusinessError(format!(
+                "Stock move {} is a reversal and cannot be reversed",
+                move_id
+            )));
+        }
+
+        let reversal = sqlx::query_as!(
+            StockMove,
+            r#"
+            INSERT INTO stock_moves (
+                tenant_id, product_id, source_location_id, destination_location_id,
+                move_type, quantity, unit_cost, reference_type, reference_id,
+                lot_serial_id, idempotency_key, move_reason, batch_info, metadata,
+                reason_code, source_type, source_id, reversal_of_move_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+            ON CONFLICT (tenant_id, idempotency_key) DO NOTHING
+            RETURNING
+                move_id, tenant_id, product_id, source_location_id, destination_location_id,
+                move_type, quantity, unit_cost, total_cost, reference_type, reference_id,
+                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at,
+                reason_code, source_type, source_id, reversal_of_move_id
+            "#,
+            tenant_id,
+            original.product_id,
+            original.destination_location_id,
+            original.source_location_id,
+            REVERSAL_MOVE_TYPE,
+            -original.quantity,
+            original.unit_cost,
+            original.reference_type,
+            original.reference_id,
+            original.lot_serial_id,
+            format!("reversal-{}", original.move_id),
+            format!("Reversal of stock move {}", original.move_id),
+            original.batch_info,
+            original.metadata,
+            REVERSAL_REASON_CODE,
+            original.source_type,
+            original.source_id,
+            original.move_id,
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?
+        .ok_or_else(|| {
+            AppError::Conflict(format!("Stock move {} has already been reversed", move_id))
+        })?;
+
+        Self::insert_level_history_rows(
+            conn,
+            tenant_id,
+            reversal.move_id,
+            reversal.product_id,
+            &reversal.move_type,
+            location_deltas(
+                reversal.source_location_id,
+                reversal.destination_location_id,
+                reversal.quantity,
+            ),
+        )
+        .await?;
+
+        Ok(reversal)
+    }
+
+    /// Internal helper: Record a stock move within a transaction
+    /// This is used by services for transactional orchestration
+    /// Returns the created move_id and the transaction
+    pub async fn record_with_tx<'a>(
+        &self,
+        mut tx: sqlx::Transaction<'a, sqlx::Postgres>,
+        intent: &MoveIntent,
+        tenant_id: Uuid,
+    ) -> Result<(Uuid, sqlx::Transaction<'a, sqlx::Postgres>), AppError> {
+        let move_id = Self::insert_move(tx.deref_mut(), intent, tenant_id).await?;
         Ok((move_id, tx))
     }
 
-    /// Internal helper: Create a stock move idempotently within a transaction
+    /// Internal helper: Record a stock move idempotently within a transaction
     /// Returns true if the row was created, false if it already existed (no-op)
-    pub async fn create_idempotent_with_tx<'a>(
+    pub async fn record_idempotent_with_tx<'a>(
         &self,
         mut tx: sqlx::Transaction<'a, sqlx::Postgres>,
-        stock_move: &CreateStockMoveRequest,
+        intent: &MoveIntent,
         tenant_id: Uuid,
     ) -> Result<(bool, sqlx::Transaction<'a, sqlx::Postgres>), AppError> {
         let result = sqlx::query!(
@@ -80,97 +254,156 @@ impl PgStockMoveRepository {
             INSERT INTO stock_moves (
                 tenant_id, product_id, source_location_id, destination_location_id,
                 move_type, quantity, unit_cost, reference_type, reference_id,
-                lot_serial_id, idempotency_key, move_reason, batch_info, metadata
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                lot_serial_id, idempotency_key, move_reason, batch_info, metadata,
+                reason_code, source_type, source_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
             ON CONFLICT (tenant_id, idempotency_key) DO NOTHING
+            RETURNING move_id
             "#,
             tenant_id,
-            stock_move.product_id,
-            stock_move.source_location_id,
-            stock_move.destination_location_id,
-            stock_move.move_type,
-            stock_move.quantity,
-            stock_move.unit_cost,
-            stock_move.reference_type,
-            stock_move.reference_id,
-            stock_move.lot_serial_id,
-            stock_move.idempotency_key,
-            stock_move.move_reason,
-            stock_move.batch_info,
-            stock_move.metadata,
+            intent.product_id,
+            intent.source_location_id,
+            intent.destination_location_id,
+            intent.move_type(),
+            intent.quantity,
+            intent.unit_cost,
+            intent.reference_type(),
+            intent.source_id,
+            intent.lot_serial_id,
+            intent.idempotency_key,
+            intent.move_reason,
+            intent.batch_info,
+            intent.metadata,
+            intent.reason_code,
+            intent.source_type.as_str(),
+            intent.source_id,
         )
-        .execute(tx.deref_mut())
+        .fetch_optional(tx.deref_mut())
         .await
         .map_err(|e| AppError::DatabaseError(e.to_string()))?;
 
         // Return true if a row was inserted, false if it was a no-op due to conflict
-        Ok((result.rows_affected() > 0, tx))
+        let Some(inserted) = result else {
+            return Ok((false, tx));
+        };
+        Self::insert_level_history(tx.deref_mut(), tenant_id, inserted.move_id, intent).await?;
+        Ok((true, tx))
     }
-}
 
-#[async_trait]
-impl StockMoveRepository for PgStockMoveRepository {
-    async fn create(
+    /// Move up to `batch_size` stock moves dated before `cutoff` into
+    /// `stock_moves_archive`, oldest first, across all tenants.
+    ///
+    /// Rows are copied unchanged, so balances summed over hot and archived moves
+    /// do not change. Moves still referenced from the hot table (by a stock
+    /// adjustment or by a reversal that has not been archived) stay until their
+    /// referrer is archived. Once a move is archived its idempotency key no longer
+    /// blocks a duplicate, so the retention window must outlast any client retry.
+    /// Returns the number of archived moves.
+    pub async fn archive_before(
+        pool: &PgPool,
+        cutoff: DateTime<Utc>,
+        batch_size: i64,
+    ) -> Result<u64, AppError> {
+        let archived = sqlx::query(
+            r#"
+            WITH candidates AS (
+                SELECT sm.move_id
+                FROM stock_moves sm
+                WHERE sm.move_date < $1
+                  AND NOT EXISTS (
+                      SELECT 1 FROM stock_adjustments sa
+                      WHERE sa.tenant_id = sm.tenant_id AND sa.move_id = sm.move_id
+                  )
+                  AND NOT EXISTS (
+                      SELECT 1 FROM stock_moves r
+                      WHERE r.tenant_id = sm.tenant_id AND r.reversal_of_move_id = sm.move_id
+                  )
+                ORDER BY sm.move_date
+                LIMIT $2
+                FOR UPDATE OF sm SKIP LOCKED
+            ),
+            moved AS (
+                DELETE FROM stock_moves sm
+                USING candidates c
+                WHERE sm.move_id = c.move_id
+                RETURNING sm.*
+            )
+            INSERT INTO stock_moves_archive
+            SELECT * FROM moved
+            "#,
+        )
+        .bind(cutoff)
+        .bind(batch_size)
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(archived)
+    }
+
+    async fn find_by_idempotency_key(
         &self,
-        stock_move: &CreateStockMoveRequest,
         tenant_id: Uuid,
-    ) -> Result<StockMove, AppError> {
-        // First check if this idempotency key already exists
-        if let Some(existing) = sqlx::query_as!(
+        idempotency_key: &str,
+    ) -> Result<Option<StockMove>, AppError> {
+        sqlx::query_as!(
             StockMove,
             r#"
             SELECT
                 move_id, tenant_id, product_id, source_location_id, destination_location_id,
                 move_type, quantity, unit_cost, total_cost, reference_type, reference_id,
-                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at
+                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at,
+                reason_code, source_type, source_id, reversal_of_move_id
             FROM stock_moves
             WHERE tenant_id = $1 AND idempotency_key = $2
             "#,
             tenant_id,
-            stock_move.idempotency_key,
+            idempotency_key,
         )
         .fetch_optional(&*self.pool)
         .await
-        .map_err(|e| AppError::DatabaseError(e.to_string()))?
+        .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl StockMoveRepository for PgStockMoveRepository {
+    async fn record(&self, intent: &MoveIntent, tenant_id: Uuid) -> Result<StockMove, AppError> {
+        // First check if this idempotency key already exists
+        if let Some(existing) = self
+            .find_by_idempotency_key(tenant_id, &intent.idempotency_key)
+            .await?
         {
             // Idempotent: return existing stock_move
             return Ok(existing);
         }
 
-        // Create new stock_move
-        let created_move = sqlx::query_as!(
-            StockMove,
-            r#"
-            INSERT INTO stock_moves (
-                tenant_id, product_id, source_location_id, destination_location_id,
-                move_type, quantity, unit_cost, reference_type, reference_id,
-                lot_serial_id, idempotency_key, move_reason, batch_info, metadata
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
-            RETURNING
-                move_id, tenant_id, product_id, source_location_id, destination_location_id,
-                move_type, quantity, unit_cost, total_cost, reference_type, reference_id,
-                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at
-            "#,
-            tenant_id,
-            stock_move.product_id,
-            stock_move.source_location_id,
-            stock_move.destination_location_id,
-            stock_move.move_type,
-            stock_move.quantity,
-            stock_move.unit_cost,
-            stock_move.reference_type,
-            stock_move.reference_id,
-            stock_move.lot_serial_id,
-            stock_move.idempotency_key,
-            stock_move.move_reason,
-            stock_move.batch_info,
-            stock_move.metadata,
-        )
-        .fetch_one(&*self.pool)
-        .await
-        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        Self::insert_move(&mut conn, intent, tenant_id).await?;
+
+        self.find_by_idempotency_key(tenant_id, &intent.idempotency_key)
+            .await?
+            .ok_or_else(|| AppError::InternalError("Recorded stock move not found".to_string()))
+    }
+
+    async fn reverse_move(&self, tenant_id: Uuid, move_id: Uuid) -> Result<StockMove, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let reversal = Self::insert_reversal(tx.deref_mut(), tenant_id, move_id).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
 
-        Ok(created_move)
+        Ok(reversal)
     }
 
     async fn find_by_reference(
@@ -185,7 +418,8 @@ impl StockMoveRepository for PgStockMoveRepository {
             SELECT
                 move_id, tenant_id, product_id, source_location_id, destination_location_id,
                 move_type, quantity, unit_cost, total_cost, reference_type, reference_id,
-                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at
+                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at,
+                reason_code, source_type, source_id, reversal_of_move_id
             FROM stock_moves
             WHERE tenant_id = $1 AND reference_type = $2 AND reference_id = $3
             ORDER BY created_at ASC
@@ -236,7 +470,8 @@ impl StockMoveRepository for PgStockMoveRepository {
             SELECT
                 move_id, tenant_id, product_id, source_location_id, destination_location_id,
                 move_type, quantity, unit_cost, total_cost, reference_type, reference_id,
-                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at
+                lot_serial_id, idempotency_key, move_date, move_reason, batch_info, metadata, created_at,
+                reason_code, source_type, source_id, reversal_of_move_id
             FROM stock_moves
             WHERE tenant_id = $1 AND lot_serial_id = $2
             ORDER BY created_at ASC
@@ -263,6 +498,39 @@ impl PgInventoryLevelRepository {
         Self { pool }
     }
 
+    /// Upsert an available quantity delta at a location
+    async fn apply_available_delta<'e>(
+        executor: impl sqlx::PgExecutor<'e>,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        location_id: Option<Uuid>,
+        product_id: Uuid,
+        quantity_change: i64,
+    ) -> Result<(), AppError> {
+        // Use upsert pattern: insert with quantity_change if not exists,
+        // or update existing record with the delta.
+        // The unique constraint is on (tenant_id, warehouse_id, location_id, product_id)
+        sqlx::query!(
+            r#"
+            INSERT INTO inventory_levels (tenant_id, warehouse_id, location_id, product_id, available_quantity, reserved_quantity)
+            VALUES ($1, $2, $3, $4, GREATEST($5::bigint, 0), 0)
+            ON CONFLICT (tenant_id, warehouse_id, location_id, product_id) WHERE deleted_at IS NULL
+            DO UPDATE SET
+                available_quantity = GREATEST(inventory_levels.available_quantity + $5::bigint, 0),
+                updated_at = NOW()
+            "#,
+            tenant_id,
+            warehouse_id,
+            location_id,
+            product_id,
+            quantity_change
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
     /// Internal helper: Upsert available quantity within a transaction
     /// This is used by services for transactional orchestration
     /// Uses upsert pattern: insert if not exists, or update existing record
@@ -297,6 +565,85 @@ impl PgInventoryLevelRepository {
 
         Ok(tx)
     }
+
+    /// Internal helper: Move quantity from available to reserved within a transaction
+    /// Guarded so that available stock can never go negative; fails if the
+    /// warehouse does not hold enough available stock.
+    pub async fn reserve_with_tx<'a>(
+        &self,
+        mut tx: sqlx::Transaction<'a, sqlx::Postgres>,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        product_id: Uuid,
+        quantity: i64,
+    ) -> Result<sqlx::Transaction<'a, sqlx::Postgres>, AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE inventory_levels
+            SET available_quantity = available_quantity - $4,
+                reserved_quantity = reserved_quantity + $4,
+                updated_at = NOW()
+            WHERE tenant_id = $1 AND product_id = $2 AND warehouse_id = $3
+              AND available_quantity >= $4
+              AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            product_id,
+            warehouse_id,
+            quantity
+        )
+        .execute(tx.deref_mut())
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::ValidationError(format!(
+                "Insufficient stock available for reservation of product {}",
+                product_id
+            )));
+        }
+
+        Ok(tx)
+    }
+
+    /// Internal helper: Consume previously reserved quantity within a transaction
+    /// Used when reserved stock physically leaves the warehouse (e.g. delivery shipment).
+    /// Guarded so that reserved stock can never go negative.
+    pub async fn consume_reserved_with_tx<'a>(
+        &self,
+        mut tx: sqlx::Transaction<'a, sqlx::Postgres>,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        product_id: Uuid,
+        quantity: i64,
+    ) -> Result<sqlx::Transaction<'a, sqlx::Postgres>, AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE inventory_levels
+            SET reserved_quantity = reserved_quantity - $4,
+                updated_at = NOW()
+            WHERE tenant_id = $1 AND product_id = $2 AND warehouse_id = $3
+              AND reserved_quantity >= $4
+              AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            product_id,
+            warehouse_id,
+            quantity
+        )
+        .execute(tx.deref_mut())
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::ValidationError(format!(
+                "Insufficient reserved stock to ship product {}",
+                product_id
+            )));
+        }
+
+        Ok(tx)
+    }
 }
 
 #[async_trait]
@@ -370,28 +717,35 @@ impl InventoryLevelRepository for PgInventoryLevelRepository {
         product_id: Uuid,
         quantity_change: i64,
     ) -> Result<(), AppError> {
-        // Use upsert pattern: insert with quantity_change if not exists,
-        // or update existing record with the delta.
-        // The unique constraint is on (tenant_id, warehouse_id, location_id, product_id)
-        sqlx::query!(
-            r#"
-            INSERT INTO inventory_levels (tenant_id, warehouse_id, location_id, product_id, available_quantity, reserved_quantity)
-            VALUES ($1, $2, $3, $4, GREATEST($5::bigint, 0), 0)
-            ON CONFLICT (tenant_id, warehouse_id, location_id, product_id) WHERE deleted_at IS NULL
-            DO UPDATE SET
-                available_quantity = GREATEST(inventory_levels.available_quantity + $5::bigint, 0),
-                updated_at = NOW()
-            "#,
+        Self::apply_available_delta(
+            &*self.pool,
             tenant_id,
             warehouse_id,
             location_id,
             product_id,
-            quantity_change
+            quantity_change,
+        )
+        .await
+    }
+
+    async fn update_available_quantity_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        location_id: Option<Uuid>,
+        product_id: Uuid,
+        quantity_change: i64,
+    ) -> Result<(), AppError> {
+        Self::apply_available_delta(
+            tx.deref_mut(),
+            tenant_id,
+            warehouse_id,
+            location_id,
+            product_id,
+            quantity_change,
         )
-        .execute(&*self.pool)
         .await
-        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
-        Ok(())
     }
 
     async fn upsert(
@@ -431,4 +785,41 @@ impl InventoryLevelRepository for PgInventoryLevelRepository {
             .map_err(|e| AppError::DatabaseError(e.to_string()))?;
         Ok(())
     }
+
+    async fn history(
+        &self,
+        tenant_id: Uuid,
+        location_id: Uuid,
+        product_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<InventoryLevelHistoryEntry>, AppError> {
+        // The running balance covers every change; the range only limits which
+        // entries are returned
+        sqlx::query_as::<_, InventoryLevelHistoryEntry>(
+            r#"
+            SELECT history_id, move_id, move_type, quantity_change, balance, recorded_at
+            FROM (
+                SELECT
+                    history_id, move_id, move_type, quantity_change, recorded_at,
+                    SUM(quantity_change) OVER (ORDER BY history_id)::BIGINT AS balance
+                FROM inventory_level_history
+                WHERE tenant_id = $1 AND location_id = $2 AND product_id = $3
+            ) timeline
+            WHERE ($4::timestamptz IS NULL OR recorded_at >= $4)
+              AND ($5::timestamptz IS NULL OR recorded_at < $5)
+            ORDER BY history_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(location_id)
+        .bind(product_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to load inventory level history: {}", e))
+        })
+    }
 }
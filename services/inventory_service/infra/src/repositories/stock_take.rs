@@ -11,6 +11,7 @@ use inventory_service_core::domains::inventory::stock_take::{
 use inventory_service_core::repositories::stock_take::{
     StockTakeLineCountUpdate, StockTakeLineRepository, StockTakeRepository,
 };
+use shared_db::AssertTenantFiltered;
 use shared_error::AppError;
 
 /// Helper type for infra-internal transaction operations
@@ -624,7 +625,7 @@ impl StockTakeLineRepository for PgStockTakeLineRepository {
             "#,
         );
 
-        let query = query_builder.build();
+        let query = query_builder.assert_tenant_filtered().build();
         query.execute(&*self.pool).await.map_err(|e| {
             AppError::DatabaseError(format!("Failed to batch update stock take line counts: {}", e))
         })?;
@@ -0,0 +1,107 @@
+//! PostgreSQL implementation of TenantSettingsRepository
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+use inventory_service_core::domains::inventory::feature_flag::{FeatureFlag, TenantFeatureFlag};
+use inventory_service_core::repositories::tenant_settings::TenantSettingsRepository;
+use inventory_service_core::Result;
+use shared_error::AppError;
+
+/// PostgreSQL implementation of TenantSettingsRepository
+pub struct PgTenantSettingsRepository {
+    pool: PgPool,
+}
+
+impl PgTenantSettingsRepository {
+    /// Create a new repository instance
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_flag(row: &sqlx::postgres::PgRow, flag: FeatureFlag) -> TenantFeatureFlag {
+        TenantFeatureFlag {
+            tenant_id: row.get("tenant_id"),
+            flag,
+            enabled: row.get("enabled"),
+            updated_by: row.get("updated_by"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
+
+#[async_trait]
+impl TenantSettingsRepository for PgTenantSettingsRepository {
+    async fn find_flag(
+        &self,
+        tenant_id: Uuid,
+        flag: FeatureFlag,
+    ) -> Result<Option<TenantFeatureFlag>> {
+        let row = sqlx::query(
+            r#"
+            SELECT tenant_id, setting_key, enabled, updated_by, updated_at
+            FROM tenant_settings
+            WHERE tenant_id = $1 AND setting_key = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(flag.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to load feature flag: {}", e)))?;
+
+        Ok(row.map(|r| Self::row_to_flag(&r, flag)))
+    }
+
+    async fn list_flags(&self, tenant_id: Uuid) -> Result<Vec<TenantFeatureFlag>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT tenant_id, setting_key, enabled, updated_by, updated_at
+            FROM tenant_settings
+            WHERE tenant_id = $1
+            ORDER BY setting_key
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to list feature flags: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|r| {
+                let key: String = r.get("setting_key");
+                FeatureFlag::parse(&key).map(|flag| Self::row_to_flag(r, flag))
+            })
+            .collect())
+    }
+
+    async fn upsert_flag(
+        &self,
+        tenant_id: Uuid,
+        flag: FeatureFlag,
+        enabled: bool,
+        updated_by: Option<Uuid>,
+    ) -> Result<TenantFeatureFlag> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO tenant_settings (tenant_id, setting_key, enabled, updated_by)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (tenant_id, setting_key) DO UPDATE
+            SET enabled = EXCLUDED.enabled,
+                updated_by = EXCLUDED.updated_by
+            RETURNING tenant_id, setting_key, enabled, updated_by, updated_at
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(flag.as_str())
+        .bind(enabled)
+        .bind(updated_by)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to save feature flag: {}", e)))?;
+
+        Ok(Self::row_to_flag(&row, flag))
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{PgPool, Postgres, QueryBuilder};
 use std::sync::Arc;
 use uuid::Uuid;
@@ -7,6 +8,7 @@ use inventory_service_core::domains::inventory::transfer::{
     Transfer, TransferItem, TransferPriority, TransferStatus, TransferType,
 };
 use inventory_service_core::repositories::transfer::{TransferItemRepository, TransferRepository};
+use shared_db::AssertTenantFiltered;
 use shared_error::AppError;
 
 /// PostgreSQL implementation of TransferRepository
@@ -56,11 +58,13 @@ impl PgTransferRepository {
             _ => Err(AppError::DataCorruption(format!("Unknown transfer priority: {}", s))),
         }
     }
-}
 
-#[async_trait]
-impl TransferRepository for PgTransferRepository {
-    async fn create(&self, tenant_id: Uuid, transfer: &Transfer) -> Result<Transfer, AppError> {
+    /// Insert a transfer header on the pool or inside a caller's transaction
+    pub async fn insert_transfer<'e>(
+        executor: impl sqlx::PgExecutor<'e>,
+        tenant_id: Uuid,
+        transfer: &Transfer,
+    ) -> Result<Transfer, AppError> {
         let row = sqlx::query!(
             r#"
             INSERT INTO stock_transfers (
@@ -75,7 +79,7 @@ impl TransferRepository for PgTransferRepository {
                       source_warehouse_id, destination_warehouse_id, status, transfer_type, priority,
                       transfer_date, expected_ship_date, actual_ship_date,
                       expected_receive_date, actual_receive_date,
-                      shipping_method, carrier, tracking_number, shipping_cost,
+                      shipping_method, carrier, tracking_number, expected_arrival, shipping_cost,
                       notes, reason, created_by, updated_by, approved_by, approved_at,
                       total_quantity, total_value, currency_code,
                       created_at, updated_at, deleted_at, deleted_by
@@ -120,7 +124,7 @@ impl TransferRepository for PgTransferRepository {
             transfer.total_value,
             transfer.currency_code
         )
-        .fetch_one(&*self.pool)
+        .fetch_one(executor)
         .await
         .map_err(|e| AppError::DatabaseError(format!("Failed to create transfer: {}", e)))?;
 
@@ -142,6 +146,7 @@ impl TransferRepository for PgTransferRepository {
             shipping_method: row.shipping_method,
             carrier: row.carrier,
             tracking_number: row.tracking_number,
+            expected_arrival: row.expected_arrival,
             shipping_cost: row.shipping_cost,
             notes: row.notes,
             reason: row.reason,
@@ -158,6 +163,13 @@ impl TransferRepository for PgTransferRepository {
             deleted_by: row.deleted_by,
         })
     }
+}
+
+#[async_trait]
+impl TransferRepository for PgTransferRepository {
+    async fn create(&self, tenant_id: Uuid, transfer: &Transfer) -> Result<Transfer, AppError> {
+        Self::insert_transfer(&*self.pool, tenant_id, transfer).await
+    }
 
     async fn find_by_id(
         &self,
@@ -170,7 +182,7 @@ impl TransferRepository for PgTransferRepository {
                    source_warehouse_id, destination_warehouse_id, status, transfer_type, priority,
                    transfer_date, expected_ship_date, actual_ship_date,
                    expected_receive_date, actual_receive_date,
-                   shipping_method, carrier, tracking_number, shipping_cost,
+                   shipping_method, carrier, tracking_number, expected_arrival, shipping_cost,
                    notes, reason, created_by, updated_by, approved_by, approved_at,
                    total_quantity, total_value, currency_code,
                    created_at, updated_at, deleted_at, deleted_by
@@ -203,6 +215,7 @@ impl TransferRepository for PgTransferRepository {
                 shipping_method: r.shipping_method,
                 carrier: r.carrier,
                 tracking_number: r.tracking_number,
+                expected_arrival: r.expected_arrival,
                 shipping_cost: r.shipping_cost,
                 notes: r.notes,
                 reason: r.reason,
@@ -248,7 +261,7 @@ impl TransferRepository for PgTransferRepository {
                    source_warehouse_id, destination_warehouse_id, status, transfer_type, priority,
                    transfer_date, expected_ship_date, actual_ship_date,
                    expected_receive_date, actual_receive_date,
-                   shipping_method, carrier, tracking_number, shipping_cost,
+                   shipping_method, carrier, tracking_number, expected_arrival, shipping_cost,
                    notes, reason, created_by, updated_by, approved_by, approved_at,
                    total_quantity, total_value, currency_code,
                    created_at, updated_at, deleted_at, deleted_by
@@ -293,6 +306,7 @@ impl TransferRepository for PgTransferRepository {
                 shipping_method: r.shipping_method,
                 carrier: r.carrier,
                 tracking_number: r.tracking_number,
+                expected_arrival: r.expected_arrival,
                 shipping_cost: r.shipping_cost,
                 notes: r.notes,
                 reason: r.reason,
@@ -398,6 +412,7 @@ impl TransferRepository for PgTransferRepository {
             r#"
             UPDATE stock_transfers
             SET status = $1, approved_by = $2, approved_at = NOW(),
+                actual_ship_date = COALESCE(actual_ship_date, NOW()),
                 updated_by = $3, updated_at = NOW()
             WHERE tenant_id = $4 AND transfer_id = $5 AND deleted_at IS NULL
             "#,
@@ -466,6 +481,115 @@ impl TransferRepository for PgTransferRepository {
         Ok(())
     }
 
+    async fn update_tracking(
+        &self,
+        tenant_id: Uuid,
+        transfer_id: Uuid,
+        expected_arrival: Option<DateTime<Utc>>,
+        carrier: Option<String>,
+        tracking_number: Option<String>,
+        updated_by: Uuid,
+    ) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"
+            UPDATE stock_transfers
+            SET expected_arrival = COALESCE($1, expected_arrival),
+                carrier = COALESCE($2, carrier),
+                tracking_number = COALESCE($3, tracking_number),
+                updated_by = $4, updated_at = NOW()
+            WHERE tenant_id = $5 AND transfer_id = $6 AND deleted_at IS NULL
+            "#,
+            expected_arrival,
+            carrier,
+            tracking_number,
+            updated_by,
+            tenant_id,
+            transfer_id
+        )
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to update transfer tracking: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_in_transit(
+        &self,
+        tenant_id: Uuid,
+        destination_warehouse_id: Option<Uuid>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Transfer>, AppError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT transfer_id, tenant_id, transfer_number, reference_number,
+                   source_warehouse_id, destination_warehouse_id, status, transfer_type, priority,
+                   transfer_date, expected_ship_date, actual_ship_date,
+                   expected_receive_date, actual_receive_date,
+                   shipping_method, carrier, tracking_number, expected_arrival, shipping_cost,
+                   notes, reason, created_by, updated_by, approved_by, approved_at,
+                   total_quantity, total_value, currency_code,
+                   created_at, updated_at, deleted_at, deleted_by
+            FROM stock_transfers
+            WHERE tenant_id = $1
+              AND deleted_at IS NULL
+              AND status IN ('confirmed', 'partially_picked', 'picked', 'partially_shipped', 'shipped')
+              AND ($2::UUID IS NULL OR destination_warehouse_id = $2)
+            ORDER BY expected_arrival ASC NULLS LAST, created_at ASC
+            LIMIT $3
+            "#,
+            tenant_id,
+            destination_warehouse_id,
+            limit.unwrap_or(100)
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to list in-transit transfers: {}", e))
+        })?;
+
+        let mut transfers = Vec::with_capacity(rows.len());
+        for r in rows {
+            transfers.push(Transfer {
+                transfer_id: r.transfer_id,
+                tenant_id: r.tenant_id,
+                transfer_number: r.transfer_number,
+                reference_number: r.reference_number,
+                source_warehouse_id: r.source_warehouse_id,
+                destination_warehouse_id: r.destination_warehouse_id,
+                status: Self::string_to_transfer_status(&r.status)?,
+                transfer_type: Self::string_to_transfer_type(&r.transfer_type)?,
+                priority: Self::string_to_transfer_priority(&r.priority)?,
+                transfer_date: r.transfer_date,
+                expected_ship_date: r.expected_ship_date,
+                actual_ship_date: r.actual_ship_date,
+                expected_receive_date: r.expected_receive_date,
+                actual_receive_date: r.actual_receive_date,
+                shipping_method: r.shipping_method,
+                carrier: r.carrier,
+                tracking_number: r.tracking_number,
+                expected_arrival: r.expected_arrival,
+                shipping_cost: r.shipping_cost,
+                notes: r.notes,
+                reason: r.reason,
+                created_by: r.created_by,
+                updated_by: r.updated_by,
+                approved_by: r.approved_by,
+                approved_at: r.approved_at,
+                total_quantity: r.total_quantity.unwrap_or(0),
+                total_value: r.total_value.unwrap_or(0),
+                currency_code: r.currency_code.unwrap_or_else(|| "VND".to_string()),
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+                deleted_at: r.deleted_at,
+                deleted_by: r.deleted_by,
+            });
+        }
+
+        Ok(transfers)
+    }
+
     async fn delete(
         &self,
         tenant_id: Uuid,
@@ -500,12 +624,10 @@ impl PgTransferItemRepository {
     pub fn new(pool: Arc<PgPool>) -> Self {
         Self { pool }
     }
-}
 
-#[async_trait]
-impl TransferItemRepository for PgTransferItemRepository {
-    async fn create_batch(
-        &self,
+    /// Insert transfer items on the pool or inside a caller's transaction
+    pub async fn insert_items<'e>(
+        executor: impl sqlx::PgExecutor<'e>,
         tenant_id: Uuid,
         items: &[TransferItem],
     ) -> Result<Vec<TransferItem>, AppError> {
@@ -553,12 +675,25 @@ impl TransferItemRepository for PgTransferItemRepository {
             "#,
         );
 
-        let query = query_builder.build_query_as::<TransferItem>();
+        let query = query_builder
+            .assert_tenant_filtered()
+            .build_query_as::<TransferItem>();
 
-        let created_items = query.fetch_all(&*self.pool).await?;
+        let created_items = query.fetch_all(executor).await?;
 
         Ok(created_items)
     }
+}
+
+#[async_trait]
+impl TransferItemRepository for PgTransferItemRepository {
+    async fn create_batch(
+        &self,
+        tenant_id: Uuid,
+        items: &[TransferItem],
+    ) -> Result<Vec<TransferItem>, AppError> {
+        Self::insert_items(&*self.pool, tenant_id, items).await
+    }
 
     async fn find_by_transfer_id(
         &self,
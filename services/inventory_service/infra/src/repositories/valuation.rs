@@ -4,14 +4,17 @@ use num_traits::cast::ToPrimitive;
 // PostgreSQL implementations of the ValuationRepository, ValuationLayerRepository,
 // and ValuationHistoryRepository traits.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use inventory_service_core::domains::inventory::valuation::{
-    Valuation, ValuationHistory, ValuationLayer, ValuationMethod, ValuationScopeType,
-    ValuationSettings,
+    replay_avco, replay_fifo_layers, CostedMove, LayerConsumption, RoundingPolicy, Valuation,
+    ValuationHistory, ValuationLayer, ValuationMethod, ValuationScopeType, ValuationSettings,
 };
+use inventory_service_core::dto::reports::LayerConsumptionOrder;
 use inventory_service_core::repositories::valuation::{
     ValuationHistoryRepository, ValuationLayerRepository, ValuationRepository,
     ValuationSettingsRepository,
@@ -22,10 +25,11 @@ use inventory_service_core::Result;
 ///
 /// This struct provides concrete implementations for:
 /// - ValuationRepository: Core valuation operations
-/// - ValuationLayerRepository: FIFO cost layer management
+/// - ValuationLayerRepository: FIFO/LIFO cost layer management
 /// - ValuationHistoryRepository: Audit trail and historical tracking
 pub struct ValuationRepositoryImpl {
     pool: PgPool,
+    rounding_policy: RoundingPolicy,
 }
 
 impl ValuationRepositoryImpl {
@@ -37,7 +41,16 @@ impl ValuationRepositoryImpl {
     /// # Returns
     /// New ValuationRepositoryImpl instance
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            rounding_policy: RoundingPolicy::default(),
+        }
+    }
+
+    /// Use `policy` when dividing cent amounts (AVCO unit cost and issue value)
+    pub fn with_rounding_policy(mut self, policy: RoundingPolicy) -> Self {
+        self.rounding_policy = policy;
+        self
     }
 
     /// Convert database string to ValuationMethod enum
@@ -50,6 +63,7 @@ impl ValuationRepositoryImpl {
     fn string_to_valuation_method(s: &str) -> Result<ValuationMethod> {
         match s {
             "fifo" => Ok(ValuationMethod::Fifo),
+            "lifo" => Ok(ValuationMethod::Lifo),
             "avco" => Ok(ValuationMethod::Avco),
             "standard" => Ok(ValuationMethod::Standard),
             unknown => Err(shared_error::AppError::DataCorruption(format!(
@@ -58,6 +72,122 @@ impl ValuationRepositoryImpl {
             ))),
         }
     }
+
+    /// Consume quantity from a product's cost layers in `order` within `tx`
+    ///
+    /// Layers created at the same instant are ordered by layer_id (UUIDv7).
+    /// Emptied layers are deleted.
+    ///
+    /// # Returns
+    /// Total cost of the consumed quantity and what was taken from each layer,
+    /// or an error if the layers hold less
+    async fn consume_layers_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        quantity_to_consume: i64,
+        order: LayerConsumptionOrder,
+    ) -> Result<(i64, Vec<LayerConsumption>)> {
+        let layers = match order {
+            LayerConsumptionOrder::Fifo => {
+                sqlx::query_as!(
+                    ValuationLayer,
+                    r#"
+                    SELECT layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
+                           created_at, updated_at
+                    FROM inventory_valuation_layers
+                    WHERE tenant_id = $1 AND product_id = $2 AND quantity > 0
+                    ORDER BY created_at ASC, layer_id ASC
+                    "#,
+                    tenant_id,
+                    product_id
+                )
+                .fetch_all(&mut **tx)
+                .await?
+            },
+            LayerConsumptionOrder::Lifo => {
+                sqlx::query_as!(
+                    ValuationLayer,
+                    r#"
+                    SELECT layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
+                           created_at, updated_at
+                    FROM inventory_valuation_layers
+                    WHERE tenant_id = $1 AND product_id = $2 AND quantity > 0
+                    ORDER BY created_at DESC, layer_id DESC
+                    "#,
+                    tenant_id,
+                    product_id
+                )
+                .fetch_all(&mut **tx)
+                .await?
+            },
+        };
+
+        let overflow = || {
+            shared_error::AppError::ValidationError(
+                "Inventory value calculation overflow".to_string(),
+            )
+        };
+        let mut remaining_to_consume = quantity_to_consume;
+        let mut total_cost = 0i64;
+        let mut consumed = Vec::new();
+
+        for layer in layers {
+            if remaining_to_consume <= 0 {
+                break;
+            }
+
+            let consume_from_this_layer = remaining_to_consume.min(layer.quantity);
+
+            // Update layer quantity
+            sqlx::query!(
+                r#"
+                UPDATE inventory_valuation_layers
+                SET quantity = quantity - $3, total_value = (quantity - $3) * unit_cost
+                WHERE layer_id = $1 AND tenant_id = $2 AND quantity >= $3
+                "#,
+                layer.layer_id,
+                tenant_id,
+                consume_from_this_layer
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            let cost_increment = consume_from_this_layer
+                .checked_mul(layer.unit_cost)
+                .ok_or_else(overflow)?;
+            total_cost = total_cost
+                .checked_add(cost_increment)
+                .ok_or_else(overflow)?;
+            remaining_to_consume -= consume_from_this_layer;
+            consumed.push(LayerConsumption {
+                layer_id: layer.layer_id,
+                quantity_taken: consume_from_this_layer,
+                unit_cost: layer.unit_cost,
+            });
+        }
+
+        if remaining_to_consume > 0 {
+            return Err(shared_error::AppError::BusinessError(format!(
+                "Insufficient cost layers: {} units still needed after consuming all layers",
+                remaining_to_consume
+            )));
+        }
+
+        // Clean up empty layers
+        sqlx::query!(
+            r#"
+            DELETE FROM inventory_valuation_layers
+            WHERE tenant_id = $1 AND product_id = $2 AND quantity = 0
+            "#,
+            tenant_id,
+            product_id
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        Ok((total_cost, consumed))
+    }
 }
 
 #[async_trait]
@@ -110,6 +240,59 @@ impl ValuationRepository for ValuationRepositoryImpl {
             .transpose()?)
     }
 
+    /// Find valuation records for several products in one query
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier for multi-tenancy
+    /// * `product_ids` - Product identifiers
+    ///
+    /// # Returns
+    /// Map of product_id -> valuation; products without a valuation are absent
+    async fn find_by_product_ids(
+        &self,
+        tenant_id: Uuid,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Valuation>> {
+        if product_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                valuation_id, tenant_id, product_id, valuation_method,
+                current_unit_cost, total_quantity, total_value, standard_cost,
+                last_updated, updated_by
+            FROM inventory_valuations
+            WHERE tenant_id = $1 AND product_id = ANY($2)
+            "#,
+            tenant_id,
+            product_ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|r| -> Result<(Uuid, Valuation)> {
+                let valuation = Valuation {
+                    valuation_id: r.valuation_id,
+                    tenant_id: r.tenant_id,
+                    product_id: r.product_id,
+                    valuation_method: Self::string_to_valuation_method(
+                        r.valuation_method.as_str(),
+                    )?,
+                    current_unit_cost: r.current_unit_cost,
+                    total_quantity: r.total_quantity,
+                    total_value: r.total_value,
+                    standard_cost: r.standard_cost,
+                    last_updated: r.last_updated,
+                    updated_by: r.updated_by,
+                };
+                Ok((valuation.product_id, valuation))
+            })
+            .collect()
+    }
+
     /// Create a new valuation record
     ///
     /// # Arguments
@@ -120,6 +303,7 @@ impl ValuationRepository for ValuationRepositoryImpl {
     async fn create(&self, valuation: &Valuation) -> Result<Valuation> {
         let method_str = match valuation.valuation_method {
             ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
             ValuationMethod::Avco => "avco",
             ValuationMethod::Standard => "standard",
         };
@@ -181,6 +365,7 @@ impl ValuationRepository for ValuationRepositoryImpl {
     ) -> Result<Valuation> {
         let method_str = match valuation.valuation_method {
             ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
             ValuationMethod::Avco => "avco",
             ValuationMethod::Standard => "standard",
         };
@@ -241,6 +426,7 @@ impl ValuationRepository for ValuationRepositoryImpl {
     ) -> Result<Valuation> {
         let method_str = match method {
             ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
             ValuationMethod::Avco => "avco",
             ValuationMethod::Standard => "standard",
         };
@@ -277,6 +463,191 @@ impl ValuationRepository for ValuationRepositoryImpl {
         })
     }
 
+    /// Change valuation method and re-layer existing stock
+    ///
+    /// Switching between FIFO and LIFO keeps the existing layers; moving into a
+    /// layered method seeds them from the average cost of the stock on hand.
+    /// Runs in a single transaction with the valuation row locked, so no
+    /// stock movement can interleave with the re-layering.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `method` - New valuation method
+    /// * `updated_by` - User who made the change
+    ///
+    /// # Returns
+    /// Updated valuation record
+    async fn change_valuation_method(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        method: ValuationMethod,
+        updated_by: Option<Uuid>,
+    ) -> Result<Valuation> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the row for update
+        let current = sqlx::query_as!(
+            Valuation,
+            r#"
+            SELECT
+                valuation_id, tenant_id, product_id, valuation_method,
+                current_unit_cost, total_quantity, total_value, standard_cost,
+                last_updated, updated_by
+            FROM inventory_valuations
+            WHERE tenant_id = $1 AND product_id = $2
+            FOR UPDATE
+            "#,
+            tenant_id,
+            product_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| shared_error::AppError::NotFound("Valuation not found".to_string()))?;
+
+        if current.valuation_method == method {
+            tx.commit().await?;
+            return Ok(current);
+        }
+
+        if current.total_quantity < 0 || current.total_value < 0 {
+            return Err(shared_error::AppError::BusinessError(format!(
+                "Cannot change valuation method while product is in negative stock (quantity {}, value {})",
+                current.total_quantity, current.total_value
+            )));
+        }
+
+        // Average cost of the stock on hand; total_value stays authoritative so
+        // the rounded division never loses value
+        let average_cost = if current.total_quantity > 0 {
+            self.rounding_policy
+                .divide(i128::from(current.total_value), i128::from(current.total_quantity))
+        } else {
+            None
+        };
+
+        // FIFO and LIFO share the same layers and only differ in which end is
+        // consumed, so switching between them keeps the real receipt costs
+        let is_layered =
+            |m: &ValuationMethod| matches!(m, ValuationMethod::Fifo | ValuationMethod::Lifo);
+        let relayer = !(is_layered(&current.valuation_method) && is_layered(&method));
+
+        // Layers only describe FIFO/LIFO stock; any other method starts from none
+        if relayer {
+            sqlx::query!(
+                r#"
+                DELETE FROM inventory_valuation_layers
+                WHERE tenant_id = $1 AND product_id = $2
+                "#,
+                tenant_id,
+                product_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // Each layer must hold quantity * unit_cost exactly, so the stock is split
+        // by the division remainder: that many units one cent above the rest
+        if relayer && is_layered(&method) && current.total_quantity > 0 {
+            let base_cost = current.total_value / current.total_quantity;
+            let remainder = current.total_value % current.total_quantity;
+            let layers = [
+                (remainder, base_cost + 1),
+                (current.total_quantity - remainder, base_cost),
+            ];
+            for (quantity, unit_cost) in layers.into_iter().filter(|(quantity, _)| *quantity > 0) {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO inventory_valuation_layers (
+                        layer_id, tenant_id, product_id, quantity, unit_cost, total_value
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    "#,
+                    Uuid::now_v7(),
+                    tenant_id,
+                    product_id,
+                    quantity,
+                    unit_cost,
+                    quantity * unit_cost
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        let old_method_str = match current.valuation_method {
+            ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
+            ValuationMethod::Avco => "avco",
+            ValuationMethod::Standard => "standard",
+        };
+        let new_method_str = match method {
+            ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
+            ValuationMethod::Avco => "avco",
+            ValuationMethod::Standard => "standard",
+        };
+
+        // Insert history record with pre-change state
+        sqlx::query!(
+            r#"
+            INSERT INTO inventory_valuation_history (
+                valuation_id, tenant_id, product_id, valuation_method,
+                unit_cost, total_quantity, total_value, standard_cost,
+                changed_by, change_reason
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            current.valuation_id,
+            current.tenant_id,
+            current.product_id,
+            old_method_str,
+            current.current_unit_cost,
+            current.total_quantity,
+            current.total_value,
+            current.standard_cost,
+            updated_by,
+            format!("method_change: {} -> {}", old_method_str, new_method_str)
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let row = sqlx::query!(
+            r#"
+            UPDATE inventory_valuations
+            SET valuation_method = $3, current_unit_cost = $4, updated_by = $5
+            WHERE tenant_id = $1 AND product_id = $2
+            RETURNING valuation_id, tenant_id, product_id, valuation_method,
+                      current_unit_cost, total_quantity, total_value, standard_cost,
+                      last_updated, updated_by
+            "#,
+            tenant_id,
+            product_id,
+            new_method_str,
+            average_cost,
+            updated_by
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        let valuation_method = Self::string_to_valuation_method(row.valuation_method.as_str())?;
+        Ok(Valuation {
+            valuation_id: row.valuation_id,
+            tenant_id: row.tenant_id,
+            product_id: row.product_id,
+            valuation_method,
+            current_unit_cost: row.current_unit_cost,
+            total_quantity: row.total_quantity,
+            total_value: row.total_value,
+            standard_cost: row.standard_cost,
+            last_updated: row.last_updated,
+            updated_by: row.updated_by,
+        })
+    }
+
     /// Set the standard cost for a product
     ///
     /// # Arguments
@@ -328,7 +699,7 @@ impl ValuationRepository for ValuationRepositoryImpl {
 
     /// Update valuation based on stock movement
     ///
-    /// Handles receipts and deliveries for all valuation methods (FIFO, AVCO, Standard)
+    /// Handles receipts and deliveries for all valuation methods (FIFO, LIFO, AVCO, Standard)
     /// with proper cost layer management and transaction safety.
     ///
     /// # Arguments
@@ -370,7 +741,7 @@ impl ValuationRepository for ValuationRepositoryImpl {
         .ok_or_else(|| shared_error::AppError::NotFound("Valuation not found".to_string()))?;
 
         let (new_quantity, new_value, new_unit_cost) = match current.valuation_method {
-            ValuationMethod::Fifo => {
+            ValuationMethod::Fifo | ValuationMethod::Lifo => {
                 let new_quantity = current.total_quantity + quantity_change;
                 let new_value = if quantity_change > 0 {
                     // Receipt: create new cost layer
@@ -414,81 +785,19 @@ impl ValuationRepository for ValuationRepositoryImpl {
                     .await?;
                     new_total
                 } else {
-                    // Delivery: consume layers within this transaction
-                    let mut remaining_to_consume = quantity_change.abs();
-                    let mut total_cost = 0i64;
-
-                    // Get layers ordered by creation time (FIFO)
-                    let layers = sqlx::query_as!(
-                        ValuationLayer,
-                        r#"
-                        SELECT layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
-                               created_at, updated_at
-                        FROM inventory_valuation_layers
-                        WHERE tenant_id = $1 AND product_id = $2 AND quantity > 0
-                        ORDER BY created_at ASC
-                        "#,
-                        tenant_id,
-                        product_id
-                    )
-                    .fetch_all(&mut *tx)
-                    .await?;
-
-                    for layer in layers {
-                        if remaining_to_consume <= 0 {
-                            break;
-                        }
-
-                        let consume_from_this_layer = remaining_to_consume.min(layer.quantity);
-
-                        // Update layer quantity
-                        sqlx::query!(
-                            r#"
-                            UPDATE inventory_valuation_layers
-                            SET quantity = quantity - $3, total_value = (quantity - $3) * unit_cost
-                            WHERE layer_id = $1 AND tenant_id = $2 AND quantity >= $3
-                            "#,
-                            layer.layer_id,
-                            tenant_id,
-                            consume_from_this_layer
-                        )
-                        .execute(&mut *tx)
-                        .await?;
-
-                        let cost_increment = consume_from_this_layer
-                            .checked_mul(layer.unit_cost)
-                            .ok_or_else(|| {
-                            shared_error::AppError::ValidationError(
-                                "Inventory value calculation overflow".to_string(),
-                            )
-                        })?;
-                        total_cost = total_cost.checked_add(cost_increment).ok_or_else(|| {
-                            shared_error::AppError::ValidationError(
-                                "Inventory value calculation overflow".to_string(),
-                            )
-                        })?;
-                        remaining_to_consume -= consume_from_this_layer;
-                    }
-
-                    if remaining_to_consume > 0 {
-                        return Err(shared_error::AppError::BusinessError(
-                            format!(
-                                "Insufficient FIFO layers: {} units still needed after consuming all layers",
-                                remaining_to_consume
-                            )
-                        ));
-                    }
-
-                    // Clean up empty layers
-                    sqlx::query!(
-                        r#"
-                        DELETE FROM inventory_valuation_layers
-                        WHERE tenant_id = $1 AND product_id = $2 AND quantity = 0
-                        "#,
+                    // Delivery: consume layers within this transaction, oldest
+                    // first for FIFO and newest first for LIFO
+                    let order = match current.valuation_method {
+                        ValuationMethod::Lifo => LayerConsumptionOrder::Lifo,
+                        _ => LayerConsumptionOrder::Fifo,
+                    };
+                    let (total_cost, _) = Self::consume_layers_in_tx(
+                        &mut tx,
                         tenant_id,
-                        product_id
+                        product_id,
+                        quantity_change.abs(),
+                        order,
                     )
-                    .execute(&mut *tx)
                     .await?;
 
                     current.total_value.checked_sub(total_cost).ok_or_else(|| {
@@ -500,54 +809,22 @@ impl ValuationRepository for ValuationRepositoryImpl {
                 (new_quantity, new_value, current.current_unit_cost)
             },
             ValuationMethod::Avco => {
-                let new_quantity = current.total_quantity + quantity_change;
-                if new_quantity == 0 {
-                    (0, 0, None)
-                } else if quantity_change > 0 {
-                    // Receipt: recalculate average
-                    let receipt_value = unit_cost
-                        .unwrap_or(0)
-                        .checked_mul(quantity_change)
-                        .ok_or_else(|| {
-                            shared_error::AppError::ValidationError(
-                                "Inventory value calculation overflow".to_string(),
-                            )
-                        })?;
-                    let new_value =
-                        current
-                            .total_value
-                            .checked_add(receipt_value)
-                            .ok_or_else(|| {
-                                shared_error::AppError::ValidationError(
-                                    "Inventory value calculation overflow".to_string(),
-                                )
-                            })?;
-                    let new_unit_cost = Some(new_value / new_quantity);
-                    (new_quantity, new_value, new_unit_cost)
-                } else {
-                    // Delivery: use current average
-                    let delivery_value = current
-                        .current_unit_cost
-                        .unwrap_or(0)
-                        .checked_mul(quantity_change.abs())
-                        .ok_or_else(|| {
-                            shared_error::AppError::ValidationError(
-                                "Inventory value calculation overflow".to_string(),
-                            )
-                        })?;
-                    let new_value = current.total_value.checked_sub(delivery_value).ok_or_else(|| {
-                        shared_error::AppError::BusinessError(
-                            format!(
-                                "Insufficient inventory value: attempting to deliver {} but only {} available",
-                                delivery_value, current.total_value
-                            )
+                // Never issue more than is on hand; layers enforce this for FIFO/LIFO
+                current
+                    .quantity_after_move(quantity_change)
+                    .map_err(shared_error::AppError::BusinessError)?;
+                current
+                    .avco_after_move(quantity_change, unit_cost, self.rounding_policy)
+                    .ok_or_else(|| {
+                        shared_error::AppError::ValidationError(
+                            "Inventory value calculation overflow".to_string(),
                         )
-                    })?;
-                    (new_quantity, new_value, current.current_unit_cost)
-                }
+                    })?
             },
             ValuationMethod::Standard => {
-                let new_quantity = current.total_quantity + quantity_change;
+                let new_quantity = current
+                    .quantity_after_move(quantity_change)
+                    .map_err(shared_error::AppError::BusinessError)?;
                 let new_value = if new_quantity == 0 {
                     0
                 } else {
@@ -648,6 +925,7 @@ impl ValuationRepository for ValuationRepositoryImpl {
         // Insert history record with pre-change state
         let method_str = match before.valuation_method {
             ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
             ValuationMethod::Avco => "avco",
             ValuationMethod::Standard => "standard",
         };
@@ -746,6 +1024,7 @@ impl ValuationRepository for ValuationRepositoryImpl {
         // Insert history record with pre-change state
         let method_str = match current.valuation_method {
             ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
             ValuationMethod::Avco => "avco",
             ValuationMethod::Standard => "standard",
         };
@@ -786,6 +1065,323 @@ impl ValuationRepository for ValuationRepositoryImpl {
             updated_by: row.updated_by,
         })
     }
+
+    /// Rebuild FIFO cost layers by replaying the product's stock moves
+    ///
+    /// Runs in one transaction: replaces every layer with the replayed ones,
+    /// resets the valuation totals to match and records the pre-rebuild state
+    /// in the valuation history.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `updated_by` - User who requested the rebuild
+    ///
+    /// # Returns
+    /// Updated valuation record
+    async fn rebuild_layers(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        updated_by: Option<Uuid>,
+    ) -> Result<Valuation> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the row so no movement lands between the replay and the rewrite
+        let current = sqlx::query_as!(
+            Valuation,
+            r#"
+            SELECT
+                valuation_id, tenant_id, product_id, valuation_method,
+                current_unit_cost, total_quantity, total_value, standard_cost,
+                last_updated, updated_by
+            FROM inventory_valuations
+            WHERE tenant_id = $1 AND product_id = $2
+            FOR UPDATE
+            "#,
+            tenant_id,
+            product_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| shared_error::AppError::NotFound("Valuation not found".to_string()))?;
+
+        if current.valuation_method != ValuationMethod::Fifo {
+            return Err(shared_error::AppError::BusinessError(
+                "Cost layers can only be rebuilt for products valued with FIFO".to_string(),
+            ));
+        }
+
+        // Archived moves still carry cost, so replay hot and archived history
+        let moves: Vec<CostedMove> = sqlx::query!(
+            r#"
+            SELECT move_id AS "move_id!", source_location_id, destination_location_id,
+                   quantity AS "quantity!", unit_cost, move_date AS "move_date!"
+            FROM stock_moves_with_archive
+            WHERE tenant_id = $1 AND product_id = $2
+            ORDER BY move_date ASC, created_at ASC, move_id ASC
+            "#,
+            tenant_id,
+            product_id
+        )
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            CostedMove::from_move(
+                row.move_id,
+                row.source_location_id,
+                row.destination_location_id,
+                row.quantity,
+                row.unit_cost,
+                row.move_date,
+            )
+        })
+        .collect();
+
+        let layers = replay_fifo_layers(tenant_id, product_id, &moves)
+            .map_err(shared_error::AppError::BusinessError)?;
+
+        let mut total_quantity = 0i64;
+        let mut total_value = 0i64;
+        for layer in &layers {
+            total_quantity += layer.quantity;
+            total_value = total_value.checked_add(layer.total_value).ok_or_else(|| {
+                shared_error::AppError::ValidationError(
+                    "Inventory value calculation overflow".to_string(),
+                )
+            })?;
+        }
+
+        sqlx::query!(
+            r#"
+            DELETE FROM inventory_valuation_layers
+            WHERE tenant_id = $1 AND product_id = $2
+            "#,
+            tenant_id,
+            product_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for layer in &layers {
+            sqlx::query!(
+                r#"
+                INSERT INTO inventory_valuation_layers (
+                    layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
+                    created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $7)
+                "#,
+                layer.layer_id,
+                tenant_id,
+                product_id,
+                layer.quantity,
+                layer.unit_cost,
+                layer.total_value,
+                layer.created_at
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // Insert history record with pre-rebuild state
+        sqlx::query!(
+            r#"
+            INSERT INTO inventory_valuation_history (
+                valuation_id, tenant_id, product_id, valuation_method,
+                unit_cost, total_quantity, total_value, standard_cost,
+                changed_by, change_reason
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            current.valuation_id,
+            current.tenant_id,
+            current.product_id,
+            "fifo",
+            current.current_unit_cost,
+            current.total_quantity,
+            current.total_value,
+            current.standard_cost,
+            updated_by,
+            format!("layer_rebuild: {} stock moves replayed", moves.len())
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let row = sqlx::query!(
+            r#"
+            UPDATE inventory_valuations
+            SET total_quantity = $3, total_value = $4, updated_by = $5
+            WHERE tenant_id = $1 AND product_id = $2
+            RETURNING valuation_id, tenant_id, product_id, valuation_method,
+                      current_unit_cost, total_quantity, total_value, standard_cost,
+                      last_updated, updated_by
+            "#,
+            tenant_id,
+            product_id,
+            total_quantity,
+            total_value,
+            updated_by
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        let valuation_method = Self::string_to_valuation_method(row.valuation_method.as_str())?;
+        Ok(Valuation {
+            valuation_id: row.valuation_id,
+            tenant_id: row.tenant_id,
+            product_id: row.product_id,
+            valuation_method,
+            current_unit_cost: row.current_unit_cost,
+            total_quantity: row.total_quantity,
+            total_value: row.total_value,
+            standard_cost: row.standard_cost,
+            last_updated: row.last_updated,
+            updated_by: row.updated_by,
+        })
+    }
+
+    /// Recompute the AVCO running average by replaying the product's stock moves
+    ///
+    /// Runs in one transaction with the valuation row locked: replays every
+    /// receipt and delivery, resets the totals and unit cost to the result and
+    /// records the pre-recompute state in the valuation history.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `updated_by` - User who requested the recompute
+    ///
+    /// # Returns
+    /// Updated valuation record
+    async fn recompute_avco(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        updated_by: Option<Uuid>,
+    ) -> Result<Valuation> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the row so no movement lands between the replay and the rewrite
+        let current = sqlx::query_as!(
+            Valuation,
+            r#"
+            SELECT
+                valuation_id, tenant_id, product_id, valuation_method,
+                current_unit_cost, total_quantity, total_value, standard_cost,
+                last_updated, updated_by
+            FROM inventory_valuations
+            WHERE tenant_id = $1 AND product_id = $2
+            FOR UPDATE
+            "#,
+            tenant_id,
+            product_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| shared_error::AppError::NotFound("Valuation not found".to_string()))?;
+
+        if current.valuation_method != ValuationMethod::Avco {
+            return Err(shared_error::AppError::BusinessError(
+                "Average cost can only be recomputed for products valued with AVCO".to_string(),
+            ));
+        }
+
+        // Archived moves still carry cost, so replay hot and archived history
+        let moves: Vec<CostedMove> = sqlx::query!(
+            r#"
+            SELECT move_id AS "move_id!", source_location_id, destination_location_id,
+                   quantity AS "quantity!", unit_cost, move_date AS "move_date!"
+            FROM stock_moves_with_archive
+            WHERE tenant_id = $1 AND product_id = $2
+              AND move_type IN ('receipt', 'delivery')
+            ORDER BY move_date ASC, created_at ASC, move_id ASC
+            "#,
+            tenant_id,
+            product_id
+        )
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            CostedMove::from_move(
+                row.move_id,
+                row.source_location_id,
+                row.destination_location_id,
+                row.quantity,
+                row.unit_cost,
+                row.move_date,
+            )
+        })
+        .collect();
+
+        let (total_quantity, total_value, current_unit_cost) =
+            replay_avco(tenant_id, product_id, &moves, self.rounding_policy)
+                .map_err(shared_error::AppError::BusinessError)?;
+
+        // Insert history record with pre-recompute state
+        sqlx::query!(
+            r#"
+            INSERT INTO inventory_valuation_history (
+                valuation_id, tenant_id, product_id, valuation_method,
+                unit_cost, total_quantity, total_value, standard_cost,
+                changed_by, change_reason
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            current.valuation_id,
+            current.tenant_id,
+            current.product_id,
+            "avco",
+            current.current_unit_cost,
+            current.total_quantity,
+            current.total_value,
+            current.standard_cost,
+            updated_by,
+            "avco_recompute"
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let row = sqlx::query!(
+            r#"
+            UPDATE inventory_valuations
+            SET current_unit_cost = $3, total_quantity = $4, total_value = $5, updated_by = $6,
+                last_updated = NOW()
+            WHERE tenant_id = $1 AND product_id = $2
+            RETURNING valuation_id, tenant_id, product_id, valuation_method,
+                      current_unit_cost, total_quantity, total_value, standard_cost,
+                      last_updated, updated_by
+            "#,
+            tenant_id,
+            product_id,
+            current_unit_cost,
+            total_quantity,
+            total_value,
+            updated_by
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        let valuation_method = Self::string_to_valuation_method(row.valuation_method.as_str())?;
+        Ok(Valuation {
+            valuation_id: row.valuation_id,
+            tenant_id: row.tenant_id,
+            product_id: row.product_id,
+            valuation_method,
+            current_unit_cost: row.current_unit_cost,
+            total_quantity: row.total_quantity,
+            total_value: row.total_value,
+            standard_cost: row.standard_cost,
+            last_updated: row.last_updated,
+            updated_by: row.updated_by,
+        })
+    }
 }
 
 #[async_trait]
@@ -811,7 +1407,7 @@ impl ValuationLayerRepository for ValuationRepositoryImpl {
                    created_at, updated_at
             FROM inventory_valuation_layers
             WHERE tenant_id = $1 AND product_id = $2 AND quantity > 0
-            ORDER BY created_at ASC
+            ORDER BY created_at ASC, layer_id ASC
             "#,
             tenant_id,
             product_id
@@ -893,70 +1489,69 @@ impl ValuationLayerRepository for ValuationRepositoryImpl {
         product_id: Uuid,
         quantity_to_consume: i64,
     ) -> Result<i64> {
-        let mut tx = self.pool.begin().await?;
-        let mut remaining_to_consume = quantity_to_consume;
-        let mut total_cost = 0i64;
+        let (total_cost, _) = self
+            .consume_layers_detailed(tenant_id, product_id, quantity_to_consume)
+            .await?;
+        Ok(total_cost)
+    }
 
-        // Get layers ordered by creation time (FIFO)
-        let layers = sqlx::query_as!(
-            ValuationLayer,
-            r#"
-            SELECT layer_id, tenant_id, product_id, quantity, unit_cost, total_value,
-                   created_at, updated_at
-            FROM inventory_valuation_layers
-            WHERE tenant_id = $1 AND product_id = $2 AND quantity > 0
-            ORDER BY created_at ASC
-            "#,
+    /// Consume cost layers for delivery (FIFO), reporting each layer taken from
+    ///
+    /// Reduces layer quantities starting from oldest layers.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `quantity_to_consume` - Quantity to consume from layers
+    ///
+    /// # Returns
+    /// Total cost of consumed layers and the quantity taken from each, oldest first
+    async fn consume_layers_detailed(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        quantity_to_consume: i64,
+    ) -> Result<(i64, Vec<LayerConsumption>)> {
+        let mut tx = self.pool.begin().await?;
+        let consumption = Self::consume_layers_in_tx(
+            &mut tx,
             tenant_id,
-            product_id
+            product_id,
+            quantity_to_consume,
+            LayerConsumptionOrder::Fifo,
         )
-        .fetch_all(&mut *tx)
         .await?;
+        tx.commit().await?;
+        Ok(consumption)
+    }
 
-        for layer in layers {
-            if remaining_to_consume <= 0 {
-                break;
-            }
-
-            let consume_from_this_layer = remaining_to_consume.min(layer.quantity);
-
-            // Update layer quantity
-            sqlx::query!(
-                r#"
-                UPDATE inventory_valuation_layers
-                SET quantity = quantity - $3, total_value = (quantity - $3) * unit_cost
-                WHERE layer_id = $1 AND tenant_id = $2 AND quantity >= $3
-                "#,
-                layer.layer_id,
-                tenant_id,
-                consume_from_this_layer
-            )
-            .execute(&mut *tx)
-            .await?;
-
-            total_cost += consume_from_this_layer * layer.unit_cost;
-            remaining_to_consume -= consume_from_this_layer;
-        }
-
-        if remaining_to_consume > 0 {
-            return Err(shared_error::AppError::BusinessError(format!(
-                "Insufficient FIFO layers: {} units still needed after consuming all layers",
-                remaining_to_consume
-            )));
-        }
-
-        // Clean up empty layers
-        sqlx::query!(
-            r#"
-            DELETE FROM inventory_valuation_layers
-            WHERE tenant_id = $1 AND product_id = $2 AND quantity = 0
-            "#,
+    /// Consume cost layers for delivery (LIFO)
+    ///
+    /// Reduces layer quantities starting from newest layers.
+    /// Returns total cost of consumed quantity.
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `quantity_to_consume` - Quantity to consume from layers
+    ///
+    /// # Returns
+    /// Total cost of consumed layers
+    async fn consume_layers_lifo(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        quantity_to_consume: i64,
+    ) -> Result<i64> {
+        let mut tx = self.pool.begin().await?;
+        let (total_cost, _) = Self::consume_layers_in_tx(
+            &mut tx,
             tenant_id,
-            product_id
+            product_id,
+            quantity_to_consume,
+            LayerConsumptionOrder::Lifo,
         )
-        .execute(&mut *tx)
         .await?;
-
         tx.commit().await?;
         Ok(total_cost)
     }
@@ -1094,6 +1689,7 @@ impl ValuationHistoryRepository for ValuationRepositoryImpl {
     async fn create(&self, history: &ValuationHistory) -> Result<ValuationHistory> {
         let method_str = match history.valuation_method {
             ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
             ValuationMethod::Avco => "avco",
             ValuationMethod::Standard => "standard",
         };
@@ -1220,6 +1816,7 @@ impl ValuationSettingsRepositoryImpl {
     fn string_to_method(s: &str) -> Result<ValuationMethod> {
         match s {
             "fifo" => Ok(ValuationMethod::Fifo),
+            "lifo" => Ok(ValuationMethod::Lifo),
             "avco" => Ok(ValuationMethod::Avco),
             "standard" => Ok(ValuationMethod::Standard),
             unknown => Err(shared_error::AppError::DataCorruption(format!(
@@ -1233,6 +1830,7 @@ impl ValuationSettingsRepositoryImpl {
     fn method_to_string(method: &ValuationMethod) -> &'static str {
         match method {
             ValuationMethod::Fifo => "fifo",
+            ValuationMethod::Lifo => "lifo",
             ValuationMethod::Avco => "avco",
             ValuationMethod::Standard => "standard",
         }
@@ -3,22 +3,26 @@
 //! PostgreSQL implementation of the WarehouseRepository trait.
 
 use async_trait::async_trait;
+use serde_json::json;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use inventory_service_core::domains::inventory::dto::warehouse_dto::{
     CreateWarehouseLocationRequest, CreateWarehouseRequest, CreateWarehouseZoneRequest,
-    UpdateWarehouseLocationRequest, UpdateWarehouseZoneRequest, WarehouseTreeNode,
-    WarehouseTreeResponse, WarehouseZoneWithLocations,
+    LocationMergeResponse, UpdateWarehouseLocationRequest, UpdateWarehouseZoneRequest,
+    WarehouseTreeNode, WarehouseTreeResponse, WarehouseZoneWithLocations,
 };
 use inventory_service_core::domains::inventory::warehouse::Warehouse;
 use inventory_service_core::domains::inventory::warehouse_location::WarehouseLocation;
 use inventory_service_core::domains::inventory::warehouse_zone::WarehouseZone;
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
 use inventory_service_core::repositories::warehouse::WarehouseRepository;
 use inventory_service_core::Result;
 use serde_json;
 use shared_error::AppError;
 
+use super::stock::PgStockMoveRepository;
+
 /// PostgreSQL implementation of WarehouseRepository
 pub struct WarehouseRepositoryImpl {
     pool: PgPool,
@@ -388,7 +392,8 @@ impl WarehouseRepository for WarehouseRepositoryImpl {
             r#"
             SELECT
                 location_id, tenant_id, warehouse_id, zone_id, location_code, location_name, description,
-                location_type, coordinates, dimensions, capacity_info, location_attributes,
+                location_type, coordinates, dimensions, capacity_info,
+                capacity AS max_capacity_units, location_attributes,
                 is_active, created_at, updated_at, deleted_at
             FROM warehouse_locations
             WHERE tenant_id = $1 AND deleted_at IS NULL
@@ -426,7 +431,7 @@ impl WarehouseRepository for WarehouseRepositoryImpl {
     ) -> Result<Option<WarehouseLocation>> {
         let location = sqlx::query_as!(
             WarehouseLocation,
-            "SELECT location_id, tenant_id, warehouse_id, zone_id, location_code, location_name, description, location_type, coordinates, dimensions, capacity_info, location_attributes, is_active, created_at, updated_at, deleted_at FROM warehouse_locations WHERE tenant_id = $1 AND location_id = $2 AND deleted_at IS NULL",
+            "SELECT location_id, tenant_id, warehouse_id, zone_id, location_code, location_name, description, location_type, coordinates, dimensions, capacity_info, capacity AS max_capacity_units, location_attributes, is_active, created_at, updated_at, deleted_at FROM warehouse_locations WHERE tenant_id = $1 AND location_id = $2 AND deleted_at IS NULL",
             tenant_id,
             location_id
         )
@@ -516,7 +521,8 @@ impl WarehouseRepository for WarehouseRepositoryImpl {
         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
         RETURNING
             location_id, tenant_id, warehouse_id, zone_id, location_code, location_name, description,
-            location_type, coordinates, dimensions, capacity_info, location_attributes,
+            location_type, coordinates, dimensions, capacity_info,
+            capacity AS max_capacity_units, location_attributes,
             is_active, created_at, updated_at, deleted_at
         "#,
         tenant_id,
@@ -676,6 +682,7 @@ impl WarehouseRepository for WarehouseRepositoryImpl {
                 l.coordinates,
                 l.dimensions,
                 l.capacity_info,
+                l.capacity AS max_capacity_units,
                 l.location_attributes,
                 l.is_active,
                 l.created_at,
@@ -732,6 +739,7 @@ impl WarehouseRepository for WarehouseRepositoryImpl {
                 coordinates,
                 dimensions,
                 capacity_info,
+                capacity AS max_capacity_units,
                 location_attributes,
                 is_active,
                 created_at,
@@ -779,10 +787,228 @@ impl WarehouseRepository for WarehouseRepositoryImpl {
         Ok(result.rows_affected() > 0)
     }
 
+    async fn merge_location(
+        &self,
+        tenant_id: Uuid,
+        from_location_id: Uuid,
+        to_location_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<LocationMergeResponse> {
+        if from_location_id == to_location_id {
+            return Err(AppError::ValidationError(
+                "Cannot merge a location into itself".to_string(),
+            ));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        // Lock both locations in id order so concurrent merges cannot deadlock
+        let locations = sqlx::query!(
+            r#"
+            SELECT location_id, warehouse_id, capacity, is_active
+            FROM warehouse_locations
+            WHERE tenant_id = $1 AND location_id = ANY($2) AND deleted_at IS NULL
+            ORDER BY location_id
+            FOR UPDATE
+            "#,
+            tenant_id,
+            &[from_location_id, to_location_id][..]
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let from = locations
+            .iter()
+            .find(|location| location.location_id == from_location_id)
+            .ok_or_else(|| AppError::NotFound("Source location not found".to_string()))?;
+        let to = locations
+            .iter()
+            .find(|location| location.location_id == to_location_id)
+            .ok_or_else(|| AppError::NotFound("Target location not found".to_string()))?;
+
+        if from.warehouse_id != to.warehouse_id {
+            return Err(AppError::ValidationError(
+                "Locations must belong to the same warehouse; use a transfer instead".to_string(),
+            ));
+        }
+        if !to.is_active {
+            return Err(AppError::ValidationError("Target location is inactive".to_string()));
+        }
+        let warehouse_id = from.warehouse_id;
+
+        let levels = sqlx::query!(
+            r#"
+            SELECT inventory_id, product_id, available_quantity,
+                   reserved_quantity + quality_hold_quantity + quarantined_quantity AS "committed_quantity!"
+            FROM inventory_levels
+            WHERE tenant_id = $1 AND location_id = $2 AND deleted_at IS NULL
+            ORDER BY product_id
+            FOR UPDATE
+            "#,
+            tenant_id,
+            from_location_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        // Reservations and quality holds refer to the source location, so they
+        // have to be released before its stock can move
+        if levels.iter().any(|level| level.committed_quantity > 0) {
+            return Err(AppError::BusinessError(
+                "Source location holds reserved or held stock; release it before merging"
+                    .to_string(),
+            ));
+        }
+
+        let quantity_moved: i64 = levels.iter().map(|level| level.available_quantity).sum();
+
+        let target_stock = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(SUM(
+                available_quantity + reserved_quantity + quality_hold_quantity + quarantined_quantity
+            ), 0)::BIGINT AS "stock!"
+            FROM inventory_levels
+            WHERE tenant_id = $1 AND location_id = $2 AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            to_location_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if let Some(capacity) = to.capacity {
+            if target_stock + quantity_moved > capacity {
+                return Err(AppError::BusinessError(format!(
+                    "Merging {} units would exceed the target location's capacity ({} of {} used)",
+                    quantity_moved, target_stock, capacity
+                )));
+            }
+        }
+
+        let merge_id = Uuid::now_v7();
+        let mut move_ids = Vec::new();
+        for level in levels.iter().filter(|level| level.available_quantity > 0) {
+            sqlx::query!(
+                r#"
+                INSERT INTO inventory_levels (tenant_id, warehouse_id, location_id, product_id, available_quantity, reserved_quantity)
+                VALUES ($1, $2, $3, $4, $5, 0)
+                ON CONFLICT (tenant_id, warehouse_id, location_id, product_id) WHERE deleted_at IS NULL
+                DO UPDATE SET
+                    available_quantity = inventory_levels.available_quantity + EXCLUDED.available_quantity,
+                    updated_at = NOW()
+                "#,
+                tenant_id,
+                warehouse_id,
+                to_location_id,
+                level.product_id,
+                level.available_quantity
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            let intent = MoveIntent::new(
+                MoveSourceType::Transfer,
+                merge_id,
+                "location_merge",
+                level.product_id,
+                level.available_quantity,
+                format!("location-merge-{}-{}", merge_id, level.product_id),
+            )
+            .with_locations(Some(from_location_id), Some(to_location_id))
+            .with_metadata(Some(json!({
+                "merged_from": from_location_id,
+                "merged_into": to_location_id,
+                "merged_by": user_id,
+            })));
+            move_ids.push(PgStockMoveRepository::insert_move(&mut tx, &intent, tenant_id).await?);
+        }
+
+        // Retire the emptied level rows and the location itself
+        sqlx::query!(
+            r#"
+            UPDATE inventory_levels
+            SET available_quantity = 0, deleted_at = NOW(), updated_at = NOW()
+            WHERE tenant_id = $1 AND location_id = $2 AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            from_location_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE warehouse_locations
+            SET is_active = false, current_stock = 0, updated_by = $3, updated_at = NOW()
+            WHERE tenant_id = $1 AND location_id = $2
+            "#,
+            tenant_id,
+            from_location_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE warehouse_locations
+            SET current_stock = $3, updated_by = $4, updated_at = NOW()
+            WHERE tenant_id = $1 AND location_id = $2
+            "#,
+            tenant_id,
+            to_location_id,
+            target_stock + quantity_moved,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(LocationMergeResponse {
+            from_location_id,
+            to_location_id,
+            products_moved: move_ids.len() as u32,
+            quantity_moved,
+            move_ids,
+        })
+    }
+
     // ========================================================================
     // Capacity and Analytics
     // ========================================================================
 
+    async fn get_available_capacity(
+        &self,
+        tenant_id: Uuid,
+        location_id: Uuid,
+    ) -> Result<Option<i64>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                l.capacity,
+                COALESCE(SUM(
+                    il.available_quantity + il.reserved_quantity
+                        + il.quality_hold_quantity + il.quarantined_quantity
+                ), 0)::BIGINT AS "on_hand!"
+            FROM warehouse_locations l
+            LEFT JOIN inventory_levels il
+                ON il.tenant_id = l.tenant_id
+               AND il.location_id = l.location_id
+               AND il.deleted_at IS NULL
+            WHERE l.tenant_id = $1 AND l.location_id = $2 AND l.deleted_at IS NULL
+            GROUP BY l.location_id, l.capacity
+            "#,
+            tenant_id,
+            location_id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Location {} not found", location_id)))?;
+
+        Ok(row.capacity.map(|capacity| (capacity - row.on_hand).max(0)))
+    }
+
     async fn get_capacity_utilization(
         &self,
         _tenant_id: Uuid,
@@ -10,14 +10,18 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use inventory_service_core::dto::adjustment::{
-    AddAdjustmentLinesRequest, AdjustmentDocument, AdjustmentDocumentResponse,
-    AdjustmentDocumentWithLinesResponse, AdjustmentLine, AdjustmentListQuery,
-    AdjustmentListResponse, AdjustmentReasonCode, AdjustmentStatus, AdjustmentSummary,
-    AdjustmentType, CreateAdjustmentRequest, PostAdjustmentRequest,
+    validate_quick_adjustment, AddAdjustmentLinesRequest, AdjustmentDocument,
+    AdjustmentDocumentResponse, AdjustmentDocumentWithLinesResponse, AdjustmentLine,
+    AdjustmentListQuery, AdjustmentListResponse, AdjustmentReasonCode, AdjustmentStatus,
+    AdjustmentSummary, AdjustmentType, CreateAdjustmentRequest, PostAdjustmentRequest,
+    QuickAdjustmentRequest, QuickAdjustmentResponse,
 };
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
 use inventory_service_core::services::adjustment::AdjustmentService;
 use shared_error::AppError;
 
+use crate::repositories::stock::PgStockMoveRepository;
+
 /// PostgreSQL implementation of AdjustmentService
 pub struct PgAdjustmentService {
     pool: Arc<PgPool>,
@@ -598,8 +602,6 @@ impl AdjustmentService for PgAdjustmentService {
 
         // Create stock moves for each line
         for line in &line_rows {
-            let move_id = Uuid::now_v7();
-
             // Determine quantity sign based on adjustment type
             let move_qty = match line.adjustment_type.as_str() {
                 "increase" => line.qty,
@@ -620,29 +622,19 @@ impl AdjustmentService for PgAdjustmentService {
             };
 
             // Create stock move
-            sqlx::query(
-                r#"
-                INSERT INTO stock_moves (
-                    move_id, tenant_id, product_id, source_location_id, destination_location_id,
-                    move_type, quantity, reference_type, reference_id, move_date,
-                    move_reason, lot_serial_id, created_at
-                )
-                VALUES ($1, $2, $3, $4, $5, 'adjustment', $6, 'adjustment', $7, $8, $9, $10, $8)
-                "#,
+            let mut stock_move = MoveIntent::new(
+                MoveSourceType::Adjustment,
+                adjustment_id,
+                line.reason_code.as_str(),
+                line.product_id,
+                move_qty,
+                format!("adjustment-{}-line-{}", adjustment_id, line.adjustment_line_id),
             )
-            .bind(move_id)
-            .bind(tenant_id)
-            .bind(line.product_id)
-            .bind(source_loc)
-            .bind(dest_loc)
-            .bind(move_qty)
-            .bind(adjustment_id)
-            .bind(posted_at)
-            .bind(&line.reason_notes)
-            .bind(line.lot_id)
-            .execute(&mut *tx)
-            .await
-            .map_err(|e| AppError::DatabaseError(format!("Failed to create stock move: {}", e)))?;
+            .with_locations(Some(source_loc), Some(dest_loc))
+            .with_lot_serial(line.lot_id);
+            stock_move.move_reason = line.reason_notes.clone();
+            let move_id =
+                PgStockMoveRepository::insert_move(&mut tx, &stock_move, tenant_id).await?;
 
             // Update line with stock move reference
             sqlx::query(
@@ -806,6 +798,122 @@ impl AdjustmentService for PgAdjustmentService {
             adjustment: updated_row.into(),
         })
     }
+
+    async fn adjust(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        request: QuickAdjustmentRequest,
+    ) -> Result<QuickAdjustmentResponse, AppError> {
+        validate_quick_adjustment(&request)
+            .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                AppError::DatabaseError(format!("Failed to begin transaction: {}", e))
+            })?;
+
+        // Apply the delta to the level. Increases create the level if needed;
+        // decreases are guarded so available stock never goes negative.
+        let available_quantity = if request.delta > 0 {
+            sqlx::query_scalar::<_, i64>(
+                r#"
+                INSERT INTO inventory_levels (
+                    tenant_id, warehouse_id, location_id, product_id,
+                    available_quantity, reserved_quantity
+                )
+                VALUES ($1, $2, $3, $4, $5, 0)
+                ON CONFLICT (tenant_id, warehouse_id, location_id, product_id) WHERE deleted_at IS NULL
+                DO UPDATE SET
+                    available_quantity = inventory_levels.available_quantity + $5,
+                    updated_at = NOW()
+                RETURNING available_quantity
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(request.warehouse_id)
+            .bind(request.location_id)
+            .bind(request.product_id)
+            .bind(request.delta)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(format!("Failed to update inventory levels: {}", e))
+            })?
+        } else {
+            sqlx::query_scalar::<_, i64>(
+                r#"
+                UPDATE inventory_levels
+                SET available_quantity = available_quantity + $1,
+                    updated_at = NOW()
+                WHERE tenant_id = $2
+                  AND warehouse_id = $3
+                  AND location_id IS NOT DISTINCT FROM $4
+                  AND product_id = $5
+                  AND deleted_at IS NULL
+                  AND available_quantity + $1 >= 0
+                RETURNING available_quantity
+                "#,
+            )
+            .bind(request.delta)
+            .bind(tenant_id)
+            .bind(request.warehouse_id)
+            .bind(request.location_id)
+            .bind(request.product_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(format!("Failed to update inventory levels: {}", e))
+            })?
+            .ok_or_else(|| {
+                AppError::ValidationError(format!(
+                    "Insufficient stock to adjust product {} by {}",
+                    request.product_id, request.delta
+                ))
+            })?
+        };
+
+        // Record the adjustment as a stock move. Levels are tracked per warehouse,
+        // so the warehouse/location and reason are kept in the move metadata.
+        // A quick adjustment has no document of its own, so it gets a fresh source id.
+        let adjustment_ref = Uuid::now_v7();
+        let adjusted_at = Utc::now();
+        let reason_code = request.reason_code.to_string();
+        let metadata = serde_json::json!({
+            "warehouse_id": request.warehouse_id,
+            "location_id": request.location_id,
+            "reason_code": reason_code,
+            "adjusted_by": user_id,
+        });
+
+        let stock_move = MoveIntent::new(
+            MoveSourceType::Adjustment,
+            adjustment_ref,
+            reason_code.as_str(),
+            request.product_id,
+            request.delta,
+            format!("adjustment-{}", adjustment_ref),
+        )
+        .with_note(request.note.clone().unwrap_or_else(|| reason_code.clone()))
+        .with_metadata(Some(metadata));
+        let move_id = PgStockMoveRepository::insert_move(&mut tx, &stock_move, tenant_id).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(QuickAdjustmentResponse {
+            move_id,
+            product_id: request.product_id,
+            warehouse_id: request.warehouse_id,
+            location_id: request.location_id,
+            delta: request.delta,
+            reason_code: request.reason_code,
+            available_quantity,
+            adjusted_by: user_id,
+            adjusted_at,
+        })
+    }
 }
 
 #[cfg(test)]
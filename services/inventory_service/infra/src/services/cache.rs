@@ -12,19 +12,60 @@ use std::sync::Arc;
 use std::time::Duration;
 use uuid::Uuid;
 
+use inventory_service_core::domains::inventory::feature_flag::FeatureFlag;
 use inventory_service_core::domains::inventory::product::Product;
 use inventory_service_core::models::InventoryLevel;
-use inventory_service_core::services::{CacheService, InventoryCache, ProductCache};
+use inventory_service_core::services::{
+    CacheService, FeatureFlagCache, InventoryCache, ProductCache,
+};
 use shared_error::AppError;
 
+/// Key prefix of cached products
+const PRODUCT_KEY_PREFIX: &str = "product:";
+/// Key prefix of cached inventory levels
+const INVENTORY_KEY_PREFIX: &str = "inventory:";
+/// Key prefix of cached category entries
+const CATEGORY_KEY_PREFIX: &str = "category:";
+
+/// Time-to-live of cached values per entity type
+///
+/// A configured TTL replaces whatever TTL the caller passes for keys of that
+/// entity; an unset one keeps the caller's TTL, so the default changes nothing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheTtlConfig {
+    pub product_ttl: Option<Duration>,
+    pub inventory_ttl: Option<Duration>,
+    pub category_ttl: Option<Duration>,
+}
+
+impl CacheTtlConfig {
+    /// TTL for `key`: the configured one for its entity, else `requested`
+    pub fn ttl_for(&self, key: &str, requested: Option<Duration>) -> Option<Duration> {
+        let configured = if key.starts_with(PRODUCT_KEY_PREFIX) {
+            self.product_ttl
+        } else if key.starts_with(INVENTORY_KEY_PREFIX) {
+            self.inventory_ttl
+        } else if key.starts_with(CATEGORY_KEY_PREFIX) {
+            self.category_ttl
+        } else {
+            None
+        };
+        configured.or(requested)
+    }
+}
+
 /// Redis-based cache implementation
 pub struct RedisCache {
     pool: Pool<RedisConnectionManager>,
+    ttls: CacheTtlConfig,
 }
 
 impl RedisCache {
     /// Create a new Redis cache instance backed by a Redis connection pool
-    pub async fn new(redis_url: &str) -> Result<Self, AppError> {
+    ///
+    /// `ttls` overrides the TTL of values per entity type; pass
+    /// `CacheTtlConfig::default()` to use the TTL given with each value.
+    pub async fn new(redis_url: &str, ttls: CacheTtlConfig) -> Result<Self, AppError> {
         let manager = RedisConnectionManager::new(redis_url).map_err(|e| {
             AppError::InternalError(format!("Redis connection manager error: {}", e))
         })?;
@@ -34,7 +75,7 @@ impl RedisCache {
             .await
             .map_err(|e| AppError::InternalError(format!("Redis pool creation error: {}", e)))?;
 
-        Ok(Self { pool })
+        Ok(Self { pool, ttls })
     }
 
     /// Get async pooled connection
@@ -49,12 +90,17 @@ impl RedisCache {
 
     /// Generate cache key for product
     fn product_key(tenant_id: &Uuid, product_id: &Uuid) -> String {
-        format!("product:{}:{}", tenant_id, product_id)
+        format!("{}{}:{}", PRODUCT_KEY_PREFIX, tenant_id, product_id)
     }
 
     /// Generate cache key for inventory level
     fn inventory_key(tenant_id: &Uuid, product_id: &Uuid) -> String {
-        format!("inventory:{}:{}", tenant_id, product_id)
+        format!("{}{}:{}", INVENTORY_KEY_PREFIX, tenant_id, product_id)
+    }
+
+    /// Generate cache key for tenant feature flag
+    fn feature_flag_key(tenant_id: &Uuid, flag: FeatureFlag) -> String {
+        format!("feature_flag:{}:{}", tenant_id, flag)
     }
 }
 
@@ -88,7 +134,7 @@ impl CacheService for RedisCache {
         let json = serde_json::to_string(&value)
             .map_err(|e| AppError::InternalError(format!("JSON serialize error: {}", e)))?;
 
-        match ttl {
+        match self.ttls.ttl_for(key.as_ref(), ttl) {
             Some(duration) => {
                 let secs = duration.as_secs().max(1);
                 conn.set_ex(key.as_ref(), json, secs).await
@@ -184,7 +230,68 @@ impl InventoryCache for RedisCache {
     }
 }
 
+#[async_trait]
+impl FeatureFlagCache for RedisCache {
+    async fn get_feature_flag(
+        &self,
+        tenant_id: &Uuid,
+        flag: FeatureFlag,
+    ) -> Result<Option<bool>, AppError> {
+        let key = Self::feature_flag_key(tenant_id, flag);
+        self.get(&key).await
+    }
+
+    async fn set_feature_flag(
+        &self,
+        tenant_id: &Uuid,
+        flag: FeatureFlag,
+        enabled: bool,
+        ttl: Option<Duration>,
+    ) -> Result<(), AppError> {
+        let key = Self::feature_flag_key(tenant_id, flag);
+        self.set(&key, enabled, ttl).await
+    }
+
+    async fn invalidate_feature_flag(
+        &self,
+        tenant_id: &Uuid,
+        flag: FeatureFlag,
+    ) -> Result<(), AppError> {
+        let key = Self::feature_flag_key(tenant_id, flag);
+        self.delete(&key).await
+    }
+}
+
 /// Type alias for Arc-wrapped cache service
 pub type SharedCache = Arc<dyn CacheService + Send + Sync>;
 pub type SharedProductCache = Arc<dyn ProductCache + Send + Sync>;
 pub type SharedInventoryCache = Arc<dyn InventoryCache + Send + Sync>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_ttl_config_keeps_requested_ttl() {
+        let ttls = CacheTtlConfig::default();
+        let requested = Some(Duration::from_secs(42));
+
+        assert_eq!(ttls.ttl_for("product:t:p", requested), requested);
+        assert_eq!(ttls.ttl_for("inventory:t:p", None), None);
+    }
+
+    #[test]
+    fn test_ttl_config_picks_ttl_by_key_prefix() {
+        let ttls = CacheTtlConfig {
+            product_ttl: Some(Duration::from_secs(3600)),
+            inventory_ttl: Some(Duration::from_secs(15)),
+            category_ttl: None,
+        };
+        let requested = Some(Duration::from_secs(60));
+
+        assert_eq!(ttls.ttl_for("product:t:p", requested), Some(Duration::from_secs(3600)));
+        assert_eq!(ttls.ttl_for("inventory:t:p", None), Some(Duration::from_secs(15)));
+        assert_eq!(ttls.ttl_for("category:t:c", requested), requested);
+        assert_eq!(ttls.ttl_for("feature_flag:t:f", requested), requested);
+    }
+}
@@ -6,20 +6,25 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use slug;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 use validator::Validate;
 
 use inventory_service_core::domains::category::{Category, CategoryBreadcrumb, CategoryNode};
 use inventory_service_core::dto::category::{
-    BulkOperationResponse, CategoryCreateRequest, CategoryListQuery, CategoryListResponse,
+    order_for_import, BulkOperationResponse, CategoryCreateRequest, CategoryExportDocument,
+    CategoryExportItem, CategoryImportConflict, CategoryImportMapping, CategoryImportResponse,
+    CategoryImportStatus, CategoryListQuery, CategoryListResponse, CategoryPatchRequest,
     CategoryResponse, CategoryStatsResponse, CategoryTreeResponse, CategoryUpdateRequest,
-    MoveToCategoryRequest,
+    MoveToCategoryRequest, CATEGORY_EXPORT_VERSION,
 };
 use inventory_service_core::repositories::category::CategoryRepository;
 use inventory_service_core::services::category::CategoryService;
 use inventory_service_core::Result;
 use shared_error::AppError;
 
+use super::cache::SharedProductCache;
+
 /// Business logic implementation for category operations
 ///
 /// This struct implements all category business operations with proper
@@ -27,6 +32,7 @@ use shared_error::AppError;
 /// between the repository layer and API layer.
 pub struct CategoryServiceImpl<R: CategoryRepository> {
     repository: R,
+    product_cache: Option<SharedProductCache>,
 }
 
 impl<R: CategoryRepository> CategoryServiceImpl<R> {
@@ -38,7 +44,56 @@ impl<R: CategoryRepository> CategoryServiceImpl<R> {
     /// # Returns
     /// New CategoryServiceImpl instance
     pub fn new(repository: R) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            product_cache: None,
+        }
+    }
+
+    /// Evict cached products whenever this service changes their category
+    ///
+    /// Pass the same cache the product service reads from.
+    pub fn with_product_cache(mut self, cache: SharedProductCache) -> Self {
+        self.product_cache = Some(cache);
+        self
+    }
+
+    /// Reject a code or slug already used by another active category
+    ///
+    /// Soft-deleted categories are ignored, so their code and slug can be
+    /// reused; the partial unique indexes on `product_categories` follow the
+    /// same rule.
+    async fn ensure_identifiers_available(
+        &self,
+        tenant_id: Uuid,
+        category_id: Option<Uuid>,
+        code: Option<&str>,
+        slug: Option<&str>,
+    ) -> Result<()> {
+        let is_other = |found: &Category| Some(found.category_id) != category_id;
+
+        if let Some(code) = code {
+            if let Some(found) = self.repository.find_by_code(tenant_id, code).await? {
+                if is_other(&found) {
+                    return Err(AppError::Conflict(format!(
+                        "Category code '{}' is already in use",
+                        code
+                    )));
+                }
+            }
+        }
+        if let Some(slug) = slug {
+            if let Some(found) = self.repository.find_by_slug(tenant_id, slug).await? {
+                if is_other(&found) {
+                    return Err(AppError::Conflict(format!(
+                        "Category slug '{}' is already in use",
+                        slug
+                    )));
+                }
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -64,6 +119,9 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
             .clone()
             .unwrap_or_else(|| slug::slugify(&request.name));
 
+        self.ensure_identifiers_available(tenant_id, None, request.code.as_deref(), Some(&slug))
+            .await?;
+
         // Validate parent category exists if provided
         if let Some(parent_id) = request.parent_category_id {
             if !self.repository.exists(tenant_id, parent_id).await? {
@@ -129,6 +187,11 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
         Ok(category)
     }
 
+    /// Check if a category exists
+    async fn category_exists(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool> {
+        self.repository.exists(tenant_id, category_id).await
+    }
+
     /// Get a category with its breadcrumb path
     ///
     /// Returns the category along with its complete breadcrumb trail
@@ -251,6 +314,14 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
             existing_category.parent_category_id = request.parent_category_id;
         }
 
+        self.ensure_identifiers_available(
+            tenant_id,
+            Some(category_id),
+            existing_category.code.as_deref(),
+            existing_category.slug.as_deref(),
+        )
+        .await?;
+
         existing_category.updated_at = Utc::now();
 
         // Save to repository
@@ -259,6 +330,104 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
         Ok(updated_category)
     }
 
+    /// Partially update a category
+    ///
+    /// Only the fields present in the request are merged; a `null` parent
+    /// makes the category a root and other `null`s clear the field.
+    async fn patch_category(
+        &self,
+        tenant_id: Uuid,
+        category_id: Uuid,
+        request: CategoryPatchRequest,
+    ) -> Result<Category> {
+        request
+            .validate()
+            .map_err(|e| AppError::ValidationError(format!("Invalid category data: {:?}", e)))?;
+
+        let mut existing_category = self.get_category(tenant_id, category_id).await?;
+
+        // Validate a new parent: it must exist and must not create a cycle
+        if let Some(Some(parent_id)) = request.parent_category_id {
+            if Some(parent_id) != existing_category.parent_category_id {
+                if !self.repository.exists(tenant_id, parent_id).await? {
+                    return Err(AppError::NotFound(format!(
+                        "Parent category {} not found",
+                        parent_id
+                    )));
+                }
+                if !self
+                    .validate_parent(tenant_id, Some(category_id), parent_id)
+                    .await?
+                {
+                    return Err(AppError::ValidationError(
+                        "Invalid parent category relationship".to_string(),
+                    ));
+                }
+            }
+        }
+
+        // Merge: absent fields keep their value, `Some(None)` clears a nullable one
+        if let Some(parent_category_id) = request.parent_category_id {
+            existing_category.parent_category_id = parent_category_id;
+        }
+        if let Some(ref name) = request.name {
+            existing_category.name = name.clone();
+        }
+        if let Some(description) = request.description {
+            existing_category.description = description;
+        }
+        if let Some(code) = request.code {
+            existing_category.code = code;
+        }
+        if let Some(display_order) = request.display_order {
+            existing_category.display_order = i32::try_from(display_order).map_err(|_| {
+                AppError::ValidationError("display_order value too large".to_string())
+            })?;
+        }
+        if let Some(icon) = request.icon {
+            existing_category.icon = icon;
+        }
+        if let Some(color) = request.color {
+            existing_category.color = color;
+        }
+        if let Some(image_url) = request.image_url {
+            existing_category.image_url = image_url;
+        }
+        if let Some(is_active) = request.is_active {
+            existing_category.is_active = is_active;
+        }
+        if let Some(is_visible) = request.is_visible {
+            existing_category.is_visible = is_visible;
+        }
+        if let Some(slug) = request.slug {
+            existing_category.slug = slug;
+        } else if let Some(ref name) = request.name {
+            // Regenerate slug if name changed but slug not provided
+            existing_category.slug = Some(slug::slugify(name));
+        }
+        if let Some(meta_title) = request.meta_title {
+            existing_category.meta_title = meta_title;
+        }
+        if let Some(meta_description) = request.meta_description {
+            existing_category.meta_description = meta_description;
+        }
+        if let Some(meta_keywords) = request.meta_keywords {
+            existing_category.meta_keywords = meta_keywords;
+        }
+
+        self.ensure_identifiers_available(
+            tenant_id,
+            Some(category_id),
+            existing_category.code.as_deref(),
+            existing_category.slug.as_deref(),
+        )
+        .await?;
+
+        existing_category.updated_at = Utc::now();
+
+        self.repository.update(existing_category).await
+    }
+
     /// Delete a category with safety checks
     ///
     /// Performs soft delete only if the category has no children
@@ -284,6 +453,25 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
         Ok(deleted)
     }
 
+    /// Restore a soft-deleted category
+    ///
+    /// Recounts products from the root of the category's path so the
+    /// ancestors' totals include the restored subtree again.
+    async fn restore_category(&self, tenant_id: Uuid, category_id: Uuid) -> Result<Category> {
+        let restored = self.repository.restore(tenant_id, category_id).await?;
+
+        let root_id = restored
+            .get_path_ids()
+            .first()
+            .copied()
+            .unwrap_or(category_id);
+        self.repository
+            .update_product_counts(tenant_id, root_id)
+            .await?;
+
+        self.get_category(tenant_id, category_id).await
+    }
+
     /// List categories with filtering and pagination
     ///
     /// Returns paginated results based on query parameters including
@@ -299,18 +487,19 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
             .map_err(|e| AppError::ValidationError(format!("Invalid list query: {:?}", e)))?;
 
         // Get categories from repository
-        let (categories, total_count) = self.repository.list(tenant_id, &query).await?;
+        let (mut categories, total_count) = self.repository.list(tenant_id, &query).await?;
 
-        // Convert to response DTOs
-        let category_responses = categories.into_iter().map(CategoryResponse::from).collect();
-
-        // Create pagination info
-        let pagination = inventory_service_core::dto::common::PaginationInfo::new(
+        // Create pagination info (drops the look-ahead row when the count was skipped)
+        let pagination = inventory_service_core::dto::common::PaginationInfo::from_optional_total(
             query.page,
-            query.page_size,
-            total_count as u64,
+            query.page_size(),
+            total_count.map(|count| count as u64),
+            &mut categories,
         );
 
+        // Convert to response DTOs
+        let category_responses = categories.into_iter().map(CategoryResponse::from).collect();
+
         Ok(CategoryListResponse {
             categories: category_responses,
             pagination,
@@ -411,9 +600,18 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
         // Call repository method
         let count = self
             .repository
-            .move_products_to_category(tenant_id, request.product_ids, request.category_id)
+            .move_products_to_category(tenant_id, request.product_ids.clone(), request.category_id)
             .await?;
 
+        // Cached products still carry their old category
+        if let Some(cache) = &self.product_cache {
+            for product_id in &request.product_ids {
+                if let Err(e) = cache.invalidate_product(&tenant_id, product_id).await {
+                    tracing::warn!("Product cache invalidation failed for {}: {}", product_id, e);
+                }
+            }
+        }
+
         Ok(BulkOperationResponse {
             success: true,
             affected_count: count as u32,
@@ -560,14 +758,170 @@ impl<R: CategoryRepository + 'static> CategoryService for CategoryServiceImpl<R>
             message: format!("Deleted {} categories", count),
         })
     }
+
+    async fn recount_product_counts(&self, tenant_id: Uuid) -> Result<BulkOperationResponse> {
+        let count = self
+            .repository
+            .recount_all_product_counts(tenant_id)
+            .await?;
+
+        Ok(BulkOperationResponse {
+            success: true,
+            affected_count: count as u32,
+            message: format!("Corrected product counts for {} categories", count),
+        })
+    }
+
+    /// Export the category tree with parents referenced by code
+    ///
+    /// Walks the tree depth-first so every parent is written before its children.
+    async fn export_categories(&self, tenant_id: Uuid) -> Result<CategoryExportDocument> {
+        fn flatten(
+            node: CategoryNode,
+            parent_code: Option<String>,
+            out: &mut Vec<CategoryExportItem>,
+        ) {
+            let item = CategoryExportItem::from_category(&node.category, parent_code);
+            let code = item.code.clone();
+            out.push(item);
+            for child in node.children {
+                flatten(child, Some(code.clone()), out);
+            }
+        }
+
+        let tree = self.repository.get_tree(tenant_id, None).await?;
+        let mut categories = Vec::new();
+        for node in tree {
+            flatten(node, None, &mut categories);
+        }
+
+        Ok(CategoryExportDocument {
+            version: CATEGORY_EXPORT_VERSION,
+            exported_at: Some(Utc::now()),
+            categories,
+        })
+    }
+
+    /// Import a category tree, creating parents before children
+    ///
+    /// Categories whose code already exists in the tenant are reused rather
+    /// than recreated, so importing the same document twice is a no-op.
+    async fn import_categories(
+        &self,
+        tenant_id: Uuid,
+        document: CategoryExportDocument,
+    ) -> Result<CategoryImportResponse> {
+        document
+            .validate()
+            .map_err(|e| AppError::ValidationError(format!("Invalid import document: {:?}", e)))?;
+        if document.version > CATEGORY_EXPORT_VERSION {
+            return Err(AppError::ValidationError(format!(
+                "Unsupported category export version {}",
+                document.version
+            )));
+        }
+
+        // Resolve parent codes that point at categories already in the tenant
+        let document_codes: HashSet<&str> = document
+            .categories
+            .iter()
+            .map(|c| c.code.as_str())
+            .collect();
+        let mut code_to_id: HashMap<String, Uuid> = HashMap::new();
+        for parent_code in document
+            .categories
+            .iter()
+            .filter_map(|c| c.parent_code.as_deref())
+            .filter(|code| !document_codes.contains(code))
+        {
+            if code_to_id.contains_key(parent_code) {
+                continue;
+            }
+            if let Some(existing) = self.repository.find_by_code(tenant_id, parent_code).await? {
+                code_to_id.insert(parent_code.to_string(), existing.category_id);
+            }
+        }
+
+        let (ordered, mut conflicts) =
+            order_for_import(document.categories, |code| code_to_id.contains_key(code));
+
+        let mut mappings = Vec::with_capacity(ordered.len());
+        let mut created_count = 0;
+        let mut existing_count = 0;
+
+        for item in ordered {
+            let code = item.code.clone();
+
+            if let Some(existing) = self.repository.find_by_code(tenant_id, &code).await? {
+                code_to_id.insert(code.clone(), existing.category_id);
+                existing_count += 1;
+                mappings.push(CategoryImportMapping {
+                    code,
+                    category_id: existing.category_id,
+                    status: CategoryImportStatus::Existing,
+                });
+                continue;
+            }
+
+            let parent_id = match &item.parent_code {
+                None => None,
+                Some(parent_code) => match code_to_id.get(parent_code) {
+                    Some(id) => Some(*id),
+                    None => {
+                        conflicts.push(CategoryImportConflict {
+                            code,
+                            reason: format!("Parent code '{}' could not be imported", parent_code),
+                        });
+                        continue;
+                    },
+                },
+            };
+
+            match self
+                .create_category(tenant_id, item.into_create_request(parent_id))
+                .await
+            {
+                Ok(created) => {
+                    code_to_id.insert(code.clone(), created.category_id);
+                    created_count += 1;
+                    mappings.push(CategoryImportMapping {
+                        code,
+                        category_id: created.category_id,
+                        status: CategoryImportStatus::Created,
+                    });
+                },
+                Err(
+                    e @ (AppError::Conflict(_)
+                    | AppError::ValidationError(_)
+                    | AppError::NotFound(_)),
+                ) => {
+                    conflicts.push(CategoryImportConflict {
+                        code,
+                        reason: e.to_string(),
+                    });
+                },
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(CategoryImportResponse {
+            created_count,
+            existing_count,
+            mappings,
+            conflicts,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use async_trait::async_trait;
+    use inventory_service_core::domains::inventory::product::Product;
     use inventory_service_core::dto::category::{CategoryCreateRequest, MoveToCategoryRequest};
+    use inventory_service_core::services::ProductCache;
     use std::sync::Mutex;
+    use std::time::Duration;
 
     // Mock repository for testing
     #[derive(Clone)]
@@ -628,12 +982,18 @@ mod tests {
             Ok(true)
         }
 
+        async fn restore(&self, tenant_id: Uuid, category_id: Uuid) -> Result<Category> {
+            self.find_by_id(tenant_id, category_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Category not found".to_string()))
+        }
+
         async fn list(
             &self,
             _tenant_id: Uuid,
             _query: &CategoryListQuery,
-        ) -> Result<(Vec<Category>, i64)> {
-            Ok((self.categories.lock().unwrap().clone(), 0))
+        ) -> Result<(Vec<Category>, Option<i64>)> {
+            Ok((self.categories.lock().unwrap().clone(), Some(0)))
         }
 
         async fn get_root_categories(&self, _tenant_id: Uuid) -> Result<Vec<Category>> {
@@ -709,6 +1069,10 @@ mod tests {
             Ok(1)
         }
 
+        async fn recount_all_product_counts(&self, _tenant_id: Uuid) -> Result<u64> {
+            Ok(0)
+        }
+
         async fn move_products_to_category(
             &self,
             _tenant_id: Uuid,
@@ -718,6 +1082,21 @@ mod tests {
             Ok(1)
         }
 
+        async fn move_category(
+            &self,
+            _tenant_id: Uuid,
+            category_id: Uuid,
+            new_parent_id: Option<Uuid>,
+        ) -> Result<Category> {
+            let mut categories = self.categories.lock().unwrap();
+            let category = categories
+                .iter_mut()
+                .find(|c| c.category_id == category_id)
+                .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+            category.parent_category_id = new_parent_id;
+            Ok(category.clone())
+        }
+
         async fn get_products_in_tree(
             &self,
             _tenant_id: Uuid,
@@ -866,6 +1245,56 @@ mod tests {
         assert_eq!(result.unwrap().affected_count, 1);
     }
 
+    /// Product cache that records which products were evicted
+    #[derive(Default)]
+    struct EvictionRecorder {
+        evicted: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl ProductCache for EvictionRecorder {
+        async fn get_product(
+            &self,
+            _tenant_id: &Uuid,
+            _product_id: &Uuid,
+        ) -> Result<Option<Product>> {
+            Ok(None)
+        }
+
+        async fn set_product(
+            &self,
+            _tenant_id: &Uuid,
+            _product: &Product,
+            _ttl: Option<Duration>,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn invalidate_product(&self, _tenant_id: &Uuid, product_id: &Uuid) -> Result<()> {
+            self.evicted.lock().unwrap().push(*product_id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_move_products_to_category_evicts_cached_products() {
+        let cache = std::sync::Arc::new(EvictionRecorder::default());
+        let service = CategoryServiceImpl::new(MockCategoryRepository::new())
+            .with_product_cache(cache.clone());
+
+        let product_ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+        let request = MoveToCategoryRequest {
+            product_ids: product_ids.clone(),
+            category_id: Uuid::new_v4(),
+        };
+
+        service
+            .move_products_to_category(Uuid::new_v4(), request)
+            .await
+            .unwrap();
+        assert_eq!(*cache.evicted.lock().unwrap(), product_ids);
+    }
+
     #[tokio::test]
     async fn test_bulk_activate_categories_empty() {
         let repo = MockCategoryRepository::new();
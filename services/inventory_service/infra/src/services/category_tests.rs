@@ -7,7 +7,8 @@ use uuid::Uuid;
 
 use inventory_service_core::domains::category::{Category, CategoryNode};
 use inventory_service_core::dto::category::{
-    CategoryCreateRequest, CategoryListQuery, CategoryUpdateRequest, MoveToCategoryRequest,
+    CategoryCreateRequest, CategoryListQuery, CategoryPatchRequest, CategoryUpdateRequest,
+    MoveToCategoryRequest,
 };
 use inventory_service_core::repositories::category::CategoryRepository;
 use inventory_service_core::services::category::CategoryService;
@@ -29,8 +30,9 @@ mock! {
         async fn update(&self, category: Category) -> Result<Category>;
         async fn delete(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
         async fn hard_delete(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
+        async fn restore(&self, tenant_id: Uuid, category_id: Uuid) -> Result<Category>;
         async fn exists(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
-        async fn list(&self, tenant_id: Uuid, query: &CategoryListQuery) -> Result<(Vec<Category>, i64)>;
+        async fn list(&self, tenant_id: Uuid, query: &CategoryListQuery) -> Result<(Vec<Category>, Option<i64>)>;
         async fn get_root_categories(&self, tenant_id: Uuid) -> Result<Vec<Category>>;
         async fn get_tree(&self, tenant_id: Uuid, parent_id: Option<Uuid>) -> Result<Vec<CategoryNode>>;
         async fn get_children(&self, tenant_id: Uuid, parent_id: Uuid) -> Result<Vec<Category>>;
@@ -43,11 +45,13 @@ mock! {
         async fn has_children(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
         async fn has_products(&self, tenant_id: Uuid, category_id: Uuid) -> Result<bool>;
         async fn move_products_to_category(&self, tenant_id: Uuid, product_ids: Vec<Uuid>, category_id: Uuid) -> Result<i32>;
+        async fn move_category(&self, tenant_id: Uuid, category_id: Uuid, new_parent_id: Option<Uuid>) -> Result<Category>;
         async fn get_products_in_tree(&self, tenant_id: Uuid, category_id: Uuid) -> Result<Vec<Uuid>>;
         async fn bulk_activate(&self, tenant_id: Uuid, category_ids: Vec<Uuid>) -> Result<i32>;
         async fn bulk_deactivate(&self, tenant_id: Uuid, category_ids: Vec<Uuid>) -> Result<i32>;
         async fn bulk_delete(&self, tenant_id: Uuid, category_ids: Vec<Uuid>) -> Result<i32>;
         async fn update_product_counts(&self, tenant_id: Uuid, category_id: Uuid) -> Result<i32>;
+        async fn recount_all_product_counts(&self, tenant_id: Uuid) -> Result<u64>;
     }
 }
 #[cfg(test)]
@@ -442,4 +446,61 @@ mod tests {
         assert_eq!(categories.len(), 1);
         assert_eq!(categories[0].name, "Test Category");
     }
+
+    /// Repository mock for patching `existing`: identifiers are free and the
+    /// saved category is returned as-is
+    fn patch_repo(existing: Category) -> MockCategoryRepositoryImpl {
+        let mut mock_repo = MockCategoryRepositoryImpl::new();
+        mock_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(existing.clone())));
+        mock_repo.expect_find_by_code().returning(|_, _| Ok(None));
+        mock_repo.expect_find_by_slug().returning(|_, _| Ok(None));
+        mock_repo.expect_update().returning(|category| Ok(category));
+        mock_repo
+    }
+
+    #[tokio::test]
+    async fn test_patch_category_name_only_keeps_description_and_parent() {
+        let parent_id = Uuid::new_v4();
+        let existing = Category {
+            parent_category_id: Some(parent_id),
+            ..create_test_category()
+        };
+        let service = CategoryServiceImpl::new(patch_repo(existing));
+        let request: CategoryPatchRequest =
+            serde_json::from_value(serde_json::json!({ "name": "Renamed" })).unwrap();
+
+        let category = service
+            .patch_category(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await
+            .unwrap();
+        assert_eq!(category.name, "Renamed");
+        assert_eq!(category.slug.as_deref(), Some("renamed"));
+        assert_eq!(category.description.as_deref(), Some("Test description"));
+        assert_eq!(category.parent_category_id, Some(parent_id));
+    }
+
+    #[tokio::test]
+    async fn test_patch_category_null_clears_fields() {
+        let existing = Category {
+            parent_category_id: Some(Uuid::new_v4()),
+            ..create_test_category()
+        };
+        let service = CategoryServiceImpl::new(patch_repo(existing));
+        let request: CategoryPatchRequest = serde_json::from_value(serde_json::json!({
+            "description": null,
+            "parentCategoryId": null
+        }))
+        .unwrap();
+
+        let category = service
+            .patch_category(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await
+            .unwrap();
+        assert_eq!(category.name, "Test Category");
+        assert_eq!(category.description, None);
+        assert_eq!(category.parent_category_id, None);
+        assert_eq!(category.code.as_deref(), Some("TEST"));
+    }
 }
@@ -8,18 +8,20 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::{PgPool, Row};
+use sqlx::{PgConnection, PgPool, Row};
 use std::ops::DerefMut;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use inventory_service_core::dto::cycle_count::{
-    calculate_summary, CountType, CreateCycleCountRequest, CycleCountLine, CycleCountLineStatus,
-    CycleCountListQuery, CycleCountListResponse, CycleCountResponse, CycleCountSession,
-    CycleCountStatus, CycleCountWithLinesResponse, GenerateLinesRequest, LineAdjustment,
-    ReconcileRequest, ReconcileResponse, SkipLinesRequest, SubmitCountsRequest,
+    calculate_summary, select_line_products, validate_line_selection_strategy, CountType,
+    CreateCycleCountRequest, CycleCountLine, CycleCountLineStatus, CycleCountListQuery,
+    CycleCountListResponse, CycleCountResponse, CycleCountSession, CycleCountStatus,
+    CycleCountWithLinesResponse, GenerateLinesRequest, LineAdjustment, LineCandidate,
+    LineSelectionStrategy, ReconcileRequest, ReconcileResponse, SkipLinesRequest,
+    SubmitCountsRequest,
 };
-use inventory_service_core::models::CreateStockMoveRequest;
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
 use inventory_service_core::services::cycle_count::CycleCountingService;
 use shared_error::AppError;
 
@@ -235,6 +237,68 @@ impl PgCycleCountingService {
         })
     }
 
+    /// Load the stocked products of a warehouse that lines can be generated for
+    ///
+    /// A product's last count is its latest counted line in any session of the
+    /// same warehouse.
+    async fn load_line_candidates(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        warehouse_id: Uuid,
+        product_id: Option<Uuid>,
+        zone_id: Option<Uuid>,
+    ) -> Result<Vec<LineCandidate>, AppError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                il.product_id,
+                (SUM(il.available_quantity) * COALESCE(MAX(p.cost_price), 0))::BIGINT
+                    AS stock_value,
+                (
+                    SELECT MAX(l.counted_at)
+                    FROM stock_take_lines l
+                    JOIN stock_takes s
+                      ON s.tenant_id = l.tenant_id AND s.stock_take_id = l.stock_take_id
+                    WHERE l.tenant_id = $1
+                      AND l.product_id = il.product_id
+                      AND l.line_status = 'counted'
+                      AND l.deleted_at IS NULL
+                      AND s.warehouse_id = $2
+                      AND s.deleted_at IS NULL
+                ) AS last_counted_at,
+                random() AS sample_key
+            FROM inventory_levels il
+            JOIN products p ON p.tenant_id = il.tenant_id AND p.product_id = il.product_id
+            LEFT JOIN warehouse_locations wl
+              ON wl.tenant_id = il.tenant_id AND wl.location_id = il.location_id
+            WHERE il.tenant_id = $1
+              AND il.warehouse_id = $2
+              AND il.deleted_at IS NULL
+              AND il.available_quantity > 0
+              AND ($3::uuid IS NULL OR il.product_id = $3)
+              AND ($4::uuid IS NULL OR wl.zone_id = $4)
+            GROUP BY il.product_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(warehouse_id)
+        .bind(product_id)
+        .bind(zone_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to load line candidates: {}", e)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| LineCandidate {
+                product_id: row.get("product_id"),
+                stock_value: row.get("stock_value"),
+                last_counted_at: row.get("last_counted_at"),
+                sample_key: row.get("sample_key"),
+            })
+            .collect())
+    }
+
     /// Update session status
     async fn update_session_status(
         &self,
@@ -510,6 +574,9 @@ impl CycleCountingService for PgCycleCountingService {
             )));
         }
 
+        validate_line_selection_strategy(&request.strategy)
+            .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
         // Start transaction
         let mut tx =
             self.pool.begin().await.map_err(|e| {
@@ -531,6 +598,20 @@ impl CycleCountingService for PgCycleCountingService {
             .map_err(|e| AppError::DatabaseError(format!("Failed to delete lines: {}", e)))?;
         }
 
+        let zone_id = match request.strategy {
+            LineSelectionStrategy::Zone { zone_id } => Some(zone_id),
+            _ => None,
+        };
+        let candidates = Self::load_line_candidates(
+            tx.deref_mut(),
+            tenant_id,
+            session.warehouse_id,
+            request.product_id,
+            zone_id,
+        )
+        .await?;
+        let product_ids = select_line_products(&request.strategy, &candidates, Utc::now());
+
         // Generate lines from inventory levels as of the session's as_of timestamp
         // For MVP, we use current inventory levels (snapshot at as_of would require historical tracking)
         let location = session.location_id.unwrap_or(session.warehouse_id);
@@ -554,7 +635,7 @@ impl CycleCountingService for PgCycleCountingService {
               AND il.warehouse_id = $4
               AND il.deleted_at IS NULL
               AND il.available_quantity > 0
-              AND ($5::uuid IS NULL OR il.product_id = $5)
+              AND il.product_id = ANY($5)
             ON CONFLICT DO NOTHING
             "#,
         )
@@ -562,7 +643,7 @@ impl CycleCountingService for PgCycleCountingService {
         .bind(cycle_count_id)
         .bind(location)
         .bind(session.warehouse_id)
-        .bind(request.product_id)
+        .bind(&product_ids)
         .execute(tx.deref_mut())
         .await
         .map_err(|e| AppError::DatabaseError(format!("Failed to generate lines: {}", e)))?;
@@ -846,25 +927,21 @@ impl CycleCountingService for PgCycleCountingService {
             // Create stock move for the adjustment
             let idempotency_key = format!("cc-{}-line-{}", cycle_count_id, line.line_id);
             let location = line.location_id;
-            let stock_move = CreateStockMoveRequest {
-                product_id: line.product_id,
-                source_location_id: Some(location),
-                destination_location_id: Some(location),
-                move_type: "adjustment".to_string(),
-                quantity: difference,
-                unit_cost: None,
-                reference_type: "cycle_count".to_string(),
-                reference_id: cycle_count_id,
+            let stock_move = MoveIntent::new(
+                MoveSourceType::CycleCount,
+                cycle_count_id,
+                "cycle_count_variance",
+                line.product_id,
+                difference,
                 idempotency_key,
-                move_reason: Some(format!("Cycle count {} adjustment", session.session_number)),
-                lot_serial_id: line.lot_id.or(line.serial_id),
-                batch_info: None,
-                metadata: None,
-            };
+            )
+            .with_locations(Some(location), Some(location))
+            .with_lot_serial(line.lot_id.or(line.serial_id))
+            .with_note(format!("Cycle count {} adjustment", session.session_number));
 
             let (move_id, new_tx) = self
                 .stock_move_repo
-                .create_with_tx(tx, stock_move, tenant_id)
+                .record_with_tx(tx, &stock_move, tenant_id)
                 .await?;
             tx = new_tx;
             moves_created += 1;
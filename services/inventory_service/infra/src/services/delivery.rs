@@ -1,9 +1,13 @@
 //! Delivery service implementation
 //!
 //! This module contains the business logic implementation for Delivery Order operations.
+//!
+//! Stock flow:
+//! - pick: reserves picked quantities (available -> reserved)
+//! - pack: status transition only
+//! - ship: consumes the reservation and records outbound stock moves; a delivery may be
+//!   shipped in several parcels, each recorded as a shipment
 
-// Temporarily disabled delivery service to focus on reconciliation testing
-/*
 use async_trait::async_trait;
 use chrono::Utc;
 use std::sync::Arc;
@@ -11,31 +15,34 @@ use uuid::Uuid;
 
 use inventory_service_core::dto::delivery::{
     PackItemsRequest, PackItemsResponse, PickItemsRequest, PickItemsResponse, ShipItemsRequest,
-    ShipItemsResponse,
+    ShipItemsResponse, ShipmentRemainingLine,
 };
-use inventory_service_core::models::{CreateStockMoveRequest, DeliveryOrderStatus};
-use inventory_service_core::repositories::{
-    DeliveryOrderItemRepository, DeliveryOrderRepository, InventoryLevelRepository,
-    StockMoveRepository,
+use inventory_service_core::models::{
+    DeliveryOrderStatus, DeliveryShipment, DeliveryShipmentItem, MoveIntent, MoveSourceType,
 };
 use inventory_service_core::services::delivery::DeliveryService;
 use shared_error::AppError;
 
+use crate::repositories::delivery_order::{
+    PgDeliveryOrderItemRepository, PgDeliveryOrderRepository,
+};
+use crate::repositories::stock::{PgInventoryLevelRepository, PgStockMoveRepository};
+
 /// PostgreSQL implementation of the delivery service
 pub struct DeliveryServiceImpl {
-    delivery_repo: Arc<crate::repositories::delivery_order::PgDeliveryOrderRepository>,
-    delivery_item_repo: Arc<crate::repositories::delivery_order::PgDeliveryOrderItemRepository>,
-    stock_move_repo: Arc<crate::repositories::stock::PgStockMoveRepository>,
-    inventory_level_repo: Arc<crate::repositories::stock::PgInventoryLevelRepository>,
+    delivery_repo: Arc<PgDeliveryOrderRepository>,
+    delivery_item_repo: Arc<PgDeliveryOrderItemRepository>,
+    stock_move_repo: Arc<PgStockMoveRepository>,
+    inventory_level_repo: Arc<PgInventoryLevelRepository>,
 }
 
 impl DeliveryServiceImpl {
     /// Create a new delivery service with the given repositories
     pub fn new(
-        delivery_repo: Arc<crate::repositories::delivery_order::PgDeliveryOrderRepository>,
-        delivery_item_repo: Arc<crate::repositories::delivery_order::PgDeliveryOrderItemRepository>,
-        stock_move_repo: Arc<crate::repositories::stock::PgStockMoveRepository>,
-        inventory_level_repo: Arc<crate::repositories::stock::PgInventoryLevelRepository>,
+        delivery_repo: Arc<PgDeliveryOrderRepository>,
+        delivery_item_repo: Arc<PgDeliveryOrderItemRepository>,
+        stock_move_repo: Arc<PgStockMoveRepository>,
+        inventory_level_repo: Arc<PgInventoryLevelRepository>,
     ) -> Self {
         Self {
             delivery_repo,
@@ -74,10 +81,12 @@ impl DeliveryService for DeliveryServiceImpl {
                 AppError::NotFound(format!("Delivery order {} not found", delivery_id))
             })?;
 
-        // Check if the delivery order is in a valid state for picking
-        if delivery_order.status != DeliveryOrderStatus::Confirmed {
+        // Picking may continue on a partially picked order
+        if delivery_order.status != DeliveryOrderStatus::Confirmed
+            && delivery_order.status != DeliveryOrderStatus::PartiallyPicked
+        {
             return Err(AppError::ValidationError(format!(
-                "Cannot pick items for delivery order with status '{}'. Only 'Confirmed' orders can be picked.",
+                "Cannot pick items for delivery order with status '{}'. Only 'confirmed' or 'partially_picked' orders can be picked.",
                 delivery_order.status
             )));
         }
@@ -135,6 +144,18 @@ impl DeliveryService for DeliveryServiceImpl {
                 )));
             }
 
+            // Reserve the picked quantity so it can no longer be sold or picked elsewhere
+            tx = self
+                .inventory_level_repo
+                .reserve_with_tx(
+                    tx,
+                    tenant_id,
+                    delivery_order.warehouse_id,
+                    delivery_item.product_id,
+                    pick_item.picked_quantity,
+                )
+                .await?;
+
             // Update the picked quantity
             delivery_item.picked_quantity += pick_item.picked_quantity;
             delivery_item.updated_at = Utc::now();
@@ -172,7 +193,9 @@ impl DeliveryService for DeliveryServiceImpl {
             .await?;
 
         // Commit the transaction
-        tx.commit().await?;
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
 
         Ok(PickItemsResponse {
             delivery_id,
@@ -204,7 +227,7 @@ impl DeliveryService for DeliveryServiceImpl {
         // Check if the delivery order is in a valid state for packing
         if delivery_order.status != DeliveryOrderStatus::Picked {
             return Err(AppError::ValidationError(format!(
-                "Cannot pack items for delivery order with status '{}'. Only 'Picked' orders can be packed.",
+                "Cannot pack items for delivery order with status '{}'. Only 'picked' orders can be packed.",
                 delivery_order.status
             )));
         }
@@ -242,7 +265,9 @@ impl DeliveryService for DeliveryServiceImpl {
             .await?;
 
         // Commit the transaction
-        tx.commit().await?;
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
 
         Ok(PackItemsResponse {
             delivery_id,
@@ -270,10 +295,12 @@ impl DeliveryService for DeliveryServiceImpl {
                 AppError::NotFound(format!("Delivery order {} not found", delivery_id))
             })?;
 
-        // Check if the delivery order is in a valid state for shipping
-        if delivery_order.status != DeliveryOrderStatus::Packed {
+        // Shipping may continue on a partially shipped order
+        if delivery_order.status != DeliveryOrderStatus::Packed
+            && delivery_order.status != DeliveryOrderStatus::PartiallyShipped
+        {
             return Err(AppError::ValidationError(format!(
-                "Cannot ship items for delivery order with status '{}'. Only 'Packed' orders can be shipped.",
+                "Cannot ship items for delivery order with status '{}'. Only 'packed' or 'partially_shipped' orders can be shipped.",
                 delivery_order.status
             )));
         }
@@ -296,97 +323,198 @@ impl DeliveryService for DeliveryServiceImpl {
         }
 
         // Get all delivery items
-        let delivery_items = self
+        let mut delivery_items = self
             .delivery_item_repo
             .find_by_delivery_id_with_tx(&mut tx, tenant_id, delivery_id)
             .await?;
 
+        // Resolve the quantity to ship per line (index into delivery_items, quantity)
+        let mut ship_lines: Vec<(usize, i64)> = Vec::new();
+        match request.items.as_deref() {
+            Some(requested) if !requested.is_empty() => {
+                for ship_item in requested {
+                    let index = delivery_items
+                        .iter()
+                        .position(|item| item.delivery_item_id == ship_item.delivery_item_id)
+                        .ok_or_else(|| {
+                            AppError::ValidationError(format!(
+                                "Delivery item {} does not belong to delivery order {}",
+                                ship_item.delivery_item_id, delivery_id
+                            ))
+                        })?;
+
+                    if ship_lines.iter().any(|(i, _)| *i == index) {
+                        return Err(AppError::ValidationError(format!(
+                            "Delivery item {} is listed more than once",
+                            ship_item.delivery_item_id
+                        )));
+                    }
+
+                    if ship_item.quantity <= 0 {
+                        return Err(AppError::ValidationError(format!(
+                            "Ship quantity must be positive for item {}",
+                            ship_item.delivery_item_id
+                        )));
+                    }
+
+                    // Never ship more than has been picked
+                    let item = &delivery_items[index];
+                    let shippable = item.picked_quantity - item.delivered_quantity;
+                    if ship_item.quantity > shippable {
+                        return Err(AppError::ValidationError(format!(
+                            "Cannot ship {} units for item {}. Only {} picked units remaining to ship.",
+                            ship_item.quantity, ship_item.delivery_item_id, shippable
+                        )));
+                    }
+
+                    ship_lines.push((index, ship_item.quantity));
+                }
+            },
+            _ => {
+                // No explicit lines: ship everything picked but not yet delivered
+                for (index, item) in delivery_items.iter().enumerate() {
+                    let shippable = item.picked_quantity - item.delivered_quantity;
+                    if shippable > 0 {
+                        ship_lines.push((index, shippable));
+                    }
+                }
+            },
+        }
+
+        if ship_lines.is_empty() {
+            return Err(AppError::ValidationError(
+                "Nothing to ship: all picked quantities have already been shipped".to_string(),
+            ));
+        }
+
         let shipped_at = Utc::now();
+        let shipment_id = Uuid::now_v7();
+
+        // Record the shipment (one per ship call)
+        let shipment = DeliveryShipment {
+            shipment_id,
+            tenant_id,
+            delivery_id,
+            tracking_number: request.tracking_number.clone(),
+            carrier: request.carrier.clone(),
+            shipping_cost: request.shipping_cost,
+            notes: request.notes.clone(),
+            shipped_by: user_id,
+            shipped_at,
+            created_at: shipped_at,
+        };
+        self.delivery_repo
+            .create_shipment_with_tx(&mut tx, &shipment)
+            .await?;
+
         let mut total_cogs = 0i64;
         let mut stock_moves_created = 0;
 
-        // Process each delivery item
-        for item in &delivery_items {
-            // Skip items that weren't picked (shouldn't happen in Packed status, but safety check)
-            if item.picked_quantity <= 0 {
-                continue;
-            }
-
-            let picked_qty = item.picked_quantity;
+        // Process each shipped line
+        for (index, ship_qty) in ship_lines {
+            let item = &mut delivery_items[index];
 
             // Create stock move (warehouse -> customer virtual location)
-            let idempotency_key = format!("do-{}-item-{}", delivery_id, item.delivery_item_id);
-
-            // Validate inventory level exists (needed regardless for idempotency)
-            self.inventory_level_repo
-                .find_by_product(tenant_id, delivery_order.warehouse_id, item.product_id)
-                .await?
-                .ok_or_else(|| {
-                    AppError::ValidationError(format!(
-                        "No inventory level found for product {}",
-                        item.product_id
-                    ))
-                })?;
+            let idempotency_key = format!(
+                "do-{}-shipment-{}-item-{}",
+                delivery_id, shipment_id, item.delivery_item_id
+            );
 
             // For deliveries, we use the item's unit_price as COGS
             // In a real system, this might come from inventory valuation
-            let unit_cost = Some(item.unit_price);
-            let total_cost = unit_cost.flatten().map(|cost| cost * picked_qty);
+            let unit_cost = item.unit_price;
+
+            // Warehouse-level stock to customer (virtual location); warehouse recorded in metadata
+            let stock_move = MoveIntent::new(
+                MoveSourceType::Delivery,
+                delivery_id,
+                "sales_delivery",
+                item.product_id,
+                -ship_qty, // Negative for outgoing
+                idempotency_key,
+            )
+            .with_unit_cost(unit_cost)
+            // TODO: Set lot_serial_id if lot-tracked product
+            .with_note(format!("Delivery order {}", delivery_order.delivery_number))
+            .with_metadata(Some(serde_json::json!({
+                "delivery_item_id": item.delivery_item_id,
+                "shipment_id": shipment_id,
+                "warehouse_id": delivery_order.warehouse_id,
+                "customer_id": delivery_order.customer_id
+            })));
+
+            // Each shipment gets its own moves: the shipment id keeps the key unique
+            let (_, new_tx) = self
+                .stock_move_repo
+                .record_with_tx(tx, &stock_move, tenant_id)
+                .await?;
+            tx = new_tx;
+            stock_moves_created += 1;
+
+            // Consume the reservation taken at pick time
+            tx = self
+                .inventory_level_repo
+                .consume_reserved_with_tx(
+                    tx,
+                    tenant_id,
+                    delivery_order.warehouse_id,
+                    item.product_id,
+                    ship_qty,
+                )
+                .await?;
+
+            // Accumulate COGS
+            if let Some(cost) = unit_cost {
+                let overflow =
+                    || AppError::ValidationError("Shipment COGS calculation overflow".to_string());
+                let line_cogs = cost.checked_mul(ship_qty).ok_or_else(overflow)?;
+                total_cogs = total_cogs.checked_add(line_cogs).ok_or_else(overflow)?;
+            }
 
-            let stock_move = CreateStockMoveRequest {
+            // Record the shipment line
+            let shipment_item = DeliveryShipmentItem {
+                shipment_item_id: Uuid::now_v7(),
+                tenant_id,
+                shipment_id,
+                delivery_item_id: item.delivery_item_id,
                 product_id: item.product_id,
-                source_location_id: Some(delivery_order.warehouse_id), // From warehouse
-                destination_location_id: None, // To customer (virtual location)
-                move_type: "delivery".to_string(),
-                quantity: -picked_qty, // Negative for outgoing
-                unit_cost: unit_cost.flatten(),
-                reference_type: "do".to_string(),
-                reference_id: delivery_id,
-                idempotency_key: idempotency_key.clone(),
-                move_reason: Some(format!("Delivery order {}", delivery_order.delivery_number)),
-                lot_serial_id: None, // TODO: Set if lot-tracked product
-                batch_info: None,
-                metadata: Some(serde_json::json!({
-                    "delivery_item_id": item.delivery_item_id,
-                    "customer_id": delivery_order.customer_id
-                })),
+                quantity: ship_qty,
+                created_at: shipped_at,
             };
-
-            // Create stock move idempotently within transaction
-            // Returns true if created, false if already existed (no-op)
-            let created = self
-                .stock_move_repo
-                .create_idempotent_with_tx(&mut tx, &stock_move, tenant_id)
+            self.delivery_repo
+                .create_shipment_item_with_tx(&mut tx, &shipment_item)
                 .await?;
 
-            if created {
-                stock_moves_created += 1;
-
-                // Update inventory level (decrement available stock)
-                self.inventory_level_repo
-                    .update_available_quantity_with_tx(
-                        &mut tx,
-                        tenant_id,
-                        delivery_order.warehouse_id,
-                        item.product_id,
-                        -picked_qty,
-                    )
-                    .await?;
-
-                // Accumulate COGS
-                if let Some(cost) = total_cost {
-                    total_cogs += cost;
-                }
-            }
+            // Track the cumulative shipped quantity on the line
+            item.delivered_quantity += ship_qty;
+            item.updated_at = shipped_at;
+            self.delivery_item_repo
+                .update_with_tx(&mut tx, item)
+                .await?;
         }
 
-        // Update the delivery order status to Shipped
-        delivery_order.status = DeliveryOrderStatus::Shipped;
+        let remaining: Vec<ShipmentRemainingLine> = delivery_items
+            .iter()
+            .map(|item| ShipmentRemainingLine {
+                delivery_item_id: item.delivery_item_id,
+                ordered_quantity: item.ordered_quantity,
+                shipped_quantity: item.delivered_quantity,
+                remaining_quantity: (item.ordered_quantity - item.delivered_quantity).max(0),
+            })
+            .collect();
+        let all_fully_shipped = remaining.iter().all(|line| line.remaining_quantity == 0);
+
+        // Update the delivery order status based on full shipment
+        delivery_order.status = if all_fully_shipped {
+            DeliveryOrderStatus::Shipped
+        } else {
+            DeliveryOrderStatus::PartiallyShipped
+        };
         delivery_order.actual_ship_date = Some(shipped_at);
         delivery_order.updated_by = Some(user_id);
         delivery_order.updated_at = shipped_at;
 
-        // Update shipping information if provided
+        // Keep the latest shipment's details on the order
         if let Some(tracking_number) = request.tracking_number {
             delivery_order.tracking_number = Some(tracking_number);
         }
@@ -394,7 +522,8 @@ impl DeliveryService for DeliveryServiceImpl {
             delivery_order.carrier = Some(carrier);
         }
         if let Some(shipping_cost) = request.shipping_cost {
-            delivery_order.shipping_cost = Some(shipping_cost);
+            delivery_order.shipping_cost =
+                Some(delivery_order.shipping_cost.unwrap_or(0) + shipping_cost);
         }
         if let Some(notes) = request.notes {
             delivery_order.notes = Some(notes);
@@ -409,15 +538,18 @@ impl DeliveryService for DeliveryServiceImpl {
         // This would typically be done via an event bus/message queue
 
         // Commit the transaction
-        tx.commit().await?;
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
 
         Ok(ShipItemsResponse {
             delivery_id,
+            shipment_id,
             status: delivery_order.status.to_string(),
             shipped_at,
             stock_moves_created,
             total_cogs,
+            remaining,
         })
     }
 }
-*/
@@ -25,6 +25,26 @@ impl RedisDistributedLockService {
         Ok(Self { redis_client })
     }
 
+    /// Check that Redis is reachable and answering commands
+    pub async fn ping(&self) -> Result<()> {
+        let mut conn = self
+            .redis_client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| {
+                shared_error::AppError::InternalError(format!("Redis connection error: {}", e))
+            })?;
+
+        redis::cmd("PING")
+            .query_async::<String>(&mut conn)
+            .await
+            .map_err(|e| {
+                shared_error::AppError::InternalError(format!("Redis PING error: {}", e))
+            })?;
+
+        Ok(())
+    }
+
     /// Generate lock key
     fn lock_key(&self, tenant_id: Uuid, resource_type: &str, resource_id: &str) -> String {
         format!("lock:{}:{}:{}", tenant_id, resource_type, resource_id)
@@ -0,0 +1,124 @@
+//! Feature flag service implementation
+//!
+//! Resolves per-tenant feature flags from `tenant_settings`, caching each
+//! lookup in Redis. Cache failures are logged and the database is used instead.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use inventory_service_core::domains::inventory::dto::feature_flag_dto::{
+    FeatureFlagDto, FeatureFlagListResponse,
+};
+use inventory_service_core::domains::inventory::feature_flag::{FeatureFlag, TenantFeatureFlag};
+use inventory_service_core::repositories::TenantSettingsRepository;
+use inventory_service_core::services::{FeatureFlagCache, FeatureFlagService};
+use inventory_service_core::Result;
+
+/// How long a resolved flag value stays cached
+const FEATURE_FLAG_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Feature flag service backed by the tenant settings repository
+pub struct FeatureFlagServiceImpl {
+    repository: Arc<dyn TenantSettingsRepository>,
+    cache: Option<Arc<dyn FeatureFlagCache>>,
+}
+
+impl FeatureFlagServiceImpl {
+    /// Create new service instance; pass `None` to disable caching
+    pub fn new(
+        repository: Arc<dyn TenantSettingsRepository>,
+        cache: Option<Arc<dyn FeatureFlagCache>>,
+    ) -> Self {
+        Self { repository, cache }
+    }
+
+    fn to_dto(flag: FeatureFlag, stored: Option<TenantFeatureFlag>) -> FeatureFlagDto {
+        match stored {
+            Some(setting) => FeatureFlagDto {
+                flag,
+                enabled: setting.enabled,
+                overridden: true,
+                updated_at: Some(setting.updated_at),
+            },
+            None => FeatureFlagDto {
+                flag,
+                enabled: flag.default_enabled(),
+                overridden: false,
+                updated_at: None,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl FeatureFlagService for FeatureFlagServiceImpl {
+    async fn is_enabled(&self, tenant_id: Uuid, flag: FeatureFlag) -> Result<bool> {
+        if let Some(cache) = &self.cache {
+            match cache.get_feature_flag(&tenant_id, flag).await {
+                Ok(Some(enabled)) => return Ok(enabled),
+                Ok(None) => {},
+                Err(e) => tracing::warn!("Feature flag cache read failed for {}: {}", flag, e),
+            }
+        }
+
+        let enabled = self
+            .repository
+            .find_flag(tenant_id, flag)
+            .await?
+            .map(|setting| setting.enabled)
+            .unwrap_or_else(|| flag.default_enabled());
+
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache
+                .set_feature_flag(&tenant_id, flag, enabled, Some(FEATURE_FLAG_CACHE_TTL))
+                .await
+            {
+                tracing::warn!("Feature flag cache write failed for {}: {}", flag, e);
+            }
+        }
+
+        Ok(enabled)
+    }
+
+    async fn list_flags(&self, tenant_id: Uuid) -> Result<FeatureFlagListResponse> {
+        let stored = self.repository.list_flags(tenant_id).await?;
+
+        let flags = FeatureFlag::ALL
+            .iter()
+            .map(|&flag| {
+                let setting = stored.iter().find(|s| s.flag == flag).cloned();
+                Self::to_dto(flag, setting)
+            })
+            .collect();
+
+        Ok(FeatureFlagListResponse { flags })
+    }
+
+    async fn get_flag(&self, tenant_id: Uuid, flag: FeatureFlag) -> Result<FeatureFlagDto> {
+        let stored = self.repository.find_flag(tenant_id, flag).await?;
+        Ok(Self::to_dto(flag, stored))
+    }
+
+    async fn set_flag(
+        &self,
+        tenant_id: Uuid,
+        flag: FeatureFlag,
+        enabled: bool,
+        user_id: Uuid,
+    ) -> Result<FeatureFlagDto> {
+        let stored = self
+            .repository
+            .upsert_flag(tenant_id, flag, enabled, Some(user_id))
+            .await?;
+
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache.invalidate_feature_flag(&tenant_id, flag).await {
+                tracing::warn!("Feature flag cache invalidation failed for {}: {}", flag, e);
+            }
+        }
+
+        Ok(Self::to_dto(flag, Some(stored)))
+    }
+}
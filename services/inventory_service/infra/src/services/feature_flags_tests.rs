@@ -0,0 +1,220 @@
+//! Unit tests for FeatureFlagServiceImpl
+//!
+//! Covers default-off flags, per-tenant overrides, and cache invalidation
+//! when a flag is updated.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use mockall::mock;
+use mockall::predicate::eq;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use uuid::Uuid;
+
+use inventory_service_core::domains::inventory::feature_flag::{FeatureFlag, TenantFeatureFlag};
+use inventory_service_core::repositories::TenantSettingsRepository;
+use inventory_service_core::services::{FeatureFlagCache, FeatureFlagService};
+use inventory_service_core::Result;
+use shared_error::AppError;
+
+use super::feature_flags::FeatureFlagServiceImpl;
+
+mock! {
+    pub TenantSettingsRepositoryImpl {}
+
+    #[async_trait::async_trait]
+    impl TenantSettingsRepository for TenantSettingsRepositoryImpl {
+        async fn find_flag(
+            &self,
+            tenant_id: Uuid,
+            flag: FeatureFlag,
+        ) -> Result<Option<TenantFeatureFlag>>;
+        async fn list_flags(&self, tenant_id: Uuid) -> Result<Vec<TenantFeatureFlag>>;
+        async fn upsert_flag(
+            &self,
+            tenant_id: Uuid,
+            flag: FeatureFlag,
+            enabled: bool,
+            updated_by: Option<Uuid>,
+        ) -> Result<TenantFeatureFlag>;
+    }
+}
+
+/// In-memory stand-in for the Redis feature flag cache
+#[derive(Default)]
+struct InMemoryFlagCache {
+    entries: Mutex<HashMap<(Uuid, FeatureFlag), bool>>,
+}
+
+impl InMemoryFlagCache {
+    fn contains(&self, tenant_id: Uuid, flag: FeatureFlag) -> bool {
+        self.entries
+            .lock()
+            .unwrap()
+            .contains_key(&(tenant_id, flag))
+    }
+}
+
+#[async_trait]
+impl FeatureFlagCache for InMemoryFlagCache {
+    async fn get_feature_flag(
+        &self,
+        tenant_id: &Uuid,
+        flag: FeatureFlag,
+    ) -> std::result::Result<Option<bool>, AppError> {
+        Ok(self
+            .entries
+            .lock()
+            .unwrap()
+            .get(&(*tenant_id, flag))
+            .copied())
+    }
+
+    async fn set_feature_flag(
+        &self,
+        tenant_id: &Uuid,
+        flag: FeatureFlag,
+        enabled: bool,
+        _ttl: Option<Duration>,
+    ) -> std::result::Result<(), AppError> {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert((*tenant_id, flag), enabled);
+        Ok(())
+    }
+
+    async fn invalidate_feature_flag(
+        &self,
+        tenant_id: &Uuid,
+        flag: FeatureFlag,
+    ) -> std::result::Result<(), AppError> {
+        self.entries.lock().unwrap().remove(&(*tenant_id, flag));
+        Ok(())
+    }
+}
+
+fn stored_flag(tenant_id: Uuid, flag: FeatureFlag, enabled: bool) -> TenantFeatureFlag {
+    TenantFeatureFlag {
+        tenant_id,
+        flag,
+        enabled,
+        updated_by: None,
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_flags_default_off_without_tenant_setting() {
+    let tenant_id = Uuid::now_v7();
+    let mut repo = MockTenantSettingsRepositoryImpl::new();
+    repo.expect_find_flag().returning(|_, _| Ok(None));
+    repo.expect_list_flags().returning(|_| Ok(vec![]));
+
+    let service = FeatureFlagServiceImpl::new(Arc::new(repo), None);
+
+    for flag in FeatureFlag::ALL {
+        assert!(!service.is_enabled(tenant_id, flag).await.unwrap());
+    }
+
+    let listed = service.list_flags(tenant_id).await.unwrap();
+    assert_eq!(listed.flags.len(), FeatureFlag::ALL.len());
+    assert!(listed.flags.iter().all(|f| !f.enabled && !f.overridden));
+}
+
+#[tokio::test]
+async fn test_per_tenant_override_does_not_leak() {
+    let tenant_a = Uuid::now_v7();
+    let tenant_b = Uuid::now_v7();
+    let mut repo = MockTenantSettingsRepositoryImpl::new();
+    repo.expect_find_flag()
+        .with(eq(tenant_a), eq(FeatureFlag::BlindCounts))
+        .returning(|tenant_id, flag| Ok(Some(stored_flag(tenant_id, flag, true))));
+    repo.expect_find_flag()
+        .with(eq(tenant_b), eq(FeatureFlag::BlindCounts))
+        .returning(|_, _| Ok(None));
+
+    let cache = Arc::new(InMemoryFlagCache::default());
+    let service = FeatureFlagServiceImpl::new(Arc::new(repo), Some(cache.clone()));
+
+    assert!(service
+        .is_enabled(tenant_a, FeatureFlag::BlindCounts)
+        .await
+        .unwrap());
+    assert!(!service
+        .is_enabled(tenant_b, FeatureFlag::BlindCounts)
+        .await
+        .unwrap());
+
+    // Both results are cached under their own tenant
+    assert!(cache.contains(tenant_a, FeatureFlag::BlindCounts));
+    assert!(cache.contains(tenant_b, FeatureFlag::BlindCounts));
+}
+
+#[tokio::test]
+async fn test_cached_value_served_without_database() {
+    let tenant_id = Uuid::now_v7();
+    let mut repo = MockTenantSettingsRepositoryImpl::new();
+    repo.expect_find_flag()
+        .times(1)
+        .returning(|tenant_id, flag| Ok(Some(stored_flag(tenant_id, flag, true))));
+
+    let cache = Arc::new(InMemoryFlagCache::default());
+    let service = FeatureFlagServiceImpl::new(Arc::new(repo), Some(cache));
+
+    assert!(service
+        .is_enabled(tenant_id, FeatureFlag::NegativeStock)
+        .await
+        .unwrap());
+    assert!(service
+        .is_enabled(tenant_id, FeatureFlag::NegativeStock)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_set_flag_invalidates_cache() {
+    let tenant_id = Uuid::now_v7();
+    let user_id = Uuid::now_v7();
+    let enabled = Arc::new(Mutex::new(false));
+
+    let mut repo = MockTenantSettingsRepositoryImpl::new();
+    let read_state = enabled.clone();
+    repo.expect_find_flag()
+        .times(2)
+        .returning(move |tenant_id, flag| {
+            Ok(Some(stored_flag(tenant_id, flag, *read_state.lock().unwrap())))
+        });
+    let write_state = enabled.clone();
+    repo.expect_upsert_flag()
+        .with(eq(tenant_id), eq(FeatureFlag::DeliveryEnabled), eq(true), eq(Some(user_id)))
+        .times(1)
+        .returning(move |tenant_id, flag, value, _| {
+            *write_state.lock().unwrap() = value;
+            Ok(stored_flag(tenant_id, flag, value))
+        });
+
+    let cache = Arc::new(InMemoryFlagCache::default());
+    let service = FeatureFlagServiceImpl::new(Arc::new(repo), Some(cache.clone()));
+
+    assert!(!service
+        .is_enabled(tenant_id, FeatureFlag::DeliveryEnabled)
+        .await
+        .unwrap());
+    assert!(cache.contains(tenant_id, FeatureFlag::DeliveryEnabled));
+
+    let updated = service
+        .set_flag(tenant_id, FeatureFlag::DeliveryEnabled, true, user_id)
+        .await
+        .unwrap();
+    assert!(updated.enabled);
+    assert!(updated.overridden);
+    assert!(!cache.contains(tenant_id, FeatureFlag::DeliveryEnabled));
+
+    // The next lookup goes back to the database and sees the new value
+    assert!(service
+        .is_enabled(tenant_id, FeatureFlag::DeliveryEnabled)
+        .await
+        .unwrap());
+}
@@ -2,6 +2,9 @@ use async_trait::async_trait;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use inventory_service_core::models::{
+    NewStockReservation, ReservationStatus, StockReservation, StockReservationStatus,
+};
 use inventory_service_core::repositories::InventoryRepository;
 use inventory_service_core::services::InventoryService;
 use shared_error::AppError;
@@ -25,7 +28,7 @@ impl InventoryService for InventoryServiceImpl {
         warehouse_id: Uuid,
         product_id: Uuid,
         quantity: i64,
-    ) -> Result<(), AppError> {
+    ) -> Result<ReservationStatus, AppError> {
         self.inventory_repo
             .reserve_stock(tenant_id, warehouse_id, product_id, quantity)
             .await
@@ -53,4 +56,79 @@ impl InventoryService for InventoryServiceImpl {
             .get_available_stock(tenant_id, warehouse_id, product_id)
             .await
     }
+
+    async fn queue_reservation(
+        &self,
+        tenant_id: Uuid,
+        reservation: NewStockReservation,
+    ) -> Result<StockReservation, AppError> {
+        if reservation.quantity <= 0 {
+            return Err(AppError::ValidationError(
+                "Quantity to reserve must be positive".to_string(),
+            ));
+        }
+        self.inventory_repo
+            .queue_reservation(tenant_id, &reservation)
+            .await
+    }
+
+    async fn allocate_scarce(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        available: i64,
+    ) -> Result<Vec<StockReservation>, AppError> {
+        if available < 0 {
+            return Err(AppError::ValidationError(
+                "Available quantity cannot be negative".to_string(),
+            ));
+        }
+        self.inventory_repo
+            .allocate_scarce(tenant_id, product_id, available)
+            .await
+    }
+
+    async fn release_source_reservations(
+        &self,
+        tenant_id: Uuid,
+        source_id: Uuid,
+    ) -> Result<Vec<StockReservation>, AppError> {
+        let claimed = self
+            .inventory_repo
+            .claim_source_reservations(tenant_id, source_id)
+            .await?;
+
+        let mut released = Vec::with_capacity(claimed.len());
+        let mut remaining = claimed.into_iter();
+        while let Some(mut reservation) = remaining.next() {
+            if reservation.allocated_quantity > 0 {
+                if let Err(e) = self
+                    .inventory_repo
+                    .release_stock(
+                        tenant_id,
+                        reservation.warehouse_id,
+                        reservation.product_id,
+                        reservation.allocated_quantity,
+                    )
+                    .await
+                {
+                    // Reopen what was not released so a redelivery can retry it
+                    for pending in std::iter::once(reservation).chain(remaining) {
+                        self.inventory_repo
+                            .restore_reservation_status(
+                                tenant_id,
+                                pending.reservation_id,
+                                pending.status,
+                            )
+                            .await?;
+                    }
+                    return Err(e);
+                }
+            }
+            reservation.status = StockReservationStatus::Released;
+            released.push(reservation);
+        }
+
+        Ok(released)
+    }
 }
@@ -0,0 +1,241 @@
+//! Inventory Snapshot Service Implementation
+//!
+//! Copies a tenant's inventory levels into a snapshot and restores them by
+//! writing one compensating adjustment move per level that has drifted.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use inventory_service_core::dto::inventory_snapshot::{
+    InventorySnapshotResponse, SnapshotRestoreResponse, SNAPSHOT_RESTORE_REASON,
+};
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
+use inventory_service_core::services::inventory_snapshot::InventorySnapshotService;
+use inventory_service_core::Result;
+use shared_error::AppError;
+
+use crate::repositories::stock::PgStockMoveRepository;
+
+/// A level whose available quantity differs from its snapshot value
+#[derive(sqlx::FromRow)]
+struct LevelDrift {
+    warehouse_id: Uuid,
+    location_id: Option<Uuid>,
+    product_id: Uuid,
+    /// Snapshot value minus current value
+    delta: i64,
+}
+
+/// PostgreSQL implementation of InventorySnapshotService
+pub struct PgInventorySnapshotService {
+    pool: Arc<PgPool>,
+}
+
+impl PgInventorySnapshotService {
+    /// Create a new snapshot service
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InventorySnapshotService for PgInventorySnapshotService {
+    async fn snapshot(&self, tenant_id: Uuid, user_id: Uuid) -> Result<InventorySnapshotResponse> {
+        let mut tx = self.pool.begin().await?;
+
+        let (snapshot_id, created_at): (Uuid, chrono::DateTime<Utc>) = sqlx::query_as(
+            "INSERT INTO inventory_snapshots (tenant_id, created_by)
+             VALUES ($1, $2)
+             RETURNING snapshot_id, created_at",
+        )
+        .bind(tenant_id)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let level_count = sqlx::query(
+            "INSERT INTO inventory_snapshot_levels (
+                 snapshot_id, tenant_id, warehouse_id, location_id, product_id,
+                 available_quantity, reserved_quantity
+             )
+             SELECT $1, tenant_id, warehouse_id, location_id, product_id,
+                    available_quantity, reserved_quantity
+             FROM inventory_levels
+             WHERE tenant_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(snapshot_id)
+        .bind(tenant_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        sqlx::query("UPDATE inventory_snapshots SET level_count = $1 WHERE snapshot_id = $2")
+            .bind(level_count as i64)
+            .bind(snapshot_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(InventorySnapshotResponse {
+            snapshot_id,
+            tenant_id,
+            level_count,
+            created_by: user_id,
+            created_at,
+        })
+    }
+
+    async fn restore(
+        &self,
+        tenant_id: Uuid,
+        snapshot_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<SnapshotRestoreResponse> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the snapshot so concurrent restores of it run one after the other
+        let exists: Option<Uuid> = sqlx::query_scalar(
+            "SELECT snapshot_id FROM inventory_snapshots
+             WHERE tenant_id = $1 AND snapshot_id = $2
+             FOR UPDATE",
+        )
+        .bind(tenant_id)
+        .bind(snapshot_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if exists.is_none() {
+            return Err(AppError::NotFound(format!(
+                "Inventory snapshot {} not found",
+                snapshot_id
+            )));
+        }
+
+        // Hold the tenant's levels still while the drift is computed and applied
+        sqlx::query(
+            "SELECT 1 FROM inventory_levels
+             WHERE tenant_id = $1 AND deleted_at IS NULL
+             FOR UPDATE",
+        )
+        .bind(tenant_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // Levels missing on either side count as zero. The nil UUID stands in for
+        // a warehouse-wide level so the full join can use plain equality.
+        let drifts: Vec<LevelDrift> = sqlx::query_as(
+            "WITH snap AS (
+                 SELECT warehouse_id, location_id, product_id, available_quantity
+                 FROM inventory_snapshot_levels
+                 WHERE tenant_id = $1 AND snapshot_id = $2
+             ),
+             live AS (
+                 SELECT warehouse_id, location_id, product_id, available_quantity
+                 FROM inventory_levels
+                 WHERE tenant_id = $1 AND deleted_at IS NULL
+             )
+             SELECT COALESCE(snap.warehouse_id, live.warehouse_id) AS warehouse_id,
+                    COALESCE(snap.location_id, live.location_id) AS location_id,
+                    COALESCE(snap.product_id, live.product_id) AS product_id,
+                    COALESCE(snap.available_quantity, 0)
+                        - COALESCE(live.available_quantity, 0) AS delta
+             FROM snap
+             FULL JOIN live
+               ON snap.warehouse_id = live.warehouse_id
+              AND snap.product_id = live.product_id
+              AND COALESCE(snap.location_id, '00000000-0000-0000-0000-000000000000'::uuid)
+                = COALESCE(live.location_id, '00000000-0000-0000-0000-000000000000'::uuid)
+             WHERE COALESCE(snap.available_quantity, 0)
+                   <> COALESCE(live.available_quantity, 0)
+             ORDER BY 1, 3, 2",
+        )
+        .bind(tenant_id)
+        .bind(snapshot_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for drift in &drifts {
+            let updated = sqlx::query(
+                "UPDATE inventory_levels
+                 SET available_quantity = available_quantity + $1,
+                     updated_at = NOW()
+                 WHERE tenant_id = $2
+                   AND warehouse_id = $3
+                   AND location_id IS NOT DISTINCT FROM $4
+                   AND product_id = $5
+                   AND deleted_at IS NULL
+                   AND available_quantity + $1 >= 0",
+            )
+            .bind(drift.delta)
+            .bind(tenant_id)
+            .bind(drift.warehouse_id)
+            .bind(drift.location_id)
+            .bind(drift.product_id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+            // The level was removed since the snapshot; bring it back
+            if updated == 0 {
+                sqlx::query(
+                    "INSERT INTO inventory_levels (
+                         tenant_id, warehouse_id, location_id, product_id,
+                         available_quantity, reserved_quantity
+                     )
+                     VALUES ($1, $2, $3, $4, $5, 0)",
+                )
+                .bind(tenant_id)
+                .bind(drift.warehouse_id)
+                .bind(drift.location_id)
+                .bind(drift.product_id)
+                .bind(drift.delta)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            // Levels are tracked per warehouse, so the warehouse/location are kept
+            // in the move metadata, as for manual adjustments
+            let metadata = serde_json::json!({
+                "warehouse_id": drift.warehouse_id,
+                "location_id": drift.location_id,
+                "snapshot_id": snapshot_id,
+                "restored_by": user_id,
+            });
+            let stock_move = MoveIntent::new(
+                MoveSourceType::Adjustment,
+                snapshot_id,
+                SNAPSHOT_RESTORE_REASON,
+                drift.product_id,
+                drift.delta,
+                format!("snapshot-restore-{}", Uuid::now_v7()),
+            )
+            .with_note(format!("Restore of inventory snapshot {}", snapshot_id))
+            .with_metadata(Some(metadata));
+            PgStockMoveRepository::insert_move(&mut tx, &stock_move, tenant_id).await?;
+        }
+
+        let restored_at = Utc::now();
+        sqlx::query(
+            "UPDATE inventory_snapshots
+             SET last_restored_at = $1, last_restored_by = $2
+             WHERE snapshot_id = $3",
+        )
+        .bind(restored_at)
+        .bind(user_id)
+        .bind(snapshot_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(SnapshotRestoreResponse {
+            snapshot_id,
+            levels_adjusted: drifts.len() as u64,
+            restored_by: user_id,
+            restored_at,
+        })
+    }
+}
@@ -4,6 +4,9 @@ use mockall::mock;
 use mockall::predicate::*;
 use uuid::Uuid;
 
+use inventory_service_core::models::{
+    NewStockReservation, ReservationStatus, StockReservation, StockReservationStatus,
+};
 use inventory_service_core::repositories::InventoryRepository;
 use inventory_service_core::services::InventoryService;
 use inventory_service_core::Result;
@@ -24,7 +27,7 @@ mock! {
             warehouse_id: Uuid,
             product_id: Uuid,
             quantity: i64,
-        ) -> Result<()>;
+        ) -> Result<ReservationStatus>;
 
         async fn release_stock(
             &self,
@@ -40,6 +43,32 @@ mock! {
             warehouse_id: Uuid,
             product_id: Uuid,
         ) -> Result<i64>;
+
+        async fn queue_reservation(
+            &self,
+            tenant_id: Uuid,
+            reservation: &NewStockReservation,
+        ) -> Result<StockReservation>;
+
+        async fn allocate_scarce(
+            &self,
+            tenant_id: Uuid,
+            product_id: Uuid,
+            available: i64,
+        ) -> Result<Vec<StockReservation>>;
+
+        async fn claim_source_reservations(
+            &self,
+            tenant_id: Uuid,
+            source_id: Uuid,
+        ) -> Result<Vec<StockReservation>>;
+
+        async fn restore_reservation_status(
+            &self,
+            tenant_id: Uuid,
+            reservation_id: Uuid,
+            status: StockReservationStatus,
+        ) -> Result<()>;
     }
 }
 
@@ -62,7 +91,7 @@ mod tests {
         mock_repo
             .expect_reserve_stock()
             .with(eq(tenant_id), eq(warehouse_id), eq(product_id), eq(quantity))
-            .returning(|_, _, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(ReservationStatus::Reserved));
 
         let service = InventoryServiceImpl::new(Arc::new(mock_repo));
 
@@ -108,7 +137,7 @@ mod tests {
         mock_repo
             .expect_reserve_stock()
             .with(eq(tenant_id), eq(warehouse_id), eq(product_id), eq(quantity))
-            .returning(|_, _, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(ReservationStatus::Reserved));
 
         let service = InventoryServiceImpl::new(Arc::new(mock_repo));
 
@@ -142,6 +171,27 @@ mod tests {
         assert!(matches!(result.unwrap_err(), AppError::InternalError(_)));
     }
 
+    #[tokio::test]
+    async fn test_reserve_stock_backordered_status_passthrough() {
+        let mut mock_repo = MockInventoryRepositoryImpl::new();
+        let tenant_id = Uuid::new_v4();
+        let warehouse_id = Uuid::new_v4();
+        let product_id = Uuid::new_v4();
+        let quantity = 12i64;
+
+        mock_repo
+            .expect_reserve_stock()
+            .with(eq(tenant_id), eq(warehouse_id), eq(product_id), eq(quantity))
+            .returning(|_, _, _, _| Ok(ReservationStatus::Backordered));
+
+        let service = InventoryServiceImpl::new(Arc::new(mock_repo));
+
+        let result = service
+            .reserve_stock(tenant_id, warehouse_id, product_id, quantity)
+            .await;
+        assert_eq!(result.unwrap(), ReservationStatus::Backordered);
+    }
+
     // =========================================================================
     // release_stock Tests
     // =========================================================================
@@ -368,14 +418,14 @@ mod tests {
             .expect_reserve_stock()
             .with(eq(tenant_a), eq(warehouse_id), eq(product_id), eq(quantity))
             .times(1)
-            .returning(|_, _, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(ReservationStatus::Reserved));
 
         // Second call for tenant B
         mock_repo
             .expect_reserve_stock()
             .with(eq(tenant_b), eq(warehouse_id), eq(product_id), eq(quantity))
             .times(1)
-            .returning(|_, _, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(ReservationStatus::Reserved));
 
         let service = InventoryServiceImpl::new(Arc::new(mock_repo));
 
@@ -408,7 +458,7 @@ mod tests {
             .expect_reserve_stock()
             .with(eq(tenant_id), eq(warehouse_id), eq(product_id), eq(quantity))
             .times(1)
-            .returning(|_, _, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(ReservationStatus::Reserved));
 
         mock_repo
             .expect_release_stock()
@@ -430,4 +480,98 @@ mod tests {
             .await;
         assert!(release_result.is_ok());
     }
+
+    // =========================================================================
+    // release_source_reservations Tests
+    // =========================================================================
+
+    fn claimed_reservation(
+        tenant_id: Uuid,
+        source_id: Uuid,
+        allocated: i64,
+        status: StockReservationStatus,
+    ) -> StockReservation {
+        StockReservation {
+            reservation_id: Uuid::new_v4(),
+            tenant_id,
+            warehouse_id: Uuid::new_v4(),
+            product_id: Uuid::new_v4(),
+            source_type: Some("sales_order".to_string()),
+            source_id: Some(source_id),
+            priority: 0,
+            requested_quantity: 10,
+            allocated_quantity: allocated,
+            backordered_quantity: 10 - allocated,
+            status,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_release_source_reservations_nothing_open() {
+        let mut mock_repo = MockInventoryRepositoryImpl::new();
+        let tenant_id = Uuid::new_v4();
+        let order_id = Uuid::new_v4();
+
+        mock_repo
+            .expect_claim_source_reservations()
+            .with(eq(tenant_id), eq(order_id))
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        mock_repo.expect_release_stock().never();
+
+        let service = InventoryServiceImpl::new(Arc::new(mock_repo));
+
+        let released = service
+            .release_source_reservations(tenant_id, order_id)
+            .await
+            .unwrap();
+        assert!(released.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_release_source_reservations_restores_on_failure() {
+        let mut mock_repo = MockInventoryRepositoryImpl::new();
+        let tenant_id = Uuid::new_v4();
+        let order_id = Uuid::new_v4();
+        let first =
+            claimed_reservation(tenant_id, order_id, 4, StockReservationStatus::Backordered);
+        let second = claimed_reservation(tenant_id, order_id, 10, StockReservationStatus::Reserved);
+        let (first_id, second_id) = (first.reservation_id, second.reservation_id);
+        let claimed = vec![first, second];
+
+        mock_repo
+            .expect_claim_source_reservations()
+            .returning(move |_, _| Ok(claimed.clone()));
+        mock_repo
+            .expect_release_stock()
+            .with(always(), always(), always(), eq(4))
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        mock_repo
+            .expect_release_stock()
+            .with(always(), always(), always(), eq(10))
+            .times(1)
+            .returning(|_, _, _, _| {
+                Err(AppError::ValidationError("Insufficient reserved stock to release".to_string()))
+            });
+        // Only the reservation that was not released is reopened
+        mock_repo
+            .expect_restore_reservation_status()
+            .with(eq(tenant_id), eq(second_id), eq(StockReservationStatus::Reserved))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock_repo
+            .expect_restore_reservation_status()
+            .with(always(), eq(first_id), always())
+            .never();
+
+        let service = InventoryServiceImpl::new(Arc::new(mock_repo));
+
+        let result = service
+            .release_source_reservations(tenant_id, order_id)
+            .await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+    }
 }
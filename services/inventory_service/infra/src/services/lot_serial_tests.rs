@@ -8,7 +8,7 @@ use mockall::mock;
 use uuid::Uuid;
 
 use inventory_service_core::models::{
-    CreateStockMoveRequest, LotSerial, LotSerialStatus, LotSerialTrackingType, StockMove,
+    LotSerial, LotSerialStatus, LotSerialTrackingType, MoveIntent, StockMove,
 };
 use inventory_service_core::repositories::{LotSerialRepository, StockMoveRepository};
 use inventory_service_core::Result;
@@ -58,11 +58,8 @@ mock! {
 
     #[async_trait::async_trait]
     impl StockMoveRepository for StockMoveRepositoryImpl {
-        async fn create(
-            &self,
-            stock_move: &CreateStockMoveRequest,
-            tenant_id: Uuid,
-        ) -> Result<StockMove>;
+        async fn record(&self, intent: &MoveIntent, tenant_id: Uuid) -> Result<StockMove>;
+        async fn reverse_move(&self, tenant_id: Uuid, move_id: Uuid) -> Result<StockMove>;
         async fn find_by_reference(
             &self,
             tenant_id: Uuid,
@@ -153,6 +150,10 @@ mod tests {
             batch_info: None,
             metadata: None,
             created_at: Utc::now(),
+            reason_code: Some("goods_receipt".to_string()),
+            source_type: Some("receipt".to_string()),
+            source_id: Some(Uuid::new_v4()),
+            reversal_of_move_id: None,
         }
     }
 
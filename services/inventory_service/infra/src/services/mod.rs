@@ -7,7 +7,9 @@ pub mod category;
 pub mod cycle_count;
 pub mod delivery;
 pub mod distributed_lock;
+pub mod feature_flags;
 pub mod inventory;
+pub mod inventory_snapshot;
 pub mod lot_serial;
 
 pub mod adjustment;
@@ -28,12 +30,16 @@ pub mod rma;
 pub mod scrap;
 pub mod stock_levels;
 pub mod stock_take;
+pub mod tenant_export;
+pub mod tenant_provisioning;
 pub mod transfer;
 pub mod valuation;
 
 #[cfg(test)]
 mod category_tests;
 #[cfg(test)]
+mod feature_flags_tests;
+#[cfg(test)]
 mod inventory_tests;
 #[cfg(test)]
 mod lot_serial_tests;
@@ -45,12 +51,16 @@ mod replenishment_tests;
 mod valuation_tests;
 
 // Re-export services for convenience
-pub use cache::{RedisCache, SharedCache, SharedInventoryCache, SharedProductCache};
-pub use category::CategoryServiceImpl;
-// pub use delivery::DeliveryServiceImpl;
 pub use self::picking_method::PickingMethodServiceImpl;
+pub use cache::{
+    CacheTtlConfig, RedisCache, SharedCache, SharedInventoryCache, SharedProductCache,
+};
+pub use category::CategoryServiceImpl;
+pub use delivery::DeliveryServiceImpl;
 pub use distributed_lock::RedisDistributedLockService;
+pub use feature_flags::FeatureFlagServiceImpl;
 pub use inventory::InventoryServiceImpl;
+pub use inventory_snapshot::PgInventorySnapshotService;
 pub use landed_cost::LandedCostServiceImpl;
 pub use lot_serial::LotSerialServiceImpl;
 pub use product::ProductServiceImpl;
@@ -62,9 +72,11 @@ pub use quality::PgQualityControlPointService;
 pub use receipt::ReceiptServiceImpl;
 pub use reconciliation::PgStockReconciliationService;
 pub use removal_strategy::RemovalStrategyServiceImpl;
-pub use replenishment::PgReplenishmentService;
+pub use replenishment::{PgReplenishmentService, ReorderEventPublisher, REORDER_TRIGGERED_SUBJECT};
 pub use rma::PgRmaService;
 pub use stock_take::PgStockTakeService;
+pub use tenant_export::{write_tenant_export, PgTenantExportService};
+pub use tenant_provisioning::PgTenantProvisioningService;
 pub use transfer::PgTransferService;
 pub use valuation::ValuationServiceImpl;
 
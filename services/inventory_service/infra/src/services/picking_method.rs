@@ -4,6 +4,7 @@
 
 use async_trait::async_trait;
 use chrono::Utc;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use uuid::Uuid;
@@ -13,6 +14,9 @@ use inventory_service_core::domains::inventory::dto::picking_method_dto::{
     PickingOptimizationRequest, PickingPlanResponse, PickingTask, UpdatePickingMethodRequest,
 };
 use inventory_service_core::domains::inventory::picking_method::PickingMethod;
+use inventory_service_core::domains::inventory::picking_route::{
+    plan_route, route_distance, RouteStop,
+};
 use inventory_service_core::repositories::picking_method::PickingMethodRepository;
 use inventory_service_core::services::picking_method::PickingMethodService;
 use inventory_service_core::Result;
@@ -148,7 +152,7 @@ impl PickingMethodService for PickingMethodServiceImpl {
 
         // Route to specific optimization based on method type (case-insensitive)
         let method_type = method.method_type.to_ascii_lowercase();
-        match method_type.as_str() {
+        let plan = match method_type.as_str() {
             "batch" => {
                 self.generate_batch_picking_plan(
                     tenant_id,
@@ -182,7 +186,9 @@ impl PickingMethodService for PickingMethodServiceImpl {
             _ => Err(inventory_service_core::AppError::ValidationError(
                 "Unsupported picking method type".to_string(),
             )),
-        }
+        }?;
+
+        self.sequence_by_route(tenant_id, plan).await
     }
 
     async fn confirm_picking_plan(
@@ -239,6 +245,72 @@ impl PickingMethodService for PickingMethodServiceImpl {
 }
 
 impl PickingMethodServiceImpl {
+    // ========================================================================
+    // Route Sequencing
+    // ========================================================================
+
+    /// Re-sequence plan tasks along the shortest walk between their locations
+    ///
+    /// Tasks at the same location stay together. When any location lacks
+    /// coordinates the tasks are ordered by location code and the plan
+    /// carries no distance estimate.
+    async fn sequence_by_route(
+        &self,
+        tenant_id: Uuid,
+        mut plan: PickingPlanResponse,
+    ) -> Result<PickingPlanResponse> {
+        // One stop per location, in the order the plan first visits them
+        let mut stops: Vec<RouteStop> = Vec::new();
+        for task in &plan.tasks {
+            if !stops
+                .iter()
+                .any(|stop| stop.location_id == task.location_id)
+            {
+                stops.push(RouteStop {
+                    location_id: task.location_id,
+                    location_code: task.location_code.clone(),
+                    point: None,
+                });
+            }
+        }
+
+        let location_ids: Vec<Uuid> = stops.iter().map(|stop| stop.location_id).collect();
+        let points: HashMap<Uuid, _> = self
+            .repository
+            .find_route_stops(tenant_id, plan.warehouse_id, &location_ids)
+            .await?
+            .into_iter()
+            .map(|stop| (stop.location_id, stop.point))
+            .collect();
+        for stop in &mut stops {
+            stop.point = points.get(&stop.location_id).copied().flatten();
+        }
+
+        let unsequenced_distance = route_distance(&stops);
+        let route = plan_route(stops);
+        let rank: HashMap<Uuid, usize> = route
+            .stops
+            .iter()
+            .enumerate()
+            .map(|(rank, stop)| (stop.location_id, rank))
+            .collect();
+
+        plan.tasks.sort_by_key(|task| rank[&task.location_id]);
+        for (index, task) in plan.tasks.iter_mut().enumerate() {
+            task.sequence = index as i32 + 1;
+        }
+
+        plan.metrics.total_distance_meters = route.total_distance_meters;
+        if let (Some(before), Some(after)) = (unsequenced_distance, route.total_distance_meters) {
+            if before > 0.0 {
+                plan.metrics.travel_time_reduction_percent =
+                    Some((before - after) / before * 100.0);
+            }
+        }
+
+        Ok(plan)
+    }
+
     // ========================================================================
     // Batch Picking Operations
     // ========================================================================
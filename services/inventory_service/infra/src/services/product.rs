@@ -4,6 +4,7 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
 use inventory_service_core::domains::inventory::dto::search_dto::{
@@ -11,19 +12,62 @@ use inventory_service_core::domains::inventory::dto::search_dto::{
     SearchSuggestionsResponse,
 };
 use inventory_service_core::domains::inventory::product::Product;
+use inventory_service_core::dto::product::{
+    parse_product_attributes, validate_product_attributes, ProductAttributes,
+};
 use inventory_service_core::repositories::product::ProductRepository;
 use inventory_service_core::services::product::ProductService;
 use inventory_service_core::Result;
 
+use super::cache::SharedProductCache;
+
+/// How long a product read stays cached
+///
+/// Writes through this service evict the entry at once; the TTL only bounds
+/// staleness from writes made elsewhere (imports, other services).
+const PRODUCT_CACHE_TTL: Duration = Duration::from_secs(60);
+
 /// Implementation of ProductService
 pub struct ProductServiceImpl {
     repository: Arc<dyn ProductRepository>,
+    cache: Option<SharedProductCache>,
 }
 
 impl ProductServiceImpl {
     /// Create new service instance
     pub fn new(repository: Arc<dyn ProductRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            cache: None,
+        }
+    }
+
+    /// Cache product reads, evicting entries whenever this service writes them
+    pub fn with_cache(mut self, cache: SharedProductCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Load a product from the repository, bypassing the cache
+    ///
+    /// Writes start from this so they never apply changes to a stale copy.
+    async fn find_product(&self, tenant_id: Uuid, product_id: Uuid) -> Result<Product> {
+        self.repository
+            .find_by_id(tenant_id, product_id)
+            .await?
+            .ok_or_else(|| shared_error::AppError::NotFound("Product not found".to_string()))
+    }
+
+    /// Evict products after a successful write
+    async fn invalidate_cached(&self, tenant_id: Uuid, product_ids: &[Uuid]) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        for product_id in product_ids {
+            if let Err(e) = cache.invalidate_product(&tenant_id, product_id).await {
+                tracing::warn!("Product cache invalidation failed for {}: {}", product_id, e);
+            }
+        }
     }
 }
 
@@ -82,6 +126,11 @@ impl ProductService for ProductServiceImpl {
             )));
         }
 
+        if let Some(ref attributes) = request.attributes {
+            parse_product_attributes(attributes)
+                .map_err(shared_error::AppError::ValidationError)?;
+        }
+
         // Create product entity
         let mut product = Product::new(
             tenant_id,
@@ -120,6 +169,7 @@ impl ProductService for ProductServiceImpl {
         product.is_active = request.is_active.unwrap_or(true);
         product.is_sellable = request.is_sellable.unwrap_or(true);
         product.is_purchaseable = request.is_purchaseable.unwrap_or(true);
+        product.overcommit_pct = request.overcommit_pct.unwrap_or(0);
 
         // Apply barcode fields with validation
         if let Some(ref barcode) = request.barcode {
@@ -138,10 +188,30 @@ impl ProductService for ProductServiceImpl {
     }
 
     async fn get_product(&self, tenant_id: Uuid, product_id: Uuid) -> Result<Product> {
-        self.repository
-            .find_by_id(tenant_id, product_id)
-            .await?
-            .ok_or_else(|| shared_error::AppError::NotFound("Product not found".to_string()))
+        if let Some(cache) = &self.cache {
+            match cache.get_product(&tenant_id, &product_id).await {
+                Ok(Some(product)) => return Ok(product),
+                Ok(None) => {},
+                Err(e) => tracing::warn!("Product cache read failed for {}: {}", product_id, e),
+            }
+        }
+
+        let product = self.find_product(tenant_id, product_id).await?;
+
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache
+                .set_product(&tenant_id, &product, Some(PRODUCT_CACHE_TTL))
+                .await
+            {
+                tracing::warn!("Product cache write failed for {}: {}", product_id, e);
+            }
+        }
+
+        Ok(product)
+    }
+
+    async fn product_exists(&self, tenant_id: Uuid, product_id: Uuid) -> Result<bool> {
+        self.repository.exists(tenant_id, product_id).await
     }
 
     async fn list_products(
@@ -164,8 +234,11 @@ impl ProductService for ProductServiceImpl {
             sellable_only: query.is_sellable,
             sort_by: None,
             sort_order: None,
+            order_by: query.sort_specs(),
+            attributes: query.attributes.clone(),
             page: Some(query.page as u32),
-            limit: Some(query.page_size as u32),
+            limit: Some(query.page_size() as u32),
+            include_total: Some(query.include_total),
         };
 
         // Use the existing search_products method
@@ -199,10 +272,11 @@ impl ProductService for ProductServiceImpl {
                     currency_code: p.currency_code,
                     weight_grams: None,
                     dimensions: None,
-                    attributes: None,
+                    attributes: p.attributes,
                     is_active: p.is_active,
                     is_sellable: p.is_sellable,
                     is_purchaseable: true,
+                    overcommit_pct: 0,
                     created_at: p.created_at,
                     updated_at: p.updated_at,
                 }
@@ -222,11 +296,16 @@ impl ProductService for ProductServiceImpl {
         request: inventory_service_core::dto::product::ProductUpdateRequest,
     ) -> Result<Product> {
         // Get existing product
-        let mut product = self.get_product(tenant_id, product_id).await?;
+        let mut product = self.find_product(tenant_id, product_id).await?;
 
         // SKU cannot be updated for now - it's the primary identifier
         // TODO: Implement SKU update with proper conflict checking
 
+        if let Some(ref attributes) = request.attributes {
+            parse_product_attributes(attributes)
+                .map_err(shared_error::AppError::ValidationError)?;
+        }
+
         // Apply updates
         if let Some(name) = request.name {
             product.name = name;
@@ -279,6 +358,9 @@ impl ProductService for ProductServiceImpl {
         if let Some(is_purchaseable) = request.is_purchaseable {
             product.is_purchaseable = is_purchaseable;
         }
+        if let Some(overcommit_pct) = request.overcommit_pct {
+            product.overcommit_pct = overcommit_pct;
+        }
 
         // Apply barcode fields with validation
         if let Some(ref barcode) = request.barcode {
@@ -310,14 +392,112 @@ impl ProductService for ProductServiceImpl {
         product.touch();
 
         // Save to repository
-        self.repository
+        let updated = self
+            .repository
             .update(tenant_id, product_id, &product)
-            .await
+            .await?;
+        self.invalidate_cached(tenant_id, &[product_id]).await;
+        Ok(updated)
+    }
+
+    async fn patch_product(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        request: inventory_service_core::dto::product::ProductPatchRequest,
+    ) -> Result<Product> {
+        let mut product = self.find_product(tenant_id, product_id).await?;
+
+        if let Some(Some(ref attributes)) = request.attributes {
+            parse_product_attributes(attributes)
+                .map_err(shared_error::AppError::ValidationError)?;
+        }
+
+        // Merge: absent fields keep their value, `Some(None)` clears a nullable one
+        let barcode_changed = request.barcode.is_some() || request.barcode_type.is_some();
+        if let Some(name) = request.name {
+            product.name = name;
+        }
+        if let Some(description) = request.description {
+            product.description = description;
+        }
+        if let Some(product_type) = request.product_type {
+            product.product_type = product_type;
+        }
+        if let Some(barcode) = request.barcode {
+            product.barcode = barcode;
+        }
+        if let Some(barcode_type) = request.barcode_type {
+            product.barcode_type = barcode_type;
+        }
+        if let Some(category_id) = request.category_id {
+            product.category_id = category_id;
+        }
+        if let Some(item_group_id) = request.item_group_id {
+            product.item_group_id = item_group_id;
+        }
+        if let Some(track_inventory) = request.track_inventory {
+            product.track_inventory = track_inventory;
+        }
+        if let Some(tracking_method) = request.tracking_method {
+            product.tracking_method = tracking_method;
+        }
+        if let Some(default_uom_id) = request.default_uom_id {
+            product.default_uom_id = default_uom_id;
+        }
+        if let Some(sale_price) = request.sale_price {
+            product.sale_price = sale_price;
+        }
+        if let Some(cost_price) = request.cost_price {
+            product.cost_price = cost_price;
+        }
+        if let Some(currency_code) = request.currency_code {
+            product.currency_code = currency_code;
+        }
+        if let Some(weight_grams) = request.weight_grams {
+            product.weight_grams = weight_grams;
+        }
+        if let Some(dimensions) = request.dimensions {
+            product.dimensions = dimensions;
+        }
+        if let Some(attributes) = request.attributes {
+            product.attributes = attributes;
+        }
+        if let Some(is_active) = request.is_active {
+            product.is_active = is_active;
+        }
+        if let Some(is_sellable) = request.is_sellable {
+            product.is_sellable = is_sellable;
+        }
+        if let Some(is_purchaseable) = request.is_purchaseable {
+            product.is_purchaseable = is_purchaseable;
+        }
+        if let Some(overcommit_pct) = request.overcommit_pct {
+            product.overcommit_pct = overcommit_pct;
+        }
+
+        // The merged barcode must match the merged barcode type
+        if barcode_changed {
+            if let (Some(barcode), Some(barcode_type)) = (&product.barcode, &product.barcode_type) {
+                barcode_type
+                    .validate_barcode(barcode)
+                    .map_err(shared_error::AppError::ValidationError)?;
+            }
+        }
+
+        product.touch();
+
+        let updated = self
+            .repository
+            .update(tenant_id, product_id, &product)
+            .await?;
+        self.invalidate_cached(tenant_id, &[product_id]).await;
+        Ok(updated)
     }
 
     async fn delete_product(&self, tenant_id: Uuid, product_id: Uuid) -> Result<()> {
         // Get product to check if it exists
-        let _product = self.get_product(tenant_id, product_id).await?;
+        let _product = self.find_product(tenant_id, product_id).await?;
 
         // TODO: Check for active transactions before deleting
 
@@ -326,6 +506,7 @@ impl ProductService for ProductServiceImpl {
         if !deleted {
             return Err(shared_error::AppError::NotFound("Product not found".to_string()));
         }
+        self.invalidate_cached(tenant_id, &[product_id]).await;
         Ok(())
     }
 
@@ -382,7 +563,12 @@ impl ProductService for ProductServiceImpl {
             ));
         }
 
-        self.repository.bulk_activate(tenant_id, product_ids).await
+        let affected = self
+            .repository
+            .bulk_activate(tenant_id, product_ids)
+            .await?;
+        self.invalidate_cached(tenant_id, product_ids).await;
+        Ok(affected)
     }
 
     async fn bulk_deactivate_products(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64> {
@@ -392,9 +578,12 @@ impl ProductService for ProductServiceImpl {
             ));
         }
 
-        self.repository
+        let affected = self
+            .repository
             .bulk_deactivate(tenant_id, product_ids)
-            .await
+            .await?;
+        self.invalidate_cached(tenant_id, product_ids).await;
+        Ok(affected)
     }
 
     async fn bulk_delete_products(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64> {
@@ -404,6 +593,54 @@ impl ProductService for ProductServiceImpl {
             ));
         }
 
-        self.repository.bulk_delete(tenant_id, product_ids).await
+        let affected = self
+            .repository
+            .bulk_soft_delete(tenant_id, product_ids)
+            .await?;
+        self.invalidate_cached(tenant_id, product_ids).await;
+        Ok(affected)
+    }
+
+    async fn bulk_restore_products(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<i64> {
+        if product_ids.is_empty() {
+            return Err(shared_error::AppError::ValidationError(
+                "No product IDs provided".to_string(),
+            ));
+        }
+
+        let affected = self.repository.bulk_restore(tenant_id, product_ids).await?;
+        self.invalidate_cached(tenant_id, product_ids).await;
+        Ok(affected)
+    }
+
+    async fn get_product_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<ProductAttributes> {
+        self.repository
+            .get_attributes(tenant_id, product_id)
+            .await?
+            .ok_or_else(|| shared_error::AppError::NotFound("Product not found".to_string()))
+    }
+
+    async fn set_product_attributes(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        attributes: ProductAttributes,
+    ) -> Result<ProductAttributes> {
+        validate_product_attributes(&attributes)
+            .map_err(shared_error::AppError::ValidationError)?;
+
+        let updated = self
+            .repository
+            .set_attributes(tenant_id, product_id, &attributes)
+            .await?;
+        if !updated {
+            return Err(shared_error::AppError::NotFound("Product not found".to_string()));
+        }
+        self.invalidate_cached(tenant_id, &[product_id]).await;
+        Ok(attributes)
     }
 }
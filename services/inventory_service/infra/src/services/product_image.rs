@@ -33,10 +33,14 @@ pub struct ProductImageServiceImpl {
 impl ProductImageServiceImpl {
     /// Create a new ProductImageService
     pub fn new(repository: Arc<dyn ProductImageRepository>, storage: SharedStorageClient) -> Self {
+        let processing_config = ImageProcessingConfig {
+            limits: storage.image_limits().clone(),
+            ..Default::default()
+        };
         Self {
             repository,
             storage,
-            processing_config: ImageProcessingConfig::default(),
+            processing_config,
         }
     }
 
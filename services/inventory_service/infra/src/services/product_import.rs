@@ -236,6 +236,7 @@ impl ProductImportServiceImpl {
             is_active: row.is_active.unwrap_or(true),
             is_sellable: true,
             is_purchaseable: true,
+            overcommit_pct: 0,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             deleted_at: None,
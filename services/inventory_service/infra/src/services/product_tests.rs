@@ -8,15 +8,19 @@ use inventory_service_core::domains::inventory::dto::search_dto::{
     AppliedFilters, ProductSearchRequest, ProductSearchResponse, SearchFacets, SearchMeta,
     SearchSuggestionsRequest, SearchSuggestionsResponse,
 };
-use inventory_service_core::domains::inventory::product::Product;
+use inventory_service_core::domains::inventory::product::{BarcodeType, Product};
+use inventory_service_core::dto::product::ProductPatchRequest;
 use inventory_service_core::dto::PaginationInfo;
 use inventory_service_core::repositories::product::ProductRepository;
 use inventory_service_core::services::product::ProductService;
+use inventory_service_core::services::ProductCache;
 use inventory_service_core::Result;
 use shared_error::AppError;
 
 use super::ProductServiceImpl;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 // Mock the ProductRepository trait
 mock! {
@@ -40,6 +44,7 @@ mock! {
         async fn find_by_ids(&self, tenant_id: Uuid, product_ids: &[Uuid]) -> Result<Vec<Product>>;
         async fn find_by_sku(&self, tenant_id: Uuid, sku: &str) -> Result<Option<Product>>;
         async fn find_by_barcode(&self, tenant_id: Uuid, barcode: &str) -> Result<Option<Product>>;
+        async fn exists(&self, tenant_id: Uuid, product_id: Uuid) -> Result<bool>;
         async fn create(&self, product: &Product) -> Result<Product>;
         async fn update(&self, tenant_id: Uuid, product_id: Uuid, product: &Product) -> Result<Product>;
         async fn delete(&self, tenant_id: Uuid, product_id: Uuid) -> Result<bool>;
@@ -79,14 +84,7 @@ mod tests {
     fn create_empty_search_response() -> ProductSearchResponse {
         ProductSearchResponse {
             products: vec![],
-            pagination: PaginationInfo {
-                page: 1,
-                page_size: 20,
-                total_items: 0,
-                total_pages: 0,
-                has_next: false,
-                has_prev: false,
-            },
+            pagination: PaginationInfo::new(1, 20, 0),
             facets: SearchFacets {
                 categories: vec![],
                 price_ranges: vec![],
@@ -95,7 +93,7 @@ mod tests {
             meta: SearchMeta {
                 query: Some("test".to_string()),
                 execution_time_ms: 10,
-                total_found: 0,
+                total_found: Some(0),
                 applied_filters: AppliedFilters {
                     category_ids: None,
                     price_min: None,
@@ -212,7 +210,7 @@ mod tests {
 
         let result = service.search_products(tenant_id, request).await;
         assert!(result.is_ok());
-        assert_eq!(result.unwrap().pagination.total_items, 0);
+        assert_eq!(result.unwrap().pagination.total_items, Some(0));
     }
 
     #[tokio::test]
@@ -324,4 +322,184 @@ mod tests {
             .await;
         assert!(result.is_ok());
     }
+
+    // =========================================================================
+    // patch_product Tests
+    // =========================================================================
+
+    /// Service whose repository returns the product it is asked to save
+    fn patch_service(product: Product) -> ProductServiceImpl {
+        let mut mock_repo = MockProductRepositoryImpl::new();
+        mock_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(product.clone())));
+        mock_repo
+            .expect_update()
+            .returning(|_, _, product| Ok(product.clone()));
+        ProductServiceImpl::new(Arc::new(mock_repo))
+    }
+
+    fn described_product() -> Product {
+        let mut product = create_test_product();
+        product.description = Some("Original description".to_string());
+        product
+    }
+
+    #[tokio::test]
+    async fn test_patch_product_name_only_keeps_description() {
+        let service = patch_service(described_product());
+        let request: ProductPatchRequest =
+            serde_json::from_value(serde_json::json!({ "name": "Renamed" })).unwrap();
+
+        let product = service
+            .patch_product(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await
+            .unwrap();
+        assert_eq!(product.name, "Renamed");
+        assert_eq!(product.description.as_deref(), Some("Original description"));
+    }
+
+    #[tokio::test]
+    async fn test_patch_product_null_description_clears_it() {
+        let service = patch_service(described_product());
+        let request: ProductPatchRequest =
+            serde_json::from_value(serde_json::json!({ "description": null })).unwrap();
+
+        let product = service
+            .patch_product(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await
+            .unwrap();
+        assert_eq!(product.name, "Test Product");
+        assert_eq!(product.description, None);
+    }
+
+    #[tokio::test]
+    async fn test_patch_product_rejects_barcode_not_matching_type() {
+        let mut product = create_test_product();
+        product.barcode_type = Some(BarcodeType::Ean13);
+        let service = patch_service(product);
+        let request: ProductPatchRequest =
+            serde_json::from_value(serde_json::json!({ "barcode": "not-an-ean" })).unwrap();
+
+        let result = service
+            .patch_product(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+    }
+
+    // =========================================================================
+    // Product cache Tests
+    // =========================================================================
+
+    /// In-memory stand-in for the Redis product cache
+    #[derive(Default)]
+    struct InMemoryProductCache {
+        entries: Mutex<HashMap<(Uuid, Uuid), Product>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ProductCache for InMemoryProductCache {
+        async fn get_product(
+            &self,
+            tenant_id: &Uuid,
+            product_id: &Uuid,
+        ) -> std::result::Result<Option<Product>, AppError> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .get(&(*tenant_id, *product_id))
+                .cloned())
+        }
+
+        async fn set_product(
+            &self,
+            tenant_id: &Uuid,
+            product: &Product,
+            _ttl: Option<Duration>,
+        ) -> std::result::Result<(), AppError> {
+            self.entries
+                .lock()
+                .unwrap()
+                .insert((*tenant_id, product.product_id), product.clone());
+            Ok(())
+        }
+
+        async fn invalidate_product(
+            &self,
+            tenant_id: &Uuid,
+            product_id: &Uuid,
+        ) -> std::result::Result<(), AppError> {
+            self.entries
+                .lock()
+                .unwrap()
+                .remove(&(*tenant_id, *product_id));
+            Ok(())
+        }
+    }
+
+    /// Cached service over a repository holding a single product
+    fn cached_service(product: Product) -> (ProductServiceImpl, Arc<InMemoryProductCache>) {
+        let stored = Arc::new(Mutex::new(product));
+        let mut mock_repo = MockProductRepositoryImpl::new();
+        let read = stored.clone();
+        mock_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(read.lock().unwrap().clone())));
+        let write = stored.clone();
+        mock_repo.expect_update().returning(move |_, _, product| {
+            *write.lock().unwrap() = product.clone();
+            Ok(product.clone())
+        });
+        mock_repo.expect_delete().returning(|_, _| Ok(true));
+
+        let cache = Arc::new(InMemoryProductCache::default());
+        let service = ProductServiceImpl::new(Arc::new(mock_repo)).with_cache(cache.clone());
+        (service, cache)
+    }
+
+    #[tokio::test]
+    async fn test_update_product_evicts_cached_product() {
+        let tenant_id = Uuid::new_v4();
+        let product = create_test_product();
+        let product_id = product.product_id;
+        let (service, cache) = cached_service(product);
+
+        // Warm the cache
+        let before = service.get_product(tenant_id, product_id).await.unwrap();
+        assert_eq!(before.name, "Test Product");
+        assert!(cache
+            .get_product(&tenant_id, &product_id)
+            .await
+            .unwrap()
+            .is_some());
+
+        let request: inventory_service_core::dto::product::ProductUpdateRequest =
+            serde_json::from_value(serde_json::json!({ "name": "Renamed" })).unwrap();
+        service
+            .update_product(tenant_id, product_id, request)
+            .await
+            .unwrap();
+
+        // The next read sees the new name instead of the cached copy
+        let after = service.get_product(tenant_id, product_id).await.unwrap();
+        assert_eq!(after.name, "Renamed");
+    }
+
+    #[tokio::test]
+    async fn test_delete_product_evicts_cached_product() {
+        let tenant_id = Uuid::new_v4();
+        let product = create_test_product();
+        let product_id = product.product_id;
+        let (service, cache) = cached_service(product);
+
+        service.get_product(tenant_id, product_id).await.unwrap();
+        service.delete_product(tenant_id, product_id).await.unwrap();
+
+        assert!(cache
+            .get_product(&tenant_id, &product_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
@@ -147,16 +147,8 @@ impl ProductVariantService for ProductVariantServiceImpl {
     ) -> Result<VariantListResponse> {
         let (variants, total_count) = self.variant_repository.list(tenant_id, &query).await?;
 
-        let total_pages = (total_count as u32).div_ceil(query.page_size as u32).max(1);
-
-        let pagination = PaginationInfo {
-            page: query.page as u32,
-            page_size: query.page_size as u32,
-            total_items: total_count as u64,
-            total_pages,
-            has_next: query.page < total_pages as i64,
-            has_prev: query.page > 1,
-        };
+        let pagination =
+            PaginationInfo::new(query.page as u32, query.page_size as u32, total_count as u64);
 
         Ok(VariantListResponse {
             variants,
@@ -9,12 +9,13 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use inventory_service_core::models::{
-    ConfirmPutawayRequest, ConfirmPutawayResponse, CreateStockMoveRequest, PutawayRequest,
+    ConfirmPutawayRequest, ConfirmPutawayResponse, MoveIntent, MoveSourceType, PutawayRequest,
     PutawayRule, PutawaySuggestion, StorageLocation,
 };
 use inventory_service_core::repositories::putaway::{
     PutawayRepository, PutawayService, TransactionalPutawayRepository,
 };
+use inventory_service_core::repositories::warehouse::WarehouseRepository;
 
 use shared_error::AppError;
 
@@ -24,6 +25,7 @@ use crate::repositories::stock::PgStockMoveRepository;
 pub struct PgPutawayService<R: PutawayRepository + TransactionalPutawayRepository + Send + Sync> {
     putaway_repo: Arc<R>,
     stock_move_repo: PgStockMoveRepository,
+    warehouse_repo: Option<Arc<dyn WarehouseRepository>>,
 }
 
 impl<R: PutawayRepository + TransactionalPutawayRepository + Send + Sync> PgPutawayService<R> {
@@ -32,8 +34,51 @@ impl<R: PutawayRepository + TransactionalPutawayRepository + Send + Sync> PgPuta
         Self {
             putaway_repo,
             stock_move_repo,
+            warehouse_repo: None,
         }
     }
+
+    /// Check allocations against each location's available capacity
+    ///
+    /// Without a warehouse repository, only the location stock update's own
+    /// capacity check applies.
+    pub fn with_warehouse_repository(
+        mut self,
+        warehouse_repo: Arc<dyn WarehouseRepository>,
+    ) -> Self {
+        self.warehouse_repo = Some(warehouse_repo);
+        self
+    }
+
+    /// Reject allocations that would over-fill a location
+    ///
+    /// Quantities are aggregated per location, so splitting an allocation
+    /// across several lines cannot bypass the limit.
+    async fn ensure_capacity(
+        &self,
+        tenant_id: &Uuid,
+        per_location_qty: &HashMap<Uuid, i64>,
+    ) -> Result<(), AppError> {
+        let Some(warehouse_repo) = &self.warehouse_repo else {
+            return Ok(());
+        };
+
+        for (location_id, quantity) in per_location_qty {
+            let available = warehouse_repo
+                .get_available_capacity(*tenant_id, *location_id)
+                .await?;
+            if let Some(available) = available {
+                if *quantity > available {
+                    return Err(AppError::Conflict(format!(
+                        "Putting away {} units would exceed the capacity of location {} ({} available)",
+                        quantity, location_id, available
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -148,33 +193,33 @@ impl<R: PutawayRepository + TransactionalPutawayRepository + Send + Sync> Putawa
             })?;
         }
 
-        // Capacity validation will be performed atomically within the transaction
+        self.ensure_capacity(tenant_id, &per_location_qty).await?;
+
+        // Stock updates re-check capacity atomically within the transaction
 
         // Begin transaction for atomic putaway
         let mut tx = self.putaway_repo.begin_transaction().await?;
 
         // Create stock moves and update location stock atomically
         for allocation in &request.allocations {
-            let stock_move = CreateStockMoveRequest {
-                product_id: request.product_id, // Fixed: Use actual product_id from request
-                source_location_id: None,       // Putaway from receiving area
-                destination_location_id: Some(allocation.location_id),
-                move_type: "putaway".to_string(),
-                quantity: allocation.quantity,
-                unit_cost: allocation.unit_cost, // Use unit_cost from allocation
-                reference_type: request.reference_type.clone(),
-                reference_id: request.reference_id,
-                lot_serial_id: None,
-                idempotency_key: Uuid::now_v7().to_string(),
-                move_reason: Some("Putaway confirmation".to_string()),
-                batch_info: None,
-                metadata: None,
-            };
+            // Putaway from receiving area, linked to the document that received the goods
+            let stock_move = MoveIntent::new(
+                MoveSourceType::Putaway,
+                request.reference_id,
+                "putaway",
+                request.product_id,
+                allocation.quantity,
+                Uuid::now_v7().to_string(),
+            )
+            .with_locations(None, Some(allocation.location_id))
+            .with_unit_cost(allocation.unit_cost)
+            .with_reference_type(request.reference_type.clone())
+            .with_note("Putaway confirmation");
 
             // Create stock move within transaction
             let (move_id, new_tx) = self
                 .stock_move_repo
-                .create_with_tx(tx, stock_move, *tenant_id)
+                .record_with_tx(tx, &stock_move, *tenant_id)
                 .await?;
             stock_moves_created.push(move_id);
             tx = new_tx;
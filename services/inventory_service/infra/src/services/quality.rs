@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use inventory_service_core::domains::quality::{
-    CreateQualityControlPoint, QualityControlPoint, UpdateQualityControlPoint,
+    CreateQualityControlPoint, QualityCheck, QualityControlPoint, RecordQualityCheckResult,
+    UpdateQualityControlPoint,
 };
 use inventory_service_core::repositories::quality::QualityControlPointRepository;
 use inventory_service_core::services::quality::QualityControlPointService;
@@ -75,4 +76,26 @@ impl QualityControlPointService for PgQualityControlPointService {
     ) -> Result<Vec<QualityControlPoint>, AppError> {
         self.repo.find_by_warehouse(tenant_id, warehouse_id).await
     }
+
+    async fn list_checks_for_receipt(
+        &self,
+        tenant_id: Uuid,
+        receipt_id: Uuid,
+    ) -> Result<Vec<QualityCheck>, AppError> {
+        self.repo
+            .find_checks_by_reference(tenant_id, "receipt", receipt_id)
+            .await
+    }
+
+    async fn record_check_result(
+        &self,
+        tenant_id: Uuid,
+        qc_id: Uuid,
+        inspector_id: Uuid,
+        result: RecordQualityCheckResult,
+    ) -> Result<QualityCheck, AppError> {
+        self.repo
+            .record_check_result(tenant_id, qc_id, inspector_id, result.passed, result.notes)
+            .await
+    }
 }
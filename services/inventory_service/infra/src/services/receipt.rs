@@ -54,6 +54,29 @@ where
             distributed_lock_service,
         }
     }
+
+    /// Look up the receipt previously created for the request's external reference
+    async fn find_existing_receipt(
+        &self,
+        tenant_id: Uuid,
+        request: &ReceiptCreateRequest,
+    ) -> Result<Option<ReceiptResponse>, AppError> {
+        let Some(external_reference) = request.external_reference.as_deref() else {
+            return Ok(None);
+        };
+        match self
+            .receipt_repository
+            .find_id_by_external_reference(tenant_id, external_reference)
+            .await?
+        {
+            Some(receipt_id) => self
+                .receipt_repository
+                .get_receipt(tenant_id, receipt_id)
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
 #[async_trait]
@@ -73,6 +96,11 @@ where
         // Validate request
         self.validate_receipt_request(tenant_id, &request).await?;
 
+        // A resubmitted external reference resolves to the receipt already created
+        if let Some(existing) = self.find_existing_receipt(tenant_id, &request).await? {
+            return Ok(existing);
+        }
+
         // Generate idempotency key from request data
         let idempotency_key = generate_idempotency_key(&request);
 
@@ -140,6 +168,13 @@ where
                 .await; // Ignore errors during cleanup
         }
 
+        // A concurrent submission with the same external reference won the insert
+        if let Err(AppError::Conflict(_)) = &receipt_result {
+            if let Some(existing) = self.find_existing_receipt(tenant_id, &request).await? {
+                return Ok(existing);
+            }
+        }
+
         receipt_result
     }
 
@@ -166,6 +201,9 @@ where
     }
 
     /// Validate and complete a goods receipt note
+    ///
+    /// Received quantities become available stock, except for products with an active
+    /// incoming QC point: those are placed in quality hold until a check passes.
     async fn validate_receipt(
         &self,
         tenant_id: Uuid,
@@ -177,6 +215,26 @@ where
             .await
     }
 
+    /// Reverse a received goods receipt entered in error
+    async fn reverse_receipt(
+        &self,
+        tenant_id: Uuid,
+        receipt_id: Uuid,
+        reason: &str,
+        user_id: Uuid,
+    ) -> Result<ReceiptResponse, AppError> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(AppError::ValidationError(
+                "A reason is required to reverse a receipt".to_string(),
+            ));
+        }
+
+        self.receipt_repository
+            .reverse_receipt(tenant_id, receipt_id, reason, user_id)
+            .await
+    }
+
     /// Validate receipt data before creation
     async fn validate_receipt_request(
         &self,
@@ -353,6 +411,7 @@ mod tests {
             warehouse_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
             supplier_id: Some(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap()),
             reference_number: Some("PO-123".to_string()),
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -392,6 +451,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -505,6 +565,14 @@ mod tests {
             unimplemented!("Not needed for validation tests")
         }
 
+        async fn find_id_by_external_reference(
+            &self,
+            _tenant_id: Uuid,
+            _external_reference: &str,
+        ) -> Result<Option<Uuid>, AppError> {
+            Ok(None)
+        }
+
         async fn receipt_exists(
             &self,
             _tenant_id: Uuid,
@@ -521,6 +589,130 @@ mod tests {
         ) -> Result<ReceiptResponse, AppError> {
             unimplemented!("Not needed for validation tests")
         }
+
+        async fn reverse_receipt(
+            &self,
+            _tenant_id: Uuid,
+            _receipt_id: Uuid,
+            _reason: &str,
+            _user_id: Uuid,
+        ) -> Result<ReceiptResponse, AppError> {
+            unimplemented!("Not needed for validation tests")
+        }
+    }
+
+    /// Receipt repository that keeps created receipts in memory
+    #[derive(Default)]
+    struct InMemoryReceiptRepository {
+        receipts: std::sync::Mutex<Vec<ReceiptResponse>>,
+    }
+
+    impl InMemoryReceiptRepository {
+        fn created_count(&self) -> usize {
+            self.receipts.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait]
+    impl ReceiptRepository for InMemoryReceiptRepository {
+        async fn create_receipt(
+            &self,
+            tenant_id: Uuid,
+            user_id: Uuid,
+            request: &ReceiptCreateRequest,
+            _idempotency_key: &str,
+        ) -> Result<ReceiptResponse, AppError> {
+            let now = chrono::Utc::now();
+            let receipt = ReceiptResponse {
+                receipt_id: Uuid::now_v7(),
+                receipt_number: "GRN-TEST".to_string(),
+                tenant_id,
+                warehouse_id: request.warehouse_id,
+                supplier_id: request.supplier_id,
+                reference_number: request.reference_number.clone(),
+                external_reference: request.external_reference.clone(),
+                status: "confirmed".to_string(),
+                receipt_date: now,
+                expected_delivery_date: request.expected_delivery_date,
+                actual_delivery_date: None,
+                notes: request.notes.clone(),
+                created_by: user_id,
+                total_quantity: None,
+                total_value: None,
+                currency_code: request.currency_code.clone(),
+                items: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            };
+            self.receipts.lock().unwrap().push(receipt.clone());
+            Ok(receipt)
+        }
+
+        async fn get_receipt(
+            &self,
+            tenant_id: Uuid,
+            receipt_id: Uuid,
+        ) -> Result<ReceiptResponse, AppError> {
+            self.receipts
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|r| r.tenant_id == tenant_id && r.receipt_id == receipt_id)
+                .cloned()
+                .ok_or_else(|| AppError::NotFound("Receipt not found".to_string()))
+        }
+
+        async fn list_receipts(
+            &self,
+            _tenant_id: Uuid,
+            _query: ReceiptListQuery,
+        ) -> Result<ReceiptListResponse, AppError> {
+            unimplemented!("Not needed for external reference tests")
+        }
+
+        async fn find_id_by_external_reference(
+            &self,
+            tenant_id: Uuid,
+            external_reference: &str,
+        ) -> Result<Option<Uuid>, AppError> {
+            Ok(self
+                .receipts
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|r| {
+                    r.tenant_id == tenant_id
+                        && r.external_reference.as_deref() == Some(external_reference)
+                })
+                .map(|r| r.receipt_id))
+        }
+
+        async fn receipt_exists(
+            &self,
+            _tenant_id: Uuid,
+            _receipt_id: Uuid,
+        ) -> Result<bool, AppError> {
+            unimplemented!("Not needed for external reference tests")
+        }
+
+        async fn validate_receipt(
+            &self,
+            _tenant_id: Uuid,
+            _receipt_id: Uuid,
+            _user_id: Uuid,
+        ) -> Result<ReceiptResponse, AppError> {
+            unimplemented!("Not needed for external reference tests")
+        }
+
+        async fn reverse_receipt(
+            &self,
+            _tenant_id: Uuid,
+            _receipt_id: Uuid,
+            _reason: &str,
+            _user_id: Uuid,
+        ) -> Result<ReceiptResponse, AppError> {
+            unimplemented!("Not needed for external reference tests")
+        }
     }
 
     struct DummyProductRepository;
@@ -559,6 +751,7 @@ mod tests {
                 is_active: true,
                 is_sellable: true,
                 is_purchaseable: true,
+                overcommit_pct: 0,
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
                 deleted_at: None,
@@ -603,6 +796,7 @@ mod tests {
                         is_active: true,
                         is_sellable: true,
                         is_purchaseable: true,
+                        overcommit_pct: 0,
                         created_at: chrono::Utc::now(),
                         updated_at: chrono::Utc::now(),
                         deleted_at: None,
@@ -628,6 +822,10 @@ mod tests {
             unimplemented!("Not needed for validation tests")
         }
 
+        async fn exists(&self, _tenant_id: Uuid, _product_id: Uuid) -> Result<bool, AppError> {
+            unimplemented!("Not needed for validation tests")
+        }
+
         async fn create(&self, _product: &Product) -> Result<Product, AppError> {
             unimplemented!("Not needed for validation tests")
         }
@@ -703,6 +901,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -736,6 +935,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -759,6 +959,7 @@ mod tests {
             warehouse_id: Uuid::nil(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -794,6 +995,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -829,6 +1031,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -864,6 +1067,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -899,6 +1103,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -936,6 +1141,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -975,6 +1181,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -1010,6 +1217,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -1049,6 +1257,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -1088,6 +1297,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -1124,6 +1334,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -1160,6 +1371,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -1199,6 +1411,7 @@ mod tests {
             warehouse_id: Uuid::new_v4(),
             supplier_id: None,
             reference_number: None,
+            external_reference: None,
             expected_delivery_date: None,
             notes: None,
             currency_code: "USD".to_string(),
@@ -1220,4 +1433,77 @@ mod tests {
         let result = service.validate_receipt_request(tenant_id, &request).await;
         assert!(result.is_ok());
     }
+
+    fn external_reference_request(external_reference: &str) -> ReceiptCreateRequest {
+        ReceiptCreateRequest {
+            warehouse_id: Uuid::new_v4(),
+            supplier_id: None,
+            reference_number: None,
+            external_reference: Some(external_reference.to_string()),
+            expected_delivery_date: None,
+            notes: None,
+            currency_code: "USD".to_string(),
+            items: vec![
+                inventory_service_core::dto::receipt::ReceiptItemCreateRequest {
+                    product_id: Uuid::from_u128(3), // tracking_method = None
+                    expected_quantity: 10,
+                    received_quantity: 10,
+                    unit_cost: Some(1000),
+                    uom_id: None,
+                    lot_number: None,
+                    serial_numbers: None,
+                    expiry_date: None,
+                    notes: None,
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resubmitted_external_reference_returns_existing_receipt() {
+        let tenant_id = Uuid::new_v4();
+        let receipt_repo = Arc::new(InMemoryReceiptRepository::default());
+        let service = ReceiptServiceImpl::new(
+            receipt_repo.clone(),
+            Arc::new(DummyProductRepository),
+            Arc::new(DummyDistributedLockService),
+        );
+        let request = external_reference_request("PO-RETRY-1");
+
+        let first = service
+            .create_receipt(tenant_id, Uuid::new_v4(), request.clone())
+            .await
+            .unwrap();
+        let second = service
+            .create_receipt(tenant_id, Uuid::new_v4(), request)
+            .await
+            .unwrap();
+
+        assert_eq!(first.receipt_id, second.receipt_id);
+        assert_eq!(second.external_reference.as_deref(), Some("PO-RETRY-1"));
+        assert_eq!(receipt_repo.created_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_external_reference_is_scoped_per_tenant() {
+        let receipt_repo = Arc::new(InMemoryReceiptRepository::default());
+        let service = ReceiptServiceImpl::new(
+            receipt_repo.clone(),
+            Arc::new(DummyProductRepository),
+            Arc::new(DummyDistributedLockService),
+        );
+        let request = external_reference_request("PO-SHARED");
+
+        let tenant_a = service
+            .create_receipt(Uuid::new_v4(), Uuid::new_v4(), request.clone())
+            .await
+            .unwrap();
+        let tenant_b = service
+            .create_receipt(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await
+            .unwrap();
+
+        assert_ne!(tenant_a.receipt_id, tenant_b.receipt_id);
+        assert_eq!(receipt_repo.created_count(), 2);
+    }
 }
@@ -1,21 +1,31 @@
 use async_trait::async_trait;
 use chrono::Utc;
+use csv::{ReaderBuilder, Trim};
 use sqlx::PgPool;
 
+use std::collections::HashMap;
+use std::io::Cursor;
 use std::sync::Arc;
 use uuid::Uuid;
+use validator::Validate;
 
-use inventory_service_core::domains::inventory::reconciliation::ReconciliationStatus;
-use inventory_service_core::dto::common::PaginationInfo;
+use inventory_service_core::domains::inventory::reconciliation::{
+    ReconciliationStatus, StockReconciliation,
+};
+use inventory_service_core::dto::common::{list_fetch_limit, DateRange, PaginationInfo};
+use inventory_service_core::dto::product_import::ImportRowError;
 use inventory_service_core::dto::reconciliation::{
     ApproveReconciliationRequest, ApproveReconciliationResponse, CountReconciliationRequest,
     CountReconciliationResponse, CreateReconciliationRequest, CreateReconciliationResponse,
-    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ReconciliationAnalyticsResponse,
-    ReconciliationDetailResponse, ReconciliationListQuery, ReconciliationListResponse,
-    ScanBarcodeRequest, ScanBarcodeResponse, VarianceAnalysisResponse, VarianceRange,
+    FinalizeReconciliationRequest, FinalizeReconciliationResponse, ImportReconciliationRequest,
+    ImportReconciliationResponse, ReconciliationAnalyticsResponse, ReconciliationCountItem,
+    ReconciliationDetailResponse, ReconciliationImportQuery, ReconciliationListQuery,
+    ReconciliationListResponse, ReconciliationSnapshotRow, ReconciliationTrendQuery,
+    ReconciliationTrendResponse, ScanBarcodeRequest, ScanBarcodeResponse, VarianceAnalysisResponse,
+    VarianceRange,
 };
 use inventory_service_core::dto::stock_take::StockAdjustment;
-use inventory_service_core::models::CreateStockMoveRequest;
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
 use inventory_service_core::repositories::reconciliation::{
     ReconciliationItemCountUpdate, StockReconciliationItemRepository, StockReconciliationRepository,
 };
@@ -67,6 +77,154 @@ impl PgStockReconciliationService {
         let cents = (f * 100.0).round() as i64;
         Ok(cents)
     }
+
+    /// Build a draft reconciliation from a creation request
+    fn new_reconciliation(
+        tenant_id: Uuid,
+        user_id: Uuid,
+        request: CreateReconciliationRequest,
+    ) -> StockReconciliation {
+        StockReconciliation {
+            reconciliation_id: Uuid::now_v7(),
+            tenant_id,
+            reconciliation_number: String::new(), // Will be set by trigger
+            name: request.name,
+            description: request.description,
+            status: ReconciliationStatus::Draft,
+            cycle_type: request.cycle_type,
+            warehouse_id: request.warehouse_id,
+            location_filter: request.location_filter,
+            product_filter: request.product_filter,
+            total_items: 0,    // Will be updated by trigger
+            counted_items: 0,  // Will be updated by trigger
+            total_variance: 0, // Will be updated by trigger
+            created_by: user_id,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            started_at: None,
+            completed_at: None,
+            approved_by: None,
+            approved_at: None,
+            notes: request.notes,
+        }
+    }
+}
+
+/// Maximum number of rows accepted in one snapshot import
+pub const MAX_SNAPSHOT_ROWS: usize = 5000;
+
+/// Parse a `sku,location,counted_qty` CSV snapshot
+///
+/// The header row is required; `location` may be left empty.
+fn parse_snapshot_csv(data: &[u8]) -> Result<Vec<ReconciliationSnapshotRow>, AppError> {
+    let mut reader = ReaderBuilder::new()
+        .trim(Trim::All)
+        .from_reader(Cursor::new(data));
+
+    reader
+        .deserialize()
+        .collect::<Result<Vec<ReconciliationSnapshotRow>, _>>()
+        .map_err(|e| AppError::ValidationError(format!("Failed to parse CSV: {}", e)))
+}
+
+/// Distinct non-blank values, trimmed
+fn distinct_values<'a>(values: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut distinct: Vec<String> = values
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect();
+    distinct.sort();
+    distinct.dedup();
+    distinct
+}
+
+/// Turn snapshot rows into count items, collecting an error for every unresolvable row
+///
+/// Reconciliation items are keyed by product and warehouse, so rows for the same SKU
+/// are summed into one item. The item keeps its location only when every row for the
+/// SKU names the same location.
+fn resolve_snapshot_rows(
+    rows: &[ReconciliationSnapshotRow],
+    warehouse_id: Uuid,
+    products: &HashMap<String, Uuid>,
+    locations: &HashMap<String, Uuid>,
+) -> (Vec<ReconciliationCountItem>, Vec<ImportRowError>) {
+    let mut items: Vec<ReconciliationCountItem> = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, row) in rows.iter().enumerate() {
+        let row_number = index as i32 + 1;
+        let row_error = |field: &str, error: String| ImportRowError {
+            row_number,
+            field: field.to_string(),
+            error,
+        };
+        let mut failed = false;
+
+        let sku = row.sku.trim();
+        let product_id = if sku.is_empty() {
+            errors.push(row_error("sku", "SKU is required".to_string()));
+            failed = true;
+            None
+        } else {
+            let product_id = products.get(sku).copied();
+            if product_id.is_none() {
+                errors.push(row_error("sku", format!("Unknown SKU '{}'", sku)));
+                failed = true;
+            }
+            product_id
+        };
+
+        let location_code = row
+            .location
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty());
+        let location_id = match location_code {
+            Some(code) => {
+                let location_id = locations.get(code).copied();
+                if location_id.is_none() {
+                    errors.push(row_error(
+                        "location",
+                        format!("Unknown location '{}' in warehouse", code),
+                    ));
+                    failed = true;
+                }
+                location_id
+            },
+            None => None,
+        };
+
+        if row.counted_qty < 0 {
+            errors
+                .push(row_error("counted_qty", "Counted quantity cannot be negative".to_string()));
+            failed = true;
+        }
+
+        let Some(product_id) = product_id.filter(|_| !failed) else {
+            continue;
+        };
+
+        match items.iter_mut().find(|item| item.product_id == product_id) {
+            Some(item) => {
+                item.counted_quantity += row.counted_qty;
+                if item.location_id != location_id {
+                    item.location_id = None;
+                }
+            },
+            None => items.push(ReconciliationCountItem {
+                product_id,
+                warehouse_id,
+                location_id,
+                counted_quantity: row.counted_qty,
+                unit_cost: None,
+                notes: None,
+            }),
+        }
+    }
+
+    (items, errors)
 }
 
 #[async_trait]
@@ -77,30 +235,7 @@ impl StockReconciliationService for PgStockReconciliationService {
         user_id: Uuid,
         request: CreateReconciliationRequest,
     ) -> Result<CreateReconciliationResponse, AppError> {
-        let reconciliation =
-            inventory_service_core::domains::inventory::reconciliation::StockReconciliation {
-                reconciliation_id: Uuid::now_v7(),
-                tenant_id,
-                reconciliation_number: String::new(), // Will be set by trigger
-                name: request.name,
-                description: request.description,
-                status: ReconciliationStatus::Draft,
-                cycle_type: request.cycle_type.clone(),
-                warehouse_id: request.warehouse_id,
-                location_filter: request.location_filter.clone(),
-                product_filter: request.product_filter.clone(),
-                total_items: 0,    // Will be updated by trigger
-                counted_items: 0,  // Will be updated by trigger
-                total_variance: 0, // Will be updated by trigger
-                created_by: user_id,
-                created_at: Utc::now(),
-                updated_at: Utc::now(),
-                started_at: None,
-                completed_at: None,
-                approved_by: None,
-                approved_at: None,
-                notes: request.notes,
-            };
+        let reconciliation = Self::new_reconciliation(tenant_id, user_id, request.clone());
 
         let created_reconciliation = self
             .reconciliation_repo
@@ -151,6 +286,114 @@ impl StockReconciliationService for PgStockReconciliationService {
         })
     }
 
+    async fn import_reconciliation(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        request: ImportReconciliationRequest,
+    ) -> Result<ImportReconciliationResponse, AppError> {
+        request
+            .validate()
+            .map_err(|e| AppError::ValidationError(e.to_string()))?;
+        if request.rows.len() > MAX_SNAPSHOT_ROWS {
+            return Err(AppError::ValidationError(format!(
+                "Snapshot has {} rows, the maximum is {}",
+                request.rows.len(),
+                MAX_SNAPSHOT_ROWS
+            )));
+        }
+        let warehouse_id = request.reconciliation.warehouse_id.ok_or_else(|| {
+            AppError::ValidationError("warehouse_id is required to import a snapshot".to_string())
+        })?;
+        if !self
+            .reconciliation_repo
+            .warehouse_exists(tenant_id, warehouse_id)
+            .await?
+        {
+            return Err(AppError::NotFound("Warehouse not found".to_string()));
+        }
+
+        let skus = distinct_values(request.rows.iter().map(|row| row.sku.as_str()));
+        let location_codes = distinct_values(
+            request
+                .rows
+                .iter()
+                .filter_map(|row| row.location.as_deref()),
+        );
+        let products = self
+            .reconciliation_repo
+            .resolve_skus(tenant_id, &skus)
+            .await?;
+        let locations = self
+            .reconciliation_repo
+            .resolve_location_codes(tenant_id, warehouse_id, &location_codes)
+            .await?;
+
+        let (items, errors) =
+            resolve_snapshot_rows(&request.rows, warehouse_id, &products, &locations);
+        if !errors.is_empty() {
+            return Ok(ImportReconciliationResponse {
+                reconciliation: None,
+                items: Vec::new(),
+                errors,
+            });
+        }
+
+        let mut reconciliation =
+            Self::new_reconciliation(tenant_id, user_id, request.reconciliation);
+        // Counts arrive with the snapshot, so the session starts out in progress
+        reconciliation.status = ReconciliationStatus::InProgress;
+        reconciliation.started_at = Some(Utc::now());
+        reconciliation.total_items = items.len() as i32;
+
+        let counts: Vec<ReconciliationItemCountUpdate> = items
+            .into_iter()
+            .map(|item| ReconciliationItemCountUpdate {
+                product_id: item.product_id,
+                warehouse_id: item.warehouse_id,
+                location_id: item.location_id,
+                counted_quantity: item.counted_quantity,
+                unit_cost: item.unit_cost,
+                counted_by: user_id,
+                notes: item.notes,
+            })
+            .collect();
+
+        let created = self
+            .reconciliation_repo
+            .create_with_counts(tenant_id, &reconciliation, &counts)
+            .await?;
+        let reconciliation = self
+            .reconciliation_repo
+            .find_by_id(tenant_id, created.reconciliation_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::InternalError("Failed to retrieve imported reconciliation".to_string())
+            })?;
+        let items = self
+            .reconciliation_item_repo
+            .find_by_reconciliation_id(tenant_id, reconciliation.reconciliation_id)
+            .await?;
+
+        Ok(ImportReconciliationResponse {
+            reconciliation: Some(reconciliation),
+            items,
+            errors: Vec::new(),
+        })
+    }
+
+    async fn import_reconciliation_csv(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        query: ReconciliationImportQuery,
+        data: &[u8],
+    ) -> Result<ImportReconciliationResponse, AppError> {
+        let rows = parse_snapshot_csv(data)?;
+        self.import_reconciliation(tenant_id, user_id, query.into_request(rows))
+            .await
+    }
+
     async fn count_reconciliation(
         &self,
         tenant_id: Uuid,
@@ -274,27 +517,25 @@ impl StockReconciliationService for PgStockReconciliationService {
                     None => continue,
                 };
                 let unit_cost_cents = PgStockReconciliationService::f64_to_cents(unit_cost)?;
-                let stock_move = CreateStockMoveRequest {
-                    product_id: item.product_id,
-                    source_location_id: Some(item.warehouse_id),
-                    destination_location_id: Some(item.warehouse_id), // Same warehouse for adjustment
-                    move_type: "adjustment".to_string(),
-                    quantity: variance,
-                    unit_cost: Some(unit_cost_cents),
-                    reference_type: "reconciliation".to_string(),
-                    reference_id: reconciliation_id,
-                    idempotency_key: format!(
+                let stock_move = MoveIntent::new(
+                    MoveSourceType::Reconciliation,
+                    reconciliation_id,
+                    "reconciliation_variance",
+                    item.product_id,
+                    variance,
+                    format!(
                         "rec-{}-item-{}-{}",
                         reconciliation_id, item.product_id, item.warehouse_id
                     ),
-                    move_reason: Some(format!(
-                        "Reconciliation {} adjustment",
-                        reconciliation.reconciliation_number
-                    )),
-                    lot_serial_id: None, // TODO: Set if lot-tracked product
-                    batch_info: None,
-                    metadata: None,
-                };
+                )
+                // Same warehouse for adjustment
+                .with_locations(Some(item.warehouse_id), Some(item.warehouse_id))
+                .with_unit_cost(Some(unit_cost_cents))
+                // TODO: Set lot_serial_id if lot-tracked product
+                .with_note(format!(
+                    "Reconciliation {} adjustment",
+                    reconciliation.reconciliation_number
+                ));
                 stock_moves_to_create.push(stock_move);
 
                 // Prepare inventory update
@@ -325,7 +566,7 @@ impl StockReconciliationService for PgStockReconciliationService {
             for stock_move in &stock_moves_to_create {
                 let (_move_id, new_tx) = self
                     .stock_move_repo
-                    .create_with_tx(current_tx, stock_move.clone(), tenant_id)
+                    .record_with_tx(current_tx, stock_move, tenant_id)
                     .await?;
                 current_tx = new_tx;
             }
@@ -448,35 +689,44 @@ impl StockReconciliationService for PgStockReconciliationService {
         let page = query.page.unwrap_or(1).max(1) as i64;
         let offset = (page - 1) * limit;
 
-        let total = self
-            .reconciliation_repo
-            .count(tenant_id, query.warehouse_id, query.status.clone(), query.cycle_type.clone())
-            .await?;
+        // Count only when requested; otherwise fetch one look-ahead row for has_next
+        let total = if query.include_total {
+            let total = self
+                .reconciliation_repo
+                .count(
+                    tenant_id,
+                    query.warehouse_id,
+                    query.status.clone(),
+                    query.cycle_type.clone(),
+                )
+                .await?;
+            Some(total as u64)
+        } else {
+            None
+        };
 
-        let reconciliations = self
+        let mut reconciliations = self
             .reconciliation_repo
             .list(
                 tenant_id,
                 query.warehouse_id,
                 query.status,
                 query.cycle_type,
-                Some(limit as u32),
+                Some(list_fetch_limit(limit as u32, query.include_total) as u32),
                 Some(offset as u32),
             )
             .await?;
 
-        let total_pages = ((total + limit - 1) / limit).max(1) as u32;
+        let pagination = PaginationInfo::from_optional_total(
+            page as u32,
+            limit as u32,
+            total,
+            &mut reconciliations,
+        );
 
         Ok(ReconciliationListResponse {
             reconciliations,
-            pagination: PaginationInfo {
-                page: page as u32,
-                page_size: limit as u32,
-                total_items: total as u64,
-                total_pages,
-                has_next: page < total_pages as i64,
-                has_prev: page > 1,
-            },
+            pagination,
         })
     }
 
@@ -497,6 +747,25 @@ impl StockReconciliationService for PgStockReconciliationService {
         })
     }
 
+    async fn get_variance_trend(
+        &self,
+        tenant_id: Uuid,
+        range: DateRange,
+        query: ReconciliationTrendQuery,
+    ) -> Result<ReconciliationTrendResponse, AppError> {
+        let periods = self
+            .reconciliation_repo
+            .variance_trend(tenant_id, range.from, range.to, query.group_by, query.warehouse_id)
+            .await?;
+
+        Ok(ReconciliationTrendResponse {
+            group_by: query.group_by,
+            from: range.from,
+            to: range.to,
+            periods,
+        })
+    }
+
     async fn get_variance_analysis(
         &self,
         tenant_id: Uuid,
@@ -666,3 +935,89 @@ impl StockReconciliationService for PgStockReconciliationService {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(sku: &str, location: Option<&str>, counted_qty: i64) -> ReconciliationSnapshotRow {
+        ReconciliationSnapshotRow {
+            sku: sku.to_string(),
+            location: location.map(str::to_string),
+            counted_qty,
+        }
+    }
+
+    #[test]
+    fn test_parse_snapshot_csv_with_optional_location() {
+        let rows =
+            parse_snapshot_csv(b"sku,location,counted_qty\nSKU-1, A-01 ,5\nSKU-2,,0\n").unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].sku, "SKU-1");
+        assert_eq!(rows[0].location.as_deref(), Some("A-01"));
+        assert_eq!(rows[0].counted_qty, 5);
+        assert_eq!(rows[1].location, None);
+    }
+
+    #[test]
+    fn test_parse_snapshot_csv_rejects_bad_quantity() {
+        let result = parse_snapshot_csv(b"sku,location,counted_qty\nSKU-1,A-01,five\n");
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_resolve_snapshot_rows_reports_each_bad_row() {
+        let warehouse_id = Uuid::now_v7();
+        let product_id = Uuid::now_v7();
+        let products = HashMap::from([("SKU-1".to_string(), product_id)]);
+        let locations = HashMap::from([("A-01".to_string(), Uuid::now_v7())]);
+        let rows = vec![
+            row("SKU-1", Some("A-01"), 4),
+            row("MISSING", None, 1),
+            row("SKU-1", Some("Z-99"), 2),
+            row("", None, -1),
+        ];
+
+        let (items, errors) = resolve_snapshot_rows(&rows, warehouse_id, &products, &locations);
+
+        assert_eq!(items.len(), 1);
+        let reported: Vec<(i32, &str)> = errors
+            .iter()
+            .map(|e| (e.row_number, e.field.as_str()))
+            .collect();
+        assert_eq!(reported, vec![(2, "sku"), (3, "location"), (4, "sku"), (4, "counted_qty")]);
+    }
+
+    #[test]
+    fn test_resolve_snapshot_rows_sums_repeated_skus() {
+        let warehouse_id = Uuid::now_v7();
+        let product_id = Uuid::now_v7();
+        let shelf_a = Uuid::now_v7();
+        let products = HashMap::from([("SKU-1".to_string(), product_id)]);
+        let locations = HashMap::from([
+            ("A-01".to_string(), shelf_a),
+            ("B-01".to_string(), Uuid::now_v7()),
+        ]);
+
+        let (items, errors) = resolve_snapshot_rows(
+            &[row("SKU-1", Some("A-01"), 3), row("SKU-1", Some("A-01"), 4)],
+            warehouse_id,
+            &products,
+            &locations,
+        );
+        assert!(errors.is_empty());
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].counted_quantity, 7);
+        assert_eq!(items[0].location_id, Some(shelf_a));
+
+        let (items, _) = resolve_snapshot_rows(
+            &[row("SKU-1", Some("A-01"), 3), row("SKU-1", Some("B-01"), 4)],
+            warehouse_id,
+            &products,
+            &locations,
+        );
+        assert_eq!(items[0].counted_quantity, 7);
+        assert_eq!(items[0].location_id, None);
+    }
+}
@@ -1,8 +1,11 @@
 use async_trait::async_trait;
 use inventory_service_core::domains::replenishment::{
-    CreateReorderRule, ReorderRule, ReplenishmentCheckResult, UpdateReorderRule,
+    CreateInternalReplenishmentRule, CreateReorderRule, InternalReplenishmentRule,
+    InternalTransferSuggestion, ReorderRule, ReplenishmentCheckResult, UpdateReorderRule,
+};
+use inventory_service_core::repositories::replenishment::{
+    InternalReplenishmentRuleRepository, ReorderRuleRepository,
 };
-use inventory_service_core::repositories::replenishment::ReorderRuleRepository;
 
 use inventory_service_core::repositories::InventoryLevelRepository;
 use inventory_service_core::services::replenishment::ReplenishmentService;
@@ -11,24 +14,46 @@ use shared_events::{EventEnvelope, NatsClient, ReorderTriggeredEvent};
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// NATS subject for reorder events
+pub const REORDER_TRIGGERED_SUBJECT: &str = "inventory.reorder.triggered";
+
+/// Destination for reorder events emitted by replenishment checks
+#[async_trait]
+pub trait ReorderEventPublisher: Send + Sync {
+    /// Publish a reorder event
+    async fn publish_reorder(&self, event: &ReorderTriggeredEvent) -> Result<(), AppError>;
+}
+
+#[async_trait]
+impl ReorderEventPublisher for NatsClient {
+    async fn publish_reorder(&self, event: &ReorderTriggeredEvent) -> Result<(), AppError> {
+        let envelope = EventEnvelope::new(REORDER_TRIGGERED_SUBJECT, event.clone());
+        self.publish_event(REORDER_TRIGGERED_SUBJECT.to_string(), &envelope)
+            .await
+    }
+}
+
 /// PostgreSQL implementation of ReplenishmentService
 pub struct PgReplenishmentService {
     reorder_repo: Arc<dyn ReorderRuleRepository>,
+    internal_rule_repo: Arc<dyn InternalReplenishmentRuleRepository>,
     inventory_repo: Arc<dyn InventoryLevelRepository>,
-    nats_client: Option<Arc<NatsClient>>,
+    event_publisher: Option<Arc<dyn ReorderEventPublisher>>,
 }
 
 impl PgReplenishmentService {
     /// Create a new replenishment service
     pub fn new(
         reorder_repo: Arc<dyn ReorderRuleRepository>,
+        internal_rule_repo: Arc<dyn InternalReplenishmentRuleRepository>,
         inventory_repo: Arc<dyn InventoryLevelRepository>,
-        nats_client: Option<Arc<NatsClient>>,
+        event_publisher: Option<Arc<dyn ReorderEventPublisher>>,
     ) -> Self {
         Self {
             reorder_repo,
+            internal_rule_repo,
             inventory_repo,
-            nats_client,
+            event_publisher,
         }
     }
 
@@ -87,6 +112,78 @@ cross-warehouse aggregation not implemented – treating available as 0"
             suggested_order_quantity,
         )
     }
+
+    /// Evaluate a rule against current stock
+    async fn evaluate_rule(
+        &self,
+        tenant_id: Uuid,
+        rule: &ReorderRule,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<ReplenishmentCheckResult, AppError> {
+        let projected_quantity = self
+            .calculate_projected_quantity(tenant_id, rule.product_id, warehouse_id)
+            .await?;
+        // Current quantity is the same as projected for now
+        let current_quantity = projected_quantity;
+        let (
+            effective_reorder_point,
+            _target_quantity,
+            needs_replenishment,
+            suggested_order_quantity,
+        ) = self.compute_replenishment_decision(rule, projected_quantity);
+
+        Ok(ReplenishmentCheckResult {
+            product_id: rule.product_id,
+            warehouse_id,
+            current_quantity,
+            projected_quantity,
+            reorder_point: effective_reorder_point,
+            suggested_order_quantity,
+            needs_replenishment,
+            action_taken: None,
+        })
+    }
+
+    /// Publish the reorder event for a check result
+    async fn publish_reorder_event(
+        publisher: &dyn ReorderEventPublisher,
+        tenant_id: Uuid,
+        rule_id: Uuid,
+        result: &ReplenishmentCheckResult,
+    ) -> Result<(), AppError> {
+        let event = ReorderTriggeredEvent {
+            event_id: uuid::Uuid::now_v7(),
+            tenant_id,
+            product_id: result.product_id,
+            warehouse_id: result.warehouse_id,
+            current_quantity: result.current_quantity,
+            projected_quantity: result.projected_quantity,
+            reorder_point: result.reorder_point,
+            suggested_order_quantity: result.suggested_order_quantity,
+            rule_id,
+            triggered_at: chrono::Utc::now(),
+        };
+        publisher.publish_reorder(&event).await
+    }
+
+    /// Publish the reorder event if publishing is enabled and describe the outcome
+    async fn trigger_reorder(
+        &self,
+        tenant_id: Uuid,
+        rule_id: Uuid,
+        result: &ReplenishmentCheckResult,
+    ) -> String {
+        let Some(publisher) = &self.event_publisher else {
+            return "Reorder needed but event publishing disabled".to_string();
+        };
+        match Self::publish_reorder_event(publisher.as_ref(), tenant_id, rule_id, result).await {
+            Ok(_) => "Reorder triggered event published".to_string(),
+            Err(e) => {
+                tracing::warn!("Failed to publish reorder event: {}", e);
+                "Reorder needed but event publishing failed".to_string()
+            },
+        }
+    }
 }
 
 #[async_trait]
@@ -139,59 +236,65 @@ impl ReplenishmentService for PgReplenishmentService {
         let mut results = Vec::with_capacity(rules.len());
 
         for rule in rules {
-            let projected_quantity = self
-                .calculate_projected_quantity(tenant_id, rule.product_id, rule.warehouse_id)
+            let mut result = self
+                .evaluate_rule(tenant_id, &rule, rule.warehouse_id)
                 .await?;
-            let current_quantity = projected_quantity;
-            let (
-                effective_reorder_point,
-                _target_quantity,
-                needs_replenishment,
-                suggested_order_quantity,
-            ) = self.compute_replenishment_decision(&rule, projected_quantity);
-
-            let action_taken = if needs_replenishment {
-                if let Some(nats) = &self.nats_client {
-                    let event = ReorderTriggeredEvent {
-                        event_id: uuid::Uuid::now_v7(),
-                        tenant_id,
-                        product_id: rule.product_id,
-                        warehouse_id: rule.warehouse_id,
-                        current_quantity,
-                        projected_quantity,
-                        reorder_point: effective_reorder_point,
-                        suggested_order_quantity,
-                        rule_id: rule.rule_id,
-                        triggered_at: chrono::Utc::now(),
-                    };
-                    let envelope = EventEnvelope::new("inventory.reorder.triggered", event);
-                    match nats
-                        .publish_event("inventory.reorder.triggered".to_string(), &envelope)
-                        .await
-                    {
-                        Ok(_) => Some("Reorder triggered event published".to_string()),
-                        Err(e) => {
-                            tracing::warn!("Failed to publish reorder event: {}", e);
-                            Some("Reorder needed but event publishing failed".to_string())
-                        },
-                    }
-                } else {
-                    Some("Reorder needed but event publishing disabled".to_string())
-                }
-            } else {
-                None
-            };
-
-            results.push(ReplenishmentCheckResult {
-                product_id: rule.product_id,
-                warehouse_id: rule.warehouse_id,
-                current_quantity,
-                projected_quantity,
-                reorder_point: effective_reorder_point,
-                suggested_order_quantity,
-                needs_replenishment,
-                action_taken,
-            });
+            if result.needs_replenishment {
+                result.action_taken =
+                    Some(self.trigger_reorder(tenant_id, rule.rule_id, &result).await);
+            }
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    async fn run_scheduled_replenishment_check(
+        &self,
+        tenant_id: Uuid,
+        cooldown_seconds: i64,
+    ) -> Result<Vec<ReplenishmentCheckResult>, AppError> {
+        let rules = self.reorder_repo.find_all_active(tenant_id).await?;
+        let mut results = Vec::with_capacity(rules.len());
+
+        for rule in rules {
+            let mut result = self
+                .evaluate_rule(tenant_id, &rule, rule.warehouse_id)
+                .await?;
+            if result.needs_replenishment {
+                result.action_taken = Some(match &self.event_publisher {
+                    None => "Reorder needed but event publishing disabled".to_string(),
+                    Some(publisher) => {
+                        let claimed = self
+                            .reorder_repo
+                            .try_mark_triggered(tenant_id, rule.rule_id, cooldown_seconds)
+                            .await?;
+                        if !claimed {
+                            "Reorder already triggered within cooldown".to_string()
+                        } else {
+                            match Self::publish_reorder_event(
+                                publisher.as_ref(),
+                                tenant_id,
+                                rule.rule_id,
+                                &result,
+                            )
+                            .await
+                            {
+                                Ok(_) => "Reorder triggered event published".to_string(),
+                                Err(e) => {
+                                    tracing::warn!("Failed to publish reorder event: {}", e);
+                                    // Let the next run retry instead of waiting out the cooldown
+                                    self.reorder_repo
+                                        .clear_triggered(tenant_id, rule.rule_id)
+                                        .await?;
+                                    "Reorder needed but event publishing failed".to_string()
+                                },
+                            }
+                        }
+                    },
+                });
+            }
+            results.push(result);
         }
 
         Ok(results)
@@ -228,66 +331,109 @@ impl ReplenishmentService for PgReplenishmentService {
             &rules[0]
         };
 
-        // Calculate projected quantity
-        let projected_quantity = self
-            .calculate_projected_quantity(tenant_id, product_id, warehouse_id)
-            .await?;
+        let mut result = self.evaluate_rule(tenant_id, rule, warehouse_id).await?;
 
-        // Get current quantity (same as projected for now)
-        let current_quantity = projected_quantity;
+        // Publish reorder triggered event if needed
+        if result.needs_replenishment {
+            result.action_taken =
+                Some(self.trigger_reorder(tenant_id, rule.rule_id, &result).await);
+        }
 
-        let (
-            effective_reorder_point,
-            _target_quantity,
-            needs_replenishment,
-            suggested_order_quantity,
-        ) = self.compute_replenishment_decision(rule, projected_quantity);
+        Ok(result)
+    }
 
-        let mut action_taken = None;
+    async fn create_internal_replenishment_rule(
+        &self,
+        tenant_id: Uuid,
+        rule: CreateInternalReplenishmentRule,
+    ) -> Result<InternalReplenishmentRule, AppError> {
+        if rule.from_warehouse_id == rule.to_warehouse_id {
+            return Err(AppError::ValidationError(
+                "Source and destination warehouses must differ".to_string(),
+            ));
+        }
+        if rule.min_quantity < 0 || rule.max_quantity < rule.min_quantity {
+            return Err(AppError::ValidationError(
+                "Quantities must satisfy 0 <= min_quantity <= max_quantity".to_string(),
+            ));
+        }
+        if rule.source_safety_stock < 0 {
+            return Err(AppError::ValidationError(
+                "source_safety_stock cannot be negative".to_string(),
+            ));
+        }
+        self.internal_rule_repo.create(tenant_id, rule).await
+    }
 
-        // Publish reorder triggered event if needed
-        if needs_replenishment {
-            if let Some(nats) = &self.nats_client {
-                let event = ReorderTriggeredEvent {
-                    event_id: uuid::Uuid::now_v7(),
+    async fn list_internal_replenishment_rules(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<InternalReplenishmentRule>, AppError> {
+        self.internal_rule_repo.find_all_active(tenant_id).await
+    }
+
+    async fn delete_internal_replenishment_rule(
+        &self,
+        tenant_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<(), AppError> {
+        self.internal_rule_repo.delete(tenant_id, rule_id).await
+    }
+
+    async fn suggest_internal_transfers(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<InternalTransferSuggestion>, AppError> {
+        let rules = self.internal_rule_repo.find_all_active(tenant_id).await?;
+        // Open transfers plus the suggestions made so far; each suggestion moves
+        // quantity here so later rules sharing a warehouse see it as allocated.
+        let mut pending = self
+            .internal_rule_repo
+            .find_open_transfer_quantities(tenant_id)
+            .await?;
+        let mut suggestions = Vec::new();
+
+        for rule in rules {
+            let to_key = (rule.to_warehouse_id, rule.product_id);
+            let from_key = (rule.from_warehouse_id, rule.product_id);
+
+            let destination_quantity = self
+                .calculate_projected_quantity(
+                    tenant_id,
+                    rule.product_id,
+                    Some(rule.to_warehouse_id),
+                )
+                .await?
+                + pending.get(&to_key).copied().unwrap_or(0);
+            if destination_quantity >= rule.min_quantity {
+                continue;
+            }
+            let source_quantity = self
+                .calculate_projected_quantity(
                     tenant_id,
-                    product_id,
-                    warehouse_id,
-                    current_quantity,
-                    projected_quantity,
-                    reorder_point: effective_reorder_point,
-                    suggested_order_quantity,
+                    rule.product_id,
+                    Some(rule.from_warehouse_id),
+                )
+                .await?
+                + pending.get(&from_key).copied().unwrap_or(0);
+
+            if let Some(suggested_quantity) =
+                rule.suggested_transfer_quantity(destination_quantity, source_quantity)
+            {
+                *pending.entry(from_key).or_insert(0) -= suggested_quantity;
+                *pending.entry(to_key).or_insert(0) += suggested_quantity;
+                suggestions.push(InternalTransferSuggestion {
                     rule_id: rule.rule_id,
-                    triggered_at: chrono::Utc::now(),
-                };
-                let envelope = EventEnvelope::new("inventory.reorder.triggered", event);
-                match nats
-                    .publish_event("inventory.reorder.triggered".to_string(), &envelope)
-                    .await
-                {
-                    Ok(_) => {
-                        action_taken = Some("Reorder triggered event published".to_string());
-                    },
-                    Err(e) => {
-                        tracing::warn!("Failed to publish reorder event: {}", e);
-                        action_taken =
-                            Some("Reorder needed but event publishing failed".to_string());
-                    },
-                }
-            } else {
-                action_taken = Some("Reorder needed but event publishing disabled".to_string());
+                    product_id: rule.product_id,
+                    from_warehouse_id: rule.from_warehouse_id,
+                    to_warehouse_id: rule.to_warehouse_id,
+                    destination_quantity,
+                    source_quantity,
+                    suggested_quantity,
+                });
             }
         }
 
-        Ok(ReplenishmentCheckResult {
-            product_id,
-            warehouse_id,
-            current_quantity,
-            projected_quantity,
-            reorder_point: effective_reorder_point,
-            suggested_order_quantity,
-            needs_replenishment,
-            action_taken,
-        })
+        Ok(suggestions)
     }
 }
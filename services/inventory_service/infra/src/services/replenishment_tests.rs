@@ -3,14 +3,14 @@
 //! These tests validate the repository interactions for stock replenishment,
 //! including reorder rules, safety stock, and min/max quantity management.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use mockall::mock;
 use uuid::Uuid;
 
 use inventory_service_core::domains::replenishment::{
     CreateReorderRule, ReorderRule, UpdateReorderRule,
 };
-use inventory_service_core::models::InventoryLevel;
+use inventory_service_core::models::{InventoryLevel, InventoryLevelHistoryEntry};
 use inventory_service_core::repositories::replenishment::ReorderRuleRepository;
 use inventory_service_core::repositories::InventoryLevelRepository;
 use inventory_service_core::Result;
@@ -38,6 +38,13 @@ mock! {
             updates: UpdateReorderRule,
         ) -> Result<ReorderRule>;
         async fn delete(&self, tenant_id: Uuid, rule_id: Uuid) -> Result<()>;
+        async fn try_mark_triggered(
+            &self,
+            tenant_id: Uuid,
+            rule_id: Uuid,
+            cooldown_seconds: i64,
+        ) -> Result<bool>;
+        async fn clear_triggered(&self, tenant_id: Uuid, rule_id: Uuid) -> Result<()>;
     }
 }
 
@@ -61,6 +68,15 @@ mock! {
             product_id: Uuid,
             quantity_change: i64,
         ) -> Result<()>;
+        async fn update_available_quantity_in_tx<'a>(
+            &self,
+            tx: &mut sqlx::Transaction<'a, sqlx::Postgres>,
+            tenant_id: Uuid,
+            warehouse_id: Uuid,
+            location_id: Option<Uuid>,
+            product_id: Uuid,
+            quantity_change: i64,
+        ) -> Result<()>;
         async fn upsert(
             &self,
             tenant_id: Uuid,
@@ -69,6 +85,14 @@ mock! {
             available_quantity: i64,
             reserved_quantity: i64,
         ) -> Result<()>;
+        async fn history(
+            &self,
+            tenant_id: Uuid,
+            location_id: Uuid,
+            product_id: Uuid,
+            from: Option<DateTime<Utc>>,
+            to: Option<DateTime<Utc>>,
+        ) -> Result<Vec<InventoryLevelHistoryEntry>>;
     }
 }
 
@@ -6,17 +6,189 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use inventory_service_core::dto::reports::{
     calculate_avg_inventory, calculate_dio, calculate_turnover_ratio, get_age_bucket_label,
-    AgingBasis, StockAgingReportQuery, StockAgingReportResponse, StockAgingReportRow,
-    TurnoverGroupBy, TurnoverReportQuery, TurnoverReportResponse, TurnoverReportRow,
+    oldest_remaining_layer, remaining_layers, AgingBasis, LayerConsumptionOrder,
+    StockAgingReportQuery, StockAgingReportResponse, StockAgingReportRow, TurnoverGroupBy,
+    TurnoverReportQuery, TurnoverReportResponse, TurnoverReportRow,
 };
 use inventory_service_core::services::reports::ReportsService;
 use shared_error::AppError;
 
+/// Stock aging by the last inbound move into each warehouse
+const LAST_INBOUND_AGING_SQL: &str = r#"
+    WITH current_stock AS (
+        SELECT
+            il.product_id,
+            il.warehouse_id,
+            il.location_id,
+            COALESCE(il.lot_serial_id, NULL) as lot_id,
+            SUM(il.available_quantity) as qty_on_hand
+        FROM inventory_levels il
+        WHERE il.tenant_id = $1
+          AND il.available_quantity > 0
+          AND il.deleted_at IS NULL
+          AND ($2::UUID IS NULL OR il.warehouse_id = $2)
+          AND ($3::UUID IS NULL OR il.location_id = $3)
+          AND ($4::UUID IS NULL OR il.product_id = $4)
+        GROUP BY il.product_id, il.warehouse_id, il.location_id, il.lot_serial_id
+    ),
+    last_inbound AS (
+        SELECT DISTINCT ON (sm.product_id, sm.destination_location_id)
+            sm.product_id,
+            sm.destination_location_id as warehouse_id,
+            sm.move_date as basis_timestamp
+        FROM stock_moves sm
+        WHERE sm.tenant_id = $1
+          AND sm.quantity > 0
+          AND sm.move_type IN ('receipt', 'grn', 'inbound', 'transfer_in')
+          AND sm.move_date <= $5
+        ORDER BY sm.product_id, sm.destination_location_id, sm.move_date DESC
+    )
+    SELECT
+        cs.product_id,
+        COALESCE(p.sku, '') as product_sku,
+        COALESCE(p.name, 'Unknown') as product_name,
+        NULL::UUID as variant_id,
+        cs.warehouse_id,
+        COALESCE(w.name, 'Unknown') as warehouse_name,
+        cs.location_id,
+        sl.name as location_name,
+        cs.lot_id,
+        ls.lot_number,
+        cs.qty_on_hand,
+        li.basis_timestamp,
+        EXTRACT(DAY FROM ($5::TIMESTAMPTZ - COALESCE(li.basis_timestamp, $5)))::INTEGER as age_days,
+        NULL::BIGINT as value_cents
+    FROM current_stock cs
+    LEFT JOIN products p ON cs.product_id = p.product_id AND p.tenant_id = $1
+    LEFT JOIN warehouses w ON cs.warehouse_id = w.warehouse_id AND w.tenant_id = $1
+    LEFT JOIN warehouse_locations sl ON cs.location_id = sl.location_id AND sl.tenant_id = $1
+    LEFT JOIN lots_serial_numbers ls ON cs.lot_id = ls.lot_serial_id AND ls.tenant_id = $1
+    LEFT JOIN last_inbound li ON cs.product_id = li.product_id AND cs.warehouse_id = li.warehouse_id
+    WHERE ($6::UUID IS NULL OR p.category_id = $6)
+    ORDER BY COALESCE(EXTRACT(DAY FROM ($5::TIMESTAMPTZ - li.basis_timestamp)), 9999) DESC, p.name
+    LIMIT $7 OFFSET $8
+"#;
+
+/// Stock aging by the last move of any type
+const LAST_MOVEMENT_AGING_SQL: &str = r#"
+    WITH current_stock AS (
+        SELECT
+            il.product_id,
+            il.warehouse_id,
+            il.location_id,
+            COALESCE(il.lot_serial_id, NULL) as lot_id,
+            SUM(il.available_quantity) as qty_on_hand
+        FROM inventory_levels il
+        WHERE il.tenant_id = $1
+          AND il.available_quantity > 0
+          AND il.deleted_at IS NULL
+          AND ($2::UUID IS NULL OR il.warehouse_id = $2)
+          AND ($3::UUID IS NULL OR il.location_id = $3)
+          AND ($4::UUID IS NULL OR il.product_id = $4)
+        GROUP BY il.product_id, il.warehouse_id, il.location_id, il.lot_serial_id
+    ),
+    last_movement AS (
+        SELECT DISTINCT ON (sm.product_id, COALESCE(sm.destination_location_id, sm.source_location_id))
+            sm.product_id,
+            COALESCE(sm.destination_location_id, sm.source_location_id) as warehouse_id,
+            sm.move_date as basis_timestamp
+        FROM stock_moves sm
+        WHERE sm.tenant_id = $1
+          AND sm.move_date <= $5
+        ORDER BY sm.product_id, COALESCE(sm.destination_location_id, sm.source_location_id), sm.move_date DESC
+    )
+    SELECT
+        cs.product_id,
+        COALESCE(p.sku, '') as product_sku,
+        COALESCE(p.name, 'Unknown') as product_name,
+        NULL::UUID as variant_id,
+        cs.warehouse_id,
+        COALESCE(w.name, 'Unknown') as warehouse_name,
+        cs.location_id,
+        sl.name as location_name,
+        cs.lot_id,
+        ls.lot_number,
+        cs.qty_on_hand,
+        lm.basis_timestamp,
+        EXTRACT(DAY FROM ($5::TIMESTAMPTZ - COALESCE(lm.basis_timestamp, $5)))::INTEGER as age_days,
+        NULL::BIGINT as value_cents
+    FROM current_stock cs
+    LEFT JOIN products p ON cs.product_id = p.product_id AND p.tenant_id = $1
+    LEFT JOIN warehouses w ON cs.warehouse_id = w.warehouse_id AND w.tenant_id = $1
+    LEFT JOIN warehouse_locations sl ON cs.location_id = sl.location_id AND sl.tenant_id = $1
+    LEFT JOIN lots_serial_numbers ls ON cs.lot_id = ls.lot_serial_id AND ls.tenant_id = $1
+    LEFT JOIN last_movement lm ON cs.product_id = lm.product_id AND cs.warehouse_id = lm.warehouse_id
+    WHERE ($6::UUID IS NULL OR p.category_id = $6)
+    ORDER BY COALESCE(EXTRACT(DAY FROM ($5::TIMESTAMPTZ - lm.basis_timestamp)), 9999) DESC, p.name
+    LIMIT $7 OFFSET $8
+"#;
+
+/// Current stock for cost-layer aging, with each product's valuation method
+const COST_LAYER_STOCK_SQL: &str = r#"
+    WITH current_stock AS (
+        SELECT
+            il.product_id,
+            il.warehouse_id,
+            il.location_id,
+            il.lot_serial_id as lot_id,
+            SUM(il.available_quantity) as qty_on_hand
+        FROM inventory_levels il
+        WHERE il.tenant_id = $1
+          AND il.available_quantity > 0
+          AND il.deleted_at IS NULL
+          AND ($2::UUID IS NULL OR il.warehouse_id = $2)
+          AND ($3::UUID IS NULL OR il.location_id = $3)
+          AND ($4::UUID IS NULL OR il.product_id = $4)
+        GROUP BY il.product_id, il.warehouse_id, il.location_id, il.lot_serial_id
+    )
+    SELECT
+        cs.product_id,
+        COALESCE(p.sku, '') as product_sku,
+        COALESCE(p.name, 'Unknown') as product_name,
+        NULL::UUID as variant_id,
+        cs.warehouse_id,
+        COALESCE(w.name, 'Unknown') as warehouse_name,
+        cs.location_id,
+        sl.name as location_name,
+        cs.lot_id,
+        ls.lot_number,
+        cs.qty_on_hand,
+        NULL::TIMESTAMPTZ as basis_timestamp,
+        NULL::INTEGER as age_days,
+        NULL::BIGINT as value_cents,
+        COALESCE(iv.valuation_method, 'fifo') as valuation_method
+    FROM current_stock cs
+    LEFT JOIN products p ON cs.product_id = p.product_id AND p.tenant_id = $1
+    LEFT JOIN warehouses w ON cs.warehouse_id = w.warehouse_id AND w.tenant_id = $1
+    LEFT JOIN warehouse_locations sl ON cs.location_id = sl.location_id AND sl.tenant_id = $1
+    LEFT JOIN lots_serial_numbers ls ON cs.lot_id = ls.lot_serial_id AND ls.tenant_id = $1
+    LEFT JOIN inventory_valuations iv ON cs.product_id = iv.product_id AND iv.tenant_id = $1
+    WHERE ($5::UUID IS NULL OR p.category_id = $5)
+"#;
+
+/// Move history for cost-layer aging, resolved to source/destination warehouses
+const COST_LAYER_MOVES_SQL: &str = r#"
+    SELECT
+        sm.product_id,
+        sl.warehouse_id as source_warehouse_id,
+        dl.warehouse_id as destination_warehouse_id,
+        sm.quantity::BIGINT as quantity,
+        sm.move_date
+    FROM stock_moves sm
+    LEFT JOIN warehouse_locations sl ON sm.source_location_id = sl.location_id AND sl.tenant_id = $1
+    LEFT JOIN warehouse_locations dl ON sm.destination_location_id = dl.location_id AND dl.tenant_id = $1
+    WHERE sm.tenant_id = $1
+      AND sm.product_id = ANY($2)
+      AND sm.move_date <= $3
+    ORDER BY sm.move_date, sm.move_id
+"#;
+
 /// PostgreSQL implementation of ReportsService
 pub struct PgReportsService {
     pool: Arc<PgPool>,
@@ -27,6 +199,120 @@ impl PgReportsService {
     pub fn new(pool: Arc<PgPool>) -> Self {
         Self { pool }
     }
+
+    /// Fetch one page of aging rows whose basis is computed in SQL
+    async fn fetch_aging_rows(
+        &self,
+        sql: &str,
+        tenant_id: Uuid,
+        query: &StockAgingReportQuery,
+        as_of: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<StockAgingRow>, AppError> {
+        sqlx::query_as::<_, StockAgingRow>(sql)
+            .bind(tenant_id)
+            .bind(query.warehouse_id)
+            .bind(query.location_id)
+            .bind(query.product_id)
+            .bind(as_of)
+            .bind(query.category_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to fetch stock aging: {}", e)))
+    }
+
+    /// Fetch one page of aging rows dated by their oldest remaining cost layer
+    ///
+    /// Each product's moves are replayed per warehouse, consuming layers in
+    /// the order of the product's valuation method. Ages are only known after
+    /// the replay, so sorting and pagination happen in memory.
+    async fn fetch_cost_layer_aging_rows(
+        &self,
+        tenant_id: Uuid,
+        query: &StockAgingReportQuery,
+        as_of: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<StockAgingRow>, AppError> {
+        let stock = sqlx::query_as::<_, CostLayerStockRow>(COST_LAYER_STOCK_SQL)
+            .bind(tenant_id)
+            .bind(query.warehouse_id)
+            .bind(query.location_id)
+            .bind(query.product_id)
+            .bind(query.category_id)
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to fetch stock aging: {}", e)))?;
+
+        if stock.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut product_ids: Vec<Uuid> = stock.iter().map(|row| row.stock.product_id).collect();
+        product_ids.sort_unstable();
+        product_ids.dedup();
+
+        let moves = sqlx::query_as::<_, CostLayerMoveRow>(COST_LAYER_MOVES_SQL)
+            .bind(tenant_id)
+            .bind(&product_ids)
+            .bind(as_of)
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(format!("Failed to fetch moves for stock aging: {}", e))
+            })?;
+
+        let mut moves_by_product: HashMap<Uuid, Vec<&CostLayerMoveRow>> = HashMap::new();
+        for stock_move in &moves {
+            moves_by_product
+                .entry(stock_move.product_id)
+                .or_default()
+                .push(stock_move);
+        }
+
+        let mut oldest_layers: HashMap<(Uuid, Uuid), Option<DateTime<Utc>>> = HashMap::new();
+        let mut rows: Vec<StockAgingRow> = stock
+            .into_iter()
+            .map(|row| {
+                let mut stock = row.stock;
+                let key = (stock.product_id, stock.warehouse_id);
+                let basis = *oldest_layers.entry(key).or_insert_with(|| {
+                    let history: Vec<(DateTime<Utc>, i64)> = moves_by_product
+                        .get(&stock.product_id)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|m| {
+                            m.signed_quantity(stock.warehouse_id)
+                                .map(|quantity| (m.move_date, quantity))
+                        })
+                        .collect();
+                    let order = LayerConsumptionOrder::for_valuation_method(&row.valuation_method);
+                    oldest_remaining_layer(&remaining_layers(&history, order))
+                });
+
+                stock.basis_timestamp = basis;
+                stock.age_days = basis.map(|basis| (as_of - basis).num_days() as i32);
+                stock
+            })
+            .collect();
+
+        // Same order as the SQL-based reports: unknown basis first, then oldest
+        rows.sort_by(|a, b| {
+            let age = |row: &StockAgingRow| row.age_days.unwrap_or(9999);
+            age(b)
+                .cmp(&age(a))
+                .then_with(|| a.product_name.cmp(&b.product_name))
+        });
+
+        Ok(rows
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
 }
 
 /// Helper struct for stock aging SQL results
@@ -48,6 +334,40 @@ struct StockAgingRow {
     value_cents: Option<i64>,
 }
 
+/// Current stock row for cost-layer aging
+#[derive(Debug, sqlx::FromRow)]
+struct CostLayerStockRow {
+    #[sqlx(flatten)]
+    stock: StockAgingRow,
+    valuation_method: String,
+}
+
+/// A stock move as seen by cost-layer aging
+#[derive(Debug, sqlx::FromRow)]
+struct CostLayerMoveRow {
+    product_id: Uuid,
+    source_warehouse_id: Option<Uuid>,
+    destination_warehouse_id: Option<Uuid>,
+    quantity: i64,
+    move_date: DateTime<Utc>,
+}
+
+impl CostLayerMoveRow {
+    /// Quantity entering (positive) or leaving (negative) a warehouse
+    ///
+    /// Returns None for moves that stay inside the warehouse or never touch it.
+    fn signed_quantity(&self, warehouse_id: Uuid) -> Option<i64> {
+        let inbound = self.destination_warehouse_id == Some(warehouse_id);
+        let outbound = self.source_warehouse_id == Some(warehouse_id);
+        match (inbound, outbound) {
+            // Keeps the sign so negative adjustments reduce stock
+            (true, false) => Some(self.quantity),
+            (false, true) => Some(-self.quantity.abs()),
+            _ => None,
+        }
+    }
+}
+
 /// Helper struct for turnover SQL results
 #[derive(Debug, sqlx::FromRow)]
 struct TurnoverRow {
@@ -74,133 +394,35 @@ impl ReportsService for PgReportsService {
         let page = query.page.unwrap_or(1).max(1);
         let offset = ((page - 1) as i64) * limit;
 
-        // Build the SQL query based on aging basis
-        let sql = match query.aging_basis {
+        let rows = match query.aging_basis {
             AgingBasis::LastInbound => {
-                r#"
-                WITH current_stock AS (
-                    SELECT
-                        il.product_id,
-                        il.warehouse_id,
-                        il.location_id,
-                        COALESCE(il.lot_serial_id, NULL) as lot_id,
-                        SUM(il.available_quantity) as qty_on_hand
-                    FROM inventory_levels il
-                    WHERE il.tenant_id = $1
-                      AND il.available_quantity > 0
-                      AND il.deleted_at IS NULL
-                      AND ($2::UUID IS NULL OR il.warehouse_id = $2)
-                      AND ($3::UUID IS NULL OR il.location_id = $3)
-                      AND ($4::UUID IS NULL OR il.product_id = $4)
-                    GROUP BY il.product_id, il.warehouse_id, il.location_id, il.lot_serial_id
-                ),
-                last_inbound AS (
-                    SELECT DISTINCT ON (sm.product_id, sm.destination_location_id)
-                        sm.product_id,
-                        sm.destination_location_id as warehouse_id,
-                        sm.move_date as basis_timestamp
-                    FROM stock_moves sm
-                    WHERE sm.tenant_id = $1
-                      AND sm.quantity > 0
-                      AND sm.move_type IN ('receipt', 'grn', 'inbound', 'transfer_in')
-                      AND sm.move_date <= $5
-                    ORDER BY sm.product_id, sm.destination_location_id, sm.move_date DESC
+                self.fetch_aging_rows(
+                    LAST_INBOUND_AGING_SQL,
+                    tenant_id,
+                    &query,
+                    as_of,
+                    limit,
+                    offset,
                 )
-                SELECT
-                    cs.product_id,
-                    COALESCE(p.sku, '') as product_sku,
-                    COALESCE(p.name, 'Unknown') as product_name,
-                    NULL::UUID as variant_id,
-                    cs.warehouse_id,
-                    COALESCE(w.name, 'Unknown') as warehouse_name,
-                    cs.location_id,
-                    sl.name as location_name,
-                    cs.lot_id,
-                    ls.lot_number,
-                    cs.qty_on_hand,
-                    li.basis_timestamp,
-                    EXTRACT(DAY FROM ($5::TIMESTAMPTZ - COALESCE(li.basis_timestamp, $5)))::INTEGER as age_days,
-                    NULL::BIGINT as value_cents
-                FROM current_stock cs
-                LEFT JOIN products p ON cs.product_id = p.product_id AND p.tenant_id = $1
-                LEFT JOIN warehouses w ON cs.warehouse_id = w.warehouse_id AND w.tenant_id = $1
-                LEFT JOIN warehouse_locations sl ON cs.location_id = sl.location_id AND sl.tenant_id = $1
-                LEFT JOIN lots_serial_numbers ls ON cs.lot_id = ls.lot_serial_id AND ls.tenant_id = $1
-                LEFT JOIN last_inbound li ON cs.product_id = li.product_id AND cs.warehouse_id = li.warehouse_id
-                WHERE ($6::UUID IS NULL OR p.category_id = $6)
-                ORDER BY COALESCE(EXTRACT(DAY FROM ($5::TIMESTAMPTZ - li.basis_timestamp)), 9999) DESC, p.name
-                LIMIT $7 OFFSET $8
-                "#
+                .await?
             },
             AgingBasis::LastMovement => {
-                r#"
-                WITH current_stock AS (
-                    SELECT
-                        il.product_id,
-                        il.warehouse_id,
-                        il.location_id,
-                        COALESCE(il.lot_serial_id, NULL) as lot_id,
-                        SUM(il.available_quantity) as qty_on_hand
-                    FROM inventory_levels il
-                    WHERE il.tenant_id = $1
-                      AND il.available_quantity > 0
-                      AND il.deleted_at IS NULL
-                      AND ($2::UUID IS NULL OR il.warehouse_id = $2)
-                      AND ($3::UUID IS NULL OR il.location_id = $3)
-                      AND ($4::UUID IS NULL OR il.product_id = $4)
-                    GROUP BY il.product_id, il.warehouse_id, il.location_id, il.lot_serial_id
-                ),
-                last_movement AS (
-                    SELECT DISTINCT ON (sm.product_id, COALESCE(sm.destination_location_id, sm.source_location_id))
-                        sm.product_id,
-                        COALESCE(sm.destination_location_id, sm.source_location_id) as warehouse_id,
-                        sm.move_date as basis_timestamp
-                    FROM stock_moves sm
-                    WHERE sm.tenant_id = $1
-                      AND sm.move_date <= $5
-                    ORDER BY sm.product_id, COALESCE(sm.destination_location_id, sm.source_location_id), sm.move_date DESC
+                self.fetch_aging_rows(
+                    LAST_MOVEMENT_AGING_SQL,
+                    tenant_id,
+                    &query,
+                    as_of,
+                    limit,
+                    offset,
                 )
-                SELECT
-                    cs.product_id,
-                    COALESCE(p.sku, '') as product_sku,
-                    COALESCE(p.name, 'Unknown') as product_name,
-                    NULL::UUID as variant_id,
-                    cs.warehouse_id,
-                    COALESCE(w.name, 'Unknown') as warehouse_name,
-                    cs.location_id,
-                    sl.name as location_name,
-                    cs.lot_id,
-                    ls.lot_number,
-                    cs.qty_on_hand,
-                    lm.basis_timestamp,
-                    EXTRACT(DAY FROM ($5::TIMESTAMPTZ - COALESCE(lm.basis_timestamp, $5)))::INTEGER as age_days,
-                    NULL::BIGINT as value_cents
-                FROM current_stock cs
-                LEFT JOIN products p ON cs.product_id = p.product_id AND p.tenant_id = $1
-                LEFT JOIN warehouses w ON cs.warehouse_id = w.warehouse_id AND w.tenant_id = $1
-                LEFT JOIN warehouse_locations sl ON cs.location_id = sl.location_id AND sl.tenant_id = $1
-                LEFT JOIN lots_serial_numbers ls ON cs.lot_id = ls.lot_serial_id AND ls.tenant_id = $1
-                LEFT JOIN last_movement lm ON cs.product_id = lm.product_id AND cs.warehouse_id = lm.warehouse_id
-                WHERE ($6::UUID IS NULL OR p.category_id = $6)
-                ORDER BY COALESCE(EXTRACT(DAY FROM ($5::TIMESTAMPTZ - lm.basis_timestamp)), 9999) DESC, p.name
-                LIMIT $7 OFFSET $8
-                "#
+                .await?
+            },
+            AgingBasis::CostLayers => {
+                self.fetch_cost_layer_aging_rows(tenant_id, &query, as_of, limit, offset)
+                    .await?
             },
         };
 
-        let rows = sqlx::query_as::<_, StockAgingRow>(sql)
-            .bind(tenant_id)
-            .bind(query.warehouse_id)
-            .bind(query.location_id)
-            .bind(query.product_id)
-            .bind(as_of)
-            .bind(query.category_id)
-            .bind(limit)
-            .bind(offset)
-            .fetch_all(self.pool.as_ref())
-            .await
-            .map_err(|e| AppError::DatabaseError(format!("Failed to fetch stock aging: {}", e)))?;
-
         // Count total for pagination
         let count_sql = r#"
             SELECT COUNT(DISTINCT (il.product_id, il.warehouse_id, il.location_id, il.lot_serial_id))
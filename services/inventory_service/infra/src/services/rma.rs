@@ -12,7 +12,7 @@ use inventory_service_core::dto::rma::{
     ReceiveRmaResponse,
 };
 use inventory_service_core::models::{
-    CreateStockMoveRequest, RmaAction, RmaItem, RmaRequest, RmaStatus,
+    MoveIntent, MoveSourceType, RmaAction, RmaItem, RmaRequest, RmaStatus,
 };
 use inventory_service_core::repositories::{RmaItemRepository, RmaRepository, StockMoveRepository};
 use inventory_service_core::services::rma::RmaService;
@@ -235,27 +235,26 @@ impl RmaService for PgRmaService {
                 // For now, we will use a placeholder destination_location_id
                 let destination_location_id = None; // TODO: Determine actual warehouse location
 
-                let stock_move = CreateStockMoveRequest {
-                    product_id: rma_item.product_id,
-                    source_location_id: None, // Customer virtual location
-                    destination_location_id,
-                    move_type: "rma_return".to_string(),
-                    quantity: received_item.received_quantity,
-                    unit_cost: rma_item.unit_cost,
-                    reference_type: "rma".to_string(),
-                    reference_id: rma_id,
-                    idempotency_key: format!("rma-{}-item-{}", rma_id, rma_item.rma_item_id),
-                    move_reason: Some(format!("RMA {} return", rma.rma_number)),
-                    lot_serial_id: None, // TODO: Set if lot-tracked product
-                    batch_info: None,
-                    metadata: Some(serde_json::json!({
-                        "rma_item_id": rma_item.rma_item_id,
-                        "condition": received_item.condition,
-                        "action": rma_item.action
-                    })),
-                };
+                // From Customer virtual location back to warehouse
+                let stock_move = MoveIntent::new(
+                    MoveSourceType::Rma,
+                    rma_id,
+                    "customer_return",
+                    rma_item.product_id,
+                    received_item.received_quantity,
+                    format!("rma-{}-item-{}", rma_id, rma_item.rma_item_id),
+                )
+                .with_locations(None, destination_location_id)
+                .with_unit_cost(rma_item.unit_cost)
+                // TODO: Set lot_serial_id if lot-tracked product
+                .with_note(format!("RMA {} return", rma.rma_number))
+                .with_metadata(Some(serde_json::json!({
+                    "rma_item_id": rma_item.rma_item_id,
+                    "condition": received_item.condition,
+                    "action": rma_item.action
+                })));
 
-                self.stock_move_repo.create(&stock_move, tenant_id).await?;
+                self.stock_move_repo.record(&stock_move, tenant_id).await?;
                 stock_moves_created += 1;
             }
         }
@@ -14,18 +14,32 @@ use inventory_service_core::dto::scrap::{
     ScrapDocumentResponse, ScrapDocumentWithLinesResponse, ScrapLine, ScrapListQuery,
     ScrapListResponse, ScrapReasonCode, ScrapStatus,
 };
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
 use inventory_service_core::services::scrap::ScrapService;
 use shared_error::AppError;
+use shared_types::{SharedClock, SystemClock};
+
+use crate::repositories::stock::PgStockMoveRepository;
 
 /// PostgreSQL implementation of ScrapService
 pub struct PgScrapService {
     pool: Arc<PgPool>,
+    clock: SharedClock,
 }
 
 impl PgScrapService {
     /// Create a new scrap service instance
     pub fn new(pool: Arc<PgPool>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Use a different time source for posting dates and expiry cut-offs
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
     }
 
     /// Convert database status string to ScrapStatus enum
@@ -123,6 +137,17 @@ impl ScrapLineRow {
     }
 }
 
+/// Expired lot eligible for an automatic scrap proposal
+#[derive(Debug, sqlx::FromRow)]
+struct ExpiredLotRow {
+    lot_serial_id: Uuid,
+    product_id: Uuid,
+    warehouse_id: Uuid,
+    lot_number: Option<String>,
+    remaining_quantity: i64,
+    expiry_date: chrono::DateTime<Utc>,
+}
+
 #[async_trait]
 impl ScrapService for PgScrapService {
     async fn create_scrap(
@@ -435,36 +460,24 @@ impl ScrapService for PgScrapService {
             ));
         }
 
-        let posted_at = Utc::now();
+        let posted_at = self.clock.now();
 
         // Create stock moves for each line
         for line in &line_rows {
-            let move_id = Uuid::now_v7();
-
             // Create stock move (negative quantity from source to scrap location)
-            sqlx::query(
-                r#"
-                INSERT INTO stock_moves (
-                    move_id, tenant_id, product_id, source_location_id, destination_location_id,
-                    move_type, quantity, reference_type, reference_id, move_date,
-                    move_reason, lot_serial_id, created_at
-                )
-                VALUES ($1, $2, $3, $4, $5, 'scrap', $6, 'scrap', $7, $8, $9, $10, $8)
-                "#,
+            let mut stock_move = MoveIntent::new(
+                MoveSourceType::Scrap,
+                scrap_id,
+                line.reason_code.as_deref().unwrap_or("scrap"),
+                line.product_id,
+                -line.qty, // Negative to decrease source
+                format!("scrap-{}-line-{}", scrap_id, line.scrap_line_id),
             )
-            .bind(move_id)
-            .bind(tenant_id)
-            .bind(line.product_id)
-            .bind(line.source_location_id)
-            .bind(doc_row.scrap_location_id)
-            .bind(-line.qty) // Negative to decrease source
-            .bind(scrap_id)
-            .bind(posted_at)
-            .bind(&line.reason)
-            .bind(line.lot_id)
-            .execute(&mut *tx)
-            .await
-            .map_err(|e| AppError::DatabaseError(format!("Failed to create stock move: {}", e)))?;
+            .with_locations(Some(line.source_location_id), Some(doc_row.scrap_location_id))
+            .with_lot_serial(line.lot_id);
+            stock_move.move_reason = line.reason.clone();
+            let move_id =
+                PgStockMoveRepository::insert_move(&mut tx, &stock_move, tenant_id).await?;
 
             // Update line with stock move reference
             sqlx::query(
@@ -607,6 +620,126 @@ impl ScrapService for PgScrapService {
             scrap: updated_row.into(),
         })
     }
+
+    async fn propose_expired_lot_scraps(
+        &self,
+        tenant_id: Uuid,
+        grace_days: u32,
+    ) -> Result<Vec<ScrapDocumentWithLinesResponse>, AppError> {
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                AppError::DatabaseError(format!("Failed to begin transaction: {}", e))
+            })?;
+
+        let now = self.clock.now();
+
+        // Lock candidate lots so concurrent runs cannot propose the same lot twice
+        let lots = sqlx::query_as::<_, ExpiredLotRow>(
+            r#"
+            SELECT
+                l.lot_serial_id, l.product_id, l.warehouse_id, l.lot_number,
+                l.remaining_quantity, l.expiry_date
+            FROM lots_serial_numbers l
+            WHERE l.tenant_id = $1
+              AND l.tracking_type = 'lot'
+              AND l.status IN ('active', 'expired', 'quarantined')
+              AND l.deleted_at IS NULL
+              AND l.warehouse_id IS NOT NULL
+              AND l.remaining_quantity > 0
+              AND l.expiry_date < $3 - make_interval(days => $2)
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM scrap_lines sl
+                  JOIN scrap_documents sd
+                    ON sd.tenant_id = sl.tenant_id AND sd.scrap_id = sl.scrap_id
+                  WHERE sl.tenant_id = l.tenant_id
+                    AND sl.lot_id = l.lot_serial_id
+                    AND sd.status <> 'cancelled'
+              )
+            ORDER BY l.warehouse_id, l.expiry_date, l.lot_serial_id
+            FOR UPDATE OF l SKIP LOCKED
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(grace_days as i32)
+        .bind(now)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to fetch expired lots: {}", e)))?;
+
+        let mut scrap_ids = Vec::new();
+        let mut current_warehouse: Option<Uuid> = None;
+        let mut current_scrap_id = Uuid::nil();
+
+        // Rows are ordered by warehouse, so each warehouse gets one document
+        for lot in &lots {
+            if current_warehouse != Some(lot.warehouse_id) {
+                current_scrap_id = Uuid::now_v7();
+                current_warehouse = Some(lot.warehouse_id);
+
+                sqlx::query(
+                    r#"
+                    INSERT INTO scrap_documents (
+                        tenant_id, scrap_id, reference, status, scrap_location_id, notes
+                    )
+                    VALUES ($1, $2, $3, 'draft', $4, $5)
+                    "#,
+                )
+                .bind(tenant_id)
+                .bind(current_scrap_id)
+                .bind(format!("EXPIRY-{}", now.format("%Y%m%d")))
+                .bind(lot.warehouse_id)
+                .bind(format!(
+                    "Proposed automatically for lots expired more than {} days ago",
+                    grace_days
+                ))
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(format!("Failed to create scrap document: {}", e))
+                })?;
+
+                scrap_ids.push(current_scrap_id);
+            }
+
+            sqlx::query(
+                r#"
+                INSERT INTO scrap_lines (
+                    tenant_id, scrap_line_id, scrap_id, product_id,
+                    source_location_id, lot_id, qty, reason_code, reason
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(Uuid::now_v7())
+            .bind(current_scrap_id)
+            .bind(lot.product_id)
+            .bind(lot.warehouse_id)
+            .bind(lot.lot_serial_id)
+            .bind(lot.remaining_quantity)
+            .bind(ScrapReasonCode::Expired.to_string())
+            .bind(format!(
+                "Lot {} expired on {}",
+                lot.lot_number.as_deref().unwrap_or("-"),
+                lot.expiry_date.format("%Y-%m-%d")
+            ))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to insert scrap line: {}", e)))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
+
+        let mut proposals = Vec::with_capacity(scrap_ids.len());
+        for scrap_id in scrap_ids {
+            proposals.push(self.get_scrap(tenant_id, scrap_id).await?);
+        }
+
+        Ok(proposals)
+    }
 }
 
 #[cfg(test)]
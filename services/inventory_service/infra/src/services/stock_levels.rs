@@ -1,7 +1,8 @@
 //! Stock Levels Service Implementation
 //!
 //! PostgreSQL implementation of the StockLevelsService trait for listing
-//! inventory stock levels with product and warehouse details.
+//! inventory stock levels with product and warehouse details and reading
+//! per-location level history.
 
 use async_trait::async_trait;
 use sqlx::PgPool;
@@ -10,11 +11,15 @@ use uuid::Uuid;
 
 use inventory_service_core::dto::common::PaginationInfo;
 use inventory_service_core::dto::stock_levels::{
-    StockLevelListQuery, StockLevelListResponse, StockLevelResponse, StockLevelSummary, StockStatus,
+    LevelHistoryQuery, LevelHistoryResponse, StockLevelListQuery, StockLevelListResponse,
+    StockLevelResponse, StockLevelSummary, StockStatus,
 };
+use inventory_service_core::repositories::InventoryLevelRepository;
 use inventory_service_core::services::stock_levels::StockLevelsService;
 use shared_error::AppError;
 
+use crate::repositories::stock::PgInventoryLevelRepository;
+
 /// PostgreSQL implementation of StockLevelsService
 pub struct PgStockLevelsService {
     pool: Arc<PgPool>,
@@ -40,6 +45,7 @@ struct StockLevelRow {
     warehouse_name: String,
     available_quantity: i64,
     reserved_quantity: i64,
+    backordered_quantity: i64,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -49,6 +55,7 @@ struct SummaryRow {
     total_products: Option<i64>,
     total_available_quantity: Option<i64>,
     total_reserved_quantity: Option<i64>,
+    total_backordered_quantity: Option<i64>,
     low_stock_count: Option<i64>,
     out_of_stock_count: Option<i64>,
 }
@@ -77,6 +84,7 @@ impl StockLevelsService for PgStockLevelsService {
             "warehouse_name" => "w.warehouse_name",
             "available_quantity" | "available" => "il.available_quantity",
             "reserved_quantity" | "reserved" => "il.reserved_quantity",
+            "backordered_quantity" | "backordered" => "il.backordered_quantity",
             "updated_at" => "il.updated_at",
             _ => "p.name",
         };
@@ -102,6 +110,7 @@ impl StockLevelsService for PgStockLevelsService {
                 w.warehouse_name,
                 il.available_quantity,
                 il.reserved_quantity,
+                il.backordered_quantity,
                 il.updated_at
             FROM inventory_levels il
             INNER JOIN products p ON p.product_id = il.product_id AND p.tenant_id = il.tenant_id
@@ -163,6 +172,7 @@ impl StockLevelsService for PgStockLevelsService {
                 COUNT(DISTINCT il.product_id)::bigint as total_products,
                 COALESCE(SUM(il.available_quantity), 0)::bigint as total_available_quantity,
                 COALESCE(SUM(il.reserved_quantity), 0)::bigint as total_reserved_quantity,
+                COALESCE(SUM(il.backordered_quantity), 0)::bigint as total_backordered_quantity,
                 COUNT(DISTINCT CASE WHEN il.available_quantity > 0 AND il.available_quantity <= 10 THEN il.product_id END)::bigint as low_stock_count,
                 COUNT(DISTINCT CASE WHEN il.available_quantity = 0 THEN il.product_id END)::bigint as out_of_stock_count
             FROM inventory_levels il
@@ -199,6 +209,7 @@ impl StockLevelsService for PgStockLevelsService {
                     warehouse_name: row.warehouse_name,
                     available_quantity: row.available_quantity,
                     reserved_quantity: row.reserved_quantity,
+                    backordered_quantity: row.backordered_quantity,
                     total_quantity: total,
                     status,
                     reorder_point,
@@ -214,9 +225,34 @@ impl StockLevelsService for PgStockLevelsService {
                 total_products: summary_row.total_products.unwrap_or(0),
                 total_available_quantity: summary_row.total_available_quantity.unwrap_or(0),
                 total_reserved_quantity: summary_row.total_reserved_quantity.unwrap_or(0),
+                total_backordered_quantity: summary_row.total_backordered_quantity.unwrap_or(0),
                 low_stock_count: summary_row.low_stock_count.unwrap_or(0),
                 out_of_stock_count: summary_row.out_of_stock_count.unwrap_or(0),
             },
         })
     }
+
+    async fn level_history(
+        &self,
+        tenant_id: Uuid,
+        query: LevelHistoryQuery,
+    ) -> Result<LevelHistoryResponse, AppError> {
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from >= to {
+                return Err(AppError::ValidationError(
+                    "'from' must be earlier than 'to'".to_string(),
+                ));
+            }
+        }
+
+        let entries = PgInventoryLevelRepository::new(self.pool.clone())
+            .history(tenant_id, query.location_id, query.product_id, query.from, query.to)
+            .await?;
+
+        Ok(LevelHistoryResponse {
+            location_id: query.location_id,
+            product_id: query.product_id,
+            entries,
+        })
+    }
 }
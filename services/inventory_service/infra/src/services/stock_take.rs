@@ -11,7 +11,7 @@ use inventory_service_core::dto::stock_take::{
     FinalizeStockTakeRequest, FinalizeStockTakeResponse, StockAdjustment, StockTakeDetailResponse,
     StockTakeListQuery, StockTakeListResponse,
 };
-use inventory_service_core::models::CreateStockMoveRequest;
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
 
 use inventory_service_core::repositories::product::ProductRepository;
 use inventory_service_core::repositories::stock::InventoryLevelRepository;
@@ -308,24 +308,16 @@ impl StockTakeService for PgStockTakeService {
                 // Prepare stock move for adjustment
                 // For stock take adjustments, both source and destination are NULL
                 // This represents a warehouse-level inventory correction (not a location transfer)
-                let stock_move = CreateStockMoveRequest {
-                    product_id: line.product_id,
-                    source_location_id: None,
-                    destination_location_id: None,
-                    move_type: "adjustment".to_string(),
-                    quantity: difference,
-                    unit_cost: None,
-                    reference_type: "adjustment".to_string(),
-                    reference_id: stock_take_id,
-                    idempotency_key: format!("st-{}-line-{}", stock_take_id, line.line_id),
-                    move_reason: Some(format!(
-                        "Stock take {} adjustment",
-                        stock_take.stock_take_number
-                    )),
-                    lot_serial_id: None, // TODO: Set if lot-tracked product
-                    batch_info: None,
-                    metadata: None,
-                };
+                let stock_move = MoveIntent::new(
+                    MoveSourceType::StockTake,
+                    stock_take_id,
+                    "stock_take_variance",
+                    line.product_id,
+                    difference,
+                    format!("st-{}-line-{}", stock_take_id, line.line_id),
+                )
+                // TODO: Set lot_serial_id if lot-tracked product
+                .with_note(format!("Stock take {} adjustment", stock_take.stock_take_number));
                 stock_moves_to_create.push(stock_move);
 
                 // Prepare inventory update
@@ -361,7 +353,7 @@ impl StockTakeService for PgStockTakeService {
             for stock_move in &stock_moves_to_create {
                 let (_move_id, new_tx) = self
                     .stock_move_repo
-                    .create_with_tx(current_tx, stock_move.clone(), tenant_id)
+                    .record_with_tx(current_tx, stock_move, tenant_id)
                     .await?;
                 current_tx = new_tx;
             }
@@ -450,18 +442,9 @@ impl StockTakeService for PgStockTakeService {
             .count(tenant_id, query.warehouse_id, query.status)
             .await?;
 
-        let total_pages = ((total + limit - 1) / limit).max(1) as u32;
-
         Ok(StockTakeListResponse {
             stock_takes,
-            pagination: PaginationInfo {
-                page: page as u32,
-                page_size: limit as u32,
-                total_items: total as u64,
-                total_pages,
-                has_next: page < total_pages as i64,
-                has_prev: page > 1,
-            },
+            pagination: PaginationInfo::new(page as u32, limit as u32, total as u64),
         })
     }
 }
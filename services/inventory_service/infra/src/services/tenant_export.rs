@@ -0,0 +1,286 @@
+//! Tenant Export Service Implementation
+//!
+//! Writes a tenant's rows to a temporary NDJSON file using keyset pagination,
+//! so only one page is held in memory, then uploads the file as a private
+//! object and hands back a presigned download URL.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+use uuid::Uuid;
+
+use inventory_service_core::dto::tenant_export::{
+    TenantExportEntity, TenantExportEntityCount, TenantExportResponse, TENANT_EXPORT_CONTENT_TYPE,
+};
+use inventory_service_core::services::tenant_export::TenantExportService;
+use inventory_service_core::Result;
+use shared_error::AppError;
+
+use crate::storage::SharedStorageClient;
+
+/// Rows read per page while exporting
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+/// Table and primary key column an entity is exported from
+fn entity_source(entity: TenantExportEntity) -> (&'static str, &'static str) {
+    match entity {
+        TenantExportEntity::Category => ("product_categories", "category_id"),
+        TenantExportEntity::Product => ("products", "product_id"),
+        TenantExportEntity::Warehouse => ("warehouses", "warehouse_id"),
+        TenantExportEntity::InventoryLevel => ("inventory_levels", "inventory_id"),
+        TenantExportEntity::StockMove => ("stock_moves", "move_id"),
+        TenantExportEntity::Valuation => ("inventory_valuations", "valuation_id"),
+        TenantExportEntity::ValuationLayer => ("inventory_valuation_layers", "layer_id"),
+        TenantExportEntity::ValuationHistory => ("inventory_valuation_history", "history_id"),
+    }
+}
+
+/// Write every exported row of a tenant to `writer` as NDJSON
+///
+/// All pages are read in one repeatable-read transaction, so the file is a
+/// consistent snapshot even while the tenant keeps trading.
+///
+/// # Returns
+/// Rows written per entity, in file order
+pub async fn write_tenant_export<W>(
+    pool: &PgPool,
+    tenant_id: Uuid,
+    writer: &mut W,
+) -> Result<Vec<TenantExportEntityCount>>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+
+    let mut counts = Vec::with_capacity(TenantExportEntity::ALL.len());
+    for entity in TenantExportEntity::ALL {
+        let (table, key) = entity_source(entity);
+        // Table and key names come from the fixed list above, never from input
+        let page_query = format!(
+            "SELECT t.{key}, to_jsonb(t) FROM {table} t
+             WHERE t.tenant_id = $1 AND ($2::uuid IS NULL OR t.{key} > $2)
+             ORDER BY t.{key}
+             LIMIT $3"
+        );
+
+        let mut rows = 0u64;
+        let mut after: Option<Uuid> = None;
+        loop {
+            let page: Vec<(Uuid, Value)> = sqlx::query_as(&page_query)
+                .bind(tenant_id)
+                .bind(after)
+                .bind(EXPORT_PAGE_SIZE)
+                .fetch_all(&mut *tx)
+                .await?;
+
+            for (_, data) in &page {
+                let mut line =
+                    serde_json::to_vec(&json!({ "entity": entity.as_str(), "data": data }))
+                        .map_err(|e| {
+                            AppError::InternalError(format!(
+                                "Failed to serialize export row: {}",
+                                e
+                            ))
+                        })?;
+                line.push(b'\n');
+                writer.write_all(&line).await.map_err(write_error)?;
+            }
+
+            rows += page.len() as u64;
+            match page.last() {
+                Some((last_key, _)) if page.len() as i64 == EXPORT_PAGE_SIZE => {
+                    after = Some(*last_key)
+                },
+                _ => break,
+            }
+        }
+
+        counts.push(TenantExportEntityCount { entity, rows });
+    }
+
+    writer.flush().await.map_err(write_error)?;
+    tx.commit().await?;
+
+    Ok(counts)
+}
+
+fn write_error(e: std::io::Error) -> AppError {
+    AppError::InternalError(format!("Failed to write export file: {}", e))
+}
+
+/// PostgreSQL + RustFS implementation of TenantExportService
+pub struct PgTenantExportService {
+    pool: Arc<PgPool>,
+    storage: SharedStorageClient,
+    /// Minimum time between two exports of the same tenant
+    cooldown: Duration,
+    /// Lifetime of the presigned download URL
+    url_ttl: Duration,
+}
+
+impl PgTenantExportService {
+    /// Create a new export service
+    pub fn new(
+        pool: Arc<PgPool>,
+        storage: SharedStorageClient,
+        cooldown: Duration,
+        url_ttl: Duration,
+    ) -> Self {
+        Self {
+            pool,
+            storage,
+            cooldown,
+            url_ttl,
+        }
+    }
+
+    /// Generate object key for an export file
+    fn generate_object_key(tenant_id: Uuid, export_id: Uuid) -> String {
+        format!("exports/{}/{}.ndjson", tenant_id, export_id)
+    }
+
+    /// Record a new export, refusing it while the tenant's last one is within the cooldown
+    async fn start_export(&self, tenant_id: Uuid, user_id: Uuid) -> Result<(Uuid, String)> {
+        let mut tx = self.pool.begin().await?;
+
+        // Serialize export requests of the same tenant
+        sqlx::query_scalar!(
+            r#"
+            SELECT tenant_id FROM tenants
+            WHERE tenant_id = $1 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+            tenant_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Tenant not found".to_string()))?;
+
+        // Failed exports do not count against the cooldown
+        let recent = sqlx::query_scalar!(
+            r#"
+            SELECT created_at FROM tenant_exports
+            WHERE tenant_id = $1 AND status <> 'failed'
+              AND created_at > NOW() - make_interval(secs => $2)
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+            tenant_id,
+            self.cooldown.as_secs_f64()
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(created_at) = recent {
+            let retry_at = created_at + chrono::Duration::seconds(self.cooldown.as_secs() as i64);
+            return Err(AppError::TooManyRequests(format!(
+                "Tenant was exported recently; a new export is possible after {}",
+                retry_at.to_rfc3339()
+            )));
+        }
+
+        let export_id = Uuid::now_v7();
+        let object_key = Self::generate_object_key(tenant_id, export_id);
+        sqlx::query!(
+            r#"
+            INSERT INTO tenant_exports (export_id, tenant_id, requested_by, object_key)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            export_id,
+            tenant_id,
+            user_id,
+            object_key
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok((export_id, object_key))
+    }
+
+    /// Write the export to a temporary file and upload it
+    async fn build_and_upload(
+        &self,
+        tenant_id: Uuid,
+        object_key: &str,
+        path: &Path,
+    ) -> Result<Vec<TenantExportEntityCount>> {
+        let file = tokio::fs::File::create(path).await.map_err(write_error)?;
+        let mut writer = BufWriter::new(file);
+        let counts = write_tenant_export(&self.pool, tenant_id, &mut writer).await?;
+        drop(writer);
+
+        self.storage
+            .upload_private_file(object_key, path, TENANT_EXPORT_CONTENT_TYPE)
+            .await?;
+        Ok(counts)
+    }
+
+    async fn finish_export(&self, export_id: Uuid, status: &str) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE tenant_exports
+            SET status = $2, completed_at = NOW()
+            WHERE export_id = $1
+            "#,
+            export_id,
+            status
+        )
+        .execute(&*self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TenantExportService for PgTenantExportService {
+    async fn export_tenant(&self, tenant_id: Uuid, user_id: Uuid) -> Result<TenantExportResponse> {
+        let (export_id, object_key) = self.start_export(tenant_id, user_id).await?;
+
+        let path = std::env::temp_dir().join(format!("tenant-export-{}.ndjson", export_id));
+        let result = self.build_and_upload(tenant_id, &object_key, &path).await;
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to remove export file");
+        }
+
+        let counts = match result {
+            Ok(counts) => counts,
+            Err(e) => {
+                if let Err(mark_err) = self.finish_export(export_id, "failed").await {
+                    tracing::error!(export_id = %export_id, error = %mark_err, "Failed to mark export failed");
+                }
+                return Err(e);
+            },
+        };
+        self.finish_export(export_id, "completed").await?;
+
+        let download_url = self
+            .storage
+            .presigned_download_url(&object_key, self.url_ttl)
+            .await?;
+        let expires_at = Utc::now() + chrono::Duration::seconds(self.url_ttl.as_secs() as i64);
+
+        tracing::info!(
+            tenant_id = %tenant_id,
+            export_id = %export_id,
+            requested_by = %user_id,
+            "Tenant export completed"
+        );
+
+        Ok(TenantExportResponse {
+            export_id,
+            tenant_id,
+            download_url,
+            expires_at,
+            counts,
+        })
+    }
+}
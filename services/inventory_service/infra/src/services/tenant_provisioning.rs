@@ -0,0 +1,188 @@
+//! Tenant Provisioning Service Implementation
+//!
+//! PostgreSQL implementation of TenantProvisioningService. Each default entity
+//! is looked up by its natural key and only inserted when missing, with the
+//! tenant row locked so concurrent seeds cannot create duplicates.
+
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use inventory_service_core::dto::tenant_provisioning::{
+    SeededEntity, TenantSeedConfig, TenantSeedResponse, WarehouseSeed,
+    DEFAULT_REMOVAL_STRATEGY_NAME,
+};
+use inventory_service_core::services::tenant_provisioning::TenantProvisioningService;
+use inventory_service_core::Result;
+use shared_error::AppError;
+
+/// PostgreSQL implementation of TenantProvisioningService
+pub struct PgTenantProvisioningService {
+    pool: Arc<PgPool>,
+    config: TenantSeedConfig,
+}
+
+impl PgTenantProvisioningService {
+    /// Create a new provisioning service seeding the given set of defaults
+    pub fn new(pool: Arc<PgPool>, config: TenantSeedConfig) -> Self {
+        Self { pool, config }
+    }
+
+    async fn seed_warehouse(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        seed: &WarehouseSeed,
+    ) -> Result<SeededEntity> {
+        let existing = sqlx::query_scalar!(
+            r#"
+            SELECT warehouse_id FROM warehouses
+            WHERE tenant_id = $1 AND warehouse_code = $2 AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            seed.code
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if let Some(id) = existing {
+            return Ok(SeededEntity { id, created: false });
+        }
+
+        let id = sqlx::query_scalar!(
+            r#"
+            INSERT INTO warehouses (tenant_id, warehouse_code, warehouse_name, warehouse_type)
+            VALUES ($1, $2, $3, 'main')
+            RETURNING warehouse_id
+            "#,
+            tenant_id,
+            seed.code,
+            seed.name
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(SeededEntity { id, created: true })
+    }
+
+    async fn seed_category(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        name: &str,
+    ) -> Result<SeededEntity> {
+        let existing = sqlx::query_scalar!(
+            r#"
+            SELECT category_id FROM product_categories
+            WHERE tenant_id = $1 AND parent_category_id IS NULL AND name = $2
+              AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            name
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if let Some(id) = existing {
+            return Ok(SeededEntity { id, created: false });
+        }
+
+        // path and level are filled in by the category path trigger
+        let id = sqlx::query_scalar!(
+            r#"
+            INSERT INTO product_categories (category_id, tenant_id, name, path)
+            VALUES ($1, $2, $3, '')
+            RETURNING category_id
+            "#,
+            Uuid::now_v7(),
+            tenant_id,
+            name
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(SeededEntity { id, created: true })
+    }
+
+    async fn seed_removal_strategy(
+        conn: &mut PgConnection,
+        tenant_id: Uuid,
+        strategy_type: &str,
+        user_id: Uuid,
+    ) -> Result<SeededEntity> {
+        let existing = sqlx::query_scalar!(
+            r#"
+            SELECT strategy_id FROM removal_strategies
+            WHERE tenant_id = $1 AND name = $2 AND deleted_at IS NULL
+            "#,
+            tenant_id,
+            DEFAULT_REMOVAL_STRATEGY_NAME
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if let Some(id) = existing {
+            return Ok(SeededEntity { id, created: false });
+        }
+
+        // Tenant-wide scope: no warehouse and no product
+        let id = sqlx::query_scalar!(
+            r#"
+            INSERT INTO removal_strategies (tenant_id, name, strategy_type, created_by)
+            VALUES ($1, $2, $3, $4)
+            RETURNING strategy_id
+            "#,
+            tenant_id,
+            DEFAULT_REMOVAL_STRATEGY_NAME,
+            strategy_type,
+            user_id
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(SeededEntity { id, created: true })
+    }
+}
+
+#[async_trait]
+impl TenantProvisioningService for PgTenantProvisioningService {
+    async fn seed_defaults(&self, tenant_id: Uuid, user_id: Uuid) -> Result<TenantSeedResponse> {
+        let mut tx = self.pool.begin().await?;
+
+        // Serialize seeds of the same tenant
+        sqlx::query_scalar!(
+            r#"
+            SELECT tenant_id FROM tenants
+            WHERE tenant_id = $1 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+            tenant_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Tenant not found".to_string()))?;
+
+        let warehouse = match &self.config.warehouse {
+            Some(seed) => Some(Self::seed_warehouse(&mut tx, tenant_id, seed).await?),
+            None => None,
+        };
+        let category = match &self.config.category_name {
+            Some(name) => Some(Self::seed_category(&mut tx, tenant_id, name).await?),
+            None => None,
+        };
+        let removal_strategy = match &self.config.removal_strategy {
+            Some(strategy_type) => {
+                Some(Self::seed_removal_strategy(&mut tx, tenant_id, strategy_type, user_id).await?)
+            },
+            None => None,
+        };
+
+        tx.commit().await?;
+
+        Ok(TenantSeedResponse {
+            tenant_id,
+            warehouse,
+            category,
+            removal_strategy,
+        })
+    }
+}
@@ -21,26 +21,156 @@ fn parse_date_to_datetime(date_str: &str) -> Result<DateTime<Utc>, String> {
     Err(format!("Invalid date format: {}", date_str))
 }
 
+/// Validate a create request and build the draft transfer and its items.
+/// Item `transfer_id`s are left nil until the header is inserted.
+fn build_draft_transfer(
+    tenant_id: Uuid,
+    user_id: Uuid,
+    request: CreateTransferRequest,
+) -> Result<(Transfer, Vec<TransferItem>), AppError> {
+    // Validate warehouses are different
+    if request.source_warehouse_id == request.destination_warehouse_id {
+        return Err(AppError::ValidationError(
+            "Source and destination warehouses must be different".to_string(),
+        ));
+    }
+
+    // Parse dates - accepts both YYYY-MM-DD and RFC3339 formats
+    let expected_ship_date = if let Some(date_str) = &request.expected_ship_date {
+        Some(parse_date_to_datetime(date_str).map_err(|_| {
+            AppError::ValidationError(
+                "Invalid expected_ship_date format. Use YYYY-MM-DD or ISO 8601 format.".to_string(),
+            )
+        })?)
+    } else {
+        None
+    };
+
+    let expected_receive_date = if let Some(date_str) = &request.expected_receive_date {
+        Some(parse_date_to_datetime(date_str).map_err(|_| {
+            AppError::ValidationError(
+                "Invalid expected_receive_date format. Use YYYY-MM-DD or ISO 8601 format."
+                    .to_string(),
+            )
+        })?)
+    } else {
+        None
+    };
+
+    // Calculate totals
+    let mut total_quantity = 0i64;
+    let mut total_value = 0i64;
+
+    // Validate item quantities are positive
+    for item_req in &request.items {
+        if item_req.quantity <= 0 {
+            return Err(AppError::ValidationError(format!(
+                "Transfer item quantity must be positive, got {}",
+                item_req.quantity
+            )));
+        }
+    }
+
+    // Note: Product and UOM existence/validation is handled by database constraints
+    // (foreign key constraints on product_id and uom_id in stock_transfer_items table)
+
+    let items: Vec<TransferItem> = request
+        .items
+        .into_iter()
+        .map(|item_req| {
+            let line_total = item_req.quantity * item_req.unit_cost.unwrap_or(0);
+            total_quantity += item_req.quantity;
+            total_value += line_total;
+
+            TransferItem {
+                transfer_item_id: Uuid::now_v7(),
+                tenant_id,
+                transfer_id: Uuid::nil(), // Will be set after transfer creation
+                product_id: item_req.product_id,
+                quantity: item_req.quantity,
+                uom_id: item_req.uom_id,
+                unit_cost: item_req.unit_cost,
+                line_total,
+                line_number: item_req.line_number,
+                source_zone_id: item_req.source_zone_id,
+                source_location_id: item_req.source_location_id,
+                destination_zone_id: item_req.destination_zone_id,
+                destination_location_id: item_req.destination_location_id,
+                notes: item_req.notes,
+                updated_by: Some(user_id),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                deleted_at: None,
+                deleted_by: None,
+            }
+        })
+        .collect();
+
+    // Create transfer
+    let transfer = Transfer {
+        transfer_id: Uuid::now_v7(),
+        tenant_id,
+        transfer_number: "".to_string(), // Will be set later
+        reference_number: request.reference_number,
+        source_warehouse_id: request.source_warehouse_id,
+        destination_warehouse_id: request.destination_warehouse_id,
+        status: TransferStatus::Draft,
+        transfer_type: request.transfer_type,
+        priority: request.priority,
+        transfer_date: Utc::now(),
+        expected_ship_date,
+        actual_ship_date: None,
+        expected_receive_date,
+        actual_receive_date: None,
+        shipping_method: request.shipping_method,
+        carrier: None,
+        tracking_number: None,
+        expected_arrival: None,
+        shipping_cost: None,
+        notes: request.notes,
+        reason: request.reason,
+        created_by: user_id,
+        updated_by: Some(user_id),
+        approved_by: None,
+        approved_at: None,
+        total_quantity,
+        total_value,
+        currency_code: "VND".to_string(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        deleted_at: None,
+        deleted_by: None,
+    };
+
+    Ok((transfer, items))
+}
+
 use inventory_service_core::domains::inventory::dto::transfer_dto::{
     CancelTransferRequest, CancelTransferResponse, ConfirmTransferRequest, ConfirmTransferResponse,
-    CreateTransferRequest, CreateTransferResponse, ListTransfersParams, ListTransfersResponse,
+    CreateTransferRequest, CreateTransferResponse, ListInTransitTransfersParams,
+    ListInTransitTransfersResponse, ListTransfersParams, ListTransfersResponse,
     ReceiveTransferRequest, ReceiveTransferResponse, TransferResponse,
+    UpdateTransferTrackingRequest,
 };
 use inventory_service_core::domains::inventory::transfer::{
     Transfer, TransferItem, TransferStatus,
 };
-use inventory_service_core::models::CreateStockMoveRequest;
-use inventory_service_core::repositories::stock::{InventoryLevelRepository, StockMoveRepository};
+use inventory_service_core::models::{MoveIntent, MoveSourceType};
+use inventory_service_core::repositories::stock::InventoryLevelRepository;
 use inventory_service_core::repositories::transfer::{TransferItemRepository, TransferRepository};
 use inventory_service_core::repositories::warehouse::WarehouseRepository;
 use inventory_service_core::services::transfer::TransferService;
 use shared_error::AppError;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::repositories::stock::PgStockMoveRepository;
+use crate::repositories::transfer::{PgTransferItemRepository, PgTransferRepository};
 
 /// PostgreSQL implementation of TransferService
 pub struct PgTransferService {
+    pool: Arc<PgPool>,
     transfer_repo: Arc<dyn TransferRepository>,
     transfer_item_repo: Arc<dyn TransferItemRepository>,
-    stock_move_repo: Arc<dyn StockMoveRepository>,
     inventory_repo: Arc<dyn InventoryLevelRepository>,
     warehouse_repo: Arc<dyn WarehouseRepository>,
 }
@@ -48,16 +178,16 @@ pub struct PgTransferService {
 impl PgTransferService {
     /// Create a new service instance
     pub fn new(
+        pool: Arc<PgPool>,
         transfer_repo: Arc<dyn TransferRepository>,
         transfer_item_repo: Arc<dyn TransferItemRepository>,
-        stock_move_repo: Arc<dyn StockMoveRepository>,
         inventory_repo: Arc<dyn InventoryLevelRepository>,
         warehouse_repo: Arc<dyn WarehouseRepository>,
     ) -> Self {
         Self {
+            pool,
             transfer_repo,
             transfer_item_repo,
-            stock_move_repo,
             inventory_repo,
             warehouse_repo,
         }
@@ -103,6 +233,248 @@ impl PgTransferService {
 
         Ok(location.location_id)
     }
+
+    /// Begin a transaction and lock the transfer row
+    ///
+    /// Returns the transfer's current status and receipt time so callers can
+    /// check the expected state while holding the lock.
+    async fn begin_locked(
+        &self,
+        tenant_id: Uuid,
+        transfer_id: Uuid,
+    ) -> Result<(Transaction<'static, Postgres>, String, Option<DateTime<Utc>>), AppError> {
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                AppError::DatabaseError(format!("Failed to begin transaction: {}", e))
+            })?;
+
+        let (status, actual_receive_date): (String, Option<DateTime<Utc>>) = sqlx::query_as(
+            r#"
+            SELECT status, actual_receive_date
+            FROM stock_transfers
+            WHERE tenant_id = $1 AND transfer_id = $2 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(transfer_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to lock transfer: {}", e)))?
+        .ok_or_else(|| AppError::NotFound("Transfer not found".to_string()))?;
+
+        Ok((tx, status, actual_receive_date))
+    }
+
+    /// Load the transfer, its items and the fallback locations for both sides
+    async fn load_for_posting(
+        &self,
+        tenant_id: Uuid,
+        transfer_id: Uuid,
+    ) -> Result<(Transfer, Vec<TransferItem>, Uuid, Uuid), AppError> {
+        let transfer = self
+            .transfer_repo
+            .find_by_id(tenant_id, transfer_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Transfer not found".to_string()))?;
+
+        let items = self
+            .transfer_item_repo
+            .find_by_transfer_id(tenant_id, transfer_id)
+            .await?;
+
+        // Get fallback locations for items without specified locations
+        let fallback_source_location_id = self
+            .get_or_create_default_location(tenant_id, transfer.source_warehouse_id)
+            .await?;
+        let fallback_destination_location_id = self
+            .get_or_create_default_location(tenant_id, transfer.destination_warehouse_id)
+            .await?;
+
+        Ok((transfer, items, fallback_source_location_id, fallback_destination_location_id))
+    }
+
+    /// Write the receipt moves, destination credits and status in `tx`
+    #[allow(clippy::too_many_arguments)]
+    async fn post_receipt(
+        &self,
+        tx: &mut Transaction<'static, Postgres>,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        transfer: &Transfer,
+        items: &[TransferItem],
+        fallback_source_location_id: Uuid,
+        fallback_destination_location_id: Uuid,
+    ) -> Result<usize, AppError> {
+        // Create stock moves from source to destination (complete the transfer)
+        // Module 4.5: Now supports location-level tracking from transfer items
+        let mut stock_moves_created = 0;
+        for item in items {
+            // Use item's location if specified, otherwise use fallback
+            let effective_source_location_id = item
+                .source_location_id
+                .unwrap_or(fallback_source_location_id);
+            let effective_destination_location_id = item
+                .destination_location_id
+                .unwrap_or(fallback_destination_location_id);
+
+            let stock_move = MoveIntent::new(
+                MoveSourceType::Transfer,
+                transfer.transfer_id,
+                "transfer_in",
+                item.product_id,
+                item.quantity, // Incoming to destination
+                format!(
+                    "transfer-receive-{}-item-{}",
+                    transfer.transfer_id, item.transfer_item_id
+                ),
+            )
+            .with_locations(
+                Some(effective_source_location_id),
+                Some(effective_destination_location_id),
+            )
+            .with_unit_cost(item.unit_cost)
+            // TODO: Set lot_serial_id if lot-tracked product
+            .with_note(format!("Transfer {} receipt", transfer.transfer_number));
+            PgStockMoveRepository::insert_move(tx, &stock_move, tenant_id).await?;
+            stock_moves_created += 1;
+
+            // Update inventory levels (increment destination)
+            // Module 4.5: Now supports location-level inventory tracking
+            self.inventory_repo
+                .update_available_quantity_in_tx(
+                    tx,
+                    tenant_id,
+                    transfer.destination_warehouse_id,
+                    item.destination_location_id,
+                    item.product_id,
+                    item.quantity,
+                )
+                .await?;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE stock_transfers
+            SET status = 'received', actual_receive_date = NOW(),
+                updated_by = $1, updated_at = NOW()
+            WHERE tenant_id = $2 AND transfer_id = $3 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .bind(tenant_id)
+        .bind(transfer.transfer_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to receive transfer: {}", e)))?;
+
+        Ok(stock_moves_created)
+    }
+
+    /// Compensate a receipt whose destination step failed
+    ///
+    /// The receipt transaction was rolled back, but the source was already
+    /// decremented when the transfer shipped, so the stock would otherwise be
+    /// lost. Returns every item to the source with a compensating stock move
+    /// and cancels the transfer in one transaction, provided the transfer is
+    /// still shipped. Only non-retryable failures are compensated; `cause` is
+    /// logged, never stored on the transfer.
+    #[allow(clippy::too_many_arguments)]
+    async fn compensate_failed_receipt(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        transfer: &Transfer,
+        items: &[TransferItem],
+        fallback_source_location_id: Uuid,
+        fallback_destination_location_id: Uuid,
+        cause: &AppError,
+    ) {
+        tracing::warn!(
+            tenant_id = %tenant_id,
+            transfer_id = %transfer.transfer_id,
+            error = %cause,
+            "Transfer receipt failed, returning stock to source"
+        );
+
+        let result = async {
+            let (mut tx, status, _) = self.begin_locked(tenant_id, transfer.transfer_id).await?;
+            if status != "shipped" {
+                // Another request already settled the transfer
+                return Ok(());
+            }
+
+            for item in items {
+                let stock_move = MoveIntent::new(
+                    MoveSourceType::Transfer,
+                    transfer.transfer_id,
+                    "transfer_compensation",
+                    item.product_id,
+                    item.quantity, // Returning to source
+                    format!(
+                        "transfer-compensate-{}-item-{}",
+                        transfer.transfer_id, item.transfer_item_id
+                    ),
+                )
+                .with_locations(
+                    Some(
+                        item.destination_location_id
+                            .unwrap_or(fallback_destination_location_id),
+                    ),
+                    Some(
+                        item.source_location_id
+                            .unwrap_or(fallback_source_location_id),
+                    ),
+                )
+                .with_unit_cost(item.unit_cost)
+                .with_note(format!(
+                    "Transfer {} compensation: receipt failed",
+                    transfer.transfer_number
+                ));
+                PgStockMoveRepository::insert_move(&mut tx, &stock_move, tenant_id).await?;
+
+                self.inventory_repo
+                    .update_available_quantity_in_tx(
+                        &mut tx,
+                        tenant_id,
+                        transfer.source_warehouse_id,
+                        item.source_location_id,
+                        item.product_id,
+                        item.quantity,
+                    )
+                    .await?;
+            }
+
+            sqlx::query(
+                r#"
+                UPDATE stock_transfers
+                SET status = 'cancelled', reason = $1, updated_by = $2, updated_at = NOW()
+                WHERE tenant_id = $3 AND transfer_id = $4 AND deleted_at IS NULL
+                "#,
+            )
+            .bind("Receipt failed, stock returned to source")
+            .bind(user_id)
+            .bind(tenant_id)
+            .bind(transfer.transfer_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to cancel transfer: {}", e)))?;
+
+            tx.commit().await.map_err(|e| {
+                AppError::DatabaseError(format!("Failed to commit transaction: {}", e))
+            })
+        }
+        .await;
+
+        if let Err(e) = result {
+            tracing::error!(
+                tenant_id = %tenant_id,
+                transfer_id = %transfer.transfer_id,
+                error = %e,
+                "Failed to compensate transfer receipt"
+            );
+        }
+    }
 }
 
 #[async_trait]
@@ -174,142 +546,63 @@ impl TransferService for PgTransferService {
         user_id: Uuid,
         request: CreateTransferRequest,
     ) -> Result<CreateTransferResponse, AppError> {
-        // Validate warehouses are different
-        if request.source_warehouse_id == request.destination_warehouse_id {
-            return Err(AppError::ValidationError(
-                "Source and destination warehouses must be different".to_string(),
-            ));
-        }
-
-        // Parse dates - accepts both YYYY-MM-DD and RFC3339 formats
-        let expected_ship_date = if let Some(date_str) = &request.expected_ship_date {
-            Some(parse_date_to_datetime(date_str).map_err(|_| {
-                AppError::ValidationError(
-                    "Invalid expected_ship_date format. Use YYYY-MM-DD or ISO 8601 format."
-                        .to_string(),
-                )
-            })?)
-        } else {
-            None
-        };
-
-        let expected_receive_date = if let Some(date_str) = &request.expected_receive_date {
-            Some(parse_date_to_datetime(date_str).map_err(|_| {
-                AppError::ValidationError(
-                    "Invalid expected_receive_date format. Use YYYY-MM-DD or ISO 8601 format."
-                        .to_string(),
-                )
-            })?)
-        } else {
-            None
-        };
-
-        // Calculate totals
-        let mut total_quantity = 0i64;
-        let mut total_value = 0i64;
-
-        // Validate item quantities are positive
-        for item_req in &request.items {
-            if item_req.quantity <= 0 {
-                return Err(AppError::ValidationError(format!(
-                    "Transfer item quantity must be positive, got {}",
-                    item_req.quantity
-                )));
-            }
-        }
-
-        // Note: Product and UOM existence/validation is handled by database constraints
-        // (foreign key constraints on product_id and uom_id in stock_transfer_items table)
+        self.create_transfers(tenant_id, user_id, vec![request])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::InternalError("No transfer was created".to_string()))
+    }
 
-        let items: Vec<TransferItem> = request
-            .items
+    async fn create_transfers(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        requests: Vec<CreateTransferRequest>,
+    ) -> Result<Vec<CreateTransferResponse>, AppError> {
+        // Validate everything before opening the transaction
+        let drafts = requests
             .into_iter()
-            .map(|item_req| {
-                let line_total = item_req.quantity * item_req.unit_cost.unwrap_or(0);
-                total_quantity += item_req.quantity;
-                total_value += line_total;
-
-                TransferItem {
-                    transfer_item_id: Uuid::now_v7(),
-                    tenant_id,
-                    transfer_id: Uuid::nil(), // Will be set after transfer creation
-                    product_id: item_req.product_id,
-                    quantity: item_req.quantity,
-                    uom_id: item_req.uom_id,
-                    unit_cost: item_req.unit_cost,
-                    line_total,
-                    line_number: item_req.line_number,
-                    source_zone_id: item_req.source_zone_id,
-                    source_location_id: item_req.source_location_id,
-                    destination_zone_id: item_req.destination_zone_id,
-                    destination_location_id: item_req.destination_location_id,
-                    notes: item_req.notes,
-                    updated_by: Some(user_id),
-                    created_at: Utc::now(),
-                    updated_at: Utc::now(),
-                    deleted_at: None,
-                    deleted_by: None,
-                }
-            })
-            .collect();
-
-        // Create transfer
-        let transfer = Transfer {
-            transfer_id: Uuid::now_v7(),
-            tenant_id,
-            transfer_number: "".to_string(), // Will be set later
-            reference_number: request.reference_number,
-            source_warehouse_id: request.source_warehouse_id,
-            destination_warehouse_id: request.destination_warehouse_id,
-            status: TransferStatus::Draft,
-            transfer_type: request.transfer_type,
-            priority: request.priority,
-            transfer_date: Utc::now(),
-            expected_ship_date,
-            actual_ship_date: None,
-            expected_receive_date,
-            actual_receive_date: None,
-            shipping_method: request.shipping_method,
-            carrier: None,
-            tracking_number: None,
-            shipping_cost: None,
-            notes: request.notes,
-            reason: request.reason,
-            created_by: user_id,
-            updated_by: Some(user_id),
-            approved_by: None,
-            approved_at: None,
-            total_quantity,
-            total_value,
-            currency_code: "VND".to_string(),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            deleted_at: None,
-            deleted_by: None,
-        };
-
-        let created_transfer = self.transfer_repo.create(tenant_id, &transfer).await?;
+            .map(|request| build_draft_transfer(tenant_id, user_id, request))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut tx =
+            self.pool.begin().await.map_err(|e| {
+                AppError::DatabaseError(format!("Failed to begin transaction: {}", e))
+            })?;
+
+        let mut responses = Vec::with_capacity(drafts.len());
+        for (transfer, items) in drafts {
+            let created_transfer =
+                PgTransferRepository::insert_transfer(&mut *tx, tenant_id, &transfer).await?;
+
+            // Set transfer_id for items and create them
+            let items_with_transfer_id: Vec<TransferItem> = items
+                .into_iter()
+                .map(|mut item| {
+                    item.transfer_id = created_transfer.transfer_id;
+                    item
+                })
+                .collect();
+
+            let created_items = PgTransferItemRepository::insert_items(
+                &mut *tx,
+                tenant_id,
+                &items_with_transfer_id,
+            )
+            .await?;
 
-        // Set transfer_id for items and create them
-        let items_with_transfer_id: Vec<TransferItem> = items
-            .into_iter()
-            .map(|mut item| {
-                item.transfer_id = created_transfer.transfer_id;
-                item
-            })
-            .collect();
+            responses.push(CreateTransferResponse {
+                transfer_id: created_transfer.transfer_id,
+                transfer_number: created_transfer.transfer_number,
+                status: created_transfer.status,
+                items_count: created_items.len(),
+            });
+        }
 
-        let created_items = self
-            .transfer_item_repo
-            .create_batch(tenant_id, &items_with_transfer_id)
-            .await?;
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
 
-        Ok(CreateTransferResponse {
-            transfer_id: created_transfer.transfer_id,
-            transfer_number: created_transfer.transfer_number,
-            status: created_transfer.status,
-            items_count: created_items.len(),
-        })
+        Ok(responses)
     }
 
     async fn confirm_transfer(
@@ -319,34 +612,19 @@ impl TransferService for PgTransferService {
         user_id: Uuid,
         _request: ConfirmTransferRequest,
     ) -> Result<ConfirmTransferResponse, AppError> {
-        // Find transfer
-        let transfer = self
-            .transfer_repo
-            .find_by_id(tenant_id, transfer_id)
-            .await?
-            .ok_or_else(|| AppError::NotFound("Transfer not found".to_string()))?;
-
-        if transfer.status != TransferStatus::Draft {
+        // Lock the transfer so concurrent confirmations cannot both debit
+        let (mut tx, status, _) = self.begin_locked(tenant_id, transfer_id).await?;
+        if status != "draft" {
             return Err(AppError::ValidationError(
                 "Only draft transfers can be confirmed".to_string(),
             ));
         }
 
-        // Get items
-        let items = self
-            .transfer_item_repo
-            .find_by_transfer_id(tenant_id, transfer_id)
-            .await?;
-
-        // Get fallback locations for items without specified locations
-        let fallback_source_location_id = self
-            .get_or_create_default_location(tenant_id, transfer.source_warehouse_id)
-            .await?;
-        let fallback_destination_location_id = self
-            .get_or_create_default_location(tenant_id, transfer.destination_warehouse_id)
-            .await?;
+        let (transfer, items, fallback_source_location_id, fallback_destination_location_id) =
+            self.load_for_posting(tenant_id, transfer_id).await?;
 
-        // Create stock moves from source to destination (simplified 2-step flow)
+        // Decrement source levels and create stock moves from source to
+        // destination (simplified 2-step flow) together with the status change
         // Module 4.5: Now supports location-level tracking from transfer items
         for item in &items {
             // Use item's source_location_id if specified, otherwise use fallback
@@ -357,42 +635,55 @@ impl TransferService for PgTransferService {
                 .destination_location_id
                 .unwrap_or(fallback_destination_location_id);
 
-            let stock_move = CreateStockMoveRequest {
-                product_id: item.product_id,
-                source_location_id: Some(effective_source_location_id),
-                destination_location_id: Some(effective_destination_location_id),
-                move_type: "transfer".to_string(),
-                quantity: -item.quantity, // Outgoing from source
-                unit_cost: item.unit_cost,
-                reference_type: "transfer".to_string(),
-                reference_id: transfer_id,
-                idempotency_key: format!("transfer-{}-item-{}", transfer_id, item.transfer_item_id),
-                move_reason: Some(format!("Transfer {} confirmation", transfer.transfer_number)),
-                lot_serial_id: None, // TODO: Set if lot-tracked product
-                batch_info: None,
-                metadata: None,
-            };
-            self.stock_move_repo.create(&stock_move, tenant_id).await?;
-        }
-
-        // Update inventory levels (decrement source)
-        // Module 4.5: Now supports location-level inventory tracking
-        for item in &items {
             self.inventory_repo
-                .update_available_quantity(
+                .update_available_quantity_in_tx(
+                    &mut tx,
                     tenant_id,
                     transfer.source_warehouse_id,
-                    item.source_location_id, // Use item's location if specified
+                    item.source_location_id,
                     item.product_id,
                     -item.quantity,
                 )
                 .await?;
+
+            let stock_move = MoveIntent::new(
+                MoveSourceType::Transfer,
+                transfer_id,
+                "transfer_out",
+                item.product_id,
+                -item.quantity, // Outgoing from source
+                format!("transfer-{}-item-{}", transfer_id, item.transfer_item_id),
+            )
+            .with_locations(
+                Some(effective_source_location_id),
+                Some(effective_destination_location_id),
+            )
+            .with_unit_cost(item.unit_cost)
+            // TODO: Set lot_serial_id if lot-tracked product
+            .with_note(format!("Transfer {} confirmation", transfer.transfer_number));
+            PgStockMoveRepository::insert_move(&mut tx, &stock_move, tenant_id).await?;
         }
 
         // Confirm transfer (set to Shipped)
-        self.transfer_repo
-            .confirm_transfer(tenant_id, transfer_id, user_id, user_id)
-            .await?;
+        sqlx::query(
+            r#"
+            UPDATE stock_transfers
+            SET status = 'shipped', approved_by = $1, approved_at = NOW(),
+                actual_ship_date = COALESCE(actual_ship_date, NOW()),
+                updated_by = $1, updated_at = NOW()
+            WHERE tenant_id = $2 AND transfer_id = $3 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .bind(tenant_id)
+        .bind(transfer_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to confirm transfer: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
 
         Ok(ConfirmTransferResponse {
             transfer_id,
@@ -408,85 +699,70 @@ impl TransferService for PgTransferService {
         user_id: Uuid,
         _request: ReceiveTransferRequest,
     ) -> Result<ReceiveTransferResponse, AppError> {
-        // Find transfer
-        let transfer = self
-            .transfer_repo
-            .find_by_id(tenant_id, transfer_id)
-            .await?
-            .ok_or_else(|| AppError::NotFound("Transfer not found".to_string()))?;
-
-        if transfer.status != TransferStatus::Shipped {
+        // Lock the transfer so the shipped -> received transition happens once
+        let (mut tx, status, actual_receive_date) =
+            self.begin_locked(tenant_id, transfer_id).await?;
+        if status == "received" {
+            // Already received: replaying the request must not credit twice
+            return Ok(ReceiveTransferResponse {
+                transfer_id,
+                status: TransferStatus::Received,
+                received_at: actual_receive_date.unwrap_or_else(Utc::now).to_rfc3339(),
+                stock_moves_created: 0,
+            });
+        }
+        if status != "shipped" {
             return Err(AppError::ValidationError(
                 "Only shipped transfers can be received".to_string(),
             ));
         }
 
-        // Get items
-        let items = self
-            .transfer_item_repo
-            .find_by_transfer_id(tenant_id, transfer_id)
-            .await?;
-
-        // Get fallback locations for items without specified locations
-        let fallback_source_location_id = self
-            .get_or_create_default_location(tenant_id, transfer.source_warehouse_id)
-            .await?;
-        let fallback_destination_location_id = self
-            .get_or_create_default_location(tenant_id, transfer.destination_warehouse_id)
-            .await?;
-
-        // Create stock moves from source to destination (complete the transfer)
-        // Module 4.5: Now supports location-level tracking from transfer items
-        let mut stock_moves_created = 0;
-        for item in &items {
-            // Use item's location if specified, otherwise use fallback
-            let effective_source_location_id = item
-                .source_location_id
-                .unwrap_or(fallback_source_location_id);
-            let effective_destination_location_id = item
-                .destination_location_id
-                .unwrap_or(fallback_destination_location_id);
-
-            let stock_move = CreateStockMoveRequest {
-                product_id: item.product_id,
-                source_location_id: Some(effective_source_location_id),
-                destination_location_id: Some(effective_destination_location_id),
-                move_type: "transfer".to_string(),
-                quantity: item.quantity, // Incoming to destination
-                unit_cost: item.unit_cost,
-                reference_type: "transfer".to_string(),
-                reference_id: transfer_id,
-                idempotency_key: format!(
-                    "transfer-receive-{}-item-{}",
-                    transfer_id, item.transfer_item_id
-                ),
-                move_reason: Some(format!("Transfer {} receipt", transfer.transfer_number)),
-                lot_serial_id: None, // TODO: Set if lot-tracked product
-                batch_info: None,
-                metadata: None,
-            };
-            self.stock_move_repo.create(&stock_move, tenant_id).await?;
-            stock_moves_created += 1;
-        }
+        let (transfer, items, fallback_source_location_id, fallback_destination_location_id) =
+            self.load_for_posting(tenant_id, transfer_id).await?;
 
-        // Update inventory levels (increment destination)
-        // Module 4.5: Now supports location-level inventory tracking
-        for item in &items {
-            self.inventory_repo
-                .update_available_quantity(
+        let posted = self
+            .post_receipt(
+                &mut tx,
+                tenant_id,
+                user_id,
+                &transfer,
+                &items,
+                fallback_source_location_id,
+                fallback_destination_location_id,
+            )
+            .await;
+        let stock_moves_created = match posted {
+            Ok(count) => {
+                tx.commit().await.map_err(|e| {
+                    AppError::DatabaseError(format!("Failed to commit transaction: {}", e))
+                })?;
+                count
+            },
+            Err(e) if e.is_retryable() => {
+                // A transient failure (pool timeout, deadlock, serialization
+                // conflict) rolls back and leaves the transfer shipped, so the
+                // receipt can simply be retried
+                drop(tx);
+                return Err(e);
+            },
+            Err(e) => {
+                // The source was decremented at shipment, so a failure here
+                // must return the stock to the source rather than leave it in
+                // limbo. Release the lock first; compensation re-acquires it.
+                drop(tx);
+                self.compensate_failed_receipt(
                     tenant_id,
-                    transfer.destination_warehouse_id,
-                    item.destination_location_id, // Use item's destination location if specified
-                    item.product_id,
-                    item.quantity,
+                    user_id,
+                    &transfer,
+                    &items,
+                    fallback_source_location_id,
+                    fallback_destination_location_id,
+                    &e,
                 )
-                .await?;
-        }
-
-        // Receive transfer
-        self.transfer_repo
-            .receive_transfer(tenant_id, transfer_id, user_id)
-            .await?;
+                .await;
+                return Err(e);
+            },
+        };
 
         // TODO: Publish inventory.transfer.completed event
 
@@ -505,35 +781,36 @@ impl TransferService for PgTransferService {
         user_id: Uuid,
         request: CancelTransferRequest,
     ) -> Result<CancelTransferResponse, AppError> {
-        // Find transfer
-        let transfer = self
-            .transfer_repo
-            .find_by_id(tenant_id, transfer_id)
-            .await?
-            .ok_or_else(|| AppError::NotFound("Transfer not found".to_string()))?;
+        let (mut tx, status, _) = self.begin_locked(tenant_id, transfer_id).await?;
 
         // Only draft or confirmed transfers can be cancelled
-        if transfer.status != TransferStatus::Draft && transfer.status != TransferStatus::Confirmed
-        {
+        if status != "draft" && status != "confirmed" {
             return Err(AppError::ValidationError(format!(
                 "Cannot cancel transfer in '{}' status. Only draft or confirmed transfers can be cancelled.",
-                match transfer.status {
-                    TransferStatus::Draft => "draft",
-                    TransferStatus::Confirmed => "confirmed",
-                    TransferStatus::PartiallyPicked => "partially_picked",
-                    TransferStatus::Picked => "picked",
-                    TransferStatus::PartiallyShipped => "partially_shipped",
-                    TransferStatus::Shipped => "shipped",
-                    TransferStatus::Received => "received",
-                    TransferStatus::Cancelled => "cancelled",
-                }
+                status
             )));
         }
 
         // Cancel the transfer
-        self.transfer_repo
-            .cancel_transfer(tenant_id, transfer_id, user_id, request.reason)
-            .await?;
+        sqlx::query(
+            r#"
+            UPDATE stock_transfers
+            SET status = 'cancelled', reason = COALESCE($1, reason),
+                updated_by = $2, updated_at = NOW()
+            WHERE tenant_id = $3 AND transfer_id = $4 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(request.reason)
+        .bind(user_id)
+        .bind(tenant_id)
+        .bind(transfer_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to cancel transfer: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to commit transaction: {}", e)))?;
 
         Ok(CancelTransferResponse {
             transfer_id,
@@ -541,4 +818,90 @@ impl TransferService for PgTransferService {
             cancelled_at: Utc::now().to_rfc3339(),
         })
     }
+
+    async fn update_tracking(
+        &self,
+        tenant_id: Uuid,
+        transfer_id: Uuid,
+        user_id: Uuid,
+        request: UpdateTransferTrackingRequest,
+    ) -> Result<TransferResponse, AppError> {
+        let transfer = self
+            .transfer_repo
+            .find_by_id(tenant_id, transfer_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Transfer not found".to_string()))?;
+
+        if matches!(transfer.status, TransferStatus::Received | TransferStatus::Cancelled) {
+            return Err(AppError::ValidationError(
+                "Cannot update tracking of a received or cancelled transfer".to_string(),
+            ));
+        }
+
+        let expected_arrival = if let Some(date_str) = &request.expected_arrival {
+            Some(parse_date_to_datetime(date_str).map_err(|_| {
+                AppError::ValidationError(
+                    "Invalid expected_arrival format. Use YYYY-MM-DD or ISO 8601 format."
+                        .to_string(),
+                )
+            })?)
+        } else {
+            None
+        };
+
+        // Arrival must come after shipping; fall back to the planned ship date
+        if let Some(arrival) = expected_arrival {
+            if let Some(ship_date) = transfer.actual_ship_date.or(transfer.expected_ship_date) {
+                if arrival <= ship_date {
+                    return Err(AppError::ValidationError(format!(
+                        "expected_arrival ({}) must be after the ship date ({})",
+                        arrival.to_rfc3339(),
+                        ship_date.to_rfc3339()
+                    )));
+                }
+            }
+        }
+
+        let carrier = request.carrier.map(|c| c.trim().to_string());
+        let tracking_number = request.tracking_number.map(|t| t.trim().to_string());
+        for (field, value) in [("carrier", &carrier), ("tracking_number", &tracking_number)] {
+            if value
+                .as_ref()
+                .is_some_and(|v| v.is_empty() || v.len() > 100)
+            {
+                return Err(AppError::ValidationError(format!(
+                    "{} must be between 1 and 100 characters",
+                    field
+                )));
+            }
+        }
+
+        self.transfer_repo
+            .update_tracking(
+                tenant_id,
+                transfer_id,
+                expected_arrival,
+                carrier,
+                tracking_number,
+                user_id,
+            )
+            .await?;
+
+        self.get_transfer(tenant_id, transfer_id).await
+    }
+
+    async fn list_in_transit(
+        &self,
+        tenant_id: Uuid,
+        params: ListInTransitTransfersParams,
+    ) -> Result<ListInTransitTransfersResponse, AppError> {
+        let limit = params.limit.unwrap_or(100).clamp(1, 500);
+
+        let items = self
+            .transfer_repo
+            .list_in_transit(tenant_id, params.destination_warehouse_id, Some(limit))
+            .await?;
+
+        Ok(ListInTransitTransfersResponse { items })
+    }
 }
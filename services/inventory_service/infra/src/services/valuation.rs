@@ -1,7 +1,7 @@
 //! Valuation service implementation
 //!
 //! Business logic implementation for inventory valuation operations.
-//! Supports FIFO, AVCO, and Standard costing methods with cost layer management.
+//! Supports FIFO, LIFO, AVCO, and Standard costing methods with cost layer management.
 
 use async_trait::async_trait;
 use chrono::Utc;
@@ -30,7 +30,7 @@ use inventory_service_core::Result;
 /// Implementation of ValuationService
 ///
 /// Provides business logic for inventory valuation operations including:
-/// - Valuation method management (FIFO, AVCO, Standard)
+/// - Valuation method management (FIFO, LIFO, AVCO, Standard)
 /// - Cost layer management for FIFO costing
 /// - Stock movement processing with automatic cost calculation
 /// - Cost adjustments and revaluations with audit trails
@@ -94,8 +94,8 @@ impl ValuationService for ValuationServiceImpl {
 
     /// Set the valuation method for a product
     ///
-    /// Creates valuation record if it doesn't exist.
-    /// For FIFO method, initializes cost layers from existing stock.
+    /// Creates valuation record if it doesn't exist, otherwise changes the
+    /// method and re-layers the existing stock.
     ///
     /// # Arguments
     /// * `request` - Request with tenant_id, product_id, and new valuation method
@@ -112,37 +112,38 @@ impl ValuationService for ValuationServiceImpl {
             .find_by_product_id(request.tenant_id, request.product_id)
             .await?;
 
-        // Reject changing to FIFO if product has existing inventory
-        if matches!(request.valuation_method, ValuationMethod::Fifo) {
-            if let Some(ref val) = existing {
-                if val.total_quantity > 0 {
-                    return Err(shared_error::AppError::BusinessError(
-                        "Cannot change to FIFO valuation method when product has existing inventory. \
-                         FIFO requires cost layer initialization from stock move history.".to_string(),
-                    ));
-                }
-            }
+        if existing.is_some() {
+            // Existing stock must be re-layered for the new method
+            return self.change_method(request).await;
         }
 
-        let valuation = if let Some(_val) = existing {
-            // Update existing
-            self.valuation_repo
-                .set_valuation_method(
-                    request.tenant_id,
-                    request.product_id,
-                    request.valuation_method.clone(),
-                    None, // TODO: get from auth context
-                )
-                .await?
-        } else {
-            // Create new valuation
-            let new_valuation = Valuation::new(
+        let new_valuation =
+            Valuation::new(request.tenant_id, request.product_id, request.valuation_method);
+        let valuation = self.valuation_repo.create(&new_valuation).await?;
+
+        Ok(self.valuation_to_dto(valuation))
+    }
+
+    /// Change the valuation method of an existing valuation
+    ///
+    /// Re-layers the stock on hand so quantity and total value carry over
+    /// to the new method.
+    ///
+    /// # Arguments
+    /// * `request` - Request with tenant_id, product_id, and new valuation method
+    ///
+    /// # Returns
+    /// Updated valuation data as DTO
+    async fn change_method(&self, request: SetValuationMethodRequest) -> Result<ValuationDto> {
+        let valuation = self
+            .valuation_repo
+            .change_valuation_method(
                 request.tenant_id,
                 request.product_id,
-                request.valuation_method.clone(),
-            );
-            self.valuation_repo.create(&new_valuation).await?
-        };
+                request.valuation_method,
+                None, // TODO: get from auth context
+            )
+            .await?;
 
         Ok(self.valuation_to_dto(valuation))
     }
@@ -360,6 +361,43 @@ impl ValuationService for ValuationServiceImpl {
         Ok(self.valuation_to_dto(updated))
     }
 
+    /// Rebuild FIFO cost layers from the stock move history
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    /// * `user_id` - Administrator requesting the rebuild
+    ///
+    /// # Returns
+    /// Updated valuation data as DTO
+    async fn rebuild_layers(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<ValuationDto> {
+        let updated = self
+            .valuation_repo
+            .rebuild_layers(tenant_id, product_id, user_id)
+            .await?;
+
+        Ok(self.valuation_to_dto(updated))
+    }
+
+    /// Recompute the AVCO running average from the stock move history
+    ///
+    /// # Arguments
+    /// * `tenant_id` - Tenant identifier
+    /// * `product_id` - Product identifier
+    ///
+    /// # Returns
+    /// Updated valuation
+    async fn recompute_avco(&self, tenant_id: Uuid, product_id: Uuid) -> Result<Valuation> {
+        self.valuation_repo
+            .recompute_avco(tenant_id, product_id, None)
+            .await
+    }
+
     /// Process stock movement and update valuation
     ///
     /// Handles receipts and deliveries for all valuation methods.
@@ -390,7 +428,7 @@ impl ValuationService for ValuationServiceImpl {
             .ok_or_else(|| shared_error::AppError::NotFound("Valuation not found".to_string()))?;
 
         let result = match pre_change_valuation.valuation_method {
-            ValuationMethod::Fifo => {
+            ValuationMethod::Fifo | ValuationMethod::Lifo => {
                 self.process_fifo_movement(
                     tenant_id,
                     product_id,
@@ -442,7 +480,7 @@ impl ValuationService for ValuationServiceImpl {
 
     /// Calculate current inventory value
     ///
-    /// For FIFO: sums all active layer values
+    /// For FIFO/LIFO: sums all active layer values
     /// For AVCO/Standard: returns stored total_value
     ///
     /// # Arguments
@@ -459,7 +497,7 @@ impl ValuationService for ValuationServiceImpl {
             .ok_or_else(|| shared_error::AppError::NotFound("Valuation not found".to_string()))?;
 
         match valuation.valuation_method {
-            ValuationMethod::Fifo => {
+            ValuationMethod::Fifo | ValuationMethod::Lifo => {
                 // Sum of all active layer values
                 let layers = self
                     .layer_repo
@@ -666,9 +704,10 @@ impl ValuationService for ValuationServiceImpl {
 }
 
 impl ValuationServiceImpl {
-    /// Process FIFO stock movement
+    /// Process FIFO or LIFO stock movement
     ///
-    /// Creates new layers for receipts, consumes existing layers for deliveries.
+    /// Creates new layers for receipts, consumes existing layers for deliveries
+    /// (oldest first for FIFO, newest first for LIFO).
     /// Updates valuation totals accordingly.
     ///
     /// # Arguments
@@ -3,13 +3,15 @@
 //! These tests validate the repository interactions for inventory valuation,
 //! supporting FIFO, AVCO, and Standard costing methods.
 
+use std::collections::HashMap;
+
 use chrono::Utc;
 use mockall::mock;
 use mockall::predicate::*;
 use uuid::Uuid;
 
 use inventory_service_core::domains::inventory::valuation::{
-    Valuation, ValuationHistory, ValuationLayer, ValuationMethod,
+    LayerConsumption, Valuation, ValuationHistory, ValuationLayer, ValuationMethod,
 };
 use inventory_service_core::repositories::valuation::{
     ValuationHistoryRepository, ValuationLayerRepository, ValuationRepository,
@@ -29,6 +31,12 @@ mock! {
             product_id: Uuid,
         ) -> Result<Option<Valuation>>;
 
+        async fn find_by_product_ids(
+            &self,
+            tenant_id: Uuid,
+            product_ids: &[Uuid],
+        ) -> Result<HashMap<Uuid, Valuation>>;
+
         async fn create(&self, valuation: &Valuation) -> Result<Valuation>;
 
         async fn update(
@@ -46,6 +54,14 @@ mock! {
             updated_by: Option<Uuid>,
         ) -> Result<Valuation>;
 
+        async fn change_valuation_method(
+            &self,
+            tenant_id: Uuid,
+            product_id: Uuid,
+            method: ValuationMethod,
+            updated_by: Option<Uuid>,
+        ) -> Result<Valuation>;
+
         async fn set_standard_cost(
             &self,
             tenant_id: Uuid,
@@ -80,6 +96,20 @@ mock! {
             reason: &str,
             updated_by: Option<Uuid>,
         ) -> Result<Valuation>;
+
+        async fn rebuild_layers(
+            &self,
+            tenant_id: Uuid,
+            product_id: Uuid,
+            updated_by: Option<Uuid>,
+        ) -> Result<Valuation>;
+
+        async fn recompute_avco(
+            &self,
+            tenant_id: Uuid,
+            product_id: Uuid,
+            updated_by: Option<Uuid>,
+        ) -> Result<Valuation>;
     }
 }
 
@@ -104,6 +134,20 @@ mock! {
             quantity_to_consume: i64,
         ) -> Result<i64>;
 
+        async fn consume_layers_detailed(
+            &self,
+            tenant_id: Uuid,
+            product_id: Uuid,
+            quantity_to_consume: i64,
+        ) -> Result<(i64, Vec<LayerConsumption>)>;
+
+        async fn consume_layers_lifo(
+            &self,
+            tenant_id: Uuid,
+            product_id: Uuid,
+            quantity_to_consume: i64,
+        ) -> Result<i64>;
+
         async fn get_total_quantity(&self, tenant_id: Uuid, product_id: Uuid) -> Result<i64>;
 
         async fn cleanup_empty_layers(&self, tenant_id: Uuid, product_id: Uuid) -> Result<i64>;
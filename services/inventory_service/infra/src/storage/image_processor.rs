@@ -10,7 +10,7 @@ use image::codecs::jpeg::JpegEncoder;
 use image::codecs::png::PngEncoder;
 use image::codecs::webp::WebPEncoder;
 use image::Limits;
-use image::{DynamicImage, GenericImageView, ImageFormat, ImageReader};
+use image::{DynamicImage, GenericImageView, ImageError, ImageFormat, ImageReader};
 use metrics::{counter, histogram};
 use shared_error::AppError;
 use std::io::Cursor;
@@ -30,6 +30,69 @@ const MAX_DECODE_DIMENSION: u32 = 16384; // 16K x 16K max
 /// Prevents ZIP bomb attacks via compressed images
 const MAX_DECODE_ALLOC_BYTES: u64 = 256 * 1024 * 1024;
 
+/// Default maximum width/height accepted for an uploaded image
+const DEFAULT_MAX_INPUT_DIMENSION: u32 = 8192;
+
+/// Default maximum pixel count accepted for an uploaded image (40 megapixels)
+const DEFAULT_MAX_INPUT_PIXELS: u64 = 40_000_000;
+
+/// Bounds on the dimensions of uploaded images
+///
+/// Checked against the image header before any pixel data is decoded, so a
+/// "pixel flood" image is rejected without allocating its frame buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageDimensionLimits {
+    /// Maximum width in pixels
+    pub max_width: u32,
+    /// Maximum height in pixels
+    pub max_height: u32,
+    /// Maximum decoded pixel count (width x height)
+    pub max_pixels: u64,
+}
+
+impl Default for ImageDimensionLimits {
+    fn default() -> Self {
+        Self {
+            max_width: DEFAULT_MAX_INPUT_DIMENSION,
+            max_height: DEFAULT_MAX_INPUT_DIMENSION,
+            max_pixels: DEFAULT_MAX_INPUT_PIXELS,
+        }
+    }
+}
+
+impl ImageDimensionLimits {
+    /// Read limits from `IMAGE_MAX_WIDTH`, `IMAGE_MAX_HEIGHT` and `IMAGE_MAX_PIXELS`
+    pub fn from_env() -> Result<Self, AppError> {
+        let limits = Self {
+            max_width: super::env_number("IMAGE_MAX_WIDTH", DEFAULT_MAX_INPUT_DIMENSION)?,
+            max_height: super::env_number("IMAGE_MAX_HEIGHT", DEFAULT_MAX_INPUT_DIMENSION)?,
+            max_pixels: super::env_number("IMAGE_MAX_PIXELS", DEFAULT_MAX_INPUT_PIXELS)?,
+        };
+        limits.validate()?;
+        Ok(limits)
+    }
+
+    /// Validate limits are usable and within what the decoder accepts
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.max_width == 0 || self.max_width > MAX_DECODE_DIMENSION {
+            return Err(AppError::ConfigError(format!(
+                "IMAGE_MAX_WIDTH must be between 1 and {}, got {}",
+                MAX_DECODE_DIMENSION, self.max_width
+            )));
+        }
+        if self.max_height == 0 || self.max_height > MAX_DECODE_DIMENSION {
+            return Err(AppError::ConfigError(format!(
+                "IMAGE_MAX_HEIGHT must be between 1 and {}, got {}",
+                MAX_DECODE_DIMENSION, self.max_height
+            )));
+        }
+        if self.max_pixels == 0 {
+            return Err(AppError::ConfigError("IMAGE_MAX_PIXELS must be positive".to_string()));
+        }
+        Ok(())
+    }
+}
+
 /// Image processing configuration
 #[derive(Clone, Debug)]
 pub struct ImageProcessingConfig {
@@ -37,6 +100,8 @@ pub struct ImageProcessingConfig {
     pub max_dimension: u32,
     /// JPEG quality (1-100)
     pub jpeg_quality: u8,
+    /// Bounds on the dimensions of input images
+    pub limits: ImageDimensionLimits,
 }
 
 impl Default for ImageProcessingConfig {
@@ -44,6 +109,7 @@ impl Default for ImageProcessingConfig {
         Self {
             max_dimension: MAX_IMAGE_DIMENSION,
             jpeg_quality: JPEG_QUALITY,
+            limits: ImageDimensionLimits::default(),
         }
     }
 }
@@ -76,6 +142,9 @@ pub fn process_product_image(
 ) -> Result<ProcessedImage, AppError> {
     let start = Instant::now();
 
+    // Reject oversized images before decoding any pixel data
+    check_image_dimensions(data, &config.limits)?;
+
     let img = load_image(data)?;
     let (orig_w, orig_h) = img.dimensions();
 
@@ -122,6 +191,52 @@ pub fn process_product_image(
     })
 }
 
+/// Check an image's dimensions against the limits by reading only its header
+///
+/// # Returns
+/// The image dimensions (width, height)
+///
+/// # Errors
+/// * `PayloadTooLarge` - width, height or pixel count exceeds the limits
+/// * `ValidationError` - the header cannot be read
+pub fn check_image_dimensions(
+    data: &[u8],
+    limits: &ImageDimensionLimits,
+) -> Result<(u32, u32), AppError> {
+    let mut reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| AppError::ValidationError(format!("Failed to detect image format: {}", e)))?;
+
+    // Only the header is read here; the bounds below are what protect decoding
+    reader.no_limits();
+
+    let (width, height) = reader.into_dimensions().map_err(|e| match e {
+        ImageError::Limits(_) => {
+            AppError::PayloadTooLarge("Image dimensions exceed the decoder's limits".to_string())
+        },
+        e => AppError::ValidationError(format!("Failed to read image dimensions: {}", e)),
+    })?;
+
+    if width > limits.max_width || height > limits.max_height {
+        counter!("image_dimension_rejected_total", "reason" => "dimension").increment(1);
+        return Err(AppError::PayloadTooLarge(format!(
+            "Image dimensions {}x{} exceed the maximum of {}x{}",
+            width, height, limits.max_width, limits.max_height
+        )));
+    }
+
+    let pixels = u64::from(width) * u64::from(height);
+    if pixels > limits.max_pixels {
+        counter!("image_dimension_rejected_total", "reason" => "pixels").increment(1);
+        return Err(AppError::PayloadTooLarge(format!(
+            "Image has {} pixels, exceeding the maximum of {}",
+            pixels, limits.max_pixels
+        )));
+    }
+
+    Ok((width, height))
+}
+
 /// Load image from bytes with security limits
 ///
 /// Applies decoding limits to prevent:
@@ -255,6 +370,96 @@ mod tests {
         assert_eq!(processed.content_type, "image/png");
     }
 
+    /// Encode a PNG chunk with its length and CRC
+    fn png_chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut crc = 0xFFFF_FFFFu32;
+        for byte in kind.iter().chain(data) {
+            crc ^= u32::from(*byte);
+            for _ in 0..8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+
+        let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+        chunk.extend_from_slice(kind);
+        chunk.extend_from_slice(data);
+        chunk.extend_from_slice(&(!crc).to_be_bytes());
+        chunk
+    }
+
+    /// A PNG whose header claims the given dimensions, without pixel data
+    fn create_png_header(width: u32, height: u32) -> Vec<u8> {
+        let mut ihdr = width.to_be_bytes().to_vec();
+        ihdr.extend_from_slice(&height.to_be_bytes());
+        // 8-bit RGB, default compression/filter, no interlace
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut data = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        data.extend(png_chunk(b"IHDR", &ihdr));
+        data.extend(png_chunk(b"IDAT", &[]));
+        data.extend(png_chunk(b"IEND", &[]));
+        data
+    }
+
+    #[test]
+    fn test_pixel_flood_image_rejected() {
+        let data = create_png_header(20_000, 20_000);
+        let config = ImageProcessingConfig::default();
+
+        let result = check_image_dimensions(&data, &config.limits);
+        assert!(matches!(result, Err(AppError::PayloadTooLarge(_))));
+
+        let result = process_product_image(&data, "image/png", &config);
+        assert!(matches!(result, Err(AppError::PayloadTooLarge(_))));
+    }
+
+    #[test]
+    fn test_dimension_limits() {
+        let data = create_test_png();
+
+        // 100x100 passes the defaults
+        let dims = check_image_dimensions(&data, &ImageDimensionLimits::default());
+        assert_eq!(dims.unwrap(), (100, 100));
+
+        let narrow = ImageDimensionLimits {
+            max_width: 99,
+            ..Default::default()
+        };
+        let result = check_image_dimensions(&data, &narrow);
+        assert!(matches!(result, Err(AppError::PayloadTooLarge(_))));
+
+        let few_pixels = ImageDimensionLimits {
+            max_pixels: 9_999,
+            ..Default::default()
+        };
+        let result = check_image_dimensions(&data, &few_pixels);
+        assert!(matches!(result, Err(AppError::PayloadTooLarge(_))));
+
+        // Within the side limits, but 64 megapixels
+        let large = create_png_header(8_000, 8_000);
+        let result = check_image_dimensions(&large, &ImageDimensionLimits::default());
+        assert!(matches!(result, Err(AppError::PayloadTooLarge(_))));
+    }
+
+    #[test]
+    fn test_dimension_limits_validation() {
+        assert!(ImageDimensionLimits::default().validate().is_ok());
+        let zero = ImageDimensionLimits {
+            max_pixels: 0,
+            ..Default::default()
+        };
+        assert!(zero.validate().is_err());
+        let beyond_decoder = ImageDimensionLimits {
+            max_width: MAX_DECODE_DIMENSION + 1,
+            ..Default::default()
+        };
+        assert!(beyond_decoder.validate().is_err());
+    }
+
     #[test]
     fn test_invalid_image_data() {
         let invalid_data = vec![0, 1, 2, 3, 4, 5];
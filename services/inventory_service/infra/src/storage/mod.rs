@@ -6,8 +6,12 @@
 
 pub mod image_processor;
 
-pub use image_processor::{process_product_image, ImageProcessingConfig, ProcessedImage};
+pub use image_processor::{
+    check_image_dimensions, process_product_image, ImageDimensionLimits, ImageProcessingConfig,
+    ProcessedImage,
+};
 
+use aws_sdk_s3::presigning::PresigningConfig;
 use aws_sdk_s3::primitives::ByteStream;
 use aws_sdk_s3::types::ObjectCannedAcl;
 use aws_sdk_s3::Client;
@@ -18,18 +22,16 @@ use std::time::{Duration, Instant};
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
 use tokio_retry::Retry;
 
-/// Maximum retry attempts for S3 operations
-const MAX_RETRIES: usize = 3;
-/// Base delay for exponential backoff (100ms)
-const RETRY_BASE_DELAY_MS: u64 = 100;
-
-/// Supported image magic bytes signatures
-const MAGIC_BYTES: &[(&str, &[u8])] = &[
-    ("image/jpeg", &[0xFF, 0xD8, 0xFF]),
-    ("image/png", &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A]),
-    ("image/gif", &[0x47, 0x49, 0x46, 0x38]), // GIF87a or GIF89a
-    ("image/webp", &[0x52, 0x49, 0x46, 0x46]), // RIFF header (need to check WEBP after)
-];
+/// Default retry attempts for S3 operations
+const DEFAULT_MAX_RETRIES: usize = 3;
+/// Default base delay for exponential backoff (100ms)
+const DEFAULT_RETRY_BASE_DELAY_MS: u64 = 100;
+/// Default cap on a single backoff delay (5s)
+const DEFAULT_RETRY_MAX_DELAY_MS: u64 = 5_000;
+
+/// Upper bounds accepted for retry settings
+const MAX_RETRIES_LIMIT: usize = 10;
+const RETRY_DELAY_LIMIT_MS: u64 = 60_000;
 
 /// Storage configuration
 #[derive(Clone, Debug)]
@@ -40,6 +42,14 @@ pub struct StorageConfig {
     pub bucket_name: String,
     pub region: String,
     pub public_url: Option<String>,
+    /// Retry attempts after the first failure (0 disables retries)
+    pub max_retries: usize,
+    /// Base delay for exponential backoff
+    pub retry_base_delay_ms: u64,
+    /// Cap on a single backoff delay
+    pub retry_max_delay_ms: u64,
+    /// Bounds on the dimensions of uploaded images
+    pub image_limits: ImageDimensionLimits,
 }
 
 impl StorageConfig {
@@ -62,7 +72,7 @@ impl StorageConfig {
             },
         };
 
-        Ok(Self {
+        let config = Self {
             endpoint: std::env::var("RUSTFS_ENDPOINT")
                 .or_else(|_| std::env::var("S3_ENDPOINT"))
                 .unwrap_or_else(|_| "http://localhost:9000".to_string()),
@@ -73,10 +83,188 @@ impl StorageConfig {
                 .unwrap_or_else(|_| "anthill-files".to_string()),
             region: std::env::var("RUSTFS_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
             public_url: std::env::var("RUSTFS_PUBLIC_URL").ok(),
-        })
+            max_retries: env_number("RUSTFS_MAX_RETRIES", DEFAULT_MAX_RETRIES)?,
+            retry_base_delay_ms: env_number(
+                "RUSTFS_RETRY_BASE_DELAY_MS",
+                DEFAULT_RETRY_BASE_DELAY_MS,
+            )?,
+            retry_max_delay_ms: env_number(
+                "RUSTFS_RETRY_MAX_DELAY_MS",
+                DEFAULT_RETRY_MAX_DELAY_MS,
+            )?,
+            image_limits: ImageDimensionLimits::from_env()?,
+        };
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validate retry settings are within sane bounds
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.max_retries > MAX_RETRIES_LIMIT {
+            return Err(AppError::ConfigError(format!(
+                "RUSTFS_MAX_RETRIES must be at most {}, got {}",
+                MAX_RETRIES_LIMIT, self.max_retries
+            )));
+        }
+        if self.retry_base_delay_ms == 0 || self.retry_base_delay_ms > RETRY_DELAY_LIMIT_MS {
+            return Err(AppError::ConfigError(format!(
+                "RUSTFS_RETRY_BASE_DELAY_MS must be between 1 and {}, got {}",
+                RETRY_DELAY_LIMIT_MS, self.retry_base_delay_ms
+            )));
+        }
+        if self.retry_max_delay_ms < self.retry_base_delay_ms
+            || self.retry_max_delay_ms > RETRY_DELAY_LIMIT_MS
+        {
+            return Err(AppError::ConfigError(format!(
+                "RUSTFS_RETRY_MAX_DELAY_MS must be between RUSTFS_RETRY_BASE_DELAY_MS and {}, got {}",
+                RETRY_DELAY_LIMIT_MS, self.retry_max_delay_ms
+            )));
+        }
+        self.image_limits.validate()
     }
 }
 
+/// Read a numeric environment variable, falling back to a default when unset
+fn env_number<T: std::str::FromStr>(name: &str, default: T) -> Result<T, AppError> {
+    match std::env::var(name) {
+        Ok(value) => value.trim().parse().map_err(|_| {
+            AppError::ConfigError(format!("{} must be a number, got '{}'", name, value))
+        }),
+        Err(_) => Ok(default),
+    }
+}
+
+/// Kinds of file content recognised by magic-byte validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+    Pdf,
+    Zip,
+    Xlsx,
+    Csv,
+}
+
+impl FileKind {
+    /// Image kinds accepted for avatars and product images
+    pub const IMAGES: &'static [FileKind] =
+        &[FileKind::Jpeg, FileKind::Png, FileKind::Gif, FileKind::Webp];
+
+    /// MIME type stored alongside the uploaded object
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            FileKind::Jpeg => "image/jpeg",
+            FileKind::Png => "image/png",
+            FileKind::Gif => "image/gif",
+            FileKind::Webp => "image/webp",
+            FileKind::Pdf => "application/pdf",
+            FileKind::Zip => "application/zip",
+            FileKind::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            FileKind::Csv => "text/csv",
+        }
+    }
+}
+
+/// Binary signatures, checked in order before the text heuristics
+const MAGIC_BYTES: &[(FileKind, &[u8])] = &[
+    (FileKind::Jpeg, &[0xFF, 0xD8, 0xFF]),
+    (FileKind::Png, &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A]),
+    (FileKind::Gif, &[0x47, 0x49, 0x46, 0x38]), // GIF87a or GIF89a
+    (FileKind::Webp, &[0x52, 0x49, 0x46, 0x46]), // RIFF header (need to check WEBP after)
+    (FileKind::Pdf, b"%PDF-"),
+    (FileKind::Zip, &[0x50, 0x4B, 0x03, 0x04]), // PK local file header
+];
+
+/// Bytes inspected by the CSV heuristic
+const CSV_SNIFF_BYTES: usize = 8 * 1024;
+
+/// Detect the kind of `data` from its content, ignoring any claimed type
+pub fn detect_file_kind(data: &[u8]) -> Option<FileKind> {
+    for (kind, magic) in MAGIC_BYTES {
+        if !data.starts_with(magic) {
+            continue;
+        }
+        match kind {
+            // RIFF header must be followed by WEBP
+            FileKind::Webp if data.get(8..12) != Some(&b"WEBP"[..]) => continue,
+            // XLSX is a ZIP container holding a workbook part (entry names are stored uncompressed)
+            FileKind::Zip if contains(data, b"xl/workbook.xml") => return Some(FileKind::Xlsx),
+            _ => return Some(*kind),
+        }
+    }
+
+    looks_like_csv(data).then_some(FileKind::Csv)
+}
+
+/// Validate file content against an allowlist of kinds by checking magic bytes
+///
+/// Returns the detected kind if it is allowed. An XLSX file is also accepted
+/// where plain ZIP archives are.
+pub fn validate_magic_bytes(data: &[u8], allowed: &[FileKind]) -> Result<FileKind, AppError> {
+    let allowed_names = || {
+        allowed
+            .iter()
+            .map(|kind| kind.mime_type())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let kind = detect_file_kind(data).ok_or_else(|| {
+        AppError::ValidationError(format!(
+            "Unrecognized file content: magic bytes do not match supported formats ({})",
+            allowed_names()
+        ))
+    })?;
+
+    let is_allowed =
+        allowed.contains(&kind) || (kind == FileKind::Xlsx && allowed.contains(&FileKind::Zip));
+    if !is_allowed {
+        return Err(AppError::ValidationError(format!(
+            "File type '{}' is not allowed. Allowed types: {}",
+            kind.mime_type(),
+            allowed_names()
+        )));
+    }
+
+    Ok(kind)
+}
+
+/// Heuristic CSV check: UTF-8 text without control characters whose first
+/// line contains a delimiter
+fn looks_like_csv(data: &[u8]) -> bool {
+    let data = data.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(data);
+    let sample = &data[..data.len().min(CSV_SNIFF_BYTES)];
+
+    let text = match std::str::from_utf8(sample) {
+        Ok(text) => text,
+        // The sample may cut a multi-byte character in half
+        Err(e) if e.error_len().is_none() => {
+            std::str::from_utf8(&sample[..e.valid_up_to()]).unwrap_or_default()
+        },
+        Err(_) => return false,
+    };
+
+    if text.trim().is_empty()
+        || text
+            .chars()
+            .any(|c| c.is_control() && !matches!(c, '\t' | '\r' | '\n'))
+    {
+        return false;
+    }
+
+    let header = text.lines().next().unwrap_or_default();
+    header.contains([',', ';', '\t'])
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
 /// Validate image by checking magic bytes
 ///
 /// Returns the detected MIME type if valid, error otherwise
@@ -85,23 +273,14 @@ pub fn validate_image_magic_bytes(data: &[u8]) -> Result<String, AppError> {
         return Err(AppError::ValidationError("File too small to be a valid image".to_string()));
     }
 
-    for (mime_type, magic) in MAGIC_BYTES {
-        if data.starts_with(magic) {
-            // Special check for WebP: RIFF header must be followed by WEBP
-            if *mime_type == "image/webp" {
-                if data.len() >= 12 && &data[8..12] == b"WEBP" {
-                    return Ok(mime_type.to_string());
-                }
-                continue;
-            }
-            return Ok(mime_type.to_string());
-        }
-    }
-
-    Err(AppError::ValidationError(
-        "Invalid image file: magic bytes do not match supported formats (JPEG, PNG, GIF, WebP)"
-            .to_string(),
-    ))
+    validate_magic_bytes(data, FileKind::IMAGES)
+        .map(|kind| kind.mime_type().to_string())
+        .map_err(|_| {
+            AppError::ValidationError(
+                "Invalid image file: magic bytes do not match supported formats (JPEG, PNG, GIF, WebP)"
+                    .to_string(),
+            )
+        })
 }
 
 /// S3 Storage client wrapper with enterprise features
@@ -113,6 +292,8 @@ pub struct StorageClient {
 impl StorageClient {
     /// Create a new storage client
     pub async fn new(config: StorageConfig) -> Result<Self, AppError> {
+        config.validate()?;
+
         let credentials = aws_sdk_s3::config::Credentials::new(
             &config.access_key,
             &config.secret_key,
@@ -140,13 +321,13 @@ impl StorageClient {
         Self::new(config).await
     }
 
-    /// Get retry strategy with exponential backoff and jitter
-    fn retry_strategy() -> impl Iterator<Item = Duration> {
-        ExponentialBackoff::from_millis(RETRY_BASE_DELAY_MS)
+    /// Get retry strategy with exponential backoff and jitter from the configured policy
+    fn retry_strategy(&self) -> impl Iterator<Item = Duration> {
+        ExponentialBackoff::from_millis(self.config.retry_base_delay_ms)
             .factor(2)
-            .max_delay(Duration::from_secs(5))
+            .max_delay(Duration::from_millis(self.config.retry_max_delay_ms))
             .map(jitter)
-            .take(MAX_RETRIES)
+            .take(self.config.max_retries)
     }
 
     /// Upload a file to storage with retry logic
@@ -174,7 +355,7 @@ impl StorageClient {
         let content_type_owned = content_type.to_string();
         let client = self.client.clone();
 
-        let result = Retry::spawn(Self::retry_strategy(), || {
+        let result = Retry::spawn(self.retry_strategy(), || {
             let data = data_clone.clone();
             let bucket = bucket.clone();
             let key = key_owned.clone();
@@ -233,13 +414,13 @@ impl StorageClient {
 
                 Err(AppError::InternalError(format!(
                     "Failed to upload file after {} retries: {}",
-                    MAX_RETRIES, e
+                    self.config.max_retries, e
                 )))
             },
         }
     }
 
-    /// Upload a validated image (validates magic bytes before upload)
+    /// Upload a validated image (validates magic bytes and dimensions before upload)
     ///
     /// # Arguments
     /// * `key` - The object key (path) in the bucket
@@ -257,6 +438,9 @@ impl StorageClient {
         // Validate magic bytes
         let detected_type = validate_image_magic_bytes(&data)?;
 
+        // Validate dimensions from the header, without decoding
+        check_image_dimensions(&data, &self.config.image_limits)?;
+
         // Log if claimed type doesn't match detected type (potential attack)
         if claimed_content_type != detected_type {
             tracing::warn!(
@@ -273,6 +457,152 @@ impl StorageClient {
         Ok((url, detected_type))
     }
 
+    /// Upload a file whose content must match one of the allowed kinds
+    ///
+    /// Like [`Self::upload_validated_image`], the detected MIME type is stored
+    /// instead of the claimed one.
+    ///
+    /// # Returns
+    /// The public URL and detected file kind
+    pub async fn upload_validated_file(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        claimed_content_type: &str,
+        allowed: &[FileKind],
+    ) -> Result<(String, FileKind), AppError> {
+        let kind = validate_magic_bytes(&data, allowed)?;
+
+        if claimed_content_type != kind.mime_type() {
+            tracing::warn!(
+                claimed = %claimed_content_type,
+                detected = %kind.mime_type(),
+                key = %key,
+                "Content-Type mismatch: using detected type"
+            );
+            counter!("storage_content_type_mismatch").increment(1);
+        }
+
+        let url = self.upload(key, data, kind.mime_type()).await?;
+        Ok((url, kind))
+    }
+
+    /// Upload a local file as a private object with retry logic
+    ///
+    /// The file is streamed from disk rather than read into memory, and no
+    /// public-read ACL is set: the object is only reachable through
+    /// [`Self::presigned_download_url`].
+    pub async fn upload_private_file(
+        &self,
+        key: &str,
+        path: &std::path::Path,
+        content_type: &str,
+    ) -> Result<(), AppError> {
+        let start = Instant::now();
+        let data_len = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Failed to read upload file: {}", e)))?
+            .len();
+
+        let bucket = self.config.bucket_name.clone();
+        let key_owned = key.to_string();
+        let content_type_owned = content_type.to_string();
+        let path_owned = path.to_path_buf();
+        let client = self.client.clone();
+
+        let result = Retry::spawn(self.retry_strategy(), || {
+            let bucket = bucket.clone();
+            let key = key_owned.clone();
+            let content_type = content_type_owned.clone();
+            let path = path_owned.clone();
+            let client = client.clone();
+
+            async move {
+                // Each attempt re-opens the file so the body starts from the beginning
+                let body = ByteStream::from_path(&path).await.map_err(|e| {
+                    tracing::warn!("Failed to open upload file, may retry: {:?}", e);
+                    e.to_string()
+                })?;
+
+                client
+                    .put_object()
+                    .bucket(&bucket)
+                    .key(&key)
+                    .body(body)
+                    .content_type(&content_type)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        tracing::warn!("S3 upload attempt failed, may retry: {:?}", e);
+                        e.to_string()
+                    })
+            }
+        })
+        .await;
+
+        let duration = start.elapsed();
+
+        match result {
+            Ok(_) => {
+                counter!("storage_upload_total", "status" => "success").increment(1);
+                histogram!("storage_upload_duration_seconds").record(duration.as_secs_f64());
+                histogram!("storage_upload_bytes").record(data_len as f64);
+
+                tracing::info!(
+                    key = %key,
+                    size_bytes = %data_len,
+                    duration_ms = %duration.as_millis(),
+                    "Private file uploaded successfully"
+                );
+                Ok(())
+            },
+            Err(e) => {
+                counter!("storage_upload_total", "status" => "failure").increment(1);
+
+                tracing::error!(
+                    key = %key,
+                    error = %e,
+                    duration_ms = %duration.as_millis(),
+                    "Failed to upload private file after retries"
+                );
+
+                Err(AppError::InternalError(format!(
+                    "Failed to upload file after {} retries: {}",
+                    self.config.max_retries, e
+                )))
+            },
+        }
+    }
+
+    /// Create a time-limited GET URL for a (private) object
+    pub async fn presigned_download_url(
+        &self,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<String, AppError> {
+        let presigning = PresigningConfig::expires_in(expires_in).map_err(|e| {
+            AppError::InternalError(format!("Invalid presigned URL lifetime: {}", e))
+        })?;
+
+        let request = self
+            .client
+            .get_object()
+            .bucket(&self.config.bucket_name)
+            .key(key)
+            .presigned(presigning)
+            .await
+            .map_err(|e| {
+                AppError::InternalError(format!("Failed to presign download URL: {}", e))
+            })?;
+
+        Ok(request.uri().to_string())
+    }
+
+    /// Bounds applied to uploaded image dimensions
+    pub fn image_limits(&self) -> &ImageDimensionLimits {
+        &self.config.image_limits
+    }
+
     /// Get the public URL for an object
     pub fn get_public_url(&self, key: &str) -> String {
         if let Some(public_url) = &self.config.public_url {
@@ -289,7 +619,7 @@ impl StorageClient {
         let key_owned = key.to_string();
         let client = self.client.clone();
 
-        let result = Retry::spawn(Self::retry_strategy(), || {
+        let result = Retry::spawn(self.retry_strategy(), || {
             let bucket = bucket.clone();
             let key = key_owned.clone();
             let client = client.clone();
@@ -335,12 +665,25 @@ impl StorageClient {
 
                 Err(AppError::InternalError(format!(
                     "Failed to delete file after {} retries: {}",
-                    MAX_RETRIES, e
+                    self.config.max_retries, e
                 )))
             },
         }
     }
 
+    /// Delete a file given its public URL
+    ///
+    /// Returns `ValidationError` if the URL does not point into our bucket.
+    pub async fn delete_by_url(&self, url: &str) -> Result<(), AppError> {
+        let key = self
+            .extract_key_from_url(url)
+            .filter(|key| !key.is_empty())
+            .ok_or_else(|| {
+                AppError::ValidationError(format!("URL does not belong to this storage: {}", url))
+            })?;
+        self.delete(&key).await
+    }
+
     /// Delete a file silently (log errors but don't fail)
     /// Useful for cleanup operations where failure shouldn't block the main operation
     pub async fn delete_silent(&self, key: &str) {
@@ -442,4 +785,121 @@ mod tests {
         let result = validate_image_magic_bytes(&small_data);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_detect_pdf() {
+        let pdf_data = b"%PDF-1.7\n%\xE2\xE3\xCF\xD3\n1 0 obj\n".to_vec();
+        assert_eq!(detect_file_kind(&pdf_data), Some(FileKind::Pdf));
+        assert_eq!(validate_magic_bytes(&pdf_data, &[FileKind::Pdf]).unwrap(), FileKind::Pdf);
+    }
+
+    #[test]
+    fn test_detect_zip_and_xlsx() {
+        let mut zip_data = vec![0x50, 0x4B, 0x03, 0x04, 0x14, 0x00, 0x00, 0x00];
+        zip_data.extend_from_slice(b"notes.txt");
+        assert_eq!(detect_file_kind(&zip_data), Some(FileKind::Zip));
+
+        let mut xlsx_data = vec![0x50, 0x4B, 0x03, 0x04, 0x14, 0x00, 0x00, 0x00];
+        xlsx_data.extend_from_slice(b"[Content_Types].xml....xl/workbook.xml");
+        assert_eq!(detect_file_kind(&xlsx_data), Some(FileKind::Xlsx));
+        // Every XLSX is a ZIP archive
+        assert_eq!(validate_magic_bytes(&xlsx_data, &[FileKind::Zip]).unwrap(), FileKind::Xlsx);
+    }
+
+    #[test]
+    fn test_detect_csv() {
+        let csv_data = "\u{FEFF}sku,name,quantity\nSKU-1,Café,3\n".as_bytes();
+        assert_eq!(validate_magic_bytes(csv_data, &[FileKind::Csv]).unwrap(), FileKind::Csv);
+
+        // Text without delimiters or with binary content is not CSV
+        assert_eq!(detect_file_kind(b"just some prose"), None);
+        assert_eq!(detect_file_kind(b"a,b\n\x00\x01\x02"), None);
+    }
+
+    #[test]
+    fn test_spoofed_extension_caught_by_magic_bytes() {
+        // A PNG uploaded as "spec-sheet.pdf"
+        let png_data = vec![
+            0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D,
+        ];
+        let result = validate_magic_bytes(&png_data, &[FileKind::Pdf]);
+        assert!(matches!(result, Err(AppError::ValidationError(msg)) if msg.contains("image/png")));
+    }
+
+    #[test]
+    fn test_disallowed_kind_rejected() {
+        let pdf_data = b"%PDF-1.4\n".to_vec();
+        assert!(validate_magic_bytes(&pdf_data, FileKind::IMAGES).is_err());
+        assert!(validate_image_magic_bytes(b"%PDF-1.4\n1 0 obj").is_err());
+        assert!(validate_magic_bytes(&pdf_data, &[FileKind::Csv]).is_err());
+    }
+
+    fn retry_test_config(max_retries: usize) -> StorageConfig {
+        StorageConfig {
+            endpoint: "http://127.0.0.1:9".to_string(),
+            access_key: "test".to_string(),
+            secret_key: "test".to_string(),
+            bucket_name: "test-bucket".to_string(),
+            region: "us-east-1".to_string(),
+            public_url: None,
+            max_retries,
+            retry_base_delay_ms: 1,
+            retry_max_delay_ms: 1,
+            image_limits: ImageDimensionLimits::default(),
+        }
+    }
+
+    /// Run an always-failing operation under the client's retry policy, returning attempts made
+    async fn attempts_until_failure(client: &StorageClient) -> usize {
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        let result: Result<(), &str> = Retry::spawn(client.retry_strategy(), || {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async { Err("simulated S3 failure") }
+        })
+        .await;
+        assert!(result.is_err());
+        attempts.into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_zero_retries_fails_fast() {
+        let client = StorageClient::new(retry_test_config(0)).await.unwrap();
+        assert_eq!(attempts_until_failure(&client).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_three_retries_retries_thrice() {
+        let client = StorageClient::new(retry_test_config(3)).await.unwrap();
+        // One initial attempt plus three retries
+        assert_eq!(attempts_until_failure(&client).await, 4);
+    }
+
+    #[test]
+    fn test_retry_config_bounds() {
+        assert!(retry_test_config(3).validate().is_ok());
+        assert!(retry_test_config(MAX_RETRIES_LIMIT + 1).validate().is_err());
+
+        let zero_delay = StorageConfig {
+            retry_base_delay_ms: 0,
+            ..retry_test_config(3)
+        };
+        assert!(zero_delay.validate().is_err());
+
+        let inverted = StorageConfig {
+            retry_base_delay_ms: 500,
+            retry_max_delay_ms: 100,
+            ..retry_test_config(3)
+        };
+        assert!(inverted.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_url_rejects_foreign_url() {
+        let client = StorageClient::new(retry_test_config(0)).await.unwrap();
+
+        let result = client
+            .delete_by_url("https://cdn.example.com/products/tenant/image.png")
+            .await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+    }
 }
@@ -1,10 +1,11 @@
 use axum::{
     extract::{Extension, Path},
     http::StatusCode,
-    Json,
 };
 use shared_auth::casbin::{CoreApi, MgmtApi};
+use shared_auth::enforcer::ANY_WAREHOUSE;
 use shared_auth::extractors::RequireAdmin;
+use shared_error::extract::Json;
 use shared_error::AppError;
 use std::collections::HashMap;
 use user_service_core::domains::auth::domain::service::AuthService;
@@ -100,6 +101,7 @@ pub async fn create_role<S: AuthService>(
             tenant_id.to_string(),
             permission.resource.clone(),
             permission.action.clone(),
+            ANY_WAREHOUSE.to_string(),
         ];
 
         let added = enforcer
@@ -305,6 +307,7 @@ pub async fn update_role<S: AuthService>(
             tenant_id.to_string(),
             permission.resource.clone(),
             permission.action.clone(),
+            ANY_WAREHOUSE.to_string(),
         ];
 
         match enforcer.add_policy(policy).await {
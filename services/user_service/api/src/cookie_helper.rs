@@ -21,6 +21,15 @@ impl<'a> CookieConfig<'a> {
             refresh_token_max_age: config.jwt_refresh_expiration,
         }
     }
+
+    /// Cookie lifetimes matching the tokens issued to `role`
+    pub fn for_role(config: &'a Config, role: &str) -> Self {
+        Self {
+            config,
+            access_token_max_age: config.access_token_expiration(role),
+            refresh_token_max_age: config.refresh_token_expiration(role),
+        }
+    }
 }
 
 /// Build a Set-Cookie header value for an authentication cookie
@@ -181,6 +190,24 @@ mod tests {
         assert!(refresh_cookie.contains("Max-Age=604800"));
     }
 
+    #[test]
+    fn test_cookie_max_age_follows_role_override() {
+        let mut config = test_config();
+        config
+            .jwt_expiration_overrides
+            .insert("admin".to_string(), 300);
+        let mut headers = HeaderMap::new();
+
+        set_auth_cookies(&mut headers, "a", "r", &CookieConfig::for_role(&config, "admin"))
+            .unwrap();
+        set_auth_cookies(&mut headers, "a", "r", &CookieConfig::for_role(&config, "user")).unwrap();
+
+        let cookies: Vec<_> = headers.get_all(SET_COOKIE).iter().collect();
+        assert!(cookies[0].to_str().unwrap().contains("Max-Age=300"));
+        assert!(cookies[1].to_str().unwrap().contains("Max-Age=604800"));
+        assert!(cookies[2].to_str().unwrap().contains("Max-Age=900"));
+    }
+
     #[test]
     fn test_clear_auth_cookies() {
         let config = test_config();
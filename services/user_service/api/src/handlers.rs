@@ -4,14 +4,18 @@ use axum::{
     extract::{Extension, Query},
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
-    Json,
 };
 use chrono::Utc;
 use serde::Deserialize;
 use shared_auth::casbin::{CoreApi, MgmtApi};
-use shared_auth::enforcer::{add_role_for_user, copy_policies_for_tenant, SharedEnforcer};
+use shared_auth::enforcer::{
+    add_policies, add_role_for_user, copy_policies_for_tenant, remove_policies, PolicyBatchResult,
+    SharedEnforcer, ANY_WAREHOUSE,
+};
 use shared_auth::extractors::{AuthUser, JwtSecretProvider, RequireAdmin};
+use shared_error::extract::Json;
 use shared_error::AppError;
+use shared_jwt::TokenDenylist;
 use std::sync::Arc;
 use user_service_core::domains::auth::{
     domain::{
@@ -23,7 +27,8 @@ use user_service_core::domains::auth::{
     },
     dto::auth_dto::{
         AuthResp, CheckTenantSlugQuery, CheckTenantSlugResp, ErrorResp, HealthResp, LoginReq,
-        OptionalRefreshReq, RefreshReq, RegisterReq, RegisterResp, UserInfo, UserListResp,
+        OptionalRefreshReq, RateLimitedResp, RefreshReq, RegisterReq, RegisterResp, UserInfo,
+        UserListResp,
     },
 };
 use user_service_infra::auth::EmailSender;
@@ -49,6 +54,8 @@ pub struct AppState<S: AuthService> {
     pub invitation_rate_limiter: Arc<InvitationRateLimiter>,
     // Email sender for sending invitation emails
     pub email_sender: Option<Arc<dyn EmailSender>>,
+    // Revoked access tokens, written on logout and checked by the auth middleware
+    pub token_denylist: Arc<dyn TokenDenylist>,
 }
 
 impl<S: AuthService> Clone for AppState<S> {
@@ -65,6 +72,7 @@ impl<S: AuthService> Clone for AppState<S> {
             config: self.config.clone(),
             invitation_rate_limiter: Arc::clone(&self.invitation_rate_limiter),
             email_sender: self.email_sender.clone(),
+            token_denylist: Arc::clone(&self.token_denylist),
         }
     }
 }
@@ -223,6 +231,7 @@ pub async fn health_check() -> Json<HealthResp> {
         (status = 201, description = "User registered successfully. Email verification required before login.", body = RegisterResp),
         (status = 400, description = "Invalid request (validation error)", body = ErrorResp),
         (status = 409, description = "User already exists in the tenant", body = ErrorResp),
+        (status = 429, description = "Too many registration attempts", body = RateLimitedResp, headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
     )
 )]
 pub async fn register<S: AuthService>(
@@ -381,7 +390,7 @@ pub async fn check_tenant_slug<S: AuthService>(
     responses(
         (status = 200, description = "Login successful", body = AuthResp),
         (status = 401, description = "Invalid credentials", body = ErrorResp),
-        (status = 429, description = "Too many login attempts", body = ErrorResp),
+        (status = 429, description = "Too many login attempts", body = RateLimitedResp, headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
     )
 )]
 pub async fn login<S: AuthService>(
@@ -425,7 +434,7 @@ pub async fn login<S: AuthService>(
         .await?;
 
     // Set httpOnly cookies for authentication tokens
-    let cookie_config = CookieConfig::new(&state.config);
+    let cookie_config = CookieConfig::for_role(&state.config, &resp.user.role);
     let mut headers = HeaderMap::new();
     set_auth_cookies(&mut headers, &resp.access_token, &resp.refresh_token, &cookie_config)
         .map_err(|e| AppError::InternalError(format!("Failed to set auth cookies: {}", e)))?;
@@ -443,6 +452,7 @@ pub async fn login<S: AuthService>(
     responses(
         (status = 200, description = "Token refreshed", body = AuthResp),
         (status = 401, description = "Invalid refresh token", body = ErrorResp),
+        (status = 429, description = "Too many refresh attempts", body = RateLimitedResp, headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
     )
 )]
 pub async fn refresh_token<S: AuthService>(
@@ -481,7 +491,7 @@ pub async fn refresh_token<S: AuthService>(
         .await?;
 
     // Set httpOnly cookies for new authentication tokens
-    let cookie_config = CookieConfig::new(&state.config);
+    let cookie_config = CookieConfig::for_role(&state.config, &resp.user.role);
     let mut headers = HeaderMap::new();
     set_auth_cookies(&mut headers, &resp.access_token, &resp.refresh_token, &cookie_config)
         .map_err(|e| AppError::InternalError(format!("Failed to set auth cookies: {}", e)))?;
@@ -490,6 +500,9 @@ pub async fn refresh_token<S: AuthService>(
 }
 
 /// Logout user by revoking refresh token session
+///
+/// The access token presented with the request, if any, is added to the
+/// denylist so it stops working before it expires.
 #[utoipa::path(
     post,
     path = "/api/v1/auth/logout",
@@ -523,6 +536,19 @@ pub async fn logout<S: AuthService>(
         state.auth_service.logout(&token).await?;
     }
 
+    // Revoke the access token too; one that no longer validates needs no entry
+    let access_token_value = req_headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .map(str::to_string)
+        .or_else(|| get_cookie_value(&req_headers, "access_token"));
+    if let Some(claims) = access_token_value
+        .and_then(|token| shared_jwt::decode_access(&token, &state.jwt_secret).ok())
+    {
+        state.token_denylist.revoke(claims.jti, claims.exp).await?;
+    }
+
     // Always clear httpOnly cookies
     let mut headers = HeaderMap::new();
     clear_auth_cookies(&mut headers, &state.config)
@@ -644,6 +670,9 @@ pub struct CreatePolicyReq {
     pub resource: String,
     #[validate(length(min = 1, max = 255))]
     pub action: String,
+    /// Limit the rule to one warehouse; omit for a rule that applies in all of them
+    #[serde(default)]
+    pub warehouse_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, utoipa::ToSchema, validator::Validate)]
@@ -654,6 +683,62 @@ pub struct DeletePolicyReq {
     pub resource: String,
     #[validate(length(min = 1))]
     pub action: String,
+    /// Warehouse the rule is limited to; omit for an unscoped rule
+    #[serde(default)]
+    pub warehouse_id: Option<Uuid>,
+}
+
+/// Warehouse field of a Casbin rule for an optional warehouse scope
+fn policy_warehouse(warehouse_id: Option<Uuid>) -> String {
+    warehouse_id
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| ANY_WAREHOUSE.to_string())
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, validator::Validate)]
+pub struct BatchPolicyReq {
+    /// Rules to add or remove, applied together in one transaction
+    #[validate(length(min = 1, max = 100), nested)]
+    pub policies: Vec<CreatePolicyReq>,
+}
+
+/// A tenant policy rule as reported by the batch endpoints
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct PolicyRuleInfo {
+    pub role: String,
+    pub resource: String,
+    pub action: String,
+    /// Warehouse the rule is limited to; absent for unscoped rules
+    pub warehouse_id: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct BatchPolicyResp {
+    /// Rules this request added or removed
+    pub applied: Vec<PolicyRuleInfo>,
+    /// Rules left as they were: already present when adding, absent when removing
+    pub unchanged: Vec<PolicyRuleInfo>,
+}
+
+impl From<PolicyBatchResult> for BatchPolicyResp {
+    fn from(result: PolicyBatchResult) -> Self {
+        // Rules are [role, tenant_id, resource, action, warehouse]
+        let to_info = |rules: Vec<Vec<String>>| {
+            rules
+                .into_iter()
+                .map(|rule| PolicyRuleInfo {
+                    role: rule[0].clone(),
+                    resource: rule[2].clone(),
+                    action: rule[3].clone(),
+                    warehouse_id: Some(rule[4].clone()).filter(|wh| wh != ANY_WAREHOUSE),
+                })
+                .collect()
+        };
+        Self {
+            applied: to_info(result.applied),
+            unchanged: to_info(result.unchanged),
+        }
+    }
 }
 
 /// Protected role that cannot be assigned or modified via admin APIs.
@@ -709,6 +794,7 @@ pub async fn add_policy<S: AuthService>(
             admin_user.tenant_id.to_string(),
             payload.resource.clone(),
             payload.action.clone(),
+            policy_warehouse(payload.warehouse_id),
         ])
         .await
         .map_err(|e| AppError::InternalError(format!("Failed to add policy: {}", e)))?;
@@ -772,6 +858,7 @@ pub async fn remove_policy<S: AuthService>(
             admin_user.tenant_id.to_string(),
             payload.resource.clone(),
             payload.action.clone(),
+            policy_warehouse(payload.warehouse_id),
         ])
         .await
         .map_err(|e| AppError::InternalError(format!("Failed to remove policy: {}", e)))?;
@@ -792,6 +879,121 @@ pub async fn remove_policy<S: AuthService>(
     }
 }
 
+/// Validate a batch request and turn it into tenant-scoped Casbin rules
+fn batch_policy_rules(
+    payload: &BatchPolicyReq,
+    tenant_id: Uuid,
+) -> Result<Vec<Vec<String>>, AppError> {
+    use validator::Validate;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    // Same guard as the single-rule endpoints: owner policies are system-managed
+    if payload
+        .policies
+        .iter()
+        .any(|p| p.role.to_lowercase() == OWNER_ROLE)
+    {
+        return Err(AppError::Forbidden(
+            "Cannot change policies of 'owner' role. Owner policies are managed by the system only."
+                .to_string(),
+        ));
+    }
+
+    Ok(payload
+        .policies
+        .iter()
+        .map(|p| {
+            vec![
+                p.role.clone(),
+                tenant_id.to_string(),
+                p.resource.clone(),
+                p.action.clone(),
+                policy_warehouse(p.warehouse_id),
+            ]
+        })
+        .collect())
+}
+
+/// Add several policies at once (admin only)
+///
+/// Either every new rule is stored or none is. Rules that already exist are
+/// reported as unchanged rather than failing the request.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/policies/batch",
+    tag = "admin",
+    operation_id = "admin_add_policies_batch",
+    request_body = BatchPolicyReq,
+    responses(
+        (status = 200, description = "Batch applied", body = BatchPolicyResp),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin only"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_policies_batch<S: AuthService>(
+    RequireAdmin(admin_user): RequireAdmin,
+    Extension(state): Extension<AppState<S>>,
+    Json(payload): Json<BatchPolicyReq>,
+) -> Result<Json<BatchPolicyResp>, AppError> {
+    let rules = batch_policy_rules(&payload, admin_user.tenant_id)?;
+
+    let result = add_policies(&state.enforcer, rules)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Failed to add policies: {}", e)))?;
+
+    if !result.applied.is_empty() {
+        // Bump tenant authz version to invalidate existing tokens
+        bump_tenant_authz_version(&state, admin_user.tenant_id, "add_policies_batch").await;
+    }
+
+    Ok(Json(result.into()))
+}
+
+/// Remove several policies at once (admin only)
+///
+/// Either every existing rule is removed or none is. Rules that do not exist
+/// are reported as unchanged rather than failing the request.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/policies/batch",
+    tag = "admin",
+    operation_id = "admin_remove_policies_batch",
+    request_body = BatchPolicyReq,
+    responses(
+        (status = 200, description = "Batch applied", body = BatchPolicyResp),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin only"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn remove_policies_batch<S: AuthService>(
+    RequireAdmin(admin_user): RequireAdmin,
+    Extension(state): Extension<AppState<S>>,
+    Json(payload): Json<BatchPolicyReq>,
+) -> Result<Json<BatchPolicyResp>, AppError> {
+    let rules = batch_policy_rules(&payload, admin_user.tenant_id)?;
+
+    let result = remove_policies(&state.enforcer, rules)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Failed to remove policies: {}", e)))?;
+
+    if !result.applied.is_empty() {
+        // Bump tenant authz version to invalidate existing tokens
+        bump_tenant_authz_version(&state, admin_user.tenant_id, "remove_policies_batch").await;
+    }
+
+    Ok(Json(result.into()))
+}
+
 // Note: Legacy assign_role_to_user and revoke_role_from_user handlers have been moved
 // to admin_handlers.rs with enhanced validation and error handling.
 // The new implementations include:
@@ -1,16 +1,16 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
-    Json,
 };
 
 use shared_auth::extractors::RequireAdmin;
+use shared_error::extract::Json;
 use shared_error::AppError;
 use shared_jwt::{encode_jwt, Claims};
 use user_service_core::domains::auth::{
     domain::service::AuthService,
     dto::{
-        auth_dto::{ErrorResp, UserInfo},
+        auth_dto::{ErrorResp, RateLimitedResp, UserInfo},
         invitation_dto::{
             AcceptInvitationRequest, AcceptInvitationResponse, CreateInvitationRequest,
             CreateInvitationResponse, InvitationListItem, InvitedByInfo, ListInvitationsQuery,
@@ -162,7 +162,7 @@ where
         (status = 400, description = "Invalid request", body = ErrorResp),
         (status = 401, description = "Invalid or expired invitation", body = ErrorResp),
         (status = 410, description = "Invitation has expired", body = ErrorResp),
-        (status = 429, description = "Too many acceptance attempts", body = ErrorResp),
+        (status = 429, description = "Too many acceptance attempts", body = RateLimitedResp, headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
     )
 )]
 pub async fn accept_invitation<S>(
@@ -210,17 +210,22 @@ where
         .accepted_user_id
         .ok_or_else(|| AppError::InternalError("Accepted invitation missing user ID".into()))?;
 
+    let access_expiration = state
+        .config
+        .access_token_expiration(&invitation.invited_role);
     let access_claims = Claims::new_access(
         user_id,
         invitation.tenant_id,
         invitation.invited_role.clone(),
-        state.config.jwt_expiration,
+        access_expiration,
     );
     let refresh_claims = Claims::new_refresh(
         user_id,
         invitation.tenant_id,
         invitation.invited_role.clone(),
-        state.config.jwt_refresh_expiration,
+        state
+            .config
+            .refresh_token_expiration(&invitation.invited_role),
     );
 
     let access_token = encode_jwt(&access_claims, &state.jwt_secret)?;
@@ -230,7 +235,7 @@ where
         access_token,
         refresh_token,
         token_type: "Bearer".to_string(),
-        expires_in: state.config.jwt_expiration,
+        expires_in: access_expiration,
         user: UserInfo {
             id: user_id,
             email: invitation.email,
@@ -20,12 +20,17 @@ use axum::{middleware, Extension, Router};
 use shared_auth::{create_enforcer, AuthzState, AuthzVersionState};
 use shared_config::Config;
 use shared_db::PgPool;
+use shared_jwt::InMemoryTokenDenylist;
 use std::sync::Arc;
 use tower_http::trace::TraceLayer;
 use user_service_core::domains::auth::domain::authz_version_repository::AuthzVersionRepository;
+use user_service_core::domains::auth::domain::repository::TenantRepository;
+use user_service_core::domains::auth::utils::password_validator::{
+    BreachedPasswordChecker, PasswordPolicy, PasswordValidator,
+};
 use user_service_infra::auth::{
-    AuthServiceImpl, InvitationServiceImpl, PgInvitationRepository, PgSessionRepository,
-    PgTenantRepository, PgUserRepository, RedisAuthzVersionRepository,
+    AuthServiceImpl, HibpBreachedPasswordChecker, InvitationServiceImpl, PgInvitationRepository,
+    PgSessionRepository, PgTenantRepository, PgUserRepository, RedisAuthzVersionRepository,
 };
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
@@ -35,6 +40,42 @@ type ConcreteAuthService =
 
 type AppRouter = Router;
 
+/// Build the platform password policy from configuration
+pub fn password_policy_from_config(config: &Config) -> PasswordPolicy {
+    PasswordPolicy {
+        min_length: config.password_min_length,
+        require_uppercase: config.password_require_uppercase,
+        require_lowercase: config.password_require_lowercase,
+        require_digit: config.password_require_digit,
+        require_symbol: config.password_require_symbol,
+        min_score: config.password_min_score,
+        check_breached: config.password_check_breached,
+    }
+}
+
+/// Create the HIBP breached-password checker
+///
+/// Always created so tenants can opt in via settings even when the platform
+/// policy leaves the check off; returns None if the HTTP client cannot be built.
+pub fn create_breach_checker() -> Option<Arc<dyn BreachedPasswordChecker>> {
+    match HibpBreachedPasswordChecker::new() {
+        Ok(checker) => Some(Arc::new(checker)),
+        Err(e) => {
+            tracing::warn!("Breached-password checks unavailable: {}", e);
+            None
+        },
+    }
+}
+
+/// Build the password validator shared by every flow that sets a password
+pub fn password_validator_from_config(
+    config: &Config,
+    tenant_repo: Arc<dyn TenantRepository>,
+) -> PasswordValidator {
+    PasswordValidator::new(password_policy_from_config(config), create_breach_checker())
+        .with_tenant_repo(tenant_repo)
+}
+
 /// Create test app with database pool and config (for integration tests)
 pub async fn get_app(db_pool: PgPool, config: &Config) -> AppRouter {
     // Initialize Casbin enforcer
@@ -55,6 +96,9 @@ pub async fn get_app(db_pool: PgPool, config: &Config) -> AppRouter {
         None
     };
 
+    // One validator for every flow that sets a password
+    let password_validator = password_validator_from_config(config, Arc::new(tenant_repo.clone()));
+
     // Initialize auth service
     let auth_service = AuthServiceImpl::new(
         user_repo.clone(),
@@ -63,6 +107,11 @@ pub async fn get_app(db_pool: PgPool, config: &Config) -> AppRouter {
         config.jwt_secret.clone(),
         config.jwt_expiration,
         config.jwt_refresh_expiration,
+    )
+    .with_password_validator(password_validator.clone())
+    .with_expiration_overrides(
+        config.jwt_expiration_overrides.clone(),
+        config.jwt_refresh_expiration_overrides.clone(),
     );
 
     // Initialize invitation service
@@ -73,7 +122,8 @@ pub async fn get_app(db_pool: PgPool, config: &Config) -> AppRouter {
         config.invitation_expiry_hours,
         config.invitation_max_attempts,
         config.invitation_max_per_admin_per_day,
-    );
+    )
+    .with_password_validator(password_validator);
 
     // Create app state
     let state = AppState {
@@ -90,6 +140,7 @@ pub async fn get_app(db_pool: PgPool, config: &Config) -> AppRouter {
         config: config.clone(),
         invitation_rate_limiter: Arc::new(crate::rate_limiter::InvitationRateLimiter::default()),
         email_sender: None, // Not used in tests
+        token_denylist: Arc::new(InMemoryTokenDenylist::new()),
     };
 
     create_router(&state, authz_version_repo)
@@ -103,6 +154,7 @@ pub fn create_router(
     let authz_state = AuthzState {
         enforcer: state.enforcer.clone(),
         jwt_secret: state.jwt_secret.clone(),
+        denylist: state.token_denylist.clone(),
     };
 
     // Public routes (no auth required)
@@ -132,6 +184,10 @@ pub fn create_router(
             "/api/v1/admin/policies",
             post(handlers::add_policy::<ConcreteAuthService>).delete(handlers::remove_policy::<ConcreteAuthService>),
         )
+        .route(
+            "/api/v1/admin/policies/batch",
+            post(handlers::add_policies_batch::<ConcreteAuthService>).delete(handlers::remove_policies_batch::<ConcreteAuthService>),
+        )
         .layer(Extension(state.clone()))
         .layer(shared_auth::CasbinAuthLayer::new(authz_state.clone()));
 
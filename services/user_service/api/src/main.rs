@@ -1,5 +1,4 @@
 use axum::extract::DefaultBodyLimit;
-use axum::http::Method;
 use axum::routing::{delete, get, post, put};
 use axum::{
     http::{header, HeaderValue},
@@ -7,16 +6,19 @@ use axum::{
 };
 use shared_auth::enforcer::create_enforcer;
 use shared_auth::middleware::AuthzState;
-use shared_rate_limit::{RateLimitConfig, RateLimitEndpoint, RateLimitLayer, RateLimitState};
+use shared_jwt::{InMemoryTokenDenylist, RedisTokenDenylist, TokenDenylist};
+use shared_rate_limit::{
+    BackendErrorPolicy, Enforcement, RateLimitConfig, RateLimitEndpoint, RateLimitLayer,
+    RateLimitState,
+};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::set_header::SetResponseHeaderLayer;
 use tower_http::trace::TraceLayer;
 use user_service_api::{
-    admin_handlers, handlers, invitation_handlers, password_reset_handlers, permission_handlers,
-    profile_handlers, rate_limiter::InvitationRateLimiter, verification_handlers, AppState,
-    ProfileAppState,
+    admin_handlers, handlers, invitation_handlers, password_reset_handlers,
+    password_validator_from_config, permission_handlers, profile_handlers,
+    rate_limiter::InvitationRateLimiter, verification_handlers, AppState, ProfileAppState,
 };
 use user_service_core::domains::auth::domain::authz_version_repository::AuthzVersionRepository;
 use user_service_infra::auth::{
@@ -82,6 +84,9 @@ async fn main() {
     let session_repo = PgSessionRepository::new(db_pool.clone());
     let profile_repo = PgUserProfileRepository::new(db_pool.clone());
 
+    // One validator for every flow that sets a password
+    let password_validator = password_validator_from_config(&config, Arc::new(tenant_repo.clone()));
+
     // Initialize services
     let auth_service = AuthServiceImpl::new(
         user_repo.clone(),
@@ -90,6 +95,11 @@ async fn main() {
         config.jwt_secret.clone(),
         config.jwt_expiration,
         config.jwt_refresh_expiration,
+    )
+    .with_password_validator(password_validator.clone())
+    .with_expiration_overrides(
+        config.jwt_expiration_overrides.clone(),
+        config.jwt_refresh_expiration_overrides.clone(),
     );
 
     // Initialize storage client for file uploads (RustFS)
@@ -132,7 +142,8 @@ async fn main() {
         config.invitation_expiry_hours,
         config.invitation_max_attempts,
         config.invitation_max_per_admin_per_day,
-    );
+    )
+    .with_password_validator(password_validator.clone());
 
     // Initialize email verification service
     let verification_repo = PgEmailVerificationRepository::new(db_pool.clone());
@@ -178,6 +189,20 @@ async fn main() {
             None
         };
 
+    // Revoked access tokens must be visible to every service instance, so the
+    // in-memory denylist is only a fallback for single-instance setups
+    let token_denylist: Arc<dyn TokenDenylist> = if let Some(redis_url) = &config.redis_url {
+        tracing::info!("✅ Initializing token denylist with Redis");
+        Arc::new(
+            RedisTokenDenylist::new(redis_url)
+                .await
+                .expect("Failed to initialize token denylist"),
+        )
+    } else {
+        tracing::warn!("⚠️ Redis not configured - revoked tokens tracked in memory only");
+        Arc::new(InMemoryTokenDenylist::new())
+    };
+
     // Initialize password reset service
     let password_reset_repo = PgPasswordResetRepository::new(db_pool.clone());
     let password_reset_session_repo = PgSessionRepository::new(db_pool.clone());
@@ -190,8 +215,8 @@ async fn main() {
         config.rate_limit_forgot_max,
         (config.rate_limit_forgot_window / 60) as i64, // Convert seconds to minutes
         email_sender.clone(),
-        8, // Minimum password length
-    );
+    )
+    .with_password_validator(password_validator);
     let password_reset_service = Arc::new(password_reset_service);
 
     // Create application states
@@ -207,6 +232,7 @@ async fn main() {
         config: config.clone(),
         invitation_rate_limiter: Arc::new(InvitationRateLimiter::default()),
         email_sender: Some(email_sender.clone()),
+        token_denylist: token_denylist.clone(),
     };
 
     let profile_state = ProfileAppState {
@@ -236,6 +262,7 @@ async fn main() {
     let authz_state = AuthzState {
         enforcer: enforcer.clone(),
         jwt_secret: config.jwt_secret.clone(),
+        denylist: token_denylist,
     };
 
     tracing::info!("✅ Services initialized");
@@ -263,6 +290,16 @@ async fn main() {
         trusted_ips: config.rate_limit_trusted_ips.clone(),
         trust_proxy_headers: config.rate_limit_trust_proxy_headers,
         proxy_count: config.rate_limit_proxy_count,
+        enforcement: if config.rate_limit_monitor_mode {
+            Enforcement::Monitor
+        } else {
+            Enforcement::Enforce
+        },
+        on_backend_error: if config.rate_limit_fail_closed {
+            BackendErrorPolicy::FailClosed
+        } else {
+            BackendErrorPolicy::FailOpen
+        },
         ..Default::default()
     };
     let rate_limit_state = RateLimitState::from_config(rate_limit_config).await;
@@ -456,6 +493,10 @@ async fn main() {
             "/api/v1/admin/policies",
             post(handlers::add_policy::<AuthServiceImpl<PgUserRepository, PgTenantRepository, PgSessionRepository>>).delete(handlers::remove_policy::<AuthServiceImpl<PgUserRepository, PgTenantRepository, PgSessionRepository>>),
         )
+        .route(
+            "/api/v1/admin/policies/batch",
+            post(handlers::add_policies_batch::<AuthServiceImpl<PgUserRepository, PgTenantRepository, PgSessionRepository>>).delete(handlers::remove_policies_batch::<AuthServiceImpl<PgUserRepository, PgTenantRepository, PgSessionRepository>>),
+        )
         .layer(Extension(combined_state.app.clone()))
         .layer(shared_auth::CasbinAuthLayer::new(authz_state.clone()))
         .layer(Extension(authz_state.clone()));
@@ -587,54 +628,7 @@ async fn main() {
         .merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", openapi::ApiDoc::openapi()))
         .layer(Extension(combined_state))
         // CORS configuration
-        .layer({
-            let origins = config.get_cors_origins();
-            let mut cors = CorsLayer::new()
-                .allow_methods([
-                    Method::GET,
-                    Method::POST,
-                    Method::PUT,
-                    Method::DELETE,
-                ])
-                .allow_headers([
-                    header::CONTENT_TYPE,
-                    header::AUTHORIZATION,
-                    axum::http::header::HeaderName::from_static("x-tenant-id"),
-                ]);
-
-            if origins.is_empty() {
-                cors = cors.allow_origin(AllowOrigin::any());
-                cors = cors.allow_credentials(false); // Explicitly disable credentials for wildcard origins
-            } else {
-                // Validate that wildcard is not in configured origins
-                if origins.iter().any(|o| o == "*") {
-                    panic!(
-                        "CORS configuration error: wildcard origin '*' cannot be used with credentials. \
-                         Either remove '*' and specify exact origins, or leave CORS_ORIGINS empty for development."
-                    );
-                }
-
-                let header_values: Result<Vec<HeaderValue>, _> = origins
-                    .into_iter()
-                    .map(|origin| {
-                        HeaderValue::from_str(&origin).map_err(|e| {
-                            format!("Invalid CORS origin '{}': {}", origin, e)
-                        })
-                    })
-                    .collect();
-
-                match header_values {
-                    Ok(values) => {
-                        cors = cors.allow_origin(AllowOrigin::list(values));
-                        // Only allow credentials when specific origins are configured
-                        cors = cors.allow_credentials(true);
-                    }
-                    Err(e) => panic!("CORS configuration error: {}", e),
-                }
-            }
-
-            cors
-        })
+        .layer(shared_http::build_cors(&config))
         // Security headers
         .layer(SetResponseHeaderLayer::if_not_present(
             header::STRICT_TRANSPORT_SECURITY,
@@ -3,11 +3,31 @@ use user_service_core::domains::auth::dto::auth_dto::*;
 use user_service_core::domains::auth::dto::email_verification_dto::*;
 use user_service_core::domains::auth::dto::invitation_dto::*;
 use user_service_core::domains::auth::dto::password_reset_dto::*;
-use utoipa::OpenApi;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/// Registers the JWT bearer scheme referenced by `security(("bearer_auth" = []))`
+pub struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
 
 /// OpenAPI documentation for User Service
 #[derive(OpenApi)]
 #[openapi(
+    modifiers(&SecurityAddon),
     paths(
         crate::handlers::health_check,
         crate::handlers::register,
@@ -19,6 +39,8 @@ use utoipa::OpenApi;
         // Low-level policy management
         crate::handlers::add_policy,
         crate::handlers::remove_policy,
+        crate::handlers::add_policies_batch,
+        crate::handlers::remove_policies_batch,
         // TODO: OAuth2 endpoints - add after feature "openapi" enables in core
         // crate::oauth_handlers::oauth_authorize,
         // crate::oauth_handlers::oauth_callback,
@@ -63,8 +85,12 @@ use utoipa::OpenApi;
             UserInfo,
             UserListResp,
             ErrorResp,
+            RateLimitedResp,
             crate::handlers::CreatePolicyReq,
             crate::handlers::DeletePolicyReq,
+            crate::handlers::BatchPolicyReq,
+            crate::handlers::BatchPolicyResp,
+            crate::handlers::PolicyRuleInfo,
             // Admin DTOs (comprehensive role management)
             CreateRoleReq,
             CreateRoleResp,
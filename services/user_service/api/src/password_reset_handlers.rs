@@ -1,4 +1,5 @@
-use axum::{extract::ConnectInfo, http::HeaderMap, Extension, Json};
+use axum::{extract::ConnectInfo, http::HeaderMap, Extension};
+use shared_error::extract::Json;
 use shared_error::AppError;
 use std::{net::SocketAddr, sync::Arc};
 use user_service_core::domains::auth::{
@@ -24,6 +25,7 @@ use validator::Validate;
     responses(
         (status = 200, description = "Reset email sent (or would be sent if email exists)", body = ForgotPasswordResp),
         (status = 400, description = "Invalid email format", body = String),
+        (status = 429, description = "Too many reset requests", body = user_service_core::domains::auth::dto::auth_dto::RateLimitedResp, headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
     )
 )]
 pub async fn forgot_password<PRS>(
@@ -1,10 +1,9 @@
-use axum::{
-    extract::{Extension, Query},
-    Json,
-};
+use axum::extract::{Extension, Query};
 use serde::Deserialize;
 use shared_auth::casbin::{CoreApi, MgmtApi};
+use shared_auth::enforcer::NO_WAREHOUSE;
 use shared_auth::extractors::AuthUser;
+use shared_error::extract::Json;
 use shared_error::AppError;
 use user_service_core::domains::auth::domain::service::AuthService;
 
@@ -15,6 +14,9 @@ use crate::handlers::AppState;
 pub struct PermissionCheckQuery {
     pub resource: String,
     pub action: String,
+    /// Warehouse the action targets; omit for actions not tied to a warehouse
+    #[serde(default)]
+    pub warehouse_id: Option<String>,
 }
 
 /// Check if the current user has permission for a specific resource and action
@@ -26,6 +28,7 @@ pub struct PermissionCheckQuery {
     params(
         ("resource" = String, Query, description = "Resource to check permission for"),
         ("action" = String, Query, description = "Action to check permission for"),
+        ("warehouse_id" = Option<String>, Query, description = "Warehouse the action targets"),
     ),
     responses(
         (status = 200, description = "Permission check result", body = PermissionCheckResp),
@@ -44,13 +47,14 @@ pub async fn check_permission<S: AuthService>(
     let enforcer = state.enforcer.read().await;
 
     // Check permission using Casbin enforcer
-    // Format: subject, tenant, resource, action
+    // Format: subject, tenant, resource, action, warehouse
     let allowed = enforcer
         .enforce((
             &auth_user.user_id.to_string(),
             &auth_user.tenant_id.to_string(),
             &query.resource,
             &query.action,
+            query.warehouse_id.as_deref().unwrap_or(NO_WAREHOUSE),
         ))
         .map_err(|e| AppError::InternalError(format!("Permission check failed: {}", e)))?;
 
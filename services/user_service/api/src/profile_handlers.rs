@@ -1,10 +1,10 @@
 use axum::{
     extract::{Extension, Multipart, Path},
     http::StatusCode,
-    Json,
 };
 use serde::{Deserialize, Serialize};
 use shared_auth::extractors::{AuthUser, JwtSecretProvider};
+use shared_error::extract::Json;
 use shared_error::AppError;
 use std::sync::Arc;
 use user_service_core::domains::auth::domain::profile_service::ProfileService;
@@ -108,6 +108,7 @@ pub async fn update_profile<S: ProfileService>(
         (status = 401, description = "Unauthorized"),
         (status = 413, description = "Payload too large - file exceeds 5MB limit"),
         (status = 415, description = "Unsupported media type - only images allowed"),
+        (status = 429, description = "Too many uploads", body = user_service_core::domains::auth::dto::auth_dto::RateLimitedResp, headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
     )
 )]
 pub async fn upload_avatar<S: ProfileService>(
@@ -1,4 +1,5 @@
-use axum::{extract::ConnectInfo, http::HeaderMap, Extension, Json};
+use axum::{extract::ConnectInfo, http::HeaderMap, Extension};
+use shared_error::extract::Json;
 use shared_error::AppError;
 use std::{net::SocketAddr, sync::Arc};
 use user_service_core::domains::auth::{
@@ -65,6 +66,7 @@ where
         (status = 200, description = "Verification email sent or rate limit info", body = ResendVerificationResp),
         (status = 400, description = "Email already verified or invalid", body = String),
         (status = 404, description = "User not found", body = String),
+        (status = 429, description = "Too many resend requests", body = user_service_core::domains::auth::dto::auth_dto::RateLimitedResp, headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
     )
 )]
 pub async fn resend_verification<EVS>(
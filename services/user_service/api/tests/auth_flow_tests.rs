@@ -461,6 +461,68 @@ async fn test_jwt_expiration_flow() {
     db.cleanup().await;
 }
 
+#[tokio::test]
+#[ignore]
+async fn test_jwt_expiration_override_per_role() {
+    let db = TestDatabaseConfig::new().await;
+
+    use std::collections::HashMap;
+    use user_service_core::domains::auth::{domain::service::AuthService, dto::auth_dto::LoginReq};
+    use user_service_infra::auth::{
+        AuthServiceImpl, PgSessionRepository, PgTenantRepository, PgUserRepository,
+    };
+
+    let jwt_secret = "test-secret-key-at-least-32-characters-long".to_string();
+    let auth_service = AuthServiceImpl::new(
+        PgUserRepository::new(db.pool().clone()),
+        PgTenantRepository::new(db.pool().clone()),
+        PgSessionRepository::new(db.pool().clone()),
+        jwt_secret.clone(),
+        900,    // default access lifetime
+        604800, // default refresh lifetime
+    )
+    .with_expiration_overrides(
+        HashMap::from([("admin".to_string(), 300)]),
+        HashMap::from([("admin".to_string(), 86400)]),
+    );
+
+    let tenant_id = db.create_tenant("JWT Override Test", None).await;
+    let password_hash = bcrypt::hash("TestPass123!", bcrypt::DEFAULT_COST).unwrap();
+    db.create_user(tenant_id, "admin@override.com", &password_hash, "admin", None)
+        .await;
+    db.create_user(tenant_id, "user@override.com", &password_hash, "user", None)
+        .await;
+
+    let login = |email: &str| LoginReq {
+        email: email.to_string(),
+        password: "TestPass123!".to_string(),
+    };
+    let lifetime = |token: &str| {
+        let claims = shared_jwt::decode_jwt_single(token, &jwt_secret).unwrap();
+        claims.exp - claims.iat
+    };
+
+    // Admin gets the shorter override
+    let admin = auth_service
+        .login(login("admin@override.com"), Some(tenant_id.to_string()), None, None)
+        .await
+        .expect("Admin login should succeed");
+    assert_eq!(admin.expires_in, 300);
+    assert_eq!(lifetime(&admin.access_token), 300);
+    assert_eq!(lifetime(&admin.refresh_token), 86400);
+
+    // Roles without an override keep the defaults
+    let user = auth_service
+        .login(login("user@override.com"), Some(tenant_id.to_string()), None, None)
+        .await
+        .expect("User login should succeed");
+    assert_eq!(user.expires_in, 900);
+    assert_eq!(lifetime(&user.access_token), 900);
+    assert_eq!(lifetime(&user.refresh_token), 604800);
+
+    db.cleanup().await;
+}
+
 #[tokio::test]
 #[ignore]
 async fn test_invalid_jwt_token_flow() {
@@ -219,3 +219,40 @@ async fn test_list_users_tenant_isolation() {
         assert_eq!(user["tenant_id"], user_a.tenant_id.to_string());
     }
 }
+
+#[tokio::test]
+async fn test_logged_out_access_token_is_rejected() {
+    let (app, db_pool, config) = setup_test_app().await;
+
+    let user =
+        sqlx::query!("SELECT user_id, tenant_id FROM users WHERE email = $1", "user@test.com")
+            .fetch_one(&db_pool)
+            .await
+            .expect("Failed to fetch user");
+
+    let user_token = helpers::generate_jwt(user.user_id, user.tenant_id, "user", &config);
+    let list_users = || {
+        Request::builder()
+            .uri("/api/v1/users")
+            .method(http::Method::GET)
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", user_token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(list_users()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let logout = Request::builder()
+        .uri("/api/v1/auth/logout")
+        .method(http::Method::POST)
+        .header(http::header::AUTHORIZATION, format!("Bearer {}", user_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(logout).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The token has not expired, but it was revoked at logout
+    let response = app.oneshot(list_users()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -209,8 +209,8 @@ async fn test_tenant_version_mismatch_token_should_be_stale() {
         get_db_versions(ctx.db.pool(), tenant_id, user_id).await;
 
     // Verify the stale token has lower tenant version
-    let stale_claims =
-        shared_jwt::decode_jwt(&stale_token, &ctx.jwt_secret).expect("Token should be valid JWT");
+    let stale_claims = shared_jwt::decode_jwt_single(&stale_token, &ctx.jwt_secret)
+        .expect("Token should be valid JWT");
     assert!(
         stale_claims.tenant_v < current_tenant_v,
         "Stale token tenant_v ({}) should be less than current ({})",
@@ -265,9 +265,12 @@ async fn test_tenant_policy_change_invalidates_all_users_tokens() {
     let new_tenant_v = bump_tenant_version(ctx.db.pool(), tenant_id).await;
 
     // All tokens should now be stale (tenant version mismatch)
-    let admin_claims = shared_jwt::decode_jwt(&admin_token, &ctx.jwt_secret).expect("Valid JWT");
-    let user1_claims = shared_jwt::decode_jwt(&user1_token, &ctx.jwt_secret).expect("Valid JWT");
-    let user2_claims = shared_jwt::decode_jwt(&user2_token, &ctx.jwt_secret).expect("Valid JWT");
+    let admin_claims =
+        shared_jwt::decode_jwt_single(&admin_token, &ctx.jwt_secret).expect("Valid JWT");
+    let user1_claims =
+        shared_jwt::decode_jwt_single(&user1_token, &ctx.jwt_secret).expect("Valid JWT");
+    let user2_claims =
+        shared_jwt::decode_jwt_single(&user2_token, &ctx.jwt_secret).expect("Valid JWT");
 
     assert!(admin_claims.tenant_v < new_tenant_v, "Admin token should be stale");
     assert!(user1_claims.tenant_v < new_tenant_v, "User1 token should be stale");
@@ -314,7 +317,8 @@ async fn test_user_version_mismatch_only_affects_that_user() {
     let new_user1_v = bump_user_version(ctx.db.pool(), user1_id).await;
 
     // User1's token should be stale
-    let user1_claims = shared_jwt::decode_jwt(&user1_token, &ctx.jwt_secret).expect("Valid JWT");
+    let user1_claims =
+        shared_jwt::decode_jwt_single(&user1_token, &ctx.jwt_secret).expect("Valid JWT");
     assert!(
         user1_claims.user_v < new_user1_v,
         "User1 token should be stale: token_v={}, current_v={}",
@@ -324,7 +328,8 @@ async fn test_user_version_mismatch_only_affects_that_user() {
 
     // User2's token should still be valid (their version unchanged)
     let (_, current_user2_v) = get_db_versions(ctx.db.pool(), tenant_id, user2_id).await;
-    let user2_claims = shared_jwt::decode_jwt(&user2_token, &ctx.jwt_secret).expect("Valid JWT");
+    let user2_claims =
+        shared_jwt::decode_jwt_single(&user2_token, &ctx.jwt_secret).expect("Valid JWT");
     assert_eq!(user2_claims.user_v, current_user2_v, "User2 token should still be valid");
 
     // Tenant version should not have changed
@@ -361,7 +366,7 @@ async fn test_user_suspension_bumps_version() {
     let new_user_v = bump_user_version(ctx.db.pool(), user_id).await;
 
     // Token should now be stale
-    let claims = shared_jwt::decode_jwt(&token, &ctx.jwt_secret).expect("Valid JWT");
+    let claims = shared_jwt::decode_jwt_single(&token, &ctx.jwt_secret).expect("Valid JWT");
     assert!(
         claims.user_v < new_user_v,
         "Token should be stale after suspension: token_v={}, current_v={}",
@@ -391,7 +396,7 @@ async fn test_legacy_token_without_versions_should_skip_check() {
     let legacy_token = ctx.create_jwt(user_id, tenant_id, "user");
 
     // Decode and verify no versions
-    let claims = shared_jwt::decode_jwt(&legacy_token, &ctx.jwt_secret).expect("Valid JWT");
+    let claims = shared_jwt::decode_jwt_single(&legacy_token, &ctx.jwt_secret).expect("Valid JWT");
 
     // Legacy tokens have tenant_v=0, user_v=0
     assert_eq!(claims.tenant_v, 0, "Legacy token should have tenant_v=0");
@@ -418,7 +423,7 @@ async fn test_token_with_versions_has_authz_versions_returns_true() {
     let token = create_jwt_with_versions(&ctx, user_id, tenant_id, "user", 1, 1);
 
     // Decode and verify versions exist
-    let claims = shared_jwt::decode_jwt(&token, &ctx.jwt_secret).expect("Valid JWT");
+    let claims = shared_jwt::decode_jwt_single(&token, &ctx.jwt_secret).expect("Valid JWT");
 
     assert_eq!(claims.tenant_v, 1, "Token should have tenant_v=1");
     assert_eq!(claims.user_v, 1, "Token should have user_v=1");
@@ -472,7 +477,7 @@ async fn test_multiple_version_bumps() {
     assert_eq!(v3, initial_tenant_v + 3);
 
     // Token should be stale regardless of how many bumps occurred
-    let claims = shared_jwt::decode_jwt(&token, &ctx.jwt_secret).expect("Valid JWT");
+    let claims = shared_jwt::decode_jwt_single(&token, &ctx.jwt_secret).expect("Valid JWT");
     let (current_tenant_v, _) = get_db_versions(ctx.db.pool(), tenant_id, user_id).await;
 
     assert!(claims.tenant_v < current_tenant_v, "Token should be stale after multiple bumps");
@@ -515,7 +520,7 @@ async fn test_tenant_and_user_version_both_stale() {
     let new_user_v = bump_user_version(ctx.db.pool(), user_id).await;
 
     // Token should be stale on both counts
-    let claims = shared_jwt::decode_jwt(&token, &ctx.jwt_secret).expect("Valid JWT");
+    let claims = shared_jwt::decode_jwt_single(&token, &ctx.jwt_secret).expect("Valid JWT");
 
     assert!(claims.tenant_v < new_tenant_v, "Token should be stale on tenant version");
     assert!(claims.user_v < new_user_v, "Token should be stale on user version");
@@ -557,12 +562,12 @@ async fn test_cross_tenant_isolation() {
     let new_tenant1_v = bump_tenant_version(ctx.db.pool(), tenant1_id).await;
 
     // Tenant1 user's token should be stale
-    let claims1 = shared_jwt::decode_jwt(&token1, &ctx.jwt_secret).expect("Valid JWT");
+    let claims1 = shared_jwt::decode_jwt_single(&token1, &ctx.jwt_secret).expect("Valid JWT");
     assert!(claims1.tenant_v < new_tenant1_v, "Tenant1 user token should be stale");
 
     // Tenant2 user's token should still be valid (different tenant)
     let (current_tenant2_v, _) = get_db_versions(ctx.db.pool(), tenant2_id, user2_id).await;
-    let claims2 = shared_jwt::decode_jwt(&token2, &ctx.jwt_secret).expect("Valid JWT");
+    let claims2 = shared_jwt::decode_jwt_single(&token2, &ctx.jwt_secret).expect("Valid JWT");
     assert_eq!(claims2.tenant_v, current_tenant2_v, "Tenant2 user token should still be valid");
 
     ctx.cleanup().await;
@@ -0,0 +1,118 @@
+// Frozen Clock Tests
+// Token lifetimes and account lock windows follow the injected clock, so exact
+// expiry boundaries can be checked without sleeping.
+// Run: docker-compose -f docker-compose.test.yml up -d && cargo test --test frozen_clock_tests -- --ignored
+
+mod test_database;
+
+use chrono::{Duration, TimeZone, Utc};
+use shared_error::AppError;
+use shared_jwt::{decode_jwt_single_at, JWT_LEEWAY_SECS};
+use shared_types::MockClock;
+use std::sync::Arc;
+use test_database::TestDatabaseConfig;
+use user_service_core::domains::auth::domain::service::AuthService;
+use user_service_core::domains::auth::dto::auth_dto::LoginReq;
+use user_service_infra::auth::{
+    AuthServiceImpl, PgSessionRepository, PgTenantRepository, PgUserRepository,
+};
+use uuid::Uuid;
+
+const JWT_SECRET: &str = "test-secret-key-at-least-32-characters-long";
+const PASSWORD: &str = "SecurePass123!";
+
+type TestAuthService = AuthServiceImpl<PgUserRepository, PgTenantRepository, PgSessionRepository>;
+
+fn auth_service(db: &TestDatabaseConfig, clock: &MockClock) -> TestAuthService {
+    let pool = db.pool().clone();
+    AuthServiceImpl::new(
+        PgUserRepository::new(pool.clone()),
+        PgTenantRepository::new(pool.clone()),
+        PgSessionRepository::new(pool),
+        JWT_SECRET.to_string(),
+        900,
+        604800,
+    )
+    .with_clock(Arc::new(clock.clone()))
+}
+
+async fn login(
+    service: &TestAuthService,
+    tenant_id: Uuid,
+    email: &str,
+) -> Result<String, AppError> {
+    let req = LoginReq {
+        email: email.to_string(),
+        password: PASSWORD.to_string(),
+    };
+    service
+        .login(req, Some(tenant_id.to_string()), None, None)
+        .await
+        .map(|resp| resp.access_token)
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_tokens_are_stamped_from_injected_clock() {
+    let db = TestDatabaseConfig::new().await;
+    let tenant_id = db.create_tenant("Frozen Clock Tokens", None).await;
+    let hash = bcrypt::hash(PASSWORD, 4).unwrap();
+    db.create_user(tenant_id, "tokens@frozen.com", &hash, "user", None)
+        .await;
+
+    let frozen = Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap();
+    let clock = MockClock::new(frozen);
+    let service = auth_service(&db, &clock);
+
+    let token = login(&service, tenant_id, "tokens@frozen.com")
+        .await
+        .unwrap();
+    let claims = decode_jwt_single_at(&token, JWT_SECRET, &clock).unwrap();
+    assert_eq!(claims.iat, frozen.timestamp());
+    assert_eq!(claims.exp, frozen.timestamp() + 900);
+
+    // Valid until the leeway past `exp` runs out, and not a second longer
+    clock.set(frozen + Duration::seconds(900 + JWT_LEEWAY_SECS));
+    assert!(decode_jwt_single_at(&token, JWT_SECRET, &clock).is_ok());
+    clock.advance(Duration::seconds(1));
+    assert!(matches!(
+        decode_jwt_single_at(&token, JWT_SECRET, &clock),
+        Err(AppError::Unauthorized(_))
+    ));
+
+    db.cleanup().await;
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_account_lock_ends_exactly_at_locked_until() {
+    let db = TestDatabaseConfig::new().await;
+    let tenant_id = db.create_tenant("Frozen Clock Lockout", None).await;
+    let hash = bcrypt::hash(PASSWORD, 4).unwrap();
+    let user_id = db
+        .create_user(tenant_id, "locked@frozen.com", &hash, "user", None)
+        .await;
+
+    let locked_until = Utc.with_ymd_and_hms(2030, 1, 1, 12, 0, 0).unwrap();
+    sqlx::query("UPDATE users SET locked_until = $1 WHERE user_id = $2")
+        .bind(locked_until)
+        .bind(user_id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let clock = MockClock::new(locked_until - Duration::seconds(1));
+    let service = auth_service(&db, &clock);
+
+    // One second before the lock ends the account is still locked
+    let result = login(&service, tenant_id, "locked@frozen.com").await;
+    assert!(matches!(result, Err(AppError::ValidationError(ref msg)) if msg.contains("locked")));
+
+    // At the boundary the lock is over
+    clock.advance(Duration::seconds(1));
+    assert!(login(&service, tenant_id, "locked@frozen.com")
+        .await
+        .is_ok());
+
+    db.cleanup().await;
+}
@@ -0,0 +1,36 @@
+//! OpenAPI spec tests
+//!
+//! Checks that the exported user service spec declares the bearer scheme and
+//! marks admin operations with it, while auth endpoints stay public.
+
+use serde_json::Value;
+use user_service_api::openapi::ApiDoc;
+use utoipa::OpenApi;
+
+fn exported_spec() -> Value {
+    let yaml = serde_yaml::to_string(&ApiDoc::openapi()).expect("Spec should serialize to YAML");
+    serde_yaml::from_str(&yaml).expect("Exported spec should deserialize")
+}
+
+#[test]
+fn test_spec_declares_bearer_security_scheme() {
+    let spec = exported_spec();
+    let scheme = &spec["components"]["securitySchemes"]["bearer_auth"];
+
+    assert_eq!(scheme["type"], "http");
+    assert_eq!(scheme["scheme"], "bearer");
+    assert_eq!(scheme["bearerFormat"], "JWT");
+}
+
+#[test]
+fn test_admin_operations_require_bearer_auth_and_login_is_public() {
+    let spec = exported_spec();
+
+    let list_roles = &spec["paths"]["/api/v1/admin/roles"]["get"];
+    assert!(list_roles["security"]
+        .as_array()
+        .is_some_and(|s| s.iter().any(|r| r.get("bearer_auth").is_some())));
+
+    let login = &spec["paths"]["/api/v1/auth/login"]["post"];
+    assert!(login.get("security").is_none());
+}
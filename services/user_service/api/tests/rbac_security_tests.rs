@@ -432,6 +432,7 @@ async fn test_rbac_complex_policy_evaluation() {
             tenant.tenant_id.to_string(),
             "/api/v1/code".to_string(),
             "GET".to_string(),
+            "*".to_string(),
         ])
         .await
         .ok();
@@ -441,6 +442,7 @@ async fn test_rbac_complex_policy_evaluation() {
             tenant.tenant_id.to_string(),
             "/api/v1/code".to_string(),
             "POST".to_string(),
+            "*".to_string(),
         ])
         .await
         .ok();
@@ -452,15 +454,15 @@ async fn test_rbac_complex_policy_evaluation() {
     let e = enforcer.write().await;
 
     let can_get = e
-        .enforce(("role:developer", tenant.tenant_id.to_string(), "/api/v1/code", "GET"))
+        .enforce(("role:developer", tenant.tenant_id.to_string(), "/api/v1/code", "GET", ""))
         .unwrap();
 
     let can_post = e
-        .enforce(("role:developer", tenant.tenant_id.to_string(), "/api/v1/code", "POST"))
+        .enforce(("role:developer", tenant.tenant_id.to_string(), "/api/v1/code", "POST", ""))
         .unwrap();
 
     let can_delete = e
-        .enforce(("role:developer", tenant.tenant_id.to_string(), "/api/v1/code", "DELETE"))
+        .enforce(("role:developer", tenant.tenant_id.to_string(), "/api/v1/code", "DELETE", ""))
         .unwrap();
 
     assert!(can_get, "Developer should be able to GET /api/v1/code");
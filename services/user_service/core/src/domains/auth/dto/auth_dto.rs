@@ -16,6 +16,24 @@ pub struct ErrorResp {
     pub code: Option<String>,
 }
 
+/// Rate limit exceeded (429) response
+///
+/// The same wait is sent in the `Retry-After` header.
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RateLimitedResp {
+    /// Error message
+    #[schema(example = "Too many requests. Please try again in 30 seconds.")]
+    pub error: String,
+
+    /// Error code for client-side handling
+    #[schema(example = "TOO_MANY_REQUESTS")]
+    pub code: String,
+
+    /// Seconds to wait before retrying
+    #[schema(example = 30)]
+    pub retry_after_seconds: u64,
+}
+
 /// Health check response
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct HealthResp {
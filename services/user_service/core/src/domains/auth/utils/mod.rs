@@ -3,5 +3,7 @@ pub mod password_validator;
 
 // Re-export for convenience
 pub use password_validator::{
-    validate_password_quick, validate_password_strength, PasswordValidationResult,
+    check_password_policy, validate_password_quick, validate_password_strength,
+    validate_password_with_policy, BreachedPasswordChecker, PasswordPolicy, PasswordRule,
+    PasswordRuleFailure, PasswordValidationResult,
 };
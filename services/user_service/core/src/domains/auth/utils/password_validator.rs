@@ -1,32 +1,150 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared_error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
 use zxcvbn::{zxcvbn, Score};
 
-/// Password strength requirements
-pub struct PasswordStrength {
-    /// Minimum password length
+use crate::domains::auth::domain::{model::Tenant, repository::TenantRepository};
+
+/// Password policy rules
+///
+/// The platform-wide policy comes from configuration; tenants may tighten it
+/// through the `password_policy` object in their settings (see
+/// [`PasswordPolicy::with_tenant_overrides`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    /// Minimum password length (in characters)
     pub min_length: usize,
+    /// Require at least one uppercase letter
+    pub require_uppercase: bool,
+    /// Require at least one lowercase letter
+    pub require_lowercase: bool,
+    /// Require at least one digit
+    pub require_digit: bool,
+    /// Require at least one non-alphanumeric character
+    pub require_symbol: bool,
     /// Minimum zxcvbn score (0-4, recommend 3+)
-    pub min_score: Score,
+    pub min_score: u8,
+    /// Reject passwords found in known breaches
+    pub check_breached: bool,
 }
 
-impl Default for PasswordStrength {
+impl Default for PasswordPolicy {
     fn default() -> Self {
         Self {
             min_length: 8,
-            min_score: Score::Three, // Strong password
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_symbol: false,
+            min_score: 3, // Strong password
+            check_breached: false,
+        }
+    }
+}
+
+/// Tenant-level policy settings; every field is optional
+#[derive(Debug, Default, Deserialize)]
+struct PasswordPolicyOverrides {
+    min_length: Option<usize>,
+    require_uppercase: Option<bool>,
+    require_lowercase: Option<bool>,
+    require_digit: Option<bool>,
+    require_symbol: Option<bool>,
+    min_score: Option<u8>,
+    check_breached: Option<bool>,
+}
+
+impl PasswordPolicy {
+    /// Apply the `password_policy` object from tenant settings
+    ///
+    /// Overrides can only make the policy stricter: a tenant cannot lower the
+    /// platform minimum length or score, nor switch off a required rule.
+    /// Missing or malformed settings leave the policy unchanged.
+    pub fn with_tenant_overrides(&self, settings: &serde_json::Value) -> Self {
+        let overrides = settings
+            .get("password_policy")
+            .and_then(|value| serde_json::from_value::<PasswordPolicyOverrides>(value.clone()).ok())
+            .unwrap_or_default();
+
+        Self {
+            min_length: self.min_length.max(overrides.min_length.unwrap_or(0)),
+            require_uppercase: self.require_uppercase || overrides.require_uppercase == Some(true),
+            require_lowercase: self.require_lowercase || overrides.require_lowercase == Some(true),
+            require_digit: self.require_digit || overrides.require_digit == Some(true),
+            require_symbol: self.require_symbol || overrides.require_symbol == Some(true),
+            min_score: self.min_score.max(overrides.min_score.unwrap_or(0)).min(4),
+            check_breached: self.check_breached || overrides.check_breached == Some(true),
         }
     }
 }
 
+/// Individual password policy rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordRule {
+    MinLength,
+    Uppercase,
+    Lowercase,
+    Digit,
+    Symbol,
+    Strength,
+    Breached,
+}
+
+/// A rule the password failed, with a user-facing message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PasswordRuleFailure {
+    pub rule: PasswordRule,
+    pub message: String,
+}
+
 /// Validation result with detailed feedback
 #[derive(Debug)]
 pub struct PasswordValidationResult {
     pub is_valid: bool,
     pub score: Score,
+    /// Policy rules the password failed (empty when valid)
+    pub failures: Vec<PasswordRuleFailure>,
     pub feedback: Vec<String>,
     pub estimated_crack_time: String,
 }
 
-/// Validate password strength using zxcvbn
+impl PasswordValidationResult {
+    fn fail(&mut self, rule: PasswordRule, message: String) {
+        self.is_valid = false;
+        self.feedback.push(message.clone());
+        self.failures.push(PasswordRuleFailure { rule, message });
+    }
+
+    /// Whether a specific rule failed
+    pub fn failed(&self, rule: PasswordRule) -> bool {
+        self.failures.iter().any(|f| f.rule == rule)
+    }
+
+    /// Convert into the error message used by `validate_password_quick`
+    pub fn into_result(self) -> Result<(), String> {
+        if !self.is_valid {
+            if self.feedback.is_empty() {
+                return Err("Password is too weak".to_string());
+            }
+            return Err(self.feedback.join(". "));
+        }
+
+        Ok(())
+    }
+}
+
+/// Lookup of passwords exposed in known data breaches
+#[async_trait]
+pub trait BreachedPasswordChecker: Send + Sync {
+    /// Number of times the password appears in breach corpora (0 if never seen)
+    async fn breach_count(&self, password: &str) -> Result<u64, AppError>;
+}
+
+/// Validate password strength using zxcvbn and the default policy
 ///
 /// # Arguments
 /// * `password` - The password to validate
@@ -38,81 +156,182 @@ pub fn validate_password_strength(
     password: &str,
     user_inputs: &[&str],
 ) -> PasswordValidationResult {
-    let requirements = PasswordStrength::default();
+    validate_password_with_policy(password, user_inputs, &PasswordPolicy::default())
+}
 
-    let mut feedback = Vec::new();
+/// Validate password strength against a specific policy
+///
+/// Every rule is evaluated so callers get the full list of failures at once.
+/// The breached-password rule needs I/O and is checked separately by
+/// [`check_password_policy`].
+pub fn validate_password_with_policy(
+    password: &str,
+    user_inputs: &[&str],
+    policy: &PasswordPolicy,
+) -> PasswordValidationResult {
+    let mut result = PasswordValidationResult {
+        is_valid: true,
+        score: Score::Zero,
+        failures: Vec::new(),
+        feedback: Vec::new(),
+        estimated_crack_time: "Instant".to_string(),
+    };
 
     // Check minimum length
-    if password.len() < requirements.min_length {
-        feedback
-            .push(format!("Password must be at least {} characters long", requirements.min_length));
-        return PasswordValidationResult {
-            is_valid: false,
-            score: Score::Zero,
-            feedback,
-            estimated_crack_time: "Instant".to_string(),
-        };
+    if password.chars().count() < policy.min_length {
+        result.fail(
+            PasswordRule::MinLength,
+            format!("Password must be at least {} characters long", policy.min_length),
+        );
+        return result;
+    }
+
+    // Check required character classes
+    if policy.require_uppercase && !password.chars().any(char::is_uppercase) {
+        result
+            .fail(PasswordRule::Uppercase, "Password must contain an uppercase letter".to_string());
+    }
+    if policy.require_lowercase && !password.chars().any(char::is_lowercase) {
+        result
+            .fail(PasswordRule::Lowercase, "Password must contain a lowercase letter".to_string());
+    }
+    if policy.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+        result.fail(PasswordRule::Digit, "Password must contain a digit".to_string());
+    }
+    if policy.require_symbol && !password.chars().any(|c| !c.is_alphanumeric()) {
+        result.fail(PasswordRule::Symbol, "Password must contain a symbol".to_string());
     }
 
     // Run zxcvbn analysis
     let entropy = zxcvbn(password, user_inputs);
     let score = entropy.score();
-
-    // Check if password meets minimum score
-    let is_valid = score >= requirements.min_score;
+    result.score = score;
 
     // Add suggestions from zxcvbn
     if let Some(zxcvbn_feedback) = entropy.feedback() {
         if let Some(warning) = zxcvbn_feedback.warning() {
-            feedback.push(warning.to_string());
+            result.feedback.push(warning.to_string());
         }
 
         for suggestion in zxcvbn_feedback.suggestions() {
-            feedback.push(suggestion.to_string());
+            result.feedback.push(suggestion.to_string());
         }
     }
 
     // Add score-based feedback
-    match score {
-        Score::Zero => feedback.push("Password is extremely weak".to_string()),
-        Score::One => feedback.push("Password is very weak".to_string()),
-        Score::Two => feedback.push("Password is weak".to_string()),
-        Score::Three => {
-            if !is_valid {
-                feedback.push("Password is acceptable but could be stronger".to_string());
-            }
-        },
-        Score::Four => {}, // Strong, no additional feedback needed
-        _ => {},           // Future-proof for any additional scores
+    if u8::from(score) < policy.min_score {
+        let message = match score {
+            Score::Zero => "Password is extremely weak",
+            Score::One => "Password is very weak",
+            Score::Two => "Password is weak",
+            _ => "Password is acceptable but could be stronger",
+        };
+        result.fail(PasswordRule::Strength, message.to_string());
     }
 
     // Get crack time estimate
     let times = entropy.crack_times();
-    let crack_time = format!(
+    result.estimated_crack_time = format!(
         "Estimated crack time (offline slow hash): {}",
         times.offline_slow_hashing_1e4_per_second()
     );
 
-    PasswordValidationResult {
-        is_valid,
-        score,
-        feedback,
-        estimated_crack_time: crack_time,
+    result
+}
+
+/// Validate a password against a policy, including the breached-password rule
+///
+/// The breach lookup only runs when the policy enables it, a checker is
+/// available and the password passed every local rule.
+pub async fn check_password_policy(
+    password: &str,
+    user_inputs: &[&str],
+    policy: &PasswordPolicy,
+    breach_checker: Option<&dyn BreachedPasswordChecker>,
+) -> Result<PasswordValidationResult, AppError> {
+    let mut result = validate_password_with_policy(password, user_inputs, policy);
+
+    if let (true, true, Some(checker)) = (result.is_valid, policy.check_breached, breach_checker) {
+        let count = checker.breach_count(password).await?;
+        if count > 0 {
+            result.fail(
+                PasswordRule::Breached,
+                "Password has appeared in a known data breach; choose a different password"
+                    .to_string(),
+            );
+        }
     }
+
+    Ok(result)
 }
 
-/// Quick validation - returns error message if invalid
-pub fn validate_password_quick(password: &str, user_inputs: &[&str]) -> Result<(), String> {
-    let result = validate_password_strength(password, user_inputs);
+/// Policy-aware password validation shared by every flow that sets a password
+///
+/// Registration, admin resets, invitation acceptance and the forgot-password
+/// flow all go through this, so they enforce the same policy.
+#[derive(Clone, Default)]
+pub struct PasswordValidator {
+    policy: PasswordPolicy,
+    breach_checker: Option<Arc<dyn BreachedPasswordChecker>>,
+    tenant_repo: Option<Arc<dyn TenantRepository>>,
+}
 
-    if !result.is_valid {
-        if result.feedback.is_empty() {
-            return Err("Password is too weak".to_string());
+impl PasswordValidator {
+    pub fn new(
+        policy: PasswordPolicy,
+        breach_checker: Option<Arc<dyn BreachedPasswordChecker>>,
+    ) -> Self {
+        Self {
+            policy,
+            breach_checker,
+            tenant_repo: None,
         }
-        return Err(result.feedback.join(". "));
     }
 
-    Ok(())
+    /// Look up tenant settings for [`Self::validate_for_tenant`]
+    pub fn with_tenant_repo(mut self, tenant_repo: Arc<dyn TenantRepository>) -> Self {
+        self.tenant_repo = Some(tenant_repo);
+        self
+    }
+
+    /// Validate a password against the platform policy tightened by tenant settings
+    pub async fn validate(
+        &self,
+        password: &str,
+        user_inputs: &[&str],
+        tenant: Option<&Tenant>,
+    ) -> Result<(), AppError> {
+        let policy = match tenant {
+            Some(tenant) => self.policy.with_tenant_overrides(&tenant.settings),
+            None => self.policy.clone(),
+        };
+
+        check_password_policy(password, user_inputs, &policy, self.breach_checker.as_deref())
+            .await?
+            .into_result()
+            .map_err(|e| AppError::ValidationError(format!("Password validation failed: {}", e)))
+    }
+
+    /// Validate a password for a tenant that the caller only knows by id
+    ///
+    /// Without a tenant repository the platform policy applies unchanged.
+    pub async fn validate_for_tenant(
+        &self,
+        password: &str,
+        user_inputs: &[&str],
+        tenant_id: Uuid,
+    ) -> Result<(), AppError> {
+        let tenant = match &self.tenant_repo {
+            Some(repo) => repo.find_by_id(tenant_id).await?,
+            None => None,
+        };
+        self.validate(password, user_inputs, tenant.as_ref()).await
+    }
+}
+
+/// Quick validation - returns error message if invalid
+pub fn validate_password_quick(password: &str, user_inputs: &[&str]) -> Result<(), String> {
+    validate_password_strength(password, user_inputs).into_result()
 }
 
 #[cfg(test)]
@@ -188,4 +407,242 @@ mod tests {
         let result = validate_password_strength("correct horse battery staple", &[]);
         assert!(result.is_valid);
     }
+
+    fn policy_with(update: impl FnOnce(&mut PasswordPolicy)) -> PasswordPolicy {
+        // Score 0 so only the rule under test can fail
+        let mut policy = PasswordPolicy {
+            min_score: 0,
+            ..PasswordPolicy::default()
+        };
+        update(&mut policy);
+        policy
+    }
+
+    #[test]
+    fn test_policy_min_length() {
+        let policy = policy_with(|p| p.min_length = 12);
+        let result = validate_password_with_policy("short-pass", &[], &policy);
+        assert!(!result.is_valid);
+        assert!(result.failed(PasswordRule::MinLength));
+
+        let policy = policy_with(|p| p.min_length = 4);
+        assert!(validate_password_with_policy("short-pass", &[], &policy).is_valid);
+    }
+
+    #[test]
+    fn test_policy_require_uppercase() {
+        let policy = policy_with(|p| p.require_uppercase = true);
+        let result = validate_password_with_policy("lowercase only", &[], &policy);
+        assert!(!result.is_valid);
+        assert!(result.failed(PasswordRule::Uppercase));
+        assert!(validate_password_with_policy("Lowercase only", &[], &policy).is_valid);
+
+        let policy = policy_with(|p| p.require_uppercase = false);
+        assert!(validate_password_with_policy("lowercase only", &[], &policy).is_valid);
+    }
+
+    #[test]
+    fn test_policy_require_lowercase() {
+        let policy = policy_with(|p| p.require_lowercase = true);
+        let result = validate_password_with_policy("UPPERCASE ONLY", &[], &policy);
+        assert!(result.failed(PasswordRule::Lowercase));
+        assert!(validate_password_with_policy("UPPERCASE ONLy", &[], &policy).is_valid);
+
+        let policy = policy_with(|p| p.require_lowercase = false);
+        assert!(validate_password_with_policy("UPPERCASE ONLY", &[], &policy).is_valid);
+    }
+
+    #[test]
+    fn test_policy_require_digit() {
+        let policy = policy_with(|p| p.require_digit = true);
+        let result = validate_password_with_policy("no digits here", &[], &policy);
+        assert!(result.failed(PasswordRule::Digit));
+        assert!(validate_password_with_policy("no digits here 1", &[], &policy).is_valid);
+
+        let policy = policy_with(|p| p.require_digit = false);
+        assert!(validate_password_with_policy("no digits here", &[], &policy).is_valid);
+    }
+
+    #[test]
+    fn test_policy_require_symbol() {
+        let policy = policy_with(|p| p.require_symbol = true);
+        let result = validate_password_with_policy("NoSymbolsHere1", &[], &policy);
+        assert!(result.failed(PasswordRule::Symbol));
+        assert!(validate_password_with_policy("NoSymbolsHere1!", &[], &policy).is_valid);
+
+        let policy = policy_with(|p| p.require_symbol = false);
+        assert!(validate_password_with_policy("NoSymbolsHere1", &[], &policy).is_valid);
+    }
+
+    #[test]
+    fn test_policy_min_score() {
+        let policy = policy_with(|p| p.min_score = 3);
+        let result = validate_password_with_policy("password123", &[], &policy);
+        assert!(result.failed(PasswordRule::Strength));
+
+        let policy = policy_with(|p| p.min_score = 0);
+        assert!(validate_password_with_policy("password123", &[], &policy).is_valid);
+    }
+
+    #[test]
+    fn test_policy_reports_every_failed_rule() {
+        let policy = policy_with(|p| {
+            p.require_uppercase = true;
+            p.require_digit = true;
+            p.require_symbol = true;
+        });
+        let result = validate_password_with_policy("lowercaseletters", &[], &policy);
+        let rules: Vec<PasswordRule> = result.failures.iter().map(|f| f.rule).collect();
+        assert_eq!(
+            rules,
+            vec![
+                PasswordRule::Uppercase,
+                PasswordRule::Digit,
+                PasswordRule::Symbol
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tenant_overrides_only_tighten() {
+        let base = PasswordPolicy::default();
+        let settings = serde_json::json!({
+            "password_policy": {
+                "min_length": 4,
+                "require_symbol": true,
+                "min_score": 4,
+                "check_breached": true
+            }
+        });
+        let policy = base.with_tenant_overrides(&settings);
+        assert_eq!(policy.min_length, base.min_length);
+        assert!(policy.require_symbol);
+        assert_eq!(policy.min_score, 4);
+        assert!(policy.check_breached);
+
+        let tightened = PasswordPolicy {
+            require_digit: true,
+            ..PasswordPolicy::default()
+        };
+        let relaxed = serde_json::json!({ "password_policy": { "require_digit": false } });
+        assert!(tightened.with_tenant_overrides(&relaxed).require_digit);
+    }
+
+    #[test]
+    fn test_tenant_overrides_ignore_missing_settings() {
+        let base = PasswordPolicy::default();
+        assert_eq!(base.with_tenant_overrides(&serde_json::json!({})), base);
+        assert_eq!(
+            base.with_tenant_overrides(&serde_json::json!({ "password_policy": "strict" })),
+            base
+        );
+    }
+
+    /// Breach checker that reports a fixed count for one password
+    struct MockBreachChecker {
+        breached_password: &'static str,
+    }
+
+    #[async_trait]
+    impl BreachedPasswordChecker for MockBreachChecker {
+        async fn breach_count(&self, password: &str) -> Result<u64, AppError> {
+            Ok(if password == self.breached_password {
+                3_861_493
+            } else {
+                0
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_breached_password_rejected() {
+        let checker = MockBreachChecker {
+            breached_password: "Tr0ub4dor&3xKcd!",
+        };
+        let policy = PasswordPolicy {
+            check_breached: true,
+            ..PasswordPolicy::default()
+        };
+
+        let result = check_password_policy("Tr0ub4dor&3xKcd!", &[], &policy, Some(&checker))
+            .await
+            .unwrap();
+        assert!(!result.is_valid);
+        assert!(result.failed(PasswordRule::Breached));
+
+        let result =
+            check_password_policy("correct horse battery staple", &[], &policy, Some(&checker))
+                .await
+                .unwrap();
+        assert!(result.is_valid);
+    }
+
+    #[tokio::test]
+    async fn test_breach_check_disabled_by_policy() {
+        let checker = MockBreachChecker {
+            breached_password: "Tr0ub4dor&3xKcd!",
+        };
+        let policy = PasswordPolicy::default();
+
+        let result = check_password_policy("Tr0ub4dor&3xKcd!", &[], &policy, Some(&checker))
+            .await
+            .unwrap();
+        assert!(result.is_valid);
+    }
+
+    fn tenant_with_settings(settings: serde_json::Value) -> Tenant {
+        Tenant {
+            tenant_id: Uuid::now_v7(),
+            name: "Acme".to_string(),
+            slug: "acme".to_string(),
+            plan: "free".to_string(),
+            plan_expires_at: None,
+            settings: sqlx::types::Json(settings),
+            status: "active".to_string(),
+            owner_user_id: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validator_runs_breach_check() {
+        let validator = PasswordValidator::new(
+            PasswordPolicy {
+                check_breached: true,
+                ..PasswordPolicy::default()
+            },
+            Some(Arc::new(MockBreachChecker {
+                breached_password: "Tr0ub4dor&3xKcd!",
+            })),
+        );
+
+        let err = validator
+            .validate("Tr0ub4dor&3xKcd!", &[], None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::ValidationError(_)));
+        assert!(validator
+            .validate("correct horse battery staple", &[], None)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validator_applies_tenant_overrides() {
+        let validator = PasswordValidator::new(PasswordPolicy::default(), None);
+        let tenant = tenant_with_settings(
+            serde_json::json!({ "password_policy": { "require_symbol": true } }),
+        );
+
+        assert!(validator
+            .validate("correct horse battery staple", &[], None)
+            .await
+            .is_ok());
+        assert!(validator
+            .validate("correcthorsebatterystaple", &[], Some(&tenant))
+            .await
+            .is_err());
+    }
 }
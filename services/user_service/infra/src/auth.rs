@@ -3,6 +3,7 @@ pub mod audit_log_repository;
 pub mod authz_version_repository;
 pub mod email_verification_repository;
 pub mod email_verification_service;
+pub mod hibp_checker;
 pub mod invitation_repository;
 pub mod invitation_service;
 pub mod password_reset_repository;
@@ -19,6 +20,7 @@ pub use audit_log_repository::PgAuditLogRepository;
 pub use authz_version_repository::RedisAuthzVersionRepository;
 pub use email_verification_repository::PgEmailVerificationRepository;
 pub use email_verification_service::EmailVerificationServiceImpl;
+pub use hibp_checker::HibpBreachedPasswordChecker;
 pub use invitation_repository::PgInvitationRepository;
 pub use invitation_service::InvitationServiceImpl;
 pub use password_reset_repository::PgPasswordResetRepository;
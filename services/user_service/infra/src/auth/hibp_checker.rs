@@ -0,0 +1,112 @@
+//! Have I Been Pwned breached-password checker
+//!
+//! Uses the k-anonymity range API: only the first 5 hex characters of the
+//! password's SHA-1 hash are sent, and the suffix is matched locally.
+
+use async_trait::async_trait;
+use sha1::{Digest, Sha1};
+use shared_error::AppError;
+use std::time::Duration;
+use user_service_core::domains::auth::utils::password_validator::BreachedPasswordChecker;
+
+const DEFAULT_RANGE_API_URL: &str = "https://api.pwnedpasswords.com/range";
+
+/// Breached-password checker backed by the HIBP range API
+///
+/// Lookups fail open: if the API is unreachable the password is treated as
+/// not breached so sign-ups keep working during an outage.
+pub struct HibpBreachedPasswordChecker {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HibpBreachedPasswordChecker {
+    /// Create a checker against the public HIBP API
+    pub fn new() -> Result<Self, AppError> {
+        Self::with_base_url(DEFAULT_RANGE_API_URL)
+    }
+
+    /// Create a checker against a custom range endpoint (e.g. a self-hosted mirror)
+    pub fn with_base_url(base_url: &str) -> Result<Self, AppError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(3))
+            .user_agent("anthill-user-service")
+            .build()
+            .map_err(|e| AppError::InternalError(format!("Failed to build HIBP client: {}", e)))?;
+
+        Ok(Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+}
+
+/// Split the uppercase SHA-1 hex digest into the 5-char prefix and the suffix
+fn hash_prefix_and_suffix(password: &str) -> (String, String) {
+    let digest = hex::encode_upper(Sha1::digest(password.as_bytes()));
+    let (prefix, suffix) = digest.split_at(5);
+    (prefix.to_string(), suffix.to_string())
+}
+
+/// Find the breach count for a hash suffix in a range response (`SUFFIX:COUNT` per line)
+fn parse_range_response(body: &str, suffix: &str) -> u64 {
+    body.lines()
+        .filter_map(|line| line.trim().split_once(':'))
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(suffix))
+        .and_then(|(_, count)| count.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for HibpBreachedPasswordChecker {
+    async fn breach_count(&self, password: &str) -> Result<u64, AppError> {
+        let (prefix, suffix) = hash_prefix_and_suffix(password);
+        let url = format!("{}/{}", self.base_url, prefix);
+
+        // Add-Padding hides the real response size from network observers
+        let response = self
+            .client
+            .get(&url)
+            .header("Add-Padding", "true")
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+
+        let body = match response {
+            Ok(response) => response.text().await,
+            Err(e) => Err(e),
+        };
+
+        match body {
+            Ok(body) => Ok(parse_range_response(&body, &suffix)),
+            Err(e) => {
+                tracing::warn!("HIBP range lookup failed, skipping breach check: {}", e);
+                Ok(0)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_prefix_and_suffix() {
+        // SHA-1("password") = 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+        let (prefix, suffix) = hash_prefix_and_suffix("password");
+        assert_eq!(prefix, "5BAA6");
+        assert_eq!(suffix, "1E4C9B93F3F0682250B6CF8331B7EE68FD8");
+    }
+
+    #[test]
+    fn test_parse_range_response() {
+        let body = "0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD8:3861493\r\n\
+                    011053FD0102E94D6AE2F8B83D76FAF94F6:0\r\n";
+        assert_eq!(parse_range_response(body, "1E4C9B93F3F0682250B6CF8331B7EE68FD8"), 3_861_493);
+        assert_eq!(parse_range_response(body, "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"), 0);
+        // Padding entries carry a zero count
+        assert_eq!(parse_range_response(body, "011053FD0102E94D6AE2F8B83D76FAF94F6"), 0);
+    }
+}
@@ -12,7 +12,7 @@ use user_service_core::domains::auth::domain::{
 };
 use user_service_core::domains::auth::utils::{
     invitation_utils::{generate_invite_token, hash_token},
-    password_validator::validate_password_quick,
+    password_validator::PasswordValidator,
 };
 use user_service_core::domains::auth::{
     domain::{model::User, repository::UserRepository},
@@ -32,6 +32,7 @@ where
     invitation_expiry_hours: i64,
     invitation_max_attempts: i32,
     invitation_max_per_admin_per_day: i32,
+    password_validator: PasswordValidator,
 }
 
 impl<IR, UR> InvitationServiceImpl<IR, UR>
@@ -54,9 +55,16 @@ where
             invitation_expiry_hours,
             invitation_max_attempts,
             invitation_max_per_admin_per_day,
+            password_validator: PasswordValidator::default(),
         }
     }
 
+    /// Use the platform password validator shared with the other password flows
+    pub fn with_password_validator(mut self, password_validator: PasswordValidator) -> Self {
+        self.password_validator = password_validator;
+        self
+    }
+
     #[allow(dead_code)]
     fn user_to_user_info(&self, user: &User) -> UserInfo {
         UserInfo {
@@ -249,8 +257,9 @@ where
             full_name.unwrap_or(""),
             &invitation.tenant_id.to_string(),
         ];
-        validate_password_quick(password, &user_inputs)
-            .map_err(|e| AppError::ValidationError(format!("Password validation failed: {}", e)))?;
+        self.password_validator
+            .validate_for_tenant(password, &user_inputs, invitation.tenant_id)
+            .await?;
 
         // Hash password (offload to blocking thread pool)
         let password = password.to_string(); // Clone for move into closure
@@ -12,6 +12,7 @@ use user_service_core::domains::auth::{
     dto::password_reset_dto::{
         mask_email, ForgotPasswordResp, ResetPasswordResp, ValidateResetTokenResp,
     },
+    utils::password_validator::PasswordValidator,
 };
 use uuid::Uuid;
 
@@ -39,7 +40,7 @@ where
     rate_limit_max: u32,
     rate_limit_window_minutes: i64,
     email_sender: SharedEmailSender,
-    min_password_length: usize,
+    password_validator: PasswordValidator,
 }
 
 impl<PRR, UR, SR> PasswordResetServiceImpl<PRR, UR, SR>
@@ -58,7 +59,6 @@ where
         rate_limit_max: u32,
         rate_limit_window_minutes: i64,
         email_sender: SharedEmailSender,
-        min_password_length: usize,
     ) -> Self {
         Self {
             reset_repo,
@@ -69,10 +69,16 @@ where
             rate_limit_max,
             rate_limit_window_minutes,
             email_sender,
-            min_password_length,
+            password_validator: PasswordValidator::default(),
         }
     }
 
+    /// Use the platform password validator shared with the other password flows
+    pub fn with_password_validator(mut self, password_validator: PasswordValidator) -> Self {
+        self.password_validator = password_validator;
+        self
+    }
+
     /// Generate a cryptographically secure reset token
     /// Returns (plaintext_token, token_hash)
     fn generate_token() -> (String, String) {
@@ -127,29 +133,6 @@ where
         self.email_sender.send(content).await
     }
 
-    /// Validate password strength
-    fn validate_password(&self, password: &str) -> Result<(), AppError> {
-        if password.len() < self.min_password_length {
-            return Err(AppError::ValidationError(format!(
-                "Password must be at least {} characters",
-                self.min_password_length
-            )));
-        }
-
-        // Basic strength checks
-        let has_uppercase = password.chars().any(|c| c.is_uppercase());
-        let has_lowercase = password.chars().any(|c| c.is_lowercase());
-        let has_digit = password.chars().any(|c| c.is_ascii_digit());
-
-        if !has_uppercase || !has_lowercase || !has_digit {
-            return Err(AppError::ValidationError(
-                "Password must contain uppercase, lowercase, and numeric characters".to_string(),
-            ));
-        }
-
-        Ok(())
-    }
-
     /// Hash password with bcrypt
     fn hash_password(&self, password: &str) -> Result<String, AppError> {
         bcrypt::hash(password, bcrypt::DEFAULT_COST)
@@ -345,9 +328,6 @@ where
             ));
         }
 
-        // Validate new password strength
-        self.validate_password(new_password)?;
-
         // Get user to get email for audit
         let mut user = self
             .user_repo
@@ -355,6 +335,12 @@ where
             .await?
             .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
+        // Validate new password against the platform and tenant policy
+        let user_inputs = [user.email.as_str(), user.full_name.as_deref().unwrap_or("")];
+        self.password_validator
+            .validate_for_tenant(new_password, &user_inputs, reset_token.tenant_id)
+            .await?;
+
         // Hash new password
         let password_hash = self.hash_password(new_password)?;
 
@@ -174,7 +174,10 @@ impl ProfileService for ProfileServiceImpl {
         let old_avatar_url = user.avatar_url.clone();
 
         // Process image: resize to avatar dimensions and compress
-        let config = ImageProcessingConfig::default();
+        let mut config = ImageProcessingConfig::default();
+        if let Some(storage) = &self.storage_client {
+            config.limits = storage.image_limits().clone();
+        }
         let processed = process_avatar(&file_data, &request.content_type, &config)?;
 
         tracing::info!(
@@ -208,14 +211,15 @@ impl ProfileService for ProfileServiceImpl {
             // Clean up old avatar if it exists and is different from new one
             if let Some(old_url) = &old_avatar_url {
                 if old_url != &url {
-                    if let Some(old_key) = storage.extract_key_from_url(old_url) {
-                        tracing::info!(
-                            old_key = %old_key,
+                    tracing::info!(old_url = %old_url, user_id = %user_id, "Cleaning up old avatar");
+                    // Don't fail the upload if cleanup fails
+                    if let Err(e) = storage.delete_by_url(old_url).await {
+                        tracing::warn!(
+                            old_url = %old_url,
                             user_id = %user_id,
-                            "Cleaning up old avatar"
+                            error = %e,
+                            "Failed to clean up old avatar"
                         );
-                        // Delete silently - don't fail upload if cleanup fails
-                        storage.delete_silent(&old_key).await;
                     }
                 }
             }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use shared_db::AssertTenantFiltered;
 use shared_error::AppError;
 use sqlx::PgPool;
 use user_service_core::domains::auth::domain::{
@@ -223,12 +224,14 @@ impl UserRepository for PgUserRepository {
 
         // Execute count query
         let total: (i64,) = count_builder
+            .assert_tenant_filtered()
             .build_query_as::<(i64,)>()
             .fetch_one(&self.pool)
             .await?;
 
         // Execute data query
         let users = query_builder
+            .assert_tenant_filtered()
             .build_query_as::<User>()
             .fetch_all(&self.pool)
             .await?;
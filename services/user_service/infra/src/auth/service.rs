@@ -1,9 +1,10 @@
 use async_trait::async_trait;
-use chrono::Utc;
 use serde_json;
 use sha2::{Digest, Sha256};
 use shared_error::AppError;
-use shared_jwt::{decode_jwt, encode_jwt, Claims};
+use shared_jwt::{decode_refresh_at, encode_jwt, Claims};
+use shared_types::{SharedClock, SystemClock};
+use std::collections::HashMap;
 use user_service_core::domains::auth::{
     domain::{
         model::{Session, Tenant, User},
@@ -14,7 +15,7 @@ use user_service_core::domains::auth::{
     dto::auth_dto::{
         AuthResp, LoginReq, RefreshReq, RegisterReq, RegisterResp, UserInfo, UserListResp,
     },
-    utils::password_validator::validate_password_quick,
+    utils::password_validator::PasswordValidator,
 };
 use uuid::Uuid;
 
@@ -31,6 +32,10 @@ where
     jwt_secret: String,
     jwt_expiration: i64,
     jwt_refresh_expiration: i64,
+    jwt_expiration_overrides: HashMap<String, i64>,
+    jwt_refresh_expiration_overrides: HashMap<String, i64>,
+    password_validator: PasswordValidator,
+    clock: SharedClock,
 }
 
 impl<UR, TR, SR> AuthServiceImpl<UR, TR, SR>
@@ -54,9 +59,64 @@ where
             jwt_secret,
             jwt_expiration,
             jwt_refresh_expiration,
+            jwt_expiration_overrides: HashMap::new(),
+            jwt_refresh_expiration_overrides: HashMap::new(),
+            password_validator: PasswordValidator::default(),
+            clock: SystemClock::shared(),
         }
     }
 
+    /// Use a different time source for token, session and lockout timestamps
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Use the platform password validator shared with the other password flows
+    pub fn with_password_validator(mut self, password_validator: PasswordValidator) -> Self {
+        self.password_validator = password_validator;
+        self
+    }
+
+    /// Use per-role token lifetimes, falling back to the default expirations
+    pub fn with_expiration_overrides(
+        mut self,
+        access_overrides: HashMap<String, i64>,
+        refresh_overrides: HashMap<String, i64>,
+    ) -> Self {
+        self.jwt_expiration_overrides = access_overrides;
+        self.jwt_refresh_expiration_overrides = refresh_overrides;
+        self
+    }
+
+    /// Access token lifetime in seconds for a role
+    fn access_expiration(&self, role: &str) -> i64 {
+        self.jwt_expiration_overrides
+            .get(role)
+            .copied()
+            .unwrap_or(self.jwt_expiration)
+    }
+
+    /// Refresh token lifetime in seconds for a role
+    fn refresh_expiration(&self, role: &str) -> i64 {
+        self.jwt_refresh_expiration_overrides
+            .get(role)
+            .copied()
+            .unwrap_or(self.jwt_refresh_expiration)
+    }
+
+    /// Validate a password against the platform policy tightened by tenant settings
+    async fn validate_password(
+        &self,
+        password: &str,
+        user_inputs: &[&str],
+        tenant: Option<&Tenant>,
+    ) -> Result<(), AppError> {
+        self.password_validator
+            .validate(password, user_inputs, tenant)
+            .await
+    }
+
     fn user_to_user_info(&self, user: &User) -> UserInfo {
         UserInfo {
             id: user.user_id,
@@ -89,7 +149,7 @@ where
         ip_address: Option<String>,
         user_agent: Option<String>,
     ) -> Result<Session, AppError> {
-        let now = Utc::now();
+        let now = self.clock.now();
         let session = Session {
             session_id: Uuid::new_v4(),
             user_id,
@@ -143,7 +203,7 @@ where
             } else {
                 // Create new tenant - user will become 'owner'
                 let tenant_id = Uuid::now_v7();
-                let now = Utc::now();
+                let now = self.clock.now();
                 let tenant = Tenant {
                     tenant_id,
                     name: tenant_name.clone(),
@@ -201,8 +261,8 @@ where
             req.full_name.as_str(),
             tenant.name.as_str(),
         ];
-        validate_password_quick(&req.password, &user_inputs)
-            .map_err(|e| AppError::ValidationError(format!("Password validation failed: {}", e)))?;
+        self.validate_password(&req.password, &user_inputs, Some(&tenant))
+            .await?;
 
         // Hash password
         let password_hash = bcrypt::hash(&req.password, bcrypt::DEFAULT_COST)
@@ -215,7 +275,7 @@ where
 
         // Create user
         let user_id = Uuid::now_v7();
-        let now = Utc::now();
+        let now = self.clock.now();
         let user = User {
             user_id,
             tenant_id: tenant.tenant_id,
@@ -340,25 +400,26 @@ where
         }
 
         // Check if account is locked
+        let now = self.clock.now();
         if let Some(locked_until) = user.locked_until {
-            if locked_until > chrono::Utc::now() {
+            if locked_until > now {
                 return Err(AppError::ValidationError("Account is temporarily locked".to_string()));
             }
         }
 
         // Generate JWT tokens
-        let access_claims = Claims::new_access(
-            user.user_id,
-            user.tenant_id,
-            user.role.clone(),
-            self.jwt_expiration,
-        );
+        let access_expiration = self.access_expiration(&user.role);
+        let refresh_expiration = self.refresh_expiration(&user.role);
+        let access_claims =
+            Claims::new_access(user.user_id, user.tenant_id, user.role.clone(), access_expiration)
+                .issued_at(now.timestamp());
         let refresh_claims = Claims::new_refresh(
             user.user_id,
             user.tenant_id,
             user.role.clone(),
-            self.jwt_refresh_expiration,
-        );
+            refresh_expiration,
+        )
+        .issued_at(now.timestamp());
 
         let access_token = encode_jwt(&access_claims, &self.jwt_secret)?;
         let refresh_token = encode_jwt(&refresh_claims, &self.jwt_secret)?;
@@ -377,16 +438,14 @@ where
             ip_address,
             user_agent,
             device_info: None,
-            access_token_expires_at: chrono::Utc::now()
-                + chrono::Duration::seconds(self.jwt_expiration),
-            refresh_token_expires_at: chrono::Utc::now()
-                + chrono::Duration::seconds(self.jwt_refresh_expiration),
+            access_token_expires_at: now + chrono::Duration::seconds(access_expiration),
+            refresh_token_expires_at: now + chrono::Duration::seconds(refresh_expiration),
             revoked: false,
             revoked_at: None,
             revoked_reason: None,
             auth_method: "jwt".to_string(),
-            created_at: chrono::Utc::now(),
-            last_used_at: chrono::Utc::now(),
+            created_at: now,
+            last_used_at: now,
         };
 
         self.session_repo.create(&session).await?;
@@ -398,7 +457,7 @@ where
             access_token,
             refresh_token,
             token_type: "Bearer".to_string(),
-            expires_in: self.jwt_expiration,
+            expires_in: access_expiration,
             user: UserInfo {
                 id: user.user_id,
                 tenant_id: user.tenant_id,
@@ -418,13 +477,8 @@ where
         ip_address: Option<String>,
         user_agent: Option<String>,
     ) -> Result<AuthResp, AppError> {
-        // Decode and validate refresh token
-        let claims = decode_jwt(&req.refresh_token, &self.jwt_secret)?;
-
-        // Verify it's a refresh token
-        if claims.token_type != "refresh" {
-            return Err(AppError::InvalidToken);
-        }
+        // Decode and validate refresh token (rejects access tokens)
+        let claims = decode_refresh_at(&req.refresh_token, &self.jwt_secret, self.clock.as_ref())?;
 
         // Get user to ensure still active
         let user = self
@@ -434,18 +488,19 @@ where
             .ok_or(AppError::UserNotFound)?;
 
         // Generate new tokens
-        let new_access_claims = Claims::new_access(
-            user.user_id,
-            user.tenant_id,
-            user.role.clone(),
-            self.jwt_expiration,
-        );
+        let access_expiration = self.access_expiration(&user.role);
+        let refresh_expiration = self.refresh_expiration(&user.role);
+        let now = self.clock.now().timestamp();
+        let new_access_claims =
+            Claims::new_access(user.user_id, user.tenant_id, user.role.clone(), access_expiration)
+                .issued_at(now);
         let new_refresh_claims = Claims::new_refresh(
             user.user_id,
             user.tenant_id,
             user.role.clone(),
-            self.jwt_refresh_expiration,
-        );
+            refresh_expiration,
+        )
+        .issued_at(now);
 
         let access_token = encode_jwt(&new_access_claims, &self.jwt_secret)?;
         let refresh_token = encode_jwt(&new_refresh_claims, &self.jwt_secret)?;
@@ -468,8 +523,8 @@ where
             user.tenant_id,
             &access_token,
             &refresh_token,
-            self.jwt_expiration,
-            self.jwt_refresh_expiration,
+            access_expiration,
+            refresh_expiration,
             ip_address,
             user_agent,
         )
@@ -479,7 +534,7 @@ where
             access_token,
             refresh_token,
             token_type: "Bearer".to_string(),
-            expires_in: self.jwt_expiration,
+            expires_in: access_expiration,
             user: self.user_to_user_info(&user),
         })
     }
@@ -612,8 +667,8 @@ where
         // Validate password strength (include tenant name for consistency with registration)
         let full_name = req.full_name.as_deref().unwrap_or("");
         let user_inputs = [req.email.as_str(), full_name, tenant.name.as_str()];
-        validate_password_quick(&req.password, &user_inputs)
-            .map_err(|e| AppError::ValidationError(format!("Password validation failed: {}", e)))?;
+        self.validate_password(&req.password, &user_inputs, Some(&tenant))
+            .await?;
 
         // Hash password with bcrypt
         let password_hash = bcrypt::hash(&req.password, bcrypt::DEFAULT_COST)
@@ -621,7 +676,7 @@ where
 
         // Create user with UUID v7
         let user_id = Uuid::now_v7();
-        let now = Utc::now();
+        let now = self.clock.now();
         let user = User {
             user_id,
             tenant_id: admin_tenant_id,
@@ -727,7 +782,7 @@ where
 
         // 4. Update user status
         user.status = "suspended".to_string();
-        user.updated_at = Utc::now();
+        user.updated_at = self.clock.now();
         let updated_user = self.user_repo.update(&user).await?;
 
         // 5. Revoke all sessions (force logout)
@@ -773,7 +828,7 @@ where
 
         // 3. Update status to active
         user.status = "active".to_string();
-        user.updated_at = Utc::now();
+        user.updated_at = self.clock.now();
         let updated_user = self.user_repo.update(&user).await?;
 
         tracing::info!(
@@ -823,7 +878,7 @@ where
         }
 
         // 4. Soft delete user
-        let now = Utc::now();
+        let now = self.clock.now();
         user.deleted_at = Some(now);
         user.status = "inactive".to_string();
         user.updated_at = now;
@@ -860,7 +915,9 @@ where
     ) -> Result<user_service_core::domains::auth::dto::admin_dto::AdminResetPasswordResp, AppError>
     {
         // 1. Validate password strength
-        validate_password_quick(&new_password, &[]).map_err(AppError::ValidationError)?;
+        let tenant = self.tenant_repo.find_by_id(admin_tenant_id).await?;
+        self.validate_password(&new_password, &[], tenant.as_ref())
+            .await?;
 
         // 2. Fetch target user
         // Use find_by_id_any_status to allow resetting password for suspended users
@@ -876,8 +933,8 @@ where
 
         // 4. Update user password and timestamp
         user.password_hash = Some(password_hash);
-        user.password_changed_at = Some(Utc::now());
-        user.updated_at = Utc::now();
+        user.password_changed_at = Some(self.clock.now());
+        user.updated_at = self.clock.now();
         let updated_user = self.user_repo.update(&user).await?;
 
         // 5. Optionally revoke all sessions (force logout)
@@ -172,7 +172,7 @@ pub async fn authz_version_middleware(
         .ok_or(AuthzVersionError::InvalidToken)?;
 
     // Decode JWT (validation including expiry is done by jsonwebtoken)
-    let claims = shared_jwt::decode_jwt(token, &state.jwt_secret)
+    let claims = shared_jwt::decode_access(token, &state.jwt_secret)
         .map_err(|_| AuthzVersionError::InvalidToken)?;
 
     // Skip version check for legacy tokens (without version claims)
@@ -271,6 +271,7 @@ impl DecisionCache for NoOpDecisionCache {
 /// Cached enforcement wrapper
 ///
 /// Checks cache first, falls back to enforcer on miss, and caches the result.
+/// Decisions are for requests that target no particular warehouse.
 pub async fn enforce_cached(
     cache: Arc<dyn DecisionCache>,
     enforcer: &crate::enforcer::SharedEnforcer,
@@ -291,7 +292,7 @@ pub async fn enforce_cached(
     // 2. Cache miss - enforce and cache result
     let e = enforcer.read().await;
     let allowed = e
-        .enforce((subject, tenant_id.to_string(), resource, action))
+        .enforce((subject, tenant_id.to_string(), resource, action, crate::enforcer::NO_WAREHOUSE))
         .map_err(|e| shared_error::AppError::InternalError(format!("Casbin error: {}", e)))?;
     drop(e);
 
@@ -7,6 +7,14 @@ use tracing::{info, warn};
 /// Casbin enforcer type wrapped in Arc<RwLock<>> for thread-safe sharing
 pub type SharedEnforcer = Arc<RwLock<Enforcer>>;
 
+/// Warehouse field of a policy that applies in every warehouse
+pub const ANY_WAREHOUSE: &str = "*";
+
+/// Warehouse field of a request that targets no particular warehouse
+///
+/// Only unscoped (`ANY_WAREHOUSE`) policies match such requests.
+pub const NO_WAREHOUSE: &str = "";
+
 /// Resolve the Casbin model file path using multiple fallback strategies.
 ///
 /// Search order:
@@ -141,22 +149,36 @@ pub async fn enforce(
     tenant_id: &str,
     resource: &str,
     action: &str,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    enforce_in_warehouse(enforcer, user_id, tenant_id, resource, action, NO_WAREHOUSE).await
+}
+
+/// Check a permission for a request that targets a specific warehouse
+///
+/// Policies scoped to another warehouse do not match; unscoped policies do.
+pub async fn enforce_in_warehouse(
+    enforcer: &SharedEnforcer,
+    user_id: &str,
+    tenant_id: &str,
+    resource: &str,
+    action: &str,
+    warehouse_id: &str,
 ) -> Result<bool, Box<dyn std::error::Error>> {
     let e = enforcer.read().await;
 
-    let allowed = e.enforce((user_id, tenant_id, resource, action))?;
+    let allowed = e.enforce((user_id, tenant_id, resource, action, warehouse_id))?;
 
     if !allowed {
         warn!(
-            "Permission denied: user={}, tenant={}, resource={}, action={}",
-            user_id, tenant_id, resource, action
+            "Permission denied: user={}, tenant={}, resource={}, action={}, warehouse={}",
+            user_id, tenant_id, resource, action, warehouse_id
         );
     }
 
     Ok(allowed)
 }
 
-/// Add a policy rule
+/// Add a policy rule that applies in every warehouse
 ///
 /// # Example
 /// ```no_run
@@ -178,12 +200,13 @@ pub async fn add_policy(
             domain.to_string(),
             resource.to_string(),
             action.to_string(),
+            ANY_WAREHOUSE.to_string(),
         ])
         .await?;
     Ok(added)
 }
 
-/// Remove a policy rule
+/// Remove a policy rule that applies in every warehouse
 pub async fn remove_policy(
     enforcer: &SharedEnforcer,
     subject: &str,
@@ -198,11 +221,105 @@ pub async fn remove_policy(
             domain.to_string(),
             resource.to_string(),
             action.to_string(),
+            ANY_WAREHOUSE.to_string(),
         ])
         .await?;
     Ok(removed)
 }
 
+/// Outcome of a batch policy update
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicyBatchResult {
+    /// Rules the batch added or removed
+    pub applied: Vec<Vec<String>>,
+    /// Rules left as they were: already present when adding, absent when removing
+    pub unchanged: Vec<Vec<String>>,
+}
+
+/// Split a batch into rules to apply and rules already in the wanted state.
+/// Duplicates within the batch are applied once.
+fn partition_batch(
+    e: &Enforcer,
+    rules: Vec<Vec<String>>,
+    apply_if_present: bool,
+) -> PolicyBatchResult {
+    let mut result = PolicyBatchResult::default();
+    for rule in rules {
+        if result.applied.contains(&rule) || result.unchanged.contains(&rule) {
+            continue;
+        }
+        if e.has_policy(rule.clone()) == apply_if_present {
+            result.applied.push(rule);
+        } else {
+            result.unchanged.push(rule);
+        }
+    }
+    result
+}
+
+/// Add several policy rules at once
+///
+/// Rules already present are reported in `unchanged`; the others are written in
+/// one adapter transaction, so either all of them are added or none is. The
+/// policy is then reloaded from storage so the enforcer matches what was
+/// committed, also after a failure.
+///
+/// # Example
+/// ```no_run
+/// use shared_auth::enforcer::{add_policies, SharedEnforcer, ANY_WAREHOUSE};
+///
+/// // Give a new role its permissions in one round-trip
+/// async fn grant_picker(enforcer: &SharedEnforcer) -> Result<(), Box<dyn std::error::Error>> {
+///     let rule = |action: &str| {
+///         vec![
+///             "picker".to_string(),
+///             "tenant-123".to_string(),
+///             "/api/v1/inventory/picking/*".to_string(),
+///             action.to_string(),
+///             ANY_WAREHOUSE.to_string(),
+///         ]
+///     };
+///     let result = add_policies(enforcer, vec![rule("GET"), rule("POST")]).await?;
+///     println!("{} added, {} already present", result.applied.len(), result.unchanged.len());
+///     Ok(())
+/// }
+/// ```
+pub async fn add_policies(
+    enforcer: &SharedEnforcer,
+    rules: Vec<Vec<String>>,
+) -> Result<PolicyBatchResult, Box<dyn std::error::Error>> {
+    let mut e = enforcer.write().await;
+    let result = partition_batch(&e, rules, false);
+    if result.applied.is_empty() {
+        return Ok(result);
+    }
+
+    let outcome = e.add_policies(result.applied.clone()).await;
+    e.load_policy().await?;
+    outcome?;
+    Ok(result)
+}
+
+/// Remove several policy rules at once
+///
+/// Rules that do not exist are reported in `unchanged`; the others are deleted
+/// in one adapter transaction and the policy is reloaded, as for `add_policies`.
+pub async fn remove_policies(
+    enforcer: &SharedEnforcer,
+    rules: Vec<Vec<String>>,
+) -> Result<PolicyBatchResult, Box<dyn std::error::Error>> {
+    let mut e = enforcer.write().await;
+    let result = partition_batch(&e, rules, true);
+    if result.applied.is_empty() {
+        return Ok(result);
+    }
+
+    let outcome = e.remove_policies(result.applied.clone()).await;
+    e.load_policy().await?;
+    outcome?;
+    Ok(result)
+}
+
 /// Assign a role to a user in a specific tenant
 ///
 /// # Example
@@ -272,13 +389,13 @@ pub async fn copy_policies_for_tenant(
     let mut e = enforcer.write().await;
 
     // Get all policies from the source domain
-    // Policy format: [subject, domain, resource, action]
+    // Policy format: [subject, domain, resource, action, warehouse]
     let all_policies = e.get_policy();
 
     let mut copied_count = 0;
 
     for policy in all_policies {
-        // Policy is Vec<String> with format: [subject, domain, resource, action]
+        // Policy is Vec<String> with format: [subject, domain, resource, action, warehouse]
         if policy.len() >= 4 && policy[1] == source_domain {
             // Create new policy with target domain
             let new_policy = vec![
@@ -286,6 +403,10 @@ pub async fn copy_policies_for_tenant(
                 target_domain.to_string(), // domain (new tenant ID)
                 policy[2].clone(),         // resource (e.g., "/api/v1/admin/*")
                 policy[3].clone(),         // action (e.g., "GET", "POST")
+                policy
+                    .get(4)
+                    .cloned()
+                    .unwrap_or_else(|| ANY_WAREHOUSE.to_string()), // warehouse
             ];
 
             // Add the new policy (ignore if already exists)
@@ -314,7 +435,7 @@ pub async fn copy_policies_for_tenant(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use casbin::{Enforcer, MgmtApi, RbacApi};
+    use casbin::{Enforcer, MemoryAdapter, MgmtApi, RbacApi};
     use sqlx::postgres::PgPoolOptions;
     use sqlx_adapter::SqlxAdapter;
 
@@ -356,6 +477,151 @@ mod tests {
         Enforcer::new(model, adapter).await.unwrap()
     }
 
+    /// Enforcer on the production model with policies kept in memory only
+    async fn setup_memory_enforcer() -> SharedEnforcer {
+        let resolved_model_path =
+            resolve_model_path(None).expect("Failed to resolve model path for tests");
+        let model = DefaultModel::from_file(
+            resolved_model_path
+                .to_str()
+                .expect("Invalid UTF-8 in model path"),
+        )
+        .await
+        .expect("Failed to load Casbin model");
+
+        let enforcer = Enforcer::new(model, MemoryAdapter::default())
+            .await
+            .unwrap();
+        Arc::new(RwLock::new(enforcer))
+    }
+
+    async fn setup_shared_enforcer() -> SharedEnforcer {
+        Arc::new(RwLock::new(setup_test_enforcer().await))
+    }
+
+    fn rule(domain: &str, resource: &str, action: &str) -> Vec<String> {
+        vec![
+            "picker".to_string(),
+            domain.to_string(),
+            resource.to_string(),
+            action.to_string(),
+            ANY_WAREHOUSE.to_string(),
+        ]
+    }
+
+    #[tokio::test]
+    #[ignore] // TODO: Fix PostgreSQL permissions for test database
+    async fn test_batch_reports_rules_already_in_place() {
+        let enforcer = setup_shared_enforcer().await;
+        let domain = format!("batch-{}", uuid::Uuid::new_v4());
+        let existing = rule(&domain, "/api/v1/inventory/picking/*", "GET");
+        let new = rule(&domain, "/api/v1/inventory/picking/*", "POST");
+        let missing = rule(&domain, "/api/v1/inventory/picking/*", "DELETE");
+
+        add_policy(&enforcer, "picker", &domain, "/api/v1/inventory/picking/*", "GET")
+            .await
+            .unwrap();
+
+        let added = add_policies(&enforcer, vec![existing.clone(), new.clone(), new.clone()])
+            .await
+            .unwrap();
+        assert_eq!(added.applied, vec![new.clone()]);
+        assert_eq!(added.unchanged, vec![existing.clone()]);
+        assert!(enforcer
+            .read()
+            .await
+            .enforce(("picker", domain.as_str(), "/api/v1/inventory/picking/42", "POST", ""))
+            .unwrap());
+
+        let removed =
+            remove_policies(&enforcer, vec![existing.clone(), new.clone(), missing.clone()])
+                .await
+                .unwrap();
+        assert_eq!(removed.applied, vec![existing, new]);
+        assert_eq!(removed.unchanged, vec![missing]);
+    }
+
+    #[tokio::test]
+    #[ignore] // TODO: Fix PostgreSQL permissions for test database
+    async fn test_batch_is_atomic_on_error() {
+        let enforcer = setup_shared_enforcer().await;
+        let domain = format!("batch-{}", uuid::Uuid::new_v4());
+        let valid = rule(&domain, "/api/v1/inventory/picking/*", "GET");
+        // casbin_rule columns hold at most 128 characters
+        let too_long = rule(&domain, &format!("/api/v1/{}", "x".repeat(200)), "GET");
+
+        let result = add_policies(&enforcer, vec![valid.clone(), too_long]).await;
+        assert!(result.is_err());
+
+        // Neither the enforcer nor storage kept the valid rule
+        assert!(!enforcer.read().await.has_policy(valid.clone()));
+        enforcer.write().await.load_policy().await.unwrap();
+        assert!(!enforcer.read().await.has_policy(valid));
+    }
+
+    #[tokio::test]
+    async fn test_warehouse_scoped_permissions() {
+        let enforcer = setup_memory_enforcer().await;
+        let resource = "/api/v1/inventory/warehouses/*";
+        let (warehouse_a, warehouse_b) = ("wh-a", "wh-b");
+        {
+            let mut e = enforcer.write().await;
+            e.add_grouping_policy(vec!["alice".into(), "staff_a".into(), "tenant1".into()])
+                .await
+                .unwrap();
+            e.add_grouping_policy(vec!["bob".into(), "admin".into(), "tenant1".into()])
+                .await
+                .unwrap();
+        }
+        add_policies(
+            &enforcer,
+            vec![
+                vec![
+                    "staff_a".into(),
+                    "tenant1".into(),
+                    resource.into(),
+                    "PUT".into(),
+                    warehouse_a.into(),
+                ],
+                vec![
+                    "admin".into(),
+                    "tenant1".into(),
+                    resource.into(),
+                    "PUT".into(),
+                    ANY_WAREHOUSE.into(),
+                ],
+            ],
+        )
+        .await
+        .unwrap();
+
+        let check = |user: &'static str, warehouse: &'static str| {
+            let enforcer = enforcer.clone();
+            async move {
+                enforce_in_warehouse(
+                    &enforcer,
+                    user,
+                    "tenant1",
+                    "/api/v1/inventory/warehouses/42",
+                    "PUT",
+                    warehouse,
+                )
+                .await
+                .unwrap()
+            }
+        };
+
+        // Scoped staff: only their own warehouse, and not requests without one
+        assert!(check("alice", warehouse_a).await);
+        assert!(!check("alice", warehouse_b).await);
+        assert!(!check("alice", NO_WAREHOUSE).await);
+
+        // Unscoped admin: every warehouse
+        assert!(check("bob", warehouse_a).await);
+        assert!(check("bob", warehouse_b).await);
+        assert!(check("bob", NO_WAREHOUSE).await);
+    }
+
     #[tokio::test]
     #[ignore] // TODO: Fix PostgreSQL permissions for test database
     async fn test_role_assignments() {
@@ -390,13 +656,18 @@ mod tests {
             "tenant1".to_string(),
             "data1".to_string(),
             "read".to_string(),
+            ANY_WAREHOUSE.to_string(),
         ])
         .await
         .unwrap();
 
-        assert!(e.enforce(("admin", "tenant1", "data1", "read")).unwrap());
-        assert!(!e.enforce(("admin", "tenant1", "data1", "write")).unwrap());
-        assert!(!e.enforce(("user", "tenant1", "data1", "read")).unwrap());
+        assert!(e
+            .enforce(("admin", "tenant1", "data1", "read", ""))
+            .unwrap());
+        assert!(!e
+            .enforce(("admin", "tenant1", "data1", "write", ""))
+            .unwrap());
+        assert!(!e.enforce(("user", "tenant1", "data1", "read", "")).unwrap());
     }
 
     #[tokio::test]
@@ -408,12 +679,17 @@ mod tests {
             "tenant1".to_string(),
             "data1".to_string(),
             "read".to_string(),
+            ANY_WAREHOUSE.to_string(),
         ])
         .await
         .unwrap();
 
-        assert!(e.enforce(("user1", "tenant1", "data1", "read")).unwrap());
-        assert!(!e.enforce(("user1", "tenant2", "data1", "read")).unwrap());
+        assert!(e
+            .enforce(("user1", "tenant1", "data1", "read", ""))
+            .unwrap());
+        assert!(!e
+            .enforce(("user1", "tenant2", "data1", "read", ""))
+            .unwrap());
     }
 
     #[tokio::test]
@@ -432,15 +708,16 @@ mod tests {
             "tenant1".to_string(),
             "/api/v1/admin/users".to_string(),
             "POST".to_string(),
+            ANY_WAREHOUSE.to_string(),
         ])
         .await
         .unwrap();
 
         assert!(e
-            .enforce(("alice", "tenant1", "/api/v1/admin/users", "POST"))
+            .enforce(("alice", "tenant1", "/api/v1/admin/users", "POST", ""))
             .unwrap());
         assert!(!e
-            .enforce(("bob", "tenant1", "/api/v1/admin/users", "POST"))
+            .enforce(("bob", "tenant1", "/api/v1/admin/users", "POST", ""))
             .unwrap());
     }
 }
@@ -10,7 +10,10 @@ use uuid::Uuid;
 use casbin::CoreApi;
 use shared_jwt::Claims;
 
-use crate::{enforcer::SharedEnforcer, middleware::AuthzState};
+use crate::{
+    enforcer::SharedEnforcer,
+    middleware::{authenticate, AuthzState},
+};
 
 pub trait JwtSecretProvider {
     fn get_jwt_secret(&self) -> &str;
@@ -77,25 +80,19 @@ impl AuthUser {
 /// Validate JWT token and return AuthUser
 ///
 /// Only accepts "access" tokens - refresh tokens are rejected to prevent
-/// using long-lived refresh tokens for API authentication.
-fn validate_token(token: &str, authz_state: &AuthzState) -> Result<AuthUser, StatusCode> {
-    match shared_jwt::decode_jwt(token, &authz_state.jwt_secret) {
+/// using long-lived refresh tokens for API authentication. Revoked tokens
+/// are rejected as well.
+async fn validate_token(token: &str, authz_state: &AuthzState) -> Result<AuthUser, StatusCode> {
+    // Security: Ensure only access tokens are accepted for API authentication
+    // Refresh tokens should only be used at the /auth/refresh endpoint
+    match authenticate(token, authz_state).await {
         Ok(claims) => {
-            // Security: Ensure only access tokens are accepted for API authentication
-            // Refresh tokens should only be used at the /auth/refresh endpoint
-            if claims.token_type != "access" {
-                warn!(
-                    "JWT validation failed for user {}: expected 'access' token, got '{}'",
-                    claims.sub, claims.token_type
-                );
-                return Err(StatusCode::UNAUTHORIZED);
-            }
             debug!("Validated JWT for user {}", claims.sub);
             Ok(AuthUser::from_claims(claims))
         },
         Err(e) => {
-            warn!("JWT validation failed: {}", e);
-            Err(StatusCode::UNAUTHORIZED)
+            warn!("JWT validation failed: {:?}", e);
+            Err(e.status_code())
         },
     }
 }
@@ -128,7 +125,7 @@ impl FromRequestParts<()> for AuthUser {
             .ok_or(StatusCode::UNAUTHORIZED)?;
 
         // Validate JWT token
-        validate_token(token, &authz_state)
+        validate_token(token, &authz_state).await
     }
 }
 
@@ -300,11 +297,20 @@ impl FromRequestParts<()> for RequirePermission {
             .map(|uri| uri.path().to_string())
             .unwrap_or_else(|| parts.uri.path().to_string());
         let action = parts.method.as_str().to_string();
+        let warehouse = crate::middleware::warehouse_from_path(&resource)
+            .unwrap_or(crate::enforcer::NO_WAREHOUSE)
+            .to_string();
 
-        // Check permission with Casbin
+        // Check permission with Casbin, scoped to the warehouse in the path
         let e = enforcer.read().await;
         let allowed = e
-            .enforce((user.user_id.to_string(), user.tenant_id.to_string(), &resource, &action))
+            .enforce((
+                user.user_id.to_string(),
+                user.tenant_id.to_string(),
+                &resource,
+                &action,
+                &warehouse,
+            ))
             .map_err(|e| {
                 warn!("Casbin error: {}", e);
                 StatusCode::INTERNAL_SERVER_ERROR
@@ -85,10 +85,9 @@ where
                 return Ok(error.into_response());
             }
 
-            let claims = match shared_jwt::decode_jwt(token.unwrap(), &state.jwt_secret) {
+            let claims = match crate::middleware::authenticate(token.unwrap(), &state).await {
                 Ok(claims) => claims,
-                Err(_) => {
-                    let error = AuthError::InvalidToken;
+                Err(error) => {
                     return Ok(error.into_response());
                 },
             };
@@ -100,6 +99,8 @@ where
 
             let resource = req.uri().path();
             let action = req.method().as_str();
+            let warehouse = crate::middleware::warehouse_from_path(resource)
+                .unwrap_or(crate::enforcer::NO_WAREHOUSE);
 
             let allowed = match crate::middleware::check_permission(
                 &state.enforcer,
@@ -107,6 +108,7 @@ where
                 &claims.tenant_id.to_string(),
                 resource,
                 action,
+                warehouse,
             )
             .await
             {
@@ -118,8 +120,8 @@ where
 
             if !allowed {
                 warn!(
-                    "Permission denied: user={}, tenant={}, resource={}, action={}",
-                    claims.sub, claims.tenant_id, resource, action
+                    "Permission denied: user={}, tenant={}, resource={}, action={}, warehouse={}",
+                    claims.sub, claims.tenant_id, resource, action, warehouse
                 );
                 let error = AuthError::PermissionDenied;
                 return Ok(error.into_response());
@@ -7,8 +7,9 @@ pub mod middleware;
 
 // Re-export commonly used types
 pub use enforcer::{
-    add_policy, add_role_for_user, copy_policies_for_tenant, create_enforcer, enforce,
-    get_roles_for_user, remove_policy, remove_role_for_user, SharedEnforcer,
+    add_policies, add_policy, add_role_for_user, copy_policies_for_tenant, create_enforcer,
+    enforce, enforce_in_warehouse, get_roles_for_user, remove_policies, remove_policy,
+    remove_role_for_user, PolicyBatchResult, SharedEnforcer, ANY_WAREHOUSE, NO_WAREHOUSE,
 };
 
 // Re-export decision cache
@@ -18,7 +19,7 @@ pub use decision_cache::{
 };
 
 // Re-export middleware
-pub use middleware::{casbin_middleware, AuthError, AuthzState};
+pub use middleware::{casbin_middleware, warehouse_from_path, AuthError, AuthzState};
 
 // Re-export authz version middleware
 pub use authz_version::{
@@ -1,24 +1,47 @@
-use crate::enforcer::SharedEnforcer;
+use crate::enforcer::{SharedEnforcer, NO_WAREHOUSE};
+use crate::extractors::AuthUser;
 use axum::extract::{Extension, Request};
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
 use casbin::CoreApi;
 use http::{header, StatusCode};
+use shared_error::AppError;
+use shared_jwt::{Claims, TokenDenylist};
+use std::sync::Arc;
 use tracing::{debug, warn};
 
 #[derive(Clone)]
 pub struct AuthzState {
     pub enforcer: SharedEnforcer,
     pub jwt_secret: String,
+    /// Revoked access tokens, checked on every authenticated request
+    pub denylist: Arc<dyn TokenDenylist>,
+}
+
+/// Decode an access token and reject it if it has been revoked
+pub(crate) async fn authenticate(token: &str, state: &AuthzState) -> Result<Claims, AuthError> {
+    shared_jwt::decode_access_checked(token, &state.jwt_secret, state.denylist.as_ref())
+        .await
+        .map_err(|e| match e {
+            AppError::ServiceUnavailable(msg) => {
+                warn!("Token denylist unavailable: {}", msg);
+                AuthError::DenylistUnavailable
+            },
+            _ => AuthError::InvalidToken,
+        })
 }
 
 /// Casbin authorization middleware
 ///
 /// This middleware:
 /// 1. Extracts JWT from Authorization header
-/// 2. Validates JWT and extracts claims (user_id, tenant_id, role)
-/// 3. Checks permissions using Casbin enforcer
+/// 2. Validates JWT, rejects revoked tokens and extracts claims (user_id,
+///    tenant_id, role)
+/// 3. Checks permissions using Casbin enforcer, scoped to the warehouse in the
+///    path (see [`warehouse_from_path`])
 /// 4. Returns 403 Forbidden if permission denied
+/// 5. Otherwise adds the caller's [`AuthUser`] to the request extensions for
+///    inner middleware
 ///
 /// # Usage
 /// ```no_run
@@ -62,17 +85,17 @@ pub async fn casbin_middleware(
         .ok_or(AuthError::InvalidToken)?;
 
     // Decode and validate JWT
-    let claims =
-        shared_jwt::decode_jwt(token, &state.jwt_secret).map_err(|_| AuthError::InvalidToken)?;
+    let claims = authenticate(token, &state).await?;
 
     debug!(
         "JWT validated: user_id={}, tenant_id={}, role={}",
         claims.sub, claims.tenant_id, claims.role
     );
 
-    // Get resource and action from request
+    // Get resource, action and target warehouse from request
     let resource = request.uri().path();
     let action = request.method().as_str();
+    let warehouse = warehouse_from_path(resource).unwrap_or(NO_WAREHOUSE);
 
     // Check permission with Casbin
     let allowed = check_permission(
@@ -81,13 +104,14 @@ pub async fn casbin_middleware(
         &claims.tenant_id.to_string(),
         resource,
         action,
+        warehouse,
     )
     .await?;
 
     if !allowed {
         warn!(
-            "Permission denied: user={}, tenant={}, resource={}, action={}",
-            claims.sub, claims.tenant_id, resource, action
+            "Permission denied: user={}, tenant={}, resource={}, action={}, warehouse={}",
+            claims.sub, claims.tenant_id, resource, action, warehouse
         );
         return Err(AuthError::PermissionDenied);
     }
@@ -98,27 +122,46 @@ pub async fn casbin_middleware(
     );
 
     // Permission granted, continue to next middleware/handler
+    request
+        .extensions_mut()
+        .insert(AuthUser::from_claims(claims));
     Ok(next.run(request).await)
 }
 
 /// Check permission using Casbin enforcer
+///
+/// Pass `NO_WAREHOUSE` when the request does not target a warehouse.
 pub async fn check_permission(
     enforcer: &SharedEnforcer,
     user_id: &str,
     tenant_id: &str,
     resource: &str,
     action: &str,
+    warehouse_id: &str,
 ) -> Result<bool, AuthError> {
     let e = enforcer.read().await;
 
     // Try to enforce with user_id first
     let allowed = e
-        .enforce((user_id, tenant_id, resource, action))
+        .enforce((user_id, tenant_id, resource, action, warehouse_id))
         .map_err(|e| AuthError::CasbinError(e.to_string()))?;
 
     Ok(allowed)
 }
 
+/// Warehouse a request path targets, if any
+///
+/// Returns the segment after `warehouses` when it is a UUID, e.g. the ID in
+/// `/api/v1/inventory/warehouses/{id}/zones`. Collection paths such as
+/// `/warehouses` or `/warehouses/tree` target no warehouse.
+pub fn warehouse_from_path(path: &str) -> Option<&str> {
+    let mut segments = path.split('/');
+    segments.find(|segment| *segment == "warehouses")?;
+    segments
+        .next()
+        .filter(|segment| uuid::Uuid::parse_str(segment).is_ok())
+}
+
 /// Authentication/Authorization errors
 #[derive(Debug)]
 pub enum AuthError {
@@ -126,6 +169,19 @@ pub enum AuthError {
     InvalidToken,
     PermissionDenied,
     CasbinError(String),
+    DenylistUnavailable,
+}
+
+impl AuthError {
+    /// HTTP status this error is reported with
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AuthError::MissingToken | AuthError::InvalidToken => StatusCode::UNAUTHORIZED,
+            AuthError::PermissionDenied => StatusCode::FORBIDDEN,
+            AuthError::CasbinError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AuthError::DenylistUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
 }
 
 impl IntoResponse for AuthError {
@@ -138,6 +194,9 @@ impl IntoResponse for AuthError {
                 warn!("Casbin error: {}", e);
                 (StatusCode::INTERNAL_SERVER_ERROR, "Authorization check failed")
             },
+            AuthError::DenylistUnavailable => {
+                (StatusCode::SERVICE_UNAVAILABLE, "Token revocation check unavailable")
+            },
         };
 
         let body = serde_json::json!({
@@ -151,6 +210,73 @@ impl IntoResponse for AuthError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enforcer::{add_policy, add_role_for_user, resolve_model_path};
+    use axum::{body::Body, routing::get, Router};
+    use casbin::{DefaultModel, Enforcer, MemoryAdapter};
+    use shared_jwt::{encode_jwt, InMemoryTokenDenylist};
+    use tokio::sync::RwLock;
+    use tower::Service;
+    use uuid::Uuid;
+
+    const SECRET: &str = "test_secret";
+
+    /// Router guarded by `casbin_middleware` where `user_id` may GET `/api/v1/things`
+    async fn guarded_router(
+        user_id: Uuid,
+        tenant_id: Uuid,
+        denylist: Arc<InMemoryTokenDenylist>,
+    ) -> Router {
+        let model_path = resolve_model_path(None).unwrap();
+        let model = DefaultModel::from_file(model_path.to_str().unwrap())
+            .await
+            .unwrap();
+        let enforcer: SharedEnforcer = Arc::new(RwLock::new(
+            Enforcer::new(model, MemoryAdapter::default())
+                .await
+                .unwrap(),
+        ));
+        add_policy(&enforcer, "user", &tenant_id.to_string(), "/api/v1/things", "GET")
+            .await
+            .unwrap();
+        add_role_for_user(&enforcer, &user_id.to_string(), "user", &tenant_id.to_string())
+            .await
+            .unwrap();
+
+        Router::new()
+            .route("/api/v1/things", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn(casbin_middleware))
+            .layer(Extension(AuthzState {
+                enforcer,
+                jwt_secret: SECRET.to_string(),
+                denylist,
+            }))
+    }
+
+    fn get_things(token: &str) -> Request {
+        http::Request::builder()
+            .uri("/api/v1/things")
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_revoked_token_is_rejected() {
+        let (user_id, tenant_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let denylist = Arc::new(InMemoryTokenDenylist::new());
+        let mut app = guarded_router(user_id, tenant_id, denylist.clone()).await;
+
+        let claims = Claims::new_access(user_id, tenant_id, "user".to_string(), 900);
+        let token = encode_jwt(&claims, SECRET).unwrap();
+
+        let response = app.call(get_things(&token)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        denylist.revoke(claims.jti, claims.exp).await.unwrap();
+
+        let response = app.call(get_things(&token)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
 
     #[tokio::test]
     async fn test_auth_error_responses() {
@@ -162,4 +288,18 @@ mod tests {
         let response = err.into_response();
         assert_eq!(response.status(), StatusCode::FORBIDDEN);
     }
+
+    #[test]
+    fn test_warehouse_from_path() {
+        let id = "0190a1b2-c3d4-7e5f-8a9b-0c1d2e3f4a5b";
+
+        assert_eq!(warehouse_from_path(&format!("/api/v1/inventory/warehouses/{}", id)), Some(id));
+        assert_eq!(
+            warehouse_from_path(&format!("/api/v1/inventory/warehouses/{}/zones/7", id)),
+            Some(id)
+        );
+        assert_eq!(warehouse_from_path("/api/v1/inventory/warehouses"), None);
+        assert_eq!(warehouse_from_path("/api/v1/inventory/warehouses/tree"), None);
+        assert_eq!(warehouse_from_path("/api/v1/inventory/products/123"), None);
+    }
 }
@@ -1,4 +1,5 @@
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
 
 /// Application configuration loaded from environment variables
 #[derive(Debug, Clone, Deserialize)]
@@ -17,6 +18,15 @@ pub struct Config {
     #[serde(default = "default_jwt_refresh_expiration")]
     pub jwt_refresh_expiration: i64,
 
+    /// Access token expiration in seconds per role, overriding `jwt_expiration`
+    /// Set as `role=seconds` pairs, e.g. JWT_EXPIRATION_OVERRIDES=admin=300,service=3600
+    #[serde(default, deserialize_with = "deserialize_role_expirations")]
+    pub jwt_expiration_overrides: HashMap<String, i64>,
+
+    /// Refresh token expiration in seconds per role, overriding `jwt_refresh_expiration`
+    #[serde(default, deserialize_with = "deserialize_role_expirations")]
+    pub jwt_refresh_expiration_overrides: HashMap<String, i64>,
+
     /// Server host address
     #[serde(default = "default_host")]
     pub host: String,
@@ -30,9 +40,26 @@ pub struct Config {
     #[serde(default = "default_port")]
     pub port: u16,
 
+    /// gRPC server port for services that expose one (default: 50051)
+    /// Set to 0 to disable the gRPC server
+    #[serde(default = "default_grpc_port")]
+    pub grpc_port: u16,
+
     /// CORS allowed origins (comma-separated list, optional)
     pub cors_origins: Option<String>,
 
+    /// CORS allowed methods (comma-separated list)
+    #[serde(default = "default_cors_allowed_methods")]
+    pub cors_allowed_methods: String,
+
+    /// CORS allowed request headers (comma-separated list)
+    #[serde(default = "default_cors_allowed_headers")]
+    pub cors_allowed_headers: String,
+
+    /// How long browsers may cache preflight responses (seconds, 0 = don't send max-age)
+    #[serde(default = "default_cors_max_age_seconds")]
+    pub cors_max_age_seconds: u64,
+
     /// NATS server URL (optional - for event-driven messaging)
     pub nats_url: Option<String>,
 
@@ -179,6 +206,16 @@ pub struct Config {
     /// Trusted IPs that bypass rate limiting (comma-separated, supports CIDR notation, optional)
     pub rate_limit_trusted_ips: Option<String>,
 
+    /// Monitor mode: count and log requests that would be rate limited without blocking them
+    /// (default: false)
+    #[serde(default)]
+    pub rate_limit_monitor_mode: bool,
+
+    /// Reject requests with 503 when the rate limit backend (Redis) fails instead of
+    /// letting them through unlimited (default: false, i.e. fail open)
+    #[serde(default)]
+    pub rate_limit_fail_closed: bool,
+
     // ===== Decision Cache Configuration =====
     /// Enable authorization decision caching (default: true)
     #[serde(default = "default_decision_cache_enabled")]
@@ -224,6 +261,179 @@ pub struct Config {
     /// Cookie path (default: "/")
     #[serde(default = "default_cookie_path")]
     pub cookie_path: String,
+
+    // ===== Password Policy =====
+    /// Minimum password length (default: 8)
+    #[serde(default = "default_password_min_length")]
+    pub password_min_length: usize,
+
+    /// Require at least one uppercase letter (default: false)
+    #[serde(default)]
+    pub password_require_uppercase: bool,
+
+    /// Require at least one lowercase letter (default: false)
+    #[serde(default)]
+    pub password_require_lowercase: bool,
+
+    /// Require at least one digit (default: false)
+    #[serde(default)]
+    pub password_require_digit: bool,
+
+    /// Require at least one symbol (default: false)
+    #[serde(default)]
+    pub password_require_symbol: bool,
+
+    /// Minimum zxcvbn strength score, 0-4 (default: 3)
+    #[serde(default = "default_password_min_score")]
+    pub password_min_score: u8,
+
+    /// Reject passwords found in known breaches via the HIBP range API (default: false)
+    /// Only the first 5 characters of the SHA-1 hash leave the service
+    #[serde(default)]
+    pub password_check_breached: bool,
+
+    // ===== Feature Flags =====
+    /// Enable the delivery pick/pack/ship workflow (default: false)
+    /// When disabled, delivery endpoints respond with 503 Service Unavailable
+    #[serde(default)]
+    pub delivery_enabled: bool,
+
+    // ===== Background Jobs =====
+    /// Interval in seconds between category product count recomputations (default: 3600)
+    /// Set to 0 to disable the periodic job
+    #[serde(default = "default_category_recount_interval_seconds")]
+    pub category_recount_interval_seconds: u64,
+
+    /// Interval in seconds between scheduled replenishment checks (default: 900)
+    /// Reorder events are only emitted when NATS is configured; set to 0 to disable
+    #[serde(default = "default_replenishment_check_interval_seconds")]
+    pub replenishment_check_interval_seconds: u64,
+
+    /// Minimum seconds between reorder events for the same rule (default: 86400)
+    #[serde(default = "default_replenishment_cooldown_seconds")]
+    pub replenishment_cooldown_seconds: u64,
+
+    /// Interval in seconds between expired lot scrap proposal runs (default: 86400)
+    /// Set to 0 to disable the periodic job
+    #[serde(default = "default_expiry_scrap_interval_seconds")]
+    pub expiry_scrap_interval_seconds: u64,
+
+    /// Days past expiry before a lot gets a draft scrap proposal (default: 7)
+    #[serde(default = "default_expiry_scrap_grace_days")]
+    pub expiry_scrap_grace_days: u32,
+
+    /// Days a stock move stays in `stock_moves` before it is archived (default: 0)
+    /// Set to 0 to keep every move in the hot table
+    #[serde(default)]
+    pub stock_move_retention_days: u32,
+
+    /// Interval in seconds between stock move archival runs (default: 86400)
+    /// Set to 0 to disable the periodic job
+    #[serde(default = "default_stock_move_archive_interval_seconds")]
+    pub stock_move_archive_interval_seconds: u64,
+
+    /// Maximum number of outbox events claimed per batch, at least 1 (default: 50)
+    #[serde(default = "default_outbox_batch_size")]
+    pub outbox_batch_size: i32,
+
+    /// Milliseconds between outbox polls once the outbox has been drained (default: 5000)
+    #[serde(default = "default_outbox_poll_interval_ms")]
+    pub outbox_poll_interval_ms: u64,
+
+    /// Maximum number of outbox publishes awaiting NATS at the same time (default: 10)
+    #[serde(default = "default_outbox_max_in_flight")]
+    pub outbox_max_in_flight: usize,
+
+    /// Failed publish attempts before an outbox event is dead-lettered, at least 1 (default: 3)
+    #[serde(default = "default_outbox_max_attempts")]
+    pub outbox_max_attempts: i32,
+
+    // ===== Pagination =====
+    /// Page size used by list endpoints when the client sends none or zero (default: 20)
+    #[serde(default = "default_page_size")]
+    pub default_page_size: u32,
+
+    /// Largest page size a list endpoint returns; larger requests are clamped (default: 100)
+    #[serde(default = "default_max_page_size")]
+    pub max_page_size: u32,
+
+    // ===== Tenant Onboarding =====
+    /// Code of the default warehouse seeded for a tenant (default: "MAIN")
+    /// Leave empty to skip the warehouse
+    #[serde(default = "default_tenant_seed_warehouse_code")]
+    pub tenant_seed_warehouse_code: String,
+
+    /// Name of the default warehouse seeded for a tenant (default: "Main Warehouse")
+    #[serde(default = "default_tenant_seed_warehouse_name")]
+    pub tenant_seed_warehouse_name: String,
+
+    /// Name of the root category seeded for a tenant (default: "Uncategorized")
+    /// Leave empty to skip the category
+    #[serde(default = "default_tenant_seed_category_name")]
+    pub tenant_seed_category_name: String,
+
+    /// Type of the tenant-wide removal strategy seeded for a tenant (default: "fifo")
+    /// Leave empty to skip the removal strategy
+    #[serde(default = "default_tenant_seed_removal_strategy")]
+    pub tenant_seed_removal_strategy: String,
+
+    /// Seed a tenant's defaults on its first authenticated request (default: false)
+    #[serde(default)]
+    pub tenant_seed_on_first_access: bool,
+
+    // ===== Report Limits =====
+    /// Maximum number of report queries running at once (default: 4)
+    #[serde(default = "default_report_max_concurrency")]
+    pub report_max_concurrency: usize,
+
+    /// How long a report request waits for a free slot before it is rejected
+    /// with 503 (default: 2000ms, 0 rejects immediately)
+    #[serde(default = "default_report_queue_timeout_ms")]
+    pub report_queue_timeout_ms: u64,
+
+    /// Span of a report's date range when `from` is omitted (default: 90 days)
+    #[serde(default = "default_report_default_range_days")]
+    pub report_default_range_days: u32,
+
+    /// Longest date range a report accepts (default: 730 days)
+    #[serde(default = "default_report_max_range_days")]
+    pub report_max_range_days: u32,
+
+    // ===== Upload Limits =====
+    /// Maximum number of file uploads handled at once per process (default: 4)
+    #[serde(default = "default_max_concurrent_uploads")]
+    pub max_concurrent_uploads: usize,
+
+    /// How long an upload waits for a free slot before it is rejected with 503
+    /// (default: 5000ms, 0 rejects immediately)
+    #[serde(default = "default_upload_queue_timeout_ms")]
+    pub upload_queue_timeout_ms: u64,
+
+    // ===== Cache TTLs =====
+    /// Seconds cached products live in Redis (unset: each caller's own TTL)
+    pub product_cache_ttl_seconds: Option<u64>,
+
+    /// Seconds cached inventory levels live in Redis (unset: each caller's own TTL)
+    pub inventory_cache_ttl_seconds: Option<u64>,
+
+    /// Seconds cached category entries live in Redis (unset: each caller's own TTL)
+    pub category_cache_ttl_seconds: Option<u64>,
+
+    // ===== Tenant Export =====
+    /// Minimum time between two exports of the same tenant (default: 3600s)
+    #[serde(default = "default_tenant_export_cooldown_seconds")]
+    pub tenant_export_cooldown_seconds: u64,
+
+    /// How long an export's presigned download URL stays valid (default: 3600s)
+    #[serde(default = "default_tenant_export_url_ttl_seconds")]
+    pub tenant_export_url_ttl_seconds: u64,
+
+    // ===== Idempotency =====
+    /// Age after which an idempotency key still marked in flight is treated as
+    /// left behind by a crashed request and may be claimed again (default: 300s,
+    /// 0 disables reclaiming and the cleanup watchdog)
+    #[serde(default = "default_idempotency_in_flight_stale_seconds")]
+    pub idempotency_in_flight_stale_seconds: u64,
 }
 
 fn default_jwt_expiration() -> i64 {
@@ -234,6 +444,48 @@ fn default_jwt_refresh_expiration() -> i64 {
     604_800 // 7 days
 }
 
+/// Parse `role=seconds` pairs separated by commas
+pub fn parse_role_expirations(value: &str) -> Result<HashMap<String, i64>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (role, seconds) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected role=seconds, got '{}'", pair))?;
+            let seconds: i64 = seconds
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid expiration for role '{}'", role.trim()))?;
+            if seconds <= 0 {
+                return Err(format!("expiration for role '{}' must be positive", role.trim()));
+            }
+            Ok((role.trim().to_string(), seconds))
+        })
+        .collect()
+}
+
+/// Accept role expirations either as a map or as a `role=seconds,...` string
+fn deserialize_role_expirations<'de, D>(deserializer: D) -> Result<HashMap<String, i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RoleExpirations {
+        Map(HashMap<String, i64>),
+        Pairs(String),
+    }
+
+    match RoleExpirations::deserialize(deserializer)? {
+        RoleExpirations::Map(map) => Ok(map),
+        RoleExpirations::Pairs(pairs) => {
+            parse_role_expirations(&pairs).map_err(serde::de::Error::custom)
+        },
+    }
+}
+
 fn default_host() -> String {
     "0.0.0.0".to_string()
 }
@@ -242,6 +494,22 @@ fn default_port() -> u16 {
     3000
 }
 
+fn default_grpc_port() -> u16 {
+    50051
+}
+
+fn default_cors_allowed_methods() -> String {
+    "GET,POST,PUT,PATCH,DELETE".to_string()
+}
+
+fn default_cors_allowed_headers() -> String {
+    "content-type,authorization,x-idempotency-key,x-tenant-id".to_string()
+}
+
+fn default_cors_max_age_seconds() -> u64 {
+    3600 // 1 hour
+}
+
 fn default_casbin_model_path() -> String {
     "shared/auth/model.conf".to_string()
 }
@@ -399,6 +667,115 @@ fn default_cookie_path() -> String {
     "/".to_string()
 }
 
+// Password policy defaults
+fn default_password_min_length() -> usize {
+    8
+}
+
+fn default_password_min_score() -> u8 {
+    3
+}
+
+fn default_category_recount_interval_seconds() -> u64 {
+    3600 // 1 hour
+}
+
+fn default_replenishment_check_interval_seconds() -> u64 {
+    900 // 15 minutes
+}
+
+fn default_replenishment_cooldown_seconds() -> u64 {
+    86400 // 24 hours
+}
+
+fn default_expiry_scrap_interval_seconds() -> u64 {
+    86400 // 24 hours
+}
+
+fn default_expiry_scrap_grace_days() -> u32 {
+    7
+}
+
+fn default_stock_move_archive_interval_seconds() -> u64 {
+    86400 // 24 hours
+}
+
+fn default_outbox_batch_size() -> i32 {
+    50
+}
+
+fn default_outbox_poll_interval_ms() -> u64 {
+    5000
+}
+
+fn default_outbox_max_in_flight() -> usize {
+    10
+}
+
+fn default_outbox_max_attempts() -> i32 {
+    3
+}
+
+fn default_page_size() -> u32 {
+    20
+}
+
+fn default_max_page_size() -> u32 {
+    100
+}
+
+fn default_tenant_seed_warehouse_code() -> String {
+    "MAIN".to_string()
+}
+
+fn default_tenant_seed_warehouse_name() -> String {
+    "Main Warehouse".to_string()
+}
+
+fn default_tenant_seed_category_name() -> String {
+    "Uncategorized".to_string()
+}
+
+fn default_tenant_seed_removal_strategy() -> String {
+    "fifo".to_string()
+}
+
+fn default_report_max_concurrency() -> usize {
+    4
+}
+
+fn default_report_queue_timeout_ms() -> u64 {
+    2000
+}
+
+fn default_report_default_range_days() -> u32 {
+    90
+}
+
+fn default_report_max_range_days() -> u32 {
+    730
+}
+
+fn default_max_concurrent_uploads() -> usize {
+    4
+}
+
+fn default_upload_queue_timeout_ms() -> u64 {
+    5000
+}
+
+fn default_tenant_export_cooldown_seconds() -> u64 {
+    3600
+}
+
+fn default_tenant_export_url_ttl_seconds() -> u64 {
+    3600
+}
+
+fn default_idempotency_in_flight_stale_seconds() -> u64 {
+    300
+}
+
 impl Config {
     /// Load configuration from environment variables
     pub fn from_env() -> Result<Self, config::ConfigError> {
@@ -412,6 +789,10 @@ impl Config {
             .set_default("jwt_refresh_expiration", 604800)?
             .set_default("host", "0.0.0.0")?
             .set_default("port", 3000)?
+            .set_default("grpc_port", 50051)?
+            .set_default("cors_allowed_methods", default_cors_allowed_methods())?
+            .set_default("cors_allowed_headers", default_cors_allowed_headers())?
+            .set_default("cors_max_age_seconds", 3600)?
             .set_default("casbin_model_path", "shared/auth/model.conf")?
             .set_default("max_connections", 10)?
             .set_default("invitation_base_url", "https://app.example.com")?
@@ -446,6 +827,8 @@ impl Config {
             .set_default("rate_limit_file_upload_window", 3600)?
             .set_default("rate_limit_trust_proxy_headers", false)?
             .set_default("rate_limit_proxy_count", 0)?
+            .set_default("rate_limit_monitor_mode", false)?
+            .set_default("rate_limit_fail_closed", false)?
             // Decision cache defaults
             .set_default("decision_cache_enabled", true)?
             .set_default("decision_cache_ttl_seconds", 15)?
@@ -458,7 +841,49 @@ impl Config {
             // Cookie configuration defaults
             .set_default("cookie_secure", true)?
             .set_default("cookie_same_site", "Strict")?
-            .set_default("cookie_path", "/")?;
+            .set_default("cookie_path", "/")?
+            // Password policy defaults
+            .set_default("password_min_length", 8)?
+            .set_default("password_require_uppercase", false)?
+            .set_default("password_require_lowercase", false)?
+            .set_default("password_require_digit", false)?
+            .set_default("password_require_symbol", false)?
+            .set_default("password_min_score", 3)?
+            .set_default("password_check_breached", false)?
+            // Feature flag defaults
+            .set_default("delivery_enabled", false)?
+            // Background job defaults
+            .set_default("category_recount_interval_seconds", 3600)?
+            .set_default("replenishment_check_interval_seconds", 900)?
+            .set_default("replenishment_cooldown_seconds", 86400)?
+            .set_default("expiry_scrap_interval_seconds", 86400)?
+            .set_default("expiry_scrap_grace_days", 7)?
+            .set_default("stock_move_retention_days", 0)?
+            .set_default("stock_move_archive_interval_seconds", 86400)?
+            .set_default("outbox_batch_size", 50)?
+            .set_default("outbox_poll_interval_ms", 5000)?
+            .set_default("outbox_max_in_flight", 10)?
+            .set_default("outbox_max_attempts", 3)?
+            // Pagination defaults
+            .set_default("default_page_size", 20)?
+            .set_default("max_page_size", 100)?
+            // Tenant onboarding defaults
+            .set_default("tenant_seed_warehouse_code", "MAIN")?
+            .set_default("tenant_seed_warehouse_name", "Main Warehouse")?
+            .set_default("tenant_seed_category_name", "Uncategorized")?
+            .set_default("tenant_seed_removal_strategy", "fifo")?
+            .set_default("tenant_seed_on_first_access", false)?
+            // Report concurrency defaults
+            .set_default("report_max_concurrency", 4)?
+            .set_default("report_queue_timeout_ms", 2000)?
+            .set_default("report_default_range_days", 90)?
+            .set_default("report_max_range_days", 730)?
+            // Upload concurrency defaults
+            .set_default("max_concurrent_uploads", 4)?
+            .set_default("upload_queue_timeout_ms", 5000)?
+            .set_default("tenant_export_cooldown_seconds", 3600)?
+            .set_default("tenant_export_url_ttl_seconds", 3600)?
+            .set_default("idempotency_in_flight_stale_seconds", 300)?;
 
         // Add environment variables
         builder = builder.add_source(config::Environment::default());
@@ -467,9 +892,38 @@ impl Config {
 
         let deserialized = config.try_deserialize::<Config>()?;
 
+        // Zero or negative values would stall the outbox worker or dead-letter
+        // events on their first failure
+        if deserialized.outbox_batch_size < 1 {
+            return Err(config::ConfigError::Message(
+                "outbox_batch_size must be at least 1".to_string(),
+            ));
+        }
+        if deserialized.outbox_max_attempts < 1 {
+            return Err(config::ConfigError::Message(
+                "outbox_max_attempts must be at least 1".to_string(),
+            ));
+        }
+
         Ok(deserialized)
     }
 
+    /// Access token lifetime in seconds for a role
+    pub fn access_token_expiration(&self, role: &str) -> i64 {
+        self.jwt_expiration_overrides
+            .get(role)
+            .copied()
+            .unwrap_or(self.jwt_expiration)
+    }
+
+    /// Refresh token lifetime in seconds for a role
+    pub fn refresh_token_expiration(&self, role: &str) -> i64 {
+        self.jwt_refresh_expiration_overrides
+            .get(role)
+            .copied()
+            .unwrap_or(self.jwt_refresh_expiration)
+    }
+
     /// Get CORS allowed origins as a vector
     /// If cors_origins is None or empty, returns empty vec (accept all origins)
     pub fn get_cors_origins(&self) -> Vec<String> {
@@ -482,6 +936,25 @@ impl Config {
             })
             .unwrap_or_default()
     }
+
+    /// Get CORS allowed methods as a vector
+    pub fn get_cors_allowed_methods(&self) -> Vec<String> {
+        split_list(&self.cors_allowed_methods)
+    }
+
+    /// Get CORS allowed request headers as a vector
+    pub fn get_cors_allowed_headers(&self) -> Vec<String> {
+        split_list(&self.cors_allowed_headers)
+    }
+}
+
+/// Split a comma-separated list, dropping empty entries
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
 }
 
 impl Default for Config {
@@ -491,9 +964,15 @@ impl Default for Config {
             jwt_secret: String::new(),
             jwt_expiration: default_jwt_expiration(),
             jwt_refresh_expiration: default_jwt_refresh_expiration(),
+            jwt_expiration_overrides: HashMap::new(),
+            jwt_refresh_expiration_overrides: HashMap::new(),
             host: default_host(),
             port: default_port(),
+            grpc_port: default_grpc_port(),
             cors_origins: None,
+            cors_allowed_methods: default_cors_allowed_methods(),
+            cors_allowed_headers: default_cors_allowed_headers(),
+            cors_max_age_seconds: default_cors_max_age_seconds(),
             nats_url: None,
             redis_url: None,
             casbin_model_path: default_casbin_model_path(),
@@ -531,6 +1010,8 @@ impl Default for Config {
             rate_limit_trust_proxy_headers: false,
             rate_limit_proxy_count: 0,
             rate_limit_trusted_ips: None,
+            rate_limit_monitor_mode: false,
+            rate_limit_fail_closed: false,
             decision_cache_enabled: default_decision_cache_enabled(),
             decision_cache_ttl_seconds: default_decision_cache_ttl_seconds(),
             decision_cache_max_entries: default_decision_cache_max_entries(),
@@ -542,6 +1023,44 @@ impl Default for Config {
             cookie_secure: default_cookie_secure(),
             cookie_same_site: default_cookie_same_site(),
             cookie_path: default_cookie_path(),
+            password_min_length: default_password_min_length(),
+            password_require_uppercase: false,
+            password_require_lowercase: false,
+            password_require_digit: false,
+            password_require_symbol: false,
+            password_min_score: default_password_min_score(),
+            password_check_breached: false,
+            delivery_enabled: false,
+            category_recount_interval_seconds: default_category_recount_interval_seconds(),
+            replenishment_check_interval_seconds: default_replenishment_check_interval_seconds(),
+            replenishment_cooldown_seconds: default_replenishment_cooldown_seconds(),
+            expiry_scrap_interval_seconds: default_expiry_scrap_interval_seconds(),
+            expiry_scrap_grace_days: default_expiry_scrap_grace_days(),
+            stock_move_retention_days: 0,
+            stock_move_archive_interval_seconds: default_stock_move_archive_interval_seconds(),
+            outbox_batch_size: default_outbox_batch_size(),
+            outbox_poll_interval_ms: default_outbox_poll_interval_ms(),
+            outbox_max_in_flight: default_outbox_max_in_flight(),
+            outbox_max_attempts: default_outbox_max_attempts(),
+            default_page_size: default_page_size(),
+            max_page_size: default_max_page_size(),
+            tenant_seed_warehouse_code: default_tenant_seed_warehouse_code(),
+            tenant_seed_warehouse_name: default_tenant_seed_warehouse_name(),
+            tenant_seed_category_name: default_tenant_seed_category_name(),
+            tenant_seed_removal_strategy: default_tenant_seed_removal_strategy(),
+            tenant_seed_on_first_access: false,
+            report_max_concurrency: default_report_max_concurrency(),
+            report_queue_timeout_ms: default_report_queue_timeout_ms(),
+            report_default_range_days: default_report_default_range_days(),
+            report_max_range_days: default_report_max_range_days(),
+            max_concurrent_uploads: default_max_concurrent_uploads(),
+            upload_queue_timeout_ms: default_upload_queue_timeout_ms(),
+            product_cache_ttl_seconds: None,
+            inventory_cache_ttl_seconds: None,
+            category_cache_ttl_seconds: None,
+            tenant_export_cooldown_seconds: default_tenant_export_cooldown_seconds(),
+            tenant_export_url_ttl_seconds: default_tenant_export_url_ttl_seconds(),
+            idempotency_in_flight_stale_seconds: default_idempotency_in_flight_stale_seconds(),
         }
     }
 }
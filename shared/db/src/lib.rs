@@ -2,6 +2,13 @@ use shared_error::AppError;
 use sqlx::postgres::PgPoolOptions;
 pub use sqlx::PgPool;
 
+pub mod tenant;
+
+pub use tenant::{
+    begin_tenant_tx, debug_assert_tenant_filtered, missing_tenant_filter, AssertTenantFiltered,
+    TenantScopedPool,
+};
+
 /// Initialize database connection pool
 pub async fn init_pool(database_url: &str, max_connections: u32) -> Result<PgPool, AppError> {
     PgPoolOptions::new()
@@ -0,0 +1,180 @@
+//! Tenant-scoped database access
+//!
+//! Repositories filter by `tenant_id` explicitly; this module adds a second
+//! line of defence. A transaction started with [`begin_tenant_tx`] sets the
+//! transaction-local `app.current_tenant` setting, and the row-level security
+//! policies on tenant tables hide every row that belongs to another tenant.
+//! Connections that never set it keep the unscoped behaviour, so background
+//! jobs and cross-tenant admin queries continue to work.
+//!
+//! Row-level security does not apply to superusers, so the services must
+//! connect as a regular role for the policies to take effect.
+
+use shared_error::AppError;
+use sqlx::{PgPool, Postgres, QueryBuilder, Transaction};
+use uuid::Uuid;
+
+/// Postgres setting read by the `tenant_isolation` row-level security policies
+pub const CURRENT_TENANT_SETTING: &str = "app.current_tenant";
+
+/// Tables that are shared across tenants and never need a tenant filter
+const GLOBAL_TABLES: &[&str] = &["tenants", "casbin_rule", "_sqlx_migrations"];
+
+/// Begin a transaction scoped to a tenant
+///
+/// The scope is transaction-local (`set_config(.., true)`), so it is cleared
+/// on commit or rollback and never leaks to the next user of the pooled
+/// connection.
+pub async fn begin_tenant_tx(
+    pool: &PgPool,
+    tenant_id: Uuid,
+) -> Result<Transaction<'static, Postgres>, AppError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to begin transaction: {}", e)))?;
+
+    sqlx::query("SELECT set_config($1, $2, true)")
+        .bind(CURRENT_TENANT_SETTING)
+        .bind(tenant_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Failed to set tenant scope: {}", e)))?;
+
+    Ok(tx)
+}
+
+/// Pool wrapper whose transactions are always scoped to a tenant
+#[derive(Debug, Clone)]
+pub struct TenantScopedPool {
+    pool: PgPool,
+}
+
+impl TenantScopedPool {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Begin a transaction that can only see `tenant_id`'s rows
+    pub async fn begin(&self, tenant_id: Uuid) -> Result<Transaction<'static, Postgres>, AppError> {
+        begin_tenant_tx(&self.pool, tenant_id).await
+    }
+
+    /// Underlying unscoped pool, for cross-tenant work such as background jobs
+    pub fn inner(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+/// Return the first tenant table a query reads or writes without mentioning `tenant_id`
+///
+/// This is a lexical check meant for tests and debug builds: every table
+/// referenced after `FROM`, `JOIN`, `UPDATE` or `INTO` that is not in the
+/// global table list requires a `tenant_id` reference somewhere in the query.
+pub fn missing_tenant_filter(sql: &str) -> Option<String> {
+    let normalized = sql.to_lowercase();
+    if normalized.contains("tenant_id") {
+        return None;
+    }
+
+    let tokens: Vec<&str> = normalized
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')' || c == ';')
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    tokens
+        .windows(2)
+        .filter(|pair| matches!(pair[0], "from" | "join" | "update" | "into"))
+        .map(|pair| pair[1].trim_matches('"'))
+        .map(|table| table.rsplit('.').next().unwrap_or(table))
+        .find(|table| {
+            table.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !table.starts_with("pg_")
+                && !GLOBAL_TABLES.contains(table)
+        })
+        .map(str::to_string)
+}
+
+/// Panic in debug builds when a query touches a tenant table without a tenant filter
+///
+/// Release builds skip the check entirely.
+#[track_caller]
+pub fn debug_assert_tenant_filtered(sql: &str) {
+    if cfg!(debug_assertions) {
+        if let Some(table) = missing_tenant_filter(sql) {
+            panic!("Query on tenant table '{}' is missing a tenant_id filter: {}", table, sql);
+        }
+    }
+}
+
+/// Tenant filter check for queries assembled at runtime
+///
+/// `sqlx::query!` literals are reviewed as written; builders are not, so every
+/// runtime query goes through this before it is built.
+pub trait AssertTenantFiltered {
+    /// Run [`debug_assert_tenant_filtered`] on the SQL assembled so far
+    fn assert_tenant_filtered(&mut self) -> &mut Self;
+}
+
+impl AssertTenantFiltered for QueryBuilder<'_, Postgres> {
+    #[track_caller]
+    fn assert_tenant_filtered(&mut self) -> &mut Self {
+        debug_assert_tenant_filtered(self.sql());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filtered_query_passes() {
+        let sql = "SELECT * FROM products WHERE tenant_id = $1 AND product_id = $2";
+        assert_eq!(missing_tenant_filter(sql), None);
+    }
+
+    #[test]
+    fn test_unfiltered_query_detected() {
+        let sql = "SELECT * FROM products WHERE product_id = $1";
+        assert_eq!(missing_tenant_filter(sql), Some("products".to_string()));
+
+        let sql = "UPDATE inventory_levels SET available_quantity = 0 WHERE inventory_id = $1";
+        assert_eq!(missing_tenant_filter(sql), Some("inventory_levels".to_string()));
+
+        let sql = "SELECT p.name FROM tenants t JOIN public.products p ON p.sku = t.slug";
+        assert_eq!(missing_tenant_filter(sql), Some("products".to_string()));
+    }
+
+    #[test]
+    fn test_global_tables_are_exempt() {
+        assert_eq!(missing_tenant_filter("SELECT slug FROM tenants WHERE slug = $1"), None);
+        assert_eq!(missing_tenant_filter("SELECT * FROM casbin_rule WHERE ptype = 'p'"), None);
+        assert_eq!(missing_tenant_filter("SELECT 1"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing a tenant_id filter")]
+    fn test_debug_assertion_panics() {
+        debug_assert_tenant_filtered("DELETE FROM stock_moves WHERE move_id = $1");
+    }
+
+    #[test]
+    fn test_filtered_builder_passes_through() {
+        let mut builder =
+            QueryBuilder::<Postgres>::new("SELECT * FROM products WHERE tenant_id = ");
+        builder.push_bind(Uuid::nil());
+        assert_eq!(
+            builder.assert_tenant_filtered().sql(),
+            "SELECT * FROM products WHERE tenant_id = $1"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "missing a tenant_id filter")]
+    fn test_unfiltered_builder_panics() {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT * FROM products WHERE sku = ");
+        builder.push_bind("SKU-1");
+        builder.assert_tenant_filtered();
+    }
+}
@@ -0,0 +1,164 @@
+//! Request extractors that reject with `AppError`.
+//!
+//! Axum's built-in `Json` extractor answers malformed bodies with a plain-text
+//! 400/415/422. The `Json` wrapper here keeps the same behavior on success but
+//! maps every rejection into the standard `{error, code}` envelope.
+
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest, OptionalFromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::AppError;
+
+/// JSON extractor and response that reports rejections as `AppError`.
+///
+/// Drop-in replacement for `axum::Json`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json<T>(pub T);
+
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::Json(value) = <axum::Json<T> as FromRequest<S>>::from_request(req, state).await?;
+        Ok(Json(value))
+    }
+}
+
+impl<T, S> OptionalFromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Option<Self>, Self::Rejection> {
+        let value = <axum::Json<T> as OptionalFromRequest<S>>::from_request(req, state).await?;
+        Ok(value.map(|axum::Json(value)| Json(value)))
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Json(value)
+    }
+}
+
+impl From<JsonRejection> for AppError {
+    /// The message keeps axum's detail, which names the failing field path and
+    /// the line/column of the error.
+    fn from(rejection: JsonRejection) -> Self {
+        match rejection {
+            JsonRejection::JsonDataError(e) => {
+                AppError::ValidationError(format!("Invalid JSON body: {}", e.body_text()))
+            },
+            JsonRejection::JsonSyntaxError(e) => {
+                AppError::ValidationError(format!("Malformed JSON body: {}", e.body_text()))
+            },
+            JsonRejection::MissingJsonContentType(e) => {
+                AppError::UnsupportedMediaType(e.body_text())
+            },
+            other if other.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+                AppError::PayloadTooLarge(other.body_text())
+            },
+            other => AppError::ValidationError(other.body_text()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde::Deserialize;
+    use serde_json::Value;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Payload {
+        name: String,
+        quantity: i64,
+    }
+
+    fn json_request(body: &'static str) -> Request {
+        Request::builder()
+            .method("POST")
+            .uri("/")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn rejection_envelope(body: &'static str) -> (StatusCode, Value) {
+        let err = <Json<Payload> as FromRequest<()>>::from_request(json_request(body), &())
+            .await
+            .expect_err("body should be rejected");
+        let response = err.into_response();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).expect("rejection should be JSON"))
+    }
+
+    #[tokio::test]
+    async fn test_valid_body_is_extracted() {
+        let Json(payload) = <Json<Payload> as FromRequest<()>>::from_request(
+            json_request(r#"{"name":"widget","quantity":3}"#),
+            &(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(payload.name, "widget");
+        assert_eq!(payload.quantity, 3);
+    }
+
+    #[tokio::test]
+    async fn test_syntax_error_returns_envelope_with_position() {
+        let (status, body) = rejection_envelope(r#"{"name":"widget","quantity":"#).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        let message = body["error"].as_str().unwrap();
+        assert!(message.starts_with("Malformed JSON body"), "{}", message);
+        assert!(message.contains("column"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_type_mismatch_returns_envelope_with_field() {
+        let (status, body) = rejection_envelope(r#"{"name":"widget","quantity":"three"}"#).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        let message = body["error"].as_str().unwrap();
+        assert!(message.starts_with("Invalid JSON body"), "{}", message);
+        assert!(message.contains("quantity"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_missing_content_type_is_unsupported_media_type() {
+        let req = Request::builder()
+            .method("POST")
+            .uri("/")
+            .body(Body::from(r#"{"name":"widget","quantity":3}"#))
+            .unwrap();
+        let err = <Json<Payload> as FromRequest<()>>::from_request(req, &())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AppError::UnsupportedMediaType(_)));
+    }
+}
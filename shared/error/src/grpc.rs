@@ -0,0 +1,83 @@
+//! gRPC status mapping for `AppError`.
+//!
+//! gRPC handlers return the same errors as the HTTP handlers. The status code is
+//! derived from the HTTP status so both transports agree, and the stable error
+//! code travels in the `x-error-code` metadata entry.
+
+use axum::http::StatusCode;
+use tonic::{metadata::MetadataValue, Code, Status};
+
+use crate::AppError;
+
+/// Metadata key carrying the machine-stable `ErrorCode` string
+pub const ERROR_CODE_METADATA_KEY: &str = "x-error-code";
+
+/// Metadata key carrying the `error_id` of server-side failures
+pub const ERROR_ID_METADATA_KEY: &str = "x-error-id";
+
+fn grpc_code(status: StatusCode) -> Code {
+    match status {
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        StatusCode::NOT_FOUND | StatusCode::GONE => Code::NotFound,
+        StatusCode::CONFLICT => Code::AlreadyExists,
+        StatusCode::TOO_MANY_REQUESTS => Code::ResourceExhausted,
+        StatusCode::PAYLOAD_TOO_LARGE => Code::OutOfRange,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => Code::InvalidArgument,
+        StatusCode::SERVICE_UNAVAILABLE => Code::Unavailable,
+        _ => Code::Internal,
+    }
+}
+
+impl From<AppError> for Status {
+    fn from(err: AppError) -> Self {
+        let error_code = err.code();
+        let (status, message) = err.status_and_message();
+        let error_id = err.log();
+        let mut grpc_status = Status::new(grpc_code(status), message);
+        grpc_status
+            .metadata_mut()
+            .insert(ERROR_CODE_METADATA_KEY, MetadataValue::from_static(error_code.as_str()));
+        if let Some(error_id) = error_id {
+            if let Ok(value) = MetadataValue::try_from(error_id.to_string()) {
+                grpc_status
+                    .metadata_mut()
+                    .insert(ERROR_ID_METADATA_KEY, value);
+            }
+        }
+        grpc_status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_follows_http_mapping() {
+        let cases = [
+            (AppError::ValidationError("bad".into()), Code::InvalidArgument),
+            (AppError::InvalidToken, Code::Unauthenticated),
+            (AppError::Forbidden("no".into()), Code::PermissionDenied),
+            (AppError::NotFound("missing".into()), Code::NotFound),
+            (AppError::Conflict("dup".into()), Code::AlreadyExists),
+            (AppError::TooManyRequests("slow".into()), Code::ResourceExhausted),
+            (AppError::ServiceUnavailable("down".into()), Code::Unavailable),
+            (AppError::DatabaseError("secret".into()), Code::Internal),
+        ];
+        for (err, expected) in cases {
+            let code = err.code();
+            let status = Status::from(err);
+            assert_eq!(status.code(), expected);
+            assert_eq!(status.metadata().get(ERROR_CODE_METADATA_KEY).unwrap(), code.as_str());
+        }
+    }
+
+    #[test]
+    fn test_internal_details_are_not_leaked() {
+        let status = Status::from(AppError::DatabaseError("connection string".into()));
+        assert_eq!(status.message(), "Database error");
+        assert!(status.metadata().get(ERROR_ID_METADATA_KEY).is_some());
+    }
+}
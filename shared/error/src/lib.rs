@@ -1,11 +1,116 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
+use serde::{Serialize, Serializer};
 use serde_json::json;
 use std::fmt;
-use validator::ValidationErrors;
+use uuid::Uuid;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+pub mod extract;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+/// Machine-stable error codes returned in the `code` field of error responses.
+///
+/// Clients may depend on these strings; never rename an existing code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    DatabaseError,
+    Unauthorized,
+    InvalidCredentials,
+    TokenExpired,
+    InvalidToken,
+    ValidationError,
+    UserExists,
+    UserNotFound,
+    TenantNotFound,
+    NotFound,
+    Forbidden,
+    Conflict,
+    Gone,
+    TooManyRequests,
+    DataCorruption,
+    BusinessError,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    CasbinError,
+    InternalError,
+    ConfigError,
+    ServiceUnavailable,
+}
+
+impl ErrorCode {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::DatabaseError => "DATABASE_ERROR",
+            ErrorCode::Unauthorized => "UNAUTHORIZED",
+            ErrorCode::InvalidCredentials => "INVALID_CREDENTIALS",
+            ErrorCode::TokenExpired => "TOKEN_EXPIRED",
+            ErrorCode::InvalidToken => "INVALID_TOKEN",
+            ErrorCode::ValidationError => "VALIDATION_ERROR",
+            ErrorCode::UserExists => "USER_EXISTS",
+            ErrorCode::UserNotFound => "USER_NOT_FOUND",
+            ErrorCode::TenantNotFound => "TENANT_NOT_FOUND",
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::Forbidden => "FORBIDDEN",
+            ErrorCode::Conflict => "CONFLICT",
+            ErrorCode::Gone => "GONE",
+            ErrorCode::TooManyRequests => "TOO_MANY_REQUESTS",
+            ErrorCode::DataCorruption => "DATA_CORRUPTION",
+            ErrorCode::BusinessError => "BUSINESS_ERROR",
+            ErrorCode::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            ErrorCode::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            ErrorCode::CasbinError => "CASBIN_ERROR",
+            ErrorCode::InternalError => "INTERNAL_ERROR",
+            ErrorCode::ConfigError => "CONFIG_ERROR",
+            ErrorCode::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// A validation failure on a single request field.
+///
+/// `field` is the path of the field in the request body, with nested fields
+/// joined by `.` and list items by index (`items[0].quantity`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(
+        field: impl Into<String>,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// `Retry-After` sent with `ServiceUnavailable`, which carries no delay of its own
+pub const SERVICE_UNAVAILABLE_RETRY_AFTER_SECS: u64 = 30;
 
 #[derive(Debug)]
 pub enum AppError {
@@ -20,18 +125,20 @@ pub enum AppError {
 
     // Validation errors
     ValidationError(String),
+    ValidationErrors(Vec<FieldError>), // Per-field errors for form mapping
 
     // Business logic errors
     UserAlreadyExists,
     UserNotFound,
     TenantNotFound,
-    NotFound(String),        // Generic not found with custom message
-    Forbidden(String),       // Forbidden access with custom message
-    Conflict(String),        // Resource conflict with custom message
-    Gone(String),            // Resource is gone (expired, etc.)
-    TooManyRequests(String), // Rate limit exceeded
-    DataCorruption(String),  // Data integrity issues
-    BusinessError(String),   // Business logic errors
+    NotFound(String),                      // Generic not found with custom message
+    Forbidden(String),                     // Forbidden access with custom message
+    Conflict(String),                      // Resource conflict with custom message
+    Gone(String),                          // Resource is gone (expired, etc.)
+    TooManyRequests(String),               // Rate limit exceeded
+    RateLimited { retry_after_secs: u64 }, // Rate limit exceeded, with Retry-After
+    DataCorruption(String),                // Data integrity issues
+    BusinessError(String),                 // Business logic errors
 
     // File upload errors
     PayloadTooLarge(String),      // File size exceeds limit
@@ -57,6 +164,14 @@ impl fmt::Display for AppError {
             AppError::TokenExpired => write!(f, "Token expired"),
             AppError::InvalidToken => write!(f, "Invalid token"),
             AppError::ValidationError(msg) => write!(f, "{}", msg),
+            AppError::ValidationErrors(fields) => {
+                write!(f, "Validation failed")?;
+                for (i, field) in fields.iter().enumerate() {
+                    let sep = if i == 0 { ": " } else { "; " };
+                    write!(f, "{}{}: {}", sep, field.field, field.message)?;
+                }
+                Ok(())
+            },
             AppError::UserAlreadyExists => write!(f, "User already exists"),
             AppError::UserNotFound => write!(f, "User not found"),
             AppError::TenantNotFound => write!(f, "Tenant not found"),
@@ -65,6 +180,9 @@ impl fmt::Display for AppError {
             AppError::Conflict(msg) => write!(f, "Conflict: {}", msg),
             AppError::Gone(msg) => write!(f, "Gone: {}", msg),
             AppError::TooManyRequests(msg) => write!(f, "Too many requests: {}", msg),
+            AppError::RateLimited { retry_after_secs } => {
+                write!(f, "Rate limited: retry after {} seconds", retry_after_secs)
+            },
             AppError::DataCorruption(msg) => write!(f, "Data corruption: {}", msg),
             AppError::BusinessError(msg) => write!(f, "Business error: {}", msg),
             AppError::PayloadTooLarge(msg) => write!(f, "Payload too large: {}", msg),
@@ -81,109 +199,185 @@ impl fmt::Display for AppError {
 
 impl std::error::Error for AppError {}
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let (status, error_message, error_code) = match self {
-            AppError::Database(ref e) => {
-                tracing::error!("Database error: {:?}", e);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Database error".to_string(),
-                    "DATABASE_ERROR",
-                )
-            },
-            AppError::Unauthorized(ref msg) => {
-                (StatusCode::UNAUTHORIZED, msg.clone(), "UNAUTHORIZED")
-            },
-            AppError::InvalidCredentials => {
-                (StatusCode::UNAUTHORIZED, self.to_string(), "INVALID_CREDENTIALS")
-            },
-            AppError::TokenExpired => (StatusCode::UNAUTHORIZED, self.to_string(), "TOKEN_EXPIRED"),
-            AppError::InvalidToken => (StatusCode::UNAUTHORIZED, self.to_string(), "INVALID_TOKEN"),
-            AppError::ValidationError(ref msg) => {
-                (StatusCode::BAD_REQUEST, msg.clone(), "VALIDATION_ERROR")
-            },
-            AppError::UserAlreadyExists => (StatusCode::CONFLICT, self.to_string(), "USER_EXISTS"),
-            AppError::UserNotFound => (StatusCode::NOT_FOUND, self.to_string(), "USER_NOT_FOUND"),
-            AppError::TenantNotFound => {
-                (StatusCode::NOT_FOUND, self.to_string(), "TENANT_NOT_FOUND")
-            },
-            AppError::NotFound(ref msg) => (StatusCode::NOT_FOUND, msg.clone(), "NOT_FOUND"),
-            AppError::Forbidden(ref msg) => (StatusCode::FORBIDDEN, msg.clone(), "FORBIDDEN"),
-            AppError::Conflict(ref msg) => (StatusCode::CONFLICT, msg.clone(), "CONFLICT"),
-            AppError::Gone(ref msg) => (StatusCode::GONE, msg.clone(), "GONE"),
-            AppError::TooManyRequests(ref msg) => {
-                (StatusCode::TOO_MANY_REQUESTS, msg.clone(), "TOO_MANY_REQUESTS")
+impl AppError {
+    /// Stable error code for this error.
+    ///
+    /// The match is exhaustive so adding a variant without a code fails to compile.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            AppError::Database(_) | AppError::DatabaseError(_) => ErrorCode::DatabaseError,
+            AppError::Unauthorized(_) => ErrorCode::Unauthorized,
+            AppError::InvalidCredentials => ErrorCode::InvalidCredentials,
+            AppError::TokenExpired => ErrorCode::TokenExpired,
+            AppError::InvalidToken => ErrorCode::InvalidToken,
+            AppError::ValidationError(_) | AppError::ValidationErrors(_) => {
+                ErrorCode::ValidationError
             },
-            AppError::DataCorruption(ref msg) => {
-                tracing::error!("Data corruption: {}", msg);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Data corruption detected".to_string(),
-                    "DATA_CORRUPTION",
-                )
+            AppError::UserAlreadyExists => ErrorCode::UserExists,
+            AppError::UserNotFound => ErrorCode::UserNotFound,
+            AppError::TenantNotFound => ErrorCode::TenantNotFound,
+            AppError::NotFound(_) => ErrorCode::NotFound,
+            AppError::Forbidden(_) => ErrorCode::Forbidden,
+            AppError::Conflict(_) => ErrorCode::Conflict,
+            AppError::Gone(_) => ErrorCode::Gone,
+            AppError::TooManyRequests(_) | AppError::RateLimited { .. } => {
+                ErrorCode::TooManyRequests
             },
-            AppError::BusinessError(ref msg) => {
-                tracing::error!("Business error: {}", msg);
-                (StatusCode::BAD_REQUEST, msg.clone(), "BUSINESS_ERROR")
+            AppError::DataCorruption(_) => ErrorCode::DataCorruption,
+            AppError::BusinessError(_) => ErrorCode::BusinessError,
+            AppError::PayloadTooLarge(_) => ErrorCode::PayloadTooLarge,
+            AppError::UnsupportedMediaType(_) => ErrorCode::UnsupportedMediaType,
+            AppError::Casbin(_) => ErrorCode::CasbinError,
+            AppError::InternalServerError(_) | AppError::InternalError(_) => {
+                ErrorCode::InternalError
+            },
+            AppError::ConfigError(_) => ErrorCode::ConfigError,
+            AppError::ServiceUnavailable(_) => ErrorCode::ServiceUnavailable,
+        }
+    }
+
+    /// HTTP status and client-facing message for this error.
+    ///
+    /// Internal details are replaced with a generic message; [`AppError::log`]
+    /// records them. Shared by the HTTP envelope and the gRPC status mapping.
+    pub fn status_and_message(&self) -> (StatusCode, String) {
+        match *self {
+            AppError::Database(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Database error".to_string())
+            },
+            AppError::Unauthorized(ref msg) => (StatusCode::UNAUTHORIZED, msg.clone()),
+            AppError::InvalidCredentials => (StatusCode::UNAUTHORIZED, self.to_string()),
+            AppError::TokenExpired => (StatusCode::UNAUTHORIZED, self.to_string()),
+            AppError::InvalidToken => (StatusCode::UNAUTHORIZED, self.to_string()),
+            AppError::ValidationError(ref msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            AppError::ValidationErrors(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "validation failed".to_string())
             },
-            AppError::PayloadTooLarge(ref msg) => {
-                (StatusCode::PAYLOAD_TOO_LARGE, msg.clone(), "PAYLOAD_TOO_LARGE")
+            AppError::UserAlreadyExists => (StatusCode::CONFLICT, self.to_string()),
+            AppError::UserNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            AppError::TenantNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            AppError::NotFound(ref msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            AppError::Forbidden(ref msg) => (StatusCode::FORBIDDEN, msg.clone()),
+            AppError::Conflict(ref msg) => (StatusCode::CONFLICT, msg.clone()),
+            AppError::Gone(ref msg) => (StatusCode::GONE, msg.clone()),
+            AppError::TooManyRequests(ref msg) => (StatusCode::TOO_MANY_REQUESTS, msg.clone()),
+            AppError::RateLimited { retry_after_secs } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                format!("Too many requests. Please try again in {} seconds.", retry_after_secs),
+            ),
+            AppError::DataCorruption(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Data corruption detected".to_string())
             },
+            AppError::BusinessError(ref msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            AppError::PayloadTooLarge(ref msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg.clone()),
             AppError::UnsupportedMediaType(ref msg) => {
-                (StatusCode::UNSUPPORTED_MEDIA_TYPE, msg.clone(), "UNSUPPORTED_MEDIA_TYPE")
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, msg.clone())
+            },
+            AppError::Casbin(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Authorization error".to_string())
+            },
+            AppError::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+            },
+            AppError::InternalError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal error".to_string())
+            },
+            AppError::ConfigError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Configuration error".to_string())
+            },
+            AppError::DatabaseError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "Database error".to_string())
+            },
+            AppError::ServiceUnavailable(ref msg) => (StatusCode::SERVICE_UNAVAILABLE, msg.clone()),
+        }
+    }
+
+    /// Log the details hidden from the client.
+    ///
+    /// Server-side failures get a fresh `error_id` that is logged with them and
+    /// returned, so the response can carry it and support can find the log line.
+    pub fn log(&self) -> Option<Uuid> {
+        let error_id = Uuid::now_v7();
+        match *self {
+            AppError::Database(ref e) => {
+                tracing::error!(%error_id, "Database error: {:?}", e);
+            },
+            AppError::DataCorruption(ref msg) => {
+                tracing::error!(%error_id, "Data corruption: {}", msg);
             },
             AppError::Casbin(ref e) => {
-                tracing::error!("Casbin error: {:?}", e);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Authorization error".to_string(),
-                    "CASBIN_ERROR",
-                )
-            },
-            AppError::InternalServerError(ref msg) => {
-                tracing::error!("Internal error: {}", msg);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error".to_string(),
-                    "INTERNAL_ERROR",
-                )
-            },
-            AppError::InternalError(ref msg) => {
-                tracing::error!("Internal error: {}", msg);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal error".to_string(),
-                    "INTERNAL_ERROR",
-                )
+                tracing::error!(%error_id, "Casbin error: {:?}", e);
+            },
+            AppError::InternalServerError(ref msg) | AppError::InternalError(ref msg) => {
+                tracing::error!(%error_id, "Internal error: {}", msg);
             },
             AppError::ConfigError(ref msg) => {
-                tracing::error!("Config error: {}", msg);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Configuration error".to_string(),
-                    "CONFIG_ERROR",
-                )
+                tracing::error!(%error_id, "Config error: {}", msg);
             },
             AppError::DatabaseError(ref msg) => {
-                tracing::error!("Database error: {}", msg);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Database error".to_string(),
-                    "DATABASE_ERROR",
-                )
+                tracing::error!(%error_id, "Database error: {}", msg);
             },
-            AppError::ServiceUnavailable(ref msg) => {
-                (StatusCode::SERVICE_UNAVAILABLE, msg.clone(), "SERVICE_UNAVAILABLE")
+            AppError::BusinessError(ref msg) => {
+                tracing::error!("Business error: {}", msg);
+                return None;
             },
-        };
+            _ => return None,
+        }
+        Some(error_id)
+    }
 
-        let body = Json(json!({
+    /// Whether the same request may succeed if sent again later.
+    ///
+    /// True for transient failures: database errors, unavailable dependencies
+    /// and rate limiting. Everything else will fail the same way on retry.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            AppError::Database(_)
+                | AppError::DatabaseError(_)
+                | AppError::ServiceUnavailable(_)
+                | AppError::RateLimited { .. }
+        )
+    }
+
+    /// Seconds the client should wait before retrying, sent as `Retry-After`.
+    pub fn retry_after(&self) -> Option<u64> {
+        match *self {
+            AppError::RateLimited { retry_after_secs } => Some(retry_after_secs),
+            AppError::ServiceUnavailable(_) => Some(SERVICE_UNAVAILABLE_RETRY_AFTER_SECS),
+            _ => None,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let error_code = self.code();
+        let (status, error_message) = self.status_and_message();
+        let retry_after = self.retry_after();
+        let error_id = self.log();
+
+        let mut body = json!({
             "error": error_message,
             "code": error_code,
-        }));
+        });
+        if let AppError::ValidationErrors(fields) = &self {
+            body["fields"] = json!(fields);
+        }
+        if let AppError::RateLimited { retry_after_secs } = &self {
+            body["retry_after_seconds"] = json!(retry_after_secs);
+        }
+        if let Some(error_id) = error_id {
+            body["error_id"] = json!(error_id);
+        }
 
-        (status, body).into_response()
+        let mut response = (status, Json(body)).into_response();
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(secs));
+        }
+        response
     }
 }
 
@@ -208,6 +402,257 @@ impl From<casbin::Error> for AppError {
 
 impl From<ValidationErrors> for AppError {
     fn from(err: ValidationErrors) -> Self {
-        AppError::ValidationError(err.to_string())
+        let mut fields = Vec::new();
+        collect_field_errors(&err, "", &mut fields);
+        fields.sort_by(|a, b| a.field.cmp(&b.field).then_with(|| a.code.cmp(&b.code)));
+        AppError::ValidationErrors(fields)
+    }
+}
+
+/// Flatten nested `validator` errors into `FieldError`s with dotted paths.
+fn collect_field_errors(errors: &ValidationErrors, prefix: &str, out: &mut Vec<FieldError>) {
+    for (name, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                out.extend(field_errors.iter().map(|e| {
+                    let message = e
+                        .message
+                        .as_ref()
+                        .map(|m| m.to_string())
+                        .unwrap_or_else(|| e.to_string());
+                    FieldError::new(path.clone(), e.code.to_string(), message)
+                }));
+            },
+            ValidationErrorsKind::Struct(nested) => collect_field_errors(nested, &path, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_field_errors(nested, &format!("{}[{}]", path, index), out);
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expected wire code per variant. Exhaustive on purpose: a new `AppError`
+    /// variant must be added here (and given a code) before the tests compile.
+    fn expected_code(err: &AppError) -> &'static str {
+        match err {
+            AppError::Database(_) => "DATABASE_ERROR",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::InvalidCredentials => "INVALID_CREDENTIALS",
+            AppError::TokenExpired => "TOKEN_EXPIRED",
+            AppError::InvalidToken => "INVALID_TOKEN",
+            AppError::ValidationError(_) => "VALIDATION_ERROR",
+            AppError::ValidationErrors(_) => "VALIDATION_ERROR",
+            AppError::UserAlreadyExists => "USER_EXISTS",
+            AppError::UserNotFound => "USER_NOT_FOUND",
+            AppError::TenantNotFound => "TENANT_NOT_FOUND",
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::Forbidden(_) => "FORBIDDEN",
+            AppError::Conflict(_) => "CONFLICT",
+            AppError::Gone(_) => "GONE",
+            AppError::TooManyRequests(_) => "TOO_MANY_REQUESTS",
+            AppError::RateLimited { .. } => "TOO_MANY_REQUESTS",
+            AppError::DataCorruption(_) => "DATA_CORRUPTION",
+            AppError::BusinessError(_) => "BUSINESS_ERROR",
+            AppError::PayloadTooLarge(_) => "PAYLOAD_TOO_LARGE",
+            AppError::UnsupportedMediaType(_) => "UNSUPPORTED_MEDIA_TYPE",
+            AppError::Casbin(_) => "CASBIN_ERROR",
+            AppError::InternalServerError(_) => "INTERNAL_ERROR",
+            AppError::InternalError(_) => "INTERNAL_ERROR",
+            AppError::ConfigError(_) => "CONFIG_ERROR",
+            AppError::DatabaseError(_) => "DATABASE_ERROR",
+            AppError::ServiceUnavailable(_) => "SERVICE_UNAVAILABLE",
+        }
+    }
+
+    fn all_variants() -> Vec<AppError> {
+        let msg = || "test".to_string();
+        vec![
+            AppError::Database(sqlx::Error::RowNotFound),
+            AppError::Unauthorized(msg()),
+            AppError::InvalidCredentials,
+            AppError::TokenExpired,
+            AppError::InvalidToken,
+            AppError::ValidationError(msg()),
+            AppError::ValidationErrors(vec![FieldError::new("name", "length", "too long")]),
+            AppError::UserAlreadyExists,
+            AppError::UserNotFound,
+            AppError::TenantNotFound,
+            AppError::NotFound(msg()),
+            AppError::Forbidden(msg()),
+            AppError::Conflict(msg()),
+            AppError::Gone(msg()),
+            AppError::TooManyRequests(msg()),
+            AppError::RateLimited {
+                retry_after_secs: 5,
+            },
+            AppError::DataCorruption(msg()),
+            AppError::BusinessError(msg()),
+            AppError::PayloadTooLarge(msg()),
+            AppError::UnsupportedMediaType(msg()),
+            AppError::Casbin(casbin::Error::from(std::io::Error::other("test"))),
+            AppError::InternalServerError(msg()),
+            AppError::InternalError(msg()),
+            AppError::ConfigError(msg()),
+            AppError::DatabaseError(msg()),
+            AppError::ServiceUnavailable(msg()),
+        ]
+    }
+
+    #[test]
+    fn test_every_variant_maps_to_stable_code() {
+        for err in all_variants() {
+            assert_eq!(err.code().as_str(), expected_code(&err), "code for {:?}", err);
+        }
+    }
+
+    #[test]
+    fn test_error_code_serializes_as_str() {
+        for err in all_variants() {
+            let code = err.code();
+            assert_eq!(serde_json::to_value(code).unwrap(), json!(code.as_str()));
+            assert_eq!(code.to_string(), code.as_str());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validation_errors_response_lists_fields() {
+        let err = AppError::ValidationErrors(vec![
+            FieldError::new("email", "email", "Invalid email"),
+            FieldError::new("name", "length", "Name is too long"),
+        ]);
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "error": "validation failed",
+                "code": "VALIDATION_ERROR",
+                "fields": [
+                    {"field": "email", "code": "email", "message": "Invalid email"},
+                    {"field": "name", "code": "length", "message": "Name is too long"},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_validator_errors_convert_to_field_errors() {
+        use validator::Validate;
+
+        #[derive(Validate)]
+        struct Line {
+            #[validate(range(min = 1))]
+            quantity: i64,
+        }
+
+        #[derive(Validate)]
+        struct Request {
+            #[validate(length(min = 1, message = "Name is required"))]
+            name: String,
+            #[validate(nested)]
+            lines: Vec<Line>,
+        }
+
+        let request = Request {
+            name: String::new(),
+            lines: vec![Line { quantity: 1 }, Line { quantity: 0 }],
+        };
+        let AppError::ValidationErrors(fields) = AppError::from(request.validate().unwrap_err())
+        else {
+            panic!("expected ValidationErrors");
+        };
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].field, "lines[1].quantity");
+        assert_eq!(fields[0].code, "range");
+        assert_eq!(fields[1], FieldError::new("name", "length", "Name is required"));
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(AppError::Database(sqlx::Error::PoolTimedOut).is_retryable());
+        assert!(AppError::DatabaseError("timeout".into()).is_retryable());
+        assert!(AppError::ServiceUnavailable("down".into()).is_retryable());
+        assert!(AppError::RateLimited {
+            retry_after_secs: 1
+        }
+        .is_retryable());
+
+        assert!(!AppError::ValidationError("bad".into()).is_retryable());
+        assert!(!AppError::Conflict("dup".into()).is_retryable());
+        assert!(!AppError::InternalError("bug".into()).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_response_sets_retry_after() {
+        let response = AppError::RateLimited {
+            retry_after_secs: 42,
+        }
+        .into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "42");
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "TOO_MANY_REQUESTS");
+        assert!(body["error"].as_str().unwrap().contains("42 seconds"));
+        assert_eq!(body["retry_after_seconds"], 42);
+    }
+
+    #[test]
+    fn test_retry_after_only_on_retry_hinted_errors() {
+        let unavailable = AppError::ServiceUnavailable("down".into()).into_response();
+        assert_eq!(
+            unavailable.headers()[header::RETRY_AFTER],
+            SERVICE_UNAVAILABLE_RETRY_AFTER_SECS.to_string().as_str()
+        );
+
+        let conflict = AppError::Conflict("dup".into()).into_response();
+        assert!(!conflict.headers().contains_key(header::RETRY_AFTER));
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_carry_error_id() {
+        let response = AppError::DatabaseError("connection reset".into()).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["error"], "Database error");
+        let error_id = body["error_id"].as_str().unwrap();
+        assert!(Uuid::parse_str(error_id).is_ok());
+    }
+
+    #[test]
+    fn test_only_server_errors_get_error_id() {
+        for err in all_variants() {
+            let (status, _) = err.status_and_message();
+            assert_eq!(
+                err.log().is_some(),
+                status == StatusCode::INTERNAL_SERVER_ERROR,
+                "error_id for {:?}",
+                err
+            );
+        }
     }
 }
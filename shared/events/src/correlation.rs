@@ -0,0 +1,63 @@
+//! Correlation ids linking a request to the events it causes
+//!
+//! The id is carried in a task-local scope rather than passed through every
+//! call: the HTTP layer scopes each request with its id, envelopes created in
+//! that scope pick it up, and consumers re-enter the scope of the id found on
+//! an incoming envelope so the events they publish in turn keep it.
+
+use std::future::Future;
+
+/// HTTP header carrying a caller-supplied correlation id
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// Longest correlation id accepted from a caller
+pub const MAX_CORRELATION_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// Correlation id of the current scope, if any
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Run a future with the given correlation id in scope
+pub async fn with_correlation_id<F: Future>(correlation_id: String, future: F) -> F::Output {
+    CORRELATION_ID.scope(correlation_id, future).await
+}
+
+/// Whether a caller-supplied correlation id is safe to adopt
+///
+/// Ids end up in logs, headers and event payloads, so only short, printable
+/// ASCII values without spaces are accepted.
+pub fn is_valid_correlation_id(correlation_id: &str) -> bool {
+    !correlation_id.is_empty()
+        && correlation_id.len() <= MAX_CORRELATION_ID_LEN
+        && correlation_id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_correlation_id_is_scoped() {
+        assert_eq!(current_correlation_id(), None);
+
+        let inner =
+            with_correlation_id("req-1".to_string(), async { current_correlation_id() }).await;
+        assert_eq!(inner.as_deref(), Some("req-1"));
+
+        assert_eq!(current_correlation_id(), None);
+    }
+
+    #[test]
+    fn test_is_valid_correlation_id() {
+        assert!(is_valid_correlation_id("0192f3a4-7b1c-7d2e-9f00-0123456789ab"));
+        assert!(!is_valid_correlation_id(""));
+        assert!(!is_valid_correlation_id("has space"));
+        assert!(!is_valid_correlation_id("line\nbreak"));
+        assert!(!is_valid_correlation_id(&"a".repeat(MAX_CORRELATION_ID_LEN + 1)));
+    }
+}
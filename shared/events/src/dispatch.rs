@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::Instrument;
+
+use shared_error::AppError;
+
+use crate::correlation::with_correlation_id;
+use crate::events::{EventEnvelope, EventHeader};
+
+type Handler = Arc<dyn Fn(Value) -> BoxFuture<'static, Result<(), AppError>> + Send + Sync>;
+
+/// What happened to a dispatched event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchOutcome {
+    /// A handler was registered for the event type and version and ran
+    Handled,
+    /// No handler matched; the event was logged and dropped
+    Skipped,
+}
+
+/// Routes incoming envelopes to handlers by `(event_type, schema_version)`
+///
+/// Events with an unknown type or version are logged and skipped so a
+/// producer rolling out a new payload version never crashes older consumers.
+#[derive(Clone, Default)]
+pub struct EventDispatcher {
+    handlers: HashMap<(String, u32), Handler>,
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a handler for one event type at one schema version
+    pub fn register<T, F, Fut>(mut self, event_type: &str, schema_version: u32, handler: F) -> Self
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn(EventEnvelope<T>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), AppError>> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        let wrapped: Handler = Arc::new(move |value: Value| {
+            let handler = handler.clone();
+            Box::pin(async move {
+                let envelope: EventEnvelope<T> = serde_json::from_value(value).map_err(|e| {
+                    AppError::ValidationError(format!("Invalid event payload: {}", e))
+                })?;
+                handler(envelope).await
+            })
+        });
+        self.handlers
+            .insert((event_type.to_string(), schema_version), wrapped);
+        self
+    }
+
+    /// Decode an envelope and run the matching handler
+    ///
+    /// The handler runs in a tracing span tagged with the envelope's
+    /// correlation id, and with that id in scope so events it publishes carry
+    /// it on.
+    ///
+    /// Returns an error only when the payload is not an envelope or a matching
+    /// handler fails; unmatched events yield [`DispatchOutcome::Skipped`].
+    pub async fn dispatch(&self, payload: &[u8]) -> Result<DispatchOutcome, AppError> {
+        let value: Value = serde_json::from_slice(payload)
+            .map_err(|e| AppError::ValidationError(format!("Invalid event envelope: {}", e)))?;
+        let header: EventHeader = serde_json::from_value(value.clone())
+            .map_err(|e| AppError::ValidationError(format!("Invalid event envelope: {}", e)))?;
+
+        match self
+            .handlers
+            .get(&(header.event_type.clone(), header.schema_version))
+        {
+            Some(handler) => {
+                let span = tracing::info_span!(
+                    "event",
+                    event_type = %header.event_type,
+                    correlation_id = header.correlation_id.as_deref().unwrap_or_default(),
+                );
+                let handled = handler(value).instrument(span);
+                match header.correlation_id {
+                    Some(correlation_id) => with_correlation_id(correlation_id, handled).await?,
+                    None => handled.await?,
+                }
+                Ok(DispatchOutcome::Handled)
+            },
+            None => {
+                tracing::warn!(
+                    "Skipping {} event with unsupported schema version {}",
+                    header.event_type,
+                    header.schema_version
+                );
+                Ok(DispatchOutcome::Skipped)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Mutex;
+
+    use crate::correlation::current_correlation_id;
+
+    #[derive(Deserialize)]
+    struct StockChanged {
+        quantity: i64,
+    }
+
+    fn counting_dispatcher(total: Arc<AtomicI64>) -> EventDispatcher {
+        EventDispatcher::new().register(
+            "stock.changed",
+            1,
+            move |event: EventEnvelope<StockChanged>| {
+                let total = total.clone();
+                async move {
+                    total.fetch_add(event.data.quantity, Ordering::SeqCst);
+                    Ok(())
+                }
+            },
+        )
+    }
+
+    fn payload(schema_version: u32, data: Value) -> Vec<u8> {
+        let envelope =
+            EventEnvelope::new("stock.changed", data).with_schema_version(schema_version);
+        serde_json::to_vec(&envelope).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_handles_known_version() {
+        let total = Arc::new(AtomicI64::new(0));
+        let dispatcher = counting_dispatcher(total.clone());
+
+        let outcome = dispatcher
+            .dispatch(&payload(1, json!({ "quantity": 5 })))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome, DispatchOutcome::Handled);
+        assert_eq!(total.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skips_unknown_version() {
+        let total = Arc::new(AtomicI64::new(0));
+        let dispatcher = counting_dispatcher(total.clone());
+
+        // v2 renamed the field; the v1 handler must never see it
+        let outcome = dispatcher
+            .dispatch(&payload(2, json!({ "qty": { "amount": 5 } })))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome, DispatchOutcome::Skipped);
+        assert_eq!(total.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_treats_unversioned_envelope_as_v1() {
+        let total = Arc::new(AtomicI64::new(0));
+        let dispatcher = counting_dispatcher(total.clone());
+        let legacy = json!({
+            "event_type": "stock.changed",
+            "data": { "quantity": 3 },
+            "timestamp": chrono::Utc::now(),
+        });
+
+        let outcome = dispatcher
+            .dispatch(&serde_json::to_vec(&legacy).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome, DispatchOutcome::Handled);
+        assert_eq!(total.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_runs_handler_in_event_correlation_scope() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorder = seen.clone();
+        let dispatcher = EventDispatcher::new().register(
+            "stock.changed",
+            1,
+            move |event: EventEnvelope<StockChanged>| {
+                let recorder = recorder.clone();
+                async move {
+                    recorder
+                        .lock()
+                        .unwrap()
+                        .push((event.correlation_id, current_correlation_id()));
+                    Ok(())
+                }
+            },
+        );
+
+        // Enqueued while handling request "req-42"
+        let request_payload = with_correlation_id("req-42".to_string(), async {
+            payload(1, json!({ "quantity": 1 }))
+        })
+        .await;
+        dispatcher.dispatch(&request_payload).await.unwrap();
+
+        // Published outside any request
+        dispatcher
+            .dispatch(&payload(1, json!({ "quantity": 1 })))
+            .await
+            .unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(
+            *seen,
+            vec![
+                (Some("req-42".to_string()), Some("req-42".to_string())),
+                (None, None),
+            ]
+        );
+    }
+}
@@ -1,21 +1,62 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::correlation::current_correlation_id;
+
+/// Schema version of every event payload defined in this crate
+///
+/// Bump it for an event type when its payload changes incompatibly, and keep
+/// consumers registered for the old version until producers have migrated.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+fn default_schema_version() -> u32 {
+    CURRENT_SCHEMA_VERSION
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventEnvelope<T> {
     pub event_type: String,
+    /// Payload schema version; envelopes published before versioning count as v1
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     pub data: T,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Id of the request that caused the event, for cross-service tracing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
 }
 
 impl<T> EventEnvelope<T> {
+    /// Create an envelope carrying the correlation id of the current scope
     pub fn new(event_type: &str, data: T) -> Self {
         Self {
             event_type: event_type.to_string(),
+            schema_version: CURRENT_SCHEMA_VERSION,
             data,
             timestamp: chrono::Utc::now(),
+            correlation_id: current_correlation_id(),
         }
     }
+
+    pub fn with_schema_version(mut self, schema_version: u32) -> Self {
+        self.schema_version = schema_version;
+        self
+    }
+
+    pub fn with_correlation_id(mut self, correlation_id: Option<String>) -> Self {
+        self.correlation_id = correlation_id;
+        self
+    }
+}
+
+/// Envelope fields needed to route an event without parsing its payload
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EventHeader {
+    pub event_type: String,
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub correlation_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +69,15 @@ pub struct OrderConfirmedEvent {
     pub notes: Option<String>,
 }
 
+/// An order was cancelled upstream; its stock reservations should be released
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderCancelledEvent {
+    pub order_id: Uuid,
+    pub tenant_id: Uuid,
+    pub reason: Option<String>,
+    pub cancelled_at: chrono::DateTime<chrono::Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderItem {
     pub product_id: Uuid,
@@ -1,6 +1,10 @@
+pub mod correlation;
+pub mod dispatch;
 pub mod events;
 pub mod nats;
 
+pub use correlation::*;
+pub use dispatch::*;
 pub use events::*;
 pub use nats::*;
 
@@ -49,6 +49,12 @@ impl NatsClient {
     }
 }
 
+impl From<async_nats::Client> for NatsClient {
+    fn from(client: async_nats::Client) -> Self {
+        Self { client }
+    }
+}
+
 static NATS_CLIENT: once_cell::sync::OnceCell<NatsClient> = once_cell::sync::OnceCell::new();
 
 pub async fn init_nats_client(url: &str) -> Result<(), AppError> {
@@ -0,0 +1,186 @@
+//! CORS layer construction
+//!
+//! Builds the service CORS policy from `CORS_ORIGINS`, `CORS_ALLOWED_METHODS`,
+//! `CORS_ALLOWED_HEADERS` and `CORS_MAX_AGE_SECONDS`.
+
+use std::time::Duration;
+
+use axum::http::{HeaderName, HeaderValue, Method};
+use shared_config::Config;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// Build the CORS layer for a service
+///
+/// - No configured origins: any origin is allowed and credentials are disabled
+///   (development setup).
+/// - Explicit origins: only those origins are allowed, with credentials so
+///   cookie auth works.
+///
+/// # Panics
+/// On invalid configuration, so a misconfigured service fails at startup:
+/// a wildcard `*` among explicit origins (browsers reject wildcard origins
+/// with credentials), or an unparsable origin, method or header.
+pub fn build_cors(config: &Config) -> CorsLayer {
+    let methods: Vec<Method> = config
+        .get_cors_allowed_methods()
+        .iter()
+        .map(|method| {
+            Method::from_bytes(method.to_ascii_uppercase().as_bytes()).unwrap_or_else(|e| {
+                panic!("CORS configuration error: invalid method '{}': {}", method, e)
+            })
+        })
+        .collect();
+
+    let headers: Vec<HeaderName> = config
+        .get_cors_allowed_headers()
+        .iter()
+        .map(|header| {
+            HeaderName::from_bytes(header.to_ascii_lowercase().as_bytes()).unwrap_or_else(|e| {
+                panic!("CORS configuration error: invalid header '{}': {}", header, e)
+            })
+        })
+        .collect();
+
+    let mut cors = CorsLayer::new()
+        .allow_methods(methods)
+        .allow_headers(headers);
+
+    if config.cors_max_age_seconds > 0 {
+        cors = cors.max_age(Duration::from_secs(config.cors_max_age_seconds));
+    }
+
+    let origins = config.get_cors_origins();
+    if origins.is_empty() {
+        // Explicitly disable credentials for wildcard origins
+        return cors
+            .allow_origin(AllowOrigin::any())
+            .allow_credentials(false);
+    }
+
+    if origins.iter().any(|o| o == "*") {
+        panic!(
+            "CORS configuration error: wildcard origin '*' cannot be used with credentials. \
+             Either remove '*' and specify exact origins, or leave CORS_ORIGINS empty for development."
+        );
+    }
+
+    let origins: Vec<HeaderValue> = origins
+        .iter()
+        .map(|origin| {
+            HeaderValue::from_str(origin).unwrap_or_else(|e| {
+                panic!("CORS configuration error: invalid origin '{}': {}", origin, e)
+            })
+        })
+        .collect();
+
+    // Only allow credentials when specific origins are configured
+    cors.allow_origin(AllowOrigin::list(origins))
+        .allow_credentials(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn preflight(config: &Config, origin: &str) -> axum::http::Response<Body> {
+        let app = Router::new()
+            .route("/items", get(|| async { "ok" }))
+            .layer(build_cors(config));
+
+        app.oneshot(
+            Request::builder()
+                .method(Method::OPTIONS)
+                .uri("/items")
+                .header(header::ORIGIN, origin)
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "PATCH")
+                .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "x-idempotency-key")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn header_str(response: &axum::http::Response<Body>, name: header::HeaderName) -> &str {
+        response
+            .headers()
+            .get(name)
+            .map(|v| v.to_str().unwrap())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_empty_origins_allow_any_without_credentials() {
+        let config = Config::default();
+
+        let response = preflight(&config, "https://anywhere.example.com").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(header_str(&response, header::ACCESS_CONTROL_ALLOW_ORIGIN), "*");
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS)
+            .is_none());
+        assert!(header_str(&response, header::ACCESS_CONTROL_ALLOW_METHODS).contains("PATCH"));
+        assert!(header_str(&response, header::ACCESS_CONTROL_ALLOW_HEADERS)
+            .contains("x-idempotency-key"));
+        assert_eq!(header_str(&response, header::ACCESS_CONTROL_MAX_AGE), "3600");
+    }
+
+    #[tokio::test]
+    async fn test_explicit_origins_allow_listed_with_credentials() {
+        let config = Config {
+            cors_origins: Some("https://app.example.com, https://admin.example.com".to_string()),
+            ..Default::default()
+        };
+
+        let response = preflight(&config, "https://admin.example.com").await;
+        assert_eq!(
+            header_str(&response, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            "https://admin.example.com"
+        );
+        assert_eq!(header_str(&response, header::ACCESS_CONTROL_ALLOW_CREDENTIALS), "true");
+
+        let response = preflight(&config, "https://evil.example.com").await;
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_configured_methods_headers_and_max_age() {
+        let config = Config {
+            cors_allowed_methods: "get, post".to_string(),
+            cors_allowed_headers: "Content-Type".to_string(),
+            cors_max_age_seconds: 0,
+            ..Default::default()
+        };
+
+        let response = preflight(&config, "https://app.example.com").await;
+
+        let methods = header_str(&response, header::ACCESS_CONTROL_ALLOW_METHODS);
+        assert!(methods.contains("GET") && methods.contains("POST"));
+        assert!(!methods.contains("PATCH"));
+        assert_eq!(header_str(&response, header::ACCESS_CONTROL_ALLOW_HEADERS), "content-type");
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_MAX_AGE)
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "wildcard origin")]
+    fn test_wildcard_among_explicit_origins_panics() {
+        let config = Config {
+            cors_origins: Some("https://app.example.com,*".to_string()),
+            ..Default::default()
+        };
+        let _ = build_cors(&config);
+    }
+}
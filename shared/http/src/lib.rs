@@ -0,0 +1,10 @@
+//! Shared HTTP building blocks for Anthill services
+//!
+//! Layers that every service applies the same way live here so their
+//! behavior cannot drift between services.
+//!
+//! - [`build_cors`]: CORS layer built from the shared [`Config`](shared_config::Config)
+
+pub mod cors;
+
+pub use cors::build_cors;
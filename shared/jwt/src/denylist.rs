@@ -0,0 +1,193 @@
+//! Revoked token tracking
+//!
+//! A denylist records the `jti` of tokens revoked before they expire, such as
+//! an access token whose user logged out. Entries only need to live as long as
+//! the token would still be accepted, which is until `exp` plus
+//! [`JWT_LEEWAY_SECS`](crate::JWT_LEEWAY_SECS), so each one expires then.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use shared_error::AppError;
+use shared_types::{SharedClock, SystemClock};
+use uuid::Uuid;
+
+use crate::JWT_LEEWAY_SECS;
+
+/// Storage for revoked token ids
+#[async_trait]
+pub trait TokenDenylist: Send + Sync {
+    /// Check whether the token with this `jti` has been revoked
+    async fn is_revoked(&self, jti: Uuid) -> Result<bool, AppError>;
+
+    /// Revoke the token with this `jti` until `expires_at` (Unix timestamp)
+    ///
+    /// Tokens that have already expired need no entry and are ignored.
+    async fn revoke(&self, jti: Uuid, expires_at: i64) -> Result<(), AppError>;
+}
+
+/// Seconds a token expiring at `expires_at` is still accepted after `now`,
+/// or `None` if the decoder already rejects it
+fn remaining_lifetime(expires_at: i64, now: i64) -> Option<u64> {
+    let remaining = expires_at + JWT_LEEWAY_SECS - now;
+    (remaining > 0).then_some(remaining as u64)
+}
+
+/// In-memory denylist for tests and single-instance deployments
+pub struct InMemoryTokenDenylist {
+    /// Revoked jti -> last second the token is accepted (Unix timestamp)
+    revoked: Mutex<HashMap<Uuid, i64>>,
+    clock: SharedClock,
+}
+
+impl Default for InMemoryTokenDenylist {
+    fn default() -> Self {
+        Self::with_clock(SystemClock::shared())
+    }
+}
+
+impl InMemoryTokenDenylist {
+    /// Create an empty denylist
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a denylist that reads the time from `clock`, which should be
+    /// the clock tokens are decoded against
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            revoked: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl TokenDenylist for InMemoryTokenDenylist {
+    async fn is_revoked(&self, jti: Uuid) -> Result<bool, AppError> {
+        let now = self.clock.now().timestamp();
+        let mut revoked = self.revoked.lock().unwrap_or_else(|e| e.into_inner());
+        revoked.retain(|_, accepted_until| *accepted_until >= now);
+        Ok(revoked.contains_key(&jti))
+    }
+
+    async fn revoke(&self, jti: Uuid, expires_at: i64) -> Result<(), AppError> {
+        if remaining_lifetime(expires_at, self.clock.now().timestamp()).is_some() {
+            self.revoked
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(jti, expires_at + JWT_LEEWAY_SECS);
+        }
+        Ok(())
+    }
+}
+
+/// Redis denylist, shared across instances
+///
+/// Each revoked `jti` is a key whose TTL is the token's remaining lifetime,
+/// so Redis drops it once the token could no longer be used anyway.
+#[derive(Clone)]
+pub struct RedisTokenDenylist {
+    connection: ConnectionManager,
+    key_prefix: String,
+    clock: SharedClock,
+}
+
+impl RedisTokenDenylist {
+    /// Create a new Redis denylist
+    pub async fn new(redis_url: &str) -> Result<Self, AppError> {
+        Self::with_prefix(redis_url, "jwt:revoked").await
+    }
+
+    /// Create with custom key prefix
+    pub async fn with_prefix(redis_url: &str, prefix: &str) -> Result<Self, AppError> {
+        let client = redis::Client::open(redis_url)
+            .map_err(|e| AppError::InternalError(format!("Invalid Redis URL: {}", e)))?;
+
+        let connection = ConnectionManager::new(client).await.map_err(|e| {
+            AppError::ServiceUnavailable(format!("Failed to connect to Redis: {}", e))
+        })?;
+
+        Ok(Self {
+            connection,
+            key_prefix: prefix.to_string(),
+            clock: SystemClock::shared(),
+        })
+    }
+
+    /// Compute entry TTLs against `clock`, which should be the clock tokens
+    /// are decoded against
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    fn key(&self, jti: Uuid) -> String {
+        format!("{}:{}", self.key_prefix, jti)
+    }
+}
+
+#[async_trait]
+impl TokenDenylist for RedisTokenDenylist {
+    async fn is_revoked(&self, jti: Uuid) -> Result<bool, AppError> {
+        let mut conn = self.connection.clone();
+        conn.exists(self.key(jti)).await.map_err(|e| {
+            AppError::ServiceUnavailable(format!("Failed to check token denylist: {}", e))
+        })
+    }
+
+    async fn revoke(&self, jti: Uuid, expires_at: i64) -> Result<(), AppError> {
+        let Some(ttl) = remaining_lifetime(expires_at, self.clock.now().timestamp()) else {
+            return Ok(());
+        };
+
+        let mut conn = self.connection.clone();
+        conn.set_ex::<_, _, ()>(self.key(jti), 1, ttl)
+            .await
+            .map_err(|e| AppError::ServiceUnavailable(format!("Failed to revoke token: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_denylist_revokes_until_expiry() {
+        let denylist = InMemoryTokenDenylist::new();
+        let now = chrono::Utc::now().timestamp();
+        let live = Uuid::now_v7();
+        let expired = Uuid::now_v7();
+
+        denylist.revoke(live, now + 900).await.unwrap();
+        denylist
+            .revoke(expired, now - JWT_LEEWAY_SECS - 1)
+            .await
+            .unwrap();
+
+        assert!(denylist.is_revoked(live).await.unwrap());
+        assert!(!denylist.is_revoked(expired).await.unwrap());
+        assert!(!denylist.is_revoked(Uuid::now_v7()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_denylist_outlives_expiry_by_leeway() {
+        let start = chrono::Utc::now();
+        let clock = shared_types::MockClock::new(start);
+        let denylist = InMemoryTokenDenylist::with_clock(std::sync::Arc::new(clock.clone()));
+        let jti = Uuid::now_v7();
+
+        // Expired, but the decoder still accepts it, so it is still recorded
+        denylist
+            .revoke(jti, start.timestamp() - JWT_LEEWAY_SECS / 2)
+            .await
+            .unwrap();
+        assert!(denylist.is_revoked(jti).await.unwrap());
+
+        clock.advance(chrono::Duration::seconds(JWT_LEEWAY_SECS / 2 + 1));
+        assert!(!denylist.is_revoked(jti).await.unwrap());
+    }
+}
@@ -1,8 +1,72 @@
-use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use std::collections::HashMap;
+
+use jsonwebtoken::errors::{Error as JwtError, ErrorKind};
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
 use serde::{Deserialize, Serialize};
 use shared_error::AppError;
+use shared_types::{Clock, SystemClock};
 use uuid::Uuid;
 
+mod denylist;
+
+pub use denylist::{InMemoryTokenDenylist, RedisTokenDenylist, TokenDenylist};
+
+/// Key id stamped on tokens signed with the single configured secret
+///
+/// Tokens issued before key ids existed carry no `kid` and are verified
+/// with the key registered under this id.
+pub const DEFAULT_KEY_ID: &str = "default";
+
+/// Seconds of clock skew tolerated when checking `exp` and `nbf`
+pub const JWT_LEEWAY_SECS: i64 = 60;
+
+/// Signing algorithm for JWT tokens
+///
+/// Tokens issued by this platform use HS256 with a shared secret. RS256 and
+/// EdDSA verify tokens from external identity providers with their public
+/// key (PEM encoded).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JwtAlgorithm {
+    /// HMAC with SHA-256 and a shared secret
+    Hs256,
+    /// RSA PKCS#1 v1.5 with SHA-256
+    Rs256,
+    /// Ed25519
+    EdDsa,
+}
+
+impl JwtAlgorithm {
+    fn algorithm(self) -> Algorithm {
+        match self {
+            Self::Hs256 => Algorithm::HS256,
+            Self::Rs256 => Algorithm::RS256,
+            Self::EdDsa => Algorithm::EdDSA,
+        }
+    }
+
+    /// Build a signing key: the secret for HS256, a PEM private key otherwise
+    pub fn encoding_key(self, key: &[u8]) -> Result<EncodingKey, AppError> {
+        match self {
+            Self::Hs256 => Ok(EncodingKey::from_secret(key)),
+            Self::Rs256 => EncodingKey::from_rsa_pem(key),
+            Self::EdDsa => EncodingKey::from_ed_pem(key),
+        }
+        .map_err(|e| AppError::InternalError(format!("Invalid JWT signing key: {}", e)))
+    }
+
+    /// Build a verification key: the secret for HS256, a PEM public key otherwise
+    pub fn decoding_key(self, key: &[u8]) -> Result<DecodingKey, AppError> {
+        match self {
+            Self::Hs256 => Ok(DecodingKey::from_secret(key)),
+            Self::Rs256 => DecodingKey::from_rsa_pem(key),
+            Self::EdDsa => DecodingKey::from_ed_pem(key),
+        }
+        .map_err(|e| AppError::InternalError(format!("Invalid JWT verification key: {}", e)))
+    }
+}
+
 /// JWT Claims for access and refresh tokens
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
@@ -33,6 +97,16 @@ pub struct Claims {
     /// Used for immediate-effect permission invalidation
     #[serde(default = "default_version")]
     pub user_v: i64,
+
+    /// Token ID, unique per issued token
+    /// Tokens issued before this claim existed decode with the nil UUID
+    #[serde(default)]
+    pub jti: Uuid,
+
+    /// Not before (Unix timestamp)
+    /// Tokens issued before this claim existed decode with 0
+    #[serde(default)]
+    pub nbf: i64,
 }
 
 /// Default version for backward compatibility with existing tokens
@@ -53,6 +127,8 @@ impl Claims {
             token_type: "access".to_string(),
             tenant_v: 0,
             user_v: 0,
+            jti: Uuid::now_v7(),
+            nbf: now,
         }
     }
 
@@ -75,6 +151,8 @@ impl Claims {
             token_type: "access".to_string(),
             tenant_v,
             user_v,
+            jti: Uuid::now_v7(),
+            nbf: now,
         }
     }
 
@@ -90,6 +168,8 @@ impl Claims {
             token_type: "refresh".to_string(),
             tenant_v: 0,
             user_v: 0,
+            jti: Uuid::now_v7(),
+            nbf: now,
         }
     }
 
@@ -112,9 +192,22 @@ impl Claims {
             token_type: "refresh".to_string(),
             tenant_v,
             user_v,
+            jti: Uuid::now_v7(),
+            nbf: now,
         }
     }
 
+    /// Stamp the claims as issued at `now` (Unix timestamp), keeping their lifetime
+    ///
+    /// Lets callers with an injected clock set `iat`, `nbf` and `exp` from it.
+    pub fn issued_at(mut self, now: i64) -> Self {
+        let lifetime = self.exp - self.iat;
+        self.iat = now;
+        self.nbf = now;
+        self.exp = now + lifetime;
+        self
+    }
+
     /// Check if this token has authorization versions set
     /// Tokens without versions (legacy) should skip version validation
     pub fn has_authz_versions(&self) -> bool {
@@ -122,26 +215,203 @@ impl Claims {
     }
 }
 
-/// Encode claims into a JWT token
+/// Encode claims into a JWT token signed under [`DEFAULT_KEY_ID`]
 pub fn encode_jwt(claims: &Claims, secret: &str) -> Result<String, AppError> {
+    encode_jwt_with_kid(claims, DEFAULT_KEY_ID, secret)
+}
+
+/// Encode claims into a JWT token, recording `kid` in the header
+///
+/// The key id tells verifiers which secret signed the token, so a new key
+/// can be introduced while tokens signed with the old one stay valid.
+pub fn encode_jwt_with_kid(claims: &Claims, kid: &str, secret: &str) -> Result<String, AppError> {
     let key = EncodingKey::from_secret(secret.as_bytes());
-    encode(&Header::new(Algorithm::HS256), claims, &key)
+    encode_jwt_with_key(claims, Some(kid), JwtAlgorithm::Hs256, &key)
+}
+
+/// Encode claims into a JWT token with an explicit algorithm and key
+///
+/// Build `key` with [`JwtAlgorithm::encoding_key`]; it must match `algorithm`.
+pub fn encode_jwt_with_key(
+    claims: &Claims,
+    kid: Option<&str>,
+    algorithm: JwtAlgorithm,
+    key: &EncodingKey,
+) -> Result<String, AppError> {
+    let mut header = Header::new(algorithm.algorithm());
+    header.kid = kid.map(str::to_string);
+    encode(&header, claims, key)
         .map_err(|e| AppError::InternalError(format!("Failed to encode JWT: {}", e)))
 }
 
-/// Decode and validate a JWT token
-pub fn decode_jwt(token: &str, secret: &str) -> Result<Claims, AppError> {
+/// Decode and validate a JWT token against a set of signing keys
+///
+/// `keys` maps key id to secret. The token's `kid` header selects the secret;
+/// tokens without one use [`DEFAULT_KEY_ID`].
+pub fn decode_jwt(token: &str, keys: &HashMap<String, String>) -> Result<Claims, AppError> {
+    decode_jwt_at(token, keys, &SystemClock)
+}
+
+/// [`decode_jwt`], checking `exp` and `nbf` against `clock` instead of the wall clock
+pub fn decode_jwt_at(
+    token: &str,
+    keys: &HashMap<String, String>,
+    clock: &dyn Clock,
+) -> Result<Claims, AppError> {
+    let header = decode_header(token)
+        .map_err(|e| AppError::Unauthorized(format!("Invalid token: {}", e)))?;
+    let kid = header.kid.as_deref().unwrap_or(DEFAULT_KEY_ID);
+    let secret = keys
+        .get(kid)
+        .ok_or_else(|| AppError::Unauthorized(format!("Unknown signing key id: {}", kid)))?;
+
     let key = DecodingKey::from_secret(secret.as_bytes());
-    let validation = Validation::new(Algorithm::HS256);
+    decode_jwt_with_key_at(token, JwtAlgorithm::Hs256, &key, clock)
+}
+
+/// Decode and validate a JWT token with an explicit algorithm and key
+///
+/// Only `algorithm` is accepted, so a token cannot choose a weaker one
+/// through its header.
+pub fn decode_jwt_with_key(
+    token: &str,
+    algorithm: JwtAlgorithm,
+    key: &DecodingKey,
+) -> Result<Claims, AppError> {
+    decode_jwt_with_key_at(token, algorithm, key, &SystemClock)
+}
 
-    decode::<Claims>(token, &key, &validation)
+/// [`decode_jwt_with_key`], checking `exp` and `nbf` against `clock`
+///
+/// Both are allowed [`JWT_LEEWAY_SECS`] of skew, the same as jsonwebtoken's
+/// own checks, which only read the wall clock and are disabled here.
+pub fn decode_jwt_with_key_at(
+    token: &str,
+    algorithm: JwtAlgorithm,
+    key: &DecodingKey,
+    clock: &dyn Clock,
+) -> Result<Claims, AppError> {
+    let invalid = |e: JwtError| AppError::Unauthorized(format!("Invalid token: {}", e));
+
+    // `exp` stays a required claim; only its time check moves to the clock below
+    let mut validation = Validation::new(algorithm.algorithm());
+    validation.validate_exp = false;
+    validation.validate_nbf = false;
+
+    let claims = decode::<Claims>(token, key, &validation)
         .map(|data| data.claims)
-        .map_err(|e| AppError::Unauthorized(format!("Invalid token: {}", e)))
+        .map_err(invalid)?;
+
+    let now = clock.now().timestamp();
+    if claims.exp < now - JWT_LEEWAY_SECS {
+        return Err(invalid(ErrorKind::ExpiredSignature.into()));
+    }
+    if claims.nbf > now + JWT_LEEWAY_SECS {
+        return Err(invalid(ErrorKind::ImmatureSignature.into()));
+    }
+    Ok(claims)
+}
+
+/// Decode and validate a JWT token signed with the single configured secret
+///
+/// Registers `secret` under [`DEFAULT_KEY_ID`] and delegates to [`decode_jwt`].
+pub fn decode_jwt_single(token: &str, secret: &str) -> Result<Claims, AppError> {
+    decode_jwt_single_at(token, secret, &SystemClock)
+}
+
+/// [`decode_jwt_single`], checking `exp` and `nbf` against `clock`
+pub fn decode_jwt_single_at(
+    token: &str,
+    secret: &str,
+    clock: &dyn Clock,
+) -> Result<Claims, AppError> {
+    let keys = HashMap::from([(DEFAULT_KEY_ID.to_string(), secret.to_string())]);
+    decode_jwt_at(token, &keys, clock)
+}
+
+/// Decode a token signed with `secret` and reject it if it has been revoked
+///
+/// Tokens issued before `jti` existed carry the nil id and cannot be revoked
+/// individually; they are accepted until they expire.
+pub async fn decode_jwt_checked(
+    token: &str,
+    secret: &str,
+    denylist: &dyn TokenDenylist,
+) -> Result<Claims, AppError> {
+    let claims = decode_jwt_single(token, secret)?;
+    ensure_not_revoked(&claims, denylist).await?;
+    Ok(claims)
+}
+
+/// Decode an access token and reject it if it has been revoked
+///
+/// The checked counterpart of [`decode_access`], used for API authentication.
+pub async fn decode_access_checked(
+    token: &str,
+    secret: &str,
+    denylist: &dyn TokenDenylist,
+) -> Result<Claims, AppError> {
+    decode_access_checked_at(token, secret, denylist, &SystemClock).await
+}
+
+/// [`decode_access_checked`], checking `exp` and `nbf` against `clock`
+///
+/// `denylist` should read the same clock, so its entries last exactly as long
+/// as the decoder would otherwise accept the token.
+pub async fn decode_access_checked_at(
+    token: &str,
+    secret: &str,
+    denylist: &dyn TokenDenylist,
+    clock: &dyn Clock,
+) -> Result<Claims, AppError> {
+    let claims = decode_with_type(token, secret, "access", clock)?;
+    ensure_not_revoked(&claims, denylist).await?;
+    Ok(claims)
+}
+
+async fn ensure_not_revoked(claims: &Claims, denylist: &dyn TokenDenylist) -> Result<(), AppError> {
+    if !claims.jti.is_nil() && denylist.is_revoked(claims.jti).await? {
+        return Err(AppError::Unauthorized("Token has been revoked".to_string()));
+    }
+    Ok(())
+}
+
+/// Decode a token and check that it is an access token
+///
+/// Use this for API authentication so refresh tokens cannot stand in for access tokens.
+pub fn decode_access(token: &str, secret: &str) -> Result<Claims, AppError> {
+    decode_with_type(token, secret, "access", &SystemClock)
+}
+
+/// Decode a token and check that it is a refresh token
+///
+/// Use this at the refresh endpoint so access tokens cannot be exchanged for new tokens.
+pub fn decode_refresh(token: &str, secret: &str) -> Result<Claims, AppError> {
+    decode_refresh_at(token, secret, &SystemClock)
+}
+
+/// [`decode_refresh`], checking `exp` and `nbf` against `clock`
+pub fn decode_refresh_at(token: &str, secret: &str, clock: &dyn Clock) -> Result<Claims, AppError> {
+    decode_with_type(token, secret, "refresh", clock)
+}
+
+fn decode_with_type(
+    token: &str,
+    secret: &str,
+    expected: &str,
+    clock: &dyn Clock,
+) -> Result<Claims, AppError> {
+    let claims = decode_jwt_single_at(token, secret, clock)?;
+    if claims.token_type != expected {
+        return Err(AppError::InvalidToken);
+    }
+    Ok(claims)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use shared_types::MockClock;
 
     #[test]
     fn test_jwt_encode_decode() {
@@ -152,10 +422,296 @@ mod tests {
         let claims = Claims::new_access(user_id, tenant_id, "admin".to_string(), 3600);
         let token = encode_jwt(&claims, secret).unwrap();
 
-        let decoded = decode_jwt(&token, secret).unwrap();
+        let decoded = decode_jwt_single(&token, secret).unwrap();
         assert_eq!(decoded.sub, user_id);
         assert_eq!(decoded.tenant_id, tenant_id);
         assert_eq!(decoded.role, "admin");
         assert_eq!(decoded.token_type, "access");
     }
+
+    #[test]
+    fn test_decode_jwt_selects_key_by_kid() {
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let old_token = encode_jwt_with_kid(&claims, "2025-01", "old_secret").unwrap();
+        let new_token = encode_jwt_with_kid(&claims, "2025-06", "new_secret").unwrap();
+
+        // During the overlap window both keys are accepted
+        let keys = HashMap::from([
+            ("2025-01".to_string(), "old_secret".to_string()),
+            ("2025-06".to_string(), "new_secret".to_string()),
+        ]);
+        assert_eq!(decode_jwt(&old_token, &keys).unwrap().sub, claims.sub);
+        assert_eq!(decode_jwt(&new_token, &keys).unwrap().sub, claims.sub);
+
+        // Once the old key is retired its tokens are rejected
+        let keys = HashMap::from([("2025-06".to_string(), "new_secret".to_string())]);
+        assert!(matches!(decode_jwt(&old_token, &keys), Err(AppError::Unauthorized(_))));
+    }
+
+    #[test]
+    fn test_token_without_kid_uses_default_key() {
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let legacy_token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(b"test_secret"),
+        )
+        .unwrap();
+
+        assert_eq!(decode_jwt_single(&legacy_token, "test_secret").unwrap().sub, claims.sub);
+        assert_eq!(
+            decode_header(&encode_jwt(&claims, "test_secret").unwrap())
+                .unwrap()
+                .kid
+                .as_deref(),
+            Some(DEFAULT_KEY_ID)
+        );
+    }
+
+    const RS256_PRIVATE_KEY: &str = include_str!("testdata/rs256_private.pem");
+    const RS256_PUBLIC_KEY: &str = include_str!("testdata/rs256_public.pem");
+    const ED25519_PRIVATE_KEY: &str = include_str!("testdata/ed25519_private.pem");
+    const ED25519_PUBLIC_KEY: &str = include_str!("testdata/ed25519_public.pem");
+
+    #[test]
+    fn test_rs256_encode_decode() {
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let signing_key = JwtAlgorithm::Rs256
+            .encoding_key(RS256_PRIVATE_KEY.as_bytes())
+            .unwrap();
+        let verifying_key = JwtAlgorithm::Rs256
+            .decoding_key(RS256_PUBLIC_KEY.as_bytes())
+            .unwrap();
+
+        let token =
+            encode_jwt_with_key(&claims, Some("idp-1"), JwtAlgorithm::Rs256, &signing_key).unwrap();
+        assert_eq!(decode_header(&token).unwrap().alg, Algorithm::RS256);
+
+        let decoded = decode_jwt_with_key(&token, JwtAlgorithm::Rs256, &verifying_key).unwrap();
+        assert_eq!(decoded.sub, claims.sub);
+        assert_eq!(decoded.tenant_id, claims.tenant_id);
+    }
+
+    #[test]
+    fn test_rs256_token_rejected_for_other_algorithm() {
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let signing_key = JwtAlgorithm::Rs256
+            .encoding_key(RS256_PRIVATE_KEY.as_bytes())
+            .unwrap();
+        let token = encode_jwt_with_key(&claims, None, JwtAlgorithm::Rs256, &signing_key).unwrap();
+
+        // The public key used as an HMAC secret must not verify the token
+        let hmac_key = JwtAlgorithm::Hs256
+            .decoding_key(RS256_PUBLIC_KEY.as_bytes())
+            .unwrap();
+        assert!(matches!(
+            decode_jwt_with_key(&token, JwtAlgorithm::Hs256, &hmac_key),
+            Err(AppError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_eddsa_encode_decode() {
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let signing_key = JwtAlgorithm::EdDsa
+            .encoding_key(ED25519_PRIVATE_KEY.as_bytes())
+            .unwrap();
+        let verifying_key = JwtAlgorithm::EdDsa
+            .decoding_key(ED25519_PUBLIC_KEY.as_bytes())
+            .unwrap();
+
+        let token = encode_jwt_with_key(&claims, None, JwtAlgorithm::EdDsa, &signing_key).unwrap();
+        let decoded = decode_jwt_with_key(&token, JwtAlgorithm::EdDsa, &verifying_key).unwrap();
+        assert_eq!(decoded.sub, claims.sub);
+    }
+
+    #[test]
+    fn test_invalid_pem_is_rejected() {
+        assert!(matches!(
+            JwtAlgorithm::Rs256.decoding_key(b"not a pem"),
+            Err(AppError::InternalError(_))
+        ));
+    }
+
+    #[test]
+    fn test_issued_at_keeps_lifetime() {
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 900)
+            .issued_at(1_700_000_000);
+
+        assert_eq!(claims.iat, 1_700_000_000);
+        assert_eq!(claims.nbf, 1_700_000_000);
+        assert_eq!(claims.exp, 1_700_000_900);
+    }
+
+    #[test]
+    fn test_constructors_set_unique_jti_and_nbf() {
+        let user_id = Uuid::new_v4();
+        let tenant_id = Uuid::new_v4();
+        let access = Claims::new_access(user_id, tenant_id, "user".to_string(), 900);
+        let refresh = Claims::new_refresh(user_id, tenant_id, "user".to_string(), 900);
+
+        assert_ne!(access.jti, refresh.jti);
+        assert!(!access.jti.is_nil());
+        assert_eq!(access.nbf, access.iat);
+        assert_eq!(refresh.nbf, refresh.iat);
+    }
+
+    #[test]
+    fn test_token_before_nbf_is_rejected() {
+        let mut claims =
+            Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        claims.nbf = claims.iat + 600;
+        let token = encode_jwt(&claims, "test_secret").unwrap();
+
+        assert!(matches!(
+            decode_jwt_single(&token, "test_secret"),
+            Err(AppError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_expiry_and_nbf_follow_injected_clock() {
+        let issued = chrono::Utc::now() - chrono::Duration::days(30);
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 900)
+            .issued_at(issued.timestamp());
+        let token = encode_jwt(&claims, "test_secret").unwrap();
+
+        // Long expired by the wall clock, but valid at the frozen time
+        let clock = MockClock::new(issued);
+        assert!(decode_jwt_single(&token, "test_secret").is_err());
+        assert!(decode_jwt_single_at(&token, "test_secret", &clock).is_ok());
+
+        // Accepted up to the leeway past `exp`, rejected one second later
+        clock.set(issued + chrono::Duration::seconds(900 + JWT_LEEWAY_SECS));
+        assert!(decode_jwt_single_at(&token, "test_secret", &clock).is_ok());
+        clock.advance(chrono::Duration::seconds(1));
+        assert!(matches!(
+            decode_jwt_single_at(&token, "test_secret", &clock),
+            Err(AppError::Unauthorized(_))
+        ));
+
+        // Likewise before `nbf`
+        clock.set(issued - chrono::Duration::seconds(JWT_LEEWAY_SECS));
+        assert!(decode_jwt_single_at(&token, "test_secret", &clock).is_ok());
+        clock.advance(chrono::Duration::seconds(-1));
+        assert!(matches!(
+            decode_jwt_single_at(&token, "test_secret", &clock),
+            Err(AppError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_claims_without_jti_and_nbf_still_decode() {
+        #[derive(Serialize)]
+        struct LegacyClaims {
+            sub: Uuid,
+            tenant_id: Uuid,
+            role: String,
+            iat: i64,
+            exp: i64,
+            token_type: String,
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let legacy = LegacyClaims {
+            sub: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            role: "user".to_string(),
+            iat: now,
+            exp: now + 3600,
+            token_type: "access".to_string(),
+        };
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &legacy,
+            &EncodingKey::from_secret(b"test_secret"),
+        )
+        .unwrap();
+
+        let decoded = decode_jwt_single(&token, "test_secret").unwrap();
+        assert_eq!(decoded.sub, legacy.sub);
+        assert!(decoded.jti.is_nil());
+        assert_eq!(decoded.nbf, 0);
+    }
+
+    #[test]
+    fn test_decode_access_rejects_refresh_token() {
+        let secret = "test_secret";
+        let claims = Claims::new_refresh(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let token = encode_jwt(&claims, secret).unwrap();
+
+        assert!(matches!(decode_access(&token, secret), Err(AppError::InvalidToken)));
+        assert_eq!(decode_refresh(&token, secret).unwrap().token_type, "refresh");
+    }
+
+    #[test]
+    fn test_decode_refresh_rejects_access_token() {
+        let secret = "test_secret";
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let token = encode_jwt(&claims, secret).unwrap();
+
+        assert!(matches!(decode_refresh(&token, secret), Err(AppError::InvalidToken)));
+        assert_eq!(decode_access(&token, secret).unwrap().token_type, "access");
+    }
+
+    #[tokio::test]
+    async fn test_decode_jwt_checked_rejects_revoked_token() {
+        let denylist = InMemoryTokenDenylist::new();
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let token = encode_jwt(&claims, "test_secret").unwrap();
+
+        let decoded = decode_jwt_checked(&token, "test_secret", &denylist)
+            .await
+            .unwrap();
+        assert_eq!(decoded.jti, claims.jti);
+
+        denylist.revoke(claims.jti, claims.exp).await.unwrap();
+        assert!(matches!(
+            decode_jwt_checked(&token, "test_secret", &denylist).await,
+            Err(AppError::Unauthorized(_))
+        ));
+
+        // Other tokens of the same user are unaffected
+        let other = Claims::new_access(claims.sub, claims.tenant_id, "user".to_string(), 3600);
+        let other_token = encode_jwt(&other, "test_secret").unwrap();
+        assert!(decode_jwt_checked(&other_token, "test_secret", &denylist)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_revoked_token_stays_rejected_through_leeway() {
+        let issued = chrono::Utc::now();
+        let clock = MockClock::new(issued);
+        let denylist = InMemoryTokenDenylist::with_clock(std::sync::Arc::new(clock.clone()));
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 900)
+            .issued_at(issued.timestamp());
+        let token = encode_jwt(&claims, "test_secret").unwrap();
+
+        denylist.revoke(claims.jti, claims.exp).await.unwrap();
+
+        // Past `exp` but within the leeway the decoder would accept the token,
+        // so the denylist entry must still be there
+        clock.set(issued + chrono::Duration::seconds(900 + 30));
+        assert!(decode_access_checked_at(
+            &token,
+            "test_secret",
+            &InMemoryTokenDenylist::new(),
+            &clock
+        )
+        .await
+        .is_ok());
+        assert!(matches!(
+            decode_access_checked_at(&token, "test_secret", &denylist, &clock).await,
+            Err(AppError::Unauthorized(msg)) if msg.contains("revoked")
+        ));
+    }
+
+    #[test]
+    fn test_decode_access_keeps_signature_errors() {
+        let claims = Claims::new_access(Uuid::new_v4(), Uuid::new_v4(), "user".to_string(), 3600);
+        let token = encode_jwt(&claims, "test_secret").unwrap();
+
+        assert!(matches!(decode_access(&token, "other_secret"), Err(AppError::Unauthorized(_))));
+    }
 }
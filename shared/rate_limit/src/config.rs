@@ -4,6 +4,71 @@ use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
 use std::str::FromStr;
 
+/// How exceeded limits are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Enforcement {
+    /// Reject requests over the limit with 429
+    #[default]
+    Enforce,
+    /// Dry run: allow every request but record and log the ones that would be blocked
+    Monitor,
+}
+
+/// How requests are counted against a limit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitStrategy {
+    /// Count requests in the trailing window ending now, so a limit can never
+    /// be exceeded by bursting on both sides of a window boundary
+    #[default]
+    SlidingWindow,
+    /// Count requests in clock-aligned windows that reset all at once. Cheaper
+    /// to reason about, but allows up to 2x the limit across a boundary
+    FixedWindow,
+}
+
+impl RateLimitStrategy {
+    /// Earliest timestamp (inclusive) that still counts towards the limit at `now`
+    ///
+    /// `now` and `window` must use the same unit.
+    pub fn window_start(&self, now: u64, window: u64) -> u64 {
+        match self {
+            Self::SlidingWindow => now.saturating_sub(window),
+            Self::FixedWindow if window == 0 => now,
+            Self::FixedWindow => now - now % window,
+        }
+    }
+
+    /// When a request denied at `now` can next be retried, in the unit of `now`
+    pub fn reset_at(&self, now: u64, window: u64) -> u64 {
+        match self {
+            Self::SlidingWindow => now + window,
+            Self::FixedWindow => self.window_start(now, window) + window,
+        }
+    }
+}
+
+/// What to do with a request when the limiter backend (e.g. Redis) fails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendErrorPolicy {
+    /// Let the request through unlimited, favouring availability
+    #[default]
+    FailOpen,
+    /// Reject the request with 503, for security-sensitive deployments
+    FailClosed,
+}
+
+impl BackendErrorPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FailOpen => "fail_open",
+            Self::FailClosed => "fail_closed",
+        }
+    }
+}
+
 /// Rate limit configuration for different endpoints
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimitConfig {
@@ -66,6 +131,18 @@ pub struct RateLimitConfig {
     #[serde(default = "default_enabled")]
     pub enabled: bool,
 
+    /// Counting algorithm used by the limiter backend (fixed at startup)
+    #[serde(default)]
+    pub strategy: RateLimitStrategy,
+
+    /// Enforce limits or only monitor how often they would trip
+    #[serde(default)]
+    pub enforcement: Enforcement,
+
+    /// Whether requests are allowed or rejected when the limiter backend errors
+    #[serde(default)]
+    pub on_backend_error: BackendErrorPolicy,
+
     /// Trusted IPs/CIDRs that bypass rate limiting (comma-separated, e.g., "127.0.0.1,10.0.0.0/8")
     #[serde(default)]
     pub trusted_ips: Option<String>,
@@ -161,6 +238,9 @@ impl Default for RateLimitConfig {
             lockout_duration_seconds: default_lockout_duration_seconds(),
             global_requests_per_second: default_global_requests_per_second(),
             enabled: default_enabled(),
+            strategy: RateLimitStrategy::default(),
+            enforcement: Enforcement::default(),
+            on_backend_error: BackendErrorPolicy::default(),
             trusted_ips: None,
             trust_proxy_headers: false,
             proxy_count: default_proxy_count(),
@@ -346,10 +426,24 @@ mod tests {
         assert_eq!(config.login_window_seconds, 900);
         assert_eq!(config.register_max_attempts, 3);
         assert!(config.enabled);
+        assert_eq!(config.strategy, RateLimitStrategy::SlidingWindow);
+        assert_eq!(config.enforcement, Enforcement::Enforce);
+        assert_eq!(config.on_backend_error, BackendErrorPolicy::FailOpen);
         assert!(!config.trust_proxy_headers);
         assert_eq!(config.proxy_count, 1);
     }
 
+    #[test]
+    fn test_fixed_window_aligns_to_clock() {
+        let fixed = RateLimitStrategy::FixedWindow;
+        assert_eq!(fixed.window_start(125, 60), 120);
+        assert_eq!(fixed.reset_at(125, 60), 180);
+
+        let sliding = RateLimitStrategy::SlidingWindow;
+        assert_eq!(sliding.window_start(125, 60), 65);
+        assert_eq!(sliding.reset_at(125, 60), 185);
+    }
+
     #[test]
     fn test_trusted_ips_exact() {
         let config = RateLimitConfig {
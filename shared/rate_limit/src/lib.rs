@@ -29,7 +29,9 @@
 //! ```rust,ignore
 //! use shared_rate_limit::AccountLockout;
 //!
-//! let lockout = AccountLockout::new(rate_limiter, 10, 3600);
+//! // Lockout state lives in its own store (Redis when configured), separate
+//! // from rate-limit windows
+//! let lockout = AccountLockout::from_config(&config).await;
 //!
 //! // Check if account is locked
 //! let status = lockout.check_lockout(user_id).await?;
@@ -50,18 +52,25 @@
 pub mod config;
 pub mod limiter;
 pub mod lockout;
+pub mod lockout_store;
 pub mod memory_limiter;
 pub mod middleware;
 pub mod redis_limiter;
 
 // Re-export main types
-pub use config::{EndpointRules, RateLimitConfig, RateLimitRule};
+pub use config::{
+    BackendErrorPolicy, EndpointRules, Enforcement, RateLimitConfig, RateLimitRule,
+    RateLimitStrategy,
+};
 pub use limiter::{KeyGenerator, RateLimitError, RateLimitResult, RateLimiter};
-pub use lockout::{AccountLockout, LockoutStatus};
+pub use lockout::{AccountLockout, BackoffSchedule, LockoutStatus};
+pub use lockout_store::{
+    InMemoryLockoutStore, LockoutStore, RateLimiterLockoutStore, RedisLockoutStore,
+};
 pub use memory_limiter::InMemoryRateLimiter;
 pub use middleware::{
-    RateLimitEndpoint, RateLimitExt, RateLimitLayer, RateLimitMiddleware, RateLimitState,
-    SharedRateLimiter,
+    RateLimitEndpoint, RateLimitExt, RateLimitKeyFn, RateLimitLayer, RateLimitMiddleware,
+    RateLimitState, SharedRateLimiter,
 };
 pub use redis_limiter::RedisRateLimiter;
 
@@ -100,6 +109,34 @@ mod tests {
         assert!(!result.allowed, "6th request should be denied");
     }
 
+    #[tokio::test]
+    async fn test_monitor_mode_allows_but_counts_would_block() {
+        let config = RateLimitConfig {
+            login_max_attempts: 5,
+            login_window_seconds: 60,
+            enforcement: Enforcement::Monitor,
+            ..Default::default()
+        };
+
+        let state = RateLimitState::from_config(config).await;
+        let ip = "192.168.1.101";
+        for _ in 0..5 {
+            state
+                .check_endpoint(RateLimitEndpoint::Login, ip)
+                .await
+                .unwrap();
+        }
+        assert_eq!(state.would_block_count(), 0);
+
+        // 6th request would be denied, but monitor mode lets it through
+        let result = state
+            .check_endpoint(RateLimitEndpoint::Login, ip)
+            .await
+            .unwrap();
+        assert!(result.allowed, "6th request should be allowed in monitor mode");
+        assert_eq!(state.would_block_count(), 1);
+    }
+
     #[tokio::test]
     async fn test_integration_different_endpoints() {
         let config = RateLimitConfig {
@@ -145,6 +182,7 @@ mod tests {
             state.limiter.clone(),
             3,  // Lock after 3 attempts
             60, // 60 second lockout
+            BackoffSchedule::default(),
         );
 
         let user_id = "test-user-lockout";
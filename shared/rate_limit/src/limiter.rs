@@ -123,6 +123,18 @@ impl KeyGenerator {
     pub fn user_key(prefix: &str, user_id: &str) -> String {
         format!("{}:{}", prefix, user_id)
     }
+
+    /// Generate a hashed key for a caller-supplied identifier
+    ///
+    /// Kept under a separate `custom` segment so it never shares a bucket with
+    /// the IP, email or user keys of the same endpoint.
+    pub fn custom_key(prefix: &str, key: &str) -> String {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        hasher.update(key.as_bytes());
+        let hash = hex::encode(hasher.finalize());
+        format!("{}:custom:{}", prefix, &hash[..16])
+    }
 }
 
 #[cfg(test)]
@@ -170,4 +182,11 @@ mod tests {
             KeyGenerator::user_key("rate_limit:refresh", "550e8400-e29b-41d4-a716-446655440000");
         assert_eq!(key, "rate_limit:refresh:550e8400-e29b-41d4-a716-446655440000");
     }
+
+    #[test]
+    fn test_key_generator_custom() {
+        let key = KeyGenerator::custom_key("rate_limit:global:ip", "tenant-a");
+        assert!(key.starts_with("rate_limit:global:ip:custom:"));
+        assert_ne!(key, KeyGenerator::ip_key("rate_limit:global:ip", "tenant-a"));
+    }
 }
@@ -1,8 +1,12 @@
 //! Account lockout functionality for failed login attempts
 
-use crate::limiter::{RateLimitError, RateLimiter};
-use crate::memory_limiter::InMemoryRateLimiter;
+use crate::config::RateLimitConfig;
+use crate::limiter::RateLimitError;
+use crate::lockout_store::{
+    InMemoryLockoutStore, LockoutStore, RateLimiterLockoutStore, RedisLockoutStore,
+};
 use crate::middleware::SharedRateLimiter;
+use shared_types::SharedClock;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::{info, warn};
@@ -18,6 +22,9 @@ pub struct LockoutStatus {
     pub remaining_seconds: Option<u64>,
     /// Progressive delay to apply (in milliseconds)
     pub delay_ms: Option<u64>,
+    /// Which failed attempt this status was produced for, set by
+    /// [`AccountLockout::record_failed_attempt`]
+    pub attempt_number: Option<u32>,
 }
 
 impl LockoutStatus {
@@ -28,6 +35,7 @@ impl LockoutStatus {
             failed_attempts,
             remaining_seconds: None,
             delay_ms,
+            attempt_number: None,
         }
     }
 
@@ -38,6 +46,65 @@ impl LockoutStatus {
             failed_attempts,
             remaining_seconds: Some(remaining_seconds),
             delay_ms: None,
+            attempt_number: None,
+        }
+    }
+
+    /// Tag the status with the failed attempt it was produced for
+    pub fn with_attempt_number(mut self, attempt_number: u32) -> Self {
+        self.attempt_number = Some(attempt_number);
+        self
+    }
+}
+
+/// Delay applied after each failed login attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackoffSchedule {
+    /// No delays
+    None,
+    /// Explicit delay in milliseconds per attempt number (1st failure uses the
+    /// first entry); attempts past the end reuse the last entry
+    Steps(Vec<u64>),
+    /// `base_ms * factor^(attempt - 1)`, capped at `max_ms`
+    Exponential {
+        base_ms: u64,
+        factor: u64,
+        max_ms: u64,
+    },
+}
+
+impl Default for BackoffSchedule {
+    /// 1s, 2s, 4s, 8s, then 16s for every further attempt
+    fn default() -> Self {
+        Self::Exponential {
+            base_ms: 1000,
+            factor: 2,
+            max_ms: 16_000,
+        }
+    }
+}
+
+impl BackoffSchedule {
+    /// Delay in milliseconds for the given failed attempt number (1-based)
+    pub fn delay_for(&self, attempt: u32) -> Option<u64> {
+        if attempt == 0 {
+            return None;
+        }
+
+        match self {
+            Self::None => None,
+            Self::Steps(steps) => {
+                let index = (attempt as usize - 1).min(steps.len().checked_sub(1)?);
+                Some(steps[index])
+            },
+            Self::Exponential {
+                base_ms,
+                factor,
+                max_ms,
+            } => {
+                let multiplier = factor.checked_pow(attempt - 1).unwrap_or(u64::MAX);
+                Some(base_ms.saturating_mul(multiplier).min(*max_ms))
+            },
         }
     }
 }
@@ -45,82 +112,96 @@ impl LockoutStatus {
 /// Account lockout manager
 #[derive(Clone)]
 pub struct AccountLockout {
-    /// The underlying rate limiter
-    limiter: Arc<SharedRateLimiter>,
+    /// Where failed attempts and locks are kept
+    store: Arc<dyn LockoutStore>,
     /// Maximum failed attempts before lockout
     threshold: u32,
     /// Lockout duration in seconds
     lockout_duration: u64,
-    /// Enable progressive delays
-    progressive_delays: bool,
+    /// Progressive delay per failed attempt
+    backoff: BackoffSchedule,
 }
 
 impl AccountLockout {
-    /// Create a new account lockout manager
-    pub fn new(limiter: Arc<SharedRateLimiter>, threshold: u32, lockout_duration: u64) -> Self {
-        Self {
-            limiter,
+    /// Create a new account lockout manager backed by the rate limiter's state
+    ///
+    /// Prefer [`AccountLockout::with_store`] so lockout state does not share keys
+    /// with rate-limit windows.
+    pub fn new(
+        limiter: Arc<SharedRateLimiter>,
+        threshold: u32,
+        lockout_duration: u64,
+        backoff: BackoffSchedule,
+    ) -> Self {
+        Self::with_store(
+            Arc::new(RateLimiterLockoutStore::new(limiter)),
             threshold,
             lockout_duration,
-            progressive_delays: true,
-        }
+        )
+        .with_backoff(backoff)
     }
 
-    /// Create with in-memory limiter for testing
-    pub fn in_memory(threshold: u32, lockout_duration: u64) -> Self {
+    /// Create an account lockout manager with a dedicated store
+    pub fn with_store(store: Arc<dyn LockoutStore>, threshold: u32, lockout_duration: u64) -> Self {
         Self {
-            limiter: Arc::new(SharedRateLimiter::InMemory(InMemoryRateLimiter::new())),
+            store,
             threshold,
             lockout_duration,
-            progressive_delays: true,
+            backoff: BackoffSchedule::default(),
         }
     }
 
-    /// Disable progressive delays
-    pub fn without_progressive_delays(mut self) -> Self {
-        self.progressive_delays = false;
-        self
+    /// Create from configuration, using a Redis store when `redis_url` is set
+    /// (falling back to in-memory if Redis is unreachable)
+    pub async fn from_config(config: &RateLimitConfig) -> Self {
+        let store: Arc<dyn LockoutStore> = match &config.redis_url {
+            Some(redis_url) => match RedisLockoutStore::new(redis_url).await {
+                Ok(store) => {
+                    info!("Account lockout using Redis backend");
+                    Arc::new(store)
+                },
+                Err(e) => {
+                    warn!(
+                        "Failed to connect to Redis for account lockout: {}. Falling back to in-memory.",
+                        e
+                    );
+                    Arc::new(InMemoryLockoutStore::new())
+                },
+            },
+            None => Arc::new(InMemoryLockoutStore::new()),
+        };
+
+        Self::with_store(store, config.lockout_threshold, config.lockout_duration_seconds)
     }
 
-    /// Build the lockout key for a user
-    fn lockout_key(user_id: &str) -> String {
-        format!("lockout:user:{}", user_id)
+    /// Create with in-memory store for testing
+    pub fn in_memory(threshold: u32, lockout_duration: u64) -> Self {
+        Self::with_store(Arc::new(InMemoryLockoutStore::new()), threshold, lockout_duration)
     }
 
-    /// Build the failed attempts key for a user
-    fn failed_key(user_id: &str) -> String {
-        format!("failed_login:user:{}", user_id)
+    /// Create with an in-memory store that reads the time from `clock`
+    pub fn in_memory_with_clock(threshold: u32, lockout_duration: u64, clock: SharedClock) -> Self {
+        Self::with_store(
+            Arc::new(InMemoryLockoutStore::with_clock(clock)),
+            threshold,
+            lockout_duration,
+        )
     }
 
-    /// Calculate progressive delay based on failed attempts
-    fn calculate_delay(&self, attempts: u32) -> Option<u64> {
-        if !self.progressive_delays || attempts == 0 {
-            return None;
-        }
+    /// Use a different progressive delay schedule
+    pub fn with_backoff(mut self, backoff: BackoffSchedule) -> Self {
+        self.backoff = backoff;
+        self
+    }
 
-        // Progressive delay: 1s, 2s, 4s, 8s, 16s (capped at 16 seconds)
-        // attempts: 1->1s, 2->2s, 3->4s, 4->8s, 5+->16s
-        let delay_secs = 1u64 << (attempts.min(5) - 1);
-        Some(delay_secs * 1000) // Return in milliseconds
+    /// Disable progressive delays
+    pub fn without_progressive_delays(self) -> Self {
+        self.with_backoff(BackoffSchedule::None)
     }
 
     /// Check if an account is locked
     pub async fn check_lockout(&self, user_id: &str) -> Result<LockoutStatus, RateLimitError> {
-        let lockout_key = Self::lockout_key(user_id);
-        let failed_key = Self::failed_key(user_id);
-
-        // Check lockout status
-        let lockout_count = match &*self.limiter {
-            SharedRateLimiter::Redis(l) => l.get_count(&lockout_key).await?,
-            SharedRateLimiter::InMemory(l) => l.get_count(&lockout_key).await?,
-        };
-
-        if lockout_count > 0 {
-            // Account is locked - get actual remaining TTL
-            let remaining = match &*self.limiter {
-                SharedRateLimiter::Redis(l) => l.get_ttl(&lockout_key).await?,
-                SharedRateLimiter::InMemory(l) => l.get_ttl(&lockout_key).await?,
-            };
+        if let Some(remaining) = self.store.lock_remaining(user_id).await? {
             // Use actual TTL if available, otherwise fall back to full duration
             let remaining_seconds = if remaining > 0 {
                 remaining
@@ -130,13 +211,8 @@ impl AccountLockout {
             return Ok(LockoutStatus::locked(self.threshold, remaining_seconds));
         }
 
-        // Get failed attempt count
-        let failed_count = match &*self.limiter {
-            SharedRateLimiter::Redis(l) => l.get_count(&failed_key).await?,
-            SharedRateLimiter::InMemory(l) => l.get_count(&failed_key).await?,
-        };
-
-        let delay = self.calculate_delay(failed_count);
+        let failed_count = self.store.failure_count(user_id).await?;
+        let delay = self.backoff.delay_for(failed_count);
         Ok(LockoutStatus::unlocked(failed_count, delay))
     }
 
@@ -145,47 +221,31 @@ impl AccountLockout {
         &self,
         user_id: &str,
     ) -> Result<LockoutStatus, RateLimitError> {
-        let failed_key = Self::failed_key(user_id);
-
-        // Increment failed attempts
-        let result = self
-            .limiter
-            .check(&failed_key, self.threshold + 1, Duration::from_secs(self.lockout_duration))
-            .await?;
-
-        let failed_count = self.threshold + 1 - result.remaining;
+        let duration = Duration::from_secs(self.lockout_duration);
+        let failed_count = self.store.increment_failures(user_id, duration).await?;
 
         // Check if we've hit the threshold
         if failed_count >= self.threshold {
-            // Lock the account
-            let lockout_key = Self::lockout_key(user_id);
-            self.limiter
-                .check(&lockout_key, 1, Duration::from_secs(self.lockout_duration))
-                .await?;
+            self.store.lock(user_id, duration).await?;
 
             info!("Account {} locked after {} failed attempts", user_id, failed_count);
 
-            return Ok(LockoutStatus::locked(failed_count, self.lockout_duration));
+            return Ok(LockoutStatus::locked(failed_count, self.lockout_duration)
+                .with_attempt_number(failed_count));
         }
 
-        let delay = self.calculate_delay(failed_count);
+        let delay = self.backoff.delay_for(failed_count);
         warn!(
             "Failed login attempt {} for user {}, delay: {:?}ms",
             failed_count, user_id, delay
         );
 
-        Ok(LockoutStatus::unlocked(failed_count, delay))
+        Ok(LockoutStatus::unlocked(failed_count, delay).with_attempt_number(failed_count))
     }
 
     /// Record a successful login (reset failed attempts)
     pub async fn record_success(&self, user_id: &str) -> Result<(), RateLimitError> {
-        let failed_key = Self::failed_key(user_id);
-        let lockout_key = Self::lockout_key(user_id);
-
-        self.limiter.reset(&failed_key).await?;
-        self.limiter.reset(&lockout_key).await?;
-
-        Ok(())
+        self.store.clear(user_id).await
     }
 
     /// Manually unlock an account (admin action)
@@ -197,6 +257,8 @@ impl AccountLockout {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::{Duration as ChronoDuration, TimeZone, Utc};
+    use shared_types::MockClock;
 
     #[tokio::test]
     async fn test_lockout_after_threshold() {
@@ -282,6 +344,43 @@ mod tests {
         assert_eq!(status.delay_ms, Some(16000));
     }
 
+    #[tokio::test]
+    async fn test_configured_backoff_schedule() {
+        let limiter = Arc::new(SharedRateLimiter::InMemory(
+            crate::memory_limiter::InMemoryRateLimiter::new(),
+        ));
+        let lockout =
+            AccountLockout::new(limiter, 10, 60, BackoffSchedule::Steps(vec![250, 500, 3000]));
+        let user_id = "test-user-9";
+
+        for (attempt, expected) in [(1, 250), (2, 500), (3, 3000), (4, 3000)] {
+            let status = lockout.record_failed_attempt(user_id).await.unwrap();
+            assert_eq!(status.attempt_number, Some(attempt));
+            assert_eq!(status.delay_ms, Some(expected), "attempt {}", attempt);
+        }
+
+        // Checking does not record an attempt
+        let status = lockout.check_lockout(user_id).await.unwrap();
+        assert_eq!(status.attempt_number, None);
+        assert_eq!(status.delay_ms, Some(3000));
+    }
+
+    #[test]
+    fn test_exponential_backoff_caps_at_max() {
+        let backoff = BackoffSchedule::Exponential {
+            base_ms: 100,
+            factor: 3,
+            max_ms: 1000,
+        };
+        assert_eq!(backoff.delay_for(0), None);
+        assert_eq!(backoff.delay_for(1), Some(100));
+        assert_eq!(backoff.delay_for(2), Some(300));
+        assert_eq!(backoff.delay_for(3), Some(900));
+        assert_eq!(backoff.delay_for(4), Some(1000));
+        assert_eq!(backoff.delay_for(100), Some(1000));
+        assert_eq!(BackoffSchedule::Steps(vec![]).delay_for(1), None);
+    }
+
     #[tokio::test]
     async fn test_success_resets_attempts() {
         let lockout = AccountLockout::in_memory(5, 60);
@@ -301,4 +400,91 @@ mod tests {
         let status = lockout.check_lockout(user_id).await.unwrap();
         assert_eq!(status.failed_attempts, 0);
     }
+
+    #[tokio::test]
+    async fn test_lock_expires_exactly_after_lockout_duration() {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+        let lockout = AccountLockout::in_memory_with_clock(2, 60, Arc::new(clock.clone()));
+        let user_id = "test-user-8";
+
+        lockout.record_failed_attempt(user_id).await.unwrap();
+        assert!(
+            lockout
+                .record_failed_attempt(user_id)
+                .await
+                .unwrap()
+                .is_locked
+        );
+
+        // One second before the end the lock is still in place
+        clock.advance(ChronoDuration::seconds(59));
+        let status = lockout.check_lockout(user_id).await.unwrap();
+        assert!(status.is_locked);
+        assert_eq!(status.remaining_seconds, Some(1));
+
+        // At exactly the lockout duration it is gone, along with the failures
+        clock.advance(ChronoDuration::seconds(1));
+        let status = lockout.check_lockout(user_id).await.unwrap();
+        assert!(!status.is_locked);
+        assert_eq!(status.failed_attempts, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dedicated_store_survives_rate_limit_reset() {
+        let limiter = Arc::new(SharedRateLimiter::InMemory(
+            crate::memory_limiter::InMemoryRateLimiter::new(),
+        ));
+        let store = Arc::new(InMemoryLockoutStore::new());
+        let lockout = AccountLockout::with_store(store, 2, 60);
+        let user_id = "test-user-6";
+
+        lockout.record_failed_attempt(user_id).await.unwrap();
+        lockout.record_failed_attempt(user_id).await.unwrap();
+
+        // Lockout state is not kept in the rate limiter
+        assert_eq!(
+            limiter
+                .get_count("failed_login:user:test-user-6")
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(limiter.get_count("lockout:user:test-user-6").await.unwrap(), 0);
+
+        // Rate-limit windows resetting leaves the lock in place
+        limiter
+            .reset("failed_login:user:test-user-6")
+            .await
+            .unwrap();
+        limiter.reset("lockout:user:test-user-6").await.unwrap();
+
+        let status = lockout.check_lockout(user_id).await.unwrap();
+        assert!(status.is_locked);
+        assert!(status.remaining_seconds.unwrap() <= 60);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_adapter_keeps_existing_keys() {
+        let limiter = Arc::new(SharedRateLimiter::InMemory(
+            crate::memory_limiter::InMemoryRateLimiter::new(),
+        ));
+        let lockout = AccountLockout::new(limiter.clone(), 2, 60, BackoffSchedule::default());
+        let user_id = "test-user-7";
+
+        lockout.record_failed_attempt(user_id).await.unwrap();
+        assert_eq!(
+            limiter
+                .get_count("failed_login:user:test-user-7")
+                .await
+                .unwrap(),
+            1
+        );
+
+        lockout.record_failed_attempt(user_id).await.unwrap();
+        assert!(lockout.check_lockout(user_id).await.unwrap().is_locked);
+
+        // With the adapter, resetting the rate-limit keys also clears the lock
+        limiter.reset("lockout:user:test-user-7").await.unwrap();
+        assert!(!lockout.check_lockout(user_id).await.unwrap().is_locked);
+    }
 }
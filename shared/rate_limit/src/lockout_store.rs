@@ -0,0 +1,398 @@
+//! Storage backends for account lockout state
+//!
+//! Lockout counters are kept apart from rate-limit windows so they can use
+//! their own TTLs and namespace, and are not cleared when rate-limit keys are
+//! reset or expire.
+
+use crate::limiter::RateLimitError;
+use crate::middleware::SharedRateLimiter;
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use shared_types::{SharedClock, SystemClock};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Storage for failed-attempt counters and account locks
+#[async_trait]
+pub trait LockoutStore: Send + Sync {
+    /// Record a failed attempt and return the number of failures within `window`
+    async fn increment_failures(
+        &self,
+        user_id: &str,
+        window: Duration,
+    ) -> Result<u32, RateLimitError>;
+
+    /// Get the number of recorded failures without incrementing
+    async fn failure_count(&self, user_id: &str) -> Result<u32, RateLimitError>;
+
+    /// Lock the account for `duration`
+    async fn lock(&self, user_id: &str, duration: Duration) -> Result<(), RateLimitError>;
+
+    /// Remaining lock time in seconds, or `None` if the account is not locked
+    async fn lock_remaining(&self, user_id: &str) -> Result<Option<u64>, RateLimitError>;
+
+    /// Clear failures and any lock for the account
+    async fn clear(&self, user_id: &str) -> Result<(), RateLimitError>;
+}
+
+/// Lockout data for one account
+#[derive(Debug, Clone, Default)]
+struct LockoutEntry {
+    failures: u32,
+    /// When the failure counter expires (unix seconds)
+    failures_expire_at: u64,
+    /// When the lock expires (unix seconds), if locked
+    locked_until: Option<u64>,
+}
+
+impl LockoutEntry {
+    fn active_failures(&self, now: u64) -> u32 {
+        if now < self.failures_expire_at {
+            self.failures
+        } else {
+            0
+        }
+    }
+
+    fn lock_remaining(&self, now: u64) -> Option<u64> {
+        self.locked_until
+            .filter(|&until| until > now)
+            .map(|until| until - now)
+    }
+}
+
+/// In-memory lockout store for single-instance deployments and tests
+#[derive(Debug, Clone)]
+pub struct InMemoryLockoutStore {
+    entries: Arc<RwLock<HashMap<String, LockoutEntry>>>,
+    clock: SharedClock,
+}
+
+impl Default for InMemoryLockoutStore {
+    fn default() -> Self {
+        Self::with_clock(SystemClock::shared())
+    }
+}
+
+impl InMemoryLockoutStore {
+    /// Create a new in-memory lockout store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a store that reads the time from `clock`, so tests can freeze it
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(HashMap::new())),
+            clock,
+        }
+    }
+
+    /// Current timestamp in seconds
+    fn now_secs(&self) -> u64 {
+        self.clock.now().timestamp().max(0) as u64
+    }
+}
+
+#[async_trait]
+impl LockoutStore for InMemoryLockoutStore {
+    async fn increment_failures(
+        &self,
+        user_id: &str,
+        window: Duration,
+    ) -> Result<u32, RateLimitError> {
+        let now = self.now_secs();
+        let mut entries = self.entries.write().await;
+        let entry = entries.entry(user_id.to_string()).or_default();
+
+        if entry.active_failures(now) == 0 {
+            // Window starts with the first failure, like a Redis INCR + EXPIRE
+            entry.failures = 0;
+            entry.failures_expire_at = now + window.as_secs();
+        }
+        entry.failures += 1;
+        Ok(entry.failures)
+    }
+
+    async fn failure_count(&self, user_id: &str) -> Result<u32, RateLimitError> {
+        let entries = self.entries.read().await;
+        Ok(entries
+            .get(user_id)
+            .map(|entry| entry.active_failures(self.now_secs()))
+            .unwrap_or(0))
+    }
+
+    async fn lock(&self, user_id: &str, duration: Duration) -> Result<(), RateLimitError> {
+        let mut entries = self.entries.write().await;
+        let entry = entries.entry(user_id.to_string()).or_default();
+        entry.locked_until = Some(self.now_secs() + duration.as_secs());
+        Ok(())
+    }
+
+    async fn lock_remaining(&self, user_id: &str) -> Result<Option<u64>, RateLimitError> {
+        let entries = self.entries.read().await;
+        Ok(entries
+            .get(user_id)
+            .and_then(|entry| entry.lock_remaining(self.now_secs())))
+    }
+
+    async fn clear(&self, user_id: &str) -> Result<(), RateLimitError> {
+        self.entries.write().await.remove(user_id);
+        Ok(())
+    }
+}
+
+/// Redis lockout store, shared across instances
+///
+/// Uses plain counters with their own TTLs under a dedicated key prefix, so
+/// lockout state is independent of the rate limiter's sliding-window keys.
+#[derive(Clone)]
+pub struct RedisLockoutStore {
+    connection: ConnectionManager,
+    key_prefix: String,
+}
+
+impl RedisLockoutStore {
+    /// Create a new Redis lockout store
+    pub async fn new(redis_url: &str) -> Result<Self, RateLimitError> {
+        Self::with_prefix(redis_url, "lockout").await
+    }
+
+    /// Create with custom key prefix
+    pub async fn with_prefix(redis_url: &str, prefix: &str) -> Result<Self, RateLimitError> {
+        let client = redis::Client::open(redis_url)
+            .map_err(|e| RateLimitError::RedisError(e.to_string()))?;
+
+        let connection = ConnectionManager::new(client)
+            .await
+            .map_err(|e| RateLimitError::RedisError(e.to_string()))?;
+
+        Ok(Self {
+            connection,
+            key_prefix: prefix.to_string(),
+        })
+    }
+
+    fn failures_key(&self, user_id: &str) -> String {
+        format!("{}:failures:{}", self.key_prefix, user_id)
+    }
+
+    fn lock_key(&self, user_id: &str) -> String {
+        format!("{}:locked:{}", self.key_prefix, user_id)
+    }
+}
+
+#[async_trait]
+impl LockoutStore for RedisLockoutStore {
+    async fn increment_failures(
+        &self,
+        user_id: &str,
+        window: Duration,
+    ) -> Result<u32, RateLimitError> {
+        // The window starts with the first failure; later failures don't extend it
+        let script = redis::Script::new(
+            r#"
+            local count = redis.call('INCR', KEYS[1])
+            if count == 1 then
+                redis.call('EXPIRE', KEYS[1], ARGV[1])
+            end
+            return count
+            "#,
+        );
+
+        let mut conn = self.connection.clone();
+        let count: u32 = script
+            .key(self.failures_key(user_id))
+            .arg(window.as_secs().max(1))
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| RateLimitError::RedisError(e.to_string()))?;
+
+        Ok(count)
+    }
+
+    async fn failure_count(&self, user_id: &str) -> Result<u32, RateLimitError> {
+        let mut conn = self.connection.clone();
+        let count: Option<u32> = conn
+            .get(self.failures_key(user_id))
+            .await
+            .map_err(|e| RateLimitError::RedisError(e.to_string()))?;
+
+        Ok(count.unwrap_or(0))
+    }
+
+    async fn lock(&self, user_id: &str, duration: Duration) -> Result<(), RateLimitError> {
+        let mut conn = self.connection.clone();
+        conn.set_ex::<_, _, ()>(self.lock_key(user_id), 1, duration.as_secs().max(1))
+            .await
+            .map_err(|e| RateLimitError::RedisError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn lock_remaining(&self, user_id: &str) -> Result<Option<u64>, RateLimitError> {
+        let mut conn = self.connection.clone();
+        let ttl: i64 = conn
+            .ttl(self.lock_key(user_id))
+            .await
+            .map_err(|e| RateLimitError::RedisError(e.to_string()))?;
+
+        // TTL returns -2 if key doesn't exist, -1 if no TTL set
+        Ok(match ttl {
+            -2 => None,
+            ttl if ttl > 0 => Some(ttl as u64),
+            _ => Some(0),
+        })
+    }
+
+    async fn clear(&self, user_id: &str) -> Result<(), RateLimitError> {
+        let mut conn = self.connection.clone();
+        conn.del::<_, ()>(&[self.failures_key(user_id), self.lock_key(user_id)])
+            .await
+            .map_err(|e| RateLimitError::RedisError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for RedisLockoutStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisLockoutStore")
+            .field("key_prefix", &self.key_prefix)
+            .finish()
+    }
+}
+
+/// Lockout store backed by the rate limiter's state
+///
+/// Keeps the original behaviour of `AccountLockout::new`, where lockout keys
+/// live alongside rate-limit keys in the same backend.
+#[derive(Clone)]
+pub struct RateLimiterLockoutStore {
+    limiter: Arc<SharedRateLimiter>,
+}
+
+impl RateLimiterLockoutStore {
+    /// Wrap a shared rate limiter
+    pub fn new(limiter: Arc<SharedRateLimiter>) -> Self {
+        Self { limiter }
+    }
+
+    /// Build the lockout key for a user
+    fn lockout_key(user_id: &str) -> String {
+        format!("lockout:user:{}", user_id)
+    }
+
+    /// Build the failed attempts key for a user
+    fn failed_key(user_id: &str) -> String {
+        format!("failed_login:user:{}", user_id)
+    }
+}
+
+#[async_trait]
+impl LockoutStore for RateLimiterLockoutStore {
+    async fn increment_failures(
+        &self,
+        user_id: &str,
+        window: Duration,
+    ) -> Result<u32, RateLimitError> {
+        let result = self
+            .limiter
+            .check(&Self::failed_key(user_id), u32::MAX, window)
+            .await?;
+        Ok(u32::MAX - result.remaining)
+    }
+
+    async fn failure_count(&self, user_id: &str) -> Result<u32, RateLimitError> {
+        self.limiter.get_count(&Self::failed_key(user_id)).await
+    }
+
+    async fn lock(&self, user_id: &str, duration: Duration) -> Result<(), RateLimitError> {
+        self.limiter
+            .check(&Self::lockout_key(user_id), 1, duration)
+            .await?;
+        Ok(())
+    }
+
+    async fn lock_remaining(&self, user_id: &str) -> Result<Option<u64>, RateLimitError> {
+        let lockout_key = Self::lockout_key(user_id);
+        if self.limiter.get_count(&lockout_key).await? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(self.limiter.get_ttl(&lockout_key).await?))
+    }
+
+    async fn clear(&self, user_id: &str) -> Result<(), RateLimitError> {
+        self.limiter.reset(&Self::failed_key(user_id)).await?;
+        self.limiter.reset(&Self::lockout_key(user_id)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_store_counts_and_locks() {
+        let store = InMemoryLockoutStore::new();
+        let window = Duration::from_secs(60);
+
+        assert_eq!(store.failure_count("user-1").await.unwrap(), 0);
+        assert_eq!(store.increment_failures("user-1", window).await.unwrap(), 1);
+        assert_eq!(store.increment_failures("user-1", window).await.unwrap(), 2);
+        assert_eq!(store.failure_count("user-1").await.unwrap(), 2);
+        assert_eq!(store.failure_count("user-2").await.unwrap(), 0);
+
+        assert_eq!(store.lock_remaining("user-1").await.unwrap(), None);
+        store
+            .lock("user-1", Duration::from_secs(300))
+            .await
+            .unwrap();
+        let remaining = store.lock_remaining("user-1").await.unwrap().unwrap();
+        assert!(remaining > 0 && remaining <= 300);
+
+        store.clear("user-1").await.unwrap();
+        assert_eq!(store.failure_count("user-1").await.unwrap(), 0);
+        assert_eq!(store.lock_remaining("user-1").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_expires_failures_and_locks() {
+        let store = InMemoryLockoutStore::new();
+
+        store
+            .increment_failures("user-1", Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(store.failure_count("user-1").await.unwrap(), 0);
+
+        store.lock("user-1", Duration::ZERO).await.unwrap();
+        assert_eq!(store.lock_remaining("user-1").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires running Redis instance"]
+    async fn test_redis_store_counts_and_locks() {
+        let redis_url = std::env::var("REDIS_URL").unwrap_or("redis://localhost:6379".to_string());
+        let store = RedisLockoutStore::new(&redis_url).await.unwrap();
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let window = Duration::from_secs(60);
+
+        assert_eq!(store.increment_failures(&user_id, window).await.unwrap(), 1);
+        assert_eq!(store.increment_failures(&user_id, window).await.unwrap(), 2);
+        assert_eq!(store.failure_count(&user_id).await.unwrap(), 2);
+
+        store
+            .lock(&user_id, Duration::from_secs(300))
+            .await
+            .unwrap();
+        assert!(store.lock_remaining(&user_id).await.unwrap().is_some());
+
+        store.clear(&user_id).await.unwrap();
+        assert_eq!(store.failure_count(&user_id).await.unwrap(), 0);
+        assert_eq!(store.lock_remaining(&user_id).await.unwrap(), None);
+    }
+}
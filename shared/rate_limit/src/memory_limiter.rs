@@ -1,5 +1,6 @@
-//! In-memory rate limiter implementation using sliding window
+//! In-memory rate limiter implementation using sliding or fixed windows
 
+use crate::config::RateLimitStrategy;
 use crate::limiter::{RateLimitError, RateLimitResult, RateLimiter};
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -31,7 +32,11 @@ impl RateLimitEntry {
     }
 }
 
-/// In-memory rate limiter using sliding window log algorithm
+/// In-memory rate limiter using a timestamp log
+///
+/// With [`RateLimitStrategy::SlidingWindow`] (the default) the log is trimmed to
+/// the trailing window; with [`RateLimitStrategy::FixedWindow`] it is trimmed to
+/// the start of the current clock-aligned window.
 ///
 /// This implementation is suitable for single-instance deployments or testing.
 /// For distributed systems, use `RedisRateLimiter` instead.
@@ -41,6 +46,8 @@ pub struct InMemoryRateLimiter {
     store: Arc<RwLock<HashMap<String, RateLimitEntry>>>,
     /// Maximum entries before cleanup
     max_entries: usize,
+    /// Window counting algorithm
+    strategy: RateLimitStrategy,
 }
 
 impl InMemoryRateLimiter {
@@ -49,6 +56,7 @@ impl InMemoryRateLimiter {
         Self {
             store: Arc::new(RwLock::new(HashMap::new())),
             max_entries: 10_000, // Default max entries
+            strategy: RateLimitStrategy::default(),
         }
     }
 
@@ -57,9 +65,16 @@ impl InMemoryRateLimiter {
         Self {
             store: Arc::new(RwLock::new(HashMap::new())),
             max_entries,
+            strategy: RateLimitStrategy::default(),
         }
     }
 
+    /// Use a different window counting algorithm
+    pub fn with_strategy(mut self, strategy: RateLimitStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Get current timestamp in seconds
     fn now_secs() -> u64 {
         SystemTime::now()
@@ -73,26 +88,18 @@ impl InMemoryRateLimiter {
         // Remove entries that haven't been accessed recently (1 hour)
         store.retain(|_, entry| now - entry.window_start < 3600);
     }
-}
 
-impl Default for InMemoryRateLimiter {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[async_trait]
-impl RateLimiter for InMemoryRateLimiter {
-    async fn check_rate_limit(
+    /// Check and record a request at the given time (seconds since the epoch)
+    async fn check_at(
         &self,
         key: &str,
         max_requests: u32,
         window: Duration,
+        now: u64,
     ) -> Result<RateLimitResult, RateLimitError> {
-        let now = Self::now_secs();
         let window_secs = window.as_secs();
-        let window_start = now.saturating_sub(window_secs);
-        let reset_at = now + window_secs;
+        let window_start = self.strategy.window_start(now, window_secs);
+        let reset_at = self.strategy.reset_at(now, window_secs);
 
         let mut store = self.store.write().await;
 
@@ -120,6 +127,25 @@ impl RateLimiter for InMemoryRateLimiter {
             Ok(RateLimitResult::allowed(max_requests, remaining, reset_at))
         }
     }
+}
+
+impl Default for InMemoryRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RateLimiter for InMemoryRateLimiter {
+    async fn check_rate_limit(
+        &self,
+        key: &str,
+        max_requests: u32,
+        window: Duration,
+    ) -> Result<RateLimitResult, RateLimitError> {
+        self.check_at(key, max_requests, window, Self::now_secs())
+            .await
+    }
 
     async fn reset(&self, key: &str) -> Result<(), RateLimitError> {
         let mut store = self.store.write().await;
@@ -177,6 +203,7 @@ impl Clone for InMemoryRateLimiter {
         Self {
             store: Arc::clone(&self.store),
             max_entries: self.max_entries,
+            strategy: self.strategy,
         }
     }
 }
@@ -242,6 +269,34 @@ mod tests {
         assert_eq!(limiter.get_count(key).await.unwrap(), 2);
     }
 
+    /// Sends `max` requests just before a window boundary and `max` just after
+    async fn count_allowed_across_boundary(strategy: RateLimitStrategy) -> usize {
+        let limiter = InMemoryRateLimiter::new().with_strategy(strategy);
+        let key = "test:boundary";
+        let window = Duration::from_secs(60);
+        let boundary = 1_700_000_040; // multiple of 60
+
+        let mut allowed = 0;
+        for now in [boundary - 1, boundary] {
+            for _ in 0..5 {
+                if limiter.check_at(key, 5, window, now).await.unwrap().allowed {
+                    allowed += 1;
+                }
+            }
+        }
+        allowed
+    }
+
+    #[tokio::test]
+    async fn test_fixed_window_allows_boundary_burst() {
+        assert_eq!(count_allowed_across_boundary(RateLimitStrategy::FixedWindow).await, 10);
+    }
+
+    #[tokio::test]
+    async fn test_sliding_window_blocks_boundary_burst() {
+        assert_eq!(count_allowed_across_boundary(RateLimitStrategy::SlidingWindow).await, 5);
+    }
+
     #[tokio::test]
     async fn test_different_keys_independent() {
         let limiter = InMemoryRateLimiter::new();
@@ -1,22 +1,29 @@
 //! Axum middleware for rate limiting
 
-use crate::config::RateLimitConfig;
+use crate::config::{BackendErrorPolicy, Enforcement, RateLimitConfig};
 use crate::limiter::{KeyGenerator, RateLimitError, RateLimitResult, RateLimiter};
 use crate::memory_limiter::InMemoryRateLimiter;
 use crate::redis_limiter::RedisRateLimiter;
 use axum::body::Body;
 use axum::extract::ConnectInfo;
-use axum::http::{header, HeaderValue, Request, Response, StatusCode};
+use axum::http::{header, HeaderValue, Request, Response};
+use axum::response::IntoResponse;
+use shared_error::AppError;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
 use std::task::{Context, Poll};
 use std::time::Duration;
+use tokio::sync::watch;
 use tower::{Layer, Service};
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+/// Derives a rate limit bucket key from a request, see [`RateLimitLayer::with_key_fn`]
+pub type RateLimitKeyFn = Arc<dyn Fn(&Request<Body>) -> Option<String> + Send + Sync>;
+
 /// Rate limit endpoint type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RateLimitEndpoint {
@@ -66,6 +73,8 @@ impl RateLimitEndpoint {
 pub enum SharedRateLimiter {
     Redis(RedisRateLimiter),
     InMemory(InMemoryRateLimiter),
+    /// Any other [`RateLimiter`] implementation
+    Custom(Arc<dyn RateLimiter>),
 }
 
 impl SharedRateLimiter {
@@ -74,8 +83,8 @@ impl SharedRateLimiter {
         if let Some(redis_url) = &config.redis_url {
             match RedisRateLimiter::new(redis_url).await {
                 Ok(limiter) => {
-                    info!("Rate limiter using Redis backend");
-                    return Self::Redis(limiter);
+                    info!("Rate limiter using Redis backend ({:?})", config.strategy);
+                    return Self::Redis(limiter.with_strategy(config.strategy));
                 },
                 Err(e) => {
                     warn!(
@@ -86,8 +95,8 @@ impl SharedRateLimiter {
             }
         }
 
-        info!("Rate limiter using in-memory backend");
-        Self::InMemory(InMemoryRateLimiter::new())
+        info!("Rate limiter using in-memory backend ({:?})", config.strategy);
+        Self::InMemory(InMemoryRateLimiter::new().with_strategy(config.strategy))
     }
 
     /// Check rate limit
@@ -100,6 +109,7 @@ impl SharedRateLimiter {
         match self {
             Self::Redis(limiter) => limiter.check_rate_limit(key, max_requests, window).await,
             Self::InMemory(limiter) => limiter.check_rate_limit(key, max_requests, window).await,
+            Self::Custom(limiter) => limiter.check_rate_limit(key, max_requests, window).await,
         }
     }
 
@@ -108,6 +118,7 @@ impl SharedRateLimiter {
         match self {
             Self::Redis(limiter) => limiter.reset(key).await,
             Self::InMemory(limiter) => limiter.reset(key).await,
+            Self::Custom(limiter) => limiter.reset(key).await,
         }
     }
 
@@ -116,6 +127,7 @@ impl SharedRateLimiter {
         match self {
             Self::Redis(limiter) => limiter.get_count(key).await,
             Self::InMemory(limiter) => limiter.get_count(key).await,
+            Self::Custom(limiter) => limiter.get_count(key).await,
         }
     }
 
@@ -124,6 +136,7 @@ impl SharedRateLimiter {
         match self {
             Self::Redis(limiter) => limiter.get_ttl(key).await,
             Self::InMemory(limiter) => limiter.get_ttl(key).await,
+            Self::Custom(limiter) => limiter.get_ttl(key).await,
         }
     }
 
@@ -132,6 +145,7 @@ impl SharedRateLimiter {
         match self {
             Self::Redis(limiter) => limiter.is_healthy().await,
             Self::InMemory(limiter) => limiter.is_healthy().await,
+            Self::Custom(limiter) => limiter.is_healthy().await,
         }
     }
 }
@@ -141,21 +155,72 @@ impl SharedRateLimiter {
 pub struct RateLimitState {
     /// The rate limiter implementation
     pub limiter: Arc<SharedRateLimiter>,
-    /// Configuration
-    pub config: RateLimitConfig,
+    /// Current configuration, swapped as a whole by [`RateLimitState::reload`]
+    config: Arc<RwLock<Arc<RateLimitConfig>>>,
+    /// Requests that exceeded a limit but were allowed in monitor mode
+    would_block: Arc<AtomicU64>,
 }
 
 impl RateLimitState {
     /// Create a new rate limit state
     pub async fn from_config(config: RateLimitConfig) -> Self {
         let limiter = SharedRateLimiter::from_config(&config).await;
+        Self::new(limiter, config)
+    }
+
+    /// Create a rate limit state around an existing limiter
+    pub fn new(limiter: SharedRateLimiter, config: RateLimitConfig) -> Self {
         Self {
             limiter: Arc::new(limiter),
-            config,
+            config: Arc::new(RwLock::new(Arc::new(config))),
+            would_block: Arc::new(AtomicU64::new(0)),
         }
     }
 
+    /// Snapshot of the current configuration
+    pub fn config(&self) -> Arc<RateLimitConfig> {
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Replace the configuration for all clones of this state
+    ///
+    /// Takes effect on the next checked request. The limiter backend is chosen
+    /// at startup, so a changed `redis_url` or `strategy` is ignored. Counters already
+    /// recorded are kept and measured against the new limits.
+    pub fn reload(&self, config: RateLimitConfig) {
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(config);
+        info!("Rate limit configuration reloaded");
+    }
+
+    /// Reload the configuration whenever `config_rx` receives a new value
+    ///
+    /// The spawned task ends when the sender is dropped.
+    pub fn subscribe(
+        &self,
+        mut config_rx: watch::Receiver<RateLimitConfig>,
+    ) -> tokio::task::JoinHandle<()> {
+        let state = self.clone();
+        tokio::spawn(async move {
+            while config_rx.changed().await.is_ok() {
+                let config = config_rx.borrow_and_update().clone();
+                state.reload(config);
+            }
+        })
+    }
+
+    /// Number of requests that would have been blocked in monitor mode
+    pub fn would_block_count(&self) -> u64 {
+        self.would_block.load(Ordering::Relaxed)
+    }
+
     /// Check rate limit for an endpoint
+    ///
+    /// In [`Enforcement::Monitor`] mode an exceeded limit is reported as allowed and
+    /// counted in the `rate_limit_would_block` metric instead.
+    ///
     /// The identifier should be:
     /// - IP address for IP-based endpoints (Login, Register, AcceptInvite, Global)
     /// - Email for email-based endpoints (ForgotPassword)
@@ -165,29 +230,6 @@ impl RateLimitState {
         endpoint: RateLimitEndpoint,
         identifier: &str,
     ) -> Result<RateLimitResult, RateLimitError> {
-        let (max_requests, window_seconds) = match endpoint {
-            RateLimitEndpoint::Login => {
-                (self.config.login_max_attempts, self.config.login_window_seconds)
-            },
-            RateLimitEndpoint::Register => {
-                (self.config.register_max_attempts, self.config.register_window_seconds)
-            },
-            RateLimitEndpoint::ForgotPassword => {
-                (self.config.forgot_password_max, self.config.forgot_password_window)
-            },
-            RateLimitEndpoint::ResendVerification => {
-                (self.config.resend_verification_max, self.config.resend_verification_window)
-            },
-            RateLimitEndpoint::Refresh => (self.config.refresh_max, self.config.refresh_window),
-            RateLimitEndpoint::AcceptInvite => {
-                (self.config.accept_invite_max, self.config.accept_invite_window)
-            },
-            RateLimitEndpoint::FileUpload => {
-                (self.config.file_upload_max, self.config.file_upload_window)
-            },
-            RateLimitEndpoint::Global => (self.config.global_requests_per_second * 60, 60),
-        };
-
         // Use appropriate key generator based on endpoint type
         let key = match endpoint {
             RateLimitEndpoint::Login
@@ -204,9 +246,66 @@ impl RateLimitState {
             },
         };
 
-        self.limiter
-            .check(&key, max_requests, Duration::from_secs(window_seconds))
-            .await
+        self.check_key(endpoint, &key).await
+    }
+
+    /// Check rate limit for an endpoint using a caller-derived bucket key
+    ///
+    /// The endpoint's limits apply, but the bucket is keyed on `custom_key`
+    /// instead of the endpoint's usual IP, email or user identifier.
+    pub async fn check_endpoint_custom(
+        &self,
+        endpoint: RateLimitEndpoint,
+        custom_key: &str,
+    ) -> Result<RateLimitResult, RateLimitError> {
+        let key = KeyGenerator::custom_key(endpoint.key_prefix(), custom_key);
+        self.check_key(endpoint, &key).await
+    }
+
+    /// Check and record a request against an already generated limiter key
+    async fn check_key(
+        &self,
+        endpoint: RateLimitEndpoint,
+        key: &str,
+    ) -> Result<RateLimitResult, RateLimitError> {
+        let config = self.config();
+        let (max_requests, window_seconds) = match endpoint {
+            RateLimitEndpoint::Login => (config.login_max_attempts, config.login_window_seconds),
+            RateLimitEndpoint::Register => {
+                (config.register_max_attempts, config.register_window_seconds)
+            },
+            RateLimitEndpoint::ForgotPassword => {
+                (config.forgot_password_max, config.forgot_password_window)
+            },
+            RateLimitEndpoint::ResendVerification => {
+                (config.resend_verification_max, config.resend_verification_window)
+            },
+            RateLimitEndpoint::Refresh => (config.refresh_max, config.refresh_window),
+            RateLimitEndpoint::AcceptInvite => {
+                (config.accept_invite_max, config.accept_invite_window)
+            },
+            RateLimitEndpoint::FileUpload => (config.file_upload_max, config.file_upload_window),
+            RateLimitEndpoint::Global => (config.global_requests_per_second * 60, 60),
+        };
+
+        let result = self
+            .limiter
+            .check(key, max_requests, Duration::from_secs(window_seconds))
+            .await?;
+
+        // Monitor mode: record what would have been blocked, but let the request through
+        if !result.allowed && config.enforcement == Enforcement::Monitor {
+            self.would_block.fetch_add(1, Ordering::Relaxed);
+            metrics::counter!("rate_limit_would_block", "endpoint" => endpoint.key_prefix())
+                .increment(1);
+            info!("Rate limit would block {} on {:?} (monitor mode)", key, endpoint);
+            return Ok(RateLimitResult {
+                allowed: true,
+                ..result
+            });
+        }
+
+        Ok(result)
     }
 }
 
@@ -217,6 +316,8 @@ pub struct RateLimitLayer {
     endpoint: RateLimitEndpoint,
     /// JWT secret for user-based rate limiting
     jwt_secret: Option<String>,
+    /// Custom bucket key extractor, overriding the IP/user identifier
+    key_fn: Option<RateLimitKeyFn>,
 }
 
 impl RateLimitLayer {
@@ -226,6 +327,26 @@ impl RateLimitLayer {
             state,
             endpoint,
             jwt_secret: None,
+            key_fn: None,
+        }
+    }
+
+    /// Create a new rate limit layer that derives the bucket key from the request
+    ///
+    /// `key_fn` may read headers, the URI/path or extensions, but not the body.
+    /// It runs synchronously on every request before the limiter is consulted,
+    /// so it must be cheap and must not block. When it returns `None` the
+    /// endpoint's usual key (client IP, or user for user-based endpoints) is used.
+    /// The endpoint's limits and window still apply.
+    pub fn with_key_fn<F>(state: RateLimitState, endpoint: RateLimitEndpoint, key_fn: F) -> Self
+    where
+        F: Fn(&Request<Body>) -> Option<String> + Send + Sync + 'static,
+    {
+        Self {
+            state,
+            endpoint,
+            jwt_secret: None,
+            key_fn: Some(Arc::new(key_fn)),
         }
     }
 
@@ -239,6 +360,7 @@ impl RateLimitLayer {
             state,
             endpoint,
             jwt_secret: Some(jwt_secret),
+            key_fn: None,
         }
     }
 }
@@ -252,6 +374,7 @@ impl<S> Layer<S> for RateLimitLayer {
             state: self.state.clone(),
             endpoint: self.endpoint,
             jwt_secret: self.jwt_secret.clone(),
+            key_fn: self.key_fn.clone(),
         }
     }
 }
@@ -263,6 +386,7 @@ pub struct RateLimitMiddleware<S> {
     state: RateLimitState,
     endpoint: RateLimitEndpoint,
     jwt_secret: Option<String>,
+    key_fn: Option<RateLimitKeyFn>,
 }
 
 impl<S> Service<Request<Body>> for RateLimitMiddleware<S>
@@ -282,25 +406,33 @@ where
         let state = self.state.clone();
         let endpoint = self.endpoint;
         let jwt_secret = self.jwt_secret.clone();
+        let key_fn = self.key_fn.clone();
         let mut inner = self.inner.clone();
 
         Box::pin(async move {
+            let config = state.config();
+
             // Skip rate limiting if disabled
-            if !state.config.enabled {
+            if !config.enabled {
                 return inner.call(req).await;
             }
 
             // Extract client IP using configuration
-            let ip = extract_client_ip(&req, &state.config);
+            let ip = extract_client_ip(&req, &config);
 
             // Check if IP is trusted
-            if state.config.is_trusted_ip(&ip) {
+            if config.is_trusted_ip(&ip) {
                 debug!("Trusted IP {} bypassing rate limit", ip);
                 return inner.call(req).await;
             }
 
+            // A caller-supplied key takes precedence over the endpoint's identifier
+            let custom_key = key_fn.as_ref().and_then(|key_fn| key_fn(&req));
+
             // Determine the identifier based on endpoint type
-            let identifier = if endpoint.is_user_based() {
+            let identifier = if let Some(custom_key) = &custom_key {
+                custom_key.clone()
+            } else if endpoint.is_user_based() {
                 // For user-based endpoints, extract user ID from JWT
                 match extract_user_id_from_jwt(&req, jwt_secret.as_deref()) {
                     Some(user_id) => user_id.to_string(),
@@ -319,7 +451,12 @@ where
             };
 
             // Check rate limit
-            match state.check_endpoint(endpoint, &identifier).await {
+            let checked = if custom_key.is_some() {
+                state.check_endpoint_custom(endpoint, &identifier).await
+            } else {
+                state.check_endpoint(endpoint, &identifier).await
+            };
+            match checked {
                 Ok(result) if result.allowed => {
                     // Add rate limit headers to response
                     let response = inner.call(req).await?;
@@ -334,9 +471,23 @@ where
                     Ok(rate_limit_exceeded_response(&result))
                 },
                 Err(e) => {
-                    // Log error but allow request (fail open for availability)
-                    warn!("Rate limit check failed: {}. Allowing request.", e);
-                    inner.call(req).await
+                    let policy = config.on_backend_error;
+                    metrics::counter!(
+                        "rate_limit_backend_errors",
+                        "endpoint" => endpoint.key_prefix(),
+                        "policy" => policy.as_str()
+                    )
+                    .increment(1);
+                    match policy {
+                        BackendErrorPolicy::FailOpen => {
+                            warn!("Rate limit check failed: {}. Allowing request.", e);
+                            inner.call(req).await
+                        },
+                        BackendErrorPolicy::FailClosed => {
+                            warn!("Rate limit check failed: {}. Rejecting request.", e);
+                            Ok(rate_limit_unavailable_response())
+                        },
+                    }
                 },
             }
         })
@@ -355,7 +506,7 @@ fn extract_user_id_from_jwt<B>(req: &Request<B>, jwt_secret: Option<&str>) -> Op
     let token = auth_str.strip_prefix("Bearer ")?;
 
     // Decode JWT to get user ID
-    match shared_jwt::decode_jwt(token, jwt_secret) {
+    match shared_jwt::decode_access(token, jwt_secret) {
         Ok(claims) => Some(claims.sub),
         Err(e) => {
             debug!("Failed to decode JWT for rate limiting: {}", e);
@@ -430,19 +581,14 @@ fn add_rate_limit_headers<B>(mut response: Response<B>, result: &RateLimitResult
 }
 
 /// Create a 429 Too Many Requests response
+///
+/// Uses the standard `{error, code}` envelope; `AppError::RateLimited` sets
+/// the `Retry-After` header.
 fn rate_limit_exceeded_response(result: &RateLimitResult) -> Response<Body> {
-    let body = serde_json::json!({
-        "error": "rate_limit_exceeded",
-        "message": "Too many requests. Please try again later.",
-        "retry_after_seconds": result.retry_after
-    });
-
-    let mut response = Response::builder()
-        .status(StatusCode::TOO_MANY_REQUESTS)
-        .header(header::CONTENT_TYPE, "application/json")
-        .header("retry-after", result.retry_after.to_string())
-        .body(Body::from(body.to_string()))
-        .unwrap();
+    let mut response = AppError::RateLimited {
+        retry_after_secs: result.retry_after,
+    }
+    .into_response();
 
     // Add rate limit headers
     let headers = response.headers_mut();
@@ -457,6 +603,17 @@ fn rate_limit_exceeded_response(result: &RateLimitResult) -> Response<Body> {
     response
 }
 
+/// Create a 503 response for requests rejected because the limiter is unavailable
+///
+/// Uses the standard `{error, code}` envelope; `AppError::ServiceUnavailable`
+/// sets the `Retry-After` header.
+fn rate_limit_unavailable_response() -> Response<Body> {
+    AppError::ServiceUnavailable(
+        "Service temporarily unavailable. Please try again later.".to_string(),
+    )
+    .into_response()
+}
+
 /// Extension trait for adding rate limiting to axum routers
 pub trait RateLimitExt {
     /// Apply rate limiting to this router for a specific endpoint
@@ -475,6 +632,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::http::StatusCode;
 
     #[test]
     fn test_endpoint_key_prefix() {
@@ -526,6 +684,116 @@ mod tests {
         assert_eq!(count, 1);
     }
 
+    /// Limiter whose backend is always unreachable
+    struct FailingLimiter;
+
+    #[async_trait::async_trait]
+    impl RateLimiter for FailingLimiter {
+        async fn check_rate_limit(
+            &self,
+            _key: &str,
+            _max_requests: u32,
+            _window: Duration,
+        ) -> Result<RateLimitResult, RateLimitError> {
+            Err(RateLimitError::RedisError("connection refused".to_string()))
+        }
+
+        async fn reset(&self, _key: &str) -> Result<(), RateLimitError> {
+            Err(RateLimitError::RedisError("connection refused".to_string()))
+        }
+
+        async fn get_count(&self, _key: &str) -> Result<u32, RateLimitError> {
+            Err(RateLimitError::RedisError("connection refused".to_string()))
+        }
+
+        async fn get_ttl(&self, _key: &str) -> Result<u64, RateLimitError> {
+            Err(RateLimitError::RedisError("connection refused".to_string()))
+        }
+
+        async fn is_healthy(&self) -> bool {
+            false
+        }
+    }
+
+    async fn call_with_failing_backend(policy: BackendErrorPolicy) -> Response<Body> {
+        let config = RateLimitConfig {
+            on_backend_error: policy,
+            ..Default::default()
+        };
+        let state =
+            RateLimitState::new(SharedRateLimiter::Custom(Arc::new(FailingLimiter)), config);
+        let app = axum::Router::new()
+            .route("/login", axum::routing::post(|| async { "ok" }))
+            .layer(RateLimitLayer::new(state, RateLimitEndpoint::Login));
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/login")
+            .body(Body::empty())
+            .unwrap();
+        tower::ServiceExt::oneshot(app, request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_backend_error_fail_open_allows_request() {
+        let response = call_with_failing_backend(BackendErrorPolicy::FailOpen).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_backend_error_fail_closed_rejects_request() {
+        let response = call_with_failing_backend(BackendErrorPolicy::FailClosed).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "SERVICE_UNAVAILABLE");
+    }
+
+    async fn call_tenant_webhook(app: &axum::Router, tenant: Option<&str>) -> StatusCode {
+        let mut request = Request::builder().method("POST").uri("/webhook");
+        if let Some(tenant) = tenant {
+            request = request.header("x-tenant-id", tenant);
+        }
+        tower::ServiceExt::oneshot(app.clone(), request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_key_fn_buckets_by_custom_key() {
+        let config = RateLimitConfig {
+            login_max_attempts: 1,
+            ..Default::default()
+        };
+        let state = RateLimitState::from_config(config).await;
+        let app = axum::Router::new()
+            .route("/webhook", axum::routing::post(|| async { "ok" }))
+            .layer(RateLimitLayer::with_key_fn(state, RateLimitEndpoint::Login, |req| {
+                req.headers()
+                    .get("x-tenant-id")
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string)
+            }));
+
+        assert_eq!(call_tenant_webhook(&app, Some("tenant-a")).await, StatusCode::OK);
+        assert_eq!(
+            call_tenant_webhook(&app, Some("tenant-a")).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        // Other tenants have their own bucket
+        assert_eq!(call_tenant_webhook(&app, Some("tenant-b")).await, StatusCode::OK);
+
+        // No key: falls back to the IP bucket, which is still untouched
+        assert_eq!(call_tenant_webhook(&app, None).await, StatusCode::OK);
+        assert_eq!(call_tenant_webhook(&app, None).await, StatusCode::TOO_MANY_REQUESTS);
+    }
+
     #[tokio::test]
     async fn test_rate_limit_state() {
         let config = RateLimitConfig {
@@ -551,4 +819,54 @@ mod tests {
             .unwrap();
         assert!(!result.allowed);
     }
+
+    #[tokio::test]
+    async fn test_reload_applies_to_next_check() {
+        let state = RateLimitState::from_config(RateLimitConfig {
+            login_max_attempts: 5,
+            login_window_seconds: 60,
+            ..Default::default()
+        })
+        .await;
+
+        for _ in 0..2 {
+            let result = state
+                .check_endpoint(RateLimitEndpoint::Login, "10.1.1.1")
+                .await
+                .unwrap();
+            assert!(result.allowed);
+        }
+
+        // Tighten the limit mid-flight: the two recorded attempts already use it up
+        state.reload(RateLimitConfig {
+            login_max_attempts: 2,
+            login_window_seconds: 60,
+            ..Default::default()
+        });
+        let result = state
+            .check_endpoint(RateLimitEndpoint::Login, "10.1.1.1")
+            .await
+            .unwrap();
+        assert!(!result.allowed);
+        assert_eq!(result.limit, 2);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_reloads_from_watch_channel() {
+        let config = RateLimitConfig::default();
+        let state = RateLimitState::from_config(config.clone()).await;
+        let (config_tx, config_rx) = watch::channel(config);
+        let task = state.subscribe(config_rx);
+
+        config_tx
+            .send(RateLimitConfig {
+                login_max_attempts: 1,
+                ..Default::default()
+            })
+            .unwrap();
+        drop(config_tx);
+        task.await.unwrap();
+
+        assert_eq!(state.config().login_max_attempts, 1);
+    }
 }
@@ -1,5 +1,6 @@
-//! Redis-based rate limiter implementation using sliding window
+//! Redis-based rate limiter implementation using sliding or fixed windows
 
+use crate::config::RateLimitStrategy;
 use crate::limiter::{RateLimitError, RateLimitResult, RateLimiter};
 use async_trait::async_trait;
 use redis::aio::ConnectionManager;
@@ -9,9 +10,12 @@ use std::time::{Duration, SystemTime};
 use tokio::sync::RwLock;
 use tracing::{debug, error};
 
-/// Redis-based rate limiter using sliding window log algorithm
+/// Redis-based rate limiter using a sorted-set timestamp log
 ///
-/// Uses Redis sorted sets to implement accurate sliding window rate limiting.
+/// Each request is a sorted-set member scored by its timestamp. Entries older
+/// than the window start are dropped with `ZREMRANGEBYSCORE` before counting:
+/// the trailing window for [`RateLimitStrategy::SlidingWindow`] (the default),
+/// or the current clock-aligned window for [`RateLimitStrategy::FixedWindow`].
 /// Suitable for distributed deployments where multiple instances need to share
 /// rate limit state.
 #[derive(Clone)]
@@ -23,6 +27,8 @@ pub struct RedisRateLimiter {
     redis_url: String,
     /// Key prefix for all rate limit keys
     key_prefix: String,
+    /// Window counting algorithm
+    strategy: RateLimitStrategy,
 }
 
 impl RedisRateLimiter {
@@ -39,9 +45,16 @@ impl RedisRateLimiter {
             connection: Arc::new(RwLock::new(Some(connection))),
             redis_url: redis_url.to_string(),
             key_prefix: "rl".to_string(), // Short prefix to save memory
+            strategy: RateLimitStrategy::default(),
         })
     }
 
+    /// Use a different window counting algorithm
+    pub fn with_strategy(mut self, strategy: RateLimitStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Create with custom key prefix
     pub async fn with_prefix(redis_url: &str, prefix: &str) -> Result<Self, RateLimitError> {
         let mut limiter = Self::new(redis_url).await?;
@@ -97,8 +110,12 @@ impl RateLimiter for RedisRateLimiter {
         let full_key = self.build_key(key);
         let now = Self::now_millis();
         let window_millis = window.as_millis() as u64;
-        let window_start = now.saturating_sub(window_millis);
-        let reset_at = Self::now_secs() + window.as_secs();
+        // ZREMRANGEBYSCORE bounds are inclusive, so drop everything before the window start
+        let window_start = self
+            .strategy
+            .window_start(now, window_millis)
+            .saturating_sub(1);
+        let reset_at = self.strategy.reset_at(Self::now_secs(), window.as_secs());
 
         let conn_guard = self.connection.read().await;
         let mut conn = conn_guard
@@ -236,6 +253,7 @@ impl std::fmt::Debug for RedisRateLimiter {
         f.debug_struct("RedisRateLimiter")
             .field("redis_url", &"[REDACTED]")
             .field("key_prefix", &self.key_prefix)
+            .field("strategy", &self.strategy)
             .finish()
     }
 }
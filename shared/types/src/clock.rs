@@ -0,0 +1,91 @@
+//! Time source abstraction
+//!
+//! Code that makes time-dependent decisions (token expiry, lockout windows,
+//! expiry cut-offs) asks a [`Clock`] for the current time instead of calling
+//! `Utc::now()` directly, so tests can freeze and advance time.
+
+use chrono::{DateTime, Duration, Utc};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+/// Source of the current time
+pub trait Clock: Debug + Send + Sync {
+    /// Current time
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock shared between services
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The real wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// The system clock as a [`SharedClock`], the default for services
+    pub fn shared() -> SharedClock {
+        Arc::new(SystemClock)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A frozen clock that only moves when told to
+///
+/// Clones share the same time, so a test can keep one handle and pass
+/// another to the code under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    /// Create a clock frozen at `now`
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Jump to `now`
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = now;
+    }
+
+    /// Move the clock forward (or back, for a negative duration)
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) += by;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_mock_clock_is_frozen_until_advanced() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let clock = MockClock::new(start);
+        let shared: SharedClock = Arc::new(clock.clone());
+
+        assert_eq!(shared.now(), start);
+        assert_eq!(shared.now(), start);
+
+        clock.advance(Duration::seconds(90));
+        assert_eq!(shared.now(), start + Duration::seconds(90));
+
+        clock.set(start);
+        assert_eq!(shared.now(), start);
+    }
+}
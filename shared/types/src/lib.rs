@@ -1,5 +1,8 @@
 // Common types used across services
+pub mod clock;
+
 pub use chrono::{DateTime, Utc};
+pub use clock::{Clock, MockClock, SharedClock, SystemClock};
 pub use uuid::Uuid;
 
 // Money as cents (i64 to avoid floating point issues)